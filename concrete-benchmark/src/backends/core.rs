@@ -18,6 +18,10 @@ pub fn bench() {
     glwe_ciphertext_discarding_decryption::bench::<CoreEngine, GlweSecretKey64,  GlweCiphertext64, PlaintextVector64>(&mut criterion);
     glwe_ciphertext_discarding_encryption::bench::<CoreEngine, GlweSecretKey32, PlaintextVector32,  GlweCiphertext32>(&mut criterion);
     glwe_ciphertext_discarding_encryption::bench::<CoreEngine, GlweSecretKey64, PlaintextVector64,  GlweCiphertext64>(&mut criterion);
+    glwe_ciphertext_ggsw_ciphertext_discarding_external_product::bench::<CoreEngine, GlweCiphertext32, FourierGgswCiphertext32, GlweCiphertext32>(&mut criterion);
+    glwe_ciphertext_ggsw_ciphertext_discarding_external_product::bench::<CoreEngine, GlweCiphertext64, FourierGgswCiphertext64, GlweCiphertext64>(&mut criterion);
+    glwe_ciphertext_ggsw_ciphertext_external_product::bench::<CoreEngine, GlweCiphertext32, FourierGgswCiphertext32, GlweCiphertext32>(&mut criterion);
+    glwe_ciphertext_ggsw_ciphertext_external_product::bench::<CoreEngine, GlweCiphertext64, FourierGgswCiphertext64, GlweCiphertext64>(&mut criterion);
     glwe_ciphertext_vector_decryption::bench::<CoreEngine, GlweSecretKey32, GlweCiphertextVector32, PlaintextVector32>(&mut criterion);
     glwe_ciphertext_vector_decryption::bench::<CoreEngine, GlweSecretKey64, GlweCiphertextVector64, PlaintextVector64>(&mut criterion);
     glwe_ciphertext_vector_encryption::bench::<CoreEngine, GlweSecretKey32, PlaintextVector32, GlweCiphertextVector32>(&mut criterion);
@@ -30,6 +34,8 @@ pub fn bench() {
     glwe_ciphertext_vector_zero_encryption::bench::<CoreEngine, GlweSecretKey64, GlweCiphertextVector64>(&mut criterion);
     glwe_ciphertext_zero_encryption::bench::<CoreEngine, GlweSecretKey32, GlweCiphertext32>(&mut criterion);
     glwe_ciphertext_zero_encryption::bench::<CoreEngine, GlweSecretKey64, GlweCiphertext64>(&mut criterion);
+    glwe_relinearization_key_creation::bench::<CoreEngine, GlweSecretKey32, GlweRelinearizationKey32>(&mut criterion);
+    glwe_relinearization_key_creation::bench::<CoreEngine, GlweSecretKey64, GlweRelinearizationKey64>(&mut criterion);
     glwe_secret_key_creation::bench::<CoreEngine, GlweSecretKey32>(&mut criterion);
     glwe_secret_key_creation::bench::<CoreEngine, GlweSecretKey64>(&mut criterion);
     lwe_bootstrap_key_conversion::bench::<CoreEngine, LweBootstrapKey32, FourierLweBootstrapKey32>(&mut criterion);
@@ -62,6 +68,8 @@ pub fn bench() {
     lwe_ciphertext_discarding_extraction::bench::<CoreEngine, GlweCiphertext64, LweCiphertext64>(&mut criterion);
     lwe_ciphertext_discarding_keyswitch::bench::<CoreEngine, LweKeyswitchKey32, LweCiphertext32, LweCiphertext32>(&mut criterion);
     lwe_ciphertext_discarding_keyswitch::bench::<CoreEngine, LweKeyswitchKey64, LweCiphertext64, LweCiphertext64>(&mut criterion);
+    lwe_ciphertext_discarding_keyswitch::bench::<CoreEngine, ColumnMajorLweKeyswitchKey32, LweCiphertext32, LweCiphertext32>(&mut criterion);
+    lwe_ciphertext_discarding_keyswitch::bench::<CoreEngine, ColumnMajorLweKeyswitchKey64, LweCiphertext64, LweCiphertext64>(&mut criterion);
     lwe_ciphertext_discarding_opposite::bench::<CoreEngine, LweCiphertext32, LweCiphertext32>(&mut criterion);
     lwe_ciphertext_discarding_opposite::bench::<CoreEngine, LweCiphertext64, LweCiphertext64>(&mut criterion);
     lwe_ciphertext_plaintext_fusing_addition::bench::<CoreEngine, LweCiphertext32, Plaintext32>(&mut criterion);
@@ -72,6 +80,8 @@ pub fn bench() {
     lwe_ciphertext_vector_decryption::bench::<CoreEngine, LweSecretKey64, LweCiphertextVector64, PlaintextVector64>(&mut criterion);
     lwe_ciphertext_vector_encryption::bench::<CoreEngine, LweSecretKey32, PlaintextVector32, LweCiphertextVector32>(&mut criterion);
     lwe_ciphertext_vector_encryption::bench::<CoreEngine, LweSecretKey64, PlaintextVector64, LweCiphertextVector64>(&mut criterion);
+    lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch::bench::<CoreEngine, PackingKeyswitchKey32, LweCiphertextVector32, GlweCiphertext32>(&mut criterion);
+    lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch::bench::<CoreEngine, PackingKeyswitchKey64, LweCiphertextVector64, GlweCiphertext64>(&mut criterion);
     lwe_ciphertext_vector_discarding_decryption::bench::<CoreEngine, LweSecretKey32, LweCiphertextVector32, PlaintextVector32>(&mut criterion);
     lwe_ciphertext_vector_discarding_decryption::bench::<CoreEngine, LweSecretKey64, LweCiphertextVector64, PlaintextVector64>(&mut criterion);
     lwe_ciphertext_vector_discarding_encryption::bench::<CoreEngine, LweSecretKey32, PlaintextVector32, LweCiphertextVector32>(&mut criterion);
@@ -0,0 +1,127 @@
+//! A module benchmarking the bootstraps of the `core` backend with each [`BootstrapDispatch`]
+//! strategy.
+//!
+//! The parameters are supported by the small kernel, so that the strategies select different
+//! kernels. The strategy the engines are created with is chosen from the results of this
+//! benchmark.
+use crate::synthesizer::{
+    SynthesizableGlweCiphertextEntity, SynthesizableLweBootstrapKeyEntity,
+    SynthesizableLweCiphertextEntity, Synthesizer,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use concrete_core::prelude::*;
+use criterion::{black_box, BenchmarkId, Criterion};
+
+/// Benchmarks the bootstraps for the `u32` and `u64` types.
+pub fn bench() {
+    let mut criterion = Criterion::default().configure_from_args();
+    bench_dispatch::<FourierLweBootstrapKey32, GlweCiphertext32, LweCiphertext32>(&mut criterion);
+    bench_dispatch::<FourierLweBootstrapKey64, GlweCiphertext64, LweCiphertext64>(&mut criterion);
+}
+
+fn bench_dispatch<BootstrapKey, Accumulator, Ciphertext>(c: &mut Criterion)
+where
+    CoreEngine:
+        LweCiphertextDiscardingBootstrapEngine<BootstrapKey, Accumulator, Ciphertext, Ciphertext>,
+    BootstrapKey: SynthesizableLweBootstrapKeyEntity<
+        InputKeyDistribution = Ciphertext::KeyDistribution,
+        OutputKeyDistribution = Ciphertext::KeyDistribution,
+    >,
+    Accumulator: SynthesizableGlweCiphertextEntity<KeyDistribution = Ciphertext::KeyDistribution>,
+    Ciphertext: SynthesizableLweCiphertextEntity,
+{
+    let mut group = c.benchmark_group(format!(
+        "BootstrapDispatch<{}>",
+        crate::utils::type_name::<BootstrapKey>()
+    ));
+    let mut engine = CoreEngine::new().unwrap();
+    let mut synthesizer = Synthesizer::default();
+
+    for param in PARAMETERS {
+        let (_, glwe_dim, poly_size, _, _) = param;
+        for dispatch in [BootstrapDispatch::Generic, BootstrapDispatch::Specialized] {
+            group.bench_with_input(
+                BenchmarkId::new(
+                    format!("{:?}", dispatch),
+                    format!("k={}, N={}", glwe_dim.0, poly_size.0),
+                ),
+                &param,
+                |b, param| {
+                    let (input_lwe_dim, glwe_dim, poly_size, base_log, level) = param.to_owned();
+                    let output_lwe_dim = LweDimension(glwe_dim.0 * poly_size.0);
+                    let bsk = BootstrapKey::synthesize(
+                        &mut synthesizer,
+                        input_lwe_dim,
+                        poly_size,
+                        glwe_dim,
+                        base_log,
+                        level,
+                        VARIANCE,
+                    );
+                    let accumulator =
+                        Accumulator::synthesize(&mut synthesizer, poly_size, glwe_dim, VARIANCE);
+                    let mut output_lwe =
+                        Ciphertext::synthesize(&mut synthesizer, output_lwe_dim, VARIANCE);
+                    let input_lwe =
+                        Ciphertext::synthesize(&mut synthesizer, input_lwe_dim, VARIANCE);
+                    engine.set_bootstrap_dispatch(dispatch);
+                    b.iter(|| {
+                        engine
+                            .discard_bootstrap_lwe_ciphertext(
+                                black_box(&mut output_lwe),
+                                black_box(&input_lwe),
+                                black_box(&accumulator),
+                                black_box(&bsk),
+                            )
+                            .unwrap();
+                    });
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+/// The variance used to encrypt everything in the benchmark.
+const VARIANCE: Variance = Variance(0.00000001);
+
+/// The parameters the benchmark is executed against.
+const PARAMETERS: [(
+    LweDimension,
+    GlweDimension,
+    PolynomialSize,
+    DecompositionBaseLog,
+    DecompositionLevelCount,
+); 4] = [
+    (
+        LweDimension(630),
+        GlweDimension(1),
+        PolynomialSize(256),
+        DecompositionBaseLog(7),
+        DecompositionLevelCount(3),
+    ),
+    (
+        LweDimension(630),
+        GlweDimension(1),
+        PolynomialSize(512),
+        DecompositionBaseLog(7),
+        DecompositionLevelCount(3),
+    ),
+    (
+        LweDimension(630),
+        GlweDimension(1),
+        PolynomialSize(1024),
+        DecompositionBaseLog(7),
+        DecompositionLevelCount(3),
+    ),
+    (
+        LweDimension(630),
+        GlweDimension(2),
+        PolynomialSize(512),
+        DecompositionBaseLog(7),
+        DecompositionLevelCount(3),
+    ),
+];
@@ -0,0 +1,409 @@
+//! A module fitting the cost models of `concrete-npe` on the running times of the backends.
+//!
+//! A [`CostModel`] estimates the running time of an operation as a linear combination of the
+//! times of the elementary operations of a backend. The keyswitches and bootstraps of a backend
+//! are timed on several sets of parameters, and the times of the elementary operations are fitted
+//! on these measurements with a least squares regression. The errors are taken relative to the
+//! measured times, so that the fastest operations weigh as much as the slowest ones.
+//!
+//! The fitted models are printed, to be copied in the constants of [`CostModel`].
+use crate::synthesizer::{
+    SynthesizableGlweCiphertextEntity, SynthesizableLweBootstrapKeyEntity,
+    SynthesizableLweCiphertextEntity, SynthesizableLweKeyswitchKeyEntity, Synthesizer,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use concrete_core::specification::engines::{
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingKeyswitchEngine,
+};
+use concrete_npe::{estimate_keyswitch_cost, estimate_pbs_cost, CostModel};
+use std::time::{Duration, Instant};
+
+/// The parameters of a timed keyswitch: the input and output lwe dimensions, and the
+/// decomposition of the key.
+pub type KeyswitchParameters = (
+    LweDimension,
+    LweDimension,
+    DecompositionBaseLog,
+    DecompositionLevelCount,
+);
+
+/// The parameters of a timed bootstrap: the input lwe dimension, the glwe dimension and the
+/// polynomial size of the output, and the decomposition of the key.
+pub type BootstrapParameters = (
+    LweDimension,
+    GlweDimension,
+    PolynomialSize,
+    DecompositionBaseLog,
+    DecompositionLevelCount,
+);
+
+/// Fits and prints the cost models of the backends activated by the feature flags.
+pub fn calibrate() {
+    #[cfg(feature = "backend_core")]
+    {
+        use concrete_core::backends::core::engines::CoreEngine;
+        use concrete_core::backends::core::entities::{
+            FourierLweBootstrapKey64, GlweCiphertext64, LweCiphertext64, LweKeyswitchKey64,
+        };
+        let model = fit::<
+            CoreEngine,
+            LweKeyswitchKey64,
+            FourierLweBootstrapKey64,
+            GlweCiphertext64,
+            LweCiphertext64,
+        >(
+            &CostModel::CORE,
+            &KEYSWITCH_PARAMETERS,
+            &CORE_BOOTSTRAP_PARAMETERS,
+        );
+        println!("core: {:#?}", model);
+    }
+    #[cfg(feature = "backend_reference")]
+    {
+        use concrete_core::backends::reference::engines::ReferenceEngine;
+        use concrete_core::backends::reference::entities::{
+            ReferenceGlweCiphertext64, ReferenceLweBootstrapKey64, ReferenceLweCiphertext64,
+            ReferenceLweKeyswitchKey64,
+        };
+        let model = fit::<
+            ReferenceEngine,
+            ReferenceLweKeyswitchKey64,
+            ReferenceLweBootstrapKey64,
+            ReferenceGlweCiphertext64,
+            ReferenceLweCiphertext64,
+        >(
+            &CostModel::REFERENCE,
+            &KEYSWITCH_PARAMETERS,
+            &REFERENCE_BOOTSTRAP_PARAMETERS,
+        );
+        println!("reference: {:#?}", model);
+    }
+}
+
+/// A generic function fitting the cost model of an engine on its keyswitches and bootstraps.
+///
+/// The polynomial multiplication of the fitted model is the one of `model`. The times of the
+/// elementary operations which are never performed with this multiplication are left to zero.
+pub fn fit<Engine, KeyswitchKey, BootstrapKey, Accumulator, Ciphertext>(
+    model: &CostModel,
+    keyswitch_parameters: &[KeyswitchParameters],
+    bootstrap_parameters: &[BootstrapParameters],
+) -> CostModel
+where
+    Engine: LweCiphertextDiscardingKeyswitchEngine<KeyswitchKey, Ciphertext, Ciphertext>
+        + LweCiphertextDiscardingBootstrapEngine<BootstrapKey, Accumulator, Ciphertext, Ciphertext>,
+    KeyswitchKey: SynthesizableLweKeyswitchKeyEntity<
+        InputKeyDistribution = Ciphertext::KeyDistribution,
+        OutputKeyDistribution = Ciphertext::KeyDistribution,
+    >,
+    BootstrapKey: SynthesizableLweBootstrapKeyEntity<
+        InputKeyDistribution = Ciphertext::KeyDistribution,
+        OutputKeyDistribution = Ciphertext::KeyDistribution,
+    >,
+    Accumulator: SynthesizableGlweCiphertextEntity<KeyDistribution = Ciphertext::KeyDistribution>,
+    Ciphertext: SynthesizableLweCiphertextEntity,
+{
+    let mut engine = Engine::new().unwrap();
+    let mut synthesizer = Synthesizer::default();
+    let mut samples = Vec::new();
+
+    for &(input_lwe_dim, output_lwe_dim, base_log, level) in keyswitch_parameters {
+        let ksk = KeyswitchKey::synthesize(
+            &mut synthesizer,
+            input_lwe_dim,
+            output_lwe_dim,
+            base_log,
+            level,
+            VARIANCE,
+        );
+        let input_lwe = Ciphertext::synthesize(&mut synthesizer, input_lwe_dim, VARIANCE);
+        let mut output_lwe = Ciphertext::synthesize(&mut synthesizer, output_lwe_dim, VARIANCE);
+        let nanoseconds = time(|| {
+            engine
+                .discard_keyswitch_lwe_ciphertext(&mut output_lwe, &input_lwe, &ksk)
+                .unwrap()
+        });
+        let counts = operation_counts(model, |unit| {
+            estimate_keyswitch_cost(unit, input_lwe_dim, output_lwe_dim, level)
+        });
+        samples.push((counts, nanoseconds));
+    }
+
+    for &(input_lwe_dim, glwe_dim, poly_size, base_log, level) in bootstrap_parameters {
+        let output_lwe_dim = LweDimension(glwe_dim.0 * poly_size.0);
+        let bsk = BootstrapKey::synthesize(
+            &mut synthesizer,
+            input_lwe_dim,
+            poly_size,
+            glwe_dim,
+            base_log,
+            level,
+            VARIANCE,
+        );
+        let accumulator = Accumulator::synthesize(&mut synthesizer, poly_size, glwe_dim, VARIANCE);
+        let input_lwe = Ciphertext::synthesize(&mut synthesizer, input_lwe_dim, VARIANCE);
+        let mut output_lwe = Ciphertext::synthesize(&mut synthesizer, output_lwe_dim, VARIANCE);
+        let nanoseconds = time(|| {
+            engine
+                .discard_bootstrap_lwe_ciphertext(&mut output_lwe, &input_lwe, &accumulator, &bsk)
+                .unwrap()
+        });
+        let counts = operation_counts(model, |unit| {
+            estimate_pbs_cost(unit, input_lwe_dim, glwe_dim, poly_size, level)
+        });
+        samples.push((counts, nanoseconds));
+    }
+
+    let [integer_multiply_add, decomposition, fft_butterfly, fourier_multiply_add] =
+        relative_least_squares(&samples);
+    CostModel {
+        polynomial_multiplication: model.polynomial_multiplication,
+        integer_multiply_add,
+        decomposition,
+        fft_butterfly,
+        fourier_multiply_add,
+    }
+}
+
+/// Returns the mean running time of `operation` in nanoseconds.
+fn time<F: FnMut()>(mut operation: F) -> f64 {
+    // The first execution allocates the buffers of the engine, and is not timed.
+    operation();
+    let start = Instant::now();
+    let mut repetitions = 0;
+    while repetitions < MIN_REPETITIONS || start.elapsed() < MEASUREMENT_TIME {
+        operation();
+        repetitions += 1;
+    }
+    start.elapsed().as_secs_f64() * 1e9 / repetitions as f64
+}
+
+/// Returns the number of each elementary operation performed by an operation, in the order of the
+/// fields of [`CostModel`].
+///
+/// The cost model is linear in the times of the elementary operations: the counts are recovered by
+/// estimating the cost of the operation with models in which a single elementary operation takes
+/// a microsecond, and the others are free.
+fn operation_counts<F: Fn(&CostModel) -> Duration>(model: &CostModel, estimate: F) -> [f64; 4] {
+    let count = |times: [f64; 4]| {
+        let unit = CostModel {
+            polynomial_multiplication: model.polynomial_multiplication,
+            integer_multiply_add: times[0],
+            decomposition: times[1],
+            fft_butterfly: times[2],
+            fourier_multiply_add: times[3],
+        };
+        estimate(&unit).as_secs_f64() * 1e6
+    };
+    [
+        count([1e3, 0., 0., 0.]),
+        count([0., 1e3, 0., 0.]),
+        count([0., 0., 1e3, 0.]),
+        count([0., 0., 0., 1e3]),
+    ]
+}
+
+/// Returns the non-negative times of the elementary operations minimizing the sum of the squared
+/// relative errors of the estimated times over the `(counts, nanoseconds)` samples.
+///
+/// The elementary operations which are performed by none of the samples are given a zero time.
+fn relative_least_squares(samples: &[([f64; 4], f64)]) -> [f64; 4] {
+    let mut unknowns: Vec<usize> = (0..4)
+        .filter(|&i| samples.iter().any(|(counts, _)| counts[i] > 0.))
+        .collect();
+    // A noisy measurement may give a negative time to an elementary operation whose count is
+    // correlated with the ones of the others. Its time is then fixed to zero, and the others are
+    // fitted again.
+    loop {
+        let solution = solve_normal_equations(samples, &unknowns);
+        let most_negative = (0..unknowns.len())
+            .filter(|&position| solution[position] < 0.)
+            .min_by(|&a, &b| solution[a].total_cmp(&solution[b]));
+        if let Some(position) = most_negative {
+            unknowns.remove(position);
+            continue;
+        }
+        let mut times = [0.; 4];
+        for (&i, time) in unknowns.iter().zip(solution) {
+            times[i] = time;
+        }
+        return times;
+    }
+}
+
+/// Solves the normal equations of the least squares problem restricted to the `unknowns`
+/// elementary operations, and returns their times in the same order.
+fn solve_normal_equations(samples: &[([f64; 4], f64)], unknowns: &[usize]) -> Vec<f64> {
+    // Each sample gives the equation counts / nanoseconds . times = 1.
+    let size = unknowns.len();
+    let mut system = vec![vec![0.; size + 1]; size];
+    for (counts, nanoseconds) in samples {
+        let row: Vec<f64> = unknowns.iter().map(|&i| counts[i] / nanoseconds).collect();
+        for j in 0..size {
+            for k in 0..size {
+                system[j][k] += row[j] * row[k];
+            }
+            system[j][size] += row[j];
+        }
+    }
+    // Gaussian elimination with partial pivoting.
+    for pivot in 0..size {
+        let best = (pivot..size)
+            .max_by(|&a, &b| system[a][pivot].abs().total_cmp(&system[b][pivot].abs()))
+            .unwrap();
+        system.swap(pivot, best);
+        let (upper, lower) = system.split_at_mut(pivot + 1);
+        let pivot_row = &upper[pivot];
+        for row in lower.iter_mut() {
+            let factor = row[pivot] / pivot_row[pivot];
+            for (value, pivot_value) in row[pivot..].iter_mut().zip(&pivot_row[pivot..]) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+    let mut solution = vec![0.; size];
+    for row in (0..size).rev() {
+        let known: f64 = (row + 1..size)
+            .map(|column| system[row][column] * solution[column])
+            .sum();
+        solution[row] = (system[row][size] - known) / system[row][row];
+    }
+    solution
+}
+
+/// The variance used to encrypt everything in the calibration.
+const VARIANCE: Variance = Variance(0.00000001);
+
+/// The minimal number of timed executions of an operation.
+const MIN_REPETITIONS: usize = 10;
+
+/// The minimal duration of the timed executions of an operation.
+const MEASUREMENT_TIME: Duration = Duration::from_millis(500);
+
+/// The parameters of the timed keyswitches.
+///
+/// The output dimensions vary independently of the input ones, which separates the time of the
+/// decomposition from the one of the multiply-adds. The decomposition dominates the keyswitch to
+/// a tiny output dimension.
+const KEYSWITCH_PARAMETERS: [KeyswitchParameters; 4] = [
+    (
+        LweDimension(512),
+        LweDimension(256),
+        DecompositionBaseLog(4),
+        DecompositionLevelCount(3),
+    ),
+    (
+        LweDimension(1024),
+        LweDimension(512),
+        DecompositionBaseLog(3),
+        DecompositionLevelCount(5),
+    ),
+    (
+        LweDimension(1024),
+        LweDimension(4),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(10),
+    ),
+    (
+        LweDimension(2048),
+        LweDimension(630),
+        DecompositionBaseLog(4),
+        DecompositionLevelCount(4),
+    ),
+];
+
+/// The parameters of the timed bootstraps of the `core` backend.
+///
+/// The polynomial sizes and glwe dimensions vary, which separates the time of the fourier
+/// transforms from the one of the products in the fourier domain.
+#[cfg(feature = "backend_core")]
+const CORE_BOOTSTRAP_PARAMETERS: [BootstrapParameters; 6] = [
+    (
+        LweDimension(64),
+        GlweDimension(1),
+        PolynomialSize(512),
+        DecompositionBaseLog(7),
+        DecompositionLevelCount(3),
+    ),
+    (
+        LweDimension(64),
+        GlweDimension(1),
+        PolynomialSize(1024),
+        DecompositionBaseLog(7),
+        DecompositionLevelCount(3),
+    ),
+    (
+        LweDimension(64),
+        GlweDimension(1),
+        PolynomialSize(2048),
+        DecompositionBaseLog(7),
+        DecompositionLevelCount(3),
+    ),
+    (
+        LweDimension(64),
+        GlweDimension(1),
+        PolynomialSize(1024),
+        DecompositionBaseLog(15),
+        DecompositionLevelCount(1),
+    ),
+    (
+        LweDimension(64),
+        GlweDimension(2),
+        PolynomialSize(1024),
+        DecompositionBaseLog(10),
+        DecompositionLevelCount(2),
+    ),
+    (
+        LweDimension(64),
+        GlweDimension(3),
+        PolynomialSize(512),
+        DecompositionBaseLog(5),
+        DecompositionLevelCount(4),
+    ),
+];
+
+/// The parameters of the timed bootstraps of the `reference` backend.
+///
+/// The schoolbook products are quadratic in the polynomial size, hence smaller parameters than
+/// for the `core` backend.
+#[cfg(feature = "backend_reference")]
+const REFERENCE_BOOTSTRAP_PARAMETERS: [BootstrapParameters; 5] = [
+    (
+        LweDimension(16),
+        GlweDimension(1),
+        PolynomialSize(128),
+        DecompositionBaseLog(7),
+        DecompositionLevelCount(3),
+    ),
+    (
+        LweDimension(16),
+        GlweDimension(1),
+        PolynomialSize(256),
+        DecompositionBaseLog(7),
+        DecompositionLevelCount(3),
+    ),
+    (
+        LweDimension(16),
+        GlweDimension(1),
+        PolynomialSize(512),
+        DecompositionBaseLog(7),
+        DecompositionLevelCount(3),
+    ),
+    (
+        LweDimension(16),
+        GlweDimension(2),
+        PolynomialSize(256),
+        DecompositionBaseLog(10),
+        DecompositionLevelCount(2),
+    ),
+    (
+        LweDimension(16),
+        GlweDimension(3),
+        PolynomialSize(128),
+        DecompositionBaseLog(5),
+        DecompositionLevelCount(4),
+    ),
+];
@@ -0,0 +1,105 @@
+use crate::synthesizer::{
+    SynthesizableGgswCiphertextEntity, SynthesizableGlweCiphertextEntity, Synthesizer,
+};
+use crate::utils::benchmark_name;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use concrete_core::specification::engines::GlweCiphertextGgswCiphertextExternalProductEngine;
+use concrete_core::specification::entities::GlweCiphertextEntity;
+use criterion::{black_box, BenchmarkId, Criterion};
+
+/// A generic function benchmarking the glwe-ggsw external product operation.
+pub fn bench<Engine, GlweInput, GgswInput, OutputCiphertext>(c: &mut Criterion)
+where
+    Engine:
+        GlweCiphertextGgswCiphertextExternalProductEngine<GlweInput, GgswInput, OutputCiphertext>,
+    GlweInput: SynthesizableGlweCiphertextEntity,
+    GgswInput: SynthesizableGgswCiphertextEntity<KeyDistribution = GlweInput::KeyDistribution>,
+    OutputCiphertext: GlweCiphertextEntity<KeyDistribution = GlweInput::KeyDistribution>,
+{
+    let mut group = c.benchmark_group(
+        benchmark_name!(impl GlweCiphertextGgswCiphertextExternalProductEngine<
+            GlweInput,
+            GgswInput,
+            OutputCiphertext
+            > for Engine),
+    );
+
+    let mut engine = Engine::new().unwrap();
+    let mut synthesizer = Synthesizer::default();
+
+    for param in PARAMETERS {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{:?}", param)),
+            &param,
+            |b, param| {
+                let (glwe_dim, poly_size, base_log, level) = param.to_owned();
+                let glwe_input =
+                    GlweInput::synthesize(&mut synthesizer, poly_size, glwe_dim, VARIANCE);
+                let ggsw_input = GgswInput::synthesize(
+                    &mut synthesizer,
+                    poly_size,
+                    glwe_dim,
+                    base_log,
+                    level,
+                    VARIANCE,
+                );
+                b.iter(|| {
+                    black_box(
+                        engine
+                            .compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+                                black_box(&glwe_input),
+                                black_box(&ggsw_input),
+                            )
+                            .unwrap(),
+                    );
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+/// The variance used to encrypt everything in the benchmark.
+const VARIANCE: Variance = Variance(0.00000001);
+
+/// The parameters the benchmark is executed against.
+const PARAMETERS: [(
+    GlweDimension,
+    PolynomialSize,
+    DecompositionBaseLog,
+    DecompositionLevelCount,
+); 5] = [
+    (
+        GlweDimension(1),
+        PolynomialSize(256),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(3),
+    ),
+    (
+        GlweDimension(1),
+        PolynomialSize(512),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(3),
+    ),
+    (
+        GlweDimension(1),
+        PolynomialSize(1024),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(3),
+    ),
+    (
+        GlweDimension(1),
+        PolynomialSize(2048),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(3),
+    ),
+    (
+        GlweDimension(1),
+        PolynomialSize(4096),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(3),
+    ),
+];
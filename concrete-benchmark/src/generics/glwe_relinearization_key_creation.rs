@@ -0,0 +1,76 @@
+use crate::synthesizer::{
+    SynthesizableGlweRelinearizationKeyEntity, SynthesizableGlweSecretKeyEntity, Synthesizer,
+};
+use crate::utils::benchmark_name;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use concrete_core::specification::engines::GlweRelinearizationKeyCreationEngine;
+use criterion::{black_box, BenchmarkId, Criterion};
+
+/// A generic function benchmarking the glwe relinearization key generation operation.
+pub fn bench<Engine, SecretKey, RelinearizationKey>(c: &mut Criterion)
+where
+    Engine: GlweRelinearizationKeyCreationEngine<SecretKey, RelinearizationKey>,
+    SecretKey: SynthesizableGlweSecretKeyEntity,
+    RelinearizationKey:
+        SynthesizableGlweRelinearizationKeyEntity<KeyDistribution = SecretKey::KeyDistribution>,
+{
+    let mut group = c.benchmark_group(benchmark_name!(impl GlweRelinearizationKeyCreationEngine<
+            SecretKey,
+            RelinearizationKey
+            > for Engine));
+
+    let mut engine = Engine::new().unwrap();
+    let mut synthesizer = Synthesizer::default();
+
+    for param in PARAMETERS {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{:?}", param)),
+            &param,
+            |b, param| {
+                let (glwe_dim, poly_size, base_log, level) = param.to_owned();
+                let glwe_sk = SecretKey::synthesize(&mut synthesizer, poly_size, glwe_dim);
+                b.iter(|| {
+                    black_box(
+                        engine
+                            .create_glwe_relinearization_key(&glwe_sk, level, base_log, VARIANCE)
+                            .unwrap(),
+                    );
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+/// The variance used to encrypt everything in the benchmark.
+const VARIANCE: Variance = Variance(0.00000001);
+
+/// The parameters the benchmark is executed against.
+const PARAMETERS: [(
+    GlweDimension,
+    PolynomialSize,
+    DecompositionBaseLog,
+    DecompositionLevelCount,
+); 3] = [
+    (
+        GlweDimension(1),
+        PolynomialSize(1024),
+        DecompositionBaseLog(7),
+        DecompositionLevelCount(3),
+    ),
+    (
+        GlweDimension(2),
+        PolynomialSize(1024),
+        DecompositionBaseLog(7),
+        DecompositionLevelCount(3),
+    ),
+    (
+        GlweDimension(1),
+        PolynomialSize(2048),
+        DecompositionBaseLog(7),
+        DecompositionLevelCount(3),
+    ),
+];
@@ -62,7 +62,10 @@ where
 const VARIANCE: Variance = Variance(0.00000001);
 
 /// The parameters the benchmark is executed against.
-const PARAMETERS: [(LweDimension, DecompositionBaseLog, DecompositionLevelCount); 5] = [
+///
+/// The last ones are large enough for the keyswitch key not to fit in the caches, which exposes
+/// the effect of its memory layout.
+const PARAMETERS: [(LweDimension, DecompositionBaseLog, DecompositionLevelCount); 7] = [
     (
         LweDimension(100),
         DecompositionBaseLog(2),
@@ -88,4 +91,14 @@ const PARAMETERS: [(LweDimension, DecompositionBaseLog, DecompositionLevelCount)
         DecompositionBaseLog(2),
         DecompositionLevelCount(3),
     ),
+    (
+        LweDimension(1024),
+        DecompositionBaseLog(4),
+        DecompositionLevelCount(5),
+    ),
+    (
+        LweDimension(1024),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(8),
+    ),
 ];
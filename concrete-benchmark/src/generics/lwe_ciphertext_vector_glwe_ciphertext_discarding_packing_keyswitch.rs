@@ -0,0 +1,112 @@
+use crate::synthesizer::{
+    SynthesizableGlweCiphertextEntity, SynthesizableLweCiphertextVectorEntity,
+    SynthesizablePackingKeyswitchKeyEntity, Synthesizer,
+};
+use crate::utils::benchmark_name;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount, LweDimension,
+    PolynomialSize,
+};
+use concrete_core::specification::engines::LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine;
+use criterion::{black_box, BenchmarkId, Criterion};
+
+/// A generic function benchmarking the discarding lwe to glwe packing keyswitch operation.
+pub fn bench<Engine, PackingKeyswitchKey, InputCiphertextVector, OutputCiphertext>(
+    c: &mut Criterion,
+) where
+    Engine: LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine<
+        PackingKeyswitchKey,
+        InputCiphertextVector,
+        OutputCiphertext,
+    >,
+    PackingKeyswitchKey: SynthesizablePackingKeyswitchKeyEntity,
+    InputCiphertextVector: SynthesizableLweCiphertextVectorEntity<
+        KeyDistribution = PackingKeyswitchKey::InputKeyDistribution,
+    >,
+    OutputCiphertext: SynthesizableGlweCiphertextEntity<
+        KeyDistribution = PackingKeyswitchKey::OutputKeyDistribution,
+    >,
+{
+    let mut group = c.benchmark_group(
+        benchmark_name!(impl LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine<
+            PackingKeyswitchKey, 
+            InputCiphertextVector, 
+            OutputCiphertext
+            > for Engine),
+    );
+
+    let mut engine = Engine::new().unwrap();
+    let mut synthesizer = Synthesizer::default();
+
+    for param in PARAMETERS {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{:?}", param)),
+            &param,
+            |b, param| {
+                let (lwe_dim, glwe_dim, poly_size, base_log, level, count) = param.to_owned();
+                let pksk = PackingKeyswitchKey::synthesize(
+                    &mut synthesizer,
+                    lwe_dim,
+                    glwe_dim,
+                    poly_size,
+                    base_log,
+                    level,
+                    VARIANCE,
+                );
+                let mut output_glwe =
+                    OutputCiphertext::synthesize(&mut synthesizer, poly_size, glwe_dim, VARIANCE);
+                let input_lwe_vector =
+                    InputCiphertextVector::synthesize(&mut synthesizer, lwe_dim, count, VARIANCE);
+                b.iter(|| {
+                    engine
+                        .discard_packing_keyswitch_lwe_ciphertext_vector(
+                            black_box(&mut output_glwe),
+                            black_box(&input_lwe_vector),
+                            black_box(&pksk),
+                        )
+                        .unwrap();
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+/// The variance used to encrypt everything in the benchmark.
+const VARIANCE: Variance = Variance(0.00000001);
+
+/// The parameters the benchmark is executed against.
+const PARAMETERS: [(
+    LweDimension,
+    GlweDimension,
+    PolynomialSize,
+    DecompositionBaseLog,
+    DecompositionLevelCount,
+    LweCiphertextCount,
+); 3] = [
+    (
+        LweDimension(100),
+        GlweDimension(1),
+        PolynomialSize(256),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(3),
+        LweCiphertextCount(16),
+    ),
+    (
+        LweDimension(300),
+        GlweDimension(1),
+        PolynomialSize(512),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(3),
+        LweCiphertextCount(64),
+    ),
+    (
+        LweDimension(500),
+        GlweDimension(1),
+        PolynomialSize(1024),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(3),
+        LweCiphertextCount(256),
+    ),
+];
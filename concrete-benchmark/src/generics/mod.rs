@@ -9,12 +9,15 @@ pub mod glwe_ciphertext_decryption;
 pub mod glwe_ciphertext_discarding_decryption;
 pub mod glwe_ciphertext_discarding_encryption;
 pub mod glwe_ciphertext_encryption;
+pub mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
+pub mod glwe_ciphertext_ggsw_ciphertext_external_product;
 pub mod glwe_ciphertext_vector_decryption;
 pub mod glwe_ciphertext_vector_discarding_decryption;
 pub mod glwe_ciphertext_vector_discarding_encryption;
 pub mod glwe_ciphertext_vector_encryption;
 pub mod glwe_ciphertext_vector_zero_encryption;
 pub mod glwe_ciphertext_zero_encryption;
+pub mod glwe_relinearization_key_creation;
 pub mod glwe_secret_key_creation;
 pub mod lwe_bootstrap_key_conversion;
 pub mod lwe_bootstrap_key_creation;
@@ -38,6 +41,7 @@ pub mod lwe_ciphertext_vector_discarding_affine_transformation;
 pub mod lwe_ciphertext_vector_discarding_decryption;
 pub mod lwe_ciphertext_vector_discarding_encryption;
 pub mod lwe_ciphertext_vector_encryption;
+pub mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
 pub mod lwe_ciphertext_vector_zero_encryption;
 pub mod lwe_ciphertext_zero_encryption;
 pub mod lwe_keyswitch_key_creation;
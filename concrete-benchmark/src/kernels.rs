@@ -0,0 +1,66 @@
+//! A module benchmarking the vectorized kernels of the `core` backend against their scalar
+//! fallback.
+//!
+//! The name of each benchmark group contains the architecture of the machine, and whether the
+//! NEON kernels are used, which makes it possible to compare the numbers obtained on `aarch64`
+//! machines with the ones obtained on `x86_64` machines.
+use concrete_core::backends::core::private::math::kernels::{
+    neon_available, scalar, VectorKernels,
+};
+use criterion::{black_box, BenchmarkId, Criterion};
+
+/// Benchmarks the kernels for the `u32` and `u64` types.
+pub fn bench() {
+    let mut criterion = Criterion::default().configure_from_args();
+    bench_kernels::<u32>(&mut criterion);
+    bench_kernels::<u64>(&mut criterion);
+}
+
+fn bench_kernels<T: VectorKernels>(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!(
+        "VectorKernels<{}> on {} (neon: {})",
+        crate::utils::type_name::<T>(),
+        std::env::consts::ARCH,
+        neon_available()
+    ));
+    let factor = T::ONE + T::TWO;
+    let mask = (T::ONE << BASE_LOG) - T::ONE;
+    for size in SIZES {
+        let rhs = vec![T::ONE; size];
+        let mut lhs = vec![T::ONE; size];
+        let mut states = vec![T::MAX; size];
+        group.bench_with_input(
+            BenchmarkId::new("wrapping_add_scaled_assign/kernel", size),
+            &size,
+            |b, _| b.iter(|| T::wrapping_add_scaled_assign(black_box(&mut lhs), &rhs, factor)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("wrapping_add_scaled_assign/scalar", size),
+            &size,
+            |b, _| b.iter(|| scalar::wrapping_add_scaled_assign(black_box(&mut lhs), &rhs, factor)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("decompose_one_level/kernel", size),
+            &size,
+            |b, _| {
+                b.iter(|| T::decompose_one_level(black_box(&mut lhs), &mut states, BASE_LOG, mask))
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("decompose_one_level/scalar", size),
+            &size,
+            |b, _| {
+                b.iter(|| {
+                    scalar::decompose_one_level(black_box(&mut lhs), &mut states, BASE_LOG, mask)
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+/// The base log used in the decomposition benchmarks.
+const BASE_LOG: usize = 4;
+
+/// The sizes of the slices the benchmark is executed against.
+const SIZES: [usize; 4] = [512, 1024, 2048, 4096];
@@ -6,15 +6,30 @@
 //! a new backend mainly consists in appropriately instantiating the benchmarks.
 
 pub mod backends;
+#[cfg(feature = "backend_core")]
+pub mod bootstrap_dispatch;
+pub mod calibration;
 pub mod generics;
+#[cfg(feature = "backend_core")]
+pub mod kernels;
 pub mod synthesizer;
 pub mod utils;
 
 // The main entry point. Uses criterion as benchmark harness.
 fn main() {
+    // The `calibrate` argument fits the cost models of the backends instead of benchmarking them.
+    if std::env::args().nth(1).as_deref() == Some("calibrate") {
+        calibration::calibrate();
+        return;
+    }
+
     // We instantiate the benchmarks for different backends depending on the feature flag activated.
     #[cfg(feature = "backend_core")]
     backends::core::bench();
+    #[cfg(feature = "backend_core")]
+    kernels::bench();
+    #[cfg(feature = "backend_core")]
+    bootstrap_dispatch::bench();
 
     // We launch the benchmarks.
     criterion::Criterion::default()
@@ -27,6 +27,18 @@ pub trait SynthesizableCleartextVectorEntity: CleartextVectorEntity {
     fn synthesize(synthesizer: &mut Synthesizer, count: CleartextCount) -> Self;
 }
 
+/// A trait to generate a ggsw ciphertext entity.
+pub trait SynthesizableGgswCiphertextEntity: GgswCiphertextEntity {
+    fn synthesize(
+        synthesizer: &mut Synthesizer,
+        poly_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+        base_log: DecompositionBaseLog,
+        level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Self;
+}
+
 /// A trait to generate a glwe ciphertext entity.
 pub trait SynthesizableGlweCiphertextEntity: GlweCiphertextEntity {
     fn synthesize(
@@ -48,6 +60,18 @@ pub trait SynthesizableGlweCiphertextVectorEntity: GlweCiphertextVectorEntity {
     ) -> Self;
 }
 
+/// A trait to generate a glwe relinearization key entity.
+pub trait SynthesizableGlweRelinearizationKeyEntity: GlweRelinearizationKeyEntity {
+    fn synthesize(
+        synthesizer: &mut Synthesizer,
+        poly_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+        base_log: DecompositionBaseLog,
+        level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Self;
+}
+
 /// A trait to generate a glwe secret key entity.
 pub trait SynthesizableGlweSecretKeyEntity: GlweSecretKeyEntity {
     fn synthesize(
@@ -106,6 +130,19 @@ pub trait SynthesizableLweSecretKeyEntity: LweSecretKeyEntity {
     fn synthesize(synthesizer: &mut Synthesizer, lwe_dimension: LweDimension) -> Self;
 }
 
+/// A trait to generate a packing keyswitch key entity.
+pub trait SynthesizablePackingKeyswitchKeyEntity: PackingKeyswitchKeyEntity {
+    fn synthesize(
+        synthesizer: &mut Synthesizer,
+        input_lwe_dimension: LweDimension,
+        output_glwe_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+        base_log: DecompositionBaseLog,
+        level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Self;
+}
+
 /// A trait to generate a plaintext entity.
 pub trait SynthesizablePlaintextEntity: PlaintextEntity {
     fn synthesize(synthesizer: &mut Synthesizer) -> Self;
@@ -120,6 +157,8 @@ pub trait SynthesizablePlaintextVectorEntity: PlaintextVectorEntity {
 pub struct Synthesizer {
     #[cfg(feature = "backend_core")]
     core_engine: concrete_core::backends::core::engines::CoreEngine,
+    #[cfg(feature = "backend_reference")]
+    reference_engine: concrete_core::backends::reference::engines::ReferenceEngine,
 }
 
 impl Default for Synthesizer {
@@ -127,6 +166,9 @@ impl Default for Synthesizer {
         Synthesizer {
             #[cfg(feature = "backend_core")]
             core_engine: concrete_core::backends::core::engines::CoreEngine::new().unwrap(),
+            #[cfg(feature = "backend_reference")]
+            reference_engine: concrete_core::backends::reference::engines::ReferenceEngine::new()
+                .unwrap(),
         }
     }
 }
@@ -170,6 +212,68 @@ mod core {
         }
     }
 
+    impl SynthesizableGgswCiphertextEntity for FourierGgswCiphertext32 {
+        fn synthesize(
+            synthesizer: &mut Synthesizer,
+            poly_size: PolynomialSize,
+            glwe_dimension: GlweDimension,
+            base_log: DecompositionBaseLog,
+            level_count: DecompositionLevelCount,
+            noise: Variance,
+        ) -> Self {
+            let secret_key: GlweSecretKey32 = synthesizer
+                .core_engine
+                .create_glwe_secret_key(glwe_dimension, poly_size)
+                .unwrap();
+            let plaintext = synthesizer.core_engine.create_plaintext(&1u32).unwrap();
+            let ggsw: GgswCiphertext32 = synthesizer
+                .core_engine
+                .encrypt_scalar_ggsw_ciphertext(
+                    &secret_key,
+                    &plaintext,
+                    noise,
+                    level_count,
+                    base_log,
+                )
+                .unwrap();
+            synthesizer
+                .core_engine
+                .convert_ggsw_ciphertext(&ggsw)
+                .unwrap()
+        }
+    }
+
+    impl SynthesizableGgswCiphertextEntity for FourierGgswCiphertext64 {
+        fn synthesize(
+            synthesizer: &mut Synthesizer,
+            poly_size: PolynomialSize,
+            glwe_dimension: GlweDimension,
+            base_log: DecompositionBaseLog,
+            level_count: DecompositionLevelCount,
+            noise: Variance,
+        ) -> Self {
+            let secret_key: GlweSecretKey64 = synthesizer
+                .core_engine
+                .create_glwe_secret_key(glwe_dimension, poly_size)
+                .unwrap();
+            let plaintext = synthesizer.core_engine.create_plaintext(&1u64).unwrap();
+            let ggsw: GgswCiphertext64 = synthesizer
+                .core_engine
+                .encrypt_scalar_ggsw_ciphertext(
+                    &secret_key,
+                    &plaintext,
+                    noise,
+                    level_count,
+                    base_log,
+                )
+                .unwrap();
+            synthesizer
+                .core_engine
+                .convert_ggsw_ciphertext(&ggsw)
+                .unwrap()
+        }
+    }
+
     impl SynthesizableGlweCiphertextEntity for GlweCiphertext32 {
         fn synthesize(
             synthesizer: &mut Synthesizer,
@@ -244,6 +348,46 @@ mod core {
         }
     }
 
+    impl SynthesizableGlweRelinearizationKeyEntity for GlweRelinearizationKey32 {
+        fn synthesize(
+            synthesizer: &mut Synthesizer,
+            poly_size: PolynomialSize,
+            glwe_dimension: GlweDimension,
+            base_log: DecompositionBaseLog,
+            level_count: DecompositionLevelCount,
+            noise: Variance,
+        ) -> Self {
+            let glwe_sk: GlweSecretKey32 = synthesizer
+                .core_engine
+                .create_glwe_secret_key(glwe_dimension, poly_size)
+                .unwrap();
+            synthesizer
+                .core_engine
+                .create_glwe_relinearization_key(&glwe_sk, level_count, base_log, noise)
+                .unwrap()
+        }
+    }
+
+    impl SynthesizableGlweRelinearizationKeyEntity for GlweRelinearizationKey64 {
+        fn synthesize(
+            synthesizer: &mut Synthesizer,
+            poly_size: PolynomialSize,
+            glwe_dimension: GlweDimension,
+            base_log: DecompositionBaseLog,
+            level_count: DecompositionLevelCount,
+            noise: Variance,
+        ) -> Self {
+            let glwe_sk: GlweSecretKey64 = synthesizer
+                .core_engine
+                .create_glwe_secret_key(glwe_dimension, poly_size)
+                .unwrap();
+            synthesizer
+                .core_engine
+                .create_glwe_relinearization_key(&glwe_sk, level_count, base_log, noise)
+                .unwrap()
+        }
+    }
+
     impl SynthesizableGlweSecretKeyEntity for GlweSecretKey32 {
         fn synthesize(
             synthesizer: &mut Synthesizer,
@@ -508,6 +652,54 @@ mod core {
         }
     }
 
+    impl SynthesizableLweKeyswitchKeyEntity for ColumnMajorLweKeyswitchKey32 {
+        fn synthesize(
+            synthesizer: &mut Synthesizer,
+            input_lwe_dimension: LweDimension,
+            output_lwe_dimension: LweDimension,
+            base_log: DecompositionBaseLog,
+            level_count: DecompositionLevelCount,
+            noise: Variance,
+        ) -> Self {
+            let ksk = LweKeyswitchKey32::synthesize(
+                synthesizer,
+                input_lwe_dimension,
+                output_lwe_dimension,
+                base_log,
+                level_count,
+                noise,
+            );
+            synthesizer
+                .core_engine
+                .convert_lwe_keyswitch_key(&ksk)
+                .unwrap()
+        }
+    }
+
+    impl SynthesizableLweKeyswitchKeyEntity for ColumnMajorLweKeyswitchKey64 {
+        fn synthesize(
+            synthesizer: &mut Synthesizer,
+            input_lwe_dimension: LweDimension,
+            output_lwe_dimension: LweDimension,
+            base_log: DecompositionBaseLog,
+            level_count: DecompositionLevelCount,
+            noise: Variance,
+        ) -> Self {
+            let ksk = LweKeyswitchKey64::synthesize(
+                synthesizer,
+                input_lwe_dimension,
+                output_lwe_dimension,
+                base_log,
+                level_count,
+                noise,
+            );
+            synthesizer
+                .core_engine
+                .convert_lwe_keyswitch_key(&ksk)
+                .unwrap()
+        }
+    }
+
     impl SynthesizableLweSecretKeyEntity for LweSecretKey32 {
         fn synthesize(synthesizer: &mut Synthesizer, lwe_dimension: LweDimension) -> Self {
             synthesizer
@@ -526,6 +718,68 @@ mod core {
         }
     }
 
+    impl SynthesizablePackingKeyswitchKeyEntity for PackingKeyswitchKey32 {
+        fn synthesize(
+            synthesizer: &mut Synthesizer,
+            input_lwe_dimension: LweDimension,
+            output_glwe_dimension: GlweDimension,
+            output_polynomial_size: PolynomialSize,
+            base_log: DecompositionBaseLog,
+            level_count: DecompositionLevelCount,
+            noise: Variance,
+        ) -> Self {
+            let input_lwe_sk = synthesizer
+                .core_engine
+                .create_lwe_secret_key(input_lwe_dimension)
+                .unwrap();
+            let output_glwe_sk = synthesizer
+                .core_engine
+                .create_glwe_secret_key(output_glwe_dimension, output_polynomial_size)
+                .unwrap();
+            synthesizer
+                .core_engine
+                .create_packing_keyswitch_key(
+                    &input_lwe_sk,
+                    &output_glwe_sk,
+                    level_count,
+                    base_log,
+                    noise,
+                )
+                .unwrap()
+        }
+    }
+
+    impl SynthesizablePackingKeyswitchKeyEntity for PackingKeyswitchKey64 {
+        fn synthesize(
+            synthesizer: &mut Synthesizer,
+            input_lwe_dimension: LweDimension,
+            output_glwe_dimension: GlweDimension,
+            output_polynomial_size: PolynomialSize,
+            base_log: DecompositionBaseLog,
+            level_count: DecompositionLevelCount,
+            noise: Variance,
+        ) -> Self {
+            let input_lwe_sk = synthesizer
+                .core_engine
+                .create_lwe_secret_key(input_lwe_dimension)
+                .unwrap();
+            let output_glwe_sk = synthesizer
+                .core_engine
+                .create_glwe_secret_key(output_glwe_dimension, output_polynomial_size)
+                .unwrap();
+            synthesizer
+                .core_engine
+                .create_packing_keyswitch_key(
+                    &input_lwe_sk,
+                    &output_glwe_sk,
+                    level_count,
+                    base_log,
+                    noise,
+                )
+                .unwrap()
+        }
+    }
+
     impl SynthesizablePlaintextEntity for Plaintext32 {
         fn synthesize(synthesizer: &mut Synthesizer) -> Self {
             synthesizer.core_engine.create_plaintext(&1u32).unwrap()
@@ -556,3 +810,166 @@ mod core {
         }
     }
 }
+
+// The entities of the reference backend are synthesized with the `core` backend, and converted.
+#[cfg(feature = "backend_reference")]
+mod reference {
+    use super::*;
+    use concrete_commons::dispersion::Variance;
+    use concrete_commons::parameters::{
+        DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    };
+
+    impl SynthesizableGlweCiphertextEntity for ReferenceGlweCiphertext32 {
+        fn synthesize(
+            synthesizer: &mut Synthesizer,
+            poly_size: PolynomialSize,
+            glwe_dimension: GlweDimension,
+            noise: Variance,
+        ) -> Self {
+            let ciphertext =
+                GlweCiphertext32::synthesize(synthesizer, poly_size, glwe_dimension, noise);
+            synthesizer
+                .reference_engine
+                .convert_glwe_ciphertext(&ciphertext)
+                .unwrap()
+        }
+    }
+    impl SynthesizableGlweCiphertextEntity for ReferenceGlweCiphertext64 {
+        fn synthesize(
+            synthesizer: &mut Synthesizer,
+            poly_size: PolynomialSize,
+            glwe_dimension: GlweDimension,
+            noise: Variance,
+        ) -> Self {
+            let ciphertext =
+                GlweCiphertext64::synthesize(synthesizer, poly_size, glwe_dimension, noise);
+            synthesizer
+                .reference_engine
+                .convert_glwe_ciphertext(&ciphertext)
+                .unwrap()
+        }
+    }
+    impl SynthesizableLweBootstrapKeyEntity for ReferenceLweBootstrapKey32 {
+        fn synthesize(
+            synthesizer: &mut Synthesizer,
+            lwe_dimension: LweDimension,
+            poly_size: PolynomialSize,
+            glwe_dimension: GlweDimension,
+            base_log: DecompositionBaseLog,
+            level_count: DecompositionLevelCount,
+            noise: Variance,
+        ) -> Self {
+            let bsk = LweBootstrapKey32::synthesize(
+                synthesizer,
+                lwe_dimension,
+                poly_size,
+                glwe_dimension,
+                base_log,
+                level_count,
+                noise,
+            );
+            synthesizer
+                .reference_engine
+                .convert_lwe_bootstrap_key(&bsk)
+                .unwrap()
+        }
+    }
+    impl SynthesizableLweBootstrapKeyEntity for ReferenceLweBootstrapKey64 {
+        fn synthesize(
+            synthesizer: &mut Synthesizer,
+            lwe_dimension: LweDimension,
+            poly_size: PolynomialSize,
+            glwe_dimension: GlweDimension,
+            base_log: DecompositionBaseLog,
+            level_count: DecompositionLevelCount,
+            noise: Variance,
+        ) -> Self {
+            let bsk = LweBootstrapKey64::synthesize(
+                synthesizer,
+                lwe_dimension,
+                poly_size,
+                glwe_dimension,
+                base_log,
+                level_count,
+                noise,
+            );
+            synthesizer
+                .reference_engine
+                .convert_lwe_bootstrap_key(&bsk)
+                .unwrap()
+        }
+    }
+    impl SynthesizableLweCiphertextEntity for ReferenceLweCiphertext32 {
+        fn synthesize(
+            synthesizer: &mut Synthesizer,
+            lwe_dimension: LweDimension,
+            noise: Variance,
+        ) -> Self {
+            let ciphertext = LweCiphertext32::synthesize(synthesizer, lwe_dimension, noise);
+            synthesizer
+                .reference_engine
+                .convert_lwe_ciphertext(&ciphertext)
+                .unwrap()
+        }
+    }
+    impl SynthesizableLweCiphertextEntity for ReferenceLweCiphertext64 {
+        fn synthesize(
+            synthesizer: &mut Synthesizer,
+            lwe_dimension: LweDimension,
+            noise: Variance,
+        ) -> Self {
+            let ciphertext = LweCiphertext64::synthesize(synthesizer, lwe_dimension, noise);
+            synthesizer
+                .reference_engine
+                .convert_lwe_ciphertext(&ciphertext)
+                .unwrap()
+        }
+    }
+    impl SynthesizableLweKeyswitchKeyEntity for ReferenceLweKeyswitchKey32 {
+        fn synthesize(
+            synthesizer: &mut Synthesizer,
+            input_lwe_dimension: LweDimension,
+            output_lwe_dimension: LweDimension,
+            base_log: DecompositionBaseLog,
+            level_count: DecompositionLevelCount,
+            noise: Variance,
+        ) -> Self {
+            let ksk = LweKeyswitchKey32::synthesize(
+                synthesizer,
+                input_lwe_dimension,
+                output_lwe_dimension,
+                base_log,
+                level_count,
+                noise,
+            );
+            synthesizer
+                .reference_engine
+                .convert_lwe_keyswitch_key(&ksk)
+                .unwrap()
+        }
+    }
+    impl SynthesizableLweKeyswitchKeyEntity for ReferenceLweKeyswitchKey64 {
+        fn synthesize(
+            synthesizer: &mut Synthesizer,
+            input_lwe_dimension: LweDimension,
+            output_lwe_dimension: LweDimension,
+            base_log: DecompositionBaseLog,
+            level_count: DecompositionLevelCount,
+            noise: Variance,
+        ) -> Self {
+            let ksk = LweKeyswitchKey64::synthesize(
+                synthesizer,
+                input_lwe_dimension,
+                output_lwe_dimension,
+                base_log,
+                level_count,
+                noise,
+            );
+            synthesizer
+                .reference_engine
+                .convert_lwe_keyswitch_key(&ksk)
+                .unwrap()
+        }
+    }
+}
@@ -12,6 +12,17 @@
 //! In any of those cases, the corresponding type implements the `DispersionParameter` trait,
 //! which makes if possible to use any of those representations generically when noise must be
 //! defined.
+//!
+//! The noise can also be sampled from a bounded distribution, which newer security analyses tend
+//! to prefer over the gaussian one:
+//!
+//! + [`BoundedUniform`] describes a uniform distribution over the integers of a symmetric
+//!   interval.
+//! + [`TUniform`] describes a uniform distribution over a symmetric interval whose bound is a
+//!   power of two, the two bounds having half the probability of the other values.
+//!
+//! Those types implement the `DispersionParameter` trait as well, and the distribution of the
+//! noise they describe is given by [`DispersionParameter::get_modular_distribution`].
 
 #[cfg(feature = "serde_serialize")]
 use serde::{Deserialize, Serialize};
@@ -43,6 +54,40 @@ pub trait DispersionParameter: Copy {
     fn get_modular_log_standard_dev<Uint>(&self) -> f64
     where
         Uint: UnsignedInteger;
+    /// For a `Uint` type representing $\mathbb{Z}/2^q\mathbb{Z}$, we return the distribution the
+    /// noise is sampled from. Unless stated otherwise, the distribution is a gaussian.
+    fn get_modular_distribution<Uint>(&self) -> ModularDistribution
+    where
+        Uint: UnsignedInteger,
+    {
+        ModularDistribution::Gaussian {
+            std: self.get_modular_standard_dev::<Uint>(),
+        }
+    }
+}
+
+/// A family of distributions the noise can be sampled from.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NoiseDistribution {
+    /// The centered gaussian distributions.
+    Gaussian,
+    /// The uniform distributions over the integers of a symmetric interval, see
+    /// [`BoundedUniform`].
+    BoundedUniform,
+    /// The TUniform distributions, see [`TUniform`].
+    TUniform,
+}
+
+/// The distribution of the noise, in $\mathbb{Z}/2^q\mathbb{Z}$.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ModularDistribution {
+    /// A centered gaussian distribution, with standard deviation `std`.
+    Gaussian { std: f64 },
+    /// A uniform distribution over the integers of $[-B, B]$, with $B$ the `bound`.
+    BoundedUniform { bound: u128 },
+    /// A TUniform distribution over the integers of $[-2^b, 2^b]$, with $b$ the `log2_bound`.
+    TUniform { log2_bound: u32 },
 }
 
 /// A distribution parameter that uses the base-2 logarithm of the standard deviation as
@@ -69,6 +114,7 @@ pub trait DispersionParameter: Copy {
 /// let modular_params = LogStandardDev::from_modular_log_standard_dev::<u32>(22.);
 /// assert_eq!(modular_params.get_standard_dev(), 2_f64.powf(-10.));
 /// ```
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub struct LogStandardDev(pub f64);
 
@@ -202,6 +248,7 @@ impl DispersionParameter for StandardDev {
 ///     2_f64.powf(32. - 25.).powi(2)
 /// );
 /// ```
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub struct Variance(pub f64);
 
@@ -247,3 +294,194 @@ impl DispersionParameter for Variance {
         Uint::BITS as f64 + self.0.sqrt().log2()
     }
 }
+
+/// A distribution parameter describing a uniform distribution over the integers of a symmetric
+/// interval, using the bound of the interval on the torus as representation.
+///
+/// For a `Uint` type representing $\mathbb{Z}/2^q\mathbb{Z}$, a bound $B$ on the torus is mapped
+/// to the integer bound $M = \lfloor B 2^q \rceil$, and the noise is sampled uniformly in
+/// $[-M, M]$. Its variance is then $M(M+1)/3$. On the torus, the variance is the one of the
+/// continuous distribution, i.e. $B^2/3$.
+///
+/// # Example:
+///
+/// ```
+/// use concrete_commons::dispersion::{BoundedUniform, DispersionParameter, ModularDistribution};
+/// let params = BoundedUniform::from_bound(2_f64.powf(-20.));
+/// assert_eq!(params.get_variance(), 2_f64.powf(-40.) / 3.);
+/// assert_eq!(params.get_modular_bound::<u32>(), 4096);
+/// assert_eq!(params.get_modular_variance::<u32>(), (4096. * 4097.) / 3.);
+/// assert_eq!(
+///     params.get_modular_distribution::<u32>(),
+///     ModularDistribution::BoundedUniform { bound: 4096 }
+/// );
+/// ```
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct BoundedUniform(pub f64);
+
+impl BoundedUniform {
+    pub fn from_bound(bound: f64) -> BoundedUniform {
+        BoundedUniform(bound)
+    }
+
+    pub fn from_modular_bound<Uint>(bound: u128) -> BoundedUniform
+    where
+        Uint: UnsignedInteger,
+    {
+        BoundedUniform(bound as f64 / 2_f64.powi(Uint::BITS as i32))
+    }
+
+    /// Returns the bounded uniform distribution with the same variance as `dispersion`, on the
+    /// torus.
+    pub fn from_dispersion(dispersion: impl DispersionParameter) -> BoundedUniform {
+        BoundedUniform((3. * dispersion.get_variance()).sqrt())
+    }
+
+    /// For a `Uint` type representing $\mathbb{Z}/2^q\mathbb{Z}$, we return $M$.
+    pub fn get_modular_bound<Uint>(&self) -> u128
+    where
+        Uint: UnsignedInteger,
+    {
+        (self.0 * 2_f64.powi(Uint::BITS as i32)).round() as u128
+    }
+}
+
+impl DispersionParameter for BoundedUniform {
+    fn get_standard_dev(&self) -> f64 {
+        self.get_variance().sqrt()
+    }
+    fn get_variance(&self) -> f64 {
+        self.0.powi(2) / 3.
+    }
+    fn get_log_standard_dev(&self) -> f64 {
+        self.get_standard_dev().log2()
+    }
+    fn get_modular_standard_dev<Uint>(&self) -> f64
+    where
+        Uint: UnsignedInteger,
+    {
+        self.get_modular_variance::<Uint>().sqrt()
+    }
+    fn get_modular_variance<Uint>(&self) -> f64
+    where
+        Uint: UnsignedInteger,
+    {
+        let bound = self.get_modular_bound::<Uint>() as f64;
+        bound * (bound + 1.) / 3.
+    }
+    fn get_modular_log_standard_dev<Uint>(&self) -> f64
+    where
+        Uint: UnsignedInteger,
+    {
+        self.get_modular_standard_dev::<Uint>().log2()
+    }
+    fn get_modular_distribution<Uint>(&self) -> ModularDistribution
+    where
+        Uint: UnsignedInteger,
+    {
+        ModularDistribution::BoundedUniform {
+            bound: self.get_modular_bound::<Uint>(),
+        }
+    }
+}
+
+/// A distribution parameter describing a TUniform distribution, using the base-2 logarithm of its
+/// bound on the torus as representation.
+///
+/// For a `Uint` type representing $\mathbb{Z}/2^q\mathbb{Z}$, a bound $2^p$ on the torus is
+/// mapped to the integer bound $2^b$, with $b = \lfloor q + p \rceil$. The noise takes every
+/// integer value of $]-2^b, 2^b[$ with probability $2^{-(b+1)}$, and the values $\pm 2^b$ with
+/// probability $2^{-(b+2)}$. Its variance is then $(2^{2b+1} + 1)/6$. On the torus, the variance
+/// is the one of the continuous limit, i.e. $2^{2p}/3$.
+///
+/// # Example:
+///
+/// ```
+/// use concrete_commons::dispersion::{DispersionParameter, ModularDistribution, TUniform};
+/// let params = TUniform::from_modular_log_bound::<u64>(42);
+/// assert_eq!(params.get_log_bound(), -22.);
+/// assert_eq!(params.get_variance(), 2_f64.powf(-44.) / 3.);
+/// assert_eq!(
+///     params.get_modular_variance::<u64>(),
+///     (2_f64.powf(85.) + 1.) / 6.
+/// );
+/// assert_eq!(
+///     params.get_modular_distribution::<u64>(),
+///     ModularDistribution::TUniform { log2_bound: 42 }
+/// );
+/// ```
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct TUniform(pub f64);
+
+impl TUniform {
+    pub fn from_log_bound(log_bound: f64) -> TUniform {
+        TUniform(log_bound)
+    }
+
+    pub fn from_modular_log_bound<Uint>(log_bound: u32) -> TUniform
+    where
+        Uint: UnsignedInteger,
+    {
+        TUniform(log_bound as f64 - Uint::BITS as f64)
+    }
+
+    /// Returns the TUniform distribution whose variance on the torus is the closest to the one of
+    /// `dispersion`.
+    pub fn from_dispersion(dispersion: impl DispersionParameter) -> TUniform {
+        TUniform((3. * dispersion.get_variance()).sqrt().log2().round())
+    }
+
+    /// Returns the base 2 logarithm of the bound on the torus, i.e. $p$.
+    pub fn get_log_bound(&self) -> f64 {
+        self.0
+    }
+
+    /// For a `Uint` type representing $\mathbb{Z}/2^q\mathbb{Z}$, we return $b$.
+    pub fn get_modular_log_bound<Uint>(&self) -> u32
+    where
+        Uint: UnsignedInteger,
+    {
+        (Uint::BITS as f64 + self.0).round().max(0.) as u32
+    }
+}
+
+impl DispersionParameter for TUniform {
+    fn get_standard_dev(&self) -> f64 {
+        self.get_variance().sqrt()
+    }
+    fn get_variance(&self) -> f64 {
+        f64::powf(2., self.0 * 2.) / 3.
+    }
+    fn get_log_standard_dev(&self) -> f64 {
+        self.get_standard_dev().log2()
+    }
+    fn get_modular_standard_dev<Uint>(&self) -> f64
+    where
+        Uint: UnsignedInteger,
+    {
+        self.get_modular_variance::<Uint>().sqrt()
+    }
+    fn get_modular_variance<Uint>(&self) -> f64
+    where
+        Uint: UnsignedInteger,
+    {
+        let log_bound = self.get_modular_log_bound::<Uint>() as f64;
+        (f64::powf(2., 2. * log_bound + 1.) + 1.) / 6.
+    }
+    fn get_modular_log_standard_dev<Uint>(&self) -> f64
+    where
+        Uint: UnsignedInteger,
+    {
+        self.get_modular_standard_dev::<Uint>().log2()
+    }
+    fn get_modular_distribution<Uint>(&self) -> ModularDistribution
+    where
+        Uint: UnsignedInteger,
+    {
+        ModularDistribution::TUniform {
+            log2_bound: self.get_modular_log_bound::<Uint>(),
+        }
+    }
+}
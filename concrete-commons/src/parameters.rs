@@ -58,6 +58,16 @@ pub struct GswCiphertextCount(pub usize);
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 pub struct GgswCiphertextCount(pub usize);
 
+/// The number of keys in an LWE keyswitch key batch.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct LweKeyswitchKeyCount(pub usize);
+
+/// The index of a key in an LWE keyswitch key batch.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct LweKeyswitchKeyIndex(pub usize);
+
 /// The number of scalars in an LWE ciphertext, i.e. the number of scalar in an LWE mask plus one.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
@@ -182,3 +192,184 @@ pub struct LutCountLog(pub usize);
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 pub struct ModulusSwitchOffset(pub usize);
+
+/// The logarithm of the power-of-two modulus of a ciphertext.
+///
+/// When the coefficients of a ciphertext are integers modulo $2^k$, this type represents the $k$
+/// value.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct CiphertextModulusLog(pub usize);
+
+/// The number of bits of the messages encoded in a ciphertext.
+///
+/// When the messages are integers smaller than $2^p$, this type represents the $p$ value.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct MessageBitCount(pub usize);
+
+/// The extremum computed by an element-wise comparison of two ciphertexts.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub enum Extremum {
+    /// The smaller of the two messages.
+    Minimum,
+    /// The larger of the two messages.
+    Maximum,
+}
+
+/// The logarithm of the power-of-two denominator of a rational scalar.
+///
+/// When multiplying by a rational $\frac{a}{2^d}$, this type represents the $d$ value.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct DenominatorLog(pub usize);
+
+/// The master seed the secret keys of many clients are derived from.
+///
+/// The master seed must be kept secret, since it gives access to all the derived keys.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct MasterSeed(pub u128);
+
+/// The identifier of a client, used to derive its secret keys from a [`MasterSeed`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct ClientId(pub u64);
+
+/// The factor $\Delta$ by which the messages are scaled before their encryption.
+///
+/// When multiplying two ciphertexts encrypting messages scaled by $\Delta$, the product is scaled
+/// by $\Delta^2$, and this type also represents the $\Delta$ value used to bring it back to
+/// $\Delta$.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct ScalingFactor(pub u64);
+
+impl ScalingFactor {
+    /// Returns the scaling factor $2^{\log}$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::ScalingFactor;
+    /// let delta = ScalingFactor::from_log2(40);
+    /// assert_eq!(delta, ScalingFactor(1 << 40));
+    /// assert_eq!(delta.log2(), 40.);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `log` is not smaller than 64, since the factor would not fit in a `u64`.
+    pub fn from_log2(log: usize) -> ScalingFactor {
+        assert!(
+            log < 64,
+            "A scaling factor of 2^{} does not fit in 64 bits.",
+            log
+        );
+        ScalingFactor(1 << log)
+    }
+
+    /// Returns the base 2 logarithm of the scaling factor.
+    pub fn log2(&self) -> f64 {
+        (self.0 as f64).log2()
+    }
+
+    /// Returns the scaling factor as a float.
+    pub fn to_f64(&self) -> f64 {
+        self.0 as f64
+    }
+}
+
+/// The number of encryptions of zero contained in an LWE public key.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct LwePublicKeyZeroEncryptionCount(pub usize);
+
+/// The largest absolute value taken by the messages encrypted in a ciphertext.
+///
+/// When the messages are integers in $[-m, m]$, this type represents the $m$ value. It is
+/// expressed in the unit of the messages, and not in the one of the torus: the scaling factor of
+/// the encoding is not applied.
+#[derive(Debug, PartialEq, PartialOrd, Copy, Clone)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct MessageBound(pub f64);
+
+impl MessageBound {
+    /// Returns the bound $m$, checking that it is a finite non-negative number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is negative, infinite or nan.
+    pub fn new(bound: f64) -> MessageBound {
+        assert!(
+            bound.is_finite() && bound >= 0.,
+            "A message bound must be finite and non-negative, got {}.",
+            bound
+        );
+        MessageBound(bound)
+    }
+
+    /// Returns the bound $2^{\log}$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting bound is infinite or nan.
+    pub fn from_log2(log: f64) -> MessageBound {
+        MessageBound::new(f64::powf(2., log))
+    }
+
+    /// Returns the bound $2^p - 1$ of the messages made of $p$ bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{MessageBitCount, MessageBound};
+    /// assert_eq!(
+    ///     MessageBound::from_message_bit_count(MessageBitCount(4)),
+    ///     MessageBound(15.)
+    /// );
+    /// ```
+    pub fn from_message_bit_count(bit_count: MessageBitCount) -> MessageBound {
+        MessageBound::new(f64::powi(2., bit_count.0 as i32) - 1.)
+    }
+
+    /// Returns the base 2 logarithm of the bound.
+    pub fn log2(&self) -> f64 {
+        self.0.log2()
+    }
+}
+
+/// The order in which the coefficients of a ciphertext are laid out in a raw container.
+///
+/// The ciphertexts of this library store their mask first, followed by their body. This tag allows
+/// to read or write raw containers storing the body first, without having to reorder the
+/// coefficients by hand. The layouts only describe the order of the coefficients: the byte
+/// compatibility with another implementation is not checked by this library.
+///
+/// For GLWE ciphertexts, the mask and the body are made of polynomials, and the whole body
+/// polynomial $B$ is placed after or before the mask polynomials $(A_0, \dots, A_{k-1})$.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub enum CiphertextLayout {
+    /// The mask coefficients, followed by the body: $(a_0, \dots, a_{n-1}, b)$.
+    MaskFirst,
+    /// The body, followed by the mask coefficients: $(b, a_0, \dots, a_{n-1})$.
+    BodyFirst,
+}
+
+impl Default for CiphertextLayout {
+    /// Returns the [`CiphertextLayout::MaskFirst`] layout used by this library.
+    fn default() -> Self {
+        CiphertextLayout::MaskFirst
+    }
+}
+
+/// The amount of memory, in bytes, an operation is allowed to use for its intermediate values.
+///
+/// The operations on large vectors of ciphertexts can split their inputs into chunks processed one
+/// after the other, so that the memory they use on the device performing the computation stays
+/// below this budget.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct MemoryBudget(pub usize);
@@ -0,0 +1,68 @@
+//! An example of benchmarks driven by the fixtures.
+//!
+//! The fixtures of `concrete-core-fixture` already know how to generate the inputs of the engines,
+//! for every set of parameters. Here, we time a few engines of the `core` backend with them. The
+//! output has the format of a timing budget file, and can be used as such.
+use concrete_core::prelude::{
+    AbstractEngine, CoreEngine, LweCiphertext32, LweCiphertext64, LweKeyswitchKey64,
+    LweSecretKey32, LweSecretKey64, Plaintext32, Plaintext64,
+};
+use concrete_core_fixture::benchmark::WarmupSize;
+use concrete_core_fixture::fixture::{
+    LweCiphertextDiscardingKeyswitchFixture, LweCiphertextEncryptionFixture,
+    LweCiphertextFusingAdditionFixture,
+};
+use concrete_core_fixture::presets::REDUCED_REPETITIONS;
+use concrete_core_fixture::{BenchmarkFixture, Maker, Precision32, Precision64, SampleSize};
+
+const WARMUP_SIZE: WarmupSize = WarmupSize(5);
+const SAMPLE_SIZE: SampleSize = SampleSize(20);
+
+fn main() {
+    let mut maker = Maker::default();
+    let mut engine = CoreEngine::new().unwrap();
+    <LweCiphertextEncryptionFixture as BenchmarkFixture<
+        Precision32,
+        CoreEngine,
+        (Plaintext32, LweSecretKey32, LweCiphertext32),
+    >>::benchmark_all_parameters(
+        &mut maker,
+        &mut engine,
+        REDUCED_REPETITIONS,
+        WARMUP_SIZE,
+        SAMPLE_SIZE,
+    );
+    <LweCiphertextEncryptionFixture as BenchmarkFixture<
+        Precision64,
+        CoreEngine,
+        (Plaintext64, LweSecretKey64, LweCiphertext64),
+    >>::benchmark_all_parameters(
+        &mut maker,
+        &mut engine,
+        REDUCED_REPETITIONS,
+        WARMUP_SIZE,
+        SAMPLE_SIZE,
+    );
+    <LweCiphertextFusingAdditionFixture as BenchmarkFixture<
+        Precision64,
+        CoreEngine,
+        (LweCiphertext64, LweCiphertext64),
+    >>::benchmark_all_parameters(
+        &mut maker,
+        &mut engine,
+        REDUCED_REPETITIONS,
+        WARMUP_SIZE,
+        SAMPLE_SIZE,
+    );
+    <LweCiphertextDiscardingKeyswitchFixture as BenchmarkFixture<
+        Precision64,
+        CoreEngine,
+        (LweKeyswitchKey64, LweCiphertext64, LweCiphertext64),
+    >>::benchmark_all_parameters(
+        &mut maker,
+        &mut engine,
+        REDUCED_REPETITIONS,
+        WARMUP_SIZE,
+        SAMPLE_SIZE,
+    );
+}
@@ -0,0 +1,161 @@
+//! An example of a fixture implemented outside of `concrete-core-fixture`.
+//!
+//! A crate wrapping `concrete-core` can check its own properties with the fixtures machinery. Here,
+//! we check that applying the opposite twice to an LWE ciphertext leaves its plaintext unchanged,
+//! without adding noise, for any implementor of the `LweCiphertextFusingOppositeEngine` trait.
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LweDimension;
+use concrete_core::prelude::{
+    AbstractEngine, CoreEngine, LweCiphertext64, LweCiphertextEntity,
+    LweCiphertextFusingOppositeEngine,
+};
+use concrete_core_fixture::generation::prototyping::{
+    PrototypesLweCiphertext, PrototypesLweSecretKey, PrototypesPlaintext,
+};
+use concrete_core_fixture::generation::synthesizing::SynthesizesLweCiphertext;
+use concrete_core_fixture::presets::{
+    STANDARD_LWE_DIMENSIONS, STANDARD_NOISE, STANDARD_REPETITIONS, STANDARD_SAMPLE_SIZE,
+};
+use concrete_core_fixture::raw::generation::RawUnsignedIntegers;
+use concrete_core_fixture::raw::statistical_test::assert_noise_distribution;
+use concrete_core_fixture::{Fixture, IntegerPrecision, Maker, Precision64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture checking that the opposite is an involution.
+pub struct DoubleOppositeFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct DoubleOppositeParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, Engine, Ciphertext> Fixture<Precision, Engine, (Ciphertext,)>
+    for DoubleOppositeFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextFusingOppositeEngine<Ciphertext>,
+    Ciphertext: LweCiphertextEntity,
+    Maker: SynthesizesLweCiphertext<Precision, Ciphertext>,
+{
+    type Parameters = DoubleOppositeParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, Ciphertext::KeyDistribution>>::LweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, Ciphertext::KeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (Ciphertext,);
+    type PostExecutionContext = (Ciphertext,);
+    type Criteria = (Variance,);
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            STANDARD_LWE_DIMENSIONS
+                .iter()
+                .map(|lwe_dimension| DoubleOppositeParameters {
+                    noise: STANDARD_NOISE,
+                    lwe_dimension: *lwe_dimension,
+                }),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        (maker.new_lwe_secret_key(parameters.lwe_dimension),)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext = Precision::Raw::uniform();
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+        );
+        (proto_plaintext, proto_ciphertext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_ciphertext) = sample_proto;
+        (maker.synthesize_lwe_ciphertext(proto_ciphertext),)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (mut ciphertext,) = context;
+        engine.fuse_opp_lwe_ciphertext(&mut ciphertext).unwrap();
+        engine.fuse_opp_lwe_ciphertext(&mut ciphertext).unwrap();
+        (ciphertext,)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (ciphertext,) = context;
+        let (proto_plaintext, _) = sample_proto;
+        let (proto_secret_key,) = repetition_proto;
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(&ciphertext);
+        let proto_output_plaintext =
+            maker.decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, &proto_output_ciphertext);
+        maker.destroy_lwe_ciphertext(ciphertext);
+        (
+            maker.transform_plaintext_to_raw(proto_plaintext),
+            maker.transform_plaintext_to_raw(&proto_output_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (parameters.noise,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
+
+fn main() {
+    let mut maker = Maker::default();
+    let mut engine = CoreEngine::new().unwrap();
+    let result = <DoubleOppositeFixture as Fixture<
+        Precision64,
+        CoreEngine,
+        (LweCiphertext64,),
+    >>::stress_all_parameters(
+        &mut maker,
+        &mut engine,
+        STANDARD_REPETITIONS,
+        STANDARD_SAMPLE_SIZE,
+    );
+    assert!(result);
+    println!("The opposite is an involution for the standard parameters.");
+}
@@ -0,0 +1,46 @@
+//! An example of soak run driven by the fixtures.
+//!
+//! Here, we keep encrypting with the same LWE secret key of the `core` backend, for the number of
+//! minutes given as first argument (one by default), and check that the variance of the noise
+//! does not drift over time. The process exits with an error if an alert was raised.
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LweDimension;
+use concrete_core::prelude::{CoreEngine, LweCiphertext64, LweSecretKey64, Plaintext64};
+use concrete_core_fixture::fixture::{
+    LweCiphertextEncryptionFixture, LweCiphertextEncryptionParameters,
+};
+use concrete_core_fixture::generation::{engine_seed, seed_from_env};
+use concrete_core_fixture::soak::{SoakConfig, SoakFixture};
+use concrete_core_fixture::{Maker, Precision64};
+use std::time::Duration;
+
+fn main() {
+    let minutes = std::env::args()
+        .nth(1)
+        .map(|minutes| minutes.parse::<u64>().expect("Invalid number of minutes."))
+        .unwrap_or(1);
+    let seed = seed_from_env();
+    let mut maker = Maker::new_seeded(seed);
+    let mut engine = CoreEngine::new_seeded(engine_seed(seed));
+    let parameters = LweCiphertextEncryptionParameters {
+        noise: Variance(2_f64.powf(-50.)),
+        lwe_dimension: LweDimension(630),
+    };
+    let config = SoakConfig {
+        duration: Duration::from_secs(60 * minutes),
+        ..SoakConfig::default()
+    };
+    let report = <LweCiphertextEncryptionFixture as SoakFixture<
+        Precision64,
+        CoreEngine,
+        (Plaintext64, LweSecretKey64, LweCiphertext64),
+    >>::soak(&mut maker, &mut engine, &parameters, &config)
+    .expect("The fixture does not measure its noise.");
+    for window in report.windows.iter() {
+        println!("{}", window);
+    }
+    if !report.passed() {
+        eprintln!("The soak run failed with the seed {}.", seed);
+        std::process::exit(1);
+    }
+}
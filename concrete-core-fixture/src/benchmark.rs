@@ -0,0 +1,176 @@
+//! A module containing a benchmark runner driven by the fixtures.
+//!
+//! The [`BenchmarkFixture`] trait is implemented for every [`Fixture`], and reuses the
+//! [`Fixture::prepare_context`] and [`Fixture::execute_engine`] methods to time the executions of
+//! the engine, for every set of parameters of the fixture. This gives timing numbers for any
+//! implementor of an engine trait, without writing a dedicated benchmark.
+//!
+//! Only the executions of the engine are timed: the generation of the inputs, and the disposal of
+//! the entities by [`Fixture::process_context`] are not. The outcomes are not verified, and the
+//! unchecked entry points of the engines are always executed.
+//!
+//! The reports are printed in the format of the budget files of the [`timing`](crate::timing)
+//! module, such that the output of a benchmark run can be used as a timing budget.
+//!
+//! [`Fixture::prepare_context`]: crate::fixture::Fixture::prepare_context
+//! [`Fixture::execute_engine`]: crate::fixture::Fixture::execute_engine
+//! [`Fixture::process_context`]: crate::fixture::Fixture::process_context
+use crate::fixture::Fixture;
+use crate::generation::{IntegerPrecision, Maker};
+use crate::timing::{fixture_key, Timings};
+use crate::{Repetitions, SampleSize};
+use concrete_core::prelude::AbstractEngine;
+use std::fmt::{Display, Formatter};
+use std::time::Instant;
+
+/// The number of untimed executions of the engine performed before the timed ones, in every
+/// repetition.
+#[derive(Clone, Copy, Debug)]
+pub struct WarmupSize(pub usize);
+
+/// The timings measured for a fixture and a set of parameters.
+#[derive(Debug, Clone)]
+pub struct BenchmarkReport {
+    /// The key identifying the fixture, as returned by [`fixture_key`].
+    pub fixture_key: String,
+    /// The position of the parameters in the iterator returned by
+    /// [`Fixture::parameters_iterator`].
+    pub parameters_index: usize,
+    /// The durations of the timed executions of the engine.
+    pub timings: Timings,
+}
+
+impl BenchmarkReport {
+    /// Returns the mean duration of an execution in milliseconds, if any execution was timed.
+    pub fn mean_ms(&self) -> Option<f64> {
+        self.timings.mean().map(|mean| mean.as_secs_f64() * 1000.)
+    }
+}
+
+impl Display for BenchmarkReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.mean_ms() {
+            Some(mean_ms) => write!(
+                f,
+                "{} {} {:.6}",
+                self.fixture_key, self.parameters_index, mean_ms
+            ),
+            None => write!(
+                f,
+                "# {} {} no execution timed",
+                self.fixture_key, self.parameters_index
+            ),
+        }
+    }
+}
+
+/// A trait benchmarking the implementors of the engine trait of a fixture.
+///
+/// This trait is implemented for every type implementing [`Fixture`], and only provides default
+/// methods.
+pub trait BenchmarkFixture<Precision: IntegerPrecision, Engine: AbstractEngine, RelatedEntities>:
+    Fixture<Precision, Engine, RelatedEntities>
+{
+    /// A method which times the executions of the engine for every set of parameters, prints the
+    /// reports on the standard output, and returns them.
+    fn benchmark_all_parameters(
+        maker: &mut Maker,
+        engine: &mut Engine,
+        repetitions: Repetitions,
+        warmup_size: WarmupSize,
+        sample_size: SampleSize,
+    ) -> Vec<BenchmarkReport>
+    where
+        Self: Sized,
+    {
+        let key = fixture_key::<Self, Precision, RelatedEntities>();
+        Self::parameters_iterator()
+            .enumerate()
+            .map(|(parameters_index, parameters)| {
+                let report = BenchmarkReport {
+                    fixture_key: key.clone(),
+                    parameters_index,
+                    timings: Self::benchmark(
+                        maker,
+                        engine,
+                        &parameters,
+                        repetitions,
+                        warmup_size,
+                        sample_size,
+                    ),
+                };
+                println!("{}", report);
+                report
+            })
+            .collect()
+    }
+
+    /// A method which times the executions of the engine for a fixed set of parameters.
+    ///
+    /// For every repetition, new repetition-level prototypes are generated, and the engine is
+    /// executed `warmup_size` times, then `sample_size` timed times, on new sample-level
+    /// prototypes.
+    fn benchmark(
+        maker: &mut Maker,
+        engine: &mut Engine,
+        parameters: &Self::Parameters,
+        repetitions: Repetitions,
+        warmup_size: WarmupSize,
+        sample_size: SampleSize,
+    ) -> Timings {
+        let mut timings = Timings::default();
+        let mut discarded = Timings::default();
+        for _ in 0..repetitions.0 {
+            let repetition_proto = Self::generate_random_repetition_prototypes(parameters, maker);
+            for _ in 0..warmup_size.0 {
+                Self::benchmark_sample(
+                    maker,
+                    engine,
+                    parameters,
+                    &repetition_proto,
+                    &mut discarded,
+                );
+            }
+            for _ in 0..sample_size.0 {
+                Self::benchmark_sample(maker, engine, parameters, &repetition_proto, &mut timings);
+            }
+        }
+        timings
+    }
+
+    /// A method which times a single execution of the engine on new sample-level prototypes,
+    /// and records its duration in `timings`.
+    fn benchmark_sample(
+        maker: &mut Maker,
+        engine: &mut Engine,
+        parameters: &Self::Parameters,
+        repetition_proto: &Self::RepetitionPrototypes,
+        timings: &mut Timings,
+    ) {
+        let sample_proto =
+            Self::generate_random_sample_prototypes(parameters, maker, repetition_proto);
+        let pre_execution_context =
+            Self::prepare_context(parameters, maker, repetition_proto, &sample_proto);
+        let start = Instant::now();
+        let post_execution_context =
+            Self::execute_engine(parameters, engine, pre_execution_context);
+        timings.record(start.elapsed());
+        // The context is processed to dispose of the entities, and the outcome is dropped.
+        Self::process_context(
+            parameters,
+            maker,
+            repetition_proto,
+            &sample_proto,
+            post_execution_context,
+        );
+    }
+}
+
+impl<Precision, Engine, RelatedEntities, F> BenchmarkFixture<Precision, Engine, RelatedEntities>
+    for F
+where
+    Precision: IntegerPrecision,
+    Engine: AbstractEngine,
+    F: Fixture<Precision, Engine, RelatedEntities>,
+{
+}
@@ -2,15 +2,19 @@ use crate::fixture::Fixture;
 use crate::generation::prototyping::PrototypesCleartext;
 use crate::generation::synthesizing::SynthesizesCleartext;
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
 
 use concrete_core::prelude::{CleartextCreationEngine, CleartextEntity};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `CleartextCreationEngine` trait.
 pub struct CleartextCreationFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct CleartextCreationParameters;
 
@@ -67,6 +71,16 @@ where
         (cleartext,)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (raw_cleartext,) = context;
+        let cleartext = engine.create_cleartext(&raw_cleartext).unwrap();
+        (cleartext,)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -95,4 +109,11 @@ where
         let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
         assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
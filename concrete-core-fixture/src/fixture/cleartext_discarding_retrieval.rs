@@ -2,15 +2,19 @@ use crate::fixture::Fixture;
 use crate::generation::prototyping::PrototypesCleartext;
 use crate::generation::synthesizing::SynthesizesCleartext;
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
 
 use concrete_core::prelude::{CleartextDiscardingRetrievalEngine, CleartextEntity};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `CleartextDiscardingRetrievalEngine` trait.
 pub struct CleartextDiscardingRetrievalFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct CleartextDiscardingRetrievalParameters;
 
@@ -77,6 +81,18 @@ where
         (cleartext, raw_output)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (cleartext, mut raw_output) = context;
+        engine
+            .discard_retrieve_cleartext(&mut raw_output, &cleartext)
+            .unwrap();
+        (cleartext, raw_output)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -105,4 +121,11 @@ where
         let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
         assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
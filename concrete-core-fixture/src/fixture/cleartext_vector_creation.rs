@@ -2,16 +2,20 @@ use crate::fixture::Fixture;
 use crate::generation::prototyping::PrototypesCleartextVector;
 use crate::generation::synthesizing::SynthesizesCleartextVector;
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::CleartextCount;
 
 use concrete_core::prelude::{CleartextVectorCreationEngine, CleartextVectorEntity};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `CleartextVectorCreationEngine` trait.
 pub struct CleartextVectorCreationFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct CleartextVectorCreationParameters {
     count: CleartextCount,
@@ -81,6 +85,18 @@ where
         (cleartext_vector,)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (raw_cleartext_vector,) = context;
+        let cleartext_vector = engine
+            .create_cleartext_vector(&raw_cleartext_vector)
+            .unwrap();
+        (cleartext_vector,)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -111,4 +127,11 @@ where
         let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
         assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
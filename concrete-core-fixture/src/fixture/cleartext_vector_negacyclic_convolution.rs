@@ -0,0 +1,206 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::PrototypesCleartextVector;
+use crate::generation::synthesizing::SynthesizesCleartextVector;
+use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::raw::generation::{negacyclic_convolution, RawUnsignedIntegers};
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{PolynomialCount, PolynomialSize};
+
+use concrete_core::prelude::{CleartextVectorEntity, CleartextVectorNegacyclicConvolutionEngine};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `CleartextVectorNegacyclicConvolutionEngine` trait.
+pub struct CleartextVectorNegacyclicConvolutionFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct CleartextVectorNegacyclicConvolutionParameters {
+    polynomial_size: PolynomialSize,
+    polynomial_count: PolynomialCount,
+}
+
+impl<Precision, Engine, InputCleartextVector1, InputCleartextVector2, OutputCleartextVector>
+    Fixture<
+        Precision,
+        Engine,
+        (
+            InputCleartextVector1,
+            InputCleartextVector2,
+            OutputCleartextVector,
+        ),
+    > for CleartextVectorNegacyclicConvolutionFixture
+where
+    Precision: IntegerPrecision,
+    Engine: CleartextVectorNegacyclicConvolutionEngine<
+        InputCleartextVector1,
+        InputCleartextVector2,
+        OutputCleartextVector,
+    >,
+    InputCleartextVector1: CleartextVectorEntity,
+    InputCleartextVector2: CleartextVectorEntity,
+    OutputCleartextVector: CleartextVectorEntity,
+    Maker: SynthesizesCleartextVector<Precision, InputCleartextVector1>
+        + SynthesizesCleartextVector<Precision, InputCleartextVector2>
+        + SynthesizesCleartextVector<Precision, OutputCleartextVector>,
+{
+    type Parameters = CleartextVectorNegacyclicConvolutionParameters;
+    type RepetitionPrototypes = ();
+    type SamplePrototypes = (
+        <Maker as PrototypesCleartextVector<Precision>>::CleartextVectorProto,
+        <Maker as PrototypesCleartextVector<Precision>>::CleartextVectorProto,
+    );
+    type PreExecutionContext = (InputCleartextVector1, InputCleartextVector2);
+    type PostExecutionContext = (
+        InputCleartextVector1,
+        InputCleartextVector2,
+        OutputCleartextVector,
+    );
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                CleartextVectorNegacyclicConvolutionParameters {
+                    polynomial_size: PolynomialSize(256),
+                    polynomial_count: PolynomialCount(1),
+                },
+                CleartextVectorNegacyclicConvolutionParameters {
+                    polynomial_size: PolynomialSize(16),
+                    polynomial_count: PolynomialCount(10),
+                },
+                CleartextVectorNegacyclicConvolutionParameters {
+                    polynomial_size: PolynomialSize(1),
+                    polynomial_count: PolynomialCount(100),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let count = parameters.polynomial_size.0 * parameters.polynomial_count.0;
+        let raw_cleartext_vector1 = Precision::Raw::uniform_vec(count);
+        let raw_cleartext_vector2 = Precision::Raw::uniform_vec(count);
+        (
+            maker.transform_raw_vec_to_cleartext_vector(&raw_cleartext_vector1),
+            maker.transform_raw_vec_to_cleartext_vector(&raw_cleartext_vector2),
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_cleartext_vector1, proto_cleartext_vector2) = sample_proto;
+        (
+            maker.synthesize_cleartext_vector(proto_cleartext_vector1),
+            maker.synthesize_cleartext_vector(proto_cleartext_vector2),
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (cleartext_vector1, cleartext_vector2) = context;
+        let output_cleartext_vector = unsafe {
+            engine.convolve_cleartext_vector_unchecked(
+                &cleartext_vector1,
+                &cleartext_vector2,
+                parameters.polynomial_size,
+            )
+        };
+        (
+            cleartext_vector1,
+            cleartext_vector2,
+            output_cleartext_vector,
+        )
+    }
+
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (cleartext_vector1, cleartext_vector2) = context;
+        let output_cleartext_vector = engine
+            .convolve_cleartext_vector(
+                &cleartext_vector1,
+                &cleartext_vector2,
+                parameters.polynomial_size,
+            )
+            .unwrap();
+        (
+            cleartext_vector1,
+            cleartext_vector2,
+            output_cleartext_vector,
+        )
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (cleartext_vector1, cleartext_vector2, output_cleartext_vector) = context;
+        let (proto_cleartext_vector1, proto_cleartext_vector2) = sample_proto;
+        let raw_cleartext_vector1 =
+            maker.transform_cleartext_vector_to_raw_vec(proto_cleartext_vector1);
+        let raw_cleartext_vector2 =
+            maker.transform_cleartext_vector_to_raw_vec(proto_cleartext_vector2);
+        let proto_output_cleartext_vector =
+            maker.unsynthesize_cleartext_vector(&output_cleartext_vector);
+        maker.destroy_cleartext_vector(cleartext_vector1);
+        maker.destroy_cleartext_vector(cleartext_vector2);
+        maker.destroy_cleartext_vector(output_cleartext_vector);
+        (
+            negacyclic_convolution(
+                &raw_cleartext_vector1,
+                &raw_cleartext_vector2,
+                parameters.polynomial_size.0,
+            ),
+            maker.transform_cleartext_vector_to_raw_vec(&proto_output_cleartext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (Variance(0.),)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+}
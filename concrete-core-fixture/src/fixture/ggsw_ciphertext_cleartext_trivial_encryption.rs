@@ -0,0 +1,241 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesCleartext, PrototypesGgswCiphertext, PrototypesGlweCiphertext,
+    PrototypesGlweSecretKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesCleartext, SynthesizesGgswCiphertext};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use concrete_core::prelude::{
+    CleartextEntity, GgswCiphertextCleartextTrivialEncryptionEngine, GgswCiphertextEntity,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `GgswCiphertextCleartextTrivialEncryptionEngine`
+/// trait.
+///
+/// The trivial GGSW ciphertext is checked by using it in an external product with a GLWE
+/// ciphertext encrypted under a fresh key, whose decryption must contain the input messages
+/// multiplied by the cleartext.
+pub struct GgswCiphertextCleartextTrivialEncryptionFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct GgswCiphertextCleartextTrivialEncryptionParameters {
+    pub glwe_noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+}
+
+impl<Precision, Engine, Cleartext, Ciphertext> Fixture<Precision, Engine, (Cleartext, Ciphertext)>
+    for GgswCiphertextCleartextTrivialEncryptionFixture
+where
+    Precision: IntegerPrecision,
+    Engine: GgswCiphertextCleartextTrivialEncryptionEngine<Cleartext, Ciphertext>,
+    Cleartext: CleartextEntity,
+    Ciphertext: GgswCiphertextEntity,
+    Maker: SynthesizesCleartext<Precision, Cleartext>
+        + SynthesizesGgswCiphertext<Precision, Ciphertext>,
+{
+    type Parameters = GgswCiphertextCleartextTrivialEncryptionParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesCleartext<Precision>>::CleartextProto,
+        <Maker as PrototypesGlweSecretKey<Precision, Ciphertext::KeyDistribution>>::GlweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, Ciphertext::KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (Cleartext,);
+    type PostExecutionContext = (Cleartext, Ciphertext);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GgswCiphertextCleartextTrivialEncryptionParameters {
+                    glwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(512),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    decomposition_level_count: DecompositionLevelCount(4),
+                },
+                GgswCiphertextCleartextTrivialEncryptionParameters {
+                    glwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(1024),
+                    decomposition_base_log: DecompositionBaseLog(4),
+                    decomposition_level_count: DecompositionLevelCount(5),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let raw_cleartext = Precision::Raw::pick(&[
+            Precision::Raw::zero(),
+            Precision::Raw::one(),
+            Precision::Raw::power_of_two(1),
+        ]);
+        let proto_cleartext = maker.transform_raw_to_cleartext(&raw_cleartext);
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        (proto_cleartext, proto_secret_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (_, proto_secret_key) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.polynomial_size.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_glwe_ciphertext = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.glwe_noise,
+        );
+        (proto_plaintext_vector, proto_glwe_ciphertext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_cleartext, _) = repetition_proto;
+        (maker.synthesize_cleartext(proto_cleartext),)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (cleartext,) = context;
+        let ciphertext = unsafe {
+            engine.trivially_encrypt_cleartext_ggsw_ciphertext_unchecked(
+                parameters.polynomial_size,
+                parameters.glwe_dimension.to_glwe_size(),
+                parameters.decomposition_level_count,
+                parameters.decomposition_base_log,
+                &cleartext,
+            )
+        };
+        (cleartext, ciphertext)
+    }
+
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (cleartext,) = context;
+        let ciphertext = engine
+            .trivially_encrypt_cleartext_ggsw_ciphertext(
+                parameters.polynomial_size,
+                parameters.glwe_dimension.to_glwe_size(),
+                parameters.decomposition_level_count,
+                parameters.decomposition_base_log,
+                &cleartext,
+            )
+            .unwrap();
+        (cleartext, ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (cleartext, ciphertext) = context;
+        let (proto_cleartext, proto_secret_key) = repetition_proto;
+        let (proto_plaintext_vector, proto_glwe_ciphertext) = sample_proto;
+        let proto_ggsw_ciphertext = maker.unsynthesize_ggsw_ciphertext(&ciphertext);
+        maker.destroy_cleartext(cleartext);
+        maker.destroy_ggsw_ciphertext(ciphertext);
+        let proto_output_glwe_ciphertext = maker
+            .compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+                proto_glwe_ciphertext,
+                &proto_ggsw_ciphertext,
+            );
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_secret_key,
+            &proto_output_glwe_ciphertext,
+        );
+        let raw_cleartext = maker.transform_cleartext_to_raw(proto_cleartext);
+        let raw_expected_vector = maker
+            .transform_plaintext_vector_to_raw_vec(proto_plaintext_vector)
+            .into_iter()
+            .map(|v| v * raw_cleartext)
+            .collect();
+        (
+            raw_expected_vector,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let (proto_cleartext, _) = repetition_proto;
+        let raw_cleartext = maker.transform_cleartext_to_raw(proto_cleartext);
+        // A trivial GGSW ciphertext behaves like a noiseless one in the external product. The
+        // binary estimate averages the output noise over a zero and a one message, hence it is
+        // doubled to get the noise for a one message, then scaled by the cleartext.
+        let binary_variance = concrete_npe::estimate_external_product_noise_with_binary_ggsw::<
+            Precision::Raw,
+            Variance,
+            Variance,
+            Ciphertext::KeyDistribution,
+        >(
+            parameters.polynomial_size,
+            parameters.glwe_dimension,
+            parameters.glwe_noise,
+            Variance(0.),
+            parameters.decomposition_base_log,
+            parameters.decomposition_level_count,
+        );
+        let output_variance =
+            concrete_npe::estimate_integer_plaintext_multiplication_noise::<Precision::Raw, _>(
+                Variance(2. * binary_variance.get_variance()),
+                raw_cleartext,
+            );
+        (output_variance,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means = means.into_iter().flatten().collect::<Vec<_>>();
+        let actual = actual.into_iter().flatten().collect::<Vec<_>>();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+}
@@ -0,0 +1,182 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGgswCiphertext, PrototypesGlweSecretKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::SynthesizesGgswCiphertext;
+use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_variance_below;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use concrete_core::prelude::{GgswCiphertextConversionEngine, GgswCiphertextEntity};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `GgswCiphertextConversionEngine` trait, from and to
+/// the Fourier domain.
+///
+/// The GGSW ciphertext is converted to the Fourier domain and back. The floating point errors
+/// introduced by the round trip are measured directly on the coefficients of the ciphertext, and
+/// must stay below the noise bound of the FFT.
+pub struct GgswCiphertextConversionFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct GgswCiphertextConversionParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+}
+
+impl<Precision, Engine, Ciphertext, FourierCiphertext>
+    Fixture<Precision, Engine, (Ciphertext, FourierCiphertext)> for GgswCiphertextConversionFixture
+where
+    Precision: IntegerPrecision,
+    Engine: GgswCiphertextConversionEngine<Ciphertext, FourierCiphertext>
+        + GgswCiphertextConversionEngine<FourierCiphertext, Ciphertext>,
+    Ciphertext: GgswCiphertextEntity,
+    FourierCiphertext: GgswCiphertextEntity<KeyDistribution = Ciphertext::KeyDistribution>,
+    Maker: SynthesizesGgswCiphertext<Precision, Ciphertext>
+        + SynthesizesGgswCiphertext<Precision, FourierCiphertext>,
+{
+    type Parameters = GgswCiphertextConversionParameters;
+    type RepetitionPrototypes =
+        (<Maker as PrototypesGlweSecretKey<Precision, Ciphertext::KeyDistribution>>::GlweSecretKeyProto,);
+    type SamplePrototypes =
+        (<Maker as PrototypesGgswCiphertext<Precision, Ciphertext::KeyDistribution>>::GgswCiphertextProto,);
+    type PreExecutionContext = (Ciphertext,);
+    type PostExecutionContext = (Ciphertext, FourierCiphertext, Ciphertext);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            [256, 1024, 4096, 16384]
+                .iter()
+                .flat_map(|&polynomial_size| {
+                    [1, 6].iter().map(move |&decomposition_level_count| {
+                        GgswCiphertextConversionParameters {
+                            noise: Variance(LogStandardDev(-20.).get_variance()),
+                            glwe_dimension: GlweDimension(1),
+                            polynomial_size: PolynomialSize(polynomial_size),
+                            decomposition_base_log: DecompositionBaseLog(4),
+                            decomposition_level_count: DecompositionLevelCount(
+                                decomposition_level_count,
+                            ),
+                        }
+                    })
+                })
+                .collect::<Vec<_>>()
+                .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext = Precision::Raw::pick(&[Precision::Raw::zero(), Precision::Raw::one()]);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_ggsw_ciphertext = maker.encrypt_plaintext_to_ggsw_ciphertext(
+            proto_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+        );
+        (proto_ggsw_ciphertext,)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_ggsw_ciphertext,) = sample_proto;
+        (maker.synthesize_ggsw_ciphertext(proto_ggsw_ciphertext),)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (ciphertext,) = context;
+        let fourier_ciphertext = unsafe { engine.convert_ggsw_ciphertext_unchecked(&ciphertext) };
+        let output_ciphertext =
+            unsafe { engine.convert_ggsw_ciphertext_unchecked(&fourier_ciphertext) };
+        (ciphertext, fourier_ciphertext, output_ciphertext)
+    }
+
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (ciphertext,) = context;
+        let fourier_ciphertext = engine.convert_ggsw_ciphertext(&ciphertext).unwrap();
+        let output_ciphertext = engine.convert_ggsw_ciphertext(&fourier_ciphertext).unwrap();
+        (ciphertext, fourier_ciphertext, output_ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (ciphertext, fourier_ciphertext, output_ciphertext) = context;
+        let (proto_ggsw_ciphertext,) = sample_proto;
+        let proto_output_ggsw_ciphertext = maker.unsynthesize_ggsw_ciphertext(&output_ciphertext);
+        maker.destroy_ggsw_ciphertext(ciphertext);
+        maker.destroy_ggsw_ciphertext(fourier_ciphertext);
+        maker.destroy_ggsw_ciphertext(output_ciphertext);
+        (
+            maker.transform_ggsw_ciphertext_to_raw_vec(proto_ggsw_ciphertext),
+            maker.transform_ggsw_ciphertext_to_raw_vec(&proto_output_ggsw_ciphertext),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let predicted_variance = concrete_npe::estimate_fourier_round_trip_noise::<Precision::Raw>(
+            parameters.polynomial_size,
+        );
+        (predicted_variance,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (expected, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let expected = expected.into_iter().flatten().collect::<Vec<_>>();
+        let actual = actual.into_iter().flatten().collect::<Vec<_>>();
+        assert_noise_variance_below(&actual, expected.as_slice(), criteria.0)
+    }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+}
@@ -0,0 +1,318 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesCleartextVector, PrototypesGlweSecretKey, PrototypesPlaintextVector,
+    PrototypesRingGlweCiphertext,
+};
+use crate::generation::synthesizing::{SynthesizesCleartextVector, SynthesizesRingGlweCiphertext};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::numeric::{CastInto, UnsignedInteger};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+use concrete_core::prelude::markers::{CyclicRing, RingStructureMarker};
+use concrete_core::prelude::{
+    CleartextVectorEntity, GlweCiphertextCleartextVectorDiscardingMultiplicationEngine,
+    GlweCiphertextEntity,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+use std::any::TypeId;
+
+/// A fixture for the types implementing the
+/// `GlweCiphertextCleartextVectorDiscardingMultiplicationEngine` trait.
+///
+/// The expected output is computed on the raw plaintexts, in the ring of the ciphertexts.
+pub struct GlweCiphertextCleartextVectorDiscardingMultiplicationFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct GlweCiphertextCleartextVectorDiscardingMultiplicationParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub cleartext_bound: usize,
+}
+
+impl<Precision, Engine, InputCiphertext, CleartextVector, OutputCiphertext>
+    Fixture<Precision, Engine, (InputCiphertext, CleartextVector, OutputCiphertext)>
+    for GlweCiphertextCleartextVectorDiscardingMultiplicationFixture
+where
+    Precision: IntegerPrecision,
+    Engine: GlweCiphertextCleartextVectorDiscardingMultiplicationEngine<
+        InputCiphertext,
+        CleartextVector,
+        OutputCiphertext,
+    >,
+    InputCiphertext: GlweCiphertextEntity,
+    CleartextVector: CleartextVectorEntity,
+    OutputCiphertext: GlweCiphertextEntity<
+        KeyDistribution = InputCiphertext::KeyDistribution,
+        RingStructure = InputCiphertext::RingStructure,
+    >,
+    Maker: SynthesizesRingGlweCiphertext<Precision, InputCiphertext>
+        + SynthesizesRingGlweCiphertext<Precision, OutputCiphertext>
+        + SynthesizesCleartextVector<Precision, CleartextVector>,
+{
+    type Parameters = GlweCiphertextCleartextVectorDiscardingMultiplicationParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, InputCiphertext::KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesCleartextVector<Precision>>::CleartextVectorProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            InputCiphertext::KeyDistribution,
+            InputCiphertext::RingStructure,
+        >>::RingGlweCiphertextProto,
+        <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            OutputCiphertext::KeyDistribution,
+            OutputCiphertext::RingStructure,
+        >>::RingGlweCiphertextProto,
+    );
+    type PreExecutionContext = (InputCiphertext, CleartextVector, OutputCiphertext);
+    type PostExecutionContext = (InputCiphertext, CleartextVector, OutputCiphertext);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextCleartextVectorDiscardingMultiplicationParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2048),
+                    cleartext_bound: 4,
+                },
+                GlweCiphertextCleartextVectorDiscardingMultiplicationParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                    cleartext_bound: 4,
+                },
+                GlweCiphertextCleartextVectorDiscardingMultiplicationParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(3),
+                    polynomial_size: PolynomialSize(1024),
+                    cleartext_bound: 4,
+                },
+                GlweCiphertextCleartextVectorDiscardingMultiplicationParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(4),
+                    polynomial_size: PolynomialSize(512),
+                    cleartext_bound: 4,
+                },
+                GlweCiphertextCleartextVectorDiscardingMultiplicationParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2),
+                    cleartext_bound: 4,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let raw_cleartext_vector = Precision::Raw::uniform_between_vec(
+            1..parameters.cleartext_bound,
+            parameters.polynomial_size.0,
+        );
+        let proto_cleartext_vector =
+            maker.transform_raw_vec_to_cleartext_vector(raw_cleartext_vector.as_slice());
+        (proto_secret_key, proto_cleartext_vector)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key, _) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.polynomial_size.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_input_ciphertext = <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            InputCiphertext::KeyDistribution,
+            InputCiphertext::RingStructure,
+        >>::encrypt_plaintext_vector_to_ring_glwe_ciphertext(
+            maker,
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        let proto_output_ciphertext = <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            OutputCiphertext::KeyDistribution,
+            OutputCiphertext::RingStructure,
+        >>::encrypt_plaintext_vector_to_ring_glwe_ciphertext(
+            maker,
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        (
+            proto_plaintext_vector,
+            proto_input_ciphertext,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_cleartext_vector) = repetition_proto;
+        let (_, proto_input_ciphertext, proto_output_ciphertext) = sample_proto;
+        let synth_input_ciphertext = maker.synthesize_ring_glwe_ciphertext(proto_input_ciphertext);
+        let synth_cleartext_vector = maker.synthesize_cleartext_vector(proto_cleartext_vector);
+        let synth_output_ciphertext =
+            maker.synthesize_ring_glwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_input_ciphertext,
+            synth_cleartext_vector,
+            synth_output_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext, cleartext_vector, mut output_ciphertext) = context;
+        unsafe {
+            engine.discard_mul_glwe_ciphertext_cleartext_vector_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &cleartext_vector,
+            )
+        };
+        (input_ciphertext, cleartext_vector, output_ciphertext)
+    }
+
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext, cleartext_vector, mut output_ciphertext) = context;
+        engine
+            .discard_mul_glwe_ciphertext_cleartext_vector(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &cleartext_vector,
+            )
+            .unwrap();
+        (input_ciphertext, cleartext_vector, output_ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_ciphertext, cleartext_vector, output_ciphertext) = context;
+        let (proto_secret_key, proto_cleartext_vector) = repetition_proto;
+        let (proto_plaintext_vector, ..) = sample_proto;
+        let raw_plaintext_vector =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector);
+        let raw_cleartext_vector =
+            maker.transform_cleartext_vector_to_raw_vec(proto_cleartext_vector);
+        let expected_mean = multiply_in_ring::<Precision::Raw, InputCiphertext::RingStructure>(
+            raw_plaintext_vector.as_slice(),
+            raw_cleartext_vector.as_slice(),
+        );
+        let proto_output_ciphertext = maker.unsynthesize_ring_glwe_ciphertext(&output_ciphertext);
+        let proto_output_plaintext_vector = <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            OutputCiphertext::KeyDistribution,
+            OutputCiphertext::RingStructure,
+        >>::decrypt_ring_glwe_ciphertext_to_plaintext_vector(
+            maker,
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_ring_glwe_ciphertext(input_ciphertext);
+        maker.destroy_cleartext_vector(cleartext_vector);
+        maker.destroy_ring_glwe_ciphertext(output_ciphertext);
+        (
+            expected_mean,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let (_, proto_cleartext_vector) = repetition_proto;
+        let raw_cleartext_vector =
+            maker.transform_cleartext_vector_to_raw_vec(proto_cleartext_vector);
+        // Every coefficient of the output noise is a combination of all the coefficients of the
+        // input noise, weighted by the cleartexts up to their sign, whatever the ring.
+        let squared_norm: f64 = raw_cleartext_vector
+            .iter()
+            .map(|c| {
+                let c: f64 = (*c).cast_into();
+                c * c
+            })
+            .sum();
+        (Variance(parameters.noise.get_variance() * squared_norm),)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+}
+
+// Computes the product of two polynomials in the ring given by `RingStructure`.
+fn multiply_in_ring<Raw, RingStructure>(polynomial: &[Raw], other: &[Raw]) -> Vec<Raw>
+where
+    Raw: UnsignedInteger,
+    RingStructure: RingStructureMarker,
+{
+    let size = polynomial.len();
+    let is_cyclic = TypeId::of::<RingStructure>() == TypeId::of::<CyclicRing>();
+    let mut output = vec![Raw::ZERO; size];
+    for (i, coefficient) in polynomial.iter().enumerate() {
+        for (j, other_coefficient) in other.iter().enumerate() {
+            let product = coefficient.wrapping_mul(*other_coefficient);
+            let degree = i + j;
+            if degree < size {
+                output[degree] = output[degree].wrapping_add(product);
+            } else if is_cyclic {
+                // X^N = 1 in the cyclic ring.
+                output[degree - size] = output[degree - size].wrapping_add(product);
+            } else {
+                // X^N = -1 in the negacyclic ring.
+                output[degree - size] = output[degree - size].wrapping_sub(product);
+            }
+        }
+    }
+    output
+}
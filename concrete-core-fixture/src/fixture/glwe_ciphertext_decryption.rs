@@ -6,6 +6,7 @@ use crate::generation::synthesizing::{
     SynthesizesGlweCiphertext, SynthesizesGlweSecretKey, SynthesizesPlaintextVector,
 };
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
@@ -14,10 +15,13 @@ use concrete_core::prelude::{
     GlweCiphertextDecryptionEngine, GlweCiphertextEntity, GlweSecretKeyEntity,
     PlaintextVectorEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextDecryptionEngine` trait.
 pub struct GlweCiphertextDecryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextDecryptionParameters {
     pub noise: Variance,
@@ -56,8 +60,23 @@ where
             vec![
                 GlweCiphertextDecryptionParameters {
                     noise: Variance(0.00000001),
-                    glwe_dimension: GlweDimension(200),
-                    polynomial_size: PolynomialSize(256),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2048),
+                },
+                GlweCiphertextDecryptionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweCiphertextDecryptionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(3),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweCiphertextDecryptionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(4),
+                    polynomial_size: PolynomialSize(512),
                 },
                 GlweCiphertextDecryptionParameters {
                     noise: Variance(0.00000001),
@@ -119,6 +138,18 @@ where
         (secret_key, ciphertext, plaintext_vector)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, ciphertext) = context;
+        let plaintext_vector = engine
+            .decrypt_glwe_ciphertext(&secret_key, &ciphertext)
+            .unwrap();
+        (secret_key, ciphertext, plaintext_vector)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -152,4 +183,11 @@ where
         let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
         assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
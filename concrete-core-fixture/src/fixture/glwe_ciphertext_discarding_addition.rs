@@ -0,0 +1,280 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweSecretKey, PrototypesPlaintextVector, PrototypesRingGlweCiphertext,
+};
+use crate::generation::synthesizing::SynthesizesRingGlweCiphertext;
+use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+use concrete_core::prelude::{GlweCiphertextDiscardingAdditionEngine, GlweCiphertextEntity};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `GlweCiphertextDiscardingAdditionEngine` trait.
+pub struct GlweCiphertextDiscardingAdditionFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct GlweCiphertextDiscardingAdditionParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+}
+
+impl<Precision, Engine, InputCiphertext, OutputCiphertext>
+    Fixture<Precision, Engine, (InputCiphertext, OutputCiphertext)>
+    for GlweCiphertextDiscardingAdditionFixture
+where
+    Precision: IntegerPrecision,
+    Engine: GlweCiphertextDiscardingAdditionEngine<InputCiphertext, OutputCiphertext>,
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity<
+        KeyDistribution = InputCiphertext::KeyDistribution,
+        RingStructure = InputCiphertext::RingStructure,
+    >,
+    Maker: SynthesizesRingGlweCiphertext<Precision, InputCiphertext>
+        + SynthesizesRingGlweCiphertext<Precision, OutputCiphertext>,
+{
+    type Parameters = GlweCiphertextDiscardingAdditionParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, InputCiphertext::KeyDistribution>>::GlweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            InputCiphertext::KeyDistribution,
+            InputCiphertext::RingStructure,
+        >>::RingGlweCiphertextProto,
+        <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            InputCiphertext::KeyDistribution,
+            InputCiphertext::RingStructure,
+        >>::RingGlweCiphertextProto,
+        <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            OutputCiphertext::KeyDistribution,
+            OutputCiphertext::RingStructure,
+        >>::RingGlweCiphertextProto,
+    );
+    type PreExecutionContext = (InputCiphertext, InputCiphertext, OutputCiphertext);
+    type PostExecutionContext = (InputCiphertext, InputCiphertext, OutputCiphertext);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextDiscardingAdditionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2048),
+                },
+                GlweCiphertextDiscardingAdditionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweCiphertextDiscardingAdditionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(3),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweCiphertextDiscardingAdditionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(4),
+                    polynomial_size: PolynomialSize(512),
+                },
+                GlweCiphertextDiscardingAdditionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext_vector_1 = Precision::Raw::uniform_vec(parameters.polynomial_size.0);
+        let proto_plaintext_vector_1 =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector_1.as_slice());
+        let raw_plaintext_vector_2 = Precision::Raw::uniform_vec(parameters.polynomial_size.0);
+        let proto_plaintext_vector_2 =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector_2.as_slice());
+        let proto_input_ciphertext_1 = <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            InputCiphertext::KeyDistribution,
+            InputCiphertext::RingStructure,
+        >>::encrypt_plaintext_vector_to_ring_glwe_ciphertext(
+            maker,
+            proto_secret_key,
+            &proto_plaintext_vector_1,
+            parameters.noise,
+        );
+        let proto_input_ciphertext_2 = <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            InputCiphertext::KeyDistribution,
+            InputCiphertext::RingStructure,
+        >>::encrypt_plaintext_vector_to_ring_glwe_ciphertext(
+            maker,
+            proto_secret_key,
+            &proto_plaintext_vector_2,
+            parameters.noise,
+        );
+        let proto_output_ciphertext = <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            OutputCiphertext::KeyDistribution,
+            OutputCiphertext::RingStructure,
+        >>::encrypt_plaintext_vector_to_ring_glwe_ciphertext(
+            maker,
+            proto_secret_key,
+            &proto_plaintext_vector_1,
+            parameters.noise,
+        );
+        (
+            proto_plaintext_vector_1,
+            proto_plaintext_vector_2,
+            proto_input_ciphertext_1,
+            proto_input_ciphertext_2,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, _, proto_input_ciphertext_1, proto_input_ciphertext_2, proto_output_ciphertext) =
+            sample_proto;
+        let synth_input_ciphertext_1 =
+            maker.synthesize_ring_glwe_ciphertext(proto_input_ciphertext_1);
+        let synth_input_ciphertext_2 =
+            maker.synthesize_ring_glwe_ciphertext(proto_input_ciphertext_2);
+        let synth_output_ciphertext =
+            maker.synthesize_ring_glwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_input_ciphertext_1,
+            synth_input_ciphertext_2,
+            synth_output_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext_1, input_ciphertext_2, mut output_ciphertext) = context;
+        unsafe {
+            engine.discard_add_glwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext_1,
+                &input_ciphertext_2,
+            )
+        };
+        (input_ciphertext_1, input_ciphertext_2, output_ciphertext)
+    }
+
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext_1, input_ciphertext_2, mut output_ciphertext) = context;
+        engine
+            .discard_add_glwe_ciphertext(
+                &mut output_ciphertext,
+                &input_ciphertext_1,
+                &input_ciphertext_2,
+            )
+            .unwrap();
+        (input_ciphertext_1, input_ciphertext_2, output_ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_ciphertext_1, input_ciphertext_2, output_ciphertext) = context;
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext_vector_1, proto_plaintext_vector_2, ..) = sample_proto;
+        let raw_plaintext_vector_1 =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector_1);
+        let raw_plaintext_vector_2 =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector_2);
+        let expected_mean = raw_plaintext_vector_1
+            .iter()
+            .zip(raw_plaintext_vector_2.iter())
+            .map(|(a, b)| a.wrapping_add(*b))
+            .collect();
+        let proto_output_ciphertext = maker.unsynthesize_ring_glwe_ciphertext(&output_ciphertext);
+        let proto_output_plaintext_vector = <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            OutputCiphertext::KeyDistribution,
+            OutputCiphertext::RingStructure,
+        >>::decrypt_ring_glwe_ciphertext_to_plaintext_vector(
+            maker,
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_ring_glwe_ciphertext(input_ciphertext_1);
+        maker.destroy_ring_glwe_ciphertext(input_ciphertext_2);
+        maker.destroy_ring_glwe_ciphertext(output_ciphertext);
+        (
+            expected_mean,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let predicted_variance: Variance = concrete_npe::estimate_addition_noise::<
+            Precision::Raw,
+            _,
+            _,
+        >(parameters.noise, parameters.noise);
+        (predicted_variance,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+}
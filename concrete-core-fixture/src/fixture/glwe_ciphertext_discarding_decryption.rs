@@ -6,6 +6,7 @@ use crate::generation::synthesizing::{
     SynthesizesGlweCiphertext, SynthesizesGlweSecretKey, SynthesizesPlaintextVector,
 };
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
@@ -14,10 +15,13 @@ use concrete_core::prelude::{
     GlweCiphertextDiscardingDecryptionEngine, GlweCiphertextEntity, GlweSecretKeyEntity,
     PlaintextVectorEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextDiscardingDecryptionEngine` trait.
 pub struct GlweCiphertextDiscardingDecryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextDiscardingDecryptionParameters {
     pub noise: Variance,
@@ -57,8 +61,23 @@ where
             vec![
                 GlweCiphertextDiscardingDecryptionParameters {
                     noise: Variance(0.00000001),
-                    glwe_dimension: GlweDimension(200),
-                    polynomial_size: PolynomialSize(256),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2048),
+                },
+                GlweCiphertextDiscardingDecryptionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweCiphertextDiscardingDecryptionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(3),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweCiphertextDiscardingDecryptionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(4),
+                    polynomial_size: PolynomialSize(512),
                 },
                 GlweCiphertextDiscardingDecryptionParameters {
                     noise: Variance(0.00000001),
@@ -133,6 +152,18 @@ where
         (secret_key, ciphertext, plaintext_vector)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, ciphertext, mut plaintext_vector) = context;
+        engine
+            .discard_decrypt_glwe_ciphertext(&secret_key, &mut plaintext_vector, &ciphertext)
+            .unwrap();
+        (secret_key, ciphertext, plaintext_vector)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -166,4 +197,11 @@ where
         let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
         assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
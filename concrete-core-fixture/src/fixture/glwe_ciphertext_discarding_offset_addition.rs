@@ -0,0 +1,315 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweSecretKey, PrototypesPlaintextVector, PrototypesRingGlweCiphertext,
+};
+use crate::generation::synthesizing::SynthesizesRingGlweCiphertext;
+use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::{CastInto, UnsignedInteger};
+use concrete_commons::parameters::{GlweDimension, MonomialIndex, PlaintextCount, PolynomialSize};
+use concrete_core::prelude::{GlweCiphertextDiscardingOffsetAdditionEngine, GlweCiphertextEntity};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `GlweCiphertextDiscardingOffsetAdditionEngine` trait.
+pub struct GlweCiphertextDiscardingOffsetAdditionFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct GlweCiphertextDiscardingOffsetAdditionParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+}
+
+impl<Precision, Engine, InputCiphertext, OutputCiphertext>
+    Fixture<Precision, Engine, (InputCiphertext, OutputCiphertext)>
+    for GlweCiphertextDiscardingOffsetAdditionFixture
+where
+    Precision: IntegerPrecision,
+    Engine: GlweCiphertextDiscardingOffsetAdditionEngine<InputCiphertext, OutputCiphertext>,
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity<
+        KeyDistribution = InputCiphertext::KeyDistribution,
+        RingStructure = InputCiphertext::RingStructure,
+    >,
+    Maker: SynthesizesRingGlweCiphertext<Precision, InputCiphertext>
+        + SynthesizesRingGlweCiphertext<Precision, OutputCiphertext>,
+{
+    type Parameters = GlweCiphertextDiscardingOffsetAdditionParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, InputCiphertext::KeyDistribution>>::GlweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            InputCiphertext::KeyDistribution,
+            InputCiphertext::RingStructure,
+        >>::RingGlweCiphertextProto,
+        <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            InputCiphertext::KeyDistribution,
+            InputCiphertext::RingStructure,
+        >>::RingGlweCiphertextProto,
+        <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            OutputCiphertext::KeyDistribution,
+            OutputCiphertext::RingStructure,
+        >>::RingGlweCiphertextProto,
+        MonomialIndex,
+        PlaintextCount,
+    );
+    type PreExecutionContext = (
+        InputCiphertext,
+        InputCiphertext,
+        OutputCiphertext,
+        MonomialIndex,
+        PlaintextCount,
+    );
+    type PostExecutionContext = (InputCiphertext, InputCiphertext, OutputCiphertext);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextDiscardingOffsetAdditionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2048),
+                },
+                GlweCiphertextDiscardingOffsetAdditionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweCiphertextDiscardingOffsetAdditionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(3),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweCiphertextDiscardingOffsetAdditionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(4),
+                    polynomial_size: PolynomialSize(512),
+                },
+                GlweCiphertextDiscardingOffsetAdditionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let polynomial_size = parameters.polynomial_size.0;
+        // The slots of the second input are drawn first, so that the offset can be drawn among the
+        // ones keeping them inside the polynomial.
+        let slot_count: f64 = Precision::Raw::uniform_between(1..polynomial_size + 1).cast_into();
+        let slot_count = PlaintextCount(slot_count as usize);
+        let offset: f64 =
+            Precision::Raw::uniform_between(0..polynomial_size - slot_count.0 + 1).cast_into();
+        let offset = MonomialIndex(offset as usize);
+        let raw_plaintext_vector_1 = Precision::Raw::uniform_vec(polynomial_size);
+        let proto_plaintext_vector_1 =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector_1.as_slice());
+        let mut raw_plaintext_vector_2 = Precision::Raw::uniform_vec(slot_count.0);
+        raw_plaintext_vector_2.resize(polynomial_size, Precision::Raw::zero());
+        let proto_plaintext_vector_2 =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector_2.as_slice());
+        let proto_input_ciphertext_1 = <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            InputCiphertext::KeyDistribution,
+            InputCiphertext::RingStructure,
+        >>::encrypt_plaintext_vector_to_ring_glwe_ciphertext(
+            maker,
+            proto_secret_key,
+            &proto_plaintext_vector_1,
+            parameters.noise,
+        );
+        let proto_input_ciphertext_2 = <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            InputCiphertext::KeyDistribution,
+            InputCiphertext::RingStructure,
+        >>::encrypt_plaintext_vector_to_ring_glwe_ciphertext(
+            maker,
+            proto_secret_key,
+            &proto_plaintext_vector_2,
+            parameters.noise,
+        );
+        let proto_output_ciphertext = <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            OutputCiphertext::KeyDistribution,
+            OutputCiphertext::RingStructure,
+        >>::encrypt_plaintext_vector_to_ring_glwe_ciphertext(
+            maker,
+            proto_secret_key,
+            &proto_plaintext_vector_1,
+            parameters.noise,
+        );
+        (
+            proto_plaintext_vector_1,
+            proto_plaintext_vector_2,
+            proto_input_ciphertext_1,
+            proto_input_ciphertext_2,
+            proto_output_ciphertext,
+            offset,
+            slot_count,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (
+            _,
+            _,
+            proto_input_ciphertext_1,
+            proto_input_ciphertext_2,
+            proto_output_ciphertext,
+            offset,
+            slot_count,
+        ) = sample_proto;
+        let synth_input_ciphertext_1 =
+            maker.synthesize_ring_glwe_ciphertext(proto_input_ciphertext_1);
+        let synth_input_ciphertext_2 =
+            maker.synthesize_ring_glwe_ciphertext(proto_input_ciphertext_2);
+        let synth_output_ciphertext =
+            maker.synthesize_ring_glwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_input_ciphertext_1,
+            synth_input_ciphertext_2,
+            synth_output_ciphertext,
+            *offset,
+            *slot_count,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext_1, input_ciphertext_2, mut output_ciphertext, offset, slot_count) =
+            context;
+        unsafe {
+            engine.discard_offset_add_glwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext_1,
+                &input_ciphertext_2,
+                offset,
+                slot_count,
+            )
+        };
+        (input_ciphertext_1, input_ciphertext_2, output_ciphertext)
+    }
+
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext_1, input_ciphertext_2, mut output_ciphertext, offset, slot_count) =
+            context;
+        engine
+            .discard_offset_add_glwe_ciphertext(
+                &mut output_ciphertext,
+                &input_ciphertext_1,
+                &input_ciphertext_2,
+                offset,
+                slot_count,
+            )
+            .unwrap();
+        (input_ciphertext_1, input_ciphertext_2, output_ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_ciphertext_1, input_ciphertext_2, output_ciphertext) = context;
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext_vector_1, proto_plaintext_vector_2, .., offset, slot_count) =
+            sample_proto;
+        let raw_plaintext_vector_1 =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector_1);
+        let raw_plaintext_vector_2 =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector_2);
+        let mut expected_mean = raw_plaintext_vector_1;
+        expected_mean[offset.0..offset.0 + slot_count.0]
+            .iter_mut()
+            .zip(raw_plaintext_vector_2.iter())
+            .for_each(|(a, b)| *a = a.wrapping_add(*b));
+        let proto_output_ciphertext = maker.unsynthesize_ring_glwe_ciphertext(&output_ciphertext);
+        let proto_output_plaintext_vector = <Maker as PrototypesRingGlweCiphertext<
+            Precision,
+            OutputCiphertext::KeyDistribution,
+            OutputCiphertext::RingStructure,
+        >>::decrypt_ring_glwe_ciphertext_to_plaintext_vector(
+            maker,
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_ring_glwe_ciphertext(input_ciphertext_1);
+        maker.destroy_ring_glwe_ciphertext(input_ciphertext_2);
+        maker.destroy_ring_glwe_ciphertext(output_ciphertext);
+        (
+            expected_mean,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let predicted_variance: Variance = concrete_npe::estimate_addition_noise::<
+            Precision::Raw,
+            _,
+            _,
+        >(parameters.noise, parameters.noise);
+        (predicted_variance,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+}
@@ -0,0 +1,233 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweAutomorphismKey, PrototypesGlweCiphertext, PrototypesGlweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesGlweAutomorphismKey, SynthesizesGlweCiphertext};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use concrete_core::prelude::{
+    GlweAutomorphismKeyEntity, GlweCiphertextEntity, GlweCiphertextTraceEngine,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `GlweCiphertextTraceEngine` trait.
+pub struct GlweCiphertextTraceFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct GlweCiphertextTraceParameters {
+    pub input_noise: Variance,
+    pub key_noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub decomp_base_log: DecompositionBaseLog,
+}
+
+impl<Precision, Engine, AutomorphismKey, InputCiphertext, OutputCiphertext>
+    Fixture<Precision, Engine, (AutomorphismKey, InputCiphertext, OutputCiphertext)>
+    for GlweCiphertextTraceFixture
+where
+    Precision: IntegerPrecision,
+    Engine: GlweCiphertextTraceEngine<AutomorphismKey, InputCiphertext, OutputCiphertext>,
+    AutomorphismKey: GlweAutomorphismKeyEntity,
+    InputCiphertext: GlweCiphertextEntity<KeyDistribution = AutomorphismKey::KeyDistribution>,
+    OutputCiphertext: GlweCiphertextEntity<KeyDistribution = AutomorphismKey::KeyDistribution>,
+    Maker: SynthesizesGlweAutomorphismKey<Precision, AutomorphismKey>
+        + SynthesizesGlweCiphertext<Precision, InputCiphertext>
+        + SynthesizesGlweCiphertext<Precision, OutputCiphertext>,
+{
+    type Parameters = GlweCiphertextTraceParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, AutomorphismKey::KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGlweAutomorphismKey<Precision, AutomorphismKey::KeyDistribution>>::GlweAutomorphismKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, AutomorphismKey::KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (AutomorphismKey, InputCiphertext);
+    type PostExecutionContext = (AutomorphismKey, InputCiphertext, OutputCiphertext);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextTraceParameters {
+                    input_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-20.).get_variance(),
+                    ),
+                    key_noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(256),
+                    decomp_level_count: DecompositionLevelCount(4),
+                    decomp_base_log: DecompositionBaseLog(6),
+                },
+                GlweCiphertextTraceParameters {
+                    input_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-20.).get_variance(),
+                    ),
+                    key_noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2),
+                    decomp_level_count: DecompositionLevelCount(4),
+                    decomp_base_log: DecompositionBaseLog(6),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let proto_automorphism_key = maker.new_glwe_automorphism_key(
+            &proto_secret_key,
+            parameters.decomp_level_count,
+            parameters.decomp_base_log,
+            parameters.key_noise,
+        );
+        (proto_secret_key, proto_automorphism_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key, _) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.polynomial_size.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_input_ciphertext = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.input_noise,
+        );
+        (proto_plaintext_vector, proto_input_ciphertext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_automorphism_key) = repetition_proto;
+        let (_, proto_input_ciphertext) = sample_proto;
+        (
+            maker.synthesize_glwe_automorphism_key(proto_automorphism_key),
+            maker.synthesize_glwe_ciphertext(proto_input_ciphertext),
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (automorphism_key, input_ciphertext) = context;
+        let output_ciphertext =
+            unsafe { engine.trace_glwe_ciphertext_unchecked(&automorphism_key, &input_ciphertext) };
+        (automorphism_key, input_ciphertext, output_ciphertext)
+    }
+
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (automorphism_key, input_ciphertext) = context;
+        let output_ciphertext = engine
+            .trace_glwe_ciphertext(&automorphism_key, &input_ciphertext)
+            .unwrap();
+        (automorphism_key, input_ciphertext, output_ciphertext)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (automorphism_key, input_ciphertext, output_ciphertext) = context;
+        let (proto_secret_key, _) = repetition_proto;
+        let (proto_plaintext_vector, _) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(&output_ciphertext);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_glwe_automorphism_key(automorphism_key);
+        maker.destroy_glwe_ciphertext(input_ciphertext);
+        maker.destroy_glwe_ciphertext(output_ciphertext);
+        // The trace of the plaintext is the constant polynomial N * m_0.
+        let raw_plaintext_vector =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector);
+        let mut expected = Precision::Raw::zero_vec(parameters.polynomial_size.0);
+        expected[0] = raw_plaintext_vector[0].wrapping_mul(Precision::Raw::power_of_two(
+            parameters.polynomial_size.0.trailing_zeros() as usize,
+        ));
+        (
+            expected,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        // Every step of the trace adds the keyswitched automorphism of the ciphertext to itself,
+        // which doubles the noise of the input before adding the noise of the keyswitch.
+        let mask_size = LweDimension(parameters.glwe_dimension.0 * parameters.polynomial_size.0);
+        let mut predicted_variance = parameters.input_noise;
+        for _ in 0..parameters.polynomial_size.0.trailing_zeros() {
+            let switched_variance: Variance =
+                concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+                    Precision::Raw,
+                    _,
+                    _,
+                    AutomorphismKey::KeyDistribution,
+                >(
+                    mask_size,
+                    predicted_variance,
+                    parameters.key_noise,
+                    parameters.decomp_base_log,
+                    parameters.decomp_level_count,
+                );
+            predicted_variance =
+                Variance(predicted_variance.get_variance() + switched_variance.get_variance());
+        }
+        (predicted_variance,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+}
@@ -2,6 +2,7 @@ use crate::fixture::Fixture;
 use crate::generation::prototyping::{PrototypesGlweCiphertext, PrototypesPlaintextVector};
 use crate::generation::synthesizing::{SynthesizesGlweCiphertext, SynthesizesPlaintextVector};
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
@@ -9,10 +10,13 @@ use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 use concrete_core::prelude::{
     GlweCiphertextEntity, GlweCiphertextTrivialDecryptionEngine, PlaintextVectorEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextTrivialDecryptionEngine` trait.
 pub struct GlweCiphertextTrivialDecryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextTrivialDecryptionParameters {
     pub glwe_dimension: GlweDimension,
@@ -45,8 +49,20 @@ where
         Box::new(
             vec![
                 GlweCiphertextTrivialDecryptionParameters {
-                    glwe_dimension: GlweDimension(200),
-                    polynomial_size: PolynomialSize(256),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2048),
+                },
+                GlweCiphertextTrivialDecryptionParameters {
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweCiphertextTrivialDecryptionParameters {
+                    glwe_dimension: GlweDimension(3),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweCiphertextTrivialDecryptionParameters {
+                    glwe_dimension: GlweDimension(4),
+                    polynomial_size: PolynomialSize(512),
                 },
                 GlweCiphertextTrivialDecryptionParameters {
                     glwe_dimension: GlweDimension(1),
@@ -100,6 +116,18 @@ where
         (ciphertext, plaintext_vector)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (ciphertext,) = context;
+        let plaintext_vector = engine
+            .trivially_decrypt_glwe_ciphertext(&ciphertext)
+            .unwrap();
+        (ciphertext, plaintext_vector)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -132,4 +160,11 @@ where
         let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
         assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
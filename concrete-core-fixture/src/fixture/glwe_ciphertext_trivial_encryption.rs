@@ -2,6 +2,7 @@ use crate::fixture::Fixture;
 use crate::generation::prototyping::{PrototypesGlweCiphertext, PrototypesPlaintextVector};
 use crate::generation::synthesizing::{SynthesizesGlweCiphertext, SynthesizesPlaintextVector};
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
@@ -9,10 +10,13 @@ use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 use concrete_core::prelude::{
     GlweCiphertextEntity, GlweCiphertextTrivialEncryptionEngine, PlaintextVectorEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextTrivialEncryptionEngine` trait.
 pub struct GlweCiphertextTrivialEncryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextTrivialEncryptionParameters {
     pub glwe_dimension: GlweDimension,
@@ -43,8 +47,20 @@ where
         Box::new(
             vec![
                 GlweCiphertextTrivialEncryptionParameters {
-                    glwe_dimension: GlweDimension(200),
-                    polynomial_size: PolynomialSize(256),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2048),
+                },
+                GlweCiphertextTrivialEncryptionParameters {
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweCiphertextTrivialEncryptionParameters {
+                    glwe_dimension: GlweDimension(3),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweCiphertextTrivialEncryptionParameters {
+                    glwe_dimension: GlweDimension(4),
+                    polynomial_size: PolynomialSize(512),
                 },
                 GlweCiphertextTrivialEncryptionParameters {
                     glwe_dimension: GlweDimension(1),
@@ -97,6 +113,21 @@ where
         (plaintext_vector, ciphertext)
     }
 
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (plaintext_vector,) = context;
+        let ciphertext = engine
+            .trivially_encrypt_glwe_ciphertext(
+                parameters.glwe_dimension.to_glwe_size(),
+                &plaintext_vector,
+            )
+            .unwrap();
+        (plaintext_vector, ciphertext)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -131,4 +162,11 @@ where
         let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
@@ -12,12 +12,16 @@ use crate::generation::synthesizing::{
     SynthesizesGlweCiphertextVector, SynthesizesGlweSecretKey, SynthesizesPlaintextVector,
 };
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextVectorDecryptionEngine` trait.
 pub struct GlweCiphertextVectorDecryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextVectorDecryptionParameters {
     pub glwe_dimension: GlweDimension,
@@ -57,8 +61,26 @@ where
         Box::new(
             vec![
                 GlweCiphertextVectorDecryptionParameters {
-                    glwe_dimension: GlweDimension(200),
-                    polynomial_size: PolynomialSize(256),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2048),
+                    count: GlweCiphertextCount(10),
+                    noise: Variance(0.00000001),
+                },
+                GlweCiphertextVectorDecryptionParameters {
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                    count: GlweCiphertextCount(10),
+                    noise: Variance(0.00000001),
+                },
+                GlweCiphertextVectorDecryptionParameters {
+                    glwe_dimension: GlweDimension(3),
+                    polynomial_size: PolynomialSize(1024),
+                    count: GlweCiphertextCount(10),
+                    noise: Variance(0.00000001),
+                },
+                GlweCiphertextVectorDecryptionParameters {
+                    glwe_dimension: GlweDimension(4),
+                    polynomial_size: PolynomialSize(512),
                     count: GlweCiphertextCount(10),
                     noise: Variance(0.00000001),
                 },
@@ -126,6 +148,18 @@ where
         (secret_key, plaintext_vector, ciphertext_vector)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, ciphertext_vector) = context;
+        let plaintext_vector = engine
+            .decrypt_glwe_ciphertext_vector(&secret_key, &ciphertext_vector)
+            .unwrap();
+        (secret_key, plaintext_vector, ciphertext_vector)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -166,4 +200,11 @@ where
         let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
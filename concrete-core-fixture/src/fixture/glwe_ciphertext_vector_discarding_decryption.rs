@@ -13,12 +13,16 @@ use crate::generation::synthesizing::{
     SynthesizesGlweCiphertextVector, SynthesizesGlweSecretKey, SynthesizesPlaintextVector,
 };
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextVectorDiscardingDecryptionEngine` trait.
 pub struct GlweCiphertextVectorDiscardingDecryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextVectorDiscardingDecryptionParameters {
     pub noise: Variance,
@@ -63,8 +67,26 @@ where
             vec![
                 GlweCiphertextVectorDiscardingDecryptionParameters {
                     noise: Variance(0.00000001),
-                    glwe_dimension: GlweDimension(200),
-                    polynomial_size: PolynomialSize(256),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2048),
+                    count: GlweCiphertextCount(10),
+                },
+                GlweCiphertextVectorDiscardingDecryptionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                    count: GlweCiphertextCount(10),
+                },
+                GlweCiphertextVectorDiscardingDecryptionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(3),
+                    polynomial_size: PolynomialSize(1024),
+                    count: GlweCiphertextCount(10),
+                },
+                GlweCiphertextVectorDiscardingDecryptionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(4),
+                    polynomial_size: PolynomialSize(512),
                     count: GlweCiphertextCount(10),
                 },
                 GlweCiphertextVectorDiscardingDecryptionParameters {
@@ -143,6 +165,22 @@ where
         (secret_key, ciphertext_vector, plaintext_vector)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, ciphertext_vector, mut plaintext_vector) = context;
+        engine
+            .discard_decrypt_glwe_ciphertext_vector(
+                &secret_key,
+                &mut plaintext_vector,
+                &ciphertext_vector,
+            )
+            .unwrap();
+        (secret_key, ciphertext_vector, plaintext_vector)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -176,4 +214,11 @@ where
         let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
         assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
@@ -13,12 +13,16 @@ use crate::generation::synthesizing::{
     SynthesizesGlweCiphertextVector, SynthesizesGlweSecretKey, SynthesizesPlaintextVector,
 };
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextVectorDiscardingEncryptionEngine` trait.
 pub struct GlweCiphertextVectorDiscardingEncryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextVectorDiscardingEncryptionParameters {
     pub noise: Variance,
@@ -63,8 +67,26 @@ where
             vec![
                 GlweCiphertextVectorDiscardingEncryptionParameters {
                     noise: Variance(0.00000001),
-                    glwe_dimension: GlweDimension(200),
-                    polynomial_size: PolynomialSize(256),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2048),
+                    count: GlweCiphertextCount(10),
+                },
+                GlweCiphertextVectorDiscardingEncryptionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                    count: GlweCiphertextCount(10),
+                },
+                GlweCiphertextVectorDiscardingEncryptionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(3),
+                    polynomial_size: PolynomialSize(1024),
+                    count: GlweCiphertextCount(10),
+                },
+                GlweCiphertextVectorDiscardingEncryptionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(4),
+                    polynomial_size: PolynomialSize(512),
                     count: GlweCiphertextCount(10),
                 },
                 GlweCiphertextVectorDiscardingEncryptionParameters {
@@ -136,6 +158,23 @@ where
         (secret_key, plaintext_vector, ciphertext_vector)
     }
 
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, plaintext_vector, mut ciphertext_vector) = context;
+        engine
+            .discard_encrypt_glwe_ciphertext_vector(
+                &secret_key,
+                &mut ciphertext_vector,
+                &plaintext_vector,
+                parameters.noise,
+            )
+            .unwrap();
+        (secret_key, plaintext_vector, ciphertext_vector)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -175,4 +214,15 @@ where
         let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+
+    fn checked_outcome_matches(_checked: &Self::Outcome, _unchecked: &Self::Outcome) -> bool {
+        true
+    }
 }
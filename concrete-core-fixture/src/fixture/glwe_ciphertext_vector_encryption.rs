@@ -6,6 +6,7 @@ use crate::generation::synthesizing::{
     SynthesizesGlweCiphertextVector, SynthesizesGlweSecretKey, SynthesizesPlaintextVector,
 };
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
@@ -14,10 +15,13 @@ use concrete_core::prelude::{
     GlweCiphertextVectorEncryptionEngine, GlweCiphertextVectorEntity, GlweSecretKeyEntity,
     PlaintextVectorEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextVectorEncryptionEngine` trait.
 pub struct GlweCiphertextVectorEncryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextVectorEncryptionParameters {
     pub noise: Variance,
@@ -54,8 +58,26 @@ where
         Box::new(
             vec![
                 GlweCiphertextVectorEncryptionParameters {
-                    glwe_dimension: GlweDimension(200),
-                    polynomial_size: PolynomialSize(256),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2048),
+                    count: GlweCiphertextCount(10),
+                    noise: Variance(0.00000001),
+                },
+                GlweCiphertextVectorEncryptionParameters {
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                    count: GlweCiphertextCount(10),
+                    noise: Variance(0.00000001),
+                },
+                GlweCiphertextVectorEncryptionParameters {
+                    glwe_dimension: GlweDimension(3),
+                    polynomial_size: PolynomialSize(1024),
+                    count: GlweCiphertextCount(10),
+                    noise: Variance(0.00000001),
+                },
+                GlweCiphertextVectorEncryptionParameters {
+                    glwe_dimension: GlweDimension(4),
+                    polynomial_size: PolynomialSize(512),
                     count: GlweCiphertextCount(10),
                     noise: Variance(0.00000001),
                 },
@@ -121,6 +143,18 @@ where
         (secret_key, plaintext_vector, ciphertext)
     }
 
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, plaintext_vector) = context;
+        let ciphertext = engine
+            .encrypt_glwe_ciphertext_vector(&secret_key, &plaintext_vector, parameters.noise)
+            .unwrap();
+        (secret_key, plaintext_vector, ciphertext)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -161,4 +195,15 @@ where
         let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+
+    fn checked_outcome_matches(_checked: &Self::Outcome, _unchecked: &Self::Outcome) -> bool {
+        true
+    }
 }
@@ -4,6 +4,7 @@ use crate::generation::synthesizing::{
     SynthesizesGlweCiphertextVector, SynthesizesPlaintextVector,
 };
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
@@ -11,10 +12,13 @@ use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, Polynomia
 use concrete_core::prelude::{
     GlweCiphertextVectorEntity, GlweCiphertextVectorTrivialDecryptionEngine, PlaintextVectorEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextVectorTrivialDecryptionEngine` trait.
 pub struct GlweCiphertextVectorTrivialDecryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextVectorTrivialDecryptionParameters {
     pub glwe_dimension: GlweDimension,
@@ -52,8 +56,23 @@ where
         Box::new(
             vec![
                 GlweCiphertextVectorTrivialDecryptionParameters {
-                    glwe_dimension: GlweDimension(200),
-                    polynomial_size: PolynomialSize(256),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2048),
+                    count: GlweCiphertextCount(100),
+                },
+                GlweCiphertextVectorTrivialDecryptionParameters {
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                    count: GlweCiphertextCount(100),
+                },
+                GlweCiphertextVectorTrivialDecryptionParameters {
+                    glwe_dimension: GlweDimension(3),
+                    polynomial_size: PolynomialSize(1024),
+                    count: GlweCiphertextCount(100),
+                },
+                GlweCiphertextVectorTrivialDecryptionParameters {
+                    glwe_dimension: GlweDimension(4),
+                    polynomial_size: PolynomialSize(512),
                     count: GlweCiphertextCount(100),
                 },
                 GlweCiphertextVectorTrivialDecryptionParameters {
@@ -113,6 +132,18 @@ where
         (ciphertext_vector, plaintext_vector)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (ciphertext_vector,) = context;
+        let plaintext_vector = engine
+            .trivially_decrypt_glwe_ciphertext_vector(&ciphertext_vector)
+            .unwrap();
+        (ciphertext_vector, plaintext_vector)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -145,4 +176,11 @@ where
         let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
         assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
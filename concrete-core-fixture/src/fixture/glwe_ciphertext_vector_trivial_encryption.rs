@@ -4,6 +4,7 @@ use crate::generation::synthesizing::{
     SynthesizesGlweCiphertextVector, SynthesizesPlaintextVector,
 };
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
@@ -11,10 +12,13 @@ use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, Polynomia
 use concrete_core::prelude::{
     GlweCiphertextVectorEntity, GlweCiphertextVectorTrivialEncryptionEngine, PlaintextVectorEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextVectorTrivialEncryptionEngine` trait.
 pub struct GlweCiphertextVectorTrivialEncryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextVectorTrivialEncryptionParameters {
     pub glwe_dimension: GlweDimension,
@@ -46,8 +50,23 @@ where
         Box::new(
             vec![
                 GlweCiphertextVectorTrivialEncryptionParameters {
-                    glwe_dimension: GlweDimension(200),
-                    polynomial_size: PolynomialSize(256),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2048),
+                    count: GlweCiphertextCount(100),
+                },
+                GlweCiphertextVectorTrivialEncryptionParameters {
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                    count: GlweCiphertextCount(100),
+                },
+                GlweCiphertextVectorTrivialEncryptionParameters {
+                    glwe_dimension: GlweDimension(3),
+                    polynomial_size: PolynomialSize(1024),
+                    count: GlweCiphertextCount(100),
+                },
+                GlweCiphertextVectorTrivialEncryptionParameters {
+                    glwe_dimension: GlweDimension(4),
+                    polynomial_size: PolynomialSize(512),
                     count: GlweCiphertextCount(100),
                 },
                 GlweCiphertextVectorTrivialEncryptionParameters {
@@ -104,6 +123,22 @@ where
         (plaintext_vector, ciphertext_vector)
     }
 
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (plaintext_vector,) = context;
+        let ciphertext_vector = engine
+            .trivially_encrypt_glwe_ciphertext_vector(
+                parameters.glwe_dimension.to_glwe_size(),
+                parameters.count,
+                &plaintext_vector,
+            )
+            .unwrap();
+        (plaintext_vector, ciphertext_vector)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -141,4 +176,11 @@ where
         let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
@@ -11,12 +11,16 @@ use crate::generation::prototyping::{
 };
 use crate::generation::synthesizing::{SynthesizesGlweCiphertextVector, SynthesizesGlweSecretKey};
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextVectorZeroEncryptionEngine` trait.
 pub struct GlweCiphertextVectorZeroEncryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextVectorZeroEncryptionParameters {
     pub noise: Variance,
@@ -51,8 +55,26 @@ where
             vec![
                 GlweCiphertextVectorZeroEncryptionParameters {
                     noise: Variance(0.00000001),
-                    glwe_dimension: GlweDimension(200),
-                    polynomial_size: PolynomialSize(256),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2048),
+                    count: GlweCiphertextCount(100),
+                },
+                GlweCiphertextVectorZeroEncryptionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                    count: GlweCiphertextCount(100),
+                },
+                GlweCiphertextVectorZeroEncryptionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(3),
+                    polynomial_size: PolynomialSize(1024),
+                    count: GlweCiphertextCount(100),
+                },
+                GlweCiphertextVectorZeroEncryptionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(4),
+                    polynomial_size: PolynomialSize(512),
                     count: GlweCiphertextCount(100),
                 },
                 GlweCiphertextVectorZeroEncryptionParameters {
@@ -108,6 +130,18 @@ where
         (secret_key, ciphertext_vector)
     }
 
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key,) = context;
+        let ciphertext_vector = engine
+            .zero_encrypt_glwe_ciphertext_vector(&secret_key, parameters.noise, parameters.count)
+            .unwrap();
+        (secret_key, ciphertext_vector)
+    }
+
     fn process_context(
         parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -146,4 +180,15 @@ where
         let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+
+    fn checked_outcome_matches(_checked: &Self::Outcome, _unchecked: &Self::Outcome) -> bool {
+        true
+    }
 }
@@ -3,10 +3,13 @@ use crate::generation::synthesizing::SynthesizesGlweSecretKey;
 use crate::generation::{IntegerPrecision, Maker};
 use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 use concrete_core::prelude::{GlweSecretKeyCreationEngine, GlweSecretKeyEntity};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweSecretKeyCreationEngine` trait.
 pub struct GlweSecretKeyCreationFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct GlweSecretKeyCreationParameters {
     pub glwe_dimension: GlweDimension,
@@ -31,10 +34,28 @@ where
 
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
         Box::new(
-            vec![GlweSecretKeyCreationParameters {
-                glwe_dimension: GlweDimension(1),
-                polynomial_size: PolynomialSize(1024),
-            }]
+            vec![
+                GlweSecretKeyCreationParameters {
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweSecretKeyCreationParameters {
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2048),
+                },
+                GlweSecretKeyCreationParameters {
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweSecretKeyCreationParameters {
+                    glwe_dimension: GlweDimension(3),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweSecretKeyCreationParameters {
+                    glwe_dimension: GlweDimension(4),
+                    polynomial_size: PolynomialSize(512),
+                },
+            ]
             .into_iter(),
         )
     }
@@ -74,6 +95,17 @@ where
         (sk,)
     }
 
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        _context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let sk = engine
+            .create_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size)
+            .unwrap();
+        (sk,)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -97,4 +129,8 @@ where
     fn verify(_criteria: &Self::Criteria, _outputs: &[Self::Outcome]) -> bool {
         unimplemented!()
     }
+
+    fn checked_outcome_matches(_checked: &Self::Outcome, _unchecked: &Self::Outcome) -> bool {
+        true
+    }
 }
@@ -6,10 +6,13 @@ use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 use concrete_core::prelude::{
     GlweSecretKeyEntity, GlweToLweSecretKeyTransmutationEngine, LweSecretKeyEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweKeyswitchKeyCreationEngine` trait.
 pub struct LweKeyswitchKeyCreationFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweKeyswitchKeyCreationParameters {
     pub glwe_dimension: GlweDimension,
@@ -38,10 +41,28 @@ where
 
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
         Box::new(
-            vec![LweKeyswitchKeyCreationParameters {
-                glwe_dimension: GlweDimension(1),
-                polynomial_size: PolynomialSize(1024),
-            }]
+            vec![
+                LweKeyswitchKeyCreationParameters {
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                LweKeyswitchKeyCreationParameters {
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2048),
+                },
+                LweKeyswitchKeyCreationParameters {
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                LweKeyswitchKeyCreationParameters {
+                    glwe_dimension: GlweDimension(3),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                LweKeyswitchKeyCreationParameters {
+                    glwe_dimension: GlweDimension(4),
+                    polynomial_size: PolynomialSize(512),
+                },
+            ]
             .into_iter(),
         )
     }
@@ -83,6 +104,18 @@ where
         (sk_out,)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (sk_in,) = context;
+        let sk_out = engine
+            .transmute_glwe_secret_key_to_lwe_secret_key(sk_in)
+            .unwrap();
+        (sk_out,)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -0,0 +1,178 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweSecretKey, PrototypesLweBootstrapKey, PrototypesLweSecretKey,
+};
+use crate::generation::synthesizing::SynthesizesLweBootstrapKey;
+use crate::generation::{IntegerPrecision, Maker};
+use crate::presets::single_noise;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use concrete_core::prelude::markers::BinaryKeyDistribution;
+use concrete_core::prelude::{EntityConstantTimeEqualityEngine, LweBootstrapKeyEntity};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `EntityConstantTimeEqualityEngine` trait with LWE
+/// bootstrap keys.
+///
+/// Every sample compares a bootstrap key with a copy of itself, and with another bootstrap key
+/// generated with the same parameters.
+pub struct LweBootstrapKeyConstantTimeEqualityFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweBootstrapKeyConstantTimeEqualityParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub level: DecompositionLevelCount,
+    pub base_log: DecompositionBaseLog,
+}
+
+impl<Precision, Engine, BootstrapKey> Fixture<Precision, Engine, (BootstrapKey,)>
+    for LweBootstrapKeyConstantTimeEqualityFixture
+where
+    Precision: IntegerPrecision,
+    Engine: EntityConstantTimeEqualityEngine<BootstrapKey>,
+    BootstrapKey: LweBootstrapKeyEntity<
+        InputKeyDistribution = BinaryKeyDistribution,
+        OutputKeyDistribution = BinaryKeyDistribution,
+    >,
+    Maker: SynthesizesLweBootstrapKey<Precision, BootstrapKey>,
+{
+    type Parameters = LweBootstrapKeyConstantTimeEqualityParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweBootstrapKey<
+            Precision,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+        >>::LweBootstrapKeyProto,
+        <Maker as PrototypesLweBootstrapKey<
+            Precision,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+        >>::LweBootstrapKeyProto,
+    );
+    type SamplePrototypes = ();
+    type PreExecutionContext = (BootstrapKey, BootstrapKey, BootstrapKey);
+    type PostExecutionContext = (BootstrapKey, BootstrapKey, BootstrapKey, Vec<bool>);
+    type Criteria = (Vec<bool>,);
+    type Outcome = Vec<bool>;
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![LweBootstrapKeyConstantTimeEqualityParameters {
+                noise: single_noise(),
+                lwe_dimension: LweDimension(630),
+                glwe_dimension: GlweDimension(1),
+                polynomial_size: PolynomialSize(1024),
+                level: DecompositionLevelCount(3),
+                base_log: DecompositionBaseLog(7),
+            }]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let new_bootstrap_key = |maker: &mut Maker| {
+            let proto_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+                Precision,
+                BinaryKeyDistribution,
+            >>::new_lwe_secret_key(
+                maker, parameters.lwe_dimension
+            );
+            let proto_glwe_secret_key =
+                maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+            maker.new_lwe_bootstrap_key(
+                &proto_lwe_secret_key,
+                &proto_glwe_secret_key,
+                parameters.level,
+                parameters.base_log,
+                parameters.noise,
+            )
+        };
+        (new_bootstrap_key(maker), new_bootstrap_key(maker))
+    }
+
+    fn generate_random_sample_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_bootstrap_key, proto_other_bootstrap_key) = repetition_proto;
+        (
+            maker.synthesize_lwe_bootstrap_key(proto_bootstrap_key),
+            maker.synthesize_lwe_bootstrap_key(proto_bootstrap_key),
+            maker.synthesize_lwe_bootstrap_key(proto_other_bootstrap_key),
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (bootstrap_key, copy, other) = context;
+        let equalities = unsafe {
+            vec![
+                engine.constant_time_eq_unchecked(&bootstrap_key, &copy),
+                engine.constant_time_eq_unchecked(&bootstrap_key, &other),
+            ]
+        };
+        (bootstrap_key, copy, other, equalities)
+    }
+
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (bootstrap_key, copy, other) = context;
+        let equalities = vec![
+            engine.constant_time_eq(&bootstrap_key, &copy).unwrap(),
+            engine.constant_time_eq(&bootstrap_key, &other).unwrap(),
+        ];
+        (bootstrap_key, copy, other, equalities)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (bootstrap_key, copy, other, equalities) = context;
+        maker.destroy_lwe_bootstrap_key(bootstrap_key);
+        maker.destroy_lwe_bootstrap_key(copy);
+        maker.destroy_lwe_bootstrap_key(other);
+        equalities
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (vec![true, false],)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (expected,) = criteria;
+        outputs.iter().all(|equalities| equalities == expected)
+    }
+}
@@ -9,10 +9,13 @@ use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
 };
 use concrete_core::prelude::{LweBootstrapKeyConversionEngine, LweBootstrapKeyEntity};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweBootstrapKeyConversionEngine` trait.
 pub struct LweSecretKeyConversionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweSecretKeyConversionParameters {
     pub lwe_dimension: LweDimension,
@@ -112,6 +115,16 @@ where
         (bsk_out,)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (bsk_in,) = context;
+        let bsk_out = engine.convert_lwe_bootstrap_key(&bsk_in).unwrap();
+        (bsk_out,)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
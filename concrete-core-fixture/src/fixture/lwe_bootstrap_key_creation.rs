@@ -11,10 +11,13 @@ use concrete_commons::parameters::{
 use concrete_core::prelude::{
     GlweSecretKeyEntity, LweBootstrapKeyCreationEngine, LweBootstrapKeyEntity, LweSecretKeyEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweBootstrapKeyCreationEngine` trait.
 pub struct LweSecretKeyCreationFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweSecretKeyCreationParameters {
     pub lwe_dimension: LweDimension,
@@ -126,6 +129,24 @@ where
         (sk,)
     }
 
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (sk_in, sk_out) = context;
+        let sk = engine
+            .create_lwe_bootstrap_key(
+                &sk_in,
+                &sk_out,
+                parameters.base_log,
+                parameters.level,
+                parameters.noise,
+            )
+            .unwrap();
+        (sk,)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -149,4 +170,8 @@ where
     fn verify(_criteria: &Self::Criteria, _outputs: &[Self::Outcome]) -> bool {
         unimplemented!()
     }
+
+    fn checked_outcome_matches(_checked: &Self::Outcome, _unchecked: &Self::Outcome) -> bool {
+        true
+    }
 }
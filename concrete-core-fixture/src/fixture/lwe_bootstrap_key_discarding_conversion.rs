@@ -9,10 +9,13 @@ use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
 };
 use concrete_core::prelude::{LweBootstrapKeyDiscardingConversionEngine, LweBootstrapKeyEntity};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweBootstrapKeyDiscardingConversionEngine` trait.
 pub struct LweSecretKeyDiscardingConversionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweSecretKeyDiscardingConversionParameters {
     pub lwe_dimension: LweDimension,
@@ -135,6 +138,18 @@ where
         (bsk_out,)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (bsk_in, mut bsk_out) = context;
+        engine
+            .discard_convert_lwe_bootstrap_key(&mut bsk_out, &bsk_in)
+            .unwrap();
+        (bsk_out,)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
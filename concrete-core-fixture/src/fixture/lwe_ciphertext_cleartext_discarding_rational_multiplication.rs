@@ -0,0 +1,230 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesCleartext, PrototypesLweCiphertext, PrototypesLweSecretKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{SynthesizesCleartext, SynthesizesLweCiphertext};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{single_noise, SINGLE_LWE_DIMENSION};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::parameters::{DenominatorLog, LweDimension};
+use concrete_core::prelude::{
+    CleartextEntity, LweCiphertextCleartextDiscardingRationalMultiplicationEngine,
+    LweCiphertextEntity,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the
+/// `LweCiphertextCleartextDiscardingRationalMultiplicationEngine` trait.
+pub struct LweCiphertextCleartextDiscardingRationalMultiplicationFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweCiphertextCleartextDiscardingRationalMultiplicationParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub denominator_log: DenominatorLog,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, Engine, InputCiphertext, Cleartext, OutputCiphertext>
+    Fixture<Precision, Engine, (InputCiphertext, Cleartext, OutputCiphertext)>
+    for LweCiphertextCleartextDiscardingRationalMultiplicationFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextCleartextDiscardingRationalMultiplicationEngine<
+        InputCiphertext,
+        Cleartext,
+        OutputCiphertext,
+    >,
+    InputCiphertext: LweCiphertextEntity,
+    Cleartext: CleartextEntity,
+    OutputCiphertext: LweCiphertextEntity<KeyDistribution = InputCiphertext::KeyDistribution>,
+    Maker: SynthesizesCleartext<Precision, Cleartext>
+        + SynthesizesLweCiphertext<Precision, InputCiphertext>
+        + SynthesizesLweCiphertext<Precision, OutputCiphertext>,
+{
+    type Parameters = LweCiphertextCleartextDiscardingRationalMultiplicationParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, InputCiphertext::KeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesCleartext<Precision>>::CleartextProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, InputCiphertext::KeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesLweCiphertext<Precision, InputCiphertext::KeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (InputCiphertext, Cleartext, OutputCiphertext);
+    type PostExecutionContext = (InputCiphertext, Cleartext, OutputCiphertext);
+    type Criteria = (Variance,);
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(vec![1, 5, 10].into_iter().map(|denominator_log| {
+            LweCiphertextCleartextDiscardingRationalMultiplicationParameters {
+                noise: single_noise(),
+                lwe_dimension: SINGLE_LWE_DIMENSION,
+                denominator_log: DenominatorLog(denominator_log),
+            }
+        }))
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        let raw_numerator = Precision::Raw::uniform_zero_centered(1024);
+        let proto_numerator = maker.transform_raw_to_cleartext(&raw_numerator);
+        (proto_secret_key, proto_numerator)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key, _) = repetition_proto;
+        let raw_plaintext = Precision::Raw::uniform();
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_input_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+        );
+        let proto_output_ciphertext =
+            maker.trivially_encrypt_zero_to_lwe_ciphertext(parameters.lwe_dimension);
+        (
+            proto_plaintext,
+            proto_input_ciphertext,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_numerator) = repetition_proto;
+        let (_, proto_input_ciphertext, proto_output_ciphertext) = sample_proto;
+        let synth_input_ciphertext = maker.synthesize_lwe_ciphertext(proto_input_ciphertext);
+        let synth_numerator = maker.synthesize_cleartext(proto_numerator);
+        let synth_output_ciphertext = maker.synthesize_lwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_input_ciphertext,
+            synth_numerator,
+            synth_output_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext, numerator, mut output_ciphertext) = context;
+        unsafe {
+            engine.discard_rational_mul_lwe_ciphertext_cleartext_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &numerator,
+                parameters.denominator_log,
+            )
+        };
+        (input_ciphertext, numerator, output_ciphertext)
+    }
+
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext, numerator, mut output_ciphertext) = context;
+        engine
+            .discard_rational_mul_lwe_ciphertext_cleartext(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &numerator,
+                parameters.denominator_log,
+            )
+            .unwrap();
+        (input_ciphertext, numerator, output_ciphertext)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_ciphertext, numerator, output_ciphertext) = context;
+        let (proto_plaintext, ..) = sample_proto;
+        let (proto_secret_key, proto_numerator) = repetition_proto;
+        let raw_plaintext = maker.transform_plaintext_to_raw(proto_plaintext);
+        let raw_numerator = maker.transform_cleartext_to_raw(proto_numerator);
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(&output_ciphertext);
+        let proto_output_plaintext =
+            maker.decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, &proto_output_ciphertext);
+        maker.destroy_lwe_ciphertext(input_ciphertext);
+        maker.destroy_cleartext(numerator);
+        maker.destroy_lwe_ciphertext(output_ciphertext);
+        // The most significant bits of the output are undefined, and are shifted out of both the
+        // expected and the actual values.
+        let denominator_log = parameters.denominator_log.0;
+        let expected_mean = rational_mul(raw_plaintext, raw_numerator, denominator_log);
+        let actual = maker.transform_plaintext_to_raw(&proto_output_plaintext);
+        (expected_mean << denominator_log, actual << denominator_log)
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let (_, proto_numerator) = repetition_proto;
+        let raw_numerator = maker.transform_cleartext_to_raw(proto_numerator);
+        let predicted_variance: Variance = concrete_npe::estimate_rational_multiplication_noise::<
+            Precision::Raw,
+            Variance,
+            InputCiphertext::KeyDistribution,
+        >(
+            parameters.noise,
+            raw_numerator,
+            parameters.denominator_log,
+            parameters.lwe_dimension,
+        );
+        // The outcomes are shifted by the denominator log, and so is their noise.
+        let shift = f64::powi(4., parameters.denominator_log.0 as i32);
+        (Variance(predicted_variance.get_variance() * shift),)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+}
+
+// Computes the exact product of a raw plaintext with a rational, rounded to the nearest integer.
+fn rational_mul<Raw: RawUnsignedIntegers>(plaintext: Raw, numerator: Raw, shift: usize) -> Raw {
+    let plaintext: u128 = plaintext.cast_into();
+    let numerator: u128 = numerator.cast_into();
+    let product = plaintext * numerator;
+    if shift == 0 {
+        Raw::cast_from(product)
+    } else {
+        Raw::cast_from(((product >> (shift - 1)) + 1) >> 1)
+    }
+}
@@ -1,4 +1,4 @@
-use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::dispersion::Variance;
 use concrete_commons::numeric::UnsignedInteger;
 use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
@@ -11,13 +11,18 @@ use crate::generation::prototyping::{
 };
 use crate::generation::synthesizing::{SynthesizesCleartext, SynthesizesLweCiphertext};
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{single_noise, SINGLE_LWE_DIMENSION};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextCleartextFusingMultiplicationEngine`
 /// trait.
 pub struct LweCiphertextCleartextFusingMultiplicationFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextCleartextFusingMultiplicationParameters {
     pub noise: Variance,
@@ -52,8 +57,8 @@ where
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
         Box::new(
             vec![LweCiphertextCleartextFusingMultiplicationParameters {
-                noise: Variance(LogStandardDev::from_log_standard_dev(-15.).get_variance()),
-                lwe_dimension: LweDimension(600),
+                noise: single_noise(),
+                lwe_dimension: SINGLE_LWE_DIMENSION,
             }]
             .into_iter(),
         )
@@ -108,6 +113,18 @@ where
         (ciphertext, cleartext)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (mut ciphertext, cleartext) = context;
+        engine
+            .fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &cleartext)
+            .unwrap();
+        (ciphertext, cleartext)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -151,4 +168,11 @@ where
         let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
@@ -12,12 +12,17 @@ use crate::generation::synthesizing::{
     SynthesizesLweCiphertext, SynthesizesLweSecretKey, SynthesizesPlaintext,
 };
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{STANDARD_LWE_DIMENSIONS, STANDARD_NOISE};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextDecryptionEngine` trait.
 pub struct LweCiphertextDecryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextDecryptionParameters {
     pub noise: Variance,
@@ -49,35 +54,12 @@ where
     type Outcome = (Precision::Raw, Precision::Raw);
 
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
-        Box::new(
-            vec![
-                LweCiphertextDecryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(100),
-                },
-                LweCiphertextDecryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(300),
-                },
-                LweCiphertextDecryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(600),
-                },
-                LweCiphertextDecryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(1000),
-                },
-                LweCiphertextDecryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(3000),
-                },
-                LweCiphertextDecryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(6000),
-                },
-            ]
-            .into_iter(),
-        )
+        Box::new(STANDARD_LWE_DIMENSIONS.iter().map(|lwe_dimension| {
+            LweCiphertextDecryptionParameters {
+                noise: STANDARD_NOISE,
+                lwe_dimension: *lwe_dimension,
+            }
+        }))
     }
 
     fn generate_random_repetition_prototypes(
@@ -128,6 +110,18 @@ where
         (secret_key, ciphertext, plaintext)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, ciphertext) = context;
+        let plaintext = engine
+            .decrypt_lwe_ciphertext(&secret_key, &ciphertext)
+            .unwrap();
+        (secret_key, ciphertext, plaintext)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -159,4 +153,11 @@ where
         let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
@@ -8,25 +8,25 @@ use crate::generation::synthesizing::{
     SynthesizesGlweCiphertext, SynthesizesLweBootstrapKey, SynthesizesLweCiphertext,
 };
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::standard_bootstrap_presets;
 use crate::raw::statistical_test::assert_delta_std_dev;
-use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
-use concrete_commons::key_kinds::{BinaryKeyKind, GaussianKeyKind, TernaryKeyKind};
-use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::Numeric;
 use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
 };
-use concrete_core::prelude::markers::{
-    BinaryKeyDistribution, GaussianKeyDistribution, KeyDistributionMarker, TernaryKeyDistribution,
-};
 use concrete_core::prelude::{
     GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextDiscardingBootstrapEngine,
     LweCiphertextEntity,
 };
-use std::any::TypeId;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingBootstrapEngine` trait.
 pub struct LweCiphertextDiscardingBootstrapFixture1;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextDiscardingBootstrapParameters1 {
     pub noise: Variance,
@@ -79,35 +79,16 @@ where
     type Outcome = (Precision::Raw, Precision::Raw);
 
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
-        Box::new(
-            vec![
-                LweCiphertextDiscardingBootstrapParameters1 {
-                    noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
-                    lwe_dimension: LweDimension(630),
-                    glwe_dimension: GlweDimension(1),
-                    poly_size: PolynomialSize(512),
-                    decomp_level_count: DecompositionLevelCount(3),
-                    decomp_base_log: DecompositionBaseLog(7),
-                },
-                LweCiphertextDiscardingBootstrapParameters1 {
-                    noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
-                    lwe_dimension: LweDimension(630),
-                    glwe_dimension: GlweDimension(1),
-                    poly_size: PolynomialSize(1024),
-                    decomp_level_count: DecompositionLevelCount(3),
-                    decomp_base_log: DecompositionBaseLog(7),
-                },
-                LweCiphertextDiscardingBootstrapParameters1 {
-                    noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
-                    lwe_dimension: LweDimension(630),
-                    glwe_dimension: GlweDimension(1),
-                    poly_size: PolynomialSize(2048),
-                    decomp_level_count: DecompositionLevelCount(3),
-                    decomp_base_log: DecompositionBaseLog(7),
-                },
-            ]
-            .into_iter(),
-        )
+        Box::new(standard_bootstrap_presets().into_iter().map(|preset| {
+            LweCiphertextDiscardingBootstrapParameters1 {
+                noise: preset.noise,
+                lwe_dimension: preset.lwe_dimension,
+                glwe_dimension: preset.glwe_dimension,
+                poly_size: preset.poly_size,
+                decomp_level_count: preset.decomp_level_count,
+                decomp_base_log: preset.decomp_base_log,
+            }
+        }))
     }
 
     fn generate_random_repetition_prototypes(
@@ -220,6 +201,28 @@ where
         )
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (bootstrap_key, accumulator, mut output_ciphertext, input_ciphertext) = context;
+        engine
+            .discard_bootstrap_lwe_ciphertext(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &accumulator,
+                &bootstrap_key,
+            )
+            .unwrap();
+        (
+            bootstrap_key,
+            accumulator,
+            output_ciphertext,
+            input_ciphertext,
+        )
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -256,15 +259,18 @@ where
         _maker: &mut Maker,
         _repetition_proto: &Self::RepetitionPrototypes,
     ) -> Self::Criteria {
-        let predicted_variance: Variance =
-            fix_estimate_pbs_noise::<Precision::Raw, Variance, OutputCiphertext::KeyDistribution>(
-                parameters.lwe_dimension,
-                parameters.poly_size,
-                parameters.glwe_dimension,
-                parameters.decomp_base_log,
-                parameters.decomp_level_count,
-                parameters.noise,
-            );
+        let predicted_variance: Variance = concrete_npe::estimate_pbs_noise::<
+            Precision::Raw,
+            Variance,
+            OutputCiphertext::KeyDistribution,
+        >(
+            parameters.lwe_dimension,
+            parameters.poly_size,
+            parameters.glwe_dimension,
+            parameters.decomp_base_log,
+            parameters.decomp_level_count,
+            parameters.noise,
+        );
         (predicted_variance,)
     }
 
@@ -272,53 +278,11 @@ where
         let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
         assert_delta_std_dev(&actual, means.as_slice(), criteria.0)
     }
-}
 
-// FIXME:
-// The current NPE does not use the key distribution markers of concrete-core. This function makes
-// the mapping. This function should be removed as soon as the npe uses the types of concrete-core.
-fn fix_estimate_pbs_noise<T, D, K>(
-    lwe_mask_size: LweDimension,
-    poly_size: PolynomialSize,
-    rlwe_mask_size: GlweDimension,
-    base_log: DecompositionBaseLog,
-    level: DecompositionLevelCount,
-    dispersion_bsk: D,
-) -> Variance
-where
-    T: UnsignedInteger,
-    D: DispersionParameter,
-    K: KeyDistributionMarker,
-{
-    let k_type_id = TypeId::of::<K>();
-    if k_type_id == TypeId::of::<BinaryKeyDistribution>() {
-        concrete_npe::estimate_pbs_noise::<T, D, BinaryKeyKind>(
-            lwe_mask_size,
-            poly_size,
-            rlwe_mask_size,
-            base_log,
-            level,
-            dispersion_bsk,
-        )
-    } else if k_type_id == TypeId::of::<TernaryKeyDistribution>() {
-        concrete_npe::estimate_pbs_noise::<T, D, TernaryKeyKind>(
-            lwe_mask_size,
-            poly_size,
-            rlwe_mask_size,
-            base_log,
-            level,
-            dispersion_bsk,
-        )
-    } else if k_type_id == TypeId::of::<GaussianKeyDistribution>() {
-        concrete_npe::estimate_pbs_noise::<T, D, GaussianKeyKind>(
-            lwe_mask_size,
-            poly_size,
-            rlwe_mask_size,
-            base_log,
-            level,
-            dispersion_bsk,
-        )
-    } else {
-        panic!("Unknown key distribution encountered.")
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
     }
 }
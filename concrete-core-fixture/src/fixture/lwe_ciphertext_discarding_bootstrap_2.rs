@@ -17,10 +17,13 @@ use concrete_core::prelude::{
     GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextDiscardingBootstrapEngine,
     LweCiphertextEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingBootstrapEngine` trait.
 pub struct LweCiphertextDiscardingBootstrapFixture2;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextDiscardingBootstrapParameters2 {
     pub noise: Variance,
@@ -74,14 +77,24 @@ where
 
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
         Box::new(
-            vec![LweCiphertextDiscardingBootstrapParameters2 {
-                noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
-                lwe_dimension: LweDimension(630),
-                glwe_dimension: GlweDimension(1),
-                poly_size: PolynomialSize(1024),
-                decomp_level_count: DecompositionLevelCount(3),
-                decomp_base_log: DecompositionBaseLog(7),
-            }]
+            vec![
+                LweCiphertextDiscardingBootstrapParameters2 {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+                    lwe_dimension: LweDimension(630),
+                    glwe_dimension: GlweDimension(1),
+                    poly_size: PolynomialSize(1024),
+                    decomp_level_count: DecompositionLevelCount(3),
+                    decomp_base_log: DecompositionBaseLog(7),
+                },
+                LweCiphertextDiscardingBootstrapParameters2 {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+                    lwe_dimension: LweDimension(630),
+                    glwe_dimension: GlweDimension(2),
+                    poly_size: PolynomialSize(512),
+                    decomp_level_count: DecompositionLevelCount(3),
+                    decomp_base_log: DecompositionBaseLog(7),
+                },
+            ]
             .into_iter(),
         )
     }
@@ -204,6 +217,28 @@ where
         )
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (bootstrap_key, accumulator, mut output_ciphertext, input_ciphertext) = context;
+        engine
+            .discard_bootstrap_lwe_ciphertext(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &accumulator,
+                &bootstrap_key,
+            )
+            .unwrap();
+        (
+            bootstrap_key,
+            accumulator,
+            output_ciphertext,
+            input_ciphertext,
+        )
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -6,6 +6,8 @@ use crate::generation::synthesizing::{
     SynthesizesLweCiphertext, SynthesizesLweSecretKey, SynthesizesPlaintext,
 };
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{STANDARD_LWE_DIMENSIONS, STANDARD_NOISE};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
@@ -14,10 +16,13 @@ use concrete_core::prelude::{
     LweCiphertextDiscardingDecryptionEngine, LweCiphertextEntity, LweSecretKeyEntity,
     PlaintextEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingDecryptionEngine` trait.
 pub struct LweCiphertextDiscardingDecryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextDiscardingDecryptionParameters {
     pub noise: Variance,
@@ -52,35 +57,12 @@ where
     type Outcome = (Precision::Raw, Precision::Raw);
 
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
-        Box::new(
-            vec![
-                LweCiphertextDiscardingDecryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(100),
-                },
-                LweCiphertextDiscardingDecryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(300),
-                },
-                LweCiphertextDiscardingDecryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(600),
-                },
-                LweCiphertextDiscardingDecryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(1000),
-                },
-                LweCiphertextDiscardingDecryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(3000),
-                },
-                LweCiphertextDiscardingDecryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(6000),
-                },
-            ]
-            .into_iter(),
-        )
+        Box::new(STANDARD_LWE_DIMENSIONS.iter().map(|lwe_dimension| {
+            LweCiphertextDiscardingDecryptionParameters {
+                noise: STANDARD_NOISE,
+                lwe_dimension: *lwe_dimension,
+            }
+        }))
     }
 
     fn generate_random_repetition_prototypes(
@@ -136,6 +118,18 @@ where
         (ciphertext, secret_key, plaintext)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (ciphertext, secret_key, mut plaintext) = context;
+        engine
+            .discard_decrypt_lwe_ciphertext(&secret_key, &mut plaintext, &ciphertext)
+            .unwrap();
+        (ciphertext, secret_key, plaintext)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -167,4 +161,11 @@ where
         let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
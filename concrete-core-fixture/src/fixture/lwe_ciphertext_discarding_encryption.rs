@@ -6,6 +6,8 @@ use crate::generation::synthesizing::{
     SynthesizesLweCiphertext, SynthesizesLweSecretKey, SynthesizesPlaintext,
 };
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{STANDARD_LWE_DIMENSIONS, STANDARD_NOISE};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
@@ -14,10 +16,13 @@ use concrete_core::prelude::{
     LweCiphertextDiscardingEncryptionEngine, LweCiphertextEntity, LweSecretKeyEntity,
     PlaintextEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingEncryptionEngine` trait.
 pub struct LweCiphertextDiscardingEncryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextDiscardingEncryptionParameters {
     pub noise: Variance,
@@ -52,35 +57,12 @@ where
     type Outcome = (Precision::Raw, Precision::Raw);
 
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
-        Box::new(
-            vec![
-                LweCiphertextDiscardingEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(100),
-                },
-                LweCiphertextDiscardingEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(300),
-                },
-                LweCiphertextDiscardingEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(600),
-                },
-                LweCiphertextDiscardingEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(1000),
-                },
-                LweCiphertextDiscardingEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(3000),
-                },
-                LweCiphertextDiscardingEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(6000),
-                },
-            ]
-            .into_iter(),
-        )
+        Box::new(STANDARD_LWE_DIMENSIONS.iter().map(|lwe_dimension| {
+            LweCiphertextDiscardingEncryptionParameters {
+                noise: STANDARD_NOISE,
+                lwe_dimension: *lwe_dimension,
+            }
+        }))
     }
 
     fn generate_random_repetition_prototypes(
@@ -134,6 +116,23 @@ where
         (plaintext, secret_key, ciphertext)
     }
 
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (plaintext, secret_key, mut ciphertext) = context;
+        engine
+            .discard_encrypt_lwe_ciphertext(
+                &secret_key,
+                &mut ciphertext,
+                &plaintext,
+                parameters.noise,
+            )
+            .unwrap();
+        (plaintext, secret_key, ciphertext)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -168,4 +167,15 @@ where
         let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+
+    fn checked_outcome_matches(_checked: &Self::Outcome, _unchecked: &Self::Outcome) -> bool {
+        true
+    }
 }
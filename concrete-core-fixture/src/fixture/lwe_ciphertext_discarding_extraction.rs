@@ -5,6 +5,7 @@ use crate::generation::prototyping::{
 };
 use crate::generation::synthesizing::{SynthesizesGlweCiphertext, SynthesizesLweCiphertext};
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
@@ -12,10 +13,13 @@ use concrete_commons::parameters::{GlweDimension, LweDimension, MonomialIndex, P
 use concrete_core::prelude::{
     GlweCiphertextEntity, LweCiphertextDiscardingExtractionEngine, LweCiphertextEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingExtractionEngine` trait.
 pub struct LweCiphertextDiscardingExtractionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextDiscardingExtractionParameters {
     pub noise: Variance,
@@ -52,12 +56,50 @@ where
 
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
         Box::new(
-            vec![LweCiphertextDiscardingExtractionParameters {
-                noise: Variance(0.00000001),
-                glwe_dimension: GlweDimension(200),
-                poly_size: PolynomialSize(256),
-                nth: MonomialIndex(0),
-            }]
+            vec![
+                LweCiphertextDiscardingExtractionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(1),
+                    poly_size: PolynomialSize(2048),
+                    nth: MonomialIndex(0),
+                },
+                LweCiphertextDiscardingExtractionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(2),
+                    poly_size: PolynomialSize(1024),
+                    nth: MonomialIndex(1023),
+                },
+                LweCiphertextDiscardingExtractionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(3),
+                    poly_size: PolynomialSize(1024),
+                    nth: MonomialIndex(517),
+                },
+                LweCiphertextDiscardingExtractionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(4),
+                    poly_size: PolynomialSize(512),
+                    nth: MonomialIndex(511),
+                },
+                LweCiphertextDiscardingExtractionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(2),
+                    poly_size: PolynomialSize(256),
+                    nth: MonomialIndex(1),
+                },
+                LweCiphertextDiscardingExtractionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(2),
+                    poly_size: PolynomialSize(256),
+                    nth: MonomialIndex(137),
+                },
+                LweCiphertextDiscardingExtractionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(2),
+                    poly_size: PolynomialSize(256),
+                    nth: MonomialIndex(255),
+                },
+            ]
             .into_iter(),
         )
     }
@@ -123,8 +165,20 @@ where
         (glwe_ciphertext, lwe_ciphertext)
     }
 
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (glwe_ciphertext, mut lwe_ciphertext) = context;
+        engine
+            .discard_extract_lwe_ciphertext(&mut lwe_ciphertext, &glwe_ciphertext, parameters.nth)
+            .unwrap();
+        (glwe_ciphertext, lwe_ciphertext)
+    }
+
     fn process_context(
-        _parameters: &Self::Parameters,
+        parameters: &Self::Parameters,
         maker: &mut Maker,
         repetition_proto: &Self::RepetitionPrototypes,
         sample_proto: &Self::SamplePrototypes,
@@ -141,7 +195,7 @@ where
         maker.destroy_lwe_ciphertext(lwe_ciphertext);
         maker.destroy_glwe_ciphertext(glwe_ciphertext);
         (
-            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector)[_parameters.nth.0],
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector)[parameters.nth.0],
             maker.transform_plaintext_to_raw(&proto_output_plaintext),
         )
     }
@@ -158,4 +212,11 @@ where
         let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
@@ -4,23 +4,23 @@ use crate::generation::prototyping::{
 };
 use crate::generation::synthesizing::{SynthesizesLweCiphertext, SynthesizesLweKeyswitchKey};
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{reduced_precision_keyswitch_preset, REDUCED_PRECISION_BITS};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
-use concrete_commons::key_kinds::{BinaryKeyKind, GaussianKeyKind, TernaryKeyKind};
-use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::numeric::Numeric;
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
-use concrete_core::prelude::markers::{
-    BinaryKeyDistribution, GaussianKeyDistribution, KeyDistributionMarker, TernaryKeyDistribution,
-};
 use concrete_core::prelude::{
     LweCiphertextDiscardingKeyswitchEngine, LweCiphertextEntity, LweKeyswitchKeyEntity,
 };
-use std::any::TypeId;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingKeyswitchEngine` trait.
 pub struct LweCiphertextDiscardingKeyswitchFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextDiscardingKeyswitchParameters {
     pub n_bit_msg: usize,
@@ -65,6 +65,21 @@ where
     type Outcome = (Precision::Raw, Precision::Raw);
 
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        if Precision::Raw::BITS <= REDUCED_PRECISION_BITS {
+            let preset = reduced_precision_keyswitch_preset();
+            return Box::new(
+                vec![LweCiphertextDiscardingKeyswitchParameters {
+                    n_bit_msg: 4,
+                    input_noise: preset.input_noise,
+                    ksk_noise: preset.ksk_noise,
+                    input_lwe_dimension: preset.input_lwe_dimension,
+                    output_lwe_dimension: preset.output_lwe_dimension,
+                    decomp_level_count: preset.decomp_level_count,
+                    decomp_base_log: preset.decomp_base_log,
+                }]
+                .into_iter(),
+            );
+        }
         Box::new(
             vec![LweCiphertextDiscardingKeyswitchParameters {
                 n_bit_msg: 8,
@@ -173,6 +188,22 @@ where
         (output_ciphertext, input_ciphertext, keyswitch_key)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (mut output_ciphertext, input_ciphertext, keyswitch_key) = context;
+        engine
+            .discard_keyswitch_lwe_ciphertext(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &keyswitch_key,
+            )
+            .unwrap();
+        (output_ciphertext, input_ciphertext, keyswitch_key)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -207,7 +238,7 @@ where
         _repetition_proto: &Self::RepetitionPrototypes,
     ) -> Self::Criteria {
         let predicted_variance: Variance =
-            fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+            concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
                 Precision::Raw,
                 _,
                 _,
@@ -226,65 +257,11 @@ where
         let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
-}
 
-// FIXME:
-// The current NPE does not use the key distribution markers of concrete-core. This function makes
-// the mapping. This function should be removed as soon as the npe uses the types of concrete-core.
-pub(crate) fn fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms<T, D1, D2, K>(
-    lwe_mask_size: LweDimension,
-    dispersion_lwe: D1,
-    dispersion_ksk: D2,
-    base_log: DecompositionBaseLog,
-    level: DecompositionLevelCount,
-) -> Variance
-where
-    T: UnsignedInteger,
-    D1: DispersionParameter,
-    D2: DispersionParameter,
-    K: KeyDistributionMarker,
-{
-    let k_type_id = TypeId::of::<K>();
-    if k_type_id == TypeId::of::<BinaryKeyDistribution>() {
-        concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
-            T,
-            D1,
-            D2,
-            BinaryKeyKind,
-        >(
-            lwe_mask_size,
-            dispersion_lwe,
-            dispersion_ksk,
-            base_log,
-            level,
-        )
-    } else if k_type_id == TypeId::of::<TernaryKeyDistribution>() {
-        concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
-            T,
-            D1,
-            D2,
-            TernaryKeyKind,
-        >(
-            lwe_mask_size,
-            dispersion_lwe,
-            dispersion_ksk,
-            base_log,
-            level,
-        )
-    } else if k_type_id == TypeId::of::<GaussianKeyDistribution>() {
-        concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
-            T,
-            D1,
-            D2,
-            GaussianKeyKind,
-        >(
-            lwe_mask_size,
-            dispersion_lwe,
-            dispersion_ksk,
-            base_log,
-            level,
-        )
-    } else {
-        panic!("Unknown key distribution encountered.")
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
     }
 }
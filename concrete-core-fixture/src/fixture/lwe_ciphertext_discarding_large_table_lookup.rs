@@ -0,0 +1,351 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGgswCiphertext, PrototypesGlweSecretKey, PrototypesLweBootstrapKey,
+    PrototypesLweCiphertext, PrototypesLweSecretKey, PrototypesPlaintext,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGgswCiphertext, SynthesizesLweBootstrapKey, SynthesizesLweCiphertext,
+    SynthesizesPlaintextVector,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::presets::standard_bootstrap_presets;
+use crate::raw::generation::{decode_message, encode_message, RawUnsignedIntegers};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::{CastFrom, CastInto, Numeric};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, MessageBitCount,
+    PlaintextCount, PolynomialSize,
+};
+use concrete_core::prelude::{
+    GgswCiphertextEntity, LargeTableLookupStructure, LweBootstrapKeyEntity,
+    LweCiphertextDiscardingLargeTableLookupEngine, LweCiphertextEntity, PlaintextVectorEntity,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `LweCiphertextDiscardingLargeTableLookupEngine`
+/// trait.
+pub struct LweCiphertextDiscardingLargeTableLookupFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweCiphertextDiscardingLargeTableLookupParameters {
+    pub table_size: PlaintextCount,
+    pub message_bits: MessageBitCount,
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub poly_size: PolynomialSize,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub decomp_base_log: DecompositionBaseLog,
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        Engine,
+        BootstrapKey,
+        GgswCiphertext,
+        PlaintextVector,
+        InputCiphertext,
+        OutputCiphertext,
+    >
+    Fixture<
+        Precision,
+        Engine,
+        (
+            BootstrapKey,
+            GgswCiphertext,
+            PlaintextVector,
+            InputCiphertext,
+            OutputCiphertext,
+        ),
+    > for LweCiphertextDiscardingLargeTableLookupFixture
+where
+    Precision: IntegerPrecision,
+    Precision::Raw: CastFrom<usize> + CastInto<usize>,
+    Engine: LweCiphertextDiscardingLargeTableLookupEngine<
+        BootstrapKey,
+        GgswCiphertext,
+        PlaintextVector,
+        InputCiphertext,
+        OutputCiphertext,
+    >,
+    BootstrapKey: LweBootstrapKeyEntity,
+    GgswCiphertext: GgswCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    PlaintextVector: PlaintextVectorEntity,
+    InputCiphertext: LweCiphertextEntity<KeyDistribution = BootstrapKey::InputKeyDistribution>,
+    OutputCiphertext: LweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    Maker: SynthesizesLweBootstrapKey<Precision, BootstrapKey>
+        + SynthesizesGgswCiphertext<Precision, GgswCiphertext>
+        + SynthesizesPlaintextVector<Precision, PlaintextVector>
+        + SynthesizesLweCiphertext<Precision, InputCiphertext>
+        + SynthesizesLweCiphertext<Precision, OutputCiphertext>,
+{
+    type Parameters = LweCiphertextDiscardingLargeTableLookupParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, BootstrapKey::InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, BootstrapKey::OutputKeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesLweSecretKey<Precision, BootstrapKey::OutputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLweBootstrapKey<Precision, BootstrapKey::InputKeyDistribution, BootstrapKey::OutputKeyDistribution>>::LweBootstrapKeyProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+    );
+    type SamplePrototypes = (
+        usize,
+        <Maker as PrototypesLweCiphertext<Precision, BootstrapKey::InputKeyDistribution>>::LweCiphertextProto,
+        Vec<<Maker as PrototypesGgswCiphertext<Precision, BootstrapKey::OutputKeyDistribution>>::GgswCiphertextProto>,
+        <Maker as PrototypesLweCiphertext<Precision, BootstrapKey::OutputKeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (
+        BootstrapKey,
+        Vec<GgswCiphertext>,
+        PlaintextVector,
+        InputCiphertext,
+        OutputCiphertext,
+    );
+    type PostExecutionContext = (
+        BootstrapKey,
+        Vec<GgswCiphertext>,
+        PlaintextVector,
+        InputCiphertext,
+        OutputCiphertext,
+    );
+    type Criteria = (MessageBitCount,);
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        // The table holds sixteen times as many entries as an accumulator can.
+        Box::new(
+            standard_bootstrap_presets()
+                .into_iter()
+                .filter(|preset| preset.poly_size == PolynomialSize(1024))
+                .map(|preset| LweCiphertextDiscardingLargeTableLookupParameters {
+                    table_size: PlaintextCount(1 << 8),
+                    message_bits: MessageBitCount(2),
+                    noise: preset.noise,
+                    lwe_dimension: preset.lwe_dimension,
+                    glwe_dimension: preset.glwe_dimension,
+                    poly_size: preset.poly_size,
+                    decomp_level_count: preset.decomp_level_count,
+                    decomp_base_log: preset.decomp_base_log,
+                }),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            BootstrapKey::InputKeyDistribution,
+        >>::new_lwe_secret_key(maker, parameters.lwe_dimension);
+        let proto_glwe_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            BootstrapKey::OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker, parameters.glwe_dimension, parameters.poly_size
+        );
+        let proto_bootstrap_key = maker.new_lwe_bootstrap_key(
+            &proto_lwe_secret_key,
+            &proto_glwe_secret_key,
+            parameters.decomp_level_count,
+            parameters.decomp_base_log,
+            parameters.noise,
+        );
+        let proto_big_lwe_secret_key =
+            maker.transmute_glwe_secret_key_to_lwe_secret_key(&proto_glwe_secret_key);
+        let raw_table: Vec<Precision::Raw> = Precision::Raw::uniform_between_vec(
+            0..1 << parameters.message_bits.0,
+            parameters.table_size.0,
+        )
+        .into_iter()
+        .map(|message| encode_message(message, parameters.message_bits.0, 1))
+        .collect();
+        let proto_table = maker.transform_raw_vec_to_plaintext_vector(&raw_table);
+        (
+            proto_lwe_secret_key,
+            proto_glwe_secret_key,
+            proto_big_lwe_secret_key,
+            proto_bootstrap_key,
+            proto_table,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_lwe_secret_key, proto_glwe_secret_key, ..) = repetition_proto;
+        let structure = LargeTableLookupStructure::new(parameters.table_size, parameters.poly_size);
+        let index: usize = Precision::Raw::uniform_between(0..parameters.table_size.0).cast_into();
+        let (low, high) = structure.split_index(index);
+        let raw_plaintext = encode_message(Precision::Raw::cast_from(low), structure.low_bits, 1);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_input_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            BootstrapKey::InputKeyDistribution,
+        >>::encrypt_plaintext_to_lwe_ciphertext(
+            maker,
+            proto_lwe_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+        );
+        let proto_selectors = high
+            .into_iter()
+            .map(|bit| {
+                let raw_bit = if bit {
+                    Precision::Raw::ONE
+                } else {
+                    Precision::Raw::ZERO
+                };
+                let proto_bit = maker.transform_raw_to_plaintext(&raw_bit);
+                maker.encrypt_plaintext_to_ggsw_ciphertext(
+                    proto_glwe_secret_key,
+                    &proto_bit,
+                    parameters.noise,
+                    parameters.decomp_level_count,
+                    parameters.decomp_base_log,
+                )
+            })
+            .collect();
+        let proto_output_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            BootstrapKey::OutputKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            maker,
+            LweDimension(parameters.glwe_dimension.0 * parameters.poly_size.0),
+        );
+        (
+            index,
+            proto_input_ciphertext,
+            proto_selectors,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, _, _, proto_bootstrap_key, proto_table) = repetition_proto;
+        let (_, proto_input_ciphertext, proto_selectors, proto_output_ciphertext) = sample_proto;
+        let synth_bootstrap_key = maker.synthesize_lwe_bootstrap_key(proto_bootstrap_key);
+        let synth_selectors = proto_selectors
+            .iter()
+            .map(|proto_selector| maker.synthesize_ggsw_ciphertext(proto_selector))
+            .collect();
+        let synth_table = maker.synthesize_plaintext_vector(proto_table);
+        let synth_input_ciphertext = maker.synthesize_lwe_ciphertext(proto_input_ciphertext);
+        let synth_output_ciphertext = maker.synthesize_lwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_bootstrap_key,
+            synth_selectors,
+            synth_table,
+            synth_input_ciphertext,
+            synth_output_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (bootstrap_key, selectors, table, input_ciphertext, mut output_ciphertext) = context;
+        unsafe {
+            engine.discard_lookup_large_table_lwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                selectors.as_slice(),
+                &table,
+                &bootstrap_key,
+            )
+        };
+        (
+            bootstrap_key,
+            selectors,
+            table,
+            input_ciphertext,
+            output_ciphertext,
+        )
+    }
+
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (bootstrap_key, selectors, table, input_ciphertext, mut output_ciphertext) = context;
+        engine
+            .discard_lookup_large_table_lwe_ciphertext(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                selectors.as_slice(),
+                &table,
+                &bootstrap_key,
+            )
+            .unwrap();
+        (
+            bootstrap_key,
+            selectors,
+            table,
+            input_ciphertext,
+            output_ciphertext,
+        )
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (bootstrap_key, selectors, table, input_ciphertext, output_ciphertext) = context;
+        let (_, _, proto_big_lwe_secret_key, _, proto_table) = repetition_proto;
+        let (index, ..) = sample_proto;
+        let raw_table = maker.transform_plaintext_vector_to_raw_vec(proto_table);
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(&output_ciphertext);
+        let proto_output_plaintext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            BootstrapKey::OutputKeyDistribution,
+        >>::decrypt_lwe_ciphertext_to_plaintext(
+            maker,
+            proto_big_lwe_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_lwe_bootstrap_key(bootstrap_key);
+        for selector in selectors {
+            maker.destroy_ggsw_ciphertext(selector);
+        }
+        maker.destroy_plaintext_vector(table);
+        maker.destroy_lwe_ciphertext(input_ciphertext);
+        maker.destroy_lwe_ciphertext(output_ciphertext);
+        (
+            raw_table[*index],
+            maker.transform_plaintext_to_raw(&proto_output_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (parameters.message_bits,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        // The result is exact once decoded, hence no statistical test is needed.
+        let (message_bits,) = criteria;
+        let decode = |raw| decode_message::<Precision::Raw>(raw, message_bits.0, 1);
+        outputs
+            .iter()
+            .all(|(expected, actual)| decode(*expected) == decode(*actual))
+    }
+}
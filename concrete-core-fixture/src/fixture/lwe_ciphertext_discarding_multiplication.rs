@@ -0,0 +1,434 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweRelinearizationKey, PrototypesGlweSecretKey, PrototypesLweCiphertext,
+    PrototypesLweSecretKey, PrototypesPackingKeyswitchKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweRelinearizationKey, SynthesizesLweCiphertext, SynthesizesPackingKeyswitchKey,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::{decode_message, encode_message, RawUnsignedIntegers};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::Numeric;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, MessageBitCount,
+    PolynomialSize, ScalingFactor,
+};
+use concrete_core::prelude::{
+    GlweRelinearizationKeyEntity, LweCiphertextDiscardingMultiplicationEngine, LweCiphertextEntity,
+    PackingKeyswitchKeyEntity,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `LweCiphertextDiscardingMultiplicationEngine` trait.
+pub struct LweCiphertextDiscardingMultiplicationFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweCiphertextDiscardingMultiplicationParameters {
+    /// The number of bits of the messages of the inputs.
+    pub input_message_bits: MessageBitCount,
+    /// The number of bits of the message space, which must contain the products.
+    pub message_bits: MessageBitCount,
+    pub padding_bits: usize,
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub poly_size: PolynomialSize,
+    pub pksk_decomp_level_count: DecompositionLevelCount,
+    pub pksk_decomp_base_log: DecompositionBaseLog,
+    pub rlk_decomp_level_count: DecompositionLevelCount,
+    pub rlk_decomp_base_log: DecompositionBaseLog,
+}
+
+impl LweCiphertextDiscardingMultiplicationParameters {
+    fn scale<Raw: Numeric>(&self) -> ScalingFactor {
+        ScalingFactor::from_log2(Raw::BITS - self.message_bits.0 - self.padding_bits)
+    }
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        Engine,
+        PackingKeyswitchKey,
+        RelinearizationKey,
+        InputCiphertext1,
+        InputCiphertext2,
+        OutputCiphertext,
+    >
+    Fixture<
+        Precision,
+        Engine,
+        (
+            PackingKeyswitchKey,
+            RelinearizationKey,
+            InputCiphertext1,
+            InputCiphertext2,
+            OutputCiphertext,
+        ),
+    > for LweCiphertextDiscardingMultiplicationFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextDiscardingMultiplicationEngine<
+        PackingKeyswitchKey,
+        RelinearizationKey,
+        InputCiphertext1,
+        InputCiphertext2,
+        OutputCiphertext,
+    >,
+    PackingKeyswitchKey: PackingKeyswitchKeyEntity,
+    RelinearizationKey:
+        GlweRelinearizationKeyEntity<KeyDistribution = PackingKeyswitchKey::OutputKeyDistribution>,
+    InputCiphertext1:
+        LweCiphertextEntity<KeyDistribution = PackingKeyswitchKey::InputKeyDistribution>,
+    InputCiphertext2:
+        LweCiphertextEntity<KeyDistribution = PackingKeyswitchKey::InputKeyDistribution>,
+    OutputCiphertext: LweCiphertextEntity<KeyDistribution = RelinearizationKey::KeyDistribution>,
+    Maker: SynthesizesPackingKeyswitchKey<Precision, PackingKeyswitchKey>
+        + SynthesizesGlweRelinearizationKey<Precision, RelinearizationKey>
+        + SynthesizesLweCiphertext<Precision, InputCiphertext1>
+        + SynthesizesLweCiphertext<Precision, InputCiphertext2>
+        + SynthesizesLweCiphertext<Precision, OutputCiphertext>,
+{
+    type Parameters = LweCiphertextDiscardingMultiplicationParameters;
+    type RepetitionPrototypes =
+        (
+            <Maker as PrototypesLweSecretKey<
+                Precision,
+                PackingKeyswitchKey::InputKeyDistribution,
+            >>::LweSecretKeyProto,
+            <Maker as PrototypesLweSecretKey<
+                Precision,
+                PackingKeyswitchKey::OutputKeyDistribution,
+            >>::LweSecretKeyProto,
+            <Maker as PrototypesPackingKeyswitchKey<
+                Precision,
+                PackingKeyswitchKey::InputKeyDistribution,
+                PackingKeyswitchKey::OutputKeyDistribution,
+            >>::PackingKeyswitchKeyProto,
+            <Maker as PrototypesGlweRelinearizationKey<
+                Precision,
+                PackingKeyswitchKey::OutputKeyDistribution,
+            >>::GlweRelinearizationKeyProto,
+        );
+    type SamplePrototypes =
+        (
+            <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+            <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+            <Maker as PrototypesLweCiphertext<
+                Precision,
+                PackingKeyswitchKey::InputKeyDistribution,
+            >>::LweCiphertextProto,
+            <Maker as PrototypesLweCiphertext<
+                Precision,
+                PackingKeyswitchKey::InputKeyDistribution,
+            >>::LweCiphertextProto,
+            <Maker as PrototypesLweCiphertext<
+                Precision,
+                PackingKeyswitchKey::OutputKeyDistribution,
+            >>::LweCiphertextProto,
+        );
+    type PreExecutionContext = (
+        PackingKeyswitchKey,
+        RelinearizationKey,
+        InputCiphertext1,
+        InputCiphertext2,
+        OutputCiphertext,
+    );
+    type PostExecutionContext = (
+        PackingKeyswitchKey,
+        RelinearizationKey,
+        InputCiphertext1,
+        InputCiphertext2,
+        OutputCiphertext,
+    );
+    type Criteria = (MessageBitCount, usize);
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        // The product is computed on the whole precision before the rescaling, such that the
+        // encoding and the noise of the keys depend on the precision.
+        let parameters = if Precision::Raw::BITS <= 32 {
+            vec![
+                LweCiphertextDiscardingMultiplicationParameters {
+                    input_message_bits: MessageBitCount(2),
+                    message_bits: MessageBitCount(4),
+                    padding_bits: 0,
+                    noise: Variance(2_f64.powf(-60.)),
+                    lwe_dimension: LweDimension(4),
+                    glwe_dimension: GlweDimension(1),
+                    poly_size: PolynomialSize(256),
+                    pksk_decomp_level_count: DecompositionLevelCount(3),
+                    pksk_decomp_base_log: DecompositionBaseLog(8),
+                    rlk_decomp_level_count: DecompositionLevelCount(3),
+                    rlk_decomp_base_log: DecompositionBaseLog(7),
+                },
+                LweCiphertextDiscardingMultiplicationParameters {
+                    input_message_bits: MessageBitCount(2),
+                    message_bits: MessageBitCount(4),
+                    padding_bits: 0,
+                    noise: Variance(2_f64.powf(-60.)),
+                    lwe_dimension: LweDimension(4),
+                    glwe_dimension: GlweDimension(2),
+                    poly_size: PolynomialSize(128),
+                    pksk_decomp_level_count: DecompositionLevelCount(3),
+                    pksk_decomp_base_log: DecompositionBaseLog(8),
+                    rlk_decomp_level_count: DecompositionLevelCount(3),
+                    rlk_decomp_base_log: DecompositionBaseLog(7),
+                },
+            ]
+        } else {
+            vec![
+                LweCiphertextDiscardingMultiplicationParameters {
+                    input_message_bits: MessageBitCount(2),
+                    message_bits: MessageBitCount(4),
+                    padding_bits: 2,
+                    noise: Variance(2_f64.powf(-100.)),
+                    lwe_dimension: LweDimension(10),
+                    glwe_dimension: GlweDimension(1),
+                    poly_size: PolynomialSize(256),
+                    pksk_decomp_level_count: DecompositionLevelCount(4),
+                    pksk_decomp_base_log: DecompositionBaseLog(10),
+                    rlk_decomp_level_count: DecompositionLevelCount(4),
+                    rlk_decomp_base_log: DecompositionBaseLog(12),
+                },
+                LweCiphertextDiscardingMultiplicationParameters {
+                    input_message_bits: MessageBitCount(2),
+                    message_bits: MessageBitCount(4),
+                    padding_bits: 2,
+                    noise: Variance(2_f64.powf(-100.)),
+                    lwe_dimension: LweDimension(10),
+                    glwe_dimension: GlweDimension(2),
+                    poly_size: PolynomialSize(128),
+                    pksk_decomp_level_count: DecompositionLevelCount(4),
+                    pksk_decomp_base_log: DecompositionBaseLog(10),
+                    rlk_decomp_level_count: DecompositionLevelCount(4),
+                    rlk_decomp_base_log: DecompositionBaseLog(12),
+                },
+            ]
+        };
+        Box::new(parameters.into_iter())
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_input_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            PackingKeyswitchKey::InputKeyDistribution,
+        >>::new_lwe_secret_key(maker, parameters.lwe_dimension);
+        let proto_glwe_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            PackingKeyswitchKey::OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker, parameters.glwe_dimension, parameters.poly_size
+        );
+        let proto_packing_keyswitch_key = maker.new_packing_keyswitch_key(
+            &proto_input_secret_key,
+            &proto_glwe_secret_key,
+            parameters.pksk_decomp_level_count,
+            parameters.pksk_decomp_base_log,
+            parameters.noise,
+        );
+        let proto_relinearization_key = maker.new_glwe_relinearization_key(
+            &proto_glwe_secret_key,
+            parameters.rlk_decomp_level_count,
+            parameters.rlk_decomp_base_log,
+            parameters.noise,
+        );
+        // The output is encrypted under the LWE key extracted from the GLWE key.
+        let proto_output_secret_key =
+            maker.transmute_glwe_secret_key_to_lwe_secret_key(&proto_glwe_secret_key);
+        (
+            proto_input_secret_key,
+            proto_output_secret_key,
+            proto_packing_keyswitch_key,
+            proto_relinearization_key,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_input_secret_key, ..) = repetition_proto;
+        // The messages span the whole input message range, extremes included.
+        let encrypt = |maker: &mut Maker| {
+            let message = Precision::Raw::uniform_between(0..1 << parameters.input_message_bits.0);
+            let raw_plaintext =
+                encode_message(message, parameters.message_bits.0, parameters.padding_bits);
+            let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+            let proto_ciphertext = <Maker as PrototypesLweCiphertext<
+                Precision,
+                PackingKeyswitchKey::InputKeyDistribution,
+            >>::encrypt_plaintext_to_lwe_ciphertext(
+                maker,
+                proto_input_secret_key,
+                &proto_plaintext,
+                parameters.noise,
+            );
+            (proto_plaintext, proto_ciphertext)
+        };
+        let (proto_plaintext1, proto_input_ciphertext1) = encrypt(maker);
+        let (proto_plaintext2, proto_input_ciphertext2) = encrypt(maker);
+        let proto_output_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            PackingKeyswitchKey::OutputKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            maker,
+            LweDimension(parameters.glwe_dimension.0 * parameters.poly_size.0),
+        );
+        (
+            proto_plaintext1,
+            proto_plaintext2,
+            proto_input_ciphertext1,
+            proto_input_ciphertext2,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, _, proto_packing_keyswitch_key, proto_relinearization_key) = repetition_proto;
+        let (_, _, proto_input_ciphertext1, proto_input_ciphertext2, proto_output_ciphertext) =
+            sample_proto;
+        (
+            maker.synthesize_packing_keyswitch_key(proto_packing_keyswitch_key),
+            maker.synthesize_glwe_relinearization_key(proto_relinearization_key),
+            maker.synthesize_lwe_ciphertext(proto_input_ciphertext1),
+            maker.synthesize_lwe_ciphertext(proto_input_ciphertext2),
+            maker.synthesize_lwe_ciphertext(proto_output_ciphertext),
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (
+            packing_keyswitch_key,
+            relinearization_key,
+            input_ciphertext1,
+            input_ciphertext2,
+            mut output_ciphertext,
+        ) = context;
+        unsafe {
+            engine.discard_mul_lwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext1,
+                &input_ciphertext2,
+                &packing_keyswitch_key,
+                &relinearization_key,
+                parameters.scale::<Precision::Raw>(),
+            )
+        };
+        (
+            packing_keyswitch_key,
+            relinearization_key,
+            input_ciphertext1,
+            input_ciphertext2,
+            output_ciphertext,
+        )
+    }
+
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (
+            packing_keyswitch_key,
+            relinearization_key,
+            input_ciphertext1,
+            input_ciphertext2,
+            mut output_ciphertext,
+        ) = context;
+        engine
+            .discard_mul_lwe_ciphertext(
+                &mut output_ciphertext,
+                &input_ciphertext1,
+                &input_ciphertext2,
+                &packing_keyswitch_key,
+                &relinearization_key,
+                parameters.scale::<Precision::Raw>(),
+            )
+            .unwrap();
+        (
+            packing_keyswitch_key,
+            relinearization_key,
+            input_ciphertext1,
+            input_ciphertext2,
+            output_ciphertext,
+        )
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (
+            packing_keyswitch_key,
+            relinearization_key,
+            input_ciphertext1,
+            input_ciphertext2,
+            output_ciphertext,
+        ) = context;
+        let (_, proto_output_secret_key, ..) = repetition_proto;
+        let (proto_plaintext1, proto_plaintext2, ..) = sample_proto;
+        let decode = |raw| decode_message(raw, parameters.message_bits.0, parameters.padding_bits);
+        let message1 = decode(maker.transform_plaintext_to_raw(proto_plaintext1));
+        let message2 = decode(maker.transform_plaintext_to_raw(proto_plaintext2));
+        let predicted_output = encode_message(
+            message1 * message2,
+            parameters.message_bits.0,
+            parameters.padding_bits,
+        );
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(&output_ciphertext);
+        let proto_output_plaintext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            PackingKeyswitchKey::OutputKeyDistribution,
+        >>::decrypt_lwe_ciphertext_to_plaintext(
+            maker,
+            proto_output_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_packing_keyswitch_key(packing_keyswitch_key);
+        maker.destroy_glwe_relinearization_key(relinearization_key);
+        maker.destroy_lwe_ciphertext(input_ciphertext1);
+        maker.destroy_lwe_ciphertext(input_ciphertext2);
+        maker.destroy_lwe_ciphertext(output_ciphertext);
+        (
+            predicted_output,
+            maker.transform_plaintext_to_raw(&proto_output_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (parameters.message_bits, parameters.padding_bits)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        // The product is exact once decoded, hence no statistical test is needed.
+        let (message_bits, padding_bits) = criteria;
+        let decode = |raw| decode_message::<Precision::Raw>(raw, message_bits.0, *padding_bits);
+        outputs
+            .iter()
+            .all(|(expected, actual)| decode(*expected) == decode(*actual))
+    }
+}
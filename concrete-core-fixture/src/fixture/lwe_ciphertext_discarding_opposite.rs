@@ -4,17 +4,22 @@ use crate::generation::prototyping::{
 };
 use crate::generation::synthesizing::SynthesizesLweCiphertext;
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{single_noise, SINGLE_LWE_DIMENSION};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
-use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::dispersion::Variance;
 use concrete_commons::numeric::UnsignedInteger;
 use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{LweCiphertextDiscardingOppositeEngine, LweCiphertextEntity};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingOppositeEngine`
 /// trait.
 pub struct LweCiphertextDiscardingOppositeFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextDiscardingOppositeParameters {
     pub noise: Variance,
@@ -50,8 +55,8 @@ where
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
         Box::new(
             vec![LweCiphertextDiscardingOppositeParameters {
-                noise: Variance(LogStandardDev::from_log_standard_dev(-15.).get_variance()),
-                lwe_dimension: LweDimension(600),
+                noise: single_noise(),
+                lwe_dimension: SINGLE_LWE_DIMENSION,
             }]
             .into_iter(),
         )
@@ -110,6 +115,18 @@ where
         (input_ciphertext, output_ciphertext)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext, mut output_ciphertext) = context;
+        engine
+            .discard_opp_lwe_ciphertext(&mut output_ciphertext, &input_ciphertext)
+            .unwrap();
+        (input_ciphertext, output_ciphertext)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -145,4 +162,11 @@ where
         let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
@@ -6,17 +6,26 @@ use crate::generation::synthesizing::{
     SynthesizesLweCiphertext, SynthesizesLweSecretKey, SynthesizesPlaintext,
 };
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{
+    near_zero_noise, reduced_precision_noise, REDUCED_PRECISION_BITS, SINGLE_LWE_DIMENSION,
+    STANDARD_LWE_DIMENSIONS, STANDARD_NOISE, ZERO_NOISE,
+};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::Numeric;
 use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     LweCiphertextEncryptionEngine, LweCiphertextEntity, LweSecretKeyEntity, PlaintextEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextEncryptionEngine` trait.
 pub struct LweCiphertextEncryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextEncryptionParameters {
     pub noise: Variance,
@@ -48,35 +57,25 @@ where
     type Outcome = (Precision::Raw, Precision::Raw);
 
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
-        Box::new(
-            vec![
-                LweCiphertextEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(100),
-                },
-                LweCiphertextEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(300),
-                },
-                LweCiphertextEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(600),
-                },
-                LweCiphertextEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(1000),
-                },
-                LweCiphertextEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(3000),
-                },
-                LweCiphertextEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(6000),
-                },
-            ]
-            .into_iter(),
-        )
+        let noise = if Precision::Raw::BITS <= REDUCED_PRECISION_BITS {
+            reduced_precision_noise()
+        } else {
+            STANDARD_NOISE
+        };
+        let standard = STANDARD_LWE_DIMENSIONS.iter().map(move |lwe_dimension| {
+            LweCiphertextEncryptionParameters {
+                noise,
+                lwe_dimension: *lwe_dimension,
+            }
+        });
+        // The noiseless encryptions must yield the plaintexts exactly.
+        let edge_cases = vec![ZERO_NOISE, near_zero_noise()]
+            .into_iter()
+            .map(|noise| LweCiphertextEncryptionParameters {
+                noise,
+                lwe_dimension: SINGLE_LWE_DIMENSION,
+            });
+        Box::new(standard.chain(edge_cases))
     }
 
     fn generate_random_repetition_prototypes(
@@ -122,6 +121,18 @@ where
         (plaintext, secret_key, ciphertext)
     }
 
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (plaintext, secret_key) = context;
+        let ciphertext = engine
+            .encrypt_lwe_ciphertext(&secret_key, &plaintext, parameters.noise)
+            .unwrap();
+        (plaintext, secret_key, ciphertext)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -156,4 +167,15 @@ where
         let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+
+    fn checked_outcome_matches(_checked: &Self::Outcome, _unchecked: &Self::Outcome) -> bool {
+        true
+    }
 }
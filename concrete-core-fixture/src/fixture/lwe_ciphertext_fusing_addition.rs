@@ -4,17 +4,24 @@ use crate::generation::prototyping::{
 };
 use crate::generation::synthesizing::SynthesizesLweCiphertext;
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{
+    reduced_precision_noise, single_noise, REDUCED_PRECISION_BITS, SINGLE_LWE_DIMENSION,
+};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
-use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
-use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
 use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{LweCiphertextEntity, LweCiphertextFusingAdditionEngine};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextFusingAdditionEngine`
 /// trait.
 pub struct LweCiphertextFusingAdditionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextFusingAdditionParameters {
     pub noise: Variance,
@@ -50,10 +57,15 @@ where
     type Outcome = (Precision::Raw, Precision::Raw);
 
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        let noise = if Precision::Raw::BITS <= REDUCED_PRECISION_BITS {
+            reduced_precision_noise()
+        } else {
+            single_noise()
+        };
         Box::new(
             vec![LweCiphertextFusingAdditionParameters {
-                noise: Variance(LogStandardDev::from_log_standard_dev(-15.).get_variance()),
-                lwe_dimension: LweDimension(600),
+                noise,
+                lwe_dimension: SINGLE_LWE_DIMENSION,
             }]
             .into_iter(),
         )
@@ -118,6 +130,18 @@ where
         (input_ciphertext, output_ciphertext)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext, mut output_ciphertext) = context;
+        engine
+            .fuse_add_lwe_ciphertext(&mut output_ciphertext, &input_ciphertext)
+            .unwrap();
+        (input_ciphertext, output_ciphertext)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -159,4 +183,11 @@ where
         let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
@@ -0,0 +1,315 @@
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::LweDimension;
+use concrete_core::prelude::{
+    LweCiphertextCount, LweCiphertextJaggedVectorCreationEngine,
+    LweCiphertextJaggedVectorDiscardingRowSummationEngine, LweCiphertextJaggedVectorEntity,
+    LweCiphertextJaggedVectorFusingAdditionEngine, LweCiphertextVectorEntity,
+};
+
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertextVector, PrototypesLweSecretKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweCiphertextJaggedVector, SynthesizesLweCiphertextVector,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::presets::{single_noise, SINGLE_LWE_DIMENSION};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `LweCiphertextJaggedVectorFusingAdditionEngine` trait.
+///
+/// The jagged vectors are created and read back with the `LweCiphertextJaggedVectorCreationEngine`
+/// and the `LweCiphertextJaggedVectorDiscardingRowSummationEngine` of the same engine: the rows of a
+/// single ciphertext check the sums element-wise, and the longer rows check that the rows are
+/// matched.
+pub struct LweCiphertextJaggedVectorFusingAdditionFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweCiphertextJaggedVectorFusingAdditionParameters {
+    pub row_lengths: Vec<LweCiphertextCount>,
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, Engine, InputCiphertextVector, JaggedVector, OutputCiphertextVector>
+    Fixture<Precision, Engine, (InputCiphertextVector, JaggedVector, OutputCiphertextVector)>
+    for LweCiphertextJaggedVectorFusingAdditionFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextJaggedVectorCreationEngine<InputCiphertextVector, JaggedVector>
+        + LweCiphertextJaggedVectorFusingAdditionEngine<JaggedVector, JaggedVector>
+        + LweCiphertextJaggedVectorDiscardingRowSummationEngine<JaggedVector, OutputCiphertextVector>,
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    JaggedVector:
+        LweCiphertextJaggedVectorEntity<KeyDistribution = InputCiphertextVector::KeyDistribution>,
+    OutputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = InputCiphertextVector::KeyDistribution>,
+    Maker: SynthesizesLweCiphertextVector<Precision, InputCiphertextVector>
+        + SynthesizesLweCiphertextJaggedVector<Precision, JaggedVector>
+        + SynthesizesLweCiphertextVector<Precision, OutputCiphertextVector>,
+{
+    type Parameters = LweCiphertextJaggedVectorFusingAdditionParameters;
+    type RepetitionPrototypes = <Maker as PrototypesLweSecretKey<
+        Precision,
+        InputCiphertextVector::KeyDistribution,
+    >>::LweSecretKeyProto;
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputCiphertextVector::KeyDistribution,
+        >>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputCiphertextVector::KeyDistribution,
+        >>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputCiphertextVector::KeyDistribution,
+        >>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (
+        InputCiphertextVector,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    );
+    type PostExecutionContext = (JaggedVector, JaggedVector, OutputCiphertextVector);
+    // The variance of the sum of each row.
+    type Criteria = (Vec<Variance>,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                vec![LweCiphertextCount(1); 5],
+                vec![
+                    LweCiphertextCount(2),
+                    LweCiphertextCount(0),
+                    LweCiphertextCount(7),
+                    LweCiphertextCount(1),
+                    LweCiphertextCount(3),
+                ],
+            ]
+            .into_iter()
+            .map(
+                |row_lengths| LweCiphertextJaggedVectorFusingAdditionParameters {
+                    row_lengths,
+                    noise: single_noise(),
+                    lwe_dimension: SINGLE_LWE_DIMENSION,
+                },
+            ),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        maker.new_lwe_secret_key(parameters.lwe_dimension)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let proto_secret_key = repetition_proto;
+        let lwe_ciphertext_count: usize = parameters.row_lengths.iter().map(|row| row.0).sum();
+        let raw_plaintext_vector1 = Precision::Raw::uniform_vec(lwe_ciphertext_count);
+        let raw_plaintext_vector2 = Precision::Raw::uniform_vec(lwe_ciphertext_count);
+        let proto_plaintext_vector1 =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector1);
+        let proto_plaintext_vector2 =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector2);
+        let proto_input_ciphertext_vector1 = maker
+            .encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+                proto_secret_key,
+                &proto_plaintext_vector1,
+                parameters.noise,
+            );
+        let proto_input_ciphertext_vector2 = maker
+            .encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+                proto_secret_key,
+                &proto_plaintext_vector2,
+                parameters.noise,
+            );
+        let proto_output_ciphertext_vector = maker
+            .trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+                parameters.lwe_dimension,
+                LweCiphertextCount(parameters.row_lengths.len()),
+            );
+        (
+            proto_plaintext_vector1,
+            proto_plaintext_vector2,
+            proto_input_ciphertext_vector1,
+            proto_input_ciphertext_vector2,
+            proto_output_ciphertext_vector,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (
+            _,
+            _,
+            proto_input_ciphertext_vector1,
+            proto_input_ciphertext_vector2,
+            proto_output_ciphertext_vector,
+        ) = sample_proto;
+        let synth_input_ciphertext_vector1 =
+            maker.synthesize_lwe_ciphertext_vector(proto_input_ciphertext_vector1);
+        let synth_input_ciphertext_vector2 =
+            maker.synthesize_lwe_ciphertext_vector(proto_input_ciphertext_vector2);
+        let synth_output_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_output_ciphertext_vector);
+        (
+            synth_input_ciphertext_vector1,
+            synth_input_ciphertext_vector2,
+            synth_output_ciphertext_vector,
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext_vector1, input_ciphertext_vector2, mut output_ciphertext_vector) =
+            context;
+        let mut jagged_vector1 = unsafe {
+            engine.create_lwe_ciphertext_jagged_vector_unchecked(
+                input_ciphertext_vector1,
+                parameters.row_lengths.as_slice(),
+            )
+        };
+        let jagged_vector2 = unsafe {
+            engine.create_lwe_ciphertext_jagged_vector_unchecked(
+                input_ciphertext_vector2,
+                parameters.row_lengths.as_slice(),
+            )
+        };
+        unsafe {
+            engine.fuse_add_lwe_ciphertext_jagged_vector_unchecked(
+                &mut jagged_vector1,
+                &jagged_vector2,
+            );
+            engine.discard_sum_rows_lwe_ciphertext_jagged_vector_unchecked(
+                &mut output_ciphertext_vector,
+                &jagged_vector1,
+            );
+        };
+        (jagged_vector1, jagged_vector2, output_ciphertext_vector)
+    }
+
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext_vector1, input_ciphertext_vector2, mut output_ciphertext_vector) =
+            context;
+        let mut jagged_vector1 = engine
+            .create_lwe_ciphertext_jagged_vector(
+                input_ciphertext_vector1,
+                parameters.row_lengths.as_slice(),
+            )
+            .unwrap();
+        let jagged_vector2 = engine
+            .create_lwe_ciphertext_jagged_vector(
+                input_ciphertext_vector2,
+                parameters.row_lengths.as_slice(),
+            )
+            .unwrap();
+        engine
+            .fuse_add_lwe_ciphertext_jagged_vector(&mut jagged_vector1, &jagged_vector2)
+            .unwrap();
+        engine
+            .discard_sum_rows_lwe_ciphertext_jagged_vector(
+                &mut output_ciphertext_vector,
+                &jagged_vector1,
+            )
+            .unwrap();
+        (jagged_vector1, jagged_vector2, output_ciphertext_vector)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (jagged_vector1, jagged_vector2, output_ciphertext_vector) = context;
+        let (proto_plaintext_vector1, proto_plaintext_vector2, ..) = sample_proto;
+        let proto_secret_key = repetition_proto;
+        let raw_plaintext_vector1 =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector1);
+        let raw_plaintext_vector2 =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector2);
+        let raw_plaintext_vector: Vec<Precision::Raw> = raw_plaintext_vector1
+            .iter()
+            .zip(raw_plaintext_vector2.iter())
+            .map(|(&a, &b)| a.wrapping_add(b))
+            .collect();
+        let mut rows = raw_plaintext_vector.as_slice();
+        let predicted_output = parameters
+            .row_lengths
+            .iter()
+            .map(|row_length| {
+                let (row, remaining_rows) = rows.split_at(row_length.0);
+                rows = remaining_rows;
+                row.iter()
+                    .fold(Precision::Raw::zero(), |sum, &a| sum.wrapping_add(a))
+            })
+            .collect();
+        let proto_output_ciphertext_vector =
+            maker.unsynthesize_lwe_ciphertext_vector(&output_ciphertext_vector);
+        let proto_output_plaintext_vector = maker
+            .decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+                proto_secret_key,
+                &proto_output_ciphertext_vector,
+            );
+        maker.destroy_lwe_ciphertext_jagged_vector(jagged_vector1);
+        maker.destroy_lwe_ciphertext_jagged_vector(jagged_vector2);
+        maker.destroy_lwe_ciphertext_vector(output_ciphertext_vector);
+        (
+            predicted_output,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let row_variances = parameters
+            .row_lengths
+            .iter()
+            .map(|row_length| Variance(2. * parameters.noise.get_variance() * row_length.0 as f64))
+            .collect();
+        (row_variances,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        criteria.0.iter().enumerate().all(|(row, variance)| {
+            let (means, actual): (Vec<_>, Vec<_>) = outputs
+                .iter()
+                .map(|(means, actual)| (means[row], actual[row]))
+                .unzip();
+            assert_noise_distribution(&actual, means.as_slice(), *variance)
+        })
+    }
+}
@@ -0,0 +1,196 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertext, PrototypesLweSecretKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweCiphertext, SynthesizesLweSecretKey, SynthesizesPlaintext,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{single_noise, SINGLE_LWE_DIMENSION};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_variance;
+use concrete_commons::dispersion::{
+    BoundedUniform, DispersionParameter, NoiseDistribution, TUniform, Variance,
+};
+use concrete_commons::parameters::LweDimension;
+use concrete_core::prelude::{
+    CoreEngine, LweCiphertextEncryptionEngine, LweCiphertextEntity, LweSecretKeyEntity,
+    PlaintextEntity,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the `LweCiphertextEncryptionEngine` implementations of the `CoreEngine`, when the
+/// noise is sampled from the distributions selected with `CoreEngine::set_noise_distribution`.
+///
+/// The noise of the bounded uniform and TUniform distributions is not gaussian, hence only its
+/// variance is checked.
+pub struct LweCiphertextNoiseDistributionEncryptionFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweCiphertextNoiseDistributionEncryptionParameters {
+    pub noise: Variance,
+    pub noise_distribution: NoiseDistribution,
+    pub lwe_dimension: LweDimension,
+}
+
+impl<Precision, Plaintext, SecretKey, Ciphertext>
+    Fixture<Precision, CoreEngine, (Plaintext, SecretKey, Ciphertext)>
+    for LweCiphertextNoiseDistributionEncryptionFixture
+where
+    Precision: IntegerPrecision,
+    CoreEngine: LweCiphertextEncryptionEngine<SecretKey, Plaintext, Ciphertext>,
+    Plaintext: PlaintextEntity,
+    SecretKey: LweSecretKeyEntity,
+    Ciphertext: LweCiphertextEntity<KeyDistribution = SecretKey::KeyDistribution>,
+    Maker: SynthesizesPlaintext<Precision, Plaintext>
+        + SynthesizesLweSecretKey<Precision, SecretKey>
+        + SynthesizesLweCiphertext<Precision, Ciphertext>,
+{
+    type Parameters = LweCiphertextNoiseDistributionEncryptionParameters;
+    type RepetitionPrototypes = (<Maker as PrototypesLweSecretKey<Precision, Ciphertext::KeyDistribution>>::LweSecretKeyProto, );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        Precision::Raw,
+    );
+    type PreExecutionContext = (Plaintext, SecretKey);
+    type PostExecutionContext = (Plaintext, SecretKey, Ciphertext);
+    type Criteria = (Variance,);
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                NoiseDistribution::BoundedUniform,
+                NoiseDistribution::TUniform,
+            ]
+            .into_iter()
+            .map(|noise_distribution| {
+                LweCiphertextNoiseDistributionEncryptionParameters {
+                    noise: single_noise(),
+                    noise_distribution,
+                    lwe_dimension: SINGLE_LWE_DIMENSION,
+                }
+            }),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let raw_plaintext = Precision::Raw::uniform();
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        (proto_plaintext, raw_plaintext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext, _) = sample_proto;
+        let synth_plaintext = maker.synthesize_plaintext(proto_plaintext);
+        let synth_secret_key = maker.synthesize_lwe_secret_key(proto_secret_key);
+        (synth_plaintext, synth_secret_key)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut CoreEngine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (plaintext, secret_key) = context;
+        engine.set_noise_distribution(parameters.noise_distribution);
+        let ciphertext = unsafe {
+            engine.encrypt_lwe_ciphertext_unchecked(&secret_key, &plaintext, parameters.noise)
+        };
+        // The engine is shared with the other fixtures, hence the gaussian noise is restored.
+        engine.set_noise_distribution(NoiseDistribution::Gaussian);
+        (plaintext, secret_key, ciphertext)
+    }
+
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut CoreEngine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (plaintext, secret_key) = context;
+        engine.set_noise_distribution(parameters.noise_distribution);
+        let ciphertext = engine
+            .encrypt_lwe_ciphertext(&secret_key, &plaintext, parameters.noise)
+            .unwrap();
+        engine.set_noise_distribution(NoiseDistribution::Gaussian);
+        (plaintext, secret_key, ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (plaintext, secret_key, ciphertext) = context;
+        let (proto_secret_key,) = repetition_proto;
+        let (_, raw_plaintext) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(&ciphertext);
+        maker.destroy_lwe_ciphertext(ciphertext);
+        maker.destroy_plaintext(plaintext);
+        maker.destroy_lwe_secret_key(secret_key);
+        let proto_plaintext =
+            maker.decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, &proto_output_ciphertext);
+        (
+            *raw_plaintext,
+            maker.transform_plaintext_to_raw(&proto_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        // The distributions are built from the noise as the engine does, the bound of the TUniform
+        // distribution being rounded to a power of two.
+        let variance = match parameters.noise_distribution {
+            NoiseDistribution::Gaussian => parameters.noise.get_variance(),
+            NoiseDistribution::BoundedUniform => {
+                BoundedUniform::from_dispersion(parameters.noise).get_variance()
+            }
+            NoiseDistribution::TUniform => {
+                TUniform::from_dispersion(parameters.noise).get_variance()
+            }
+        };
+        (Variance(variance),)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        assert_noise_variance(&actual, means.as_slice(), criteria.0)
+    }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+
+    fn checked_outcome_matches(_checked: &Self::Outcome, _unchecked: &Self::Outcome) -> bool {
+        true
+    }
+}
@@ -4,23 +4,30 @@ use crate::generation::prototyping::{
 };
 use crate::generation::synthesizing::{SynthesizesLweCiphertext, SynthesizesPlaintext};
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{single_noise, NEAR_ZERO_PLAINTEXT_WIDTH, SINGLE_LWE_DIMENSION};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
-use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::dispersion::Variance;
 use concrete_commons::numeric::UnsignedInteger;
 use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     LweCiphertextEntity, LweCiphertextPlaintextDiscardingSubtractionEngine, PlaintextEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextPlaintextDiscardingSubtractionEngine`
 /// trait.
 pub struct LweCiphertextPlaintextDiscardingSubtractionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextPlaintextDiscardingSubtractionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
+    /// Whether the plaintexts are sampled close to zero, such that the body wraps around it.
+    pub inputs_near_zero: bool,
 }
 
 #[allow(clippy::type_complexity)]
@@ -58,10 +65,18 @@ where
 
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
         Box::new(
-            vec![LweCiphertextPlaintextDiscardingSubtractionParameters {
-                noise: Variance(LogStandardDev::from_log_standard_dev(-15.).get_variance()),
-                lwe_dimension: LweDimension(600),
-            }]
+            vec![
+                LweCiphertextPlaintextDiscardingSubtractionParameters {
+                    noise: single_noise(),
+                    lwe_dimension: SINGLE_LWE_DIMENSION,
+                    inputs_near_zero: false,
+                },
+                LweCiphertextPlaintextDiscardingSubtractionParameters {
+                    noise: single_noise(),
+                    lwe_dimension: SINGLE_LWE_DIMENSION,
+                    inputs_near_zero: true,
+                },
+            ]
             .into_iter(),
         )
     }
@@ -79,7 +94,14 @@ where
         repetition_proto: &Self::RepetitionPrototypes,
     ) -> Self::SamplePrototypes {
         let (proto_secret_key,) = repetition_proto;
-        let raw_plaintext = Precision::Raw::uniform();
+        let (raw_plaintext, raw_plaintext_sub) = if parameters.inputs_near_zero {
+            (
+                Precision::Raw::uniform_zero_centered(NEAR_ZERO_PLAINTEXT_WIDTH),
+                Precision::Raw::uniform_zero_centered(NEAR_ZERO_PLAINTEXT_WIDTH),
+            )
+        } else {
+            (Precision::Raw::uniform(), Precision::Raw::uniform())
+        };
         let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
         let proto_input_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext(
             proto_secret_key,
@@ -87,7 +109,6 @@ where
             parameters.noise,
         );
 
-        let raw_plaintext_sub = Precision::Raw::uniform();
         let proto_plaintext_sub = maker.transform_raw_to_plaintext(&raw_plaintext_sub);
 
         let proto_output_ciphertext =
@@ -133,6 +154,22 @@ where
         (input_ciphertext, plaintext, output_ciphertext)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext, plaintext, mut output_ciphertext) = context;
+        engine
+            .discard_sub_lwe_ciphertext_plaintext(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &plaintext,
+            )
+            .unwrap();
+        (input_ciphertext, plaintext, output_ciphertext)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -170,4 +207,11 @@ where
         let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
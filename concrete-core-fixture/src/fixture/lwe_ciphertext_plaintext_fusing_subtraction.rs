@@ -4,23 +4,30 @@ use crate::generation::prototyping::{
 };
 use crate::generation::synthesizing::{SynthesizesLweCiphertext, SynthesizesPlaintext};
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{single_noise, NEAR_ZERO_PLAINTEXT_WIDTH, SINGLE_LWE_DIMENSION};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
-use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::dispersion::Variance;
 use concrete_commons::numeric::UnsignedInteger;
 use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     LweCiphertextEntity, LweCiphertextPlaintextFusingSubtractionEngine, PlaintextEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextPlaintextFusingSubtractionEngine`
 /// trait.
 pub struct LweCiphertextPlaintextFusingSubtractionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextPlaintextFusingSubtractionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
+    /// Whether the plaintexts are sampled close to zero, such that the body wraps around it.
+    pub inputs_near_zero: bool,
 }
 
 #[allow(clippy::type_complexity)]
@@ -51,10 +58,18 @@ where
 
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
         Box::new(
-            vec![LweCiphertextPlaintextFusingSubtractionParameters {
-                noise: Variance(LogStandardDev::from_log_standard_dev(-15.).get_variance()),
-                lwe_dimension: LweDimension(600),
-            }]
+            vec![
+                LweCiphertextPlaintextFusingSubtractionParameters {
+                    noise: single_noise(),
+                    lwe_dimension: SINGLE_LWE_DIMENSION,
+                    inputs_near_zero: false,
+                },
+                LweCiphertextPlaintextFusingSubtractionParameters {
+                    noise: single_noise(),
+                    lwe_dimension: SINGLE_LWE_DIMENSION,
+                    inputs_near_zero: true,
+                },
+            ]
             .into_iter(),
         )
     }
@@ -72,7 +87,14 @@ where
         repetition_proto: &Self::RepetitionPrototypes,
     ) -> Self::SamplePrototypes {
         let (proto_secret_key,) = repetition_proto;
-        let raw_plaintext = Precision::Raw::uniform();
+        let (raw_plaintext, raw_plaintext_sub) = if parameters.inputs_near_zero {
+            (
+                Precision::Raw::uniform_zero_centered(NEAR_ZERO_PLAINTEXT_WIDTH),
+                Precision::Raw::uniform_zero_centered(NEAR_ZERO_PLAINTEXT_WIDTH),
+            )
+        } else {
+            (Precision::Raw::uniform(), Precision::Raw::uniform())
+        };
         let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
         let proto_output_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext(
             proto_secret_key,
@@ -80,7 +102,6 @@ where
             parameters.noise,
         );
 
-        let raw_plaintext_sub = Precision::Raw::uniform();
         let proto_plaintext_sub = maker.transform_raw_to_plaintext(&raw_plaintext_sub);
 
         (
@@ -114,6 +135,18 @@ where
         (output_ciphertext, plaintext)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (mut output_ciphertext, plaintext) = context;
+        engine
+            .fuse_sub_lwe_ciphertext_plaintext(&mut output_ciphertext, &plaintext)
+            .unwrap();
+        (output_ciphertext, plaintext)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -150,4 +183,11 @@ where
         let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
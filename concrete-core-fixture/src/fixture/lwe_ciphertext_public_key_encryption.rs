@@ -0,0 +1,219 @@
+use crate::fixture::{Fixture, OutcomeGroup};
+use crate::generation::prototyping::{
+    PrototypesLweCiphertext, PrototypesLwePublicKey, PrototypesLweSecretKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweCiphertext, SynthesizesLwePublicKey, SynthesizesPlaintext,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::STANDARD_NOISE;
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_variance;
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+use concrete_core::prelude::{
+    LweCiphertextEntity, LweCiphertextPublicKeyEncryptionEngine, LwePublicKeyEntity,
+    PlaintextEntity,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `LweCiphertextPublicKeyEncryptionEngine` trait.
+pub struct LweCiphertextPublicKeyEncryptionFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweCiphertextPublicKeyEncryptionParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+}
+
+impl<Precision, Engine, PublicKey, Plaintext, Ciphertext>
+    Fixture<Precision, Engine, (PublicKey, Plaintext, Ciphertext)>
+    for LweCiphertextPublicKeyEncryptionFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextPublicKeyEncryptionEngine<PublicKey, Plaintext, Ciphertext>,
+    PublicKey: LwePublicKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: LweCiphertextEntity<KeyDistribution = PublicKey::KeyDistribution>,
+    Maker: SynthesizesLwePublicKey<Precision, PublicKey>
+        + SynthesizesPlaintext<Precision, Plaintext>
+        + SynthesizesLweCiphertext<Precision, Ciphertext>,
+{
+    type Parameters = LweCiphertextPublicKeyEncryptionParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, PublicKey::KeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLwePublicKey<Precision, PublicKey::KeyDistribution>>::LwePublicKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        Precision::Raw,
+    );
+    type PreExecutionContext = (PublicKey, Plaintext);
+    type PostExecutionContext = (PublicKey, Plaintext, Ciphertext);
+    type Criteria = (Variance,);
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                (LweDimension(100), LwePublicKeyZeroEncryptionCount(64)),
+                (LweDimension(600), LwePublicKeyZeroEncryptionCount(64)),
+                (LweDimension(600), LwePublicKeyZeroEncryptionCount(512)),
+            ]
+            .into_iter()
+            .map(|(lwe_dimension, lwe_public_key_zero_encryption_count)| {
+                LweCiphertextPublicKeyEncryptionParameters {
+                    noise: STANDARD_NOISE,
+                    lwe_dimension,
+                    lwe_public_key_zero_encryption_count,
+                }
+            }),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        let proto_public_key = maker.new_lwe_public_key(
+            &proto_secret_key,
+            parameters.noise,
+            parameters.lwe_public_key_zero_encryption_count,
+        );
+        (proto_secret_key, proto_public_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let raw_plaintext = Precision::Raw::uniform();
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        (proto_plaintext, raw_plaintext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_public_key) = repetition_proto;
+        let (proto_plaintext, _) = sample_proto;
+        let synth_public_key = maker.synthesize_lwe_public_key(proto_public_key);
+        let synth_plaintext = maker.synthesize_plaintext(proto_plaintext);
+        (synth_public_key, synth_plaintext)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (public_key, plaintext) = context;
+        let ciphertext = unsafe {
+            engine.encrypt_lwe_ciphertext_with_public_key_unchecked(&public_key, &plaintext)
+        };
+        (public_key, plaintext, ciphertext)
+    }
+
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (public_key, plaintext) = context;
+        let ciphertext = engine
+            .encrypt_lwe_ciphertext_with_public_key(&public_key, &plaintext)
+            .unwrap();
+        (public_key, plaintext, ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (public_key, plaintext, ciphertext) = context;
+        let (proto_secret_key, _) = repetition_proto;
+        let (_, raw_plaintext) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(&ciphertext);
+        maker.destroy_lwe_ciphertext(ciphertext);
+        maker.destroy_plaintext(plaintext);
+        maker.destroy_lwe_public_key(public_key);
+        let proto_plaintext =
+            maker.decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, &proto_output_ciphertext);
+        (
+            *raw_plaintext,
+            maker.transform_plaintext_to_raw(&proto_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        // Every encryption of zero of the public key is selected with probability one half.
+        let selected_count = parameters.lwe_public_key_zero_encryption_count.0 as f64 / 2.;
+        (Variance(parameters.noise.get_variance() * selected_count),)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        // The noises of the encryptions made with the same public key are biased by the noises of
+        // its encryptions of zero, so they are not centered within a repetition. The differences
+        // between the noises of two encryptions cancel this bias, and their variance is the one
+        // of a single encryption, averaged over the public keys.
+        let (first, second): (Vec<_>, Vec<_>) = outputs
+            .chunks_exact(2)
+            .map(|pair| {
+                let (first_expected, first_actual) = pair[0];
+                let (second_expected, second_actual) = pair[1];
+                (
+                    first_actual.wrapping_sub(first_expected),
+                    second_actual.wrapping_sub(second_expected),
+                )
+            })
+            .unzip();
+        assert_noise_variance(&first, &second, criteria.0)
+    }
+
+    fn verify_groups(groups: &[OutcomeGroup<Self::Criteria, Self::Outcome>]) -> Vec<bool> {
+        // The variance of the differences only matches the criteria on average over the public
+        // keys, hence the samples of all the repetitions are verified together. The pairs must
+        // not straddle two repetitions, so an odd sample is left out of each repetition.
+        let outputs: Vec<_> = groups
+            .iter()
+            .flat_map(|group| {
+                let even_length = group.outcomes.len() / 2 * 2;
+                group.outcomes[..even_length].iter().cloned()
+            })
+            .collect();
+        let verdict =
+            <Self as Fixture<Precision, Engine, (PublicKey, Plaintext, Ciphertext)>>::verify(
+                &groups[0].criteria,
+                outputs.as_slice(),
+            );
+        vec![verdict; groups.len()]
+    }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+
+    fn checked_outcome_matches(_checked: &Self::Outcome, _unchecked: &Self::Outcome) -> bool {
+        true
+    }
+}
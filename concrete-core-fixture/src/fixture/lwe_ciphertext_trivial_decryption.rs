@@ -2,6 +2,7 @@ use crate::fixture::Fixture;
 use crate::generation::prototyping::{PrototypesLweCiphertext, PrototypesPlaintext};
 use crate::generation::synthesizing::{SynthesizesLweCiphertext, SynthesizesPlaintext};
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
@@ -9,10 +10,13 @@ use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     LweCiphertextEntity, LweCiphertextTrivialDecryptionEngine, PlaintextEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextTrivialDecryptionEngine` trait.
 pub struct LweCiphertextTrivialDecryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextTrivialDecryptionParameters {
     pub lwe_dimension: LweDimension,
@@ -109,6 +113,18 @@ where
         (plaintext, ciphertext)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (ciphertext,) = context;
+        let plaintext = engine
+            .trivially_decrypt_lwe_ciphertext(&ciphertext)
+            .unwrap();
+        (plaintext, ciphertext)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -139,4 +155,11 @@ where
         let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
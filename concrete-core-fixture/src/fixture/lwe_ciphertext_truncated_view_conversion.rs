@@ -0,0 +1,214 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertext, PrototypesLweSecretKey, PrototypesLweSecretKeyDerivation,
+    PrototypesPlaintext,
+};
+use crate::generation::synthesizing::SynthesizesLweCiphertext;
+use crate::generation::{Maker, Precision32, Precision64};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{single_noise, SINGLE_LWE_DIMENSION};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_variance;
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::parameters::{ClientId, LweDimension, MasterSeed};
+use concrete_core::prelude::markers::BinaryKeyDistribution;
+use concrete_core::prelude::{
+    LweCiphertextConversionEngine, LweCiphertextEntity, LweCiphertextTruncatedView32,
+    LweCiphertextTruncatedViewCreationEngine,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `LweCiphertextTruncatedViewCreationEngine` trait,
+/// together with the `LweCiphertextConversionEngine` materializing the views.
+///
+/// The 64 bits input ciphertexts and the 32 bits output ciphertexts are encrypted under keys
+/// derived from the same master seed, which share the same bits.
+pub struct LweCiphertextTruncatedViewConversionFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweCiphertextTruncatedViewConversionParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Engine, InputCiphertext, OutputCiphertext>
+    Fixture<Precision64, Engine, (InputCiphertext, OutputCiphertext)>
+    for LweCiphertextTruncatedViewConversionFixture
+where
+    Engine: for<'a> LweCiphertextTruncatedViewCreationEngine<
+            &'a InputCiphertext,
+            LweCiphertextTruncatedView32<'a>,
+        > + for<'a> LweCiphertextConversionEngine<LweCiphertextTruncatedView32<'a>, OutputCiphertext>,
+    InputCiphertext: LweCiphertextEntity<KeyDistribution = BinaryKeyDistribution>,
+    OutputCiphertext: LweCiphertextEntity<KeyDistribution = BinaryKeyDistribution>,
+    Maker: SynthesizesLweCiphertext<Precision64, InputCiphertext>
+        + SynthesizesLweCiphertext<Precision32, OutputCiphertext>
+        + PrototypesLweSecretKeyDerivation<Precision64, BinaryKeyDistribution>
+        + PrototypesLweSecretKeyDerivation<Precision32, BinaryKeyDistribution>,
+{
+    type Parameters = LweCiphertextTruncatedViewConversionParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision64, BinaryKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLweSecretKey<Precision32, BinaryKeyDistribution>>::LweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        u64,
+        <Maker as PrototypesLweCiphertext<Precision64, BinaryKeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (InputCiphertext,);
+    type PostExecutionContext = (InputCiphertext, OutputCiphertext);
+    type Criteria = (Variance,);
+    type Outcome = (u32, u32);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![LweCiphertextTruncatedViewConversionParameters {
+                noise: single_noise(),
+                lwe_dimension: SINGLE_LWE_DIMENSION,
+            }]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let master_seed = MasterSeed(u128::uniform());
+        let proto_input_secret_key = <Maker as PrototypesLweSecretKeyDerivation<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::derive_lwe_secret_key(
+            maker, master_seed, ClientId(0), parameters.lwe_dimension
+        );
+        let proto_output_secret_key = <Maker as PrototypesLweSecretKeyDerivation<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::derive_lwe_secret_key(
+            maker, master_seed, ClientId(0), parameters.lwe_dimension
+        );
+        (proto_input_secret_key, proto_output_secret_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_input_secret_key, _) = repetition_proto;
+        let raw_plaintext = u64::uniform();
+        let proto_plaintext =
+            <Maker as PrototypesPlaintext<Precision64>>::transform_raw_to_plaintext(
+                maker,
+                &raw_plaintext,
+            );
+        let proto_input_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::encrypt_plaintext_to_lwe_ciphertext(
+            maker,
+            proto_input_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+        );
+        (raw_plaintext, proto_input_ciphertext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_input_ciphertext) = sample_proto;
+        (maker.synthesize_lwe_ciphertext(proto_input_ciphertext),)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext,) = context;
+        let output_ciphertext = {
+            let view =
+                unsafe { engine.create_lwe_ciphertext_truncated_view_unchecked(&input_ciphertext) };
+            unsafe { engine.convert_lwe_ciphertext_unchecked(&view) }
+        };
+        (input_ciphertext, output_ciphertext)
+    }
+
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext,) = context;
+        let output_ciphertext = {
+            let view = engine
+                .create_lwe_ciphertext_truncated_view(&input_ciphertext)
+                .unwrap();
+            engine.convert_lwe_ciphertext(&view).unwrap()
+        };
+        (input_ciphertext, output_ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_ciphertext, output_ciphertext) = context;
+        let (_, proto_output_secret_key) = repetition_proto;
+        let (raw_plaintext, _) = sample_proto;
+        // The plaintext rounded to its 32 most significant bits.
+        let expected_mean = (raw_plaintext.wrapping_add(1 << 31) >> 32) as u32;
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(&output_ciphertext);
+        let proto_output_plaintext = <Maker as PrototypesLweCiphertext<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::decrypt_lwe_ciphertext_to_plaintext(
+            maker,
+            proto_output_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_lwe_ciphertext(input_ciphertext);
+        maker.destroy_lwe_ciphertext(output_ciphertext);
+        (
+            expected_mean,
+            <Maker as PrototypesPlaintext<Precision32>>::transform_plaintext_to_raw(
+                maker,
+                &proto_output_plaintext,
+            ),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        // The rounding of the coefficients adds the noise of a modulus switch to 2^32.
+        let rounding_variance = (parameters.lwe_dimension.0 + 2) as f64 / 24. / f64::powi(2., 64);
+        (Variance(
+            parameters.noise.get_variance() + rounding_variance,
+        ),)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        assert_noise_variance(&actual, means.as_slice(), criteria.0)
+    }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+}
@@ -0,0 +1,250 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertextVector, PrototypesLweSecretKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::SynthesizesLweCiphertextVector;
+use crate::generation::{IntegerPrecision, Maker};
+use crate::presets::single_noise;
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+use concrete_core::prelude::markers::BinaryKeyDistribution;
+use concrete_core::prelude::{EntityConstantTimeEqualityEngine, LweCiphertextVectorEntity};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `EntityConstantTimeEqualityEngine` trait with LWE
+/// ciphertext vectors.
+///
+/// Every sample compares four pairs of ciphertext vectors:
+/// + two copies of the same encrypted vector, which are equal;
+/// + two trivial encryptions which only differ by their last coefficient;
+/// + two trivial encryptions of different lengths;
+/// + two trivial encryptions of zeros with the same number of coefficients, but different LWE
+/// dimensions and counts.
+pub struct LweCiphertextVectorConstantTimeEqualityFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweCiphertextVectorConstantTimeEqualityParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub lwe_ciphertext_count: LweCiphertextCount,
+    // The layout of the reshaped vector, which must contain as many coefficients as the one of
+    // the other vectors.
+    pub reshaped_lwe_dimension: LweDimension,
+    pub reshaped_lwe_ciphertext_count: LweCiphertextCount,
+}
+
+impl<Precision, Engine, CiphertextVector> Fixture<Precision, Engine, (CiphertextVector,)>
+    for LweCiphertextVectorConstantTimeEqualityFixture
+where
+    Precision: IntegerPrecision,
+    Engine: EntityConstantTimeEqualityEngine<CiphertextVector>,
+    CiphertextVector: LweCiphertextVectorEntity<KeyDistribution = BinaryKeyDistribution>,
+    Maker: SynthesizesLweCiphertextVector<Precision, CiphertextVector>,
+{
+    type Parameters = LweCiphertextVectorConstantTimeEqualityParameters;
+    type RepetitionPrototypes =
+        (<Maker as PrototypesLweSecretKey<Precision, BinaryKeyDistribution>>::LweSecretKeyProto,);
+    type SamplePrototypes = (
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            BinaryKeyDistribution,
+        >>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            BinaryKeyDistribution,
+        >>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            BinaryKeyDistribution,
+        >>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            BinaryKeyDistribution,
+        >>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            BinaryKeyDistribution,
+        >>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            BinaryKeyDistribution,
+        >>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = Vec<(CiphertextVector, CiphertextVector)>;
+    type PostExecutionContext = (Vec<(CiphertextVector, CiphertextVector)>, Vec<bool>);
+    type Criteria = (Vec<bool>,);
+    type Outcome = Vec<bool>;
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextVectorConstantTimeEqualityParameters {
+                    noise: single_noise(),
+                    lwe_dimension: LweDimension(1023),
+                    lwe_ciphertext_count: LweCiphertextCount(1000),
+                    reshaped_lwe_dimension: LweDimension(2047),
+                    reshaped_lwe_ciphertext_count: LweCiphertextCount(500),
+                },
+                LweCiphertextVectorConstantTimeEqualityParameters {
+                    noise: single_noise(),
+                    lwe_dimension: LweDimension(3),
+                    lwe_ciphertext_count: LweCiphertextCount(2),
+                    reshaped_lwe_dimension: LweDimension(1),
+                    reshaped_lwe_ciphertext_count: LweCiphertextCount(4),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        (maker.new_lwe_secret_key(parameters.lwe_dimension),)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let count = parameters.lwe_ciphertext_count.0;
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(count);
+        let mut raw_modified_plaintext_vector = raw_plaintext_vector.clone();
+        raw_modified_plaintext_vector[count - 1] =
+            raw_modified_plaintext_vector[count - 1].wrapping_add(Precision::Raw::ONE);
+        let trivially_encrypt = |maker: &mut Maker, raw: &[Precision::Raw]| {
+            let proto_plaintext_vector = maker.transform_raw_vec_to_plaintext_vector(raw);
+            maker.trivially_encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+                parameters.lwe_dimension,
+                &proto_plaintext_vector,
+            )
+        };
+        let proto_trivial_ciphertext_vector = trivially_encrypt(maker, &raw_plaintext_vector);
+        let proto_modified_ciphertext_vector =
+            trivially_encrypt(maker, &raw_modified_plaintext_vector);
+        let proto_shorter_ciphertext_vector =
+            trivially_encrypt(maker, &raw_plaintext_vector[..count - 1]);
+        let proto_zeros_ciphertext_vector = maker.trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+            parameters.lwe_dimension,
+            parameters.lwe_ciphertext_count,
+        );
+        let proto_reshaped_ciphertext_vector = maker
+            .trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+                parameters.reshaped_lwe_dimension,
+                parameters.reshaped_lwe_ciphertext_count,
+            );
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_ciphertext_vector = maker.encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        (
+            proto_ciphertext_vector,
+            proto_trivial_ciphertext_vector,
+            proto_modified_ciphertext_vector,
+            proto_shorter_ciphertext_vector,
+            proto_zeros_ciphertext_vector,
+            proto_reshaped_ciphertext_vector,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (
+            proto_ciphertext_vector,
+            proto_trivial_ciphertext_vector,
+            proto_modified_ciphertext_vector,
+            proto_shorter_ciphertext_vector,
+            proto_zeros_ciphertext_vector,
+            proto_reshaped_ciphertext_vector,
+        ) = sample_proto;
+        vec![
+            (proto_ciphertext_vector, proto_ciphertext_vector),
+            (
+                proto_trivial_ciphertext_vector,
+                proto_modified_ciphertext_vector,
+            ),
+            (
+                proto_trivial_ciphertext_vector,
+                proto_shorter_ciphertext_vector,
+            ),
+            (
+                proto_zeros_ciphertext_vector,
+                proto_reshaped_ciphertext_vector,
+            ),
+        ]
+        .into_iter()
+        .map(|(lhs, rhs)| {
+            (
+                maker.synthesize_lwe_ciphertext_vector(lhs),
+                maker.synthesize_lwe_ciphertext_vector(rhs),
+            )
+        })
+        .collect()
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let equalities = context
+            .iter()
+            .map(|(lhs, rhs)| unsafe { engine.constant_time_eq_unchecked(lhs, rhs) })
+            .collect();
+        (context, equalities)
+    }
+
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let equalities = context
+            .iter()
+            .map(|(lhs, rhs)| engine.constant_time_eq(lhs, rhs).unwrap())
+            .collect();
+        (context, equalities)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (pairs, equalities) = context;
+        for (lhs, rhs) in pairs {
+            maker.destroy_lwe_ciphertext_vector(lhs);
+            maker.destroy_lwe_ciphertext_vector(rhs);
+        }
+        equalities
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (vec![true, false, false, false],)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (expected,) = criteria;
+        outputs.iter().all(|equalities| equalities == expected)
+    }
+}
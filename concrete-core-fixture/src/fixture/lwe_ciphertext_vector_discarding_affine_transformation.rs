@@ -8,6 +8,7 @@ use crate::generation::synthesizing::{
     SynthesizesPlaintext,
 };
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
@@ -18,11 +19,14 @@ use concrete_core::prelude::{
     LweCiphertextVectorDiscardingAffineTransformationEngine, LweCiphertextVectorEntity,
     PlaintextEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the
 /// `LweCiphertextVectorDiscardingAffineTransformationEngine` trait.
 pub struct LweCiphertextVectorDiscardingAffineTransformationFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextVectorDiscardingAffineTransformationParameters {
     pub nb_ct: LweCiphertextCount,
@@ -168,6 +172,23 @@ where
         (output_ciphertext, ciphertext_vector, weights, bias)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (mut output_ciphertext, ciphertext_vector, weights, bias) = context;
+        engine
+            .discard_affine_transform_lwe_ciphertext_vector(
+                &mut output_ciphertext,
+                &ciphertext_vector,
+                &weights,
+                &bias,
+            )
+            .unwrap();
+        (output_ciphertext, ciphertext_vector, weights, bias)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -219,4 +240,11 @@ where
         let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
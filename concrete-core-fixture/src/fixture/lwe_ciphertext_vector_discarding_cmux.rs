@@ -0,0 +1,336 @@
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use concrete_core::prelude::{
+    GgswCiphertextEntity, LweCiphertextCount, LweCiphertextVectorDiscardingCmuxEngine,
+    LweCiphertextVectorEntity,
+};
+
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGgswCiphertext, PrototypesGlweSecretKey, PrototypesLweCiphertextVector,
+    PrototypesLweSecretKey, PrototypesPlaintext, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesGgswCiphertext, SynthesizesLweCiphertextVector};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_variance_below;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `LweCiphertextVectorDiscardingCmuxEngine` trait.
+pub struct LweCiphertextVectorDiscardingCmuxFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweCiphertextVectorDiscardingCmuxParameters {
+    pub lwe_ciphertext_count: LweCiphertextCount,
+    pub ggsw_noise: Variance,
+    pub lwe_noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, Engine, GgswCiphertext, InputCiphertextVector, OutputCiphertextVector>
+    Fixture<
+        Precision,
+        Engine,
+        (
+            GgswCiphertext,
+            InputCiphertextVector,
+            OutputCiphertextVector,
+        ),
+    > for LweCiphertextVectorDiscardingCmuxFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextVectorDiscardingCmuxEngine<
+        GgswCiphertext,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    >,
+    GgswCiphertext: GgswCiphertextEntity,
+    InputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = GgswCiphertext::KeyDistribution>,
+    OutputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = GgswCiphertext::KeyDistribution>,
+    Maker: SynthesizesGgswCiphertext<Precision, GgswCiphertext>
+        + SynthesizesLweCiphertextVector<Precision, InputCiphertextVector>
+        + SynthesizesLweCiphertextVector<Precision, OutputCiphertextVector>,
+{
+    type Parameters = LweCiphertextVectorDiscardingCmuxParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, GgswCiphertext::KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesLweSecretKey<Precision, GgswCiphertext::KeyDistribution>>::LweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        Precision::Raw,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGgswCiphertext<Precision, GgswCiphertext::KeyDistribution>>::GgswCiphertextProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            GgswCiphertext::KeyDistribution,
+        >>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            GgswCiphertext::KeyDistribution,
+        >>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            GgswCiphertext::KeyDistribution,
+        >>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (
+        GgswCiphertext,
+        InputCiphertextVector,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    );
+    type PostExecutionContext = (
+        GgswCiphertext,
+        InputCiphertextVector,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    );
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        // A single ciphertext is selected in the standard domain, while a hundred ciphertexts
+        // make the conversion of the condition to the Fourier domain pay off.
+        Box::new(
+            vec![LweCiphertextCount(1), LweCiphertextCount(100)]
+                .into_iter()
+                .map(
+                    |lwe_ciphertext_count| LweCiphertextVectorDiscardingCmuxParameters {
+                        lwe_ciphertext_count,
+                        ggsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                        lwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                        glwe_dimension: GlweDimension(1),
+                        polynomial_size: PolynomialSize(1024),
+                        decomposition_base_log: DecompositionBaseLog(6),
+                        decomposition_level_count: DecompositionLevelCount(4),
+                    },
+                ),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_glwe_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let proto_lwe_secret_key =
+            maker.transmute_glwe_secret_key_to_lwe_secret_key(&proto_glwe_secret_key);
+        (proto_glwe_secret_key, proto_lwe_secret_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_glwe_secret_key, proto_lwe_secret_key) = repetition_proto;
+        let raw_condition = Precision::Raw::pick(&[Precision::Raw::zero(), Precision::Raw::one()]);
+        let proto_condition_plaintext = maker.transform_raw_to_plaintext(&raw_condition);
+        let proto_condition = maker.encrypt_plaintext_to_ggsw_ciphertext(
+            proto_glwe_secret_key,
+            &proto_condition_plaintext,
+            parameters.ggsw_noise,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+        );
+        let raw_if_true_plaintext_vector =
+            Precision::Raw::uniform_vec(parameters.lwe_ciphertext_count.0);
+        let raw_if_false_plaintext_vector =
+            Precision::Raw::uniform_vec(parameters.lwe_ciphertext_count.0);
+        let proto_if_true_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_if_true_plaintext_vector);
+        let proto_if_false_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_if_false_plaintext_vector);
+        let proto_if_true_ciphertext_vector = maker
+            .encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+                proto_lwe_secret_key,
+                &proto_if_true_plaintext_vector,
+                parameters.lwe_noise,
+            );
+        let proto_if_false_ciphertext_vector = maker
+            .encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+                proto_lwe_secret_key,
+                &proto_if_false_plaintext_vector,
+                parameters.lwe_noise,
+            );
+        let proto_output_ciphertext_vector = maker
+            .trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+                LweDimension(parameters.glwe_dimension.0 * parameters.polynomial_size.0),
+                parameters.lwe_ciphertext_count,
+            );
+        (
+            raw_condition,
+            proto_if_true_plaintext_vector,
+            proto_if_false_plaintext_vector,
+            proto_condition,
+            proto_if_true_ciphertext_vector,
+            proto_if_false_ciphertext_vector,
+            proto_output_ciphertext_vector,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (
+            _,
+            _,
+            _,
+            proto_condition,
+            proto_if_true_ciphertext_vector,
+            proto_if_false_ciphertext_vector,
+            proto_output_ciphertext_vector,
+        ) = sample_proto;
+        let synth_condition = maker.synthesize_ggsw_ciphertext(proto_condition);
+        let synth_if_true_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_if_true_ciphertext_vector);
+        let synth_if_false_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_if_false_ciphertext_vector);
+        let synth_output_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_output_ciphertext_vector);
+        (
+            synth_condition,
+            synth_if_true_ciphertext_vector,
+            synth_if_false_ciphertext_vector,
+            synth_output_ciphertext_vector,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (condition, if_true_ciphertext_vector, if_false_ciphertext_vector, mut output) =
+            context;
+        unsafe {
+            engine.discard_cmux_lwe_ciphertext_vector_unchecked(
+                &mut output,
+                &condition,
+                &if_true_ciphertext_vector,
+                &if_false_ciphertext_vector,
+            )
+        };
+        (
+            condition,
+            if_true_ciphertext_vector,
+            if_false_ciphertext_vector,
+            output,
+        )
+    }
+
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (condition, if_true_ciphertext_vector, if_false_ciphertext_vector, mut output) =
+            context;
+        engine
+            .discard_cmux_lwe_ciphertext_vector(
+                &mut output,
+                &condition,
+                &if_true_ciphertext_vector,
+                &if_false_ciphertext_vector,
+            )
+            .unwrap();
+        (
+            condition,
+            if_true_ciphertext_vector,
+            if_false_ciphertext_vector,
+            output,
+        )
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (condition, if_true_ciphertext_vector, if_false_ciphertext_vector, output) = context;
+        let (raw_condition, proto_if_true_plaintext_vector, proto_if_false_plaintext_vector, ..) =
+            sample_proto;
+        let (_, proto_lwe_secret_key) = repetition_proto;
+        let predicted_output = if *raw_condition == Precision::Raw::one() {
+            maker.transform_plaintext_vector_to_raw_vec(proto_if_true_plaintext_vector)
+        } else {
+            maker.transform_plaintext_vector_to_raw_vec(proto_if_false_plaintext_vector)
+        };
+        let proto_output_ciphertext_vector = maker.unsynthesize_lwe_ciphertext_vector(&output);
+        let proto_output_plaintext_vector = maker
+            .decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+                proto_lwe_secret_key,
+                &proto_output_ciphertext_vector,
+            );
+        maker.destroy_ggsw_ciphertext(condition);
+        maker.destroy_lwe_ciphertext_vector(if_true_ciphertext_vector);
+        maker.destroy_lwe_ciphertext_vector(if_false_ciphertext_vector);
+        maker.destroy_lwe_ciphertext_vector(output);
+        (
+            predicted_output,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        // The external product is computed on the difference of the two inputs, and its output is
+        // added to the `if_false` input. The estimation assumes the condition encrypts one, and
+        // hence bounds the noise of the conditions encrypting zero.
+        let difference_variance = Variance(2. * parameters.lwe_noise.get_variance());
+        let external_product_variance: Variance =
+            concrete_npe::estimate_external_product_noise_with_binary_ggsw::<
+                Precision::Raw,
+                Variance,
+                Variance,
+                GgswCiphertext::KeyDistribution,
+            >(
+                parameters.polynomial_size,
+                parameters.glwe_dimension,
+                difference_variance,
+                parameters.ggsw_noise,
+                parameters.decomposition_base_log,
+                parameters.decomposition_level_count,
+            );
+        let output_variance = concrete_npe::estimate_addition_noise::<Precision::Raw, _, _>(
+            external_product_variance,
+            parameters.lwe_noise,
+        );
+        (output_variance,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_variance_below(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+}
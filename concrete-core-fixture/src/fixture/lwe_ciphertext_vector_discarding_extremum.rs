@@ -0,0 +1,399 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweSecretKey, PrototypesLweBootstrapKey, PrototypesLweCiphertextVector,
+    PrototypesLweKeyswitchKey, PrototypesLweSecretKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweBootstrapKey, SynthesizesLweCiphertextVector, SynthesizesLweKeyswitchKey,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::presets::standard_bootstrap_presets;
+use crate::raw::generation::{decode_message, encode_message, RawUnsignedIntegers};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, Extremum, GlweDimension, LweDimension,
+    MessageBitCount, PolynomialSize,
+};
+use concrete_core::prelude::{
+    LweBootstrapKeyEntity, LweCiphertextCount, LweCiphertextVectorDiscardingExtremumEngine,
+    LweCiphertextVectorEntity, LweKeyswitchKeyEntity,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `LweCiphertextVectorDiscardingExtremumEngine` trait.
+pub struct LweCiphertextVectorDiscardingExtremumFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweCiphertextVectorDiscardingExtremumParameters {
+    pub lwe_ciphertext_count: LweCiphertextCount,
+    pub message_bits: MessageBitCount,
+    pub extremum: Extremum,
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub poly_size: PolynomialSize,
+    pub bsk_decomp_level_count: DecompositionLevelCount,
+    pub bsk_decomp_base_log: DecompositionBaseLog,
+    pub ksk_decomp_level_count: DecompositionLevelCount,
+    pub ksk_decomp_base_log: DecompositionBaseLog,
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        Engine,
+        KeyswitchKey,
+        BootstrapKey,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    >
+    Fixture<
+        Precision,
+        Engine,
+        (
+            KeyswitchKey,
+            BootstrapKey,
+            InputCiphertextVector,
+            OutputCiphertextVector,
+        ),
+    > for LweCiphertextVectorDiscardingExtremumFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextVectorDiscardingExtremumEngine<
+        KeyswitchKey,
+        BootstrapKey,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    >,
+    KeyswitchKey: LweKeyswitchKeyEntity<
+        InputKeyDistribution = BootstrapKey::OutputKeyDistribution,
+        OutputKeyDistribution = BootstrapKey::InputKeyDistribution,
+    >,
+    BootstrapKey: LweBootstrapKeyEntity,
+    InputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    OutputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    Maker: SynthesizesLweKeyswitchKey<Precision, KeyswitchKey>
+        + SynthesizesLweBootstrapKey<Precision, BootstrapKey>
+        + SynthesizesLweCiphertextVector<Precision, InputCiphertextVector>
+        + SynthesizesLweCiphertextVector<Precision, OutputCiphertextVector>,
+{
+    type Parameters = LweCiphertextVectorDiscardingExtremumParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, BootstrapKey::OutputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLweKeyswitchKey<Precision, BootstrapKey::OutputKeyDistribution, BootstrapKey::InputKeyDistribution>>::LweKeyswitchKeyProto,
+        <Maker as PrototypesLweBootstrapKey<Precision, BootstrapKey::InputKeyDistribution, BootstrapKey::OutputKeyDistribution>>::LweBootstrapKeyProto,
+    );
+    type SamplePrototypes =
+        (
+            <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+            <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+            <Maker as PrototypesLweCiphertextVector<
+                Precision,
+                BootstrapKey::OutputKeyDistribution,
+            >>::LweCiphertextVectorProto,
+            <Maker as PrototypesLweCiphertextVector<
+                Precision,
+                BootstrapKey::OutputKeyDistribution,
+            >>::LweCiphertextVectorProto,
+            <Maker as PrototypesLweCiphertextVector<
+                Precision,
+                BootstrapKey::OutputKeyDistribution,
+            >>::LweCiphertextVectorProto,
+        );
+    type PreExecutionContext = (
+        KeyswitchKey,
+        BootstrapKey,
+        InputCiphertextVector,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    );
+    type PostExecutionContext = (
+        KeyswitchKey,
+        BootstrapKey,
+        InputCiphertextVector,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    );
+    type Criteria = (MessageBitCount,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        // Every element of every sample is bootstrapped, hence a single set of parameters for each
+        // extremum.
+        Box::new(
+            standard_bootstrap_presets()
+                .into_iter()
+                .filter(|preset| preset.poly_size == PolynomialSize(1024))
+                .flat_map(|preset| {
+                    vec![Extremum::Minimum, Extremum::Maximum]
+                        .into_iter()
+                        .map(
+                            move |extremum| LweCiphertextVectorDiscardingExtremumParameters {
+                                lwe_ciphertext_count: LweCiphertextCount(5),
+                                message_bits: MessageBitCount(2),
+                                extremum,
+                                noise: preset.noise,
+                                lwe_dimension: preset.lwe_dimension,
+                                glwe_dimension: preset.glwe_dimension,
+                                poly_size: preset.poly_size,
+                                bsk_decomp_level_count: preset.decomp_level_count,
+                                bsk_decomp_base_log: preset.decomp_base_log,
+                                ksk_decomp_level_count: DecompositionLevelCount(5),
+                                ksk_decomp_base_log: DecompositionBaseLog(4),
+                            },
+                        )
+                }),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            BootstrapKey::InputKeyDistribution,
+        >>::new_lwe_secret_key(maker, parameters.lwe_dimension);
+        let proto_glwe_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            BootstrapKey::OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker, parameters.glwe_dimension, parameters.poly_size
+        );
+        let proto_bootstrap_key = maker.new_lwe_bootstrap_key(
+            &proto_lwe_secret_key,
+            &proto_glwe_secret_key,
+            parameters.bsk_decomp_level_count,
+            parameters.bsk_decomp_base_log,
+            parameters.noise,
+        );
+        let proto_big_lwe_secret_key =
+            maker.transmute_glwe_secret_key_to_lwe_secret_key(&proto_glwe_secret_key);
+        let proto_keyswitch_key = maker.new_lwe_keyswitch_key(
+            &proto_big_lwe_secret_key,
+            &proto_lwe_secret_key,
+            parameters.ksk_decomp_level_count,
+            parameters.ksk_decomp_base_log,
+            parameters.noise,
+        );
+        (
+            proto_big_lwe_secret_key,
+            proto_keyswitch_key,
+            proto_bootstrap_key,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_big_lwe_secret_key, ..) = repetition_proto;
+        let count = parameters.lwe_ciphertext_count.0;
+        // The messages span the whole message range, extremes included.
+        let encode = |message| encode_message(message, parameters.message_bits.0, 2);
+        let raw_plaintext_vector1: Vec<Precision::Raw> =
+            Precision::Raw::uniform_between_vec(0..1 << parameters.message_bits.0, count)
+                .into_iter()
+                .map(encode)
+                .collect();
+        let raw_plaintext_vector2: Vec<Precision::Raw> =
+            Precision::Raw::uniform_between_vec(0..1 << parameters.message_bits.0, count)
+                .into_iter()
+                .map(encode)
+                .collect();
+        let proto_plaintext_vector1 =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector1);
+        let proto_plaintext_vector2 =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector2);
+        let proto_input_ciphertext_vector1 = maker
+            .encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+                proto_big_lwe_secret_key,
+                &proto_plaintext_vector1,
+                parameters.noise,
+            );
+        let proto_input_ciphertext_vector2 = maker
+            .encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+                proto_big_lwe_secret_key,
+                &proto_plaintext_vector2,
+                parameters.noise,
+            );
+        let proto_output_ciphertext_vector = maker
+            .trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+                LweDimension(parameters.glwe_dimension.0 * parameters.poly_size.0),
+                parameters.lwe_ciphertext_count,
+            );
+        (
+            proto_plaintext_vector1,
+            proto_plaintext_vector2,
+            proto_input_ciphertext_vector1,
+            proto_input_ciphertext_vector2,
+            proto_output_ciphertext_vector,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_keyswitch_key, proto_bootstrap_key) = repetition_proto;
+        let (
+            _,
+            _,
+            proto_input_ciphertext_vector1,
+            proto_input_ciphertext_vector2,
+            proto_output_ciphertext_vector,
+        ) = sample_proto;
+        let synth_keyswitch_key = maker.synthesize_lwe_keyswitch_key(proto_keyswitch_key);
+        let synth_bootstrap_key = maker.synthesize_lwe_bootstrap_key(proto_bootstrap_key);
+        let synth_input_ciphertext_vector1 =
+            maker.synthesize_lwe_ciphertext_vector(proto_input_ciphertext_vector1);
+        let synth_input_ciphertext_vector2 =
+            maker.synthesize_lwe_ciphertext_vector(proto_input_ciphertext_vector2);
+        let synth_output_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_output_ciphertext_vector);
+        (
+            synth_keyswitch_key,
+            synth_bootstrap_key,
+            synth_input_ciphertext_vector1,
+            synth_input_ciphertext_vector2,
+            synth_output_ciphertext_vector,
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (
+            keyswitch_key,
+            bootstrap_key,
+            input_ciphertext_vector1,
+            input_ciphertext_vector2,
+            mut output_ciphertext_vector,
+        ) = context;
+        unsafe {
+            engine.discard_extremum_lwe_ciphertext_vector_unchecked(
+                &mut output_ciphertext_vector,
+                &input_ciphertext_vector1,
+                &input_ciphertext_vector2,
+                &keyswitch_key,
+                &bootstrap_key,
+                parameters.message_bits,
+                parameters.extremum,
+            )
+        };
+        (
+            keyswitch_key,
+            bootstrap_key,
+            input_ciphertext_vector1,
+            input_ciphertext_vector2,
+            output_ciphertext_vector,
+        )
+    }
+
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (
+            keyswitch_key,
+            bootstrap_key,
+            input_ciphertext_vector1,
+            input_ciphertext_vector2,
+            mut output_ciphertext_vector,
+        ) = context;
+        engine
+            .discard_extremum_lwe_ciphertext_vector(
+                &mut output_ciphertext_vector,
+                &input_ciphertext_vector1,
+                &input_ciphertext_vector2,
+                &keyswitch_key,
+                &bootstrap_key,
+                parameters.message_bits,
+                parameters.extremum,
+            )
+            .unwrap();
+        (
+            keyswitch_key,
+            bootstrap_key,
+            input_ciphertext_vector1,
+            input_ciphertext_vector2,
+            output_ciphertext_vector,
+        )
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (
+            keyswitch_key,
+            bootstrap_key,
+            input_ciphertext_vector1,
+            input_ciphertext_vector2,
+            output_ciphertext_vector,
+        ) = context;
+        let (proto_big_lwe_secret_key, ..) = repetition_proto;
+        let (proto_plaintext_vector1, proto_plaintext_vector2, ..) = sample_proto;
+        let raw_plaintext_vector1 =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector1);
+        let raw_plaintext_vector2 =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector2);
+        let predicted_output = raw_plaintext_vector1
+            .iter()
+            .zip(raw_plaintext_vector2.iter())
+            .map(|(&a, &b)| match parameters.extremum {
+                Extremum::Minimum => a.min(b),
+                Extremum::Maximum => a.max(b),
+            })
+            .collect();
+        let proto_output_ciphertext_vector =
+            maker.unsynthesize_lwe_ciphertext_vector(&output_ciphertext_vector);
+        let proto_output_plaintext_vector = maker
+            .decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+                proto_big_lwe_secret_key,
+                &proto_output_ciphertext_vector,
+            );
+        maker.destroy_lwe_keyswitch_key(keyswitch_key);
+        maker.destroy_lwe_bootstrap_key(bootstrap_key);
+        maker.destroy_lwe_ciphertext_vector(input_ciphertext_vector1);
+        maker.destroy_lwe_ciphertext_vector(input_ciphertext_vector2);
+        maker.destroy_lwe_ciphertext_vector(output_ciphertext_vector);
+        (
+            predicted_output,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (parameters.message_bits,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        // The result is exact once decoded, hence no statistical test is needed.
+        let (message_bits,) = criteria;
+        let decode = |raw| decode_message::<Precision::Raw>(raw, message_bits.0, 2);
+        outputs.iter().all(|(expected, actual)| {
+            expected
+                .iter()
+                .zip(actual.iter())
+                .all(|(&e, &a)| decode(e) == decode(a))
+        })
+    }
+}
@@ -0,0 +1,260 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::CastInto;
+use concrete_commons::parameters::{LweCiphertextIndex, LweDimension};
+use concrete_core::prelude::{
+    LweCiphertextCount, LweCiphertextVectorDiscardingGatherEngine, LweCiphertextVectorEntity,
+};
+
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertextVector, PrototypesLweSecretKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::SynthesizesLweCiphertextVector;
+use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{single_noise, SINGLE_LWE_DIMENSION};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `LweCiphertextVectorDiscardingGatherEngine` trait.
+///
+/// The output ciphertexts must decrypt to the input plaintexts found at the indices.
+pub struct LweCiphertextVectorDiscardingGatherFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweCiphertextVectorDiscardingGatherParameters {
+    pub input_lwe_ciphertext_count: LweCiphertextCount,
+    pub output_lwe_ciphertext_count: LweCiphertextCount,
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, Engine, InputCiphertextVector, OutputCiphertextVector>
+    Fixture<Precision, Engine, (InputCiphertextVector, OutputCiphertextVector)>
+    for LweCiphertextVectorDiscardingGatherFixture
+where
+    Precision: IntegerPrecision,
+    Engine:
+        LweCiphertextVectorDiscardingGatherEngine<InputCiphertextVector, OutputCiphertextVector>,
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = InputCiphertextVector::KeyDistribution>,
+    Maker: SynthesizesLweCiphertextVector<Precision, InputCiphertextVector>
+        + SynthesizesLweCiphertextVector<Precision, OutputCiphertextVector>,
+{
+    type Parameters = LweCiphertextVectorDiscardingGatherParameters;
+    type RepetitionPrototypes = <Maker as PrototypesLweSecretKey<
+        Precision,
+        InputCiphertextVector::KeyDistribution,
+    >>::LweSecretKeyProto;
+    type SamplePrototypes = (
+        Vec<LweCiphertextIndex>,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputCiphertextVector::KeyDistribution,
+        >>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputCiphertextVector::KeyDistribution,
+        >>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (
+        Vec<LweCiphertextIndex>,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    );
+    type PostExecutionContext = (InputCiphertextVector, OutputCiphertextVector);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextVectorDiscardingGatherParameters {
+                    input_lwe_ciphertext_count: LweCiphertextCount(1),
+                    output_lwe_ciphertext_count: LweCiphertextCount(1),
+                    noise: single_noise(),
+                    lwe_dimension: SINGLE_LWE_DIMENSION,
+                },
+                LweCiphertextVectorDiscardingGatherParameters {
+                    input_lwe_ciphertext_count: LweCiphertextCount(100),
+                    output_lwe_ciphertext_count: LweCiphertextCount(50),
+                    noise: single_noise(),
+                    lwe_dimension: SINGLE_LWE_DIMENSION,
+                },
+                LweCiphertextVectorDiscardingGatherParameters {
+                    input_lwe_ciphertext_count: LweCiphertextCount(50),
+                    output_lwe_ciphertext_count: LweCiphertextCount(100),
+                    noise: single_noise(),
+                    lwe_dimension: SINGLE_LWE_DIMENSION,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        maker.new_lwe_secret_key(parameters.lwe_dimension)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let proto_secret_key = repetition_proto;
+        // The indices are drawn independently, so that some input ciphertexts are gathered
+        // multiple times.
+        let indices = Precision::Raw::uniform_between_vec(
+            0..parameters.input_lwe_ciphertext_count.0,
+            parameters.output_lwe_ciphertext_count.0,
+        )
+        .into_iter()
+        .map(|index| {
+            let index: f64 = index.cast_into();
+            LweCiphertextIndex(index as usize)
+        })
+        .collect();
+        let raw_input_plaintext_vector =
+            Precision::Raw::uniform_vec(parameters.input_lwe_ciphertext_count.0);
+        let raw_output_plaintext_vector =
+            Precision::Raw::uniform_vec(parameters.output_lwe_ciphertext_count.0);
+        let proto_input_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_input_plaintext_vector);
+        let proto_output_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_output_plaintext_vector);
+        let proto_input_ciphertext_vector = maker
+            .encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+                proto_secret_key,
+                &proto_input_plaintext_vector,
+                parameters.noise,
+            );
+        let proto_output_ciphertext_vector = maker
+            .encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+                proto_secret_key,
+                &proto_output_plaintext_vector,
+                parameters.noise,
+            );
+        (
+            indices,
+            proto_input_plaintext_vector,
+            proto_output_plaintext_vector,
+            proto_input_ciphertext_vector,
+            proto_output_ciphertext_vector,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (indices, .., proto_input_ciphertext_vector, proto_output_ciphertext_vector) =
+            sample_proto;
+        let synth_input_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_input_ciphertext_vector);
+        let synth_output_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_output_ciphertext_vector);
+        (
+            indices.clone(),
+            synth_input_ciphertext_vector,
+            synth_output_ciphertext_vector,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (indices, input_ciphertext_vector, mut output_ciphertext_vector) = context;
+        unsafe {
+            engine.discard_gather_lwe_ciphertext_vector_unchecked(
+                &mut output_ciphertext_vector,
+                &input_ciphertext_vector,
+                &indices,
+            )
+        };
+        (input_ciphertext_vector, output_ciphertext_vector)
+    }
+
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (indices, input_ciphertext_vector, mut output_ciphertext_vector) = context;
+        engine
+            .discard_gather_lwe_ciphertext_vector(
+                &mut output_ciphertext_vector,
+                &input_ciphertext_vector,
+                &indices,
+            )
+            .unwrap();
+        (input_ciphertext_vector, output_ciphertext_vector)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_ciphertext_vector, output_ciphertext_vector) = context;
+        let (indices, proto_input_plaintext_vector, ..) = sample_proto;
+        let proto_secret_key = repetition_proto;
+        let raw_input_plaintext_vector =
+            maker.transform_plaintext_vector_to_raw_vec(proto_input_plaintext_vector);
+        let predicted_output = indices
+            .iter()
+            .map(|index| raw_input_plaintext_vector[index.0])
+            .collect();
+        let proto_output_ciphertext_vector =
+            maker.unsynthesize_lwe_ciphertext_vector(&output_ciphertext_vector);
+        let proto_decrypted_plaintext_vector = maker
+            .decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+                proto_secret_key,
+                &proto_output_ciphertext_vector,
+            );
+        maker.destroy_lwe_ciphertext_vector(input_ciphertext_vector);
+        maker.destroy_lwe_ciphertext_vector(output_ciphertext_vector);
+        (
+            predicted_output,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_decrypted_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        // The ciphertexts are copied, and keep their noise.
+        (parameters.noise,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+}
@@ -0,0 +1,323 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweSecretKey, PrototypesLweBootstrapKey, PrototypesLweCiphertext,
+    PrototypesLweCiphertextVector, PrototypesLweKeyswitchKey, PrototypesLweSecretKey,
+    PrototypesPlaintext, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweBootstrapKey, SynthesizesLweCiphertext, SynthesizesLweCiphertextVector,
+    SynthesizesLweKeyswitchKey,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::presets::standard_bootstrap_presets;
+use crate::raw::generation::{decode_message, encode_message, RawUnsignedIntegers};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::Numeric;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, MessageBitCount,
+    PolynomialSize,
+};
+use concrete_core::prelude::{
+    LweBootstrapKeyEntity, LweCiphertextCount, LweCiphertextEntity,
+    LweCiphertextVectorDiscardingParityEngine, LweCiphertextVectorEntity, LweKeyswitchKeyEntity,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `LweCiphertextVectorDiscardingParityEngine` trait.
+pub struct LweCiphertextVectorDiscardingParityFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweCiphertextVectorDiscardingParityParameters {
+    pub lwe_ciphertext_count: LweCiphertextCount,
+    pub message_bits: MessageBitCount,
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub poly_size: PolynomialSize,
+    pub bsk_decomp_level_count: DecompositionLevelCount,
+    pub bsk_decomp_base_log: DecompositionBaseLog,
+    pub ksk_decomp_level_count: DecompositionLevelCount,
+    pub ksk_decomp_base_log: DecompositionBaseLog,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, Engine, KeyswitchKey, BootstrapKey, InputCiphertextVector, OutputCiphertext>
+    Fixture<
+        Precision,
+        Engine,
+        (
+            KeyswitchKey,
+            BootstrapKey,
+            InputCiphertextVector,
+            OutputCiphertext,
+        ),
+    > for LweCiphertextVectorDiscardingParityFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextVectorDiscardingParityEngine<
+        KeyswitchKey,
+        BootstrapKey,
+        InputCiphertextVector,
+        OutputCiphertext,
+    >,
+    KeyswitchKey: LweKeyswitchKeyEntity<
+        InputKeyDistribution = BootstrapKey::OutputKeyDistribution,
+        OutputKeyDistribution = BootstrapKey::InputKeyDistribution,
+    >,
+    BootstrapKey: LweBootstrapKeyEntity,
+    InputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    OutputCiphertext: LweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    Maker: SynthesizesLweKeyswitchKey<Precision, KeyswitchKey>
+        + SynthesizesLweBootstrapKey<Precision, BootstrapKey>
+        + SynthesizesLweCiphertextVector<Precision, InputCiphertextVector>
+        + SynthesizesLweCiphertext<Precision, OutputCiphertext>,
+{
+    type Parameters = LweCiphertextVectorDiscardingParityParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, BootstrapKey::OutputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLweKeyswitchKey<Precision, BootstrapKey::OutputKeyDistribution, BootstrapKey::InputKeyDistribution>>::LweKeyswitchKeyProto,
+        <Maker as PrototypesLweBootstrapKey<Precision, BootstrapKey::InputKeyDistribution, BootstrapKey::OutputKeyDistribution>>::LweBootstrapKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<Precision, BootstrapKey::OutputKeyDistribution>>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertext<Precision, BootstrapKey::OutputKeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (
+        KeyswitchKey,
+        BootstrapKey,
+        InputCiphertextVector,
+        OutputCiphertext,
+    );
+    type PostExecutionContext = (
+        KeyswitchKey,
+        BootstrapKey,
+        InputCiphertextVector,
+        OutputCiphertext,
+    );
+    type Criteria = (MessageBitCount,);
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        // Every node of the reduction tree is bootstrapped, hence a single set of parameters. The
+        // vector of a single ciphertext is copied without any bootstrap, and the odd count leaves
+        // a ciphertext unpaired at the first level of the tree.
+        Box::new(
+            standard_bootstrap_presets()
+                .into_iter()
+                .filter(|preset| preset.poly_size == PolynomialSize(1024))
+                .flat_map(|preset| {
+                    vec![LweCiphertextCount(1), LweCiphertextCount(3)]
+                        .into_iter()
+                        .map(move |lwe_ciphertext_count| {
+                            LweCiphertextVectorDiscardingParityParameters {
+                                lwe_ciphertext_count,
+                                message_bits: MessageBitCount(1),
+                                noise: preset.noise,
+                                lwe_dimension: preset.lwe_dimension,
+                                glwe_dimension: preset.glwe_dimension,
+                                poly_size: preset.poly_size,
+                                bsk_decomp_level_count: preset.decomp_level_count,
+                                bsk_decomp_base_log: preset.decomp_base_log,
+                                ksk_decomp_level_count: DecompositionLevelCount(5),
+                                ksk_decomp_base_log: DecompositionBaseLog(4),
+                            }
+                        })
+                }),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            BootstrapKey::InputKeyDistribution,
+        >>::new_lwe_secret_key(maker, parameters.lwe_dimension);
+        let proto_glwe_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            BootstrapKey::OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker, parameters.glwe_dimension, parameters.poly_size
+        );
+        let proto_bootstrap_key = maker.new_lwe_bootstrap_key(
+            &proto_lwe_secret_key,
+            &proto_glwe_secret_key,
+            parameters.bsk_decomp_level_count,
+            parameters.bsk_decomp_base_log,
+            parameters.noise,
+        );
+        let proto_big_lwe_secret_key =
+            maker.transmute_glwe_secret_key_to_lwe_secret_key(&proto_glwe_secret_key);
+        let proto_keyswitch_key = maker.new_lwe_keyswitch_key(
+            &proto_big_lwe_secret_key,
+            &proto_lwe_secret_key,
+            parameters.ksk_decomp_level_count,
+            parameters.ksk_decomp_base_log,
+            parameters.noise,
+        );
+        (
+            proto_big_lwe_secret_key,
+            proto_keyswitch_key,
+            proto_bootstrap_key,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_big_lwe_secret_key, ..) = repetition_proto;
+        let raw_plaintext_vector: Vec<Precision::Raw> =
+            Precision::Raw::uniform_between_vec(0..2, parameters.lwe_ciphertext_count.0)
+                .into_iter()
+                .map(|bit| encode_message(bit, parameters.message_bits.0, 2))
+                .collect();
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_input_ciphertext_vector = maker
+            .encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+                proto_big_lwe_secret_key,
+                &proto_plaintext_vector,
+                parameters.noise,
+            );
+        let proto_output_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            BootstrapKey::OutputKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            maker,
+            LweDimension(parameters.glwe_dimension.0 * parameters.poly_size.0),
+        );
+        (
+            proto_plaintext_vector,
+            proto_input_ciphertext_vector,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_keyswitch_key, proto_bootstrap_key) = repetition_proto;
+        let (_, proto_input_ciphertext_vector, proto_output_ciphertext) = sample_proto;
+        let synth_keyswitch_key = maker.synthesize_lwe_keyswitch_key(proto_keyswitch_key);
+        let synth_bootstrap_key = maker.synthesize_lwe_bootstrap_key(proto_bootstrap_key);
+        let synth_input_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_input_ciphertext_vector);
+        let synth_output_ciphertext = maker.synthesize_lwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_keyswitch_key,
+            synth_bootstrap_key,
+            synth_input_ciphertext_vector,
+            synth_output_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (keyswitch_key, bootstrap_key, input_ciphertext_vector, mut output_ciphertext) =
+            context;
+        unsafe {
+            engine.discard_parity_lwe_ciphertext_vector_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext_vector,
+                &keyswitch_key,
+                &bootstrap_key,
+                parameters.message_bits,
+            )
+        };
+        (
+            keyswitch_key,
+            bootstrap_key,
+            input_ciphertext_vector,
+            output_ciphertext,
+        )
+    }
+
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (keyswitch_key, bootstrap_key, input_ciphertext_vector, mut output_ciphertext) =
+            context;
+        engine
+            .discard_parity_lwe_ciphertext_vector(
+                &mut output_ciphertext,
+                &input_ciphertext_vector,
+                &keyswitch_key,
+                &bootstrap_key,
+                parameters.message_bits,
+            )
+            .unwrap();
+        (
+            keyswitch_key,
+            bootstrap_key,
+            input_ciphertext_vector,
+            output_ciphertext,
+        )
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (keyswitch_key, bootstrap_key, input_ciphertext_vector, output_ciphertext) = context;
+        let (proto_big_lwe_secret_key, ..) = repetition_proto;
+        let (proto_plaintext_vector, ..) = sample_proto;
+        let parity = maker
+            .transform_plaintext_vector_to_raw_vec(proto_plaintext_vector)
+            .into_iter()
+            .map(|raw| decode_message(raw, parameters.message_bits.0, 2))
+            .fold(Precision::Raw::ZERO, |parity, bit| parity ^ bit);
+        let predicted_output = encode_message(parity, parameters.message_bits.0, 2);
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(&output_ciphertext);
+        let proto_output_plaintext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            BootstrapKey::OutputKeyDistribution,
+        >>::decrypt_lwe_ciphertext_to_plaintext(
+            maker,
+            proto_big_lwe_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_lwe_keyswitch_key(keyswitch_key);
+        maker.destroy_lwe_bootstrap_key(bootstrap_key);
+        maker.destroy_lwe_ciphertext_vector(input_ciphertext_vector);
+        maker.destroy_lwe_ciphertext(output_ciphertext);
+        (
+            predicted_output,
+            maker.transform_plaintext_to_raw(&proto_output_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (parameters.message_bits,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        // The result is exact once decoded, hence no statistical test is needed.
+        let (message_bits,) = criteria;
+        let decode = |raw| decode_message::<Precision::Raw>(raw, message_bits.0, 2);
+        outputs
+            .iter()
+            .all(|(expected, actual)| decode(*expected) == decode(*actual))
+    }
+}
@@ -0,0 +1,299 @@
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, LweDimension, PolynomialSize,
+};
+use concrete_core::prelude::{
+    permutation_network_layer_count, permutation_network_switch_bits, GgswCiphertextEntity,
+    LweCiphertextCount, LweCiphertextVectorDiscardingPermutationEngine, LweCiphertextVectorEntity,
+};
+
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGgswCiphertext, PrototypesGlweSecretKey, PrototypesLweCiphertextVector,
+    PrototypesLweSecretKey, PrototypesPlaintext, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesGgswCiphertext, SynthesizesLweCiphertextVector};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `LweCiphertextVectorDiscardingPermutationEngine`
+/// trait.
+pub struct LweCiphertextVectorDiscardingPermutationFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweCiphertextVectorDiscardingPermutationParameters {
+    pub lwe_ciphertext_count: LweCiphertextCount,
+    pub ggsw_noise: Variance,
+    pub lwe_noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, Engine, GgswCiphertext, InputCiphertextVector, OutputCiphertextVector>
+    Fixture<
+        Precision,
+        Engine,
+        (
+            GgswCiphertext,
+            InputCiphertextVector,
+            OutputCiphertextVector,
+        ),
+    > for LweCiphertextVectorDiscardingPermutationFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextVectorDiscardingPermutationEngine<
+        GgswCiphertext,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    >,
+    GgswCiphertext: GgswCiphertextEntity,
+    InputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = GgswCiphertext::KeyDistribution>,
+    OutputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = GgswCiphertext::KeyDistribution>,
+    Maker: SynthesizesGgswCiphertext<Precision, GgswCiphertext>
+        + SynthesizesLweCiphertextVector<Precision, InputCiphertextVector>
+        + SynthesizesLweCiphertextVector<Precision, OutputCiphertextVector>,
+{
+    type Parameters = LweCiphertextVectorDiscardingPermutationParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, GgswCiphertext::KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesLweSecretKey<Precision, GgswCiphertext::KeyDistribution>>::LweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        Vec<usize>,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        Vec<<Maker as PrototypesGgswCiphertext<Precision, GgswCiphertext::KeyDistribution>>::GgswCiphertextProto>,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            GgswCiphertext::KeyDistribution,
+        >>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            GgswCiphertext::KeyDistribution,
+        >>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (
+        Vec<GgswCiphertext>,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    );
+    type PostExecutionContext = (
+        Vec<GgswCiphertext>,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    );
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![LweCiphertextCount(2), LweCiphertextCount(8)]
+                .into_iter()
+                .map(
+                    |lwe_ciphertext_count| LweCiphertextVectorDiscardingPermutationParameters {
+                        lwe_ciphertext_count,
+                        ggsw_noise: Variance(LogStandardDev(-40.).get_variance()),
+                        lwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                        lwe_dimension: LweDimension(128),
+                        decomposition_base_log: DecompositionBaseLog(7),
+                        decomposition_level_count: DecompositionLevelCount(3),
+                    },
+                ),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        // The switches act on the input ciphertexts as GGSW ciphertexts with polynomials of size
+        // one, encrypted under the transmutation of the LWE secret key.
+        let proto_lwe_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        let proto_glwe_secret_key = maker
+            .transmute_lwe_secret_key_to_glwe_secret_key(&proto_lwe_secret_key, PolynomialSize(1));
+        (proto_glwe_secret_key, proto_lwe_secret_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_glwe_secret_key, proto_lwe_secret_key) = repetition_proto;
+        let count = parameters.lwe_ciphertext_count.0;
+        // A random permutation is obtained by sorting the positions along random keys.
+        let keys = Precision::Raw::uniform_vec(count);
+        let mut permutation: Vec<usize> = (0..count).collect();
+        permutation.sort_by_key(|position| keys[*position]);
+        let proto_switches = permutation_network_switch_bits(&permutation)
+            .into_iter()
+            .map(|bit| {
+                let raw_bit = if bit {
+                    Precision::Raw::one()
+                } else {
+                    Precision::Raw::zero()
+                };
+                let proto_plaintext = maker.transform_raw_to_plaintext(&raw_bit);
+                maker.encrypt_plaintext_to_ggsw_ciphertext(
+                    proto_glwe_secret_key,
+                    &proto_plaintext,
+                    parameters.ggsw_noise,
+                    parameters.decomposition_level_count,
+                    parameters.decomposition_base_log,
+                )
+            })
+            .collect();
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(count);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_input_ciphertext_vector = maker
+            .encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+                proto_lwe_secret_key,
+                &proto_plaintext_vector,
+                parameters.lwe_noise,
+            );
+        let proto_output_ciphertext_vector = maker
+            .trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+                parameters.lwe_dimension,
+                parameters.lwe_ciphertext_count,
+            );
+        (
+            permutation,
+            proto_plaintext_vector,
+            proto_switches,
+            proto_input_ciphertext_vector,
+            proto_output_ciphertext_vector,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, _, proto_switches, proto_input_ciphertext_vector, proto_output_ciphertext_vector) =
+            sample_proto;
+        let synth_switches = proto_switches
+            .iter()
+            .map(|proto_switch| maker.synthesize_ggsw_ciphertext(proto_switch))
+            .collect();
+        let synth_input_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_input_ciphertext_vector);
+        let synth_output_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_output_ciphertext_vector);
+        (
+            synth_switches,
+            synth_input_ciphertext_vector,
+            synth_output_ciphertext_vector,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (switches, input_ciphertext_vector, mut output) = context;
+        unsafe {
+            engine.discard_permute_lwe_ciphertext_vector_unchecked(
+                &mut output,
+                &input_ciphertext_vector,
+                switches.as_slice(),
+            )
+        };
+        (switches, input_ciphertext_vector, output)
+    }
+
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (switches, input_ciphertext_vector, mut output) = context;
+        engine
+            .discard_permute_lwe_ciphertext_vector(
+                &mut output,
+                &input_ciphertext_vector,
+                switches.as_slice(),
+            )
+            .unwrap();
+        (switches, input_ciphertext_vector, output)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (switches, input_ciphertext_vector, output) = context;
+        let (permutation, proto_plaintext_vector, ..) = sample_proto;
+        let (_, proto_lwe_secret_key) = repetition_proto;
+        let raw_input = maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector);
+        let mut predicted_output = raw_input.clone();
+        for (input, destination) in permutation.iter().enumerate() {
+            predicted_output[*destination] = raw_input[input];
+        }
+        let proto_output_ciphertext_vector = maker.unsynthesize_lwe_ciphertext_vector(&output);
+        let proto_output_plaintext_vector = maker
+            .decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+                proto_lwe_secret_key,
+                &proto_output_ciphertext_vector,
+            );
+        for switch in switches {
+            maker.destroy_ggsw_ciphertext(switch);
+        }
+        maker.destroy_lwe_ciphertext_vector(input_ciphertext_vector);
+        maker.destroy_lwe_ciphertext_vector(output);
+        (
+            predicted_output,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let layer_count = permutation_network_layer_count(parameters.lwe_ciphertext_count);
+        let output_variance = concrete_npe::estimate_permutation_network_noise_with_binary_ggsw::<
+            Precision::Raw,
+            _,
+            _,
+            GgswCiphertext::KeyDistribution,
+        >(
+            layer_count,
+            parameters.lwe_dimension,
+            parameters.decomposition_base_log,
+            parameters.decomposition_level_count,
+            parameters.lwe_noise,
+            parameters.ggsw_noise,
+        )[layer_count - 1];
+        (output_variance,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+}
@@ -13,12 +13,17 @@ use crate::generation::synthesizing::{
     SynthesizesLweCiphertextVector, SynthesizesLweSecretKey, SynthesizesPlaintextVector,
 };
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{near_zero_noise, ZERO_NOISE};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextVectorEncryptionEngine` trait.
 pub struct LweCiphertextVectorEncryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextVectorEncryptionParameters {
     pub noise: Variance,
@@ -88,6 +93,17 @@ where
                     lwe_dimension: LweDimension(6000),
                     lwe_ciphertext_count: LweCiphertextCount(100),
                 },
+                // The noiseless encryptions must yield the plaintexts exactly.
+                LweCiphertextVectorEncryptionParameters {
+                    noise: ZERO_NOISE,
+                    lwe_dimension: LweDimension(600),
+                    lwe_ciphertext_count: LweCiphertextCount(100),
+                },
+                LweCiphertextVectorEncryptionParameters {
+                    noise: near_zero_noise(),
+                    lwe_dimension: LweDimension(600),
+                    lwe_ciphertext_count: LweCiphertextCount(100),
+                },
             ]
             .into_iter(),
         )
@@ -142,6 +158,18 @@ where
         (secret_key, plaintext_vector, ciphertext_vector)
     }
 
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, plaintext_vector) = context;
+        let ciphertext_vector = engine
+            .encrypt_lwe_ciphertext_vector(&secret_key, &plaintext_vector, parameters.noise)
+            .unwrap();
+        (secret_key, plaintext_vector, ciphertext_vector)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -182,4 +210,15 @@ where
         let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
         assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+
+    fn checked_outcome_matches(_checked: &Self::Outcome, _unchecked: &Self::Outcome) -> bool {
+        true
+    }
 }
@@ -1,4 +1,4 @@
-use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::dispersion::Variance;
 use concrete_commons::numeric::UnsignedInteger;
 use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
@@ -11,13 +11,18 @@ use crate::generation::prototyping::{
 };
 use crate::generation::synthesizing::SynthesizesLweCiphertextVector;
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{single_noise, SINGLE_LWE_DIMENSION};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextVectorFusingAdditionEngine`
 /// trait.
 pub struct LweCiphertextVectorFusingAdditionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextVectorFusingAdditionParameters {
     pub lwe_ciphertext_count: LweCiphertextCount,
@@ -65,13 +70,13 @@ where
             vec![
                 LweCiphertextVectorFusingAdditionParameters {
                     lwe_ciphertext_count: LweCiphertextCount(1),
-                    noise: Variance(LogStandardDev::from_log_standard_dev(-15.).get_variance()),
-                    lwe_dimension: LweDimension(600),
+                    noise: single_noise(),
+                    lwe_dimension: SINGLE_LWE_DIMENSION,
                 },
                 LweCiphertextVectorFusingAdditionParameters {
                     lwe_ciphertext_count: LweCiphertextCount(100),
-                    noise: Variance(LogStandardDev::from_log_standard_dev(-15.).get_variance()),
-                    lwe_dimension: LweDimension(600),
+                    noise: single_noise(),
+                    lwe_dimension: SINGLE_LWE_DIMENSION,
                 },
             ]
             .into_iter(),
@@ -149,6 +154,18 @@ where
         (input_ciphertext_vector, output_ciphertext_vector)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext_vector, mut output_ciphertext_vector) = context;
+        engine
+            .fuse_add_lwe_ciphertext_vector(&mut output_ciphertext_vector, &input_ciphertext_vector)
+            .unwrap();
+        (input_ciphertext_vector, output_ciphertext_vector)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -202,4 +219,11 @@ where
         let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
         assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
@@ -1,4 +1,4 @@
-use crate::fixture::{fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms, Fixture};
+use crate::fixture::Fixture;
 use crate::generation::prototyping::{
     PrototypesGlweCiphertext, PrototypesGlweSecretKey, PrototypesLweCiphertextVector,
     PrototypesLweSecretKey, PrototypesPackingKeyswitchKey, PrototypesPlaintextVector,
@@ -7,6 +7,7 @@ use crate::generation::synthesizing::{
     SynthesizesGlweCiphertext, SynthesizesLweCiphertextVector, SynthesizesPackingKeyswitchKey,
 };
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
@@ -18,11 +19,14 @@ use concrete_core::prelude::{
     LweCiphertextVectorEntity, LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine,
     PackingKeyswitchKeyEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the
 /// `LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine` trait.
 pub struct LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchParameters {
     pub input_lwe_noise: Variance,
@@ -221,6 +225,22 @@ where
         (output_ciphertext, input_ciphertext_vector, pksk)
     }
 
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (mut output_ciphertext, input_ciphertext_vector, pksk) = context;
+        engine
+            .discard_packing_keyswitch_lwe_ciphertext_vector(
+                &mut output_ciphertext,
+                &input_ciphertext_vector,
+                &pksk,
+            )
+            .unwrap();
+        (output_ciphertext, input_ciphertext_vector, pksk)
+    }
+
     fn process_context(
         parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -260,7 +280,7 @@ where
         _repetition_proto: &Self::RepetitionPrototypes,
     ) -> Self::Criteria {
         let predicted_variance: Variance =
-            fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+            concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
                 Precision::Raw,
                 _,
                 _,
@@ -291,4 +311,11 @@ where
             .collect::<Vec<_>>();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
@@ -0,0 +1,196 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertextVector, PrototypesLweSecretKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesLweCiphertextVector, SynthesizesLweSecretKey};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::VARIANCE_CONFIDENCE_FACTOR;
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension, MessageBitCount};
+use concrete_core::prelude::{
+    LweCiphertextVectorEntity, LweCiphertextVectorNoiseStatisticsEngine, LweSecretKeyEntity,
+    NoiseStatistics,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `LweCiphertextVectorNoiseStatisticsEngine` trait.
+pub struct LweCiphertextVectorNoiseStatisticsFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweCiphertextVectorNoiseStatisticsParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub lwe_ciphertext_count: LweCiphertextCount,
+    pub message_bits: MessageBitCount,
+}
+
+impl<Precision, Engine, SecretKey, CiphertextVector>
+    Fixture<Precision, Engine, (SecretKey, CiphertextVector)>
+    for LweCiphertextVectorNoiseStatisticsFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextVectorNoiseStatisticsEngine<SecretKey, CiphertextVector>,
+    SecretKey: LweSecretKeyEntity,
+    CiphertextVector: LweCiphertextVectorEntity<KeyDistribution = SecretKey::KeyDistribution>,
+    Maker: SynthesizesLweSecretKey<Precision, SecretKey>
+        + SynthesizesLweCiphertextVector<Precision, CiphertextVector>,
+{
+    type Parameters = LweCiphertextVectorNoiseStatisticsParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, CiphertextVector::KeyDistribution>>::LweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesLweCiphertextVector<Precision, SecretKey::KeyDistribution>>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (SecretKey, CiphertextVector);
+    type PostExecutionContext = (SecretKey, CiphertextVector, NoiseStatistics);
+    type Criteria = (Variance,);
+    type Outcome = NoiseStatistics;
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextVectorNoiseStatisticsParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(100),
+                    lwe_ciphertext_count: LweCiphertextCount(100),
+                    message_bits: MessageBitCount(4),
+                },
+                LweCiphertextVectorNoiseStatisticsParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(600),
+                    lwe_ciphertext_count: LweCiphertextCount(100),
+                    message_bits: MessageBitCount(8),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_n_msb_vec(
+            parameters.message_bits.0,
+            parameters.lwe_ciphertext_count.0,
+        );
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_ciphertext_vector = maker.encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        (proto_ciphertext_vector,)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_ciphertext_vector,) = sample_proto;
+        let secret_key = maker.synthesize_lwe_secret_key(proto_secret_key);
+        let ciphertext_vector = maker.synthesize_lwe_ciphertext_vector(proto_ciphertext_vector);
+        (secret_key, ciphertext_vector)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, ciphertext_vector) = context;
+        let statistics = unsafe {
+            engine.compute_lwe_ciphertext_vector_noise_statistics_unchecked(
+                &secret_key,
+                &ciphertext_vector,
+                parameters.message_bits,
+            )
+        };
+        (secret_key, ciphertext_vector, statistics)
+    }
+
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, ciphertext_vector) = context;
+        let statistics = engine
+            .compute_lwe_ciphertext_vector_noise_statistics(
+                &secret_key,
+                &ciphertext_vector,
+                parameters.message_bits,
+            )
+            .unwrap();
+        (secret_key, ciphertext_vector, statistics)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (secret_key, ciphertext_vector, statistics) = context;
+        maker.destroy_lwe_ciphertext_vector(ciphertext_vector);
+        maker.destroy_lwe_secret_key(secret_key);
+        statistics
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (parameters.noise,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        // The statistics of the samples are pooled, and compared to the encryption noise.
+        let sample_count = outputs
+            .iter()
+            .map(|statistics| statistics.sample_count.0)
+            .sum::<usize>() as f64;
+        let mean = outputs
+            .iter()
+            .map(|statistics| statistics.mean * statistics.sample_count.0 as f64)
+            .sum::<f64>()
+            / sample_count;
+        let mean_square = outputs
+            .iter()
+            .map(|statistics| {
+                (statistics.variance.get_variance() + statistics.mean.powi(2))
+                    * statistics.sample_count.0 as f64
+            })
+            .sum::<f64>()
+            / sample_count;
+        let expected_variance = criteria.0.get_variance();
+        let mean_tolerance =
+            VARIANCE_CONFIDENCE_FACTOR * f64::sqrt(expected_variance / sample_count);
+        let variance_tolerance = VARIANCE_CONFIDENCE_FACTOR * f64::sqrt(2. / sample_count);
+        mean.abs() <= mean_tolerance
+            && (mean_square / expected_variance - 1.).abs() <= variance_tolerance
+            && outputs
+                .iter()
+                .all(|statistics| statistics.budget_consumed < 1.)
+    }
+}
@@ -0,0 +1,224 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertextVector, PrototypesLwePhaseVector, PrototypesLweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweCiphertextVector, SynthesizesLwePhaseVector, SynthesizesLweSecretKey,
+    SynthesizesPlaintextVector,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension, MessageBitCount};
+use concrete_core::prelude::{
+    LweCiphertextVectorEntity, LweCiphertextVectorPhaseExportingDecryptionEngine,
+    LwePhaseVectorEntity, LweSecretKeyEntity, PlaintextVectorEntity,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `LweCiphertextVectorPhaseExportingDecryptionEngine`
+/// trait.
+pub struct LweCiphertextVectorPhaseExportingDecryptionFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweCiphertextVectorPhaseExportingDecryptionParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub lwe_ciphertext_count: LweCiphertextCount,
+    pub message_bits: MessageBitCount,
+}
+
+impl<Precision, Engine, SecretKey, CiphertextVector, PlaintextVector, PhaseVector>
+    Fixture<Precision, Engine, (SecretKey, CiphertextVector, PlaintextVector, PhaseVector)>
+    for LweCiphertextVectorPhaseExportingDecryptionFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextVectorPhaseExportingDecryptionEngine<
+        SecretKey,
+        CiphertextVector,
+        PlaintextVector,
+        PhaseVector,
+    >,
+    SecretKey: LweSecretKeyEntity,
+    CiphertextVector: LweCiphertextVectorEntity<KeyDistribution = SecretKey::KeyDistribution>,
+    PlaintextVector: PlaintextVectorEntity,
+    PhaseVector: LwePhaseVectorEntity,
+    Maker: SynthesizesLweSecretKey<Precision, SecretKey>
+        + SynthesizesLweCiphertextVector<Precision, CiphertextVector>
+        + SynthesizesPlaintextVector<Precision, PlaintextVector>
+        + SynthesizesLwePhaseVector<Precision, PhaseVector>,
+{
+    type Parameters = LweCiphertextVectorPhaseExportingDecryptionParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, CiphertextVector::KeyDistribution>>::LweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<Precision, SecretKey::KeyDistribution>>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (SecretKey, CiphertextVector);
+    type PostExecutionContext = (SecretKey, CiphertextVector, PlaintextVector, PhaseVector);
+    type Criteria = (Variance,);
+    type Outcome = (
+        Vec<Precision::Raw>,
+        Vec<Precision::Raw>,
+        Vec<Precision::Raw>,
+    );
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextVectorPhaseExportingDecryptionParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(100),
+                    lwe_ciphertext_count: LweCiphertextCount(1),
+                    message_bits: MessageBitCount(4),
+                },
+                LweCiphertextVectorPhaseExportingDecryptionParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(100),
+                    lwe_ciphertext_count: LweCiphertextCount(100),
+                    message_bits: MessageBitCount(4),
+                },
+                LweCiphertextVectorPhaseExportingDecryptionParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(600),
+                    lwe_ciphertext_count: LweCiphertextCount(100),
+                    message_bits: MessageBitCount(8),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_n_msb_vec(
+            parameters.message_bits.0,
+            parameters.lwe_ciphertext_count.0,
+        );
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_ciphertext_vector = maker.encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        (proto_plaintext_vector, proto_ciphertext_vector)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_secret_key,) = repetition_proto;
+        let (_, proto_ciphertext_vector) = sample_proto;
+        let secret_key = maker.synthesize_lwe_secret_key(proto_secret_key);
+        let ciphertext_vector = maker.synthesize_lwe_ciphertext_vector(proto_ciphertext_vector);
+        (secret_key, ciphertext_vector)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, ciphertext_vector) = context;
+        let (plaintext_vector, phase_vector) = unsafe {
+            engine.decrypt_lwe_ciphertext_vector_exporting_phases_unchecked(
+                &secret_key,
+                &ciphertext_vector,
+                parameters.message_bits,
+            )
+        };
+        (
+            secret_key,
+            ciphertext_vector,
+            plaintext_vector,
+            phase_vector,
+        )
+    }
+
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, ciphertext_vector) = context;
+        let (plaintext_vector, phase_vector) = engine
+            .decrypt_lwe_ciphertext_vector_exporting_phases(
+                &secret_key,
+                &ciphertext_vector,
+                parameters.message_bits,
+            )
+            .unwrap();
+        (
+            secret_key,
+            ciphertext_vector,
+            plaintext_vector,
+            phase_vector,
+        )
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (proto_plaintext_vector, _) = sample_proto;
+        let (secret_key, ciphertext_vector, plaintext_vector, phase_vector) = context;
+        let proto_output_plaintext_vector = maker.unsynthesize_plaintext_vector(&plaintext_vector);
+        let proto_phase_vector = maker.unsynthesize_lwe_phase_vector(&phase_vector);
+        maker.destroy_lwe_ciphertext_vector(ciphertext_vector);
+        maker.destroy_lwe_secret_key(secret_key);
+        maker.destroy_plaintext_vector(plaintext_vector);
+        maker.destroy_lwe_phase_vector(phase_vector);
+        (
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector),
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+            maker.transform_lwe_phase_vector_to_raw_vec(&proto_phase_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (parameters.noise,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        // The decoded messages must be exact, and the phases must carry the encryption noise.
+        let messages_match = outputs
+            .iter()
+            .all(|(expected, decrypted, _)| expected == decrypted);
+        let (means, phases): (Vec<_>, Vec<_>) = outputs
+            .iter()
+            .map(|(expected, _, phases)| (expected.clone(), phases.clone()))
+            .unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let phases: Vec<Precision::Raw> = phases.into_iter().flatten().collect();
+        messages_match && assert_noise_distribution(phases.as_slice(), means.as_slice(), criteria.0)
+    }
+}
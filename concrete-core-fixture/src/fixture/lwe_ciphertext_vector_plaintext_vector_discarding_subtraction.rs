@@ -0,0 +1,286 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::LweDimension;
+use concrete_core::prelude::{
+    LweCiphertextCount, LweCiphertextVectorEntity,
+    LweCiphertextVectorPlaintextVectorDiscardingSubtractionEngine, PlaintextVectorEntity,
+};
+
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertextVector, PrototypesLweSecretKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesLweCiphertextVector, SynthesizesPlaintextVector};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{single_noise, NEAR_ZERO_PLAINTEXT_WIDTH, SINGLE_LWE_DIMENSION};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the
+/// `LweCiphertextVectorPlaintextVectorDiscardingSubtractionEngine` trait.
+pub struct LweCiphertextVectorPlaintextVectorDiscardingSubtractionFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweCiphertextVectorPlaintextVectorDiscardingSubtractionParameters {
+    pub lwe_ciphertext_count: LweCiphertextCount,
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    /// Whether the plaintexts are sampled close to zero, such that the bodies wrap around it.
+    pub inputs_near_zero: bool,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, Engine, InputCiphertextVector, PlaintextVector, OutputCiphertextVector>
+    Fixture<
+        Precision,
+        Engine,
+        (
+            InputCiphertextVector,
+            PlaintextVector,
+            OutputCiphertextVector,
+        ),
+    > for LweCiphertextVectorPlaintextVectorDiscardingSubtractionFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextVectorPlaintextVectorDiscardingSubtractionEngine<
+        InputCiphertextVector,
+        PlaintextVector,
+        OutputCiphertextVector,
+    >,
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    OutputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = InputCiphertextVector::KeyDistribution>,
+    Maker: SynthesizesPlaintextVector<Precision, PlaintextVector>
+        + SynthesizesLweCiphertextVector<Precision, InputCiphertextVector>
+        + SynthesizesLweCiphertextVector<Precision, OutputCiphertextVector>,
+{
+    type Parameters = LweCiphertextVectorPlaintextVectorDiscardingSubtractionParameters;
+    type RepetitionPrototypes = <Maker as PrototypesLweSecretKey<
+        Precision,
+        InputCiphertextVector::KeyDistribution,
+    >>::LweSecretKeyProto;
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputCiphertextVector::KeyDistribution,
+        >>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputCiphertextVector::KeyDistribution,
+        >>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (
+        InputCiphertextVector,
+        PlaintextVector,
+        OutputCiphertextVector,
+    );
+    type PostExecutionContext = (
+        InputCiphertextVector,
+        PlaintextVector,
+        OutputCiphertextVector,
+    );
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextVectorPlaintextVectorDiscardingSubtractionParameters {
+                    lwe_ciphertext_count: LweCiphertextCount(1),
+                    noise: single_noise(),
+                    lwe_dimension: SINGLE_LWE_DIMENSION,
+                    inputs_near_zero: false,
+                },
+                LweCiphertextVectorPlaintextVectorDiscardingSubtractionParameters {
+                    lwe_ciphertext_count: LweCiphertextCount(100),
+                    noise: single_noise(),
+                    lwe_dimension: SINGLE_LWE_DIMENSION,
+                    inputs_near_zero: false,
+                },
+                LweCiphertextVectorPlaintextVectorDiscardingSubtractionParameters {
+                    lwe_ciphertext_count: LweCiphertextCount(100),
+                    noise: single_noise(),
+                    lwe_dimension: SINGLE_LWE_DIMENSION,
+                    inputs_near_zero: true,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        maker.new_lwe_secret_key(parameters.lwe_dimension)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let proto_secret_key = repetition_proto;
+        let count = parameters.lwe_ciphertext_count.0;
+        let (raw_plaintext_vector, raw_plaintext_vector_sub) = if parameters.inputs_near_zero {
+            (
+                Precision::Raw::uniform_zero_centered_vec(NEAR_ZERO_PLAINTEXT_WIDTH, count),
+                Precision::Raw::uniform_zero_centered_vec(NEAR_ZERO_PLAINTEXT_WIDTH, count),
+            )
+        } else {
+            (
+                Precision::Raw::uniform_vec(count),
+                Precision::Raw::uniform_vec(count),
+            )
+        };
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_plaintext_vector_sub =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector_sub);
+        let proto_input_ciphertext_vector = maker
+            .encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+                proto_secret_key,
+                &proto_plaintext_vector,
+                parameters.noise,
+            );
+        let proto_output_ciphertext_vector = maker
+            .trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+                parameters.lwe_dimension,
+                parameters.lwe_ciphertext_count,
+            );
+        (
+            proto_plaintext_vector,
+            proto_plaintext_vector_sub,
+            proto_input_ciphertext_vector,
+            proto_output_ciphertext_vector,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (
+            _,
+            proto_plaintext_vector_sub,
+            proto_input_ciphertext_vector,
+            proto_output_ciphertext_vector,
+        ) = sample_proto;
+        let synth_input_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_input_ciphertext_vector);
+        let synth_plaintext_vector = maker.synthesize_plaintext_vector(proto_plaintext_vector_sub);
+        let synth_output_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_output_ciphertext_vector);
+        (
+            synth_input_ciphertext_vector,
+            synth_plaintext_vector,
+            synth_output_ciphertext_vector,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext_vector, plaintext_vector, mut output_ciphertext_vector) = context;
+        unsafe {
+            engine.discard_sub_lwe_ciphertext_vector_plaintext_vector_unchecked(
+                &mut output_ciphertext_vector,
+                &input_ciphertext_vector,
+                &plaintext_vector,
+            )
+        };
+        (
+            input_ciphertext_vector,
+            plaintext_vector,
+            output_ciphertext_vector,
+        )
+    }
+
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext_vector, plaintext_vector, mut output_ciphertext_vector) = context;
+        engine
+            .discard_sub_lwe_ciphertext_vector_plaintext_vector(
+                &mut output_ciphertext_vector,
+                &input_ciphertext_vector,
+                &plaintext_vector,
+            )
+            .unwrap();
+        (
+            input_ciphertext_vector,
+            plaintext_vector,
+            output_ciphertext_vector,
+        )
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_ciphertext_vector, plaintext_vector, output_ciphertext_vector) = context;
+        let (proto_plaintext_vector, proto_plaintext_vector_sub, ..) = sample_proto;
+        let proto_secret_key = repetition_proto;
+        let raw_plaintext_vector =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector);
+        let raw_plaintext_vector_sub =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector_sub);
+        let predicted_output = raw_plaintext_vector
+            .iter()
+            .zip(raw_plaintext_vector_sub.iter())
+            .map(|(&a, &b)| a.wrapping_sub(b))
+            .collect();
+        let proto_output_ciphertext_vector =
+            maker.unsynthesize_lwe_ciphertext_vector(&output_ciphertext_vector);
+        let proto_output_plaintext_vector = maker
+            .decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+                proto_secret_key,
+                &proto_output_ciphertext_vector,
+            );
+        maker.destroy_lwe_ciphertext_vector(input_ciphertext_vector);
+        maker.destroy_plaintext_vector(plaintext_vector);
+        maker.destroy_lwe_ciphertext_vector(output_ciphertext_vector);
+        (
+            predicted_output,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (parameters.noise,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+}
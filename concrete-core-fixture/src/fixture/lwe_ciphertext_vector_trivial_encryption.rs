@@ -2,6 +2,7 @@ use crate::fixture::Fixture;
 use crate::generation::prototyping::{PrototypesLweCiphertextVector, PrototypesPlaintextVector};
 use crate::generation::synthesizing::{SynthesizesLweCiphertextVector, SynthesizesPlaintextVector};
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
@@ -9,10 +10,13 @@ use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
 use concrete_core::prelude::{
     LweCiphertextVectorEntity, LweCiphertextVectorTrivialEncryptionEngine, PlaintextVectorEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextVectorTrivialEncryptionEngine` trait.
 pub struct LweCiphertextVectorTrivialEncryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextVectorTrivialEncryptionParameters {
     pub lwe_dimension: LweDimension,
@@ -97,6 +101,21 @@ where
         (plaintext_vector, ciphertext_vector)
     }
 
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (plaintext_vector,) = context;
+        let ciphertext_vector = engine
+            .trivially_encrypt_lwe_ciphertext_vector(
+                parameters.lwe_dimension.to_lwe_size(),
+                &plaintext_vector,
+            )
+            .unwrap();
+        (plaintext_vector, ciphertext_vector)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -134,4 +153,11 @@ where
         let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
 }
@@ -11,12 +11,16 @@ use crate::generation::prototyping::{
 };
 use crate::generation::synthesizing::{SynthesizesLweCiphertextVector, SynthesizesLweSecretKey};
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextVectorZeroEncryptionEngine` trait.
 pub struct LweCiphertextVectorZeroEncryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextVectorZeroEncryptionParameters {
     pub noise: Variance,
@@ -127,6 +131,22 @@ where
         (secret_key, ciphertext_vector)
     }
 
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key,) = context;
+        let ciphertext_vector = engine
+            .zero_encrypt_lwe_ciphertext_vector(
+                &secret_key,
+                parameters.noise,
+                parameters.lwe_ciphertext_count,
+            )
+            .unwrap();
+        (secret_key, ciphertext_vector)
+    }
+
     fn process_context(
         parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -164,4 +184,15 @@ where
         let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
         assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+
+    fn checked_outcome_matches(_checked: &Self::Outcome, _unchecked: &Self::Outcome) -> bool {
+        true
+    }
 }
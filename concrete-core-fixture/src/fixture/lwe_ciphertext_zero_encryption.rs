@@ -11,11 +11,16 @@ use crate::generation::prototyping::{
 };
 use crate::generation::synthesizing::{SynthesizesLweCiphertext, SynthesizesLweSecretKey};
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{STANDARD_LWE_DIMENSIONS, STANDARD_NOISE};
 use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextZeroEncryptionEngine` trait.
 pub struct LweCiphertextZeroEncryptionFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextZeroEncryptionParameters {
     pub noise: Variance,
@@ -41,35 +46,12 @@ where
     type Outcome = (Precision::Raw, Precision::Raw);
 
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
-        Box::new(
-            vec![
-                LweCiphertextZeroEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(100),
-                },
-                LweCiphertextZeroEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(300),
-                },
-                LweCiphertextZeroEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(600),
-                },
-                LweCiphertextZeroEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(1000),
-                },
-                LweCiphertextZeroEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(3000),
-                },
-                LweCiphertextZeroEncryptionParameters {
-                    noise: Variance(0.00000001),
-                    lwe_dimension: LweDimension(6000),
-                },
-            ]
-            .into_iter(),
-        )
+        Box::new(STANDARD_LWE_DIMENSIONS.iter().map(|lwe_dimension| {
+            LweCiphertextZeroEncryptionParameters {
+                noise: STANDARD_NOISE,
+                lwe_dimension: *lwe_dimension,
+            }
+        }))
     }
 
     fn generate_random_repetition_prototypes(
@@ -108,6 +90,18 @@ where
         (secret_key, ciphertext)
     }
 
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key,) = context;
+        let ciphertext = engine
+            .zero_encrypt_lwe_ciphertext(&secret_key, parameters.noise)
+            .unwrap();
+        (secret_key, ciphertext)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -140,4 +134,15 @@ where
         let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+
+    fn checked_outcome_matches(_checked: &Self::Outcome, _unchecked: &Self::Outcome) -> bool {
+        true
+    }
 }
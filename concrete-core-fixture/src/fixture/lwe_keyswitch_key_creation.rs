@@ -7,10 +7,13 @@ use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount
 use concrete_core::prelude::{
     LweKeyswitchKeyCreationEngine, LweKeyswitchKeyEntity, LweSecretKeyEntity,
 };
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweKeyswitchKeyCreationEngine` trait.
 pub struct LweKeyswitchKeyCreationFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweKeyswitchKeyCreationParameters {
     pub noise: Variance,
@@ -114,6 +117,24 @@ where
         (ksk,)
     }
 
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (sk_in, sk_out) = context;
+        let ksk = engine
+            .create_lwe_keyswitch_key(
+                &sk_in,
+                &sk_out,
+                parameters.level,
+                parameters.base_log,
+                parameters.noise,
+            )
+            .unwrap();
+        (ksk,)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -137,4 +158,8 @@ where
     fn verify(_criteria: &Self::Criteria, _outputs: &[Self::Outcome]) -> bool {
         unimplemented!()
     }
+
+    fn checked_outcome_matches(_checked: &Self::Outcome, _unchecked: &Self::Outcome) -> bool {
+        true
+    }
 }
@@ -3,10 +3,13 @@ use crate::generation::synthesizing::SynthesizesLweSecretKey;
 use crate::generation::{IntegerPrecision, Maker};
 use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{LweSecretKeyCreationEngine, LweSecretKeyEntity};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweSecretKeyCreationEngine` trait.
 pub struct LweSecretKeyCreationFixture;
 
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct LweSecretKeyCreationParameters {
     pub lwe_dimension: LweDimension,
@@ -67,6 +70,17 @@ where
         (sk,)
     }
 
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        _context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let sk = engine
+            .create_lwe_secret_key(parameters.lwe_dimension)
+            .unwrap();
+        (sk,)
+    }
+
     fn process_context(
         _parameters: &Self::Parameters,
         maker: &mut Maker,
@@ -90,4 +104,8 @@ where
     fn verify(_criteria: &Self::Criteria, _outputs: &[Self::Outcome]) -> bool {
         unimplemented!()
     }
+
+    fn checked_outcome_matches(_checked: &Self::Outcome, _unchecked: &Self::Outcome) -> bool {
+        true
+    }
 }
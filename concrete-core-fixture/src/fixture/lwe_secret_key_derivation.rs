@@ -0,0 +1,202 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{PrototypesLweSecretKey, PrototypesLweSecretKeyDerivation};
+use crate::generation::synthesizing::SynthesizesLweSecretKey;
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::parameters::{ClientId, LweDimension, MasterSeed};
+use concrete_core::prelude::markers::BinaryKeyDistribution;
+use concrete_core::prelude::{
+    EntityConstantTimeEqualityEngine, LweSecretKeyDerivationEngine, LweSecretKeyEntity,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `LweSecretKeyDerivationEngine` trait.
+///
+/// Every sample derives a key from a master seed and a client id, and compares it with:
+/// + a key derived again from the same inputs, which is equal;
+/// + a key derived from the same inputs by the engine of the maker, which is equal;
+/// + a key derived for the next client id, which is different;
+/// + a key derived from the next master seed, which is different.
+pub struct LweSecretKeyDerivationFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweSecretKeyDerivationParameters {
+    pub lwe_dimension: LweDimension,
+}
+
+impl<Precision, Engine, SecretKey> Fixture<Precision, Engine, (SecretKey,)>
+    for LweSecretKeyDerivationFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweSecretKeyDerivationEngine<SecretKey> + EntityConstantTimeEqualityEngine<SecretKey>,
+    SecretKey: LweSecretKeyEntity<KeyDistribution = BinaryKeyDistribution>,
+    Maker: SynthesizesLweSecretKey<Precision, SecretKey>
+        + PrototypesLweSecretKeyDerivation<Precision, BinaryKeyDistribution>,
+{
+    type Parameters = LweSecretKeyDerivationParameters;
+    type RepetitionPrototypes = ();
+    type SamplePrototypes = (
+        u128,
+        u64,
+        <Maker as PrototypesLweSecretKey<Precision, BinaryKeyDistribution>>::LweSecretKeyProto,
+    );
+    type PreExecutionContext = (MasterSeed, ClientId, SecretKey);
+    type PostExecutionContext = (Vec<SecretKey>, Vec<bool>);
+    type Criteria = (Vec<bool>,);
+    type Outcome = Vec<bool>;
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSecretKeyDerivationParameters {
+                    lwe_dimension: LweDimension(1024),
+                },
+                LweSecretKeyDerivationParameters {
+                    lwe_dimension: LweDimension(100),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let raw_master_seed = u128::uniform();
+        let raw_client_id = u64::uniform();
+        let proto_secret_key = maker.derive_lwe_secret_key(
+            MasterSeed(raw_master_seed),
+            ClientId(raw_client_id),
+            parameters.lwe_dimension,
+        );
+        (raw_master_seed, raw_client_id, proto_secret_key)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (raw_master_seed, raw_client_id, proto_secret_key) = sample_proto;
+        (
+            MasterSeed(*raw_master_seed),
+            ClientId(*raw_client_id),
+            maker.synthesize_lwe_secret_key(proto_secret_key),
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (master_seed, client_id, maker_key) = context;
+        let keys = derivation_inputs(master_seed, client_id)
+            .into_iter()
+            .map(|(master_seed, client_id)| unsafe {
+                engine.derive_lwe_secret_key_unchecked(
+                    master_seed,
+                    client_id,
+                    parameters.lwe_dimension,
+                )
+            })
+            .collect::<Vec<_>>();
+        let equalities = compare_keys(engine, &keys, &maker_key);
+        (vec_with(keys, maker_key), equalities)
+    }
+
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (master_seed, client_id, maker_key) = context;
+        let keys = derivation_inputs(master_seed, client_id)
+            .into_iter()
+            .map(|(master_seed, client_id)| {
+                engine
+                    .derive_lwe_secret_key(master_seed, client_id, parameters.lwe_dimension)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let equalities = compare_keys(engine, &keys, &maker_key);
+        (vec_with(keys, maker_key), equalities)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (keys, equalities) = context;
+        for key in keys {
+            maker.destroy_lwe_secret_key(key);
+        }
+        equalities
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (vec![true, true, false, false],)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (expected,) = criteria;
+        outputs.iter().all(|equalities| equalities == expected)
+    }
+}
+
+// Returns the inputs of the derived keys: the inputs of the sample twice, then the next client id,
+// and the next master seed.
+fn derivation_inputs(master_seed: MasterSeed, client_id: ClientId) -> Vec<(MasterSeed, ClientId)> {
+    vec![
+        (master_seed, client_id),
+        (master_seed, client_id),
+        (master_seed, ClientId(client_id.0.wrapping_add(1))),
+        (MasterSeed(master_seed.0.wrapping_add(1)), client_id),
+    ]
+}
+
+// Compares the first derived key with the other ones, and with the key derived by the maker.
+fn compare_keys<Engine, SecretKey>(
+    engine: &mut Engine,
+    keys: &[SecretKey],
+    maker_key: &SecretKey,
+) -> Vec<bool>
+where
+    Engine: EntityConstantTimeEqualityEngine<SecretKey>,
+    SecretKey: LweSecretKeyEntity,
+{
+    let (first, others) = keys.split_first().unwrap();
+    let mut equalities = vec![
+        engine.constant_time_eq(first, &others[0]).unwrap(),
+        engine.constant_time_eq(first, maker_key).unwrap(),
+    ];
+    equalities.extend(
+        others[1..]
+            .iter()
+            .map(|other| engine.constant_time_eq(first, other).unwrap()),
+    );
+    equalities
+}
+
+fn vec_with<T>(mut values: Vec<T>, value: T) -> Vec<T> {
+    values.push(value);
+    values
+}
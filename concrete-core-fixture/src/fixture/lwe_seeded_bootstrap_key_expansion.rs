@@ -0,0 +1,362 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweSecretKey, PrototypesLweCiphertext,
+    PrototypesLweSecretKey, PrototypesLweSeededBootstrapKey, PrototypesPlaintext,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweCiphertext, SynthesizesLweBootstrapKey, SynthesizesLweCiphertext,
+    SynthesizesLweSeededBootstrapKey,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::standard_bootstrap_presets;
+use crate::raw::statistical_test::assert_delta_std_dev;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::Numeric;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use concrete_core::prelude::{
+    GlweCiphertextEntity, LweBootstrapKeyConversionEngine, LweBootstrapKeyEntity,
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextEntity, LweSeededBootstrapKeyEntity,
+    LweSeededBootstrapKeyExpansionEngine,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `LweSeededBootstrapKeyExpansionEngine` trait.
+///
+/// The expanded keys are converted to the Fourier domain and used to bootstrap fresh ciphertexts,
+/// whose output noise is checked against the prediction made for a standard bootstrap key.
+pub struct LweSeededBootstrapKeyExpansionFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweSeededBootstrapKeyExpansionParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub poly_size: PolynomialSize,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub decomp_base_log: DecompositionBaseLog,
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        Engine,
+        SeededBootstrapKey,
+        BootstrapKey,
+        FourierBootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertext,
+    >
+    Fixture<
+        Precision,
+        Engine,
+        (
+            SeededBootstrapKey,
+            BootstrapKey,
+            FourierBootstrapKey,
+            Accumulator,
+            InputCiphertext,
+            OutputCiphertext,
+        ),
+    > for LweSeededBootstrapKeyExpansionFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweSeededBootstrapKeyExpansionEngine<SeededBootstrapKey, BootstrapKey>
+        + LweBootstrapKeyConversionEngine<BootstrapKey, FourierBootstrapKey>
+        + LweCiphertextDiscardingBootstrapEngine<
+            FourierBootstrapKey,
+            Accumulator,
+            InputCiphertext,
+            OutputCiphertext,
+        >,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+    Accumulator: GlweCiphertextEntity<KeyDistribution = OutputCiphertext::KeyDistribution>,
+    SeededBootstrapKey: LweSeededBootstrapKeyEntity<
+        InputKeyDistribution = InputCiphertext::KeyDistribution,
+        OutputKeyDistribution = OutputCiphertext::KeyDistribution,
+    >,
+    BootstrapKey: LweBootstrapKeyEntity<
+        InputKeyDistribution = InputCiphertext::KeyDistribution,
+        OutputKeyDistribution = OutputCiphertext::KeyDistribution,
+    >,
+    FourierBootstrapKey: LweBootstrapKeyEntity<
+        InputKeyDistribution = InputCiphertext::KeyDistribution,
+        OutputKeyDistribution = OutputCiphertext::KeyDistribution,
+    >,
+    Maker: SynthesizesLweSeededBootstrapKey<Precision, SeededBootstrapKey>
+        + SynthesizesLweBootstrapKey<Precision, BootstrapKey>
+        + SynthesizesLweBootstrapKey<Precision, FourierBootstrapKey>
+        + SynthesizesGlweCiphertext<Precision, Accumulator>
+        + SynthesizesLweCiphertext<Precision, InputCiphertext>
+        + SynthesizesLweCiphertext<Precision, OutputCiphertext>,
+{
+    type Parameters = LweSeededBootstrapKeyExpansionParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweCiphertext<Precision, OutputCiphertext::KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesLweSecretKey<Precision, InputCiphertext::KeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, OutputCiphertext::KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesLweSeededBootstrapKey<Precision, InputCiphertext::KeyDistribution, OutputCiphertext::KeyDistribution>>::LweSeededBootstrapKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, InputCiphertext::KeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesLweCiphertext<Precision, OutputCiphertext::KeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (
+        SeededBootstrapKey,
+        Accumulator,
+        OutputCiphertext,
+        InputCiphertext,
+    );
+    type PostExecutionContext = (
+        SeededBootstrapKey,
+        BootstrapKey,
+        FourierBootstrapKey,
+        Accumulator,
+        OutputCiphertext,
+        InputCiphertext,
+    );
+    type Criteria = (Variance,);
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        // The smallest preset is enough to check the expanded keys, and keeps the key creation
+        // of each repetition affordable.
+        Box::new(
+            standard_bootstrap_presets()
+                .into_iter()
+                .take(1)
+                .map(|preset| LweSeededBootstrapKeyExpansionParameters {
+                    noise: preset.noise,
+                    lwe_dimension: preset.lwe_dimension,
+                    glwe_dimension: preset.glwe_dimension,
+                    poly_size: preset.poly_size,
+                    decomp_level_count: preset.decomp_level_count,
+                    decomp_base_log: preset.decomp_base_log,
+                }),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let raw_plaintext_vector =
+            vec![Precision::Raw::ONE << (Precision::Raw::BITS - 3); parameters.poly_size.0];
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_accumulator = maker.trivially_encrypt_plaintext_vector_to_glwe_ciphertext(
+            parameters.glwe_dimension,
+            &proto_plaintext_vector,
+        );
+        let proto_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            InputCiphertext::KeyDistribution,
+        >>::new_lwe_secret_key(maker, parameters.lwe_dimension);
+        let proto_glwe_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputCiphertext::KeyDistribution,
+        >>::new_glwe_secret_key(
+            maker, parameters.glwe_dimension, parameters.poly_size
+        );
+        let proto_bootstrap_key = maker.new_lwe_seeded_bootstrap_key(
+            &proto_lwe_secret_key,
+            &proto_glwe_secret_key,
+            parameters.decomp_level_count,
+            parameters.decomp_base_log,
+            parameters.noise,
+        );
+        (
+            proto_accumulator,
+            proto_lwe_secret_key,
+            proto_glwe_secret_key,
+            proto_bootstrap_key,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (_, proto_lwe_secret_key, ..) = repetition_proto;
+        let raw_plaintext = Precision::Raw::ONE << (Precision::Raw::BITS - 2);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_input_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputCiphertext::KeyDistribution,
+        >>::encrypt_plaintext_to_lwe_ciphertext(
+            maker,
+            proto_lwe_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+        );
+        let proto_output_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            OutputCiphertext::KeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            maker,
+            LweDimension(parameters.glwe_dimension.0 * parameters.poly_size.0),
+        );
+        (
+            proto_plaintext,
+            proto_input_ciphertext,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_accumulator, _, _, proto_bootstrap_key) = repetition_proto;
+        let (_, proto_input_ciphertext, proto_output_ciphertext) = sample_proto;
+        let synth_bootstrap_key = maker.synthesize_lwe_seeded_bootstrap_key(proto_bootstrap_key);
+        let synth_accumulator = maker.synthesize_glwe_ciphertext(proto_accumulator);
+        let synth_input_ciphertext = maker.synthesize_lwe_ciphertext(proto_input_ciphertext);
+        let synth_output_ciphertext = maker.synthesize_lwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_bootstrap_key,
+            synth_accumulator,
+            synth_output_ciphertext,
+            synth_input_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (seeded_bootstrap_key, accumulator, mut output_ciphertext, input_ciphertext) = context;
+        let bootstrap_key =
+            unsafe { engine.expand_lwe_seeded_bootstrap_key_unchecked(&seeded_bootstrap_key) };
+        let fourier_bootstrap_key =
+            unsafe { engine.convert_lwe_bootstrap_key_unchecked(&bootstrap_key) };
+        unsafe {
+            engine.discard_bootstrap_lwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &accumulator,
+                &fourier_bootstrap_key,
+            )
+        };
+        (
+            seeded_bootstrap_key,
+            bootstrap_key,
+            fourier_bootstrap_key,
+            accumulator,
+            output_ciphertext,
+            input_ciphertext,
+        )
+    }
+
+    fn execute_engine_checked(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (seeded_bootstrap_key, accumulator, mut output_ciphertext, input_ciphertext) = context;
+        let bootstrap_key = engine
+            .expand_lwe_seeded_bootstrap_key(&seeded_bootstrap_key)
+            .unwrap();
+        let fourier_bootstrap_key = engine.convert_lwe_bootstrap_key(&bootstrap_key).unwrap();
+        engine
+            .discard_bootstrap_lwe_ciphertext(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &accumulator,
+                &fourier_bootstrap_key,
+            )
+            .unwrap();
+        (
+            seeded_bootstrap_key,
+            bootstrap_key,
+            fourier_bootstrap_key,
+            accumulator,
+            output_ciphertext,
+            input_ciphertext,
+        )
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (
+            seeded_bootstrap_key,
+            bootstrap_key,
+            fourier_bootstrap_key,
+            accumulator,
+            output_ciphertext,
+            input_ciphertext,
+        ) = context;
+        let (_, _, proto_glwe_secret_key, _) = repetition_proto;
+        let (proto_plaintext, ..) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(&output_ciphertext);
+        let proto_output_lwe_secret_key =
+            maker.transmute_glwe_secret_key_to_lwe_secret_key(proto_glwe_secret_key);
+        let proto_output_plaintext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            OutputCiphertext::KeyDistribution,
+        >>::decrypt_lwe_ciphertext_to_plaintext(
+            maker,
+            &proto_output_lwe_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_lwe_ciphertext(input_ciphertext);
+        maker.destroy_lwe_ciphertext(output_ciphertext);
+        maker.destroy_lwe_seeded_bootstrap_key(seeded_bootstrap_key);
+        maker.destroy_lwe_bootstrap_key(bootstrap_key);
+        maker.destroy_lwe_bootstrap_key(fourier_bootstrap_key);
+        maker.destroy_glwe_ciphertext(accumulator);
+        (
+            maker.transform_plaintext_to_raw(proto_plaintext),
+            maker.transform_plaintext_to_raw(&proto_output_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let predicted_variance: Variance = concrete_npe::estimate_pbs_noise::<
+            Precision::Raw,
+            Variance,
+            OutputCiphertext::KeyDistribution,
+        >(
+            parameters.lwe_dimension,
+            parameters.poly_size,
+            parameters.glwe_dimension,
+            parameters.decomp_base_log,
+            parameters.decomp_level_count,
+            parameters.noise,
+        );
+        (predicted_variance,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        assert_delta_std_dev(&actual, means.as_slice(), criteria.0)
+    }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+}
@@ -0,0 +1,199 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertext, PrototypesLweSecretKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweCiphertext, SynthesizesLweSecretKey, SynthesizesLweSeededCiphertext,
+    SynthesizesPlaintext,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::NoiseMeasurement;
+use crate::presets::{
+    near_zero_noise, reduced_precision_noise, REDUCED_PRECISION_BITS, SINGLE_LWE_DIMENSION,
+    STANDARD_LWE_DIMENSIONS, STANDARD_NOISE, ZERO_NOISE,
+};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::Numeric;
+use concrete_commons::parameters::LweDimension;
+use concrete_core::prelude::{
+    LweCiphertextEntity, LweSecretKeyEntity, LweSeededCiphertextEncryptionEngine,
+    LweSeededCiphertextEntity, LweSeededCiphertextExpansionEngine, PlaintextEntity,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A fixture for the types implementing the `LweSeededCiphertextEncryptionEngine` trait.
+///
+/// The seeded ciphertexts are expanded with the `LweSeededCiphertextExpansionEngine` of the same
+/// engine before being decrypted, so that the noise of the round trip is checked.
+pub struct LweSeededCiphertextEncryptionFixture;
+
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct LweSeededCiphertextEncryptionParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+}
+
+impl<Precision, Engine, Plaintext, SecretKey, SeededCiphertext, Ciphertext>
+    Fixture<Precision, Engine, (Plaintext, SecretKey, SeededCiphertext, Ciphertext)>
+    for LweSeededCiphertextEncryptionFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweSeededCiphertextEncryptionEngine<SecretKey, Plaintext, SeededCiphertext>
+        + LweSeededCiphertextExpansionEngine<SeededCiphertext, Ciphertext>,
+    Plaintext: PlaintextEntity,
+    SecretKey: LweSecretKeyEntity,
+    SeededCiphertext: LweSeededCiphertextEntity<KeyDistribution = SecretKey::KeyDistribution>,
+    Ciphertext: LweCiphertextEntity<KeyDistribution = SecretKey::KeyDistribution>,
+    Maker: SynthesizesPlaintext<Precision, Plaintext>
+        + SynthesizesLweSecretKey<Precision, SecretKey>
+        + SynthesizesLweSeededCiphertext<Precision, SeededCiphertext>
+        + SynthesizesLweCiphertext<Precision, Ciphertext>,
+{
+    type Parameters = LweSeededCiphertextEncryptionParameters;
+    type RepetitionPrototypes = (<Maker as PrototypesLweSecretKey<Precision, Ciphertext::KeyDistribution>>::LweSecretKeyProto, );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        Precision::Raw,
+    );
+    type PreExecutionContext = (Plaintext, SecretKey);
+    type PostExecutionContext = (Plaintext, SecretKey, SeededCiphertext, Ciphertext);
+    type Criteria = (Variance,);
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        let noise = if Precision::Raw::BITS <= REDUCED_PRECISION_BITS {
+            reduced_precision_noise()
+        } else {
+            STANDARD_NOISE
+        };
+        let standard = STANDARD_LWE_DIMENSIONS.iter().map(move |lwe_dimension| {
+            LweSeededCiphertextEncryptionParameters {
+                noise,
+                lwe_dimension: *lwe_dimension,
+            }
+        });
+        // The noiseless encryptions must yield the plaintexts exactly.
+        let edge_cases = vec![ZERO_NOISE, near_zero_noise()]
+            .into_iter()
+            .map(|noise| LweSeededCiphertextEncryptionParameters {
+                noise,
+                lwe_dimension: SINGLE_LWE_DIMENSION,
+            });
+        Box::new(standard.chain(edge_cases))
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let raw_plaintext = Precision::Raw::uniform();
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        (proto_plaintext, raw_plaintext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext, _) = sample_proto;
+        let synth_plaintext = maker.synthesize_plaintext(proto_plaintext);
+        let synth_secret_key = maker.synthesize_lwe_secret_key(proto_secret_key);
+        (synth_plaintext, synth_secret_key)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (plaintext, secret_key) = context;
+        let seeded_ciphertext = unsafe {
+            engine.encrypt_lwe_seeded_ciphertext_unchecked(
+                &secret_key,
+                &plaintext,
+                parameters.noise,
+            )
+        };
+        let ciphertext =
+            unsafe { engine.expand_lwe_seeded_ciphertext_unchecked(&seeded_ciphertext) };
+        (plaintext, secret_key, seeded_ciphertext, ciphertext)
+    }
+
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (plaintext, secret_key) = context;
+        let seeded_ciphertext = engine
+            .encrypt_lwe_seeded_ciphertext(&secret_key, &plaintext, parameters.noise)
+            .unwrap();
+        let ciphertext = engine
+            .expand_lwe_seeded_ciphertext(&seeded_ciphertext)
+            .unwrap();
+        (plaintext, secret_key, seeded_ciphertext, ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (plaintext, secret_key, seeded_ciphertext, ciphertext) = context;
+        let (proto_secret_key,) = repetition_proto;
+        let (_, raw_plaintext) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(&ciphertext);
+        maker.destroy_lwe_ciphertext(ciphertext);
+        maker.destroy_lwe_seeded_ciphertext(seeded_ciphertext);
+        maker.destroy_plaintext(plaintext);
+        maker.destroy_lwe_secret_key(secret_key);
+        let proto_plaintext =
+            maker.decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, &proto_output_ciphertext);
+        (
+            *raw_plaintext,
+            maker.transform_plaintext_to_raw(&proto_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (parameters.noise,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+
+    fn measure_noise(
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        Some(NoiseMeasurement::from_outcomes(criteria.0, outputs))
+    }
+
+    fn checked_outcome_matches(_checked: &Self::Outcome, _unchecked: &Self::Outcome) -> bool {
+        true
+    }
+}
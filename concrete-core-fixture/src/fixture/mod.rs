@@ -15,7 +15,7 @@
 //! | | | | Compute raw outcome in a form that can be tested
 //! | | | | Collect and dispose of the entities
 //! | | | Compute verification criteria
-//! | | | Verify that the repetition sample outcomes match the criteria
+//! | Verify that the outcomes of every repetition match its criteria
 //! ```
 //!
 //! Note that this structure allows the generated data to be used for multiple executions or not.
@@ -31,10 +31,133 @@
 //!
 //! In particular, once the [`Fixture`] mandatory methods and types are defined, the user can
 //! benefit from the default methods [`Fixture::sample`], [`Fixture::test`] or [`Fixture::stress`].
+//! The [`Fixture::stress_all_parameters_with_budget`] method additionally checks the execution
+//! times of the engine against a [`TimingBudget`], and the [`Fixture::differential`] method
+//! compares the outcomes of the engine with the ones of a reference engine executed on the same
+//! inputs.
+//!
+//! The fixtures are also benchmarks: the [`BenchmarkFixture`](crate::benchmark::BenchmarkFixture)
+//! trait, implemented for every fixture, times the executions of the engine for every set of
+//! parameters.
+//!
+//! The engines are executed through their `*_unchecked` entry points by default. Depending on the
+//! [`ExecutionMode`] found in the environment, the checked entry points are executed as well, and
+//! must succeed on the valid inputs generated by the fixtures, with identical outcomes. The runs
+//! whose execution times are checked against a [`TimingBudget`] only execute the unchecked entry
+//! points, unless the environment says otherwise.
+//!
+//! The outcomes of a repetition are grouped in an [`OutcomeGroup`], along with the criteria and
+//! the [`RepetitionMetadata`] of the repetition. The groups of all the repetitions of a set of
+//! parameters are verified at once by [`Fixture::verify_groups`], which allows a fixture to compare
+//! the repetitions with each other, and the harness to report which repetition failed.
+//!
+//! With the `snapshot` feature, the failing repetitions can be exported to files, and replayed
+//! with the [`Fixture::replay`] method (see the [`snapshot`](crate::snapshot) module).
 use crate::generation::{IntegerPrecision, Maker};
+use crate::noise_export::{noise_directory, NoiseMeasurement, NoiseReport};
+use crate::snapshot::Snapshottable;
+#[cfg(feature = "snapshot")]
+use crate::snapshot::{
+    snapshot_directory, snapshot_path, RepetitionSnapshot, SNAPSHOT_FORMAT_VERSION,
+};
+use crate::timing::{fixture_key, short_type_name, TimingBudget, Timings};
 use crate::{Repetitions, SampleSize};
 use concrete_core::prelude::AbstractEngine;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{Debug, Display, Formatter};
 use std::ops::BitAnd;
+use std::time::Instant;
+
+/// The environment variable containing the execution mode of the fixtures.
+pub const EXECUTION_MODE_VARIABLE: &str = "CONCRETE_FIXTURE_EXECUTION_MODE";
+
+/// The entry points through which the fixtures execute the engines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionMode {
+    /// Only the `*_unchecked` entry points are executed.
+    Unchecked,
+    /// Only the checked entry points are executed.
+    Checked,
+    /// Both entry points are executed on the same inputs, and their outcomes are compared. Only
+    /// the unchecked executions are timed.
+    Both,
+}
+
+impl ExecutionMode {
+    /// Returns the execution mode contained in the [`EXECUTION_MODE_VARIABLE`] variable, one of
+    /// `unchecked`, `checked` or `both`. When the variable is not set, both entry points are
+    /// executed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the variable contains another value.
+    pub fn from_env() -> ExecutionMode {
+        ExecutionMode::from_env_or(ExecutionMode::Both)
+    }
+
+    /// Returns the execution mode contained in the [`EXECUTION_MODE_VARIABLE`] variable, or
+    /// `default` when the variable is not set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the variable contains another value.
+    pub fn from_env_or(default: ExecutionMode) -> ExecutionMode {
+        match std::env::var(EXECUTION_MODE_VARIABLE) {
+            Ok(mode) => match mode.trim() {
+                "unchecked" => ExecutionMode::Unchecked,
+                "checked" => ExecutionMode::Checked,
+                "both" => ExecutionMode::Both,
+                _ => panic!(
+                    "Invalid execution mode in {}: `{}`. Expected one of `unchecked`, `checked` \
+                    or `both`.",
+                    EXECUTION_MODE_VARIABLE, mode
+                ),
+            },
+            Err(_) => default,
+        }
+    }
+}
+
+/// The metadata attached to the outcomes of a repetition.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RepetitionMetadata {
+    /// The index of the repetition, among the repetitions of a set of parameters.
+    pub repetition_index: usize,
+    /// The seed of the maker which generated the prototypes, if it was created with
+    /// [`Maker::new_seeded`].
+    pub seed: Option<u128>,
+    /// The identifier of the repetition-level prototypes (such as the keys), unique for the maker
+    /// which generated them.
+    pub key_id: u64,
+}
+
+impl Display for RepetitionMetadata {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "repetition {} (key id: {}",
+            self.repetition_index, self.key_id
+        )?;
+        match self.seed {
+            Some(seed) => write!(f, ", maker seed: {})", seed),
+            None => write!(f, ", unseeded maker)"),
+        }
+    }
+}
+
+/// The outcomes of the samples of a repetition, along with the criteria and the metadata of the
+/// repetition.
+#[derive(Debug)]
+pub struct OutcomeGroup<Criteria, Outcome> {
+    /// The metadata of the repetition.
+    pub metadata: RepetitionMetadata,
+    /// The criteria computed for the repetition.
+    pub criteria: Criteria,
+    /// The outcomes of the samples of the repetition.
+    pub outcomes: Vec<Outcome>,
+}
 
 /// A trait for types implementing a fixture for a particular engine trait.
 ///
@@ -42,13 +165,13 @@ use std::ops::BitAnd;
 /// `stress` and `stress_all` use the associated types and methods.
 pub trait Fixture<Precision: IntegerPrecision, Engine: AbstractEngine, RelatedEntities> {
     /// A type containing the parameters needed to generate the execution context.
-    type Parameters;
+    type Parameters: Snapshottable + Debug + 'static;
 
     /// A type containing the input prototypes generated at the level of the repetition (reused).
-    type RepetitionPrototypes;
+    type RepetitionPrototypes: Snapshottable;
 
     /// A type containing the input prototypes generated at the level of the sample (not reused).
-    type SamplePrototypes;
+    type SamplePrototypes: Snapshottable;
 
     /// A type containing all the objects which must exist for the engine to be executed.
     type PreExecutionContext;
@@ -60,11 +183,33 @@ pub trait Fixture<Precision: IntegerPrecision, Engine: AbstractEngine, RelatedEn
     type Criteria;
 
     /// A type containing the outcome of an execution, such as it can be analyzed for correctness.
-    type Outcome;
+    type Outcome: Snapshottable + PartialEq;
 
     /// A method which outputs an iterator over parameters.
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>>;
 
+    /// A method which outputs an iterator over the parameters the fixture is executed with.
+    ///
+    /// With the `parameter_files` feature, the parameters are loaded from the file of the fixture
+    /// found in the directory given by the environment, if any (see the `parameter_grid` module).
+    /// Otherwise, the parameters of [`Fixture::generate_parameters_iterator`] are used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameter file of the fixture can not be loaded.
+    fn parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        #[cfg(feature = "parameter_files")]
+        {
+            let fixture = short_type_name(std::any::type_name::<Self>());
+            if let Some(path) = crate::parameter_grid::parameter_file(&fixture) {
+                let parameters = crate::parameter_grid::load_parameters(&path)
+                    .unwrap_or_else(|error| panic!("{} ({})", error, path.display()));
+                return Box::new(parameters.into_iter());
+            }
+        }
+        Self::generate_parameters_iterator()
+    }
+
     /// Generate a random set of repetition-level prototypes.
     fn generate_random_repetition_prototypes(
         parameters: &Self::Parameters,
@@ -97,6 +242,20 @@ pub trait Fixture<Precision: IntegerPrecision, Engine: AbstractEngine, RelatedEn
         context: Self::PreExecutionContext,
     ) -> Self::PostExecutionContext;
 
+    /// A method which executes the checked entry point of the engine in the pre-execution
+    /// context, returning the post-execution context.
+    ///
+    /// The inputs generated by the fixture being valid, this method panics if the checks of the
+    /// engine fail. The default implementation falls back to [`Fixture::execute_engine`], for the
+    /// fixtures which do not exercise the checked entry point.
+    fn execute_engine_checked(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        Self::execute_engine(parameters, engine, context)
+    }
+
     /// A method which processes the post-execution context, and returns the raw outputs.
     fn process_context(
         parameters: &Self::Parameters,
@@ -116,6 +275,42 @@ pub trait Fixture<Precision: IntegerPrecision, Engine: AbstractEngine, RelatedEn
     /// A method which verify that the outcomes verify some criteria.
     fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool;
 
+    /// A method which measures the noise of the outcomes of a repetition, for the fixtures
+    /// checking a noise distribution.
+    ///
+    /// The measurements are exported by [`Fixture::stress`] when a directory is given by the
+    /// environment (see the [`noise_export`](crate::noise_export) module). The default
+    /// implementation measures nothing.
+    fn measure_noise(
+        _criteria: &Self::Criteria,
+        _outputs: &[Self::Outcome],
+    ) -> Option<NoiseMeasurement> {
+        None
+    }
+
+    /// A method which verifies the outcomes of all the repetitions of a set of parameters, and
+    /// returns whether each group passed the verification, in the same order.
+    ///
+    /// The default implementation verifies every group independently with [`Fixture::verify`].
+    /// The fixtures can override it to compare the repetitions with each other, for instance to
+    /// detect a single repetition whose key behaves differently from the others.
+    fn verify_groups(groups: &[OutcomeGroup<Self::Criteria, Self::Outcome>]) -> Vec<bool> {
+        groups
+            .iter()
+            .map(|group| Self::verify(&group.criteria, group.outcomes.as_slice()))
+            .collect()
+    }
+
+    /// A method which checks that the outcomes of the checked and unchecked executions of the
+    /// engine on the same inputs are identical.
+    ///
+    /// The default implementation compares the outcomes for equality. The fixtures of the engines
+    /// sampling randomness (such as the encryptions), which can not produce identical outcomes,
+    /// override it.
+    fn checked_outcome_matches(checked: &Self::Outcome, unchecked: &Self::Outcome) -> bool {
+        checked == unchecked
+    }
+
     /// A method which verifies the statistical properties of a sample of engine executions, over
     /// multiple randomly generated raw inputs, over multiple sets of parameters.
     fn stress_all_parameters(
@@ -124,12 +319,47 @@ pub trait Fixture<Precision: IntegerPrecision, Engine: AbstractEngine, RelatedEn
         repetitions: Repetitions,
         sample_size: SampleSize,
     ) -> bool {
-        Self::generate_parameters_iterator()
+        Self::parameters_iterator()
             .map(|param| Self::stress(maker, engine, &param, repetitions, sample_size))
             .reduce(BitAnd::bitand)
             .unwrap()
     }
 
+    /// A method which verifies the statistical properties of a sample of engine executions, over
+    /// multiple randomly generated raw inputs, over multiple sets of parameters, and checks the
+    /// mean execution time of the engine for every set of parameters against a timing budget.
+    fn stress_all_parameters_with_budget(
+        maker: &mut Maker,
+        engine: &mut Engine,
+        repetitions: Repetitions,
+        sample_size: SampleSize,
+        budget: &TimingBudget,
+    ) -> bool
+    where
+        Self: Sized,
+    {
+        let key = fixture_key::<Self, Precision, RelatedEntities>();
+        // The checked executions would double the duration of the runs.
+        let mode = ExecutionMode::from_env_or(ExecutionMode::Unchecked);
+        Self::parameters_iterator()
+            .enumerate()
+            .map(|(index, param)| {
+                let mut timings = Timings::default();
+                let correct = Self::stress_timed(
+                    maker,
+                    engine,
+                    &param,
+                    repetitions,
+                    sample_size,
+                    &mut timings,
+                    mode,
+                );
+                correct & budget.check(&key, index, &timings)
+            })
+            .reduce(BitAnd::bitand)
+            .unwrap()
+    }
+
     /// A method which verifies the statistical properties of a sample of engine executions, over
     /// multiple randomly generated raw inputs, for a fixed set of parameters.
     fn stress(
@@ -139,21 +369,126 @@ pub trait Fixture<Precision: IntegerPrecision, Engine: AbstractEngine, RelatedEn
         repetitions: Repetitions,
         sample_size: SampleSize,
     ) -> bool {
-        for _ in 0..repetitions.0 {
+        Self::stress_timed(
+            maker,
+            engine,
+            parameters,
+            repetitions,
+            sample_size,
+            &mut Timings::default(),
+            ExecutionMode::from_env(),
+        )
+    }
+
+    /// A method which does the same as [`Fixture::stress`], executing the engine in `mode`, and
+    /// records the duration of the engine executions in `timings`.
+    fn stress_timed(
+        maker: &mut Maker,
+        engine: &mut Engine,
+        parameters: &Self::Parameters,
+        repetitions: Repetitions,
+        sample_size: SampleSize,
+        timings: &mut Timings,
+        mode: ExecutionMode,
+    ) -> bool {
+        #[cfg(feature = "snapshot")]
+        let directory = snapshot_directory();
+        #[cfg(feature = "snapshot")]
+        let mut recorded_prototypes = Vec::new();
+        let mut groups = Vec::with_capacity(repetitions.0);
+        for repetition_index in 0..repetitions.0 {
             let repetition_prototypes =
                 Self::generate_random_repetition_prototypes(parameters, maker);
-            let output = Self::test(
+            let metadata = RepetitionMetadata {
+                repetition_index,
+                seed: maker.seed(),
+                key_id: maker.next_key_id(),
+            };
+            #[cfg(feature = "snapshot")]
+            let mut sample_prototypes = directory.as_ref().map(|_| Vec::new());
+            #[cfg(not(feature = "snapshot"))]
+            let mut sample_prototypes = None;
+            let outcomes = Self::sample_recorded(
                 maker,
                 engine,
                 parameters,
                 &repetition_prototypes,
                 sample_size,
+                timings,
+                mode,
+                sample_prototypes.as_mut(),
             );
-            if !output {
-                return false;
+            let criteria = Self::compute_criteria(parameters, maker, &repetition_prototypes);
+            groups.push(OutcomeGroup {
+                metadata,
+                criteria,
+                outcomes,
+            });
+            // The prototypes are only kept when the failing repetitions can be exported.
+            #[cfg(feature = "snapshot")]
+            if let Some(sample_prototypes) = sample_prototypes {
+                recorded_prototypes.push((repetition_prototypes, sample_prototypes));
             }
         }
-        true
+        let verdicts = Self::verify_groups(groups.as_slice());
+        debug_assert_eq!(verdicts.len(), groups.len());
+        if let Some(directory) = noise_directory() {
+            let repetitions: Vec<_> = groups
+                .iter()
+                .filter_map(|group| {
+                    Self::measure_noise(&group.criteria, group.outcomes.as_slice())
+                        .map(|measurement| (group.metadata, measurement))
+                })
+                .collect();
+            if !repetitions.is_empty() {
+                let report = NoiseReport {
+                    engine: short_type_name(std::any::type_name::<Engine>()),
+                    fixture: fixture_key::<Self, Precision, RelatedEntities>(),
+                    parameters: format!("{:?}", parameters),
+                    repetitions,
+                };
+                if let Err(error) = report.export(&directory) {
+                    eprintln!("Failed to export the noise measurements: {}", error);
+                }
+            }
+        }
+        #[cfg(feature = "snapshot")]
+        let mut recorded_prototypes = recorded_prototypes.into_iter();
+        let mut passed = true;
+        for (group, verdict) in groups.into_iter().zip(verdicts) {
+            #[cfg(feature = "snapshot")]
+            let recorded = recorded_prototypes.next();
+            if verdict {
+                continue;
+            }
+            passed = false;
+            eprintln!(
+                "The outcomes of {} of {} did not match the criteria.",
+                group.metadata,
+                fixture_key::<Self, Precision, RelatedEntities>()
+            );
+            #[cfg(feature = "snapshot")]
+            if let (Some(directory), Some((repetition_prototypes, sample_prototypes))) =
+                (&directory, recorded)
+            {
+                let fixture = fixture_key::<Self, Precision, RelatedEntities>();
+                let path = snapshot_path(directory, &fixture);
+                let snapshot = RepetitionSnapshot {
+                    version: SNAPSHOT_FORMAT_VERSION,
+                    fixture,
+                    metadata: group.metadata,
+                    parameters,
+                    repetition_prototypes: &repetition_prototypes,
+                    sample_prototypes,
+                    outcomes: group.outcomes,
+                };
+                match snapshot.export(&path) {
+                    Ok(()) => eprintln!("Exported the failing repetition to {}", path.display()),
+                    Err(error) => eprintln!("Failed to export the failing repetition: {}", error),
+                }
+            }
+        }
+        passed
     }
 
     /// A method which verifies the statistical properties of a sample of engine execution, for a
@@ -178,6 +513,53 @@ pub trait Fixture<Precision: IntegerPrecision, Engine: AbstractEngine, RelatedEn
         parameters: &Self::Parameters,
         repetition_proto: &Self::RepetitionPrototypes,
         sample_size: SampleSize,
+    ) -> Vec<Self::Outcome> {
+        Self::sample_timed(
+            maker,
+            engine,
+            parameters,
+            repetition_proto,
+            sample_size,
+            &mut Timings::default(),
+            ExecutionMode::from_env(),
+        )
+    }
+
+    /// A method which does the same as [`Fixture::sample`], executing the engine in `mode`, and
+    /// records the duration of the engine executions in `timings`.
+    fn sample_timed(
+        maker: &mut Maker,
+        engine: &mut Engine,
+        parameters: &Self::Parameters,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_size: SampleSize,
+        timings: &mut Timings,
+        mode: ExecutionMode,
+    ) -> Vec<Self::Outcome> {
+        Self::sample_recorded(
+            maker,
+            engine,
+            parameters,
+            repetition_proto,
+            sample_size,
+            timings,
+            mode,
+            None,
+        )
+    }
+
+    /// A method which does the same as [`Fixture::sample_timed`], and pushes the sample-level
+    /// prototypes to `recorded_prototypes`, if any.
+    #[allow(clippy::too_many_arguments)]
+    fn sample_recorded(
+        maker: &mut Maker,
+        engine: &mut Engine,
+        parameters: &Self::Parameters,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_size: SampleSize,
+        timings: &mut Timings,
+        mode: ExecutionMode,
+        mut recorded_prototypes: Option<&mut Vec<Self::SamplePrototypes>>,
     ) -> Vec<Self::Outcome> {
         let mut outputs = Vec::with_capacity(sample_size.0);
         for _ in 0..sample_size.0 {
@@ -185,8 +567,16 @@ pub trait Fixture<Precision: IntegerPrecision, Engine: AbstractEngine, RelatedEn
                 Self::generate_random_sample_prototypes(parameters, maker, repetition_proto);
             let pre_execution_context =
                 Self::prepare_context(parameters, maker, repetition_proto, &sample_proto);
-            let post_execution_context =
-                Self::execute_engine(parameters, engine, pre_execution_context);
+            let start = Instant::now();
+            let post_execution_context = match mode {
+                ExecutionMode::Checked => {
+                    Self::execute_engine_checked(parameters, engine, pre_execution_context)
+                }
+                ExecutionMode::Unchecked | ExecutionMode::Both => {
+                    Self::execute_engine(parameters, engine, pre_execution_context)
+                }
+            };
+            timings.record(start.elapsed());
             let output = Self::process_context(
                 parameters,
                 maker,
@@ -194,10 +584,169 @@ pub trait Fixture<Precision: IntegerPrecision, Engine: AbstractEngine, RelatedEn
                 &sample_proto,
                 post_execution_context,
             );
+            if mode == ExecutionMode::Both {
+                let pre_execution_context =
+                    Self::prepare_context(parameters, maker, repetition_proto, &sample_proto);
+                let post_execution_context =
+                    Self::execute_engine_checked(parameters, engine, pre_execution_context);
+                let checked_output = Self::process_context(
+                    parameters,
+                    maker,
+                    repetition_proto,
+                    &sample_proto,
+                    post_execution_context,
+                );
+                assert!(
+                    Self::checked_outcome_matches(&checked_output, &output),
+                    "The checked and unchecked executions of the engine produced different \
+                    outcomes on the same inputs."
+                );
+            }
             outputs.push(output);
+            if let Some(recorded_prototypes) = recorded_prototypes.as_mut() {
+                recorded_prototypes.push(sample_proto);
+            }
         }
         outputs
     }
+
+    /// A method which executes the engine on the prototypes of a snapshot, and verifies that the
+    /// outcomes match the criteria of the repetition.
+    ///
+    /// The snapshot can have been exported with another backend: only the prototypes are
+    /// replayed, and the recorded outcomes are left for inspection.
+    #[cfg(feature = "snapshot")]
+    fn replay<RecordedOutcome>(
+        maker: &mut Maker,
+        engine: &mut Engine,
+        snapshot: &RepetitionSnapshot<
+            Self::Parameters,
+            Self::RepetitionPrototypes,
+            Self::SamplePrototypes,
+            RecordedOutcome,
+        >,
+    ) -> bool {
+        let parameters = &snapshot.parameters;
+        let repetition_proto = &snapshot.repetition_prototypes;
+        let outputs = snapshot
+            .sample_prototypes
+            .iter()
+            .map(|sample_proto| {
+                let context =
+                    Self::prepare_context(parameters, maker, repetition_proto, sample_proto);
+                let context = Self::execute_engine(parameters, engine, context);
+                Self::process_context(parameters, maker, repetition_proto, sample_proto, context)
+            })
+            .collect::<Vec<_>>();
+        let criteria = Self::compute_criteria(parameters, maker, repetition_proto);
+        Self::verify(&criteria, outputs.as_slice())
+    }
+
+    /// A method which executes the engine and a reference engine on the same randomly generated
+    /// inputs, for a fixed set of parameters, and checks that the outcomes of every pair of
+    /// executions are accepted by `matches`.
+    ///
+    /// The reference engine is usually the one of the `backend_reference` of `concrete-core`,
+    /// whose textbook implementations serve as a differential oracle. Since the two engines may
+    /// round or sample noise differently, `matches` is expected to compare the outcomes up to some
+    /// tolerance.
+    #[allow(clippy::too_many_arguments)]
+    fn differential<ReferenceEngine, ReferenceEntities>(
+        maker: &mut Maker,
+        engine: &mut Engine,
+        reference_engine: &mut ReferenceEngine,
+        parameters: &<Self as Fixture<Precision, Engine, RelatedEntities>>::Parameters,
+        repetitions: Repetitions,
+        sample_size: SampleSize,
+        matches: impl Fn(
+            &<Self as Fixture<Precision, Engine, RelatedEntities>>::Outcome,
+            &<Self as Fixture<Precision, Engine, RelatedEntities>>::Outcome,
+        ) -> bool,
+    ) -> bool
+    where
+        ReferenceEngine: AbstractEngine,
+        Self: Fixture<
+            Precision,
+            ReferenceEngine,
+            ReferenceEntities,
+            Parameters = <Self as Fixture<Precision, Engine, RelatedEntities>>::Parameters,
+            RepetitionPrototypes = <Self as Fixture<
+                Precision,
+                Engine,
+                RelatedEntities,
+            >>::RepetitionPrototypes,
+            SamplePrototypes = <Self as Fixture<
+                Precision,
+                Engine,
+                RelatedEntities,
+            >>::SamplePrototypes,
+            Outcome = <Self as Fixture<Precision, Engine, RelatedEntities>>::Outcome,
+        >,
+    {
+        for _ in 0..repetitions.0 {
+            let repetition_proto = <Self as Fixture<
+                Precision,
+                Engine,
+                RelatedEntities,
+            >>::generate_random_repetition_prototypes(parameters, maker);
+            for _ in 0..sample_size.0 {
+                let sample_proto = <Self as Fixture<
+                    Precision,
+                    Engine,
+                    RelatedEntities,
+                >>::generate_random_sample_prototypes(
+                    parameters, maker, &repetition_proto
+                );
+                let outcome = {
+                    let context =
+                        <Self as Fixture<Precision, Engine, RelatedEntities>>::prepare_context(
+                            parameters,
+                            maker,
+                            &repetition_proto,
+                            &sample_proto,
+                        );
+                    let context =
+                        <Self as Fixture<Precision, Engine, RelatedEntities>>::execute_engine(
+                            parameters, engine, context,
+                        );
+                    <Self as Fixture<Precision, Engine, RelatedEntities>>::process_context(
+                        parameters,
+                        maker,
+                        &repetition_proto,
+                        &sample_proto,
+                        context,
+                    )
+                };
+                let reference_outcome = {
+                    let context = <Self as Fixture<
+                        Precision,
+                        ReferenceEngine,
+                        ReferenceEntities,
+                    >>::prepare_context(
+                        parameters, maker, &repetition_proto, &sample_proto
+                    );
+                    let context = <Self as Fixture<
+                        Precision,
+                        ReferenceEngine,
+                        ReferenceEntities,
+                    >>::execute_engine(
+                        parameters, reference_engine, context
+                    );
+                    <Self as Fixture<Precision, ReferenceEngine, ReferenceEntities>>::process_context(
+                        parameters,
+                        maker,
+                        &repetition_proto,
+                        &sample_proto,
+                        context,
+                    )
+                };
+                if !matches(&outcome, &reference_outcome) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
 }
 
 mod cleartext_creation;
@@ -284,6 +833,9 @@ pub use lwe_ciphertext_cleartext_discarding_multiplication::*;
 mod lwe_ciphertext_cleartext_fusing_multiplication;
 pub use lwe_ciphertext_cleartext_fusing_multiplication::*;
 
+mod lwe_ciphertext_cleartext_fusing_multiply_add;
+pub use lwe_ciphertext_cleartext_fusing_multiply_add::*;
+
 mod lwe_ciphertext_vector_discarding_affine_transformation;
 pub use lwe_ciphertext_vector_discarding_affine_transformation::*;
 
@@ -335,6 +887,12 @@ pub use lwe_ciphertext_plaintext_fusing_subtraction::*;
 mod lwe_ciphertext_plaintext_discarding_subtraction;
 pub use lwe_ciphertext_plaintext_discarding_subtraction::*;
 
+mod lwe_ciphertext_vector_plaintext_vector_discarding_subtraction;
+pub use lwe_ciphertext_vector_plaintext_vector_discarding_subtraction::*;
+
+mod lwe_ciphertext_vector_plaintext_vector_fusing_subtraction;
+pub use lwe_ciphertext_vector_plaintext_vector_fusing_subtraction::*;
+
 mod lwe_ciphertext_vector_discarding_subtraction;
 pub use lwe_ciphertext_vector_discarding_subtraction::*;
 
@@ -398,6 +956,9 @@ pub use lwe_keyswitch_key_creation::*;
 mod lwe_secret_key_creation;
 pub use lwe_secret_key_creation::*;
 
+mod lwe_secret_key_derivation;
+pub use lwe_secret_key_derivation::*;
+
 mod glwe_secret_key_creation;
 pub use glwe_secret_key_creation::*;
 
@@ -415,3 +976,108 @@ pub use lwe_bootstrap_key_discarding_conversion::*;
 
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
 pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch::*;
+
+mod lwe_ciphertext_discarding_shrinking_keyswitch;
+pub use lwe_ciphertext_discarding_shrinking_keyswitch::*;
+
+mod cleartext_vector_negacyclic_convolution;
+pub use cleartext_vector_negacyclic_convolution::*;
+
+mod glwe_ciphertext_trace;
+pub use glwe_ciphertext_trace::*;
+
+mod ggsw_ciphertext_cleartext_trivial_encryption;
+pub use ggsw_ciphertext_cleartext_trivial_encryption::*;
+
+mod lwe_ciphertext_vector_noise_statistics;
+pub use lwe_ciphertext_vector_noise_statistics::*;
+
+mod lwe_ciphertext_discarding_large_table_lookup;
+pub use lwe_ciphertext_discarding_large_table_lookup::*;
+
+mod lwe_ciphertext_gadget_product;
+pub use lwe_ciphertext_gadget_product::*;
+
+mod glwe_ciphertext_discarding_addition;
+pub use glwe_ciphertext_discarding_addition::*;
+
+mod glwe_ciphertext_cleartext_vector_discarding_multiplication;
+pub use glwe_ciphertext_cleartext_vector_discarding_multiplication::*;
+
+mod lwe_ciphertext_vector_discarding_extremum;
+pub use lwe_ciphertext_vector_discarding_extremum::*;
+
+mod lwe_ciphertext_vector_discarding_gather;
+pub use lwe_ciphertext_vector_discarding_gather::*;
+
+mod lwe_ciphertext_vector_discarding_scatter;
+pub use lwe_ciphertext_vector_discarding_scatter::*;
+
+mod glwe_ciphertext_discarding_offset_addition;
+pub use glwe_ciphertext_discarding_offset_addition::*;
+
+mod ggsw_ciphertext_conversion;
+pub use ggsw_ciphertext_conversion::*;
+
+mod lwe_ciphertext_discarding_batched_keyswitch;
+pub use lwe_ciphertext_discarding_batched_keyswitch::*;
+
+mod lwe_ciphertext_cleartext_discarding_rational_multiplication;
+pub use lwe_ciphertext_cleartext_discarding_rational_multiplication::*;
+
+mod lwe_ciphertext_vector_phase_exporting_decryption;
+pub use lwe_ciphertext_vector_phase_exporting_decryption::*;
+
+mod lwe_ciphertext_discarding_multiplication;
+pub use lwe_ciphertext_discarding_multiplication::*;
+
+mod lwe_ciphertext_public_key_encryption;
+pub use lwe_ciphertext_public_key_encryption::*;
+
+mod lwe_ciphertext_vector_discarding_keyswitch;
+pub use lwe_ciphertext_vector_discarding_keyswitch::*;
+
+mod lwe_ciphertext_vector_discarding_parity;
+pub use lwe_ciphertext_vector_discarding_parity::*;
+
+mod lwe_ciphertext_vector_discarding_popcount;
+pub use lwe_ciphertext_vector_discarding_popcount::*;
+
+mod lwe_seeded_bootstrap_key_expansion;
+pub use lwe_seeded_bootstrap_key_expansion::*;
+
+mod lwe_seeded_ciphertext_encryption;
+pub use lwe_seeded_ciphertext_encryption::*;
+
+mod lwe_ciphertext_discarding_modulus_switch;
+pub use lwe_ciphertext_discarding_modulus_switch::*;
+
+mod lwe_ciphertext_jagged_vector_discarding_row_summation;
+pub use lwe_ciphertext_jagged_vector_discarding_row_summation::*;
+
+mod lwe_ciphertext_jagged_vector_fusing_addition;
+pub use lwe_ciphertext_jagged_vector_fusing_addition::*;
+
+mod lwe_ciphertext_truncated_view_conversion;
+pub use lwe_ciphertext_truncated_view_conversion::*;
+
+mod lwe_ciphertext_vector_discarding_cmux;
+pub use lwe_ciphertext_vector_discarding_cmux::*;
+
+mod lwe_ciphertext_vector_discarding_permutation;
+pub use lwe_ciphertext_vector_discarding_permutation::*;
+
+mod lwe_ciphertext_noise_distribution_encryption;
+pub use lwe_ciphertext_noise_distribution_encryption::*;
+
+mod lwe_bootstrap_key_constant_time_equality;
+pub use lwe_bootstrap_key_constant_time_equality::*;
+
+mod lwe_ciphertext_vector_constant_time_equality;
+pub use lwe_ciphertext_vector_constant_time_equality::*;
+
+mod lwe_ciphertext_vector_discarding_bootstrap;
+pub use lwe_ciphertext_vector_discarding_bootstrap::*;
+
+mod lwe_ciphertext_vector_discarding_budgeted_bootstrap;
+pub use lwe_ciphertext_vector_discarding_budgeted_bootstrap::*;
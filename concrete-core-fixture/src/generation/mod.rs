@@ -26,11 +26,76 @@ pub mod prototypes;
 pub mod prototyping;
 pub mod synthesizing;
 
+/// The environment variable containing the seed the fixtures are executed with.
+pub const SEED_VARIABLE: &str = "CONCRETE_FIXTURE_SEED";
+
+/// Returns the seed contained in the [`SEED_VARIABLE`] variable, or a fresh seed drawn from the
+/// entropy of the platform if the variable is not set.
+///
+/// The seed can be given in decimal, or in hexadecimal with a `0x` prefix. Creating the maker and
+/// the engine of a test from this seed, with [`Maker::new_seeded`] and [`engine_seed`], makes its
+/// executions reproducible: a failing test can be re-run with the seed it printed.
+///
+/// # Panics
+///
+/// Panics if the variable does not contain a valid 128 bits seed.
+pub fn seed_from_env() -> u128 {
+    match std::env::var(SEED_VARIABLE) {
+        Ok(seed) => {
+            let trimmed = seed.trim();
+            let parsed = match trimmed.strip_prefix("0x") {
+                Some(hexadecimal) => u128::from_str_radix(hexadecimal, 16),
+                None => trimmed.parse(),
+            };
+            parsed.unwrap_or_else(|_| {
+                panic!(
+                    "Invalid seed in {}: `{}`. Expected a decimal or hexadecimal (with a `0x` \
+                    prefix) 128 bits integer.",
+                    SEED_VARIABLE, seed
+                )
+            })
+        }
+        Err(_) => fresh_seed(),
+    }
+}
+
+/// Returns the seed of the engine executed by a fixture, derived from the seed of its maker.
+///
+/// The engines of the maker are seeded from the same value, such that the engine under test must
+/// use a different seed to keep its outputs independent from the generated inputs.
+pub fn engine_seed(maker_seed: u128) -> u128 {
+    maker_seed.wrapping_add(2)
+}
+
+// Draws a seed from the entropy of the platform. The keys of the hashers built by `RandomState`
+// are initialized from the randomness source of the os, and made unique for every hasher.
+fn fresh_seed() -> u128 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+    let half = || {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u128(
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|duration| duration.as_nanos())
+                .unwrap_or_default(),
+        );
+        u128::from(hasher.finish())
+    };
+    (half() << 64) | half()
+}
+
 /// A trait for marker type representing integer precision managed in `concrete_core`.
 pub trait IntegerPrecision {
     type Raw: RawUnsignedIntegers;
 }
 
+/// A type representing the 16 bits precision for integers.
+pub struct Precision16;
+impl IntegerPrecision for Precision16 {
+    type Raw = u16;
+}
+
 /// A type representing the 32 bits precision for integers.
 pub struct Precision32;
 impl IntegerPrecision for Precision32 {
@@ -43,6 +108,12 @@ impl IntegerPrecision for Precision64 {
     type Raw = u64;
 }
 
+/// A type representing the 128 bits precision for integers.
+pub struct Precision128;
+impl IntegerPrecision for Precision128 {
+    type Raw = u128;
+}
+
 /// The central structure used to generate the pre-execution context for all the fixtures.
 ///
 /// This structure contains the necessary tools to:
@@ -53,12 +124,78 @@ impl IntegerPrecision for Precision64 {
 /// fixture.
 pub struct Maker {
     core_engine: concrete_core::backends::core::engines::CoreEngine,
+    #[cfg(feature = "backend_reference")]
+    reference_engine: concrete_core::backends::reference::engines::ReferenceEngine,
+    // The engine is missing when no gpu is available, in which case the cuda entities can not be
+    // synthesized.
+    #[cfg(feature = "backend_cuda")]
+    cuda_engine: Option<concrete_core::backends::cuda::engines::CudaEngine>,
+    seed: Option<u128>,
+    key_count: u64,
+}
+
+impl Maker {
+    /// Creates a new maker, whose engines are seeded from the given value.
+    ///
+    /// Contrarily to [`Maker::default`], no entropy is drawn from the platform, which allows to run
+    /// the fixtures and the benchmarks where the hardware seeder is not available, such as in some
+    /// containers, CI sandboxes or WASM environments. The generated entities are determined by
+    /// the seed, which makes this constructor suitable for testing purpose only.
+    pub fn new_seeded(seed: u128) -> Maker {
+        Maker {
+            core_engine: concrete_core::backends::core::engines::CoreEngine::new_seeded(seed),
+            // The core engine derives the seeds of its generators, so that using a different seed
+            // for the reference engine is enough to keep their outputs independent. The `+ 2`
+            // offset is kept for the engine under test (see `engine_seed`).
+            #[cfg(feature = "backend_reference")]
+            reference_engine:
+                concrete_core::backends::reference::engines::ReferenceEngine::new_seeded(
+                    seed.wrapping_add(1),
+                ),
+            #[cfg(feature = "backend_cuda")]
+            cuda_engine: concrete_core::backends::cuda::engines::CudaEngine::new().ok(),
+            seed: Some(seed),
+            key_count: 0,
+        }
+    }
+
+    /// Returns the seed the maker was created with, if it was created with [`Maker::new_seeded`].
+    pub fn seed(&self) -> Option<u128> {
+        self.seed
+    }
+
+    /// Returns a new identifier for a set of repetition-level prototypes, unique for this maker.
+    pub(crate) fn next_key_id(&mut self) -> u64 {
+        self.key_count += 1;
+        self.key_count
+    }
+
+    /// Returns the engine synthesizing the cuda entities.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no gpu is available.
+    #[cfg(feature = "backend_cuda")]
+    pub(crate) fn cuda_engine(
+        &mut self,
+    ) -> &mut concrete_core::backends::cuda::engines::CudaEngine {
+        self.cuda_engine
+            .as_mut()
+            .expect("No gpu is available to synthesize the cuda entities.")
+    }
 }
 
 impl Default for Maker {
     fn default() -> Self {
         Maker {
             core_engine: concrete_core::backends::core::engines::CoreEngine::new().unwrap(),
+            #[cfg(feature = "backend_reference")]
+            reference_engine: concrete_core::backends::reference::engines::ReferenceEngine::new()
+                .unwrap(),
+            #[cfg(feature = "backend_cuda")]
+            cuda_engine: concrete_core::backends::cuda::engines::CudaEngine::new().ok(),
+            seed: None,
+            key_count: 0,
         }
     }
 }
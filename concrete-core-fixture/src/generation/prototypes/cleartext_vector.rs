@@ -1,18 +1,23 @@
 use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
 use concrete_core::prelude::{CleartextVector32, CleartextVector64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A trait implemented by cleartext vector prototypes.
-pub trait CleartextVectorPrototype {
+pub trait CleartextVectorPrototype: Snapshottable {
     type Precision: IntegerPrecision;
 }
 
 /// A type representing the prototype of a 32 bit cleartext vector entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoCleartextVector32(pub(crate) CleartextVector32);
 impl CleartextVectorPrototype for ProtoCleartextVector32 {
     type Precision = Precision32;
 }
 
 /// A type representing the prototype of a 64 bit cleartext vector entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoCleartextVector64(pub(crate) CleartextVector64);
 impl CleartextVectorPrototype for ProtoCleartextVector64 {
     type Precision = Precision64;
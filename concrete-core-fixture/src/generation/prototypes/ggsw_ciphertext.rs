@@ -1,14 +1,18 @@
 use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
 use concrete_core::prelude::{GgswCiphertext32, GgswCiphertext64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A trait implemented by ggsw ciphertext prototypes.
-pub trait GgswCiphertextPrototype {
+pub trait GgswCiphertextPrototype: Snapshottable {
     type KeyDistribution: KeyDistributionMarker;
     type Precision: IntegerPrecision;
 }
 
 /// A type representing the prototype of a 32 bit binary ggsw ciphertext entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryGgswCiphertext32(pub(crate) GgswCiphertext32);
 impl GgswCiphertextPrototype for ProtoBinaryGgswCiphertext32 {
     type KeyDistribution = BinaryKeyDistribution;
@@ -16,6 +20,7 @@ impl GgswCiphertextPrototype for ProtoBinaryGgswCiphertext32 {
 }
 
 /// A type representing the prototype of a 64 bit binary glwe ciphertext entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryGgswCiphertext64(pub(crate) GgswCiphertext64);
 impl GgswCiphertextPrototype for ProtoBinaryGgswCiphertext64 {
     type KeyDistribution = BinaryKeyDistribution;
@@ -0,0 +1,28 @@
+use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
+use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::{GlweAutomorphismKey32, GlweAutomorphismKey64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A trait implemented by glwe automorphism key prototypes.
+pub trait GlweAutomorphismKeyPrototype: Snapshottable {
+    type KeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary glwe automorphism key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryGlweAutomorphismKey32(pub(crate) GlweAutomorphismKey32);
+impl GlweAutomorphismKeyPrototype for ProtoBinaryGlweAutomorphismKey32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary glwe automorphism key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryGlweAutomorphismKey64(pub(crate) GlweAutomorphismKey64);
+impl GlweAutomorphismKeyPrototype for ProtoBinaryGlweAutomorphismKey64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
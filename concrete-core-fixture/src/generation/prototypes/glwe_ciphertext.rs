@@ -1,23 +1,72 @@
-use crate::generation::{IntegerPrecision, Precision32, Precision64};
-use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
-use concrete_core::prelude::{GlweCiphertext32, GlweCiphertext64};
+use crate::generation::{IntegerPrecision, Precision128, Precision16, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
+use concrete_core::prelude::markers::{
+    BinaryKeyDistribution, CyclicRing, KeyDistributionMarker, NegacyclicRing, RingStructureMarker,
+};
+use concrete_core::prelude::{
+    CyclicGlweCiphertext32, CyclicGlweCiphertext64, GlweCiphertext128, GlweCiphertext16,
+    GlweCiphertext32, GlweCiphertext64,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A trait implemented by glwe ciphertext prototypes.
-pub trait GlweCiphertextPrototype {
+pub trait GlweCiphertextPrototype: Snapshottable {
     type KeyDistribution: KeyDistributionMarker;
+    type RingStructure: RingStructureMarker;
     type Precision: IntegerPrecision;
 }
 
+/// A type representing the prototype of a 16 bit binary glwe ciphertext entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryGlweCiphertext16(pub(crate) GlweCiphertext16);
+impl GlweCiphertextPrototype for ProtoBinaryGlweCiphertext16 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type RingStructure = NegacyclicRing;
+    type Precision = Precision16;
+}
+
 /// A type representing the prototype of a 32 bit binary glwe ciphertext entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryGlweCiphertext32(pub(crate) GlweCiphertext32);
 impl GlweCiphertextPrototype for ProtoBinaryGlweCiphertext32 {
     type KeyDistribution = BinaryKeyDistribution;
+    type RingStructure = NegacyclicRing;
     type Precision = Precision32;
 }
 
 /// A type representing the prototype of a 64 bit binary glwe ciphertext entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryGlweCiphertext64(pub(crate) GlweCiphertext64);
 impl GlweCiphertextPrototype for ProtoBinaryGlweCiphertext64 {
     type KeyDistribution = BinaryKeyDistribution;
+    type RingStructure = NegacyclicRing;
+    type Precision = Precision64;
+}
+
+/// A type representing the prototype of a 128 bit binary glwe ciphertext entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryGlweCiphertext128(pub(crate) GlweCiphertext128);
+impl GlweCiphertextPrototype for ProtoBinaryGlweCiphertext128 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type RingStructure = NegacyclicRing;
+    type Precision = Precision128;
+}
+
+/// A type representing the prototype of a 32 bit binary glwe ciphertext entity in the cyclic ring.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryCyclicGlweCiphertext32(pub(crate) CyclicGlweCiphertext32);
+impl GlweCiphertextPrototype for ProtoBinaryCyclicGlweCiphertext32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type RingStructure = CyclicRing;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary glwe ciphertext entity in the cyclic ring.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryCyclicGlweCiphertext64(pub(crate) CyclicGlweCiphertext64);
+impl GlweCiphertextPrototype for ProtoBinaryCyclicGlweCiphertext64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type RingStructure = CyclicRing;
     type Precision = Precision64;
 }
@@ -1,14 +1,18 @@
 use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
 use concrete_core::prelude::{GlweCiphertextVector32, GlweCiphertextVector64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A trait implemented by glwe ciphertext vector prototypes.
-pub trait GlweCiphertextVectorPrototype {
+pub trait GlweCiphertextVectorPrototype: Snapshottable {
     type KeyDistribution: KeyDistributionMarker;
     type Precision: IntegerPrecision;
 }
 
 /// A type representing the prototype of a 32 bit binary glwe ciphertext vector entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryGlweCiphertextVector32(pub(crate) GlweCiphertextVector32);
 impl GlweCiphertextVectorPrototype for ProtoBinaryGlweCiphertextVector32 {
     type KeyDistribution = BinaryKeyDistribution;
@@ -16,6 +20,7 @@ impl GlweCiphertextVectorPrototype for ProtoBinaryGlweCiphertextVector32 {
 }
 
 /// A type representing the prototype of a 64 bit binary glwe ciphertext vector entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryGlweCiphertextVector64(pub(crate) GlweCiphertextVector64);
 impl GlweCiphertextVectorPrototype for ProtoBinaryGlweCiphertextVector64 {
     type KeyDistribution = BinaryKeyDistribution;
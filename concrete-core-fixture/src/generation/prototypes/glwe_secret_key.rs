@@ -1,14 +1,26 @@
-use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::generation::{IntegerPrecision, Precision128, Precision16, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
-use concrete_core::prelude::{GlweSecretKey32, GlweSecretKey64};
+use concrete_core::prelude::{GlweSecretKey128, GlweSecretKey16, GlweSecretKey32, GlweSecretKey64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A trait implemented by glwe secret key prototypes.
-pub trait GlweSecretKeyPrototype {
+pub trait GlweSecretKeyPrototype: Snapshottable {
     type KeyDistribution: KeyDistributionMarker;
     type Precision: IntegerPrecision;
 }
 
+/// A type representing the prototype of a 16 bit binary glwe secret key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryGlweSecretKey16(pub(crate) GlweSecretKey16);
+impl GlweSecretKeyPrototype for ProtoBinaryGlweSecretKey16 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision16;
+}
+
 /// A type representing the prototype of a 32 bit binary glwe secret key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryGlweSecretKey32(pub(crate) GlweSecretKey32);
 impl GlweSecretKeyPrototype for ProtoBinaryGlweSecretKey32 {
     type KeyDistribution = BinaryKeyDistribution;
@@ -16,8 +28,17 @@ impl GlweSecretKeyPrototype for ProtoBinaryGlweSecretKey32 {
 }
 
 /// A type representing the prototype of a 64 bit binary glwe secret key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryGlweSecretKey64(pub(crate) GlweSecretKey64);
 impl GlweSecretKeyPrototype for ProtoBinaryGlweSecretKey64 {
     type KeyDistribution = BinaryKeyDistribution;
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 128 bit binary glwe secret key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryGlweSecretKey128(pub(crate) GlweSecretKey128);
+impl GlweSecretKeyPrototype for ProtoBinaryGlweSecretKey128 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision128;
+}
@@ -1,15 +1,19 @@
 use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
 use concrete_core::prelude::{LweBootstrapKey32, LweBootstrapKey64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A trait implemented by lwe bootstrap key prototypes.
-pub trait LweBootstrapKeyPrototype {
+pub trait LweBootstrapKeyPrototype: Snapshottable {
     type InputKeyDistribution: KeyDistributionMarker;
     type OutputKeyDistribution: KeyDistributionMarker;
     type Precision: IntegerPrecision;
 }
 
 /// A type representing the prototype of a 32 bit binary to binary lwe bootstrap key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryBinaryLweBootstrapKey32(pub(crate) LweBootstrapKey32);
 impl LweBootstrapKeyPrototype for ProtoBinaryBinaryLweBootstrapKey32 {
     type InputKeyDistribution = BinaryKeyDistribution;
@@ -18,6 +22,7 @@ impl LweBootstrapKeyPrototype for ProtoBinaryBinaryLweBootstrapKey32 {
 }
 
 /// A type representing the prototype of a 64 bit binary to binary lwe bootstrap key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryBinaryLweBootstrapKey64(pub(crate) LweBootstrapKey64);
 impl LweBootstrapKeyPrototype for ProtoBinaryBinaryLweBootstrapKey64 {
     type InputKeyDistribution = BinaryKeyDistribution;
@@ -1,14 +1,26 @@
-use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::generation::{IntegerPrecision, Precision128, Precision16, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
-use concrete_core::prelude::{LweCiphertext32, LweCiphertext64};
+use concrete_core::prelude::{LweCiphertext128, LweCiphertext16, LweCiphertext32, LweCiphertext64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A trait implemented by lwe ciphertext prototypes.
-pub trait LweCiphertextPrototype {
+pub trait LweCiphertextPrototype: Snapshottable {
     type KeyDistribution: KeyDistributionMarker;
     type Precision: IntegerPrecision;
 }
 
+/// A type representing the prototype of a 16 bit binary lwe ciphertext entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryLweCiphertext16(pub(crate) LweCiphertext16);
+impl LweCiphertextPrototype for ProtoBinaryLweCiphertext16 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision16;
+}
+
 /// A type representing the prototype of a 32 bit binary lwe ciphertext entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryLweCiphertext32(pub(crate) LweCiphertext32);
 impl LweCiphertextPrototype for ProtoBinaryLweCiphertext32 {
     type KeyDistribution = BinaryKeyDistribution;
@@ -16,8 +28,17 @@ impl LweCiphertextPrototype for ProtoBinaryLweCiphertext32 {
 }
 
 /// A type representing the prototype of a 64 bit binary lwe ciphertext entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryLweCiphertext64(pub(crate) LweCiphertext64);
 impl LweCiphertextPrototype for ProtoBinaryLweCiphertext64 {
     type KeyDistribution = BinaryKeyDistribution;
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 128 bit binary lwe ciphertext entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryLweCiphertext128(pub(crate) LweCiphertext128);
+impl LweCiphertextPrototype for ProtoBinaryLweCiphertext128 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision128;
+}
@@ -0,0 +1,28 @@
+use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
+use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::{LweCiphertextJaggedVector32, LweCiphertextJaggedVector64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A trait implemented by lwe ciphertext jagged vector prototypes.
+pub trait LweCiphertextJaggedVectorPrototype: Snapshottable {
+    type KeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary lwe ciphertext jagged vector entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryLweCiphertextJaggedVector32(pub(crate) LweCiphertextJaggedVector32);
+impl LweCiphertextJaggedVectorPrototype for ProtoBinaryLweCiphertextJaggedVector32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary lwe ciphertext jagged vector entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryLweCiphertextJaggedVector64(pub(crate) LweCiphertextJaggedVector64);
+impl LweCiphertextJaggedVectorPrototype for ProtoBinaryLweCiphertextJaggedVector64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
@@ -1,14 +1,18 @@
 use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
 use concrete_core::prelude::{LweCiphertextVector32, LweCiphertextVector64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A trait implemented by lwe ciphertext vector prototypes.
-pub trait LweCiphertextVectorPrototype {
+pub trait LweCiphertextVectorPrototype: Snapshottable {
     type KeyDistribution: KeyDistributionMarker;
     type Precision: IntegerPrecision;
 }
 
 /// A type representing the prototype of a 32 bit binary lwe ciphertext vector entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryLweCiphertextVector32(pub(crate) LweCiphertextVector32);
 impl LweCiphertextVectorPrototype for ProtoBinaryLweCiphertextVector32 {
     type KeyDistribution = BinaryKeyDistribution;
@@ -16,6 +20,7 @@ impl LweCiphertextVectorPrototype for ProtoBinaryLweCiphertextVector32 {
 }
 
 ///  type representing the prototype of a 64 bit binary lwe ciphertext vector entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryLweCiphertextVector64(pub(crate) LweCiphertextVector64);
 impl LweCiphertextVectorPrototype for ProtoBinaryLweCiphertextVector64 {
     type KeyDistribution = BinaryKeyDistribution;
@@ -1,15 +1,30 @@
-use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::generation::{IntegerPrecision, Precision128, Precision16, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
-use concrete_core::prelude::{LweKeyswitchKey32, LweKeyswitchKey64};
+use concrete_core::prelude::{
+    LweKeyswitchKey128, LweKeyswitchKey16, LweKeyswitchKey32, LweKeyswitchKey64,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A trait implemented by lwe keyswitch key prototypes.
-pub trait LweKeyswitchKeyPrototype {
+pub trait LweKeyswitchKeyPrototype: Snapshottable {
     type InputKeyDistribution: KeyDistributionMarker;
     type OutputKeyDistribution: KeyDistributionMarker;
     type Precision: IntegerPrecision;
 }
 
+/// A type representing the prototype of a 16 bit binary to binary lwe keyswitch key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryBinaryLweKeyswitchKey16(pub(crate) LweKeyswitchKey16);
+impl LweKeyswitchKeyPrototype for ProtoBinaryBinaryLweKeyswitchKey16 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision16;
+}
+
 /// A type representing the prototype of a 32 bit binary to binary lwe keyswitch key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryBinaryLweKeyswitchKey32(pub(crate) LweKeyswitchKey32);
 impl LweKeyswitchKeyPrototype for ProtoBinaryBinaryLweKeyswitchKey32 {
     type InputKeyDistribution = BinaryKeyDistribution;
@@ -18,9 +33,19 @@ impl LweKeyswitchKeyPrototype for ProtoBinaryBinaryLweKeyswitchKey32 {
 }
 
 /// A type representing the prototype of a 64 bit binary to binary lwe keyswitch key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryBinaryLweKeyswitchKey64(pub(crate) LweKeyswitchKey64);
 impl LweKeyswitchKeyPrototype for ProtoBinaryBinaryLweKeyswitchKey64 {
     type InputKeyDistribution = BinaryKeyDistribution;
     type OutputKeyDistribution = BinaryKeyDistribution;
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 128 bit binary to binary lwe keyswitch key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryBinaryLweKeyswitchKey128(pub(crate) LweKeyswitchKey128);
+impl LweKeyswitchKeyPrototype for ProtoBinaryBinaryLweKeyswitchKey128 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision128;
+}
@@ -0,0 +1,31 @@
+use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
+use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::{LweKeyswitchKeyBatch32, LweKeyswitchKeyBatch64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A trait implemented by lwe keyswitch key batch prototypes.
+pub trait LweKeyswitchKeyBatchPrototype: Snapshottable {
+    type InputKeyDistribution: KeyDistributionMarker;
+    type OutputKeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary to binary lwe keyswitch key batch entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryBinaryLweKeyswitchKeyBatch32(pub(crate) LweKeyswitchKeyBatch32);
+impl LweKeyswitchKeyBatchPrototype for ProtoBinaryBinaryLweKeyswitchKeyBatch32 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary to binary lwe keyswitch key batch entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryBinaryLweKeyswitchKeyBatch64(pub(crate) LweKeyswitchKeyBatch64);
+impl LweKeyswitchKeyBatchPrototype for ProtoBinaryBinaryLweKeyswitchKeyBatch64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
@@ -0,0 +1,24 @@
+use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
+use concrete_core::prelude::{LwePhaseVector32, LwePhaseVector64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A trait implemented by lwe phase vector prototypes.
+pub trait LwePhaseVectorPrototype: Snapshottable {
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit lwe phase vector entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoLwePhaseVector32(pub(crate) LwePhaseVector32);
+impl LwePhaseVectorPrototype for ProtoLwePhaseVector32 {
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit lwe phase vector entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoLwePhaseVector64(pub(crate) LwePhaseVector64);
+impl LwePhaseVectorPrototype for ProtoLwePhaseVector64 {
+    type Precision = Precision64;
+}
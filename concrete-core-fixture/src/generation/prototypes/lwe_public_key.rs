@@ -0,0 +1,28 @@
+use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
+use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::{LwePublicKey32, LwePublicKey64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A trait implemented by lwe public key prototypes.
+pub trait LwePublicKeyPrototype: Snapshottable {
+    type KeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary lwe public key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryLwePublicKey32(pub(crate) LwePublicKey32);
+impl LwePublicKeyPrototype for ProtoBinaryLwePublicKey32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary lwe public key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryLwePublicKey64(pub(crate) LwePublicKey64);
+impl LwePublicKeyPrototype for ProtoBinaryLwePublicKey64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
@@ -1,14 +1,26 @@
-use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::generation::{IntegerPrecision, Precision128, Precision16, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
-use concrete_core::prelude::{LweSecretKey32, LweSecretKey64};
+use concrete_core::prelude::{LweSecretKey128, LweSecretKey16, LweSecretKey32, LweSecretKey64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A trait implemented by lwe secret key prototypes.
-pub trait LweSecretKeyPrototype {
+pub trait LweSecretKeyPrototype: Snapshottable {
     type KeyDistribution: KeyDistributionMarker;
     type Precision: IntegerPrecision;
 }
 
+/// A type representing the prototype of a 16 bit binary lwe secret key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryLweSecretKey16(pub(crate) LweSecretKey16);
+impl LweSecretKeyPrototype for ProtoBinaryLweSecretKey16 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision16;
+}
+
 /// A type representing the prototype of a 32 bit binary lwe secret key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryLweSecretKey32(pub(crate) LweSecretKey32);
 impl LweSecretKeyPrototype for ProtoBinaryLweSecretKey32 {
     type KeyDistribution = BinaryKeyDistribution;
@@ -16,8 +28,17 @@ impl LweSecretKeyPrototype for ProtoBinaryLweSecretKey32 {
 }
 
 /// A type representing the prototype of a 64 bit binary lwe secret key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryLweSecretKey64(pub(crate) LweSecretKey64);
 impl LweSecretKeyPrototype for ProtoBinaryLweSecretKey64 {
     type KeyDistribution = BinaryKeyDistribution;
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 128 bit binary lwe secret key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryLweSecretKey128(pub(crate) LweSecretKey128);
+impl LweSecretKeyPrototype for ProtoBinaryLweSecretKey128 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision128;
+}
@@ -0,0 +1,31 @@
+use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
+use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::{LweSeededBootstrapKey32, LweSeededBootstrapKey64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A trait implemented by lwe seeded bootstrap key prototypes.
+pub trait LweSeededBootstrapKeyPrototype: Snapshottable {
+    type InputKeyDistribution: KeyDistributionMarker;
+    type OutputKeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary to binary lwe seeded bootstrap key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryBinaryLweSeededBootstrapKey32(pub(crate) LweSeededBootstrapKey32);
+impl LweSeededBootstrapKeyPrototype for ProtoBinaryBinaryLweSeededBootstrapKey32 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary to binary lwe seeded bootstrap key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryBinaryLweSeededBootstrapKey64(pub(crate) LweSeededBootstrapKey64);
+impl LweSeededBootstrapKeyPrototype for ProtoBinaryBinaryLweSeededBootstrapKey64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
@@ -0,0 +1,28 @@
+use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
+use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::{LweSeededCiphertext32, LweSeededCiphertext64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A trait implemented by lwe seeded ciphertext prototypes.
+pub trait LweSeededCiphertextPrototype: Snapshottable {
+    type KeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary lwe seeded ciphertext entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryLweSeededCiphertext32(pub(crate) LweSeededCiphertext32);
+impl LweSeededCiphertextPrototype for ProtoBinaryLweSeededCiphertext32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary lwe seeded ciphertext entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryLweSeededCiphertext64(pub(crate) LweSeededCiphertext64);
+impl LweSeededCiphertextPrototype for ProtoBinaryLweSeededCiphertext64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
@@ -0,0 +1,28 @@
+use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
+use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::{LweShrinkingKeyswitchKey32, LweShrinkingKeyswitchKey64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+
+/// A trait implemented by lwe shrinking keyswitch key prototypes.
+pub trait LweShrinkingKeyswitchKeyPrototype: Snapshottable {
+    type KeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary lwe shrinking keyswitch key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryLweShrinkingKeyswitchKey32(pub(crate) LweShrinkingKeyswitchKey32);
+impl LweShrinkingKeyswitchKeyPrototype for ProtoBinaryLweShrinkingKeyswitchKey32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary lwe shrinking keyswitch key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoBinaryLweShrinkingKeyswitchKey64(pub(crate) LweShrinkingKeyswitchKey64);
+impl LweShrinkingKeyswitchKeyPrototype for ProtoBinaryLweShrinkingKeyswitchKey64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
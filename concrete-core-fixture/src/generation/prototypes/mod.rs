@@ -3,14 +3,23 @@
 mod cleartext;
 mod cleartext_vector;
 mod ggsw_ciphertext;
+mod glwe_automorphism_key;
 mod glwe_ciphertext;
 mod glwe_ciphertext_vector;
+mod glwe_relinearization_key;
 mod glwe_secret_key;
 mod lwe_bootstrap_key;
 mod lwe_ciphertext;
+mod lwe_ciphertext_jagged_vector;
 mod lwe_ciphertext_vector;
 mod lwe_keyswitch_key;
+mod lwe_keyswitch_key_batch;
+mod lwe_phase_vector;
+mod lwe_public_key;
 mod lwe_secret_key;
+mod lwe_seeded_bootstrap_key;
+mod lwe_seeded_ciphertext;
+mod lwe_shrinking_keyswitch_key;
 mod packing_keyswitch_key;
 mod plaintext;
 mod plaintext_vector;
@@ -18,14 +27,23 @@ mod plaintext_vector;
 pub use cleartext::*;
 pub use cleartext_vector::*;
 pub use ggsw_ciphertext::*;
+pub use glwe_automorphism_key::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_vector::*;
+pub use glwe_relinearization_key::*;
 pub use glwe_secret_key::*;
 pub use lwe_bootstrap_key::*;
 pub use lwe_ciphertext::*;
+pub use lwe_ciphertext_jagged_vector::*;
 pub use lwe_ciphertext_vector::*;
 pub use lwe_keyswitch_key::*;
+pub use lwe_keyswitch_key_batch::*;
+pub use lwe_phase_vector::*;
+pub use lwe_public_key::*;
 pub use lwe_secret_key::*;
+pub use lwe_seeded_bootstrap_key::*;
+pub use lwe_seeded_ciphertext::*;
+pub use lwe_shrinking_keyswitch_key::*;
 pub use packing_keyswitch_key::*;
 pub use plaintext::*;
 pub use plaintext_vector::*;
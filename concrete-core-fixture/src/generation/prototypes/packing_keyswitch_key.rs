@@ -1,15 +1,19 @@
 use crate::generation::{IntegerPrecision, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
 use concrete_core::prelude::{PackingKeyswitchKey32, PackingKeyswitchKey64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A trait implemented by packing keyswitch key prototypes.
-pub trait PackingKeyswitchKeyPrototype {
+pub trait PackingKeyswitchKeyPrototype: Snapshottable {
     type InputKeyDistribution: KeyDistributionMarker;
     type OutputKeyDistribution: KeyDistributionMarker;
     type Precision: IntegerPrecision;
 }
 
 /// A type representing the prototype of a 32 bit binary to binary packing keyswitch key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryBinaryPackingKeyswitchKey32(pub(crate) PackingKeyswitchKey32);
 impl PackingKeyswitchKeyPrototype for ProtoBinaryBinaryPackingKeyswitchKey32 {
     type InputKeyDistribution = BinaryKeyDistribution;
@@ -18,6 +22,7 @@ impl PackingKeyswitchKeyPrototype for ProtoBinaryBinaryPackingKeyswitchKey32 {
 }
 
 /// A type representing the prototype of a 64 bit binary to binary packing keyswitch key entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoBinaryBinaryPackingKeyswitchKey64(pub(crate) PackingKeyswitchKey64);
 impl PackingKeyswitchKeyPrototype for ProtoBinaryBinaryPackingKeyswitchKey64 {
     type InputKeyDistribution = BinaryKeyDistribution;
@@ -1,19 +1,38 @@
-use crate::generation::{IntegerPrecision, Precision32, Precision64};
-use concrete_core::prelude::{Plaintext32, Plaintext64};
+use crate::generation::{IntegerPrecision, Precision128, Precision16, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
+use concrete_core::prelude::{Plaintext128, Plaintext16, Plaintext32, Plaintext64};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A trait implemented by plaintext prototypes.
-pub trait PlaintextPrototype {
+pub trait PlaintextPrototype: Snapshottable {
     type Precision: IntegerPrecision;
 }
 
+/// A type representing the prototype of a 16 bit plaintext entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoPlaintext16(pub(crate) Plaintext16);
+impl PlaintextPrototype for ProtoPlaintext16 {
+    type Precision = Precision16;
+}
+
 /// A type representing the prototype of a 32 bit plaintext entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoPlaintext32(pub(crate) Plaintext32);
 impl PlaintextPrototype for ProtoPlaintext32 {
     type Precision = Precision32;
 }
 
 /// A type representing the prototype of a 64 bit plaintext entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoPlaintext64(pub(crate) Plaintext64);
 impl PlaintextPrototype for ProtoPlaintext64 {
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 128 bit plaintext entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoPlaintext128(pub(crate) Plaintext128);
+impl PlaintextPrototype for ProtoPlaintext128 {
+    type Precision = Precision128;
+}
@@ -1,19 +1,40 @@
-use crate::generation::{IntegerPrecision, Precision32, Precision64};
-use concrete_core::prelude::{PlaintextVector32, PlaintextVector64};
+use crate::generation::{IntegerPrecision, Precision128, Precision16, Precision32, Precision64};
+use crate::snapshot::Snapshottable;
+use concrete_core::prelude::{
+    PlaintextVector128, PlaintextVector16, PlaintextVector32, PlaintextVector64,
+};
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
 
 /// A trait implemented by plaintext vector prototypes.
-pub trait PlaintextVectorPrototype {
+pub trait PlaintextVectorPrototype: Snapshottable {
     type Precision: IntegerPrecision;
 }
 
+/// A type representing the prototype of a 16 bit plaintext vector entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoPlaintextVector16(pub(crate) PlaintextVector16);
+impl PlaintextVectorPrototype for ProtoPlaintextVector16 {
+    type Precision = Precision16;
+}
+
 /// A type representing the prototype of a 32 bit plaintext vector entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoPlaintextVector32(pub(crate) PlaintextVector32);
 impl PlaintextVectorPrototype for ProtoPlaintextVector32 {
     type Precision = Precision32;
 }
 
 /// A type representing the prototype of a 64 bit plaintext vector entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
 pub struct ProtoPlaintextVector64(pub(crate) PlaintextVector64);
 impl PlaintextVectorPrototype for ProtoPlaintextVector64 {
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 128 bit plaintext vector entity.
+#[cfg_attr(feature = "snapshot", derive(Serialize, Deserialize))]
+pub struct ProtoPlaintextVector128(pub(crate) PlaintextVector128);
+impl PlaintextVectorPrototype for ProtoPlaintextVector128 {
+    type Precision = Precision128;
+}
@@ -1,6 +1,8 @@
 use crate::generation::prototypes::{
     GgswCiphertextPrototype, ProtoBinaryGgswCiphertext32, ProtoBinaryGgswCiphertext64,
+    ProtoBinaryGlweCiphertext32, ProtoBinaryGlweCiphertext64,
 };
+use crate::generation::prototyping::glwe_ciphertext::PrototypesGlweCiphertext;
 use crate::generation::prototyping::glwe_secret_key::PrototypesGlweSecretKey;
 use crate::generation::prototyping::plaintext::PrototypesPlaintext;
 use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
@@ -10,15 +12,20 @@ use concrete_commons::parameters::{
 };
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
 use concrete_core::prelude::{
+    DestructionEngine, FourierGgswCiphertext32, FourierGgswCiphertext64,
+    GgswCiphertextConsumingRetrievalEngine, GgswCiphertextConversionEngine,
     GgswCiphertextScalarEncryptionEngine, GgswCiphertextScalarTrivialEncryptionEngine,
-    PlaintextCreationEngine,
+    GlweCiphertextGgswCiphertextExternalProductEngine, PlaintextCreationEngine,
 };
 
 /// A trait allowing to manipulate GGSW ciphertext prototypes.
 pub trait PrototypesGgswCiphertext<
     Precision: IntegerPrecision,
     KeyDistribution: KeyDistributionMarker,
->: PrototypesPlaintext<Precision> + PrototypesGlweSecretKey<Precision, KeyDistribution>
+>:
+    PrototypesPlaintext<Precision>
+    + PrototypesGlweSecretKey<Precision, KeyDistribution>
+    + PrototypesGlweCiphertext<Precision, KeyDistribution>
 {
     type GgswCiphertextProto: GgswCiphertextPrototype<
         Precision = Precision,
@@ -47,6 +54,15 @@ pub trait PrototypesGgswCiphertext<
         decomposition_level_count: DecompositionLevelCount,
         decomposition_base_log: DecompositionBaseLog,
     ) -> Self::GgswCiphertextProto;
+    fn compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_ciphertext: &Self::GlweCiphertextProto,
+        ggsw_ciphertext: &Self::GgswCiphertextProto,
+    ) -> Self::GlweCiphertextProto;
+    fn transform_ggsw_ciphertext_to_raw_vec(
+        &mut self,
+        ggsw_ciphertext: &Self::GgswCiphertextProto,
+    ) -> Vec<Precision::Raw>;
 }
 
 impl PrototypesGgswCiphertext<Precision32, BinaryKeyDistribution> for Maker {
@@ -114,6 +130,35 @@ impl PrototypesGgswCiphertext<Precision32, BinaryKeyDistribution> for Maker {
                 .unwrap(),
         )
     }
+
+    fn compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_ciphertext: &Self::GlweCiphertextProto,
+        ggsw_ciphertext: &Self::GgswCiphertextProto,
+    ) -> Self::GlweCiphertextProto {
+        let fourier_ggsw_ciphertext: FourierGgswCiphertext32 = self
+            .core_engine
+            .convert_ggsw_ciphertext(&ggsw_ciphertext.0)
+            .unwrap();
+        let output = self
+            .core_engine
+            .compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+                &glwe_ciphertext.0,
+                &fourier_ggsw_ciphertext,
+            )
+            .unwrap();
+        self.core_engine.destroy(fourier_ggsw_ciphertext).unwrap();
+        ProtoBinaryGlweCiphertext32(output)
+    }
+
+    fn transform_ggsw_ciphertext_to_raw_vec(
+        &mut self,
+        ggsw_ciphertext: &Self::GgswCiphertextProto,
+    ) -> Vec<u32> {
+        self.core_engine
+            .consume_retrieve_ggsw_ciphertext(ggsw_ciphertext.0.to_owned())
+            .unwrap()
+    }
 }
 
 impl PrototypesGgswCiphertext<Precision64, BinaryKeyDistribution> for Maker {
@@ -181,4 +226,33 @@ impl PrototypesGgswCiphertext<Precision64, BinaryKeyDistribution> for Maker {
                 .unwrap(),
         )
     }
+
+    fn compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_ciphertext: &Self::GlweCiphertextProto,
+        ggsw_ciphertext: &Self::GgswCiphertextProto,
+    ) -> Self::GlweCiphertextProto {
+        let fourier_ggsw_ciphertext: FourierGgswCiphertext64 = self
+            .core_engine
+            .convert_ggsw_ciphertext(&ggsw_ciphertext.0)
+            .unwrap();
+        let output = self
+            .core_engine
+            .compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+                &glwe_ciphertext.0,
+                &fourier_ggsw_ciphertext,
+            )
+            .unwrap();
+        self.core_engine.destroy(fourier_ggsw_ciphertext).unwrap();
+        ProtoBinaryGlweCiphertext64(output)
+    }
+
+    fn transform_ggsw_ciphertext_to_raw_vec(
+        &mut self,
+        ggsw_ciphertext: &Self::GgswCiphertextProto,
+    ) -> Vec<u64> {
+        self.core_engine
+            .consume_retrieve_ggsw_ciphertext(ggsw_ciphertext.0.to_owned())
+            .unwrap()
+    }
 }
@@ -0,0 +1,75 @@
+use crate::generation::prototypes::{
+    GlweAutomorphismKeyPrototype, ProtoBinaryGlweAutomorphismKey32,
+    ProtoBinaryGlweAutomorphismKey64,
+};
+use crate::generation::prototyping::glwe_secret_key::PrototypesGlweSecretKey;
+use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::GlweAutomorphismKeyCreationEngine;
+
+/// A trait allowing to manipulate glwe automorphism key prototypes.
+pub trait PrototypesGlweAutomorphismKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>: PrototypesGlweSecretKey<Precision, KeyDistribution>
+{
+    type GlweAutomorphismKeyProto: GlweAutomorphismKeyPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn new_glwe_automorphism_key(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweAutomorphismKeyProto;
+}
+
+impl PrototypesGlweAutomorphismKey<Precision32, BinaryKeyDistribution> for Maker {
+    type GlweAutomorphismKeyProto = ProtoBinaryGlweAutomorphismKey32;
+
+    fn new_glwe_automorphism_key(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweAutomorphismKeyProto {
+        ProtoBinaryGlweAutomorphismKey32(
+            self.core_engine
+                .create_glwe_automorphism_key(
+                    &secret_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesGlweAutomorphismKey<Precision64, BinaryKeyDistribution> for Maker {
+    type GlweAutomorphismKeyProto = ProtoBinaryGlweAutomorphismKey64;
+
+    fn new_glwe_automorphism_key(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweAutomorphismKeyProto {
+        ProtoBinaryGlweAutomorphismKey64(
+            self.core_engine
+                .create_glwe_automorphism_key(
+                    &secret_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+}
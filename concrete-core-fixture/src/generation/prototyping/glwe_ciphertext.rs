@@ -1,17 +1,23 @@
 use crate::generation::prototypes::{
-    GlweCiphertextPrototype, ProtoBinaryGlweCiphertext32, ProtoBinaryGlweCiphertext64,
+    GlweCiphertextPrototype, ProtoBinaryCyclicGlweCiphertext32, ProtoBinaryCyclicGlweCiphertext64,
+    ProtoBinaryGlweCiphertext128, ProtoBinaryGlweCiphertext16, ProtoBinaryGlweCiphertext32,
+    ProtoBinaryGlweCiphertext64, ProtoPlaintextVector128, ProtoPlaintextVector16,
     ProtoPlaintextVector32, ProtoPlaintextVector64,
 };
 use crate::generation::prototyping::glwe_secret_key::PrototypesGlweSecretKey;
 use crate::generation::prototyping::plaintext_vector::PrototypesPlaintextVector;
-use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use crate::generation::{
+    IntegerPrecision, Maker, Precision128, Precision16, Precision32, Precision64,
+};
 use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::{GlweDimension, PolynomialSize};
-use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::markers::{
+    BinaryKeyDistribution, CyclicRing, KeyDistributionMarker, NegacyclicRing, RingStructureMarker,
+};
 use concrete_core::prelude::{
-    GlweCiphertextDecryptionEngine, GlweCiphertextEncryptionEngine,
-    GlweCiphertextTrivialDecryptionEngine, GlweCiphertextTrivialEncryptionEngine,
-    PlaintextVectorCreationEngine,
+    CyclicGlweCiphertext32, CyclicGlweCiphertext64, GlweCiphertextDecryptionEngine,
+    GlweCiphertextEncryptionEngine, GlweCiphertextTrivialDecryptionEngine,
+    GlweCiphertextTrivialEncryptionEngine, PlaintextVectorCreationEngine,
 };
 
 /// A trait allowing to manipulate GLWE ciphertext prototypes.
@@ -52,6 +58,77 @@ pub trait PrototypesGlweCiphertext<
     ) -> Self::PlaintextVectorProto;
 }
 
+impl PrototypesGlweCiphertext<Precision16, BinaryKeyDistribution> for Maker {
+    type GlweCiphertextProto = ProtoBinaryGlweCiphertext16;
+
+    fn trivially_encrypt_zeros_to_glwe_ciphertext(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        let plaintext_vector = self
+            .core_engine
+            .create_plaintext_vector(&vec![0u16; poly_size.0])
+            .unwrap();
+        ProtoBinaryGlweCiphertext16(
+            self.core_engine
+                .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)
+                .unwrap(),
+        )
+    }
+
+    fn trivially_encrypt_plaintext_vector_to_glwe_ciphertext(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        plaintext_vector: &Self::PlaintextVectorProto,
+    ) -> Self::GlweCiphertextProto {
+        ProtoBinaryGlweCiphertext16(
+            self.core_engine
+                .trivially_encrypt_glwe_ciphertext(
+                    glwe_dimension.to_glwe_size(),
+                    &plaintext_vector.0,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn trivially_decrypt_glwe_ciphertext(
+        &mut self,
+        ciphertext: &Self::GlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector16(
+            self.core_engine
+                .trivially_decrypt_glwe_ciphertext(&ciphertext.0)
+                .unwrap(),
+        )
+    }
+
+    fn encrypt_plaintext_vector_to_glwe_ciphertext(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+    ) -> Self::GlweCiphertextProto {
+        ProtoBinaryGlweCiphertext16(
+            self.core_engine
+                .encrypt_glwe_ciphertext(&secret_key.0, &plaintext_vector.0, noise)
+                .unwrap(),
+        )
+    }
+
+    fn decrypt_glwe_ciphertext_to_plaintext_vector(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        ciphertext: &Self::GlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector16(
+            self.core_engine
+                .decrypt_glwe_ciphertext(&secret_key.0, &ciphertext.0)
+                .unwrap(),
+        )
+    }
+}
+
 impl PrototypesGlweCiphertext<Precision32, BinaryKeyDistribution> for Maker {
     type GlweCiphertextProto = ProtoBinaryGlweCiphertext32;
 
@@ -193,3 +270,225 @@ impl PrototypesGlweCiphertext<Precision64, BinaryKeyDistribution> for Maker {
         )
     }
 }
+
+impl PrototypesGlweCiphertext<Precision128, BinaryKeyDistribution> for Maker {
+    type GlweCiphertextProto = ProtoBinaryGlweCiphertext128;
+
+    fn trivially_encrypt_zeros_to_glwe_ciphertext(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        let plaintext_vector = self
+            .core_engine
+            .create_plaintext_vector(&vec![0u128; poly_size.0])
+            .unwrap();
+        ProtoBinaryGlweCiphertext128(
+            self.core_engine
+                .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)
+                .unwrap(),
+        )
+    }
+
+    fn trivially_encrypt_plaintext_vector_to_glwe_ciphertext(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        plaintext_vector: &Self::PlaintextVectorProto,
+    ) -> Self::GlweCiphertextProto {
+        ProtoBinaryGlweCiphertext128(
+            self.core_engine
+                .trivially_encrypt_glwe_ciphertext(
+                    glwe_dimension.to_glwe_size(),
+                    &plaintext_vector.0,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn trivially_decrypt_glwe_ciphertext(
+        &mut self,
+        ciphertext: &Self::GlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector128(
+            self.core_engine
+                .trivially_decrypt_glwe_ciphertext(&ciphertext.0)
+                .unwrap(),
+        )
+    }
+
+    fn encrypt_plaintext_vector_to_glwe_ciphertext(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+    ) -> Self::GlweCiphertextProto {
+        ProtoBinaryGlweCiphertext128(
+            self.core_engine
+                .encrypt_glwe_ciphertext(&secret_key.0, &plaintext_vector.0, noise)
+                .unwrap(),
+        )
+    }
+
+    fn decrypt_glwe_ciphertext_to_plaintext_vector(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        ciphertext: &Self::GlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector128(
+            self.core_engine
+                .decrypt_glwe_ciphertext(&secret_key.0, &ciphertext.0)
+                .unwrap(),
+        )
+    }
+}
+
+/// A trait allowing to manipulate GLWE ciphertext prototypes in a given polynomial ring.
+///
+/// Contrary to [`PrototypesGlweCiphertext`], whose prototypes are always in the negacyclic ring,
+/// this trait allows the fixtures to be generic over the
+/// [`RingStructure`](`concrete_core::prelude::GlweCiphertextEntity::RingStructure`) of the
+/// ciphertexts.
+pub trait PrototypesRingGlweCiphertext<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    RingStructure: RingStructureMarker,
+>:
+    PrototypesPlaintextVector<Precision> + PrototypesGlweSecretKey<Precision, KeyDistribution>
+{
+    type RingGlweCiphertextProto: GlweCiphertextPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+        RingStructure = RingStructure,
+    >;
+    fn encrypt_plaintext_vector_to_ring_glwe_ciphertext(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+    ) -> Self::RingGlweCiphertextProto;
+    fn decrypt_ring_glwe_ciphertext_to_plaintext_vector(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        ciphertext: &Self::RingGlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto;
+}
+
+impl PrototypesRingGlweCiphertext<Precision32, BinaryKeyDistribution, NegacyclicRing> for Maker {
+    type RingGlweCiphertextProto = ProtoBinaryGlweCiphertext32;
+
+    fn encrypt_plaintext_vector_to_ring_glwe_ciphertext(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+    ) -> Self::RingGlweCiphertextProto {
+        <Maker as PrototypesGlweCiphertext<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::encrypt_plaintext_vector_to_glwe_ciphertext(
+            self, secret_key, plaintext_vector, noise
+        )
+    }
+
+    fn decrypt_ring_glwe_ciphertext_to_plaintext_vector(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        ciphertext: &Self::RingGlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        <Maker as PrototypesGlweCiphertext<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::decrypt_glwe_ciphertext_to_plaintext_vector(
+            self, secret_key, ciphertext
+        )
+    }
+}
+
+impl PrototypesRingGlweCiphertext<Precision32, BinaryKeyDistribution, CyclicRing> for Maker {
+    type RingGlweCiphertextProto = ProtoBinaryCyclicGlweCiphertext32;
+
+    fn encrypt_plaintext_vector_to_ring_glwe_ciphertext(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+    ) -> Self::RingGlweCiphertextProto {
+        let ciphertext: CyclicGlweCiphertext32 = self
+            .core_engine
+            .encrypt_glwe_ciphertext(&secret_key.0, &plaintext_vector.0, noise)
+            .unwrap();
+        ProtoBinaryCyclicGlweCiphertext32(ciphertext)
+    }
+
+    fn decrypt_ring_glwe_ciphertext_to_plaintext_vector(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        ciphertext: &Self::RingGlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector32(
+            self.core_engine
+                .decrypt_glwe_ciphertext(&secret_key.0, &ciphertext.0)
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesRingGlweCiphertext<Precision64, BinaryKeyDistribution, NegacyclicRing> for Maker {
+    type RingGlweCiphertextProto = ProtoBinaryGlweCiphertext64;
+
+    fn encrypt_plaintext_vector_to_ring_glwe_ciphertext(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+    ) -> Self::RingGlweCiphertextProto {
+        <Maker as PrototypesGlweCiphertext<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::encrypt_plaintext_vector_to_glwe_ciphertext(
+            self, secret_key, plaintext_vector, noise
+        )
+    }
+
+    fn decrypt_ring_glwe_ciphertext_to_plaintext_vector(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        ciphertext: &Self::RingGlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        <Maker as PrototypesGlweCiphertext<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::decrypt_glwe_ciphertext_to_plaintext_vector(
+            self, secret_key, ciphertext
+        )
+    }
+}
+
+impl PrototypesRingGlweCiphertext<Precision64, BinaryKeyDistribution, CyclicRing> for Maker {
+    type RingGlweCiphertextProto = ProtoBinaryCyclicGlweCiphertext64;
+
+    fn encrypt_plaintext_vector_to_ring_glwe_ciphertext(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+    ) -> Self::RingGlweCiphertextProto {
+        let ciphertext: CyclicGlweCiphertext64 = self
+            .core_engine
+            .encrypt_glwe_ciphertext(&secret_key.0, &plaintext_vector.0, noise)
+            .unwrap();
+        ProtoBinaryCyclicGlweCiphertext64(ciphertext)
+    }
+
+    fn decrypt_ring_glwe_ciphertext_to_plaintext_vector(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        ciphertext: &Self::RingGlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector64(
+            self.core_engine
+                .decrypt_glwe_ciphertext(&secret_key.0, &ciphertext.0)
+                .unwrap(),
+        )
+    }
+}
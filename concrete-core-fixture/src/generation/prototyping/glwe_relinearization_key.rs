@@ -0,0 +1,75 @@
+use crate::generation::prototypes::{
+    GlweRelinearizationKeyPrototype, ProtoBinaryGlweRelinearizationKey32,
+    ProtoBinaryGlweRelinearizationKey64,
+};
+use crate::generation::prototyping::glwe_secret_key::PrototypesGlweSecretKey;
+use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::GlweRelinearizationKeyCreationEngine;
+
+/// A trait allowing to manipulate glwe relinearization key prototypes.
+pub trait PrototypesGlweRelinearizationKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>: PrototypesGlweSecretKey<Precision, KeyDistribution>
+{
+    type GlweRelinearizationKeyProto: GlweRelinearizationKeyPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn new_glwe_relinearization_key(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweRelinearizationKeyProto;
+}
+
+impl PrototypesGlweRelinearizationKey<Precision32, BinaryKeyDistribution> for Maker {
+    type GlweRelinearizationKeyProto = ProtoBinaryGlweRelinearizationKey32;
+
+    fn new_glwe_relinearization_key(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweRelinearizationKeyProto {
+        ProtoBinaryGlweRelinearizationKey32(
+            self.core_engine
+                .create_glwe_relinearization_key(
+                    &secret_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesGlweRelinearizationKey<Precision64, BinaryKeyDistribution> for Maker {
+    type GlweRelinearizationKeyProto = ProtoBinaryGlweRelinearizationKey64;
+
+    fn new_glwe_relinearization_key(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweRelinearizationKeyProto {
+        ProtoBinaryGlweRelinearizationKey64(
+            self.core_engine
+                .create_glwe_relinearization_key(
+                    &secret_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+}
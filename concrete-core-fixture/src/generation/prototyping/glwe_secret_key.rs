@@ -1,12 +1,18 @@
 use crate::generation::prototypes::{
-    GlweSecretKeyPrototype, ProtoBinaryGlweSecretKey32, ProtoBinaryGlweSecretKey64,
-    ProtoBinaryLweSecretKey32, ProtoBinaryLweSecretKey64,
+    GlweSecretKeyPrototype, ProtoBinaryGlweSecretKey128, ProtoBinaryGlweSecretKey16,
+    ProtoBinaryGlweSecretKey32, ProtoBinaryGlweSecretKey64, ProtoBinaryLweSecretKey128,
+    ProtoBinaryLweSecretKey16, ProtoBinaryLweSecretKey32, ProtoBinaryLweSecretKey64,
 };
 use crate::generation::prototyping::PrototypesLweSecretKey;
-use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use crate::generation::{
+    IntegerPrecision, Maker, Precision128, Precision16, Precision32, Precision64,
+};
 use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
-use concrete_core::prelude::{GlweSecretKeyCreationEngine, GlweToLweSecretKeyTransmutationEngine};
+use concrete_core::prelude::{
+    GlweSecretKeyCreationEngine, GlweToLweSecretKeyTransmutationEngine,
+    LweToGlweSecretKeyTransmutationEngine,
+};
 
 /// A trait allowing to manipulate GLWE secret key prototypes.
 pub trait PrototypesGlweSecretKey<
@@ -27,6 +33,50 @@ pub trait PrototypesGlweSecretKey<
         &mut self,
         glwe_key: &Self::GlweSecretKeyProto,
     ) -> Self::LweSecretKeyProto;
+    fn transmute_lwe_secret_key_to_glwe_secret_key(
+        &mut self,
+        lwe_key: &Self::LweSecretKeyProto,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto;
+}
+
+impl PrototypesGlweSecretKey<Precision16, BinaryKeyDistribution> for Maker {
+    type GlweSecretKeyProto = ProtoBinaryGlweSecretKey16;
+
+    fn new_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        ProtoBinaryGlweSecretKey16(
+            self.core_engine
+                .create_glwe_secret_key(glwe_dimension, polynomial_size)
+                .unwrap(),
+        )
+    }
+
+    fn transmute_glwe_secret_key_to_lwe_secret_key(
+        &mut self,
+        glwe_key: &Self::GlweSecretKeyProto,
+    ) -> Self::LweSecretKeyProto {
+        ProtoBinaryLweSecretKey16(
+            self.core_engine
+                .transmute_glwe_secret_key_to_lwe_secret_key(glwe_key.0.to_owned())
+                .unwrap(),
+        )
+    }
+
+    fn transmute_lwe_secret_key_to_glwe_secret_key(
+        &mut self,
+        lwe_key: &Self::LweSecretKeyProto,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        ProtoBinaryGlweSecretKey16(
+            self.core_engine
+                .transmute_lwe_secret_key_to_glwe_secret_key(lwe_key.0.to_owned(), polynomial_size)
+                .unwrap(),
+        )
+    }
 }
 
 impl PrototypesGlweSecretKey<Precision32, BinaryKeyDistribution> for Maker {
@@ -54,6 +104,18 @@ impl PrototypesGlweSecretKey<Precision32, BinaryKeyDistribution> for Maker {
                 .unwrap(),
         )
     }
+
+    fn transmute_lwe_secret_key_to_glwe_secret_key(
+        &mut self,
+        lwe_key: &Self::LweSecretKeyProto,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        ProtoBinaryGlweSecretKey32(
+            self.core_engine
+                .transmute_lwe_secret_key_to_glwe_secret_key(lwe_key.0.to_owned(), polynomial_size)
+                .unwrap(),
+        )
+    }
 }
 
 impl PrototypesGlweSecretKey<Precision64, BinaryKeyDistribution> for Maker {
@@ -81,4 +143,55 @@ impl PrototypesGlweSecretKey<Precision64, BinaryKeyDistribution> for Maker {
                 .unwrap(),
         )
     }
+
+    fn transmute_lwe_secret_key_to_glwe_secret_key(
+        &mut self,
+        lwe_key: &Self::LweSecretKeyProto,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        ProtoBinaryGlweSecretKey64(
+            self.core_engine
+                .transmute_lwe_secret_key_to_glwe_secret_key(lwe_key.0.to_owned(), polynomial_size)
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesGlweSecretKey<Precision128, BinaryKeyDistribution> for Maker {
+    type GlweSecretKeyProto = ProtoBinaryGlweSecretKey128;
+
+    fn new_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        ProtoBinaryGlweSecretKey128(
+            self.core_engine
+                .create_glwe_secret_key(glwe_dimension, polynomial_size)
+                .unwrap(),
+        )
+    }
+
+    fn transmute_glwe_secret_key_to_lwe_secret_key(
+        &mut self,
+        glwe_key: &Self::GlweSecretKeyProto,
+    ) -> Self::LweSecretKeyProto {
+        ProtoBinaryLweSecretKey128(
+            self.core_engine
+                .transmute_glwe_secret_key_to_lwe_secret_key(glwe_key.0.to_owned())
+                .unwrap(),
+        )
+    }
+
+    fn transmute_lwe_secret_key_to_glwe_secret_key(
+        &mut self,
+        lwe_key: &Self::LweSecretKeyProto,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        ProtoBinaryGlweSecretKey128(
+            self.core_engine
+                .transmute_lwe_secret_key_to_glwe_secret_key(lwe_key.0.to_owned(), polynomial_size)
+                .unwrap(),
+        )
+    }
 }
@@ -1,10 +1,13 @@
 use crate::generation::prototypes::{
-    LweCiphertextPrototype, ProtoBinaryLweCiphertext32, ProtoBinaryLweCiphertext64,
+    LweCiphertextPrototype, ProtoBinaryLweCiphertext128, ProtoBinaryLweCiphertext16,
+    ProtoBinaryLweCiphertext32, ProtoBinaryLweCiphertext64, ProtoPlaintext128, ProtoPlaintext16,
     ProtoPlaintext32, ProtoPlaintext64,
 };
 use crate::generation::prototyping::lwe_secret_key::PrototypesLweSecretKey;
 use crate::generation::prototyping::plaintext::PrototypesPlaintext;
-use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use crate::generation::{
+    IntegerPrecision, Maker, Precision128, Precision16, Precision32, Precision64,
+};
 use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
@@ -50,6 +53,70 @@ pub trait PrototypesLweCiphertext<
     ) -> Self::PlaintextProto;
 }
 
+impl PrototypesLweCiphertext<Precision16, BinaryKeyDistribution> for Maker {
+    type LweCiphertextProto = ProtoBinaryLweCiphertext16;
+
+    fn trivially_encrypt_zero_to_lwe_ciphertext(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Self::LweCiphertextProto {
+        let plaintext = self.core_engine.create_plaintext(&0u16).unwrap();
+        ProtoBinaryLweCiphertext16(
+            self.core_engine
+                .trivially_encrypt_lwe_ciphertext(lwe_dimension.to_lwe_size(), &plaintext)
+                .unwrap(),
+        )
+    }
+
+    fn trivially_encrypt_plaintext_to_lwe_ciphertext(
+        &mut self,
+        lwe_dimension: LweDimension,
+        plaintext: &Self::PlaintextProto,
+    ) -> Self::LweCiphertextProto {
+        ProtoBinaryLweCiphertext16(
+            self.core_engine
+                .trivially_encrypt_lwe_ciphertext(lwe_dimension.to_lwe_size(), &plaintext.0)
+                .unwrap(),
+        )
+    }
+
+    fn encrypt_plaintext_to_lwe_ciphertext(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        plaintext: &Self::PlaintextProto,
+        noise: Variance,
+    ) -> Self::LweCiphertextProto {
+        ProtoBinaryLweCiphertext16(
+            self.core_engine
+                .encrypt_lwe_ciphertext(&secret_key.0, &plaintext.0, noise)
+                .unwrap(),
+        )
+    }
+
+    fn decrypt_lwe_ciphertext_to_plaintext(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        ciphertext: &Self::LweCiphertextProto,
+    ) -> Self::PlaintextProto {
+        ProtoPlaintext16(
+            self.core_engine
+                .decrypt_lwe_ciphertext(&secret_key.0, &ciphertext.0)
+                .unwrap(),
+        )
+    }
+
+    fn trivially_decrypt_lwe_ciphertext_to_plaintext(
+        &mut self,
+        ciphertext: &Self::LweCiphertextProto,
+    ) -> Self::PlaintextProto {
+        ProtoPlaintext16(
+            self.core_engine
+                .trivially_decrypt_lwe_ciphertext(&ciphertext.0)
+                .unwrap(),
+        )
+    }
+}
+
 impl PrototypesLweCiphertext<Precision32, BinaryKeyDistribution> for Maker {
     type LweCiphertextProto = ProtoBinaryLweCiphertext32;
 
@@ -177,3 +244,67 @@ impl PrototypesLweCiphertext<Precision64, BinaryKeyDistribution> for Maker {
         )
     }
 }
+
+impl PrototypesLweCiphertext<Precision128, BinaryKeyDistribution> for Maker {
+    type LweCiphertextProto = ProtoBinaryLweCiphertext128;
+
+    fn trivially_encrypt_zero_to_lwe_ciphertext(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Self::LweCiphertextProto {
+        let plaintext = self.core_engine.create_plaintext(&0u128).unwrap();
+        ProtoBinaryLweCiphertext128(
+            self.core_engine
+                .trivially_encrypt_lwe_ciphertext(lwe_dimension.to_lwe_size(), &plaintext)
+                .unwrap(),
+        )
+    }
+
+    fn trivially_encrypt_plaintext_to_lwe_ciphertext(
+        &mut self,
+        lwe_dimension: LweDimension,
+        plaintext: &Self::PlaintextProto,
+    ) -> Self::LweCiphertextProto {
+        ProtoBinaryLweCiphertext128(
+            self.core_engine
+                .trivially_encrypt_lwe_ciphertext(lwe_dimension.to_lwe_size(), &plaintext.0)
+                .unwrap(),
+        )
+    }
+
+    fn encrypt_plaintext_to_lwe_ciphertext(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        plaintext: &Self::PlaintextProto,
+        noise: Variance,
+    ) -> Self::LweCiphertextProto {
+        ProtoBinaryLweCiphertext128(
+            self.core_engine
+                .encrypt_lwe_ciphertext(&secret_key.0, &plaintext.0, noise)
+                .unwrap(),
+        )
+    }
+
+    fn decrypt_lwe_ciphertext_to_plaintext(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        ciphertext: &Self::LweCiphertextProto,
+    ) -> Self::PlaintextProto {
+        ProtoPlaintext128(
+            self.core_engine
+                .decrypt_lwe_ciphertext(&secret_key.0, &ciphertext.0)
+                .unwrap(),
+        )
+    }
+
+    fn trivially_decrypt_lwe_ciphertext_to_plaintext(
+        &mut self,
+        ciphertext: &Self::LweCiphertextProto,
+    ) -> Self::PlaintextProto {
+        ProtoPlaintext128(
+            self.core_engine
+                .trivially_decrypt_lwe_ciphertext(&ciphertext.0)
+                .unwrap(),
+        )
+    }
+}
@@ -0,0 +1,58 @@
+use crate::generation::prototypes::{
+    LweCiphertextJaggedVectorPrototype, ProtoBinaryLweCiphertextJaggedVector32,
+    ProtoBinaryLweCiphertextJaggedVector64,
+};
+use crate::generation::prototyping::lwe_ciphertext_vector::PrototypesLweCiphertextVector;
+use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use concrete_commons::parameters::LweCiphertextCount;
+use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::LweCiphertextJaggedVectorCreationEngine;
+
+/// A trait allowing to manipulate lwe ciphertext jagged vector prototypes.
+pub trait PrototypesLweCiphertextJaggedVector<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>: PrototypesLweCiphertextVector<Precision, KeyDistribution>
+{
+    type LweCiphertextJaggedVectorProto: LweCiphertextJaggedVectorPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn split_lwe_ciphertext_vector_to_lwe_ciphertext_jagged_vector(
+        &mut self,
+        ciphertext_vector: &Self::LweCiphertextVectorProto,
+        row_lengths: &[LweCiphertextCount],
+    ) -> Self::LweCiphertextJaggedVectorProto;
+}
+
+impl PrototypesLweCiphertextJaggedVector<Precision32, BinaryKeyDistribution> for Maker {
+    type LweCiphertextJaggedVectorProto = ProtoBinaryLweCiphertextJaggedVector32;
+
+    fn split_lwe_ciphertext_vector_to_lwe_ciphertext_jagged_vector(
+        &mut self,
+        ciphertext_vector: &Self::LweCiphertextVectorProto,
+        row_lengths: &[LweCiphertextCount],
+    ) -> Self::LweCiphertextJaggedVectorProto {
+        ProtoBinaryLweCiphertextJaggedVector32(
+            self.core_engine
+                .create_lwe_ciphertext_jagged_vector(ciphertext_vector.0.to_owned(), row_lengths)
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesLweCiphertextJaggedVector<Precision64, BinaryKeyDistribution> for Maker {
+    type LweCiphertextJaggedVectorProto = ProtoBinaryLweCiphertextJaggedVector64;
+
+    fn split_lwe_ciphertext_vector_to_lwe_ciphertext_jagged_vector(
+        &mut self,
+        ciphertext_vector: &Self::LweCiphertextVectorProto,
+        row_lengths: &[LweCiphertextCount],
+    ) -> Self::LweCiphertextJaggedVectorProto {
+        ProtoBinaryLweCiphertextJaggedVector64(
+            self.core_engine
+                .create_lwe_ciphertext_jagged_vector(ciphertext_vector.0.to_owned(), row_lengths)
+                .unwrap(),
+        )
+    }
+}
@@ -0,0 +1,138 @@
+use crate::generation::prototyping::glwe_ciphertext::PrototypesGlweCiphertext;
+use crate::generation::prototyping::lwe_ciphertext::PrototypesLweCiphertext;
+use crate::generation::prototyping::lwe_ciphertext_vector::PrototypesLweCiphertextVector;
+use crate::generation::prototyping::packing_keyswitch_key::PrototypesPackingKeyswitchKey;
+use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use concrete_commons::parameters::{LweDimension, MonomialIndex};
+use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::{
+    GlweCiphertextEntity, LweCiphertextDiscardingExtractionEngine,
+    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine, PackingKeyswitchKeyEntity,
+};
+
+/// A trait allowing to move prototypes between the LWE and GLWE domains.
+///
+/// Fixtures composing several operations across the two domains (an extraction followed by a
+/// keyswitch, a packing followed by a bootstrap, ...) can rely on these transformations to build
+/// their inputs. Contrary to a decryption followed by a fresh encryption, they operate on the
+/// ciphertexts themselves, and keep the noise the measured operation receives unaltered.
+pub trait PrototypesLweGlweTransformation<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>:
+    PrototypesLweCiphertext<Precision, KeyDistribution>
+    + PrototypesLweCiphertextVector<Precision, KeyDistribution>
+    + PrototypesGlweCiphertext<Precision, KeyDistribution>
+    + PrototypesPackingKeyswitchKey<Precision, KeyDistribution, KeyDistribution>
+{
+    /// Extracts the `nth` coefficient of a GLWE ciphertext prototype into an LWE ciphertext
+    /// prototype.
+    ///
+    /// The output is encrypted under the LWE secret key obtained by transmuting the GLWE secret
+    /// key of the input.
+    fn extract_lwe_from_glwe_prototype(
+        &mut self,
+        glwe_ciphertext: &Self::GlweCiphertextProto,
+        nth: MonomialIndex,
+    ) -> Self::LweCiphertextProto;
+
+    /// Packs the LWE ciphertexts of a vector prototype in the coefficients of a GLWE ciphertext
+    /// prototype, using a packing keyswitch key prototype.
+    ///
+    /// The output is encrypted under the output GLWE secret key of the packing keyswitch key.
+    fn pack_lwe_prototypes_into_glwe(
+        &mut self,
+        lwe_ciphertext_vector: &Self::LweCiphertextVectorProto,
+        packing_keyswitch_key: &Self::PackingKeyswitchKeyProto,
+    ) -> Self::GlweCiphertextProto;
+}
+
+impl PrototypesLweGlweTransformation<Precision32, BinaryKeyDistribution> for Maker {
+    fn extract_lwe_from_glwe_prototype(
+        &mut self,
+        glwe_ciphertext: &Self::GlweCiphertextProto,
+        nth: MonomialIndex,
+    ) -> Self::LweCiphertextProto {
+        let lwe_dimension = LweDimension(
+            glwe_ciphertext.0.glwe_dimension().0 * glwe_ciphertext.0.polynomial_size().0,
+        );
+        let mut lwe_ciphertext = <Self as PrototypesLweCiphertext<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            self, lwe_dimension
+        );
+        self.core_engine
+            .discard_extract_lwe_ciphertext(&mut lwe_ciphertext.0, &glwe_ciphertext.0, nth)
+            .unwrap();
+        lwe_ciphertext
+    }
+
+    fn pack_lwe_prototypes_into_glwe(
+        &mut self,
+        lwe_ciphertext_vector: &Self::LweCiphertextVectorProto,
+        packing_keyswitch_key: &Self::PackingKeyswitchKeyProto,
+    ) -> Self::GlweCiphertextProto {
+        let mut glwe_ciphertext = <Self as PrototypesGlweCiphertext<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+            self,
+            packing_keyswitch_key.0.output_glwe_dimension(),
+            packing_keyswitch_key.0.output_polynomial_size(),
+        );
+        self.core_engine
+            .discard_packing_keyswitch_lwe_ciphertext_vector(
+                &mut glwe_ciphertext.0,
+                &lwe_ciphertext_vector.0,
+                &packing_keyswitch_key.0,
+            )
+            .unwrap();
+        glwe_ciphertext
+    }
+}
+
+impl PrototypesLweGlweTransformation<Precision64, BinaryKeyDistribution> for Maker {
+    fn extract_lwe_from_glwe_prototype(
+        &mut self,
+        glwe_ciphertext: &Self::GlweCiphertextProto,
+        nth: MonomialIndex,
+    ) -> Self::LweCiphertextProto {
+        let lwe_dimension = LweDimension(
+            glwe_ciphertext.0.glwe_dimension().0 * glwe_ciphertext.0.polynomial_size().0,
+        );
+        let mut lwe_ciphertext = <Self as PrototypesLweCiphertext<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            self, lwe_dimension
+        );
+        self.core_engine
+            .discard_extract_lwe_ciphertext(&mut lwe_ciphertext.0, &glwe_ciphertext.0, nth)
+            .unwrap();
+        lwe_ciphertext
+    }
+
+    fn pack_lwe_prototypes_into_glwe(
+        &mut self,
+        lwe_ciphertext_vector: &Self::LweCiphertextVectorProto,
+        packing_keyswitch_key: &Self::PackingKeyswitchKeyProto,
+    ) -> Self::GlweCiphertextProto {
+        let mut glwe_ciphertext = <Self as PrototypesGlweCiphertext<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+            self,
+            packing_keyswitch_key.0.output_glwe_dimension(),
+            packing_keyswitch_key.0.output_polynomial_size(),
+        );
+        self.core_engine
+            .discard_packing_keyswitch_lwe_ciphertext_vector(
+                &mut glwe_ciphertext.0,
+                &lwe_ciphertext_vector.0,
+                &packing_keyswitch_key.0,
+            )
+            .unwrap();
+        glwe_ciphertext
+    }
+}
@@ -1,9 +1,12 @@
 use crate::generation::prototypes::{
-    LweKeyswitchKeyPrototype, ProtoBinaryBinaryLweKeyswitchKey32,
+    LweKeyswitchKeyPrototype, ProtoBinaryBinaryLweKeyswitchKey128,
+    ProtoBinaryBinaryLweKeyswitchKey16, ProtoBinaryBinaryLweKeyswitchKey32,
     ProtoBinaryBinaryLweKeyswitchKey64,
 };
 use crate::generation::prototyping::lwe_secret_key::PrototypesLweSecretKey;
-use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use crate::generation::{
+    IntegerPrecision, Maker, Precision128, Precision16, Precision32, Precision64,
+};
 use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
@@ -33,6 +36,33 @@ pub trait PrototypesLweKeyswitchKey<
     ) -> Self::LweKeyswitchKeyProto;
 }
 
+impl PrototypesLweKeyswitchKey<Precision16, BinaryKeyDistribution, BinaryKeyDistribution>
+    for Maker
+{
+    type LweKeyswitchKeyProto = ProtoBinaryBinaryLweKeyswitchKey16;
+
+    fn new_lwe_keyswitch_key(
+        &mut self,
+        input_key: &<Self as PrototypesLweSecretKey<Precision16, BinaryKeyDistribution>>::LweSecretKeyProto,
+        output_key: &<Self as PrototypesLweSecretKey<Precision16, BinaryKeyDistribution>>::LweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweKeyswitchKeyProto {
+        ProtoBinaryBinaryLweKeyswitchKey16(
+            self.core_engine
+                .create_lwe_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+}
+
 impl PrototypesLweKeyswitchKey<Precision32, BinaryKeyDistribution, BinaryKeyDistribution>
     for Maker
 {
@@ -86,3 +116,30 @@ impl PrototypesLweKeyswitchKey<Precision64, BinaryKeyDistribution, BinaryKeyDist
         )
     }
 }
+
+impl PrototypesLweKeyswitchKey<Precision128, BinaryKeyDistribution, BinaryKeyDistribution>
+    for Maker
+{
+    type LweKeyswitchKeyProto = ProtoBinaryBinaryLweKeyswitchKey128;
+
+    fn new_lwe_keyswitch_key(
+        &mut self,
+        input_key: &<Self as PrototypesLweSecretKey<Precision128, BinaryKeyDistribution>>::LweSecretKeyProto,
+        output_key: &<Self as PrototypesLweSecretKey<Precision128, BinaryKeyDistribution>>::LweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweKeyswitchKeyProto {
+        ProtoBinaryBinaryLweKeyswitchKey128(
+            self.core_engine
+                .create_lwe_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+}
@@ -0,0 +1,87 @@
+use crate::generation::prototypes::{
+    LweKeyswitchKeyBatchPrototype, ProtoBinaryBinaryLweKeyswitchKeyBatch32,
+    ProtoBinaryBinaryLweKeyswitchKeyBatch64,
+};
+use crate::generation::prototyping::lwe_keyswitch_key::PrototypesLweKeyswitchKey;
+use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::{
+    LweKeyswitchKeyBatchAppendingEngine, LweKeyswitchKeyBatchCreationEngine, LweKeyswitchKeyEntity,
+};
+
+/// A trait allowing to manipulate lwe keyswitch key batch prototypes.
+///
+/// The keys given to `batch_lwe_keyswitch_keys` must share their parameters, and at least one key
+/// must be given. In the output batch, the key `i` is selected by the index `i`.
+pub trait PrototypesLweKeyswitchKeyBatch<
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+>: PrototypesLweKeyswitchKey<Precision, InputKeyDistribution, OutputKeyDistribution>
+{
+    type LweKeyswitchKeyBatchProto: LweKeyswitchKeyBatchPrototype<
+        Precision = Precision,
+        InputKeyDistribution = InputKeyDistribution,
+        OutputKeyDistribution = OutputKeyDistribution,
+    >;
+    fn batch_lwe_keyswitch_keys(
+        &mut self,
+        keys: &[Self::LweKeyswitchKeyProto],
+    ) -> Self::LweKeyswitchKeyBatchProto;
+}
+
+impl PrototypesLweKeyswitchKeyBatch<Precision32, BinaryKeyDistribution, BinaryKeyDistribution>
+    for Maker
+{
+    type LweKeyswitchKeyBatchProto = ProtoBinaryBinaryLweKeyswitchKeyBatch32;
+
+    fn batch_lwe_keyswitch_keys(
+        &mut self,
+        keys: &[Self::LweKeyswitchKeyProto],
+    ) -> Self::LweKeyswitchKeyBatchProto {
+        let first = &keys[0].0;
+        let mut batch = self
+            .core_engine
+            .create_lwe_keyswitch_key_batch(
+                first.input_lwe_dimension(),
+                first.output_lwe_dimension(),
+                first.decomposition_level_count(),
+                first.decomposition_base_log(),
+            )
+            .unwrap();
+        for key in keys {
+            self.core_engine
+                .append_lwe_keyswitch_key(&mut batch, &key.0)
+                .unwrap();
+        }
+        ProtoBinaryBinaryLweKeyswitchKeyBatch32(batch)
+    }
+}
+
+impl PrototypesLweKeyswitchKeyBatch<Precision64, BinaryKeyDistribution, BinaryKeyDistribution>
+    for Maker
+{
+    type LweKeyswitchKeyBatchProto = ProtoBinaryBinaryLweKeyswitchKeyBatch64;
+
+    fn batch_lwe_keyswitch_keys(
+        &mut self,
+        keys: &[Self::LweKeyswitchKeyProto],
+    ) -> Self::LweKeyswitchKeyBatchProto {
+        let first = &keys[0].0;
+        let mut batch = self
+            .core_engine
+            .create_lwe_keyswitch_key_batch(
+                first.input_lwe_dimension(),
+                first.output_lwe_dimension(),
+                first.decomposition_level_count(),
+                first.decomposition_base_log(),
+            )
+            .unwrap();
+        for key in keys {
+            self.core_engine
+                .append_lwe_keyswitch_key(&mut batch, &key.0)
+                .unwrap();
+        }
+        ProtoBinaryBinaryLweKeyswitchKeyBatch64(batch)
+    }
+}
@@ -0,0 +1,40 @@
+use crate::generation::prototypes::{
+    LwePhaseVectorPrototype, ProtoLwePhaseVector32, ProtoLwePhaseVector64,
+};
+use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use concrete_core::prelude::LwePhaseVectorRetrievalEngine;
+
+/// A trait allowing to manipulate lwe phase vector prototypes.
+pub trait PrototypesLwePhaseVector<Precision: IntegerPrecision> {
+    type LwePhaseVectorProto: LwePhaseVectorPrototype<Precision = Precision>;
+    fn transform_lwe_phase_vector_to_raw_vec(
+        &mut self,
+        phases: &Self::LwePhaseVectorProto,
+    ) -> Vec<Precision::Raw>;
+}
+
+impl PrototypesLwePhaseVector<Precision32> for Maker {
+    type LwePhaseVectorProto = ProtoLwePhaseVector32;
+
+    fn transform_lwe_phase_vector_to_raw_vec(
+        &mut self,
+        phases: &Self::LwePhaseVectorProto,
+    ) -> Vec<u32> {
+        self.core_engine
+            .retrieve_lwe_phase_vector(&phases.0)
+            .unwrap()
+    }
+}
+
+impl PrototypesLwePhaseVector<Precision64> for Maker {
+    type LwePhaseVectorProto = ProtoLwePhaseVector64;
+
+    fn transform_lwe_phase_vector_to_raw_vec(
+        &mut self,
+        phases: &Self::LwePhaseVectorProto,
+    ) -> Vec<u64> {
+        self.core_engine
+            .retrieve_lwe_phase_vector(&phases.0)
+            .unwrap()
+    }
+}
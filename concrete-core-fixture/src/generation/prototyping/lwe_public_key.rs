@@ -0,0 +1,61 @@
+use crate::generation::prototypes::{
+    LwePublicKeyPrototype, ProtoBinaryLwePublicKey32, ProtoBinaryLwePublicKey64,
+};
+use crate::generation::prototyping::lwe_secret_key::PrototypesLweSecretKey;
+use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LwePublicKeyZeroEncryptionCount;
+use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::LwePublicKeyCreationEngine;
+
+/// A trait allowing to manipulate lwe public key prototypes.
+pub trait PrototypesLwePublicKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>: PrototypesLweSecretKey<Precision, KeyDistribution>
+{
+    type LwePublicKeyProto: LwePublicKeyPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn new_lwe_public_key(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Self::LwePublicKeyProto;
+}
+
+impl PrototypesLwePublicKey<Precision32, BinaryKeyDistribution> for Maker {
+    type LwePublicKeyProto = ProtoBinaryLwePublicKey32;
+
+    fn new_lwe_public_key(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Self::LwePublicKeyProto {
+        ProtoBinaryLwePublicKey32(
+            self.core_engine
+                .create_lwe_public_key(&secret_key.0, noise, lwe_public_key_zero_encryption_count)
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesLwePublicKey<Precision64, BinaryKeyDistribution> for Maker {
+    type LwePublicKeyProto = ProtoBinaryLwePublicKey64;
+
+    fn new_lwe_public_key(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Self::LwePublicKeyProto {
+        ProtoBinaryLwePublicKey64(
+            self.core_engine
+                .create_lwe_public_key(&secret_key.0, noise, lwe_public_key_zero_encryption_count)
+                .unwrap(),
+        )
+    }
+}
@@ -1,10 +1,13 @@
 use crate::generation::prototypes::{
-    LweSecretKeyPrototype, ProtoBinaryLweSecretKey32, ProtoBinaryLweSecretKey64,
+    LweSecretKeyPrototype, ProtoBinaryLweSecretKey128, ProtoBinaryLweSecretKey16,
+    ProtoBinaryLweSecretKey32, ProtoBinaryLweSecretKey64,
 };
-use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
-use concrete_commons::parameters::LweDimension;
+use crate::generation::{
+    IntegerPrecision, Maker, Precision128, Precision16, Precision32, Precision64,
+};
+use concrete_commons::parameters::{ClientId, LweDimension, MasterSeed};
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
-use concrete_core::prelude::LweSecretKeyCreationEngine;
+use concrete_core::prelude::{LweSecretKeyCreationEngine, LweSecretKeyDerivationEngine};
 
 /// A trait allowing to manipulate lwe secret key prototypes.
 pub trait PrototypesLweSecretKey<
@@ -19,6 +22,18 @@ pub trait PrototypesLweSecretKey<
     fn new_lwe_secret_key(&mut self, lwe_dimension: LweDimension) -> Self::LweSecretKeyProto;
 }
 
+impl PrototypesLweSecretKey<Precision16, BinaryKeyDistribution> for Maker {
+    type LweSecretKeyProto = ProtoBinaryLweSecretKey16;
+
+    fn new_lwe_secret_key(&mut self, lwe_dimension: LweDimension) -> Self::LweSecretKeyProto {
+        ProtoBinaryLweSecretKey16(
+            self.core_engine
+                .create_lwe_secret_key(lwe_dimension)
+                .unwrap(),
+        )
+    }
+}
+
 impl PrototypesLweSecretKey<Precision32, BinaryKeyDistribution> for Maker {
     type LweSecretKeyProto = ProtoBinaryLweSecretKey32;
 
@@ -42,3 +57,59 @@ impl PrototypesLweSecretKey<Precision64, BinaryKeyDistribution> for Maker {
         )
     }
 }
+
+impl PrototypesLweSecretKey<Precision128, BinaryKeyDistribution> for Maker {
+    type LweSecretKeyProto = ProtoBinaryLweSecretKey128;
+
+    fn new_lwe_secret_key(&mut self, lwe_dimension: LweDimension) -> Self::LweSecretKeyProto {
+        ProtoBinaryLweSecretKey128(
+            self.core_engine
+                .create_lwe_secret_key(lwe_dimension)
+                .unwrap(),
+        )
+    }
+}
+
+/// A trait allowing to derive lwe secret key prototypes from a master seed.
+pub trait PrototypesLweSecretKeyDerivation<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>: PrototypesLweSecretKey<Precision, KeyDistribution>
+{
+    fn derive_lwe_secret_key(
+        &mut self,
+        master_seed: MasterSeed,
+        client_id: ClientId,
+        lwe_dimension: LweDimension,
+    ) -> Self::LweSecretKeyProto;
+}
+
+impl PrototypesLweSecretKeyDerivation<Precision32, BinaryKeyDistribution> for Maker {
+    fn derive_lwe_secret_key(
+        &mut self,
+        master_seed: MasterSeed,
+        client_id: ClientId,
+        lwe_dimension: LweDimension,
+    ) -> Self::LweSecretKeyProto {
+        ProtoBinaryLweSecretKey32(
+            self.core_engine
+                .derive_lwe_secret_key(master_seed, client_id, lwe_dimension)
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesLweSecretKeyDerivation<Precision64, BinaryKeyDistribution> for Maker {
+    fn derive_lwe_secret_key(
+        &mut self,
+        master_seed: MasterSeed,
+        client_id: ClientId,
+        lwe_dimension: LweDimension,
+    ) -> Self::LweSecretKeyProto {
+        ProtoBinaryLweSecretKey64(
+            self.core_engine
+                .derive_lwe_secret_key(master_seed, client_id, lwe_dimension)
+                .unwrap(),
+        )
+    }
+}
@@ -0,0 +1,109 @@
+use crate::generation::prototypes::{
+    LweShrinkingKeyswitchKeyPrototype, ProtoBinaryLweSecretKey32, ProtoBinaryLweSecretKey64,
+    ProtoBinaryLweShrinkingKeyswitchKey32, ProtoBinaryLweShrinkingKeyswitchKey64,
+};
+use crate::generation::prototyping::lwe_secret_key::PrototypesLweSecretKey;
+use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::{LweSecretKeyShrinkingEngine, LweShrinkingKeyswitchKeyCreationEngine};
+
+/// A trait allowing to manipulate lwe shrinking keyswitch key prototypes.
+pub trait PrototypesLweShrinkingKeyswitchKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>: PrototypesLweSecretKey<Precision, KeyDistribution>
+{
+    type LweShrinkingKeyswitchKeyProto: LweShrinkingKeyswitchKeyPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn shrink_lwe_secret_key(
+        &mut self,
+        input_key: &Self::LweSecretKeyProto,
+        output_lwe_dimension: LweDimension,
+    ) -> Self::LweSecretKeyProto;
+    fn new_lwe_shrinking_keyswitch_key(
+        &mut self,
+        input_key: &Self::LweSecretKeyProto,
+        output_key: &Self::LweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweShrinkingKeyswitchKeyProto;
+}
+
+impl PrototypesLweShrinkingKeyswitchKey<Precision32, BinaryKeyDistribution> for Maker {
+    type LweShrinkingKeyswitchKeyProto = ProtoBinaryLweShrinkingKeyswitchKey32;
+
+    fn shrink_lwe_secret_key(
+        &mut self,
+        input_key: &Self::LweSecretKeyProto,
+        output_lwe_dimension: LweDimension,
+    ) -> Self::LweSecretKeyProto {
+        ProtoBinaryLweSecretKey32(
+            self.core_engine
+                .shrink_lwe_secret_key(&input_key.0, output_lwe_dimension)
+                .unwrap(),
+        )
+    }
+
+    fn new_lwe_shrinking_keyswitch_key(
+        &mut self,
+        input_key: &Self::LweSecretKeyProto,
+        output_key: &Self::LweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweShrinkingKeyswitchKeyProto {
+        ProtoBinaryLweShrinkingKeyswitchKey32(
+            self.core_engine
+                .create_lwe_shrinking_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesLweShrinkingKeyswitchKey<Precision64, BinaryKeyDistribution> for Maker {
+    type LweShrinkingKeyswitchKeyProto = ProtoBinaryLweShrinkingKeyswitchKey64;
+
+    fn shrink_lwe_secret_key(
+        &mut self,
+        input_key: &Self::LweSecretKeyProto,
+        output_lwe_dimension: LweDimension,
+    ) -> Self::LweSecretKeyProto {
+        ProtoBinaryLweSecretKey64(
+            self.core_engine
+                .shrink_lwe_secret_key(&input_key.0, output_lwe_dimension)
+                .unwrap(),
+        )
+    }
+
+    fn new_lwe_shrinking_keyswitch_key(
+        &mut self,
+        input_key: &Self::LweSecretKeyProto,
+        output_key: &Self::LweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweShrinkingKeyswitchKeyProto {
+        ProtoBinaryLweShrinkingKeyswitchKey64(
+            self.core_engine
+                .create_lwe_shrinking_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+}
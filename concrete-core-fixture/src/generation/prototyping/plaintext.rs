@@ -1,5 +1,9 @@
-use crate::generation::prototypes::{PlaintextPrototype, ProtoPlaintext32, ProtoPlaintext64};
-use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use crate::generation::prototypes::{
+    PlaintextPrototype, ProtoPlaintext128, ProtoPlaintext16, ProtoPlaintext32, ProtoPlaintext64,
+};
+use crate::generation::{
+    IntegerPrecision, Maker, Precision128, Precision16, Precision32, Precision64,
+};
 use concrete_core::prelude::{PlaintextCreationEngine, PlaintextRetrievalEngine};
 
 /// A trait allowing to manipulate plaintext prototypes.
@@ -9,6 +13,18 @@ pub trait PrototypesPlaintext<Precision: IntegerPrecision> {
     fn transform_plaintext_to_raw(&mut self, plaintext: &Self::PlaintextProto) -> Precision::Raw;
 }
 
+impl PrototypesPlaintext<Precision16> for Maker {
+    type PlaintextProto = ProtoPlaintext16;
+
+    fn transform_raw_to_plaintext(&mut self, raw: &u16) -> Self::PlaintextProto {
+        ProtoPlaintext16(self.core_engine.create_plaintext(raw).unwrap())
+    }
+
+    fn transform_plaintext_to_raw(&mut self, plaintext: &Self::PlaintextProto) -> u16 {
+        self.core_engine.retrieve_plaintext(&plaintext.0).unwrap()
+    }
+}
+
 impl PrototypesPlaintext<Precision32> for Maker {
     type PlaintextProto = ProtoPlaintext32;
 
@@ -32,3 +48,15 @@ impl PrototypesPlaintext<Precision64> for Maker {
         self.core_engine.retrieve_plaintext(&plaintext.0).unwrap()
     }
 }
+
+impl PrototypesPlaintext<Precision128> for Maker {
+    type PlaintextProto = ProtoPlaintext128;
+
+    fn transform_raw_to_plaintext(&mut self, raw: &u128) -> Self::PlaintextProto {
+        ProtoPlaintext128(self.core_engine.create_plaintext(raw).unwrap())
+    }
+
+    fn transform_plaintext_to_raw(&mut self, plaintext: &Self::PlaintextProto) -> u128 {
+        self.core_engine.retrieve_plaintext(&plaintext.0).unwrap()
+    }
+}
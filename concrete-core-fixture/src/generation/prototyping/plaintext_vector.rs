@@ -1,7 +1,10 @@
 use crate::generation::prototypes::{
-    PlaintextVectorPrototype, ProtoPlaintextVector32, ProtoPlaintextVector64,
+    PlaintextVectorPrototype, ProtoPlaintextVector128, ProtoPlaintextVector16,
+    ProtoPlaintextVector32, ProtoPlaintextVector64,
+};
+use crate::generation::{
+    IntegerPrecision, Maker, Precision128, Precision16, Precision32, Precision64,
 };
-use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
 use concrete_core::prelude::{PlaintextVectorCreationEngine, PlaintextVectorRetrievalEngine};
 
 /// A trait allowing to manipulate plaintext vector prototypes.
@@ -17,6 +20,23 @@ pub trait PrototypesPlaintextVector<Precision: IntegerPrecision> {
     ) -> Vec<Precision::Raw>;
 }
 
+impl PrototypesPlaintextVector<Precision16> for Maker {
+    type PlaintextVectorProto = ProtoPlaintextVector16;
+
+    fn transform_raw_vec_to_plaintext_vector(&mut self, raw: &[u16]) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector16(self.core_engine.create_plaintext_vector(raw).unwrap())
+    }
+
+    fn transform_plaintext_vector_to_raw_vec(
+        &mut self,
+        plaintext: &Self::PlaintextVectorProto,
+    ) -> Vec<u16> {
+        self.core_engine
+            .retrieve_plaintext_vector(&plaintext.0)
+            .unwrap()
+    }
+}
+
 impl PrototypesPlaintextVector<Precision32> for Maker {
     type PlaintextVectorProto = ProtoPlaintextVector32;
 
@@ -50,3 +70,23 @@ impl PrototypesPlaintextVector<Precision64> for Maker {
             .unwrap()
     }
 }
+
+impl PrototypesPlaintextVector<Precision128> for Maker {
+    type PlaintextVectorProto = ProtoPlaintextVector128;
+
+    fn transform_raw_vec_to_plaintext_vector(
+        &mut self,
+        raw: &[u128],
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector128(self.core_engine.create_plaintext_vector(raw).unwrap())
+    }
+
+    fn transform_plaintext_vector_to_raw_vec(
+        &mut self,
+        plaintext: &Self::PlaintextVectorProto,
+    ) -> Vec<u128> {
+        self.core_engine
+            .retrieve_plaintext_vector(&plaintext.0)
+            .unwrap()
+    }
+}
@@ -48,3 +48,58 @@ mod backend_core {
         }
     }
 }
+
+#[cfg(feature = "backend_reference")]
+mod backend_reference {
+    use crate::generation::prototypes::{ProtoCleartext32, ProtoCleartext64};
+    use crate::generation::synthesizing::SynthesizesCleartext;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        CleartextCreationEngine, CleartextRetrievalEngine, DestructionEngine, ReferenceCleartext32,
+        ReferenceCleartext64,
+    };
+
+    impl SynthesizesCleartext<Precision32, ReferenceCleartext32> for Maker {
+        fn synthesize_cleartext(
+            &mut self,
+            prototype: &Self::CleartextProto,
+        ) -> ReferenceCleartext32 {
+            let raw = self.core_engine.retrieve_cleartext(&prototype.0).unwrap();
+            self.reference_engine.create_cleartext(&raw).unwrap()
+        }
+
+        fn unsynthesize_cleartext(
+            &mut self,
+            entity: &ReferenceCleartext32,
+        ) -> Self::CleartextProto {
+            let raw = self.reference_engine.retrieve_cleartext(entity).unwrap();
+            ProtoCleartext32(self.core_engine.create_cleartext(&raw).unwrap())
+        }
+
+        fn destroy_cleartext(&mut self, entity: ReferenceCleartext32) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesCleartext<Precision64, ReferenceCleartext64> for Maker {
+        fn synthesize_cleartext(
+            &mut self,
+            prototype: &Self::CleartextProto,
+        ) -> ReferenceCleartext64 {
+            let raw = self.core_engine.retrieve_cleartext(&prototype.0).unwrap();
+            self.reference_engine.create_cleartext(&raw).unwrap()
+        }
+
+        fn unsynthesize_cleartext(
+            &mut self,
+            entity: &ReferenceCleartext64,
+        ) -> Self::CleartextProto {
+            let raw = self.reference_engine.retrieve_cleartext(entity).unwrap();
+            ProtoCleartext64(self.core_engine.create_cleartext(&raw).unwrap())
+        }
+
+        fn destroy_cleartext(&mut self, entity: ReferenceCleartext64) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
+}
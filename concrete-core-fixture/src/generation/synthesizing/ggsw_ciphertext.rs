@@ -81,10 +81,9 @@ mod backend_core {
 
         fn unsynthesize_ggsw_ciphertext(
             &mut self,
-            _entity: &FourierGgswCiphertext32,
+            entity: &FourierGgswCiphertext32,
         ) -> Self::GgswCiphertextProto {
-            // FIXME:
-            unimplemented!("The backward fourier conversion was not yet implemented");
+            ProtoBinaryGgswCiphertext32(self.core_engine.convert_ggsw_ciphertext(entity).unwrap())
         }
 
         fn destroy_ggsw_ciphertext(&mut self, entity: FourierGgswCiphertext32) {
@@ -104,10 +103,9 @@ mod backend_core {
 
         fn unsynthesize_ggsw_ciphertext(
             &mut self,
-            _entity: &FourierGgswCiphertext64,
+            entity: &FourierGgswCiphertext64,
         ) -> Self::GgswCiphertextProto {
-            // FIXME:
-            unimplemented!("The backward fourier conversion was not yet implemented");
+            ProtoBinaryGgswCiphertext64(self.core_engine.convert_ggsw_ciphertext(entity).unwrap())
         }
 
         fn destroy_ggsw_ciphertext(&mut self, entity: FourierGgswCiphertext64) {
@@ -115,3 +113,66 @@ mod backend_core {
         }
     }
 }
+
+#[cfg(feature = "backend_reference")]
+mod backend_reference {
+    use crate::generation::prototypes::{ProtoBinaryGgswCiphertext32, ProtoBinaryGgswCiphertext64};
+    use crate::generation::synthesizing::SynthesizesGgswCiphertext;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, GgswCiphertextConversionEngine, ReferenceGgswCiphertext32,
+        ReferenceGgswCiphertext64,
+    };
+
+    impl SynthesizesGgswCiphertext<Precision32, ReferenceGgswCiphertext32> for Maker {
+        fn synthesize_ggsw_ciphertext(
+            &mut self,
+            prototype: &Self::GgswCiphertextProto,
+        ) -> ReferenceGgswCiphertext32 {
+            self.reference_engine
+                .convert_ggsw_ciphertext(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_ggsw_ciphertext(
+            &mut self,
+            entity: &ReferenceGgswCiphertext32,
+        ) -> Self::GgswCiphertextProto {
+            ProtoBinaryGgswCiphertext32(
+                self.reference_engine
+                    .convert_ggsw_ciphertext(entity)
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_ggsw_ciphertext(&mut self, entity: ReferenceGgswCiphertext32) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesGgswCiphertext<Precision64, ReferenceGgswCiphertext64> for Maker {
+        fn synthesize_ggsw_ciphertext(
+            &mut self,
+            prototype: &Self::GgswCiphertextProto,
+        ) -> ReferenceGgswCiphertext64 {
+            self.reference_engine
+                .convert_ggsw_ciphertext(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_ggsw_ciphertext(
+            &mut self,
+            entity: &ReferenceGgswCiphertext64,
+        ) -> Self::GgswCiphertextProto {
+            ProtoBinaryGgswCiphertext64(
+                self.reference_engine
+                    .convert_ggsw_ciphertext(entity)
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_ggsw_ciphertext(&mut self, entity: ReferenceGgswCiphertext64) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
+}
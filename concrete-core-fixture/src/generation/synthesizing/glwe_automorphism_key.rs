@@ -0,0 +1,69 @@
+use crate::generation::prototyping::PrototypesGlweAutomorphismKey;
+use crate::generation::IntegerPrecision;
+use concrete_core::prelude::GlweAutomorphismKeyEntity;
+
+pub trait SynthesizesGlweAutomorphismKey<Precision: IntegerPrecision, GlweAutomorphismKey>:
+    PrototypesGlweAutomorphismKey<Precision, GlweAutomorphismKey::KeyDistribution>
+where
+    GlweAutomorphismKey: GlweAutomorphismKeyEntity,
+{
+    fn synthesize_glwe_automorphism_key(
+        &mut self,
+        prototype: &Self::GlweAutomorphismKeyProto,
+    ) -> GlweAutomorphismKey;
+    fn unsynthesize_glwe_automorphism_key(
+        &mut self,
+        entity: &GlweAutomorphismKey,
+    ) -> Self::GlweAutomorphismKeyProto;
+    fn destroy_glwe_automorphism_key(&mut self, entity: GlweAutomorphismKey);
+}
+
+#[cfg(feature = "backend_core")]
+mod backend_core {
+    use crate::generation::prototypes::{
+        ProtoBinaryGlweAutomorphismKey32, ProtoBinaryGlweAutomorphismKey64,
+    };
+    use crate::generation::synthesizing::SynthesizesGlweAutomorphismKey;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{DestructionEngine, GlweAutomorphismKey32, GlweAutomorphismKey64};
+
+    impl SynthesizesGlweAutomorphismKey<Precision32, GlweAutomorphismKey32> for Maker {
+        fn synthesize_glwe_automorphism_key(
+            &mut self,
+            prototype: &Self::GlweAutomorphismKeyProto,
+        ) -> GlweAutomorphismKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_automorphism_key(
+            &mut self,
+            entity: &GlweAutomorphismKey32,
+        ) -> Self::GlweAutomorphismKeyProto {
+            ProtoBinaryGlweAutomorphismKey32(entity.to_owned())
+        }
+
+        fn destroy_glwe_automorphism_key(&mut self, entity: GlweAutomorphismKey32) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesGlweAutomorphismKey<Precision64, GlweAutomorphismKey64> for Maker {
+        fn synthesize_glwe_automorphism_key(
+            &mut self,
+            prototype: &Self::GlweAutomorphismKeyProto,
+        ) -> GlweAutomorphismKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_automorphism_key(
+            &mut self,
+            entity: &GlweAutomorphismKey64,
+        ) -> Self::GlweAutomorphismKeyProto {
+            ProtoBinaryGlweAutomorphismKey64(entity.to_owned())
+        }
+
+        fn destroy_glwe_automorphism_key(&mut self, entity: GlweAutomorphismKey64) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::generation::prototyping::PrototypesGlweCiphertext;
+use crate::generation::prototyping::{PrototypesGlweCiphertext, PrototypesRingGlweCiphertext};
 use crate::generation::IntegerPrecision;
 use concrete_core::prelude::GlweCiphertextEntity;
 
@@ -19,12 +19,63 @@ where
     fn destroy_glwe_ciphertext(&mut self, entity: GlweCiphertext);
 }
 
+/// A trait allowing to synthesize an actual glwe ciphertext entity of any polynomial ring from a
+/// prototype.
+pub trait SynthesizesRingGlweCiphertext<Precision: IntegerPrecision, GlweCiphertext>:
+    PrototypesRingGlweCiphertext<
+    Precision,
+    GlweCiphertext::KeyDistribution,
+    GlweCiphertext::RingStructure,
+>
+where
+    GlweCiphertext: GlweCiphertextEntity,
+{
+    fn synthesize_ring_glwe_ciphertext(
+        &mut self,
+        prototype: &Self::RingGlweCiphertextProto,
+    ) -> GlweCiphertext;
+    fn unsynthesize_ring_glwe_ciphertext(
+        &mut self,
+        entity: &GlweCiphertext,
+    ) -> Self::RingGlweCiphertextProto;
+    fn destroy_ring_glwe_ciphertext(&mut self, entity: GlweCiphertext);
+}
+
 #[cfg(feature = "backend_core")]
 mod backend_core {
-    use crate::generation::prototypes::{ProtoBinaryGlweCiphertext32, ProtoBinaryGlweCiphertext64};
-    use crate::generation::synthesizing::SynthesizesGlweCiphertext;
-    use crate::generation::{Maker, Precision32, Precision64};
-    use concrete_core::prelude::{DestructionEngine, GlweCiphertext32, GlweCiphertext64};
+    use crate::generation::prototypes::{
+        ProtoBinaryCyclicGlweCiphertext32, ProtoBinaryCyclicGlweCiphertext64,
+        ProtoBinaryGlweCiphertext128, ProtoBinaryGlweCiphertext16, ProtoBinaryGlweCiphertext32,
+        ProtoBinaryGlweCiphertext64,
+    };
+    use crate::generation::synthesizing::{
+        SynthesizesGlweCiphertext, SynthesizesRingGlweCiphertext,
+    };
+    use crate::generation::{Maker, Precision128, Precision16, Precision32, Precision64};
+    use concrete_core::prelude::{
+        CyclicGlweCiphertext32, CyclicGlweCiphertext64, DestructionEngine, GlweCiphertext128,
+        GlweCiphertext16, GlweCiphertext32, GlweCiphertext64,
+    };
+
+    impl SynthesizesGlweCiphertext<Precision16, GlweCiphertext16> for Maker {
+        fn synthesize_glwe_ciphertext(
+            &mut self,
+            prototype: &Self::GlweCiphertextProto,
+        ) -> GlweCiphertext16 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_ciphertext(
+            &mut self,
+            entity: &GlweCiphertext16,
+        ) -> Self::GlweCiphertextProto {
+            ProtoBinaryGlweCiphertext16(entity.to_owned())
+        }
+
+        fn destroy_glwe_ciphertext(&mut self, entity: GlweCiphertext16) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
 
     impl SynthesizesGlweCiphertext<Precision32, GlweCiphertext32> for Maker {
         fn synthesize_glwe_ciphertext(
@@ -65,4 +116,199 @@ mod backend_core {
             self.core_engine.destroy(entity).unwrap();
         }
     }
+
+    impl SynthesizesGlweCiphertext<Precision128, GlweCiphertext128> for Maker {
+        fn synthesize_glwe_ciphertext(
+            &mut self,
+            prototype: &Self::GlweCiphertextProto,
+        ) -> GlweCiphertext128 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_ciphertext(
+            &mut self,
+            entity: &GlweCiphertext128,
+        ) -> Self::GlweCiphertextProto {
+            ProtoBinaryGlweCiphertext128(entity.to_owned())
+        }
+
+        fn destroy_glwe_ciphertext(&mut self, entity: GlweCiphertext128) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesRingGlweCiphertext<Precision32, GlweCiphertext32> for Maker {
+        fn synthesize_ring_glwe_ciphertext(
+            &mut self,
+            prototype: &Self::RingGlweCiphertextProto,
+        ) -> GlweCiphertext32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_ring_glwe_ciphertext(
+            &mut self,
+            entity: &GlweCiphertext32,
+        ) -> Self::RingGlweCiphertextProto {
+            ProtoBinaryGlweCiphertext32(entity.to_owned())
+        }
+
+        fn destroy_ring_glwe_ciphertext(&mut self, entity: GlweCiphertext32) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesRingGlweCiphertext<Precision32, CyclicGlweCiphertext32> for Maker {
+        fn synthesize_ring_glwe_ciphertext(
+            &mut self,
+            prototype: &Self::RingGlweCiphertextProto,
+        ) -> CyclicGlweCiphertext32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_ring_glwe_ciphertext(
+            &mut self,
+            entity: &CyclicGlweCiphertext32,
+        ) -> Self::RingGlweCiphertextProto {
+            ProtoBinaryCyclicGlweCiphertext32(entity.to_owned())
+        }
+
+        fn destroy_ring_glwe_ciphertext(&mut self, entity: CyclicGlweCiphertext32) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesRingGlweCiphertext<Precision64, GlweCiphertext64> for Maker {
+        fn synthesize_ring_glwe_ciphertext(
+            &mut self,
+            prototype: &Self::RingGlweCiphertextProto,
+        ) -> GlweCiphertext64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_ring_glwe_ciphertext(
+            &mut self,
+            entity: &GlweCiphertext64,
+        ) -> Self::RingGlweCiphertextProto {
+            ProtoBinaryGlweCiphertext64(entity.to_owned())
+        }
+
+        fn destroy_ring_glwe_ciphertext(&mut self, entity: GlweCiphertext64) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesRingGlweCiphertext<Precision64, CyclicGlweCiphertext64> for Maker {
+        fn synthesize_ring_glwe_ciphertext(
+            &mut self,
+            prototype: &Self::RingGlweCiphertextProto,
+        ) -> CyclicGlweCiphertext64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_ring_glwe_ciphertext(
+            &mut self,
+            entity: &CyclicGlweCiphertext64,
+        ) -> Self::RingGlweCiphertextProto {
+            ProtoBinaryCyclicGlweCiphertext64(entity.to_owned())
+        }
+
+        fn destroy_ring_glwe_ciphertext(&mut self, entity: CyclicGlweCiphertext64) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+}
+
+#[cfg(feature = "backend_reference")]
+mod backend_reference {
+    use crate::generation::prototypes::{ProtoBinaryGlweCiphertext32, ProtoBinaryGlweCiphertext64};
+    use crate::generation::synthesizing::SynthesizesGlweCiphertext;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, GlweCiphertextConversionEngine, ReferenceGlweCiphertext32,
+        ReferenceGlweCiphertext64,
+    };
+
+    impl SynthesizesGlweCiphertext<Precision32, ReferenceGlweCiphertext32> for Maker {
+        fn synthesize_glwe_ciphertext(
+            &mut self,
+            prototype: &Self::GlweCiphertextProto,
+        ) -> ReferenceGlweCiphertext32 {
+            self.reference_engine
+                .convert_glwe_ciphertext(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_glwe_ciphertext(
+            &mut self,
+            entity: &ReferenceGlweCiphertext32,
+        ) -> Self::GlweCiphertextProto {
+            ProtoBinaryGlweCiphertext32(
+                self.reference_engine
+                    .convert_glwe_ciphertext(entity)
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_glwe_ciphertext(&mut self, entity: ReferenceGlweCiphertext32) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesGlweCiphertext<Precision64, ReferenceGlweCiphertext64> for Maker {
+        fn synthesize_glwe_ciphertext(
+            &mut self,
+            prototype: &Self::GlweCiphertextProto,
+        ) -> ReferenceGlweCiphertext64 {
+            self.reference_engine
+                .convert_glwe_ciphertext(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_glwe_ciphertext(
+            &mut self,
+            entity: &ReferenceGlweCiphertext64,
+        ) -> Self::GlweCiphertextProto {
+            ProtoBinaryGlweCiphertext64(
+                self.reference_engine
+                    .convert_glwe_ciphertext(entity)
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_glwe_ciphertext(&mut self, entity: ReferenceGlweCiphertext64) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
+}
+
+#[cfg(feature = "backend_cuda")]
+mod backend_cuda {
+    use crate::generation::prototypes::ProtoBinaryGlweCiphertext64;
+    use crate::generation::synthesizing::SynthesizesGlweCiphertext;
+    use crate::generation::{Maker, Precision64};
+    use concrete_core::prelude::{
+        CudaGlweCiphertext64, DestructionEngine, GlweCiphertextConversionEngine,
+    };
+
+    impl SynthesizesGlweCiphertext<Precision64, CudaGlweCiphertext64> for Maker {
+        fn synthesize_glwe_ciphertext(
+            &mut self,
+            prototype: &Self::GlweCiphertextProto,
+        ) -> CudaGlweCiphertext64 {
+            self.cuda_engine()
+                .convert_glwe_ciphertext(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_glwe_ciphertext(
+            &mut self,
+            entity: &CudaGlweCiphertext64,
+        ) -> Self::GlweCiphertextProto {
+            ProtoBinaryGlweCiphertext64(self.cuda_engine().convert_glwe_ciphertext(entity).unwrap())
+        }
+
+        fn destroy_glwe_ciphertext(&mut self, entity: CudaGlweCiphertext64) {
+            self.cuda_engine().destroy(entity).unwrap();
+        }
+    }
 }
@@ -0,0 +1,71 @@
+use crate::generation::prototyping::PrototypesGlweRelinearizationKey;
+use crate::generation::IntegerPrecision;
+use concrete_core::prelude::GlweRelinearizationKeyEntity;
+
+pub trait SynthesizesGlweRelinearizationKey<Precision: IntegerPrecision, GlweRelinearizationKey>:
+    PrototypesGlweRelinearizationKey<Precision, GlweRelinearizationKey::KeyDistribution>
+where
+    GlweRelinearizationKey: GlweRelinearizationKeyEntity,
+{
+    fn synthesize_glwe_relinearization_key(
+        &mut self,
+        prototype: &Self::GlweRelinearizationKeyProto,
+    ) -> GlweRelinearizationKey;
+    fn unsynthesize_glwe_relinearization_key(
+        &mut self,
+        entity: &GlweRelinearizationKey,
+    ) -> Self::GlweRelinearizationKeyProto;
+    fn destroy_glwe_relinearization_key(&mut self, entity: GlweRelinearizationKey);
+}
+
+#[cfg(feature = "backend_core")]
+mod backend_core {
+    use crate::generation::prototypes::{
+        ProtoBinaryGlweRelinearizationKey32, ProtoBinaryGlweRelinearizationKey64,
+    };
+    use crate::generation::synthesizing::SynthesizesGlweRelinearizationKey;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, GlweRelinearizationKey32, GlweRelinearizationKey64,
+    };
+
+    impl SynthesizesGlweRelinearizationKey<Precision32, GlweRelinearizationKey32> for Maker {
+        fn synthesize_glwe_relinearization_key(
+            &mut self,
+            prototype: &Self::GlweRelinearizationKeyProto,
+        ) -> GlweRelinearizationKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_relinearization_key(
+            &mut self,
+            entity: &GlweRelinearizationKey32,
+        ) -> Self::GlweRelinearizationKeyProto {
+            ProtoBinaryGlweRelinearizationKey32(entity.to_owned())
+        }
+
+        fn destroy_glwe_relinearization_key(&mut self, entity: GlweRelinearizationKey32) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesGlweRelinearizationKey<Precision64, GlweRelinearizationKey64> for Maker {
+        fn synthesize_glwe_relinearization_key(
+            &mut self,
+            prototype: &Self::GlweRelinearizationKeyProto,
+        ) -> GlweRelinearizationKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_relinearization_key(
+            &mut self,
+            entity: &GlweRelinearizationKey64,
+        ) -> Self::GlweRelinearizationKeyProto {
+            ProtoBinaryGlweRelinearizationKey64(entity.to_owned())
+        }
+
+        fn destroy_glwe_relinearization_key(&mut self, entity: GlweRelinearizationKey64) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+}
@@ -16,10 +16,35 @@ where
 
 #[cfg(feature = "backend_core")]
 mod backend_core {
-    use crate::generation::prototypes::{ProtoBinaryGlweSecretKey32, ProtoBinaryGlweSecretKey64};
+    use crate::generation::prototypes::{
+        ProtoBinaryGlweSecretKey128, ProtoBinaryGlweSecretKey16, ProtoBinaryGlweSecretKey32,
+        ProtoBinaryGlweSecretKey64,
+    };
     use crate::generation::synthesizing::SynthesizesGlweSecretKey;
-    use crate::generation::{Maker, Precision32, Precision64};
-    use concrete_core::prelude::{DestructionEngine, GlweSecretKey32, GlweSecretKey64};
+    use crate::generation::{Maker, Precision128, Precision16, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, GlweSecretKey128, GlweSecretKey16, GlweSecretKey32, GlweSecretKey64,
+    };
+
+    impl SynthesizesGlweSecretKey<Precision16, GlweSecretKey16> for Maker {
+        fn synthesize_glwe_secret_key(
+            &mut self,
+            prototype: &Self::GlweSecretKeyProto,
+        ) -> GlweSecretKey16 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_secret_key(
+            &mut self,
+            entity: &GlweSecretKey16,
+        ) -> Self::GlweSecretKeyProto {
+            ProtoBinaryGlweSecretKey16(entity.to_owned())
+        }
+
+        fn destroy_glwe_secret_key(&mut self, entity: GlweSecretKey16) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
 
     impl SynthesizesGlweSecretKey<Precision32, GlweSecretKey32> for Maker {
         fn synthesize_glwe_secret_key(
@@ -60,4 +85,87 @@ mod backend_core {
             self.core_engine.destroy(entity).unwrap();
         }
     }
+
+    impl SynthesizesGlweSecretKey<Precision128, GlweSecretKey128> for Maker {
+        fn synthesize_glwe_secret_key(
+            &mut self,
+            prototype: &Self::GlweSecretKeyProto,
+        ) -> GlweSecretKey128 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_secret_key(
+            &mut self,
+            entity: &GlweSecretKey128,
+        ) -> Self::GlweSecretKeyProto {
+            ProtoBinaryGlweSecretKey128(entity.to_owned())
+        }
+
+        fn destroy_glwe_secret_key(&mut self, entity: GlweSecretKey128) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+}
+
+#[cfg(feature = "backend_reference")]
+mod backend_reference {
+    use crate::generation::prototypes::{ProtoBinaryGlweSecretKey32, ProtoBinaryGlweSecretKey64};
+    use crate::generation::synthesizing::SynthesizesGlweSecretKey;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, GlweSecretKeyConversionEngine, ReferenceGlweSecretKey32,
+        ReferenceGlweSecretKey64,
+    };
+
+    impl SynthesizesGlweSecretKey<Precision32, ReferenceGlweSecretKey32> for Maker {
+        fn synthesize_glwe_secret_key(
+            &mut self,
+            prototype: &Self::GlweSecretKeyProto,
+        ) -> ReferenceGlweSecretKey32 {
+            self.reference_engine
+                .convert_glwe_secret_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_glwe_secret_key(
+            &mut self,
+            entity: &ReferenceGlweSecretKey32,
+        ) -> Self::GlweSecretKeyProto {
+            ProtoBinaryGlweSecretKey32(
+                self.reference_engine
+                    .convert_glwe_secret_key(entity)
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_glwe_secret_key(&mut self, entity: ReferenceGlweSecretKey32) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesGlweSecretKey<Precision64, ReferenceGlweSecretKey64> for Maker {
+        fn synthesize_glwe_secret_key(
+            &mut self,
+            prototype: &Self::GlweSecretKeyProto,
+        ) -> ReferenceGlweSecretKey64 {
+            self.reference_engine
+                .convert_glwe_secret_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_glwe_secret_key(
+            &mut self,
+            entity: &ReferenceGlweSecretKey64,
+        ) -> Self::GlweSecretKeyProto {
+            ProtoBinaryGlweSecretKey64(
+                self.reference_engine
+                    .convert_glwe_secret_key(entity)
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_glwe_secret_key(&mut self, entity: ReferenceGlweSecretKey64) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
 }
@@ -119,3 +119,104 @@ mod backend_core {
         }
     }
 }
+
+#[cfg(feature = "backend_reference")]
+mod backend_reference {
+    use crate::generation::prototypes::{
+        ProtoBinaryBinaryLweBootstrapKey32, ProtoBinaryBinaryLweBootstrapKey64,
+    };
+    use crate::generation::synthesizing::SynthesizesLweBootstrapKey;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, LweBootstrapKeyConversionEngine, ReferenceLweBootstrapKey32,
+        ReferenceLweBootstrapKey64,
+    };
+
+    impl SynthesizesLweBootstrapKey<Precision32, ReferenceLweBootstrapKey32> for Maker {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> ReferenceLweBootstrapKey32 {
+            self.reference_engine
+                .convert_lwe_bootstrap_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            entity: &ReferenceLweBootstrapKey32,
+        ) -> Self::LweBootstrapKeyProto {
+            ProtoBinaryBinaryLweBootstrapKey32(
+                self.reference_engine
+                    .convert_lwe_bootstrap_key(entity)
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_lwe_bootstrap_key(&mut self, entity: ReferenceLweBootstrapKey32) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesLweBootstrapKey<Precision64, ReferenceLweBootstrapKey64> for Maker {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> ReferenceLweBootstrapKey64 {
+            self.reference_engine
+                .convert_lwe_bootstrap_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            entity: &ReferenceLweBootstrapKey64,
+        ) -> Self::LweBootstrapKeyProto {
+            ProtoBinaryBinaryLweBootstrapKey64(
+                self.reference_engine
+                    .convert_lwe_bootstrap_key(entity)
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_lwe_bootstrap_key(&mut self, entity: ReferenceLweBootstrapKey64) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
+}
+
+#[cfg(feature = "backend_cuda")]
+mod backend_cuda {
+    use crate::generation::prototypes::ProtoBinaryBinaryLweBootstrapKey64;
+    use crate::generation::synthesizing::SynthesizesLweBootstrapKey;
+    use crate::generation::{Maker, Precision64};
+    use concrete_core::prelude::{
+        CudaLweBootstrapKey64, DestructionEngine, LweBootstrapKeyConversionEngine,
+    };
+
+    impl SynthesizesLweBootstrapKey<Precision64, CudaLweBootstrapKey64> for Maker {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> CudaLweBootstrapKey64 {
+            self.cuda_engine()
+                .convert_lwe_bootstrap_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            entity: &CudaLweBootstrapKey64,
+        ) -> Self::LweBootstrapKeyProto {
+            ProtoBinaryBinaryLweBootstrapKey64(
+                self.cuda_engine()
+                    .convert_lwe_bootstrap_key(entity)
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_lwe_bootstrap_key(&mut self, entity: CudaLweBootstrapKey64) {
+            self.cuda_engine().destroy(entity).unwrap();
+        }
+    }
+}
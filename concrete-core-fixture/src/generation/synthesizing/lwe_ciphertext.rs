@@ -15,10 +15,35 @@ where
 
 #[cfg(feature = "backend_core")]
 mod backend_core {
-    use crate::generation::prototypes::{ProtoBinaryLweCiphertext32, ProtoBinaryLweCiphertext64};
+    use crate::generation::prototypes::{
+        ProtoBinaryLweCiphertext128, ProtoBinaryLweCiphertext16, ProtoBinaryLweCiphertext32,
+        ProtoBinaryLweCiphertext64,
+    };
     use crate::generation::synthesizing::SynthesizesLweCiphertext;
-    use crate::generation::{Maker, Precision32, Precision64};
-    use concrete_core::prelude::{DestructionEngine, LweCiphertext32, LweCiphertext64};
+    use crate::generation::{Maker, Precision128, Precision16, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, LweCiphertext128, LweCiphertext16, LweCiphertext32, LweCiphertext64,
+    };
+
+    impl SynthesizesLweCiphertext<Precision16, LweCiphertext16> for Maker {
+        fn synthesize_lwe_ciphertext(
+            &mut self,
+            prototype: &Self::LweCiphertextProto,
+        ) -> LweCiphertext16 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_ciphertext(
+            &mut self,
+            entity: &LweCiphertext16,
+        ) -> Self::LweCiphertextProto {
+            ProtoBinaryLweCiphertext16(entity.to_owned())
+        }
+
+        fn destroy_lwe_ciphertext(&mut self, entity: LweCiphertext16) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
 
     impl SynthesizesLweCiphertext<Precision32, LweCiphertext32> for Maker {
         fn synthesize_lwe_ciphertext(
@@ -59,4 +84,119 @@ mod backend_core {
             self.core_engine.destroy(entity).unwrap();
         }
     }
+
+    impl SynthesizesLweCiphertext<Precision128, LweCiphertext128> for Maker {
+        fn synthesize_lwe_ciphertext(
+            &mut self,
+            prototype: &Self::LweCiphertextProto,
+        ) -> LweCiphertext128 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_ciphertext(
+            &mut self,
+            entity: &LweCiphertext128,
+        ) -> Self::LweCiphertextProto {
+            ProtoBinaryLweCiphertext128(entity.to_owned())
+        }
+
+        fn destroy_lwe_ciphertext(&mut self, entity: LweCiphertext128) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+}
+
+#[cfg(feature = "backend_reference")]
+mod backend_reference {
+    use crate::generation::prototypes::{ProtoBinaryLweCiphertext32, ProtoBinaryLweCiphertext64};
+    use crate::generation::synthesizing::SynthesizesLweCiphertext;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, LweCiphertextConversionEngine, ReferenceLweCiphertext32,
+        ReferenceLweCiphertext64,
+    };
+
+    impl SynthesizesLweCiphertext<Precision32, ReferenceLweCiphertext32> for Maker {
+        fn synthesize_lwe_ciphertext(
+            &mut self,
+            prototype: &Self::LweCiphertextProto,
+        ) -> ReferenceLweCiphertext32 {
+            self.reference_engine
+                .convert_lwe_ciphertext(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_ciphertext(
+            &mut self,
+            entity: &ReferenceLweCiphertext32,
+        ) -> Self::LweCiphertextProto {
+            ProtoBinaryLweCiphertext32(
+                self.reference_engine
+                    .convert_lwe_ciphertext(entity)
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_lwe_ciphertext(&mut self, entity: ReferenceLweCiphertext32) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesLweCiphertext<Precision64, ReferenceLweCiphertext64> for Maker {
+        fn synthesize_lwe_ciphertext(
+            &mut self,
+            prototype: &Self::LweCiphertextProto,
+        ) -> ReferenceLweCiphertext64 {
+            self.reference_engine
+                .convert_lwe_ciphertext(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_ciphertext(
+            &mut self,
+            entity: &ReferenceLweCiphertext64,
+        ) -> Self::LweCiphertextProto {
+            ProtoBinaryLweCiphertext64(
+                self.reference_engine
+                    .convert_lwe_ciphertext(entity)
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_lwe_ciphertext(&mut self, entity: ReferenceLweCiphertext64) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
+}
+
+#[cfg(feature = "backend_cuda")]
+mod backend_cuda {
+    use crate::generation::prototypes::ProtoBinaryLweCiphertext64;
+    use crate::generation::synthesizing::SynthesizesLweCiphertext;
+    use crate::generation::{Maker, Precision64};
+    use concrete_core::prelude::{
+        CudaLweCiphertext64, DestructionEngine, LweCiphertextConversionEngine,
+    };
+
+    impl SynthesizesLweCiphertext<Precision64, CudaLweCiphertext64> for Maker {
+        fn synthesize_lwe_ciphertext(
+            &mut self,
+            prototype: &Self::LweCiphertextProto,
+        ) -> CudaLweCiphertext64 {
+            self.cuda_engine()
+                .convert_lwe_ciphertext(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_ciphertext(
+            &mut self,
+            entity: &CudaLweCiphertext64,
+        ) -> Self::LweCiphertextProto {
+            ProtoBinaryLweCiphertext64(self.cuda_engine().convert_lwe_ciphertext(entity).unwrap())
+        }
+
+        fn destroy_lwe_ciphertext(&mut self, entity: CudaLweCiphertext64) {
+            self.cuda_engine().destroy(entity).unwrap();
+        }
+    }
 }
@@ -0,0 +1,70 @@
+use crate::generation::prototyping::PrototypesLweCiphertextJaggedVector;
+use crate::generation::IntegerPrecision;
+use concrete_core::prelude::LweCiphertextJaggedVectorEntity;
+
+/// A trait allowing to synthesize an actual lwe ciphertext jagged vector entity from a prototype.
+pub trait SynthesizesLweCiphertextJaggedVector<
+    Precision: IntegerPrecision,
+    LweCiphertextJaggedVector,
+>:
+    PrototypesLweCiphertextJaggedVector<Precision, LweCiphertextJaggedVector::KeyDistribution> where
+    LweCiphertextJaggedVector: LweCiphertextJaggedVectorEntity,
+{
+    fn synthesize_lwe_ciphertext_jagged_vector(
+        &mut self,
+        prototype: &Self::LweCiphertextJaggedVectorProto,
+    ) -> LweCiphertextJaggedVector;
+    fn unsynthesize_lwe_ciphertext_jagged_vector(
+        &mut self,
+        entity: &LweCiphertextJaggedVector,
+    ) -> Self::LweCiphertextJaggedVectorProto;
+    fn destroy_lwe_ciphertext_jagged_vector(&mut self, entity: LweCiphertextJaggedVector);
+}
+
+#[cfg(feature = "backend_core")]
+mod backend_core {
+    use crate::generation::prototypes::{
+        ProtoBinaryLweCiphertextJaggedVector32, ProtoBinaryLweCiphertextJaggedVector64,
+    };
+    use crate::generation::synthesizing::SynthesizesLweCiphertextJaggedVector;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, LweCiphertextJaggedVector32, LweCiphertextJaggedVector64,
+    };
+
+    impl SynthesizesLweCiphertextJaggedVector<Precision32, LweCiphertextJaggedVector32> for Maker {
+        fn synthesize_lwe_ciphertext_jagged_vector(
+            &mut self,
+            prototype: &Self::LweCiphertextJaggedVectorProto,
+        ) -> LweCiphertextJaggedVector32 {
+            prototype.0.to_owned()
+        }
+        fn unsynthesize_lwe_ciphertext_jagged_vector(
+            &mut self,
+            entity: &LweCiphertextJaggedVector32,
+        ) -> Self::LweCiphertextJaggedVectorProto {
+            ProtoBinaryLweCiphertextJaggedVector32(entity.to_owned())
+        }
+        fn destroy_lwe_ciphertext_jagged_vector(&mut self, entity: LweCiphertextJaggedVector32) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesLweCiphertextJaggedVector<Precision64, LweCiphertextJaggedVector64> for Maker {
+        fn synthesize_lwe_ciphertext_jagged_vector(
+            &mut self,
+            prototype: &Self::LweCiphertextJaggedVectorProto,
+        ) -> LweCiphertextJaggedVector64 {
+            prototype.0.to_owned()
+        }
+        fn unsynthesize_lwe_ciphertext_jagged_vector(
+            &mut self,
+            entity: &LweCiphertextJaggedVector64,
+        ) -> Self::LweCiphertextJaggedVectorProto {
+            ProtoBinaryLweCiphertextJaggedVector64(entity.to_owned())
+        }
+        fn destroy_lwe_ciphertext_jagged_vector(&mut self, entity: LweCiphertextJaggedVector64) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+}
@@ -25,11 +25,36 @@ where
 #[cfg(feature = "backend_core")]
 mod backend_core {
     use crate::generation::prototypes::{
+        ProtoBinaryBinaryLweKeyswitchKey128, ProtoBinaryBinaryLweKeyswitchKey16,
         ProtoBinaryBinaryLweKeyswitchKey32, ProtoBinaryBinaryLweKeyswitchKey64,
     };
     use crate::generation::synthesizing::SynthesizesLweKeyswitchKey;
-    use crate::generation::{Maker, Precision32, Precision64};
-    use concrete_core::prelude::{DestructionEngine, LweKeyswitchKey32, LweKeyswitchKey64};
+    use crate::generation::{Maker, Precision128, Precision16, Precision32, Precision64};
+    use concrete_core::prelude::{
+        ColumnMajorLweKeyswitchKey32, ColumnMajorLweKeyswitchKey64, DestructionEngine,
+        LweKeyswitchKey128, LweKeyswitchKey16, LweKeyswitchKey32, LweKeyswitchKey64,
+        LweKeyswitchKeyConversionEngine,
+    };
+
+    impl SynthesizesLweKeyswitchKey<Precision16, LweKeyswitchKey16> for Maker {
+        fn synthesize_lwe_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweKeyswitchKeyProto,
+        ) -> LweKeyswitchKey16 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_keyswitch_key(
+            &mut self,
+            entity: &LweKeyswitchKey16,
+        ) -> Self::LweKeyswitchKeyProto {
+            ProtoBinaryBinaryLweKeyswitchKey16(entity.to_owned())
+        }
+
+        fn destroy_lwe_keyswitch_key(&mut self, entity: LweKeyswitchKey16) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
 
     impl SynthesizesLweKeyswitchKey<Precision32, LweKeyswitchKey32> for Maker {
         fn synthesize_lwe_keyswitch_key(
@@ -70,4 +95,169 @@ mod backend_core {
             self.core_engine.destroy(entity).unwrap();
         }
     }
+
+    impl SynthesizesLweKeyswitchKey<Precision128, LweKeyswitchKey128> for Maker {
+        fn synthesize_lwe_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweKeyswitchKeyProto,
+        ) -> LweKeyswitchKey128 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_keyswitch_key(
+            &mut self,
+            entity: &LweKeyswitchKey128,
+        ) -> Self::LweKeyswitchKeyProto {
+            ProtoBinaryBinaryLweKeyswitchKey128(entity.to_owned())
+        }
+
+        fn destroy_lwe_keyswitch_key(&mut self, entity: LweKeyswitchKey128) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesLweKeyswitchKey<Precision32, ColumnMajorLweKeyswitchKey32> for Maker {
+        fn synthesize_lwe_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweKeyswitchKeyProto,
+        ) -> ColumnMajorLweKeyswitchKey32 {
+            self.core_engine
+                .convert_lwe_keyswitch_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_keyswitch_key(
+            &mut self,
+            _entity: &ColumnMajorLweKeyswitchKey32,
+        ) -> Self::LweKeyswitchKeyProto {
+            todo!()
+        }
+
+        fn destroy_lwe_keyswitch_key(&mut self, entity: ColumnMajorLweKeyswitchKey32) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesLweKeyswitchKey<Precision64, ColumnMajorLweKeyswitchKey64> for Maker {
+        fn synthesize_lwe_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweKeyswitchKeyProto,
+        ) -> ColumnMajorLweKeyswitchKey64 {
+            self.core_engine
+                .convert_lwe_keyswitch_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_keyswitch_key(
+            &mut self,
+            _entity: &ColumnMajorLweKeyswitchKey64,
+        ) -> Self::LweKeyswitchKeyProto {
+            todo!()
+        }
+
+        fn destroy_lwe_keyswitch_key(&mut self, entity: ColumnMajorLweKeyswitchKey64) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+}
+
+#[cfg(feature = "backend_reference")]
+mod backend_reference {
+    use crate::generation::prototypes::{
+        ProtoBinaryBinaryLweKeyswitchKey32, ProtoBinaryBinaryLweKeyswitchKey64,
+    };
+    use crate::generation::synthesizing::SynthesizesLweKeyswitchKey;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, LweKeyswitchKeyConversionEngine, ReferenceLweKeyswitchKey32,
+        ReferenceLweKeyswitchKey64,
+    };
+
+    impl SynthesizesLweKeyswitchKey<Precision32, ReferenceLweKeyswitchKey32> for Maker {
+        fn synthesize_lwe_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweKeyswitchKeyProto,
+        ) -> ReferenceLweKeyswitchKey32 {
+            self.reference_engine
+                .convert_lwe_keyswitch_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_keyswitch_key(
+            &mut self,
+            entity: &ReferenceLweKeyswitchKey32,
+        ) -> Self::LweKeyswitchKeyProto {
+            ProtoBinaryBinaryLweKeyswitchKey32(
+                self.reference_engine
+                    .convert_lwe_keyswitch_key(entity)
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_lwe_keyswitch_key(&mut self, entity: ReferenceLweKeyswitchKey32) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesLweKeyswitchKey<Precision64, ReferenceLweKeyswitchKey64> for Maker {
+        fn synthesize_lwe_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweKeyswitchKeyProto,
+        ) -> ReferenceLweKeyswitchKey64 {
+            self.reference_engine
+                .convert_lwe_keyswitch_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_keyswitch_key(
+            &mut self,
+            entity: &ReferenceLweKeyswitchKey64,
+        ) -> Self::LweKeyswitchKeyProto {
+            ProtoBinaryBinaryLweKeyswitchKey64(
+                self.reference_engine
+                    .convert_lwe_keyswitch_key(entity)
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_lwe_keyswitch_key(&mut self, entity: ReferenceLweKeyswitchKey64) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
+}
+
+#[cfg(feature = "backend_cuda")]
+mod backend_cuda {
+    use crate::generation::prototypes::ProtoBinaryBinaryLweKeyswitchKey64;
+    use crate::generation::synthesizing::SynthesizesLweKeyswitchKey;
+    use crate::generation::{Maker, Precision64};
+    use concrete_core::prelude::{
+        CudaLweKeyswitchKey64, DestructionEngine, LweKeyswitchKeyConversionEngine,
+    };
+
+    impl SynthesizesLweKeyswitchKey<Precision64, CudaLweKeyswitchKey64> for Maker {
+        fn synthesize_lwe_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweKeyswitchKeyProto,
+        ) -> CudaLweKeyswitchKey64 {
+            self.cuda_engine()
+                .convert_lwe_keyswitch_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_keyswitch_key(
+            &mut self,
+            entity: &CudaLweKeyswitchKey64,
+        ) -> Self::LweKeyswitchKeyProto {
+            ProtoBinaryBinaryLweKeyswitchKey64(
+                self.cuda_engine()
+                    .convert_lwe_keyswitch_key(entity)
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_lwe_keyswitch_key(&mut self, entity: CudaLweKeyswitchKey64) {
+            self.cuda_engine().destroy(entity).unwrap();
+        }
+    }
 }
@@ -0,0 +1,75 @@
+use crate::generation::prototyping::PrototypesLweKeyswitchKeyBatch;
+use crate::generation::IntegerPrecision;
+use concrete_core::prelude::LweKeyswitchKeyBatchEntity;
+
+pub trait SynthesizesLweKeyswitchKeyBatch<Precision: IntegerPrecision, LweKeyswitchKeyBatch>:
+    PrototypesLweKeyswitchKeyBatch<
+    Precision,
+    LweKeyswitchKeyBatch::InputKeyDistribution,
+    LweKeyswitchKeyBatch::OutputKeyDistribution,
+>
+where
+    LweKeyswitchKeyBatch: LweKeyswitchKeyBatchEntity,
+{
+    fn synthesize_lwe_keyswitch_key_batch(
+        &mut self,
+        prototype: &Self::LweKeyswitchKeyBatchProto,
+    ) -> LweKeyswitchKeyBatch;
+    fn unsynthesize_lwe_keyswitch_key_batch(
+        &mut self,
+        entity: &LweKeyswitchKeyBatch,
+    ) -> Self::LweKeyswitchKeyBatchProto;
+    fn destroy_lwe_keyswitch_key_batch(&mut self, entity: LweKeyswitchKeyBatch);
+}
+
+#[cfg(feature = "backend_core")]
+mod backend_core {
+    use crate::generation::prototypes::{
+        ProtoBinaryBinaryLweKeyswitchKeyBatch32, ProtoBinaryBinaryLweKeyswitchKeyBatch64,
+    };
+    use crate::generation::synthesizing::SynthesizesLweKeyswitchKeyBatch;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, LweKeyswitchKeyBatch32, LweKeyswitchKeyBatch64,
+    };
+
+    impl SynthesizesLweKeyswitchKeyBatch<Precision32, LweKeyswitchKeyBatch32> for Maker {
+        fn synthesize_lwe_keyswitch_key_batch(
+            &mut self,
+            prototype: &Self::LweKeyswitchKeyBatchProto,
+        ) -> LweKeyswitchKeyBatch32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_keyswitch_key_batch(
+            &mut self,
+            entity: &LweKeyswitchKeyBatch32,
+        ) -> Self::LweKeyswitchKeyBatchProto {
+            ProtoBinaryBinaryLweKeyswitchKeyBatch32(entity.to_owned())
+        }
+
+        fn destroy_lwe_keyswitch_key_batch(&mut self, entity: LweKeyswitchKeyBatch32) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesLweKeyswitchKeyBatch<Precision64, LweKeyswitchKeyBatch64> for Maker {
+        fn synthesize_lwe_keyswitch_key_batch(
+            &mut self,
+            prototype: &Self::LweKeyswitchKeyBatchProto,
+        ) -> LweKeyswitchKeyBatch64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_keyswitch_key_batch(
+            &mut self,
+            entity: &LweKeyswitchKeyBatch64,
+        ) -> Self::LweKeyswitchKeyBatchProto {
+            ProtoBinaryBinaryLweKeyswitchKeyBatch64(entity.to_owned())
+        }
+
+        fn destroy_lwe_keyswitch_key_batch(&mut self, entity: LweKeyswitchKeyBatch64) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+}
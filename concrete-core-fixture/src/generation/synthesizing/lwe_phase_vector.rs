@@ -0,0 +1,68 @@
+use crate::generation::prototyping::PrototypesLwePhaseVector;
+use crate::generation::IntegerPrecision;
+use concrete_core::prelude::LwePhaseVectorEntity;
+
+/// A trait allowing to synthesize an actual lwe phase vector entity from a prototype.
+pub trait SynthesizesLwePhaseVector<Precision: IntegerPrecision, LwePhaseVector>:
+    PrototypesLwePhaseVector<Precision>
+where
+    LwePhaseVector: LwePhaseVectorEntity,
+{
+    fn synthesize_lwe_phase_vector(
+        &mut self,
+        prototype: &Self::LwePhaseVectorProto,
+    ) -> LwePhaseVector;
+    fn unsynthesize_lwe_phase_vector(
+        &mut self,
+        entity: &LwePhaseVector,
+    ) -> Self::LwePhaseVectorProto;
+    fn destroy_lwe_phase_vector(&mut self, entity: LwePhaseVector);
+}
+
+#[cfg(feature = "backend_core")]
+mod backend_core {
+    use crate::generation::prototypes::{ProtoLwePhaseVector32, ProtoLwePhaseVector64};
+    use crate::generation::synthesizing::SynthesizesLwePhaseVector;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{DestructionEngine, LwePhaseVector32, LwePhaseVector64};
+
+    impl SynthesizesLwePhaseVector<Precision32, LwePhaseVector32> for Maker {
+        fn synthesize_lwe_phase_vector(
+            &mut self,
+            prototype: &Self::LwePhaseVectorProto,
+        ) -> LwePhaseVector32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_phase_vector(
+            &mut self,
+            entity: &LwePhaseVector32,
+        ) -> Self::LwePhaseVectorProto {
+            ProtoLwePhaseVector32(entity.to_owned())
+        }
+
+        fn destroy_lwe_phase_vector(&mut self, entity: LwePhaseVector32) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesLwePhaseVector<Precision64, LwePhaseVector64> for Maker {
+        fn synthesize_lwe_phase_vector(
+            &mut self,
+            prototype: &Self::LwePhaseVectorProto,
+        ) -> LwePhaseVector64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_phase_vector(
+            &mut self,
+            entity: &LwePhaseVector64,
+        ) -> Self::LwePhaseVectorProto {
+            ProtoLwePhaseVector64(entity.to_owned())
+        }
+
+        fn destroy_lwe_phase_vector(&mut self, entity: LwePhaseVector64) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::generation::prototyping::PrototypesLwePublicKey;
+use crate::generation::IntegerPrecision;
+use concrete_core::prelude::LwePublicKeyEntity;
+
+pub trait SynthesizesLwePublicKey<Precision: IntegerPrecision, LwePublicKey>:
+    PrototypesLwePublicKey<Precision, LwePublicKey::KeyDistribution>
+where
+    LwePublicKey: LwePublicKeyEntity,
+{
+    fn synthesize_lwe_public_key(&mut self, prototype: &Self::LwePublicKeyProto) -> LwePublicKey;
+    fn unsynthesize_lwe_public_key(&mut self, entity: &LwePublicKey) -> Self::LwePublicKeyProto;
+    fn destroy_lwe_public_key(&mut self, entity: LwePublicKey);
+}
+
+#[cfg(feature = "backend_core")]
+mod backend_core {
+    use crate::generation::prototypes::{ProtoBinaryLwePublicKey32, ProtoBinaryLwePublicKey64};
+    use crate::generation::synthesizing::SynthesizesLwePublicKey;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{DestructionEngine, LwePublicKey32, LwePublicKey64};
+
+    impl SynthesizesLwePublicKey<Precision32, LwePublicKey32> for Maker {
+        fn synthesize_lwe_public_key(
+            &mut self,
+            prototype: &Self::LwePublicKeyProto,
+        ) -> LwePublicKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_public_key(
+            &mut self,
+            entity: &LwePublicKey32,
+        ) -> Self::LwePublicKeyProto {
+            ProtoBinaryLwePublicKey32(entity.to_owned())
+        }
+
+        fn destroy_lwe_public_key(&mut self, entity: LwePublicKey32) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesLwePublicKey<Precision64, LwePublicKey64> for Maker {
+        fn synthesize_lwe_public_key(
+            &mut self,
+            prototype: &Self::LwePublicKeyProto,
+        ) -> LwePublicKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_public_key(
+            &mut self,
+            entity: &LwePublicKey64,
+        ) -> Self::LwePublicKeyProto {
+            ProtoBinaryLwePublicKey64(entity.to_owned())
+        }
+
+        fn destroy_lwe_public_key(&mut self, entity: LwePublicKey64) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+}
@@ -15,10 +15,34 @@ where
 
 #[cfg(feature = "backend_core")]
 mod backend_core {
-    use crate::generation::prototypes::{ProtoBinaryLweSecretKey32, ProtoBinaryLweSecretKey64};
+    use crate::generation::prototypes::{
+        ProtoBinaryLweSecretKey128, ProtoBinaryLweSecretKey16, ProtoBinaryLweSecretKey32,
+        ProtoBinaryLweSecretKey64,
+    };
     use crate::generation::synthesizing::SynthesizesLweSecretKey;
-    use crate::generation::{Maker, Precision32, Precision64};
-    use concrete_core::prelude::{DestructionEngine, LweSecretKey32, LweSecretKey64};
+    use crate::generation::{Maker, Precision128, Precision16, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, LweSecretKey128, LweSecretKey16, LweSecretKey32, LweSecretKey64,
+    };
+
+    impl SynthesizesLweSecretKey<Precision16, LweSecretKey16> for Maker {
+        fn synthesize_lwe_secret_key(
+            &mut self,
+            prototype: &Self::LweSecretKeyProto,
+        ) -> LweSecretKey16 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_secret_key(
+            &mut self,
+            entity: &LweSecretKey16,
+        ) -> Self::LweSecretKeyProto {
+            ProtoBinaryLweSecretKey16(entity.to_owned())
+        }
+        fn destroy_lwe_secret_key(&mut self, entity: LweSecretKey16) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
 
     impl SynthesizesLweSecretKey<Precision32, LweSecretKey32> for Maker {
         fn synthesize_lwe_secret_key(
@@ -58,4 +82,87 @@ mod backend_core {
             self.core_engine.destroy(entity).unwrap();
         }
     }
+
+    impl SynthesizesLweSecretKey<Precision128, LweSecretKey128> for Maker {
+        fn synthesize_lwe_secret_key(
+            &mut self,
+            prototype: &Self::LweSecretKeyProto,
+        ) -> LweSecretKey128 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_secret_key(
+            &mut self,
+            entity: &LweSecretKey128,
+        ) -> Self::LweSecretKeyProto {
+            ProtoBinaryLweSecretKey128(entity.to_owned())
+        }
+
+        fn destroy_lwe_secret_key(&mut self, entity: LweSecretKey128) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+}
+
+#[cfg(feature = "backend_reference")]
+mod backend_reference {
+    use crate::generation::prototypes::{ProtoBinaryLweSecretKey32, ProtoBinaryLweSecretKey64};
+    use crate::generation::synthesizing::SynthesizesLweSecretKey;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, LweSecretKeyConversionEngine, ReferenceLweSecretKey32,
+        ReferenceLweSecretKey64,
+    };
+
+    impl SynthesizesLweSecretKey<Precision32, ReferenceLweSecretKey32> for Maker {
+        fn synthesize_lwe_secret_key(
+            &mut self,
+            prototype: &Self::LweSecretKeyProto,
+        ) -> ReferenceLweSecretKey32 {
+            self.reference_engine
+                .convert_lwe_secret_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_secret_key(
+            &mut self,
+            entity: &ReferenceLweSecretKey32,
+        ) -> Self::LweSecretKeyProto {
+            ProtoBinaryLweSecretKey32(
+                self.reference_engine
+                    .convert_lwe_secret_key(entity)
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_lwe_secret_key(&mut self, entity: ReferenceLweSecretKey32) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesLweSecretKey<Precision64, ReferenceLweSecretKey64> for Maker {
+        fn synthesize_lwe_secret_key(
+            &mut self,
+            prototype: &Self::LweSecretKeyProto,
+        ) -> ReferenceLweSecretKey64 {
+            self.reference_engine
+                .convert_lwe_secret_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_secret_key(
+            &mut self,
+            entity: &ReferenceLweSecretKey64,
+        ) -> Self::LweSecretKeyProto {
+            ProtoBinaryLweSecretKey64(
+                self.reference_engine
+                    .convert_lwe_secret_key(entity)
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_lwe_secret_key(&mut self, entity: ReferenceLweSecretKey64) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
 }
@@ -0,0 +1,71 @@
+use crate::generation::prototyping::PrototypesLweShrinkingKeyswitchKey;
+use crate::generation::IntegerPrecision;
+use concrete_core::prelude::LweShrinkingKeyswitchKeyEntity;
+
+pub trait SynthesizesLweShrinkingKeyswitchKey<Precision: IntegerPrecision, LweShrinkingKeyswitchKey>:
+    PrototypesLweShrinkingKeyswitchKey<Precision, LweShrinkingKeyswitchKey::KeyDistribution>
+where
+    LweShrinkingKeyswitchKey: LweShrinkingKeyswitchKeyEntity,
+{
+    fn synthesize_lwe_shrinking_keyswitch_key(
+        &mut self,
+        prototype: &Self::LweShrinkingKeyswitchKeyProto,
+    ) -> LweShrinkingKeyswitchKey;
+    fn unsynthesize_lwe_shrinking_keyswitch_key(
+        &mut self,
+        entity: &LweShrinkingKeyswitchKey,
+    ) -> Self::LweShrinkingKeyswitchKeyProto;
+    fn destroy_lwe_shrinking_keyswitch_key(&mut self, entity: LweShrinkingKeyswitchKey);
+}
+
+#[cfg(feature = "backend_core")]
+mod backend_core {
+    use crate::generation::prototypes::{
+        ProtoBinaryLweShrinkingKeyswitchKey32, ProtoBinaryLweShrinkingKeyswitchKey64,
+    };
+    use crate::generation::synthesizing::SynthesizesLweShrinkingKeyswitchKey;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, LweShrinkingKeyswitchKey32, LweShrinkingKeyswitchKey64,
+    };
+
+    impl SynthesizesLweShrinkingKeyswitchKey<Precision32, LweShrinkingKeyswitchKey32> for Maker {
+        fn synthesize_lwe_shrinking_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweShrinkingKeyswitchKeyProto,
+        ) -> LweShrinkingKeyswitchKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_shrinking_keyswitch_key(
+            &mut self,
+            entity: &LweShrinkingKeyswitchKey32,
+        ) -> Self::LweShrinkingKeyswitchKeyProto {
+            ProtoBinaryLweShrinkingKeyswitchKey32(entity.to_owned())
+        }
+
+        fn destroy_lwe_shrinking_keyswitch_key(&mut self, entity: LweShrinkingKeyswitchKey32) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesLweShrinkingKeyswitchKey<Precision64, LweShrinkingKeyswitchKey64> for Maker {
+        fn synthesize_lwe_shrinking_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweShrinkingKeyswitchKeyProto,
+        ) -> LweShrinkingKeyswitchKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_shrinking_keyswitch_key(
+            &mut self,
+            entity: &LweShrinkingKeyswitchKey64,
+        ) -> Self::LweShrinkingKeyswitchKeyProto {
+            ProtoBinaryLweShrinkingKeyswitchKey64(entity.to_owned())
+        }
+
+        fn destroy_lwe_shrinking_keyswitch_key(&mut self, entity: LweShrinkingKeyswitchKey64) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+}
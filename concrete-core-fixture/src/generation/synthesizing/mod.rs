@@ -2,33 +2,105 @@
 //!
 //! This module allows to convert back and forth between prototypical entities and the actual entity
 //! types used for tests.
+//!
+//! Since every backend synthesizes its entities from the same prototypes, an entity of a backend
+//! can be transferred to another one by going through its prototype, e.g. with
+//! [`Maker::transfer_glwe_ciphertext`]. This allows to feed the exact same inputs to the engines
+//! of two backends.
 
 mod cleartext;
 mod cleartext_vector;
 mod ggsw_ciphertext;
+mod glwe_automorphism_key;
 mod glwe_ciphertext;
 mod glwe_ciphertext_vector;
+mod glwe_relinearization_key;
 mod glwe_secret_key;
 mod lwe_bootstrap_key;
 mod lwe_ciphertext;
+mod lwe_ciphertext_jagged_vector;
 mod lwe_ciphertext_vector;
 mod lwe_ciphertext_vector_glwe_ciphertext_packing_keyswitch_key;
 mod lwe_keyswitch_key;
+mod lwe_keyswitch_key_batch;
+mod lwe_phase_vector;
+mod lwe_public_key;
 mod lwe_secret_key;
+mod lwe_seeded_bootstrap_key;
+mod lwe_seeded_ciphertext;
+mod lwe_shrinking_keyswitch_key;
 mod plaintext;
 mod plaintext_vector;
 
 pub use cleartext::*;
 pub use cleartext_vector::*;
 pub use ggsw_ciphertext::*;
+pub use glwe_automorphism_key::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_vector::*;
+pub use glwe_relinearization_key::*;
 pub use glwe_secret_key::*;
 pub use lwe_bootstrap_key::*;
 pub use lwe_ciphertext::*;
+pub use lwe_ciphertext_jagged_vector::*;
 pub use lwe_ciphertext_vector::*;
 pub use lwe_ciphertext_vector_glwe_ciphertext_packing_keyswitch_key::*;
 pub use lwe_keyswitch_key::*;
+pub use lwe_keyswitch_key_batch::*;
+pub use lwe_phase_vector::*;
+pub use lwe_public_key::*;
 pub use lwe_secret_key::*;
+pub use lwe_seeded_bootstrap_key::*;
+pub use lwe_seeded_ciphertext::*;
+pub use lwe_shrinking_keyswitch_key::*;
 pub use plaintext::*;
 pub use plaintext_vector::*;
+
+use crate::generation::{IntegerPrecision, Maker};
+use concrete_core::prelude::{GgswCiphertextEntity, GlweCiphertextEntity};
+
+impl Maker {
+    /// Converts a glwe ciphertext entity into the glwe ciphertext entity of another backend,
+    /// encrypting the same message with the same mask and noise.
+    ///
+    /// The entity is unsynthesized into its prototype, which is then synthesized into the target
+    /// entity. The source entity is left untouched.
+    pub fn transfer_glwe_ciphertext<Precision, Source, Target>(&mut self, entity: &Source) -> Target
+    where
+        Precision: IntegerPrecision,
+        Source: GlweCiphertextEntity,
+        Target: GlweCiphertextEntity<KeyDistribution = Source::KeyDistribution>,
+        Maker: SynthesizesGlweCiphertext<Precision, Source>
+            + SynthesizesGlweCiphertext<Precision, Target>,
+    {
+        let prototype =
+            <Maker as SynthesizesGlweCiphertext<Precision, Source>>::unsynthesize_glwe_ciphertext(
+                self, entity,
+            );
+        <Maker as SynthesizesGlweCiphertext<Precision, Target>>::synthesize_glwe_ciphertext(
+            self, &prototype,
+        )
+    }
+
+    /// Converts a ggsw ciphertext entity into the ggsw ciphertext entity of another backend,
+    /// encrypting the same message with the same masks and noises.
+    ///
+    /// The entity is unsynthesized into its prototype, which is then synthesized into the target
+    /// entity. The source entity is left untouched.
+    pub fn transfer_ggsw_ciphertext<Precision, Source, Target>(&mut self, entity: &Source) -> Target
+    where
+        Precision: IntegerPrecision,
+        Source: GgswCiphertextEntity,
+        Target: GgswCiphertextEntity<KeyDistribution = Source::KeyDistribution>,
+        Maker: SynthesizesGgswCiphertext<Precision, Source>
+            + SynthesizesGgswCiphertext<Precision, Target>,
+    {
+        let prototype =
+            <Maker as SynthesizesGgswCiphertext<Precision, Source>>::unsynthesize_ggsw_ciphertext(
+                self, entity,
+            );
+        <Maker as SynthesizesGgswCiphertext<Precision, Target>>::synthesize_ggsw_ciphertext(
+            self, &prototype,
+        )
+    }
+}
@@ -15,10 +15,28 @@ where
 
 #[cfg(feature = "backend_core")]
 mod backend_core {
-    use crate::generation::prototypes::{ProtoPlaintext32, ProtoPlaintext64};
+    use crate::generation::prototypes::{
+        ProtoPlaintext128, ProtoPlaintext16, ProtoPlaintext32, ProtoPlaintext64,
+    };
     use crate::generation::synthesizing::SynthesizesPlaintext;
-    use crate::generation::{Maker, Precision32, Precision64};
-    use concrete_core::prelude::{DestructionEngine, Plaintext32, Plaintext64};
+    use crate::generation::{Maker, Precision128, Precision16, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, Plaintext128, Plaintext16, Plaintext32, Plaintext64,
+    };
+
+    impl SynthesizesPlaintext<Precision16, Plaintext16> for Maker {
+        fn synthesize_plaintext(&mut self, prototype: &Self::PlaintextProto) -> Plaintext16 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_plaintext(&mut self, entity: &Plaintext16) -> Self::PlaintextProto {
+            ProtoPlaintext16(entity.to_owned())
+        }
+
+        fn destroy_plaintext(&mut self, entity: Plaintext16) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
 
     impl SynthesizesPlaintext<Precision32, Plaintext32> for Maker {
         fn synthesize_plaintext(&mut self, prototype: &Self::PlaintextProto) -> Plaintext32 {
@@ -47,4 +65,73 @@ mod backend_core {
             self.core_engine.destroy(entity).unwrap();
         }
     }
+
+    impl SynthesizesPlaintext<Precision128, Plaintext128> for Maker {
+        fn synthesize_plaintext(&mut self, prototype: &Self::PlaintextProto) -> Plaintext128 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_plaintext(&mut self, entity: &Plaintext128) -> Self::PlaintextProto {
+            ProtoPlaintext128(entity.to_owned())
+        }
+
+        fn destroy_plaintext(&mut self, entity: Plaintext128) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+}
+
+#[cfg(feature = "backend_reference")]
+mod backend_reference {
+    use crate::generation::prototypes::{ProtoPlaintext32, ProtoPlaintext64};
+    use crate::generation::synthesizing::SynthesizesPlaintext;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, PlaintextCreationEngine, PlaintextRetrievalEngine, ReferencePlaintext32,
+        ReferencePlaintext64,
+    };
+
+    impl SynthesizesPlaintext<Precision32, ReferencePlaintext32> for Maker {
+        fn synthesize_plaintext(
+            &mut self,
+            prototype: &Self::PlaintextProto,
+        ) -> ReferencePlaintext32 {
+            let raw = self.core_engine.retrieve_plaintext(&prototype.0).unwrap();
+            self.reference_engine.create_plaintext(&raw).unwrap()
+        }
+
+        fn unsynthesize_plaintext(
+            &mut self,
+            entity: &ReferencePlaintext32,
+        ) -> Self::PlaintextProto {
+            let raw = self.reference_engine.retrieve_plaintext(entity).unwrap();
+            ProtoPlaintext32(self.core_engine.create_plaintext(&raw).unwrap())
+        }
+
+        fn destroy_plaintext(&mut self, entity: ReferencePlaintext32) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesPlaintext<Precision64, ReferencePlaintext64> for Maker {
+        fn synthesize_plaintext(
+            &mut self,
+            prototype: &Self::PlaintextProto,
+        ) -> ReferencePlaintext64 {
+            let raw = self.core_engine.retrieve_plaintext(&prototype.0).unwrap();
+            self.reference_engine.create_plaintext(&raw).unwrap()
+        }
+
+        fn unsynthesize_plaintext(
+            &mut self,
+            entity: &ReferencePlaintext64,
+        ) -> Self::PlaintextProto {
+            let raw = self.reference_engine.retrieve_plaintext(entity).unwrap();
+            ProtoPlaintext64(self.core_engine.create_plaintext(&raw).unwrap())
+        }
+
+        fn destroy_plaintext(&mut self, entity: ReferencePlaintext64) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
 }
@@ -21,10 +21,36 @@ where
 
 #[cfg(feature = "backend_core")]
 mod backend_core {
-    use crate::generation::prototypes::{ProtoPlaintextVector32, ProtoPlaintextVector64};
+    use crate::generation::prototypes::{
+        ProtoPlaintextVector128, ProtoPlaintextVector16, ProtoPlaintextVector32,
+        ProtoPlaintextVector64,
+    };
     use crate::generation::synthesizing::SynthesizesPlaintextVector;
-    use crate::generation::{Maker, Precision32, Precision64};
-    use concrete_core::prelude::{DestructionEngine, PlaintextVector32, PlaintextVector64};
+    use crate::generation::{Maker, Precision128, Precision16, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, PlaintextVector128, PlaintextVector16, PlaintextVector32,
+        PlaintextVector64,
+    };
+
+    impl SynthesizesPlaintextVector<Precision16, PlaintextVector16> for Maker {
+        fn synthesize_plaintext_vector(
+            &mut self,
+            prototype: &Self::PlaintextVectorProto,
+        ) -> PlaintextVector16 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_plaintext_vector(
+            &mut self,
+            entity: &PlaintextVector16,
+        ) -> Self::PlaintextVectorProto {
+            ProtoPlaintextVector16(entity.to_owned())
+        }
+
+        fn destroy_plaintext_vector(&mut self, entity: PlaintextVector16) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
 
     impl SynthesizesPlaintextVector<Precision32, PlaintextVector32> for Maker {
         fn synthesize_plaintext_vector(
@@ -65,4 +91,103 @@ mod backend_core {
             self.core_engine.destroy(entity).unwrap();
         }
     }
+
+    impl SynthesizesPlaintextVector<Precision128, PlaintextVector128> for Maker {
+        fn synthesize_plaintext_vector(
+            &mut self,
+            prototype: &Self::PlaintextVectorProto,
+        ) -> PlaintextVector128 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_plaintext_vector(
+            &mut self,
+            entity: &PlaintextVector128,
+        ) -> Self::PlaintextVectorProto {
+            ProtoPlaintextVector128(entity.to_owned())
+        }
+
+        fn destroy_plaintext_vector(&mut self, entity: PlaintextVector128) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+}
+
+#[cfg(feature = "backend_reference")]
+mod backend_reference {
+    use crate::generation::prototypes::{ProtoPlaintextVector32, ProtoPlaintextVector64};
+    use crate::generation::synthesizing::SynthesizesPlaintextVector;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, PlaintextVectorCreationEngine, PlaintextVectorRetrievalEngine,
+        ReferencePlaintextVector32, ReferencePlaintextVector64,
+    };
+
+    impl SynthesizesPlaintextVector<Precision32, ReferencePlaintextVector32> for Maker {
+        fn synthesize_plaintext_vector(
+            &mut self,
+            prototype: &Self::PlaintextVectorProto,
+        ) -> ReferencePlaintextVector32 {
+            let raw = self
+                .core_engine
+                .retrieve_plaintext_vector(&prototype.0)
+                .unwrap();
+            self.reference_engine
+                .create_plaintext_vector(raw.as_slice())
+                .unwrap()
+        }
+
+        fn unsynthesize_plaintext_vector(
+            &mut self,
+            entity: &ReferencePlaintextVector32,
+        ) -> Self::PlaintextVectorProto {
+            let raw = self
+                .reference_engine
+                .retrieve_plaintext_vector(entity)
+                .unwrap();
+            ProtoPlaintextVector32(
+                self.core_engine
+                    .create_plaintext_vector(raw.as_slice())
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_plaintext_vector(&mut self, entity: ReferencePlaintextVector32) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesPlaintextVector<Precision64, ReferencePlaintextVector64> for Maker {
+        fn synthesize_plaintext_vector(
+            &mut self,
+            prototype: &Self::PlaintextVectorProto,
+        ) -> ReferencePlaintextVector64 {
+            let raw = self
+                .core_engine
+                .retrieve_plaintext_vector(&prototype.0)
+                .unwrap();
+            self.reference_engine
+                .create_plaintext_vector(raw.as_slice())
+                .unwrap()
+        }
+
+        fn unsynthesize_plaintext_vector(
+            &mut self,
+            entity: &ReferencePlaintextVector64,
+        ) -> Self::PlaintextVectorProto {
+            let raw = self
+                .reference_engine
+                .retrieve_plaintext_vector(entity)
+                .unwrap();
+            ProtoPlaintextVector64(
+                self.core_engine
+                    .create_plaintext_vector(raw.as_slice())
+                    .unwrap(),
+            )
+        }
+
+        fn destroy_plaintext_vector(&mut self, entity: ReferencePlaintextVector64) {
+            self.reference_engine.destroy(entity).unwrap();
+        }
+    }
 }
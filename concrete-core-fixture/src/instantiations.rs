@@ -0,0 +1,208 @@
+//! A module containing the lists of the fixtures instantiated for every backend.
+//!
+//! The lists are shared by the test runners of the workspace, so that they always test the same
+//! operators. Each list is exposed as a macro taking the name of a macro of the runner, which is
+//! invoked with the list of the instantiated fixtures. The runner macro must accept two forms:
+//!
+//! + `($(($fixture: ident, $precision: ident, ($($types: ident),+))),+)`, for the fixtures
+//!   instantiated with the given precision and entity types.
+//! + `($(($fixture: ident, ($($types: ident),+))),+)`, for the fixtures instantiated with both
+//!   the 32 and 64 bits precisions, whose entity types are obtained by appending `32` and `64` to
+//!   the given prefixes.
+//!
+//! The names of the fixtures and of the entities are resolved where the list is expanded, which
+//! must import the `concrete_core::prelude` and the [`fixture`](crate::fixture) module.
+
+/// Invokes the `$test` macro with the fixtures instantiated for the `core` backend.
+#[macro_export]
+macro_rules! core_fixtures {
+    ($test: ident) => {
+        $test! {
+            (CleartextCreationFixture, (Cleartext)),
+            (CleartextRetrievalFixture, (Cleartext)),
+            (CleartextDiscardingRetrievalFixture, (Cleartext)),
+            (CleartextVectorCreationFixture, (CleartextVector)),
+            (GlweCiphertextTrivialDecryptionFixture, (PlaintextVector, GlweCiphertext)),
+            (CleartextVectorDiscardingRetrievalFixture, (CleartextVector)),
+            (CleartextVectorRetrievalFixture, (CleartextVector)),
+            (CleartextVectorNegacyclicConvolutionFixture, (CleartextVector, CleartextVector, CleartextVector)),
+            (GgswCiphertextCleartextTrivialEncryptionFixture, (Cleartext, GgswCiphertext)),
+            (GgswCiphertextConversionFixture, (GgswCiphertext, FourierGgswCiphertext)),
+            (GlweCiphertextDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
+            (GlweCiphertextDiscardingDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
+            (GlweCiphertextDiscardingEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
+            (GlweCiphertextEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
+            (GlweCiphertextTrivialEncryptionFixture, (PlaintextVector, GlweCiphertext)),
+            (GlweCiphertextZeroEncryptionFixture, (GlweSecretKey, GlweCiphertext)),
+            (GlweCiphertextTraceFixture, (GlweAutomorphismKey, GlweCiphertext, GlweCiphertext)),
+            (GlweCiphertextDiscardingAdditionFixture, (GlweCiphertext, GlweCiphertext)),
+            (GlweCiphertextDiscardingAdditionFixture, (CyclicGlweCiphertext, CyclicGlweCiphertext)),
+            (GlweCiphertextDiscardingOffsetAdditionFixture, (GlweCiphertext, GlweCiphertext)),
+            (GlweCiphertextDiscardingOffsetAdditionFixture, (CyclicGlweCiphertext,
+                CyclicGlweCiphertext)),
+            (GlweCiphertextCleartextVectorDiscardingMultiplicationFixture, (GlweCiphertext, CleartextVector,
+                GlweCiphertext)),
+            (GlweCiphertextCleartextVectorDiscardingMultiplicationFixture, (CyclicGlweCiphertext,
+                CleartextVector, CyclicGlweCiphertext)),
+            (GlweCiphertextVectorEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertextVector)),
+            (GlweCiphertextVectorDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertextVector)),
+            (GlweCiphertextVectorTrivialDecryptionFixture, (PlaintextVector, GlweCiphertextVector)),
+            (GlweCiphertextVectorTrivialEncryptionFixture, (PlaintextVector, GlweCiphertextVector)),
+            (GlweCiphertextVectorDiscardingDecryptionFixture, (PlaintextVector, GlweSecretKey,
+                GlweCiphertextVector)),
+            (GlweCiphertextVectorDiscardingEncryptionFixture, (PlaintextVector, GlweSecretKey,
+                GlweCiphertextVector)),
+            (GlweCiphertextVectorZeroEncryptionFixture, (GlweSecretKey, GlweCiphertextVector)),
+            (LweCiphertextEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
+            (LweCiphertextNoiseDistributionEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
+            (LweCiphertextVectorConstantTimeEqualityFixture, (LweCiphertextVector)),
+            (LweBootstrapKeyConstantTimeEqualityFixture, (LweBootstrapKey)),
+            (LweSecretKeyDerivationFixture, (LweSecretKey)),
+            (LweCiphertextPublicKeyEncryptionFixture, (LwePublicKey, Plaintext, LweCiphertext)),
+            (LweCiphertextZeroEncryptionFixture, (LweSecretKey, LweCiphertext)),
+            (LweCiphertextTrivialEncryptionFixture, (Plaintext, LweCiphertext)),
+            (LweCiphertextTrivialDecryptionFixture, (Plaintext, LweCiphertext)),
+            (LweCiphertextVectorZeroEncryptionFixture, (LweSecretKey, LweCiphertextVector)),
+            (LweCiphertextDecryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
+            (LweCiphertextDiscardingEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
+            (LweCiphertextVectorDecryptionFixture, (PlaintextVector, LweSecretKey, LweCiphertextVector)),
+            (LweCiphertextVectorNoiseStatisticsFixture, (LweSecretKey, LweCiphertextVector)),
+            (LweCiphertextVectorPhaseExportingDecryptionFixture, (LweSecretKey, LweCiphertextVector, PlaintextVector, LwePhaseVector)),
+            (LweCiphertextVectorEncryptionFixture, (PlaintextVector, LweSecretKey, LweCiphertextVector)),
+            (LweCiphertextVectorDiscardingEncryptionFixture, (PlaintextVector, LweSecretKey,
+                LweCiphertextVector)),
+            (LweCiphertextVectorDiscardingDecryptionFixture, (PlaintextVector, LweSecretKey,
+                LweCiphertextVector)),
+            (LweCiphertextCleartextDiscardingMultiplicationFixture, (LweCiphertext, Cleartext, LweCiphertext)),
+            (LweCiphertextCleartextDiscardingRationalMultiplicationFixture, (LweCiphertext, Cleartext,
+                LweCiphertext)),
+            (LweCiphertextCleartextFusingMultiplicationFixture, (LweCiphertext, Cleartext)),
+            (LweCiphertextCleartextFusingMultiplyAddFixture, (LweCiphertext, Cleartext, LweCiphertext)),
+            (LweCiphertextFusingOppositeFixture, (LweCiphertext)),
+            (LweCiphertextFusingSubtractionFixture, (LweCiphertext, LweCiphertext)),
+            (LweCiphertextVectorFusingAdditionFixture, (LweCiphertextVector, LweCiphertextVector)),
+            (LweCiphertextVectorFusingSubtractionFixture, (LweCiphertextVector, LweCiphertextVector)),
+            (LweCiphertextVectorDiscardingSubtractionFixture, (LweCiphertextVector, LweCiphertextVector)),
+            (LweCiphertextVectorDiscardingExtremumFixture, (LweKeyswitchKey, FourierLweBootstrapKey, LweCiphertextVector, LweCiphertextVector)),
+            (LweCiphertextVectorDiscardingParityFixture, (LweKeyswitchKey, FourierLweBootstrapKey, LweCiphertextVector, LweCiphertext)),
+            (LweCiphertextVectorDiscardingPopcountFixture, (LweKeyswitchKey, FourierLweBootstrapKey, LweCiphertextVector, LweCiphertext)),
+            (LweCiphertextVectorDiscardingAdditionFixture, (LweCiphertextVector, LweCiphertextVector)),
+            (LweCiphertextVectorDiscardingAffineTransformationFixture, (LweCiphertextVector, CleartextVector, Plaintext, LweCiphertext)),
+            (LweCiphertextVectorDiscardingGatherFixture, (LweCiphertextVector, LweCiphertextVector)),
+            (LweCiphertextVectorDiscardingScatterFixture, (LweCiphertextVector, LweCiphertextVector)),
+            (LweCiphertextVectorDiscardingCmuxFixture, (GgswCiphertext, LweCiphertextVector,
+                LweCiphertextVector)),
+            (LweCiphertextVectorDiscardingPermutationFixture, (GgswCiphertext, LweCiphertextVector,
+                LweCiphertextVector)),
+            (LweCiphertextDiscardingKeyswitchFixture, (LweKeyswitchKey, LweCiphertext, LweCiphertext)),
+            (LweCiphertextDiscardingKeyswitchFixture, (ColumnMajorLweKeyswitchKey, LweCiphertext,
+                LweCiphertext)),
+            (LweCiphertextVectorDiscardingKeyswitchFixture, (LweKeyswitchKey, LweCiphertextVector,
+                LweCiphertextVector)),
+            (LweCiphertextDiscardingBatchedKeyswitchFixture, (LweKeyswitchKeyBatch, LweCiphertext, LweCiphertext)),
+            (LweCiphertextGadgetProductFixture, (LweKeyswitchKey, LweCiphertext, LweCiphertext)),
+            (LweCiphertextDiscardingShrinkingKeyswitchFixture, (LweShrinkingKeyswitchKey, LweCiphertext, LweCiphertext)),
+            (LweCiphertextDiscardingAdditionFixture, (LweCiphertext, LweCiphertext)),
+            (LweCiphertextDiscardingOppositeFixture, (LweCiphertext, LweCiphertext)),
+            (LweCiphertextDiscardingModulusSwitchFixture, (LweCiphertext, LweCiphertext)),
+            (LweCiphertextFusingAdditionFixture, (LweCiphertext, LweCiphertext)),
+            (LweCiphertextJaggedVectorDiscardingRowSummationFixture, (LweCiphertextVector,
+                LweCiphertextJaggedVector, LweCiphertextVector)),
+            (LweCiphertextJaggedVectorFusingAdditionFixture, (LweCiphertextVector,
+                LweCiphertextJaggedVector, LweCiphertextVector)),
+            (LweCiphertextVectorTrivialDecryptionFixture, (PlaintextVector, LweCiphertextVector)),
+            (LweCiphertextVectorTrivialEncryptionFixture, (PlaintextVector, LweCiphertextVector)),
+            (LweCiphertextDiscardingSubtractionFixture, (LweCiphertext, LweCiphertext)),
+            (LweCiphertextDiscardingDecryptionFixture, (LweCiphertext, LweSecretKey, Plaintext)),
+            (LweCiphertextPlaintextDiscardingAdditionFixture, (LweCiphertext, Plaintext, LweCiphertext)),
+            (LweCiphertextPlaintextFusingAdditionFixture, (Plaintext, LweCiphertext)),
+            (LweCiphertextPlaintextDiscardingSubtractionFixture, (LweCiphertext, Plaintext, LweCiphertext)),
+            (LweCiphertextPlaintextFusingSubtractionFixture, (Plaintext, LweCiphertext)),
+            (LweCiphertextVectorPlaintextVectorDiscardingSubtractionFixture, (LweCiphertextVector,
+                PlaintextVector, LweCiphertextVector)),
+            (LweCiphertextVectorPlaintextVectorFusingSubtractionFixture, (PlaintextVector,
+                LweCiphertextVector)),
+            (LweCiphertextDiscardingBootstrapFixture1, (FourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+            (LweCiphertextDiscardingBootstrapFixture2, (FourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+            (LweCiphertextDiscardingLargeTableLookupFixture, (FourierLweBootstrapKey, FourierGgswCiphertext,
+                PlaintextVector, LweCiphertext, LweCiphertext)),
+            (LweCiphertextDiscardingExtractionFixture, (GlweCiphertext, LweCiphertext)),
+            (LweSeededCiphertextEncryptionFixture, (Plaintext, LweSecretKey, LweSeededCiphertext,
+                LweCiphertext)),
+            (LweSeededBootstrapKeyExpansionFixture, (LweSeededBootstrapKey, LweBootstrapKey,
+                FourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+            (LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchFixture, (LweCiphertextVector,
+                PackingKeyswitchKey, GlweCiphertext)),
+            (LweCiphertextDiscardingMultiplicationFixture, (PackingKeyswitchKey, GlweRelinearizationKey,
+                LweCiphertext, LweCiphertext, LweCiphertext)),
+            (PlaintextCreationFixture, (Plaintext)),
+            (PlaintextDiscardingRetrievalFixture, (Plaintext)),
+            (PlaintextRetrievalFixture, (Plaintext)),
+            (PlaintextVectorDiscardingRetrievalFixture, (PlaintextVector)),
+            (PlaintextVectorCreationFixture, (PlaintextVector)),
+            (PlaintextVectorRetrievalFixture, (PlaintextVector)),
+            (GlweCiphertextGgswCiphertextExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext)),
+            (GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext))
+        }
+
+        $test! {
+            (PlaintextCreationFixture, Precision128, (Plaintext128)),
+            (PlaintextRetrievalFixture, Precision128, (Plaintext128)),
+            (PlaintextVectorCreationFixture, Precision128, (PlaintextVector128)),
+            (PlaintextVectorRetrievalFixture, Precision128, (PlaintextVector128)),
+            (LweCiphertextEncryptionFixture, Precision128, (Plaintext128, LweSecretKey128, LweCiphertext128)),
+            (LweCiphertextDecryptionFixture, Precision128, (Plaintext128, LweSecretKey128, LweCiphertext128)),
+            (LweCiphertextDiscardingKeyswitchFixture, Precision128, (LweKeyswitchKey128, LweCiphertext128, LweCiphertext128)),
+            (GlweCiphertextEncryptionFixture, Precision128, (PlaintextVector128, GlweSecretKey128, GlweCiphertext128)),
+            (GlweCiphertextDecryptionFixture, Precision128, (PlaintextVector128, GlweSecretKey128, GlweCiphertext128)),
+            (GlweCiphertextTrivialDecryptionFixture, Precision128, (PlaintextVector128, GlweCiphertext128))
+        }
+
+        $test! {
+            (LweCiphertextTruncatedViewConversionFixture, Precision64, (LweCiphertext64, LweCiphertext32))
+        }
+
+        $test! {
+            (PlaintextCreationFixture, Precision16, (Plaintext16)),
+            (PlaintextRetrievalFixture, Precision16, (Plaintext16)),
+            (PlaintextVectorCreationFixture, Precision16, (PlaintextVector16)),
+            (PlaintextVectorRetrievalFixture, Precision16, (PlaintextVector16)),
+            (LweCiphertextEncryptionFixture, Precision16, (Plaintext16, LweSecretKey16, LweCiphertext16)),
+            (LweCiphertextDiscardingAdditionFixture, Precision16, (LweCiphertext16, LweCiphertext16)),
+            (LweCiphertextFusingAdditionFixture, Precision16, (LweCiphertext16, LweCiphertext16)),
+            (LweCiphertextDiscardingKeyswitchFixture, Precision16, (LweKeyswitchKey16, LweCiphertext16, LweCiphertext16)),
+            (GlweCiphertextTrivialDecryptionFixture, Precision16, (PlaintextVector16, GlweCiphertext16))
+        }
+    };
+}
+
+/// Invokes the `$test` macro with the fixtures instantiated for the `reference` backend.
+#[macro_export]
+macro_rules! reference_fixtures {
+    ($test: ident) => {
+        $test! {
+            (CleartextCreationFixture, (ReferenceCleartext)),
+            (CleartextRetrievalFixture, (ReferenceCleartext)),
+            (GlweCiphertextDecryptionFixture, (ReferencePlaintextVector, ReferenceGlweSecretKey,
+                ReferenceGlweCiphertext)),
+            (GlweCiphertextEncryptionFixture, (ReferencePlaintextVector, ReferenceGlweSecretKey,
+                ReferenceGlweCiphertext)),
+            (GlweCiphertextTrivialEncryptionFixture, (ReferencePlaintextVector, ReferenceGlweCiphertext)),
+            (LweCiphertextEncryptionFixture, (ReferencePlaintext, ReferenceLweSecretKey,
+                ReferenceLweCiphertext)),
+            (LweCiphertextTrivialEncryptionFixture, (ReferencePlaintext, ReferenceLweCiphertext)),
+            (LweCiphertextDecryptionFixture, (ReferencePlaintext, ReferenceLweSecretKey,
+                ReferenceLweCiphertext)),
+            (LweCiphertextCleartextFusingMultiplicationFixture, (ReferenceLweCiphertext,
+                ReferenceCleartext)),
+            (LweCiphertextDiscardingKeyswitchFixture, (ReferenceLweKeyswitchKey, ReferenceLweCiphertext,
+                ReferenceLweCiphertext)),
+            (LweCiphertextDiscardingAdditionFixture, (ReferenceLweCiphertext, ReferenceLweCiphertext)),
+            (LweCiphertextFusingAdditionFixture, (ReferenceLweCiphertext, ReferenceLweCiphertext)),
+            (PlaintextCreationFixture, (ReferencePlaintext)),
+            (PlaintextRetrievalFixture, (ReferencePlaintext)),
+            (PlaintextVectorCreationFixture, (ReferencePlaintextVector)),
+            (PlaintextVectorRetrievalFixture, (ReferencePlaintextVector))
+        }
+    };
+}
@@ -1,13 +1,87 @@
 #![deny(rustdoc::broken_intra_doc_links)]
 //! A library containing generic fixtures for `concrete-core` operators.
 //!
-//! The central abstraction of this (private) library, is the [`Fixture`](fixture::Fixture) trait
-//! which, once implemented for a given engine trait, exposes methods to sample/test/benchmark any
-//! implementor of the engine trait in question.
+//! The central abstraction of this library, is the [`Fixture`](fixture::Fixture) trait which, once
+//! implemented for a given engine trait, exposes methods to sample/test/benchmark any implementor
+//! of the engine trait in question.
+//!
+//! # Testing sdk
+//!
+//! Beyond the fixtures of the `concrete-core` operators, this library can be used by downstream
+//! crates wrapping `concrete-core`, to write their own noise-checked tests. The [`Fixture`] trait,
+//! the [`Maker`] used to generate the execution contexts, the statistical tests of the [`raw`]
+//! module and the parameter sets of the [`presets`] module are part of the public api, and follow
+//! the semantic versioning of the crate. The `external_fixture` example shows a fixture
+//! implemented outside of this crate.
+//!
+//! # Reproducible runs
+//!
+//! A [`Maker`] created with [`Maker::new_seeded`], along with an engine seeded from
+//! [`generation::engine_seed`], generates the same inputs and noise on every run. The
+//! [`generation::seed_from_env`] function returns the seed found in the `CONCRETE_FIXTURE_SEED`
+//! environment variable, such that a failing fixture can be re-run with the seed it was executed
+//! with.
+//!
+//! # Timing budgets
+//!
+//! The fixtures can also guard against large performance regressions: the [`timing`] module
+//! contains the budgets against which the execution times of the engines can be checked.
+//!
+//! # Benchmarks
+//!
+//! Every fixture can also be used to benchmark the implementors of its engine trait, for all of
+//! its sets of parameters: the [`benchmark`] module contains the [`BenchmarkFixture`] runner,
+//! which times the executions of the engine on the inputs generated by the fixture. The
+//! `benchmark_fixtures` example benchmarks a few engines of the `core` backend.
+//!
+//! # Noise measurements
+//!
+//! The fixtures checking a noise distribution can export the noise they measure, along with the
+//! variance expected by the noise model, to json and csv files. The [`noise_export`] module
+//! contains the format of the files.
+//!
+//! # Parameter files
+//!
+//! With the `parameter_files` feature, the parameters of a fixture can be loaded from a toml or
+//! json file, to run it over a large grid of parameters without recompiling the crate. The
+//! `parameter_grid` module contains the format of the files.
+//!
+//! # Soak runs
+//!
+//! The statistical tests of the fixtures are executed on a few hundred samples. To detect the bugs
+//! whose effect accumulates slowly, the [`soak`] module contains the [`SoakFixture`] runner, which
+//! executes a fixture for hours with fixed keys, and alerts when the variance of its noise drifts.
+//! The `soak_fixture` example soaks the LWE encryption of the `core` backend.
+//!
+//! # Instantiations
+//!
+//! The lists of the fixtures instantiated for every backend are shared by the test runners of the
+//! workspace, through the macros of the [`instantiations`] module.
+//!
+//! # Snapshots
+//!
+//! With the `snapshot` feature, a repetition of a fixture can be exported to a single file, and
+//! replayed later against any backend. The [`snapshot`] module contains the format of the files.
 
+pub mod benchmark;
 pub mod fixture;
 pub mod generation;
+pub mod instantiations;
+pub mod noise_export;
+#[cfg(feature = "parameter_files")]
+pub mod parameter_grid;
+pub mod presets;
 pub mod raw;
+pub mod snapshot;
+pub mod soak;
+pub mod timing;
+
+pub use benchmark::BenchmarkFixture;
+pub use fixture::Fixture;
+pub use generation::{
+    IntegerPrecision, Maker, Precision128, Precision16, Precision32, Precision64,
+};
+pub use soak::SoakFixture;
 
 /// A type representing the number of times we repeat a test for a given set of parameters.
 #[derive(Clone, Copy, Debug)]
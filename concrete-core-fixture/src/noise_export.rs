@@ -0,0 +1,262 @@
+//! A module containing the export of the noise measured by the fixtures.
+//!
+//! The [`Fixture::verify`] method only tells whether the outcomes of a repetition match its
+//! criteria. To study the noise growth of an operator, or to archive a baseline of its noise, the
+//! fixtures checking a noise distribution also implement [`Fixture::measure_noise`], which returns
+//! the error of every sample along with the variance expected by the noise model.
+//!
+//! When the `CONCRETE_FIXTURE_NOISE_DIRECTORY` environment variable points to a directory,
+//! [`Fixture::stress`] (and the methods built on it) exports these measurements for every set of
+//! parameters to two files, named after the engine, the fixture key and a hash of the parameters:
+//! + A `.json` file containing the engine, fixture and parameters, and for every repetition its
+//! metadata, the expected variance, and the measured variance (the mean square of the errors).
+//! + A `.csv` file containing the error of every sample, with the
+//! `repetition_index,sample_index,error` columns.
+//!
+//! The errors are expressed on the torus, that is as a fraction of the modulus of the
+//! ciphertexts, such that the measurements of the 32 and 64 bits precisions can be compared.
+//!
+//! [`Fixture::verify`]: crate::fixture::Fixture::verify
+//! [`Fixture::measure_noise`]: crate::fixture::Fixture::measure_noise
+//! [`Fixture::stress`]: crate::fixture::Fixture::stress
+use crate::fixture::RepetitionMetadata;
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::{torus_modular_distance, RawModulus};
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// The environment variable containing the directory the noise measurements are exported to.
+pub const NOISE_DIRECTORY_VARIABLE: &str = "CONCRETE_FIXTURE_NOISE_DIRECTORY";
+
+/// Returns the directory the noise measurements are exported to, if one is given by the
+/// environment.
+pub fn noise_directory() -> Option<PathBuf> {
+    std::env::var_os(NOISE_DIRECTORY_VARIABLE).map(PathBuf::from)
+}
+
+/// A trait implemented by the outcomes made of expected and actual raw values, from which a noise
+/// can be measured.
+pub trait NoiseOutcome {
+    /// Pushes the torus errors between the actual and expected values of the outcome.
+    fn push_errors(&self, errors: &mut Vec<f64>);
+}
+
+impl<Raw: RawUnsignedIntegers> NoiseOutcome for (Raw, Raw) {
+    fn push_errors(&self, errors: &mut Vec<f64>) {
+        errors.push(signed_torus_error(self.0, self.1));
+    }
+}
+
+impl<Raw: RawUnsignedIntegers> NoiseOutcome for (Vec<Raw>, Vec<Raw>) {
+    fn push_errors(&self, errors: &mut Vec<f64>) {
+        errors.extend(
+            self.0
+                .iter()
+                .zip(self.1.iter())
+                .map(|(expected, actual)| signed_torus_error(*expected, *actual)),
+        );
+    }
+}
+
+/// The noise measured on the outcomes of a repetition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoiseMeasurement {
+    /// The variance expected by the noise model of the operator.
+    pub expected_variance: Variance,
+    /// The torus error of every sample, in the order of the outcomes.
+    pub errors: Vec<f64>,
+}
+
+impl NoiseMeasurement {
+    /// Measures the noise of a set of outcomes, made of expected and actual values.
+    pub fn from_outcomes<Outcome: NoiseOutcome>(
+        expected_variance: Variance,
+        outcomes: &[Outcome],
+    ) -> NoiseMeasurement {
+        let mut errors = Vec::with_capacity(outcomes.len());
+        for outcome in outcomes {
+            outcome.push_errors(&mut errors);
+        }
+        NoiseMeasurement {
+            expected_variance,
+            errors,
+        }
+    }
+
+    /// Returns the mean square of the errors, if any error was measured.
+    pub fn measured_variance(&self) -> Option<f64> {
+        if self.errors.is_empty() {
+            None
+        } else {
+            Some(self.errors.iter().map(|e| e * e).sum::<f64>() / self.errors.len() as f64)
+        }
+    }
+}
+
+/// The noise measurements of all the repetitions of a fixture, for a set of parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoiseReport {
+    /// The name of the engine type.
+    pub engine: String,
+    /// The key of the fixture, as returned by [`fixture_key`](crate::timing::fixture_key).
+    pub fixture: String,
+    /// The debug representation of the parameters.
+    pub parameters: String,
+    /// The measurements of every repetition, along with its metadata.
+    pub repetitions: Vec<(RepetitionMetadata, NoiseMeasurement)>,
+}
+
+impl NoiseReport {
+    /// Returns the name of the files of the report, without extension.
+    ///
+    /// The name is made of the alphanumeric characters of the engine and fixture key, followed by
+    /// a hash of the parameters which is stable across runs, such that the measurements of a set
+    /// of parameters overwrite the ones of a previous run.
+    pub fn file_stem(&self) -> String {
+        let name: String = format!("{}-{}", self.engine, self.fixture)
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || c == '-' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        format!("{}-{:016x}", name, fnv1a(self.parameters.as_bytes()))
+    }
+
+    /// Exports the report to a `.json` and a `.csv` file in `directory`, and returns the path of
+    /// the `.json` file.
+    pub fn export<P: AsRef<Path>>(&self, directory: P) -> std::io::Result<PathBuf> {
+        std::fs::create_dir_all(directory.as_ref())?;
+        let stem = self.file_stem();
+        let json_path = directory.as_ref().join(format!("{}.json", stem));
+        File::create(&json_path)?.write_all(self.to_json().as_bytes())?;
+        let mut csv = BufWriter::new(File::create(
+            directory.as_ref().join(format!("{}.csv", stem)),
+        )?);
+        writeln!(csv, "repetition_index,sample_index,error")?;
+        for (metadata, measurement) in self.repetitions.iter() {
+            for (sample_index, error) in measurement.errors.iter().enumerate() {
+                writeln!(
+                    csv,
+                    "{},{},{:e}",
+                    metadata.repetition_index, sample_index, error
+                )?;
+            }
+        }
+        csv.flush()?;
+        Ok(json_path)
+    }
+
+    /// Returns the summary of the report, in json.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        json.push_str("{\n");
+        let _ = writeln!(json, "  \"engine\": {},", json_string(&self.engine));
+        let _ = writeln!(json, "  \"fixture\": {},", json_string(&self.fixture));
+        let _ = writeln!(json, "  \"parameters\": {},", json_string(&self.parameters));
+        json.push_str("  \"repetitions\": [");
+        for (index, (metadata, measurement)) in self.repetitions.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            let seed = metadata
+                .seed
+                .map(|seed| json_string(&seed.to_string()))
+                .unwrap_or_else(|| String::from("null"));
+            let measured_variance = measurement
+                .measured_variance()
+                .filter(|variance| variance.is_finite())
+                .map(|variance| format!("{:e}", variance))
+                .unwrap_or_else(|| String::from("null"));
+            let _ = write!(
+                json,
+                "\n    {{\"repetition_index\": {}, \"key_id\": {}, \"seed\": {}, \
+                \"sample_count\": {}, \"expected_variance\": {:e}, \"measured_variance\": {}}}",
+                metadata.repetition_index,
+                metadata.key_id,
+                seed,
+                measurement.errors.len(),
+                measurement.expected_variance.get_variance(),
+                measured_variance
+            );
+        }
+        json.push_str("\n  ]\n}\n");
+        json
+    }
+}
+
+// Returns the error between an actual and an expected raw value, on the torus, in [-1/2, 1/2).
+fn signed_torus_error<Raw: RawUnsignedIntegers>(expected: Raw, actual: Raw) -> f64 {
+    torus_modular_distance(actual, expected, RawModulus::Native)
+}
+
+// Encodes a string as a json string literal.
+fn json_string(value: &str) -> String {
+    let mut output = String::with_capacity(value.len() + 2);
+    output.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(output, "\\u{:04x}", c as u32);
+            }
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    output
+}
+
+// The 64 bits FNV-1a hash, whose value does not depend on the version of the compiler.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_signed_errors() {
+        let outcomes = vec![(0_u32, 1_u32 << 28), (0, 0_u32.wrapping_sub(1 << 28))];
+        let measurement = NoiseMeasurement::from_outcomes(Variance(0.), &outcomes);
+        assert_eq!(measurement.errors, vec![1. / 16., -1. / 16.]);
+        assert_eq!(measurement.measured_variance(), Some(1. / 256.));
+    }
+
+    #[test]
+    fn test_json_summary() {
+        let report = NoiseReport {
+            engine: String::from("CoreEngine"),
+            fixture: String::from("Fixture<Precision32,(LweCiphertext32,)>"),
+            parameters: String::from("Parameters { name: \"a\" }"),
+            repetitions: vec![(
+                RepetitionMetadata {
+                    repetition_index: 0,
+                    seed: None,
+                    key_id: 3,
+                },
+                NoiseMeasurement {
+                    expected_variance: Variance(0.25),
+                    errors: vec![],
+                },
+            )],
+        };
+        let json = report.to_json();
+        assert!(json.contains("\"parameters\": \"Parameters { name: \\\"a\\\" }\""));
+        assert!(json.contains("\"expected_variance\": 2.5e-1, \"measured_variance\": null"));
+        assert!(report
+            .file_stem()
+            .starts_with("CoreEngine-Fixture_Precision32__LweCiphertext32___-"));
+    }
+}
@@ -0,0 +1,176 @@
+//! A module containing the loading of fixture parameters from configuration files.
+//!
+//! The [`Fixture::generate_parameters_iterator`] method of every fixture returns a few sets of
+//! parameters, fixed at compile time. To run a fixture over a larger grid of parameters, such as
+//! for a noise campaign, the parameters can instead be loaded from a file, without recompiling
+//! the crate.
+//!
+//! With the `parameter_files` feature, [`Fixture::parameters_iterator`] looks for a file named
+//! after the fixture type in the directory found in the `CONCRETE_FIXTURE_PARAMETERS_DIRECTORY`
+//! environment variable, e.g. `LweCiphertextEncryptionFixture.toml` or
+//! `LweCiphertextEncryptionFixture.json`. When such a file exists, the fixture is executed with
+//! the parameters it contains, and otherwise with the ones of
+//! [`Fixture::generate_parameters_iterator`].
+//!
+//! A file contains a `parameters` array, whose elements have the fields of the parameters type of
+//! the fixture. For instance, for the `LweCiphertextEncryptionFixture`:
+//! ```toml
+//! [[parameters]]
+//! noise = 1e-20
+//! lwe_dimension = 512
+//!
+//! [[parameters]]
+//! noise = 1e-25
+//! lwe_dimension = 1024
+//! ```
+//! or equivalently, in json:
+//! ```json
+//! {"parameters": [
+//!     {"noise": 1e-20, "lwe_dimension": 512},
+//!     {"noise": 1e-25, "lwe_dimension": 1024}
+//! ]}
+//! ```
+//!
+//! The timing budgets refer to the parameters by their position in the file, when one is used.
+//!
+//! [`Fixture::generate_parameters_iterator`]: crate::fixture::Fixture::generate_parameters_iterator
+//! [`Fixture::parameters_iterator`]: crate::fixture::Fixture::parameters_iterator
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+/// The environment variable containing the directory the parameter files are loaded from.
+pub const PARAMETERS_DIRECTORY_VARIABLE: &str = "CONCRETE_FIXTURE_PARAMETERS_DIRECTORY";
+
+/// An error occurring when loading a parameter file.
+#[derive(Debug)]
+pub enum ParameterFileError {
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// The content of a `.toml` file could not be deserialized.
+    Toml(toml::de::Error),
+    /// The content of a `.json` file could not be deserialized.
+    Json(serde_json::Error),
+    /// The extension of the file is neither `toml` nor `json`.
+    UnsupportedExtension(PathBuf),
+}
+
+impl Display for ParameterFileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParameterFileError::Io(error) => {
+                write!(f, "Failed to read the parameter file: {}", error)
+            }
+            ParameterFileError::Toml(error) => {
+                write!(f, "Failed to deserialize the parameter file: {}", error)
+            }
+            ParameterFileError::Json(error) => {
+                write!(f, "Failed to deserialize the parameter file: {}", error)
+            }
+            ParameterFileError::UnsupportedExtension(path) => write!(
+                f,
+                "Unsupported parameter file `{}`. Expected a `.toml` or `.json` file.",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParameterFileError {}
+
+// The layout shared by the toml and json files.
+#[derive(Deserialize)]
+#[serde(bound = "Parameters: DeserializeOwned")]
+struct ParameterFile<Parameters> {
+    parameters: Vec<Parameters>,
+}
+
+/// Parses the parameters contained in the content of a `.toml` file.
+pub fn parse_toml<Parameters: DeserializeOwned>(
+    content: &str,
+) -> Result<Vec<Parameters>, ParameterFileError> {
+    toml::from_str::<ParameterFile<Parameters>>(content)
+        .map(|file| file.parameters)
+        .map_err(ParameterFileError::Toml)
+}
+
+/// Parses the parameters contained in the content of a `.json` file.
+pub fn parse_json<Parameters: DeserializeOwned>(
+    content: &str,
+) -> Result<Vec<Parameters>, ParameterFileError> {
+    serde_json::from_str::<ParameterFile<Parameters>>(content)
+        .map(|file| file.parameters)
+        .map_err(ParameterFileError::Json)
+}
+
+/// Loads the parameters contained in a `.toml` or `.json` file, depending on its extension.
+pub fn load_parameters<Parameters: DeserializeOwned, P: AsRef<Path>>(
+    path: P,
+) -> Result<Vec<Parameters>, ParameterFileError> {
+    let path = path.as_ref();
+    let parse = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("toml") => parse_toml::<Parameters>,
+        Some("json") => parse_json::<Parameters>,
+        _ => return Err(ParameterFileError::UnsupportedExtension(path.to_path_buf())),
+    };
+    let content = std::fs::read_to_string(path).map_err(ParameterFileError::Io)?;
+    parse(&content)
+}
+
+/// Returns the path of the parameter file of a fixture, if the environment gives a directory
+/// containing one.
+///
+/// The `.toml` file is picked when both a `.toml` and a `.json` file exist.
+pub fn parameter_file(fixture_name: &str) -> Option<PathBuf> {
+    let directory = PathBuf::from(std::env::var_os(PARAMETERS_DIRECTORY_VARIABLE)?);
+    ["toml", "json"]
+        .iter()
+        .map(|extension| directory.join(format!("{}.{}", fixture_name, extension)))
+        .find(|path| path.is_file())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use concrete_commons::dispersion::Variance;
+    use concrete_commons::parameters::LweDimension;
+    use serde::Serialize;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Parameters {
+        noise: Variance,
+        lwe_dimension: LweDimension,
+    }
+
+    #[test]
+    fn test_toml_and_json_match() {
+        let toml = "[[parameters]]\nnoise = 0.5\nlwe_dimension = 512\n\n\
+                    [[parameters]]\nnoise = 0.25\nlwe_dimension = 1024\n";
+        let json = r#"{"parameters": [
+            {"noise": 0.5, "lwe_dimension": 512},
+            {"noise": 0.25, "lwe_dimension": 1024}
+        ]}"#;
+        let expected = vec![
+            Parameters {
+                noise: Variance(0.5),
+                lwe_dimension: LweDimension(512),
+            },
+            Parameters {
+                noise: Variance(0.25),
+                lwe_dimension: LweDimension(1024),
+            },
+        ];
+        assert_eq!(parse_toml::<Parameters>(toml).unwrap(), expected);
+        assert_eq!(parse_json::<Parameters>(json).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_missing_field_is_rejected() {
+        let toml = "[[parameters]]\nnoise = 0.5\n";
+        assert!(matches!(
+            parse_toml::<Parameters>(toml),
+            Err(ParameterFileError::Toml(_))
+        ));
+    }
+}
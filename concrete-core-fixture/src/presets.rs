@@ -0,0 +1,121 @@
+//! A module containing the standard parameter presets used by the fixtures.
+//!
+//! These presets are part of the public api of the library, and follow its semantic versioning:
+//! changing one of the values below is a breaking change. Downstream crates wrapping
+//! `concrete-core` can use them to write their own fixtures, and check their operators against the
+//! same parameters as the ones used to test `concrete-core` itself (see the `external_fixture`
+//! example of this crate).
+use crate::{Repetitions, SampleSize};
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// The number of times a test is repeated for a single set of parameters.
+pub const STANDARD_REPETITIONS: Repetitions = Repetitions(10);
+
+/// The size of the sample used to perform the statistical tests.
+pub const STANDARD_SAMPLE_SIZE: SampleSize = SampleSize(100);
+
+/// The number of times a test is repeated for a single set of parameters, when the fixtures are
+/// run as a quick local check.
+pub const REDUCED_REPETITIONS: Repetitions = Repetitions(2);
+
+/// The size of the sample used to perform the statistical tests, when the fixtures are run as a
+/// quick local check.
+pub const REDUCED_SAMPLE_SIZE: SampleSize = SampleSize(50);
+
+/// The noise used to encrypt the inputs of the leveled operators fixtures.
+pub const STANDARD_NOISE: Variance = Variance(0.00000001);
+
+/// The lwe dimensions swept by the leveled operators fixtures.
+pub const STANDARD_LWE_DIMENSIONS: [LweDimension; 6] = [
+    LweDimension(100),
+    LweDimension(300),
+    LweDimension(600),
+    LweDimension(1000),
+    LweDimension(3000),
+    LweDimension(6000),
+];
+
+/// The lwe dimension used by the fixtures testing a single set of parameters.
+pub const SINGLE_LWE_DIMENSION: LweDimension = LweDimension(600);
+
+/// Returns the noise used by the fixtures testing a single set of parameters.
+pub fn single_noise() -> Variance {
+    Variance(LogStandardDev::from_log_standard_dev(-15.).get_variance())
+}
+
+/// The noise of the trivial and noiseless encryptions, swept by the encryption fixtures as an edge
+/// case.
+pub const ZERO_NOISE: Variance = Variance(0.);
+
+/// Returns a noise whose standard deviation is below the resolution of the 64 bits integers,
+/// swept by the encryption fixtures as an edge case.
+pub fn near_zero_noise() -> Variance {
+    Variance(LogStandardDev::from_log_standard_dev(-70.).get_variance())
+}
+
+/// The width of the interval centered on zero the plaintexts are sampled from, when the fixtures
+/// of the plaintext operators check that the ciphertext bodies wrap around zero.
+pub const NEAR_ZERO_PLAINTEXT_WIDTH: usize = 1 << 16;
+
+/// A set of parameters for the bootstrap fixtures.
+#[derive(Clone, Copy, Debug)]
+pub struct BootstrapPreset {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub poly_size: PolynomialSize,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub decomp_base_log: DecompositionBaseLog,
+}
+
+/// Returns the parameters swept by the bootstrap fixtures.
+pub fn standard_bootstrap_presets() -> Vec<BootstrapPreset> {
+    [512, 1024, 2048]
+        .iter()
+        .map(|poly_size| BootstrapPreset {
+            noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+            lwe_dimension: LweDimension(630),
+            glwe_dimension: GlweDimension(1),
+            poly_size: PolynomialSize(*poly_size),
+            decomp_level_count: DecompositionLevelCount(3),
+            decomp_base_log: DecompositionBaseLog(7),
+        })
+        .collect()
+}
+
+/// The largest integer precision, in bits, for which the leveled operators fixtures use the
+/// reduced precision presets below: the standard noises and decompositions are below the
+/// resolution of these integers.
+pub const REDUCED_PRECISION_BITS: usize = 16;
+
+/// Returns the noise used by the leveled operators fixtures for the reduced precisions.
+pub fn reduced_precision_noise() -> Variance {
+    Variance(LogStandardDev::from_log_standard_dev(-10.).get_variance())
+}
+
+/// A set of parameters for the keyswitch fixtures.
+#[derive(Clone, Copy, Debug)]
+pub struct KeyswitchPreset {
+    pub input_noise: Variance,
+    pub ksk_noise: Variance,
+    pub input_lwe_dimension: LweDimension,
+    pub output_lwe_dimension: LweDimension,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub decomp_base_log: DecompositionBaseLog,
+}
+
+/// Returns the parameters swept by the keyswitch fixtures for the reduced precisions, whose
+/// decomposition fits in 16 bits.
+pub fn reduced_precision_keyswitch_preset() -> KeyswitchPreset {
+    KeyswitchPreset {
+        input_noise: Variance(LogStandardDev::from_log_standard_dev(-8.).get_variance()),
+        ksk_noise: Variance(LogStandardDev::from_log_standard_dev(-12.).get_variance()),
+        input_lwe_dimension: LweDimension(600),
+        output_lwe_dimension: LweDimension(1024),
+        decomp_level_count: DecompositionLevelCount(5),
+        decomp_base_log: DecompositionBaseLog(2),
+    }
+}
@@ -1,11 +1,23 @@
-//! A module containing sampling entry points for raw integers
-use concrete_commons::numeric::{CastInto, UnsignedInteger};
+//! A module containing sampling entry points for raw integers, along with the modular arithmetic
+//! used to compute the expected outputs of the fixtures.
+use crate::raw::statistical_test::RawModulus;
+use crate::snapshot::Snapshottable;
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::numeric::{CastFrom, CastInto, UnsignedInteger};
 use concrete_core::backends::core::private::math::random::RandomGenerator;
 use std::fmt::Debug;
 use std::ops::Range;
 
 /// A trait to generate raw unsigned integer values.
-pub trait RawUnsignedIntegers: UnsignedInteger + CastInto<f64> + CastInto<i64> + Debug {
+pub trait RawUnsignedIntegers:
+    UnsignedInteger
+    + CastInto<f64>
+    + CastInto<i64>
+    + CastInto<u128>
+    + CastFrom<u128>
+    + Debug
+    + Snapshottable
+{
     fn one() -> Self;
     fn one_vec(size: usize) -> Vec<Self>;
     fn zero() -> Self;
@@ -22,6 +34,138 @@ pub trait RawUnsignedIntegers: UnsignedInteger + CastInto<f64> + CastInto<i64> +
     fn uniform_between_vec(range: Range<usize>, size: usize) -> Vec<Self>;
     fn uniform_zero_centered(width: usize) -> Self;
     fn uniform_zero_centered_vec(width: usize, size: usize) -> Vec<Self>;
+    fn uniform_binary_vec(size: usize) -> Vec<Self>;
+    fn uniform_ternary_vec(size: usize) -> Vec<Self>;
+    fn t_uniform_vec(log2_bound: u32, size: usize) -> Vec<Self>;
+    /// Samples a zero-centered discrete gaussian, whose variance is expressed on the torus.
+    fn gaussian(variance: Variance) -> Self;
+    fn gaussian_vec(variance: Variance, size: usize) -> Vec<Self>;
+}
+
+impl RawUnsignedIntegers for u16 {
+    fn one() -> Self {
+        1u16
+    }
+    fn one_vec(size: usize) -> Vec<Self> {
+        vec![1u16; size]
+    }
+    fn zero() -> Self {
+        0u16
+    }
+    fn zero_vec(size: usize) -> Vec<Self> {
+        vec![0u16; size]
+    }
+
+    fn power_of_two(pow: usize) -> Self {
+        2u16.pow(pow as u32)
+    }
+
+    fn power_of_two_vec(pow: usize, size: usize) -> Vec<Self> {
+        vec![2u16.pow(pow as u32); size]
+    }
+
+    fn pick(array: &[Self]) -> Self {
+        let mut generator = RandomGenerator::new(None);
+        let index: u16 = generator.random_uniform();
+        let index = index % array.len() as u16;
+        array[index as usize]
+    }
+
+    fn pick_vec(array: &[Self], size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        (0..size)
+            .map(|_| {
+                let index: u16 = generator.random_uniform();
+                let index = index % array.len() as u16;
+                array[index as usize]
+            })
+            .collect()
+    }
+
+    fn uniform() -> Self {
+        let mut generator = RandomGenerator::new(None);
+        generator.random_uniform()
+    }
+    fn uniform_vec(size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        generator.random_uniform_tensor(size).into_container()
+    }
+    fn uniform_n_msb(n: usize) -> Self {
+        let mut generator = RandomGenerator::new(None);
+        generator.random_uniform_n_msb(n)
+    }
+    fn uniform_n_msb_vec(n: usize, size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_uniform_n_msb_tensor(size, n)
+            .into_container()
+    }
+
+    fn uniform_between(range: Range<usize>) -> Self {
+        let mut generator = RandomGenerator::new(None);
+        let val: u16 = generator.random_uniform();
+        val % ((range.end as u16) - (range.start as u16)) + (range.start as u16)
+    }
+
+    fn uniform_between_vec(range: Range<usize>, size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        let mut output = generator.random_uniform_tensor(size).into_container();
+        output.iter_mut().for_each(|val| {
+            *val %= ((range.end as u16) - (range.start as u16)) + (range.start as u16)
+        });
+        output
+    }
+
+    fn uniform_zero_centered(width: usize) -> Self {
+        let val: u16 = Self::uniform();
+        let val = val % (width as u16);
+        let val: i16 = val as i16;
+        let val = val - ((width / 2) as i16);
+        val as u16
+    }
+    fn uniform_zero_centered_vec(width: usize, size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        let mut output = generator.random_uniform_tensor(size).into_container();
+        output.iter_mut().for_each(|val| {
+            let v = *val % (width as u16);
+            let v: i16 = v as i16;
+            let v = v - ((width / 2) as i16);
+            *val = v as u16;
+        });
+        output
+    }
+
+    fn uniform_binary_vec(size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_uniform_binary_tensor(size)
+            .into_container()
+    }
+    fn uniform_ternary_vec(size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_uniform_ternary_tensor(size)
+            .into_container()
+    }
+    fn t_uniform_vec(log2_bound: u32, size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        (0..size)
+            .map(|_| generator.random_t_uniform(log2_bound))
+            .collect()
+    }
+
+    fn gaussian(variance: Variance) -> Self {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_gaussian::<f64, u16>(0., variance.get_standard_dev())
+            .0
+    }
+    fn gaussian_vec(variance: Variance, size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_gaussian_tensor(size, 0., variance.get_standard_dev())
+            .into_container()
+    }
 }
 
 impl RawUnsignedIntegers for u32 {
@@ -116,6 +260,38 @@ impl RawUnsignedIntegers for u32 {
         });
         output
     }
+
+    fn uniform_binary_vec(size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_uniform_binary_tensor(size)
+            .into_container()
+    }
+    fn uniform_ternary_vec(size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_uniform_ternary_tensor(size)
+            .into_container()
+    }
+    fn t_uniform_vec(log2_bound: u32, size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        (0..size)
+            .map(|_| generator.random_t_uniform(log2_bound))
+            .collect()
+    }
+
+    fn gaussian(variance: Variance) -> Self {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_gaussian::<f64, u32>(0., variance.get_standard_dev())
+            .0
+    }
+    fn gaussian_vec(variance: Variance, size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_gaussian_tensor(size, 0., variance.get_standard_dev())
+            .into_container()
+    }
 }
 
 impl RawUnsignedIntegers for u64 {
@@ -208,4 +384,381 @@ impl RawUnsignedIntegers for u64 {
         });
         output
     }
+
+    fn uniform_binary_vec(size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_uniform_binary_tensor(size)
+            .into_container()
+    }
+    fn uniform_ternary_vec(size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_uniform_ternary_tensor(size)
+            .into_container()
+    }
+    fn t_uniform_vec(log2_bound: u32, size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        (0..size)
+            .map(|_| generator.random_t_uniform(log2_bound))
+            .collect()
+    }
+
+    fn gaussian(variance: Variance) -> Self {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_gaussian::<f64, u64>(0., variance.get_standard_dev())
+            .0
+    }
+    fn gaussian_vec(variance: Variance, size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_gaussian_tensor(size, 0., variance.get_standard_dev())
+            .into_container()
+    }
+}
+
+impl RawUnsignedIntegers for u128 {
+    fn one() -> Self {
+        1u128
+    }
+    fn one_vec(size: usize) -> Vec<Self> {
+        vec![1u128; size]
+    }
+    fn zero() -> Self {
+        0u128
+    }
+    fn zero_vec(size: usize) -> Vec<Self> {
+        vec![0u128; size]
+    }
+    fn power_of_two(pow: usize) -> Self {
+        2u128.pow(pow as u32)
+    }
+
+    fn power_of_two_vec(pow: usize, size: usize) -> Vec<Self> {
+        vec![2u128.pow(pow as u32); size]
+    }
+
+    fn pick(array: &[Self]) -> Self {
+        let mut generator = RandomGenerator::new(None);
+        let index: u16 = generator.random_uniform();
+        let index = index % array.len() as u16;
+        array[index as usize]
+    }
+
+    fn pick_vec(array: &[Self], size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        (0..size)
+            .map(|_| {
+                let index: u16 = generator.random_uniform();
+                let index = index % array.len() as u16;
+                array[index as usize]
+            })
+            .collect()
+    }
+    fn uniform() -> Self {
+        let mut generator = RandomGenerator::new(None);
+        generator.random_uniform()
+    }
+    fn uniform_vec(size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        generator.random_uniform_tensor(size).into_container()
+    }
+    fn uniform_n_msb(n: usize) -> Self {
+        let mut generator = RandomGenerator::new(None);
+        generator.random_uniform_n_msb(n)
+    }
+    fn uniform_n_msb_vec(n: usize, size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_uniform_n_msb_tensor(size, n)
+            .into_container()
+    }
+
+    fn uniform_between(range: Range<usize>) -> Self {
+        let mut generator = RandomGenerator::new(None);
+        let val: u128 = generator.random_uniform();
+        val % ((range.end as u128) - (range.start as u128)) + (range.start as u128)
+    }
+
+    fn uniform_between_vec(range: Range<usize>, size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        let mut output = generator.random_uniform_tensor(size).into_container();
+        output.iter_mut().for_each(|val| {
+            *val %= ((range.end as u128) - (range.start as u128)) + (range.start as u128)
+        });
+        output
+    }
+
+    fn uniform_zero_centered(width: usize) -> Self {
+        let val: u128 = Self::uniform();
+        let val = val % (width as u128);
+        let val: i128 = val as i128;
+        let val = val - ((width / 2) as i128);
+        val as u128
+    }
+    fn uniform_zero_centered_vec(width: usize, size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        let mut output = generator.random_uniform_tensor(size).into_container();
+        output.iter_mut().for_each(|val| {
+            let v = *val % (width as u128);
+            let v: i128 = v as i128;
+            let v = v - ((width / 2) as i128);
+            *val = v as u128;
+        });
+        output
+    }
+
+    fn uniform_binary_vec(size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_uniform_binary_tensor(size)
+            .into_container()
+    }
+    fn uniform_ternary_vec(size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_uniform_ternary_tensor(size)
+            .into_container()
+    }
+    fn t_uniform_vec(log2_bound: u32, size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        (0..size)
+            .map(|_| generator.random_t_uniform(log2_bound))
+            .collect()
+    }
+
+    fn gaussian(variance: Variance) -> Self {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_gaussian::<f64, u128>(0., variance.get_standard_dev())
+            .0
+    }
+    fn gaussian_vec(variance: Variance, size: usize) -> Vec<Self> {
+        let mut generator = RandomGenerator::new(None);
+        generator
+            .random_gaussian_tensor(size, 0., variance.get_standard_dev())
+            .into_container()
+    }
+}
+
+/// Encodes `message` in the most significant bits of a raw integer.
+///
+/// The message is made of `message_bits` bits, and is topped by `padding_bits` bits of padding.
+pub fn encode_message<Raw>(message: Raw, message_bits: usize, padding_bits: usize) -> Raw
+where
+    Raw: RawUnsignedIntegers,
+{
+    message << (Raw::BITS - message_bits - padding_bits)
+}
+
+/// Decodes a message encoded with [`encode_message`], rounding to the closest value.
+///
+/// The padding bits are decoded along with the message, so that the overflows of the message
+/// space are not hidden.
+pub fn decode_message<Raw>(raw: Raw, message_bits: usize, padding_bits: usize) -> Raw
+where
+    Raw: RawUnsignedIntegers,
+{
+    let delta_log = Raw::BITS - message_bits - padding_bits;
+    raw.wrapping_add(Raw::ONE << (delta_log - 1)) >> delta_log
+}
+
+/// Computes `lhs + rhs` modulo `modulus`.
+pub fn modular_add<Raw>(lhs: Raw, rhs: Raw, modulus: RawModulus) -> Raw
+where
+    Raw: RawUnsignedIntegers,
+{
+    match modulus {
+        RawModulus::Native => lhs.wrapping_add(rhs),
+        RawModulus::Custom(_) => {
+            let (lhs, rhs, modulus) = reduce(lhs, rhs, modulus);
+            Raw::cast_from((lhs + rhs) % modulus)
+        }
+    }
+}
+
+/// Computes `lhs - rhs` modulo `modulus`.
+pub fn modular_sub<Raw>(lhs: Raw, rhs: Raw, modulus: RawModulus) -> Raw
+where
+    Raw: RawUnsignedIntegers,
+{
+    match modulus {
+        RawModulus::Native => lhs.wrapping_sub(rhs),
+        RawModulus::Custom(_) => {
+            let (lhs, rhs, modulus) = reduce(lhs, rhs, modulus);
+            Raw::cast_from((lhs + modulus - rhs) % modulus)
+        }
+    }
+}
+
+/// Computes `lhs * rhs` modulo `modulus`.
+///
+/// A custom modulus must be lower than $2^{64}$.
+pub fn modular_mul<Raw>(lhs: Raw, rhs: Raw, modulus: RawModulus) -> Raw
+where
+    Raw: RawUnsignedIntegers,
+{
+    match modulus {
+        RawModulus::Native => lhs.wrapping_mul(rhs),
+        RawModulus::Custom(_) => {
+            // Both values are smaller than 2^64, hence their product fits in a u128.
+            let (lhs, rhs, modulus) = reduce(lhs, rhs, modulus);
+            Raw::cast_from((lhs * rhs) % modulus)
+        }
+    }
+}
+
+/// Computes `-value` modulo `modulus`.
+pub fn modular_neg<Raw>(value: Raw, modulus: RawModulus) -> Raw
+where
+    Raw: RawUnsignedIntegers,
+{
+    modular_sub(Raw::ZERO, value, modulus)
+}
+
+// Returns the two values reduced modulo `modulus`, along with the value of the modulus.
+fn reduce<Raw: RawUnsignedIntegers>(lhs: Raw, rhs: Raw, modulus: RawModulus) -> (u128, u128, u128) {
+    let modulus = modulus.get::<Raw>();
+    let lhs: u128 = lhs.cast_into();
+    let rhs: u128 = rhs.cast_into();
+    (lhs % modulus, rhs % modulus, modulus)
+}
+
+/// Computes the negacyclic convolution of two lists of raw polynomials.
+///
+/// The `lhs` and `rhs` slices are split in polynomials of `polynomial_size` coefficients, stored
+/// by increasing degree, and the output contains their polynomial-wise products reduced modulo
+/// $X^N + 1$. The arithmetic is wrapping, which makes this the reference computation of the
+/// operations multiplying GLWE plaintexts.
+///
+/// # Panics
+///
+/// Panics if the two slices have different lengths, or if their length is not a multiple of
+/// `polynomial_size`.
+pub fn negacyclic_convolution<Raw>(lhs: &[Raw], rhs: &[Raw], polynomial_size: usize) -> Vec<Raw>
+where
+    Raw: RawUnsignedIntegers,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    assert_eq!(lhs.len() % polynomial_size, 0);
+    let mut output = Raw::zero_vec(lhs.len());
+    for ((output, lhs), rhs) in output
+        .chunks_mut(polynomial_size)
+        .zip(lhs.chunks(polynomial_size))
+        .zip(rhs.chunks(polynomial_size))
+    {
+        for (i, lhs_i) in lhs.iter().enumerate() {
+            for (j, rhs_j) in rhs.iter().enumerate() {
+                let product = lhs_i.wrapping_mul(*rhs_j);
+                // Since X^N = -1, the monomial X^{i+j} is equal to -X^{i+j-N} when i + j >= N.
+                if i + j < polynomial_size {
+                    output[i + j] = output[i + j].wrapping_add(product);
+                } else {
+                    let k = i + j - polynomial_size;
+                    output[k] = output[k].wrapping_sub(product);
+                }
+            }
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        decode_message, encode_message, modular_add, modular_mul, modular_neg, modular_sub,
+        RawUnsignedIntegers,
+    };
+    use crate::raw::statistical_test::{assert_noise_variance, RawModulus};
+    use concrete_commons::dispersion::{DispersionParameter, TUniform, Variance};
+
+    const SAMPLE_COUNT: usize = 100_000;
+
+    #[test]
+    fn test_gaussian_variance() {
+        let variance = Variance(2_f64.powi(-20));
+        let tested = u32::gaussian_vec(variance, SAMPLE_COUNT);
+        assert!(assert_noise_variance(
+            &tested,
+            &vec![0; SAMPLE_COUNT],
+            variance
+        ));
+        let variance = Variance(2_f64.powi(-40));
+        let tested = u64::gaussian_vec(variance, SAMPLE_COUNT);
+        assert!(assert_noise_variance(
+            &tested,
+            &vec![0; SAMPLE_COUNT],
+            variance
+        ));
+        assert!(!assert_noise_variance(
+            &tested,
+            &vec![0; SAMPLE_COUNT],
+            Variance(variance.0 * 2.)
+        ));
+    }
+
+    #[test]
+    fn test_t_uniform_variance() {
+        let log2_bound = 20;
+        let tested = u64::t_uniform_vec(log2_bound, SAMPLE_COUNT);
+        let bound = 1_u64 << log2_bound;
+        assert!(tested
+            .iter()
+            .all(|&x| x <= bound || x.wrapping_neg() <= bound));
+        let variance = Variance(TUniform::from_modular_log_bound::<u64>(log2_bound).get_variance());
+        assert!(assert_noise_variance(
+            &tested,
+            &vec![0; SAMPLE_COUNT],
+            variance
+        ));
+    }
+
+    #[test]
+    fn test_binary_and_ternary_frequencies() {
+        // The frequency of every value deviates from its expectation by less than five standard
+        // deviations.
+        let tolerance = |p: f64| 5. * f64::sqrt(p * (1. - p) / SAMPLE_COUNT as f64);
+        let binary = u32::uniform_binary_vec(SAMPLE_COUNT);
+        assert!(binary.iter().all(|&x| x <= 1));
+        let ones = binary.iter().filter(|&&x| x == 1).count() as f64 / SAMPLE_COUNT as f64;
+        assert!((ones - 0.5).abs() < tolerance(0.5));
+        let ternary = u64::uniform_ternary_vec(SAMPLE_COUNT);
+        for value in [0, 1, u64::MAX] {
+            let frequency =
+                ternary.iter().filter(|&&x| x == value).count() as f64 / SAMPLE_COUNT as f64;
+            assert!((frequency - 1. / 3.).abs() < tolerance(1. / 3.));
+        }
+    }
+
+    #[test]
+    fn test_modular_arithmetic() {
+        let modulus = RawModulus::Custom(7);
+        assert_eq!(modular_add(5_u32, 4, modulus), 2);
+        assert_eq!(modular_sub(2_u32, 4, modulus), 5);
+        assert_eq!(modular_mul(6_u64, 6, modulus), 1);
+        assert_eq!(modular_neg(3_u64, modulus), 4);
+        assert_eq!(modular_neg(0_u64, modulus), 0);
+        // With the native modulus, the arithmetic is wrapping.
+        let (a, b) = (u64::uniform(), u64::uniform());
+        assert_eq!(modular_add(a, b, RawModulus::Native), a.wrapping_add(b));
+        assert_eq!(modular_sub(a, b, RawModulus::Native), a.wrapping_sub(b));
+        assert_eq!(modular_mul(a, b, RawModulus::Native), a.wrapping_mul(b));
+        assert_eq!(modular_neg(a, RawModulus::Native), a.wrapping_neg());
+        let (a, b) = (u32::uniform(), u32::uniform());
+        assert_eq!(modular_mul(a, b, RawModulus::Native), a.wrapping_mul(b));
+    }
+
+    #[test]
+    fn test_message_encoding_round_trip() {
+        for message in 0..16_u64 {
+            let encoded = encode_message(message, 4, 1);
+            assert_eq!(encoded, message << 59);
+            let noisy = encoded.wrapping_add(u64::gaussian(Variance(2_f64.powi(-20))));
+            assert_eq!(decode_message(noisy, 4, 1), message);
+        }
+        // An overflow of the message space is decoded in the padding bit.
+        let encoded = encode_message(15_u32, 4, 1).wrapping_add(encode_message(1, 4, 1));
+        assert_eq!(decode_message(encoded, 4, 1), 16);
+    }
 }
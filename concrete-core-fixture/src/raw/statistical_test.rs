@@ -4,6 +4,43 @@ use concrete_commons::dispersion::{DispersionParameter, Variance};
 use concrete_core::backends::core::private::math::random::RandomGenerator;
 use kolmogorov_smirnov;
 
+/// The modulus with respect to which raw integers are mapped to the torus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RawModulus {
+    /// The native modulus of the raw integer type, that is `2^Raw::BITS`.
+    Native,
+    /// A custom modulus, lower than the native one. Raw integers are reduced modulo this value
+    /// before being mapped to the torus. Reduced precisions (e.g. 16 bits ciphertexts stored in
+    /// `u32`) are represented by a power of two custom modulus.
+    Custom(u128),
+}
+
+impl RawModulus {
+    /// Returns the value of the modulus for the `Raw` integer type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a custom modulus is lower than two, or greater than the native modulus of `Raw`,
+    /// and for the native modulus of 128 bits integers, which does not fit in a `u128`.
+    pub fn get<Raw: RawUnsignedIntegers>(&self) -> u128 {
+        let native = 1_u128.checked_shl(Raw::BITS as u32);
+        match *self {
+            RawModulus::Native => {
+                native.expect("The native modulus of 128 bits integers does not fit in a u128.")
+            }
+            RawModulus::Custom(modulus) => {
+                assert!(
+                    modulus >= 2 && native.is_none_or(|native| modulus <= native),
+                    "Custom modulus {} is not supported by {} bits integers.",
+                    modulus,
+                    Raw::BITS
+                );
+                modulus
+            }
+        }
+    }
+}
+
 /// A function performing a Kolmogorov Smirnov statistical test.
 ///
 /// The `tested` argument points to an array of samples which is tested for normality. The
@@ -19,6 +56,37 @@ pub fn assert_noise_distribution<Raw>(
 where
     Raw: RawUnsignedIntegers,
 {
+    assert_noise_distribution_with_modulus(
+        tested,
+        expected_means,
+        expected_variance,
+        RawModulus::Native,
+    )
+}
+
+/// A function performing a Kolmogorov Smirnov statistical test, for samples living modulo an
+/// arbitrary modulus.
+///
+/// This is the same test as [`assert_noise_distribution`], except that the distance between the
+/// `tested` samples and the `expected_means` is computed modulo `modulus`. The
+/// `expected_variance` is expressed on the torus, and hence does not depend on the modulus.
+pub fn assert_noise_distribution_with_modulus<Raw>(
+    tested: &[Raw],
+    expected_means: &[Raw],
+    expected_variance: Variance,
+    modulus: RawModulus,
+) -> bool
+where
+    Raw: RawUnsignedIntegers,
+{
+    if let RawModulus::Custom(_) = modulus {
+        // Checks the modulus once, before computing the distances.
+        modulus.get::<Raw>();
+    }
+    if expected_variance.get_variance() == 0. {
+        // A noiseless output must match the expected means exactly.
+        return assert_noiseless(tested, expected_means, modulus);
+    }
     let std_dev = expected_variance.get_standard_dev();
     let confidence = 0.95;
     let n_slots = expected_means.len();
@@ -33,7 +101,9 @@ where
         .iter_mut()
         .zip(expected_means.iter())
         .zip(tested.iter())
-        .for_each(|((sample, first), second)| *sample = torus_modular_distance(*first, *second));
+        .for_each(|((sample, first), second)| {
+            *sample = torus_modular_distance(*first, *second, modulus)
+        });
 
     // fill the theoretical sample vector according to std_dev
     let theoretical_samples = generator
@@ -72,6 +142,84 @@ where
     }
 }
 
+/// The number of standard deviations of the empirical variance tolerated by
+/// [`assert_noise_variance`].
+pub const VARIANCE_CONFIDENCE_FACTOR: f64 = 5.;
+
+/// A function testing that the empirical variance of a noise matches an expected variance.
+///
+/// Contrarily to [`assert_noise_distribution`], which also accepts a noise smaller than expected,
+/// this test is two-sided, and is meant to validate noise estimators. The errors between the
+/// `tested` samples and the `expected_means` are computed on the torus, and their mean square is
+/// compared to the `expected_variance`. The test fails when the relative difference between the
+/// two exceeds [`VARIANCE_CONFIDENCE_FACTOR`] times $\sqrt{2 / n}$, which is the relative standard
+/// deviation of the empirical variance of $n$ gaussian samples.
+pub fn assert_noise_variance<Raw>(
+    tested: &[Raw],
+    expected_means: &[Raw],
+    expected_variance: Variance,
+) -> bool
+where
+    Raw: RawUnsignedIntegers,
+{
+    let modulus = RawModulus::Native;
+    if expected_variance.get_variance() == 0. {
+        // The relative error to a zero variance is not defined.
+        return assert_noiseless(tested, expected_means, modulus);
+    }
+    let sample_count = tested.len() as f64;
+    let empirical_variance = tested
+        .iter()
+        .zip(expected_means.iter())
+        .map(|(tested, expected)| f64::powi(torus_modular_distance(*tested, *expected, modulus), 2))
+        .sum::<f64>()
+        / sample_count;
+    let relative_error = (empirical_variance / expected_variance.get_variance() - 1.).abs();
+    let tolerance = VARIANCE_CONFIDENCE_FACTOR * f64::sqrt(2. / sample_count);
+    if relative_error > tolerance {
+        println!(
+            "empirical variance {:e}, expected variance {:e}, relative error {:.4} over a \
+            tolerance of {:.4}",
+            empirical_variance,
+            expected_variance.get_variance(),
+            relative_error,
+            tolerance
+        );
+    }
+    relative_error <= tolerance
+}
+
+/// A function testing that the empirical variance of a noise does not exceed a bound.
+///
+/// Contrarily to [`assert_noise_variance`], this test is one-sided, and is meant for noises which
+/// are only bounded by their model, such as the rounding errors of floating point computations.
+/// The mean square of the torus errors between the `tested` samples and the `expected_means` must
+/// be smaller than or equal to `variance_bound`.
+pub fn assert_noise_variance_below<Raw>(
+    tested: &[Raw],
+    expected_means: &[Raw],
+    variance_bound: Variance,
+) -> bool
+where
+    Raw: RawUnsignedIntegers,
+{
+    let modulus = RawModulus::Native;
+    let empirical_variance = tested
+        .iter()
+        .zip(expected_means.iter())
+        .map(|(tested, expected)| f64::powi(torus_modular_distance(*tested, *expected, modulus), 2))
+        .sum::<f64>()
+        / tested.len() as f64;
+    if empirical_variance > variance_bound.get_variance() {
+        println!(
+            "empirical variance {:e} above the bound {:e}",
+            empirical_variance,
+            variance_bound.get_variance()
+        );
+    }
+    empirical_variance <= variance_bound.get_variance()
+}
+
 pub fn assert_delta_std_dev<Raw>(
     first: &[Raw],
     second: &[Raw],
@@ -80,8 +228,9 @@ pub fn assert_delta_std_dev<Raw>(
 where
     Raw: RawUnsignedIntegers,
 {
+    let modulus = RawModulus::Native;
     for (x, y) in first.iter().zip(second.iter()) {
-        let distance: f64 = torus_modular_distance(*x, *y);
+        let distance: f64 = torus_modular_distance(*x, *y, modulus);
         let torus_distance = distance / 2_f64.powi(Raw::BITS as i32);
         if torus_distance > 5. * dist.get_standard_dev() {
             return false;
@@ -90,14 +239,199 @@ where
     true
 }
 
-fn torus_modular_distance<T: RawUnsignedIntegers>(first: T, other: T) -> f64 {
-    let d0 = first.wrapping_sub(other);
-    let d1 = other.wrapping_sub(first);
-    if d0 < d1 {
-        let d: f64 = d0.cast_into();
-        d / 2_f64.powi(T::BITS as i32)
-    } else {
-        let d: f64 = d1.cast_into();
-        -d / 2_f64.powi(T::BITS as i32)
+// Checks that the `tested` samples are all equal to the `expected_means`, modulo `modulus`.
+fn assert_noiseless<Raw>(tested: &[Raw], expected_means: &[Raw], modulus: RawModulus) -> bool
+where
+    Raw: RawUnsignedIntegers,
+{
+    let noisy_count = tested
+        .iter()
+        .zip(expected_means.iter())
+        .filter(|(tested, expected)| torus_modular_distance(**tested, **expected, modulus) != 0.)
+        .count();
+    if noisy_count != 0 {
+        println!(
+            "{} samples out of {} are noisy, while no noise was expected",
+            noisy_count,
+            tested.len()
+        );
+    }
+    noisy_count == 0
+}
+
+// Computes the signed distance between `first` and `other` on the torus, once both are reduced
+// modulo `modulus`. The shortest way around the torus is picked, so that two values on each side
+// of the wraparound point are close.
+pub(crate) fn torus_modular_distance<T: RawUnsignedIntegers>(
+    first: T,
+    other: T,
+    modulus: RawModulus,
+) -> f64 {
+    match modulus {
+        // The native modulus is handled with the wrapping arithmetic of the raw type, since it
+        // does not fit in a `u128` for 128 bits integers.
+        RawModulus::Native => {
+            let distance = first.wrapping_sub(other);
+            let scale = 2_f64.powi(-(T::BITS as i32));
+            if distance < T::ONE << (T::BITS - 1) {
+                let distance: f64 = distance.cast_into();
+                distance * scale
+            } else {
+                let distance: f64 = distance.wrapping_neg().cast_into();
+                -distance * scale
+            }
+        }
+        RawModulus::Custom(modulus) => {
+            let first: u128 = first.cast_into();
+            let other: u128 = other.cast_into();
+            let (first, other) = (first % modulus, other % modulus);
+            // The subtractions are ordered to stay in range for moduli above 2^127.
+            let d0 = if first >= other {
+                first - other
+            } else {
+                modulus - (other - first)
+            };
+            let d1 = if d0 == 0 { 0 } else { modulus - d0 };
+            if d0 < d1 {
+                d0 as f64 / modulus as f64
+            } else {
+                -(d1 as f64) / modulus as f64
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        assert_noise_distribution, assert_noise_variance, assert_noise_variance_below,
+        torus_modular_distance, RawModulus,
+    };
+    use concrete_commons::dispersion::{DispersionParameter, Variance};
+    use concrete_core::backends::core::private::math::random::RandomGenerator;
+
+    #[test]
+    fn test_torus_modular_distance_native() {
+        let modulus = RawModulus::Native;
+        assert_eq!(torus_modular_distance(1_u32 << 30, 0, modulus), 0.25);
+        assert_eq!(torus_modular_distance(0, 1_u32 << 30, modulus), -0.25);
+        // Values on each side of the wraparound point are close.
+        let distance = torus_modular_distance(1_u64, u64::MAX, modulus);
+        assert_eq!(distance, 2. / 2_f64.powi(64));
+        let distance = torus_modular_distance(u128::MAX, 1_u128, modulus);
+        assert_eq!(distance, -2. / 2_f64.powi(128));
+    }
+
+    #[test]
+    fn test_torus_modular_distance_custom() {
+        let modulus = RawModulus::Custom(1 << 16);
+        // Bits above the modulus are discarded.
+        assert_eq!(torus_modular_distance(1_u32 << 16, 0, modulus), 0.);
+        assert_eq!(
+            torus_modular_distance(1_u32, (1 << 16) - 1, modulus),
+            2. / 65536.
+        );
+        let modulus = RawModulus::Custom(7);
+        assert_eq!(torus_modular_distance(6_u64, 0, modulus), -1. / 7.);
+        assert_eq!(torus_modular_distance(3_u64, 0, modulus), 3. / 7.);
+    }
+
+    #[test]
+    fn test_torus_modular_distance_custom_near_u128() {
+        let modulus = RawModulus::Custom(u128::MAX - 1);
+        let scale = 1. / (u128::MAX - 1) as f64;
+        // The values are on each side of the wraparound point.
+        assert_eq!(
+            torus_modular_distance(1_u128, u128::MAX - 2, modulus),
+            2. * scale
+        );
+        assert_eq!(
+            torus_modular_distance(u128::MAX - 2, 1_u128, modulus),
+            -2. * scale
+        );
+        // The largest raw value is reduced to one.
+        assert_eq!(torus_modular_distance(u128::MAX, 1_u128, modulus), 0.);
+        assert_eq!(torus_modular_distance(1_u128 << 127, 0, modulus), -0.5);
+    }
+
+    #[test]
+    fn test_noise_variance_is_two_sided() {
+        let mut generator = RandomGenerator::new(None);
+        let variance = Variance(f64::powi(2., -30));
+        let tested: Vec<u64> = (0..10_000)
+            .map(|_| {
+                generator
+                    .random_gaussian::<f64, u64>(0., variance.get_standard_dev())
+                    .0
+            })
+            .collect();
+        let expected_means = vec![0_u64; tested.len()];
+        assert!(assert_noise_variance(&tested, &expected_means, variance));
+        assert!(!assert_noise_variance(
+            &tested,
+            &expected_means,
+            Variance(variance.0 * 2.)
+        ));
+        assert!(!assert_noise_variance(
+            &tested,
+            &expected_means,
+            Variance(variance.0 / 2.)
+        ));
+    }
+
+    #[test]
+    fn test_noise_variance_below_is_one_sided() {
+        let tested = vec![1_u32 << 16, 0, (1_u32 << 16).wrapping_neg(), 0];
+        let expected_means = vec![0_u32; tested.len()];
+        // The mean square of the errors is 2^-33.
+        let variance = Variance(f64::powi(2., -33));
+        assert!(assert_noise_variance_below(
+            &tested,
+            &expected_means,
+            variance
+        ));
+        assert!(assert_noise_variance_below(
+            &tested,
+            &expected_means,
+            Variance(variance.0 * 2.)
+        ));
+        assert!(!assert_noise_variance_below(
+            &tested,
+            &expected_means,
+            Variance(variance.0 / 2.)
+        ));
+    }
+
+    #[test]
+    fn test_zero_variance_requires_exact_samples() {
+        let expected_means = vec![1_u64 << 40, 3 << 60, 0, u64::MAX];
+        let noiseless = expected_means.clone();
+        let noisy: Vec<u64> = expected_means.iter().map(|m| m.wrapping_add(1)).collect();
+        assert!(assert_noise_distribution(
+            &noiseless,
+            &expected_means,
+            Variance(0.)
+        ));
+        assert!(assert_noise_variance(
+            &noiseless,
+            &expected_means,
+            Variance(0.)
+        ));
+        assert!(!assert_noise_distribution(
+            &noisy,
+            &expected_means,
+            Variance(0.)
+        ));
+        assert!(!assert_noise_variance(
+            &noisy,
+            &expected_means,
+            Variance(0.)
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_custom_modulus_too_large() {
+        RawModulus::Custom((1 << 32) + 1).get::<u32>();
     }
 }
@@ -0,0 +1,246 @@
+//! A module containing the snapshots of fixture repetitions, used to report reproducible bugs.
+//!
+//! When the `snapshot` feature is enabled, the parameters, prototypes and outcomes of the fixtures
+//! are serializable. A repetition of a fixture can then be exported to a single file with
+//! `RepetitionSnapshot::export`, attached to a bug report, and imported back with
+//! `RepetitionSnapshot::import`, to be replayed against any backend implementing the same engine
+//! trait with `Fixture::replay`.
+//!
+//! The failing repetitions are exported automatically by [`Fixture::stress`] (and the methods
+//! built on it) when the `CONCRETE_FIXTURE_SNAPSHOT_DIRECTORY` environment variable points to a
+//! directory.
+//!
+//! A snapshot contains all the inputs generated by the fixture, and the outcomes it observed. It
+//! does not contain the randomness sampled by the engine itself, such as the noise of an
+//! encryption: replaying a snapshot executes the engine on the exact same inputs, but the engine
+//! makes new random choices.
+//!
+//! [`Fixture::stress`]: crate::fixture::Fixture::stress
+#[cfg(feature = "snapshot")]
+use crate::fixture::RepetitionMetadata;
+#[cfg(feature = "snapshot")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "snapshot")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "snapshot")]
+use std::fmt::{Display, Formatter};
+#[cfg(feature = "snapshot")]
+use std::fs::File;
+#[cfg(feature = "snapshot")]
+use std::io::{BufReader, BufWriter};
+#[cfg(feature = "snapshot")]
+use std::path::{Path, PathBuf};
+
+/// A trait implemented by the types which can be stored in a snapshot.
+///
+/// With the `snapshot` feature, this trait is implemented by the serializable types. Without it,
+/// it is implemented by every type, such that the bounds of the fixtures do not depend on the
+/// feature.
+#[cfg(feature = "snapshot")]
+pub trait Snapshottable: Serialize + DeserializeOwned {}
+#[cfg(feature = "snapshot")]
+impl<T: Serialize + DeserializeOwned> Snapshottable for T {}
+
+/// A trait implemented by the types which can be stored in a snapshot.
+///
+/// With the `snapshot` feature, this trait is implemented by the serializable types. Without it,
+/// it is implemented by every type, such that the bounds of the fixtures do not depend on the
+/// feature.
+#[cfg(not(feature = "snapshot"))]
+pub trait Snapshottable {}
+#[cfg(not(feature = "snapshot"))]
+impl<T: ?Sized> Snapshottable for T {}
+
+/// The environment variable containing the directory the failing repetitions are exported to.
+#[cfg(feature = "snapshot")]
+pub const SNAPSHOT_DIRECTORY_VARIABLE: &str = "CONCRETE_FIXTURE_SNAPSHOT_DIRECTORY";
+
+/// The version of the snapshot format, incremented whenever the layout of a snapshot changes.
+#[cfg(feature = "snapshot")]
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 2;
+
+/// An error occurring when exporting or importing a snapshot.
+#[cfg(feature = "snapshot")]
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// The snapshot file could not be read or written.
+    Io(std::io::Error),
+    /// The snapshot could not be serialized or deserialized.
+    Serialization(bincode::Error),
+    /// The snapshot was exported with another version of the format.
+    VersionMismatch { found: u32, expected: u32 },
+}
+
+#[cfg(feature = "snapshot")]
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnapshotError::Io(error) => write!(f, "Failed to access the snapshot file: {}", error),
+            SnapshotError::Serialization(error) => {
+                write!(f, "Failed to serialize the snapshot: {}", error)
+            }
+            SnapshotError::VersionMismatch { found, expected } => write!(
+                f,
+                "The snapshot uses version {} of the format, while version {} is expected.",
+                found, expected
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "snapshot")]
+impl std::error::Error for SnapshotError {}
+
+/// A snapshot of a repetition of a fixture.
+///
+/// The snapshot is generic over the types it contains, so that a failing repetition can be
+/// exported from references to its data, and imported back as owned data.
+#[cfg(feature = "snapshot")]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RepetitionSnapshot<Parameters, RepetitionPrototypes, SamplePrototypes, Outcome> {
+    /// The version of the format the snapshot was exported with.
+    pub version: u32,
+    /// The key of the fixture the repetition was sampled from, as returned by
+    /// [`fixture_key`](crate::timing::fixture_key).
+    pub fixture: String,
+    /// The metadata of the repetition.
+    pub metadata: RepetitionMetadata,
+    /// The parameters of the repetition.
+    pub parameters: Parameters,
+    /// The repetition-level prototypes.
+    pub repetition_prototypes: RepetitionPrototypes,
+    /// The sample-level prototypes, one per sample.
+    pub sample_prototypes: Vec<SamplePrototypes>,
+    /// The outcomes observed for every sample.
+    pub outcomes: Vec<Outcome>,
+}
+
+#[cfg(feature = "snapshot")]
+impl<Parameters, RepetitionPrototypes, SamplePrototypes, Outcome>
+    RepetitionSnapshot<Parameters, RepetitionPrototypes, SamplePrototypes, Outcome>
+where
+    Parameters: Serialize,
+    RepetitionPrototypes: Serialize,
+    SamplePrototypes: Serialize,
+    Outcome: Serialize,
+{
+    /// Writes the snapshot to a file.
+    pub fn export<P: AsRef<Path>>(&self, path: P) -> Result<(), SnapshotError> {
+        let file = File::create(path).map_err(SnapshotError::Io)?;
+        bincode::serialize_into(BufWriter::new(file), self).map_err(SnapshotError::Serialization)
+    }
+}
+
+#[cfg(feature = "snapshot")]
+impl<Parameters, RepetitionPrototypes, SamplePrototypes, Outcome>
+    RepetitionSnapshot<Parameters, RepetitionPrototypes, SamplePrototypes, Outcome>
+where
+    Parameters: DeserializeOwned,
+    RepetitionPrototypes: DeserializeOwned,
+    SamplePrototypes: DeserializeOwned,
+    Outcome: DeserializeOwned,
+{
+    /// Reads a snapshot from a file.
+    ///
+    /// The types of the snapshot must be the ones of the fixture it was exported from, for the
+    /// same precision. They do not depend on the backend the fixture was executed with.
+    pub fn import<P: AsRef<Path>>(path: P) -> Result<Self, SnapshotError> {
+        let file = File::open(path).map_err(SnapshotError::Io)?;
+        let snapshot: Self = bincode::deserialize_from(BufReader::new(file))
+            .map_err(SnapshotError::Serialization)?;
+        if snapshot.version != SNAPSHOT_FORMAT_VERSION {
+            return Err(SnapshotError::VersionMismatch {
+                found: snapshot.version,
+                expected: SNAPSHOT_FORMAT_VERSION,
+            });
+        }
+        Ok(snapshot)
+    }
+}
+
+/// Returns the directory the failing repetitions are exported to, if one is given by the
+/// environment.
+#[cfg(feature = "snapshot")]
+pub fn snapshot_directory() -> Option<PathBuf> {
+    std::env::var_os(SNAPSHOT_DIRECTORY_VARIABLE).map(PathBuf::from)
+}
+
+/// Returns the path of a new snapshot file for a fixture, in a given directory.
+///
+/// The name of the file is made of the alphanumeric characters of the fixture key, followed by
+/// the number of nanoseconds elapsed since the unix epoch, such that successive failures do not
+/// overwrite each other.
+#[cfg(feature = "snapshot")]
+pub fn snapshot_path<P: AsRef<Path>>(directory: P, fixture_key: &str) -> PathBuf {
+    let name: String = fixture_key
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or(0);
+    directory
+        .as_ref()
+        .join(format!("{}-{}.snapshot", name, nanos))
+}
+
+#[cfg(all(test, feature = "snapshot"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_then_import_round_trips() {
+        let path = std::env::temp_dir().join("concrete_core_fixture_snapshot_round_trip.snapshot");
+        let parameters = (12usize, 3u32);
+        let repetition_prototypes = vec![1u64, 2, 3];
+        let snapshot = RepetitionSnapshot {
+            version: SNAPSHOT_FORMAT_VERSION,
+            fixture: String::from("Fixture<Precision64,(LweCiphertext64,)>"),
+            metadata: RepetitionMetadata {
+                repetition_index: 1,
+                seed: Some(42),
+                key_id: 2,
+            },
+            parameters: &parameters,
+            repetition_prototypes: &repetition_prototypes,
+            sample_prototypes: vec![4u64, 5],
+            outcomes: vec![(6u64, 7u64)],
+        };
+        snapshot.export(&path).unwrap();
+        let imported =
+            RepetitionSnapshot::<(usize, u32), Vec<u64>, u64, (u64, u64)>::import(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(imported.fixture, snapshot.fixture);
+        assert_eq!(imported.metadata, snapshot.metadata);
+        assert_eq!(imported.parameters, parameters);
+        assert_eq!(imported.repetition_prototypes, repetition_prototypes);
+        assert_eq!(imported.sample_prototypes, snapshot.sample_prototypes);
+        assert_eq!(imported.outcomes, snapshot.outcomes);
+    }
+
+    #[test]
+    fn import_rejects_other_versions() {
+        let path = std::env::temp_dir().join("concrete_core_fixture_snapshot_version.snapshot");
+        let snapshot = RepetitionSnapshot {
+            version: SNAPSHOT_FORMAT_VERSION + 1,
+            fixture: String::new(),
+            metadata: RepetitionMetadata {
+                repetition_index: 0,
+                seed: None,
+                key_id: 1,
+            },
+            parameters: (),
+            repetition_prototypes: (),
+            sample_prototypes: Vec::<()>::new(),
+            outcomes: Vec::<()>::new(),
+        };
+        snapshot.export(&path).unwrap();
+        let imported = RepetitionSnapshot::<(), (), (), ()>::import(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(
+            imported,
+            Err(SnapshotError::VersionMismatch { .. })
+        ));
+    }
+}
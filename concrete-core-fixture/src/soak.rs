@@ -0,0 +1,283 @@
+//! A module containing a soak runner, executing a single fixture for a long time to detect
+//! statistical drifts.
+//!
+//! The correctness tests execute every fixture on a few hundred samples, which can not reveal the
+//! bugs whose effect accumulates slowly, such as a corruption of the state of a random generator
+//! or an error accumulating in a cached buffer of the engine. The [`SoakFixture`] trait,
+//! implemented for every [`Fixture`], keeps executing the engine with the same repetition-level
+//! prototypes (such as the keys) for a given duration, and monitors the noise of its outcomes.
+//!
+//! The executions are grouped in windows of [`SoakConfig::window_size`] samples. The variance of
+//! the errors is measured on every window with [`Fixture::measure_noise`], such that only the
+//! fixtures checking a noise distribution can be soaked. The first
+//! [`SoakConfig::baseline_windows`] windows estimate a baseline variance, along with the
+//! dispersion of the window estimates. Every subsequent window whose variance deviates from the
+//! baseline by more than [`SoakConfig::drift_threshold`] times this dispersion raises an alert,
+//! printed on the standard error. The outcomes of every window are also verified against the
+//! criteria of the fixture.
+//!
+//! The `soak_fixture` example soaks the LWE encryption of the `core` backend.
+//!
+//! [`Fixture::measure_noise`]: crate::fixture::Fixture::measure_noise
+use crate::fixture::Fixture;
+use crate::generation::{IntegerPrecision, Maker};
+use crate::timing::fixture_key;
+use crate::SampleSize;
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_core::prelude::AbstractEngine;
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+/// The configuration of a soak run.
+#[derive(Debug, Clone, Copy)]
+pub struct SoakConfig {
+    /// The duration after which no new window is started.
+    pub duration: Duration,
+    /// The number of executions of the engine in every window.
+    pub window_size: SampleSize,
+    /// The number of windows used to estimate the baseline variance, at least two.
+    pub baseline_windows: usize,
+    /// The number of baseline dispersions a window variance can deviate from the baseline before
+    /// an alert is raised.
+    pub drift_threshold: f64,
+    /// Whether the run stops at the first alert.
+    pub stop_on_alert: bool,
+}
+
+impl Default for SoakConfig {
+    fn default() -> Self {
+        SoakConfig {
+            duration: Duration::from_secs(3600),
+            window_size: SampleSize(1000),
+            baseline_windows: 10,
+            drift_threshold: 6.,
+            stop_on_alert: false,
+        }
+    }
+}
+
+/// The noise measured on a window of a soak run.
+#[derive(Debug, Clone)]
+pub struct SoakWindow {
+    /// The index of the window in the run.
+    pub index: usize,
+    /// The time elapsed since the start of the run, at the end of the window.
+    pub elapsed: Duration,
+    /// The variance of the errors measured on the window.
+    pub measured_variance: f64,
+    /// The variance expected by the noise model of the operator.
+    pub expected_variance: Variance,
+    /// The deviation from the baseline, in baseline dispersions, once the baseline is estimated.
+    pub drift: Option<f64>,
+    /// Whether the outcomes of the window match the criteria of the fixture.
+    pub verified: bool,
+}
+
+impl SoakWindow {
+    /// Returns whether the window deviates from the baseline by more than `threshold`
+    /// dispersions, or failed its verification.
+    pub fn is_alert(&self, threshold: f64) -> bool {
+        !self.verified || self.drift.is_some_and(|drift| drift.abs() > threshold)
+    }
+}
+
+impl Display for SoakWindow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "window {} ({:.0}s): variance {:e} (expected {:e})",
+            self.index,
+            self.elapsed.as_secs_f64(),
+            self.measured_variance,
+            self.expected_variance.get_variance()
+        )?;
+        match self.drift {
+            Some(drift) => write!(f, ", drift {:+.2}", drift)?,
+            None => write!(f, ", baseline")?,
+        }
+        if !self.verified {
+            write!(f, ", verification failed")?;
+        }
+        Ok(())
+    }
+}
+
+/// The outcome of a soak run.
+#[derive(Debug, Clone)]
+pub struct SoakReport {
+    /// The key identifying the fixture, as returned by [`fixture_key`].
+    pub fixture_key: String,
+    /// The configuration of the run.
+    pub config: SoakConfig,
+    /// The baseline variance, if enough windows were executed to estimate it.
+    pub baseline_variance: Option<f64>,
+    /// The windows of the run, in execution order.
+    pub windows: Vec<SoakWindow>,
+}
+
+impl SoakReport {
+    /// Returns an iterator over the windows which raised an alert.
+    pub fn alerts(&self) -> impl Iterator<Item = &SoakWindow> {
+        let threshold = self.config.drift_threshold;
+        self.windows
+            .iter()
+            .filter(move |window| window.is_alert(threshold))
+    }
+
+    /// Returns whether the run completed its baseline, and raised no alert.
+    pub fn passed(&self) -> bool {
+        self.baseline_variance.is_some() && self.alerts().next().is_none()
+    }
+}
+
+/// A trait soaking the implementors of the engine trait of a fixture.
+///
+/// This trait is implemented for every type implementing [`Fixture`], and only provides default
+/// methods.
+pub trait SoakFixture<Precision: IntegerPrecision, Engine: AbstractEngine, RelatedEntities>:
+    Fixture<Precision, Engine, RelatedEntities>
+{
+    /// A method which executes the engine window after window for a fixed set of parameters and
+    /// fixed repetition-level prototypes, until the duration of the run elapses.
+    ///
+    /// Returns `None` if the fixture does not measure the noise of its outcomes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the configuration uses less than two baseline windows.
+    fn soak(
+        maker: &mut Maker,
+        engine: &mut Engine,
+        parameters: &Self::Parameters,
+        config: &SoakConfig,
+    ) -> Option<SoakReport>
+    where
+        Self: Sized,
+    {
+        assert!(
+            config.baseline_windows >= 2,
+            "The baseline must be estimated on at least two windows."
+        );
+        let key = fixture_key::<Self, Precision, RelatedEntities>();
+        let repetition_proto = Self::generate_random_repetition_prototypes(parameters, maker);
+        let criteria = Self::compute_criteria(parameters, maker, &repetition_proto);
+        let mut report = SoakReport {
+            fixture_key: key,
+            config: *config,
+            baseline_variance: None,
+            windows: Vec::new(),
+        };
+        let mut baseline = Vec::with_capacity(config.baseline_windows);
+        let mut dispersion = 0.;
+        let start = Instant::now();
+        while start.elapsed() < config.duration {
+            let outputs = Self::sample(
+                maker,
+                engine,
+                parameters,
+                &repetition_proto,
+                config.window_size,
+            );
+            let verified = Self::verify(&criteria, outputs.as_slice());
+            let measurement = Self::measure_noise(&criteria, outputs.as_slice())?;
+            let measured_variance = measurement.measured_variance()?;
+            let drift = match report.baseline_variance {
+                Some(baseline_variance) => {
+                    Some(drift(measured_variance, baseline_variance, dispersion))
+                }
+                None => {
+                    baseline.push(measured_variance);
+                    if baseline.len() == config.baseline_windows {
+                        let (mean, deviation) = mean_and_deviation(baseline.as_slice());
+                        // The baseline mean is itself an estimate, whose dispersion adds to the
+                        // one of the window estimates.
+                        dispersion = deviation * (1. + 1. / baseline.len() as f64).sqrt();
+                        report.baseline_variance = Some(mean);
+                    }
+                    None
+                }
+            };
+            let window = SoakWindow {
+                index: report.windows.len(),
+                elapsed: start.elapsed(),
+                measured_variance,
+                expected_variance: measurement.expected_variance,
+                drift,
+                verified,
+            };
+            let alert = window.is_alert(config.drift_threshold);
+            if alert {
+                eprintln!("Soak alert for {}: {}", report.fixture_key, window);
+            }
+            report.windows.push(window);
+            if alert && config.stop_on_alert {
+                break;
+            }
+        }
+        Some(report)
+    }
+}
+
+impl<Precision, Engine, RelatedEntities, F> SoakFixture<Precision, Engine, RelatedEntities> for F
+where
+    Precision: IntegerPrecision,
+    Engine: AbstractEngine,
+    F: Fixture<Precision, Engine, RelatedEntities>,
+{
+}
+
+// Returns the deviation of a window variance from the baseline, in baseline dispersions. When
+// the window estimates of the baseline do not disperse at all, any deviation is infinite.
+fn drift(measured_variance: f64, baseline_variance: f64, dispersion: f64) -> f64 {
+    let deviation = measured_variance - baseline_variance;
+    if dispersion > 0. {
+        deviation / dispersion
+    } else if deviation == 0. {
+        0.
+    } else {
+        deviation.signum() * f64::INFINITY
+    }
+}
+
+// Returns the mean and the (unbiased) standard deviation of the values.
+fn mean_and_deviation(values: &[f64]) -> (f64, f64) {
+    let count = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / count;
+    let variance = values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / (count - 1.);
+    (mean, variance.sqrt())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_drift() {
+        let (mean, deviation) = mean_and_deviation(&[1., 2., 3.]);
+        assert_eq!(mean, 2.);
+        assert_eq!(deviation, 1.);
+        assert_eq!(drift(5., mean, deviation), 3.);
+        assert_eq!(drift(2., 2., 0.), 0.);
+        assert_eq!(drift(1., 2., 0.), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_alerts() {
+        let window = SoakWindow {
+            index: 0,
+            elapsed: Duration::from_secs(1),
+            measured_variance: 1.,
+            expected_variance: Variance(1.),
+            drift: Some(-7.),
+            verified: true,
+        };
+        assert!(window.is_alert(6.));
+        assert!(!window.is_alert(8.));
+        let baseline_window = SoakWindow {
+            drift: None,
+            verified: false,
+            ..window
+        };
+        assert!(baseline_window.is_alert(8.));
+    }
+}
@@ -0,0 +1,295 @@
+//! A module containing the timing budgets used to guard the fixtures against performance
+//! regressions.
+//!
+//! When running a fixture with [`Fixture::stress_all_parameters_with_budget`], the duration of
+//! every execution of the engine is measured (the generation of the inputs and the analysis of the
+//! outputs are not). For every set of parameters, the mean duration of an execution is then
+//! compared to the budget of the fixture, and the run fails if the budget is exceeded by more than
+//! the budget factor.
+//!
+//! Budgets are read from a text file, containing one budget per line:
+//! ```text
+//! # <fixture key> <parameters index> <max milliseconds per operation>
+//! LweCiphertextDiscardingBootstrapFixture1<Precision64,(FourierLweBootstrapKey64,...)> 0 12.5
+//! ```
+//! The fixture key is the one returned by [`fixture_key`], and the parameters index is the
+//! position of the parameters in the iterator returned by
+//! [`Fixture::parameters_iterator`]. Empty lines and lines starting with `#` are ignored.
+//!
+//! The measured durations are printed in the same format on the standard output, so that a budget
+//! file can be bootstrapped by running the fixtures once with an empty budget file.
+//!
+//! [`Fixture`]: crate::fixture::Fixture
+//! [`Fixture::stress_all_parameters_with_budget`]: crate::fixture::Fixture::stress_all_parameters_with_budget
+//! [`Fixture::parameters_iterator`]: crate::fixture::Fixture::parameters_iterator
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::time::Duration;
+
+/// The environment variable containing the path to the budget file.
+pub const BUDGET_FILE_VARIABLE: &str = "CONCRETE_FIXTURE_TIMING_BUDGET";
+
+/// The environment variable containing the factor by which the budgets can be exceeded.
+pub const BUDGET_FACTOR_VARIABLE: &str = "CONCRETE_FIXTURE_TIMING_FACTOR";
+
+/// The factor by which the budgets can be exceeded, when none is specified.
+pub const DEFAULT_BUDGET_FACTOR: f64 = 2.;
+
+/// An error occurring when loading a timing budget.
+#[derive(Debug)]
+pub enum TimingBudgetError {
+    /// The budget file could not be read.
+    Io(std::io::Error),
+    /// A line of the budget file is malformed.
+    MalformedLine { line_number: usize, line: String },
+    /// The budget factor is not a positive number.
+    InvalidFactor(String),
+}
+
+impl Display for TimingBudgetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimingBudgetError::Io(error) => write!(f, "Failed to read the budget file: {}", error),
+            TimingBudgetError::MalformedLine { line_number, line } => write!(
+                f,
+                "Malformed line {} in the budget file: `{}`. Expected \
+                `<fixture key> <parameters index> <max milliseconds>`.",
+                line_number, line
+            ),
+            TimingBudgetError::InvalidFactor(factor) => write!(
+                f,
+                "Invalid budget factor `{}`. Expected a positive number.",
+                factor
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TimingBudgetError {}
+
+/// The durations of the engine executions measured for a set of parameters.
+#[derive(Debug, Clone, Default)]
+pub struct Timings {
+    total: Duration,
+    count: u32,
+    max: Duration,
+}
+
+impl Timings {
+    /// Records the duration of an engine execution.
+    pub fn record(&mut self, duration: Duration) {
+        self.total += duration;
+        self.count += 1;
+        self.max = self.max.max(duration);
+    }
+
+    /// Returns the number of recorded executions.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Returns the mean duration of the recorded executions, if any.
+    pub fn mean(&self) -> Option<Duration> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.total / self.count)
+        }
+    }
+
+    /// Returns the longest duration of the recorded executions.
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+}
+
+/// A set of timing budgets, along with the factor by which they can be exceeded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimingBudget {
+    budgets: HashMap<(String, usize), f64>,
+    factor: f64,
+}
+
+impl TimingBudget {
+    /// Parses a budget from the content of a budget file.
+    pub fn parse(content: &str) -> Result<TimingBudget, TimingBudgetError> {
+        let mut budgets = HashMap::new();
+        for (line_index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let malformed = || TimingBudgetError::MalformedLine {
+                line_number: line_index + 1,
+                line: line.to_owned(),
+            };
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() != 3 {
+                return Err(malformed());
+            }
+            let index = fields[1].parse::<usize>().map_err(|_| malformed())?;
+            let max_ms = fields[2].parse::<f64>().map_err(|_| malformed())?;
+            if !(max_ms.is_finite() && max_ms > 0.) {
+                return Err(malformed());
+            }
+            budgets.insert((fields[0].to_owned(), index), max_ms);
+        }
+        Ok(TimingBudget {
+            budgets,
+            factor: DEFAULT_BUDGET_FACTOR,
+        })
+    }
+
+    /// Loads a budget from a budget file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<TimingBudget, TimingBudgetError> {
+        let content = std::fs::read_to_string(path).map_err(TimingBudgetError::Io)?;
+        Self::parse(&content)
+    }
+
+    /// Loads the budget pointed to by the environment, if any.
+    ///
+    /// The budget file is read from the path contained in the [`BUDGET_FILE_VARIABLE`] variable,
+    /// and the factor from the [`BUDGET_FACTOR_VARIABLE`] variable. If the first variable is not
+    /// set, no budget is returned, and the fixtures should not be timed.
+    pub fn from_env() -> Result<Option<TimingBudget>, TimingBudgetError> {
+        let path = match std::env::var_os(BUDGET_FILE_VARIABLE) {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+        let budget = Self::from_file(path)?;
+        match std::env::var(BUDGET_FACTOR_VARIABLE) {
+            Ok(factor) => {
+                let value = factor
+                    .trim()
+                    .parse::<f64>()
+                    .map_err(|_| TimingBudgetError::InvalidFactor(factor.clone()))?;
+                if !(value.is_finite() && value > 0.) {
+                    return Err(TimingBudgetError::InvalidFactor(factor));
+                }
+                Ok(Some(budget.with_factor(value)))
+            }
+            Err(_) => Ok(Some(budget)),
+        }
+    }
+
+    /// Returns the same budget, with a different factor.
+    pub fn with_factor(self, factor: f64) -> TimingBudget {
+        TimingBudget { factor, ..self }
+    }
+
+    /// Returns the factor by which the budgets can be exceeded.
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
+
+    /// Returns the maximum number of milliseconds per operation for a fixture and a set of
+    /// parameters, if a budget was given for it.
+    pub fn get(&self, fixture_key: &str, parameters_index: usize) -> Option<f64> {
+        self.budgets
+            .get(&(fixture_key.to_owned(), parameters_index))
+            .copied()
+    }
+
+    /// Checks the timings of a fixture for a set of parameters against the budget.
+    ///
+    /// The measured timings are printed on the standard output, in the format of the budget
+    /// file. If no budget was given for this fixture and parameters, the check succeeds.
+    pub fn check(&self, fixture_key: &str, parameters_index: usize, timings: &Timings) -> bool {
+        let mean_ms = match timings.mean() {
+            Some(mean) => mean.as_secs_f64() * 1000.,
+            None => return true,
+        };
+        println!("{} {} {:.6}", fixture_key, parameters_index, mean_ms);
+        match self.get(fixture_key, parameters_index) {
+            Some(max_ms) if mean_ms > max_ms * self.factor => {
+                println!(
+                    "Timing budget exceeded for {} with parameters {}: {:.6}ms per operation \
+                    over {} executions, for a budget of {:.6}ms with a factor of {}.",
+                    fixture_key,
+                    parameters_index,
+                    mean_ms,
+                    timings.count(),
+                    max_ms,
+                    self.factor
+                );
+                false
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Returns the key identifying a fixture in a budget file.
+///
+/// The key is made of the names of the fixture, precision and related entities types, stripped
+/// from their module paths and whitespaces, e.g.
+/// `LweCiphertextEncryptionFixture<Precision64,(Plaintext64,LweSecretKey64,LweCiphertext64)>`.
+pub fn fixture_key<Fixture: ?Sized, Precision: ?Sized, RelatedEntities: ?Sized>() -> String {
+    format!(
+        "{}<{},{}>",
+        short_type_name(std::any::type_name::<Fixture>()),
+        short_type_name(std::any::type_name::<Precision>()),
+        short_type_name(std::any::type_name::<RelatedEntities>())
+    )
+}
+
+// Removes the module paths and the whitespaces from a type name.
+pub(crate) fn short_type_name(name: &str) -> String {
+    let mut output = String::with_capacity(name.len());
+    let mut segment = String::new();
+    let mut chars = name.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_alphanumeric() || c == '_' {
+            segment.push(c);
+        } else if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            segment.clear();
+        } else {
+            output.push_str(&segment);
+            segment.clear();
+            if !c.is_whitespace() {
+                output.push(c);
+            }
+        }
+    }
+    output.push_str(&segment);
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!(
+            short_type_name("(a::b::Plaintext64, c::LweCiphertext64)"),
+            "(Plaintext64,LweCiphertext64)"
+        );
+        assert_eq!(short_type_name("a::Fixture1"), "Fixture1");
+    }
+
+    #[test]
+    fn test_budget_check() {
+        let budget = TimingBudget::parse("# comment\n\nFixture<P,(E,)> 1 10.\n")
+            .unwrap()
+            .with_factor(1.5);
+        let mut timings = Timings::default();
+        timings.record(Duration::from_millis(14));
+        assert!(budget.check("Fixture<P,(E,)>", 1, &timings));
+        assert!(budget.check("Fixture<P,(E,)>", 0, &timings));
+        timings.record(Duration::from_millis(18));
+        assert!(!budget.check("Fixture<P,(E,)>", 1, &timings));
+    }
+
+    #[test]
+    fn test_budget_parse_malformed() {
+        assert!(matches!(
+            TimingBudget::parse("Fixture 1"),
+            Err(TimingBudgetError::MalformedLine { line_number: 1, .. })
+        ));
+        assert!(TimingBudget::parse("Fixture one 10").is_err());
+        assert!(TimingBudget::parse("Fixture 1 -10").is_err());
+    }
+}
@@ -1,7 +1,10 @@
-use crate::{REPETITIONS, SAMPLE_SIZE};
+use crate::{timing_budget, REPETITIONS, SAMPLE_SIZE};
 use concrete_core::prelude::*;
 use concrete_core_fixture::fixture::*;
-use concrete_core_fixture::generation::{Maker, Precision32, Precision64};
+use concrete_core_fixture::generation::{
+    engine_seed, seed_from_env, Maker, Precision128, Precision16, Precision32, Precision64,
+    SEED_VARIABLE,
+};
 use paste::paste;
 
 macro_rules! test {
@@ -9,15 +12,33 @@ macro_rules! test {
         paste!{
             #[test]
             fn [< test_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
-                let mut maker = Maker::default();
-                let mut engine = CoreEngine::new().unwrap();
-                let test_result =
-                    <$fixture as Fixture<
+                let seed = seed_from_env();
+                let mut maker = Maker::new_seeded(seed);
+                let mut engine = CoreEngine::new_seeded(engine_seed(seed));
+                let test_result = match timing_budget() {
+                    Some(budget) => <$fixture as Fixture<
                         $precision,
                         CoreEngine,
                         ($($types,)+),
-                    >>::stress_all_parameters(&mut maker, &mut engine, REPETITIONS, SAMPLE_SIZE);
-                assert!(test_result);
+                    >>::stress_all_parameters_with_budget(
+                        &mut maker,
+                        &mut engine,
+                        REPETITIONS,
+                        SAMPLE_SIZE,
+                        &budget,
+                    ),
+                    None => <$fixture as Fixture<
+                        $precision,
+                        CoreEngine,
+                        ($($types,)+),
+                    >>::stress_all_parameters(&mut maker, &mut engine, REPETITIONS, SAMPLE_SIZE),
+                };
+                assert!(
+                    test_result,
+                    "The fixture failed with the seed {}, which can be reused by setting {}.",
+                    seed,
+                    SEED_VARIABLE
+                );
             }
         }
     };
@@ -36,74 +57,4 @@ macro_rules! test {
     };
 }
 
-test! {
-    (CleartextCreationFixture, (Cleartext)),
-    (CleartextRetrievalFixture, (Cleartext)),
-    (CleartextDiscardingRetrievalFixture, (Cleartext)),
-    (CleartextVectorCreationFixture, (CleartextVector)),
-    (GlweCiphertextTrivialDecryptionFixture, (PlaintextVector, GlweCiphertext)),
-    (CleartextVectorDiscardingRetrievalFixture, (CleartextVector)),
-    (CleartextVectorRetrievalFixture, (CleartextVector)),
-    (GlweCiphertextDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
-    (GlweCiphertextDiscardingDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
-    (GlweCiphertextDiscardingEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
-    (GlweCiphertextEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
-    (GlweCiphertextTrivialEncryptionFixture, (PlaintextVector, GlweCiphertext)),
-    (GlweCiphertextZeroEncryptionFixture, (GlweSecretKey, GlweCiphertext)),
-    (GlweCiphertextVectorEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertextVector)),
-    (GlweCiphertextVectorDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertextVector)),
-    (GlweCiphertextVectorTrivialDecryptionFixture, (PlaintextVector, GlweCiphertextVector)),
-    (GlweCiphertextVectorTrivialEncryptionFixture, (PlaintextVector, GlweCiphertextVector)),
-    (GlweCiphertextVectorDiscardingDecryptionFixture, (PlaintextVector, GlweSecretKey,
-        GlweCiphertextVector)),
-    (GlweCiphertextVectorDiscardingEncryptionFixture, (PlaintextVector, GlweSecretKey,
-        GlweCiphertextVector)),
-    (GlweCiphertextVectorZeroEncryptionFixture, (GlweSecretKey, GlweCiphertextVector)),
-    (LweCiphertextEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
-    (LweCiphertextZeroEncryptionFixture, (LweSecretKey, LweCiphertext)),
-    (LweCiphertextTrivialEncryptionFixture, (Plaintext, LweCiphertext)),
-    (LweCiphertextTrivialDecryptionFixture, (Plaintext, LweCiphertext)),
-    (LweCiphertextVectorZeroEncryptionFixture, (LweSecretKey, LweCiphertextVector)),
-    (LweCiphertextDecryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
-    (LweCiphertextDiscardingEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
-    (LweCiphertextVectorDecryptionFixture, (PlaintextVector, LweSecretKey, LweCiphertextVector)),
-    (LweCiphertextVectorEncryptionFixture, (PlaintextVector, LweSecretKey, LweCiphertextVector)),
-    (LweCiphertextVectorDiscardingEncryptionFixture, (PlaintextVector, LweSecretKey,
-        LweCiphertextVector)),
-    (LweCiphertextVectorDiscardingDecryptionFixture, (PlaintextVector, LweSecretKey,
-        LweCiphertextVector)),
-    (LweCiphertextCleartextDiscardingMultiplicationFixture, (LweCiphertext, Cleartext, LweCiphertext)),
-    (LweCiphertextCleartextFusingMultiplicationFixture, (LweCiphertext, Cleartext)),
-    (LweCiphertextFusingOppositeFixture, (LweCiphertext)),
-    (LweCiphertextFusingSubtractionFixture, (LweCiphertext, LweCiphertext)),
-    (LweCiphertextVectorFusingAdditionFixture, (LweCiphertextVector, LweCiphertextVector)),
-    (LweCiphertextVectorFusingSubtractionFixture, (LweCiphertextVector, LweCiphertextVector)),
-    (LweCiphertextVectorDiscardingSubtractionFixture, (LweCiphertextVector, LweCiphertextVector)),
-    (LweCiphertextVectorDiscardingAdditionFixture, (LweCiphertextVector, LweCiphertextVector)),
-    (LweCiphertextVectorDiscardingAffineTransformationFixture, (LweCiphertextVector, CleartextVector, Plaintext, LweCiphertext)),
-    (LweCiphertextDiscardingKeyswitchFixture, (LweKeyswitchKey, LweCiphertext, LweCiphertext)),
-    (LweCiphertextDiscardingAdditionFixture, (LweCiphertext, LweCiphertext)),
-    (LweCiphertextDiscardingOppositeFixture, (LweCiphertext, LweCiphertext)),
-    (LweCiphertextFusingAdditionFixture, (LweCiphertext, LweCiphertext)),
-    (LweCiphertextVectorTrivialDecryptionFixture, (PlaintextVector, LweCiphertextVector)),
-    (LweCiphertextVectorTrivialEncryptionFixture, (PlaintextVector, LweCiphertextVector)),
-    (LweCiphertextDiscardingSubtractionFixture, (LweCiphertext, LweCiphertext)),
-    (LweCiphertextDiscardingDecryptionFixture, (LweCiphertext, LweSecretKey, Plaintext)),
-    (LweCiphertextPlaintextDiscardingAdditionFixture, (LweCiphertext, Plaintext, LweCiphertext)),
-    (LweCiphertextPlaintextFusingAdditionFixture, (Plaintext, LweCiphertext)),
-    (LweCiphertextPlaintextDiscardingSubtractionFixture, (LweCiphertext, Plaintext, LweCiphertext)),
-    (LweCiphertextPlaintextFusingSubtractionFixture, (Plaintext, LweCiphertext)),
-    (LweCiphertextDiscardingBootstrapFixture1, (FourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
-    (LweCiphertextDiscardingBootstrapFixture2, (FourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
-    (LweCiphertextDiscardingExtractionFixture, (GlweCiphertext, LweCiphertext)),
-    (LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchFixture, (LweCiphertextVector,
-        PackingKeyswitchKey, GlweCiphertext)),
-    (PlaintextCreationFixture, (Plaintext)),
-    (PlaintextDiscardingRetrievalFixture, (Plaintext)),
-    (PlaintextRetrievalFixture, (Plaintext)),
-    (PlaintextVectorDiscardingRetrievalFixture, (PlaintextVector)),
-    (PlaintextVectorCreationFixture, (PlaintextVector)),
-    (PlaintextVectorRetrievalFixture, (PlaintextVector)),
-    (GlweCiphertextGgswCiphertextExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext)),
-    (GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext))
-}
+concrete_core_fixture::core_fixtures!(test);
@@ -0,0 +1,58 @@
+use crate::{cuda_required, CUDA_REQUIRED_VARIABLE, REPETITIONS, SAMPLE_SIZE};
+use concrete_core::prelude::*;
+use concrete_core_fixture::fixture::*;
+use concrete_core_fixture::generation::{seed_from_env, Maker, Precision64, SEED_VARIABLE};
+use paste::paste;
+
+macro_rules! test {
+    ($fixture: ident, $precision: ident, ($($types:ident),+)) => {
+        paste!{
+            #[test]
+            fn [< test_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
+                // The tests are skipped on the machines without gpu, unless a gpu is required.
+                let mut engine = match CudaEngine::new() {
+                    Ok(engine) => engine,
+                    Err(error) if cuda_required() => panic!(
+                        "No gpu could be used while {} is set: {}",
+                        CUDA_REQUIRED_VARIABLE,
+                        error
+                    ),
+                    Err(error) => {
+                        println!("Skipping the test: {}", error);
+                        return;
+                    }
+                };
+                let seed = seed_from_env();
+                let mut maker = Maker::new_seeded(seed);
+                let test_result =
+                    <$fixture as Fixture<
+                        $precision,
+                        CudaEngine,
+                        ($($types,)+),
+                    >>::stress_all_parameters(&mut maker, &mut engine, REPETITIONS, SAMPLE_SIZE);
+                assert!(
+                    test_result,
+                    "The fixture failed with the seed {}, which can be reused by setting {}.",
+                    seed,
+                    SEED_VARIABLE
+                );
+            }
+        }
+    };
+    ($(($fixture: ident, $precision: ident, ($($types:ident),+))),+) => {
+        $(
+            test!{$fixture, $precision, ($($types),+)}
+        )+
+    };
+}
+
+test! {
+    (LweCiphertextDiscardingBootstrapFixture1, Precision64, (CudaLweBootstrapKey64,
+        CudaGlweCiphertext64, CudaLweCiphertext64, CudaLweCiphertext64)),
+    (LweCiphertextDiscardingBootstrapFixture2, Precision64, (CudaLweBootstrapKey64,
+        CudaGlweCiphertext64, CudaLweCiphertext64, CudaLweCiphertext64)),
+    (LweCiphertextVectorDiscardingBudgetedBootstrapFixture, Precision64, (CudaLweBootstrapKey64,
+        GlweCiphertextVector64, LweCiphertextVector64, LweCiphertextVector64)),
+    (LweCiphertextDiscardingKeyswitchFixture, Precision64, (CudaLweKeyswitchKey64,
+        CudaLweCiphertext64, CudaLweCiphertext64))
+}
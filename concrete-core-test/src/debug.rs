@@ -0,0 +1,174 @@
+//! A module testing the insecure debug backend.
+//!
+//! The fixtures can only be executed when the `CONCRETE_INSECURE_DEBUG` environment variable is
+//! set to `1`, otherwise the debug engine can not be created and the tests fail. The fixtures whose
+//! engine samples noise, such as the encryption ones, are not instantiated, since the debug engine
+//! never adds any noise.
+use crate::{REPETITIONS, SAMPLE_SIZE};
+use concrete_core::backends::debug::{
+    DEBUG_DECOMPOSITION_BASE_LOG, DEBUG_DECOMPOSITION_LEVEL_COUNT, DEBUG_GLWE_DIMENSION,
+    DEBUG_LWE_DIMENSION, DEBUG_POLYNOMIAL_SIZE, INSECURE_DEBUG_VARIABLE,
+};
+use concrete_core::prelude::*;
+use concrete_core_fixture::fixture::*;
+use concrete_core_fixture::generation::{Maker, Precision32, Precision64};
+use paste::paste;
+
+fn debug_engine() -> DebugEngine {
+    DebugEngine::new().unwrap_or_else(|error| panic!("{}", error))
+}
+
+macro_rules! test {
+    ($fixture: ident, $precision: ident, ($($types:ident),+)) => {
+        paste!{
+            #[test]
+            fn [< test_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
+                let mut maker = Maker::default();
+                let mut engine = debug_engine();
+                let test_result =
+                    <$fixture as Fixture<
+                        $precision,
+                        DebugEngine,
+                        ($($types,)+),
+                    >>::stress_all_parameters(&mut maker, &mut engine, REPETITIONS, SAMPLE_SIZE);
+                assert!(test_result);
+            }
+        }
+    };
+    ($(($fixture: ident, $precision: ident, ($($types:ident),+))),+) => {
+        $(
+            test!{$fixture, $precision, ($($types),+)}
+        )+
+    };
+    ($(($fixture: ident, ($($types:ident),+))),+) => {
+        $(
+            paste!{
+                test!{$fixture, Precision32, ($([< $types 32 >]),+)}
+                test!{$fixture, Precision64, ($([< $types 64 >]),+)}
+            }
+        )+
+    };
+}
+
+test! {
+    (CleartextCreationFixture, (ReferenceCleartext)),
+    (CleartextRetrievalFixture, (ReferenceCleartext)),
+    (GlweCiphertextDecryptionFixture, (ReferencePlaintextVector, ReferenceGlweSecretKey,
+        ReferenceGlweCiphertext)),
+    (GlweCiphertextTrivialEncryptionFixture, (ReferencePlaintextVector, ReferenceGlweCiphertext)),
+    (LweCiphertextTrivialEncryptionFixture, (ReferencePlaintext, ReferenceLweCiphertext)),
+    (LweCiphertextDecryptionFixture, (ReferencePlaintext, ReferenceLweSecretKey,
+        ReferenceLweCiphertext)),
+    (LweCiphertextCleartextFusingMultiplicationFixture, (ReferenceLweCiphertext,
+        ReferenceCleartext)),
+    (LweCiphertextDiscardingKeyswitchFixture, (ReferenceLweKeyswitchKey, ReferenceLweCiphertext,
+        ReferenceLweCiphertext)),
+    (LweCiphertextDiscardingAdditionFixture, (ReferenceLweCiphertext, ReferenceLweCiphertext)),
+    (LweCiphertextFusingAdditionFixture, (ReferenceLweCiphertext, ReferenceLweCiphertext)),
+    (PlaintextCreationFixture, (ReferencePlaintext)),
+    (PlaintextRetrievalFixture, (ReferencePlaintext)),
+    (PlaintextVectorCreationFixture, (ReferencePlaintextVector)),
+    (PlaintextVectorRetrievalFixture, (ReferencePlaintextVector))
+}
+
+// The variable set by `test_debug_engine_is_refused_without_the_environment_variable` in the
+// process it spawns.
+const REFUSAL_CHILD_VARIABLE: &str = "CONCRETE_INSECURE_DEBUG_REFUSAL_CHILD";
+
+#[test]
+fn test_debug_engine_is_refused_without_the_environment_variable() {
+    if std::env::var_os(REFUSAL_CHILD_VARIABLE).is_some() {
+        assert!(std::env::var_os(INSECURE_DEBUG_VARIABLE).is_none());
+        assert!(matches!(
+            DebugEngine::new(),
+            Err(DebugError::InsecureDebugDisabled)
+        ));
+        return;
+    }
+    // The environment of the current process is shared by the other tests, so the variable is
+    // removed in a child process, which only executes this test.
+    let status = std::process::Command::new(std::env::current_exe().unwrap())
+        .args([
+            "debug::test_debug_engine_is_refused_without_the_environment_variable",
+            "--exact",
+            "--test-threads=1",
+        ])
+        .env_remove(INSECURE_DEBUG_VARIABLE)
+        .env(REFUSAL_CHILD_VARIABLE, "1")
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
+
+#[test]
+fn test_debug_engine_is_created_with_the_environment_variable() {
+    if std::env::var(INSECURE_DEBUG_VARIABLE).map_or(true, |value| value != "1") {
+        return;
+    }
+    assert!(DebugEngine::new().is_ok());
+}
+
+// Encrypts a message, and bootstraps it with an accumulator encoding a constant function, using
+// the debug parameters.
+fn bootstrap_pipeline(engine: &mut DebugEngine) -> (ReferenceLweCiphertext64, u64) {
+    let noise = Variance(0.);
+    let lwe_sk: ReferenceLweSecretKey64 =
+        engine.create_lwe_secret_key(DEBUG_LWE_DIMENSION).unwrap();
+    let glwe_sk: ReferenceGlweSecretKey64 = engine
+        .create_glwe_secret_key(DEBUG_GLWE_DIMENSION, DEBUG_POLYNOMIAL_SIZE)
+        .unwrap();
+    let bsk = engine
+        .create_lwe_bootstrap_key(
+            &lwe_sk,
+            &glwe_sk,
+            DEBUG_DECOMPOSITION_BASE_LOG,
+            DEBUG_DECOMPOSITION_LEVEL_COUNT,
+            noise,
+        )
+        .unwrap();
+    let lut = engine
+        .create_plaintext_vector(&vec![1_u64 << 61; DEBUG_POLYNOMIAL_SIZE.0])
+        .unwrap();
+    let acc = engine
+        .trivially_encrypt_glwe_ciphertext(DEBUG_GLWE_DIMENSION.to_glwe_size(), &lut)
+        .unwrap();
+    let plaintext = engine.create_plaintext(&(1_u64 << 60)).unwrap();
+    let input = engine
+        .encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)
+        .unwrap();
+    let zero = engine.create_plaintext(&0_u64).unwrap();
+    let output_dimension = LweDimension(DEBUG_GLWE_DIMENSION.0 * DEBUG_POLYNOMIAL_SIZE.0);
+    let mut output = engine
+        .trivially_encrypt_lwe_ciphertext(output_dimension.to_lwe_size(), &zero)
+        .unwrap();
+    engine
+        .discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)
+        .unwrap();
+    let output_key = engine
+        .transmute_glwe_secret_key_to_lwe_secret_key(glwe_sk)
+        .unwrap();
+    let decrypted = engine.decrypt_lwe_ciphertext(&output_key, &output).unwrap();
+    let decrypted = engine.retrieve_plaintext(&decrypted).unwrap();
+    (output, decrypted)
+}
+
+#[test]
+fn test_debug_engine_is_deterministic() {
+    let (output_1, decrypted_1) = bootstrap_pipeline(&mut debug_engine());
+    let (output_2, decrypted_2) = bootstrap_pipeline(&mut debug_engine());
+    assert_eq!(output_1, output_2);
+    assert_eq!(decrypted_1, decrypted_2);
+    assert_eq!(decrypted_1.wrapping_add(1 << 58) >> 59, 4);
+}
+
+#[test]
+fn test_debug_engine_encryption_is_noiseless() {
+    let mut engine = debug_engine();
+    let key: ReferenceLweSecretKey32 = engine.create_lwe_secret_key(DEBUG_LWE_DIMENSION).unwrap();
+    let plaintext = engine.create_plaintext(&0x1234_5678_u32).unwrap();
+    let ciphertext = engine
+        .encrypt_lwe_ciphertext(&key, &plaintext, Variance(2_f64.powf(-10.)))
+        .unwrap();
+    let decrypted = engine.decrypt_lwe_ciphertext(&key, &ciphertext).unwrap();
+    assert_eq!(engine.retrieve_plaintext(&decrypted).unwrap(), 0x1234_5678);
+}
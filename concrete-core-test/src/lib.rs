@@ -3,14 +3,89 @@
 //!
 //! Each submodule here is expected to be activated by a given feature flag (matching the
 //! `backend_*` naming), and to contain the instantiation of a generic correctness test for every
-//! implemented operator.
+//! implemented operator. The `reference` submodule additionally checks the engines of the `core`
+//! backend against the ones of the `reference` backend, executed on the same inputs.
+//!
+//! When the `CONCRETE_FIXTURE_TIMING_BUDGET` environment variable points to a budget file, the
+//! execution times of the operators are checked against it, and the tests fail when a budget is
+//! exceeded by more than the factor found in `CONCRETE_FIXTURE_TIMING_FACTOR` (see the
+//! `concrete_core_fixture::timing` module for the format of the file).
+//!
+//! The `CONCRETE_FIXTURE_EXECUTION_MODE` environment variable selects the entry points of the
+//! engines executed by the tests: `unchecked`, `checked`, or `both`, in which case the checked
+//! executions must succeed and produce the same outcomes as the unchecked ones. It defaults to
+//! `both`, and to `unchecked` when the execution times are checked against a budget.
+//!
+//! The fixture tests are seeded: the seed of a test is printed when it fails, and a failing test
+//! can be re-run with the same inputs and noise by setting the `CONCRETE_FIXTURE_SEED`
+//! environment variable to this seed. When the variable is not set, a fresh seed is drawn for
+//! every test.
+//!
+//! When the `CONCRETE_FIXTURE_NOISE_DIRECTORY` environment variable points to a directory, the
+//! noise measured by the tests is exported to it (see the `concrete_core_fixture::noise_export`
+//! module).
+//!
+//! With the `parameter_files` feature, the parameters of a fixture are loaded from the toml or
+//! json file named after it in the directory found in `CONCRETE_FIXTURE_PARAMETERS_DIRECTORY`, if
+//! any (see the `concrete_core_fixture::parameter_grid` module).
+//!
+//! With the `snapshot` feature, the failing repetitions are exported to the directory found in
+//! `CONCRETE_FIXTURE_SNAPSHOT_DIRECTORY`, such that they can be attached to bug reports (see the
+//! `concrete_core_fixture::snapshot` module).
+//!
+//! The tests of the `cuda` submodule are skipped on the machines where no gpu is found, unless the
+//! `CONCRETE_CUDA_REQUIRED` environment variable is set to `1`, in which case they fail. The
+//! variable is set by the gpu jobs of the continuous integration, so that a missing cuda toolkit
+//! or gpu can not go unnoticed there.
+//!
+//! The `debug` submodule is only activated by the `insecure_debug` feature, and its tests require
+//! the `CONCRETE_INSECURE_DEBUG` environment variable to be set to `1`.
+//!
+//! The lists of the fixtures instantiated by the `core` and `reference` modules are shared with
+//! the `fixtures` test target of `concrete-core`, through the macros of the
+//! `concrete_core_fixture::instantiations` module.
+use concrete_core_fixture::presets::{STANDARD_REPETITIONS, STANDARD_SAMPLE_SIZE};
+use concrete_core_fixture::timing::TimingBudget;
 use concrete_core_fixture::{Repetitions, SampleSize};
 
 /// The number of time a test is repeated for a single set of parameter.
-pub const REPETITIONS: Repetitions = Repetitions(10);
+pub const REPETITIONS: Repetitions = STANDARD_REPETITIONS;
 
 /// The size of the sample used to perform statistical tests.
-pub const SAMPLE_SIZE: SampleSize = SampleSize(100);
+pub const SAMPLE_SIZE: SampleSize = STANDARD_SAMPLE_SIZE;
+
+/// The environment variable which makes the `cuda` tests fail instead of being skipped when no gpu
+/// is found.
+pub const CUDA_REQUIRED_VARIABLE: &str = "CONCRETE_CUDA_REQUIRED";
+
+/// Returns whether the [`CUDA_REQUIRED_VARIABLE`] variable is set to `1`.
+pub fn cuda_required() -> bool {
+    std::env::var(CUDA_REQUIRED_VARIABLE).as_deref() == Ok("1")
+}
+
+/// Returns the timing budget the tests are checked against, if one is given by the environment.
+///
+/// # Panics
+///
+/// Panics if the budget file can not be loaded.
+pub fn timing_budget() -> Option<TimingBudget> {
+    TimingBudget::from_env().unwrap_or_else(|error| panic!("{}", error))
+}
 
 #[cfg(all(test, feature = "backend_core"))]
 pub mod core;
+
+#[cfg(all(test, feature = "backend_reference"))]
+pub mod reference;
+
+#[cfg(all(test, feature = "backend_multithread"))]
+pub mod multithread;
+
+#[cfg(all(test, feature = "backend_cuda"))]
+pub mod cuda;
+
+#[cfg(all(test, feature = "insecure_debug"))]
+pub mod debug;
+
+#[cfg(all(test, feature = "backend_core"))]
+pub mod npe;
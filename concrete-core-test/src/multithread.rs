@@ -0,0 +1,48 @@
+use crate::{REPETITIONS, SAMPLE_SIZE};
+use concrete_core::prelude::*;
+use concrete_core_fixture::fixture::*;
+use concrete_core_fixture::generation::{
+    seed_from_env, Maker, Precision32, Precision64, SEED_VARIABLE,
+};
+use paste::paste;
+
+macro_rules! test {
+    ($fixture: ident, $precision: ident, ($($types:ident),+)) => {
+        paste!{
+            #[test]
+            fn [< test_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
+                let seed = seed_from_env();
+                let mut maker = Maker::new_seeded(seed);
+                // The engine does not draw any randomness, hence only the maker is seeded.
+                let mut engine = MultithreadEngine::new().unwrap();
+                let test_result =
+                    <$fixture as Fixture<
+                        $precision,
+                        MultithreadEngine,
+                        ($($types,)+),
+                    >>::stress_all_parameters(&mut maker, &mut engine, REPETITIONS, SAMPLE_SIZE);
+                assert!(
+                    test_result,
+                    "The fixture failed with the seed {}, which can be reused by setting {}.",
+                    seed,
+                    SEED_VARIABLE
+                );
+            }
+        }
+    };
+    ($(($fixture: ident, ($($types:ident),+))),+) => {
+        $(
+            paste!{
+                test!{$fixture, Precision32, ($([< $types 32 >]),+)}
+                test!{$fixture, Precision64, ($([< $types 64 >]),+)}
+            }
+        )+
+    };
+}
+
+test! {
+    (LweCiphertextVectorDiscardingBootstrapFixture, (FourierLweBootstrapKey, GlweCiphertextVector,
+        LweCiphertextVector, LweCiphertextVector)),
+    (LweCiphertextVectorDiscardingBudgetedBootstrapFixture, (FourierLweBootstrapKey,
+        GlweCiphertextVector, LweCiphertextVector, LweCiphertextVector))
+}
@@ -0,0 +1,564 @@
+//! A module checking the closed-form estimators of `concrete-npe` against Monte-Carlo simulations.
+//!
+//! The fixtures of the `core` module only check that the noise of an operator is not larger than
+//! predicted, with a generous slack. Here, every estimator which has a counterpart in the `core`
+//! backend is checked over a grid of parameters: the operator is executed on many fresh inputs,
+//! and the empirical variance of the output noise must match the estimate up to the statistical
+//! fluctuations of the sample (see `assert_noise_variance`).
+//!
+//! The operators without engine in the `core` backend are simulated from the public api when
+//! possible: the modulus switching is performed on the retrieved container of an LWE ciphertext
+//! vector, and the CMUX is the permutation network over two ciphertexts. The estimators of the
+//! tensor product, relinearization and multiplication of GLWE ciphertexts, whether under the same
+//! key or under independent keys, have no counterpart, and are not checked.
+use concrete_core::prelude::*;
+use concrete_core::specification::engines::{
+    permutation_network_layer_count, permutation_network_layer_pairs,
+};
+use concrete_core_fixture::raw::generation::RawUnsignedIntegers;
+use concrete_core_fixture::raw::statistical_test::assert_noise_variance;
+
+/// The number of samples used for the cheap operators, which output one sample per execution.
+const SAMPLE_COUNT: usize = 10_000;
+
+/// The number of executions sharing the same secret keys, for the operators whose noise depends on
+/// the secret keys. The estimates are averaged over the keys as well, so the keys must be renewed
+/// often enough.
+const KEY_BATCH_SIZE: usize = 10;
+
+/// The number of coefficients kept from every GLWE ciphertext output by an external product.
+const COEFFICIENTS_PER_OUTPUT: usize = 16;
+
+/// The number of most significant bits of the messages.
+const MESSAGE_BITS: usize = 4;
+
+fn variance(log_standard_dev: f64) -> Variance {
+    Variance(LogStandardDev::from_log_standard_dev(log_standard_dev).get_variance())
+}
+
+fn assert_estimate(tested: &[u64], expected: &[u64], estimate: Variance, parameters: String) {
+    assert!(
+        assert_noise_variance(tested, expected, estimate),
+        "The noise estimate does not match the simulation for {}.",
+        parameters
+    );
+}
+
+#[test]
+fn test_several_additions_noise() {
+    let mut engine = CoreEngine::new().unwrap();
+    let lwe_dimension = LweDimension(64);
+    let grid = vec![
+        vec![-15., -15.],
+        vec![-20., -18., -16., -14.],
+        vec![-25.; 10],
+    ];
+    for log_standard_devs in grid {
+        let noises: Vec<Variance> = log_standard_devs.iter().map(|s| variance(*s)).collect();
+        let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension).unwrap();
+        let mut expected = vec![0_u64; SAMPLE_COUNT];
+        let mut output: Option<LweCiphertextVector64> = None;
+        for noise in noises.iter() {
+            let messages = u64::uniform_n_msb_vec(MESSAGE_BITS, SAMPLE_COUNT);
+            for (sum, message) in expected.iter_mut().zip(messages.iter()) {
+                *sum = sum.wrapping_add(*message);
+            }
+            let plaintexts = engine.create_plaintext_vector(&messages).unwrap();
+            let ciphertexts = engine
+                .encrypt_lwe_ciphertext_vector(&key, &plaintexts, *noise)
+                .unwrap();
+            match output.as_mut() {
+                Some(output) => engine
+                    .fuse_add_lwe_ciphertext_vector(output, &ciphertexts)
+                    .unwrap(),
+                None => output = Some(ciphertexts),
+            }
+        }
+        let decrypted = engine
+            .decrypt_lwe_ciphertext_vector(&key, &output.unwrap())
+            .unwrap();
+        let tested = engine.retrieve_plaintext_vector(&decrypted).unwrap();
+        let estimate = concrete_npe::estimate_several_additions_noise::<u64, _>(&noises);
+        assert_estimate(
+            &tested,
+            &expected,
+            estimate,
+            format!("log standard deviations {:?}", log_standard_devs),
+        );
+    }
+}
+
+#[test]
+fn test_integer_plaintext_multiplication_noise() {
+    let mut engine = CoreEngine::new().unwrap();
+    let lwe_dimension = LweDimension(64);
+    let noise = variance(-20.);
+    let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension).unwrap();
+    for weight in [1_u64, 3, 17, 1000, 5_u64.wrapping_neg()] {
+        let cleartext = engine.create_cleartext(&weight).unwrap();
+        let mut output = engine.zero_encrypt_lwe_ciphertext(&key, noise).unwrap();
+        let mut tested = Vec::with_capacity(SAMPLE_COUNT);
+        let mut expected = Vec::with_capacity(SAMPLE_COUNT);
+        for _ in 0..SAMPLE_COUNT {
+            let message = u64::uniform_n_msb(MESSAGE_BITS);
+            let plaintext = engine.create_plaintext(&message).unwrap();
+            let input = engine
+                .encrypt_lwe_ciphertext(&key, &plaintext, noise)
+                .unwrap();
+            engine
+                .discard_mul_lwe_ciphertext_cleartext(&mut output, &input, &cleartext)
+                .unwrap();
+            let decrypted = engine.decrypt_lwe_ciphertext(&key, &output).unwrap();
+            tested.push(engine.retrieve_plaintext(&decrypted).unwrap());
+            expected.push(message.wrapping_mul(weight));
+        }
+        let estimate =
+            concrete_npe::estimate_integer_plaintext_multiplication_noise::<u64, _>(noise, weight);
+        assert_estimate(&tested, &expected, estimate, format!("weight {}", weight));
+    }
+}
+
+#[test]
+fn test_weighted_sum_noise() {
+    let mut engine = CoreEngine::new().unwrap();
+    let lwe_dimension = LweDimension(64);
+    let grid = vec![
+        (vec![-20., -20.], vec![2_u64, 3]),
+        (vec![-25., -20., -15.], vec![100, 1, 1_u64.wrapping_neg()]),
+        (vec![-22.; 5], vec![7_u64.wrapping_neg(); 5]),
+    ];
+    for (log_standard_devs, weights) in grid {
+        let noises: Vec<Variance> = log_standard_devs.iter().map(|s| variance(*s)).collect();
+        let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension).unwrap();
+        let cleartexts: Vec<Cleartext64> = weights
+            .iter()
+            .map(|w| engine.create_cleartext(w).unwrap())
+            .collect();
+        let zero = engine.create_plaintext(&0_u64).unwrap();
+        let mut tested = Vec::with_capacity(SAMPLE_COUNT);
+        let mut expected = Vec::with_capacity(SAMPLE_COUNT);
+        for _ in 0..SAMPLE_COUNT {
+            let mut output = engine
+                .trivially_encrypt_lwe_ciphertext(lwe_dimension.to_lwe_size(), &zero)
+                .unwrap();
+            let mut sum = 0_u64;
+            for ((noise, cleartext), weight) in noises.iter().zip(cleartexts.iter()).zip(&weights) {
+                let message = u64::uniform_n_msb(MESSAGE_BITS);
+                let plaintext = engine.create_plaintext(&message).unwrap();
+                let input = engine
+                    .encrypt_lwe_ciphertext(&key, &plaintext, *noise)
+                    .unwrap();
+                engine
+                    .fuse_mul_add_lwe_ciphertext_cleartext(&mut output, &input, cleartext)
+                    .unwrap();
+                sum = sum.wrapping_add(message.wrapping_mul(*weight));
+            }
+            let decrypted = engine.decrypt_lwe_ciphertext(&key, &output).unwrap();
+            tested.push(engine.retrieve_plaintext(&decrypted).unwrap());
+            expected.push(sum);
+        }
+        let estimate = concrete_npe::estimate_weighted_sum_noise(&noises, &weights);
+        assert_estimate(
+            &tested,
+            &expected,
+            estimate,
+            format!(
+                "log standard deviations {:?} and weights {:?}",
+                log_standard_devs, weights
+            ),
+        );
+    }
+}
+
+#[test]
+fn test_modulus_switching_noise() {
+    let mut engine = CoreEngine::new().unwrap();
+    let noise = variance(-30.);
+    for (lwe_dimension, log_modulus) in [
+        (LweDimension(64), 12),
+        (LweDimension(256), 10),
+        (LweDimension(630), 14),
+    ] {
+        let mut tested = Vec::with_capacity(SAMPLE_COUNT);
+        let mut expected = Vec::with_capacity(SAMPLE_COUNT);
+        for _ in 0..SAMPLE_COUNT / KEY_BATCH_SIZE {
+            let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension).unwrap();
+            let messages = u64::uniform_n_msb_vec(MESSAGE_BITS, KEY_BATCH_SIZE);
+            let plaintexts = engine.create_plaintext_vector(&messages).unwrap();
+            let ciphertexts: LweCiphertextVector64 = engine
+                .encrypt_lwe_ciphertext_vector(&key, &plaintexts, noise)
+                .unwrap();
+            // The switched ciphertexts are mapped back to the native modulus: every coefficient
+            // is rounded to the closest multiple of 2^(64 - log_modulus).
+            let aligned: AlignedLweCiphertextVector64 =
+                engine.convert_lwe_ciphertext_vector(&ciphertexts).unwrap();
+            let mut container = engine
+                .consume_retrieve_lwe_ciphertext_vector(aligned)
+                .unwrap();
+            let dropped_bits = 64 - log_modulus;
+            for coefficient in container.iter_mut() {
+                *coefficient = (coefficient.wrapping_add(1 << (dropped_bits - 1)) >> dropped_bits)
+                    << dropped_bits;
+            }
+            let aligned: AlignedLweCiphertextVector64 = engine
+                .create_lwe_ciphertext_vector(container, lwe_dimension.to_lwe_size())
+                .unwrap();
+            let switched: LweCiphertextVector64 =
+                engine.convert_lwe_ciphertext_vector(&aligned).unwrap();
+            let decrypted = engine
+                .decrypt_lwe_ciphertext_vector(&key, &switched)
+                .unwrap();
+            tested.extend(engine.retrieve_plaintext_vector(&decrypted).unwrap());
+            expected.extend(messages);
+        }
+        let estimate = concrete_npe::estimate_modulus_switching_noise_with_binary_key::<u64, _>(
+            lwe_dimension,
+            log_modulus,
+            noise,
+        );
+        assert_estimate(
+            &tested,
+            &expected,
+            estimate,
+            format!(
+                "lwe dimension {} and modulus 2^{}",
+                lwe_dimension.0, log_modulus
+            ),
+        );
+    }
+}
+
+#[test]
+fn test_keyswitch_noise() {
+    let mut engine = CoreEngine::new().unwrap();
+    let sample_count = 2_000;
+    // Each line favors different terms of the estimate: the rounding of the decomposition, the
+    // noise of the key, or the noise of the input.
+    let grid = [
+        (LweDimension(256), LweDimension(128), 4, 3, -10., -40.),
+        (LweDimension(256), LweDimension(128), 7, 3, -15., -25.),
+        (LweDimension(512), LweDimension(64), 2, 8, -15., -20.),
+    ];
+    for (input_dimension, output_dimension, base_log, level, input_noise, ksk_noise) in grid {
+        let (base_log, level) = (
+            DecompositionBaseLog(base_log),
+            DecompositionLevelCount(level),
+        );
+        let (input_noise, ksk_noise) = (variance(input_noise), variance(ksk_noise));
+        let mut tested = Vec::with_capacity(sample_count);
+        let mut expected = Vec::with_capacity(sample_count);
+        for _ in 0..sample_count / KEY_BATCH_SIZE {
+            let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_dimension).unwrap();
+            let output_key: LweSecretKey64 =
+                engine.create_lwe_secret_key(output_dimension).unwrap();
+            let ksk: LweKeyswitchKey64 = engine
+                .create_lwe_keyswitch_key(&input_key, &output_key, level, base_log, ksk_noise)
+                .unwrap();
+            let mut output = engine
+                .zero_encrypt_lwe_ciphertext(&output_key, ksk_noise)
+                .unwrap();
+            for _ in 0..KEY_BATCH_SIZE {
+                let message = u64::uniform_n_msb(MESSAGE_BITS);
+                let plaintext = engine.create_plaintext(&message).unwrap();
+                let input = engine
+                    .encrypt_lwe_ciphertext(&input_key, &plaintext, input_noise)
+                    .unwrap();
+                engine
+                    .discard_keyswitch_lwe_ciphertext(&mut output, &input, &ksk)
+                    .unwrap();
+                let decrypted = engine.decrypt_lwe_ciphertext(&output_key, &output).unwrap();
+                tested.push(engine.retrieve_plaintext(&decrypted).unwrap());
+                expected.push(message);
+            }
+        }
+        let estimate = concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+            u64,
+            _,
+            _,
+            BinaryKeyKind,
+        >(input_dimension, input_noise, ksk_noise, base_log, level);
+        assert_estimate(
+            &tested,
+            &expected,
+            estimate,
+            format!(
+                "{:?} -> {:?}, {:?}, {:?}",
+                input_dimension, output_dimension, base_log, level
+            ),
+        );
+    }
+}
+
+#[test]
+fn test_packing_keyswitch_noise() {
+    let mut engine = CoreEngine::new().unwrap();
+    let sample_count = 500;
+    let grid = [
+        (
+            LweDimension(128),
+            GlweDimension(1),
+            PolynomialSize(256),
+            4,
+            3,
+            -10.,
+            -40.,
+        ),
+        (
+            LweDimension(128),
+            GlweDimension(2),
+            PolynomialSize(128),
+            7,
+            3,
+            -15.,
+            -25.,
+        ),
+    ];
+    for (
+        input_dimension,
+        glwe_dimension,
+        polynomial_size,
+        base_log,
+        level,
+        input_noise,
+        ksk_noise,
+    ) in grid
+    {
+        let (base_log, level) = (
+            DecompositionBaseLog(base_log),
+            DecompositionLevelCount(level),
+        );
+        let (input_noise, ksk_noise) = (variance(input_noise), variance(ksk_noise));
+        // A single ciphertext is packed: it lands in the constant coefficient, and the other
+        // coefficients only receive the noise of the key.
+        let mut tested_constant = Vec::with_capacity(sample_count);
+        let mut expected_constant = Vec::with_capacity(sample_count);
+        let mut tested_others = Vec::with_capacity(sample_count * (polynomial_size.0 - 1));
+        for _ in 0..sample_count / KEY_BATCH_SIZE {
+            let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_dimension).unwrap();
+            let output_key: GlweSecretKey64 = engine
+                .create_glwe_secret_key(glwe_dimension, polynomial_size)
+                .unwrap();
+            let pksk: PackingKeyswitchKey64 = engine
+                .create_packing_keyswitch_key(&input_key, &output_key, level, base_log, ksk_noise)
+                .unwrap();
+            let mut output = engine
+                .zero_encrypt_glwe_ciphertext(&output_key, ksk_noise)
+                .unwrap();
+            for _ in 0..KEY_BATCH_SIZE {
+                let message = u64::uniform_n_msb(MESSAGE_BITS);
+                let plaintexts = engine.create_plaintext_vector(&[message]).unwrap();
+                let input = engine
+                    .encrypt_lwe_ciphertext_vector(&input_key, &plaintexts, input_noise)
+                    .unwrap();
+                engine
+                    .discard_packing_keyswitch_lwe_ciphertext_vector(&mut output, &input, &pksk)
+                    .unwrap();
+                let decrypted = engine
+                    .decrypt_glwe_ciphertext(&output_key, &output)
+                    .unwrap();
+                let coefficients = engine.retrieve_plaintext_vector(&decrypted).unwrap();
+                tested_constant.push(coefficients[0]);
+                expected_constant.push(message);
+                tested_others.extend_from_slice(&coefficients[1..]);
+            }
+        }
+        let parameters = format!(
+            "{:?} -> {:?}, {:?}, {:?}, {:?}",
+            input_dimension, glwe_dimension, polynomial_size, base_log, level
+        );
+        let estimate = concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+            u64,
+            _,
+            _,
+            BinaryKeyKind,
+        >(input_dimension, input_noise, ksk_noise, base_log, level);
+        assert_estimate(
+            &tested_constant,
+            &expected_constant,
+            estimate,
+            parameters.clone(),
+        );
+        let estimate = concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_non_constant_terms::<
+            u64,
+            _,
+        >(input_dimension, ksk_noise, base_log, level);
+        assert_estimate(
+            &tested_others,
+            &vec![0; tested_others.len()],
+            estimate,
+            parameters,
+        );
+    }
+}
+
+#[test]
+fn test_external_product_noise() {
+    let mut engine = CoreEngine::new().unwrap();
+    let sample_count = 1_000;
+    let grid = [
+        (GlweDimension(1), PolynomialSize(256), 7, 3, -20., -40.),
+        (GlweDimension(2), PolynomialSize(128), 4, 5, -20., -25.),
+        (GlweDimension(1), PolynomialSize(512), 10, 2, -18., -35.),
+    ];
+    for (glwe_dimension, polynomial_size, base_log, level, glwe_noise, ggsw_noise) in grid {
+        let (base_log, level) = (
+            DecompositionBaseLog(base_log),
+            DecompositionLevelCount(level),
+        );
+        let (glwe_noise, ggsw_noise) = (variance(glwe_noise), variance(ggsw_noise));
+        let mut tested = Vec::with_capacity(sample_count * COEFFICIENTS_PER_OUTPUT);
+        let mut expected = Vec::with_capacity(sample_count * COEFFICIENTS_PER_OUTPUT);
+        for _ in 0..sample_count / KEY_BATCH_SIZE {
+            let key: GlweSecretKey64 = engine
+                .create_glwe_secret_key(glwe_dimension, polynomial_size)
+                .unwrap();
+            // The estimate is averaged over a uniformly random binary message in the GGSW
+            // ciphertext, so both messages are used equally often.
+            let mut ggsw_ciphertexts: Vec<FourierGgswCiphertext64> = Vec::new();
+            for bit in [0_u64, 1] {
+                let plaintext = engine.create_plaintext(&bit).unwrap();
+                let ggsw: GgswCiphertext64 = engine
+                    .encrypt_scalar_ggsw_ciphertext(&key, &plaintext, ggsw_noise, level, base_log)
+                    .unwrap();
+                ggsw_ciphertexts.push(engine.convert_ggsw_ciphertext(&ggsw).unwrap());
+            }
+            for sample in 0..KEY_BATCH_SIZE {
+                let bit = (sample % 2) as u64;
+                let messages = u64::uniform_n_msb_vec(MESSAGE_BITS, polynomial_size.0);
+                let plaintexts = engine.create_plaintext_vector(&messages).unwrap();
+                let glwe: GlweCiphertext64 = engine
+                    .encrypt_glwe_ciphertext(&key, &plaintexts, glwe_noise)
+                    .unwrap();
+                let output = engine
+                    .compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+                        &glwe,
+                        &ggsw_ciphertexts[bit as usize],
+                    )
+                    .unwrap();
+                let decrypted = engine.decrypt_glwe_ciphertext(&key, &output).unwrap();
+                let coefficients = engine.retrieve_plaintext_vector(&decrypted).unwrap();
+                // The coefficients of an output share the same keys and GGSW ciphertext, so only
+                // a few of them are kept, for the samples to stay close to independent.
+                let stride = polynomial_size.0 / COEFFICIENTS_PER_OUTPUT;
+                tested.extend(coefficients.iter().step_by(stride));
+                expected.extend(messages.iter().step_by(stride).map(|m| m.wrapping_mul(bit)));
+            }
+        }
+        let estimate = concrete_npe::estimate_external_product_noise_with_binary_ggsw::<
+            u64,
+            _,
+            _,
+            BinaryKeyKind,
+        >(
+            polynomial_size,
+            glwe_dimension,
+            glwe_noise,
+            ggsw_noise,
+            base_log,
+            level,
+        );
+        assert_estimate(
+            &tested,
+            &expected,
+            estimate,
+            format!(
+                "{:?}, {:?}, {:?}, {:?}",
+                glwe_dimension, polynomial_size, base_log, level
+            ),
+        );
+    }
+}
+
+#[test]
+fn test_permutation_network_noise() {
+    let mut engine = CoreEngine::new().unwrap();
+    let output_count = 2_000;
+    // The network over two ciphertexts is a single layer of CMUXes.
+    let grid = [
+        (2, LweDimension(128), 7, 3, -20., -40.),
+        (2, LweDimension(256), 4, 5, -20., -25.),
+        (8, LweDimension(128), 7, 3, -20., -40.),
+    ];
+    for (ciphertext_count, lwe_dimension, base_log, level, lwe_noise, ggsw_noise) in grid {
+        let (base_log, level) = (
+            DecompositionBaseLog(base_log),
+            DecompositionLevelCount(level),
+        );
+        let (lwe_noise, ggsw_noise) = (variance(lwe_noise), variance(ggsw_noise));
+        let count = LweCiphertextCount(ciphertext_count);
+        let layer_count = permutation_network_layer_count(count);
+        let mut tested = Vec::with_capacity(output_count);
+        let mut expected = Vec::with_capacity(output_count);
+        for _ in 0..output_count / ciphertext_count / KEY_BATCH_SIZE {
+            let lwe_key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension).unwrap();
+            let glwe_key = engine
+                .transmute_lwe_secret_key_to_glwe_secret_key(lwe_key.clone(), PolynomialSize(1))
+                .unwrap();
+            // The switches encrypting the same bit share the same GGSW ciphertext.
+            let mut ggsw_ciphertexts: Vec<GgswCiphertext64> = Vec::new();
+            for bit in [0_u64, 1] {
+                let plaintext = engine.create_plaintext(&bit).unwrap();
+                ggsw_ciphertexts.push(
+                    engine
+                        .encrypt_scalar_ggsw_ciphertext(
+                            &glwe_key, &plaintext, ggsw_noise, level, base_log,
+                        )
+                        .unwrap(),
+                );
+            }
+            for _ in 0..KEY_BATCH_SIZE {
+                // The estimate is averaged over uniformly random switches, which are drawn
+                // directly rather than computed from a random permutation. The expected outputs
+                // are obtained by evaluating the network in the clear.
+                let messages = u64::uniform_n_msb_vec(MESSAGE_BITS, ciphertext_count);
+                let mut permuted = messages.clone();
+                let mut switches = Vec::new();
+                for layer in 0..layer_count {
+                    for (first, second) in permutation_network_layer_pairs(count, layer) {
+                        let bit = u64::uniform_between(0..2);
+                        if bit == 1 {
+                            permuted.swap(first, second);
+                        }
+                        switches.push(ggsw_ciphertexts[bit as usize].clone());
+                    }
+                }
+                let plaintexts = engine.create_plaintext_vector(&messages).unwrap();
+                let input = engine
+                    .encrypt_lwe_ciphertext_vector(&lwe_key, &plaintexts, lwe_noise)
+                    .unwrap();
+                let mut output = input.clone();
+                engine
+                    .discard_permute_lwe_ciphertext_vector(&mut output, &input, &switches)
+                    .unwrap();
+                let decrypted = engine
+                    .decrypt_lwe_ciphertext_vector(&lwe_key, &output)
+                    .unwrap();
+                let outputs = engine.retrieve_plaintext_vector(&decrypted).unwrap();
+                tested.extend(outputs);
+                expected.extend(permuted);
+            }
+        }
+        let estimate = concrete_npe::estimate_permutation_network_noise_with_binary_ggsw::<
+            u64,
+            _,
+            _,
+            BinaryKeyKind,
+        >(
+            layer_count,
+            lwe_dimension,
+            base_log,
+            level,
+            lwe_noise,
+            ggsw_noise,
+        )[layer_count - 1];
+        assert_estimate(
+            &tested,
+            &expected,
+            estimate,
+            format!(
+                "{} ciphertexts, {:?}, {:?}, {:?}",
+                ciphertext_count, lwe_dimension, base_log, level
+            ),
+        );
+    }
+}
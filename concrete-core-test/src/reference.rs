@@ -0,0 +1,135 @@
+use crate::{REPETITIONS, SAMPLE_SIZE};
+use concrete_core::prelude::*;
+use concrete_core_fixture::fixture::*;
+use concrete_core_fixture::generation::{
+    engine_seed, seed_from_env, Maker, Precision32, Precision64, SEED_VARIABLE,
+};
+use concrete_core_fixture::{Repetitions, SampleSize};
+use paste::paste;
+
+macro_rules! test {
+    ($fixture: ident, $precision: ident, ($($types:ident),+)) => {
+        paste!{
+            #[test]
+            fn [< test_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
+                let seed = seed_from_env();
+                let mut maker = Maker::new_seeded(seed);
+                let mut engine = ReferenceEngine::new_seeded(engine_seed(seed));
+                let test_result =
+                    <$fixture as Fixture<
+                        $precision,
+                        ReferenceEngine,
+                        ($($types,)+),
+                    >>::stress_all_parameters(&mut maker, &mut engine, REPETITIONS, SAMPLE_SIZE);
+                assert!(
+                    test_result,
+                    "The fixture failed with the seed {}, which can be reused by setting {}.",
+                    seed,
+                    SEED_VARIABLE
+                );
+            }
+        }
+    };
+    ($(($fixture: ident, $precision: ident, ($($types:ident),+))),+) => {
+        $(
+            test!{$fixture, $precision, ($($types),+)}
+        )+
+    };
+    ($(($fixture: ident, ($($types:ident),+))),+) => {
+        $(
+            paste!{
+                test!{$fixture, Precision32, ($([< $types 32 >]),+)}
+                test!{$fixture, Precision64, ($([< $types 64 >]),+)}
+            }
+        )+
+    };
+}
+
+concrete_core_fixture::reference_fixtures!(test);
+
+// The differential tests execute a core engine and the reference engine on the same inputs. The
+// two engines sample their noise and decompose the torus elements differently, so the decrypted
+// outputs are only expected to match up to a tolerance.
+fn torus_distance(a: u64, b: u64) -> u64 {
+    a.wrapping_sub(b).min(b.wrapping_sub(a))
+}
+
+#[test]
+fn test_differential_lwe_ciphertext_discarding_keyswitch_precision64() {
+    let mut maker = Maker::default();
+    let mut engine = CoreEngine::new().unwrap();
+    let mut reference_engine = ReferenceEngine::new().unwrap();
+    for parameters in <LweCiphertextDiscardingKeyswitchFixture as Fixture<
+        Precision64,
+        CoreEngine,
+        (LweKeyswitchKey64, LweCiphertext64, LweCiphertext64),
+    >>::generate_parameters_iterator()
+    {
+        assert!(<LweCiphertextDiscardingKeyswitchFixture as Fixture<
+            Precision64,
+            CoreEngine,
+            (LweKeyswitchKey64, LweCiphertext64, LweCiphertext64),
+        >>::differential::<
+            ReferenceEngine,
+            (
+                ReferenceLweKeyswitchKey64,
+                ReferenceLweCiphertext64,
+                ReferenceLweCiphertext64,
+            ),
+        >(
+            &mut maker,
+            &mut engine,
+            &mut reference_engine,
+            &parameters,
+            REPETITIONS,
+            SAMPLE_SIZE,
+            |(_, output), (_, reference_output)| {
+                torus_distance(*output, *reference_output) < 1 << 48
+            },
+        ));
+    }
+}
+
+#[test]
+fn test_differential_lwe_ciphertext_discarding_bootstrap_precision64() {
+    let mut maker = Maker::default();
+    let mut engine = CoreEngine::new().unwrap();
+    let mut reference_engine = ReferenceEngine::new().unwrap();
+    // The schoolbook bootstrap is too slow for the standard presets, so we use small parameters.
+    let parameters = LweCiphertextDiscardingBootstrapParameters1 {
+        noise: Variance(2_f64.powf(-80.)),
+        lwe_dimension: LweDimension(20),
+        glwe_dimension: GlweDimension(1),
+        poly_size: PolynomialSize(256),
+        decomp_level_count: DecompositionLevelCount(3),
+        decomp_base_log: DecompositionBaseLog(7),
+    };
+    assert!(<LweCiphertextDiscardingBootstrapFixture1 as Fixture<
+        Precision64,
+        CoreEngine,
+        (
+            FourierLweBootstrapKey64,
+            GlweCiphertext64,
+            LweCiphertext64,
+            LweCiphertext64,
+        ),
+    >>::differential::<
+        ReferenceEngine,
+        (
+            ReferenceLweBootstrapKey64,
+            ReferenceGlweCiphertext64,
+            ReferenceLweCiphertext64,
+            ReferenceLweCiphertext64,
+        ),
+    >(
+        &mut maker,
+        &mut engine,
+        &mut reference_engine,
+        &parameters,
+        Repetitions(2),
+        SampleSize(10),
+        |(_, output), (_, reference_output)| {
+            torus_distance(*output, *reference_output) < 1 << 58
+        },
+    ));
+}
@@ -0,0 +1,78 @@
+//! Compiles the kernels of the `backend_cuda` feature.
+//!
+//! The sources in the `cuda` directory are compiled with `nvcc`, and archived in a static library
+//! linked to the crate along with the cuda runtime. The cuda toolkit is looked up in the `CUDA_PATH`
+//! environment variable, and defaults to `/usr/local/cuda`. Nothing is done when the feature is
+//! disabled.
+//!
+//! When `nvcc` can not be found, a warning is emitted and the kernels are not compiled: the crate
+//! is then built against stubs reporting that no gpu is available, so that the feature can still be
+//! enabled along with all the others on machines without the cuda toolkit. When the
+//! `CONCRETE_CUDA_REQUIRED` environment variable is set to `1`, as on the gpu jobs of the
+//! continuous integration, a missing `nvcc` aborts the build instead.
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+const CUDA_SOURCES: [&str; 3] = ["device.cu", "keyswitch.cu", "bootstrap.cu"];
+
+fn main() {
+    if env::var_os("CARGO_FEATURE_BACKEND_CUDA").is_none() {
+        return;
+    }
+    println!("cargo:rerun-if-changed=cuda");
+    println!("cargo:rerun-if-env-changed=CUDA_PATH");
+    println!("cargo:rerun-if-env-changed=CONCRETE_CUDA_REQUIRED");
+    println!("cargo:rustc-check-cfg=cfg(cuda_kernels)");
+
+    let cuda_path =
+        PathBuf::from(env::var("CUDA_PATH").unwrap_or_else(|_| "/usr/local/cuda".to_string()));
+    let nvcc = cuda_path.join("bin").join("nvcc");
+    if !nvcc.is_file() {
+        assert!(
+            env::var("CONCRETE_CUDA_REQUIRED").as_deref() != Ok("1"),
+            "{} was not found, while CONCRETE_CUDA_REQUIRED is set.",
+            nvcc.display()
+        );
+        println!(
+            "cargo:warning={} was not found, the cuda backend will not find any gpu.",
+            nvcc.display()
+        );
+        return;
+    }
+    let source_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("cuda");
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    let mut objects = Vec::new();
+    for source in CUDA_SOURCES.iter() {
+        let object = out_dir.join(source).with_extension("o");
+        run(Command::new(&nvcc)
+            .args(["-O3", "--compiler-options", "-fPIC", "-c"])
+            .arg(source_dir.join(source))
+            .arg("-o")
+            .arg(&object));
+        objects.push(object);
+    }
+    run(Command::new("ar")
+        .arg("crs")
+        .arg(out_dir.join("libconcrete_cuda.a"))
+        .args(&objects));
+
+    println!("cargo:rustc-link-search=native={}", out_dir.display());
+    println!(
+        "cargo:rustc-link-search=native={}",
+        cuda_path.join("lib64").display()
+    );
+    println!("cargo:rustc-link-lib=static=concrete_cuda");
+    println!("cargo:rustc-link-lib=cudart");
+    println!("cargo:rustc-link-lib=stdc++");
+    println!("cargo:rustc-cfg=cuda_kernels");
+}
+
+// Runs a command, and aborts the build if it fails.
+fn run(command: &mut Command) {
+    let status = command
+        .status()
+        .unwrap_or_else(|error| panic!("Failed to run {:?}: {}", command, error));
+    assert!(status.success(), "{:?} failed with {}", command, status);
+}
@@ -0,0 +1,337 @@
+// LWE bootstrap of the cuda backend.
+//
+// The bootstrap is the textbook blind rotation: the accumulator is first rotated by X^{-b}, and
+// every element a_i of the input mask is then folded in with a CMux, computed as
+// ACC <- ACC + ExternalProduct(GGSW(s_i), ACC * X^{a_i} - ACC). The ciphertexts of a vector are
+// bootstrapped together, every kernel covering all the ciphertexts at once.
+//
+// The bootstrap key stores a GGSW ciphertext per input key coefficient, made of a matrix per level
+// from 1 to l, whose k + 1 rows are GLWE ciphertexts encrypting s_i * q / B^j times minus the
+// r-th GLWE key polynomial for the mask rows, and times one for the body row.
+//
+// The polynomial products of the external product are computed exactly in the NTT domain (see
+// `ntt.cuh`). The decomposed digits are small, but the key coefficients span the whole torus: the
+// key polynomials are split in four limbs of 16 bits, so that every product of a digit and a limb,
+// summed over the levels and the rows, stays below p / 2 and is recovered exactly by the inverse
+// transform. The limbs are then recombined modulo q = 2^64. The gpu copy of the key holds the
+// transforms of its limbs, computed once when the key is converted.
+#include "common.cuh"
+#include "ntt.cuh"
+
+// The number of limbs of 16 bits of a torus element.
+#define LIMB_COUNT 4
+#define LIMB_BITS 16
+
+// Fills the limbs of the coefficient `blockIdx.x * blockDim.x + threadIdx.x` of the key, the
+// limb polynomials of the polynomial P being stored at offset (P * 4 + limb) * N.
+__global__ void split_limbs_kernel(uint64_t *limbs, const uint64_t *bsk, uint64_t coefficient_count,
+                                   uint32_t polynomial_size) {
+  uint64_t index = (uint64_t)blockIdx.x * blockDim.x + threadIdx.x;
+  if (index >= coefficient_count) {
+    return;
+  }
+  uint64_t polynomial = index / polynomial_size;
+  uint32_t coefficient = index % polynomial_size;
+  for (uint32_t limb = 0; limb < LIMB_COUNT; limb++) {
+    limbs[(polynomial * LIMB_COUNT + limb) * polynomial_size + coefficient] =
+        (bsk[index] >> (limb * LIMB_BITS)) & ((1ull << LIMB_BITS) - 1);
+  }
+}
+
+// Recombines the limbs of the coefficient `blockIdx.x * blockDim.x + threadIdx.x` of the key.
+__global__ void merge_limbs_kernel(uint64_t *bsk, const uint64_t *limbs, uint64_t coefficient_count,
+                                   uint32_t polynomial_size) {
+  uint64_t index = (uint64_t)blockIdx.x * blockDim.x + threadIdx.x;
+  if (index >= coefficient_count) {
+    return;
+  }
+  uint64_t polynomial = index / polynomial_size;
+  uint32_t coefficient = index % polynomial_size;
+  uint64_t value = 0;
+  for (uint32_t limb = 0; limb < LIMB_COUNT; limb++) {
+    value |= limbs[(polynomial * LIMB_COUNT + limb) * polynomial_size + coefficient]
+             << (limb * LIMB_BITS);
+  }
+  bsk[index] = value;
+}
+
+// Fills the accumulators with the lookup tables rotated by X^{-b}, where b is the modulus switched
+// body of the input ciphertexts.
+__global__ void initialize_accumulator_kernel(uint64_t *accumulators, const uint64_t *luts,
+                                              const uint64_t *inputs, uint32_t count,
+                                              uint32_t input_lwe_dimension, uint32_t glwe_size,
+                                              uint32_t polynomial_size) {
+  uint64_t glwe_length = (uint64_t)glwe_size * polynomial_size;
+  uint64_t index = (uint64_t)blockIdx.x * blockDim.x + threadIdx.x;
+  if (index >= count * glwe_length) {
+    return;
+  }
+  uint64_t ciphertext = index / glwe_length;
+  const uint64_t *input = inputs + ciphertext * (input_lwe_dimension + 1);
+  uint32_t body_degree = modulus_switch(input[input_lwe_dimension], polynomial_size);
+  // Multiplying by X^{-b} is the same as multiplying by X^{2N - b}, since X^{2N} = 1.
+  uint32_t degree = (2 * polynomial_size - body_degree) % (2 * polynomial_size);
+  uint32_t polynomial = (index % glwe_length) / polynomial_size;
+  uint32_t coefficient = index % polynomial_size;
+  const uint64_t *lut = luts + ciphertext * glwe_length + (uint64_t)polynomial * polynomial_size;
+  accumulators[index] = rotated_coefficient(lut, coefficient, degree, polynomial_size);
+}
+
+// Fills `digits` with the decomposition of ACC * X^{a_i} - ACC, mapped to the integers modulo p.
+// The digits of the r-th polynomial of a ciphertext at level j are stored at offset
+// ((r * l) + j - 1) * N after the ones of the previous ciphertexts.
+__global__ void decompose_difference_kernel(uint64_t *digits, const uint64_t *accumulators,
+                                            const uint64_t *inputs, uint32_t mask_index,
+                                            uint32_t count, uint32_t input_lwe_dimension,
+                                            uint32_t glwe_size, uint32_t polynomial_size,
+                                            uint32_t base_log, uint32_t level_count) {
+  uint64_t glwe_length = (uint64_t)glwe_size * polynomial_size;
+  uint64_t index = (uint64_t)blockIdx.x * blockDim.x + threadIdx.x;
+  if (index >= count * glwe_length) {
+    return;
+  }
+  uint64_t ciphertext = index / glwe_length;
+  const uint64_t *input = inputs + ciphertext * (input_lwe_dimension + 1);
+  uint32_t degree = modulus_switch(input[mask_index], polynomial_size);
+  uint32_t polynomial = (index % glwe_length) / polynomial_size;
+  uint32_t coefficient = index % polynomial_size;
+  const uint64_t *source = accumulators + index - coefficient;
+  uint64_t difference =
+      rotated_coefficient(source, coefficient, degree, polynomial_size) - source[coefficient];
+  uint64_t *ciphertext_digits = digits + ciphertext * level_count * glwe_length;
+  for (uint32_t level = 1; level <= level_count; level++) {
+    uint64_t offset = ((uint64_t)polynomial * level_count + level - 1) * polynomial_size;
+    ciphertext_digits[offset + coefficient] =
+        ntt_from_signed(decompose_level(difference, base_log, level_count, level));
+  }
+}
+
+// Computes the coefficient `blockIdx.x * blockDim.x + threadIdx.x` of the products between the
+// transformed digits and the transformed limbs of the GGSW ciphertext `ggsw`, summed over the
+// levels and the rows. The products of the limb m of the output polynomial c of a ciphertext are
+// stored at offset (c * 4 + m) * N after the ones of the previous ciphertexts.
+__global__ void multiply_accumulate_kernel(uint64_t *products, const uint64_t *digits,
+                                           const uint64_t *ggsw, uint32_t count,
+                                           uint32_t glwe_size, uint32_t polynomial_size,
+                                           uint32_t level_count) {
+  uint64_t products_length = (uint64_t)glwe_size * LIMB_COUNT * polynomial_size;
+  uint64_t index = (uint64_t)blockIdx.x * blockDim.x + threadIdx.x;
+  if (index >= count * products_length) {
+    return;
+  }
+  uint64_t ciphertext = index / products_length;
+  uint32_t limb_polynomial = (index % products_length) / polynomial_size;
+  uint32_t coefficient = index % polynomial_size;
+  const uint64_t *ciphertext_digits =
+      digits + ciphertext * level_count * glwe_size * polynomial_size;
+  uint64_t sum = 0;
+  for (uint32_t level = 1; level <= level_count; level++) {
+    for (uint32_t row = 0; row < glwe_size; row++) {
+      uint64_t digit =
+          ciphertext_digits[((uint64_t)row * level_count + level - 1) * polynomial_size +
+                            coefficient];
+      // The limbs of the output polynomial c of the row are the limb polynomials
+      // (row * (k + 1) + c) * 4 + m of the matrix of the level.
+      uint64_t limb = ggsw[(((uint64_t)(level - 1) * glwe_size + row) * products_length +
+                            (uint64_t)limb_polynomial * polynomial_size) +
+                           coefficient];
+      sum = ntt_add(sum, ntt_mul(digit, limb));
+    }
+  }
+  products[index] = sum;
+}
+
+// Adds to the coefficient `blockIdx.x * blockDim.x + threadIdx.x` of the accumulators the one of
+// the external product, recombined from the products of the limbs.
+__global__ void add_products_kernel(uint64_t *accumulators, const uint64_t *products,
+                                    uint32_t count, uint32_t glwe_size, uint32_t polynomial_size) {
+  uint64_t glwe_length = (uint64_t)glwe_size * polynomial_size;
+  uint64_t index = (uint64_t)blockIdx.x * blockDim.x + threadIdx.x;
+  if (index >= count * glwe_length) {
+    return;
+  }
+  // The polynomials of all the ciphertexts are consecutive, in the accumulators as well as in the
+  // products.
+  uint64_t polynomial = index / polynomial_size;
+  uint32_t coefficient = index % polynomial_size;
+  uint64_t sum = 0;
+  for (uint32_t limb = 0; limb < LIMB_COUNT; limb++) {
+    uint64_t product = products[(polynomial * LIMB_COUNT + limb) * polynomial_size + coefficient];
+    sum += ntt_to_signed(product) << (limb * LIMB_BITS);
+  }
+  accumulators[index] += sum;
+}
+
+// Extracts the constant coefficients of the accumulators as LWE ciphertexts under the flattened
+// GLWE key.
+__global__ void sample_extract_kernel(uint64_t *outputs, const uint64_t *accumulators,
+                                      uint32_t count, uint32_t glwe_dimension,
+                                      uint32_t polynomial_size) {
+  uint64_t mask_size = (uint64_t)glwe_dimension * polynomial_size;
+  uint64_t index = (uint64_t)blockIdx.x * blockDim.x + threadIdx.x;
+  if (index >= count * (mask_size + 1)) {
+    return;
+  }
+  uint64_t ciphertext = index / (mask_size + 1);
+  uint64_t element = index % (mask_size + 1);
+  const uint64_t *accumulator = accumulators + ciphertext * (mask_size + polynomial_size);
+  if (element == mask_size) {
+    outputs[index] = accumulator[mask_size];
+    return;
+  }
+  // The constant coefficient of A_r * S_r is a_{r,0} s_{r,0} - sum_{i=1}^{N-1} a_{r,N-i} s_{r,i}.
+  uint64_t polynomial = element / polynomial_size;
+  uint32_t coefficient = element % polynomial_size;
+  const uint64_t *mask = accumulator + polynomial * polynomial_size;
+  outputs[index] = coefficient == 0 ? mask[0] : -mask[polynomial_size - coefficient];
+}
+
+// Copies the twiddle factors of the polynomial size to the gpu, and returns the inverse of the
+// polynomial size modulo p.
+static cudaError_t upload_twiddles(uint64_t *twiddles, uint32_t polynomial_size,
+                                   uint64_t *size_inverse) {
+  uint64_t *host_twiddles = new uint64_t[2 * polynomial_size];
+  *size_inverse = ntt_twiddles(host_twiddles, host_twiddles + polynomial_size, polynomial_size);
+  cudaError_t error = cudaMemcpy(twiddles, host_twiddles, 2 * polynomial_size * sizeof(uint64_t),
+                                 cudaMemcpyHostToDevice);
+  delete[] host_twiddles;
+  return error;
+}
+
+extern "C" {
+
+int cuda_convert_lwe_bootstrap_key_to_ntt_64(uint32_t gpu_index, uint64_t *ntt_bsk,
+                                             const uint64_t *bsk, uint32_t polynomial_count,
+                                             uint32_t polynomial_size) {
+  cudaError_t error = cudaSetDevice(gpu_index);
+  if (error != cudaSuccess) {
+    return error;
+  }
+  uint64_t *twiddles = nullptr;
+  error = cudaMalloc((void **)&twiddles, 2 * polynomial_size * sizeof(uint64_t));
+  uint64_t size_inverse = 0;
+  if (error == cudaSuccess) {
+    error = upload_twiddles(twiddles, polynomial_size, &size_inverse);
+  }
+  if (error == cudaSuccess) {
+    uint64_t coefficient_count = (uint64_t)polynomial_count * polynomial_size;
+    split_limbs_kernel<<<block_count(coefficient_count), CONCRETE_CUDA_BLOCK_SIZE>>>(
+        ntt_bsk, bsk, coefficient_count, polynomial_size);
+    ntt_forward_kernel<<<polynomial_count * LIMB_COUNT, CONCRETE_CUDA_NTT_BLOCK_SIZE>>>(
+        ntt_bsk, twiddles, polynomial_size);
+    error = cudaGetLastError();
+  }
+  if (error == cudaSuccess) {
+    error = cudaDeviceSynchronize();
+  }
+  cudaFree(twiddles);
+  return error;
+}
+
+int cuda_convert_lwe_bootstrap_key_from_ntt_64(uint32_t gpu_index, uint64_t *bsk,
+                                               const uint64_t *ntt_bsk, uint32_t polynomial_count,
+                                               uint32_t polynomial_size) {
+  cudaError_t error = cudaSetDevice(gpu_index);
+  if (error != cudaSuccess) {
+    return error;
+  }
+  uint64_t coefficient_count = (uint64_t)polynomial_count * polynomial_size;
+  uint64_t *twiddles = nullptr;
+  uint64_t *limbs = nullptr;
+  error = cudaMalloc((void **)&twiddles, 2 * polynomial_size * sizeof(uint64_t));
+  if (error == cudaSuccess) {
+    error = cudaMalloc((void **)&limbs, LIMB_COUNT * coefficient_count * sizeof(uint64_t));
+  }
+  uint64_t size_inverse = 0;
+  if (error == cudaSuccess) {
+    error = upload_twiddles(twiddles, polynomial_size, &size_inverse);
+  }
+  if (error == cudaSuccess) {
+    error = cudaMemcpy(limbs, ntt_bsk, LIMB_COUNT * coefficient_count * sizeof(uint64_t),
+                       cudaMemcpyDeviceToDevice);
+  }
+  if (error == cudaSuccess) {
+    ntt_inverse_kernel<<<polynomial_count * LIMB_COUNT, CONCRETE_CUDA_NTT_BLOCK_SIZE>>>(
+        limbs, twiddles + polynomial_size, size_inverse, polynomial_size);
+    merge_limbs_kernel<<<block_count(coefficient_count), CONCRETE_CUDA_BLOCK_SIZE>>>(
+        bsk, limbs, coefficient_count, polynomial_size);
+    error = cudaGetLastError();
+  }
+  if (error == cudaSuccess) {
+    error = cudaDeviceSynchronize();
+  }
+  cudaFree(twiddles);
+  cudaFree(limbs);
+  return error;
+}
+
+int cuda_bootstrap_lwe_ciphertext_vector_64(uint32_t gpu_index, uint64_t *outputs,
+                                            const uint64_t *inputs, const uint64_t *luts,
+                                            const uint64_t *ntt_bsk, uint32_t count,
+                                            uint32_t input_lwe_dimension, uint32_t glwe_dimension,
+                                            uint32_t polynomial_size, uint32_t base_log,
+                                            uint32_t level_count) {
+  cudaError_t error = cudaSetDevice(gpu_index);
+  if (error != cudaSuccess) {
+    return error;
+  }
+  uint32_t glwe_size = glwe_dimension + 1;
+  uint64_t glwe_length = (uint64_t)glwe_size * polynomial_size;
+  uint64_t ggsw_length = (uint64_t)level_count * glwe_size * LIMB_COUNT * glwe_length;
+
+  uint64_t *twiddles = nullptr;
+  uint64_t *accumulators = nullptr;
+  uint64_t *digits = nullptr;
+  uint64_t *products = nullptr;
+  error = cudaMalloc((void **)&twiddles, 2 * polynomial_size * sizeof(uint64_t));
+  if (error == cudaSuccess) {
+    error = cudaMalloc((void **)&accumulators, count * glwe_length * sizeof(uint64_t));
+  }
+  if (error == cudaSuccess) {
+    error = cudaMalloc((void **)&digits, count * level_count * glwe_length * sizeof(uint64_t));
+  }
+  if (error == cudaSuccess) {
+    error = cudaMalloc((void **)&products, count * LIMB_COUNT * glwe_length * sizeof(uint64_t));
+  }
+  uint64_t size_inverse = 0;
+  if (error == cudaSuccess) {
+    error = upload_twiddles(twiddles, polynomial_size, &size_inverse);
+  }
+
+  uint32_t blocks = block_count(count * glwe_length);
+  if (error == cudaSuccess) {
+    initialize_accumulator_kernel<<<blocks, CONCRETE_CUDA_BLOCK_SIZE>>>(
+        accumulators, luts, inputs, count, input_lwe_dimension, glwe_size, polynomial_size);
+    for (uint32_t i = 0; i < input_lwe_dimension; i++) {
+      decompose_difference_kernel<<<blocks, CONCRETE_CUDA_BLOCK_SIZE>>>(
+          digits, accumulators, inputs, i, count, input_lwe_dimension, glwe_size,
+          polynomial_size, base_log, level_count);
+      ntt_forward_kernel<<<count * glwe_size * level_count, CONCRETE_CUDA_NTT_BLOCK_SIZE>>>(
+          digits, twiddles, polynomial_size);
+      multiply_accumulate_kernel<<<block_count(count * LIMB_COUNT * glwe_length),
+                                   CONCRETE_CUDA_BLOCK_SIZE>>>(
+          products, digits, ntt_bsk + i * ggsw_length, count, glwe_size, polynomial_size,
+          level_count);
+      ntt_inverse_kernel<<<count * glwe_size * LIMB_COUNT, CONCRETE_CUDA_NTT_BLOCK_SIZE>>>(
+          products, twiddles + polynomial_size, size_inverse, polynomial_size);
+      add_products_kernel<<<blocks, CONCRETE_CUDA_BLOCK_SIZE>>>(accumulators, products, count,
+                                                                glwe_size, polynomial_size);
+    }
+    sample_extract_kernel<<<block_count(count * ((uint64_t)glwe_dimension * polynomial_size + 1)),
+                            CONCRETE_CUDA_BLOCK_SIZE>>>(outputs, accumulators, count,
+                                                        glwe_dimension, polynomial_size);
+    error = cudaGetLastError();
+  }
+  if (error == cudaSuccess) {
+    error = cudaDeviceSynchronize();
+  }
+
+  // The buffers are released whatever the outcome, and the first error is reported.
+  cudaFree(twiddles);
+  cudaFree(accumulators);
+  cudaFree(digits);
+  cudaFree(products);
+  return error;
+}
+
+} // extern "C"
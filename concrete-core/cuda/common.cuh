@@ -0,0 +1,67 @@
+// Device helpers shared by the kernels of the cuda backend.
+//
+// Torus elements are represented by unsigned integers, and all the arithmetic is wrapping, that
+// is, computed modulo q = 2^64. Polynomials are arrays of coefficients stored by increasing degree,
+// and live in Z_q[X] / (X^N + 1). The layouts of the entities are the ones of the core backend:
+// an LWE ciphertext is its mask followed by its body, and a GLWE ciphertext is its mask
+// polynomials followed by its body polynomial.
+#ifndef CONCRETE_CUDA_COMMON_CUH
+#define CONCRETE_CUDA_COMMON_CUH
+
+#include <cstdint>
+#include <cuda_runtime.h>
+
+#define CONCRETE_CUDA_BLOCK_SIZE 256
+
+// Returns the number of blocks needed to cover `count` threads.
+inline uint32_t block_count(uint64_t count) {
+  return (uint32_t)((count + CONCRETE_CUDA_BLOCK_SIZE - 1) / CONCRETE_CUDA_BLOCK_SIZE);
+}
+
+// Rounds a torus element to the closest multiple of q / B^l, with B = 2^base_log, and returns
+// its signed digit of level `level` (from 1 to l), in [-B/2, B/2), represented modulo q.
+//
+// The digits are extracted from the least significant one, to propagate the carries, exactly as
+// in the core backend.
+__device__ inline uint64_t decompose_level(uint64_t value, uint32_t base_log,
+                                           uint32_t level_count, uint32_t level) {
+  uint32_t dropped_bits = 64 - base_log * level_count;
+  uint64_t remainder = value;
+  if (dropped_bits > 0) {
+    remainder = (value + (1ull << (dropped_bits - 1))) >> dropped_bits;
+  }
+  uint64_t base = 1ull << base_log;
+  uint64_t digit = 0;
+  for (uint32_t current = level_count; current >= level; current--) {
+    digit = remainder & (base - 1);
+    remainder >>= base_log;
+    if (digit >= base >> 1) {
+      digit -= base;
+      remainder += 1;
+    }
+  }
+  return digit;
+}
+
+// Switches a torus element to the modulus 2N, that is, rounds x * 2N / q to the closest integer
+// modulo 2N. The polynomial size N must be a power of two.
+__device__ inline uint32_t modulus_switch(uint64_t value, uint32_t polynomial_size) {
+  uint32_t log_2n = __ffs(2 * polynomial_size) - 1;
+  uint32_t dropped_bits = 64 - log_2n;
+  uint64_t rounded = (value + (1ull << (dropped_bits - 1))) >> dropped_bits;
+  return (uint32_t)(rounded % (2 * polynomial_size));
+}
+
+// Returns the coefficient `index` of A * X^degree modulo X^N + 1, for a degree in [0, 2N).
+__device__ inline uint64_t rotated_coefficient(const uint64_t *polynomial, uint32_t index,
+                                               uint32_t degree, uint32_t polynomial_size) {
+  // The coefficient comes from the monomial of degree index - degree, modulo 2N. Since
+  // X^N = -1, the monomials of degree in [N, 2N) are negated.
+  uint32_t source = (index + 2 * polynomial_size - degree) % (2 * polynomial_size);
+  if (source < polynomial_size) {
+    return polynomial[source];
+  }
+  return -polynomial[source - polynomial_size];
+}
+
+#endif // CONCRETE_CUDA_COMMON_CUH
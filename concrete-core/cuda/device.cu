@@ -0,0 +1,45 @@
+// Device management and memory transfers of the cuda backend.
+//
+// All the functions return the `cudaError_t` code of the first failing call to the cuda runtime,
+// or `cudaSuccess` (0).
+#include "common.cuh"
+
+extern "C" {
+
+int cuda_get_device_count(int *count) { return cudaGetDeviceCount(count); }
+
+int cuda_malloc(void **pointer, uint64_t size, uint32_t gpu_index) {
+  cudaError_t error = cudaSetDevice(gpu_index);
+  if (error != cudaSuccess) {
+    return error;
+  }
+  return cudaMalloc(pointer, size);
+}
+
+int cuda_free(void *pointer, uint32_t gpu_index) {
+  cudaError_t error = cudaSetDevice(gpu_index);
+  if (error != cudaSuccess) {
+    return error;
+  }
+  return cudaFree(pointer);
+}
+
+int cuda_memcpy_to_gpu(void *destination, const void *source, uint64_t size,
+                       uint32_t gpu_index) {
+  cudaError_t error = cudaSetDevice(gpu_index);
+  if (error != cudaSuccess) {
+    return error;
+  }
+  return cudaMemcpy(destination, source, size, cudaMemcpyHostToDevice);
+}
+
+int cuda_memcpy_to_cpu(void *destination, const void *source, uint64_t size,
+                       uint32_t gpu_index) {
+  cudaError_t error = cudaSetDevice(gpu_index);
+  if (error != cudaSuccess) {
+    return error;
+  }
+  return cudaMemcpy(destination, source, size, cudaMemcpyDeviceToHost);
+}
+
+} // extern "C"
@@ -0,0 +1,48 @@
+// LWE keyswitch of the cuda backend.
+#include "common.cuh"
+
+// Computes the coefficient `blockIdx.x * blockDim.x + threadIdx.x` of the keyswitched ciphertext.
+//
+// The output is the trivial encryption of the input body, minus the sum over the input mask
+// elements a_i and the levels j of d_{i,j} times the encryption of s_i * q / B^j, where the d_{i,j}
+// are the digits of a_i. The key stores these encryptions by input key coefficient, then by level.
+__global__ void keyswitch_kernel(uint64_t *output, const uint64_t *input, const uint64_t *ksk,
+                                 uint32_t input_lwe_dimension, uint32_t output_lwe_dimension,
+                                 uint32_t base_log, uint32_t level_count) {
+  uint32_t output_lwe_size = output_lwe_dimension + 1;
+  uint32_t index = blockIdx.x * blockDim.x + threadIdx.x;
+  if (index >= output_lwe_size) {
+    return;
+  }
+  uint64_t sum = index == output_lwe_dimension ? input[input_lwe_dimension] : 0;
+  for (uint32_t i = 0; i < input_lwe_dimension; i++) {
+    const uint64_t *block = ksk + (uint64_t)i * level_count * output_lwe_size;
+    for (uint32_t level = 1; level <= level_count; level++) {
+      uint64_t digit = decompose_level(input[i], base_log, level_count, level);
+      sum -= digit * block[(uint64_t)(level - 1) * output_lwe_size + index];
+    }
+  }
+  output[index] = sum;
+}
+
+extern "C" {
+
+int cuda_keyswitch_lwe_ciphertext_64(uint32_t gpu_index, uint64_t *output, const uint64_t *input,
+                                     const uint64_t *ksk, uint32_t input_lwe_dimension,
+                                     uint32_t output_lwe_dimension, uint32_t base_log,
+                                     uint32_t level_count) {
+  cudaError_t error = cudaSetDevice(gpu_index);
+  if (error != cudaSuccess) {
+    return error;
+  }
+  uint32_t output_lwe_size = output_lwe_dimension + 1;
+  keyswitch_kernel<<<block_count(output_lwe_size), CONCRETE_CUDA_BLOCK_SIZE>>>(
+      output, input, ksk, input_lwe_dimension, output_lwe_dimension, base_log, level_count);
+  error = cudaGetLastError();
+  if (error != cudaSuccess) {
+    return error;
+  }
+  return cudaDeviceSynchronize();
+}
+
+} // extern "C"
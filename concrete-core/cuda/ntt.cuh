@@ -0,0 +1,172 @@
+// Negacyclic number theoretic transform of the cuda backend.
+//
+// The polynomial products of the bootstrap are computed exactly with a number theoretic transform
+// (NTT) modulo the prime p = 2^64 - 2^32 + 1, whose multiplicative group holds the 2N-th roots of
+// unity for all the polynomial sizes up to 2^31. The transforms of size N are negacyclic, that is,
+// they compute the products in Z_p[X] / (X^N + 1), and follow the Cooley-Tukey (forward) and
+// Gentleman-Sande (inverse) algorithms with the powers of a 2N-th root of unity psi merged in the
+// twiddle factors. The forward transform leaves its output in bit-reversed order, which is the
+// order expected by the inverse transform, so that the products are computed without reordering.
+//
+// The arithmetic functions are usable on the host as well, where the twiddle factors are computed.
+#ifndef CONCRETE_CUDA_NTT_CUH
+#define CONCRETE_CUDA_NTT_CUH
+
+#include <cstdint>
+
+// The number of threads of the blocks computing the transforms, one block per polynomial.
+#ifndef CONCRETE_CUDA_NTT_BLOCK_SIZE
+#define CONCRETE_CUDA_NTT_BLOCK_SIZE 256
+#endif
+
+// The prime modulus p = 2^64 - 2^32 + 1 of the transforms.
+#define NTT_PRIME 0xffffffff00000001ull
+
+// The value of 2^64 modulo p, that is 2^32 - 1.
+#define NTT_EPSILON 0xffffffffull
+
+// A generator of the multiplicative group of the integers modulo p.
+#define NTT_GENERATOR 7ull
+
+// Returns a + b modulo p, for a and b in [0, p).
+__host__ __device__ inline uint64_t ntt_add(uint64_t a, uint64_t b) {
+  uint64_t sum = a + b;
+  // The carry is worth 2^64 = 2^32 - 1 modulo p, and can not carry again since a + b < 2p.
+  if (sum < a) {
+    sum += NTT_EPSILON;
+  }
+  return sum >= NTT_PRIME ? sum - NTT_PRIME : sum;
+}
+
+// Returns a - b modulo p, for a and b in [0, p).
+__host__ __device__ inline uint64_t ntt_sub(uint64_t a, uint64_t b) {
+  uint64_t difference = a - b;
+  // The borrow is worth -2^64 = -(2^32 - 1) modulo p, and can not borrow again since a - b > -p.
+  if (a < b) {
+    difference -= NTT_EPSILON;
+  }
+  return difference;
+}
+
+// Returns hi * 2^64 + lo modulo p.
+__host__ __device__ inline uint64_t ntt_reduce(uint64_t hi, uint64_t lo) {
+  // Since 2^64 = 2^32 - 1 and 2^96 = -1 modulo p, writing hi = hi_hi * 2^32 + hi_lo gives
+  // hi * 2^64 + lo = lo - hi_hi + hi_lo * (2^32 - 1).
+  uint64_t hi_hi = hi >> 32;
+  uint64_t hi_lo = hi & NTT_EPSILON;
+  uint64_t value = lo - hi_hi;
+  if (lo < hi_hi) {
+    value -= NTT_EPSILON;
+  }
+  uint64_t product = (hi_lo << 32) - hi_lo;
+  uint64_t sum = value + product;
+  if (sum < value) {
+    sum += NTT_EPSILON;
+  }
+  return sum >= NTT_PRIME ? sum - NTT_PRIME : sum;
+}
+
+// Returns a * b modulo p, for a and b in [0, p).
+__host__ __device__ inline uint64_t ntt_mul(uint64_t a, uint64_t b) {
+#ifdef __CUDA_ARCH__
+  uint64_t hi = __umul64hi(a, b);
+#else
+  uint64_t hi = (uint64_t)(((unsigned __int128)a * b) >> 64);
+#endif
+  return ntt_reduce(hi, a * b);
+}
+
+// Returns base^exponent modulo p.
+__host__ __device__ inline uint64_t ntt_pow(uint64_t base, uint64_t exponent) {
+  uint64_t result = 1;
+  while (exponent > 0) {
+    if (exponent & 1) {
+      result = ntt_mul(result, base);
+    }
+    base = ntt_mul(base, base);
+    exponent >>= 1;
+  }
+  return result;
+}
+
+// Maps a torus element, seen as a signed integer of magnitude smaller than p / 2, to the integers
+// modulo p.
+__host__ __device__ inline uint64_t ntt_from_signed(uint64_t value) {
+  return (int64_t)value < 0 ? value + NTT_PRIME : value;
+}
+
+// Maps an integer modulo p to the signed integer of magnitude smaller than p / 2 it represents,
+// seen as a torus element.
+__host__ __device__ inline uint64_t ntt_to_signed(uint64_t value) {
+  return value > NTT_PRIME / 2 ? value - NTT_PRIME : value;
+}
+
+// Returns the `bits` least significant bits of `value` in reverse order.
+__host__ __device__ inline uint32_t ntt_bit_reverse(uint32_t value, uint32_t bits) {
+  uint32_t reversed = 0;
+  for (uint32_t bit = 0; bit < bits; bit++) {
+    reversed = (reversed << 1) | ((value >> bit) & 1);
+  }
+  return reversed;
+}
+
+// Fills the `polynomial_size` forward and inverse twiddle factors, which hold the powers of psi
+// and of its inverse in bit-reversed order, and returns the inverse of the polynomial size
+// modulo p, which scales the output of the inverse transform.
+inline uint64_t ntt_twiddles(uint64_t *forward, uint64_t *inverse, uint32_t polynomial_size) {
+  uint32_t log_n = 0;
+  while ((1u << log_n) < polynomial_size) {
+    log_n++;
+  }
+  uint64_t psi = ntt_pow(NTT_GENERATOR, (NTT_PRIME - 1) / (2 * (uint64_t)polynomial_size));
+  // Since psi^{2N} = 1, the inverse of psi is psi^{2N - 1}.
+  uint64_t psi_inverse = ntt_pow(psi, 2 * (uint64_t)polynomial_size - 1);
+  for (uint32_t index = 0; index < polynomial_size; index++) {
+    uint32_t exponent = ntt_bit_reverse(index, log_n);
+    forward[index] = ntt_pow(psi, exponent);
+    inverse[index] = ntt_pow(psi_inverse, exponent);
+  }
+  return ntt_pow(polynomial_size, NTT_PRIME - 2);
+}
+
+// Computes in place the forward transform of the polynomial `blockIdx.x` of `polynomials`.
+__global__ void ntt_forward_kernel(uint64_t *polynomials, const uint64_t *twiddles,
+                                   uint32_t polynomial_size) {
+  uint64_t *polynomial = polynomials + (uint64_t)blockIdx.x * polynomial_size;
+  uint32_t half_size = polynomial_size / 2;
+  for (uint32_t groups = 1, stride = half_size; groups < polynomial_size;
+       groups *= 2, stride /= 2) {
+    for (uint32_t butterfly = threadIdx.x; butterfly < half_size; butterfly += blockDim.x) {
+      uint32_t group = butterfly / stride;
+      uint32_t first = 2 * group * stride + butterfly % stride;
+      uint64_t u = polynomial[first];
+      uint64_t v = ntt_mul(polynomial[first + stride], twiddles[groups + group]);
+      polynomial[first] = ntt_add(u, v);
+      polynomial[first + stride] = ntt_sub(u, v);
+    }
+    __syncthreads();
+  }
+}
+
+// Computes in place the inverse transform of the polynomial `blockIdx.x` of `polynomials`.
+__global__ void ntt_inverse_kernel(uint64_t *polynomials, const uint64_t *twiddles,
+                                   uint64_t size_inverse, uint32_t polynomial_size) {
+  uint64_t *polynomial = polynomials + (uint64_t)blockIdx.x * polynomial_size;
+  uint32_t half_size = polynomial_size / 2;
+  for (uint32_t groups = half_size, stride = 1; groups >= 1; groups /= 2, stride *= 2) {
+    for (uint32_t butterfly = threadIdx.x; butterfly < half_size; butterfly += blockDim.x) {
+      uint32_t group = butterfly / stride;
+      uint32_t first = 2 * group * stride + butterfly % stride;
+      uint64_t u = polynomial[first];
+      uint64_t v = polynomial[first + stride];
+      polynomial[first] = ntt_add(u, v);
+      polynomial[first + stride] = ntt_mul(ntt_sub(u, v), twiddles[groups + group]);
+    }
+    __syncthreads();
+  }
+  for (uint32_t index = threadIdx.x; index < polynomial_size; index += blockDim.x) {
+    polynomial[index] = ntt_mul(polynomial[index], size_inverse);
+  }
+}
+
+#endif // CONCRETE_CUDA_NTT_CUH
@@ -0,0 +1,511 @@
+//! A module containing an asynchronous adapter of the core engine, for use in async services.
+//!
+//! The operations of the [`CoreEngine`] are blocking, and the heaviest of them (key generations,
+//! bootstraps) can occupy a thread for milliseconds to minutes. Calling them from an async task
+//! would stall the executor of the service. An [`AsyncCoreEngine`] instead owns a small pool of
+//! worker threads, each holding its own [`CoreEngine`], and exposes the heavy operations as
+//! methods returning an [`EngineFuture`], which can be awaited from any executor.
+//!
+//! + The number of worker threads is the concurrency limit of the engine: at most this many
+//!   operations are executed at the same time, the others wait in a queue.
+//! + Since every worker owns its engine, the Fourier buffers and the random generators of a
+//!   worker are reused by all the operations it executes.
+//! + Keys are shared between the operations through an [`Arc`], while the ciphertexts are moved
+//!   to the worker and returned with the result.
+//! + If an operation panics, the panic is propagated to the task awaiting the future, and the
+//!   worker replaces its engine by a fresh one.
+//!
+//! Operations not covered by a dedicated method can be executed with [`AsyncCoreEngine::run`].
+//!
+//! This module is only available with the `async` feature.
+//!
+//! # Example:
+//!
+//! ```
+//! use concrete_commons::dispersion::Variance;
+//! use concrete_commons::parameters::{
+//!     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+//! };
+//! use concrete_core::backends::core::asynchronous::AsyncCoreEngine;
+//! use concrete_core::prelude::*;
+//! use std::sync::Arc;
+//! # use std::error::Error;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+//! let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(512));
+//! let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+//! let noise = Variance(2_f64.powf(-25.));
+//!
+//! let mut engine = CoreEngine::new()?;
+//! let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+//! let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+//!
+//! // At most two operations are executed at the same time.
+//! let async_engine = AsyncCoreEngine::with_concurrency(2);
+//! let future = async_engine.create_lwe_bootstrap_key::<_, _, LweBootstrapKey64>(
+//!     Arc::new(lwe_sk.clone()),
+//!     Arc::new(glwe_sk.clone()),
+//!     dec_bl,
+//!     dec_lc,
+//!     noise,
+//! );
+//! // In an async context, the future would be awaited with `future.await`.
+//! let bsk = future.wait()?;
+//! let future = async_engine.convert_lwe_bootstrap_key::<_, FourierLweBootstrapKey64>(Arc::new(bsk));
+//! let fourier_bsk = future.wait()?;
+//! #
+//! assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+//!
+//! engine.destroy(lwe_sk)?;
+//! engine.destroy(glwe_sk)?;
+//! engine.destroy(fourier_bsk)?;
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use crate::backends::core::implementation::engines::{CoreEngine, CoreError};
+use crate::specification::engines::{
+    AbstractEngine, LweBootstrapKeyConversionEngine, LweBootstrapKeyConversionError,
+    LweBootstrapKeyCreationEngine, LweBootstrapKeyCreationError,
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
+    LweKeyswitchKeyCreationEngine, LweKeyswitchKeyCreationError,
+};
+use crate::specification::entities::{
+    GlweCiphertextEntity, GlweSecretKeyEntity, LweBootstrapKeyEntity, LweCiphertextEntity,
+    LweKeyswitchKeyEntity, LweSecretKeyEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use std::future::Future;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+
+type Job = Box<dyn FnOnce(&mut CoreEngine) + Send>;
+
+/// An asynchronous adapter of the [`CoreEngine`], executing the operations on a pool of worker
+/// threads.
+///
+/// The engine can be cloned cheaply, the clones sharing the same workers. The workers stop once
+/// all the clones are dropped, and all the queued operations are executed.
+///
+/// See the [`asynchronous`](super::asynchronous) module for more details.
+#[derive(Clone)]
+pub struct AsyncCoreEngine {
+    jobs: Sender<Job>,
+    concurrency: usize,
+}
+
+impl AsyncCoreEngine {
+    /// Creates an engine with one worker per available CPU core.
+    pub fn new() -> Self {
+        let concurrency = thread::available_parallelism()
+            .map(|count| count.get())
+            .unwrap_or(1);
+        Self::with_concurrency(concurrency)
+    }
+
+    /// Creates an engine executing at most `concurrency` operations at the same time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `concurrency` is zero.
+    pub fn with_concurrency(concurrency: usize) -> Self {
+        assert!(
+            concurrency > 0,
+            "The concurrency must be greater than zero."
+        );
+        let (jobs, receiver) = channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        for index in 0..concurrency {
+            let receiver = receiver.clone();
+            thread::Builder::new()
+                .name(format!("concrete-core-worker-{}", index))
+                .spawn(move || work(receiver))
+                .expect("Failed to spawn a worker thread.");
+        }
+        AsyncCoreEngine { jobs, concurrency }
+    }
+
+    /// Returns the maximum number of operations executed at the same time.
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Executes an arbitrary closure on the engine of a worker.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::asynchronous::AsyncCoreEngine;
+    /// use concrete_core::prelude::*;
+    ///
+    /// let async_engine = AsyncCoreEngine::with_concurrency(1);
+    /// let key: LweSecretKey32 = async_engine
+    ///     .run(|engine| engine.create_lwe_secret_key(LweDimension(10)))
+    ///     .wait()
+    ///     .unwrap();
+    /// assert_eq!(key.lwe_dimension(), LweDimension(10));
+    /// ```
+    pub fn run<Operation, Output>(&self, operation: Operation) -> EngineFuture<Output>
+    where
+        Operation: FnOnce(&mut CoreEngine) -> Output + Send + 'static,
+        Output: Send + 'static,
+    {
+        let state = Arc::new(FutureState {
+            slot: Mutex::new(Slot {
+                result: None,
+                waker: None,
+            }),
+            ready: Condvar::new(),
+        });
+        let job_state = state.clone();
+        let job: Job = Box::new(move |engine| {
+            let result = catch_unwind(AssertUnwindSafe(|| operation(engine)));
+            let failed = result.is_err();
+            job_state.complete(result);
+            // The engine may be left in an inconsistent state by a panic.
+            if failed {
+                *engine = CoreEngine::new().unwrap();
+            }
+        });
+        self.jobs
+            .send(job)
+            .expect("The workers of the engine have stopped.");
+        EngineFuture { state }
+    }
+
+    /// Asynchronously creates an LWE bootstrap key.
+    ///
+    /// See [`LweBootstrapKeyCreationEngine::create_lwe_bootstrap_key`].
+    pub fn create_lwe_bootstrap_key<LweSecretKey, GlweSecretKey, BootstrapKey>(
+        &self,
+        input_key: Arc<LweSecretKey>,
+        output_key: Arc<GlweSecretKey>,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> EngineFuture<Result<BootstrapKey, LweBootstrapKeyCreationError<CoreError>>>
+    where
+        CoreEngine: LweBootstrapKeyCreationEngine<LweSecretKey, GlweSecretKey, BootstrapKey>,
+        BootstrapKey: LweBootstrapKeyEntity + Send + 'static,
+        LweSecretKey: LweSecretKeyEntity<KeyDistribution = BootstrapKey::InputKeyDistribution>
+            + Send
+            + Sync
+            + 'static,
+        GlweSecretKey: GlweSecretKeyEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.run(move |engine| {
+            engine.create_lwe_bootstrap_key(
+                &*input_key,
+                &*output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    /// Asynchronously converts an LWE bootstrap key, for instance to the Fourier domain.
+    ///
+    /// See [`LweBootstrapKeyConversionEngine::convert_lwe_bootstrap_key`].
+    pub fn convert_lwe_bootstrap_key<InputKey, OutputKey>(
+        &self,
+        input: Arc<InputKey>,
+    ) -> EngineFuture<Result<OutputKey, LweBootstrapKeyConversionError<CoreError>>>
+    where
+        CoreEngine: LweBootstrapKeyConversionEngine<InputKey, OutputKey>,
+        InputKey: LweBootstrapKeyEntity + Send + Sync + 'static,
+        OutputKey: LweBootstrapKeyEntity<
+                InputKeyDistribution = InputKey::InputKeyDistribution,
+                OutputKeyDistribution = InputKey::OutputKeyDistribution,
+            > + Send
+            + 'static,
+    {
+        self.run(move |engine| engine.convert_lwe_bootstrap_key(&*input))
+    }
+
+    /// Asynchronously creates an LWE keyswitch key.
+    ///
+    /// See [`LweKeyswitchKeyCreationEngine::create_lwe_keyswitch_key`].
+    pub fn create_lwe_keyswitch_key<InputSecretKey, OutputSecretKey, KeyswitchKey>(
+        &self,
+        input_key: Arc<InputSecretKey>,
+        output_key: Arc<OutputSecretKey>,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> EngineFuture<Result<KeyswitchKey, LweKeyswitchKeyCreationError<CoreError>>>
+    where
+        CoreEngine: LweKeyswitchKeyCreationEngine<InputSecretKey, OutputSecretKey, KeyswitchKey>,
+        InputSecretKey: LweSecretKeyEntity + Send + Sync + 'static,
+        OutputSecretKey: LweSecretKeyEntity + Send + Sync + 'static,
+        KeyswitchKey: LweKeyswitchKeyEntity<
+                InputKeyDistribution = InputSecretKey::KeyDistribution,
+                OutputKeyDistribution = OutputSecretKey::KeyDistribution,
+            > + Send
+            + 'static,
+    {
+        self.run(move |engine| {
+            engine.create_lwe_keyswitch_key(
+                &*input_key,
+                &*output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    /// Asynchronously bootstraps an LWE ciphertext.
+    ///
+    /// The `output` ciphertext is moved to the worker, and returned once the bootstrap is done.
+    ///
+    /// See [`LweCiphertextDiscardingBootstrapEngine::discard_bootstrap_lwe_ciphertext`].
+    pub fn discard_bootstrap_lwe_ciphertext<
+        InputCiphertext,
+        Accumulator,
+        BootstrapKey,
+        OutputCiphertext,
+    >(
+        &self,
+        mut output: OutputCiphertext,
+        input: InputCiphertext,
+        acc: Arc<Accumulator>,
+        bsk: Arc<BootstrapKey>,
+    ) -> EngineFuture<Result<OutputCiphertext, LweCiphertextDiscardingBootstrapError<CoreError>>>
+    where
+        CoreEngine: LweCiphertextDiscardingBootstrapEngine<
+            BootstrapKey,
+            Accumulator,
+            InputCiphertext,
+            OutputCiphertext,
+        >,
+        BootstrapKey: LweBootstrapKeyEntity + Send + Sync + 'static,
+        Accumulator: GlweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>
+            + Send
+            + Sync
+            + 'static,
+        InputCiphertext: LweCiphertextEntity<KeyDistribution = BootstrapKey::InputKeyDistribution>
+            + Send
+            + 'static,
+        OutputCiphertext: LweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>
+            + Send
+            + 'static,
+    {
+        self.run(move |engine| {
+            engine
+                .discard_bootstrap_lwe_ciphertext(&mut output, &input, &*acc, &*bsk)
+                .map(|_| output)
+        })
+    }
+
+    /// Asynchronously keyswitches an LWE ciphertext.
+    ///
+    /// The `output` ciphertext is moved to the worker, and returned once the keyswitch is done.
+    ///
+    /// See [`LweCiphertextDiscardingKeyswitchEngine::discard_keyswitch_lwe_ciphertext`].
+    pub fn discard_keyswitch_lwe_ciphertext<KeyswitchKey, InputCiphertext, OutputCiphertext>(
+        &self,
+        mut output: OutputCiphertext,
+        input: InputCiphertext,
+        ksk: Arc<KeyswitchKey>,
+    ) -> EngineFuture<Result<OutputCiphertext, LweCiphertextDiscardingKeyswitchError<CoreError>>>
+    where
+        CoreEngine:
+            LweCiphertextDiscardingKeyswitchEngine<KeyswitchKey, InputCiphertext, OutputCiphertext>,
+        KeyswitchKey: LweKeyswitchKeyEntity + Send + Sync + 'static,
+        InputCiphertext: LweCiphertextEntity<KeyDistribution = KeyswitchKey::InputKeyDistribution>
+            + Send
+            + 'static,
+        OutputCiphertext: LweCiphertextEntity<KeyDistribution = KeyswitchKey::OutputKeyDistribution>
+            + Send
+            + 'static,
+    {
+        self.run(move |engine| {
+            engine
+                .discard_keyswitch_lwe_ciphertext(&mut output, &input, &*ksk)
+                .map(|_| output)
+        })
+    }
+}
+
+impl Default for AsyncCoreEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A future resolving to the output of an operation executed by an [`AsyncCoreEngine`].
+///
+/// The operation is executed whether the future is polled or not. Dropping the future does not
+/// cancel the operation, but discards its output.
+pub struct EngineFuture<Output> {
+    state: Arc<FutureState<Output>>,
+}
+
+impl<Output> EngineFuture<Output> {
+    /// Blocks the current thread until the operation is done, and returns its output.
+    ///
+    /// This allows to use the [`AsyncCoreEngine`] from synchronous code. It must not be called
+    /// from an async task, which would block the executor.
+    pub fn wait(self) -> Output {
+        let mut slot = self.state.slot.lock().unwrap();
+        loop {
+            if let Some(result) = slot.result.take() {
+                return unwrap_result(result);
+            }
+            slot = self.state.ready.wait(slot).unwrap();
+        }
+    }
+}
+
+impl<Output> Future for EngineFuture<Output> {
+    type Output = Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Output> {
+        let mut slot = self.state.slot.lock().unwrap();
+        match slot.result.take() {
+            Some(result) => Poll::Ready(unwrap_result(result)),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+struct Slot<Output> {
+    result: Option<thread::Result<Output>>,
+    waker: Option<Waker>,
+}
+
+struct FutureState<Output> {
+    slot: Mutex<Slot<Output>>,
+    ready: Condvar,
+}
+
+impl<Output> FutureState<Output> {
+    fn complete(&self, result: thread::Result<Output>) {
+        let mut slot = self.slot.lock().unwrap();
+        slot.result = Some(result);
+        if let Some(waker) = slot.waker.take() {
+            waker.wake();
+        }
+        self.ready.notify_all();
+    }
+}
+
+fn unwrap_result<Output>(result: thread::Result<Output>) -> Output {
+    match result {
+        Ok(output) => output,
+        Err(panic) => resume_unwind(panic),
+    }
+}
+
+// The loop executed by every worker thread.
+fn work(receiver: Arc<Mutex<Receiver<Job>>>) {
+    let mut engine = CoreEngine::new().unwrap();
+    loop {
+        // The lock is released as soon as a job is received, so that the other workers can
+        // receive the next ones while this one is executed.
+        let job = receiver.lock().unwrap().recv();
+        match job {
+            Ok(job) => job(&mut engine),
+            // All the senders were dropped, and the queue is empty.
+            Err(_) => return,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::{
+        LweCiphertextEncryptionEngine, LweSecretKey32, LweSecretKeyCreationEngine,
+        PlaintextCreationEngine,
+    };
+    use concrete_commons::parameters::LweDimension;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::task::Wake;
+    use std::time::Duration;
+
+    // A minimal executor, driving a future to completion on the current thread.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        struct ThreadWaker(thread::Thread);
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_futures_can_be_awaited() {
+        let async_engine = AsyncCoreEngine::with_concurrency(2);
+        let output = block_on(async {
+            let key: LweSecretKey32 = async_engine
+                .run(|engine| engine.create_lwe_secret_key(LweDimension(10)).unwrap())
+                .await;
+            let key = Arc::new(key);
+            let ciphertext = async_engine
+                .run(move |engine| {
+                    let plaintext = engine.create_plaintext(&(1_u32 << 30)).unwrap();
+                    engine
+                        .encrypt_lwe_ciphertext(&*key, &plaintext, Variance(0.))
+                        .unwrap()
+                })
+                .await;
+            ciphertext.lwe_dimension()
+        });
+        assert_eq!(output, LweDimension(10));
+    }
+
+    #[test]
+    fn test_concurrency_is_limited() {
+        let async_engine = AsyncCoreEngine::with_concurrency(2);
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+        let futures: Vec<_> = (0..8)
+            .map(|_| {
+                let running = running.clone();
+                let max_running = max_running.clone();
+                async_engine.run(move |_| {
+                    let current = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_running.fetch_max(current, Ordering::SeqCst);
+                    thread::sleep(Duration::from_millis(10));
+                    running.fetch_sub(1, Ordering::SeqCst);
+                })
+            })
+            .collect();
+        futures.into_iter().for_each(EngineFuture::wait);
+        assert_eq!(max_running.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_panics_are_propagated() {
+        let async_engine = AsyncCoreEngine::with_concurrency(1);
+        let future: EngineFuture<()> = async_engine.run(|_| panic!("The operation failed."));
+        assert!(catch_unwind(AssertUnwindSafe(|| future.wait())).is_err());
+        // The worker survives the panic.
+        assert_eq!(async_engine.run(|_| 1).wait(), 1);
+    }
+}
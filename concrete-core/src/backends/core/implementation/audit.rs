@@ -0,0 +1,347 @@
+//! A module containing the audit log of the lifetime of the entities of the core backend.
+//!
+//! Security reviews usually need to check that the secret keys are destroyed as soon as they are
+//! no longer needed, and operators need to attribute the memory used by a service to the requests
+//! it processes. An [`AuditSink`] can be attached to a
+//! [`CoreEngine`](super::engines::CoreEngine) to receive an [`AuditEvent`] every time:
+//!
+//! + a key is created by a key creation or key conversion engine,
+//! + a ciphertext is created by an encryption engine,
+//! + an entity is deserialized,
+//! + an entity is destroyed by the `DestructionEngine`.
+//!
+//! Each event carries the kind and type of the entity, the size of the data it holds, the time
+//! at which it was recorded, and the engine recording it. The module is only compiled with the
+//! `audit` feature: without it, the engines contain no audit code at all.
+//!
+//! # Example:
+//!
+//! ```
+//! use concrete_commons::parameters::LweDimension;
+//! use concrete_core::backends::core::audit::{AuditAction, AuditEvent};
+//! use concrete_core::prelude::*;
+//! use std::sync::{Arc, Mutex};
+//! # use std::error::Error;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+//! let mut engine = CoreEngine::new()?;
+//! let events = Arc::new(Mutex::new(Vec::new()));
+//! let events_handle = events.clone();
+//! engine.set_audit_sink(move |event: AuditEvent| events_handle.lock().unwrap().push(event));
+//!
+//! let key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(4))?;
+//! engine.destroy(key)?;
+//!
+//! let events = events.lock().unwrap();
+//! assert_eq!(events.len(), 2);
+//! assert_eq!(events[0].action, AuditAction::Creation);
+//! assert_eq!(events[0].entity_kind, "LweSecretKeyKind");
+//! assert_eq!(events[0].size, 4 * 8);
+//! assert_eq!(events[1].action, AuditAction::Destruction);
+//! assert!(events[1].timestamp >= events[0].timestamp);
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use std::mem::size_of;
+use std::time::SystemTime;
+
+use crate::backends::core::implementation::entities::{
+    AlignedLweBootstrapKey32, AlignedLweBootstrapKey64, AlignedLweCiphertextVector32,
+    AlignedLweCiphertextVector64, Cleartext32, Cleartext64, CleartextVector32, CleartextVector64,
+    ColumnMajorLweKeyswitchKey32, ColumnMajorLweKeyswitchKey64, CyclicGlweCiphertext32,
+    CyclicGlweCiphertext64, FourierGgswCiphertext32, FourierGgswCiphertext64,
+    FourierGlweCiphertext32, FourierGlweCiphertext64, FourierLweBootstrapKey32,
+    FourierLweBootstrapKey64, GgswCiphertext32, GgswCiphertext64, GgswSeededCiphertext32,
+    GgswSeededCiphertext64, GlweAutomorphismKey32, GlweAutomorphismKey64, GlweCiphertext128,
+    GlweCiphertext16, GlweCiphertext32, GlweCiphertext64, GlweCiphertextVector32,
+    GlweCiphertextVector64, GlweRelinearizationKey32, GlweRelinearizationKey64, GlweSecretKey128,
+    GlweSecretKey16, GlweSecretKey32, GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64,
+    LweCiphertext128, LweCiphertext16, LweCiphertext32, LweCiphertext64,
+    LweCiphertextJaggedVector32, LweCiphertextJaggedVector64, LweCiphertextTruncatedView32,
+    LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorView32,
+    LweCiphertextVectorView64, LweKeyswitchKey128, LweKeyswitchKey16, LweKeyswitchKey32,
+    LweKeyswitchKey64, LweKeyswitchKeyBatch32, LweKeyswitchKeyBatch64, LweKeyswitchKeyDelta32,
+    LweKeyswitchKeyDelta64, LwePhaseVector32, LwePhaseVector64, LwePublicKey32, LwePublicKey64,
+    LweSecretKey128, LweSecretKey16, LweSecretKey32, LweSecretKey64, LweSeededBootstrapKey32,
+    LweSeededBootstrapKey64, LweSeededCiphertext32, LweSeededCiphertext64,
+    LweShrinkingKeyswitchKey32, LweShrinkingKeyswitchKey64, PackingKeyswitchKey32,
+    PackingKeyswitchKey64, Plaintext128, Plaintext16, Plaintext32, Plaintext64, PlaintextVector128,
+    PlaintextVector16, PlaintextVector32, PlaintextVector64,
+};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::entities::AbstractEntity;
+
+/// The step of the lifetime of an entity recorded by an [`AuditEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    /// The entity was created.
+    Creation,
+    /// The entity was destroyed.
+    Destruction,
+}
+
+/// An event of the lifetime of an entity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEvent {
+    /// The step of the lifetime of the entity.
+    pub action: AuditAction,
+    /// The name of the kind of the entity, for instance `"LweSecretKeyKind"`.
+    pub entity_kind: &'static str,
+    /// The full name of the type of the entity.
+    pub entity_type: &'static str,
+    /// The size of the data held by the entity, in bytes.
+    pub size: usize,
+    /// The time at which the event was recorded.
+    pub timestamp: SystemTime,
+    /// The full name of the type of the engine which recorded the event.
+    pub engine: &'static str,
+}
+
+/// A destination for the audit events of an engine.
+///
+/// Any closure taking an [`AuditEvent`] is a sink. The sink is called synchronously by the engine,
+/// and should hand the events over to some other place (a channel, a file, a logging system)
+/// without blocking for long.
+pub trait AuditSink: Send {
+    /// Records an event.
+    fn record(&mut self, event: AuditEvent);
+}
+
+impl<F> AuditSink for F
+where
+    F: FnMut(AuditEvent) + Send,
+{
+    fn record(&mut self, event: AuditEvent) {
+        self(event)
+    }
+}
+
+// Records the audit events of an engine in its sink, if any.
+#[derive(Default)]
+pub(crate) struct AuditLog {
+    sink: Option<Box<dyn AuditSink>>,
+}
+
+impl AuditLog {
+    pub(crate) fn set_sink(&mut self, sink: Box<dyn AuditSink>) {
+        self.sink = Some(sink);
+    }
+
+    pub(crate) fn clear_sink(&mut self) {
+        self.sink = None;
+    }
+
+    pub(crate) fn record<Engine, Entity>(&mut self, action: AuditAction, entity: &Entity)
+    where
+        Entity: AuditedEntity,
+    {
+        if let Some(sink) = self.sink.as_mut() {
+            sink.record(AuditEvent {
+                action,
+                entity_kind: short_type_name::<Entity::Kind>(),
+                entity_type: std::any::type_name::<Entity>(),
+                size: entity.audited_size(),
+                timestamp: SystemTime::now(),
+                engine: std::any::type_name::<Engine>(),
+            });
+        }
+    }
+}
+
+impl std::fmt::Debug for AuditLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditLog")
+            .field("sink", &self.sink.is_some())
+            .finish()
+    }
+}
+
+// Returns the name of a type, without its module path.
+fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+// Returns the size of the elements of a tensor-backed structure, in bytes.
+fn tensor_size<T: AsRefTensor>(value: &T) -> usize {
+    value.as_tensor().len() * size_of::<T::Element>()
+}
+
+/// An entity whose lifetime can be audited.
+pub(crate) trait AuditedEntity: AbstractEntity {
+    /// Returns the size of the data held by the entity, in bytes.
+    fn audited_size(&self) -> usize;
+}
+
+// Implements the trait for the entities wrapping a tensor-backed structure.
+macro_rules! implement_tensor_audited_entity {
+    ($($entity:ty),+ $(,)?) => {
+        $(
+            impl AuditedEntity for $entity {
+                fn audited_size(&self) -> usize {
+                    tensor_size(&self.0)
+                }
+            }
+        )+
+    };
+}
+
+implement_tensor_audited_entity! {
+    AlignedLweBootstrapKey32,
+    AlignedLweBootstrapKey64,
+    AlignedLweCiphertextVector32,
+    AlignedLweCiphertextVector64,
+    CleartextVector32,
+    CleartextVector64,
+    ColumnMajorLweKeyswitchKey32,
+    ColumnMajorLweKeyswitchKey64,
+    CyclicGlweCiphertext32,
+    CyclicGlweCiphertext64,
+    FourierGgswCiphertext32,
+    FourierGgswCiphertext64,
+    FourierGlweCiphertext32,
+    FourierGlweCiphertext64,
+    FourierLweBootstrapKey32,
+    FourierLweBootstrapKey64,
+    GgswCiphertext32,
+    GgswCiphertext64,
+    GgswSeededCiphertext32,
+    GgswSeededCiphertext64,
+    GlweAutomorphismKey32,
+    GlweAutomorphismKey64,
+    GlweCiphertext128,
+    GlweCiphertext16,
+    GlweCiphertext32,
+    GlweCiphertext64,
+    GlweCiphertextVector32,
+    GlweCiphertextVector64,
+    GlweRelinearizationKey32,
+    GlweRelinearizationKey64,
+    GlweSecretKey128,
+    GlweSecretKey16,
+    GlweSecretKey32,
+    GlweSecretKey64,
+    LweBootstrapKey32,
+    LweBootstrapKey64,
+    LweCiphertext128,
+    LweCiphertext16,
+    LweCiphertext32,
+    LweCiphertext64,
+    LweCiphertextTruncatedView32<'_>,
+    LweCiphertextVector32,
+    LweCiphertextVector64,
+    LweCiphertextVectorView32<'_>,
+    LweCiphertextVectorView64<'_>,
+    LweKeyswitchKey128,
+    LweKeyswitchKey16,
+    LweKeyswitchKey32,
+    LweKeyswitchKey64,
+    LweKeyswitchKeyBatch32,
+    LweKeyswitchKeyBatch64,
+    LwePhaseVector32,
+    LwePhaseVector64,
+    LwePublicKey32,
+    LwePublicKey64,
+    LweSecretKey128,
+    LweSecretKey16,
+    LweSecretKey32,
+    LweSecretKey64,
+    LweSeededBootstrapKey32,
+    LweSeededBootstrapKey64,
+    LweShrinkingKeyswitchKey32,
+    LweShrinkingKeyswitchKey64,
+    PackingKeyswitchKey32,
+    PackingKeyswitchKey64,
+    PlaintextVector128,
+    PlaintextVector16,
+    PlaintextVector32,
+    PlaintextVector64,
+}
+
+// Implements the trait for the entities wrapping a fixed-size structure.
+macro_rules! implement_sized_audited_entity {
+    ($($entity:ty),+ $(,)?) => {
+        $(
+            impl AuditedEntity for $entity {
+                fn audited_size(&self) -> usize {
+                    std::mem::size_of_val(&self.0)
+                }
+            }
+        )+
+    };
+}
+
+implement_sized_audited_entity! {
+    Cleartext32,
+    Cleartext64,
+    LweSeededCiphertext32,
+    LweSeededCiphertext64,
+    Plaintext128,
+    Plaintext16,
+    Plaintext32,
+    Plaintext64,
+}
+
+impl AuditedEntity for LweCiphertextJaggedVector32 {
+    fn audited_size(&self) -> usize {
+        tensor_size(&self.list) + self.row_offsets.len() * size_of::<usize>()
+    }
+}
+
+impl AuditedEntity for LweCiphertextJaggedVector64 {
+    fn audited_size(&self) -> usize {
+        tensor_size(&self.list) + self.row_offsets.len() * size_of::<usize>()
+    }
+}
+
+impl AuditedEntity for LweKeyswitchKeyDelta32 {
+    fn audited_size(&self) -> usize {
+        self.body_corrections.len() * size_of::<u32>()
+    }
+}
+
+impl AuditedEntity for LweKeyswitchKeyDelta64 {
+    fn audited_size(&self) -> usize {
+        self.body_corrections.len() * size_of::<u64>()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_wrapped_ciphertext_destruction_is_recorded_once() {
+        let mut engine = CoreEngine::new().unwrap();
+        let key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(4)).unwrap();
+        let plaintext = engine.create_plaintext(&(1_u64 << 60)).unwrap();
+        let ciphertext = engine
+            .encrypt_lwe_ciphertext(&key, &plaintext, Variance(2_f64.powf(-50.)))
+            .unwrap();
+        let metadata = EncodingMetadata {
+            delta: ScalingFactor::from_log2(60),
+            message_modulus: 4,
+            carry_modulus: 4,
+            degree: 1,
+        };
+        let tagged = TaggedCiphertext::new(ciphertext, metadata);
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let events_handle = events.clone();
+        engine.set_audit_sink(move |event: AuditEvent| events_handle.lock().unwrap().push(event));
+        engine.destroy(tagged).unwrap();
+        engine.clear_audit_sink();
+        engine.destroy(plaintext).unwrap();
+        engine.destroy(key).unwrap();
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].action, AuditAction::Destruction);
+        assert_eq!(events[0].entity_kind, "LweCiphertextKind");
+        assert_eq!(events[0].size, 5 * size_of::<u64>());
+    }
+}
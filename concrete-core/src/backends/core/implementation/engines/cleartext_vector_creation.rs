@@ -72,3 +72,75 @@ impl CleartextVectorCreationEngine<u64, CleartextVector64> for CoreEngine {
         CleartextVector64(ImplCleartextList::from_container(input.to_vec()))
     }
 }
+
+// Implements the engine for integer values narrower than the cleartexts, which are losslessly
+// widened to the precision of the cleartext vector.
+macro_rules! implement_widening_cleartext_vector_creation {
+    ($($(#[$attribute:meta])* $value:ty => $cleartext_vector:ident),+ $(,)?) => {
+        $(
+            $(#[$attribute])*
+            impl CleartextVectorCreationEngine<$value, $cleartext_vector> for CoreEngine {
+                fn create_cleartext_vector(
+                    &mut self,
+                    input: &[$value],
+                ) -> Result<$cleartext_vector, CleartextVectorCreationError<Self::EngineError>> {
+                    CleartextVectorCreationError::perform_generic_checks(input)?;
+                    Ok(unsafe { self.create_cleartext_vector_unchecked(input) })
+                }
+
+                unsafe fn create_cleartext_vector_unchecked(
+                    &mut self,
+                    input: &[$value],
+                ) -> $cleartext_vector {
+                    $cleartext_vector(ImplCleartextList::from_container(
+                        input.iter().copied().map(From::from).collect(),
+                    ))
+                }
+            }
+        )+
+    };
+}
+
+implement_widening_cleartext_vector_creation! {
+    /// # Description:
+    /// Implementation of [`CleartextVectorCreationEngine`] for [`CoreEngine`] that widens 8 bits
+    /// integers to 32 bits cleartexts.
+    ///
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::CleartextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![200_u8; 100];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext_vector: CleartextVector32 = engine.create_cleartext_vector(&input)?;
+    /// #
+    /// assert_eq!(cleartext_vector.cleartext_count(), CleartextCount(100));
+    /// let retrieved: Vec<u32> = engine.retrieve_cleartext_vector(&cleartext_vector)?;
+    /// assert_eq!(retrieved, vec![200_u32; 100]);
+    /// engine.destroy(cleartext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    u8 => CleartextVector32,
+    /// # Description:
+    /// Implementation of [`CleartextVectorCreationEngine`] for [`CoreEngine`] that widens 16 bits
+    /// integers to 32 bits cleartexts.
+    u16 => CleartextVector32,
+    /// # Description:
+    /// Implementation of [`CleartextVectorCreationEngine`] for [`CoreEngine`] that widens 8 bits
+    /// integers to 64 bits cleartexts.
+    u8 => CleartextVector64,
+    /// # Description:
+    /// Implementation of [`CleartextVectorCreationEngine`] for [`CoreEngine`] that widens 16 bits
+    /// integers to 64 bits cleartexts.
+    u16 => CleartextVector64,
+    /// # Description:
+    /// Implementation of [`CleartextVectorCreationEngine`] for [`CoreEngine`] that widens 32 bits
+    /// integers to 64 bits cleartexts.
+    u32 => CleartextVector64,
+}
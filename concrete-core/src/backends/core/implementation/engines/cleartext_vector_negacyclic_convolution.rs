@@ -0,0 +1,180 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{CleartextVector32, CleartextVector64};
+use crate::backends::core::private::crypto::encoding::CleartextList as ImplCleartextList;
+use crate::backends::core::private::math::polynomial::PolynomialList;
+use crate::backends::core::private::math::tensor::{AsRefTensor, IntoTensor};
+use crate::specification::engines::{
+    CleartextVectorNegacyclicConvolutionEngine, CleartextVectorNegacyclicConvolutionError,
+};
+use crate::specification::entities::CleartextVectorEntity;
+use concrete_commons::parameters::{PolynomialCount, PolynomialSize};
+
+/// # Description:
+/// Implementation of [`CleartextVectorNegacyclicConvolutionEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl
+    CleartextVectorNegacyclicConvolutionEngine<
+        CleartextVector32,
+        CleartextVector32,
+        CleartextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PolynomialSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Two polynomials of size 4: (1 + X) * (X^3) = X^3 + X^4 = -1 + X^3 mod X^4 + 1,
+    /// // and 2 * (3 + X^2) = 6 + 2X^2.
+    /// let input1 = vec![1_u32, 1, 0, 0, 2, 0, 0, 0];
+    /// let input2 = vec![0_u32, 0, 0, 1, 3, 0, 1, 0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext_vector1: CleartextVector32 = engine.create_cleartext_vector(&input1)?;
+    /// let cleartext_vector2: CleartextVector32 = engine.create_cleartext_vector(&input2)?;
+    /// let product = engine.convolve_cleartext_vector(
+    ///     &cleartext_vector1,
+    ///     &cleartext_vector2,
+    ///     PolynomialSize(4),
+    /// )?;
+    /// #
+    /// let retrieved: Vec<u32> = engine.retrieve_cleartext_vector(&product)?;
+    /// assert_eq!(retrieved, vec![u32::MAX, 0, 0, 1, 6, 0, 2, 0]);
+    ///
+    /// engine.destroy(cleartext_vector1)?;
+    /// engine.destroy(cleartext_vector2)?;
+    /// engine.destroy(product)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convolve_cleartext_vector(
+        &mut self,
+        input1: &CleartextVector32,
+        input2: &CleartextVector32,
+        polynomial_size: PolynomialSize,
+    ) -> Result<CleartextVector32, CleartextVectorNegacyclicConvolutionError<Self::EngineError>>
+    {
+        CleartextVectorNegacyclicConvolutionError::perform_generic_checks(
+            input1,
+            input2,
+            polynomial_size,
+        )?;
+        Ok(unsafe { self.convolve_cleartext_vector_unchecked(input1, input2, polynomial_size) })
+    }
+
+    unsafe fn convolve_cleartext_vector_unchecked(
+        &mut self,
+        input1: &CleartextVector32,
+        input2: &CleartextVector32,
+        polynomial_size: PolynomialSize,
+    ) -> CleartextVector32 {
+        let polynomial_count = PolynomialCount(input1.cleartext_count().0 / polynomial_size.0);
+        let lhs = PolynomialList::from_container(
+            input1.0.as_tensor().as_container().as_slice(),
+            polynomial_size,
+        );
+        let rhs = PolynomialList::from_container(
+            input2.0.as_tensor().as_container().as_slice(),
+            polynomial_size,
+        );
+        let mut output = PolynomialList::allocate(0_u32, polynomial_count, polynomial_size);
+        for ((mut out, lhs), rhs) in output
+            .polynomial_iter_mut()
+            .zip(lhs.polynomial_iter())
+            .zip(rhs.polynomial_iter())
+        {
+            out.fill_with_wrapping_mul(&lhs, &rhs);
+        }
+        CleartextVector32(ImplCleartextList::from_container(
+            output.into_tensor().into_container(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextVectorNegacyclicConvolutionEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl
+    CleartextVectorNegacyclicConvolutionEngine<
+        CleartextVector64,
+        CleartextVector64,
+        CleartextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PolynomialSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Two polynomials of size 4: (1 + X) * (X^3) = X^3 + X^4 = -1 + X^3 mod X^4 + 1,
+    /// // and 2 * (3 + X^2) = 6 + 2X^2.
+    /// let input1 = vec![1_u64, 1, 0, 0, 2, 0, 0, 0];
+    /// let input2 = vec![0_u64, 0, 0, 1, 3, 0, 1, 0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext_vector1: CleartextVector64 = engine.create_cleartext_vector(&input1)?;
+    /// let cleartext_vector2: CleartextVector64 = engine.create_cleartext_vector(&input2)?;
+    /// let product = engine.convolve_cleartext_vector(
+    ///     &cleartext_vector1,
+    ///     &cleartext_vector2,
+    ///     PolynomialSize(4),
+    /// )?;
+    /// #
+    /// let retrieved: Vec<u64> = engine.retrieve_cleartext_vector(&product)?;
+    /// assert_eq!(retrieved, vec![u64::MAX, 0, 0, 1, 6, 0, 2, 0]);
+    ///
+    /// engine.destroy(cleartext_vector1)?;
+    /// engine.destroy(cleartext_vector2)?;
+    /// engine.destroy(product)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convolve_cleartext_vector(
+        &mut self,
+        input1: &CleartextVector64,
+        input2: &CleartextVector64,
+        polynomial_size: PolynomialSize,
+    ) -> Result<CleartextVector64, CleartextVectorNegacyclicConvolutionError<Self::EngineError>>
+    {
+        CleartextVectorNegacyclicConvolutionError::perform_generic_checks(
+            input1,
+            input2,
+            polynomial_size,
+        )?;
+        Ok(unsafe { self.convolve_cleartext_vector_unchecked(input1, input2, polynomial_size) })
+    }
+
+    unsafe fn convolve_cleartext_vector_unchecked(
+        &mut self,
+        input1: &CleartextVector64,
+        input2: &CleartextVector64,
+        polynomial_size: PolynomialSize,
+    ) -> CleartextVector64 {
+        let polynomial_count = PolynomialCount(input1.cleartext_count().0 / polynomial_size.0);
+        let lhs = PolynomialList::from_container(
+            input1.0.as_tensor().as_container().as_slice(),
+            polynomial_size,
+        );
+        let rhs = PolynomialList::from_container(
+            input2.0.as_tensor().as_container().as_slice(),
+            polynomial_size,
+        );
+        let mut output = PolynomialList::allocate(0_u64, polynomial_count, polynomial_size);
+        for ((mut out, lhs), rhs) in output
+            .polynomial_iter_mut()
+            .zip(lhs.polynomial_iter())
+            .zip(rhs.polynomial_iter())
+        {
+            out.fill_with_wrapping_mul(&lhs, &rhs);
+        }
+        CleartextVector64(ImplCleartextList::from_container(
+            output.into_tensor().into_container(),
+        ))
+    }
+}
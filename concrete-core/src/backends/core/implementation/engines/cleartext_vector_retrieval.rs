@@ -1,9 +1,16 @@
-use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::engines::{CoreEngine, CoreError};
 use crate::backends::core::implementation::entities::{CleartextVector32, CleartextVector64};
 use crate::backends::core::private::math::tensor::AsRefTensor;
 use crate::specification::engines::{
     CleartextVectorRetrievalEngine, CleartextVectorRetrievalError,
 };
+use std::convert::TryFrom;
+
+impl From<CoreError> for CleartextVectorRetrievalError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
 
 /// # Description:
 /// Implementation of [`CleartextVectorRetrievalEngine`] for [`CoreEngine`] that operates on 64 bits
@@ -80,3 +87,82 @@ impl CleartextVectorRetrievalEngine<CleartextVector64, u64> for CoreEngine {
         cleartext.0.as_tensor().as_container().to_vec()
     }
 }
+
+// Implements the engine for integer values narrower than the cleartexts. The checked entry point
+// returns a `CoreError::ValueOutOfRange` error if a cleartext does not fit in the value type,
+// while the unchecked one truncates it.
+macro_rules! implement_narrowing_cleartext_vector_retrieval {
+    ($($(#[$attribute:meta])* $cleartext_vector:ident => $value:ty),+ $(,)?) => {
+        $(
+            $(#[$attribute])*
+            impl CleartextVectorRetrievalEngine<$cleartext_vector, $value> for CoreEngine {
+                fn retrieve_cleartext_vector(
+                    &mut self,
+                    cleartext: &$cleartext_vector,
+                ) -> Result<Vec<$value>, CleartextVectorRetrievalError<Self::EngineError>> {
+                    cleartext
+                        .0
+                        .as_tensor()
+                        .iter()
+                        .map(|c| <$value>::try_from(*c).map_err(|_| CoreError::ValueOutOfRange))
+                        .collect::<Result<_, _>>()
+                        .map_err(Into::into)
+                }
+
+                unsafe fn retrieve_cleartext_vector_unchecked(
+                    &mut self,
+                    cleartext: &$cleartext_vector,
+                ) -> Vec<$value> {
+                    cleartext.0.as_tensor().iter().map(|c| *c as $value).collect()
+                }
+            }
+        )+
+    };
+}
+
+implement_narrowing_cleartext_vector_retrieval! {
+    /// # Description:
+    /// Implementation of [`CleartextVectorRetrievalEngine`] for [`CoreEngine`] that narrows 32
+    /// bits cleartexts to 8 bits integers.
+    ///
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let input = vec![3_u32; 100];
+    /// let cleartext_vector: CleartextVector32 = engine.create_cleartext_vector(&input)?;
+    /// let retrieved: Vec<u8> = engine.retrieve_cleartext_vector(&cleartext_vector)?;
+    /// assert_eq!(retrieved, vec![3_u8; 100]);
+    ///
+    /// // A cleartext which does not fit in 8 bits can not be retrieved as such.
+    /// let too_large: CleartextVector32 = engine.create_cleartext_vector(&vec![256_u32; 100])?;
+    /// let retrieved: Result<Vec<u8>, _> = engine.retrieve_cleartext_vector(&too_large);
+    /// assert!(retrieved.is_err());
+    ///
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(too_large)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    CleartextVector32 => u8,
+    /// # Description:
+    /// Implementation of [`CleartextVectorRetrievalEngine`] for [`CoreEngine`] that narrows 32
+    /// bits cleartexts to 16 bits integers.
+    CleartextVector32 => u16,
+    /// # Description:
+    /// Implementation of [`CleartextVectorRetrievalEngine`] for [`CoreEngine`] that narrows 64
+    /// bits cleartexts to 8 bits integers.
+    CleartextVector64 => u8,
+    /// # Description:
+    /// Implementation of [`CleartextVectorRetrievalEngine`] for [`CoreEngine`] that narrows 64
+    /// bits cleartexts to 16 bits integers.
+    CleartextVector64 => u16,
+    /// # Description:
+    /// Implementation of [`CleartextVectorRetrievalEngine`] for [`CoreEngine`] that narrows 64
+    /// bits cleartexts to 32 bits integers.
+    CleartextVector64 => u32,
+}
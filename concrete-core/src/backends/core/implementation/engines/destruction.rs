@@ -1,13 +1,27 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    Cleartext32, Cleartext64, CleartextVector32, CleartextVector64, FourierGgswCiphertext32,
-    FourierGgswCiphertext64, FourierGlweCiphertext32, FourierGlweCiphertext64,
-    FourierLweBootstrapKey32, FourierLweBootstrapKey64, GgswCiphertext32, GgswCiphertext64,
-    GlweCiphertext32, GlweCiphertext64, GlweCiphertextVector32, GlweCiphertextVector64,
-    GlweSecretKey32, GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64, LweCiphertext32,
-    LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64, LweKeyswitchKey32,
-    LweKeyswitchKey64, LweSecretKey32, LweSecretKey64, PackingKeyswitchKey32,
-    PackingKeyswitchKey64, Plaintext32, Plaintext64, PlaintextVector32, PlaintextVector64,
+    AccumulatingCiphertext, AlignedLweBootstrapKey32, AlignedLweBootstrapKey64,
+    AlignedLweCiphertextVector32, AlignedLweCiphertextVector64, Cleartext32, Cleartext64,
+    CleartextVector32, CleartextVector64, ColumnMajorLweKeyswitchKey32,
+    ColumnMajorLweKeyswitchKey64, CyclicGlweCiphertext32, CyclicGlweCiphertext64,
+    FourierGgswCiphertext32, FourierGgswCiphertext64, FourierGlweCiphertext32,
+    FourierGlweCiphertext64, FourierLweBootstrapKey32, FourierLweBootstrapKey64, GgswCiphertext32,
+    GgswCiphertext64, GgswSeededCiphertext32, GgswSeededCiphertext64, GlweAutomorphismKey32,
+    GlweAutomorphismKey64, GlweCiphertext128, GlweCiphertext16, GlweCiphertext32, GlweCiphertext64,
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweRelinearizationKey32,
+    GlweRelinearizationKey64, GlweSecretKey128, GlweSecretKey16, GlweSecretKey32, GlweSecretKey64,
+    LweBootstrapKey32, LweBootstrapKey64, LweCiphertext128, LweCiphertext16, LweCiphertext32,
+    LweCiphertext64, LweCiphertextJaggedVector32, LweCiphertextJaggedVector64,
+    LweCiphertextTruncatedView32, LweCiphertextVector32, LweCiphertextVector64,
+    LweCiphertextVectorView32, LweCiphertextVectorView64, LweKeyswitchKey128, LweKeyswitchKey16,
+    LweKeyswitchKey32, LweKeyswitchKey64, LweKeyswitchKeyBatch32, LweKeyswitchKeyBatch64,
+    LweKeyswitchKeyDelta32, LweKeyswitchKeyDelta64, LwePhaseVector32, LwePhaseVector64,
+    LwePublicKey32, LwePublicKey64, LweSecretKey128, LweSecretKey16, LweSecretKey32,
+    LweSecretKey64, LweSeededBootstrapKey32, LweSeededBootstrapKey64, LweSeededCiphertext32,
+    LweSeededCiphertext64, LweShrinkingKeyswitchKey32, LweShrinkingKeyswitchKey64,
+    PackingKeyswitchKey32, PackingKeyswitchKey64, Plaintext128, Plaintext16, Plaintext32,
+    Plaintext64, PlaintextVector128, PlaintextVector16, PlaintextVector32, PlaintextVector64,
+    TaggedCiphertext,
 };
 use crate::backends::core::private::math::tensor::AsMutTensor;
 use crate::specification::engines::{DestructionEngine, DestructionError};
@@ -18,7 +32,9 @@ impl DestructionEngine<Cleartext32> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: Cleartext32) {}
+    unsafe fn destroy_unchecked(&mut self, entity: Cleartext32) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<Cleartext64> for CoreEngine {
@@ -27,7 +43,9 @@ impl DestructionEngine<Cleartext64> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: Cleartext64) {}
+    unsafe fn destroy_unchecked(&mut self, entity: Cleartext64) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<CleartextVector32> for CoreEngine {
@@ -39,7 +57,9 @@ impl DestructionEngine<CleartextVector32> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: CleartextVector32) {}
+    unsafe fn destroy_unchecked(&mut self, entity: CleartextVector32) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<CleartextVector64> for CoreEngine {
@@ -51,7 +71,20 @@ impl DestructionEngine<CleartextVector64> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: CleartextVector64) {}
+    unsafe fn destroy_unchecked(&mut self, entity: CleartextVector64) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<Plaintext16> for CoreEngine {
+    fn destroy(&mut self, entity: Plaintext16) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: Plaintext16) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<Plaintext32> for CoreEngine {
@@ -60,7 +93,9 @@ impl DestructionEngine<Plaintext32> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: Plaintext32) {}
+    unsafe fn destroy_unchecked(&mut self, entity: Plaintext32) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<Plaintext64> for CoreEngine {
@@ -69,7 +104,34 @@ impl DestructionEngine<Plaintext64> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: Plaintext64) {}
+    unsafe fn destroy_unchecked(&mut self, entity: Plaintext64) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<Plaintext128> for CoreEngine {
+    fn destroy(&mut self, entity: Plaintext128) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: Plaintext128) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<PlaintextVector16> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: PlaintextVector16,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: PlaintextVector16) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<PlaintextVector32> for CoreEngine {
@@ -81,7 +143,9 @@ impl DestructionEngine<PlaintextVector32> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: PlaintextVector32) {}
+    unsafe fn destroy_unchecked(&mut self, entity: PlaintextVector32) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<PlaintextVector64> for CoreEngine {
@@ -93,7 +157,37 @@ impl DestructionEngine<PlaintextVector64> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: PlaintextVector64) {}
+    unsafe fn destroy_unchecked(&mut self, entity: PlaintextVector64) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<PlaintextVector128> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: PlaintextVector128,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: PlaintextVector128) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<LweCiphertext16> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweCiphertext16,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweCiphertext16) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<LweCiphertext32> for CoreEngine {
@@ -105,7 +199,9 @@ impl DestructionEngine<LweCiphertext32> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: LweCiphertext32) {}
+    unsafe fn destroy_unchecked(&mut self, entity: LweCiphertext32) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<LweCiphertext64> for CoreEngine {
@@ -117,7 +213,65 @@ impl DestructionEngine<LweCiphertext64> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: LweCiphertext64) {}
+    unsafe fn destroy_unchecked(&mut self, entity: LweCiphertext64) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<LweCiphertext128> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweCiphertext128,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweCiphertext128) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<LweCiphertextJaggedVector32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweCiphertextJaggedVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweCiphertextJaggedVector32) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<LweCiphertextJaggedVector64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweCiphertextJaggedVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweCiphertextJaggedVector64) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl<'a> DestructionEngine<LweCiphertextTruncatedView32<'a>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweCiphertextTruncatedView32<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweCiphertextTruncatedView32<'a>) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<LweCiphertextVector32> for CoreEngine {
@@ -129,7 +283,9 @@ impl DestructionEngine<LweCiphertextVector32> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: LweCiphertextVector32) {}
+    unsafe fn destroy_unchecked(&mut self, entity: LweCiphertextVector32) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<LweCiphertextVector64> for CoreEngine {
@@ -141,7 +297,79 @@ impl DestructionEngine<LweCiphertextVector64> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: LweCiphertextVector64) {}
+    unsafe fn destroy_unchecked(&mut self, entity: LweCiphertextVector64) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<GlweAutomorphismKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweAutomorphismKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: GlweAutomorphismKey32) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<GlweAutomorphismKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweAutomorphismKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: GlweAutomorphismKey64) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<GlweRelinearizationKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweRelinearizationKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: GlweRelinearizationKey32) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<GlweRelinearizationKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweRelinearizationKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: GlweRelinearizationKey64) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<GlweCiphertext16> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweCiphertext16,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: GlweCiphertext16) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<GlweCiphertext32> for CoreEngine {
@@ -153,7 +381,9 @@ impl DestructionEngine<GlweCiphertext32> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: GlweCiphertext32) {}
+    unsafe fn destroy_unchecked(&mut self, entity: GlweCiphertext32) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<GlweCiphertext64> for CoreEngine {
@@ -165,7 +395,51 @@ impl DestructionEngine<GlweCiphertext64> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: GlweCiphertext64) {}
+    unsafe fn destroy_unchecked(&mut self, entity: GlweCiphertext64) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<GlweCiphertext128> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweCiphertext128,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: GlweCiphertext128) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<CyclicGlweCiphertext32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: CyclicGlweCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: CyclicGlweCiphertext32) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<CyclicGlweCiphertext64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: CyclicGlweCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: CyclicGlweCiphertext64) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<FourierGlweCiphertext32> for CoreEngine {
@@ -177,7 +451,9 @@ impl DestructionEngine<FourierGlweCiphertext32> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: FourierGlweCiphertext32) {}
+    unsafe fn destroy_unchecked(&mut self, entity: FourierGlweCiphertext32) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<FourierGlweCiphertext64> for CoreEngine {
@@ -189,7 +465,9 @@ impl DestructionEngine<FourierGlweCiphertext64> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: FourierGlweCiphertext64) {}
+    unsafe fn destroy_unchecked(&mut self, entity: FourierGlweCiphertext64) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<GlweCiphertextVector32> for CoreEngine {
@@ -201,7 +479,9 @@ impl DestructionEngine<GlweCiphertextVector32> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: GlweCiphertextVector32) {}
+    unsafe fn destroy_unchecked(&mut self, entity: GlweCiphertextVector32) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<GlweCiphertextVector64> for CoreEngine {
@@ -213,7 +493,9 @@ impl DestructionEngine<GlweCiphertextVector64> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: GlweCiphertextVector64) {}
+    unsafe fn destroy_unchecked(&mut self, entity: GlweCiphertextVector64) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<GgswCiphertext32> for CoreEngine {
@@ -225,7 +507,9 @@ impl DestructionEngine<GgswCiphertext32> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: GgswCiphertext32) {}
+    unsafe fn destroy_unchecked(&mut self, entity: GgswCiphertext32) {
+        self.audit_destruction(&entity);
+    }
 }
 
 impl DestructionEngine<GgswCiphertext64> for CoreEngine {
@@ -237,185 +521,699 @@ impl DestructionEngine<GgswCiphertext64> for CoreEngine {
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: GgswCiphertext64) {}
+    unsafe fn destroy_unchecked(&mut self, entity: GgswCiphertext64) {
+        self.audit_destruction(&entity);
+    }
 }
 
-impl DestructionEngine<FourierGgswCiphertext32> for CoreEngine {
+impl DestructionEngine<GgswSeededCiphertext32> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: FourierGgswCiphertext32,
+        entity: GgswSeededCiphertext32,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: FourierGgswCiphertext32) {}
+    unsafe fn destroy_unchecked(&mut self, entity: GgswSeededCiphertext32) {
+        self.audit_destruction(&entity);
+    }
 }
 
-impl DestructionEngine<FourierGgswCiphertext64> for CoreEngine {
+impl DestructionEngine<GgswSeededCiphertext64> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: FourierGgswCiphertext64,
+        entity: GgswSeededCiphertext64,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: FourierGgswCiphertext64) {}
+    unsafe fn destroy_unchecked(&mut self, entity: GgswSeededCiphertext64) {
+        self.audit_destruction(&entity);
+    }
 }
 
-impl DestructionEngine<LweBootstrapKey32> for CoreEngine {
+impl DestructionEngine<LweSeededCiphertext32> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: LweBootstrapKey32,
+        entity: LweSeededCiphertext32,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: LweBootstrapKey32) {}
+    unsafe fn destroy_unchecked(&mut self, entity: LweSeededCiphertext32) {
+        self.audit_destruction(&entity);
+    }
 }
 
-impl DestructionEngine<LweBootstrapKey64> for CoreEngine {
+impl DestructionEngine<LweSeededCiphertext64> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: LweBootstrapKey64,
+        entity: LweSeededCiphertext64,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: LweBootstrapKey64) {}
+    unsafe fn destroy_unchecked(&mut self, entity: LweSeededCiphertext64) {
+        self.audit_destruction(&entity);
+    }
 }
 
-impl DestructionEngine<FourierLweBootstrapKey32> for CoreEngine {
+impl DestructionEngine<LweSeededBootstrapKey32> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: FourierLweBootstrapKey32,
+        entity: LweSeededBootstrapKey32,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: FourierLweBootstrapKey32) {}
+    unsafe fn destroy_unchecked(&mut self, entity: LweSeededBootstrapKey32) {
+        self.audit_destruction(&entity);
+    }
 }
 
-impl DestructionEngine<FourierLweBootstrapKey64> for CoreEngine {
+impl DestructionEngine<LweSeededBootstrapKey64> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: FourierLweBootstrapKey64,
+        entity: LweSeededBootstrapKey64,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: FourierLweBootstrapKey64) {}
+    unsafe fn destroy_unchecked(&mut self, entity: LweSeededBootstrapKey64) {
+        self.audit_destruction(&entity);
+    }
 }
 
-impl DestructionEngine<LweKeyswitchKey32> for CoreEngine {
+impl DestructionEngine<FourierGgswCiphertext32> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: LweKeyswitchKey32,
+        entity: FourierGgswCiphertext32,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: LweKeyswitchKey32) {}
+    unsafe fn destroy_unchecked(&mut self, entity: FourierGgswCiphertext32) {
+        self.audit_destruction(&entity);
+    }
 }
 
-impl DestructionEngine<LweKeyswitchKey64> for CoreEngine {
+impl DestructionEngine<FourierGgswCiphertext64> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: LweKeyswitchKey64,
+        entity: FourierGgswCiphertext64,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: LweKeyswitchKey64) {}
+    unsafe fn destroy_unchecked(&mut self, entity: FourierGgswCiphertext64) {
+        self.audit_destruction(&entity);
+    }
 }
 
-impl DestructionEngine<LweSecretKey32> for CoreEngine {
+impl DestructionEngine<LweBootstrapKey32> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: LweSecretKey32,
+        entity: LweBootstrapKey32,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, entity: LweSecretKey32) {
-        let mut entity = entity;
-        entity.0.as_mut_tensor().fill_with_element(0u32);
+    unsafe fn destroy_unchecked(&mut self, entity: LweBootstrapKey32) {
+        self.audit_destruction(&entity);
     }
 }
 
-impl DestructionEngine<LweSecretKey64> for CoreEngine {
+impl DestructionEngine<LweBootstrapKey64> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: LweSecretKey64,
+        entity: LweBootstrapKey64,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, entity: LweSecretKey64) {
-        let mut entity = entity;
-        entity.0.as_mut_tensor().fill_with_element(0u64);
+    unsafe fn destroy_unchecked(&mut self, entity: LweBootstrapKey64) {
+        self.audit_destruction(&entity);
     }
 }
 
-impl DestructionEngine<GlweSecretKey32> for CoreEngine {
+impl DestructionEngine<FourierLweBootstrapKey32> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: GlweSecretKey32,
+        entity: FourierLweBootstrapKey32,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, entity: GlweSecretKey32) {
-        let mut entity = entity;
-        entity.0.as_mut_tensor().fill_with_element(0u32);
+    unsafe fn destroy_unchecked(&mut self, entity: FourierLweBootstrapKey32) {
+        self.audit_destruction(&entity);
     }
 }
 
-impl DestructionEngine<GlweSecretKey64> for CoreEngine {
+impl DestructionEngine<FourierLweBootstrapKey64> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: GlweSecretKey64,
+        entity: FourierLweBootstrapKey64,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, entity: GlweSecretKey64) {
-        let mut entity = entity;
-        entity.0.as_mut_tensor().fill_with_element(0u64);
+    unsafe fn destroy_unchecked(&mut self, entity: FourierLweBootstrapKey64) {
+        self.audit_destruction(&entity);
     }
 }
 
-impl DestructionEngine<PackingKeyswitchKey32> for CoreEngine {
+impl DestructionEngine<LweKeyswitchKey16> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: PackingKeyswitchKey32,
+        entity: LweKeyswitchKey16,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: PackingKeyswitchKey32) {}
+    unsafe fn destroy_unchecked(&mut self, entity: LweKeyswitchKey16) {
+        self.audit_destruction(&entity);
+    }
 }
 
-impl DestructionEngine<PackingKeyswitchKey64> for CoreEngine {
+impl DestructionEngine<LweKeyswitchKey32> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: PackingKeyswitchKey64,
+        entity: LweKeyswitchKey32,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, _entity: PackingKeyswitchKey64) {}
+    unsafe fn destroy_unchecked(&mut self, entity: LweKeyswitchKey32) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<LweKeyswitchKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweKeyswitchKey64) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<LweKeyswitchKey128> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweKeyswitchKey128,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweKeyswitchKey128) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<ColumnMajorLweKeyswitchKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: ColumnMajorLweKeyswitchKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: ColumnMajorLweKeyswitchKey32) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<ColumnMajorLweKeyswitchKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: ColumnMajorLweKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: ColumnMajorLweKeyswitchKey64) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<LweKeyswitchKeyBatch32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweKeyswitchKeyBatch32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweKeyswitchKeyBatch32) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<LweKeyswitchKeyBatch64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweKeyswitchKeyBatch64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweKeyswitchKeyBatch64) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<LweKeyswitchKeyDelta32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweKeyswitchKeyDelta32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweKeyswitchKeyDelta32) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<LweKeyswitchKeyDelta64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweKeyswitchKeyDelta64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweKeyswitchKeyDelta64) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<LwePhaseVector32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LwePhaseVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LwePhaseVector32) {
+        self.audit_destruction(&entity);
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u32);
+    }
+}
+
+impl DestructionEngine<LwePhaseVector64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LwePhaseVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LwePhaseVector64) {
+        self.audit_destruction(&entity);
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u64);
+    }
+}
+
+impl DestructionEngine<LweShrinkingKeyswitchKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweShrinkingKeyswitchKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweShrinkingKeyswitchKey32) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<LweShrinkingKeyswitchKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweShrinkingKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweShrinkingKeyswitchKey64) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<LwePublicKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LwePublicKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LwePublicKey32) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<LwePublicKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LwePublicKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LwePublicKey64) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<LweSecretKey16> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweSecretKey16,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweSecretKey16) {
+        self.audit_destruction(&entity);
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u16);
+    }
+}
+
+impl DestructionEngine<LweSecretKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweSecretKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweSecretKey32) {
+        self.audit_destruction(&entity);
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u32);
+    }
+}
+
+impl DestructionEngine<LweSecretKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweSecretKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweSecretKey64) {
+        self.audit_destruction(&entity);
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u64);
+    }
+}
+
+impl DestructionEngine<LweSecretKey128> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweSecretKey128,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweSecretKey128) {
+        self.audit_destruction(&entity);
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u128);
+    }
+}
+
+impl DestructionEngine<GlweSecretKey16> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweSecretKey16,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: GlweSecretKey16) {
+        self.audit_destruction(&entity);
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u16);
+    }
+}
+
+impl DestructionEngine<GlweSecretKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweSecretKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: GlweSecretKey32) {
+        self.audit_destruction(&entity);
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u32);
+    }
+}
+
+impl DestructionEngine<GlweSecretKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweSecretKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: GlweSecretKey64) {
+        self.audit_destruction(&entity);
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u64);
+    }
+}
+
+impl DestructionEngine<GlweSecretKey128> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweSecretKey128,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: GlweSecretKey128) {
+        self.audit_destruction(&entity);
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u128);
+    }
+}
+
+impl DestructionEngine<PackingKeyswitchKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: PackingKeyswitchKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: PackingKeyswitchKey32) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<PackingKeyswitchKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: PackingKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: PackingKeyswitchKey64) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<TaggedCiphertext<LweCiphertext32>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: TaggedCiphertext<LweCiphertext32>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: TaggedCiphertext<LweCiphertext32>) {
+        self.destroy_unchecked(entity.ciphertext);
+    }
+}
+
+impl DestructionEngine<TaggedCiphertext<LweCiphertext64>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: TaggedCiphertext<LweCiphertext64>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: TaggedCiphertext<LweCiphertext64>) {
+        self.destroy_unchecked(entity.ciphertext);
+    }
+}
+
+impl DestructionEngine<AccumulatingCiphertext<LweCiphertext32>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: AccumulatingCiphertext<LweCiphertext32>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: AccumulatingCiphertext<LweCiphertext32>) {
+        self.destroy_unchecked(entity.ciphertext);
+    }
+}
+
+impl DestructionEngine<AccumulatingCiphertext<LweCiphertext64>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: AccumulatingCiphertext<LweCiphertext64>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: AccumulatingCiphertext<LweCiphertext64>) {
+        self.destroy_unchecked(entity.ciphertext);
+    }
+}
+
+impl DestructionEngine<AlignedLweCiphertextVector32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: AlignedLweCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: AlignedLweCiphertextVector32) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<AlignedLweCiphertextVector64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: AlignedLweCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: AlignedLweCiphertextVector64) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl<'a> DestructionEngine<LweCiphertextVectorView32<'a>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweCiphertextVectorView32<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweCiphertextVectorView32<'a>) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl<'a> DestructionEngine<LweCiphertextVectorView64<'a>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweCiphertextVectorView64<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweCiphertextVectorView64<'a>) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<AlignedLweBootstrapKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: AlignedLweBootstrapKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: AlignedLweBootstrapKey32) {
+        self.audit_destruction(&entity);
+    }
+}
+
+impl DestructionEngine<AlignedLweBootstrapKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: AlignedLweBootstrapKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: AlignedLweBootstrapKey64) {
+        self.audit_destruction(&entity);
+    }
 }
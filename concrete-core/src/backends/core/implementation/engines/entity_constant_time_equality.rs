@@ -0,0 +1,99 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    CyclicGlweCiphertext32, CyclicGlweCiphertext64, GgswCiphertext32, GgswCiphertext64,
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextVector32, GlweCiphertextVector64,
+    GlweSecretKey32, GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64, LweCiphertext32,
+    LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64, LweKeyswitchKey32,
+    LweKeyswitchKey64, LweSecretKey32, LweSecretKey64,
+};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    EntityConstantTimeEqualityEngine, EntityConstantTimeEqualityError,
+};
+use crate::specification::entities::{
+    GgswCiphertextEntity, GlweCiphertextEntity, GlweCiphertextVectorEntity, GlweSecretKeyEntity,
+    LweBootstrapKeyEntity, LweCiphertextEntity, LweCiphertextVectorEntity, LweKeyswitchKeyEntity,
+    LweSecretKeyEntity,
+};
+
+// Implements the engine for entities whose structural parameters are given by the listed
+// methods. The parameters are public, and are compared before the content of the entities.
+macro_rules! implement_entity_constant_time_equality {
+    ($($entity:ident => ($($parameter:ident),+)),+ $(,)?) => {
+        $(
+            impl EntityConstantTimeEqualityEngine<$entity> for CoreEngine {
+                fn constant_time_eq(
+                    &mut self,
+                    lhs: &$entity,
+                    rhs: &$entity,
+                ) -> Result<bool, EntityConstantTimeEqualityError<Self::EngineError>> {
+                    Ok(unsafe { self.constant_time_eq_unchecked(lhs, rhs) })
+                }
+
+                unsafe fn constant_time_eq_unchecked(
+                    &mut self,
+                    lhs: &$entity,
+                    rhs: &$entity,
+                ) -> bool {
+                    $(lhs.$parameter() == rhs.$parameter() &&)+
+                        lhs.0.as_tensor().constant_time_eq(rhs.0.as_tensor())
+                }
+            }
+        )+
+    };
+}
+
+implement_entity_constant_time_equality! {
+    LweSecretKey32 => (lwe_dimension),
+    LweSecretKey64 => (lwe_dimension),
+    GlweSecretKey32 => (glwe_dimension, polynomial_size),
+    GlweSecretKey64 => (glwe_dimension, polynomial_size),
+    LweCiphertext32 => (lwe_dimension),
+    LweCiphertext64 => (lwe_dimension),
+    LweCiphertextVector32 => (lwe_dimension, lwe_ciphertext_count),
+    LweCiphertextVector64 => (lwe_dimension, lwe_ciphertext_count),
+    GlweCiphertext32 => (glwe_dimension, polynomial_size),
+    GlweCiphertext64 => (glwe_dimension, polynomial_size),
+    CyclicGlweCiphertext32 => (glwe_dimension, polynomial_size),
+    CyclicGlweCiphertext64 => (glwe_dimension, polynomial_size),
+    GlweCiphertextVector32 => (glwe_dimension, polynomial_size, glwe_ciphertext_count),
+    GlweCiphertextVector64 => (glwe_dimension, polynomial_size, glwe_ciphertext_count),
+    GgswCiphertext32 => (
+        glwe_dimension,
+        polynomial_size,
+        decomposition_base_log,
+        decomposition_level_count
+    ),
+    GgswCiphertext64 => (
+        glwe_dimension,
+        polynomial_size,
+        decomposition_base_log,
+        decomposition_level_count
+    ),
+    LweKeyswitchKey32 => (
+        input_lwe_dimension,
+        output_lwe_dimension,
+        decomposition_base_log,
+        decomposition_level_count
+    ),
+    LweKeyswitchKey64 => (
+        input_lwe_dimension,
+        output_lwe_dimension,
+        decomposition_base_log,
+        decomposition_level_count
+    ),
+    LweBootstrapKey32 => (
+        input_lwe_dimension,
+        glwe_dimension,
+        polynomial_size,
+        decomposition_base_log,
+        decomposition_level_count
+    ),
+    LweBootstrapKey64 => (
+        input_lwe_dimension,
+        glwe_dimension,
+        polynomial_size,
+        decomposition_base_log,
+        decomposition_level_count
+    ),
+}
@@ -0,0 +1,171 @@
+use crate::backends::core::implementation::engines::entity_serialization::{
+    SerializationHeader, SerializedEntityKind, SERIALIZATION_FORMAT_VERSION,
+};
+use crate::backends::core::implementation::engines::{CoreEngine, CoreError};
+use crate::backends::core::implementation::entities::{
+    FourierLweBootstrapKey32, FourierLweBootstrapKey64, GgswCiphertext32, GgswCiphertext64,
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextVector32, GlweCiphertextVector64,
+    GlweSecretKey32, GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64, LweCiphertext32,
+    LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64, LweKeyswitchKey32,
+    LweKeyswitchKey64, LweKeyswitchKeyDelta32, LweKeyswitchKeyDelta64, LwePublicKey32,
+    LwePublicKey64, LweSecretKey32, LweSecretKey64, LweSeededBootstrapKey32,
+    LweSeededBootstrapKey64, LweSeededCiphertext32, LweSeededCiphertext64, PackingKeyswitchKey32,
+    PackingKeyswitchKey64,
+};
+use crate::specification::engines::{EntityDeserializationEngine, EntityDeserializationError};
+
+// Reads the header of a serialized entity, checks that it matches the `expected` entity in a
+// supported version, and returns the remaining bytes.
+fn read_header(
+    mut serialized: &[u8],
+    expected: SerializedEntityKind,
+) -> Result<&[u8], EntityDeserializationError<CoreError>> {
+    let header: SerializationHeader = bincode::deserialize_from(&mut serialized)
+        .map_err(|_| EntityDeserializationError::Engine(CoreError::Deserialization))?;
+    if header.entity != expected {
+        return Err(EntityDeserializationError::Engine(
+            CoreError::SerializedEntityMismatch,
+        ));
+    }
+    // There is a single version of the format so far. When the layout of an entity changes, the
+    // previous versions must be read here, and converted to the current layout.
+    if header.version != SERIALIZATION_FORMAT_VERSION {
+        return Err(EntityDeserializationError::Engine(
+            CoreError::UnsupportedSerializationVersion,
+        ));
+    }
+    Ok(serialized)
+}
+
+// Implements the engine for the listed entities, serialized with the
+// `EntitySerializationEngine` of the core backend.
+macro_rules! implement_entity_deserialization {
+    ($($entity:ident),+ $(,)?) => {
+        $(
+            /// # Description:
+            #[doc = concat!(
+                "Implementation of [`EntityDeserializationEngine`] for [`CoreEngine`] that \
+                operates on [`",
+                stringify!($entity),
+                "`] entities."
+            )]
+            impl<'a> EntityDeserializationEngine<&'a [u8], $entity> for CoreEngine {
+                fn deserialize(
+                    &mut self,
+                    serialized: &'a [u8],
+                ) -> Result<$entity, EntityDeserializationError<Self::EngineError>> {
+                    let payload = read_header(serialized, SerializedEntityKind::$entity)?;
+                    let entity: $entity = bincode::deserialize(payload).map_err(|_| {
+                        EntityDeserializationError::Engine(CoreError::Deserialization)
+                    })?;
+                    Ok(self.audit_creation(entity))
+                }
+
+                unsafe fn deserialize_unchecked(&mut self, serialized: &'a [u8]) -> $entity {
+                    self.deserialize(serialized).unwrap()
+                }
+            }
+        )+
+    };
+}
+
+implement_entity_deserialization! {
+    LweSecretKey32,
+    LweSecretKey64,
+    GlweSecretKey32,
+    GlweSecretKey64,
+    LweCiphertext32,
+    LweCiphertext64,
+    LweCiphertextVector32,
+    LweCiphertextVector64,
+    GlweCiphertext32,
+    GlweCiphertext64,
+    GlweCiphertextVector32,
+    GlweCiphertextVector64,
+    GgswCiphertext32,
+    GgswCiphertext64,
+    LweKeyswitchKey32,
+    LweKeyswitchKey64,
+    PackingKeyswitchKey32,
+    PackingKeyswitchKey64,
+    LweBootstrapKey32,
+    LweBootstrapKey64,
+    FourierLweBootstrapKey32,
+    FourierLweBootstrapKey64,
+    LweSeededCiphertext32,
+    LweSeededCiphertext64,
+    LweSeededBootstrapKey32,
+    LweSeededBootstrapKey64,
+    LwePublicKey32,
+    LwePublicKey64,
+    LweKeyswitchKeyDelta32,
+    LweKeyswitchKeyDelta64,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_lwe_ciphertext_round_trip() {
+        let mut engine = CoreEngine::new().unwrap();
+        let key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(630)).unwrap();
+        let plaintext = engine.create_plaintext(&(3_u64 << 50)).unwrap();
+        let ciphertext = engine
+            .encrypt_lwe_ciphertext(&key, &plaintext, Variance(2_f64.powf(-25.)))
+            .unwrap();
+        let serialized = engine.serialize(&ciphertext).unwrap();
+        let deserialized: LweCiphertext64 = engine.deserialize(serialized.as_slice()).unwrap();
+        assert_eq!(deserialized, ciphertext);
+    }
+
+    #[test]
+    fn test_fourier_lwe_bootstrap_key_round_trip() {
+        let mut engine = CoreEngine::new_with_fft_precision(FftPrecision::Split).unwrap();
+        let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(4)).unwrap();
+        let glwe_sk: GlweSecretKey64 = engine
+            .create_glwe_secret_key(GlweDimension(1), PolynomialSize(512))
+            .unwrap();
+        let bsk: FourierLweBootstrapKey64 = engine
+            .create_lwe_bootstrap_key(
+                &lwe_sk,
+                &glwe_sk,
+                DecompositionBaseLog(7),
+                DecompositionLevelCount(3),
+                Variance(2_f64.powf(-50.)),
+            )
+            .unwrap();
+        let serialized = engine.serialize(&bsk).unwrap();
+        let deserialized: FourierLweBootstrapKey64 =
+            engine.deserialize(serialized.as_slice()).unwrap();
+        assert_eq!(deserialized, bsk);
+        assert_eq!(deserialized.fft_precision(), FftPrecision::Split);
+    }
+
+    #[test]
+    fn test_mismatched_entity_is_rejected() {
+        let mut engine = CoreEngine::new().unwrap();
+        let key: LweSecretKey32 = engine.create_lwe_secret_key(LweDimension(630)).unwrap();
+        let serialized = engine.serialize(&key).unwrap();
+        let result: Result<LweSecretKey64, _> = engine.deserialize(serialized.as_slice());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            EntityDeserializationError::Engine(CoreError::SerializedEntityMismatch).to_string()
+        );
+    }
+
+    #[test]
+    fn test_unsupported_version_is_rejected() {
+        let mut engine = CoreEngine::new().unwrap();
+        let key: LweSecretKey32 = engine.create_lwe_secret_key(LweDimension(630)).unwrap();
+        let mut serialized = engine.serialize(&key).unwrap();
+        // The version follows the 4 bytes of the entity variant index.
+        serialized[4] = serialized[4].wrapping_add(1);
+        let result: Result<LweSecretKey32, _> = engine.deserialize(serialized.as_slice());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            EntityDeserializationError::Engine(CoreError::UnsupportedSerializationVersion)
+                .to_string()
+        );
+    }
+}
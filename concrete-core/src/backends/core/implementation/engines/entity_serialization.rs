@@ -0,0 +1,136 @@
+use crate::backends::core::implementation::engines::{CoreEngine, CoreError};
+use crate::backends::core::implementation::entities::{
+    FourierLweBootstrapKey32, FourierLweBootstrapKey64, GgswCiphertext32, GgswCiphertext64,
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextVector32, GlweCiphertextVector64,
+    GlweSecretKey32, GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64, LweCiphertext32,
+    LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64, LweKeyswitchKey32,
+    LweKeyswitchKey64, LweKeyswitchKeyDelta32, LweKeyswitchKeyDelta64, LwePublicKey32,
+    LwePublicKey64, LweSecretKey32, LweSecretKey64, LweSeededBootstrapKey32,
+    LweSeededBootstrapKey64, LweSeededCiphertext32, LweSeededCiphertext64, PackingKeyswitchKey32,
+    PackingKeyswitchKey64,
+};
+use crate::specification::engines::{EntitySerializationEngine, EntitySerializationError};
+use serde::{Deserialize, Serialize};
+
+/// The version of the serialized format of the core entities.
+///
+/// Every serialized entity starts with a [`SerializationHeader`] containing this version. It must
+/// be bumped whenever the serialized layout of an entity changes, and the deserialization engine
+/// must keep reading the previous versions.
+pub const SERIALIZATION_FORMAT_VERSION: u32 = 1;
+
+/// The kinds of entities serialized by the core backend engines.
+///
+/// The variants are identified by their index in the serialized format: new variants must be
+/// appended at the end of the enumeration.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SerializedEntityKind {
+    LweSecretKey32,
+    LweSecretKey64,
+    GlweSecretKey32,
+    GlweSecretKey64,
+    LweCiphertext32,
+    LweCiphertext64,
+    LweCiphertextVector32,
+    LweCiphertextVector64,
+    GlweCiphertext32,
+    GlweCiphertext64,
+    GlweCiphertextVector32,
+    GlweCiphertextVector64,
+    GgswCiphertext32,
+    GgswCiphertext64,
+    LweKeyswitchKey32,
+    LweKeyswitchKey64,
+    PackingKeyswitchKey32,
+    PackingKeyswitchKey64,
+    LweBootstrapKey32,
+    LweBootstrapKey64,
+    FourierLweBootstrapKey32,
+    FourierLweBootstrapKey64,
+    LweSeededCiphertext32,
+    LweSeededCiphertext64,
+    LweSeededBootstrapKey32,
+    LweSeededBootstrapKey64,
+    LwePublicKey32,
+    LwePublicKey64,
+    LweKeyswitchKeyDelta32,
+    LweKeyswitchKeyDelta64,
+}
+
+/// The header written before every entity serialized by the core backend.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SerializationHeader {
+    /// The type of the serialized entity.
+    pub entity: SerializedEntityKind,
+    /// The version of the serialized format, see [`SERIALIZATION_FORMAT_VERSION`].
+    pub version: u32,
+}
+
+// Implements the engine for the listed entities. The header is followed by the entity, both
+// encoded with `bincode`.
+macro_rules! implement_entity_serialization {
+    ($($entity:ident),+ $(,)?) => {
+        $(
+            /// # Description:
+            #[doc = concat!(
+                "Implementation of [`EntitySerializationEngine`] for [`CoreEngine`] that operates \
+                on [`",
+                stringify!($entity),
+                "`] entities."
+            )]
+            impl EntitySerializationEngine<$entity, Vec<u8>> for CoreEngine {
+                fn serialize(
+                    &mut self,
+                    entity: &$entity,
+                ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+                    let header = SerializationHeader {
+                        entity: SerializedEntityKind::$entity,
+                        version: SERIALIZATION_FORMAT_VERSION,
+                    };
+                    let mut serialized = bincode::serialize(&header)
+                        .map_err(|_| EntitySerializationError::Engine(CoreError::Serialization))?;
+                    bincode::serialize_into(&mut serialized, entity)
+                        .map_err(|_| EntitySerializationError::Engine(CoreError::Serialization))?;
+                    Ok(serialized)
+                }
+
+                unsafe fn serialize_unchecked(&mut self, entity: &$entity) -> Vec<u8> {
+                    self.serialize(entity).unwrap()
+                }
+            }
+        )+
+    };
+}
+
+implement_entity_serialization! {
+    LweSecretKey32,
+    LweSecretKey64,
+    GlweSecretKey32,
+    GlweSecretKey64,
+    LweCiphertext32,
+    LweCiphertext64,
+    LweCiphertextVector32,
+    LweCiphertextVector64,
+    GlweCiphertext32,
+    GlweCiphertext64,
+    GlweCiphertextVector32,
+    GlweCiphertextVector64,
+    GgswCiphertext32,
+    GgswCiphertext64,
+    LweKeyswitchKey32,
+    LweKeyswitchKey64,
+    PackingKeyswitchKey32,
+    PackingKeyswitchKey64,
+    LweBootstrapKey32,
+    LweBootstrapKey64,
+    FourierLweBootstrapKey32,
+    FourierLweBootstrapKey64,
+    LweSeededCiphertext32,
+    LweSeededCiphertext64,
+    LweSeededBootstrapKey32,
+    LweSeededBootstrapKey64,
+    LwePublicKey32,
+    LwePublicKey64,
+    LweKeyswitchKeyDelta32,
+    LweKeyswitchKeyDelta64,
+}
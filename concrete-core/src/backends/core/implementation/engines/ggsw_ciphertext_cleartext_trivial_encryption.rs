@@ -0,0 +1,214 @@
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+
+use crate::backends::core::entities::{
+    Cleartext32, Cleartext64, GgswCiphertext32, GgswCiphertext64,
+};
+use crate::backends::core::private::crypto::encoding::Plaintext as ImplPlaintext;
+use crate::backends::core::private::crypto::ggsw::StandardGgswCiphertext as ImplGgswCiphertext;
+use crate::specification::engines::{
+    GgswCiphertextCleartextTrivialEncryptionEngine, GgswCiphertextCleartextTrivialEncryptionError,
+};
+
+use crate::backends::core::engines::CoreEngine;
+
+/// # Description:
+/// Implementation of [`GgswCiphertextCleartextTrivialEncryptionEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl GgswCiphertextCleartextTrivialEncryptionEngine<Cleartext32, GgswCiphertext32> for CoreEngine {
+    /// # Example:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let input = 1_u32;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext: Cleartext32 = engine.create_cleartext(&input)?;
+    /// let ciphertext: GgswCiphertext32 = engine.trivially_encrypt_cleartext_ggsw_ciphertext(
+    ///     polynomial_size,
+    ///     glwe_dimension.to_glwe_size(),
+    ///     level,
+    ///     base_log,
+    ///     &cleartext,
+    /// )?;
+    ///
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    ///
+    /// // The decomposition can not be more precise than the integers.
+    /// let result: Result<GgswCiphertext32, _> = engine.trivially_encrypt_cleartext_ggsw_ciphertext(
+    ///     polynomial_size,
+    ///     glwe_dimension.to_glwe_size(),
+    ///     DecompositionLevelCount(9),
+    ///     base_log,
+    ///     &cleartext,
+    /// );
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(GgswCiphertextCleartextTrivialEncryptionError::DecompositionTooLarge)
+    /// ));
+    ///
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_cleartext_ggsw_ciphertext(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &Cleartext32,
+    ) -> Result<GgswCiphertext32, GgswCiphertextCleartextTrivialEncryptionError<Self::EngineError>>
+    {
+        GgswCiphertextCleartextTrivialEncryptionError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.trivially_encrypt_cleartext_ggsw_ciphertext_unchecked(
+                polynomial_size,
+                glwe_size,
+                decomposition_level_count,
+                decomposition_base_log,
+                input,
+            )
+        })
+    }
+
+    unsafe fn trivially_encrypt_cleartext_ggsw_ciphertext_unchecked(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &Cleartext32,
+    ) -> GgswCiphertext32 {
+        // The trivial GGSW encryption multiplies the gadget matrix by its input, which is hence
+        // interpreted as a cleartext and not as an encoded plaintext.
+        let ciphertext: ImplGgswCiphertext<Vec<u32>> = ImplGgswCiphertext::new_trivial_encryption(
+            polynomial_size,
+            glwe_size,
+            decomposition_level_count,
+            decomposition_base_log,
+            &ImplPlaintext((input.0).0),
+        );
+        GgswCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextCleartextTrivialEncryptionEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl GgswCiphertextCleartextTrivialEncryptionEngine<Cleartext64, GgswCiphertext64> for CoreEngine {
+    /// # Example:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let input = 1_u64;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext(&input)?;
+    /// let ciphertext: GgswCiphertext64 = engine.trivially_encrypt_cleartext_ggsw_ciphertext(
+    ///     polynomial_size,
+    ///     glwe_dimension.to_glwe_size(),
+    ///     level,
+    ///     base_log,
+    ///     &cleartext,
+    /// )?;
+    ///
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    ///
+    /// // The decomposition can not be more precise than the integers.
+    /// let result: Result<GgswCiphertext64, _> = engine.trivially_encrypt_cleartext_ggsw_ciphertext(
+    ///     polynomial_size,
+    ///     glwe_dimension.to_glwe_size(),
+    ///     DecompositionLevelCount(17),
+    ///     base_log,
+    ///     &cleartext,
+    /// );
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(GgswCiphertextCleartextTrivialEncryptionError::DecompositionTooLarge)
+    /// ));
+    ///
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_cleartext_ggsw_ciphertext(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &Cleartext64,
+    ) -> Result<GgswCiphertext64, GgswCiphertextCleartextTrivialEncryptionError<Self::EngineError>>
+    {
+        GgswCiphertextCleartextTrivialEncryptionError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.trivially_encrypt_cleartext_ggsw_ciphertext_unchecked(
+                polynomial_size,
+                glwe_size,
+                decomposition_level_count,
+                decomposition_base_log,
+                input,
+            )
+        })
+    }
+
+    unsafe fn trivially_encrypt_cleartext_ggsw_ciphertext_unchecked(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &Cleartext64,
+    ) -> GgswCiphertext64 {
+        // The trivial GGSW encryption multiplies the gadget matrix by its input, which is hence
+        // interpreted as a cleartext and not as an encoded plaintext.
+        let ciphertext: ImplGgswCiphertext<Vec<u64>> = ImplGgswCiphertext::new_trivial_encryption(
+            polynomial_size,
+            glwe_size,
+            decomposition_level_count,
+            decomposition_base_log,
+            &ImplPlaintext((input.0).0),
+        );
+        GgswCiphertext64(ciphertext)
+    }
+}
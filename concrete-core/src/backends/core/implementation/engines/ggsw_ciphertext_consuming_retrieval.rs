@@ -0,0 +1,120 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{GgswCiphertext32, GgswCiphertext64};
+use crate::backends::core::private::math::tensor::IntoTensor;
+use crate::specification::engines::{
+    GgswCiphertextConsumingRetrievalEngine, GgswCiphertextConsumingRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`GgswCiphertextConsumingRetrievalEngine`] for [`CoreEngine`] which returns
+/// the `Vec` of 32 bits integers of the ciphertext, without copying it.
+impl GgswCiphertextConsumingRetrievalEngine<GgswCiphertext32, Vec<u32>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext: GgswCiphertext32 =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let container: Vec<u32> = engine.consume_retrieve_ggsw_ciphertext(ciphertext)?;
+    /// #
+    /// assert_eq!(
+    ///     container.len(),
+    ///     level.0 * (glwe_dimension.0 + 1) * (glwe_dimension.0 + 1) * polynomial_size.0
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_ggsw_ciphertext(
+        &mut self,
+        ciphertext: GgswCiphertext32,
+    ) -> Result<Vec<u32>, GgswCiphertextConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_ggsw_ciphertext_unchecked(ciphertext) })
+    }
+
+    unsafe fn consume_retrieve_ggsw_ciphertext_unchecked(
+        &mut self,
+        ciphertext: GgswCiphertext32,
+    ) -> Vec<u32> {
+        ciphertext.0.into_tensor().into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextConsumingRetrievalEngine`] for [`CoreEngine`] which returns
+/// the `Vec` of 64 bits integers of the ciphertext, without copying it.
+impl GgswCiphertextConsumingRetrievalEngine<GgswCiphertext64, Vec<u64>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext: GgswCiphertext64 =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let container: Vec<u64> = engine.consume_retrieve_ggsw_ciphertext(ciphertext)?;
+    /// #
+    /// assert_eq!(
+    ///     container.len(),
+    ///     level.0 * (glwe_dimension.0 + 1) * (glwe_dimension.0 + 1) * polynomial_size.0
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_ggsw_ciphertext(
+        &mut self,
+        ciphertext: GgswCiphertext64,
+    ) -> Result<Vec<u64>, GgswCiphertextConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_ggsw_ciphertext_unchecked(ciphertext) })
+    }
+
+    unsafe fn consume_retrieve_ggsw_ciphertext_unchecked(
+        &mut self,
+        ciphertext: GgswCiphertext64,
+    ) -> Vec<u64> {
+        ciphertext.0.into_tensor().into_container()
+    }
+}
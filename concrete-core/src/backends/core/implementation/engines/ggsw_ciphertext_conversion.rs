@@ -2,7 +2,7 @@ use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
     FourierGgswCiphertext32, FourierGgswCiphertext64, GgswCiphertext32, GgswCiphertext64,
 };
-use crate::backends::core::private::crypto::ggsw::FourierGgswCiphertext;
+use crate::backends::core::private::crypto::ggsw::{FourierGgswCiphertext, StandardGgswCiphertext};
 use crate::backends::core::private::math::fft::Complex64;
 use crate::specification::engines::{
     GgswCiphertextConversionEngine, GgswCiphertextConversionError,
@@ -175,3 +175,155 @@ where
         (*input).clone()
     }
 }
+
+/// # Description:
+/// Implementation of [`GgswCiphertextConversionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. It converts a GGSW ciphertext from the Fourier to the standard domain.
+impl GgswCiphertextConversionEngine<FourierGgswCiphertext32, GgswCiphertext32> for CoreEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// let fourier_ciphertext: FourierGgswCiphertext32 =
+    ///     engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// // We convert the Fourier GGSW ciphertext back to the standard domain.
+    /// let standard_ciphertext: GgswCiphertext32 =
+    ///     engine.convert_ggsw_ciphertext(&fourier_ciphertext)?;
+    /// #
+    /// assert_eq!(standard_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(standard_ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(standard_ciphertext.decomposition_base_log(), base_log);
+    /// assert_eq!(standard_ciphertext.decomposition_level_count(), level);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(fourier_ciphertext)?;
+    /// engine.destroy(standard_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_ggsw_ciphertext(
+        &mut self,
+        input: &FourierGgswCiphertext32,
+    ) -> Result<GgswCiphertext32, GgswCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_ggsw_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_ggsw_ciphertext_unchecked(
+        &mut self,
+        input: &FourierGgswCiphertext32,
+    ) -> GgswCiphertext32 {
+        let mut output = StandardGgswCiphertext::allocate(
+            0u32,
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+        );
+        let buffers = self.get_fourier_u32_buffer(
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+        );
+        input.0.fill_with_backward_fourier(&mut output, buffers);
+        GgswCiphertext32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextConversionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. It converts a GGSW ciphertext from the Fourier to the standard domain.
+impl GgswCiphertextConversionEngine<FourierGgswCiphertext64, GgswCiphertext64> for CoreEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// let fourier_ciphertext: FourierGgswCiphertext64 =
+    ///     engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// // We convert the Fourier GGSW ciphertext back to the standard domain.
+    /// let standard_ciphertext: GgswCiphertext64 =
+    ///     engine.convert_ggsw_ciphertext(&fourier_ciphertext)?;
+    /// #
+    /// assert_eq!(standard_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(standard_ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(standard_ciphertext.decomposition_base_log(), base_log);
+    /// assert_eq!(standard_ciphertext.decomposition_level_count(), level);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(fourier_ciphertext)?;
+    /// engine.destroy(standard_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_ggsw_ciphertext(
+        &mut self,
+        input: &FourierGgswCiphertext64,
+    ) -> Result<GgswCiphertext64, GgswCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_ggsw_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_ggsw_ciphertext_unchecked(
+        &mut self,
+        input: &FourierGgswCiphertext64,
+    ) -> GgswCiphertext64 {
+        let mut output = StandardGgswCiphertext::allocate(
+            0u64,
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+        );
+        let buffers = self.get_fourier_u64_buffer(
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+        );
+        input.0.fill_with_backward_fourier(&mut output, buffers);
+        GgswCiphertext64(output)
+    }
+}
@@ -93,7 +93,7 @@ impl GgswCiphertextScalarEncryptionEngine<GlweSecretKey32, Plaintext32, GgswCiph
             noise,
             &mut self.encryption_generator,
         );
-        GgswCiphertext32(ciphertext)
+        self.audit_creation(GgswCiphertext32(ciphertext))
     }
 }
 
@@ -179,6 +179,6 @@ impl GgswCiphertextScalarEncryptionEngine<GlweSecretKey64, Plaintext64, GgswCiph
             noise,
             &mut self.encryption_generator,
         );
-        GgswCiphertext64(ciphertext)
+        self.audit_creation(GgswCiphertext64(ciphertext))
     }
 }
@@ -0,0 +1,185 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GgswCiphertext32, GgswCiphertext64, GgswSeededCiphertext32, GgswSeededCiphertext64,
+};
+use crate::backends::core::private::crypto::ggsw::StandardGgswCiphertext as ImplGgswCiphertext;
+use crate::specification::engines::{
+    GgswSeededCiphertextExpansionEngine, GgswSeededCiphertextExpansionError,
+};
+use crate::specification::entities::GgswSeededCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`GgswSeededCiphertextExpansionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. It regenerates the masks of the ciphertext from its seed.
+impl GgswSeededCiphertextExpansionEngine<GgswSeededCiphertext32, GgswCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(512);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 28 bits)
+    /// let input_glwe = vec![3_u32 << 28; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-40.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_ggsw = engine.create_plaintext(&1_u32)?;
+    /// let plaintext_glwe = engine.create_plaintext_vector(&input_glwe)?;
+    ///
+    /// let seeded_ggsw = engine.encrypt_scalar_ggsw_seeded_ciphertext(
+    ///     &key,
+    ///     &plaintext_ggsw,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// let ggsw: GgswCiphertext32 = engine.expand_ggsw_seeded_ciphertext(&seeded_ggsw)?;
+    /// #
+    /// assert_eq!(ggsw.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ggsw.decomposition_level_count(), level);
+    ///
+    /// // The expanded ciphertext can be used as any GGSW ciphertext.
+    /// let complex_ggsw: FourierGgswCiphertext32 = engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe: GlweCiphertext32 = engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    /// let product =
+    ///     engine.compute_external_product_glwe_ciphertext_ggsw_ciphertext(&glwe, &complex_ggsw)?;
+    /// let decrypted = engine.decrypt_glwe_ciphertext(&key, &product)?;
+    /// let output = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// for (output, input) in output.iter().zip(input_glwe.iter()) {
+    ///     let error = output.wrapping_sub(*input).min(input.wrapping_sub(*output));
+    ///     assert!(error < 1 << (28 - 2));
+    /// }
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_ggsw)?;
+    /// engine.destroy(plaintext_glwe)?;
+    /// engine.destroy(seeded_ggsw)?;
+    /// engine.destroy(ggsw)?;
+    /// engine.destroy(complex_ggsw)?;
+    /// engine.destroy(glwe)?;
+    /// engine.destroy(product)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_ggsw_seeded_ciphertext(
+        &mut self,
+        input: &GgswSeededCiphertext32,
+    ) -> Result<GgswCiphertext32, GgswSeededCiphertextExpansionError<Self::EngineError>> {
+        Ok(unsafe { self.expand_ggsw_seeded_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn expand_ggsw_seeded_ciphertext_unchecked(
+        &mut self,
+        input: &GgswSeededCiphertext32,
+    ) -> GgswCiphertext32 {
+        let mut output = ImplGgswCiphertext::allocate(
+            0u32,
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+        );
+        input.0.expand_into(&mut output);
+        GgswCiphertext32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswSeededCiphertextExpansionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. It regenerates the masks of the ciphertext from its seed.
+impl GgswSeededCiphertextExpansionEngine<GgswSeededCiphertext64, GgswCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(512);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 60 bits)
+    /// let input_glwe = vec![3_u64 << 60; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_ggsw = engine.create_plaintext(&1_u64)?;
+    /// let plaintext_glwe = engine.create_plaintext_vector(&input_glwe)?;
+    ///
+    /// let seeded_ggsw = engine.encrypt_scalar_ggsw_seeded_ciphertext(
+    ///     &key,
+    ///     &plaintext_ggsw,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// let ggsw: GgswCiphertext64 = engine.expand_ggsw_seeded_ciphertext(&seeded_ggsw)?;
+    /// #
+    /// assert_eq!(ggsw.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ggsw.decomposition_level_count(), level);
+    ///
+    /// // The expanded ciphertext can be used as any GGSW ciphertext.
+    /// let complex_ggsw: FourierGgswCiphertext64 = engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe: GlweCiphertext64 = engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    /// let product =
+    ///     engine.compute_external_product_glwe_ciphertext_ggsw_ciphertext(&glwe, &complex_ggsw)?;
+    /// let decrypted = engine.decrypt_glwe_ciphertext(&key, &product)?;
+    /// let output = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// for (output, input) in output.iter().zip(input_glwe.iter()) {
+    ///     let error = output.wrapping_sub(*input).min(input.wrapping_sub(*output));
+    ///     assert!(error < 1 << (60 - 2));
+    /// }
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_ggsw)?;
+    /// engine.destroy(plaintext_glwe)?;
+    /// engine.destroy(seeded_ggsw)?;
+    /// engine.destroy(ggsw)?;
+    /// engine.destroy(complex_ggsw)?;
+    /// engine.destroy(glwe)?;
+    /// engine.destroy(product)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_ggsw_seeded_ciphertext(
+        &mut self,
+        input: &GgswSeededCiphertext64,
+    ) -> Result<GgswCiphertext64, GgswSeededCiphertextExpansionError<Self::EngineError>> {
+        Ok(unsafe { self.expand_ggsw_seeded_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn expand_ggsw_seeded_ciphertext_unchecked(
+        &mut self,
+        input: &GgswSeededCiphertext64,
+    ) -> GgswCiphertext64 {
+        let mut output = ImplGgswCiphertext::allocate(
+            0u64,
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+        );
+        input.0.expand_into(&mut output);
+        GgswCiphertext64(output)
+    }
+}
@@ -0,0 +1,218 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+use crate::backends::core::implementation::engines::{CoreEngine, CoreError};
+use crate::backends::core::implementation::entities::{
+    GlweAutomorphismKey32, GlweAutomorphismKey64, GlweSecretKey32, GlweSecretKey64,
+};
+use crate::backends::core::private::crypto::glwe::GlweAutomorphismKey as ImplGlweAutomorphismKey;
+use crate::specification::engines::{
+    GlweAutomorphismKeyCreationEngine, GlweAutomorphismKeyCreationError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+
+impl From<CoreError> for GlweAutomorphismKeyCreationError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweAutomorphismKeyCreationEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl GlweAutomorphismKeyCreationEngine<GlweSecretKey32, GlweAutomorphismKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let secret_key: GlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let automorphism_key: GlweAutomorphismKey32 = engine.create_glwe_automorphism_key(
+    ///     &secret_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     automorphism_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     automorphism_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(automorphism_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(automorphism_key.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(secret_key)?;
+    /// engine.destroy(automorphism_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_automorphism_key(
+        &mut self,
+        secret_key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweAutomorphismKey32, GlweAutomorphismKeyCreationError<Self::EngineError>> {
+        GlweAutomorphismKeyCreationError::perform_generic_checks(
+            secret_key,
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        self.check_key_generation_cancellation()?;
+        let key = unsafe {
+            self.create_glwe_automorphism_key_unchecked(
+                secret_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        };
+        self.check_key_generation_cancellation()?;
+        Ok(key)
+    }
+
+    unsafe fn create_glwe_automorphism_key_unchecked(
+        &mut self,
+        secret_key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweAutomorphismKey32 {
+        let mut key = ImplGlweAutomorphismKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            secret_key.glwe_dimension(),
+            secret_key.polynomial_size(),
+        );
+        key.fill_with_automorphism_key_monitored(
+            &secret_key.0,
+            noise,
+            &mut self.encryption_generator,
+            self.key_generation_monitor
+                .reporter(key.automorphism_count()),
+        );
+        self.audit_creation(GlweAutomorphismKey32(key))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweAutomorphismKeyCreationEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl GlweAutomorphismKeyCreationEngine<GlweSecretKey64, GlweAutomorphismKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let secret_key: GlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let automorphism_key: GlweAutomorphismKey64 = engine.create_glwe_automorphism_key(
+    ///     &secret_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     automorphism_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     automorphism_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(automorphism_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(automorphism_key.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(secret_key)?;
+    /// engine.destroy(automorphism_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_automorphism_key(
+        &mut self,
+        secret_key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweAutomorphismKey64, GlweAutomorphismKeyCreationError<Self::EngineError>> {
+        GlweAutomorphismKeyCreationError::perform_generic_checks(
+            secret_key,
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        self.check_key_generation_cancellation()?;
+        let key = unsafe {
+            self.create_glwe_automorphism_key_unchecked(
+                secret_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        };
+        self.check_key_generation_cancellation()?;
+        Ok(key)
+    }
+
+    unsafe fn create_glwe_automorphism_key_unchecked(
+        &mut self,
+        secret_key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweAutomorphismKey64 {
+        let mut key = ImplGlweAutomorphismKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            secret_key.glwe_dimension(),
+            secret_key.polynomial_size(),
+        );
+        key.fill_with_automorphism_key_monitored(
+            &secret_key.0,
+            noise,
+            &mut self.encryption_generator,
+            self.key_generation_monitor
+                .reporter(key.automorphism_count()),
+        );
+        self.audit_creation(GlweAutomorphismKey64(key))
+    }
+}
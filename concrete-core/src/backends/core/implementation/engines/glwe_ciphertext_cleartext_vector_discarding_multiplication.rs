@@ -0,0 +1,395 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    CleartextVector32, CleartextVector64, CyclicGlweCiphertext32, CyclicGlweCiphertext64,
+    GlweCiphertext32, GlweCiphertext64,
+};
+use crate::backends::core::private::math::polynomial::Polynomial;
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::specification::engines::{
+    GlweCiphertextCleartextVectorDiscardingMultiplicationEngine,
+    GlweCiphertextCleartextVectorDiscardingMultiplicationError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCleartextVectorDiscardingMultiplicationEngine`] for
+/// [`CoreEngine`] that operates on 32 bits integers.
+impl
+    GlweCiphertextCleartextVectorDiscardingMultiplicationEngine<
+        GlweCiphertext32,
+        CleartextVector32,
+        GlweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// // The cleartext vector is the polynomial 1 + X^3
+    /// let cleartexts = vec![1_u32, 0, 0, 1];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let cleartext_vector: CleartextVector32 = engine.create_cleartext_vector(&cleartexts)?;
+    /// let ciphertext_1: GlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext_2 = ciphertext_1.clone();
+    ///
+    /// engine.discard_mul_glwe_ciphertext_cleartext_vector(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &cleartext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_2.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext_cleartext_vector(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        cleartext_vector: &CleartextVector32,
+    ) -> Result<(), GlweCiphertextCleartextVectorDiscardingMultiplicationError<Self::EngineError>>
+    {
+        GlweCiphertextCleartextVectorDiscardingMultiplicationError::perform_generic_checks(
+            output,
+            input,
+            cleartext_vector,
+        )?;
+        unsafe {
+            self.discard_mul_glwe_ciphertext_cleartext_vector_unchecked(
+                output,
+                input,
+                cleartext_vector,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_cleartext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        cleartext_vector: &CleartextVector32,
+    ) {
+        let cleartext_polynomial =
+            Polynomial::from_container(cleartext_vector.0.as_tensor().as_slice());
+        output.0.as_mut_tensor().fill_with_element(0);
+        for (mut output_polynomial, input_polynomial) in output
+            .0
+            .as_mut_polynomial_list()
+            .polynomial_iter_mut()
+            .zip(input.0.as_polynomial_list().polynomial_iter())
+        {
+            output_polynomial
+                .update_with_wrapping_add_mul(&input_polynomial, &cleartext_polynomial);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCleartextVectorDiscardingMultiplicationEngine`] for
+/// [`CoreEngine`] that operates on 64 bits integers.
+impl
+    GlweCiphertextCleartextVectorDiscardingMultiplicationEngine<
+        GlweCiphertext64,
+        CleartextVector64,
+        GlweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// // The cleartext vector is the polynomial 1 + X^3
+    /// let cleartexts = vec![1_u64, 0, 0, 1];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let cleartext_vector = engine.create_cleartext_vector(&cleartexts)?;
+    /// let ciphertext_1: GlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext_2 = ciphertext_1.clone();
+    ///
+    /// engine.discard_mul_glwe_ciphertext_cleartext_vector(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &cleartext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_2.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext_cleartext_vector(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        cleartext_vector: &CleartextVector64,
+    ) -> Result<(), GlweCiphertextCleartextVectorDiscardingMultiplicationError<Self::EngineError>>
+    {
+        GlweCiphertextCleartextVectorDiscardingMultiplicationError::perform_generic_checks(
+            output,
+            input,
+            cleartext_vector,
+        )?;
+        unsafe {
+            self.discard_mul_glwe_ciphertext_cleartext_vector_unchecked(
+                output,
+                input,
+                cleartext_vector,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_cleartext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        cleartext_vector: &CleartextVector64,
+    ) {
+        let cleartext_polynomial =
+            Polynomial::from_container(cleartext_vector.0.as_tensor().as_slice());
+        output.0.as_mut_tensor().fill_with_element(0);
+        for (mut output_polynomial, input_polynomial) in output
+            .0
+            .as_mut_polynomial_list()
+            .polynomial_iter_mut()
+            .zip(input.0.as_polynomial_list().polynomial_iter())
+        {
+            output_polynomial
+                .update_with_wrapping_add_mul(&input_polynomial, &cleartext_polynomial);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCleartextVectorDiscardingMultiplicationEngine`] for
+/// [`CoreEngine`] that operates on 32 bits integers, in the cyclic polynomial ring.
+impl
+    GlweCiphertextCleartextVectorDiscardingMultiplicationEngine<
+        CyclicGlweCiphertext32,
+        CleartextVector32,
+        CyclicGlweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// // The cleartext vector is the polynomial 1 + X^3
+    /// let cleartexts = vec![1_u32, 0, 0, 1];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let cleartext_vector: CleartextVector32 = engine.create_cleartext_vector(&cleartexts)?;
+    /// let ciphertext_1: CyclicGlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext_2 = ciphertext_1.clone();
+    ///
+    /// engine.discard_mul_glwe_ciphertext_cleartext_vector(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &cleartext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_2.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext_cleartext_vector(
+        &mut self,
+        output: &mut CyclicGlweCiphertext32,
+        input: &CyclicGlweCiphertext32,
+        cleartext_vector: &CleartextVector32,
+    ) -> Result<(), GlweCiphertextCleartextVectorDiscardingMultiplicationError<Self::EngineError>>
+    {
+        GlweCiphertextCleartextVectorDiscardingMultiplicationError::perform_generic_checks(
+            output,
+            input,
+            cleartext_vector,
+        )?;
+        unsafe {
+            self.discard_mul_glwe_ciphertext_cleartext_vector_unchecked(
+                output,
+                input,
+                cleartext_vector,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_cleartext_vector_unchecked(
+        &mut self,
+        output: &mut CyclicGlweCiphertext32,
+        input: &CyclicGlweCiphertext32,
+        cleartext_vector: &CleartextVector32,
+    ) {
+        let cleartext_polynomial =
+            Polynomial::from_container(cleartext_vector.0.as_tensor().as_slice());
+        output.0.as_mut_tensor().fill_with_element(0);
+        for (mut output_polynomial, input_polynomial) in output
+            .0
+            .as_mut_polynomial_list()
+            .polynomial_iter_mut()
+            .zip(input.0.as_polynomial_list().polynomial_iter())
+        {
+            output_polynomial
+                .update_with_wrapping_add_cyclic_mul(&input_polynomial, &cleartext_polynomial);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCleartextVectorDiscardingMultiplicationEngine`] for
+/// [`CoreEngine`] that operates on 64 bits integers, in the cyclic polynomial ring.
+impl
+    GlweCiphertextCleartextVectorDiscardingMultiplicationEngine<
+        CyclicGlweCiphertext64,
+        CleartextVector64,
+        CyclicGlweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// // The cleartext vector is the polynomial 1 + X^3
+    /// let cleartexts = vec![1_u64, 0, 0, 1];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let cleartext_vector = engine.create_cleartext_vector(&cleartexts)?;
+    /// let ciphertext_1: CyclicGlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext_2 = ciphertext_1.clone();
+    ///
+    /// engine.discard_mul_glwe_ciphertext_cleartext_vector(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &cleartext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_2.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext_cleartext_vector(
+        &mut self,
+        output: &mut CyclicGlweCiphertext64,
+        input: &CyclicGlweCiphertext64,
+        cleartext_vector: &CleartextVector64,
+    ) -> Result<(), GlweCiphertextCleartextVectorDiscardingMultiplicationError<Self::EngineError>>
+    {
+        GlweCiphertextCleartextVectorDiscardingMultiplicationError::perform_generic_checks(
+            output,
+            input,
+            cleartext_vector,
+        )?;
+        unsafe {
+            self.discard_mul_glwe_ciphertext_cleartext_vector_unchecked(
+                output,
+                input,
+                cleartext_vector,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_cleartext_vector_unchecked(
+        &mut self,
+        output: &mut CyclicGlweCiphertext64,
+        input: &CyclicGlweCiphertext64,
+        cleartext_vector: &CleartextVector64,
+    ) {
+        let cleartext_polynomial =
+            Polynomial::from_container(cleartext_vector.0.as_tensor().as_slice());
+        output.0.as_mut_tensor().fill_with_element(0);
+        for (mut output_polynomial, input_polynomial) in output
+            .0
+            .as_mut_polynomial_list()
+            .polynomial_iter_mut()
+            .zip(input.0.as_polynomial_list().polynomial_iter())
+        {
+            output_polynomial
+                .update_with_wrapping_add_cyclic_mul(&input_polynomial, &cleartext_polynomial);
+        }
+    }
+}
@@ -41,7 +41,8 @@ impl GlweCiphertextConversionEngine<GlweCiphertext32, FourierGlweCiphertext32> f
     /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
     ///
     /// // We encrypt a GLWE ciphertext in the standard domain
-    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let ciphertext: GlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
     ///
     /// // Then we convert it to the Fourier domain.
     /// let fourier_ciphertext: FourierGlweCiphertext32 =
@@ -115,7 +116,8 @@ impl GlweCiphertextConversionEngine<GlweCiphertext64, FourierGlweCiphertext64> f
     /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
     ///
     /// // We encrypt a GLWE ciphertext in the standard domain
-    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let ciphertext: GlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
     ///
     /// // Then we convert it to the Fourier domain.
     /// let fourier_ciphertext: FourierGlweCiphertext64 =
@@ -0,0 +1,304 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    CyclicGlweCiphertext32, CyclicGlweCiphertext64, GlweCiphertext32, GlweCiphertext64,
+};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::specification::engines::{
+    GlweCiphertextDiscardingAdditionEngine, GlweCiphertextDiscardingAdditionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingAdditionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl GlweCiphertextDiscardingAdditionEngine<GlweCiphertext32, GlweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_2 = vec![7_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let ciphertext_1: GlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2: GlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut ciphertext_3 = ciphertext_1.clone();
+    ///
+    /// engine.discard_add_glwe_ciphertext(&mut ciphertext_3, &ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_3.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_3.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+    ) -> Result<(), GlweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        GlweCiphertextDiscardingAdditionError::perform_generic_checks(output, input_1, input_2)?;
+        unsafe { self.discard_add_glwe_ciphertext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input_1.0.as_tensor());
+        output
+            .0
+            .as_mut_tensor()
+            .update_with_wrapping_add(input_2.0.as_tensor());
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingAdditionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl GlweCiphertextDiscardingAdditionEngine<GlweCiphertext64, GlweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = vec![3_u64 << 50; polynomial_size.0];
+    /// let input_2 = vec![7_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let ciphertext_1: GlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2: GlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut ciphertext_3 = ciphertext_1.clone();
+    ///
+    /// engine.discard_add_glwe_ciphertext(&mut ciphertext_3, &ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_3.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_3.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+    ) -> Result<(), GlweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        GlweCiphertextDiscardingAdditionError::perform_generic_checks(output, input_1, input_2)?;
+        unsafe { self.discard_add_glwe_ciphertext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input_1.0.as_tensor());
+        output
+            .0
+            .as_mut_tensor()
+            .update_with_wrapping_add(input_2.0.as_tensor());
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingAdditionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers, in the cyclic polynomial ring.
+impl GlweCiphertextDiscardingAdditionEngine<CyclicGlweCiphertext32, CyclicGlweCiphertext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_2 = vec![7_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let ciphertext_1: CyclicGlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2: CyclicGlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut ciphertext_3 = ciphertext_1.clone();
+    ///
+    /// engine.discard_add_glwe_ciphertext(&mut ciphertext_3, &ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_3.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_3.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_glwe_ciphertext(
+        &mut self,
+        output: &mut CyclicGlweCiphertext32,
+        input_1: &CyclicGlweCiphertext32,
+        input_2: &CyclicGlweCiphertext32,
+    ) -> Result<(), GlweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        GlweCiphertextDiscardingAdditionError::perform_generic_checks(output, input_1, input_2)?;
+        unsafe { self.discard_add_glwe_ciphertext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CyclicGlweCiphertext32,
+        input_1: &CyclicGlweCiphertext32,
+        input_2: &CyclicGlweCiphertext32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input_1.0.as_tensor());
+        output
+            .0
+            .as_mut_tensor()
+            .update_with_wrapping_add(input_2.0.as_tensor());
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingAdditionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers, in the cyclic polynomial ring.
+impl GlweCiphertextDiscardingAdditionEngine<CyclicGlweCiphertext64, CyclicGlweCiphertext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = vec![3_u64 << 50; polynomial_size.0];
+    /// let input_2 = vec![7_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let ciphertext_1: CyclicGlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2: CyclicGlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut ciphertext_3 = ciphertext_1.clone();
+    ///
+    /// engine.discard_add_glwe_ciphertext(&mut ciphertext_3, &ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_3.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_3.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_glwe_ciphertext(
+        &mut self,
+        output: &mut CyclicGlweCiphertext64,
+        input_1: &CyclicGlweCiphertext64,
+        input_2: &CyclicGlweCiphertext64,
+    ) -> Result<(), GlweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        GlweCiphertextDiscardingAdditionError::perform_generic_checks(output, input_1, input_2)?;
+        unsafe { self.discard_add_glwe_ciphertext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CyclicGlweCiphertext64,
+        input_1: &CyclicGlweCiphertext64,
+        input_2: &CyclicGlweCiphertext64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input_1.0.as_tensor());
+        output
+            .0
+            .as_mut_tensor()
+            .update_with_wrapping_add(input_2.0.as_tensor());
+    }
+}
@@ -33,7 +33,8 @@ impl GlweCiphertextDiscardingEncryptionEngine<GlweSecretKey32, PlaintextVector32
     /// let mut engine = CoreEngine::new()?;
     /// let key_1: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
     /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
-    /// let mut ciphertext = engine.encrypt_glwe_ciphertext(&key_1, &plaintext_vector, noise)?;
+    /// let mut ciphertext: GlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key_1, &plaintext_vector, noise)?;
     /// // We're going to re-encrypt the input with another secret key
     /// // For this, it is required that the second secret key uses the same GLWE dimension
     /// // and polynomial size as the first one.
@@ -104,7 +105,8 @@ impl GlweCiphertextDiscardingEncryptionEngine<GlweSecretKey64, PlaintextVector64
     /// let mut engine = CoreEngine::new()?;
     /// let key_1: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
     /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
-    /// let mut ciphertext = engine.encrypt_glwe_ciphertext(&key_1, &plaintext_vector, noise)?;
+    /// let mut ciphertext: GlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key_1, &plaintext_vector, noise)?;
     /// // We're going to re-encrypt the input with another secret key
     /// // For this, it is required that the second secret key uses the same GLWE dimension
     /// // and polynomial size as the first one.
@@ -0,0 +1,390 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    CyclicGlweCiphertext32, CyclicGlweCiphertext64, GlweCiphertext32, GlweCiphertext64,
+};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::specification::engines::{
+    GlweCiphertextDiscardingOffsetAdditionEngine, GlweCiphertextDiscardingOffsetAdditionError,
+};
+#[allow(deprecated)]
+use concrete_commons::parameters::{MonomialDegree, MonomialIndex, PlaintextCount};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingOffsetAdditionEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl GlweCiphertextDiscardingOffsetAdditionEngine<GlweCiphertext32, GlweCiphertext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, MonomialIndex, PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = vec![3_u32 << 20; polynomial_size.0];
+    /// // The second input only fills its first two slots, the other ones are zero padding
+    /// let input_2 = vec![7_u32 << 20, 7_u32 << 20, 0, 0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let ciphertext_1: GlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2: GlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut ciphertext_3 = ciphertext_1.clone();
+    ///
+    /// // The slots of the second input are added to the last two slots of the first one
+    /// engine.discard_offset_add_glwe_ciphertext(
+    ///     &mut ciphertext_3,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     MonomialIndex(2),
+    ///     PlaintextCount(2),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_3.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_3.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_offset_add_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        offset: MonomialIndex,
+        slot_count: PlaintextCount,
+    ) -> Result<(), GlweCiphertextDiscardingOffsetAdditionError<Self::EngineError>> {
+        GlweCiphertextDiscardingOffsetAdditionError::perform_generic_checks(
+            output, input_1, input_2, offset, slot_count,
+        )?;
+        unsafe {
+            self.discard_offset_add_glwe_ciphertext_unchecked(
+                output, input_1, input_2, offset, slot_count,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_offset_add_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        offset: MonomialIndex,
+        _slot_count: PlaintextCount,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input_1.0.as_tensor());
+        #[allow(deprecated)]
+        output
+            .0
+            .update_with_wrapping_add_negacyclic_monomial_mul(&input_2.0, MonomialDegree(offset.0));
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingOffsetAdditionEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl GlweCiphertextDiscardingOffsetAdditionEngine<GlweCiphertext64, GlweCiphertext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, MonomialIndex, PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = vec![3_u64 << 50; polynomial_size.0];
+    /// // The second input only fills its first two slots, the other ones are zero padding
+    /// let input_2 = vec![7_u64 << 50, 7_u64 << 50, 0, 0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let ciphertext_1: GlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2: GlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut ciphertext_3 = ciphertext_1.clone();
+    ///
+    /// // The slots of the second input are added to the last two slots of the first one
+    /// engine.discard_offset_add_glwe_ciphertext(
+    ///     &mut ciphertext_3,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     MonomialIndex(2),
+    ///     PlaintextCount(2),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_3.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_3.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_offset_add_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        offset: MonomialIndex,
+        slot_count: PlaintextCount,
+    ) -> Result<(), GlweCiphertextDiscardingOffsetAdditionError<Self::EngineError>> {
+        GlweCiphertextDiscardingOffsetAdditionError::perform_generic_checks(
+            output, input_1, input_2, offset, slot_count,
+        )?;
+        unsafe {
+            self.discard_offset_add_glwe_ciphertext_unchecked(
+                output, input_1, input_2, offset, slot_count,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_offset_add_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        offset: MonomialIndex,
+        _slot_count: PlaintextCount,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input_1.0.as_tensor());
+        #[allow(deprecated)]
+        output
+            .0
+            .update_with_wrapping_add_negacyclic_monomial_mul(&input_2.0, MonomialDegree(offset.0));
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingOffsetAdditionEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers, in the cyclic polynomial ring.
+impl GlweCiphertextDiscardingOffsetAdditionEngine<CyclicGlweCiphertext32, CyclicGlweCiphertext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, MonomialIndex, PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = vec![3_u32 << 20; polynomial_size.0];
+    /// // The second input only fills its first two slots, the other ones are zero padding
+    /// let input_2 = vec![7_u32 << 20, 7_u32 << 20, 0, 0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let ciphertext_1: CyclicGlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2: CyclicGlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut ciphertext_3 = ciphertext_1.clone();
+    ///
+    /// // The slots of the second input are added to the last two slots of the first one
+    /// engine.discard_offset_add_glwe_ciphertext(
+    ///     &mut ciphertext_3,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     MonomialIndex(2),
+    ///     PlaintextCount(2),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_3.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_3.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_offset_add_glwe_ciphertext(
+        &mut self,
+        output: &mut CyclicGlweCiphertext32,
+        input_1: &CyclicGlweCiphertext32,
+        input_2: &CyclicGlweCiphertext32,
+        offset: MonomialIndex,
+        slot_count: PlaintextCount,
+    ) -> Result<(), GlweCiphertextDiscardingOffsetAdditionError<Self::EngineError>> {
+        GlweCiphertextDiscardingOffsetAdditionError::perform_generic_checks(
+            output, input_1, input_2, offset, slot_count,
+        )?;
+        unsafe {
+            self.discard_offset_add_glwe_ciphertext_unchecked(
+                output, input_1, input_2, offset, slot_count,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_offset_add_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CyclicGlweCiphertext32,
+        input_1: &CyclicGlweCiphertext32,
+        input_2: &CyclicGlweCiphertext32,
+        offset: MonomialIndex,
+        _slot_count: PlaintextCount,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input_1.0.as_tensor());
+        #[allow(deprecated)]
+        output
+            .0
+            .update_with_wrapping_add_cyclic_monomial_mul(&input_2.0, MonomialDegree(offset.0));
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingOffsetAdditionEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers, in the cyclic polynomial ring.
+impl GlweCiphertextDiscardingOffsetAdditionEngine<CyclicGlweCiphertext64, CyclicGlweCiphertext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, MonomialIndex, PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = vec![3_u64 << 50; polynomial_size.0];
+    /// // The second input only fills its first two slots, the other ones are zero padding
+    /// let input_2 = vec![7_u64 << 50, 7_u64 << 50, 0, 0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let ciphertext_1: CyclicGlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2: CyclicGlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut ciphertext_3 = ciphertext_1.clone();
+    ///
+    /// // The slots of the second input are added to the last two slots of the first one
+    /// engine.discard_offset_add_glwe_ciphertext(
+    ///     &mut ciphertext_3,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     MonomialIndex(2),
+    ///     PlaintextCount(2),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_3.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_3.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_offset_add_glwe_ciphertext(
+        &mut self,
+        output: &mut CyclicGlweCiphertext64,
+        input_1: &CyclicGlweCiphertext64,
+        input_2: &CyclicGlweCiphertext64,
+        offset: MonomialIndex,
+        slot_count: PlaintextCount,
+    ) -> Result<(), GlweCiphertextDiscardingOffsetAdditionError<Self::EngineError>> {
+        GlweCiphertextDiscardingOffsetAdditionError::perform_generic_checks(
+            output, input_1, input_2, offset, slot_count,
+        )?;
+        unsafe {
+            self.discard_offset_add_glwe_ciphertext_unchecked(
+                output, input_1, input_2, offset, slot_count,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_offset_add_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CyclicGlweCiphertext64,
+        input_1: &CyclicGlweCiphertext64,
+        input_2: &CyclicGlweCiphertext64,
+        offset: MonomialIndex,
+        _slot_count: PlaintextCount,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input_1.0.as_tensor());
+        #[allow(deprecated)]
+        output
+            .0
+            .update_with_wrapping_add_cyclic_monomial_mul(&input_2.0, MonomialDegree(offset.0));
+    }
+}
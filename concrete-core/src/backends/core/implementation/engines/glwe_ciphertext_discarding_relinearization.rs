@@ -0,0 +1,185 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweRelinearizationKey32, GlweRelinearizationKey64,
+};
+use crate::specification::engines::{
+    GlweCiphertextDiscardingRelinearizationEngine, GlweCiphertextDiscardingRelinearizationError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingRelinearizationEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl
+    GlweCiphertextDiscardingRelinearizationEngine<
+        GlweRelinearizationKey32,
+        GlweCiphertext32,
+        GlweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let secret_key: GlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey32 = engine.create_glwe_relinearization_key(
+    ///     &secret_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let zeros = engine.create_plaintext_vector(&vec![0_u32; polynomial_size.0])?;
+    /// // A trivial ciphertext of the extended size 2 * (2 + 3) / 2 + 1, as output by a tensor
+    /// // product of two ciphertexts of dimension 2.
+    /// let ciphertext: GlweCiphertext32 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(GlweSize(6), &plaintext_vector)?;
+    /// let mut relinearized: GlweCiphertext32 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &zeros)?;
+    ///
+    /// engine.discard_relinearize_glwe_ciphertext(
+    ///     &mut relinearized,
+    ///     &ciphertext,
+    ///     &relinearization_key,
+    /// )?;
+    /// #
+    /// assert_eq!(relinearized.glwe_dimension(), glwe_dimension);
+    /// let decrypted = engine.decrypt_glwe_ciphertext(&secret_key, &relinearized)?;
+    /// assert_eq!(engine.retrieve_plaintext_vector(&decrypted)?, input);
+    ///
+    /// engine.destroy(secret_key)?;
+    /// engine.destroy(relinearization_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(zeros)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(relinearized)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_relinearize_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        key: &GlweRelinearizationKey32,
+    ) -> Result<(), GlweCiphertextDiscardingRelinearizationError<Self::EngineError>> {
+        GlweCiphertextDiscardingRelinearizationError::perform_generic_checks(output, input, key)?;
+        unsafe { self.discard_relinearize_glwe_ciphertext_unchecked(output, input, key) };
+        Ok(())
+    }
+
+    unsafe fn discard_relinearize_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        key: &GlweRelinearizationKey32,
+    ) {
+        key.0.relinearize(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingRelinearizationEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl
+    GlweCiphertextDiscardingRelinearizationEngine<
+        GlweRelinearizationKey64,
+        GlweCiphertext64,
+        GlweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let secret_key: GlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey64 = engine.create_glwe_relinearization_key(
+    ///     &secret_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let zeros = engine.create_plaintext_vector(&vec![0_u64; polynomial_size.0])?;
+    /// // A trivial ciphertext of the extended size 2 * (2 + 3) / 2 + 1, as output by a tensor
+    /// // product of two ciphertexts of dimension 2.
+    /// let ciphertext: GlweCiphertext64 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(GlweSize(6), &plaintext_vector)?;
+    /// let mut relinearized: GlweCiphertext64 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &zeros)?;
+    ///
+    /// engine.discard_relinearize_glwe_ciphertext(
+    ///     &mut relinearized,
+    ///     &ciphertext,
+    ///     &relinearization_key,
+    /// )?;
+    /// #
+    /// assert_eq!(relinearized.glwe_dimension(), glwe_dimension);
+    /// let decrypted = engine.decrypt_glwe_ciphertext(&secret_key, &relinearized)?;
+    /// assert_eq!(engine.retrieve_plaintext_vector(&decrypted)?, input);
+    ///
+    /// engine.destroy(secret_key)?;
+    /// engine.destroy(relinearization_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(zeros)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(relinearized)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_relinearize_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        key: &GlweRelinearizationKey64,
+    ) -> Result<(), GlweCiphertextDiscardingRelinearizationError<Self::EngineError>> {
+        GlweCiphertextDiscardingRelinearizationError::perform_generic_checks(output, input, key)?;
+        unsafe { self.discard_relinearize_glwe_ciphertext_unchecked(output, input, key) };
+        Ok(())
+    }
+
+    unsafe fn discard_relinearize_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        key: &GlweRelinearizationKey64,
+    ) {
+        key.0.relinearize(&mut output.0, &input.0);
+    }
+}
@@ -0,0 +1,314 @@
+use concrete_commons::parameters::ScalingFactor;
+
+use crate::backends::core::implementation::engines::{CoreEngine, CoreError};
+use crate::backends::core::implementation::entities::{GlweCiphertext32, GlweCiphertext64};
+use crate::specification::engines::{
+    GlweCiphertextDiscardingTensorProductEngine, GlweCiphertextDiscardingTensorProductError,
+};
+
+impl From<CoreError> for GlweCiphertextDiscardingTensorProductError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingTensorProductEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+///
+/// # Errors:
+/// Returns a [`CoreError::UnsupportedScalingFactor`] error if the scaling factor is not a power
+/// of two.
+impl
+    GlweCiphertextDiscardingTensorProductEngine<
+        GlweCiphertext32,
+        GlweCiphertext32,
+        GlweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize, ScalingFactor};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let polynomial_size = PolynomialSize(256);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let scale = ScalingFactor::from_log2(20);
+    /// let mut input_1 = vec![0_u32; polynomial_size.0];
+    /// input_1[0] = 3 << 20;
+    /// let mut input_2 = vec![0_u32; polynomial_size.0];
+    /// input_2[0] = 2 << 20;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let zeros = engine.create_plaintext_vector(&vec![0_u32; polynomial_size.0])?;
+    /// let ciphertext_1: GlweCiphertext32 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(GlweSize(2), &plaintext_vector_1)?;
+    /// let ciphertext_2: GlweCiphertext32 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(GlweSize(2), &plaintext_vector_2)?;
+    /// // The tensor product of two ciphertexts of dimension 1 has dimension 1 * (1 + 3) / 2.
+    /// let mut product: GlweCiphertext32 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(GlweSize(3), &zeros)?;
+    ///
+    /// engine.discard_tensor_product_glwe_ciphertext(
+    ///     &mut product,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     scale,
+    /// )?;
+    /// #
+    /// let decrypted = engine.trivially_decrypt_glwe_ciphertext(&product)?;
+    /// assert_eq!(engine.retrieve_plaintext_vector(&decrypted)?[0], 6 << 20);
+    ///
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(zeros)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(product)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_tensor_product_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input1: &GlweCiphertext32,
+        input2: &GlweCiphertext32,
+        scale: ScalingFactor,
+    ) -> Result<(), GlweCiphertextDiscardingTensorProductError<Self::EngineError>> {
+        if !scale.0.is_power_of_two() {
+            return Err(GlweCiphertextDiscardingTensorProductError::from(
+                CoreError::UnsupportedScalingFactor,
+            ));
+        }
+        GlweCiphertextDiscardingTensorProductError::perform_generic_checks(output, input1, input2)?;
+        unsafe {
+            self.discard_tensor_product_glwe_ciphertext_unchecked(output, input1, input2, scale)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_tensor_product_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input1: &GlweCiphertext32,
+        input2: &GlweCiphertext32,
+        scale: ScalingFactor,
+    ) {
+        output
+            .0
+            .fill_with_tensor_product(&input1.0, &input2.0, scale);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingTensorProductEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+///
+/// # Errors:
+/// Returns a [`CoreError::UnsupportedScalingFactor`] error if the scaling factor is not a power
+/// of two.
+impl
+    GlweCiphertextDiscardingTensorProductEngine<
+        GlweCiphertext64,
+        GlweCiphertext64,
+        GlweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize, ScalingFactor};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let polynomial_size = PolynomialSize(256);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let scale = ScalingFactor::from_log2(50);
+    /// let mut input_1 = vec![0_u64; polynomial_size.0];
+    /// input_1[0] = 3 << 50;
+    /// let mut input_2 = vec![0_u64; polynomial_size.0];
+    /// input_2[0] = 2 << 50;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let zeros = engine.create_plaintext_vector(&vec![0_u64; polynomial_size.0])?;
+    /// let ciphertext_1: GlweCiphertext64 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(GlweSize(2), &plaintext_vector_1)?;
+    /// let ciphertext_2: GlweCiphertext64 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(GlweSize(2), &plaintext_vector_2)?;
+    /// // The tensor product of two ciphertexts of dimension 1 has dimension 1 * (1 + 3) / 2.
+    /// let mut product: GlweCiphertext64 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(GlweSize(3), &zeros)?;
+    ///
+    /// engine.discard_tensor_product_glwe_ciphertext(
+    ///     &mut product,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     scale,
+    /// )?;
+    /// #
+    /// let decrypted = engine.trivially_decrypt_glwe_ciphertext(&product)?;
+    /// assert_eq!(engine.retrieve_plaintext_vector(&decrypted)?[0], 6 << 50);
+    ///
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(zeros)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(product)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_tensor_product_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input1: &GlweCiphertext64,
+        input2: &GlweCiphertext64,
+        scale: ScalingFactor,
+    ) -> Result<(), GlweCiphertextDiscardingTensorProductError<Self::EngineError>> {
+        if !scale.0.is_power_of_two() {
+            return Err(GlweCiphertextDiscardingTensorProductError::from(
+                CoreError::UnsupportedScalingFactor,
+            ));
+        }
+        GlweCiphertextDiscardingTensorProductError::perform_generic_checks(output, input1, input2)?;
+        unsafe {
+            self.discard_tensor_product_glwe_ciphertext_unchecked(output, input1, input2, scale)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_tensor_product_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input1: &GlweCiphertext64,
+        input2: &GlweCiphertext64,
+        scale: ScalingFactor,
+    ) {
+        output
+            .0
+            .fill_with_tensor_product(&input1.0, &input2.0, scale);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use concrete_commons::parameters::{GlweSize, PolynomialSize, ScalingFactor};
+
+    fn trivial_zero(
+        engine: &mut CoreEngine,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+    ) -> GlweCiphertext64 {
+        let zeros = engine
+            .create_plaintext_vector(&vec![0_u64; polynomial_size.0])
+            .unwrap();
+        engine
+            .trivially_encrypt_glwe_ciphertext(glwe_size, &zeros)
+            .unwrap()
+    }
+
+    fn tensor_product(
+        engine: &mut CoreEngine,
+        output: (GlweSize, PolynomialSize),
+        input1: (GlweSize, PolynomialSize),
+        input2: (GlweSize, PolynomialSize),
+    ) -> Result<(), GlweCiphertextDiscardingTensorProductError<CoreError>> {
+        let mut output = trivial_zero(engine, output.0, output.1);
+        let input1 = trivial_zero(engine, input1.0, input1.1);
+        let input2 = trivial_zero(engine, input2.0, input2.1);
+        engine.discard_tensor_product_glwe_ciphertext(
+            &mut output,
+            &input1,
+            &input2,
+            ScalingFactor::from_log2(40),
+        )
+    }
+
+    #[test]
+    fn test_polynomial_size_mismatch_is_rejected() {
+        let mut engine = CoreEngine::new().unwrap();
+        let (small, large) = (PolynomialSize(256), PolynomialSize(512));
+        assert!(matches!(
+            tensor_product(
+                &mut engine,
+                (GlweSize(3), small),
+                (GlweSize(2), small),
+                (GlweSize(2), large)
+            ),
+            Err(GlweCiphertextDiscardingTensorProductError::PolynomialSizeMismatch)
+        ));
+        assert!(matches!(
+            tensor_product(
+                &mut engine,
+                (GlweSize(3), large),
+                (GlweSize(2), small),
+                (GlweSize(2), small)
+            ),
+            Err(GlweCiphertextDiscardingTensorProductError::PolynomialSizeMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_glwe_dimension_mismatch_is_rejected() {
+        let mut engine = CoreEngine::new().unwrap();
+        let polynomial_size = PolynomialSize(256);
+        assert!(matches!(
+            tensor_product(
+                &mut engine,
+                (GlweSize(3), polynomial_size),
+                (GlweSize(2), polynomial_size),
+                (GlweSize(3), polynomial_size)
+            ),
+            Err(GlweCiphertextDiscardingTensorProductError::GlweDimensionMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_output_glwe_dimension_mismatch_is_rejected() {
+        let mut engine = CoreEngine::new().unwrap();
+        let polynomial_size = PolynomialSize(256);
+        for input_glwe_dimension in 1..4 {
+            let input_size = GlweSize(input_glwe_dimension + 1);
+            let output_glwe_dimension = input_glwe_dimension * (input_glwe_dimension + 3) / 2;
+            // The usual mistakes: the output has the input dimension, the dimension of the tensor
+            // product of the whole keys (with the body), or is off by one.
+            for wrong_glwe_dimension in [
+                input_glwe_dimension,
+                (input_glwe_dimension + 1) * (input_glwe_dimension + 2) / 2,
+                output_glwe_dimension - 1,
+                output_glwe_dimension + 1,
+            ] {
+                if wrong_glwe_dimension == output_glwe_dimension {
+                    continue;
+                }
+                assert!(matches!(
+                    tensor_product(
+                        &mut engine,
+                        (GlweSize(wrong_glwe_dimension + 1), polynomial_size),
+                        (input_size, polynomial_size),
+                        (input_size, polynomial_size)
+                    ),
+                    Err(GlweCiphertextDiscardingTensorProductError::OutputGlweDimensionMismatch)
+                ));
+            }
+            assert!(tensor_product(
+                &mut engine,
+                (GlweSize(output_glwe_dimension + 1), polynomial_size),
+                (input_size, polynomial_size),
+                (input_size, polynomial_size)
+            )
+            .is_ok());
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::backends::core::entities::{
+    GlweCiphertext32, GlweCiphertext64, PlaintextVector32, PlaintextVector64,
+};
+use crate::specification::engines::{
+    GlweCiphertextDiscardingTrivialEncryptionEngine, GlweCiphertextDiscardingTrivialEncryptionError,
+};
+
+use crate::backends::core::engines::CoreEngine;
+
+impl GlweCiphertextDiscardingTrivialEncryptionEngine<PlaintextVector32, GlweCiphertext32>
+    for CoreEngine
+{
+    /// # Example:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let zeros: PlaintextVector32 =
+    ///     engine.create_plaintext_vector(&vec![0_u32; polynomial_size.0])?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let mut ciphertext: GlweCiphertext32 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &zeros)?;
+    /// engine.discard_trivially_encrypt_glwe_ciphertext(&mut ciphertext, &plaintext_vector)?;
+    ///
+    /// let output: PlaintextVector32 = engine.trivially_decrypt_glwe_ciphertext(&ciphertext)?;
+    /// assert_eq!(engine.retrieve_plaintext_vector(&output)?, input);
+    ///
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(zeros)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_trivially_encrypt_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &PlaintextVector32,
+    ) -> Result<(), GlweCiphertextDiscardingTrivialEncryptionError<Self::EngineError>> {
+        GlweCiphertextDiscardingTrivialEncryptionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_trivially_encrypt_glwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_trivially_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &PlaintextVector32,
+    ) {
+        output.0.fill_with_trivial_encryption(&input.0);
+    }
+}
+
+impl GlweCiphertextDiscardingTrivialEncryptionEngine<PlaintextVector64, GlweCiphertext64>
+    for CoreEngine
+{
+    /// # Example:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let zeros: PlaintextVector64 =
+    ///     engine.create_plaintext_vector(&vec![0_u64; polynomial_size.0])?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let mut ciphertext: GlweCiphertext64 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &zeros)?;
+    /// engine.discard_trivially_encrypt_glwe_ciphertext(&mut ciphertext, &plaintext_vector)?;
+    ///
+    /// let output: PlaintextVector64 = engine.trivially_decrypt_glwe_ciphertext(&ciphertext)?;
+    /// assert_eq!(engine.retrieve_plaintext_vector(&output)?, input);
+    ///
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(zeros)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_trivially_encrypt_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &PlaintextVector64,
+    ) -> Result<(), GlweCiphertextDiscardingTrivialEncryptionError<Self::EngineError>> {
+        GlweCiphertextDiscardingTrivialEncryptionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_trivially_encrypt_glwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_trivially_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &PlaintextVector64,
+    ) {
+        output.0.fill_with_trivial_encryption(&input.0);
+    }
+}
@@ -50,7 +50,7 @@ impl
     ///
     /// let ggsw = engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext_ggsw, noise, level, base_log)?;
     /// let complex_ggsw: FourierGgswCiphertext32 = engine.convert_ggsw_ciphertext(&ggsw)?;
-    /// let glwe = engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    /// let glwe: GlweCiphertext32 = engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
     ///
     /// // We allocate an output ciphertext simply by cloning the input.
     /// // The content of this output ciphertext will by wiped by the external product.
@@ -149,7 +149,7 @@ impl
     ///
     /// let ggsw = engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext_ggsw, noise, level, base_log)?;
     /// let complex_ggsw: FourierGgswCiphertext64 = engine.convert_ggsw_ciphertext(&ggsw)?;
-    /// let glwe = engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    /// let glwe: GlweCiphertext64 = engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
     ///
     /// // We allocate an output ciphertext simply by cloning the input.
     /// // The content of this output ciphertext will by wiped by the external product.
@@ -52,7 +52,7 @@ impl
     ///
     /// let ggsw = engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext_ggsw, noise, level, base_log)?;
     /// let complex_ggsw: FourierGgswCiphertext32 = engine.convert_ggsw_ciphertext(&ggsw)?;
-    /// let glwe = engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    /// let glwe: GlweCiphertext32 = engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
     ///
     /// // Compute the external product.
     /// let product = engine.compute_external_product_glwe_ciphertext_ggsw_ciphertext(&glwe, &complex_ggsw)?;
@@ -150,7 +150,7 @@ impl
     ///
     /// let ggsw = engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext_ggsw, noise, level, base_log)?;
     /// let complex_ggsw: FourierGgswCiphertext64 = engine.convert_ggsw_ciphertext(&ggsw)?;
-    /// let glwe = engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    /// let glwe: GlweCiphertext64 = engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
     ///
     /// // Compute the external product.
     /// let product = engine.compute_external_product_glwe_ciphertext_ggsw_ciphertext(&glwe, &complex_ggsw)?;
@@ -0,0 +1,104 @@
+use concrete_commons::parameters::CiphertextLayout;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{GlweCiphertext32, GlweCiphertext64};
+use crate::backends::core::private::math::tensor::IntoTensor;
+use crate::specification::engines::{
+    GlweCiphertextLayoutConsumingRetrievalEngine, GlweCiphertextLayoutConsumingRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextLayoutConsumingRetrievalEngine`] for [`CoreEngine`] which
+/// returns the vector of 32 bits integers of the ciphertext, reordering its polynomials in place
+/// from the [`CiphertextLayout::MaskFirst`] layout of the `core` backend.
+impl GlweCiphertextLayoutConsumingRetrievalEngine<GlweCiphertext32, Vec<u32>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextLayout, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // A ciphertext of dimension 1 and polynomial size 2, laid out as (A_0, B).
+    /// let container = vec![1_u32, 2, 3, 4];
+    /// let layout = CiphertextLayout::MaskFirst;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext: GlweCiphertext32 =
+    ///     engine.create_glwe_ciphertext_with_layout(container, PolynomialSize(2), layout)?;
+    /// let container: Vec<u32> = engine
+    ///     .consume_retrieve_glwe_ciphertext_with_layout(ciphertext, CiphertextLayout::BodyFirst)?;
+    /// #
+    /// assert_eq!(container, vec![3, 4, 1, 2]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_glwe_ciphertext_with_layout(
+        &mut self,
+        ciphertext: GlweCiphertext32,
+        layout: CiphertextLayout,
+    ) -> Result<Vec<u32>, GlweCiphertextLayoutConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe {
+            self.consume_retrieve_glwe_ciphertext_with_layout_unchecked(ciphertext, layout)
+        })
+    }
+
+    unsafe fn consume_retrieve_glwe_ciphertext_with_layout_unchecked(
+        &mut self,
+        ciphertext: GlweCiphertext32,
+        layout: CiphertextLayout,
+    ) -> Vec<u32> {
+        let mut ciphertext = ciphertext.0;
+        ciphertext.reorder_layout(CiphertextLayout::MaskFirst, layout);
+        ciphertext.into_tensor().into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextLayoutConsumingRetrievalEngine`] for [`CoreEngine`] which
+/// returns the vector of 64 bits integers of the ciphertext, reordering its polynomials in place
+/// from the [`CiphertextLayout::MaskFirst`] layout of the `core` backend.
+impl GlweCiphertextLayoutConsumingRetrievalEngine<GlweCiphertext64, Vec<u64>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextLayout, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // A ciphertext of dimension 1 and polynomial size 2, laid out as (A_0, B).
+    /// let container = vec![1_u64, 2, 3, 4];
+    /// let layout = CiphertextLayout::MaskFirst;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext: GlweCiphertext64 =
+    ///     engine.create_glwe_ciphertext_with_layout(container, PolynomialSize(2), layout)?;
+    /// let container: Vec<u64> = engine
+    ///     .consume_retrieve_glwe_ciphertext_with_layout(ciphertext, CiphertextLayout::BodyFirst)?;
+    /// #
+    /// assert_eq!(container, vec![3, 4, 1, 2]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_glwe_ciphertext_with_layout(
+        &mut self,
+        ciphertext: GlweCiphertext64,
+        layout: CiphertextLayout,
+    ) -> Result<Vec<u64>, GlweCiphertextLayoutConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe {
+            self.consume_retrieve_glwe_ciphertext_with_layout_unchecked(ciphertext, layout)
+        })
+    }
+
+    unsafe fn consume_retrieve_glwe_ciphertext_with_layout_unchecked(
+        &mut self,
+        ciphertext: GlweCiphertext64,
+        layout: CiphertextLayout,
+    ) -> Vec<u64> {
+        let mut ciphertext = ciphertext.0;
+        ciphertext.reorder_layout(CiphertextLayout::MaskFirst, layout);
+        ciphertext.into_tensor().into_container()
+    }
+}
@@ -0,0 +1,258 @@
+use concrete_commons::parameters::{CiphertextLayout, PolynomialSize};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{GlweCiphertext32, GlweCiphertext64};
+use crate::backends::core::private::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::specification::engines::{
+    GlweCiphertextLayoutCreationEngine, GlweCiphertextLayoutCreationError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextLayoutCreationEngine`] for [`CoreEngine`] which wraps a
+/// vector of 32 bits integers, reordering its polynomials in place to the
+/// [`CiphertextLayout::MaskFirst`] layout of the `core` backend.
+impl GlweCiphertextLayoutCreationEngine<Vec<u32>, GlweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextLayout, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // A ciphertext of dimension 1 and polynomial size 2, laid out as (B, A_0).
+    /// let container = vec![3_u32, 4, 1, 2];
+    /// let layout = CiphertextLayout::BodyFirst;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext: GlweCiphertext32 =
+    ///     engine.create_glwe_ciphertext_with_layout(container, PolynomialSize(2), layout)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), GlweDimension(1));
+    /// assert_eq!(ciphertext.polynomial_size(), PolynomialSize(2));
+    ///
+    /// let container: Vec<u32> = engine
+    ///     .consume_retrieve_glwe_ciphertext_with_layout(ciphertext, CiphertextLayout::MaskFirst)?;
+    /// assert_eq!(container, vec![1, 2, 3, 4]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext_with_layout(
+        &mut self,
+        container: Vec<u32>,
+        polynomial_size: PolynomialSize,
+        layout: CiphertextLayout,
+    ) -> Result<GlweCiphertext32, GlweCiphertextLayoutCreationError<Self::EngineError>> {
+        GlweCiphertextLayoutCreationError::perform_generic_checks(
+            container.len(),
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.create_glwe_ciphertext_with_layout_unchecked(container, polynomial_size, layout)
+        })
+    }
+
+    unsafe fn create_glwe_ciphertext_with_layout_unchecked(
+        &mut self,
+        container: Vec<u32>,
+        polynomial_size: PolynomialSize,
+        layout: CiphertextLayout,
+    ) -> GlweCiphertext32 {
+        let mut ciphertext = ImplGlweCiphertext::from_container(container, polynomial_size);
+        ciphertext.reorder_layout(layout, CiphertextLayout::MaskFirst);
+        GlweCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextLayoutCreationEngine`] for [`CoreEngine`] which wraps a
+/// vector of 64 bits integers, reordering its polynomials in place to the
+/// [`CiphertextLayout::MaskFirst`] layout of the `core` backend.
+impl GlweCiphertextLayoutCreationEngine<Vec<u64>, GlweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextLayout, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // A ciphertext of dimension 1 and polynomial size 2, laid out as (B, A_0).
+    /// let container = vec![3_u64, 4, 1, 2];
+    /// let layout = CiphertextLayout::BodyFirst;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext: GlweCiphertext64 =
+    ///     engine.create_glwe_ciphertext_with_layout(container, PolynomialSize(2), layout)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), GlweDimension(1));
+    /// assert_eq!(ciphertext.polynomial_size(), PolynomialSize(2));
+    ///
+    /// let container: Vec<u64> = engine
+    ///     .consume_retrieve_glwe_ciphertext_with_layout(ciphertext, CiphertextLayout::MaskFirst)?;
+    /// assert_eq!(container, vec![1, 2, 3, 4]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext_with_layout(
+        &mut self,
+        container: Vec<u64>,
+        polynomial_size: PolynomialSize,
+        layout: CiphertextLayout,
+    ) -> Result<GlweCiphertext64, GlweCiphertextLayoutCreationError<Self::EngineError>> {
+        GlweCiphertextLayoutCreationError::perform_generic_checks(
+            container.len(),
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.create_glwe_ciphertext_with_layout_unchecked(container, polynomial_size, layout)
+        })
+    }
+
+    unsafe fn create_glwe_ciphertext_with_layout_unchecked(
+        &mut self,
+        container: Vec<u64>,
+        polynomial_size: PolynomialSize,
+        layout: CiphertextLayout,
+    ) -> GlweCiphertext64 {
+        let mut ciphertext = ImplGlweCiphertext::from_container(container, polynomial_size);
+        ciphertext.reorder_layout(layout, CiphertextLayout::MaskFirst);
+        GlweCiphertext64(ciphertext)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use concrete_commons::parameters::{CiphertextLayout, GlweDimension, PolynomialSize};
+    use std::convert::TryInto;
+
+    // A ciphertext of dimension 2 and polynomial size 2, the body-first vectors holding the little
+    // endian bytes of consecutive words, e.g. (B, A_0, A_1) = ((0x03020100, 0x07060504),
+    // (0x0b0a0908, 0x0f0e0d0c), (0x13121110, 0x17161514)) on 32 bits.
+    // The vectors are built by hand to pin the order of the coefficients and the byte order of the
+    // containers, they do not come from another implementation.
+    const POLYNOMIAL_SIZE: PolynomialSize = PolynomialSize(2);
+    const BODY_FIRST_GLWE32: [u8; 24] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+    ];
+    const MASK_FIRST_GLWE32: [u8; 24] = [
+        0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16,
+        0x17, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+    ];
+    const BODY_FIRST_GLWE64: [u8; 48] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d,
+        0x1e, 0x1f, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c,
+        0x2d, 0x2e, 0x2f,
+    ];
+    const MASK_FIRST_GLWE64: [u8; 48] = [
+        0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e,
+        0x1f, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d,
+        0x2e, 0x2f, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c,
+        0x0d, 0x0e, 0x0f,
+    ];
+
+    fn words_32(bytes: &[u8]) -> Vec<u32> {
+        bytes
+            .chunks_exact(4)
+            .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect()
+    }
+
+    fn words_64(bytes: &[u8]) -> Vec<u64> {
+        bytes
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+            .collect()
+    }
+
+    fn bytes_32(words: &[u32]) -> Vec<u8> {
+        words.iter().flat_map(|word| word.to_le_bytes()).collect()
+    }
+
+    fn bytes_64(words: &[u64]) -> Vec<u8> {
+        words.iter().flat_map(|word| word.to_le_bytes()).collect()
+    }
+
+    #[test]
+    fn test_glwe_ciphertext_layout_bytes_32() {
+        let mut engine = CoreEngine::new().unwrap();
+        let ciphertext: GlweCiphertext32 = engine
+            .create_glwe_ciphertext_with_layout(
+                words_32(&BODY_FIRST_GLWE32),
+                POLYNOMIAL_SIZE,
+                CiphertextLayout::BodyFirst,
+            )
+            .unwrap();
+        assert_eq!(ciphertext.glwe_dimension(), GlweDimension(2));
+        let mask_first: Vec<u32> = engine
+            .consume_retrieve_glwe_ciphertext_with_layout(ciphertext, CiphertextLayout::MaskFirst)
+            .unwrap();
+        assert_eq!(bytes_32(&mask_first), MASK_FIRST_GLWE32);
+
+        let ciphertext: GlweCiphertext32 = engine
+            .create_glwe_ciphertext_with_layout(
+                mask_first,
+                POLYNOMIAL_SIZE,
+                CiphertextLayout::MaskFirst,
+            )
+            .unwrap();
+        let body_first: Vec<u32> = engine
+            .consume_retrieve_glwe_ciphertext_with_layout(ciphertext, CiphertextLayout::BodyFirst)
+            .unwrap();
+        assert_eq!(bytes_32(&body_first), BODY_FIRST_GLWE32);
+
+        let mask_first: Vec<u32> = engine
+            .convert_glwe_ciphertext_vector_layout(
+                words_32(&BODY_FIRST_GLWE32),
+                GlweDimension(2).to_glwe_size(),
+                POLYNOMIAL_SIZE,
+                CiphertextLayout::BodyFirst,
+                CiphertextLayout::MaskFirst,
+            )
+            .unwrap();
+        assert_eq!(bytes_32(&mask_first), MASK_FIRST_GLWE32);
+    }
+
+    #[test]
+    fn test_glwe_ciphertext_layout_bytes_64() {
+        let mut engine = CoreEngine::new().unwrap();
+        let ciphertext: GlweCiphertext64 = engine
+            .create_glwe_ciphertext_with_layout(
+                words_64(&BODY_FIRST_GLWE64),
+                POLYNOMIAL_SIZE,
+                CiphertextLayout::BodyFirst,
+            )
+            .unwrap();
+        assert_eq!(ciphertext.glwe_dimension(), GlweDimension(2));
+        let mask_first: Vec<u64> = engine
+            .consume_retrieve_glwe_ciphertext_with_layout(ciphertext, CiphertextLayout::MaskFirst)
+            .unwrap();
+        assert_eq!(bytes_64(&mask_first), MASK_FIRST_GLWE64);
+
+        let ciphertext: GlweCiphertext64 = engine
+            .create_glwe_ciphertext_with_layout(
+                mask_first,
+                POLYNOMIAL_SIZE,
+                CiphertextLayout::MaskFirst,
+            )
+            .unwrap();
+        let body_first: Vec<u64> = engine
+            .consume_retrieve_glwe_ciphertext_with_layout(ciphertext, CiphertextLayout::BodyFirst)
+            .unwrap();
+        assert_eq!(bytes_64(&body_first), BODY_FIRST_GLWE64);
+
+        let mask_first: Vec<u64> = engine
+            .convert_glwe_ciphertext_vector_layout(
+                words_64(&BODY_FIRST_GLWE64),
+                GlweDimension(2).to_glwe_size(),
+                POLYNOMIAL_SIZE,
+                CiphertextLayout::BodyFirst,
+                CiphertextLayout::MaskFirst,
+            )
+            .unwrap();
+        assert_eq!(bytes_64(&mask_first), MASK_FIRST_GLWE64);
+    }
+}
@@ -0,0 +1,167 @@
+use crate::backends::core::implementation::engines::{CoreEngine, CoreError};
+use crate::backends::core::implementation::entities::{
+    GlweAutomorphismKey32, GlweAutomorphismKey64, GlweCiphertext32, GlweCiphertext64,
+};
+use crate::backends::core::private::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::specification::engines::{GlweCiphertextTraceEngine, GlweCiphertextTraceError};
+use crate::specification::entities::GlweCiphertextEntity;
+
+impl From<CoreError> for GlweCiphertextTraceError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextTraceEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers.
+impl GlweCiphertextTraceEngine<GlweAutomorphismKey32, GlweCiphertext32, GlweCiphertext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let secret_key: GlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_key: GlweAutomorphismKey32 = engine.create_glwe_automorphism_key(
+    ///     &secret_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext: GlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&secret_key, &plaintext_vector, noise)?;
+    ///
+    /// // The output encrypts the constant polynomial 256 * (3 << 20).
+    /// let trace = engine.trace_glwe_ciphertext(&automorphism_key, &ciphertext)?;
+    /// #
+    /// assert_eq!(trace.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(trace.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(secret_key)?;
+    /// engine.destroy(automorphism_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(trace)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trace_glwe_ciphertext(
+        &mut self,
+        key: &GlweAutomorphismKey32,
+        input: &GlweCiphertext32,
+    ) -> Result<GlweCiphertext32, GlweCiphertextTraceError<Self::EngineError>> {
+        GlweCiphertextTraceError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.trace_glwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn trace_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweAutomorphismKey32,
+        input: &GlweCiphertext32,
+    ) -> GlweCiphertext32 {
+        let mut output = ImplGlweCiphertext::allocate(
+            0u32,
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+        );
+        key.0.trace(&mut output, &input.0);
+        GlweCiphertext32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextTraceEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers.
+impl GlweCiphertextTraceEngine<GlweAutomorphismKey64, GlweCiphertext64, GlweCiphertext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let secret_key: GlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_key: GlweAutomorphismKey64 = engine.create_glwe_automorphism_key(
+    ///     &secret_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext: GlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&secret_key, &plaintext_vector, noise)?;
+    ///
+    /// // The output encrypts the constant polynomial 256 * (3 << 50).
+    /// let trace = engine.trace_glwe_ciphertext(&automorphism_key, &ciphertext)?;
+    /// #
+    /// assert_eq!(trace.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(trace.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(secret_key)?;
+    /// engine.destroy(automorphism_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(trace)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trace_glwe_ciphertext(
+        &mut self,
+        key: &GlweAutomorphismKey64,
+        input: &GlweCiphertext64,
+    ) -> Result<GlweCiphertext64, GlweCiphertextTraceError<Self::EngineError>> {
+        GlweCiphertextTraceError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.trace_glwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn trace_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweAutomorphismKey64,
+        input: &GlweCiphertext64,
+    ) -> GlweCiphertext64 {
+        let mut output = ImplGlweCiphertext::allocate(
+            0u64,
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+        );
+        key.0.trace(&mut output, &input.0);
+        GlweCiphertext64(output)
+    }
+}
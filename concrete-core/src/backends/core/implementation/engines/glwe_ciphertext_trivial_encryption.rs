@@ -1,7 +1,8 @@
 use concrete_commons::parameters::GlweSize;
 
 use crate::backends::core::entities::{
-    GlweCiphertext32, GlweCiphertext64, PlaintextVector32, PlaintextVector64,
+    GlweCiphertext128, GlweCiphertext16, GlweCiphertext32, GlweCiphertext64, PlaintextVector128,
+    PlaintextVector16, PlaintextVector32, PlaintextVector64,
 };
 use crate::backends::core::private::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
 use crate::specification::engines::{
@@ -10,6 +11,55 @@ use crate::specification::engines::{
 
 use crate::backends::core::engines::CoreEngine;
 
+impl GlweCiphertextTrivialEncryptionEngine<PlaintextVector16, GlweCiphertext16> for CoreEngine {
+    /// # Example:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let input = vec![3_u16 << 10; polynomial_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext_vector: PlaintextVector16 = engine.create_plaintext_vector(&input)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let ciphertext: GlweCiphertext16 = engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)?;
+    ///
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_glwe_ciphertext(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector16,
+    ) -> Result<GlweCiphertext16, GlweCiphertextTrivialEncryptionError<Self::EngineError>> {
+        unsafe { Ok(self.trivially_encrypt_glwe_ciphertext_unchecked(glwe_size, input)) }
+    }
+
+    unsafe fn trivially_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector16,
+    ) -> GlweCiphertext16 {
+        let ciphertext: ImplGlweCiphertext<Vec<u16>> =
+            ImplGlweCiphertext::new_trivial_encryption(glwe_size, &input.0);
+        GlweCiphertext16(ciphertext)
+    }
+}
+
 impl GlweCiphertextTrivialEncryptionEngine<PlaintextVector32, GlweCiphertext32> for CoreEngine {
     /// # Example:
     ///
@@ -107,3 +157,52 @@ impl GlweCiphertextTrivialEncryptionEngine<PlaintextVector64, GlweCiphertext64>
         GlweCiphertext64(ciphertext)
     }
 }
+
+impl GlweCiphertextTrivialEncryptionEngine<PlaintextVector128, GlweCiphertext128> for CoreEngine {
+    /// # Example:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let input = vec![3_u128 << 20; polynomial_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext_vector: PlaintextVector128 = engine.create_plaintext_vector(&input)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let ciphertext: GlweCiphertext128 = engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)?;
+    ///
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_glwe_ciphertext(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector128,
+    ) -> Result<GlweCiphertext128, GlweCiphertextTrivialEncryptionError<Self::EngineError>> {
+        unsafe { Ok(self.trivially_encrypt_glwe_ciphertext_unchecked(glwe_size, input)) }
+    }
+
+    unsafe fn trivially_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector128,
+    ) -> GlweCiphertext128 {
+        let ciphertext: ImplGlweCiphertext<Vec<u128>> =
+            ImplGlweCiphertext::new_trivial_encryption(glwe_size, &input.0);
+        GlweCiphertext128(ciphertext)
+    }
+}
@@ -84,7 +84,7 @@ impl
             noise,
             &mut self.encryption_generator,
         );
-        GlweCiphertextVector32(ciphertext_vector)
+        self.audit_creation(GlweCiphertextVector32(ciphertext_vector))
     }
 }
 
@@ -160,6 +160,6 @@ impl
             noise,
             &mut self.encryption_generator,
         );
-        GlweCiphertextVector64(ciphertext_vector)
+        self.audit_creation(GlweCiphertextVector64(ciphertext_vector))
     }
 }
@@ -0,0 +1,160 @@
+use concrete_commons::parameters::{CiphertextLayout, GlweSize, PolynomialSize};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::private::crypto::glwe::GlweList as ImplGlweList;
+use crate::backends::core::private::math::tensor::IntoTensor;
+use crate::specification::engines::{
+    GlweCiphertextVectorLayoutConversionEngine, GlweCiphertextVectorLayoutConversionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorLayoutConversionEngine`] for [`CoreEngine`] which
+/// reorders the polynomials of a vector of 32 bits integers in place.
+impl GlweCiphertextVectorLayoutConversionEngine<Vec<u32>, Vec<u32>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextLayout, GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Two ciphertexts of dimension 1 and polynomial size 2, laid out as (B, A_0).
+    /// let body_first = vec![3_u32, 4, 1, 2, 7, 8, 5, 6];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let mask_first: Vec<u32> = engine.convert_glwe_ciphertext_vector_layout(
+    ///     body_first.clone(),
+    ///     GlweSize(2),
+    ///     PolynomialSize(2),
+    ///     CiphertextLayout::BodyFirst,
+    ///     CiphertextLayout::MaskFirst,
+    /// )?;
+    /// #
+    /// assert_eq!(mask_first, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    ///
+    /// let round_trip: Vec<u32> = engine.convert_glwe_ciphertext_vector_layout(
+    ///     mask_first,
+    ///     GlweSize(2),
+    ///     PolynomialSize(2),
+    ///     CiphertextLayout::MaskFirst,
+    ///     CiphertextLayout::BodyFirst,
+    /// )?;
+    /// assert_eq!(round_trip, body_first);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_ciphertext_vector_layout(
+        &mut self,
+        input: Vec<u32>,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        input_layout: CiphertextLayout,
+        output_layout: CiphertextLayout,
+    ) -> Result<Vec<u32>, GlweCiphertextVectorLayoutConversionError<Self::EngineError>> {
+        GlweCiphertextVectorLayoutConversionError::perform_generic_checks(
+            input.len(),
+            glwe_size,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.convert_glwe_ciphertext_vector_layout_unchecked(
+                input,
+                glwe_size,
+                polynomial_size,
+                input_layout,
+                output_layout,
+            )
+        })
+    }
+
+    unsafe fn convert_glwe_ciphertext_vector_layout_unchecked(
+        &mut self,
+        input: Vec<u32>,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        input_layout: CiphertextLayout,
+        output_layout: CiphertextLayout,
+    ) -> Vec<u32> {
+        let mut list =
+            ImplGlweList::from_container(input, glwe_size.to_glwe_dimension(), polynomial_size);
+        list.reorder_layout(input_layout, output_layout);
+        list.into_tensor().into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorLayoutConversionEngine`] for [`CoreEngine`] which
+/// reorders the polynomials of a vector of 64 bits integers in place.
+impl GlweCiphertextVectorLayoutConversionEngine<Vec<u64>, Vec<u64>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextLayout, GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Two ciphertexts of dimension 1 and polynomial size 2, laid out as (B, A_0).
+    /// let body_first = vec![3_u64, 4, 1, 2, 7, 8, 5, 6];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let mask_first: Vec<u64> = engine.convert_glwe_ciphertext_vector_layout(
+    ///     body_first.clone(),
+    ///     GlweSize(2),
+    ///     PolynomialSize(2),
+    ///     CiphertextLayout::BodyFirst,
+    ///     CiphertextLayout::MaskFirst,
+    /// )?;
+    /// #
+    /// assert_eq!(mask_first, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    ///
+    /// let round_trip: Vec<u64> = engine.convert_glwe_ciphertext_vector_layout(
+    ///     mask_first,
+    ///     GlweSize(2),
+    ///     PolynomialSize(2),
+    ///     CiphertextLayout::MaskFirst,
+    ///     CiphertextLayout::BodyFirst,
+    /// )?;
+    /// assert_eq!(round_trip, body_first);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_ciphertext_vector_layout(
+        &mut self,
+        input: Vec<u64>,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        input_layout: CiphertextLayout,
+        output_layout: CiphertextLayout,
+    ) -> Result<Vec<u64>, GlweCiphertextVectorLayoutConversionError<Self::EngineError>> {
+        GlweCiphertextVectorLayoutConversionError::perform_generic_checks(
+            input.len(),
+            glwe_size,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.convert_glwe_ciphertext_vector_layout_unchecked(
+                input,
+                glwe_size,
+                polynomial_size,
+                input_layout,
+                output_layout,
+            )
+        })
+    }
+
+    unsafe fn convert_glwe_ciphertext_vector_layout_unchecked(
+        &mut self,
+        input: Vec<u64>,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        input_layout: CiphertextLayout,
+        output_layout: CiphertextLayout,
+    ) -> Vec<u64> {
+        let mut list =
+            ImplGlweList::from_container(input, glwe_size.to_glwe_dimension(), polynomial_size);
+        list.reorder_layout(input_layout, output_layout);
+        list.into_tensor().into_container()
+    }
+}
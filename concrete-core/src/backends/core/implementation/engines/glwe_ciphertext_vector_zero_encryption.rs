@@ -75,7 +75,7 @@ impl GlweCiphertextVectorZeroEncryptionEngine<GlweSecretKey32, GlweCiphertextVec
             noise,
             &mut self.encryption_generator,
         );
-        GlweCiphertextVector32(ciphertext_vector)
+        self.audit_creation(GlweCiphertextVector32(ciphertext_vector))
     }
 }
 
@@ -143,6 +143,6 @@ impl GlweCiphertextVectorZeroEncryptionEngine<GlweSecretKey64, GlweCiphertextVec
             noise,
             &mut self.encryption_generator,
         );
-        GlweCiphertextVector64(ciphertext_vector)
+        self.audit_creation(GlweCiphertextVector64(ciphertext_vector))
     }
 }
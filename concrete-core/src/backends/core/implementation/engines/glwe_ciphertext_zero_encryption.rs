@@ -61,7 +61,7 @@ impl GlweCiphertextZeroEncryptionEngine<GlweSecretKey32, GlweCiphertext32> for C
         );
         key.0
             .encrypt_zero_glwe(&mut ciphertext, noise, &mut self.encryption_generator);
-        GlweCiphertext32(ciphertext)
+        self.audit_creation(GlweCiphertext32(ciphertext))
     }
 }
 
@@ -116,6 +116,6 @@ impl GlweCiphertextZeroEncryptionEngine<GlweSecretKey64, GlweCiphertext64> for C
         );
         key.0
             .encrypt_zero_glwe(&mut ciphertext, noise, &mut self.encryption_generator);
-        GlweCiphertext64(ciphertext)
+        self.audit_creation(GlweCiphertext64(ciphertext))
     }
 }
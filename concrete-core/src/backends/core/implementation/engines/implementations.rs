@@ -0,0 +1,1099 @@
+//! The table of the operations implemented by the [`CoreEngine`].
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::*;
+use crate::backends::core::implementation::memory::{AlignedBuffer, SharedSlice};
+use crate::specification::engines::*;
+
+engine_implementations! {
+    CoreEngine {
+        CleartextCreationEngine(u32, Cleartext32),
+        CleartextCreationEngine(u64, Cleartext64),
+        CleartextDiscardingRetrievalEngine(Cleartext32, u32),
+        CleartextDiscardingRetrievalEngine(Cleartext64, u64),
+        CleartextRetrievalEngine(Cleartext32, u32),
+        CleartextRetrievalEngine(Cleartext64, u64),
+        CleartextVectorCreationEngine(u8, CleartextVector32),
+        CleartextVectorCreationEngine(u8, CleartextVector64),
+        CleartextVectorCreationEngine(u16, CleartextVector32),
+        CleartextVectorCreationEngine(u16, CleartextVector64),
+        CleartextVectorCreationEngine(u32, CleartextVector32),
+        CleartextVectorCreationEngine(u32, CleartextVector64),
+        CleartextVectorCreationEngine(u64, CleartextVector64),
+        CleartextVectorDiscardingRetrievalEngine(CleartextVector32, u32),
+        CleartextVectorDiscardingRetrievalEngine(CleartextVector64, u64),
+        CleartextVectorNegacyclicConvolutionEngine(
+            CleartextVector32,
+            CleartextVector32,
+            CleartextVector32,
+        ),
+        CleartextVectorNegacyclicConvolutionEngine(
+            CleartextVector64,
+            CleartextVector64,
+            CleartextVector64,
+        ),
+        CleartextVectorRetrievalEngine(CleartextVector32, u8),
+        CleartextVectorRetrievalEngine(CleartextVector32, u16),
+        CleartextVectorRetrievalEngine(CleartextVector32, u32),
+        CleartextVectorRetrievalEngine(CleartextVector64, u8),
+        CleartextVectorRetrievalEngine(CleartextVector64, u16),
+        CleartextVectorRetrievalEngine(CleartextVector64, u32),
+        CleartextVectorRetrievalEngine(CleartextVector64, u64),
+        DestructionEngine(AccumulatingCiphertext<LweCiphertext32>),
+        DestructionEngine(AccumulatingCiphertext<LweCiphertext64>),
+        DestructionEngine(AlignedLweBootstrapKey32),
+        DestructionEngine(AlignedLweBootstrapKey64),
+        DestructionEngine(AlignedLweCiphertextVector32),
+        DestructionEngine(AlignedLweCiphertextVector64),
+        DestructionEngine(Cleartext32),
+        DestructionEngine(Cleartext64),
+        DestructionEngine(CleartextVector32),
+        DestructionEngine(CleartextVector64),
+        DestructionEngine(ColumnMajorLweKeyswitchKey32),
+        DestructionEngine(ColumnMajorLweKeyswitchKey64),
+        DestructionEngine(CyclicGlweCiphertext32),
+        DestructionEngine(CyclicGlweCiphertext64),
+        DestructionEngine(FourierGgswCiphertext32),
+        DestructionEngine(FourierGgswCiphertext64),
+        DestructionEngine(FourierGlweCiphertext32),
+        DestructionEngine(FourierGlweCiphertext64),
+        DestructionEngine(FourierLweBootstrapKey32),
+        DestructionEngine(FourierLweBootstrapKey64),
+        DestructionEngine(GgswCiphertext32),
+        DestructionEngine(GgswCiphertext64),
+        DestructionEngine(GgswSeededCiphertext32),
+        DestructionEngine(GgswSeededCiphertext64),
+        DestructionEngine(GlweAutomorphismKey32),
+        DestructionEngine(GlweAutomorphismKey64),
+        DestructionEngine(GlweCiphertext16),
+        DestructionEngine(GlweCiphertext32),
+        DestructionEngine(GlweCiphertext64),
+        DestructionEngine(GlweCiphertext128),
+        DestructionEngine(GlweCiphertextVector32),
+        DestructionEngine(GlweCiphertextVector64),
+        DestructionEngine(GlweRelinearizationKey32),
+        DestructionEngine(GlweRelinearizationKey64),
+        DestructionEngine(GlweSecretKey16),
+        DestructionEngine(GlweSecretKey32),
+        DestructionEngine(GlweSecretKey64),
+        DestructionEngine(GlweSecretKey128),
+        DestructionEngine(LweBootstrapKey32),
+        DestructionEngine(LweBootstrapKey64),
+        DestructionEngine(LweCiphertext16),
+        DestructionEngine(LweCiphertext32),
+        DestructionEngine(LweCiphertext64),
+        DestructionEngine(LweCiphertext128),
+        DestructionEngine(LweCiphertextJaggedVector32),
+        DestructionEngine(LweCiphertextJaggedVector64),
+        DestructionEngine(LweCiphertextTruncatedView32<'static>),
+        DestructionEngine(LweCiphertextVector32),
+        DestructionEngine(LweCiphertextVector64),
+        DestructionEngine(LweCiphertextVectorView32<'static>),
+        DestructionEngine(LweCiphertextVectorView64<'static>),
+        DestructionEngine(LweKeyswitchKey16),
+        DestructionEngine(LweKeyswitchKey32),
+        DestructionEngine(LweKeyswitchKey64),
+        DestructionEngine(LweKeyswitchKey128),
+        DestructionEngine(LweKeyswitchKeyBatch32),
+        DestructionEngine(LweKeyswitchKeyBatch64),
+        DestructionEngine(LweKeyswitchKeyDelta32),
+        DestructionEngine(LweKeyswitchKeyDelta64),
+        DestructionEngine(LwePublicKey32),
+        DestructionEngine(LwePublicKey64),
+        DestructionEngine(LweSecretKey16),
+        DestructionEngine(LweSecretKey32),
+        DestructionEngine(LweSecretKey64),
+        DestructionEngine(LweSecretKey128),
+        DestructionEngine(LweSeededBootstrapKey32),
+        DestructionEngine(LweSeededBootstrapKey64),
+        DestructionEngine(LweSeededCiphertext32),
+        DestructionEngine(LweSeededCiphertext64),
+        DestructionEngine(LweShrinkingKeyswitchKey32),
+        DestructionEngine(LweShrinkingKeyswitchKey64),
+        DestructionEngine(PackingKeyswitchKey32),
+        DestructionEngine(PackingKeyswitchKey64),
+        DestructionEngine(Plaintext16),
+        DestructionEngine(Plaintext32),
+        DestructionEngine(Plaintext64),
+        DestructionEngine(Plaintext128),
+        DestructionEngine(PlaintextVector16),
+        DestructionEngine(PlaintextVector32),
+        DestructionEngine(PlaintextVector64),
+        DestructionEngine(PlaintextVector128),
+        DestructionEngine(TaggedCiphertext<LweCiphertext32>),
+        DestructionEngine(TaggedCiphertext<LweCiphertext64>),
+        EntityConstantTimeEqualityEngine(CyclicGlweCiphertext32),
+        EntityConstantTimeEqualityEngine(CyclicGlweCiphertext64),
+        EntityConstantTimeEqualityEngine(GgswCiphertext32),
+        EntityConstantTimeEqualityEngine(GgswCiphertext64),
+        EntityConstantTimeEqualityEngine(GlweCiphertext32),
+        EntityConstantTimeEqualityEngine(GlweCiphertext64),
+        EntityConstantTimeEqualityEngine(GlweCiphertextVector32),
+        EntityConstantTimeEqualityEngine(GlweCiphertextVector64),
+        EntityConstantTimeEqualityEngine(GlweSecretKey32),
+        EntityConstantTimeEqualityEngine(GlweSecretKey64),
+        EntityConstantTimeEqualityEngine(LweBootstrapKey32),
+        EntityConstantTimeEqualityEngine(LweBootstrapKey64),
+        EntityConstantTimeEqualityEngine(LweCiphertext32),
+        EntityConstantTimeEqualityEngine(LweCiphertext64),
+        EntityConstantTimeEqualityEngine(LweCiphertextVector32),
+        EntityConstantTimeEqualityEngine(LweCiphertextVector64),
+        EntityConstantTimeEqualityEngine(LweKeyswitchKey32),
+        EntityConstantTimeEqualityEngine(LweKeyswitchKey64),
+        EntityConstantTimeEqualityEngine(LweSecretKey32),
+        EntityConstantTimeEqualityEngine(LweSecretKey64),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], FourierLweBootstrapKey32),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], FourierLweBootstrapKey64),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], GgswCiphertext32),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], GgswCiphertext64),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], GlweCiphertext32),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], GlweCiphertext64),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], GlweCiphertextVector32),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], GlweCiphertextVector64),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], GlweSecretKey32),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], GlweSecretKey64),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LweBootstrapKey32),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LweBootstrapKey64),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LweCiphertext32),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LweCiphertext64),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LweCiphertextVector32),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LweCiphertextVector64),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LweKeyswitchKey32),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LweKeyswitchKey64),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LweKeyswitchKeyDelta32),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LweKeyswitchKeyDelta64),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LwePublicKey32),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LwePublicKey64),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LweSecretKey32),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LweSecretKey64),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LweSeededBootstrapKey32),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LweSeededBootstrapKey64),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LweSeededCiphertext32),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], LweSeededCiphertext64),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], PackingKeyswitchKey32),
+        #[cfg(feature = "serde_serialize")]
+        EntityDeserializationEngine(&'static [u8], PackingKeyswitchKey64),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(FourierLweBootstrapKey32, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(FourierLweBootstrapKey64, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(GgswCiphertext32, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(GgswCiphertext64, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(GlweCiphertext32, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(GlweCiphertext64, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(GlweCiphertextVector32, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(GlweCiphertextVector64, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(GlweSecretKey32, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(GlweSecretKey64, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LweBootstrapKey32, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LweBootstrapKey64, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LweCiphertext32, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LweCiphertext64, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LweCiphertextVector32, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LweCiphertextVector64, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LweKeyswitchKey32, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LweKeyswitchKey64, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LweKeyswitchKeyDelta32, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LweKeyswitchKeyDelta64, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LwePublicKey32, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LwePublicKey64, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LweSecretKey32, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LweSecretKey64, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LweSeededBootstrapKey32, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LweSeededBootstrapKey64, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LweSeededCiphertext32, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(LweSeededCiphertext64, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(PackingKeyswitchKey32, Vec<u8>),
+        #[cfg(feature = "serde_serialize")]
+        EntitySerializationEngine(PackingKeyswitchKey64, Vec<u8>),
+        GgswCiphertextCleartextTrivialEncryptionEngine(Cleartext32, GgswCiphertext32),
+        GgswCiphertextCleartextTrivialEncryptionEngine(Cleartext64, GgswCiphertext64),
+        GgswCiphertextConsumingRetrievalEngine(GgswCiphertext32, Vec<u32>),
+        GgswCiphertextConsumingRetrievalEngine(GgswCiphertext64, Vec<u64>),
+        GgswCiphertextConversionEngine(FourierGgswCiphertext32, GgswCiphertext32),
+        GgswCiphertextConversionEngine(FourierGgswCiphertext64, GgswCiphertext64),
+        GgswCiphertextConversionEngine(GgswCiphertext32, FourierGgswCiphertext32),
+        GgswCiphertextConversionEngine(GgswCiphertext64, FourierGgswCiphertext64),
+        GgswCiphertextDiscardingConversionEngine(GgswCiphertext32, FourierGgswCiphertext32),
+        GgswCiphertextDiscardingConversionEngine(GgswCiphertext64, FourierGgswCiphertext64),
+        GgswCiphertextScalarDiscardingEncryptionEngine(
+            GlweSecretKey32,
+            Plaintext32,
+            GgswCiphertext32,
+        ),
+        GgswCiphertextScalarDiscardingEncryptionEngine(
+            GlweSecretKey64,
+            Plaintext64,
+            GgswCiphertext64,
+        ),
+        GgswCiphertextScalarEncryptionEngine(GlweSecretKey32, Plaintext32, GgswCiphertext32),
+        GgswCiphertextScalarEncryptionEngine(GlweSecretKey64, Plaintext64, GgswCiphertext64),
+        GgswCiphertextScalarTrivialEncryptionEngine(Plaintext32, GgswCiphertext32),
+        GgswCiphertextScalarTrivialEncryptionEngine(Plaintext64, GgswCiphertext64),
+        GgswSeededCiphertextExpansionEngine(GgswSeededCiphertext32, GgswCiphertext32),
+        GgswSeededCiphertextExpansionEngine(GgswSeededCiphertext64, GgswCiphertext64),
+        GgswSeededCiphertextScalarEncryptionEngine(
+            GlweSecretKey32,
+            Plaintext32,
+            GgswSeededCiphertext32,
+        ),
+        GgswSeededCiphertextScalarEncryptionEngine(
+            GlweSecretKey64,
+            Plaintext64,
+            GgswSeededCiphertext64,
+        ),
+        GlweAutomorphismKeyCreationEngine(GlweSecretKey32, GlweAutomorphismKey32),
+        GlweAutomorphismKeyCreationEngine(GlweSecretKey64, GlweAutomorphismKey64),
+        GlweCiphertextBlindRotationEngine(
+            FourierLweBootstrapKey32,
+            LweCiphertext32,
+            GlweCiphertext32,
+        ),
+        GlweCiphertextBlindRotationEngine(
+            FourierLweBootstrapKey64,
+            LweCiphertext64,
+            GlweCiphertext64,
+        ),
+        GlweCiphertextCleartextVectorDiscardingMultiplicationEngine(
+            CyclicGlweCiphertext32,
+            CleartextVector32,
+            CyclicGlweCiphertext32,
+        ),
+        GlweCiphertextCleartextVectorDiscardingMultiplicationEngine(
+            CyclicGlweCiphertext64,
+            CleartextVector64,
+            CyclicGlweCiphertext64,
+        ),
+        GlweCiphertextCleartextVectorDiscardingMultiplicationEngine(
+            GlweCiphertext32,
+            CleartextVector32,
+            GlweCiphertext32,
+        ),
+        GlweCiphertextCleartextVectorDiscardingMultiplicationEngine(
+            GlweCiphertext64,
+            CleartextVector64,
+            GlweCiphertext64,
+        ),
+        GlweCiphertextConversionEngine(GlweCiphertext32, FourierGlweCiphertext32),
+        GlweCiphertextConversionEngine(GlweCiphertext64, FourierGlweCiphertext64),
+        GlweCiphertextDecryptionEngine(GlweSecretKey32, CyclicGlweCiphertext32, PlaintextVector32),
+        GlweCiphertextDecryptionEngine(GlweSecretKey16, GlweCiphertext16, PlaintextVector16),
+        GlweCiphertextDecryptionEngine(GlweSecretKey32, GlweCiphertext32, PlaintextVector32),
+        GlweCiphertextDecryptionEngine(GlweSecretKey64, CyclicGlweCiphertext64, PlaintextVector64),
+        GlweCiphertextDecryptionEngine(GlweSecretKey64, GlweCiphertext64, PlaintextVector64),
+        GlweCiphertextDecryptionEngine(GlweSecretKey128, GlweCiphertext128, PlaintextVector128),
+        GlweCiphertextDiscardingAdditionEngine(CyclicGlweCiphertext32, CyclicGlweCiphertext32),
+        GlweCiphertextDiscardingAdditionEngine(CyclicGlweCiphertext64, CyclicGlweCiphertext64),
+        GlweCiphertextDiscardingAdditionEngine(GlweCiphertext32, GlweCiphertext32),
+        GlweCiphertextDiscardingAdditionEngine(GlweCiphertext64, GlweCiphertext64),
+        GlweCiphertextDiscardingDecryptionEngine(
+            GlweSecretKey32,
+            GlweCiphertext32,
+            PlaintextVector32,
+        ),
+        GlweCiphertextDiscardingDecryptionEngine(
+            GlweSecretKey64,
+            GlweCiphertext64,
+            PlaintextVector64,
+        ),
+        GlweCiphertextDiscardingEncryptionEngine(
+            GlweSecretKey32,
+            PlaintextVector32,
+            GlweCiphertext32,
+        ),
+        GlweCiphertextDiscardingEncryptionEngine(
+            GlweSecretKey64,
+            PlaintextVector64,
+            GlweCiphertext64,
+        ),
+        GlweCiphertextDiscardingOffsetAdditionEngine(
+            CyclicGlweCiphertext32,
+            CyclicGlweCiphertext32,
+        ),
+        GlweCiphertextDiscardingOffsetAdditionEngine(
+            CyclicGlweCiphertext64,
+            CyclicGlweCiphertext64,
+        ),
+        GlweCiphertextDiscardingOffsetAdditionEngine(GlweCiphertext32, GlweCiphertext32),
+        GlweCiphertextDiscardingOffsetAdditionEngine(GlweCiphertext64, GlweCiphertext64),
+        GlweCiphertextDiscardingRelinearizationEngine(
+            GlweRelinearizationKey32,
+            GlweCiphertext32,
+            GlweCiphertext32,
+        ),
+        GlweCiphertextDiscardingRelinearizationEngine(
+            GlweRelinearizationKey64,
+            GlweCiphertext64,
+            GlweCiphertext64,
+        ),
+        GlweCiphertextDiscardingTensorProductEngine(
+            GlweCiphertext32,
+            GlweCiphertext32,
+            GlweCiphertext32,
+        ),
+        GlweCiphertextDiscardingTensorProductEngine(
+            GlweCiphertext64,
+            GlweCiphertext64,
+            GlweCiphertext64,
+        ),
+        GlweCiphertextDiscardingTrivialEncryptionEngine(PlaintextVector32, GlweCiphertext32),
+        GlweCiphertextDiscardingTrivialEncryptionEngine(PlaintextVector64, GlweCiphertext64),
+        GlweCiphertextEncryptionEngine(GlweSecretKey32, PlaintextVector32, CyclicGlweCiphertext32),
+        GlweCiphertextEncryptionEngine(GlweSecretKey16, PlaintextVector16, GlweCiphertext16),
+        GlweCiphertextEncryptionEngine(GlweSecretKey32, PlaintextVector32, GlweCiphertext32),
+        GlweCiphertextEncryptionEngine(GlweSecretKey64, PlaintextVector64, CyclicGlweCiphertext64),
+        GlweCiphertextEncryptionEngine(GlweSecretKey64, PlaintextVector64, GlweCiphertext64),
+        GlweCiphertextEncryptionEngine(GlweSecretKey128, PlaintextVector128, GlweCiphertext128),
+        GlweCiphertextGgswCiphertextDiscardingExternalProductEngine(
+            GlweCiphertext32,
+            FourierGgswCiphertext32,
+            GlweCiphertext32,
+        ),
+        GlweCiphertextGgswCiphertextDiscardingExternalProductEngine(
+            GlweCiphertext64,
+            FourierGgswCiphertext64,
+            GlweCiphertext64,
+        ),
+        GlweCiphertextGgswCiphertextExternalProductEngine(
+            GlweCiphertext32,
+            FourierGgswCiphertext32,
+            GlweCiphertext32,
+        ),
+        GlweCiphertextGgswCiphertextExternalProductEngine(
+            GlweCiphertext64,
+            FourierGgswCiphertext64,
+            GlweCiphertext64,
+        ),
+        GlweCiphertextLayoutConsumingRetrievalEngine(GlweCiphertext32, Vec<u32>),
+        GlweCiphertextLayoutConsumingRetrievalEngine(GlweCiphertext64, Vec<u64>),
+        GlweCiphertextLayoutCreationEngine(Vec<u32>, GlweCiphertext32),
+        GlweCiphertextLayoutCreationEngine(Vec<u64>, GlweCiphertext64),
+        GlweCiphertextTraceEngine(GlweAutomorphismKey32, GlweCiphertext32, GlweCiphertext32),
+        GlweCiphertextTraceEngine(GlweAutomorphismKey64, GlweCiphertext64, GlweCiphertext64),
+        GlweCiphertextTrivialDecryptionEngine(GlweCiphertext16, PlaintextVector16),
+        GlweCiphertextTrivialDecryptionEngine(GlweCiphertext32, PlaintextVector32),
+        GlweCiphertextTrivialDecryptionEngine(GlweCiphertext64, PlaintextVector64),
+        GlweCiphertextTrivialDecryptionEngine(GlweCiphertext128, PlaintextVector128),
+        GlweCiphertextTrivialEncryptionEngine(PlaintextVector16, GlweCiphertext16),
+        GlweCiphertextTrivialEncryptionEngine(PlaintextVector32, GlweCiphertext32),
+        GlweCiphertextTrivialEncryptionEngine(PlaintextVector64, GlweCiphertext64),
+        GlweCiphertextTrivialEncryptionEngine(PlaintextVector128, GlweCiphertext128),
+        GlweCiphertextVectorDecryptionEngine(
+            GlweSecretKey32,
+            GlweCiphertextVector32,
+            PlaintextVector32,
+        ),
+        GlweCiphertextVectorDecryptionEngine(
+            GlweSecretKey64,
+            GlweCiphertextVector64,
+            PlaintextVector64,
+        ),
+        GlweCiphertextVectorDiscardingDecryptionEngine(
+            GlweSecretKey32,
+            GlweCiphertextVector32,
+            PlaintextVector32,
+        ),
+        GlweCiphertextVectorDiscardingDecryptionEngine(
+            GlweSecretKey64,
+            GlweCiphertextVector64,
+            PlaintextVector64,
+        ),
+        GlweCiphertextVectorDiscardingEncryptionEngine(
+            GlweSecretKey32,
+            PlaintextVector32,
+            GlweCiphertextVector32,
+        ),
+        GlweCiphertextVectorDiscardingEncryptionEngine(
+            GlweSecretKey64,
+            PlaintextVector64,
+            GlweCiphertextVector64,
+        ),
+        GlweCiphertextVectorEncryptionEngine(
+            GlweSecretKey32,
+            PlaintextVector32,
+            GlweCiphertextVector32,
+        ),
+        GlweCiphertextVectorEncryptionEngine(
+            GlweSecretKey64,
+            PlaintextVector64,
+            GlweCiphertextVector64,
+        ),
+        GlweCiphertextVectorLayoutConversionEngine(Vec<u32>, Vec<u32>),
+        GlweCiphertextVectorLayoutConversionEngine(Vec<u64>, Vec<u64>),
+        GlweCiphertextVectorTrivialDecryptionEngine(GlweCiphertextVector32, PlaintextVector32),
+        GlweCiphertextVectorTrivialDecryptionEngine(GlweCiphertextVector64, PlaintextVector64),
+        GlweCiphertextVectorTrivialEncryptionEngine(PlaintextVector32, GlweCiphertextVector32),
+        GlweCiphertextVectorTrivialEncryptionEngine(PlaintextVector64, GlweCiphertextVector64),
+        GlweCiphertextVectorZeroEncryptionEngine(GlweSecretKey32, GlweCiphertextVector32),
+        GlweCiphertextVectorZeroEncryptionEngine(GlweSecretKey64, GlweCiphertextVector64),
+        GlweCiphertextZeroEncryptionEngine(GlweSecretKey32, GlweCiphertext32),
+        GlweCiphertextZeroEncryptionEngine(GlweSecretKey64, GlweCiphertext64),
+        GlweRelinearizationKeyCreationEngine(GlweSecretKey32, GlweRelinearizationKey32),
+        GlweRelinearizationKeyCreationEngine(GlweSecretKey64, GlweRelinearizationKey64),
+        GlweSecretKeyCreationEngine(GlweSecretKey16),
+        GlweSecretKeyCreationEngine(GlweSecretKey32),
+        GlweSecretKeyCreationEngine(GlweSecretKey64),
+        GlweSecretKeyCreationEngine(GlweSecretKey128),
+        GlweToLweSecretKeyTransmutationEngine(GlweSecretKey16, LweSecretKey16),
+        GlweToLweSecretKeyTransmutationEngine(GlweSecretKey32, LweSecretKey32),
+        GlweToLweSecretKeyTransmutationEngine(GlweSecretKey64, LweSecretKey64),
+        GlweToLweSecretKeyTransmutationEngine(GlweSecretKey128, LweSecretKey128),
+        LweBootstrapKeyConstructionEngine(AlignedBuffer<u32>, AlignedLweBootstrapKey32),
+        LweBootstrapKeyConstructionEngine(AlignedBuffer<u64>, AlignedLweBootstrapKey64),
+        LweBootstrapKeyConsumingRetrievalEngine(AlignedLweBootstrapKey32, AlignedBuffer<u32>),
+        LweBootstrapKeyConsumingRetrievalEngine(AlignedLweBootstrapKey64, AlignedBuffer<u64>),
+        LweBootstrapKeyConversionEngine(AlignedLweBootstrapKey32, FourierLweBootstrapKey32),
+        LweBootstrapKeyConversionEngine(AlignedLweBootstrapKey64, FourierLweBootstrapKey64),
+        LweBootstrapKeyConversionEngine(LweBootstrapKey32, AlignedLweBootstrapKey32),
+        LweBootstrapKeyConversionEngine(LweBootstrapKey32, FourierLweBootstrapKey32),
+        LweBootstrapKeyConversionEngine(LweBootstrapKey64, AlignedLweBootstrapKey64),
+        LweBootstrapKeyConversionEngine(LweBootstrapKey64, FourierLweBootstrapKey64),
+        LweBootstrapKeyCreationEngine(LweSecretKey32, GlweSecretKey32, FourierLweBootstrapKey32),
+        LweBootstrapKeyCreationEngine(LweSecretKey32, GlweSecretKey32, LweBootstrapKey32),
+        LweBootstrapKeyCreationEngine(LweSecretKey64, GlweSecretKey64, FourierLweBootstrapKey64),
+        LweBootstrapKeyCreationEngine(LweSecretKey64, GlweSecretKey64, LweBootstrapKey64),
+        LweCiphertextAccumulationEngine(
+            AccumulatingCiphertext<LweCiphertext32>,
+            TaggedCiphertext<LweCiphertext32>,
+            LweKeyswitchKey32,
+            FourierLweBootstrapKey32,
+        ),
+        LweCiphertextAccumulationEngine(
+            AccumulatingCiphertext<LweCiphertext64>,
+            TaggedCiphertext<LweCiphertext64>,
+            LweKeyswitchKey64,
+            FourierLweBootstrapKey64,
+        ),
+        LweCiphertextCleartextDiscardingMultiplicationEngine(
+            LweCiphertext32,
+            Cleartext32,
+            LweCiphertext32,
+        ),
+        LweCiphertextCleartextDiscardingMultiplicationEngine(
+            LweCiphertext64,
+            Cleartext64,
+            LweCiphertext64,
+        ),
+        LweCiphertextCleartextDiscardingMultiplicationEngine(
+            LweCiphertextTruncatedView32<'static>,
+            Cleartext32,
+            LweCiphertext32,
+        ),
+        LweCiphertextCleartextDiscardingMultiplicationEngine(
+            TaggedCiphertext<LweCiphertext32>,
+            Cleartext32,
+            TaggedCiphertext<LweCiphertext32>,
+        ),
+        LweCiphertextCleartextDiscardingMultiplicationEngine(
+            TaggedCiphertext<LweCiphertext64>,
+            Cleartext64,
+            TaggedCiphertext<LweCiphertext64>,
+        ),
+        LweCiphertextCleartextDiscardingRationalMultiplicationEngine(
+            LweCiphertext32,
+            Cleartext32,
+            LweCiphertext32,
+        ),
+        LweCiphertextCleartextDiscardingRationalMultiplicationEngine(
+            LweCiphertext64,
+            Cleartext64,
+            LweCiphertext64,
+        ),
+        LweCiphertextCleartextFusingMultiplicationEngine(LweCiphertext32, Cleartext32),
+        LweCiphertextCleartextFusingMultiplicationEngine(LweCiphertext64, Cleartext64),
+        LweCiphertextCleartextFusingMultiplicationEngine(
+            TaggedCiphertext<LweCiphertext32>,
+            Cleartext32,
+        ),
+        LweCiphertextCleartextFusingMultiplicationEngine(
+            TaggedCiphertext<LweCiphertext64>,
+            Cleartext64,
+        ),
+        LweCiphertextCleartextFusingMultiplyAddEngine(
+            LweCiphertext32,
+            Cleartext32,
+            LweCiphertext32,
+        ),
+        LweCiphertextCleartextFusingMultiplyAddEngine(
+            LweCiphertext64,
+            Cleartext64,
+            LweCiphertext64,
+        ),
+        LweCiphertextConversionEngine(LweCiphertextTruncatedView32<'static>, LweCiphertext32),
+        LweCiphertextDecryptionEngine(LweSecretKey16, LweCiphertext16, Plaintext16),
+        LweCiphertextDecryptionEngine(LweSecretKey32, LweCiphertext32, Plaintext32),
+        LweCiphertextDecryptionEngine(LweSecretKey64, LweCiphertext64, Plaintext64),
+        LweCiphertextDecryptionEngine(LweSecretKey128, LweCiphertext128, Plaintext128),
+        LweCiphertextDiscardingAdditionEngine(LweCiphertext16, LweCiphertext16),
+        LweCiphertextDiscardingAdditionEngine(LweCiphertext32, LweCiphertext32),
+        LweCiphertextDiscardingAdditionEngine(LweCiphertext64, LweCiphertext64),
+        LweCiphertextDiscardingAdditionEngine(
+            LweCiphertextTruncatedView32<'static>,
+            LweCiphertext32,
+        ),
+        LweCiphertextDiscardingAdditionEngine(
+            TaggedCiphertext<LweCiphertext32>,
+            TaggedCiphertext<LweCiphertext32>,
+        ),
+        LweCiphertextDiscardingAdditionEngine(
+            TaggedCiphertext<LweCiphertext64>,
+            TaggedCiphertext<LweCiphertext64>,
+        ),
+        LweCiphertextDiscardingBatchedKeyswitchEngine(
+            LweKeyswitchKeyBatch32,
+            LweCiphertext32,
+            LweCiphertext32,
+        ),
+        LweCiphertextDiscardingBatchedKeyswitchEngine(
+            LweKeyswitchKeyBatch64,
+            LweCiphertext64,
+            LweCiphertext64,
+        ),
+        LweCiphertextDiscardingBootstrapEngine(
+            FourierLweBootstrapKey32,
+            GlweCiphertext32,
+            LweCiphertext32,
+            LweCiphertext32,
+        ),
+        LweCiphertextDiscardingBootstrapEngine(
+            FourierLweBootstrapKey64,
+            GlweCiphertext64,
+            LweCiphertext64,
+            LweCiphertext64,
+        ),
+        LweCiphertextDiscardingDecryptionEngine(LweSecretKey32, LweCiphertext32, Plaintext32),
+        LweCiphertextDiscardingDecryptionEngine(LweSecretKey64, LweCiphertext64, Plaintext64),
+        LweCiphertextDiscardingEncryptionEngine(LweSecretKey32, Plaintext32, LweCiphertext32),
+        LweCiphertextDiscardingEncryptionEngine(LweSecretKey64, Plaintext64, LweCiphertext64),
+        LweCiphertextDiscardingExtractionEngine(GlweCiphertext32, LweCiphertext32),
+        LweCiphertextDiscardingExtractionEngine(GlweCiphertext64, LweCiphertext64),
+        LweCiphertextDiscardingKeyswitchEngine(
+            ColumnMajorLweKeyswitchKey32,
+            LweCiphertext32,
+            LweCiphertext32,
+        ),
+        LweCiphertextDiscardingKeyswitchEngine(
+            ColumnMajorLweKeyswitchKey64,
+            LweCiphertext64,
+            LweCiphertext64,
+        ),
+        LweCiphertextDiscardingKeyswitchEngine(LweKeyswitchKey16, LweCiphertext16, LweCiphertext16),
+        LweCiphertextDiscardingKeyswitchEngine(LweKeyswitchKey32, LweCiphertext32, LweCiphertext32),
+        LweCiphertextDiscardingKeyswitchEngine(LweKeyswitchKey64, LweCiphertext64, LweCiphertext64),
+        LweCiphertextDiscardingKeyswitchEngine(
+            LweKeyswitchKey128,
+            LweCiphertext128,
+            LweCiphertext128,
+        ),
+        LweCiphertextDiscardingLargeTableLookupEngine(
+            FourierLweBootstrapKey32,
+            FourierGgswCiphertext32,
+            PlaintextVector32,
+            LweCiphertext32,
+            LweCiphertext32,
+        ),
+        LweCiphertextDiscardingLargeTableLookupEngine(
+            FourierLweBootstrapKey64,
+            FourierGgswCiphertext64,
+            PlaintextVector64,
+            LweCiphertext64,
+            LweCiphertext64,
+        ),
+        LweCiphertextDiscardingModulusSwitchEngine(LweCiphertext32, LweCiphertext32),
+        LweCiphertextDiscardingModulusSwitchEngine(LweCiphertext64, LweCiphertext32),
+        LweCiphertextDiscardingModulusSwitchEngine(LweCiphertext64, LweCiphertext64),
+        LweCiphertextDiscardingMultiplicationEngine(
+            PackingKeyswitchKey32,
+            GlweRelinearizationKey32,
+            LweCiphertext32,
+            LweCiphertext32,
+            LweCiphertext32,
+        ),
+        LweCiphertextDiscardingMultiplicationEngine(
+            PackingKeyswitchKey64,
+            GlweRelinearizationKey64,
+            LweCiphertext64,
+            LweCiphertext64,
+            LweCiphertext64,
+        ),
+        LweCiphertextDiscardingOppositeEngine(LweCiphertext32, LweCiphertext32),
+        LweCiphertextDiscardingOppositeEngine(LweCiphertext64, LweCiphertext64),
+        LweCiphertextDiscardingShrinkingKeyswitchEngine(
+            LweShrinkingKeyswitchKey32,
+            LweCiphertext32,
+            LweCiphertext32,
+        ),
+        LweCiphertextDiscardingShrinkingKeyswitchEngine(
+            LweShrinkingKeyswitchKey64,
+            LweCiphertext64,
+            LweCiphertext64,
+        ),
+        LweCiphertextDiscardingSubtractionEngine(LweCiphertext32, LweCiphertext32),
+        LweCiphertextDiscardingSubtractionEngine(LweCiphertext64, LweCiphertext64),
+        LweCiphertextDiscardingTrivialEncryptionEngine(Plaintext32, LweCiphertext32),
+        LweCiphertextDiscardingTrivialEncryptionEngine(Plaintext64, LweCiphertext64),
+        LweCiphertextEncryptionEngine(LweSecretKey16, Plaintext16, LweCiphertext16),
+        LweCiphertextEncryptionEngine(LweSecretKey32, Plaintext32, LweCiphertext32),
+        LweCiphertextEncryptionEngine(LweSecretKey64, Plaintext64, LweCiphertext64),
+        LweCiphertextEncryptionEngine(LweSecretKey128, Plaintext128, LweCiphertext128),
+        LweCiphertextFusingAdditionEngine(LweCiphertext16, LweCiphertext16),
+        LweCiphertextFusingAdditionEngine(LweCiphertext32, LweCiphertext32),
+        LweCiphertextFusingAdditionEngine(LweCiphertext64, LweCiphertext64),
+        LweCiphertextFusingAdditionEngine(
+            TaggedCiphertext<LweCiphertext32>,
+            TaggedCiphertext<LweCiphertext32>,
+        ),
+        LweCiphertextFusingAdditionEngine(
+            TaggedCiphertext<LweCiphertext64>,
+            TaggedCiphertext<LweCiphertext64>,
+        ),
+        LweCiphertextFusingNoiseInjectionEngine(LweCiphertext32),
+        LweCiphertextFusingNoiseInjectionEngine(LweCiphertext64),
+        LweCiphertextFusingOppositeEngine(LweCiphertext32),
+        LweCiphertextFusingOppositeEngine(LweCiphertext64),
+        LweCiphertextFusingSubtractionEngine(LweCiphertext32, LweCiphertext32),
+        LweCiphertextFusingSubtractionEngine(LweCiphertext64, LweCiphertext64),
+        LweCiphertextGadgetProductEngine(LweKeyswitchKey32, LweCiphertext32, LweCiphertext32),
+        LweCiphertextGadgetProductEngine(LweKeyswitchKey64, LweCiphertext64, LweCiphertext64),
+        LweCiphertextJaggedVectorCreationEngine(LweCiphertextVector32, LweCiphertextJaggedVector32),
+        LweCiphertextJaggedVectorCreationEngine(LweCiphertextVector64, LweCiphertextJaggedVector64),
+        LweCiphertextJaggedVectorDiscardingRowSummationEngine(
+            LweCiphertextJaggedVector32,
+            LweCiphertextVector32,
+        ),
+        LweCiphertextJaggedVectorDiscardingRowSummationEngine(
+            LweCiphertextJaggedVector64,
+            LweCiphertextVector64,
+        ),
+        LweCiphertextJaggedVectorFusingAdditionEngine(
+            LweCiphertextJaggedVector32,
+            LweCiphertextJaggedVector32,
+        ),
+        LweCiphertextJaggedVectorFusingAdditionEngine(
+            LweCiphertextJaggedVector64,
+            LweCiphertextJaggedVector64,
+        ),
+        LweCiphertextPlaintextDiscardingAdditionEngine(
+            LweCiphertext32,
+            Plaintext32,
+            LweCiphertext32,
+        ),
+        LweCiphertextPlaintextDiscardingAdditionEngine(
+            LweCiphertext64,
+            Plaintext64,
+            LweCiphertext64,
+        ),
+        LweCiphertextPlaintextDiscardingSubtractionEngine(
+            LweCiphertext32,
+            Plaintext32,
+            LweCiphertext32,
+        ),
+        LweCiphertextPlaintextDiscardingSubtractionEngine(
+            LweCiphertext64,
+            Plaintext64,
+            LweCiphertext64,
+        ),
+        LweCiphertextPlaintextFusingAdditionEngine(LweCiphertext32, Plaintext32),
+        LweCiphertextPlaintextFusingAdditionEngine(LweCiphertext64, Plaintext64),
+        LweCiphertextPlaintextFusingSubtractionEngine(LweCiphertext32, Plaintext32),
+        LweCiphertextPlaintextFusingSubtractionEngine(LweCiphertext64, Plaintext64),
+        LweCiphertextPublicKeyEncryptionEngine(LwePublicKey32, Plaintext32, LweCiphertext32),
+        LweCiphertextPublicKeyEncryptionEngine(LwePublicKey64, Plaintext64, LweCiphertext64),
+        LweCiphertextTrivialDecryptionEngine(LweCiphertext16, Plaintext16),
+        LweCiphertextTrivialDecryptionEngine(LweCiphertext32, Plaintext32),
+        LweCiphertextTrivialDecryptionEngine(LweCiphertext64, Plaintext64),
+        LweCiphertextTrivialDecryptionEngine(LweCiphertext128, Plaintext128),
+        LweCiphertextTrivialEncryptionEngine(Plaintext16, LweCiphertext16),
+        LweCiphertextTrivialEncryptionEngine(Plaintext32, LweCiphertext32),
+        LweCiphertextTrivialEncryptionEngine(Plaintext64, LweCiphertext64),
+        LweCiphertextTrivialEncryptionEngine(Plaintext128, LweCiphertext128),
+        LweCiphertextTruncatedViewCreationEngine(
+            &'static LweCiphertext64,
+            LweCiphertextTruncatedView32<'static>,
+        ),
+        LweCiphertextVectorConsumingRetrievalEngine(
+            AlignedLweCiphertextVector32,
+            AlignedBuffer<u32>,
+        ),
+        LweCiphertextVectorConsumingRetrievalEngine(
+            AlignedLweCiphertextVector64,
+            AlignedBuffer<u64>,
+        ),
+        LweCiphertextVectorConversionEngine(AlignedLweCiphertextVector32, LweCiphertextVector32),
+        LweCiphertextVectorConversionEngine(AlignedLweCiphertextVector64, LweCiphertextVector64),
+        LweCiphertextVectorConversionEngine(LweCiphertextVector32, AlignedLweCiphertextVector32),
+        LweCiphertextVectorConversionEngine(LweCiphertextVector64, AlignedLweCiphertextVector64),
+        LweCiphertextVectorConversionEngine(
+            LweCiphertextVectorView32<'static>,
+            LweCiphertextVector32,
+        ),
+        LweCiphertextVectorConversionEngine(
+            LweCiphertextVectorView64<'static>,
+            LweCiphertextVector64,
+        ),
+        LweCiphertextVectorCreationEngine(AlignedBuffer<u32>, AlignedLweCiphertextVector32),
+        LweCiphertextVectorCreationEngine(AlignedBuffer<u64>, AlignedLweCiphertextVector64),
+        LweCiphertextVectorCreationEngine(
+            SharedSlice<'static, u32>,
+            LweCiphertextVectorView32<'static>,
+        ),
+        LweCiphertextVectorCreationEngine(
+            SharedSlice<'static, u64>,
+            LweCiphertextVectorView64<'static>,
+        ),
+        LweCiphertextVectorDecryptionEngine(
+            LweSecretKey32,
+            LweCiphertextVector32,
+            PlaintextVector32,
+        ),
+        LweCiphertextVectorDecryptionEngine(
+            LweSecretKey64,
+            LweCiphertextVector64,
+            PlaintextVector64,
+        ),
+        LweCiphertextVectorDecryptionEngine(
+            LweSecretKey32,
+            LweCiphertextVectorView32<'static>,
+            PlaintextVector32,
+        ),
+        LweCiphertextVectorDecryptionEngine(
+            LweSecretKey64,
+            LweCiphertextVectorView64<'static>,
+            PlaintextVector64,
+        ),
+        LweCiphertextVectorDiscardingAdditionEngine(LweCiphertextVector32, LweCiphertextVector32),
+        LweCiphertextVectorDiscardingAdditionEngine(LweCiphertextVector64, LweCiphertextVector64),
+        LweCiphertextVectorDiscardingAffineTransformationEngine(
+            LweCiphertextVector32,
+            CleartextVector32,
+            Plaintext32,
+            LweCiphertext32,
+        ),
+        LweCiphertextVectorDiscardingAffineTransformationEngine(
+            LweCiphertextVector64,
+            CleartextVector64,
+            Plaintext64,
+            LweCiphertext64,
+        ),
+        LweCiphertextVectorDiscardingCmuxEngine(
+            GgswCiphertext32,
+            LweCiphertextVector32,
+            LweCiphertextVector32,
+        ),
+        LweCiphertextVectorDiscardingCmuxEngine(
+            GgswCiphertext64,
+            LweCiphertextVector64,
+            LweCiphertextVector64,
+        ),
+        LweCiphertextVectorDiscardingDecryptionEngine(
+            LweSecretKey32,
+            LweCiphertextVector32,
+            PlaintextVector32,
+        ),
+        LweCiphertextVectorDiscardingDecryptionEngine(
+            LweSecretKey64,
+            LweCiphertextVector64,
+            PlaintextVector64,
+        ),
+        LweCiphertextVectorDiscardingEncryptionEngine(
+            LweSecretKey32,
+            PlaintextVector32,
+            LweCiphertextVector32,
+        ),
+        LweCiphertextVectorDiscardingEncryptionEngine(
+            LweSecretKey64,
+            PlaintextVector64,
+            LweCiphertextVector64,
+        ),
+        LweCiphertextVectorDiscardingExtremumEngine(
+            LweKeyswitchKey32,
+            FourierLweBootstrapKey32,
+            LweCiphertextVector32,
+            LweCiphertextVector32,
+        ),
+        LweCiphertextVectorDiscardingExtremumEngine(
+            LweKeyswitchKey64,
+            FourierLweBootstrapKey64,
+            LweCiphertextVector64,
+            LweCiphertextVector64,
+        ),
+        LweCiphertextVectorDiscardingGatherEngine(LweCiphertextVector32, LweCiphertextVector32),
+        LweCiphertextVectorDiscardingGatherEngine(LweCiphertextVector64, LweCiphertextVector64),
+        LweCiphertextVectorDiscardingKeyswitchEngine(
+            LweKeyswitchKey32,
+            LweCiphertextVector32,
+            LweCiphertextVector32,
+        ),
+        LweCiphertextVectorDiscardingKeyswitchEngine(
+            LweKeyswitchKey64,
+            LweCiphertextVector64,
+            LweCiphertextVector64,
+        ),
+        LweCiphertextVectorDiscardingParityEngine(
+            LweKeyswitchKey32,
+            FourierLweBootstrapKey32,
+            LweCiphertextVector32,
+            LweCiphertext32,
+        ),
+        LweCiphertextVectorDiscardingParityEngine(
+            LweKeyswitchKey64,
+            FourierLweBootstrapKey64,
+            LweCiphertextVector64,
+            LweCiphertext64,
+        ),
+        LweCiphertextVectorDiscardingPermutationEngine(
+            GgswCiphertext32,
+            LweCiphertextVector32,
+            LweCiphertextVector32,
+        ),
+        LweCiphertextVectorDiscardingPermutationEngine(
+            GgswCiphertext64,
+            LweCiphertextVector64,
+            LweCiphertextVector64,
+        ),
+        LweCiphertextVectorDiscardingPopcountEngine(
+            LweKeyswitchKey32,
+            FourierLweBootstrapKey32,
+            LweCiphertextVector32,
+            LweCiphertext32,
+        ),
+        LweCiphertextVectorDiscardingPopcountEngine(
+            LweKeyswitchKey64,
+            FourierLweBootstrapKey64,
+            LweCiphertextVector64,
+            LweCiphertext64,
+        ),
+        LweCiphertextVectorDiscardingScatterEngine(LweCiphertextVector32, LweCiphertextVector32),
+        LweCiphertextVectorDiscardingScatterEngine(LweCiphertextVector64, LweCiphertextVector64),
+        LweCiphertextVectorDiscardingSubtractionEngine(
+            LweCiphertextVector32,
+            LweCiphertextVector32,
+        ),
+        LweCiphertextVectorDiscardingSubtractionEngine(
+            LweCiphertextVector64,
+            LweCiphertextVector64,
+        ),
+        LweCiphertextVectorEncryptionEngine(
+            LweSecretKey32,
+            PlaintextVector32,
+            LweCiphertextVector32,
+        ),
+        LweCiphertextVectorEncryptionEngine(
+            LweSecretKey64,
+            PlaintextVector64,
+            LweCiphertextVector64,
+        ),
+        LweCiphertextVectorFusingAdditionEngine(LweCiphertextVector32, LweCiphertextVector32),
+        LweCiphertextVectorFusingAdditionEngine(LweCiphertextVector64, LweCiphertextVector64),
+        LweCiphertextVectorFusingSubtractionEngine(LweCiphertextVector32, LweCiphertextVector32),
+        LweCiphertextVectorFusingSubtractionEngine(LweCiphertextVector64, LweCiphertextVector64),
+        LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine(
+            PackingKeyswitchKey32,
+            LweCiphertextVector32,
+            GlweCiphertext32,
+        ),
+        LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine(
+            PackingKeyswitchKey64,
+            LweCiphertextVector64,
+            GlweCiphertext64,
+        ),
+        LweCiphertextVectorLayoutConsumingRetrievalEngine(
+            AlignedLweCiphertextVector32,
+            AlignedBuffer<u32>,
+        ),
+        LweCiphertextVectorLayoutConsumingRetrievalEngine(
+            AlignedLweCiphertextVector64,
+            AlignedBuffer<u64>,
+        ),
+        LweCiphertextVectorLayoutConversionEngine(Vec<u32>, Vec<u32>),
+        LweCiphertextVectorLayoutConversionEngine(Vec<u64>, Vec<u64>),
+        LweCiphertextVectorLayoutCreationEngine(AlignedBuffer<u32>, AlignedLweCiphertextVector32),
+        LweCiphertextVectorLayoutCreationEngine(AlignedBuffer<u64>, AlignedLweCiphertextVector64),
+        LweCiphertextVectorNoiseStatisticsEngine(LweSecretKey32, LweCiphertextVector32),
+        LweCiphertextVectorNoiseStatisticsEngine(LweSecretKey64, LweCiphertextVector64),
+        LweCiphertextVectorPhaseExportingDecryptionEngine(
+            LweSecretKey32,
+            LweCiphertextVector32,
+            PlaintextVector32,
+            LwePhaseVector32,
+        ),
+        LweCiphertextVectorPhaseExportingDecryptionEngine(
+            LweSecretKey64,
+            LweCiphertextVector64,
+            PlaintextVector64,
+            LwePhaseVector64,
+        ),
+        LweCiphertextVectorPlaintextVectorDiscardingSubtractionEngine(
+            LweCiphertextVector32,
+            PlaintextVector32,
+            LweCiphertextVector32,
+        ),
+        LweCiphertextVectorPlaintextVectorDiscardingSubtractionEngine(
+            LweCiphertextVector64,
+            PlaintextVector64,
+            LweCiphertextVector64,
+        ),
+        LweCiphertextVectorPlaintextVectorFusingSubtractionEngine(
+            LweCiphertextVector32,
+            PlaintextVector32,
+        ),
+        LweCiphertextVectorPlaintextVectorFusingSubtractionEngine(
+            LweCiphertextVector64,
+            PlaintextVector64,
+        ),
+        LweCiphertextVectorTrivialDecryptionEngine(LweCiphertextVector32, PlaintextVector32),
+        LweCiphertextVectorTrivialDecryptionEngine(LweCiphertextVector64, PlaintextVector64),
+        LweCiphertextVectorTrivialEncryptionEngine(PlaintextVector32, LweCiphertextVector32),
+        LweCiphertextVectorTrivialEncryptionEngine(PlaintextVector64, LweCiphertextVector64),
+        LweCiphertextVectorZeroEncryptionEngine(LweSecretKey32, LweCiphertextVector32),
+        LweCiphertextVectorZeroEncryptionEngine(LweSecretKey64, LweCiphertextVector64),
+        LweCiphertextZeroEncryptionEngine(LweSecretKey16, LweCiphertext16),
+        LweCiphertextZeroEncryptionEngine(LweSecretKey32, LweCiphertext32),
+        LweCiphertextZeroEncryptionEngine(LweSecretKey64, LweCiphertext64),
+        LweCiphertextZeroEncryptionEngine(LweSecretKey128, LweCiphertext128),
+        LweKeyswitchKeyBatchAppendingEngine(LweKeyswitchKey32, LweKeyswitchKeyBatch32),
+        LweKeyswitchKeyBatchAppendingEngine(LweKeyswitchKey64, LweKeyswitchKeyBatch64),
+        LweKeyswitchKeyBatchCreationEngine(LweKeyswitchKeyBatch32),
+        LweKeyswitchKeyBatchCreationEngine(LweKeyswitchKeyBatch64),
+        LweKeyswitchKeyBatchRemovalEngine(LweKeyswitchKeyBatch32),
+        LweKeyswitchKeyBatchRemovalEngine(LweKeyswitchKeyBatch64),
+        LweKeyswitchKeyConversionEngine(LweKeyswitchKey32, ColumnMajorLweKeyswitchKey32),
+        LweKeyswitchKeyConversionEngine(LweKeyswitchKey64, ColumnMajorLweKeyswitchKey64),
+        LweKeyswitchKeyCreationEngine(LweSecretKey16, LweSecretKey16, LweKeyswitchKey16),
+        LweKeyswitchKeyCreationEngine(LweSecretKey32, LweSecretKey32, LweKeyswitchKey32),
+        LweKeyswitchKeyCreationEngine(LweSecretKey64, LweSecretKey64, LweKeyswitchKey64),
+        LweKeyswitchKeyCreationEngine(LweSecretKey128, LweSecretKey128, LweKeyswitchKey128),
+        LweKeyswitchKeyDeltaCreationEngine(
+            LweKeyswitchKey32,
+            LweSecretKey32,
+            LweKeyswitchKeyDelta32,
+        ),
+        LweKeyswitchKeyDeltaCreationEngine(
+            LweKeyswitchKey64,
+            LweSecretKey64,
+            LweKeyswitchKeyDelta64,
+        ),
+        LweKeyswitchKeyFusingUpdateEngine(LweKeyswitchKeyDelta32, LweKeyswitchKey32),
+        LweKeyswitchKeyFusingUpdateEngine(LweKeyswitchKeyDelta64, LweKeyswitchKey64),
+        LwePhaseVectorRetrievalEngine(LwePhaseVector32, u32),
+        LwePhaseVectorRetrievalEngine(LwePhaseVector64, u64),
+        LwePublicKeyCreationEngine(LweSecretKey32, LwePublicKey32),
+        LwePublicKeyCreationEngine(LweSecretKey64, LwePublicKey64),
+        LweSecretKeyCreationEngine(LweSecretKey16),
+        LweSecretKeyCreationEngine(LweSecretKey32),
+        LweSecretKeyCreationEngine(LweSecretKey64),
+        LweSecretKeyCreationEngine(LweSecretKey128),
+        LweSecretKeyDerivationEngine(LweSecretKey32),
+        LweSecretKeyDerivationEngine(LweSecretKey64),
+        LweSecretKeyShrinkingEngine(LweSecretKey32, LweSecretKey32),
+        LweSecretKeyShrinkingEngine(LweSecretKey64, LweSecretKey64),
+        LweSeededBootstrapKeyCreationEngine(
+            LweSecretKey32,
+            GlweSecretKey32,
+            LweSeededBootstrapKey32,
+        ),
+        LweSeededBootstrapKeyCreationEngine(
+            LweSecretKey64,
+            GlweSecretKey64,
+            LweSeededBootstrapKey64,
+        ),
+        LweSeededBootstrapKeyExpansionEngine(LweSeededBootstrapKey32, LweBootstrapKey32),
+        LweSeededBootstrapKeyExpansionEngine(LweSeededBootstrapKey64, LweBootstrapKey64),
+        LweSeededCiphertextEncryptionEngine(LweSecretKey32, Plaintext32, LweSeededCiphertext32),
+        LweSeededCiphertextEncryptionEngine(LweSecretKey64, Plaintext64, LweSeededCiphertext64),
+        LweSeededCiphertextExpansionEngine(LweSeededCiphertext32, LweCiphertext32),
+        LweSeededCiphertextExpansionEngine(LweSeededCiphertext64, LweCiphertext64),
+        LweShrinkingKeyswitchKeyCreationEngine(
+            LweSecretKey32,
+            LweSecretKey32,
+            LweShrinkingKeyswitchKey32,
+        ),
+        LweShrinkingKeyswitchKeyCreationEngine(
+            LweSecretKey64,
+            LweSecretKey64,
+            LweShrinkingKeyswitchKey64,
+        ),
+        LweToGlweSecretKeyTransmutationEngine(LweSecretKey16, GlweSecretKey16),
+        LweToGlweSecretKeyTransmutationEngine(LweSecretKey32, GlweSecretKey32),
+        LweToGlweSecretKeyTransmutationEngine(LweSecretKey64, GlweSecretKey64),
+        LweToGlweSecretKeyTransmutationEngine(LweSecretKey128, GlweSecretKey128),
+        PackingKeyswitchKeyCreationEngine(LweSecretKey32, GlweSecretKey32, PackingKeyswitchKey32),
+        PackingKeyswitchKeyCreationEngine(LweSecretKey64, GlweSecretKey64, PackingKeyswitchKey64),
+        PlaintextCreationEngine(u16, Plaintext16),
+        PlaintextCreationEngine(u32, Plaintext32),
+        PlaintextCreationEngine(u64, Plaintext64),
+        PlaintextCreationEngine(u128, Plaintext128),
+        PlaintextDiscardingRetrievalEngine(Plaintext32, u32),
+        PlaintextDiscardingRetrievalEngine(Plaintext64, u64),
+        PlaintextRetrievalEngine(Plaintext16, u16),
+        PlaintextRetrievalEngine(Plaintext32, u32),
+        PlaintextRetrievalEngine(Plaintext64, u64),
+        PlaintextRetrievalEngine(Plaintext128, u128),
+        PlaintextVectorCreationEngine(u16, PlaintextVector16),
+        PlaintextVectorCreationEngine(u32, PlaintextVector32),
+        PlaintextVectorCreationEngine(u64, PlaintextVector64),
+        PlaintextVectorCreationEngine(u128, PlaintextVector128),
+        PlaintextVectorDiscardingRetrievalEngine(PlaintextVector32, u32),
+        PlaintextVectorDiscardingRetrievalEngine(PlaintextVector64, u64),
+        PlaintextVectorRetrievalEngine(PlaintextVector16, u16),
+        PlaintextVectorRetrievalEngine(PlaintextVector32, u32),
+        PlaintextVectorRetrievalEngine(PlaintextVector64, u64),
+        PlaintextVectorRetrievalEngine(PlaintextVector128, u128),
+    }
+}
@@ -0,0 +1,185 @@
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    AlignedLweBootstrapKey32, AlignedLweBootstrapKey64,
+};
+use crate::backends::core::memory::AlignedBuffer;
+use crate::backends::core::private::crypto::bootstrap::StandardBootstrapKey as ImplStandardBootstrapKey;
+use crate::specification::engines::{
+    LweBootstrapKeyConstructionEngine, LweBootstrapKeyConstructionError,
+};
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConstructionEngine`] for [`CoreEngine`] which wraps an
+/// [`AlignedBuffer`] of 32 bits integers, without copying it.
+impl LweBootstrapKeyConstructionEngine<AlignedBuffer<u32>, AlignedLweBootstrapKey32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::memory::{AlignedBuffer, Alignment};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(2), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let glwe_size = glwe_dim.to_glwe_size().0;
+    /// // The buffer would typically be filled by a DMA transfer.
+    /// let buffer = AlignedBuffer::<u32>::new(
+    ///     lwe_dim.0 * dec_lc.0 * glwe_size * glwe_size * poly_size.0,
+    ///     Alignment::CACHE_LINE,
+    /// );
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let bsk: AlignedLweBootstrapKey32 =
+    ///     engine.construct_lwe_bootstrap_key(buffer, glwe_dim, poly_size, dec_bl, dec_lc)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn construct_lwe_bootstrap_key(
+        &mut self,
+        container: AlignedBuffer<u32>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<AlignedLweBootstrapKey32, LweBootstrapKeyConstructionError<Self::EngineError>> {
+        LweBootstrapKeyConstructionError::perform_generic_checks(
+            container.len(),
+            glwe_dimension,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            32,
+        )?;
+        Ok(unsafe {
+            self.construct_lwe_bootstrap_key_unchecked(
+                container,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_base_log,
+                decomposition_level_count,
+            )
+        })
+    }
+
+    unsafe fn construct_lwe_bootstrap_key_unchecked(
+        &mut self,
+        container: AlignedBuffer<u32>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> AlignedLweBootstrapKey32 {
+        AlignedLweBootstrapKey32(ImplStandardBootstrapKey::from_container(
+            container,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            decomposition_level_count,
+            decomposition_base_log,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConstructionEngine`] for [`CoreEngine`] which wraps an
+/// [`AlignedBuffer`] of 64 bits integers, without copying it.
+impl LweBootstrapKeyConstructionEngine<AlignedBuffer<u64>, AlignedLweBootstrapKey64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::memory::{AlignedBuffer, Alignment};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(2), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let glwe_size = glwe_dim.to_glwe_size().0;
+    /// // The buffer would typically be filled by a DMA transfer.
+    /// let buffer = AlignedBuffer::<u64>::new(
+    ///     lwe_dim.0 * dec_lc.0 * glwe_size * glwe_size * poly_size.0,
+    ///     Alignment::CACHE_LINE,
+    /// );
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let bsk: AlignedLweBootstrapKey64 =
+    ///     engine.construct_lwe_bootstrap_key(buffer, glwe_dim, poly_size, dec_bl, dec_lc)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn construct_lwe_bootstrap_key(
+        &mut self,
+        container: AlignedBuffer<u64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<AlignedLweBootstrapKey64, LweBootstrapKeyConstructionError<Self::EngineError>> {
+        LweBootstrapKeyConstructionError::perform_generic_checks(
+            container.len(),
+            glwe_dimension,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            64,
+        )?;
+        Ok(unsafe {
+            self.construct_lwe_bootstrap_key_unchecked(
+                container,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_base_log,
+                decomposition_level_count,
+            )
+        })
+    }
+
+    unsafe fn construct_lwe_bootstrap_key_unchecked(
+        &mut self,
+        container: AlignedBuffer<u64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> AlignedLweBootstrapKey64 {
+        AlignedLweBootstrapKey64(ImplStandardBootstrapKey::from_container(
+            container,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            decomposition_level_count,
+            decomposition_base_log,
+        ))
+    }
+}
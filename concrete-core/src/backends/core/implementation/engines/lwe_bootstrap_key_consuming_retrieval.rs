@@ -0,0 +1,121 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    AlignedLweBootstrapKey32, AlignedLweBootstrapKey64,
+};
+use crate::backends::core::memory::AlignedBuffer;
+use crate::backends::core::private::math::tensor::IntoTensor;
+use crate::specification::engines::{
+    LweBootstrapKeyConsumingRetrievalEngine, LweBootstrapKeyConsumingRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConsumingRetrievalEngine`] for [`CoreEngine`] which returns
+/// the [`AlignedBuffer`] of 32 bits integers of the key, without copying it.
+impl LweBootstrapKeyConsumingRetrievalEngine<AlignedLweBootstrapKey32, AlignedBuffer<u32>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::memory::{AlignedBuffer, Alignment};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(2), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let aligned_bsk: AlignedLweBootstrapKey32 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// // The buffer can be handed to a DMA engine without any copy.
+    /// let buffer: AlignedBuffer<u32> = engine.consume_retrieve_lwe_bootstrap_key(aligned_bsk)?;
+    /// #
+    /// assert!(Alignment::CACHE_LINE.is_satisfied_by(buffer.as_ptr()));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_bootstrap_key(
+        &mut self,
+        bootstrap_key: AlignedLweBootstrapKey32,
+    ) -> Result<AlignedBuffer<u32>, LweBootstrapKeyConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_bootstrap_key_unchecked(bootstrap_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_bootstrap_key_unchecked(
+        &mut self,
+        bootstrap_key: AlignedLweBootstrapKey32,
+    ) -> AlignedBuffer<u32> {
+        bootstrap_key.0.into_tensor().into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConsumingRetrievalEngine`] for [`CoreEngine`] which returns
+/// the [`AlignedBuffer`] of 64 bits integers of the key, without copying it.
+impl LweBootstrapKeyConsumingRetrievalEngine<AlignedLweBootstrapKey64, AlignedBuffer<u64>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::memory::{AlignedBuffer, Alignment};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(2), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let aligned_bsk: AlignedLweBootstrapKey64 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// // The buffer can be handed to a DMA engine without any copy.
+    /// let buffer: AlignedBuffer<u64> = engine.consume_retrieve_lwe_bootstrap_key(aligned_bsk)?;
+    /// #
+    /// assert!(Alignment::CACHE_LINE.is_satisfied_by(buffer.as_ptr()));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_bootstrap_key(
+        &mut self,
+        bootstrap_key: AlignedLweBootstrapKey64,
+    ) -> Result<AlignedBuffer<u64>, LweBootstrapKeyConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_bootstrap_key_unchecked(bootstrap_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_bootstrap_key_unchecked(
+        &mut self,
+        bootstrap_key: AlignedLweBootstrapKey64,
+    ) -> AlignedBuffer<u64> {
+        bootstrap_key.0.into_tensor().into_container()
+    }
+}
@@ -1,9 +1,15 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    FourierLweBootstrapKey32, FourierLweBootstrapKey64, LweBootstrapKey32, LweBootstrapKey64,
+    AlignedLweBootstrapKey32, AlignedLweBootstrapKey64, FourierLweBootstrapKey32,
+    FourierLweBootstrapKey64, LweBootstrapKey32, LweBootstrapKey64,
+};
+use crate::backends::core::memory::{AlignedBuffer, Alignment};
+use crate::backends::core::private::crypto::bootstrap::{
+    FourierBootstrapKey as ImplFourierBootstrapKey,
+    StandardBootstrapKey as ImplStandardBootstrapKey,
 };
-use crate::backends::core::private::crypto::bootstrap::FourierBootstrapKey as ImplFourierBootstrapKey;
 use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
 use crate::specification::engines::{
     LweBootstrapKeyConversionEngine, LweBootstrapKeyConversionError,
 };
@@ -75,7 +81,7 @@ impl LweBootstrapKeyConversionEngine<LweBootstrapKey32, FourierLweBootstrapKey32
             output_bsk.glwe_dimension().to_glwe_size(),
         );
         output_bsk.0.fill_with_forward_fourier(&input.0, buffers);
-        output_bsk
+        self.audit_creation(output_bsk)
     }
 }
 
@@ -131,6 +137,72 @@ impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, FourierLweBootstrapKey64
         &mut self,
         input: &LweBootstrapKey64,
     ) -> FourierLweBootstrapKey64 {
+        let output = ImplFourierBootstrapKey::allocate_with_precision(
+            Complex64::new(0., 0.),
+            input.glwe_dimension().to_glwe_size(),
+            input.polynomial_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+            self.fft_precision,
+        );
+        let mut output_bsk = FourierLweBootstrapKey64(output);
+        let buffers = self.get_fourier_u64_buffer(
+            output_bsk.polynomial_size(),
+            output_bsk.glwe_dimension().to_glwe_size(),
+        );
+        output_bsk.0.fill_with_forward_fourier(&input.0, buffers);
+        self.audit_creation(output_bsk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. It copies a bootstrap key in the standard domain into an [`AlignedBuffer`]
+/// aligned on a cache line ([`Alignment::CACHE_LINE`]).
+impl LweBootstrapKeyConversionEngine<LweBootstrapKey32, AlignedLweBootstrapKey32> for CoreEngine {
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweBootstrapKey32,
+    ) -> Result<AlignedLweBootstrapKey32, LweBootstrapKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweBootstrapKey32,
+    ) -> AlignedLweBootstrapKey32 {
+        let buffer =
+            AlignedBuffer::from_slice(input.0.as_tensor().as_slice(), Alignment::CACHE_LINE);
+        self.audit_creation(AlignedLweBootstrapKey32(
+            ImplStandardBootstrapKey::from_container(
+                buffer,
+                input.0.glwe_size(),
+                input.0.polynomial_size(),
+                input.0.level_count(),
+                input.0.base_log(),
+            ),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. It converts an aligned bootstrap key from the standard to the Fourier domain.
+impl LweBootstrapKeyConversionEngine<AlignedLweBootstrapKey32, FourierLweBootstrapKey32>
+    for CoreEngine
+{
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &AlignedLweBootstrapKey32,
+    ) -> Result<FourierLweBootstrapKey32, LweBootstrapKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &AlignedLweBootstrapKey32,
+    ) -> FourierLweBootstrapKey32 {
         let output = ImplFourierBootstrapKey::allocate(
             Complex64::new(0., 0.),
             input.glwe_dimension().to_glwe_size(),
@@ -139,13 +211,79 @@ impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, FourierLweBootstrapKey64
             input.decomposition_base_log(),
             input.input_lwe_dimension(),
         );
+        let mut output_bsk = FourierLweBootstrapKey32(output);
+        let buffers = self.get_fourier_u32_buffer(
+            output_bsk.polynomial_size(),
+            output_bsk.glwe_dimension().to_glwe_size(),
+        );
+        output_bsk.0.fill_with_forward_fourier(&input.0, buffers);
+        self.audit_creation(output_bsk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. It copies a bootstrap key in the standard domain into an [`AlignedBuffer`]
+/// aligned on a cache line ([`Alignment::CACHE_LINE`]).
+impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, AlignedLweBootstrapKey64> for CoreEngine {
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweBootstrapKey64,
+    ) -> Result<AlignedLweBootstrapKey64, LweBootstrapKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweBootstrapKey64,
+    ) -> AlignedLweBootstrapKey64 {
+        let buffer =
+            AlignedBuffer::from_slice(input.0.as_tensor().as_slice(), Alignment::CACHE_LINE);
+        self.audit_creation(AlignedLweBootstrapKey64(
+            ImplStandardBootstrapKey::from_container(
+                buffer,
+                input.0.glwe_size(),
+                input.0.polynomial_size(),
+                input.0.level_count(),
+                input.0.base_log(),
+            ),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. It converts an aligned bootstrap key from the standard to the Fourier domain.
+impl LweBootstrapKeyConversionEngine<AlignedLweBootstrapKey64, FourierLweBootstrapKey64>
+    for CoreEngine
+{
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &AlignedLweBootstrapKey64,
+    ) -> Result<FourierLweBootstrapKey64, LweBootstrapKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &AlignedLweBootstrapKey64,
+    ) -> FourierLweBootstrapKey64 {
+        let output = ImplFourierBootstrapKey::allocate_with_precision(
+            Complex64::new(0., 0.),
+            input.glwe_dimension().to_glwe_size(),
+            input.polynomial_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+            self.fft_precision,
+        );
         let mut output_bsk = FourierLweBootstrapKey64(output);
         let buffers = self.get_fourier_u64_buffer(
             output_bsk.polynomial_size(),
             output_bsk.glwe_dimension().to_glwe_size(),
         );
         output_bsk.0.fill_with_forward_fourier(&input.0, buffers);
-        output_bsk
+        self.audit_creation(output_bsk)
     }
 }
 
@@ -1,7 +1,7 @@
 use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
 
-use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::engines::{CoreEngine, CoreError};
 use crate::backends::core::implementation::entities::{
     FourierLweBootstrapKey32, FourierLweBootstrapKey64, GlweSecretKey32, GlweSecretKey64,
     LweBootstrapKey32, LweBootstrapKey64, LweSecretKey32, LweSecretKey64,
@@ -14,6 +14,12 @@ use crate::backends::core::private::math::fft::Complex64;
 use crate::prelude::LweBootstrapKeyEntity;
 use crate::specification::engines::{LweBootstrapKeyCreationEngine, LweBootstrapKeyCreationError};
 
+impl From<CoreError> for LweBootstrapKeyCreationError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweBootstrapKeyCreationEngine`] for [`CoreEngine`] that operates on
 /// 32 bits integers. It outputs a bootstrap key in the standard domain.
@@ -68,7 +74,8 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey32, GlweSecretKey32, LweBootstrap
             decomposition_level_count,
             32,
         )?;
-        Ok(unsafe {
+        self.check_key_generation_cancellation()?;
+        let key = unsafe {
             self.create_lwe_bootstrap_key_unchecked(
                 input_key,
                 output_key,
@@ -76,7 +83,9 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey32, GlweSecretKey32, LweBootstrap
                 decomposition_level_count,
                 noise,
             )
-        })
+        };
+        self.check_key_generation_cancellation()?;
+        Ok(key)
     }
 
     unsafe fn create_lwe_bootstrap_key_unchecked(
@@ -95,13 +104,15 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey32, GlweSecretKey32, LweBootstrap
             decomposition_base_log,
             input_key.0.key_size(),
         );
-        key.fill_with_new_key(
+        key.fill_with_new_key_monitored(
             &input_key.0,
             &output_key.0,
             noise,
             &mut self.encryption_generator,
+            self.key_generation_monitor
+                .reporter(input_key.0.key_size().0),
         );
-        LweBootstrapKey32(key)
+        self.audit_creation(LweBootstrapKey32(key))
     }
 }
 
@@ -159,7 +170,8 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey64, GlweSecretKey64, LweBootstrap
             decomposition_level_count,
             64,
         )?;
-        Ok(unsafe {
+        self.check_key_generation_cancellation()?;
+        let key = unsafe {
             self.create_lwe_bootstrap_key_unchecked(
                 input_key,
                 output_key,
@@ -167,7 +179,9 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey64, GlweSecretKey64, LweBootstrap
                 decomposition_level_count,
                 noise,
             )
-        })
+        };
+        self.check_key_generation_cancellation()?;
+        Ok(key)
     }
 
     unsafe fn create_lwe_bootstrap_key_unchecked(
@@ -186,13 +200,15 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey64, GlweSecretKey64, LweBootstrap
             decomposition_base_log,
             input_key.0.key_size(),
         );
-        key.fill_with_new_key(
+        key.fill_with_new_key_monitored(
             &input_key.0,
             &output_key.0,
             noise,
             &mut self.encryption_generator,
+            self.key_generation_monitor
+                .reporter(input_key.0.key_size().0),
         );
-        LweBootstrapKey64(key)
+        self.audit_creation(LweBootstrapKey64(key))
     }
 }
 
@@ -250,7 +266,8 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey32, GlweSecretKey32, FourierLweBo
             decomposition_level_count,
             32,
         )?;
-        Ok(unsafe {
+        self.check_key_generation_cancellation()?;
+        let key = unsafe {
             self.create_lwe_bootstrap_key_unchecked(
                 input_key,
                 output_key,
@@ -258,7 +275,9 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey32, GlweSecretKey32, FourierLweBo
                 decomposition_level_count,
                 noise,
             )
-        })
+        };
+        self.check_key_generation_cancellation()?;
+        Ok(key)
     }
 
     unsafe fn create_lwe_bootstrap_key_unchecked(
@@ -277,11 +296,13 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey32, GlweSecretKey32, FourierLweBo
             decomposition_base_log,
             input_key.0.key_size(),
         );
-        key.fill_with_new_key(
+        key.fill_with_new_key_monitored(
             &input_key.0,
             &output_key.0,
             noise,
             &mut self.encryption_generator,
+            self.key_generation_monitor
+                .reporter(input_key.0.key_size().0),
         );
         let fourier_key = ImplFourierBootstrapKey::allocate(
             Complex64::new(0., 0.),
@@ -298,7 +319,7 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey32, GlweSecretKey32, FourierLweBo
             fourier_key.glwe_dimension().to_glwe_size(),
         );
         fourier_key.0.fill_with_forward_fourier(&key, buffers);
-        fourier_key
+        self.audit_creation(fourier_key)
     }
 }
 
@@ -356,7 +377,8 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey64, GlweSecretKey64, FourierLweBo
             decomposition_level_count,
             64,
         )?;
-        Ok(unsafe {
+        self.check_key_generation_cancellation()?;
+        let key = unsafe {
             self.create_lwe_bootstrap_key_unchecked(
                 input_key,
                 output_key,
@@ -364,7 +386,9 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey64, GlweSecretKey64, FourierLweBo
                 decomposition_level_count,
                 noise,
             )
-        })
+        };
+        self.check_key_generation_cancellation()?;
+        Ok(key)
     }
 
     unsafe fn create_lwe_bootstrap_key_unchecked(
@@ -383,19 +407,22 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey64, GlweSecretKey64, FourierLweBo
             decomposition_base_log,
             input_key.0.key_size(),
         );
-        key.fill_with_new_key(
+        key.fill_with_new_key_monitored(
             &input_key.0,
             &output_key.0,
             noise,
             &mut self.encryption_generator,
+            self.key_generation_monitor
+                .reporter(input_key.0.key_size().0),
         );
-        let fourier_key = ImplFourierBootstrapKey::allocate(
+        let fourier_key = ImplFourierBootstrapKey::allocate_with_precision(
             Complex64::new(0., 0.),
             output_key.0.key_size().to_glwe_size(),
             output_key.0.polynomial_size(),
             decomposition_level_count,
             decomposition_base_log,
             input_key.0.key_size(),
+            self.fft_precision,
         );
 
         let mut fourier_key = FourierLweBootstrapKey64(fourier_key);
@@ -404,6 +431,6 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey64, GlweSecretKey64, FourierLweBo
             fourier_key.glwe_dimension().to_glwe_size(),
         );
         fourier_key.0.fill_with_forward_fourier(&key, buffers);
-        fourier_key
+        self.audit_creation(fourier_key)
     }
 }
@@ -0,0 +1,481 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    AccumulatingCiphertext, FourierLweBootstrapKey32, FourierLweBootstrapKey64, LweCiphertext32,
+    LweCiphertext64, LweKeyswitchKey32, LweKeyswitchKey64, TaggedCiphertext,
+};
+use crate::backends::core::private::crypto::bootstrap::{
+    accumulation_encoding_is_supported, reset_accumulating_lwe,
+};
+use crate::backends::core::private::math::fft::ALLOWED_POLY_SIZE;
+use crate::prelude::{CoreError, LweBootstrapKeyEntity};
+use crate::specification::engines::{
+    LweCiphertextAccumulationEngine, LweCiphertextAccumulationError,
+};
+
+impl From<CoreError> for LweCiphertextAccumulationError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextAccumulationEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+///
+/// The accumulator is reset with a bootstrap evaluating the reduction of its message modulo the
+/// counter modulus of its [`AccumulationParameters`]. The encoding of the accumulator must keep
+/// exactly one bit of padding, and its message modulus must be a power of two which does not
+/// exceed the polynomial size of the bootstrap key, otherwise
+/// [`CoreError::UnsupportedEncoding`] is returned. When the addition can not be performed even
+/// after a reset, [`CoreError::MessageOverflow`] or [`CoreError::NoiseOverflow`] is returned.
+impl
+    LweCiphertextAccumulationEngine<
+        AccumulatingCiphertext<LweCiphertext32>,
+        TaggedCiphertext<LweCiphertext32>,
+        LweKeyswitchKey32,
+        FourierLweBootstrapKey32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(10), GlweDimension(1), PolynomialSize(1024));
+    /// let (bsk_lc, bsk_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let (ksk_lc, ksk_bl) = (DecompositionLevelCount(5), DecompositionBaseLog(3));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied: 3 bits of message, and 1 bit of padding. The sum is
+    /// // counted modulo 4, the remaining bit holding its carry.
+    /// let delta = 1_u32 << 28;
+    /// let metadata = EncodingMetadata {
+    ///     delta: ScalingFactor(delta as u64),
+    ///     message_modulus: 8,
+    ///     carry_modulus: 1,
+    ///     degree: 1,
+    /// };
+    /// let parameters = AccumulationParameters {
+    ///     counter_modulus: 4,
+    ///     input_variance: noise,
+    ///     reset_variance: Variance(2_f64.powf(-40.)),
+    ///     max_variance: Variance(2_f64.powf(-30.)),
+    /// };
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, bsk_bl, bsk_lc, noise)?;
+    /// let big_lwe_sk: LweSecretKey32 = engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let ksk = engine.create_lwe_keyswitch_key(&big_lwe_sk, &lwe_sk, ksk_lc, ksk_bl, noise)?;
+    /// let zero = engine.create_plaintext(&0_u32)?;
+    /// let one = engine.create_plaintext(&delta)?;
+    /// let ciphertext: LweCiphertext32 = engine.encrypt_lwe_ciphertext(&big_lwe_sk, &zero, noise)?;
+    /// let zero_metadata = EncodingMetadata { degree: 0, ..metadata };
+    /// let mut accumulator = AccumulatingCiphertext::new(ciphertext, zero_metadata, parameters, noise);
+    /// let input: LweCiphertext32 = engine.encrypt_lwe_ciphertext(&big_lwe_sk, &one, noise)?;
+    /// let input = TaggedCiphertext::new(input, metadata);
+    ///
+    /// for _ in 0..10 {
+    ///     engine.accumulate_lwe_ciphertext(&mut accumulator, &input, &ksk, &bsk)?;
+    /// }
+    /// #
+    /// // The eighth addition would have overflowed the message space:
+    /// assert_eq!(accumulator.reset_count(), 1);
+    /// assert_eq!(accumulator.metadata().degree, 6);
+    /// # let output = engine.decrypt_lwe_ciphertext(&big_lwe_sk, accumulator.as_ciphertext())?;
+    /// # let output: u32 = engine.retrieve_plaintext(&output)?;
+    /// # assert_eq!(output.wrapping_add(delta / 2) >> 28, 6);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(big_lwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(ksk)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(one)?;
+    /// engine.destroy(accumulator)?;
+    /// engine.destroy(input)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn accumulate_lwe_ciphertext(
+        &mut self,
+        accumulator: &mut AccumulatingCiphertext<LweCiphertext32>,
+        input: &TaggedCiphertext<LweCiphertext32>,
+        ksk: &LweKeyswitchKey32,
+        bsk: &FourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextAccumulationError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&bsk.polynomial_size().0) {
+            return Err(LweCiphertextAccumulationError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
+        LweCiphertextAccumulationError::perform_generic_checks(accumulator, input, ksk, bsk)?;
+        if !accumulation_encoding_is_supported::<u32>(
+            accumulator.metadata.delta,
+            accumulator.metadata.total_modulus(),
+            accumulator.parameters.counter_modulus,
+            bsk.polynomial_size(),
+        ) {
+            return Err(LweCiphertextAccumulationError::from(
+                CoreError::UnsupportedEncoding,
+            ));
+        }
+        accumulator.check_accumulation(&input.metadata)?;
+        unsafe { self.accumulate_lwe_ciphertext_unchecked(accumulator, input, ksk, bsk) };
+        Ok(())
+    }
+
+    unsafe fn accumulate_lwe_ciphertext_unchecked(
+        &mut self,
+        accumulator: &mut AccumulatingCiphertext<LweCiphertext32>,
+        input: &TaggedCiphertext<LweCiphertext32>,
+        ksk: &LweKeyswitchKey32,
+        bsk: &FourierLweBootstrapKey32,
+    ) {
+        if accumulator.needs_reset(&input.metadata) {
+            let buffers = self
+                .get_fourier_u32_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
+            reset_accumulating_lwe(
+                &mut accumulator.ciphertext.0,
+                &ksk.0,
+                &bsk.0,
+                accumulator.metadata.delta.0 as u32,
+                accumulator.metadata.total_modulus(),
+                accumulator.parameters.counter_modulus,
+                buffers,
+            );
+            accumulator.record_reset();
+        }
+        accumulator
+            .ciphertext
+            .0
+            .update_with_add(&input.ciphertext.0);
+        accumulator.record_addition(&input.metadata);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextAccumulationEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+///
+/// The accumulator is reset with a bootstrap evaluating the reduction of its message modulo the
+/// counter modulus of its [`AccumulationParameters`]. The encoding of the accumulator must keep
+/// exactly one bit of padding, and its message modulus must be a power of two which does not
+/// exceed the polynomial size of the bootstrap key, otherwise
+/// [`CoreError::UnsupportedEncoding`] is returned. When the addition can not be performed even
+/// after a reset, [`CoreError::MessageOverflow`] or [`CoreError::NoiseOverflow`] is returned.
+impl
+    LweCiphertextAccumulationEngine<
+        AccumulatingCiphertext<LweCiphertext64>,
+        TaggedCiphertext<LweCiphertext64>,
+        LweKeyswitchKey64,
+        FourierLweBootstrapKey64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(10), GlweDimension(1), PolynomialSize(1024));
+    /// let (bsk_lc, bsk_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let (ksk_lc, ksk_bl) = (DecompositionLevelCount(5), DecompositionBaseLog(3));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied: 3 bits of message, and 1 bit of padding. The sum is
+    /// // counted modulo 4, the remaining bit holding its carry.
+    /// let delta = 1_u64 << 60;
+    /// let metadata = EncodingMetadata {
+    ///     delta: ScalingFactor(delta as u64),
+    ///     message_modulus: 8,
+    ///     carry_modulus: 1,
+    ///     degree: 1,
+    /// };
+    /// let parameters = AccumulationParameters {
+    ///     counter_modulus: 4,
+    ///     input_variance: noise,
+    ///     reset_variance: Variance(2_f64.powf(-40.)),
+    ///     max_variance: Variance(2_f64.powf(-30.)),
+    /// };
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, bsk_bl, bsk_lc, noise)?;
+    /// let big_lwe_sk: LweSecretKey64 = engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let ksk = engine.create_lwe_keyswitch_key(&big_lwe_sk, &lwe_sk, ksk_lc, ksk_bl, noise)?;
+    /// let zero = engine.create_plaintext(&0_u64)?;
+    /// let one = engine.create_plaintext(&delta)?;
+    /// let ciphertext: LweCiphertext64 = engine.encrypt_lwe_ciphertext(&big_lwe_sk, &zero, noise)?;
+    /// let zero_metadata = EncodingMetadata { degree: 0, ..metadata };
+    /// let mut accumulator = AccumulatingCiphertext::new(ciphertext, zero_metadata, parameters, noise);
+    /// let input: LweCiphertext64 = engine.encrypt_lwe_ciphertext(&big_lwe_sk, &one, noise)?;
+    /// let input = TaggedCiphertext::new(input, metadata);
+    ///
+    /// for _ in 0..10 {
+    ///     engine.accumulate_lwe_ciphertext(&mut accumulator, &input, &ksk, &bsk)?;
+    /// }
+    /// #
+    /// // The eighth addition would have overflowed the message space:
+    /// assert_eq!(accumulator.reset_count(), 1);
+    /// assert_eq!(accumulator.metadata().degree, 6);
+    /// # let output = engine.decrypt_lwe_ciphertext(&big_lwe_sk, accumulator.as_ciphertext())?;
+    /// # let output: u64 = engine.retrieve_plaintext(&output)?;
+    /// # assert_eq!(output.wrapping_add(delta / 2) >> 60, 6);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(big_lwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(ksk)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(one)?;
+    /// engine.destroy(accumulator)?;
+    /// engine.destroy(input)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn accumulate_lwe_ciphertext(
+        &mut self,
+        accumulator: &mut AccumulatingCiphertext<LweCiphertext64>,
+        input: &TaggedCiphertext<LweCiphertext64>,
+        ksk: &LweKeyswitchKey64,
+        bsk: &FourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextAccumulationError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&bsk.polynomial_size().0) {
+            return Err(LweCiphertextAccumulationError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
+        LweCiphertextAccumulationError::perform_generic_checks(accumulator, input, ksk, bsk)?;
+        if !accumulation_encoding_is_supported::<u64>(
+            accumulator.metadata.delta,
+            accumulator.metadata.total_modulus(),
+            accumulator.parameters.counter_modulus,
+            bsk.polynomial_size(),
+        ) {
+            return Err(LweCiphertextAccumulationError::from(
+                CoreError::UnsupportedEncoding,
+            ));
+        }
+        accumulator.check_accumulation(&input.metadata)?;
+        unsafe { self.accumulate_lwe_ciphertext_unchecked(accumulator, input, ksk, bsk) };
+        Ok(())
+    }
+
+    unsafe fn accumulate_lwe_ciphertext_unchecked(
+        &mut self,
+        accumulator: &mut AccumulatingCiphertext<LweCiphertext64>,
+        input: &TaggedCiphertext<LweCiphertext64>,
+        ksk: &LweKeyswitchKey64,
+        bsk: &FourierLweBootstrapKey64,
+    ) {
+        if accumulator.needs_reset(&input.metadata) {
+            let buffers = self
+                .get_fourier_u64_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
+            reset_accumulating_lwe(
+                &mut accumulator.ciphertext.0,
+                &ksk.0,
+                &bsk.0,
+                accumulator.metadata.delta.0,
+                accumulator.metadata.total_modulus(),
+                accumulator.parameters.counter_modulus,
+                buffers,
+            );
+            accumulator.record_reset();
+        }
+        accumulator
+            .ciphertext
+            .0
+            .update_with_add(&input.ciphertext.0);
+        accumulator.record_addition(&input.metadata);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    struct Setup {
+        engine: CoreEngine,
+        big_lwe_sk: LweSecretKey64,
+        ksk: LweKeyswitchKey64,
+        bsk: FourierLweBootstrapKey64,
+    }
+
+    fn setup() -> Setup {
+        let mut engine = CoreEngine::new().unwrap();
+        let noise = Variance(2_f64.powf(-50.));
+        let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(10)).unwrap();
+        let glwe_sk: GlweSecretKey64 = engine
+            .create_glwe_secret_key(GlweDimension(1), PolynomialSize(1024))
+            .unwrap();
+        let bsk = engine
+            .create_lwe_bootstrap_key(
+                &lwe_sk,
+                &glwe_sk,
+                DecompositionBaseLog(7),
+                DecompositionLevelCount(3),
+                noise,
+            )
+            .unwrap();
+        let big_lwe_sk: LweSecretKey64 = engine
+            .transmute_glwe_secret_key_to_lwe_secret_key(glwe_sk)
+            .unwrap();
+        let ksk = engine
+            .create_lwe_keyswitch_key(
+                &big_lwe_sk,
+                &lwe_sk,
+                DecompositionLevelCount(5),
+                DecompositionBaseLog(3),
+                noise,
+            )
+            .unwrap();
+        Setup {
+            engine,
+            big_lwe_sk,
+            ksk,
+            bsk,
+        }
+    }
+
+    fn encrypt(
+        setup: &mut Setup,
+        message: u64,
+        message_modulus: usize,
+    ) -> TaggedCiphertext<LweCiphertext64> {
+        let delta = (1_u64 << 63) / message_modulus as u64;
+        let plaintext = setup.engine.create_plaintext(&(message * delta)).unwrap();
+        let ciphertext = setup
+            .engine
+            .encrypt_lwe_ciphertext(&setup.big_lwe_sk, &plaintext, Variance(2_f64.powf(-50.)))
+            .unwrap();
+        let metadata = EncodingMetadata {
+            delta: ScalingFactor(delta),
+            message_modulus,
+            carry_modulus: 1,
+            degree: message as usize,
+        };
+        TaggedCiphertext::new(ciphertext, metadata)
+    }
+
+    fn accumulator(
+        setup: &mut Setup,
+        message_modulus: usize,
+        parameters: AccumulationParameters,
+    ) -> AccumulatingCiphertext<LweCiphertext64> {
+        let zero = encrypt(setup, 0, message_modulus);
+        let variance = parameters.input_variance;
+        AccumulatingCiphertext::new(zero.ciphertext, zero.metadata, parameters, variance)
+    }
+
+    #[test]
+    fn test_noise_budget_triggers_resets() {
+        let mut setup = setup();
+        let parameters = AccumulationParameters {
+            counter_modulus: 16,
+            input_variance: Variance(2_f64.powf(-50.)),
+            reset_variance: Variance(2_f64.powf(-50.)),
+            max_variance: Variance(3. * 2_f64.powf(-50.)),
+        };
+        let mut accumulator = accumulator(&mut setup, 16, parameters);
+        let input = encrypt(&mut setup, 1, 16);
+        for _ in 0..6 {
+            setup
+                .engine
+                .accumulate_lwe_ciphertext(&mut accumulator, &input, &setup.ksk, &setup.bsk)
+                .unwrap();
+        }
+        // The budget allows two additions after every reset.
+        assert_eq!(accumulator.reset_count(), 2);
+        assert_eq!(accumulator.metadata().degree, 6);
+        let output = setup
+            .engine
+            .decrypt_lwe_ciphertext(&setup.big_lwe_sk, accumulator.as_ciphertext())
+            .unwrap();
+        let output: u64 = setup.engine.retrieve_plaintext(&output).unwrap();
+        assert_eq!(output.wrapping_add(1 << 58) >> 59, 6);
+    }
+
+    #[test]
+    fn test_inputs_larger_than_the_counter_are_rejected() {
+        let mut setup = setup();
+        let parameters = AccumulationParameters {
+            counter_modulus: 4,
+            input_variance: Variance(2_f64.powf(-50.)),
+            reset_variance: Variance(2_f64.powf(-40.)),
+            max_variance: Variance(2_f64.powf(-30.)),
+        };
+        let mut accumulator = accumulator(&mut setup, 8, parameters);
+        let input = encrypt(&mut setup, 3, 8);
+        setup
+            .engine
+            .accumulate_lwe_ciphertext(&mut accumulator, &input, &setup.ksk, &setup.bsk)
+            .unwrap();
+        setup
+            .engine
+            .accumulate_lwe_ciphertext(&mut accumulator, &input, &setup.ksk, &setup.bsk)
+            .unwrap();
+        let large_input = encrypt(&mut setup, 5, 8);
+        // Even after a reset, the degree of the accumulator would reach 3 + 5.
+        assert!(matches!(
+            setup.engine.accumulate_lwe_ciphertext(
+                &mut accumulator,
+                &large_input,
+                &setup.ksk,
+                &setup.bsk
+            ),
+            Err(LweCiphertextAccumulationError::Engine(
+                CoreError::MessageOverflow
+            ))
+        ));
+        assert_eq!(accumulator.reset_count(), 0);
+        assert_eq!(accumulator.metadata().degree, 6);
+    }
+
+    #[test]
+    fn test_encodings_without_one_bit_of_padding_are_rejected() {
+        let mut setup = setup();
+        let parameters = AccumulationParameters {
+            counter_modulus: 4,
+            input_variance: Variance(2_f64.powf(-50.)),
+            reset_variance: Variance(2_f64.powf(-40.)),
+            max_variance: Variance(2_f64.powf(-30.)),
+        };
+        let zero = encrypt(&mut setup, 0, 8);
+        let metadata = EncodingMetadata {
+            delta: ScalingFactor(zero.metadata.delta.0 / 2),
+            ..zero.metadata
+        };
+        let mut accumulator =
+            AccumulatingCiphertext::new(zero.ciphertext, metadata, parameters, Variance(0.));
+        let input = TaggedCiphertext::new(encrypt(&mut setup, 1, 8).ciphertext, metadata);
+        assert!(matches!(
+            setup.engine.accumulate_lwe_ciphertext(
+                &mut accumulator,
+                &input,
+                &setup.ksk,
+                &setup.bsk
+            ),
+            Err(LweCiphertextAccumulationError::Engine(
+                CoreError::UnsupportedEncoding
+            ))
+        ));
+    }
+}
@@ -1,7 +1,9 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    Cleartext32, Cleartext64, LweCiphertext32, LweCiphertext64,
+    Cleartext32, Cleartext64, LweCiphertext32, LweCiphertext64, LweCiphertextTruncatedView32,
+    TaggedCiphertext,
 };
+use crate::backends::core::private::math::tensor::AsMutTensor;
 use crate::specification::engines::{
     LweCiphertextCleartextDiscardingMultiplicationEngine,
     LweCiphertextCleartextDiscardingMultiplicationError,
@@ -142,3 +144,259 @@ impl
         output.0.fill_with_scalar_mul(&input_1.0, &input_2.0);
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextDiscardingMultiplicationEngine`] for [`CoreEngine`]
+/// that multiplies a truncated view of a 64 bits ciphertext, and stores the result in a 32 bits
+/// ciphertext.
+impl<'a>
+    LweCiphertextCleartextDiscardingMultiplicationEngine<
+        LweCiphertextTruncatedView32<'a>,
+        Cleartext32,
+        LweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_size = LweDimension(4).to_lwe_size();
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext: Cleartext32 = engine.create_cleartext(&12_u32)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext_1: LweCiphertext64 =
+    ///     engine.trivially_encrypt_lwe_ciphertext(lwe_size, &plaintext)?;
+    /// let zero = engine.create_plaintext(&0_u32)?;
+    /// let mut ciphertext_2 = engine.trivially_encrypt_lwe_ciphertext(lwe_size, &zero)?;
+    ///
+    /// let view: LweCiphertextTruncatedView32 =
+    ///     engine.create_lwe_ciphertext_truncated_view(&ciphertext_1)?;
+    /// engine.discard_mul_lwe_ciphertext_cleartext(&mut ciphertext_2, &view, &cleartext)?;
+    /// #
+    /// let output = engine.trivially_decrypt_lwe_ciphertext(&ciphertext_2)?;
+    /// assert_eq!(engine.retrieve_plaintext(&output)?, 36_u32 << 18);
+    ///
+    /// engine.destroy(view)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input_1: &LweCiphertextTruncatedView32<'a>,
+        input_2: &Cleartext32,
+    ) -> Result<(), LweCiphertextCleartextDiscardingMultiplicationError<Self::EngineError>> {
+        LweCiphertextCleartextDiscardingMultiplicationError::perform_generic_checks(
+            output, input_1,
+        )?;
+        unsafe { self.discard_mul_lwe_ciphertext_cleartext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input_1: &LweCiphertextTruncatedView32<'a>,
+        input_2: &Cleartext32,
+    ) {
+        let coefficients = input_1.truncated_coefficients();
+        for (out, coefficient) in output.0.as_mut_tensor().iter_mut().zip(coefficients) {
+            *out = coefficient.wrapping_mul(input_2.0 .0);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextDiscardingMultiplicationEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers wrapped in a [`TaggedCiphertext`].
+impl
+    LweCiphertextCleartextDiscardingMultiplicationEngine<
+        TaggedCiphertext<LweCiphertext32>,
+        Cleartext32,
+        TaggedCiphertext<LweCiphertext32>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (3 bits of message, shift by 29 bits)
+    /// let delta = 1_u32 << 29;
+    /// let metadata = EncodingMetadata {
+    ///     delta: ScalingFactor(delta as u64),
+    ///     message_modulus: 8,
+    ///     carry_modulus: 1,
+    ///     degree: 3,
+    /// };
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&(3 * delta))?;
+    /// let cleartext: Cleartext32 = engine.create_cleartext(&2_u32)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let ciphertext_1 = TaggedCiphertext::new(ciphertext_1, metadata);
+    /// let ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    /// let mut ciphertext_2 = TaggedCiphertext::new(ciphertext_2, metadata);
+    ///
+    /// engine.discard_mul_lwe_ciphertext_cleartext(&mut ciphertext_2, &ciphertext_1, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext_2.metadata().degree, 6);
+    /// // Multiplying the result by two would overflow the message space:
+    /// let mut ciphertext_3 = ciphertext_1.clone();
+    /// assert!(engine
+    ///     .discard_mul_lwe_ciphertext_cleartext(&mut ciphertext_3, &ciphertext_2, &cleartext)
+    ///     .is_err());
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut TaggedCiphertext<LweCiphertext32>,
+        input_1: &TaggedCiphertext<LweCiphertext32>,
+        input_2: &Cleartext32,
+    ) -> Result<(), LweCiphertextCleartextDiscardingMultiplicationError<Self::EngineError>> {
+        LweCiphertextCleartextDiscardingMultiplicationError::perform_generic_checks(
+            output, input_1,
+        )?;
+        self.check_carry_overflow(
+            input_1.metadata.checked_mul(u64::from(input_2.0 .0)),
+            input_1.metadata.saturating_mul(u64::from(input_2.0 .0)),
+        )
+        .map_err(LweCiphertextCleartextDiscardingMultiplicationError::Engine)?;
+        unsafe { self.discard_mul_lwe_ciphertext_cleartext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut TaggedCiphertext<LweCiphertext32>,
+        input_1: &TaggedCiphertext<LweCiphertext32>,
+        input_2: &Cleartext32,
+    ) {
+        self.discard_mul_lwe_ciphertext_cleartext_unchecked(
+            &mut output.ciphertext,
+            &input_1.ciphertext,
+            input_2,
+        );
+        output.metadata = input_1.metadata.saturating_mul(u64::from(input_2.0 .0));
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextDiscardingMultiplicationEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers wrapped in a [`TaggedCiphertext`].
+impl
+    LweCiphertextCleartextDiscardingMultiplicationEngine<
+        TaggedCiphertext<LweCiphertext64>,
+        Cleartext64,
+        TaggedCiphertext<LweCiphertext64>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (3 bits of message, shift by 61 bits)
+    /// let delta = 1_u64 << 61;
+    /// let metadata = EncodingMetadata {
+    ///     delta: ScalingFactor(delta as u64),
+    ///     message_modulus: 8,
+    ///     carry_modulus: 1,
+    ///     degree: 3,
+    /// };
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&(3 * delta))?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext(&2_u64)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let ciphertext_1 = TaggedCiphertext::new(ciphertext_1, metadata);
+    /// let ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    /// let mut ciphertext_2 = TaggedCiphertext::new(ciphertext_2, metadata);
+    ///
+    /// engine.discard_mul_lwe_ciphertext_cleartext(&mut ciphertext_2, &ciphertext_1, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext_2.metadata().degree, 6);
+    /// // Multiplying the result by two would overflow the message space:
+    /// let mut ciphertext_3 = ciphertext_1.clone();
+    /// assert!(engine
+    ///     .discard_mul_lwe_ciphertext_cleartext(&mut ciphertext_3, &ciphertext_2, &cleartext)
+    ///     .is_err());
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut TaggedCiphertext<LweCiphertext64>,
+        input_1: &TaggedCiphertext<LweCiphertext64>,
+        input_2: &Cleartext64,
+    ) -> Result<(), LweCiphertextCleartextDiscardingMultiplicationError<Self::EngineError>> {
+        LweCiphertextCleartextDiscardingMultiplicationError::perform_generic_checks(
+            output, input_1,
+        )?;
+        self.check_carry_overflow(
+            input_1.metadata.checked_mul(input_2.0 .0),
+            input_1.metadata.saturating_mul(input_2.0 .0),
+        )
+        .map_err(LweCiphertextCleartextDiscardingMultiplicationError::Engine)?;
+        unsafe { self.discard_mul_lwe_ciphertext_cleartext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut TaggedCiphertext<LweCiphertext64>,
+        input_1: &TaggedCiphertext<LweCiphertext64>,
+        input_2: &Cleartext64,
+    ) {
+        self.discard_mul_lwe_ciphertext_cleartext_unchecked(
+            &mut output.ciphertext,
+            &input_1.ciphertext,
+            input_2,
+        );
+        output.metadata = input_1.metadata.saturating_mul(input_2.0 .0);
+    }
+}
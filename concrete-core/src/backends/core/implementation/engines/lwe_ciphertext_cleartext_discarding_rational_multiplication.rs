@@ -0,0 +1,189 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    Cleartext32, Cleartext64, LweCiphertext32, LweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextCleartextDiscardingRationalMultiplicationEngine,
+    LweCiphertextCleartextDiscardingRationalMultiplicationError,
+};
+use concrete_commons::parameters::DenominatorLog;
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextDiscardingRationalMultiplicationEngine`] for
+/// [`CoreEngine`] that operates on 32 bits integers.
+impl
+    LweCiphertextCleartextDiscardingRationalMultiplicationEngine<
+        LweCiphertext32,
+        Cleartext32,
+        LweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DenominatorLog, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 12_u32 << 20;
+    /// // The ciphertext is multiplied by 3 / 2^2
+    /// let numerator_input = 3_u32;
+    /// let denominator_log = DenominatorLog(2);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let numerator: Cleartext32 = engine.create_cleartext(&numerator_input)?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.discard_rational_mul_lwe_ciphertext_cleartext(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &numerator,
+    ///     denominator_log,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(numerator)?;
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_rational_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input_1: &LweCiphertext32,
+        numerator: &Cleartext32,
+        denominator_log: DenominatorLog,
+    ) -> Result<(), LweCiphertextCleartextDiscardingRationalMultiplicationError<Self::EngineError>>
+    {
+        LweCiphertextCleartextDiscardingRationalMultiplicationError::perform_generic_checks(
+            output,
+            input_1,
+            denominator_log,
+            32,
+        )?;
+        unsafe {
+            self.discard_rational_mul_lwe_ciphertext_cleartext_unchecked(
+                output,
+                input_1,
+                numerator,
+                denominator_log,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_rational_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input_1: &LweCiphertext32,
+        numerator: &Cleartext32,
+        denominator_log: DenominatorLog,
+    ) {
+        output
+            .0
+            .fill_with_rational_mul(&input_1.0, &numerator.0, denominator_log);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextDiscardingRationalMultiplicationEngine`] for
+/// [`CoreEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextCleartextDiscardingRationalMultiplicationEngine<
+        LweCiphertext64,
+        Cleartext64,
+        LweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DenominatorLog, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 12_u64 << 50;
+    /// // The ciphertext is multiplied by 3 / 2^2
+    /// let numerator_input = 3_u64;
+    /// let denominator_log = DenominatorLog(2);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let numerator: Cleartext64 = engine.create_cleartext(&numerator_input)?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.discard_rational_mul_lwe_ciphertext_cleartext(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &numerator,
+    ///     denominator_log,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(numerator)?;
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_rational_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input_1: &LweCiphertext64,
+        numerator: &Cleartext64,
+        denominator_log: DenominatorLog,
+    ) -> Result<(), LweCiphertextCleartextDiscardingRationalMultiplicationError<Self::EngineError>>
+    {
+        LweCiphertextCleartextDiscardingRationalMultiplicationError::perform_generic_checks(
+            output,
+            input_1,
+            denominator_log,
+            64,
+        )?;
+        unsafe {
+            self.discard_rational_mul_lwe_ciphertext_cleartext_unchecked(
+                output,
+                input_1,
+                numerator,
+                denominator_log,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_rational_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input_1: &LweCiphertext64,
+        numerator: &Cleartext64,
+        denominator_log: DenominatorLog,
+    ) {
+        output
+            .0
+            .fill_with_rational_mul(&input_1.0, &numerator.0, denominator_log);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    Cleartext32, Cleartext64, LweCiphertext32, LweCiphertext64,
+    Cleartext32, Cleartext64, LweCiphertext32, LweCiphertext64, TaggedCiphertext,
 };
 use crate::specification::engines::{
     LweCiphertextCleartextFusingMultiplicationEngine,
@@ -116,3 +116,151 @@ impl LweCiphertextCleartextFusingMultiplicationEngine<LweCiphertext64, Cleartext
         output.0.update_with_scalar_mul(input.0);
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers wrapped in a [`TaggedCiphertext`].
+impl
+    LweCiphertextCleartextFusingMultiplicationEngine<TaggedCiphertext<LweCiphertext32>, Cleartext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (3 bits of message, shift by 29 bits)
+    /// let delta = 1_u32 << 29;
+    /// let metadata = EncodingMetadata {
+    ///     delta: ScalingFactor(delta as u64),
+    ///     message_modulus: 8,
+    ///     carry_modulus: 1,
+    ///     degree: 3,
+    /// };
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&(3 * delta))?;
+    /// let cleartext: Cleartext32 = engine.create_cleartext(&2_u32)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut ciphertext = TaggedCiphertext::new(ciphertext, metadata);
+    ///
+    /// engine.fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext.metadata().degree, 6);
+    /// // Multiplying the result by two would overflow the message space:
+    /// assert!(engine
+    ///     .fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &cleartext)
+    ///     .is_err());
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut TaggedCiphertext<LweCiphertext32>,
+        input: &Cleartext32,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        self.check_carry_overflow(
+            output.metadata.checked_mul(u64::from(input.0 .0)),
+            output.metadata.saturating_mul(u64::from(input.0 .0)),
+        )
+        .map_err(LweCiphertextCleartextFusingMultiplicationError::Engine)?;
+        unsafe { self.fuse_mul_lwe_ciphertext_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut TaggedCiphertext<LweCiphertext32>,
+        input: &Cleartext32,
+    ) {
+        self.fuse_mul_lwe_ciphertext_cleartext_unchecked(&mut output.ciphertext, input);
+        output.metadata = output.metadata.saturating_mul(u64::from(input.0 .0));
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers wrapped in a [`TaggedCiphertext`].
+impl
+    LweCiphertextCleartextFusingMultiplicationEngine<TaggedCiphertext<LweCiphertext64>, Cleartext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (3 bits of message, shift by 61 bits)
+    /// let delta = 1_u64 << 61;
+    /// let metadata = EncodingMetadata {
+    ///     delta: ScalingFactor(delta as u64),
+    ///     message_modulus: 8,
+    ///     carry_modulus: 1,
+    ///     degree: 3,
+    /// };
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&(3 * delta))?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext(&2_u64)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut ciphertext = TaggedCiphertext::new(ciphertext, metadata);
+    ///
+    /// engine.fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext.metadata().degree, 6);
+    /// // Multiplying the result by two would overflow the message space:
+    /// assert!(engine
+    ///     .fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &cleartext)
+    ///     .is_err());
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut TaggedCiphertext<LweCiphertext64>,
+        input: &Cleartext64,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        self.check_carry_overflow(
+            output.metadata.checked_mul(input.0 .0),
+            output.metadata.saturating_mul(input.0 .0),
+        )
+        .map_err(LweCiphertextCleartextFusingMultiplicationError::Engine)?;
+        unsafe { self.fuse_mul_lwe_ciphertext_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut TaggedCiphertext<LweCiphertext64>,
+        input: &Cleartext64,
+    ) {
+        self.fuse_mul_lwe_ciphertext_cleartext_unchecked(&mut output.ciphertext, input);
+        output.metadata = output.metadata.saturating_mul(input.0 .0);
+    }
+}
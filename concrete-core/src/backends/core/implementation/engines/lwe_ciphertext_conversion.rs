@@ -0,0 +1,62 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertext32, LweCiphertextTruncatedView32,
+};
+use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::specification::engines::{LweCiphertextConversionEngine, LweCiphertextConversionError};
+
+/// # Description:
+/// Implementation of [`LweCiphertextConversionEngine`] for [`CoreEngine`] which materializes a
+/// truncated view in an owned 32 bits ciphertext.
+impl<'a> LweCiphertextConversionEngine<LweCiphertextTruncatedView32<'a>, LweCiphertext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_size = LweDimension(4).to_lwe_size();
+    /// // The message is encoded with a shift by 60 bits, which survives the truncation.
+    /// let input = 5_u64 << 60;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext: LweCiphertext64 =
+    ///     engine.trivially_encrypt_lwe_ciphertext(lwe_size, &plaintext)?;
+    /// let view: LweCiphertextTruncatedView32 =
+    ///     engine.create_lwe_ciphertext_truncated_view(&ciphertext)?;
+    ///
+    /// let truncated: LweCiphertext32 = engine.convert_lwe_ciphertext(&view)?;
+    /// #
+    /// assert_eq!(truncated.lwe_dimension(), ciphertext.lwe_dimension());
+    /// let output = engine.trivially_decrypt_lwe_ciphertext(&truncated)?;
+    /// assert_eq!(engine.retrieve_plaintext(&output)?, 5_u32 << 28);
+    ///
+    /// engine.destroy(view)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(truncated)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertextTruncatedView32<'a>,
+    ) -> Result<LweCiphertext32, LweCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertextTruncatedView32<'a>,
+    ) -> LweCiphertext32 {
+        LweCiphertext32(ImplLweCiphertext::from_container(
+            input.truncated_coefficients().collect(),
+        ))
+    }
+}
@@ -1,10 +1,64 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    LweCiphertext32, LweCiphertext64, LweSecretKey32, LweSecretKey64, Plaintext32, Plaintext64,
+    LweCiphertext128, LweCiphertext16, LweCiphertext32, LweCiphertext64, LweSecretKey128,
+    LweSecretKey16, LweSecretKey32, LweSecretKey64, Plaintext128, Plaintext16, Plaintext32,
+    Plaintext64,
 };
 use crate::backends::core::private::crypto::encoding::Plaintext as ImplPlaintext;
 use crate::specification::engines::{LweCiphertextDecryptionEngine, LweCiphertextDecryptionError};
 
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`CoreEngine`] that operates on
+/// 16 bits integers.
+impl LweCiphertextDecryptionEngine<LweSecretKey16, LweCiphertext16, Plaintext16> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 10 bits)
+    /// let input = 3_u16 << 10;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey16 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKey16,
+        input: &LweCiphertext16,
+    ) -> Result<Plaintext16, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey16,
+        input: &LweCiphertext16,
+    ) -> Plaintext16 {
+        let mut plaintext = ImplPlaintext(0u16);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext16(plaintext)
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextDecryptionEngine`] for [`CoreEngine`] that operates on
 /// 32 bits integers.
@@ -108,3 +162,55 @@ impl LweCiphertextDecryptionEngine<LweSecretKey64, LweCiphertext64, Plaintext64>
         Plaintext64(plaintext)
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`CoreEngine`] that operates on
+/// 128 bits integers.
+impl LweCiphertextDecryptionEngine<LweSecretKey128, LweCiphertext128, Plaintext128> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 114 bits)
+    /// let input = 3_u128 << 114;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey128 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKey128,
+        input: &LweCiphertext128,
+    ) -> Result<Plaintext128, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey128,
+        input: &LweCiphertext128,
+    ) -> Plaintext128 {
+        let mut plaintext = ImplPlaintext(0u128);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext128(plaintext)
+    }
+}
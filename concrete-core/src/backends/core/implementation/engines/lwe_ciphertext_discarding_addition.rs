@@ -1,10 +1,79 @@
 use crate::backends::core::implementation::engines::CoreEngine;
-use crate::backends::core::implementation::entities::{LweCiphertext32, LweCiphertext64};
+use crate::backends::core::implementation::entities::{
+    LweCiphertext16, LweCiphertext32, LweCiphertext64, LweCiphertextTruncatedView32,
+    TaggedCiphertext,
+};
 use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
 use crate::specification::engines::{
     LweCiphertextDiscardingAdditionEngine, LweCiphertextDiscardingAdditionError,
 };
 
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingAdditionEngine`] for [`CoreEngine`] that operates on
+/// 16 bits integers.
+impl LweCiphertextDiscardingAdditionEngine<LweCiphertext16, LweCiphertext16> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 10 bits)
+    /// let input_1 = 3_u16 << 10;
+    /// let input_2 = 7_u16 << 10;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey16 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = engine.create_plaintext(&input_1)?;
+    /// let plaintext_2 = engine.create_plaintext(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    /// let mut ciphertext_3 = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_add_lwe_ciphertext(&mut ciphertext_3, &ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_3.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_1)?;
+    /// engine.destroy(plaintext_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input_1: &LweCiphertext16,
+        input_2: &LweCiphertext16,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        LweCiphertextDiscardingAdditionError::perform_generic_checks(output, input_1, input_2)?;
+        unsafe { self.discard_add_lwe_ciphertext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input_1: &LweCiphertext16,
+        input_2: &LweCiphertext16,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input_1.0.as_tensor());
+        output.0.update_with_add(&input_2.0);
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextDiscardingAdditionEngine`] for [`CoreEngine`] that operates on
 /// 32 bits integers.
@@ -136,3 +205,257 @@ impl LweCiphertextDiscardingAdditionEngine<LweCiphertext64, LweCiphertext64> for
         output.0.update_with_add(&input_2.0);
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingAdditionEngine`] for [`CoreEngine`] that adds two
+/// truncated views of 64 bits ciphertexts, and stores the result in a 32 bits ciphertext.
+impl<'a> LweCiphertextDiscardingAdditionEngine<LweCiphertextTruncatedView32<'a>, LweCiphertext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_size = LweDimension(4).to_lwe_size();
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = 3_u64 << 50;
+    /// let input_2 = 7_u64 << 50;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext_1 = engine.create_plaintext(&input_1)?;
+    /// let plaintext_2 = engine.create_plaintext(&input_2)?;
+    /// let ciphertext_1: LweCiphertext64 =
+    ///     engine.trivially_encrypt_lwe_ciphertext(lwe_size, &plaintext_1)?;
+    /// let ciphertext_2: LweCiphertext64 =
+    ///     engine.trivially_encrypt_lwe_ciphertext(lwe_size, &plaintext_2)?;
+    /// let zero = engine.create_plaintext(&0_u32)?;
+    /// let mut ciphertext_3 = engine.trivially_encrypt_lwe_ciphertext(lwe_size, &zero)?;
+    ///
+    /// let view_1: LweCiphertextTruncatedView32 =
+    ///     engine.create_lwe_ciphertext_truncated_view(&ciphertext_1)?;
+    /// let view_2: LweCiphertextTruncatedView32 =
+    ///     engine.create_lwe_ciphertext_truncated_view(&ciphertext_2)?;
+    /// engine.discard_add_lwe_ciphertext(&mut ciphertext_3, &view_1, &view_2)?;
+    /// #
+    /// let output = engine.trivially_decrypt_lwe_ciphertext(&ciphertext_3)?;
+    /// assert_eq!(engine.retrieve_plaintext(&output)?, 10_u32 << 18);
+    ///
+    /// engine.destroy(view_1)?;
+    /// engine.destroy(view_2)?;
+    /// engine.destroy(plaintext_1)?;
+    /// engine.destroy(plaintext_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input_1: &LweCiphertextTruncatedView32<'a>,
+        input_2: &LweCiphertextTruncatedView32<'a>,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        LweCiphertextDiscardingAdditionError::perform_generic_checks(output, input_1, input_2)?;
+        unsafe { self.discard_add_lwe_ciphertext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input_1: &LweCiphertextTruncatedView32<'a>,
+        input_2: &LweCiphertextTruncatedView32<'a>,
+    ) {
+        let coefficients = input_1
+            .truncated_coefficients()
+            .zip(input_2.truncated_coefficients());
+        for (out, (a, b)) in output.0.as_mut_tensor().iter_mut().zip(coefficients) {
+            *out = a.wrapping_add(b);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingAdditionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers wrapped in a [`TaggedCiphertext`].
+impl
+    LweCiphertextDiscardingAdditionEngine<
+        TaggedCiphertext<LweCiphertext32>,
+        TaggedCiphertext<LweCiphertext32>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (3 bits of message, shift by 29 bits)
+    /// let delta = 1_u32 << 29;
+    /// let metadata = EncodingMetadata {
+    ///     delta: ScalingFactor(delta as u64),
+    ///     message_modulus: 8,
+    ///     carry_modulus: 1,
+    ///     degree: 3,
+    /// };
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&(3 * delta))?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let ciphertext_1 = TaggedCiphertext::new(ciphertext_1, metadata);
+    /// let ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let ciphertext_2 = TaggedCiphertext::new(ciphertext_2, metadata);
+    /// let ciphertext_3 = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    /// let mut ciphertext_3 = TaggedCiphertext::new(ciphertext_3, metadata);
+    ///
+    /// engine.discard_add_lwe_ciphertext(&mut ciphertext_3, &ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_3.metadata().degree, 6);
+    /// // Adding a third message of degree 3 would overflow the message space:
+    /// let mut ciphertext_4 = ciphertext_1.clone();
+    /// assert!(engine
+    ///     .discard_add_lwe_ciphertext(&mut ciphertext_4, &ciphertext_3, &ciphertext_1)
+    ///     .is_err());
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// engine.destroy(ciphertext_4)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut TaggedCiphertext<LweCiphertext32>,
+        input_1: &TaggedCiphertext<LweCiphertext32>,
+        input_2: &TaggedCiphertext<LweCiphertext32>,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        LweCiphertextDiscardingAdditionError::perform_generic_checks(output, input_1, input_2)?;
+        self.check_carry_overflow(
+            input_1.metadata.checked_add(&input_2.metadata),
+            input_1.metadata.saturating_add(&input_2.metadata),
+        )
+        .map_err(LweCiphertextDiscardingAdditionError::Engine)?;
+        unsafe { self.discard_add_lwe_ciphertext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut TaggedCiphertext<LweCiphertext32>,
+        input_1: &TaggedCiphertext<LweCiphertext32>,
+        input_2: &TaggedCiphertext<LweCiphertext32>,
+    ) {
+        self.discard_add_lwe_ciphertext_unchecked(
+            &mut output.ciphertext,
+            &input_1.ciphertext,
+            &input_2.ciphertext,
+        );
+        output.metadata = input_1.metadata.saturating_add(&input_2.metadata);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingAdditionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers wrapped in a [`TaggedCiphertext`].
+impl
+    LweCiphertextDiscardingAdditionEngine<
+        TaggedCiphertext<LweCiphertext64>,
+        TaggedCiphertext<LweCiphertext64>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (3 bits of message, shift by 61 bits)
+    /// let delta = 1_u64 << 61;
+    /// let metadata = EncodingMetadata {
+    ///     delta: ScalingFactor(delta as u64),
+    ///     message_modulus: 8,
+    ///     carry_modulus: 1,
+    ///     degree: 3,
+    /// };
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&(3 * delta))?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let ciphertext_1 = TaggedCiphertext::new(ciphertext_1, metadata);
+    /// let ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let ciphertext_2 = TaggedCiphertext::new(ciphertext_2, metadata);
+    /// let ciphertext_3 = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    /// let mut ciphertext_3 = TaggedCiphertext::new(ciphertext_3, metadata);
+    ///
+    /// engine.discard_add_lwe_ciphertext(&mut ciphertext_3, &ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_3.metadata().degree, 6);
+    /// // Adding a third message of degree 3 would overflow the message space:
+    /// let mut ciphertext_4 = ciphertext_1.clone();
+    /// assert!(engine
+    ///     .discard_add_lwe_ciphertext(&mut ciphertext_4, &ciphertext_3, &ciphertext_1)
+    ///     .is_err());
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// engine.destroy(ciphertext_4)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut TaggedCiphertext<LweCiphertext64>,
+        input_1: &TaggedCiphertext<LweCiphertext64>,
+        input_2: &TaggedCiphertext<LweCiphertext64>,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        LweCiphertextDiscardingAdditionError::perform_generic_checks(output, input_1, input_2)?;
+        self.check_carry_overflow(
+            input_1.metadata.checked_add(&input_2.metadata),
+            input_1.metadata.saturating_add(&input_2.metadata),
+        )
+        .map_err(LweCiphertextDiscardingAdditionError::Engine)?;
+        unsafe { self.discard_add_lwe_ciphertext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut TaggedCiphertext<LweCiphertext64>,
+        input_1: &TaggedCiphertext<LweCiphertext64>,
+        input_2: &TaggedCiphertext<LweCiphertext64>,
+    ) {
+        self.discard_add_lwe_ciphertext_unchecked(
+            &mut output.ciphertext,
+            &input_1.ciphertext,
+            &input_2.ciphertext,
+        );
+        output.metadata = input_1.metadata.saturating_add(&input_2.metadata);
+    }
+}
@@ -0,0 +1,223 @@
+use concrete_commons::parameters::LweKeyswitchKeyIndex;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweKeyswitchKeyBatch32, LweKeyswitchKeyBatch64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingBatchedKeyswitchEngine, LweCiphertextDiscardingBatchedKeyswitchError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBatchedKeyswitchEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextDiscardingBatchedKeyswitchEngine<
+        LweKeyswitchKeyBatch32,
+        LweCiphertext32,
+        LweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let mut batch: LweKeyswitchKeyBatch32 = engine.create_lwe_keyswitch_key_batch(
+    ///     input_lwe_dimension,
+    ///     output_lwe_dimension,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    /// )?;
+    /// // One output key per client.
+    /// let mut output_keys = Vec::new();
+    /// for _ in 0..3 {
+    ///     let output_key: LweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    ///     let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    ///     let index = engine.append_lwe_keyswitch_key(&mut batch, &keyswitch_key)?;
+    ///     engine.destroy(keyswitch_key)?;
+    ///     output_keys.push((index, output_key));
+    /// }
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let (index, output_key) = &output_keys[1];
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(output_key, noise)?;
+    ///
+    /// engine.discard_batched_keyswitch_lwe_ciphertext(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &batch,
+    ///     *index,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// for (_, output_key) in output_keys {
+    ///     engine.destroy(output_key)?;
+    /// }
+    /// engine.destroy(batch)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_batched_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        ksk_batch: &LweKeyswitchKeyBatch32,
+        index: LweKeyswitchKeyIndex,
+    ) -> Result<(), LweCiphertextDiscardingBatchedKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingBatchedKeyswitchError::perform_generic_checks(
+            output, input, ksk_batch, index,
+        )?;
+        unsafe {
+            self.discard_batched_keyswitch_lwe_ciphertext_unchecked(output, input, ksk_batch, index)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_batched_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        ksk_batch: &LweKeyswitchKeyBatch32,
+        index: LweKeyswitchKeyIndex,
+    ) {
+        ksk_batch
+            .0
+            .keyswitch_ciphertext(index, &mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBatchedKeyswitchEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextDiscardingBatchedKeyswitchEngine<
+        LweKeyswitchKeyBatch64,
+        LweCiphertext64,
+        LweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let mut batch: LweKeyswitchKeyBatch64 = engine.create_lwe_keyswitch_key_batch(
+    ///     input_lwe_dimension,
+    ///     output_lwe_dimension,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    /// )?;
+    /// // One output key per client.
+    /// let mut output_keys = Vec::new();
+    /// for _ in 0..3 {
+    ///     let output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    ///     let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    ///     let index = engine.append_lwe_keyswitch_key(&mut batch, &keyswitch_key)?;
+    ///     engine.destroy(keyswitch_key)?;
+    ///     output_keys.push((index, output_key));
+    /// }
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let (index, output_key) = &output_keys[1];
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(output_key, noise)?;
+    ///
+    /// engine.discard_batched_keyswitch_lwe_ciphertext(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &batch,
+    ///     *index,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// for (_, output_key) in output_keys {
+    ///     engine.destroy(output_key)?;
+    /// }
+    /// engine.destroy(batch)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_batched_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        ksk_batch: &LweKeyswitchKeyBatch64,
+        index: LweKeyswitchKeyIndex,
+    ) -> Result<(), LweCiphertextDiscardingBatchedKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingBatchedKeyswitchError::perform_generic_checks(
+            output, input, ksk_batch, index,
+        )?;
+        unsafe {
+            self.discard_batched_keyswitch_lwe_ciphertext_unchecked(output, input, ksk_batch, index)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_batched_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        ksk_batch: &LweKeyswitchKeyBatch64,
+        index: LweKeyswitchKeyIndex,
+    ) {
+        ksk_batch
+            .0
+            .keyswitch_ciphertext(index, &mut output.0, &input.0);
+    }
+}
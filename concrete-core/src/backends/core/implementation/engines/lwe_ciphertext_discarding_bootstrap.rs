@@ -104,9 +104,18 @@ impl
         acc: &GlweCiphertext32,
         bsk: &FourierLweBootstrapKey32,
     ) {
-        let buffers =
-            self.get_fourier_u32_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
-        bsk.0.bootstrap(&mut output.0, &input.0, &acc.0, buffers);
+        let poly_size = bsk.polynomial_size();
+        let glwe_size = bsk.glwe_dimension().to_glwe_size();
+        let small =
+            self.bootstrap_dispatch
+                .selects_small_kernel(poly_size, glwe_size, bsk.0.precision());
+        let buffers = self.get_fourier_u32_buffer(poly_size, glwe_size);
+        if small {
+            bsk.0
+                .small_bootstrap(&mut output.0, &input.0, &acc.0, buffers);
+        } else {
+            bsk.0.bootstrap(&mut output.0, &input.0, &acc.0, buffers);
+        }
     }
 }
 
@@ -199,9 +208,17 @@ impl
         acc: &GlweCiphertext64,
         bsk: &FourierLweBootstrapKey64,
     ) {
-        let buffers =
-            self.get_fourier_u64_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
-
-        bsk.0.bootstrap(&mut output.0, &input.0, &acc.0, buffers);
+        let poly_size = bsk.polynomial_size();
+        let glwe_size = bsk.glwe_dimension().to_glwe_size();
+        let small =
+            self.bootstrap_dispatch
+                .selects_small_kernel(poly_size, glwe_size, bsk.0.precision());
+        let buffers = self.get_fourier_u64_buffer(poly_size, glwe_size);
+        if small {
+            bsk.0
+                .small_bootstrap(&mut output.0, &input.0, &acc.0, buffers);
+        } else {
+            bsk.0.bootstrap(&mut output.0, &input.0, &acc.0, buffers);
+        }
     }
 }
@@ -40,7 +40,8 @@ impl LweCiphertextDiscardingExtractionEngine<GlweCiphertext32, LweCiphertext32>
     ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
     /// let lwe_key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
     /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
-    /// let glwe_ciphertext = engine.encrypt_glwe_ciphertext(&glwe_key, &plaintext_vector, noise)?;
+    /// let glwe_ciphertext: GlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&glwe_key, &plaintext_vector, noise)?;
     /// // We first create an LWE ciphertext encrypting zeros
     /// let mut lwe_ciphertext = engine.zero_encrypt_lwe_ciphertext(&lwe_key, noise)?;
     ///
@@ -117,7 +118,8 @@ impl LweCiphertextDiscardingExtractionEngine<GlweCiphertext64, LweCiphertext64>
     ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
     /// let lwe_key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
     /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
-    /// let glwe_ciphertext = engine.encrypt_glwe_ciphertext(&glwe_key, &plaintext_vector, noise)?;
+    /// let glwe_ciphertext: GlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&glwe_key, &plaintext_vector, noise)?;
     /// // We first create an LWE ciphertext encrypting zeros
     /// let mut lwe_ciphertext = engine.zero_encrypt_lwe_ciphertext(&lwe_key, noise)?;
     ///
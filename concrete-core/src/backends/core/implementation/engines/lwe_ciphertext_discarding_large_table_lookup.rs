@@ -0,0 +1,277 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FourierGgswCiphertext32, FourierGgswCiphertext64, FourierLweBootstrapKey32,
+    FourierLweBootstrapKey64, LweCiphertext32, LweCiphertext64, PlaintextVector32,
+    PlaintextVector64,
+};
+use crate::backends::core::private::crypto::bootstrap::large_table_lookup_lwe;
+use crate::backends::core::private::math::fft::ALLOWED_POLY_SIZE;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::prelude::{CoreError, LweBootstrapKeyEntity};
+use crate::specification::engines::{
+    LweCiphertextDiscardingLargeTableLookupEngine, LweCiphertextDiscardingLargeTableLookupError,
+};
+
+impl From<CoreError> for LweCiphertextDiscardingLargeTableLookupError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingLargeTableLookupEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextDiscardingLargeTableLookupEngine<
+        FourierLweBootstrapKey32,
+        FourierGgswCiphertext32,
+        PlaintextVector32,
+        LweCiphertext32,
+        LweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// use concrete_core::specification::engines::LargeTableLookupStructure;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(1), PolynomialSize(512));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // A table of 1024 entries, twice as many as the coefficients of an accumulator. Here a
+    /// // hard-set encoding is applied (shift by 27 bits)
+    /// let table: Vec<u32> = (0..1024).map(|i| (i % 16) << 27).collect();
+    /// let structure = LargeTableLookupStructure::new(PlaintextCount(table.len()), poly_size);
+    /// let (low, high) = structure.split_index(715);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output = engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    /// // The low bits of the index are encoded with one bit of padding.
+    /// let plaintext =
+    ///     engine.create_plaintext(&((low as u32) << (32 - structure.low_bits - 1)))?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut selectors = Vec::new();
+    /// for bit in high {
+    ///     let plaintext = engine.create_plaintext(&(bit as u32))?;
+    ///     let selector =
+    ///         engine.encrypt_scalar_ggsw_ciphertext(&glwe_sk, &plaintext, noise, dec_lc, dec_bl)?;
+    ///     let fourier_selector: FourierGgswCiphertext32 = engine.convert_ggsw_ciphertext(&selector)?;
+    ///     selectors.push(fourier_selector);
+    ///     engine.destroy(plaintext)?;
+    ///     engine.destroy(selector)?;
+    /// }
+    /// let table_vector = engine.create_plaintext_vector(&table)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_lookup_large_table_lwe_ciphertext(
+    ///     &mut output,
+    ///     &input,
+    ///     &selectors,
+    ///     &table_vector,
+    ///     &bsk,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), LweDimension(glwe_dim.0 * poly_size.0));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(input)?;
+    /// for selector in selectors {
+    ///     engine.destroy(selector)?;
+    /// }
+    /// engine.destroy(table_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_lookup_large_table_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        selectors: &[FourierGgswCiphertext32],
+        table: &PlaintextVector32,
+        bsk: &FourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingLargeTableLookupError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&bsk.polynomial_size().0) {
+            return Err(LweCiphertextDiscardingLargeTableLookupError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
+        LweCiphertextDiscardingLargeTableLookupError::perform_generic_checks(
+            output, input, selectors, table, bsk,
+        )?;
+        unsafe {
+            self.discard_lookup_large_table_lwe_ciphertext_unchecked(
+                output, input, selectors, table, bsk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_lookup_large_table_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        selectors: &[FourierGgswCiphertext32],
+        table: &PlaintextVector32,
+        bsk: &FourierLweBootstrapKey32,
+    ) {
+        let buffers =
+            self.get_fourier_u32_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
+        let selectors: Vec<_> = selectors.iter().map(|selector| &selector.0).collect();
+        large_table_lookup_lwe(
+            &mut output.0,
+            &input.0,
+            selectors.as_slice(),
+            table.0.as_tensor().as_slice(),
+            &bsk.0,
+            buffers,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingLargeTableLookupEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextDiscardingLargeTableLookupEngine<
+        FourierLweBootstrapKey64,
+        FourierGgswCiphertext64,
+        PlaintextVector64,
+        LweCiphertext64,
+        LweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// use concrete_core::specification::engines::LargeTableLookupStructure;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(1), PolynomialSize(512));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // A table of 1024 entries, twice as many as the coefficients of an accumulator. Here a
+    /// // hard-set encoding is applied (shift by 59 bits)
+    /// let table: Vec<u64> = (0..1024).map(|i| (i % 16) << 59).collect();
+    /// let structure = LargeTableLookupStructure::new(PlaintextCount(table.len()), poly_size);
+    /// let (low, high) = structure.split_index(715);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output = engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    /// // The low bits of the index are encoded with one bit of padding.
+    /// let plaintext =
+    ///     engine.create_plaintext(&((low as u64) << (64 - structure.low_bits - 1)))?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut selectors = Vec::new();
+    /// for bit in high {
+    ///     let plaintext = engine.create_plaintext(&(bit as u64))?;
+    ///     let selector =
+    ///         engine.encrypt_scalar_ggsw_ciphertext(&glwe_sk, &plaintext, noise, dec_lc, dec_bl)?;
+    ///     let fourier_selector: FourierGgswCiphertext64 = engine.convert_ggsw_ciphertext(&selector)?;
+    ///     selectors.push(fourier_selector);
+    ///     engine.destroy(plaintext)?;
+    ///     engine.destroy(selector)?;
+    /// }
+    /// let table_vector = engine.create_plaintext_vector(&table)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_lookup_large_table_lwe_ciphertext(
+    ///     &mut output,
+    ///     &input,
+    ///     &selectors,
+    ///     &table_vector,
+    ///     &bsk,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), LweDimension(glwe_dim.0 * poly_size.0));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(input)?;
+    /// for selector in selectors {
+    ///     engine.destroy(selector)?;
+    /// }
+    /// engine.destroy(table_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_lookup_large_table_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        selectors: &[FourierGgswCiphertext64],
+        table: &PlaintextVector64,
+        bsk: &FourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingLargeTableLookupError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&bsk.polynomial_size().0) {
+            return Err(LweCiphertextDiscardingLargeTableLookupError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
+        LweCiphertextDiscardingLargeTableLookupError::perform_generic_checks(
+            output, input, selectors, table, bsk,
+        )?;
+        unsafe {
+            self.discard_lookup_large_table_lwe_ciphertext_unchecked(
+                output, input, selectors, table, bsk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_lookup_large_table_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        selectors: &[FourierGgswCiphertext64],
+        table: &PlaintextVector64,
+        bsk: &FourierLweBootstrapKey64,
+    ) {
+        let buffers =
+            self.get_fourier_u64_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
+        let selectors: Vec<_> = selectors.iter().map(|selector| &selector.0).collect();
+        large_table_lookup_lwe(
+            &mut output.0,
+            &input.0,
+            selectors.as_slice(),
+            table.0.as_tensor().as_slice(),
+            &bsk.0,
+            buffers,
+        );
+    }
+}
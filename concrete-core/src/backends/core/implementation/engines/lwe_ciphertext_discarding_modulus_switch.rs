@@ -0,0 +1,204 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweCiphertext32, LweCiphertext64};
+use crate::specification::engines::{
+    LweCiphertextDiscardingModulusSwitchEngine, LweCiphertextDiscardingModulusSwitchError,
+};
+use concrete_commons::parameters::CiphertextModulusLog;
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingModulusSwitchEngine`] for [`CoreEngine`] that
+/// switches 32 bits ciphertexts to a modulus of at most $2^{32}$, stored in 32 bits integers.
+impl LweCiphertextDiscardingModulusSwitchEngine<LweCiphertext32, LweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_size = LweDimension(4).to_lwe_size();
+    /// // The message 3 is encoded with a shift by 20 bits, and is switched to the modulus 2^12.
+    /// let input = (3_u32 << 20) + (1 << 7);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.trivially_encrypt_lwe_ciphertext(lwe_size, &plaintext)?;
+    /// let zero = engine.create_plaintext(&0_u32)?;
+    /// let mut switched = engine.trivially_encrypt_lwe_ciphertext(lwe_size, &zero)?;
+    ///
+    /// engine.discard_modulus_switch_lwe_ciphertext(
+    ///     &mut switched,
+    ///     &ciphertext,
+    ///     CiphertextModulusLog(12),
+    /// )?;
+    /// #
+    /// let output = engine.trivially_decrypt_lwe_ciphertext(&switched)?;
+    /// assert_eq!(engine.retrieve_plaintext(&output)?, 3);
+    ///
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(switched)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_modulus_switch_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        modulus_log: CiphertextModulusLog,
+    ) -> Result<(), LweCiphertextDiscardingModulusSwitchError<Self::EngineError>> {
+        LweCiphertextDiscardingModulusSwitchError::perform_generic_checks(
+            output,
+            input,
+            modulus_log,
+            32,
+            32,
+        )?;
+        unsafe { self.discard_modulus_switch_lwe_ciphertext_unchecked(output, input, modulus_log) };
+        Ok(())
+    }
+
+    unsafe fn discard_modulus_switch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        modulus_log: CiphertextModulusLog,
+    ) {
+        output.0.fill_with_modulus_switch(&input.0, modulus_log);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingModulusSwitchEngine`] for [`CoreEngine`] that
+/// switches 64 bits ciphertexts to a modulus of at most $2^{32}$, stored in 32 bits integers.
+impl LweCiphertextDiscardingModulusSwitchEngine<LweCiphertext64, LweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_size = LweDimension(4).to_lwe_size();
+    /// // The message 3 is encoded with a shift by 50 bits, and is rounded to 32 bits.
+    /// let input = (3_u64 << 50) + (1 << 31);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.trivially_encrypt_lwe_ciphertext(lwe_size, &plaintext)?;
+    /// let zero = engine.create_plaintext(&0_u32)?;
+    /// let mut switched = engine.trivially_encrypt_lwe_ciphertext(lwe_size, &zero)?;
+    ///
+    /// engine.discard_modulus_switch_lwe_ciphertext(
+    ///     &mut switched,
+    ///     &ciphertext,
+    ///     CiphertextModulusLog(32),
+    /// )?;
+    /// #
+    /// let output = engine.trivially_decrypt_lwe_ciphertext(&switched)?;
+    /// assert_eq!(engine.retrieve_plaintext(&output)?, (3_u32 << 18) + 1);
+    ///
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(switched)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_modulus_switch_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext64,
+        modulus_log: CiphertextModulusLog,
+    ) -> Result<(), LweCiphertextDiscardingModulusSwitchError<Self::EngineError>> {
+        LweCiphertextDiscardingModulusSwitchError::perform_generic_checks(
+            output,
+            input,
+            modulus_log,
+            64,
+            32,
+        )?;
+        unsafe { self.discard_modulus_switch_lwe_ciphertext_unchecked(output, input, modulus_log) };
+        Ok(())
+    }
+
+    unsafe fn discard_modulus_switch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext64,
+        modulus_log: CiphertextModulusLog,
+    ) {
+        output.0.fill_with_modulus_switch(&input.0, modulus_log);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingModulusSwitchEngine`] for [`CoreEngine`] that
+/// switches 64 bits ciphertexts to a modulus of at most $2^{64}$, stored in 64 bits integers.
+impl LweCiphertextDiscardingModulusSwitchEngine<LweCiphertext64, LweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_size = LweDimension(4).to_lwe_size();
+    /// // The message 3 is encoded with a shift by 60 bits, and is switched to the modulus 2^12.
+    /// let input = (3_u64 << 60) + (1 << 39);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.trivially_encrypt_lwe_ciphertext(lwe_size, &plaintext)?;
+    /// let zero = engine.create_plaintext(&0_u64)?;
+    /// let mut switched = engine.trivially_encrypt_lwe_ciphertext(lwe_size, &zero)?;
+    ///
+    /// engine.discard_modulus_switch_lwe_ciphertext(
+    ///     &mut switched,
+    ///     &ciphertext,
+    ///     CiphertextModulusLog(12),
+    /// )?;
+    /// #
+    /// let output = engine.trivially_decrypt_lwe_ciphertext(&switched)?;
+    /// assert_eq!(engine.retrieve_plaintext(&output)?, 3 << 8);
+    ///
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(switched)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_modulus_switch_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        modulus_log: CiphertextModulusLog,
+    ) -> Result<(), LweCiphertextDiscardingModulusSwitchError<Self::EngineError>> {
+        LweCiphertextDiscardingModulusSwitchError::perform_generic_checks(
+            output,
+            input,
+            modulus_log,
+            64,
+            64,
+        )?;
+        unsafe { self.discard_modulus_switch_lwe_ciphertext_unchecked(output, input, modulus_log) };
+        Ok(())
+    }
+
+    unsafe fn discard_modulus_switch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        modulus_log: CiphertextModulusLog,
+    ) {
+        output.0.fill_with_modulus_switch(&input.0, modulus_log);
+    }
+}
@@ -0,0 +1,328 @@
+#[allow(deprecated)]
+use concrete_commons::parameters::{GlweSize, MonomialDegree, ScalingFactor};
+
+use crate::backends::core::implementation::engines::{CoreEngine, CoreError};
+use crate::backends::core::implementation::entities::{
+    GlweRelinearizationKey32, GlweRelinearizationKey64, LweCiphertext32, LweCiphertext64,
+    PackingKeyswitchKey32, PackingKeyswitchKey64,
+};
+use crate::backends::core::private::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::specification::engines::{
+    LweCiphertextDiscardingMultiplicationEngine, LweCiphertextDiscardingMultiplicationError,
+};
+
+impl From<CoreError> for LweCiphertextDiscardingMultiplicationError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingMultiplicationEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+///
+/// # Errors:
+/// Returns a [`CoreError::UnsupportedScalingFactor`] error if the scaling factor is not a power
+/// of two.
+impl
+    LweCiphertextDiscardingMultiplicationEngine<
+        PackingKeyswitchKey32,
+        GlweRelinearizationKey32,
+        LweCiphertext32,
+        LweCiphertext32,
+        LweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize, ScalingFactor,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(4);
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let noise = Variance(2_f64.powf(-60.));
+    /// // Here a hard-set encoding is applied (shift by 28 bits)
+    /// let scale = ScalingFactor::from_log2(28);
+    /// let (input_1, input_2) = (2_u32 << 28, 3_u32 << 28);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let glwe_key: GlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let packing_key: PackingKeyswitchKey32 = engine.create_packing_keyswitch_key(
+    ///     &lwe_key,
+    ///     &glwe_key,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(8),
+    ///     noise,
+    /// )?;
+    /// let relinearization_key: GlweRelinearizationKey32 = engine.create_glwe_relinearization_key(
+    ///     &glwe_key,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    ///     noise,
+    /// )?;
+    /// // The output is encrypted under the LWE key extracted from the GLWE key.
+    /// let output_key: LweSecretKey32 = engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_key)?;
+    /// let plaintext_1 = engine.create_plaintext(&input_1)?;
+    /// let plaintext_2 = engine.create_plaintext(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&lwe_key, &plaintext_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_lwe_ciphertext(&lwe_key, &plaintext_2, noise)?;
+    /// let mut product = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_mul_lwe_ciphertext(
+    ///     &mut product,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &packing_key,
+    ///     &relinearization_key,
+    ///     scale,
+    /// )?;
+    /// #
+    /// assert_eq!(product.lwe_dimension(), LweDimension(polynomial_size.0));
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&output_key, &product)?;
+    /// let decoded = engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!((decoded.wrapping_add(scale.0 as u32 / 2) >> 28) % 16, 6);
+    ///
+    /// engine.destroy(lwe_key)?;
+    /// engine.destroy(packing_key)?;
+    /// engine.destroy(relinearization_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(plaintext_1)?;
+    /// engine.destroy(plaintext_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(product)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input1: &LweCiphertext32,
+        input2: &LweCiphertext32,
+        packing_key: &PackingKeyswitchKey32,
+        relinearization_key: &GlweRelinearizationKey32,
+        scale: ScalingFactor,
+    ) -> Result<(), LweCiphertextDiscardingMultiplicationError<Self::EngineError>> {
+        if !scale.0.is_power_of_two() {
+            return Err(LweCiphertextDiscardingMultiplicationError::from(
+                CoreError::UnsupportedScalingFactor,
+            ));
+        }
+        LweCiphertextDiscardingMultiplicationError::perform_generic_checks(
+            output,
+            input1,
+            input2,
+            packing_key,
+            relinearization_key,
+        )?;
+        unsafe {
+            self.discard_mul_lwe_ciphertext_unchecked(
+                output,
+                input1,
+                input2,
+                packing_key,
+                relinearization_key,
+                scale,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input1: &LweCiphertext32,
+        input2: &LweCiphertext32,
+        packing_key: &PackingKeyswitchKey32,
+        relinearization_key: &GlweRelinearizationKey32,
+        scale: ScalingFactor,
+    ) {
+        let polynomial_size = relinearization_key.0.polynomial_size();
+        let glwe_dimension = relinearization_key.0.glwe_dimension();
+        let glwe_size = glwe_dimension.to_glwe_size();
+        let k = glwe_dimension.0;
+
+        let mut packed_1 = ImplGlweCiphertext::allocate(0u32, polynomial_size, glwe_size);
+        let mut packed_2 = ImplGlweCiphertext::allocate(0u32, polynomial_size, glwe_size);
+        packing_key.0.keyswitch_ciphertext(&mut packed_1, &input1.0);
+        packing_key.0.keyswitch_ciphertext(&mut packed_2, &input2.0);
+
+        let mut tensor_product =
+            ImplGlweCiphertext::allocate(0u32, polynomial_size, GlweSize(k * (k + 3) / 2 + 1));
+        tensor_product.fill_with_tensor_product(&packed_1, &packed_2, scale);
+
+        let mut relinearized = ImplGlweCiphertext::allocate(0u32, polynomial_size, glwe_size);
+        relinearization_key
+            .0
+            .relinearize(&mut relinearized, &tensor_product);
+        #[allow(deprecated)]
+        relinearized.fill_lwe_with_sample_extraction(&mut output.0, MonomialDegree(0));
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingMultiplicationEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+///
+/// # Errors:
+/// Returns a [`CoreError::UnsupportedScalingFactor`] error if the scaling factor is not a power
+/// of two.
+impl
+    LweCiphertextDiscardingMultiplicationEngine<
+        PackingKeyswitchKey64,
+        GlweRelinearizationKey64,
+        LweCiphertext64,
+        LweCiphertext64,
+        LweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize, ScalingFactor,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(10);
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let noise = Variance(2_f64.powf(-100.));
+    /// // Here a hard-set encoding is applied (shift by 58 bits)
+    /// let scale = ScalingFactor::from_log2(58);
+    /// let (input_1, input_2) = (3_u64 << 58, 5_u64 << 58);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let glwe_key: GlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let packing_key: PackingKeyswitchKey64 = engine.create_packing_keyswitch_key(
+    ///     &lwe_key,
+    ///     &glwe_key,
+    ///     DecompositionLevelCount(4),
+    ///     DecompositionBaseLog(10),
+    ///     noise,
+    /// )?;
+    /// let relinearization_key: GlweRelinearizationKey64 = engine.create_glwe_relinearization_key(
+    ///     &glwe_key,
+    ///     DecompositionLevelCount(4),
+    ///     DecompositionBaseLog(12),
+    ///     noise,
+    /// )?;
+    /// // The output is encrypted under the LWE key extracted from the GLWE key.
+    /// let output_key: LweSecretKey64 = engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_key)?;
+    /// let plaintext_1 = engine.create_plaintext(&input_1)?;
+    /// let plaintext_2 = engine.create_plaintext(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&lwe_key, &plaintext_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_lwe_ciphertext(&lwe_key, &plaintext_2, noise)?;
+    /// let mut product = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_mul_lwe_ciphertext(
+    ///     &mut product,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &packing_key,
+    ///     &relinearization_key,
+    ///     scale,
+    /// )?;
+    /// #
+    /// assert_eq!(product.lwe_dimension(), LweDimension(polynomial_size.0));
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&output_key, &product)?;
+    /// let decoded = engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!((decoded.wrapping_add(scale.0 as u64 / 2) >> 58) % 64, 15);
+    ///
+    /// engine.destroy(lwe_key)?;
+    /// engine.destroy(packing_key)?;
+    /// engine.destroy(relinearization_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(plaintext_1)?;
+    /// engine.destroy(plaintext_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(product)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input1: &LweCiphertext64,
+        input2: &LweCiphertext64,
+        packing_key: &PackingKeyswitchKey64,
+        relinearization_key: &GlweRelinearizationKey64,
+        scale: ScalingFactor,
+    ) -> Result<(), LweCiphertextDiscardingMultiplicationError<Self::EngineError>> {
+        if !scale.0.is_power_of_two() {
+            return Err(LweCiphertextDiscardingMultiplicationError::from(
+                CoreError::UnsupportedScalingFactor,
+            ));
+        }
+        LweCiphertextDiscardingMultiplicationError::perform_generic_checks(
+            output,
+            input1,
+            input2,
+            packing_key,
+            relinearization_key,
+        )?;
+        unsafe {
+            self.discard_mul_lwe_ciphertext_unchecked(
+                output,
+                input1,
+                input2,
+                packing_key,
+                relinearization_key,
+                scale,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input1: &LweCiphertext64,
+        input2: &LweCiphertext64,
+        packing_key: &PackingKeyswitchKey64,
+        relinearization_key: &GlweRelinearizationKey64,
+        scale: ScalingFactor,
+    ) {
+        let polynomial_size = relinearization_key.0.polynomial_size();
+        let glwe_dimension = relinearization_key.0.glwe_dimension();
+        let glwe_size = glwe_dimension.to_glwe_size();
+        let k = glwe_dimension.0;
+
+        let mut packed_1 = ImplGlweCiphertext::allocate(0u64, polynomial_size, glwe_size);
+        let mut packed_2 = ImplGlweCiphertext::allocate(0u64, polynomial_size, glwe_size);
+        packing_key.0.keyswitch_ciphertext(&mut packed_1, &input1.0);
+        packing_key.0.keyswitch_ciphertext(&mut packed_2, &input2.0);
+
+        let mut tensor_product =
+            ImplGlweCiphertext::allocate(0u64, polynomial_size, GlweSize(k * (k + 3) / 2 + 1));
+        tensor_product.fill_with_tensor_product(&packed_1, &packed_2, scale);
+
+        let mut relinearized = ImplGlweCiphertext::allocate(0u64, polynomial_size, glwe_size);
+        relinearization_key
+            .0
+            .relinearize(&mut relinearized, &tensor_product);
+        #[allow(deprecated)]
+        relinearized.fill_lwe_with_sample_extraction(&mut output.0, MonomialDegree(0));
+    }
+}
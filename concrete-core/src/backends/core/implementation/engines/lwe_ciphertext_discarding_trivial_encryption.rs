@@ -0,0 +1,106 @@
+use crate::backends::core::entities::{LweCiphertext32, LweCiphertext64, Plaintext32, Plaintext64};
+use crate::specification::engines::{
+    LweCiphertextDiscardingTrivialEncryptionEngine, LweCiphertextDiscardingTrivialEncryptionError,
+};
+
+use crate::backends::core::engines::CoreEngine;
+
+impl LweCiphertextDiscardingTrivialEncryptionEngine<Plaintext32, LweCiphertext32> for CoreEngine {
+    /// # Example:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(10);
+    /// let input = 3_u32 << 20;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext: Plaintext32 = engine.create_plaintext(&input)?;
+    /// let zero: Plaintext32 = engine.create_plaintext(&0_u32)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let mut ciphertext: LweCiphertext32 =
+    ///     engine.trivially_encrypt_lwe_ciphertext(lwe_size, &zero)?;
+    /// engine.discard_trivially_encrypt_lwe_ciphertext(&mut ciphertext, &plaintext)?;
+    ///
+    /// let output: Plaintext32 = engine.trivially_decrypt_lwe_ciphertext(&ciphertext)?;
+    /// assert_eq!(engine.retrieve_plaintext(&output)?, input);
+    ///
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_trivially_encrypt_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &Plaintext32,
+    ) -> Result<(), LweCiphertextDiscardingTrivialEncryptionError<Self::EngineError>> {
+        unsafe { self.discard_trivially_encrypt_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_trivially_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &Plaintext32,
+    ) {
+        output.0.fill_with_trivial_encryption(&input.0);
+    }
+}
+
+impl LweCiphertextDiscardingTrivialEncryptionEngine<Plaintext64, LweCiphertext64> for CoreEngine {
+    /// # Example:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(10);
+    /// let input = 3_u64 << 50;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext: Plaintext64 = engine.create_plaintext(&input)?;
+    /// let zero: Plaintext64 = engine.create_plaintext(&0_u64)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let mut ciphertext: LweCiphertext64 =
+    ///     engine.trivially_encrypt_lwe_ciphertext(lwe_size, &zero)?;
+    /// engine.discard_trivially_encrypt_lwe_ciphertext(&mut ciphertext, &plaintext)?;
+    ///
+    /// let output: Plaintext64 = engine.trivially_decrypt_lwe_ciphertext(&ciphertext)?;
+    /// assert_eq!(engine.retrieve_plaintext(&output)?, input);
+    ///
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_trivially_encrypt_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &Plaintext64,
+    ) -> Result<(), LweCiphertextDiscardingTrivialEncryptionError<Self::EngineError>> {
+        unsafe { self.discard_trivially_encrypt_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_trivially_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &Plaintext64,
+    ) {
+        output.0.fill_with_trivial_encryption(&input.0);
+    }
+}
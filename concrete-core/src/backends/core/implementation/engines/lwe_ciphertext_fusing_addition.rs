@@ -1,9 +1,69 @@
 use crate::backends::core::implementation::engines::CoreEngine;
-use crate::backends::core::implementation::entities::{LweCiphertext32, LweCiphertext64};
+use crate::backends::core::implementation::entities::{
+    LweCiphertext16, LweCiphertext32, LweCiphertext64, TaggedCiphertext,
+};
 use crate::specification::engines::{
     LweCiphertextFusingAdditionEngine, LweCiphertextFusingAdditionError,
 };
 
+/// # Description:
+/// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`CoreEngine`] that operates on
+/// 16 bits integers.
+impl LweCiphertextFusingAdditionEngine<LweCiphertext16, LweCiphertext16> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 10 bits)
+    /// let input_1 = 3_u16 << 10;
+    /// let input_2 = 5_u16 << 10;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey16 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = engine.create_plaintext(&input_1)?;
+    /// let plaintext_2 = engine.create_plaintext(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let mut ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_1)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(plaintext_2)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input: &LweCiphertext16,
+    ) -> Result<(), LweCiphertextFusingAdditionError<Self::EngineError>> {
+        LweCiphertextFusingAdditionError::perform_generic_checks(output, input)?;
+        unsafe { self.fuse_add_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input: &LweCiphertext16,
+    ) {
+        output.0.update_with_add(&input.0);
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`CoreEngine`] that operates on
 /// 32 bits integers.
@@ -119,3 +179,159 @@ impl LweCiphertextFusingAdditionEngine<LweCiphertext64, LweCiphertext64> for Cor
         output.0.update_with_add(&input.0);
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers wrapped in a [`TaggedCiphertext`].
+impl
+    LweCiphertextFusingAdditionEngine<
+        TaggedCiphertext<LweCiphertext32>,
+        TaggedCiphertext<LweCiphertext32>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (3 bits of message, shift by 29 bits)
+    /// let delta = 1_u32 << 29;
+    /// let metadata = EncodingMetadata {
+    ///     delta: ScalingFactor(delta as u64),
+    ///     message_modulus: 8,
+    ///     carry_modulus: 1,
+    ///     degree: 3,
+    /// };
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&(3 * delta))?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut ciphertext_1 = TaggedCiphertext::new(ciphertext_1, metadata);
+    /// let ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let ciphertext_2 = TaggedCiphertext::new(ciphertext_2, metadata);
+    ///
+    /// engine.fuse_add_lwe_ciphertext(&mut ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_1.metadata().degree, 6);
+    /// // Adding a third message of degree 3 would overflow the message space:
+    /// assert!(engine
+    ///     .fuse_add_lwe_ciphertext(&mut ciphertext_1, &ciphertext_2)
+    ///     .is_err());
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext(
+        &mut self,
+        output: &mut TaggedCiphertext<LweCiphertext32>,
+        input: &TaggedCiphertext<LweCiphertext32>,
+    ) -> Result<(), LweCiphertextFusingAdditionError<Self::EngineError>> {
+        LweCiphertextFusingAdditionError::perform_generic_checks(output, input)?;
+        self.check_carry_overflow(
+            output.metadata.checked_add(&input.metadata),
+            output.metadata.saturating_add(&input.metadata),
+        )
+        .map_err(LweCiphertextFusingAdditionError::Engine)?;
+        unsafe { self.fuse_add_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut TaggedCiphertext<LweCiphertext32>,
+        input: &TaggedCiphertext<LweCiphertext32>,
+    ) {
+        self.fuse_add_lwe_ciphertext_unchecked(&mut output.ciphertext, &input.ciphertext);
+        output.metadata = output.metadata.saturating_add(&input.metadata);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers wrapped in a [`TaggedCiphertext`].
+impl
+    LweCiphertextFusingAdditionEngine<
+        TaggedCiphertext<LweCiphertext64>,
+        TaggedCiphertext<LweCiphertext64>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (3 bits of message, shift by 61 bits)
+    /// let delta = 1_u64 << 61;
+    /// let metadata = EncodingMetadata {
+    ///     delta: ScalingFactor(delta as u64),
+    ///     message_modulus: 8,
+    ///     carry_modulus: 1,
+    ///     degree: 3,
+    /// };
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&(3 * delta))?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut ciphertext_1 = TaggedCiphertext::new(ciphertext_1, metadata);
+    /// let ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let ciphertext_2 = TaggedCiphertext::new(ciphertext_2, metadata);
+    ///
+    /// engine.fuse_add_lwe_ciphertext(&mut ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_1.metadata().degree, 6);
+    /// // Adding a third message of degree 3 would overflow the message space:
+    /// assert!(engine
+    ///     .fuse_add_lwe_ciphertext(&mut ciphertext_1, &ciphertext_2)
+    ///     .is_err());
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext(
+        &mut self,
+        output: &mut TaggedCiphertext<LweCiphertext64>,
+        input: &TaggedCiphertext<LweCiphertext64>,
+    ) -> Result<(), LweCiphertextFusingAdditionError<Self::EngineError>> {
+        LweCiphertextFusingAdditionError::perform_generic_checks(output, input)?;
+        self.check_carry_overflow(
+            output.metadata.checked_add(&input.metadata),
+            output.metadata.saturating_add(&input.metadata),
+        )
+        .map_err(LweCiphertextFusingAdditionError::Engine)?;
+        unsafe { self.fuse_add_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut TaggedCiphertext<LweCiphertext64>,
+        input: &TaggedCiphertext<LweCiphertext64>,
+    ) {
+        self.fuse_add_lwe_ciphertext_unchecked(&mut output.ciphertext, &input.ciphertext);
+        output.metadata = output.metadata.saturating_add(&input.metadata);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweCiphertext32, LweCiphertext64};
+use crate::specification::engines::{
+    LweCiphertextFusingNoiseInjectionEngine, LweCiphertextFusingNoiseInjectionError,
+};
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingNoiseInjectionEngine`] for [`CoreEngine`] that operates
+/// on 32 bits integers. The noise is sampled from the distribution set with
+/// [`CoreEngine::set_noise_distribution`], gaussian by default.
+impl LweCiphertextFusingNoiseInjectionEngine<LweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // The worst-case variance predicted for the ciphertext, e.g. by `concrete-npe`.
+    /// let worst_case = Variance(2_f64.powf(-40.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.fuse_inject_noise_lwe_ciphertext(&mut ciphertext, noise, worst_case)?;
+    /// #
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    /// let output = engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!((output.wrapping_add(1 << (20 - 1))) >> 20, 3);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_inject_noise_lwe_ciphertext(
+        &mut self,
+        ciphertext: &mut LweCiphertext32,
+        current_variance: Variance,
+        target_variance: Variance,
+    ) -> Result<(), LweCiphertextFusingNoiseInjectionError<Self::EngineError>> {
+        LweCiphertextFusingNoiseInjectionError::perform_generic_checks(
+            current_variance,
+            target_variance,
+        )?;
+        unsafe {
+            self.fuse_inject_noise_lwe_ciphertext_unchecked(
+                ciphertext,
+                current_variance,
+                target_variance,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn fuse_inject_noise_lwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: &mut LweCiphertext32,
+        current_variance: Variance,
+        target_variance: Variance,
+    ) {
+        let variance = target_variance.get_variance() - current_variance.get_variance();
+        if variance == 0. {
+            return;
+        }
+        let noise: u32 = self.encryption_generator.random_noise(Variance(variance));
+        let body = ciphertext.0.get_mut_body();
+        body.0 = body.0.wrapping_add(noise);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingNoiseInjectionEngine`] for [`CoreEngine`] that operates
+/// on 64 bits integers. The noise is sampled from the distribution set with
+/// [`CoreEngine::set_noise_distribution`], gaussian by default.
+impl LweCiphertextFusingNoiseInjectionEngine<LweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // The worst-case variance predicted for the ciphertext, e.g. by `concrete-npe`.
+    /// let worst_case = Variance(2_f64.powf(-40.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.fuse_inject_noise_lwe_ciphertext(&mut ciphertext, noise, worst_case)?;
+    /// #
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    /// let output = engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!((output.wrapping_add(1 << (50 - 1))) >> 50, 3);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_inject_noise_lwe_ciphertext(
+        &mut self,
+        ciphertext: &mut LweCiphertext64,
+        current_variance: Variance,
+        target_variance: Variance,
+    ) -> Result<(), LweCiphertextFusingNoiseInjectionError<Self::EngineError>> {
+        LweCiphertextFusingNoiseInjectionError::perform_generic_checks(
+            current_variance,
+            target_variance,
+        )?;
+        unsafe {
+            self.fuse_inject_noise_lwe_ciphertext_unchecked(
+                ciphertext,
+                current_variance,
+                target_variance,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn fuse_inject_noise_lwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: &mut LweCiphertext64,
+        current_variance: Variance,
+        target_variance: Variance,
+    ) {
+        let variance = target_variance.get_variance() - current_variance.get_variance();
+        if variance == 0. {
+            return;
+        }
+        let noise: u64 = self.encryption_generator.random_noise(Variance(variance));
+        let body = ciphertext.0.get_mut_body();
+        body.0 = body.0.wrapping_add(noise);
+    }
+}
@@ -0,0 +1,180 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweKeyswitchKey32, LweKeyswitchKey64,
+};
+use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::specification::engines::{
+    LweCiphertextGadgetProductEngine, LweCiphertextGadgetProductError,
+};
+use crate::specification::entities::LweKeyswitchKeyEntity;
+use concrete_commons::parameters::DecompositionLevelCount;
+
+/// # Description:
+/// Implementation of [`LweCiphertextGadgetProductEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweCiphertextGadgetProductEngine<LweKeyswitchKey32, LweCiphertext32, LweCiphertext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(6);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    ///
+    /// // Only the two most significant levels of the keyswitch key are used.
+    /// let product: LweCiphertext32 = engine.compute_gadget_product_lwe_ciphertext(
+    ///     &ciphertext,
+    ///     &keyswitch_key,
+    ///     DecompositionLevelCount(2),
+    /// )?;
+    /// #
+    /// assert_eq!(product.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(product)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compute_gadget_product_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext32,
+        ksk: &LweKeyswitchKey32,
+        level_count: DecompositionLevelCount,
+    ) -> Result<LweCiphertext32, LweCiphertextGadgetProductError<Self::EngineError>> {
+        LweCiphertextGadgetProductError::perform_generic_checks(input, ksk, level_count)?;
+        Ok(
+            unsafe {
+                self.compute_gadget_product_lwe_ciphertext_unchecked(input, ksk, level_count)
+            },
+        )
+    }
+
+    unsafe fn compute_gadget_product_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext32,
+        ksk: &LweKeyswitchKey32,
+        level_count: DecompositionLevelCount,
+    ) -> LweCiphertext32 {
+        let mut ciphertext =
+            ImplLweCiphertext::allocate(0u32, ksk.output_lwe_dimension().to_lwe_size());
+        ksk.0
+            .gadget_product_ciphertext(&mut ciphertext, &input.0, level_count);
+        LweCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextGadgetProductEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextGadgetProductEngine<LweKeyswitchKey64, LweCiphertext64, LweCiphertext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    ///
+    /// // Only the two most significant levels of the keyswitch key are used.
+    /// let product: LweCiphertext64 = engine.compute_gadget_product_lwe_ciphertext(
+    ///     &ciphertext,
+    ///     &keyswitch_key,
+    ///     DecompositionLevelCount(2),
+    /// )?;
+    /// #
+    /// assert_eq!(product.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(product)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compute_gadget_product_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext64,
+        ksk: &LweKeyswitchKey64,
+        level_count: DecompositionLevelCount,
+    ) -> Result<LweCiphertext64, LweCiphertextGadgetProductError<Self::EngineError>> {
+        LweCiphertextGadgetProductError::perform_generic_checks(input, ksk, level_count)?;
+        Ok(
+            unsafe {
+                self.compute_gadget_product_lwe_ciphertext_unchecked(input, ksk, level_count)
+            },
+        )
+    }
+
+    unsafe fn compute_gadget_product_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext64,
+        ksk: &LweKeyswitchKey64,
+        level_count: DecompositionLevelCount,
+    ) -> LweCiphertext64 {
+        let mut ciphertext =
+            ImplLweCiphertext::allocate(0u64, ksk.output_lwe_dimension().to_lwe_size());
+        ksk.0
+            .gadget_product_ciphertext(&mut ciphertext, &input.0, level_count);
+        LweCiphertext64(ciphertext)
+    }
+}
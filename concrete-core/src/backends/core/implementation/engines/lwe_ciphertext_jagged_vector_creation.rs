@@ -0,0 +1,156 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextJaggedVector32, LweCiphertextJaggedVector64, LweCiphertextVector32,
+    LweCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextJaggedVectorCreationEngine, LweCiphertextJaggedVectorCreationError,
+};
+use concrete_commons::parameters::LweCiphertextCount;
+
+/// # Description:
+/// Implementation of [`LweCiphertextJaggedVectorCreationEngine`] for [`CoreEngine`] that operates
+/// on 32 bits integers.
+impl LweCiphertextJaggedVectorCreationEngine<LweCiphertextVector32, LweCiphertextJaggedVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_vector = vec![3_u32 << 20; 5];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let row_lengths = [LweCiphertextCount(2), LweCiphertextCount(0), LweCiphertextCount(3)];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input_vector)?;
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let jagged_vector: LweCiphertextJaggedVector32 =
+    ///     engine.create_lwe_ciphertext_jagged_vector(ciphertext_vector, &row_lengths)?;
+    /// #
+    /// assert_eq!(jagged_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(jagged_vector.lwe_ciphertext_count(), LweCiphertextCount(5));
+    /// assert_eq!(jagged_vector.row_count(), 3);
+    /// assert_eq!(jagged_vector.row_lwe_ciphertext_count(1), LweCiphertextCount(0));
+    /// assert_eq!(jagged_vector.row_lwe_ciphertext_count(2), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(jagged_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_jagged_vector(
+        &mut self,
+        input: LweCiphertextVector32,
+        row_lengths: &[LweCiphertextCount],
+    ) -> Result<
+        LweCiphertextJaggedVector32,
+        LweCiphertextJaggedVectorCreationError<Self::EngineError>,
+    > {
+        LweCiphertextJaggedVectorCreationError::perform_generic_checks(&input, row_lengths)?;
+        Ok(unsafe { self.create_lwe_ciphertext_jagged_vector_unchecked(input, row_lengths) })
+    }
+
+    unsafe fn create_lwe_ciphertext_jagged_vector_unchecked(
+        &mut self,
+        input: LweCiphertextVector32,
+        row_lengths: &[LweCiphertextCount],
+    ) -> LweCiphertextJaggedVector32 {
+        LweCiphertextJaggedVector32 {
+            list: input.0,
+            row_offsets: row_offsets(row_lengths),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextJaggedVectorCreationEngine`] for [`CoreEngine`] that operates
+/// on 64 bits integers.
+impl LweCiphertextJaggedVectorCreationEngine<LweCiphertextVector64, LweCiphertextJaggedVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_vector = vec![3_u64 << 50; 5];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let row_lengths = [LweCiphertextCount(2), LweCiphertextCount(0), LweCiphertextCount(3)];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input_vector)?;
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let jagged_vector: LweCiphertextJaggedVector64 =
+    ///     engine.create_lwe_ciphertext_jagged_vector(ciphertext_vector, &row_lengths)?;
+    /// #
+    /// assert_eq!(jagged_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(jagged_vector.lwe_ciphertext_count(), LweCiphertextCount(5));
+    /// assert_eq!(jagged_vector.row_count(), 3);
+    /// assert_eq!(jagged_vector.row_lwe_ciphertext_count(1), LweCiphertextCount(0));
+    /// assert_eq!(jagged_vector.row_lwe_ciphertext_count(2), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(jagged_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_jagged_vector(
+        &mut self,
+        input: LweCiphertextVector64,
+        row_lengths: &[LweCiphertextCount],
+    ) -> Result<
+        LweCiphertextJaggedVector64,
+        LweCiphertextJaggedVectorCreationError<Self::EngineError>,
+    > {
+        LweCiphertextJaggedVectorCreationError::perform_generic_checks(&input, row_lengths)?;
+        Ok(unsafe { self.create_lwe_ciphertext_jagged_vector_unchecked(input, row_lengths) })
+    }
+
+    unsafe fn create_lwe_ciphertext_jagged_vector_unchecked(
+        &mut self,
+        input: LweCiphertextVector64,
+        row_lengths: &[LweCiphertextCount],
+    ) -> LweCiphertextJaggedVector64 {
+        LweCiphertextJaggedVector64 {
+            list: input.0,
+            row_offsets: row_offsets(row_lengths),
+        }
+    }
+}
+
+// Returns the index of the first ciphertext of each row, followed by the total number of
+// ciphertexts.
+fn row_offsets(row_lengths: &[LweCiphertextCount]) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(row_lengths.len() + 1);
+    offsets.push(0);
+    let mut offset = 0;
+    for length in row_lengths {
+        offset += length.0;
+        offsets.push(offset);
+    }
+    offsets
+}
@@ -0,0 +1,184 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextJaggedVector32, LweCiphertextJaggedVector64, LweCiphertextVector32,
+    LweCiphertextVector64,
+};
+use crate::backends::core::private::math::tensor::AsMutTensor;
+use crate::specification::engines::{
+    LweCiphertextJaggedVectorDiscardingRowSummationEngine,
+    LweCiphertextJaggedVectorDiscardingRowSummationError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextJaggedVectorDiscardingRowSummationEngine`] for [`CoreEngine`]
+/// that operates on 32 bits integers.
+impl
+    LweCiphertextJaggedVectorDiscardingRowSummationEngine<
+        LweCiphertextJaggedVector32,
+        LweCiphertextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_vector = [1_u32, 2, 3, 4, 5]
+    ///     .iter()
+    ///     .map(|m| m << 20)
+    ///     .collect::<Vec<_>>();
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let row_lengths = [LweCiphertextCount(2), LweCiphertextCount(0), LweCiphertextCount(3)];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input_vector)?;
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let jagged_vector: LweCiphertextJaggedVector32 =
+    ///     engine.create_lwe_ciphertext_jagged_vector(ciphertext_vector, &row_lengths)?;
+    /// let mut sums: LweCiphertextVector32 =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_sum_rows_lwe_ciphertext_jagged_vector(&mut sums, &jagged_vector)?;
+    /// #
+    /// let decrypted: PlaintextVector32 = engine.decrypt_lwe_ciphertext_vector(&key, &sums)?;
+    /// let messages = engine
+    ///     .retrieve_plaintext_vector(&decrypted)?
+    ///     .iter()
+    ///     .map(|p| (p.wrapping_add(1 << 19)) >> 20)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(messages, vec![3, 0, 12]);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(jagged_vector)?;
+    /// engine.destroy(sums)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_sum_rows_lwe_ciphertext_jagged_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextJaggedVector32,
+    ) -> Result<(), LweCiphertextJaggedVectorDiscardingRowSummationError<Self::EngineError>> {
+        LweCiphertextJaggedVectorDiscardingRowSummationError::perform_generic_checks(
+            output, input,
+        )?;
+        unsafe { self.discard_sum_rows_lwe_ciphertext_jagged_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_sum_rows_lwe_ciphertext_jagged_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextJaggedVector32,
+    ) {
+        let mut ciphertexts = input.list.ciphertext_iter();
+        for (mut sum, bounds) in output
+            .0
+            .ciphertext_iter_mut()
+            .zip(input.row_offsets.windows(2))
+        {
+            sum.as_mut_tensor().fill_with_element(0u32);
+            for ciphertext in ciphertexts.by_ref().take(bounds[1] - bounds[0]) {
+                sum.update_with_add(&ciphertext);
+            }
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextJaggedVectorDiscardingRowSummationEngine`] for [`CoreEngine`]
+/// that operates on 64 bits integers.
+impl
+    LweCiphertextJaggedVectorDiscardingRowSummationEngine<
+        LweCiphertextJaggedVector64,
+        LweCiphertextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_vector = [1_u64, 2, 3, 4, 5]
+    ///     .iter()
+    ///     .map(|m| m << 50)
+    ///     .collect::<Vec<_>>();
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let row_lengths = [LweCiphertextCount(2), LweCiphertextCount(0), LweCiphertextCount(3)];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input_vector)?;
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let jagged_vector: LweCiphertextJaggedVector64 =
+    ///     engine.create_lwe_ciphertext_jagged_vector(ciphertext_vector, &row_lengths)?;
+    /// let mut sums: LweCiphertextVector64 =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_sum_rows_lwe_ciphertext_jagged_vector(&mut sums, &jagged_vector)?;
+    /// #
+    /// let decrypted: PlaintextVector64 = engine.decrypt_lwe_ciphertext_vector(&key, &sums)?;
+    /// let messages = engine
+    ///     .retrieve_plaintext_vector(&decrypted)?
+    ///     .iter()
+    ///     .map(|p| (p.wrapping_add(1 << 49)) >> 50)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(messages, vec![3, 0, 12]);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(jagged_vector)?;
+    /// engine.destroy(sums)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_sum_rows_lwe_ciphertext_jagged_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextJaggedVector64,
+    ) -> Result<(), LweCiphertextJaggedVectorDiscardingRowSummationError<Self::EngineError>> {
+        LweCiphertextJaggedVectorDiscardingRowSummationError::perform_generic_checks(
+            output, input,
+        )?;
+        unsafe { self.discard_sum_rows_lwe_ciphertext_jagged_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_sum_rows_lwe_ciphertext_jagged_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextJaggedVector64,
+    ) {
+        let mut ciphertexts = input.list.ciphertext_iter();
+        for (mut sum, bounds) in output
+            .0
+            .ciphertext_iter_mut()
+            .zip(input.row_offsets.windows(2))
+        {
+            sum.as_mut_tensor().fill_with_element(0u64);
+            for ciphertext in ciphertexts.by_ref().take(bounds[1] - bounds[0]) {
+                sum.update_with_add(&ciphertext);
+            }
+        }
+    }
+}
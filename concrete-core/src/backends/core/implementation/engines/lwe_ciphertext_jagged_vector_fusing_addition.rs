@@ -0,0 +1,159 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextJaggedVector32, LweCiphertextJaggedVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextJaggedVectorFusingAdditionEngine, LweCiphertextJaggedVectorFusingAdditionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextJaggedVectorFusingAdditionEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextJaggedVectorFusingAdditionEngine<
+        LweCiphertextJaggedVector32,
+        LweCiphertextJaggedVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_vector = vec![3_u32 << 20; 5];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let row_lengths = [LweCiphertextCount(2), LweCiphertextCount(0), LweCiphertextCount(3)];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input_vector)?;
+    /// let input_ciphertexts: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let output_ciphertexts: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let input: LweCiphertextJaggedVector32 =
+    ///     engine.create_lwe_ciphertext_jagged_vector(input_ciphertexts, &row_lengths)?;
+    /// let mut output: LweCiphertextJaggedVector32 =
+    ///     engine.create_lwe_ciphertext_jagged_vector(output_ciphertexts, &row_lengths)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext_jagged_vector(&mut output, &input)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(output.row_count(), 3);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext_jagged_vector(
+        &mut self,
+        output: &mut LweCiphertextJaggedVector32,
+        input: &LweCiphertextJaggedVector32,
+    ) -> Result<(), LweCiphertextJaggedVectorFusingAdditionError<Self::EngineError>> {
+        LweCiphertextJaggedVectorFusingAdditionError::perform_generic_checks(output, input)?;
+        unsafe { self.fuse_add_lwe_ciphertext_jagged_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_jagged_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextJaggedVector32,
+        input: &LweCiphertextJaggedVector32,
+    ) {
+        // Since the rows of both vectors have the same lengths, the rows are added by adding the
+        // flat lists of ciphertexts.
+        for (mut out, inp) in output
+            .list
+            .ciphertext_iter_mut()
+            .zip(input.list.ciphertext_iter())
+        {
+            out.update_with_add(&inp);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextJaggedVectorFusingAdditionEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextJaggedVectorFusingAdditionEngine<
+        LweCiphertextJaggedVector64,
+        LweCiphertextJaggedVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_vector = vec![3_u64 << 50; 5];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let row_lengths = [LweCiphertextCount(2), LweCiphertextCount(0), LweCiphertextCount(3)];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input_vector)?;
+    /// let input_ciphertexts: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let output_ciphertexts: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let input: LweCiphertextJaggedVector64 =
+    ///     engine.create_lwe_ciphertext_jagged_vector(input_ciphertexts, &row_lengths)?;
+    /// let mut output: LweCiphertextJaggedVector64 =
+    ///     engine.create_lwe_ciphertext_jagged_vector(output_ciphertexts, &row_lengths)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext_jagged_vector(&mut output, &input)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(output.row_count(), 3);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext_jagged_vector(
+        &mut self,
+        output: &mut LweCiphertextJaggedVector64,
+        input: &LweCiphertextJaggedVector64,
+    ) -> Result<(), LweCiphertextJaggedVectorFusingAdditionError<Self::EngineError>> {
+        LweCiphertextJaggedVectorFusingAdditionError::perform_generic_checks(output, input)?;
+        unsafe { self.fuse_add_lwe_ciphertext_jagged_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_jagged_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextJaggedVector64,
+        input: &LweCiphertextJaggedVector64,
+    ) {
+        // Since the rows of both vectors have the same lengths, the rows are added by adding the
+        // flat lists of ciphertexts.
+        for (mut out, inp) in output
+            .list
+            .ciphertext_iter_mut()
+            .zip(input.list.ciphertext_iter())
+        {
+            out.update_with_add(&inp);
+        }
+    }
+}
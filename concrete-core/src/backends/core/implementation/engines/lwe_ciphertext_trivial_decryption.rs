@@ -1,9 +1,55 @@
 use crate::backends::core::private::crypto::encoding::Plaintext as ImplPlaintext;
 use crate::prelude::{
-    CoreEngine, LweCiphertext32, LweCiphertext64, LweCiphertextTrivialDecryptionEngine,
-    LweCiphertextTrivialDecryptionError, Plaintext32, Plaintext64,
+    CoreEngine, LweCiphertext128, LweCiphertext16, LweCiphertext32, LweCiphertext64,
+    LweCiphertextTrivialDecryptionEngine, LweCiphertextTrivialDecryptionError, Plaintext128,
+    Plaintext16, Plaintext32, Plaintext64,
 };
 
+impl LweCiphertextTrivialDecryptionEngine<LweCiphertext16, Plaintext16> for CoreEngine {
+    /// # Example:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(10);
+    /// let input = 3_u16 << 10;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext: Plaintext16 = engine.create_plaintext(&input)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let ciphertext: LweCiphertext16 =
+    ///     engine.trivially_encrypt_lwe_ciphertext(lwe_size, &plaintext)?;
+    /// let output: Plaintext16 = engine.trivially_decrypt_lwe_ciphertext(&ciphertext)?;
+    /// let res = engine.retrieve_plaintext(&output)?;
+    /// assert_eq!(res, input);
+    ///
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_decrypt_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext16,
+    ) -> Result<Plaintext16, LweCiphertextTrivialDecryptionError<Self::EngineError>> {
+        unsafe { Ok(self.trivially_decrypt_lwe_ciphertext_unchecked(input)) }
+    }
+
+    unsafe fn trivially_decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext16,
+    ) -> Plaintext16 {
+        Plaintext16(ImplPlaintext(input.0.get_body().0))
+    }
+}
+
 impl LweCiphertextTrivialDecryptionEngine<LweCiphertext32, Plaintext32> for CoreEngine {
     /// # Example:
     ///
@@ -93,3 +139,48 @@ impl LweCiphertextTrivialDecryptionEngine<LweCiphertext64, Plaintext64> for Core
         Plaintext64(ImplPlaintext(input.0.get_body().0))
     }
 }
+
+impl LweCiphertextTrivialDecryptionEngine<LweCiphertext128, Plaintext128> for CoreEngine {
+    /// # Example:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(10);
+    /// let input = 3_u128 << 20;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext: Plaintext128 = engine.create_plaintext(&input)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let ciphertext: LweCiphertext128 =
+    ///     engine.trivially_encrypt_lwe_ciphertext(lwe_size, &plaintext)?;
+    ///
+    /// let output: Plaintext128 = engine.trivially_decrypt_lwe_ciphertext(&ciphertext)?;
+    /// let res = engine.retrieve_plaintext(&output)?;
+    /// assert_eq!(res, input);
+    ///
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_decrypt_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext128,
+    ) -> Result<Plaintext128, LweCiphertextTrivialDecryptionError<Self::EngineError>> {
+        unsafe { Ok(self.trivially_decrypt_lwe_ciphertext_unchecked(input)) }
+    }
+
+    unsafe fn trivially_decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext128,
+    ) -> Plaintext128 {
+        Plaintext128(ImplPlaintext(input.0.get_body().0))
+    }
+}
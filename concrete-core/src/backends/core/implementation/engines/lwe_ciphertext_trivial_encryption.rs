@@ -1,4 +1,7 @@
-use crate::prelude::{CoreEngine, LweCiphertext32, LweCiphertext64, Plaintext32, Plaintext64};
+use crate::prelude::{
+    CoreEngine, LweCiphertext128, LweCiphertext16, LweCiphertext32, LweCiphertext64, Plaintext128,
+    Plaintext16, Plaintext32, Plaintext64,
+};
 use crate::specification::engines::{
     LweCiphertextTrivialEncryptionEngine, LweCiphertextTrivialEncryptionError,
 };
@@ -6,6 +9,52 @@ use concrete_commons::parameters::LweSize;
 
 use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
 
+impl LweCiphertextTrivialEncryptionEngine<Plaintext16, LweCiphertext16> for CoreEngine {
+    /// # Example:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(10);
+    /// let input = 3_u16 << 10;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext: Plaintext16 = engine.create_plaintext(&input)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let ciphertext: LweCiphertext16 =
+    ///     engine.trivially_encrypt_lwe_ciphertext(lwe_size, &plaintext)?;
+    ///
+    /// assert_eq!(ciphertext.lwe_dimension().to_lwe_size(), lwe_size);
+    ///
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_lwe_ciphertext(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext16,
+    ) -> Result<LweCiphertext16, LweCiphertextTrivialEncryptionError<Self::EngineError>> {
+        unsafe { Ok(self.trivially_encrypt_lwe_ciphertext_unchecked(lwe_size, input)) }
+    }
+
+    unsafe fn trivially_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext16,
+    ) -> LweCiphertext16 {
+        let ciphertext = ImplLweCiphertext::new_trivial_encryption(lwe_size, &input.0);
+        LweCiphertext16(ciphertext)
+    }
+}
+
 impl LweCiphertextTrivialEncryptionEngine<Plaintext32, LweCiphertext32> for CoreEngine {
     /// # Example:
     ///
@@ -98,3 +147,50 @@ impl LweCiphertextTrivialEncryptionEngine<Plaintext64, LweCiphertext64> for Core
         LweCiphertext64(ciphertext)
     }
 }
+
+impl LweCiphertextTrivialEncryptionEngine<Plaintext128, LweCiphertext128> for CoreEngine {
+    /// # Example:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(10);
+    /// let input = 3_u128 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext: Plaintext128 = engine.create_plaintext(&input)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let ciphertext: LweCiphertext128 =
+    ///     engine.trivially_encrypt_lwe_ciphertext(lwe_size, &plaintext)?;
+    ///
+    /// assert_eq!(ciphertext.lwe_dimension().to_lwe_size(), lwe_size);
+    ///
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_lwe_ciphertext(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext128,
+    ) -> Result<LweCiphertext128, LweCiphertextTrivialEncryptionError<Self::EngineError>> {
+        unsafe { Ok(self.trivially_encrypt_lwe_ciphertext_unchecked(lwe_size, input)) }
+    }
+
+    unsafe fn trivially_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext128,
+    ) -> LweCiphertext128 {
+        let ciphertext = ImplLweCiphertext::new_trivial_encryption(lwe_size, &input.0);
+        LweCiphertext128(ciphertext)
+    }
+}
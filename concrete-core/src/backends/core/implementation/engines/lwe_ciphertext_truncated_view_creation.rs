@@ -0,0 +1,69 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertext64, LweCiphertextTruncatedView32,
+};
+use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::specification::engines::{
+    LweCiphertextTruncatedViewCreationEngine, LweCiphertextTruncatedViewCreationError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextTruncatedViewCreationEngine`] for [`CoreEngine`] which views a
+/// 64 bits ciphertext as a 32 bits one, without copying it.
+impl<'a>
+    LweCiphertextTruncatedViewCreationEngine<&'a LweCiphertext64, LweCiphertextTruncatedView32<'a>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_size = LweDimension(4).to_lwe_size();
+    /// // The message 3 is encoded with a shift by 50 bits, and is rounded to 32 bits.
+    /// let input = (3_u64 << 50) + (1 << 31);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext: LweCiphertext64 =
+    ///     engine.trivially_encrypt_lwe_ciphertext(lwe_size, &plaintext)?;
+    ///
+    /// let view: LweCiphertextTruncatedView32 =
+    ///     engine.create_lwe_ciphertext_truncated_view(&ciphertext)?;
+    /// #
+    /// assert_eq!(view.lwe_dimension(), ciphertext.lwe_dimension());
+    /// let truncated: LweCiphertext32 = engine.convert_lwe_ciphertext(&view)?;
+    /// let output = engine.trivially_decrypt_lwe_ciphertext(&truncated)?;
+    /// assert_eq!(engine.retrieve_plaintext(&output)?, (3_u32 << 18) + 1);
+    ///
+    /// engine.destroy(view)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(truncated)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_truncated_view(
+        &mut self,
+        input: &'a LweCiphertext64,
+    ) -> Result<
+        LweCiphertextTruncatedView32<'a>,
+        LweCiphertextTruncatedViewCreationError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.create_lwe_ciphertext_truncated_view_unchecked(input) })
+    }
+
+    unsafe fn create_lwe_ciphertext_truncated_view_unchecked(
+        &mut self,
+        input: &'a LweCiphertext64,
+    ) -> LweCiphertextTruncatedView32<'a> {
+        LweCiphertextTruncatedView32(ImplLweCiphertext::from_container(
+            input.0.as_tensor().as_slice(),
+        ))
+    }
+}
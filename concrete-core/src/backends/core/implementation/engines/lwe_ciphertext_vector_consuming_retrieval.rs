@@ -0,0 +1,103 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    AlignedLweCiphertextVector32, AlignedLweCiphertextVector64,
+};
+use crate::backends::core::memory::AlignedBuffer;
+use crate::backends::core::private::math::tensor::IntoTensor;
+use crate::specification::engines::{
+    LweCiphertextVectorConsumingRetrievalEngine, LweCiphertextVectorConsumingRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorConsumingRetrievalEngine`] for [`CoreEngine`] which
+/// returns the [`AlignedBuffer`] of 32 bits integers of the vector, without copying it.
+impl LweCiphertextVectorConsumingRetrievalEngine<AlignedLweCiphertextVector32, AlignedBuffer<u32>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::backends::core::memory::{AlignedBuffer, Alignment};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_size = LweSize(16);
+    /// let mut buffer = AlignedBuffer::<u32>::new(4 * lwe_size.0, Alignment::CACHE_LINE);
+    /// buffer.as_mut_slice()[0] = 42;
+    /// let pointer = buffer.as_ptr();
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_vector: AlignedLweCiphertextVector32 =
+    ///     engine.create_lwe_ciphertext_vector(buffer, lwe_size)?;
+    /// let buffer: AlignedBuffer<u32> =
+    ///     engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// #
+    /// assert_eq!(buffer.as_ptr(), pointer);
+    /// assert_eq!(buffer.as_slice()[0], 42);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_ciphertext_vector(
+        &mut self,
+        ciphertext_vector: AlignedLweCiphertextVector32,
+    ) -> Result<AlignedBuffer<u32>, LweCiphertextVectorConsumingRetrievalError<Self::EngineError>>
+    {
+        Ok(unsafe { self.consume_retrieve_lwe_ciphertext_vector_unchecked(ciphertext_vector) })
+    }
+
+    unsafe fn consume_retrieve_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        ciphertext_vector: AlignedLweCiphertextVector32,
+    ) -> AlignedBuffer<u32> {
+        ciphertext_vector.0.into_tensor().into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorConsumingRetrievalEngine`] for [`CoreEngine`] which
+/// returns the [`AlignedBuffer`] of 64 bits integers of the vector, without copying it.
+impl LweCiphertextVectorConsumingRetrievalEngine<AlignedLweCiphertextVector64, AlignedBuffer<u64>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::backends::core::memory::{AlignedBuffer, Alignment};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_size = LweSize(16);
+    /// let mut buffer = AlignedBuffer::<u64>::new(4 * lwe_size.0, Alignment::CACHE_LINE);
+    /// buffer.as_mut_slice()[0] = 42;
+    /// let pointer = buffer.as_ptr();
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_vector: AlignedLweCiphertextVector64 =
+    ///     engine.create_lwe_ciphertext_vector(buffer, lwe_size)?;
+    /// let buffer: AlignedBuffer<u64> =
+    ///     engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// #
+    /// assert_eq!(buffer.as_ptr(), pointer);
+    /// assert_eq!(buffer.as_slice()[0], 42);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_ciphertext_vector(
+        &mut self,
+        ciphertext_vector: AlignedLweCiphertextVector64,
+    ) -> Result<AlignedBuffer<u64>, LweCiphertextVectorConsumingRetrievalError<Self::EngineError>>
+    {
+        Ok(unsafe { self.consume_retrieve_lwe_ciphertext_vector_unchecked(ciphertext_vector) })
+    }
+
+    unsafe fn consume_retrieve_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        ciphertext_vector: AlignedLweCiphertextVector64,
+    ) -> AlignedBuffer<u64> {
+        ciphertext_vector.0.into_tensor().into_container()
+    }
+}
@@ -0,0 +1,233 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    AlignedLweCiphertextVector32, AlignedLweCiphertextVector64, LweCiphertextVector32,
+    LweCiphertextVector64, LweCiphertextVectorView32, LweCiphertextVectorView64,
+};
+use crate::backends::core::memory::{AlignedBuffer, Alignment};
+use crate::backends::core::private::crypto::lwe::LweList as ImplLweList;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::specification::engines::{
+    LweCiphertextVectorConversionEngine, LweCiphertextVectorConversionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorConversionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. It copies the ciphertexts into an [`AlignedBuffer`] aligned on a cache line
+/// ([`Alignment::CACHE_LINE`]).
+impl LweCiphertextVectorConversionEngine<LweCiphertextVector32, AlignedLweCiphertextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::memory::{Alignment, MemoryAlignment};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let aligned_ciphertext_vector: AlignedLweCiphertextVector32 =
+    ///     engine.convert_lwe_ciphertext_vector(&ciphertext_vector)?;
+    /// #
+    /// assert_eq!(aligned_ciphertext_vector.alignment(), Alignment::CACHE_LINE);
+    /// assert_eq!(
+    ///     aligned_ciphertext_vector.lwe_ciphertext_count(),
+    ///     ciphertext_vector.lwe_ciphertext_count()
+    /// );
+    /// let back: LweCiphertextVector32 = engine.convert_lwe_ciphertext_vector(&aligned_ciphertext_vector)?;
+    /// assert_eq!(back, ciphertext_vector);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(aligned_ciphertext_vector)?;
+    /// engine.destroy(back)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext_vector(
+        &mut self,
+        input: &LweCiphertextVector32,
+    ) -> Result<AlignedLweCiphertextVector32, LweCiphertextVectorConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_lwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &LweCiphertextVector32,
+    ) -> AlignedLweCiphertextVector32 {
+        let buffer =
+            AlignedBuffer::from_slice(input.0.as_tensor().as_slice(), Alignment::CACHE_LINE);
+        AlignedLweCiphertextVector32(ImplLweList::from_container(buffer, input.0.lwe_size()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorConversionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. It copies the ciphertexts of an aligned vector into a standard one.
+impl LweCiphertextVectorConversionEngine<AlignedLweCiphertextVector32, LweCiphertextVector32>
+    for CoreEngine
+{
+    fn convert_lwe_ciphertext_vector(
+        &mut self,
+        input: &AlignedLweCiphertextVector32,
+    ) -> Result<LweCiphertextVector32, LweCiphertextVectorConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &AlignedLweCiphertextVector32,
+    ) -> LweCiphertextVector32 {
+        let container = input.0.as_tensor().as_slice().to_vec();
+        LweCiphertextVector32(ImplLweList::from_container(container, input.0.lwe_size()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorConversionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. It copies the ciphertexts into an [`AlignedBuffer`] aligned on a cache line
+/// ([`Alignment::CACHE_LINE`]).
+impl LweCiphertextVectorConversionEngine<LweCiphertextVector64, AlignedLweCiphertextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::memory::{Alignment, MemoryAlignment};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let aligned_ciphertext_vector: AlignedLweCiphertextVector64 =
+    ///     engine.convert_lwe_ciphertext_vector(&ciphertext_vector)?;
+    /// #
+    /// assert_eq!(aligned_ciphertext_vector.alignment(), Alignment::CACHE_LINE);
+    /// assert_eq!(
+    ///     aligned_ciphertext_vector.lwe_ciphertext_count(),
+    ///     ciphertext_vector.lwe_ciphertext_count()
+    /// );
+    /// let back: LweCiphertextVector64 = engine.convert_lwe_ciphertext_vector(&aligned_ciphertext_vector)?;
+    /// assert_eq!(back, ciphertext_vector);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(aligned_ciphertext_vector)?;
+    /// engine.destroy(back)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext_vector(
+        &mut self,
+        input: &LweCiphertextVector64,
+    ) -> Result<AlignedLweCiphertextVector64, LweCiphertextVectorConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_lwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &LweCiphertextVector64,
+    ) -> AlignedLweCiphertextVector64 {
+        let buffer =
+            AlignedBuffer::from_slice(input.0.as_tensor().as_slice(), Alignment::CACHE_LINE);
+        AlignedLweCiphertextVector64(ImplLweList::from_container(buffer, input.0.lwe_size()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorConversionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. It copies the ciphertexts of an aligned vector into a standard one.
+impl LweCiphertextVectorConversionEngine<AlignedLweCiphertextVector64, LweCiphertextVector64>
+    for CoreEngine
+{
+    fn convert_lwe_ciphertext_vector(
+        &mut self,
+        input: &AlignedLweCiphertextVector64,
+    ) -> Result<LweCiphertextVector64, LweCiphertextVectorConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &AlignedLweCiphertextVector64,
+    ) -> LweCiphertextVector64 {
+        let container = input.0.as_tensor().as_slice().to_vec();
+        LweCiphertextVector64(ImplLweList::from_container(container, input.0.lwe_size()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorConversionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. It copies the ciphertexts of a view into a standard vector, which no longer
+/// borrows the viewed memory.
+impl<'a> LweCiphertextVectorConversionEngine<LweCiphertextVectorView32<'a>, LweCiphertextVector32>
+    for CoreEngine
+{
+    fn convert_lwe_ciphertext_vector(
+        &mut self,
+        input: &LweCiphertextVectorView32<'a>,
+    ) -> Result<LweCiphertextVector32, LweCiphertextVectorConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &LweCiphertextVectorView32<'a>,
+    ) -> LweCiphertextVector32 {
+        let container = input.0.as_tensor().as_slice().to_vec();
+        LweCiphertextVector32(ImplLweList::from_container(container, input.0.lwe_size()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorConversionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. It copies the ciphertexts of a view into a standard vector, which no longer
+/// borrows the viewed memory.
+impl<'a> LweCiphertextVectorConversionEngine<LweCiphertextVectorView64<'a>, LweCiphertextVector64>
+    for CoreEngine
+{
+    fn convert_lwe_ciphertext_vector(
+        &mut self,
+        input: &LweCiphertextVectorView64<'a>,
+    ) -> Result<LweCiphertextVector64, LweCiphertextVectorConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &LweCiphertextVectorView64<'a>,
+    ) -> LweCiphertextVector64 {
+        let container = input.0.as_tensor().as_slice().to_vec();
+        LweCiphertextVector64(ImplLweList::from_container(container, input.0.lwe_size()))
+    }
+}
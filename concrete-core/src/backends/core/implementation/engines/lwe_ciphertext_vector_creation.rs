@@ -0,0 +1,210 @@
+use concrete_commons::parameters::LweSize;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    AlignedLweCiphertextVector32, AlignedLweCiphertextVector64, LweCiphertextVectorView32,
+    LweCiphertextVectorView64,
+};
+use crate::backends::core::memory::{AlignedBuffer, SharedSlice};
+use crate::backends::core::private::crypto::lwe::LweList as ImplLweList;
+use crate::specification::engines::{
+    LweCiphertextVectorCreationEngine, LweCiphertextVectorCreationError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CoreEngine`] which wraps an
+/// [`AlignedBuffer`] of 32 bits integers, without copying it.
+impl LweCiphertextVectorCreationEngine<AlignedBuffer<u32>, AlignedLweCiphertextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::backends::core::memory::{AlignedBuffer, Alignment, MemoryAlignment};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_size = LweSize(16);
+    /// let buffer = AlignedBuffer::<u32>::new(4 * lwe_size.0, Alignment::SIMD_256);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_vector: AlignedLweCiphertextVector32 =
+    ///     engine.create_lwe_ciphertext_vector(buffer, lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(4));
+    /// assert_eq!(ciphertext_vector.alignment(), Alignment::SIMD_256);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: AlignedBuffer<u32>,
+        lwe_size: LweSize,
+    ) -> Result<AlignedLweCiphertextVector32, LweCiphertextVectorCreationError<Self::EngineError>>
+    {
+        LweCiphertextVectorCreationError::perform_generic_checks(container.len(), lwe_size)?;
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: AlignedBuffer<u32>,
+        lwe_size: LweSize,
+    ) -> AlignedLweCiphertextVector32 {
+        AlignedLweCiphertextVector32(ImplLweList::from_container(container, lwe_size))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CoreEngine`] which wraps an
+/// [`AlignedBuffer`] of 64 bits integers, without copying it.
+impl LweCiphertextVectorCreationEngine<AlignedBuffer<u64>, AlignedLweCiphertextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::backends::core::memory::{AlignedBuffer, Alignment, MemoryAlignment};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_size = LweSize(16);
+    /// let buffer = AlignedBuffer::<u64>::new(4 * lwe_size.0, Alignment::SIMD_256);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_vector: AlignedLweCiphertextVector64 =
+    ///     engine.create_lwe_ciphertext_vector(buffer, lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(4));
+    /// assert_eq!(ciphertext_vector.alignment(), Alignment::SIMD_256);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: AlignedBuffer<u64>,
+        lwe_size: LweSize,
+    ) -> Result<AlignedLweCiphertextVector64, LweCiphertextVectorCreationError<Self::EngineError>>
+    {
+        LweCiphertextVectorCreationError::perform_generic_checks(container.len(), lwe_size)?;
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: AlignedBuffer<u64>,
+        lwe_size: LweSize,
+    ) -> AlignedLweCiphertextVector64 {
+        AlignedLweCiphertextVector64(ImplLweList::from_container(container, lwe_size))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CoreEngine`] which views a
+/// [`SharedSlice`] of 32 bits integers, without copying it.
+impl<'a> LweCiphertextVectorCreationEngine<SharedSlice<'a, u32>, LweCiphertextVectorView32<'a>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::backends::core::memory::SharedSlice;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_size = LweSize(16);
+    /// // The memory would typically be a shared memory segment, written by another process.
+    /// let segment = vec![0_u32; 4 * lwe_size.0];
+    /// let slice = unsafe { SharedSlice::from_raw_parts(segment.as_ptr(), segment.len())? };
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_vector: LweCiphertextVectorView32 =
+    ///     engine.create_lwe_ciphertext_vector(slice, lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(4));
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: SharedSlice<'a, u32>,
+        lwe_size: LweSize,
+    ) -> Result<LweCiphertextVectorView32<'a>, LweCiphertextVectorCreationError<Self::EngineError>>
+    {
+        LweCiphertextVectorCreationError::perform_generic_checks(container.len(), lwe_size)?;
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: SharedSlice<'a, u32>,
+        lwe_size: LweSize,
+    ) -> LweCiphertextVectorView32<'a> {
+        LweCiphertextVectorView32(ImplLweList::from_container(container, lwe_size))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CoreEngine`] which views a
+/// [`SharedSlice`] of 64 bits integers, without copying it.
+impl<'a> LweCiphertextVectorCreationEngine<SharedSlice<'a, u64>, LweCiphertextVectorView64<'a>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::backends::core::memory::SharedSlice;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_size = LweSize(16);
+    /// // The memory would typically be a shared memory segment, written by another process.
+    /// let segment = vec![0_u64; 4 * lwe_size.0];
+    /// let slice = unsafe { SharedSlice::from_raw_parts(segment.as_ptr(), segment.len())? };
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_vector: LweCiphertextVectorView64 =
+    ///     engine.create_lwe_ciphertext_vector(slice, lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(4));
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: SharedSlice<'a, u64>,
+        lwe_size: LweSize,
+    ) -> Result<LweCiphertextVectorView64<'a>, LweCiphertextVectorCreationError<Self::EngineError>>
+    {
+        LweCiphertextVectorCreationError::perform_generic_checks(container.len(), lwe_size)?;
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: SharedSlice<'a, u64>,
+        lwe_size: LweSize,
+    ) -> LweCiphertextVectorView64<'a> {
+        LweCiphertextVectorView64(ImplLweList::from_container(container, lwe_size))
+    }
+}
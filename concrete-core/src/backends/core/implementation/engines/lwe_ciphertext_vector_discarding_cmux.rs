@@ -0,0 +1,416 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GgswCiphertext32, GgswCiphertext64, LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::backends::core::private::crypto::bootstrap::FourierBuffers;
+use crate::backends::core::private::crypto::ggsw::{
+    FourierGgswCiphertext as ImplFourierGgswCiphertext,
+    StandardGgswCiphertext as ImplStandardGgswCiphertext,
+};
+use crate::backends::core::private::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::backends::core::private::crypto::lwe::{
+    LweCiphertext as ImplLweCiphertext, LweList as ImplLweList,
+};
+use crate::backends::core::private::math::decomposition::SignedDecomposer;
+use crate::backends::core::private::math::fft::{Complex64, ALLOWED_POLY_SIZE};
+#[allow(deprecated)]
+use crate::backends::core::private::math::polynomial::{MonomialDegree, Polynomial};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingCmuxEngine, LweCiphertextVectorDiscardingCmuxError,
+};
+use crate::specification::entities::{GgswCiphertextEntity, LweCiphertextVectorEntity};
+use concrete_commons::parameters::{GlweSize, LweCiphertextCount, PolynomialSize};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingCmuxEngine`] for [`CoreEngine`] that operates
+/// on 32 bits integers.
+///
+/// Every input ciphertext is packed in the constant coefficient of a GLWE ciphertext, on which
+/// the external product is computed, before being unpacked by a sample extraction. The external
+/// products are computed in the Fourier domain when the condition can be converted to it, and
+/// when the vector is long enough for the conversion to pay off. They are otherwise computed in
+/// the standard domain, as for conditions with a polynomial size of one.
+impl
+    LweCiphertextVectorDiscardingCmuxEngine<
+        GgswCiphertext32,
+        LweCiphertextVector32,
+        LweCiphertextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 28 bits)
+    /// let if_true_input = vec![1_u32 << 28, 2 << 28, 3 << 28, 4 << 28];
+    /// let if_false_input = vec![5_u32 << 28, 6 << 28, 7 << 28, 8 << 28];
+    /// let noise = Variance(2_f64.powf(-40.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// // The condition is encrypted under the GLWE key with polynomials of size one obtained by
+    /// // transmuting the LWE key of the input ciphertexts.
+    /// let lwe_key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let glwe_key =
+    ///     engine.transmute_lwe_secret_key_to_glwe_secret_key(lwe_key.clone(), PolynomialSize(1))?;
+    /// let plaintext = engine.create_plaintext(&1_u32)?;
+    /// let condition =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&glwe_key, &plaintext, noise, level, base_log)?;
+    /// let if_true_plaintexts = engine.create_plaintext_vector(&if_true_input)?;
+    /// let if_false_plaintexts = engine.create_plaintext_vector(&if_false_input)?;
+    /// let if_true = engine.encrypt_lwe_ciphertext_vector(&lwe_key, &if_true_plaintexts, noise)?;
+    /// let if_false = engine.encrypt_lwe_ciphertext_vector(&lwe_key, &if_false_plaintexts, noise)?;
+    /// let mut output = if_false.clone();
+    ///
+    /// engine.discard_cmux_lwe_ciphertext_vector(&mut output, &condition, &if_true, &if_false)?;
+    /// #
+    /// assert_eq!(output.lwe_ciphertext_count(), if_true.lwe_ciphertext_count());
+    /// # let decrypted = engine.decrypt_lwe_ciphertext_vector(&lwe_key, &output)?;
+    /// # let decoded = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// # for (expected, actual) in if_true_input.iter().zip(decoded.iter()) {
+    /// #     let error = actual.wrapping_sub(*expected);
+    /// #     assert!(error < 1 << 26 || error.wrapping_neg() < 1 << 26);
+    /// # }
+    /// # engine.destroy(decrypted)?;
+    ///
+    /// engine.destroy(glwe_key)?;
+    /// engine.destroy(lwe_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(condition)?;
+    /// engine.destroy(if_true_plaintexts)?;
+    /// engine.destroy(if_false_plaintexts)?;
+    /// engine.destroy(if_true)?;
+    /// engine.destroy(if_false)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_cmux_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        condition: &GgswCiphertext32,
+        if_true: &LweCiphertextVector32,
+        if_false: &LweCiphertextVector32,
+    ) -> Result<(), LweCiphertextVectorDiscardingCmuxError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingCmuxError::perform_generic_checks(
+            output, condition, if_true, if_false,
+        )?;
+        unsafe {
+            self.discard_cmux_lwe_ciphertext_vector_unchecked(output, condition, if_true, if_false)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_cmux_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        condition: &GgswCiphertext32,
+        if_true: &LweCiphertextVector32,
+        if_false: &LweCiphertextVector32,
+    ) {
+        let glwe_size = condition.glwe_dimension().to_glwe_size();
+        let buffers = if uses_fourier_domain(
+            glwe_size,
+            condition.polynomial_size(),
+            output.lwe_ciphertext_count(),
+        ) {
+            Some(self.get_fourier_u32_buffer(condition.polynomial_size(), glwe_size))
+        } else {
+            None
+        };
+        cmux_lwe_list(
+            &mut output.0,
+            &condition.0,
+            &if_true.0,
+            &if_false.0,
+            buffers,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingCmuxEngine`] for [`CoreEngine`] that operates
+/// on 64 bits integers.
+///
+/// Every input ciphertext is packed in the constant coefficient of a GLWE ciphertext, on which
+/// the external product is computed, before being unpacked by a sample extraction. The external
+/// products are computed in the Fourier domain when the condition can be converted to it, and
+/// when the vector is long enough for the conversion to pay off. They are otherwise computed in
+/// the standard domain, as for conditions with a polynomial size of one.
+impl
+    LweCiphertextVectorDiscardingCmuxEngine<
+        GgswCiphertext64,
+        LweCiphertextVector64,
+        LweCiphertextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 60 bits)
+    /// let if_true_input = vec![1_u64 << 60, 2 << 60, 3 << 60, 4 << 60];
+    /// let if_false_input = vec![5_u64 << 60, 6 << 60, 7 << 60, 8 << 60];
+    /// let noise = Variance(2_f64.powf(-60.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// // The input ciphertexts are encrypted under the transmutation of the key of the condition.
+    /// let glwe_key: GlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let lwe_key = engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_key.clone())?;
+    /// let plaintext = engine.create_plaintext(&0_u64)?;
+    /// let condition =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&glwe_key, &plaintext, noise, level, base_log)?;
+    /// let if_true_plaintexts = engine.create_plaintext_vector(&if_true_input)?;
+    /// let if_false_plaintexts = engine.create_plaintext_vector(&if_false_input)?;
+    /// let if_true = engine.encrypt_lwe_ciphertext_vector(&lwe_key, &if_true_plaintexts, noise)?;
+    /// let if_false = engine.encrypt_lwe_ciphertext_vector(&lwe_key, &if_false_plaintexts, noise)?;
+    /// let mut output = if_true.clone();
+    ///
+    /// engine.discard_cmux_lwe_ciphertext_vector(&mut output, &condition, &if_true, &if_false)?;
+    /// #
+    /// assert_eq!(output.lwe_ciphertext_count(), if_false.lwe_ciphertext_count());
+    /// # let decrypted = engine.decrypt_lwe_ciphertext_vector(&lwe_key, &output)?;
+    /// # let decoded = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// # for (expected, actual) in if_false_input.iter().zip(decoded.iter()) {
+    /// #     let error = actual.wrapping_sub(*expected);
+    /// #     assert!(error < 1 << 56 || error.wrapping_neg() < 1 << 56);
+    /// # }
+    /// # engine.destroy(decrypted)?;
+    ///
+    /// engine.destroy(glwe_key)?;
+    /// engine.destroy(lwe_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(condition)?;
+    /// engine.destroy(if_true_plaintexts)?;
+    /// engine.destroy(if_false_plaintexts)?;
+    /// engine.destroy(if_true)?;
+    /// engine.destroy(if_false)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_cmux_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        condition: &GgswCiphertext64,
+        if_true: &LweCiphertextVector64,
+        if_false: &LweCiphertextVector64,
+    ) -> Result<(), LweCiphertextVectorDiscardingCmuxError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingCmuxError::perform_generic_checks(
+            output, condition, if_true, if_false,
+        )?;
+        unsafe {
+            self.discard_cmux_lwe_ciphertext_vector_unchecked(output, condition, if_true, if_false)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_cmux_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        condition: &GgswCiphertext64,
+        if_true: &LweCiphertextVector64,
+        if_false: &LweCiphertextVector64,
+    ) {
+        let glwe_size = condition.glwe_dimension().to_glwe_size();
+        let buffers = if uses_fourier_domain(
+            glwe_size,
+            condition.polynomial_size(),
+            output.lwe_ciphertext_count(),
+        ) {
+            Some(self.get_fourier_u64_buffer(condition.polynomial_size(), glwe_size))
+        } else {
+            None
+        };
+        cmux_lwe_list(
+            &mut output.0,
+            &condition.0,
+            &if_true.0,
+            &if_false.0,
+            buffers,
+        );
+    }
+}
+
+// Returns whether the external products are computed in the Fourier domain. A product of
+// polynomials costs about N^2 multiplications in the standard domain, against about 4 N log2(N)
+// operations for a ciphertext in the Fourier domain. The Fourier domain also requires to convert
+// the condition first, which costs as many transforms as the products of k + 1 ciphertexts.
+fn uses_fourier_domain(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    count: LweCiphertextCount,
+) -> bool {
+    if !ALLOWED_POLY_SIZE.contains(&polynomial_size.0) {
+        return false;
+    }
+    let log_size = polynomial_size.0.trailing_zeros() as usize;
+    count.0 * polynomial_size.0 > 4 * log_size * (count.0 + glwe_size.0)
+}
+
+// Computes the CMUX of every pair of input ciphertexts. The external products are computed in the
+// Fourier domain when buffers are given, and in the standard domain otherwise.
+fn cmux_lwe_list<Scalar>(
+    output: &mut ImplLweList<Vec<Scalar>>,
+    condition: &ImplStandardGgswCiphertext<Vec<Scalar>>,
+    if_true: &ImplLweList<Vec<Scalar>>,
+    if_false: &ImplLweList<Vec<Scalar>>,
+    buffers: Option<&mut FourierBuffers<Scalar>>,
+) where
+    Scalar: UnsignedTorus,
+{
+    match buffers {
+        Some(buffers) => {
+            let mut fourier_condition = ImplFourierGgswCiphertext::allocate(
+                Complex64::new(0., 0.),
+                condition.polynomial_size(),
+                condition.glwe_size(),
+                condition.decomposition_level_count(),
+                condition.decomposition_base_log(),
+            );
+            fourier_condition.fill_with_forward_fourier(condition, buffers);
+            cmux_lwe_list_with(
+                output,
+                condition,
+                if_true,
+                if_false,
+                |selected, difference| {
+                    fourier_condition.external_product(selected, difference, buffers)
+                },
+            );
+        }
+        None => cmux_lwe_list_with(
+            output,
+            condition,
+            if_true,
+            if_false,
+            |selected, difference| add_standard_external_product(selected, condition, difference),
+        ),
+    }
+}
+
+// Computes the CMUX of every pair of input ciphertexts, as `if_false + condition * (if_true -
+// if_false)`. Both terms are packed in GLWE ciphertexts, such that `external_product` adds the
+// product of the condition and the difference to the packed `if_false` ciphertext.
+fn cmux_lwe_list_with<Scalar, ExternalProduct>(
+    output: &mut ImplLweList<Vec<Scalar>>,
+    condition: &ImplStandardGgswCiphertext<Vec<Scalar>>,
+    if_true: &ImplLweList<Vec<Scalar>>,
+    if_false: &ImplLweList<Vec<Scalar>>,
+    mut external_product: ExternalProduct,
+) where
+    Scalar: UnsignedTorus,
+    ExternalProduct: FnMut(&mut ImplGlweCiphertext<Vec<Scalar>>, &ImplGlweCiphertext<Vec<Scalar>>),
+{
+    let polynomial_size = condition.polynomial_size();
+    let glwe_size = condition.glwe_size();
+    let mut difference = ImplLweCiphertext::allocate(Scalar::ZERO, output.lwe_size());
+    let mut packed_difference =
+        ImplGlweCiphertext::allocate(Scalar::ZERO, polynomial_size, glwe_size);
+    let mut selected = ImplGlweCiphertext::allocate(Scalar::ZERO, polynomial_size, glwe_size);
+    for (mut output, (if_true, if_false)) in output
+        .ciphertext_iter_mut()
+        .zip(if_true.ciphertext_iter().zip(if_false.ciphertext_iter()))
+    {
+        difference
+            .as_mut_tensor()
+            .fill_with_copy(if_true.as_tensor());
+        difference.update_with_sub(&if_false);
+        pack_lwe_ciphertext(&mut packed_difference, &difference);
+        pack_lwe_ciphertext(&mut selected, &if_false);
+        external_product(&mut selected, &packed_difference);
+        #[allow(deprecated)]
+        selected.fill_lwe_with_sample_extraction(&mut output, MonomialDegree(0));
+    }
+}
+
+// Fills a GLWE ciphertext such that the sample extraction of its constant coefficient gives back
+// the LWE ciphertext, which is encrypted under the transmutation of the GLWE secret key.
+fn pack_lwe_ciphertext<Scalar, Cont>(
+    glwe: &mut ImplGlweCiphertext<Vec<Scalar>>,
+    lwe: &ImplLweCiphertext<Cont>,
+) where
+    Scalar: UnsignedTorus,
+    ImplLweCiphertext<Cont>: AsRefTensor<Element = Scalar>,
+{
+    let polynomial_size = glwe.polynomial_size().0;
+    let (mut body, mut mask) = glwe.get_mut_body_and_mask();
+    body.as_mut_tensor().fill_with_element(Scalar::ZERO);
+    *body.as_mut_tensor().first_mut() = lwe.get_body().0;
+    mask.as_mut_tensor()
+        .fill_with_copy(lwe.get_mask().as_tensor());
+    // The extraction maps the coefficient `j > 0` of a mask polynomial to the opposite of the
+    // element `N - j` of the LWE mask, which is reverted here.
+    for mut polynomial in mask.as_mut_tensor().subtensor_iter_mut(polynomial_size) {
+        let mut tail = polynomial.get_sub_mut(1..);
+        tail.update_with_wrapping_neg();
+        tail.reverse();
+    }
+}
+
+// Adds the external product of the condition and a GLWE ciphertext to the output, with polynomial
+// products computed in the standard domain.
+fn add_standard_external_product<Scalar>(
+    output: &mut ImplGlweCiphertext<Vec<Scalar>>,
+    condition: &ImplStandardGgswCiphertext<Vec<Scalar>>,
+    glwe: &ImplGlweCiphertext<Vec<Scalar>>,
+) where
+    Scalar: UnsignedTorus,
+{
+    let polynomial_size = condition.polynomial_size().0;
+    let decomposer = SignedDecomposer::new(
+        condition.decomposition_base_log(),
+        condition.decomposition_level_count(),
+    );
+    let mut decomposition = decomposer.decompose_tensor(glwe);
+    // We loop through the levels (we reverse to match the order of the decomposition iterator.)
+    for level_matrix in condition.level_matrix_iter().rev() {
+        let term = decomposition.next_term().unwrap();
+        debug_assert_eq!(level_matrix.decomposition_level(), term.level());
+        let rows = level_matrix.row_iter().zip(
+            term.as_tensor()
+                .subtensor_iter(polynomial_size)
+                .map(Polynomial::from_tensor),
+        );
+        for (row, decomposed_polynomial) in rows {
+            let products = output
+                .as_mut_tensor()
+                .subtensor_iter_mut(polynomial_size)
+                .map(Polynomial::from_tensor)
+                .zip(
+                    row.as_tensor()
+                        .subtensor_iter(polynomial_size)
+                        .map(Polynomial::from_tensor),
+                );
+            for (mut output_polynomial, row_polynomial) in products {
+                output_polynomial
+                    .update_with_wrapping_add_mul(&row_polynomial, &decomposed_polynomial);
+            }
+        }
+    }
+}
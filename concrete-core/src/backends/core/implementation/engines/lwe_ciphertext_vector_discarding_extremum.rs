@@ -0,0 +1,381 @@
+use concrete_commons::parameters::{Extremum, MessageBitCount};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FourierLweBootstrapKey32, FourierLweBootstrapKey64, LweCiphertextVector32,
+    LweCiphertextVector64, LweKeyswitchKey32, LweKeyswitchKey64,
+};
+use crate::backends::core::private::crypto::bootstrap::discard_extremum_lwe_list;
+use crate::backends::core::private::math::fft::ALLOWED_POLY_SIZE;
+use crate::prelude::{CoreError, LweBootstrapKeyEntity};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingExtremumEngine, LweCiphertextVectorDiscardingExtremumError,
+};
+
+impl From<CoreError> for LweCiphertextVectorDiscardingExtremumError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingExtremumEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+///
+/// When the `multithread` feature is enabled, the elements of the vectors are processed in
+/// parallel.
+impl
+    LweCiphertextVectorDiscardingExtremumEngine<
+        LweKeyswitchKey32,
+        FourierLweBootstrapKey32,
+        LweCiphertextVector32,
+        LweCiphertextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, Extremum, GlweDimension, LweDimension,
+    ///     MessageBitCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(10), GlweDimension(1), PolynomialSize(1024));
+    /// let (bsk_lc, bsk_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let (ksk_lc, ksk_bl) = (DecompositionLevelCount(5), DecompositionBaseLog(3));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied: 2 bits of message, and 2 bits of padding.
+    /// let message_bits = MessageBitCount(2);
+    /// let input_1 = vec![0_u32 << 28, 1 << 28, 2 << 28, 3 << 28];
+    /// let input_2 = vec![3_u32 << 28, 1 << 28, 0 << 28, 2 << 28];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, bsk_bl, bsk_lc, noise)?;
+    /// let big_lwe_sk: LweSecretKey32 = engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let ksk = engine.create_lwe_keyswitch_key(&big_lwe_sk, &lwe_sk, ksk_lc, ksk_bl, noise)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let ciphertext_vector_1 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&big_lwe_sk, &plaintext_vector_1, noise)?;
+    /// let ciphertext_vector_2 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&big_lwe_sk, &plaintext_vector_2, noise)?;
+    /// let mut output_ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&big_lwe_sk, &plaintext_vector_1, noise)?;
+    ///
+    /// engine.discard_extremum_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector_1,
+    ///     &ciphertext_vector_2,
+    ///     &ksk,
+    ///     &bsk,
+    ///     message_bits,
+    ///     Extremum::Maximum,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(4));
+    /// # let output_plaintext_vector =
+    /// #     engine.decrypt_lwe_ciphertext_vector(&big_lwe_sk, &output_ciphertext_vector)?;
+    /// # let output: Vec<u32> = engine
+    /// #     .retrieve_plaintext_vector(&output_plaintext_vector)?
+    /// #     .iter()
+    /// #     .map(|v| v.wrapping_add(1 << 27) >> 28)
+    /// #     .collect();
+    /// # assert_eq!(output, vec![3, 1, 2, 3]);
+    /// # engine.destroy(output_plaintext_vector)?;
+    /// #
+    /// # engine.discard_extremum_lwe_ciphertext_vector(
+    /// #     &mut output_ciphertext_vector,
+    /// #     &ciphertext_vector_1,
+    /// #     &ciphertext_vector_2,
+    /// #     &ksk,
+    /// #     &bsk,
+    /// #     message_bits,
+    /// #     Extremum::Minimum,
+    /// # )?;
+    /// # let output_plaintext_vector =
+    /// #     engine.decrypt_lwe_ciphertext_vector(&big_lwe_sk, &output_ciphertext_vector)?;
+    /// # let output: Vec<u32> = engine
+    /// #     .retrieve_plaintext_vector(&output_plaintext_vector)?
+    /// #     .iter()
+    /// #     .map(|v| v.wrapping_add(1 << 27) >> 28)
+    /// #     .collect();
+    /// # assert_eq!(output, vec![0, 1, 0, 2]);
+    /// # engine.destroy(output_plaintext_vector)?;
+    /// # let short_plaintext_vector = engine.create_plaintext_vector(&input_1[..2])?;
+    /// # let short_ciphertext_vector =
+    /// #     engine.encrypt_lwe_ciphertext_vector(&big_lwe_sk, &short_plaintext_vector, noise)?;
+    /// # assert!(matches!(
+    /// #     engine.discard_extremum_lwe_ciphertext_vector(
+    /// #         &mut output_ciphertext_vector,
+    /// #         &ciphertext_vector_1,
+    /// #         &short_ciphertext_vector,
+    /// #         &ksk,
+    /// #         &bsk,
+    /// #         message_bits,
+    /// #         Extremum::Maximum,
+    /// #     ),
+    /// #     Err(LweCiphertextVectorDiscardingExtremumError::CiphertextCountMismatch)
+    /// # ));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(big_lwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(ksk)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(ciphertext_vector_1)?;
+    /// engine.destroy(ciphertext_vector_2)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_extremum_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input_1: &LweCiphertextVector32,
+        input_2: &LweCiphertextVector32,
+        ksk: &LweKeyswitchKey32,
+        bsk: &FourierLweBootstrapKey32,
+        message_bits: MessageBitCount,
+        extremum: Extremum,
+    ) -> Result<(), LweCiphertextVectorDiscardingExtremumError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&bsk.polynomial_size().0) {
+            return Err(LweCiphertextVectorDiscardingExtremumError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
+        LweCiphertextVectorDiscardingExtremumError::perform_generic_checks(
+            output,
+            input_1,
+            input_2,
+            ksk,
+            bsk,
+            message_bits,
+        )?;
+        unsafe {
+            self.discard_extremum_lwe_ciphertext_vector_unchecked(
+                output,
+                input_1,
+                input_2,
+                ksk,
+                bsk,
+                message_bits,
+                extremum,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_extremum_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input_1: &LweCiphertextVector32,
+        input_2: &LweCiphertextVector32,
+        ksk: &LweKeyswitchKey32,
+        bsk: &FourierLweBootstrapKey32,
+        message_bits: MessageBitCount,
+        extremum: Extremum,
+    ) {
+        let buffers =
+            self.get_fourier_u32_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
+        discard_extremum_lwe_list(
+            &mut output.0,
+            &input_1.0,
+            &input_2.0,
+            &ksk.0,
+            &bsk.0,
+            message_bits,
+            extremum,
+            buffers,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingExtremumEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+///
+/// When the `multithread` feature is enabled, the elements of the vectors are processed in
+/// parallel.
+impl
+    LweCiphertextVectorDiscardingExtremumEngine<
+        LweKeyswitchKey64,
+        FourierLweBootstrapKey64,
+        LweCiphertextVector64,
+        LweCiphertextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, Extremum, GlweDimension, LweDimension,
+    ///     MessageBitCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(10), GlweDimension(1), PolynomialSize(1024));
+    /// let (bsk_lc, bsk_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let (ksk_lc, ksk_bl) = (DecompositionLevelCount(5), DecompositionBaseLog(3));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied: 2 bits of message, and 2 bits of padding.
+    /// let message_bits = MessageBitCount(2);
+    /// let input_1 = vec![0_u64 << 60, 1 << 60, 2 << 60, 3 << 60];
+    /// let input_2 = vec![3_u64 << 60, 1 << 60, 0 << 60, 2 << 60];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, bsk_bl, bsk_lc, noise)?;
+    /// let big_lwe_sk: LweSecretKey64 = engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let ksk = engine.create_lwe_keyswitch_key(&big_lwe_sk, &lwe_sk, ksk_lc, ksk_bl, noise)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let ciphertext_vector_1 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&big_lwe_sk, &plaintext_vector_1, noise)?;
+    /// let ciphertext_vector_2 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&big_lwe_sk, &plaintext_vector_2, noise)?;
+    /// let mut output_ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&big_lwe_sk, &plaintext_vector_1, noise)?;
+    ///
+    /// engine.discard_extremum_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector_1,
+    ///     &ciphertext_vector_2,
+    ///     &ksk,
+    ///     &bsk,
+    ///     message_bits,
+    ///     Extremum::Maximum,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(4));
+    /// # let output_plaintext_vector =
+    /// #     engine.decrypt_lwe_ciphertext_vector(&big_lwe_sk, &output_ciphertext_vector)?;
+    /// # let output: Vec<u64> = engine
+    /// #     .retrieve_plaintext_vector(&output_plaintext_vector)?
+    /// #     .iter()
+    /// #     .map(|v| v.wrapping_add(1 << 59) >> 60)
+    /// #     .collect();
+    /// # assert_eq!(output, vec![3, 1, 2, 3]);
+    /// # engine.destroy(output_plaintext_vector)?;
+    /// #
+    /// # engine.discard_extremum_lwe_ciphertext_vector(
+    /// #     &mut output_ciphertext_vector,
+    /// #     &ciphertext_vector_1,
+    /// #     &ciphertext_vector_2,
+    /// #     &ksk,
+    /// #     &bsk,
+    /// #     message_bits,
+    /// #     Extremum::Minimum,
+    /// # )?;
+    /// # let output_plaintext_vector =
+    /// #     engine.decrypt_lwe_ciphertext_vector(&big_lwe_sk, &output_ciphertext_vector)?;
+    /// # let output: Vec<u64> = engine
+    /// #     .retrieve_plaintext_vector(&output_plaintext_vector)?
+    /// #     .iter()
+    /// #     .map(|v| v.wrapping_add(1 << 59) >> 60)
+    /// #     .collect();
+    /// # assert_eq!(output, vec![0, 1, 0, 2]);
+    /// # engine.destroy(output_plaintext_vector)?;
+    /// # let short_plaintext_vector = engine.create_plaintext_vector(&input_1[..2])?;
+    /// # let short_ciphertext_vector =
+    /// #     engine.encrypt_lwe_ciphertext_vector(&big_lwe_sk, &short_plaintext_vector, noise)?;
+    /// # assert!(matches!(
+    /// #     engine.discard_extremum_lwe_ciphertext_vector(
+    /// #         &mut output_ciphertext_vector,
+    /// #         &ciphertext_vector_1,
+    /// #         &short_ciphertext_vector,
+    /// #         &ksk,
+    /// #         &bsk,
+    /// #         message_bits,
+    /// #         Extremum::Maximum,
+    /// #     ),
+    /// #     Err(LweCiphertextVectorDiscardingExtremumError::CiphertextCountMismatch)
+    /// # ));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(big_lwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(ksk)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(ciphertext_vector_1)?;
+    /// engine.destroy(ciphertext_vector_2)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_extremum_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input_1: &LweCiphertextVector64,
+        input_2: &LweCiphertextVector64,
+        ksk: &LweKeyswitchKey64,
+        bsk: &FourierLweBootstrapKey64,
+        message_bits: MessageBitCount,
+        extremum: Extremum,
+    ) -> Result<(), LweCiphertextVectorDiscardingExtremumError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&bsk.polynomial_size().0) {
+            return Err(LweCiphertextVectorDiscardingExtremumError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
+        LweCiphertextVectorDiscardingExtremumError::perform_generic_checks(
+            output,
+            input_1,
+            input_2,
+            ksk,
+            bsk,
+            message_bits,
+        )?;
+        unsafe {
+            self.discard_extremum_lwe_ciphertext_vector_unchecked(
+                output,
+                input_1,
+                input_2,
+                ksk,
+                bsk,
+                message_bits,
+                extremum,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_extremum_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input_1: &LweCiphertextVector64,
+        input_2: &LweCiphertextVector64,
+        ksk: &LweKeyswitchKey64,
+        bsk: &FourierLweBootstrapKey64,
+        message_bits: MessageBitCount,
+        extremum: Extremum,
+    ) {
+        let buffers =
+            self.get_fourier_u64_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
+        discard_extremum_lwe_list(
+            &mut output.0,
+            &input_1.0,
+            &input_2.0,
+            &ksk.0,
+            &bsk.0,
+            message_bits,
+            extremum,
+            buffers,
+        );
+    }
+}
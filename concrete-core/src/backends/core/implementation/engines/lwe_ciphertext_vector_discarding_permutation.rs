@@ -0,0 +1,249 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GgswCiphertext32, GgswCiphertext64, LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::backends::core::private::crypto::ggsw::StandardGgswCiphertext as ImplStandardGgswCiphertext;
+use crate::backends::core::private::crypto::gsw::GswCiphertext as ImplGswCiphertext;
+use crate::backends::core::private::crypto::lwe::{
+    LweCiphertext as ImplLweCiphertext, LweList as ImplLweList,
+};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::specification::engines::{
+    permutation_network_layer_count, permutation_network_layer_pairs,
+    LweCiphertextVectorDiscardingPermutationEngine, LweCiphertextVectorDiscardingPermutationError,
+};
+use concrete_commons::parameters::LweCiphertextCount;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingPermutationEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextVectorDiscardingPermutationEngine<
+        GgswCiphertext32,
+        LweCiphertextVector32,
+        LweCiphertextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// use concrete_core::specification::engines::permutation_network_switch_bits;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 28 bits)
+    /// let input = vec![1_u32 << 28, 2 << 28, 3 << 28, 4 << 28];
+    /// let noise = Variance(2_f64.powf(-40.));
+    /// // The first ciphertext is sent to the third position, the second to the first, and so on.
+    /// let permutation = vec![2, 0, 3, 1];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// // The switches are encrypted under the GLWE key with polynomials of size one obtained by
+    /// // transmuting the LWE key of the input ciphertexts.
+    /// let lwe_key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let glwe_key =
+    ///     engine.transmute_lwe_secret_key_to_glwe_secret_key(lwe_key.clone(), PolynomialSize(1))?;
+    /// let mut switches = Vec::new();
+    /// for bit in permutation_network_switch_bits(&permutation) {
+    ///     let plaintext = engine.create_plaintext(&(bit as u32))?;
+    ///     switches.push(engine.encrypt_scalar_ggsw_ciphertext(
+    ///         &glwe_key, &plaintext, noise, level, base_log,
+    ///     )?);
+    ///     engine.destroy(plaintext)?;
+    /// }
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&lwe_key, &plaintext_vector, noise)?;
+    /// let mut output_vector = ciphertext_vector.clone();
+    ///
+    /// engine.discard_permute_lwe_ciphertext_vector(&mut output_vector, &ciphertext_vector, &switches)?;
+    /// #
+    /// assert_eq!(output_vector.lwe_ciphertext_count(), ciphertext_vector.lwe_ciphertext_count());
+    /// # let decrypted = engine.decrypt_lwe_ciphertext_vector(&lwe_key, &output_vector)?;
+    /// # let output = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// # for (i, destination) in permutation.iter().enumerate() {
+    /// #     let error = output[*destination].wrapping_sub(input[i]);
+    /// #     assert!(error < 1 << 26 || error.wrapping_neg() < 1 << 26);
+    /// # }
+    /// # engine.destroy(decrypted)?;
+    ///
+    /// engine.destroy(glwe_key)?;
+    /// engine.destroy(lwe_key)?;
+    /// for switch in switches {
+    ///     engine.destroy(switch)?;
+    /// }
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_permute_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        switches: &[GgswCiphertext32],
+    ) -> Result<(), LweCiphertextVectorDiscardingPermutationError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingPermutationError::perform_generic_checks(
+            output, input, switches,
+        )?;
+        unsafe { self.discard_permute_lwe_ciphertext_vector_unchecked(output, input, switches) };
+        Ok(())
+    }
+
+    unsafe fn discard_permute_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        switches: &[GgswCiphertext32],
+    ) {
+        let switches: Vec<_> = switches.iter().map(|switch| &switch.0).collect();
+        permute_lwe_list(&mut output.0, &input.0, switches.as_slice());
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingPermutationEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextVectorDiscardingPermutationEngine<
+        GgswCiphertext64,
+        LweCiphertextVector64,
+        LweCiphertextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// use concrete_core::specification::engines::permutation_network_switch_bits;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 60 bits)
+    /// let input = vec![1_u64 << 60, 2 << 60, 3 << 60, 4 << 60];
+    /// let noise = Variance(2_f64.powf(-40.));
+    /// // The first ciphertext is sent to the third position, the second to the first, and so on.
+    /// let permutation = vec![2, 0, 3, 1];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// // The switches are encrypted under the GLWE key with polynomials of size one obtained by
+    /// // transmuting the LWE key of the input ciphertexts.
+    /// let lwe_key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let glwe_key =
+    ///     engine.transmute_lwe_secret_key_to_glwe_secret_key(lwe_key.clone(), PolynomialSize(1))?;
+    /// let mut switches = Vec::new();
+    /// for bit in permutation_network_switch_bits(&permutation) {
+    ///     let plaintext = engine.create_plaintext(&(bit as u64))?;
+    ///     switches.push(engine.encrypt_scalar_ggsw_ciphertext(
+    ///         &glwe_key, &plaintext, noise, level, base_log,
+    ///     )?);
+    ///     engine.destroy(plaintext)?;
+    /// }
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&lwe_key, &plaintext_vector, noise)?;
+    /// let mut output_vector = ciphertext_vector.clone();
+    ///
+    /// engine.discard_permute_lwe_ciphertext_vector(&mut output_vector, &ciphertext_vector, &switches)?;
+    /// #
+    /// assert_eq!(output_vector.lwe_ciphertext_count(), ciphertext_vector.lwe_ciphertext_count());
+    /// # let decrypted = engine.decrypt_lwe_ciphertext_vector(&lwe_key, &output_vector)?;
+    /// # let output = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// # for (i, destination) in permutation.iter().enumerate() {
+    /// #     let error = output[*destination].wrapping_sub(input[i]);
+    /// #     assert!(error < 1 << 58 || error.wrapping_neg() < 1 << 58);
+    /// # }
+    /// # engine.destroy(decrypted)?;
+    ///
+    /// engine.destroy(glwe_key)?;
+    /// engine.destroy(lwe_key)?;
+    /// for switch in switches {
+    ///     engine.destroy(switch)?;
+    /// }
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_permute_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        switches: &[GgswCiphertext64],
+    ) -> Result<(), LweCiphertextVectorDiscardingPermutationError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingPermutationError::perform_generic_checks(
+            output, input, switches,
+        )?;
+        unsafe { self.discard_permute_lwe_ciphertext_vector_unchecked(output, input, switches) };
+        Ok(())
+    }
+
+    unsafe fn discard_permute_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        switches: &[GgswCiphertext64],
+    ) {
+        let switches: Vec<_> = switches.iter().map(|switch| &switch.0).collect();
+        permute_lwe_list(&mut output.0, &input.0, switches.as_slice());
+    }
+}
+
+// Evaluates the permutation network layer by layer. A GGSW ciphertext with a polynomial size of
+// one shares its layout with a GSW ciphertext, which is used to compute the CMUXes on the LWE
+// ciphertexts.
+fn permute_lwe_list<Scalar>(
+    output: &mut ImplLweList<Vec<Scalar>>,
+    input: &ImplLweList<Vec<Scalar>>,
+    switches: &[&ImplStandardGgswCiphertext<Vec<Scalar>>],
+) where
+    Scalar: UnsignedTorus,
+{
+    let lwe_size = input.lwe_size();
+    let count = LweCiphertextCount(input.count().0);
+    let mut current: Vec<_> = input
+        .ciphertext_iter()
+        .map(|ciphertext| {
+            ImplLweCiphertext::from_container(ciphertext.as_tensor().as_container().to_vec())
+        })
+        .collect();
+    let mut switches = switches.iter().map(|switch| {
+        ImplGswCiphertext::from_container(
+            switch.as_tensor().as_container().as_slice(),
+            lwe_size,
+            switch.decomposition_base_log(),
+        )
+    });
+    for layer in 0..permutation_network_layer_count(count) {
+        let mut next = current.clone();
+        for (first, second) in permutation_network_layer_pairs(count, layer) {
+            let switch = switches.next().unwrap();
+            switch.cmux(&mut next[first], &current[first], &current[second]);
+            switch.cmux(&mut next[second], &current[second], &current[first]);
+        }
+        current = next;
+    }
+    for (mut output, ciphertext) in output.ciphertext_iter_mut().zip(current.iter()) {
+        output
+            .as_mut_tensor()
+            .fill_with_copy(ciphertext.as_tensor());
+    }
+}
@@ -0,0 +1,287 @@
+use concrete_commons::parameters::MessageBitCount;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FourierLweBootstrapKey32, FourierLweBootstrapKey64, LweCiphertext32, LweCiphertext64,
+    LweCiphertextVector32, LweCiphertextVector64, LweKeyswitchKey32, LweKeyswitchKey64,
+};
+use crate::backends::core::private::crypto::bootstrap::{
+    discard_reduce_bits_lwe_list, BitReduction,
+};
+use crate::backends::core::private::math::fft::ALLOWED_POLY_SIZE;
+use crate::prelude::{CoreError, LweBootstrapKeyEntity};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingPopcountEngine, LweCiphertextVectorDiscardingPopcountError,
+};
+
+impl From<CoreError> for LweCiphertextVectorDiscardingPopcountError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingPopcountEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextVectorDiscardingPopcountEngine<
+        LweKeyswitchKey32,
+        FourierLweBootstrapKey32,
+        LweCiphertextVector32,
+        LweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     MessageBitCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(10), GlweDimension(1), PolynomialSize(1024));
+    /// let (bsk_lc, bsk_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let (ksk_lc, ksk_bl) = (DecompositionLevelCount(5), DecompositionBaseLog(3));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied: 3 bits of message, and 2 bits of padding.
+    /// let message_bits = MessageBitCount(3);
+    /// let input = vec![1_u32 << 27, 0, 1 << 27, 1 << 27, 0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, bsk_bl, bsk_lc, noise)?;
+    /// let big_lwe_sk: LweSecretKey32 = engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let ksk = engine.create_lwe_keyswitch_key(&big_lwe_sk, &lwe_sk, ksk_lc, ksk_bl, noise)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&big_lwe_sk, &plaintext_vector, noise)?;
+    /// let plaintext = engine.create_plaintext(&0_u32)?;
+    /// let mut output_ciphertext = engine.encrypt_lwe_ciphertext(&big_lwe_sk, &plaintext, noise)?;
+    ///
+    /// engine.discard_popcount_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext,
+    ///     &ciphertext_vector,
+    ///     &ksk,
+    ///     &bsk,
+    ///     message_bits,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.lwe_dimension(), big_lwe_sk.lwe_dimension());
+    /// # let output_plaintext = engine.decrypt_lwe_ciphertext(&big_lwe_sk, &output_ciphertext)?;
+    /// # let output: u32 = engine.retrieve_plaintext(&output_plaintext)?;
+    /// # assert_eq!(output.wrapping_add(1 << 26) >> 27, 3);
+    /// # assert!(matches!(
+    /// #     engine.discard_popcount_lwe_ciphertext_vector(
+    /// #         &mut output_ciphertext,
+    /// #         &ciphertext_vector,
+    /// #         &ksk,
+    /// #         &bsk,
+    /// #         MessageBitCount(2),
+    /// #     ),
+    /// #     Err(LweCiphertextVectorDiscardingPopcountError::CiphertextCountTooLarge)
+    /// # ));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(big_lwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(ksk)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_popcount_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertextVector32,
+        ksk: &LweKeyswitchKey32,
+        bsk: &FourierLweBootstrapKey32,
+        message_bits: MessageBitCount,
+    ) -> Result<(), LweCiphertextVectorDiscardingPopcountError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&bsk.polynomial_size().0) {
+            return Err(LweCiphertextVectorDiscardingPopcountError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
+        LweCiphertextVectorDiscardingPopcountError::perform_generic_checks(
+            output,
+            input,
+            ksk,
+            bsk,
+            message_bits,
+        )?;
+        unsafe {
+            self.discard_popcount_lwe_ciphertext_vector_unchecked(
+                output,
+                input,
+                ksk,
+                bsk,
+                message_bits,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_popcount_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertextVector32,
+        ksk: &LweKeyswitchKey32,
+        bsk: &FourierLweBootstrapKey32,
+        message_bits: MessageBitCount,
+    ) {
+        let buffers =
+            self.get_fourier_u32_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
+        discard_reduce_bits_lwe_list(
+            &mut output.0,
+            &input.0,
+            &ksk.0,
+            &bsk.0,
+            message_bits,
+            BitReduction::Popcount,
+            buffers,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingPopcountEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextVectorDiscardingPopcountEngine<
+        LweKeyswitchKey64,
+        FourierLweBootstrapKey64,
+        LweCiphertextVector64,
+        LweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     MessageBitCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(10), GlweDimension(1), PolynomialSize(1024));
+    /// let (bsk_lc, bsk_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let (ksk_lc, ksk_bl) = (DecompositionLevelCount(5), DecompositionBaseLog(3));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied: 3 bits of message, and 2 bits of padding.
+    /// let message_bits = MessageBitCount(3);
+    /// let input = vec![1_u64 << 59, 0, 1 << 59, 1 << 59, 0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, bsk_bl, bsk_lc, noise)?;
+    /// let big_lwe_sk: LweSecretKey64 = engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let ksk = engine.create_lwe_keyswitch_key(&big_lwe_sk, &lwe_sk, ksk_lc, ksk_bl, noise)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&big_lwe_sk, &plaintext_vector, noise)?;
+    /// let plaintext = engine.create_plaintext(&0_u64)?;
+    /// let mut output_ciphertext = engine.encrypt_lwe_ciphertext(&big_lwe_sk, &plaintext, noise)?;
+    ///
+    /// engine.discard_popcount_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext,
+    ///     &ciphertext_vector,
+    ///     &ksk,
+    ///     &bsk,
+    ///     message_bits,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.lwe_dimension(), big_lwe_sk.lwe_dimension());
+    /// # let output_plaintext = engine.decrypt_lwe_ciphertext(&big_lwe_sk, &output_ciphertext)?;
+    /// # let output: u64 = engine.retrieve_plaintext(&output_plaintext)?;
+    /// # assert_eq!(output.wrapping_add(1 << 58) >> 59, 3);
+    /// # assert!(matches!(
+    /// #     engine.discard_popcount_lwe_ciphertext_vector(
+    /// #         &mut output_ciphertext,
+    /// #         &ciphertext_vector,
+    /// #         &ksk,
+    /// #         &bsk,
+    /// #         MessageBitCount(2),
+    /// #     ),
+    /// #     Err(LweCiphertextVectorDiscardingPopcountError::CiphertextCountTooLarge)
+    /// # ));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(big_lwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(ksk)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_popcount_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertextVector64,
+        ksk: &LweKeyswitchKey64,
+        bsk: &FourierLweBootstrapKey64,
+        message_bits: MessageBitCount,
+    ) -> Result<(), LweCiphertextVectorDiscardingPopcountError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&bsk.polynomial_size().0) {
+            return Err(LweCiphertextVectorDiscardingPopcountError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
+        LweCiphertextVectorDiscardingPopcountError::perform_generic_checks(
+            output,
+            input,
+            ksk,
+            bsk,
+            message_bits,
+        )?;
+        unsafe {
+            self.discard_popcount_lwe_ciphertext_vector_unchecked(
+                output,
+                input,
+                ksk,
+                bsk,
+                message_bits,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_popcount_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertextVector64,
+        ksk: &LweKeyswitchKey64,
+        bsk: &FourierLweBootstrapKey64,
+        message_bits: MessageBitCount,
+    ) {
+        let buffers =
+            self.get_fourier_u64_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
+        discard_reduce_bits_lwe_list(
+            &mut output.0,
+            &input.0,
+            &ksk.0,
+            &bsk.0,
+            message_bits,
+            BitReduction::Popcount,
+            buffers,
+        );
+    }
+}
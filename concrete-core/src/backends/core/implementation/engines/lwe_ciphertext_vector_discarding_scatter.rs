@@ -0,0 +1,151 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingScatterEngine, LweCiphertextVectorDiscardingScatterError,
+};
+use concrete_commons::parameters::LweCiphertextIndex;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingScatterEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl LweCiphertextVectorDiscardingScatterEngine<LweCiphertextVector32, LweCiphertextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![1_u32 << 20, 2 << 20];
+    /// let output = vec![0_u32; 4];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The input ciphertexts are written at the last and second positions of the output.
+    /// let indices = vec![LweCiphertextIndex(3), LweCiphertextIndex(1)];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let input_plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let output_plaintext_vector = engine.create_plaintext_vector(&output)?;
+    /// let input_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &input_plaintext_vector, noise)?;
+    /// let mut output_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &output_plaintext_vector, noise)?;
+    ///
+    /// engine.discard_scatter_lwe_ciphertext_vector(&mut output_vector, &input_vector, &indices)?;
+    /// #
+    /// assert_eq!(output_vector.lwe_ciphertext_count(), LweCiphertextCount(4));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(input_plaintext_vector)?;
+    /// engine.destroy(output_plaintext_vector)?;
+    /// engine.destroy(input_vector)?;
+    /// engine.destroy(output_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_scatter_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        indices: &[LweCiphertextIndex],
+    ) -> Result<(), LweCiphertextVectorDiscardingScatterError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingScatterError::perform_generic_checks(output, input, indices)?;
+        unsafe { self.discard_scatter_lwe_ciphertext_vector_unchecked(output, input, indices) };
+        Ok(())
+    }
+
+    unsafe fn discard_scatter_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        indices: &[LweCiphertextIndex],
+    ) {
+        let mut output_ciphertexts: Vec<_> = output.0.ciphertext_iter_mut().collect();
+        for (input_ciphertext, index) in input.0.ciphertext_iter().zip(indices.iter()) {
+            output_ciphertexts[index.0]
+                .as_mut_tensor()
+                .fill_with_copy(input_ciphertext.as_tensor());
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingScatterEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl LweCiphertextVectorDiscardingScatterEngine<LweCiphertextVector64, LweCiphertextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![1_u64 << 50, 2 << 50];
+    /// let output = vec![0_u64; 4];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The input ciphertexts are written at the last and second positions of the output.
+    /// let indices = vec![LweCiphertextIndex(3), LweCiphertextIndex(1)];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let input_plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let output_plaintext_vector = engine.create_plaintext_vector(&output)?;
+    /// let input_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &input_plaintext_vector, noise)?;
+    /// let mut output_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &output_plaintext_vector, noise)?;
+    ///
+    /// engine.discard_scatter_lwe_ciphertext_vector(&mut output_vector, &input_vector, &indices)?;
+    /// #
+    /// assert_eq!(output_vector.lwe_ciphertext_count(), LweCiphertextCount(4));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(input_plaintext_vector)?;
+    /// engine.destroy(output_plaintext_vector)?;
+    /// engine.destroy(input_vector)?;
+    /// engine.destroy(output_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_scatter_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        indices: &[LweCiphertextIndex],
+    ) -> Result<(), LweCiphertextVectorDiscardingScatterError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingScatterError::perform_generic_checks(output, input, indices)?;
+        unsafe { self.discard_scatter_lwe_ciphertext_vector_unchecked(output, input, indices) };
+        Ok(())
+    }
+
+    unsafe fn discard_scatter_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        indices: &[LweCiphertextIndex],
+    ) {
+        let mut output_ciphertexts: Vec<_> = output.0.ciphertext_iter_mut().collect();
+        for (input_ciphertext, index) in input.0.ciphertext_iter().zip(indices.iter()) {
+            output_ciphertexts[index.0]
+                .as_mut_tensor()
+                .fill_with_copy(input_ciphertext.as_tensor());
+        }
+    }
+}
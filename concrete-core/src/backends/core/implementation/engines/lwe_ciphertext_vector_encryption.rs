@@ -74,7 +74,7 @@ impl LweCiphertextVectorEncryptionEngine<LweSecretKey32, PlaintextVector32, LweC
         );
         key.0
             .encrypt_lwe_list(&mut vector, &input.0, noise, &mut self.encryption_generator);
-        LweCiphertextVector32(vector)
+        self.audit_creation(LweCiphertextVector32(vector))
     }
 }
 
@@ -140,6 +140,6 @@ impl LweCiphertextVectorEncryptionEngine<LweSecretKey64, PlaintextVector64, LweC
         );
         key.0
             .encrypt_lwe_list(&mut vector, &input.0, noise, &mut self.encryption_generator);
-        LweCiphertextVector64(vector)
+        self.audit_creation(LweCiphertextVector64(vector))
     }
 }
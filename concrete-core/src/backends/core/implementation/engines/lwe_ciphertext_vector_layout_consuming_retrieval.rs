@@ -0,0 +1,134 @@
+use concrete_commons::parameters::CiphertextLayout;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    AlignedLweCiphertextVector32, AlignedLweCiphertextVector64,
+};
+use crate::backends::core::memory::AlignedBuffer;
+use crate::backends::core::private::math::tensor::IntoTensor;
+use crate::specification::engines::{
+    LweCiphertextVectorLayoutConsumingRetrievalEngine,
+    LweCiphertextVectorLayoutConsumingRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorLayoutConsumingRetrievalEngine`] for [`CoreEngine`]
+/// which returns the [`AlignedBuffer`] of 32 bits integers of the vector, reordering its
+/// coefficients in place from the [`CiphertextLayout::MaskFirst`] layout of the `core` backend.
+impl
+    LweCiphertextVectorLayoutConsumingRetrievalEngine<
+        AlignedLweCiphertextVector32,
+        AlignedBuffer<u32>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextLayout, LweSize};
+    /// use concrete_core::backends::core::memory::{AlignedBuffer, Alignment};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Two ciphertexts of dimension 2, laid out as (a_0, a_1, b).
+    /// let buffer = AlignedBuffer::from_slice(&[1_u32, 2, 3, 4, 5, 6], Alignment::SIMD_256);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_vector: AlignedLweCiphertextVector32 =
+    ///     engine.create_lwe_ciphertext_vector(buffer, LweSize(3))?;
+    /// let buffer: AlignedBuffer<u32> = engine.consume_retrieve_lwe_ciphertext_vector_with_layout(
+    ///     ciphertext_vector,
+    ///     CiphertextLayout::BodyFirst,
+    /// )?;
+    /// #
+    /// assert_eq!(buffer.as_slice(), &[3, 1, 2, 6, 4, 5]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_ciphertext_vector_with_layout(
+        &mut self,
+        ciphertext_vector: AlignedLweCiphertextVector32,
+        layout: CiphertextLayout,
+    ) -> Result<
+        AlignedBuffer<u32>,
+        LweCiphertextVectorLayoutConsumingRetrievalError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.consume_retrieve_lwe_ciphertext_vector_with_layout_unchecked(
+                ciphertext_vector,
+                layout,
+            )
+        })
+    }
+
+    unsafe fn consume_retrieve_lwe_ciphertext_vector_with_layout_unchecked(
+        &mut self,
+        ciphertext_vector: AlignedLweCiphertextVector32,
+        layout: CiphertextLayout,
+    ) -> AlignedBuffer<u32> {
+        let mut list = ciphertext_vector.0;
+        list.reorder_layout(CiphertextLayout::MaskFirst, layout);
+        list.into_tensor().into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorLayoutConsumingRetrievalEngine`] for [`CoreEngine`]
+/// which returns the [`AlignedBuffer`] of 64 bits integers of the vector, reordering its
+/// coefficients in place from the [`CiphertextLayout::MaskFirst`] layout of the `core` backend.
+impl
+    LweCiphertextVectorLayoutConsumingRetrievalEngine<
+        AlignedLweCiphertextVector64,
+        AlignedBuffer<u64>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextLayout, LweSize};
+    /// use concrete_core::backends::core::memory::{AlignedBuffer, Alignment};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Two ciphertexts of dimension 2, laid out as (a_0, a_1, b).
+    /// let buffer = AlignedBuffer::from_slice(&[1_u64, 2, 3, 4, 5, 6], Alignment::SIMD_256);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_vector: AlignedLweCiphertextVector64 =
+    ///     engine.create_lwe_ciphertext_vector(buffer, LweSize(3))?;
+    /// let buffer: AlignedBuffer<u64> = engine.consume_retrieve_lwe_ciphertext_vector_with_layout(
+    ///     ciphertext_vector,
+    ///     CiphertextLayout::BodyFirst,
+    /// )?;
+    /// #
+    /// assert_eq!(buffer.as_slice(), &[3, 1, 2, 6, 4, 5]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_ciphertext_vector_with_layout(
+        &mut self,
+        ciphertext_vector: AlignedLweCiphertextVector64,
+        layout: CiphertextLayout,
+    ) -> Result<
+        AlignedBuffer<u64>,
+        LweCiphertextVectorLayoutConsumingRetrievalError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.consume_retrieve_lwe_ciphertext_vector_with_layout_unchecked(
+                ciphertext_vector,
+                layout,
+            )
+        })
+    }
+
+    unsafe fn consume_retrieve_lwe_ciphertext_vector_with_layout_unchecked(
+        &mut self,
+        ciphertext_vector: AlignedLweCiphertextVector64,
+        layout: CiphertextLayout,
+    ) -> AlignedBuffer<u64> {
+        let mut list = ciphertext_vector.0;
+        list.reorder_layout(CiphertextLayout::MaskFirst, layout);
+        list.into_tensor().into_container()
+    }
+}
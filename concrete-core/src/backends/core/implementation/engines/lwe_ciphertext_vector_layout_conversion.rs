@@ -0,0 +1,140 @@
+use concrete_commons::parameters::{CiphertextLayout, LweSize};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::private::crypto::lwe::LweList as ImplLweList;
+use crate::backends::core::private::math::tensor::IntoTensor;
+use crate::specification::engines::{
+    LweCiphertextVectorLayoutConversionEngine, LweCiphertextVectorLayoutConversionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorLayoutConversionEngine`] for [`CoreEngine`] which
+/// reorders the coefficients of a vector of 32 bits integers in place.
+impl LweCiphertextVectorLayoutConversionEngine<Vec<u32>, Vec<u32>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextLayout, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Two ciphertexts of dimension 3, laid out as (b, a_0, a_1, a_2).
+    /// let body_first = vec![9_u32, 1, 2, 3, 8, 4, 5, 6];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let mask_first: Vec<u32> = engine.convert_lwe_ciphertext_vector_layout(
+    ///     body_first.clone(),
+    ///     LweSize(4),
+    ///     CiphertextLayout::BodyFirst,
+    ///     CiphertextLayout::MaskFirst,
+    /// )?;
+    /// #
+    /// assert_eq!(mask_first, vec![1, 2, 3, 9, 4, 5, 6, 8]);
+    ///
+    /// let round_trip: Vec<u32> = engine.convert_lwe_ciphertext_vector_layout(
+    ///     mask_first,
+    ///     LweSize(4),
+    ///     CiphertextLayout::MaskFirst,
+    ///     CiphertextLayout::BodyFirst,
+    /// )?;
+    /// assert_eq!(round_trip, body_first);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext_vector_layout(
+        &mut self,
+        input: Vec<u32>,
+        lwe_size: LweSize,
+        input_layout: CiphertextLayout,
+        output_layout: CiphertextLayout,
+    ) -> Result<Vec<u32>, LweCiphertextVectorLayoutConversionError<Self::EngineError>> {
+        LweCiphertextVectorLayoutConversionError::perform_generic_checks(input.len(), lwe_size)?;
+        Ok(unsafe {
+            self.convert_lwe_ciphertext_vector_layout_unchecked(
+                input,
+                lwe_size,
+                input_layout,
+                output_layout,
+            )
+        })
+    }
+
+    unsafe fn convert_lwe_ciphertext_vector_layout_unchecked(
+        &mut self,
+        input: Vec<u32>,
+        lwe_size: LweSize,
+        input_layout: CiphertextLayout,
+        output_layout: CiphertextLayout,
+    ) -> Vec<u32> {
+        let mut list = ImplLweList::from_container(input, lwe_size);
+        list.reorder_layout(input_layout, output_layout);
+        list.into_tensor().into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorLayoutConversionEngine`] for [`CoreEngine`] which
+/// reorders the coefficients of a vector of 64 bits integers in place.
+impl LweCiphertextVectorLayoutConversionEngine<Vec<u64>, Vec<u64>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextLayout, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Two ciphertexts of dimension 3, laid out as (b, a_0, a_1, a_2).
+    /// let body_first = vec![9_u64, 1, 2, 3, 8, 4, 5, 6];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let mask_first: Vec<u64> = engine.convert_lwe_ciphertext_vector_layout(
+    ///     body_first.clone(),
+    ///     LweSize(4),
+    ///     CiphertextLayout::BodyFirst,
+    ///     CiphertextLayout::MaskFirst,
+    /// )?;
+    /// #
+    /// assert_eq!(mask_first, vec![1, 2, 3, 9, 4, 5, 6, 8]);
+    ///
+    /// let round_trip: Vec<u64> = engine.convert_lwe_ciphertext_vector_layout(
+    ///     mask_first,
+    ///     LweSize(4),
+    ///     CiphertextLayout::MaskFirst,
+    ///     CiphertextLayout::BodyFirst,
+    /// )?;
+    /// assert_eq!(round_trip, body_first);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext_vector_layout(
+        &mut self,
+        input: Vec<u64>,
+        lwe_size: LweSize,
+        input_layout: CiphertextLayout,
+        output_layout: CiphertextLayout,
+    ) -> Result<Vec<u64>, LweCiphertextVectorLayoutConversionError<Self::EngineError>> {
+        LweCiphertextVectorLayoutConversionError::perform_generic_checks(input.len(), lwe_size)?;
+        Ok(unsafe {
+            self.convert_lwe_ciphertext_vector_layout_unchecked(
+                input,
+                lwe_size,
+                input_layout,
+                output_layout,
+            )
+        })
+    }
+
+    unsafe fn convert_lwe_ciphertext_vector_layout_unchecked(
+        &mut self,
+        input: Vec<u64>,
+        lwe_size: LweSize,
+        input_layout: CiphertextLayout,
+        output_layout: CiphertextLayout,
+    ) -> Vec<u64> {
+        let mut list = ImplLweList::from_container(input, lwe_size);
+        list.reorder_layout(input_layout, output_layout);
+        list.into_tensor().into_container()
+    }
+}
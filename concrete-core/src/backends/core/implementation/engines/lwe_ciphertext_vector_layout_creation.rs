@@ -0,0 +1,259 @@
+use concrete_commons::parameters::{CiphertextLayout, LweSize};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    AlignedLweCiphertextVector32, AlignedLweCiphertextVector64,
+};
+use crate::backends::core::memory::AlignedBuffer;
+use crate::backends::core::private::crypto::lwe::LweList as ImplLweList;
+use crate::specification::engines::{
+    LweCiphertextVectorLayoutCreationEngine, LweCiphertextVectorLayoutCreationError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorLayoutCreationEngine`] for [`CoreEngine`] which wraps
+/// an [`AlignedBuffer`] of 32 bits integers, reordering its coefficients in place to the
+/// [`CiphertextLayout::MaskFirst`] layout of the `core` backend.
+impl LweCiphertextVectorLayoutCreationEngine<AlignedBuffer<u32>, AlignedLweCiphertextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextLayout, LweCiphertextCount, LweSize};
+    /// use concrete_core::backends::core::memory::{AlignedBuffer, Alignment};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Two ciphertexts of dimension 2, laid out as (b, a_0, a_1).
+    /// let buffer = AlignedBuffer::from_slice(&[3_u32, 1, 2, 6, 4, 5], Alignment::SIMD_256);
+    /// let layout = CiphertextLayout::BodyFirst;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_vector: AlignedLweCiphertextVector32 =
+    ///     engine.create_lwe_ciphertext_vector_with_layout(buffer, LweSize(3), layout)?;
+    /// #
+    /// let count = ciphertext_vector.lwe_ciphertext_count();
+    /// assert_eq!(count, LweCiphertextCount(2));
+    ///
+    /// let buffer: AlignedBuffer<u32> =
+    ///     engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// assert_eq!(buffer.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector_with_layout(
+        &mut self,
+        container: AlignedBuffer<u32>,
+        lwe_size: LweSize,
+        layout: CiphertextLayout,
+    ) -> Result<
+        AlignedLweCiphertextVector32,
+        LweCiphertextVectorLayoutCreationError<Self::EngineError>,
+    > {
+        LweCiphertextVectorLayoutCreationError::perform_generic_checks(container.len(), lwe_size)?;
+        Ok(unsafe {
+            self.create_lwe_ciphertext_vector_with_layout_unchecked(container, lwe_size, layout)
+        })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_with_layout_unchecked(
+        &mut self,
+        container: AlignedBuffer<u32>,
+        lwe_size: LweSize,
+        layout: CiphertextLayout,
+    ) -> AlignedLweCiphertextVector32 {
+        let mut list = ImplLweList::from_container(container, lwe_size);
+        list.reorder_layout(layout, CiphertextLayout::MaskFirst);
+        AlignedLweCiphertextVector32(list)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorLayoutCreationEngine`] for [`CoreEngine`] which wraps
+/// an [`AlignedBuffer`] of 64 bits integers, reordering its coefficients in place to the
+/// [`CiphertextLayout::MaskFirst`] layout of the `core` backend.
+impl LweCiphertextVectorLayoutCreationEngine<AlignedBuffer<u64>, AlignedLweCiphertextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextLayout, LweCiphertextCount, LweSize};
+    /// use concrete_core::backends::core::memory::{AlignedBuffer, Alignment};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Two ciphertexts of dimension 2, laid out as (b, a_0, a_1).
+    /// let buffer = AlignedBuffer::from_slice(&[3_u64, 1, 2, 6, 4, 5], Alignment::SIMD_256);
+    /// let layout = CiphertextLayout::BodyFirst;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_vector: AlignedLweCiphertextVector64 =
+    ///     engine.create_lwe_ciphertext_vector_with_layout(buffer, LweSize(3), layout)?;
+    /// #
+    /// let count = ciphertext_vector.lwe_ciphertext_count();
+    /// assert_eq!(count, LweCiphertextCount(2));
+    ///
+    /// let buffer: AlignedBuffer<u64> =
+    ///     engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// assert_eq!(buffer.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector_with_layout(
+        &mut self,
+        container: AlignedBuffer<u64>,
+        lwe_size: LweSize,
+        layout: CiphertextLayout,
+    ) -> Result<
+        AlignedLweCiphertextVector64,
+        LweCiphertextVectorLayoutCreationError<Self::EngineError>,
+    > {
+        LweCiphertextVectorLayoutCreationError::perform_generic_checks(container.len(), lwe_size)?;
+        Ok(unsafe {
+            self.create_lwe_ciphertext_vector_with_layout_unchecked(container, lwe_size, layout)
+        })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_with_layout_unchecked(
+        &mut self,
+        container: AlignedBuffer<u64>,
+        lwe_size: LweSize,
+        layout: CiphertextLayout,
+    ) -> AlignedLweCiphertextVector64 {
+        let mut list = ImplLweList::from_container(container, lwe_size);
+        list.reorder_layout(layout, CiphertextLayout::MaskFirst);
+        AlignedLweCiphertextVector64(list)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::backends::core::memory::{AlignedBuffer, Alignment};
+    use crate::prelude::*;
+    use concrete_commons::parameters::{CiphertextLayout, LweSize};
+    use std::convert::TryInto;
+
+    // Two ciphertexts of dimension 2, the body-first vectors holding the little endian bytes of
+    // consecutive words, e.g. (b, a_0, a_1) = (0x03020100, 0x07060504, 0x0b0a0908) on 32 bits.
+    // The vectors are built by hand to pin the order of the coefficients and the byte order of the
+    // containers, they do not come from another implementation.
+    const LWE_SIZE: LweSize = LweSize(3);
+    const BODY_FIRST_LWE32: [u8; 24] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+    ];
+    const MASK_FIRST_LWE32: [u8; 24] = [
+        0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x00, 0x01, 0x02, 0x03, 0x10, 0x11, 0x12,
+        0x13, 0x14, 0x15, 0x16, 0x17, 0x0c, 0x0d, 0x0e, 0x0f,
+    ];
+    const BODY_FIRST_LWE64: [u8; 48] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d,
+        0x1e, 0x1f, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c,
+        0x2d, 0x2e, 0x2f,
+    ];
+    const MASK_FIRST_LWE64: [u8; 48] = [
+        0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16,
+        0x17, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25,
+        0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d, 0x2e, 0x2f, 0x18, 0x19, 0x1a, 0x1b, 0x1c,
+        0x1d, 0x1e, 0x1f,
+    ];
+
+    fn words_32(bytes: &[u8]) -> Vec<u32> {
+        bytes
+            .chunks_exact(4)
+            .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect()
+    }
+
+    fn words_64(bytes: &[u8]) -> Vec<u64> {
+        bytes
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+            .collect()
+    }
+
+    fn bytes_32(words: &[u32]) -> Vec<u8> {
+        words.iter().flat_map(|word| word.to_le_bytes()).collect()
+    }
+
+    fn bytes_64(words: &[u64]) -> Vec<u8> {
+        words.iter().flat_map(|word| word.to_le_bytes()).collect()
+    }
+
+    #[test]
+    fn test_lwe_ciphertext_vector_layout_bytes_32() {
+        let mut engine = CoreEngine::new().unwrap();
+        let body_first =
+            AlignedBuffer::from_slice(&words_32(&BODY_FIRST_LWE32), Alignment::SIMD_256);
+        let vector: AlignedLweCiphertextVector32 = engine
+            .create_lwe_ciphertext_vector_with_layout(
+                body_first,
+                LWE_SIZE,
+                CiphertextLayout::BodyFirst,
+            )
+            .unwrap();
+        let mask_first: AlignedBuffer<u32> = engine
+            .consume_retrieve_lwe_ciphertext_vector(vector)
+            .unwrap();
+        assert_eq!(bytes_32(mask_first.as_slice()), MASK_FIRST_LWE32);
+
+        let vector: AlignedLweCiphertextVector32 = engine
+            .create_lwe_ciphertext_vector(mask_first, LWE_SIZE)
+            .unwrap();
+        let body_first: AlignedBuffer<u32> = engine
+            .consume_retrieve_lwe_ciphertext_vector_with_layout(vector, CiphertextLayout::BodyFirst)
+            .unwrap();
+        assert_eq!(bytes_32(body_first.as_slice()), BODY_FIRST_LWE32);
+
+        let mask_first: Vec<u32> = engine
+            .convert_lwe_ciphertext_vector_layout(
+                words_32(&BODY_FIRST_LWE32),
+                LWE_SIZE,
+                CiphertextLayout::BodyFirst,
+                CiphertextLayout::MaskFirst,
+            )
+            .unwrap();
+        assert_eq!(bytes_32(&mask_first), MASK_FIRST_LWE32);
+    }
+
+    #[test]
+    fn test_lwe_ciphertext_vector_layout_bytes_64() {
+        let mut engine = CoreEngine::new().unwrap();
+        let body_first =
+            AlignedBuffer::from_slice(&words_64(&BODY_FIRST_LWE64), Alignment::SIMD_256);
+        let vector: AlignedLweCiphertextVector64 = engine
+            .create_lwe_ciphertext_vector_with_layout(
+                body_first,
+                LWE_SIZE,
+                CiphertextLayout::BodyFirst,
+            )
+            .unwrap();
+        let mask_first: AlignedBuffer<u64> = engine
+            .consume_retrieve_lwe_ciphertext_vector(vector)
+            .unwrap();
+        assert_eq!(bytes_64(mask_first.as_slice()), MASK_FIRST_LWE64);
+
+        let vector: AlignedLweCiphertextVector64 = engine
+            .create_lwe_ciphertext_vector(mask_first, LWE_SIZE)
+            .unwrap();
+        let body_first: AlignedBuffer<u64> = engine
+            .consume_retrieve_lwe_ciphertext_vector_with_layout(vector, CiphertextLayout::BodyFirst)
+            .unwrap();
+        assert_eq!(bytes_64(body_first.as_slice()), BODY_FIRST_LWE64);
+
+        let mask_first: Vec<u64> = engine
+            .convert_lwe_ciphertext_vector_layout(
+                words_64(&BODY_FIRST_LWE64),
+                LWE_SIZE,
+                CiphertextLayout::BodyFirst,
+                CiphertextLayout::MaskFirst,
+            )
+            .unwrap();
+        assert_eq!(bytes_64(&mask_first), MASK_FIRST_LWE64);
+    }
+}
@@ -0,0 +1,199 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::{CastInto, Numeric};
+use concrete_commons::parameters::{LweCiphertextCount, MessageBitCount, PlaintextCount};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweSecretKey32, LweSecretKey64,
+};
+use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::specification::engines::{
+    LweCiphertextVectorNoiseStatisticsEngine, LweCiphertextVectorNoiseStatisticsError,
+    NoiseStatistics,
+};
+use crate::specification::entities::LweCiphertextVectorEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorNoiseStatisticsEngine`] for [`CoreEngine`] that operates
+/// on 32 bits integers.
+impl LweCiphertextVectorNoiseStatisticsEngine<LweSecretKey32, LweCiphertextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension, MessageBitCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here the messages are encoded on the 4 MSB.
+    /// let message_bits = MessageBitCount(4);
+    /// let input = vec![3_u32 << 28; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let statistics = engine.compute_lwe_ciphertext_vector_noise_statistics(
+    ///     &key,
+    ///     &ciphertext_vector,
+    ///     message_bits,
+    /// )?;
+    ///
+    /// assert_eq!(statistics.sample_count, LweCiphertextCount(18));
+    /// assert!(statistics.budget_consumed < 1.);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compute_lwe_ciphertext_vector_noise_statistics(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextVector32,
+        message_bits: MessageBitCount,
+    ) -> Result<NoiseStatistics, LweCiphertextVectorNoiseStatisticsError<Self::EngineError>> {
+        LweCiphertextVectorNoiseStatisticsError::perform_generic_checks(
+            key,
+            input,
+            message_bits,
+            32,
+        )?;
+        Ok(unsafe {
+            self.compute_lwe_ciphertext_vector_noise_statistics_unchecked(key, input, message_bits)
+        })
+    }
+
+    unsafe fn compute_lwe_ciphertext_vector_noise_statistics_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextVector32,
+        message_bits: MessageBitCount,
+    ) -> NoiseStatistics {
+        let mut phases =
+            ImplPlaintextList::allocate(0u32, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_lwe_list(&mut phases, &input.0);
+        compute_noise_statistics(phases.as_tensor().as_slice(), message_bits)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorNoiseStatisticsEngine`] for [`CoreEngine`] that operates
+/// on 64 bits integers.
+impl LweCiphertextVectorNoiseStatisticsEngine<LweSecretKey64, LweCiphertextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension, MessageBitCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here the messages are encoded on the 4 MSB.
+    /// let message_bits = MessageBitCount(4);
+    /// let input = vec![3_u64 << 60; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let statistics = engine.compute_lwe_ciphertext_vector_noise_statistics(
+    ///     &key,
+    ///     &ciphertext_vector,
+    ///     message_bits,
+    /// )?;
+    ///
+    /// assert_eq!(statistics.sample_count, LweCiphertextCount(18));
+    /// assert!(statistics.budget_consumed < 1.);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compute_lwe_ciphertext_vector_noise_statistics(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextVector64,
+        message_bits: MessageBitCount,
+    ) -> Result<NoiseStatistics, LweCiphertextVectorNoiseStatisticsError<Self::EngineError>> {
+        LweCiphertextVectorNoiseStatisticsError::perform_generic_checks(
+            key,
+            input,
+            message_bits,
+            64,
+        )?;
+        Ok(unsafe {
+            self.compute_lwe_ciphertext_vector_noise_statistics_unchecked(key, input, message_bits)
+        })
+    }
+
+    unsafe fn compute_lwe_ciphertext_vector_noise_statistics_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextVector64,
+        message_bits: MessageBitCount,
+    ) -> NoiseStatistics {
+        let mut phases =
+            ImplPlaintextList::allocate(0u64, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_lwe_list(&mut phases, &input.0);
+        compute_noise_statistics(phases.as_tensor().as_slice(), message_bits)
+    }
+}
+
+// Computes the statistics of the distances between the `phases` and the closest multiples of
+// delta, without storing the distances.
+fn compute_noise_statistics<Scalar>(
+    phases: &[Scalar],
+    message_bits: MessageBitCount,
+) -> NoiseStatistics
+where
+    Scalar: UnsignedTorus,
+{
+    let delta_log = <Scalar as Numeric>::BITS - message_bits.0;
+    let half_delta = Scalar::ONE << (delta_log - 1);
+    let modulus = 2_f64.powi(<Scalar as Numeric>::BITS as i32);
+    let noise = |phase: &Scalar| -> f64 {
+        let closest = (phase.wrapping_add(half_delta) >> delta_log) << delta_log;
+        let noise: f64 = phase.wrapping_sub(closest).into_signed().cast_into();
+        noise / modulus
+    };
+    let count = phases.len() as f64;
+    let mean = phases.iter().map(noise).sum::<f64>() / count;
+    let variance = phases
+        .iter()
+        .map(|phase| (noise(phase) - mean).powi(2))
+        .sum::<f64>()
+        / count;
+    let max_deviation = phases
+        .iter()
+        .map(|phase| noise(phase).abs())
+        .fold(0., f64::max);
+    NoiseStatistics {
+        sample_count: LweCiphertextCount(phases.len()),
+        mean,
+        variance: Variance(variance),
+        max_deviation,
+        budget_consumed: max_deviation * 2_f64.powi(message_bits.0 as i32 + 1),
+    }
+}
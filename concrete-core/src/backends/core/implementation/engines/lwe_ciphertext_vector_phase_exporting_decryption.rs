@@ -0,0 +1,205 @@
+use concrete_commons::numeric::Numeric;
+use concrete_commons::parameters::{MessageBitCount, PlaintextCount};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LwePhaseVector32, LwePhaseVector64,
+    LweSecretKey32, LweSecretKey64, PlaintextVector32, PlaintextVector64,
+};
+use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::specification::engines::{
+    LweCiphertextVectorPhaseExportingDecryptionEngine,
+    LweCiphertextVectorPhaseExportingDecryptionError,
+};
+use crate::specification::entities::LweCiphertextVectorEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorPhaseExportingDecryptionEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextVectorPhaseExportingDecryptionEngine<
+        LweSecretKey32,
+        LweCiphertextVector32,
+        PlaintextVector32,
+        LwePhaseVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension, MessageBitCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here the messages are encoded on the 4 MSB.
+    /// let message_bits = MessageBitCount(4);
+    /// let input = vec![3_u32 << 28; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let (decrypted_plaintext_vector, phase_vector): (PlaintextVector32, LwePhaseVector32) =
+    ///     engine.decrypt_lwe_ciphertext_vector_exporting_phases(
+    ///         &key,
+    ///         &ciphertext_vector,
+    ///         message_bits,
+    ///     )?;
+    ///
+    /// assert_eq!(phase_vector.phase_count(), LweCiphertextCount(18));
+    /// assert_eq!(engine.retrieve_plaintext_vector(&decrypted_plaintext_vector)?, input);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// engine.destroy(phase_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector_exporting_phases(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextVector32,
+        message_bits: MessageBitCount,
+    ) -> Result<
+        (PlaintextVector32, LwePhaseVector32),
+        LweCiphertextVectorPhaseExportingDecryptionError<Self::EngineError>,
+    > {
+        LweCiphertextVectorPhaseExportingDecryptionError::perform_generic_checks(
+            key,
+            input,
+            message_bits,
+            32,
+        )?;
+        Ok(unsafe {
+            self.decrypt_lwe_ciphertext_vector_exporting_phases_unchecked(key, input, message_bits)
+        })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_exporting_phases_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextVector32,
+        message_bits: MessageBitCount,
+    ) -> (PlaintextVector32, LwePhaseVector32) {
+        let mut phases =
+            ImplPlaintextList::allocate(0u32, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_lwe_list(&mut phases, &input.0);
+        let plaintexts = round_phases(&phases, message_bits);
+        (PlaintextVector32(plaintexts), LwePhaseVector32(phases))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorPhaseExportingDecryptionEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextVectorPhaseExportingDecryptionEngine<
+        LweSecretKey64,
+        LweCiphertextVector64,
+        PlaintextVector64,
+        LwePhaseVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension, MessageBitCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here the messages are encoded on the 4 MSB.
+    /// let message_bits = MessageBitCount(4);
+    /// let input = vec![3_u64 << 60; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let (decrypted_plaintext_vector, phase_vector): (PlaintextVector64, LwePhaseVector64) =
+    ///     engine.decrypt_lwe_ciphertext_vector_exporting_phases(
+    ///         &key,
+    ///         &ciphertext_vector,
+    ///         message_bits,
+    ///     )?;
+    ///
+    /// assert_eq!(phase_vector.phase_count(), LweCiphertextCount(18));
+    /// assert_eq!(engine.retrieve_plaintext_vector(&decrypted_plaintext_vector)?, input);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// engine.destroy(phase_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector_exporting_phases(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextVector64,
+        message_bits: MessageBitCount,
+    ) -> Result<
+        (PlaintextVector64, LwePhaseVector64),
+        LweCiphertextVectorPhaseExportingDecryptionError<Self::EngineError>,
+    > {
+        LweCiphertextVectorPhaseExportingDecryptionError::perform_generic_checks(
+            key,
+            input,
+            message_bits,
+            64,
+        )?;
+        Ok(unsafe {
+            self.decrypt_lwe_ciphertext_vector_exporting_phases_unchecked(key, input, message_bits)
+        })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_exporting_phases_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextVector64,
+        message_bits: MessageBitCount,
+    ) -> (PlaintextVector64, LwePhaseVector64) {
+        let mut phases =
+            ImplPlaintextList::allocate(0u64, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_lwe_list(&mut phases, &input.0);
+        let plaintexts = round_phases(&phases, message_bits);
+        (PlaintextVector64(plaintexts), LwePhaseVector64(phases))
+    }
+}
+
+// Rounds every phase to the closest multiple of delta.
+fn round_phases<Scalar>(
+    phases: &ImplPlaintextList<Vec<Scalar>>,
+    message_bits: MessageBitCount,
+) -> ImplPlaintextList<Vec<Scalar>>
+where
+    Scalar: UnsignedTorus,
+{
+    let delta_log = <Scalar as Numeric>::BITS - message_bits.0;
+    let half_delta = Scalar::ONE << (delta_log - 1);
+    let mut plaintexts = ImplPlaintextList::allocate(Scalar::ZERO, phases.count());
+    plaintexts
+        .as_mut_tensor()
+        .fill_with_one(phases.as_tensor(), |phase| {
+            (phase.wrapping_add(half_delta) >> delta_log) << delta_log
+        });
+    plaintexts
+}
@@ -0,0 +1,195 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, PlaintextVector32, PlaintextVector64,
+};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::specification::engines::{
+    LweCiphertextVectorPlaintextVectorDiscardingSubtractionEngine,
+    LweCiphertextVectorPlaintextVectorDiscardingSubtractionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorPlaintextVectorDiscardingSubtractionEngine`] for
+/// [`CoreEngine`] that operates on 32 bits integers.
+impl
+    LweCiphertextVectorPlaintextVectorDiscardingSubtractionEngine<
+        LweCiphertextVector32,
+        PlaintextVector32,
+        LweCiphertextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(3);
+    /// let input = vec![1_u32, 2, 3];
+    /// let subtracted = vec![2_u32; 3];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let subtracted_vector: PlaintextVector32 = engine.create_plaintext_vector(&subtracted)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.trivially_encrypt_lwe_ciphertext_vector(lwe_size, &input_vector)?;
+    /// let mut output_ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.trivially_encrypt_lwe_ciphertext_vector(lwe_size, &subtracted_vector)?;
+    ///
+    /// engine.discard_sub_lwe_ciphertext_vector_plaintext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &subtracted_vector,
+    /// )?;
+    ///
+    /// // The subtraction wraps around zero.
+    /// let output_vector: PlaintextVector32 =
+    ///     engine.trivially_decrypt_lwe_ciphertext_vector(&output_ciphertext_vector)?;
+    /// assert_eq!(
+    ///     engine.retrieve_plaintext_vector(&output_vector)?,
+    ///     vec![u32::MAX, 0, 1]
+    /// );
+    ///
+    /// engine.destroy(input_vector)?;
+    /// engine.destroy(subtracted_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// engine.destroy(output_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_sub_lwe_ciphertext_vector_plaintext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input_1: &LweCiphertextVector32,
+        input_2: &PlaintextVector32,
+    ) -> Result<(), LweCiphertextVectorPlaintextVectorDiscardingSubtractionError<Self::EngineError>>
+    {
+        LweCiphertextVectorPlaintextVectorDiscardingSubtractionError::perform_generic_checks(
+            output, input_1, input_2,
+        )?;
+        unsafe {
+            self.discard_sub_lwe_ciphertext_vector_plaintext_vector_unchecked(
+                output, input_1, input_2,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_sub_lwe_ciphertext_vector_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input_1: &LweCiphertextVector32,
+        input_2: &PlaintextVector32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input_1.0.as_tensor());
+        for (mut ciphertext, plaintext) in output
+            .0
+            .ciphertext_iter_mut()
+            .zip(input_2.0.plaintext_iter())
+        {
+            let body = ciphertext.get_mut_body();
+            body.0 = body.0.wrapping_sub(plaintext.0);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorPlaintextVectorDiscardingSubtractionEngine`] for
+/// [`CoreEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextVectorPlaintextVectorDiscardingSubtractionEngine<
+        LweCiphertextVector64,
+        PlaintextVector64,
+        LweCiphertextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(3);
+    /// let input = vec![1_u64, 2, 3];
+    /// let subtracted = vec![2_u64; 3];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let subtracted_vector: PlaintextVector64 = engine.create_plaintext_vector(&subtracted)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.trivially_encrypt_lwe_ciphertext_vector(lwe_size, &input_vector)?;
+    /// let mut output_ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.trivially_encrypt_lwe_ciphertext_vector(lwe_size, &subtracted_vector)?;
+    ///
+    /// engine.discard_sub_lwe_ciphertext_vector_plaintext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &subtracted_vector,
+    /// )?;
+    ///
+    /// // The subtraction wraps around zero.
+    /// let output_vector: PlaintextVector64 =
+    ///     engine.trivially_decrypt_lwe_ciphertext_vector(&output_ciphertext_vector)?;
+    /// assert_eq!(
+    ///     engine.retrieve_plaintext_vector(&output_vector)?,
+    ///     vec![u64::MAX, 0, 1]
+    /// );
+    ///
+    /// engine.destroy(input_vector)?;
+    /// engine.destroy(subtracted_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// engine.destroy(output_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_sub_lwe_ciphertext_vector_plaintext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input_1: &LweCiphertextVector64,
+        input_2: &PlaintextVector64,
+    ) -> Result<(), LweCiphertextVectorPlaintextVectorDiscardingSubtractionError<Self::EngineError>>
+    {
+        LweCiphertextVectorPlaintextVectorDiscardingSubtractionError::perform_generic_checks(
+            output, input_1, input_2,
+        )?;
+        unsafe {
+            self.discard_sub_lwe_ciphertext_vector_plaintext_vector_unchecked(
+                output, input_1, input_2,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_sub_lwe_ciphertext_vector_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input_1: &LweCiphertextVector64,
+        input_2: &PlaintextVector64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input_1.0.as_tensor());
+        for (mut ciphertext, plaintext) in output
+            .0
+            .ciphertext_iter_mut()
+            .zip(input_2.0.plaintext_iter())
+        {
+            let body = ciphertext.get_mut_body();
+            body.0 = body.0.wrapping_sub(plaintext.0);
+        }
+    }
+}
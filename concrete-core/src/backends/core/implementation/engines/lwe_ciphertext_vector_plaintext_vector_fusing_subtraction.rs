@@ -0,0 +1,160 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, PlaintextVector32, PlaintextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorPlaintextVectorFusingSubtractionEngine,
+    LweCiphertextVectorPlaintextVectorFusingSubtractionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorPlaintextVectorFusingSubtractionEngine`] for
+/// [`CoreEngine`] that operates on 32 bits integers.
+impl
+    LweCiphertextVectorPlaintextVectorFusingSubtractionEngine<
+        LweCiphertextVector32,
+        PlaintextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(3);
+    /// let input = vec![1_u32, 2, 3];
+    /// let subtracted = vec![2_u32; 3];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let subtracted_vector: PlaintextVector32 = engine.create_plaintext_vector(&subtracted)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let mut ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.trivially_encrypt_lwe_ciphertext_vector(lwe_size, &input_vector)?;
+    ///
+    /// engine.fuse_sub_lwe_ciphertext_vector_plaintext_vector(
+    ///     &mut ciphertext_vector,
+    ///     &subtracted_vector,
+    /// )?;
+    ///
+    /// // The subtraction wraps around zero.
+    /// let output_vector: PlaintextVector32 =
+    ///     engine.trivially_decrypt_lwe_ciphertext_vector(&ciphertext_vector)?;
+    /// assert_eq!(
+    ///     engine.retrieve_plaintext_vector(&output_vector)?,
+    ///     vec![u32::MAX, 0, 1]
+    /// );
+    ///
+    /// engine.destroy(input_vector)?;
+    /// engine.destroy(subtracted_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_sub_lwe_ciphertext_vector_plaintext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &PlaintextVector32,
+    ) -> Result<(), LweCiphertextVectorPlaintextVectorFusingSubtractionError<Self::EngineError>>
+    {
+        LweCiphertextVectorPlaintextVectorFusingSubtractionError::perform_generic_checks(
+            output, input,
+        )?;
+        unsafe { self.fuse_sub_lwe_ciphertext_vector_plaintext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_sub_lwe_ciphertext_vector_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &PlaintextVector32,
+    ) {
+        for (mut ciphertext, plaintext) in
+            output.0.ciphertext_iter_mut().zip(input.0.plaintext_iter())
+        {
+            let body = ciphertext.get_mut_body();
+            body.0 = body.0.wrapping_sub(plaintext.0);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorPlaintextVectorFusingSubtractionEngine`] for
+/// [`CoreEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextVectorPlaintextVectorFusingSubtractionEngine<
+        LweCiphertextVector64,
+        PlaintextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(3);
+    /// let input = vec![1_u64, 2, 3];
+    /// let subtracted = vec![2_u64; 3];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let subtracted_vector: PlaintextVector64 = engine.create_plaintext_vector(&subtracted)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let mut ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.trivially_encrypt_lwe_ciphertext_vector(lwe_size, &input_vector)?;
+    ///
+    /// engine.fuse_sub_lwe_ciphertext_vector_plaintext_vector(
+    ///     &mut ciphertext_vector,
+    ///     &subtracted_vector,
+    /// )?;
+    ///
+    /// // The subtraction wraps around zero.
+    /// let output_vector: PlaintextVector64 =
+    ///     engine.trivially_decrypt_lwe_ciphertext_vector(&ciphertext_vector)?;
+    /// assert_eq!(
+    ///     engine.retrieve_plaintext_vector(&output_vector)?,
+    ///     vec![u64::MAX, 0, 1]
+    /// );
+    ///
+    /// engine.destroy(input_vector)?;
+    /// engine.destroy(subtracted_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_sub_lwe_ciphertext_vector_plaintext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &PlaintextVector64,
+    ) -> Result<(), LweCiphertextVectorPlaintextVectorFusingSubtractionError<Self::EngineError>>
+    {
+        LweCiphertextVectorPlaintextVectorFusingSubtractionError::perform_generic_checks(
+            output, input,
+        )?;
+        unsafe { self.fuse_sub_lwe_ciphertext_vector_plaintext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_sub_lwe_ciphertext_vector_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &PlaintextVector64,
+    ) {
+        for (mut ciphertext, plaintext) in
+            output.0.ciphertext_iter_mut().zip(input.0.plaintext_iter())
+        {
+            let body = ciphertext.get_mut_body();
+            body.0 = body.0.wrapping_sub(plaintext.0);
+        }
+    }
+}
@@ -73,7 +73,7 @@ impl LweCiphertextVectorZeroEncryptionEngine<LweSecretKey32, LweCiphertextVector
             noise,
             &mut self.encryption_generator,
         );
-        LweCiphertextVector32(vector)
+        self.audit_creation(LweCiphertextVector32(vector))
     }
 }
 
@@ -138,6 +138,6 @@ impl LweCiphertextVectorZeroEncryptionEngine<LweSecretKey64, LweCiphertextVector
             noise,
             &mut self.encryption_generator,
         );
-        LweCiphertextVector64(vector)
+        self.audit_creation(LweCiphertextVector64(vector))
     }
 }
@@ -0,0 +1,149 @@
+use concrete_commons::parameters::LweKeyswitchKeyIndex;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweKeyswitchKey32, LweKeyswitchKey64, LweKeyswitchKeyBatch32, LweKeyswitchKeyBatch64,
+};
+use crate::specification::engines::{
+    LweKeyswitchKeyBatchAppendingEngine, LweKeyswitchKeyBatchAppendingError,
+};
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyBatchAppendingEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweKeyswitchKeyBatchAppendingEngine<LweKeyswitchKey32, LweKeyswitchKeyBatch32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweKeyswitchKeyCount,
+    ///     LweKeyswitchKeyIndex,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let mut batch: LweKeyswitchKeyBatch32 = engine.create_lwe_keyswitch_key_batch(
+    ///     input_lwe_dimension,
+    ///     output_lwe_dimension,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    /// )?;
+    ///
+    /// let index = engine.append_lwe_keyswitch_key(&mut batch, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(index, LweKeyswitchKeyIndex(0));
+    /// assert_eq!(batch.key_count(), LweKeyswitchKeyCount(1));
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(batch)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn append_lwe_keyswitch_key(
+        &mut self,
+        batch: &mut LweKeyswitchKeyBatch32,
+        key: &LweKeyswitchKey32,
+    ) -> Result<LweKeyswitchKeyIndex, LweKeyswitchKeyBatchAppendingError<Self::EngineError>> {
+        LweKeyswitchKeyBatchAppendingError::perform_generic_checks(batch, key)?;
+        Ok(unsafe { self.append_lwe_keyswitch_key_unchecked(batch, key) })
+    }
+
+    unsafe fn append_lwe_keyswitch_key_unchecked(
+        &mut self,
+        batch: &mut LweKeyswitchKeyBatch32,
+        key: &LweKeyswitchKey32,
+    ) -> LweKeyswitchKeyIndex {
+        batch.0.append_key(&key.0)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyBatchAppendingEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweKeyswitchKeyBatchAppendingEngine<LweKeyswitchKey64, LweKeyswitchKeyBatch64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweKeyswitchKeyCount,
+    ///     LweKeyswitchKeyIndex,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let mut batch: LweKeyswitchKeyBatch64 = engine.create_lwe_keyswitch_key_batch(
+    ///     input_lwe_dimension,
+    ///     output_lwe_dimension,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    /// )?;
+    ///
+    /// let index = engine.append_lwe_keyswitch_key(&mut batch, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(index, LweKeyswitchKeyIndex(0));
+    /// assert_eq!(batch.key_count(), LweKeyswitchKeyCount(1));
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(batch)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn append_lwe_keyswitch_key(
+        &mut self,
+        batch: &mut LweKeyswitchKeyBatch64,
+        key: &LweKeyswitchKey64,
+    ) -> Result<LweKeyswitchKeyIndex, LweKeyswitchKeyBatchAppendingError<Self::EngineError>> {
+        LweKeyswitchKeyBatchAppendingError::perform_generic_checks(batch, key)?;
+        Ok(unsafe { self.append_lwe_keyswitch_key_unchecked(batch, key) })
+    }
+
+    unsafe fn append_lwe_keyswitch_key_unchecked(
+        &mut self,
+        batch: &mut LweKeyswitchKeyBatch64,
+        key: &LweKeyswitchKey64,
+    ) -> LweKeyswitchKeyIndex {
+        batch.0.append_key(&key.0)
+    }
+}
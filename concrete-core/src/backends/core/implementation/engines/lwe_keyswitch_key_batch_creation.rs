@@ -0,0 +1,158 @@
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweKeyswitchKeyBatch32, LweKeyswitchKeyBatch64,
+};
+use crate::backends::core::private::crypto::lwe::LweKeyswitchKeyBatch as ImplLweKeyswitchKeyBatch;
+use crate::specification::engines::{
+    LweKeyswitchKeyBatchCreationEngine, LweKeyswitchKeyBatchCreationError,
+};
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyBatchCreationEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweKeyswitchKeyBatchCreationEngine<LweKeyswitchKeyBatch32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweKeyswitchKeyCount,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let batch: LweKeyswitchKeyBatch32 = engine.create_lwe_keyswitch_key_batch(
+    ///     input_lwe_dimension,
+    ///     output_lwe_dimension,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    /// )?;
+    /// #
+    /// assert_eq!(batch.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(batch.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(batch.key_count(), LweKeyswitchKeyCount(0));
+    ///
+    /// engine.destroy(batch)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key_batch(
+        &mut self,
+        input_lwe_dimension: LweDimension,
+        output_lwe_dimension: LweDimension,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<LweKeyswitchKeyBatch32, LweKeyswitchKeyBatchCreationError<Self::EngineError>> {
+        LweKeyswitchKeyBatchCreationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_batch_unchecked(
+                input_lwe_dimension,
+                output_lwe_dimension,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_batch_unchecked(
+        &mut self,
+        input_lwe_dimension: LweDimension,
+        output_lwe_dimension: LweDimension,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> LweKeyswitchKeyBatch32 {
+        LweKeyswitchKeyBatch32(ImplLweKeyswitchKeyBatch::allocate(
+            decomposition_level_count,
+            decomposition_base_log,
+            input_lwe_dimension,
+            output_lwe_dimension,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyBatchCreationEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweKeyswitchKeyBatchCreationEngine<LweKeyswitchKeyBatch64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweKeyswitchKeyCount,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let batch: LweKeyswitchKeyBatch64 = engine.create_lwe_keyswitch_key_batch(
+    ///     input_lwe_dimension,
+    ///     output_lwe_dimension,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    /// )?;
+    /// #
+    /// assert_eq!(batch.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(batch.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(batch.key_count(), LweKeyswitchKeyCount(0));
+    ///
+    /// engine.destroy(batch)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key_batch(
+        &mut self,
+        input_lwe_dimension: LweDimension,
+        output_lwe_dimension: LweDimension,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<LweKeyswitchKeyBatch64, LweKeyswitchKeyBatchCreationError<Self::EngineError>> {
+        LweKeyswitchKeyBatchCreationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_batch_unchecked(
+                input_lwe_dimension,
+                output_lwe_dimension,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_batch_unchecked(
+        &mut self,
+        input_lwe_dimension: LweDimension,
+        output_lwe_dimension: LweDimension,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> LweKeyswitchKeyBatch64 {
+        LweKeyswitchKeyBatch64(ImplLweKeyswitchKeyBatch::allocate(
+            decomposition_level_count,
+            decomposition_base_log,
+            input_lwe_dimension,
+            output_lwe_dimension,
+        ))
+    }
+}
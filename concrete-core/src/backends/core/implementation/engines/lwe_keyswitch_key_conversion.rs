@@ -0,0 +1,181 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    ColumnMajorLweKeyswitchKey32, ColumnMajorLweKeyswitchKey64, LweKeyswitchKey32,
+    LweKeyswitchKey64,
+};
+use crate::backends::core::private::crypto::lwe::ColumnMajorLweKeyswitchKey as ImplColumnMajorLweKeyswitchKey;
+use crate::specification::engines::{
+    LweKeyswitchKeyConversionEngine, LweKeyswitchKeyConversionError,
+};
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConversionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. It transposes a keyswitch key into the column-major layout.
+impl LweKeyswitchKeyConversionEngine<LweKeyswitchKey32, ColumnMajorLweKeyswitchKey32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: LweKeyswitchKey32 = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let column_major_key: ColumnMajorLweKeyswitchKey32 =
+    ///     engine.convert_lwe_keyswitch_key(&keyswitch_key)?;
+    /// #
+    /// assert_eq!(column_major_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(column_major_key.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(
+    ///     column_major_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(column_major_key.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// // Both keys switch a ciphertext to the same result.
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut switched_1 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    /// let mut switched_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut switched_1, &ciphertext, &keyswitch_key)?;
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut switched_2, &ciphertext, &column_major_key)?;
+    /// assert_eq!(switched_1, switched_2);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(column_major_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(switched_1)?;
+    /// engine.destroy(switched_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_keyswitch_key(
+        &mut self,
+        input: &LweKeyswitchKey32,
+    ) -> Result<ColumnMajorLweKeyswitchKey32, LweKeyswitchKeyConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_lwe_keyswitch_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &LweKeyswitchKey32,
+    ) -> ColumnMajorLweKeyswitchKey32 {
+        self.audit_creation(ColumnMajorLweKeyswitchKey32(
+            ImplColumnMajorLweKeyswitchKey::from_keyswitch_key(&input.0),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConversionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. It transposes a keyswitch key into the column-major layout.
+impl LweKeyswitchKeyConversionEngine<LweKeyswitchKey64, ColumnMajorLweKeyswitchKey64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: LweKeyswitchKey64 = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let column_major_key: ColumnMajorLweKeyswitchKey64 =
+    ///     engine.convert_lwe_keyswitch_key(&keyswitch_key)?;
+    /// #
+    /// assert_eq!(column_major_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(column_major_key.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(
+    ///     column_major_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(column_major_key.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// // Both keys switch a ciphertext to the same result.
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut switched_1 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    /// let mut switched_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut switched_1, &ciphertext, &keyswitch_key)?;
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut switched_2, &ciphertext, &column_major_key)?;
+    /// assert_eq!(switched_1, switched_2);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(column_major_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(switched_1)?;
+    /// engine.destroy(switched_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_keyswitch_key(
+        &mut self,
+        input: &LweKeyswitchKey64,
+    ) -> Result<ColumnMajorLweKeyswitchKey64, LweKeyswitchKeyConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_lwe_keyswitch_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &LweKeyswitchKey64,
+    ) -> ColumnMajorLweKeyswitchKey64 {
+        self.audit_creation(ColumnMajorLweKeyswitchKey64(
+            ImplColumnMajorLweKeyswitchKey::from_keyswitch_key(&input.0),
+        ))
+    }
+}
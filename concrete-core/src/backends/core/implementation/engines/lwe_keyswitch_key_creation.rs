@@ -1,14 +1,128 @@
 use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
 
-use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::engines::{CoreEngine, CoreError};
 use crate::backends::core::implementation::entities::{
-    LweKeyswitchKey32, LweKeyswitchKey64, LweSecretKey32, LweSecretKey64,
+    LweKeyswitchKey128, LweKeyswitchKey16, LweKeyswitchKey32, LweKeyswitchKey64, LweSecretKey128,
+    LweSecretKey16, LweSecretKey32, LweSecretKey64,
 };
 use crate::backends::core::private::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
 use crate::specification::engines::{LweKeyswitchKeyCreationEngine, LweKeyswitchKeyCreationError};
 use crate::specification::entities::LweSecretKeyEntity;
 
+impl From<CoreError> for LweKeyswitchKeyCreationError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`CoreEngine`] that
+/// operates on 16 bits integers.
+impl LweKeyswitchKeyCreationEngine<LweSecretKey16, LweSecretKey16, LweKeyswitchKey16>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey16 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey16 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey16,
+        output_key: &LweSecretKey16,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKey16, LweKeyswitchKeyCreationError<Self::EngineError>> {
+        LweKeyswitchKeyCreationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            16,
+        )?;
+        self.check_key_generation_cancellation()?;
+        let key = unsafe {
+            self.create_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        };
+        self.check_key_generation_cancellation()?;
+        Ok(key)
+    }
+
+    unsafe fn create_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey16,
+        output_key: &LweSecretKey16,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweKeyswitchKey16 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key_monitored(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+            self.key_generation_monitor
+                .reporter(ksk.before_key_size().0),
+        );
+        self.audit_creation(LweKeyswitchKey16(ksk))
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`CoreEngine`] that
 /// operates on 32 bits integers.
@@ -75,7 +189,8 @@ impl LweKeyswitchKeyCreationEngine<LweSecretKey32, LweSecretKey32, LweKeyswitchK
             decomposition_base_log,
             32,
         )?;
-        Ok(unsafe {
+        self.check_key_generation_cancellation()?;
+        let key = unsafe {
             self.create_lwe_keyswitch_key_unchecked(
                 input_key,
                 output_key,
@@ -83,7 +198,9 @@ impl LweKeyswitchKeyCreationEngine<LweSecretKey32, LweSecretKey32, LweKeyswitchK
                 decomposition_base_log,
                 noise,
             )
-        })
+        };
+        self.check_key_generation_cancellation()?;
+        Ok(key)
     }
 
     unsafe fn create_lwe_keyswitch_key_unchecked(
@@ -101,13 +218,15 @@ impl LweKeyswitchKeyCreationEngine<LweSecretKey32, LweSecretKey32, LweKeyswitchK
             input_key.lwe_dimension(),
             output_key.lwe_dimension(),
         );
-        ksk.fill_with_keyswitch_key(
+        ksk.fill_with_keyswitch_key_monitored(
             &input_key.0,
             &output_key.0,
             noise,
             &mut self.encryption_generator,
+            self.key_generation_monitor
+                .reporter(ksk.before_key_size().0),
         );
-        LweKeyswitchKey32(ksk)
+        self.audit_creation(LweKeyswitchKey32(ksk))
     }
 }
 
@@ -177,7 +296,8 @@ impl LweKeyswitchKeyCreationEngine<LweSecretKey64, LweSecretKey64, LweKeyswitchK
             decomposition_base_log,
             64,
         )?;
-        Ok(unsafe {
+        self.check_key_generation_cancellation()?;
+        let key = unsafe {
             self.create_lwe_keyswitch_key_unchecked(
                 input_key,
                 output_key,
@@ -185,7 +305,9 @@ impl LweKeyswitchKeyCreationEngine<LweSecretKey64, LweSecretKey64, LweKeyswitchK
                 decomposition_base_log,
                 noise,
             )
-        })
+        };
+        self.check_key_generation_cancellation()?;
+        Ok(key)
     }
 
     unsafe fn create_lwe_keyswitch_key_unchecked(
@@ -203,12 +325,121 @@ impl LweKeyswitchKeyCreationEngine<LweSecretKey64, LweSecretKey64, LweKeyswitchK
             input_key.lwe_dimension(),
             output_key.lwe_dimension(),
         );
-        ksk.fill_with_keyswitch_key(
+        ksk.fill_with_keyswitch_key_monitored(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+            self.key_generation_monitor
+                .reporter(ksk.before_key_size().0),
+        );
+        self.audit_creation(LweKeyswitchKey64(ksk))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`CoreEngine`] that
+/// operates on 128 bits integers.
+impl LweKeyswitchKeyCreationEngine<LweSecretKey128, LweSecretKey128, LweKeyswitchKey128>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey128 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey128 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey128,
+        output_key: &LweSecretKey128,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKey128, LweKeyswitchKeyCreationError<Self::EngineError>> {
+        LweKeyswitchKeyCreationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            128,
+        )?;
+        self.check_key_generation_cancellation()?;
+        let key = unsafe {
+            self.create_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        };
+        self.check_key_generation_cancellation()?;
+        Ok(key)
+    }
+
+    unsafe fn create_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey128,
+        output_key: &LweSecretKey128,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweKeyswitchKey128 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key_monitored(
             &input_key.0,
             &output_key.0,
             noise,
             &mut self.encryption_generator,
+            self.key_generation_monitor
+                .reporter(ksk.before_key_size().0),
         );
-        LweKeyswitchKey64(ksk)
+        self.audit_creation(LweKeyswitchKey128(ksk))
     }
 }
@@ -0,0 +1,245 @@
+use concrete_commons::dispersion::Variance;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweKeyswitchKey32, LweKeyswitchKey64, LweKeyswitchKeyDelta32, LweKeyswitchKeyDelta64,
+    LweSecretKey32, LweSecretKey64,
+};
+use crate::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::specification::engines::{
+    LweKeyswitchKeyDeltaCreationEngine, LweKeyswitchKeyDeltaCreationError,
+};
+use crate::specification::entities::LweKeyswitchKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyDeltaCreationEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweKeyswitchKeyDeltaCreationEngine<LweKeyswitchKey32, LweSecretKey32, LweKeyswitchKeyDelta32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let old_output_key: LweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let new_output_key: LweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &old_output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let delta: LweKeyswitchKeyDelta32 = engine.create_lwe_keyswitch_key_delta(
+    ///     &keyswitch_key,
+    ///     &old_output_key,
+    ///     &new_output_key,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(delta.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(delta.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(old_output_key)?;
+    /// engine.destroy(new_output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(delta)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key_delta(
+        &mut self,
+        keyswitch_key: &LweKeyswitchKey32,
+        old_output_key: &LweSecretKey32,
+        new_output_key: &LweSecretKey32,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKeyDelta32, LweKeyswitchKeyDeltaCreationError<Self::EngineError>> {
+        LweKeyswitchKeyDeltaCreationError::perform_generic_checks(
+            keyswitch_key,
+            old_output_key,
+            new_output_key,
+        )?;
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_delta_unchecked(
+                keyswitch_key,
+                old_output_key,
+                new_output_key,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_delta_unchecked(
+        &mut self,
+        keyswitch_key: &LweKeyswitchKey32,
+        old_output_key: &LweSecretKey32,
+        new_output_key: &LweSecretKey32,
+        noise: Variance,
+    ) -> LweKeyswitchKeyDelta32 {
+        LweKeyswitchKeyDelta32 {
+            body_corrections: compute_body_corrections(
+                keyswitch_key.0.as_tensor().as_slice(),
+                old_output_key.0.as_tensor().as_slice(),
+                new_output_key.0.as_tensor().as_slice(),
+                noise,
+                &mut self.encryption_generator,
+            ),
+            input_lwe_dimension: keyswitch_key.input_lwe_dimension(),
+            output_lwe_dimension: keyswitch_key.output_lwe_dimension(),
+            decomposition_level_count: keyswitch_key.decomposition_level_count(),
+            decomposition_base_log: keyswitch_key.decomposition_base_log(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyDeltaCreationEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweKeyswitchKeyDeltaCreationEngine<LweKeyswitchKey64, LweSecretKey64, LweKeyswitchKeyDelta64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let old_output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let new_output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &old_output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let delta: LweKeyswitchKeyDelta64 = engine.create_lwe_keyswitch_key_delta(
+    ///     &keyswitch_key,
+    ///     &old_output_key,
+    ///     &new_output_key,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(delta.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(delta.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(old_output_key)?;
+    /// engine.destroy(new_output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(delta)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key_delta(
+        &mut self,
+        keyswitch_key: &LweKeyswitchKey64,
+        old_output_key: &LweSecretKey64,
+        new_output_key: &LweSecretKey64,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKeyDelta64, LweKeyswitchKeyDeltaCreationError<Self::EngineError>> {
+        LweKeyswitchKeyDeltaCreationError::perform_generic_checks(
+            keyswitch_key,
+            old_output_key,
+            new_output_key,
+        )?;
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_delta_unchecked(
+                keyswitch_key,
+                old_output_key,
+                new_output_key,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_delta_unchecked(
+        &mut self,
+        keyswitch_key: &LweKeyswitchKey64,
+        old_output_key: &LweSecretKey64,
+        new_output_key: &LweSecretKey64,
+        noise: Variance,
+    ) -> LweKeyswitchKeyDelta64 {
+        LweKeyswitchKeyDelta64 {
+            body_corrections: compute_body_corrections(
+                keyswitch_key.0.as_tensor().as_slice(),
+                old_output_key.0.as_tensor().as_slice(),
+                new_output_key.0.as_tensor().as_slice(),
+                noise,
+                &mut self.encryption_generator,
+            ),
+            input_lwe_dimension: keyswitch_key.input_lwe_dimension(),
+            output_lwe_dimension: keyswitch_key.output_lwe_dimension(),
+            decomposition_level_count: keyswitch_key.decomposition_level_count(),
+            decomposition_base_log: keyswitch_key.decomposition_base_log(),
+        }
+    }
+}
+
+// Computes the body correction of every ciphertext of the keyswitch key, which is made of
+// ciphertexts of `old_key.len() + 1` elements, the mask followed by the body. Only the
+// coefficients which differ between the two keys contribute to the corrections.
+fn compute_body_corrections<Scalar>(
+    keyswitch_key: &[Scalar],
+    old_key: &[Scalar],
+    new_key: &[Scalar],
+    noise: Variance,
+    generator: &mut EncryptionRandomGenerator,
+) -> Vec<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    let changes: Vec<(usize, Scalar)> = old_key
+        .iter()
+        .zip(new_key.iter())
+        .enumerate()
+        .filter(|(_, (old, new))| old != new)
+        .map(|(index, (old, new))| (index, new.wrapping_sub(*old)))
+        .collect();
+    keyswitch_key
+        .chunks(old_key.len() + 1)
+        .map(|ciphertext| {
+            changes.iter().fold(
+                generator.random_noise(noise),
+                |correction: Scalar, (index, difference)| {
+                    correction.wrapping_add(ciphertext[*index].wrapping_mul(*difference))
+                },
+            )
+        })
+        .collect()
+}
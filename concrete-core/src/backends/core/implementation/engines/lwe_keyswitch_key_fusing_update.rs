@@ -0,0 +1,195 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweKeyswitchKey32, LweKeyswitchKey64, LweKeyswitchKeyDelta32, LweKeyswitchKeyDelta64,
+};
+use crate::backends::core::private::math::tensor::{AsMutSlice, AsMutTensor};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::specification::engines::{
+    LweKeyswitchKeyFusingUpdateEngine, LweKeyswitchKeyFusingUpdateError,
+};
+use crate::specification::entities::LweKeyswitchKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyFusingUpdateEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweKeyswitchKeyFusingUpdateEngine<LweKeyswitchKeyDelta32, LweKeyswitchKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let old_output_key: LweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let new_output_key: LweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let mut keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &old_output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let delta: LweKeyswitchKeyDelta32 = engine.create_lwe_keyswitch_key_delta(
+    ///     &keyswitch_key,
+    ///     &old_output_key,
+    ///     &new_output_key,
+    ///     noise,
+    /// )?;
+    /// engine.fuse_update_lwe_keyswitch_key(&mut keyswitch_key, &delta)?;
+    ///
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let plaintext = engine.create_plaintext(&(3_u32 << 20))?;
+    /// let input = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&new_output_key, noise)?;
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut output, &input, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(old_output_key)?;
+    /// engine.destroy(new_output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(delta)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_update_lwe_keyswitch_key(
+        &mut self,
+        keyswitch_key: &mut LweKeyswitchKey32,
+        delta: &LweKeyswitchKeyDelta32,
+    ) -> Result<(), LweKeyswitchKeyFusingUpdateError<Self::EngineError>> {
+        LweKeyswitchKeyFusingUpdateError::perform_generic_checks(keyswitch_key, delta)?;
+        unsafe { self.fuse_update_lwe_keyswitch_key_unchecked(keyswitch_key, delta) };
+        Ok(())
+    }
+
+    unsafe fn fuse_update_lwe_keyswitch_key_unchecked(
+        &mut self,
+        keyswitch_key: &mut LweKeyswitchKey32,
+        delta: &LweKeyswitchKeyDelta32,
+    ) {
+        let lwe_size = keyswitch_key.output_lwe_dimension().to_lwe_size().0;
+        apply_body_corrections(
+            keyswitch_key.0.as_mut_tensor().as_mut_slice(),
+            lwe_size,
+            delta.body_corrections.as_slice(),
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyFusingUpdateEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweKeyswitchKeyFusingUpdateEngine<LweKeyswitchKeyDelta64, LweKeyswitchKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let old_output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let new_output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let mut keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &old_output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let delta: LweKeyswitchKeyDelta64 = engine.create_lwe_keyswitch_key_delta(
+    ///     &keyswitch_key,
+    ///     &old_output_key,
+    ///     &new_output_key,
+    ///     noise,
+    /// )?;
+    /// engine.fuse_update_lwe_keyswitch_key(&mut keyswitch_key, &delta)?;
+    ///
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let plaintext = engine.create_plaintext(&(3_u64 << 50))?;
+    /// let input = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&new_output_key, noise)?;
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut output, &input, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(old_output_key)?;
+    /// engine.destroy(new_output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(delta)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_update_lwe_keyswitch_key(
+        &mut self,
+        keyswitch_key: &mut LweKeyswitchKey64,
+        delta: &LweKeyswitchKeyDelta64,
+    ) -> Result<(), LweKeyswitchKeyFusingUpdateError<Self::EngineError>> {
+        LweKeyswitchKeyFusingUpdateError::perform_generic_checks(keyswitch_key, delta)?;
+        unsafe { self.fuse_update_lwe_keyswitch_key_unchecked(keyswitch_key, delta) };
+        Ok(())
+    }
+
+    unsafe fn fuse_update_lwe_keyswitch_key_unchecked(
+        &mut self,
+        keyswitch_key: &mut LweKeyswitchKey64,
+        delta: &LweKeyswitchKeyDelta64,
+    ) {
+        let lwe_size = keyswitch_key.output_lwe_dimension().to_lwe_size().0;
+        apply_body_corrections(
+            keyswitch_key.0.as_mut_tensor().as_mut_slice(),
+            lwe_size,
+            delta.body_corrections.as_slice(),
+        );
+    }
+}
+
+// Adds the corrections to the bodies of the ciphertexts of the keyswitch key, which are the last
+// elements of the chunks of `lwe_size` elements.
+fn apply_body_corrections<Scalar>(
+    keyswitch_key: &mut [Scalar],
+    lwe_size: usize,
+    corrections: &[Scalar],
+) where
+    Scalar: UnsignedTorus,
+{
+    for (ciphertext, correction) in keyswitch_key.chunks_mut(lwe_size).zip(corrections.iter()) {
+        let body = &mut ciphertext[lwe_size - 1];
+        *body = body.wrapping_add(*correction);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LwePhaseVector32, LwePhaseVector64};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{LwePhaseVectorRetrievalEngine, LwePhaseVectorRetrievalError};
+
+/// # Description:
+/// Implementation of [`LwePhaseVectorRetrievalEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers.
+impl LwePhaseVectorRetrievalEngine<LwePhaseVector32, u32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, MessageBitCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let input = vec![3_u32 << 28; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let (decrypted_plaintext_vector, phase_vector): (PlaintextVector32, LwePhaseVector32) =
+    ///     engine.decrypt_lwe_ciphertext_vector_exporting_phases(
+    ///         &key,
+    ///         &ciphertext_vector,
+    ///         MessageBitCount(4),
+    ///     )?;
+    ///
+    /// let phases: Vec<u32> = engine.retrieve_lwe_phase_vector(&phase_vector)?;
+    /// assert_eq!(phases.len(), 3);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// engine.destroy(phase_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn retrieve_lwe_phase_vector(
+        &mut self,
+        input: &LwePhaseVector32,
+    ) -> Result<Vec<u32>, LwePhaseVectorRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_lwe_phase_vector_unchecked(input) })
+    }
+
+    unsafe fn retrieve_lwe_phase_vector_unchecked(&mut self, input: &LwePhaseVector32) -> Vec<u32> {
+        input.0.as_tensor().as_container().to_vec()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LwePhaseVectorRetrievalEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers.
+impl LwePhaseVectorRetrievalEngine<LwePhaseVector64, u64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, MessageBitCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let input = vec![3_u64 << 60; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let (decrypted_plaintext_vector, phase_vector): (PlaintextVector64, LwePhaseVector64) =
+    ///     engine.decrypt_lwe_ciphertext_vector_exporting_phases(
+    ///         &key,
+    ///         &ciphertext_vector,
+    ///         MessageBitCount(4),
+    ///     )?;
+    ///
+    /// let phases: Vec<u64> = engine.retrieve_lwe_phase_vector(&phase_vector)?;
+    /// assert_eq!(phases.len(), 3);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// engine.destroy(phase_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn retrieve_lwe_phase_vector(
+        &mut self,
+        input: &LwePhaseVector64,
+    ) -> Result<Vec<u64>, LwePhaseVectorRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_lwe_phase_vector_unchecked(input) })
+    }
+
+    unsafe fn retrieve_lwe_phase_vector_unchecked(&mut self, input: &LwePhaseVector64) -> Vec<u64> {
+        input.0.as_tensor().as_container().to_vec()
+    }
+}
@@ -0,0 +1,153 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    CiphertextCount, LwePublicKeyZeroEncryptionCount, PlaintextCount,
+};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LwePublicKey32, LwePublicKey64, LweSecretKey32, LweSecretKey64,
+};
+use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::backends::core::private::crypto::lwe::LweList as ImplLweList;
+use crate::specification::engines::{LwePublicKeyCreationEngine, LwePublicKeyCreationError};
+use crate::specification::entities::LweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`LwePublicKeyCreationEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LwePublicKeyCreationEngine<LweSecretKey32, LwePublicKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let secret_key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let public_key: LwePublicKey32 =
+    ///     engine.create_lwe_public_key(&secret_key, noise, zero_encryption_count)?;
+    /// #
+    /// assert_eq!(public_key.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(public_key.lwe_zero_encryption_count(), zero_encryption_count);
+    ///
+    /// engine.destroy(secret_key)?;
+    /// engine.destroy(public_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_public_key(
+        &mut self,
+        lwe_secret_key: &LweSecretKey32,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Result<LwePublicKey32, LwePublicKeyCreationError<Self::EngineError>> {
+        LwePublicKeyCreationError::perform_generic_checks(lwe_public_key_zero_encryption_count)?;
+        Ok(unsafe {
+            self.create_lwe_public_key_unchecked(
+                lwe_secret_key,
+                noise,
+                lwe_public_key_zero_encryption_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_public_key_unchecked(
+        &mut self,
+        lwe_secret_key: &LweSecretKey32,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> LwePublicKey32 {
+        let count = lwe_public_key_zero_encryption_count.0;
+        let mut zero_encryptions = ImplLweList::allocate(
+            0u32,
+            lwe_secret_key.lwe_dimension().to_lwe_size(),
+            CiphertextCount(count),
+        );
+        let zeros = ImplPlaintextList::allocate(0u32, PlaintextCount(count));
+        lwe_secret_key.0.encrypt_lwe_list(
+            &mut zero_encryptions,
+            &zeros,
+            noise,
+            &mut self.encryption_generator,
+        );
+        self.audit_creation(LwePublicKey32(zero_encryptions))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LwePublicKeyCreationEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LwePublicKeyCreationEngine<LweSecretKey64, LwePublicKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let secret_key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let public_key: LwePublicKey64 =
+    ///     engine.create_lwe_public_key(&secret_key, noise, zero_encryption_count)?;
+    /// #
+    /// assert_eq!(public_key.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(public_key.lwe_zero_encryption_count(), zero_encryption_count);
+    ///
+    /// engine.destroy(secret_key)?;
+    /// engine.destroy(public_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_public_key(
+        &mut self,
+        lwe_secret_key: &LweSecretKey64,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Result<LwePublicKey64, LwePublicKeyCreationError<Self::EngineError>> {
+        LwePublicKeyCreationError::perform_generic_checks(lwe_public_key_zero_encryption_count)?;
+        Ok(unsafe {
+            self.create_lwe_public_key_unchecked(
+                lwe_secret_key,
+                noise,
+                lwe_public_key_zero_encryption_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_public_key_unchecked(
+        &mut self,
+        lwe_secret_key: &LweSecretKey64,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> LwePublicKey64 {
+        let count = lwe_public_key_zero_encryption_count.0;
+        let mut zero_encryptions = ImplLweList::allocate(
+            0u64,
+            lwe_secret_key.lwe_dimension().to_lwe_size(),
+            CiphertextCount(count),
+        );
+        let zeros = ImplPlaintextList::allocate(0u64, PlaintextCount(count));
+        lwe_secret_key.0.encrypt_lwe_list(
+            &mut zero_encryptions,
+            &zeros,
+            noise,
+            &mut self.encryption_generator,
+        );
+        self.audit_creation(LwePublicKey64(zero_encryptions))
+    }
+}
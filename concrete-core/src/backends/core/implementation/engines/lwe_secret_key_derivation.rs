@@ -0,0 +1,185 @@
+use concrete_commons::parameters::{ClientId, LweDimension, MasterSeed};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweSecretKey32, LweSecretKey64};
+use crate::backends::core::private::crypto::secret::generators::SecretRandomGenerator as ImplSecretRandomGenerator;
+use crate::backends::core::private::crypto::secret::LweSecretKey as ImplLweSecretKey;
+use crate::specification::engines::{LweSecretKeyDerivationEngine, LweSecretKeyDerivationError};
+
+// The domain of the LWE secret keys derived from a master seed. The domain zero is left to the
+// generators seeded with the master seed itself, whose counters start at zero.
+const LWE_SECRET_KEY_DOMAIN: u64 = 1;
+
+/// # Description:
+/// Implementation of [`LweSecretKeyDerivationEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweSecretKeyDerivationEngine<LweSecretKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{ClientId, LweDimension, MasterSeed};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // The master seed must be sampled from a secure source of randomness.
+    /// let master_seed = MasterSeed(0x0123_4567_89ab_cdef_0123_4567_89ab_cdef);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key_1: LweSecretKey32 =
+    ///     engine.derive_lwe_secret_key(master_seed, ClientId(1), lwe_dimension)?;
+    /// let key_2: LweSecretKey32 =
+    ///     engine.derive_lwe_secret_key(master_seed, ClientId(1), lwe_dimension)?;
+    /// #
+    /// assert_eq!(key_1.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(key_1, key_2);
+    /// engine.destroy(key_1)?;
+    /// engine.destroy(key_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn derive_lwe_secret_key(
+        &mut self,
+        master_seed: MasterSeed,
+        client_id: ClientId,
+        lwe_dimension: LweDimension,
+    ) -> Result<LweSecretKey32, LweSecretKeyDerivationError<Self::EngineError>> {
+        LweSecretKeyDerivationError::perform_generic_checks(lwe_dimension)?;
+        Ok(unsafe { self.derive_lwe_secret_key_unchecked(master_seed, client_id, lwe_dimension) })
+    }
+
+    unsafe fn derive_lwe_secret_key_unchecked(
+        &mut self,
+        master_seed: MasterSeed,
+        client_id: ClientId,
+        lwe_dimension: LweDimension,
+    ) -> LweSecretKey32 {
+        let mut generator = ImplSecretRandomGenerator::new_derived(
+            master_seed.0,
+            LWE_SECRET_KEY_DOMAIN,
+            client_id.0,
+        );
+        LweSecretKey32(ImplLweSecretKey::generate_binary(
+            lwe_dimension,
+            &mut generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyDerivationEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweSecretKeyDerivationEngine<LweSecretKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{ClientId, LweDimension, MasterSeed};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // The master seed must be sampled from a secure source of randomness.
+    /// let master_seed = MasterSeed(0x0123_4567_89ab_cdef_0123_4567_89ab_cdef);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key_1: LweSecretKey64 =
+    ///     engine.derive_lwe_secret_key(master_seed, ClientId(1), lwe_dimension)?;
+    /// let key_2: LweSecretKey64 =
+    ///     engine.derive_lwe_secret_key(master_seed, ClientId(1), lwe_dimension)?;
+    /// #
+    /// assert_eq!(key_1.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(key_1, key_2);
+    /// engine.destroy(key_1)?;
+    /// engine.destroy(key_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn derive_lwe_secret_key(
+        &mut self,
+        master_seed: MasterSeed,
+        client_id: ClientId,
+        lwe_dimension: LweDimension,
+    ) -> Result<LweSecretKey64, LweSecretKeyDerivationError<Self::EngineError>> {
+        LweSecretKeyDerivationError::perform_generic_checks(lwe_dimension)?;
+        Ok(unsafe { self.derive_lwe_secret_key_unchecked(master_seed, client_id, lwe_dimension) })
+    }
+
+    unsafe fn derive_lwe_secret_key_unchecked(
+        &mut self,
+        master_seed: MasterSeed,
+        client_id: ClientId,
+        lwe_dimension: LweDimension,
+    ) -> LweSecretKey64 {
+        let mut generator = ImplSecretRandomGenerator::new_derived(
+            master_seed.0,
+            LWE_SECRET_KEY_DOMAIN,
+            client_id.0,
+        );
+        LweSecretKey64(ImplLweSecretKey::generate_binary(
+            lwe_dimension,
+            &mut generator,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+    use crate::prelude::*;
+
+    fn derive(engine: &mut CoreEngine, master_seed: u128, client_id: u64) -> LweSecretKey64 {
+        engine
+            .derive_lwe_secret_key(
+                MasterSeed(master_seed),
+                ClientId(client_id),
+                LweDimension(1024),
+            )
+            .unwrap()
+    }
+
+    // Returns the fraction of the coefficients which are equal in the two keys.
+    fn agreement(key_1: &LweSecretKey64, key_2: &LweSecretKey64) -> f64 {
+        let (coefficients_1, coefficients_2) = (
+            key_1.0.as_tensor().as_slice(),
+            key_2.0.as_tensor().as_slice(),
+        );
+        let equal = coefficients_1
+            .iter()
+            .zip(coefficients_2.iter())
+            .filter(|(c1, c2)| c1 == c2)
+            .count();
+        equal as f64 / coefficients_1.len() as f64
+    }
+
+    #[test]
+    fn test_identical_ids_reproduce_identical_keys() {
+        let mut engine_1 = CoreEngine::new().unwrap();
+        let mut engine_2 = CoreEngine::new().unwrap();
+        for client_id in [0, 1, 42, u64::MAX] {
+            let key_1 = derive(&mut engine_1, 0xdead_beef, client_id);
+            let key_2 = derive(&mut engine_2, 0xdead_beef, client_id);
+            assert_eq!(key_1, key_2);
+        }
+    }
+
+    #[test]
+    fn test_different_ids_produce_independent_keys() {
+        // Two independent binary keys agree on half of their coefficients on average. The
+        // agreement of every pair of distinct keys is checked up to 6 standard deviations.
+        let mut engine = CoreEngine::new().unwrap();
+        let mut keys: Vec<LweSecretKey64> = (0..16)
+            .map(|client_id| derive(&mut engine, 0xdead_beef, client_id))
+            .collect();
+        keys.extend((0..4).map(|client_id| derive(&mut engine, 0xcafe, client_id)));
+        let tolerance = 6. * f64::sqrt(0.25 / 1024.);
+        for (i, key_1) in keys.iter().enumerate() {
+            for key_2 in keys.iter().skip(i + 1) {
+                assert!((agreement(key_1, key_2) - 0.5).abs() < tolerance);
+            }
+        }
+    }
+}
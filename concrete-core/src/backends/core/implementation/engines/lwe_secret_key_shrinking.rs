@@ -0,0 +1,103 @@
+use concrete_commons::parameters::LweDimension;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweSecretKey32, LweSecretKey64};
+use crate::backends::core::private::crypto::secret::LweSecretKey as ImplLweSecretKey;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::specification::engines::{LweSecretKeyShrinkingEngine, LweSecretKeyShrinkingError};
+
+/// # Description:
+/// Implementation of [`LweSecretKeyShrinkingEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweSecretKeyShrinkingEngine<LweSecretKey32, LweSecretKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(4);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 =
+    ///     engine.shrink_lwe_secret_key(&input_key, output_lwe_dimension)?;
+    /// #
+    /// assert_eq!(output_key.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn shrink_lwe_secret_key(
+        &mut self,
+        input: &LweSecretKey32,
+        output_lwe_dimension: LweDimension,
+    ) -> Result<LweSecretKey32, LweSecretKeyShrinkingError<Self::EngineError>> {
+        LweSecretKeyShrinkingError::perform_generic_checks(input, output_lwe_dimension)?;
+        Ok(unsafe { self.shrink_lwe_secret_key_unchecked(input, output_lwe_dimension) })
+    }
+
+    unsafe fn shrink_lwe_secret_key_unchecked(
+        &mut self,
+        input: &LweSecretKey32,
+        output_lwe_dimension: LweDimension,
+    ) -> LweSecretKey32 {
+        LweSecretKey32(ImplLweSecretKey::binary_from_container(
+            input.0.as_tensor().as_slice()[..output_lwe_dimension.0].to_vec(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyShrinkingEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweSecretKeyShrinkingEngine<LweSecretKey64, LweSecretKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(4);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 =
+    ///     engine.shrink_lwe_secret_key(&input_key, output_lwe_dimension)?;
+    /// #
+    /// assert_eq!(output_key.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn shrink_lwe_secret_key(
+        &mut self,
+        input: &LweSecretKey64,
+        output_lwe_dimension: LweDimension,
+    ) -> Result<LweSecretKey64, LweSecretKeyShrinkingError<Self::EngineError>> {
+        LweSecretKeyShrinkingError::perform_generic_checks(input, output_lwe_dimension)?;
+        Ok(unsafe { self.shrink_lwe_secret_key_unchecked(input, output_lwe_dimension) })
+    }
+
+    unsafe fn shrink_lwe_secret_key_unchecked(
+        &mut self,
+        input: &LweSecretKey64,
+        output_lwe_dimension: LweDimension,
+    ) -> LweSecretKey64 {
+        LweSecretKey64(ImplLweSecretKey::binary_from_container(
+            input.0.as_tensor().as_slice()[..output_lwe_dimension.0].to_vec(),
+        ))
+    }
+}
@@ -0,0 +1,226 @@
+use crate::backends::core::engines::CoreEngine;
+use crate::backends::core::entities::{
+    GlweSecretKey128, GlweSecretKey16, GlweSecretKey32, GlweSecretKey64, LweSecretKey128,
+    LweSecretKey16, LweSecretKey32, LweSecretKey64,
+};
+use crate::backends::core::private::crypto::secret::GlweSecretKey as ImplGlweSecretKey;
+use crate::specification::engines::{
+    LweToGlweSecretKeyTransmutationEngine, LweToGlweSecretKeyTransmutationEngineError,
+};
+use concrete_commons::parameters::PolynomialSize;
+
+impl LweToGlweSecretKeyTransmutationEngine<LweSecretKey16, GlweSecretKey16> for CoreEngine {
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use concrete_commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(8);
+    /// let polynomial_size = PolynomialSize(1);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    ///
+    /// let lwe_secret_key: LweSecretKey16 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let glwe_secret_key =
+    ///     engine.transmute_lwe_secret_key_to_glwe_secret_key(lwe_secret_key, polynomial_size)?;
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), GlweDimension(8));
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transmute_lwe_secret_key_to_glwe_secret_key(
+        &mut self,
+        lwe_secret_key: LweSecretKey16,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweSecretKey16, LweToGlweSecretKeyTransmutationEngineError<Self::EngineError>>
+    {
+        LweToGlweSecretKeyTransmutationEngineError::perform_generic_checks(
+            &lwe_secret_key,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.transmute_lwe_secret_key_to_glwe_secret_key_unchecked(
+                lwe_secret_key,
+                polynomial_size,
+            )
+        })
+    }
+
+    unsafe fn transmute_lwe_secret_key_to_glwe_secret_key_unchecked(
+        &mut self,
+        lwe_secret_key: LweSecretKey16,
+        polynomial_size: PolynomialSize,
+    ) -> GlweSecretKey16 {
+        GlweSecretKey16(ImplGlweSecretKey::from_lwe_secret_key(
+            lwe_secret_key.0,
+            polynomial_size,
+        ))
+    }
+}
+
+impl LweToGlweSecretKeyTransmutationEngine<LweSecretKey32, GlweSecretKey32> for CoreEngine {
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use concrete_commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(8);
+    /// let polynomial_size = PolynomialSize(1);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    ///
+    /// let lwe_secret_key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let glwe_secret_key =
+    ///     engine.transmute_lwe_secret_key_to_glwe_secret_key(lwe_secret_key, polynomial_size)?;
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), GlweDimension(8));
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transmute_lwe_secret_key_to_glwe_secret_key(
+        &mut self,
+        lwe_secret_key: LweSecretKey32,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweSecretKey32, LweToGlweSecretKeyTransmutationEngineError<Self::EngineError>>
+    {
+        LweToGlweSecretKeyTransmutationEngineError::perform_generic_checks(
+            &lwe_secret_key,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.transmute_lwe_secret_key_to_glwe_secret_key_unchecked(
+                lwe_secret_key,
+                polynomial_size,
+            )
+        })
+    }
+
+    unsafe fn transmute_lwe_secret_key_to_glwe_secret_key_unchecked(
+        &mut self,
+        lwe_secret_key: LweSecretKey32,
+        polynomial_size: PolynomialSize,
+    ) -> GlweSecretKey32 {
+        GlweSecretKey32(ImplGlweSecretKey::from_lwe_secret_key(
+            lwe_secret_key.0,
+            polynomial_size,
+        ))
+    }
+}
+
+impl LweToGlweSecretKeyTransmutationEngine<LweSecretKey64, GlweSecretKey64> for CoreEngine {
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use concrete_commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(8);
+    /// let polynomial_size = PolynomialSize(1);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    ///
+    /// let lwe_secret_key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let glwe_secret_key =
+    ///     engine.transmute_lwe_secret_key_to_glwe_secret_key(lwe_secret_key, polynomial_size)?;
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), GlweDimension(8));
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transmute_lwe_secret_key_to_glwe_secret_key(
+        &mut self,
+        lwe_secret_key: LweSecretKey64,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweSecretKey64, LweToGlweSecretKeyTransmutationEngineError<Self::EngineError>>
+    {
+        LweToGlweSecretKeyTransmutationEngineError::perform_generic_checks(
+            &lwe_secret_key,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.transmute_lwe_secret_key_to_glwe_secret_key_unchecked(
+                lwe_secret_key,
+                polynomial_size,
+            )
+        })
+    }
+
+    unsafe fn transmute_lwe_secret_key_to_glwe_secret_key_unchecked(
+        &mut self,
+        lwe_secret_key: LweSecretKey64,
+        polynomial_size: PolynomialSize,
+    ) -> GlweSecretKey64 {
+        GlweSecretKey64(ImplGlweSecretKey::from_lwe_secret_key(
+            lwe_secret_key.0,
+            polynomial_size,
+        ))
+    }
+}
+
+impl LweToGlweSecretKeyTransmutationEngine<LweSecretKey128, GlweSecretKey128> for CoreEngine {
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use concrete_commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(8);
+    /// let polynomial_size = PolynomialSize(1);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    ///
+    /// let lwe_secret_key: LweSecretKey128 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let glwe_secret_key =
+    ///     engine.transmute_lwe_secret_key_to_glwe_secret_key(lwe_secret_key, polynomial_size)?;
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), GlweDimension(8));
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transmute_lwe_secret_key_to_glwe_secret_key(
+        &mut self,
+        lwe_secret_key: LweSecretKey128,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweSecretKey128, LweToGlweSecretKeyTransmutationEngineError<Self::EngineError>>
+    {
+        LweToGlweSecretKeyTransmutationEngineError::perform_generic_checks(
+            &lwe_secret_key,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.transmute_lwe_secret_key_to_glwe_secret_key_unchecked(
+                lwe_secret_key,
+                polynomial_size,
+            )
+        })
+    }
+
+    unsafe fn transmute_lwe_secret_key_to_glwe_secret_key_unchecked(
+        &mut self,
+        lwe_secret_key: LweSecretKey128,
+        polynomial_size: PolynomialSize,
+    ) -> GlweSecretKey128 {
+        GlweSecretKey128(ImplGlweSecretKey::from_lwe_secret_key(
+            lwe_secret_key.0,
+            polynomial_size,
+        ))
+    }
+}
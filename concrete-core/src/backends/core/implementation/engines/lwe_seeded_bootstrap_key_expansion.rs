@@ -0,0 +1,185 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweBootstrapKey32, LweBootstrapKey64, LweSeededBootstrapKey32, LweSeededBootstrapKey64,
+};
+use crate::backends::core::private::crypto::bootstrap::StandardBootstrapKey as ImplStandardBootstrapKey;
+use crate::specification::engines::{
+    LweSeededBootstrapKeyExpansionEngine, LweSeededBootstrapKeyExpansionError,
+};
+use crate::specification::entities::LweSeededBootstrapKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweSeededBootstrapKeyExpansionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. It regenerates the masks of the key from its seed.
+impl LweSeededBootstrapKeyExpansionEngine<LweSeededBootstrapKey32, LweBootstrapKey32>
+    for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(1024));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![1_u32 << 29; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let seeded_bsk: LweSeededBootstrapKey32 =
+    ///     engine.create_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let bsk: LweBootstrapKey32 = engine.expand_lwe_seeded_bootstrap_key(&seeded_bsk)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    ///
+    /// // The expanded key can be used as any bootstrap key.
+    /// let fourier_bsk: FourierLweBootstrapKey32 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let big_lwe_sk: LweSecretKey32 =
+    ///     engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let plaintext = engine.create_plaintext(&0_u32)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc: GlweCiphertext32 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input: LweCiphertext32 = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output: LweCiphertext32 = engine.zero_encrypt_lwe_ciphertext(&big_lwe_sk, noise)?;
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &fourier_bsk)?;
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&big_lwe_sk, &output)?;
+    /// let decoded = engine.retrieve_plaintext(&decrypted)?.wrapping_add(1 << 28) >> 29;
+    /// assert_eq!(decoded, 1);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(big_lwe_sk)?;
+    /// engine.destroy(seeded_bsk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_bootstrap_key(
+        &mut self,
+        input: &LweSeededBootstrapKey32,
+    ) -> Result<LweBootstrapKey32, LweSeededBootstrapKeyExpansionError<Self::EngineError>> {
+        Ok(unsafe { self.expand_lwe_seeded_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn expand_lwe_seeded_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweSeededBootstrapKey32,
+    ) -> LweBootstrapKey32 {
+        let mut output = ImplStandardBootstrapKey::allocate(
+            0u32,
+            input.glwe_dimension().to_glwe_size(),
+            input.polynomial_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+        );
+        input.0.expand_into(&mut output);
+        LweBootstrapKey32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededBootstrapKeyExpansionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. It regenerates the masks of the key from its seed.
+impl LweSeededBootstrapKeyExpansionEngine<LweSeededBootstrapKey64, LweBootstrapKey64>
+    for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(1024));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![1_u64 << 61; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let seeded_bsk: LweSeededBootstrapKey64 =
+    ///     engine.create_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let bsk: LweBootstrapKey64 = engine.expand_lwe_seeded_bootstrap_key(&seeded_bsk)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    ///
+    /// // The expanded key can be used as any bootstrap key.
+    /// let fourier_bsk: FourierLweBootstrapKey64 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let big_lwe_sk: LweSecretKey64 =
+    ///     engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let plaintext = engine.create_plaintext(&0_u64)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc: GlweCiphertext64 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input: LweCiphertext64 = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output: LweCiphertext64 = engine.zero_encrypt_lwe_ciphertext(&big_lwe_sk, noise)?;
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &fourier_bsk)?;
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&big_lwe_sk, &output)?;
+    /// let decoded = engine.retrieve_plaintext(&decrypted)?.wrapping_add(1 << 60) >> 61;
+    /// assert_eq!(decoded, 1);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(big_lwe_sk)?;
+    /// engine.destroy(seeded_bsk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_bootstrap_key(
+        &mut self,
+        input: &LweSeededBootstrapKey64,
+    ) -> Result<LweBootstrapKey64, LweSeededBootstrapKeyExpansionError<Self::EngineError>> {
+        Ok(unsafe { self.expand_lwe_seeded_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn expand_lwe_seeded_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweSeededBootstrapKey64,
+    ) -> LweBootstrapKey64 {
+        let mut output = ImplStandardBootstrapKey::allocate(
+            0u64,
+            input.glwe_dimension().to_glwe_size(),
+            input.polynomial_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+        );
+        input.0.expand_into(&mut output);
+        LweBootstrapKey64(output)
+    }
+}
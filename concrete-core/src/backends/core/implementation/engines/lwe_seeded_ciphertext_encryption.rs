@@ -0,0 +1,170 @@
+use concrete_commons::dispersion::{BoundedUniform, NoiseDistribution, TUniform, Variance};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweSecretKey32, LweSecretKey64, LweSeededCiphertext32, LweSeededCiphertext64, Plaintext32,
+    Plaintext64,
+};
+use crate::backends::core::private::crypto::lwe::LweSeededCiphertext as ImplLweSeededCiphertext;
+use crate::specification::engines::{
+    LweSeededCiphertextEncryptionEngine, LweSeededCiphertextEncryptionError,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextEncryptionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweSeededCiphertextEncryptionEngine<LweSecretKey32, Plaintext32, LweSeededCiphertext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(630);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext: LweSeededCiphertext32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    /// // Only the body is stored, along with the seed.
+    /// assert!(ciphertext.compression_ratio() > 1.);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_seeded_ciphertext(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> Result<LweSeededCiphertext32, LweSeededCiphertextEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_seeded_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_seeded_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> LweSeededCiphertext32 {
+        let mut ciphertext = ImplLweSeededCiphertext::allocate(
+            0u32,
+            key.lwe_dimension().to_lwe_size(),
+            self.encryption_generator.random_seed(),
+        );
+        // The ciphertext is encrypted with a generator seeded from the mask seed, so the noise
+        // distribution of the engine is applied to the dispersion beforehand.
+        match self.encryption_generator.noise_distribution() {
+            NoiseDistribution::Gaussian => {
+                key.0.encrypt_seeded_lwe(&mut ciphertext, &input.0, noise)
+            }
+            NoiseDistribution::BoundedUniform => key.0.encrypt_seeded_lwe(
+                &mut ciphertext,
+                &input.0,
+                BoundedUniform::from_dispersion(noise),
+            ),
+            NoiseDistribution::TUniform => key.0.encrypt_seeded_lwe(
+                &mut ciphertext,
+                &input.0,
+                TUniform::from_dispersion(noise),
+            ),
+        }
+        self.audit_creation(LweSeededCiphertext32(ciphertext))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextEncryptionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweSeededCiphertextEncryptionEngine<LweSecretKey64, Plaintext64, LweSeededCiphertext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(630);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext: LweSeededCiphertext64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    /// // Only the body is stored, along with the seed.
+    /// assert!(ciphertext.compression_ratio() > 1.);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_seeded_ciphertext(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> Result<LweSeededCiphertext64, LweSeededCiphertextEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_seeded_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_seeded_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> LweSeededCiphertext64 {
+        let mut ciphertext = ImplLweSeededCiphertext::allocate(
+            0u64,
+            key.lwe_dimension().to_lwe_size(),
+            self.encryption_generator.random_seed(),
+        );
+        // The ciphertext is encrypted with a generator seeded from the mask seed, so the noise
+        // distribution of the engine is applied to the dispersion beforehand.
+        match self.encryption_generator.noise_distribution() {
+            NoiseDistribution::Gaussian => {
+                key.0.encrypt_seeded_lwe(&mut ciphertext, &input.0, noise)
+            }
+            NoiseDistribution::BoundedUniform => key.0.encrypt_seeded_lwe(
+                &mut ciphertext,
+                &input.0,
+                BoundedUniform::from_dispersion(noise),
+            ),
+            NoiseDistribution::TUniform => key.0.encrypt_seeded_lwe(
+                &mut ciphertext,
+                &input.0,
+                TUniform::from_dispersion(noise),
+            ),
+        }
+        self.audit_creation(LweSeededCiphertext64(ciphertext))
+    }
+}
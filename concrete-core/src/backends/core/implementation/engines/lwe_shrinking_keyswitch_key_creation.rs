@@ -0,0 +1,282 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+use crate::backends::core::implementation::engines::{CoreEngine, CoreError};
+use crate::backends::core::implementation::entities::{
+    LweSecretKey32, LweSecretKey64, LweShrinkingKeyswitchKey32, LweShrinkingKeyswitchKey64,
+};
+use crate::backends::core::private::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
+use crate::backends::core::private::crypto::secret::LweSecretKey as ImplLweSecretKey;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::specification::engines::{
+    LweShrinkingKeyswitchKeyCreationEngine, LweShrinkingKeyswitchKeyCreationError,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+
+impl From<CoreError> for LweShrinkingKeyswitchKeyCreationError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweShrinkingKeyswitchKeyCreationEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+///
+/// On top of the generic checks, this implementation checks that the output key is a prefix of
+/// the input key, and returns [`CoreError::KeyPrefixMismatch`] otherwise.
+impl
+    LweShrinkingKeyswitchKeyCreationEngine<
+        LweSecretKey32,
+        LweSecretKey32,
+        LweShrinkingKeyswitchKey32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 =
+    ///     engine.shrink_lwe_secret_key(&input_key, output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key: LweShrinkingKeyswitchKey32 = engine.create_lwe_shrinking_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_shrinking_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &LweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweShrinkingKeyswitchKey32, LweShrinkingKeyswitchKeyCreationError<Self::EngineError>>
+    {
+        LweShrinkingKeyswitchKeyCreationError::perform_generic_checks(
+            input_key,
+            output_key,
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        let shared_size = output_key.lwe_dimension().0;
+        if input_key.0.as_tensor().as_slice()[..shared_size] != *output_key.0.as_tensor().as_slice()
+        {
+            return Err(LweShrinkingKeyswitchKeyCreationError::Engine(
+                CoreError::KeyPrefixMismatch,
+            ));
+        }
+        self.check_key_generation_cancellation()?;
+        let key = unsafe {
+            self.create_lwe_shrinking_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        };
+        self.check_key_generation_cancellation()?;
+        Ok(key)
+    }
+
+    unsafe fn create_lwe_shrinking_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &LweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweShrinkingKeyswitchKey32 {
+        // Only the coefficients of the input key which are not shared with the output key are
+        // switched.
+        let shared_size = output_key.lwe_dimension().0;
+        let unshared_key = ImplLweSecretKey::binary_from_container(
+            input_key.0.as_tensor().as_slice()[shared_size..].to_vec(),
+        );
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            unshared_key.key_size(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key_monitored(
+            &unshared_key,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+            self.key_generation_monitor
+                .reporter(ksk.before_key_size().0),
+        );
+        self.audit_creation(LweShrinkingKeyswitchKey32(ksk))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweShrinkingKeyswitchKeyCreationEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+///
+/// On top of the generic checks, this implementation checks that the output key is a prefix of
+/// the input key, and returns [`CoreError::KeyPrefixMismatch`] otherwise.
+impl
+    LweShrinkingKeyswitchKeyCreationEngine<
+        LweSecretKey64,
+        LweSecretKey64,
+        LweShrinkingKeyswitchKey64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 =
+    ///     engine.shrink_lwe_secret_key(&input_key, output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key: LweShrinkingKeyswitchKey64 = engine.create_lwe_shrinking_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_shrinking_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &LweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweShrinkingKeyswitchKey64, LweShrinkingKeyswitchKeyCreationError<Self::EngineError>>
+    {
+        LweShrinkingKeyswitchKeyCreationError::perform_generic_checks(
+            input_key,
+            output_key,
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        let shared_size = output_key.lwe_dimension().0;
+        if input_key.0.as_tensor().as_slice()[..shared_size] != *output_key.0.as_tensor().as_slice()
+        {
+            return Err(LweShrinkingKeyswitchKeyCreationError::Engine(
+                CoreError::KeyPrefixMismatch,
+            ));
+        }
+        self.check_key_generation_cancellation()?;
+        let key = unsafe {
+            self.create_lwe_shrinking_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        };
+        self.check_key_generation_cancellation()?;
+        Ok(key)
+    }
+
+    unsafe fn create_lwe_shrinking_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &LweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweShrinkingKeyswitchKey64 {
+        // Only the coefficients of the input key which are not shared with the output key are
+        // switched.
+        let shared_size = output_key.lwe_dimension().0;
+        let unshared_key = ImplLweSecretKey::binary_from_container(
+            input_key.0.as_tensor().as_slice()[shared_size..].to_vec(),
+        );
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            unshared_key.key_size(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key_monitored(
+            &unshared_key,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+            self.key_generation_monitor
+                .reporter(ksk.before_key_size().0),
+        );
+        self.audit_creation(LweShrinkingKeyswitchKey64(ksk))
+    }
+}
@@ -4,15 +4,27 @@ use std::collections::BTreeMap;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
+use concrete_commons::dispersion::NoiseDistribution;
 use concrete_commons::parameters::{GlweSize, PolynomialSize};
 
-use crate::backends::core::private::crypto::bootstrap::FourierBuffers;
+#[cfg(feature = "audit")]
+use crate::backends::core::implementation::audit::{
+    AuditAction, AuditLog, AuditSink, AuditedEntity,
+};
+use crate::backends::core::implementation::entities::EncodingMetadata;
+use crate::backends::core::implementation::progress::KeyGenerationMonitor;
+use crate::backends::core::private::crypto::bootstrap::{supports_small_bootstrap, FourierBuffers};
 use crate::backends::core::private::crypto::secret::generators::{
-    EncryptionRandomGenerator as ImplEncryptionRandomGenerator,
+    derive_seed, EncryptionRandomGenerator as ImplEncryptionRandomGenerator,
     SecretRandomGenerator as ImplSecretRandomGenerator,
 };
+pub use crate::backends::core::private::math::fft::FftPrecision;
 use crate::specification::engines::sealed::AbstractEngineSeal;
 use crate::specification::engines::AbstractEngine;
+#[cfg(feature = "serde_serialize")]
+pub use entity_serialization::{
+    SerializationHeader, SerializedEntityKind, SERIALIZATION_FORMAT_VERSION,
+};
 
 /// The error which can occur in the execution of FHE operations, due to the core implementation.
 ///
@@ -24,6 +36,18 @@ use crate::specification::engines::AbstractEngine;
 pub enum CoreError {
     Borrow,
     UnsupportedPolynomialSize,
+    EncodingMismatch,
+    MessageOverflow,
+    NoiseOverflow,
+    UnsupportedEncoding,
+    KeyPrefixMismatch,
+    Cancelled,
+    ValueOutOfRange,
+    UnsupportedScalingFactor,
+    Serialization,
+    Deserialization,
+    SerializedEntityMismatch,
+    UnsupportedSerializationVersion,
 }
 
 impl Display for CoreError {
@@ -39,12 +63,119 @@ impl Display for CoreError {
                 1024, 2048, 4096, 8192, 16384."
                 )
             }
+            CoreError::EncodingMismatch => {
+                write!(
+                    f,
+                    "The tagged ciphertexts must use the same delta, message modulus and \
+                    carry modulus."
+                )
+            }
+            CoreError::MessageOverflow => {
+                write!(
+                    f,
+                    "The operation would overflow the carry space of the tagged ciphertext."
+                )
+            }
+            CoreError::NoiseOverflow => {
+                write!(
+                    f,
+                    "The operation would exceed the noise budget of the accumulating ciphertext."
+                )
+            }
+            CoreError::UnsupportedEncoding => {
+                write!(
+                    f,
+                    "The encoding of the accumulating ciphertext can not be bootstrapped."
+                )
+            }
+            CoreError::KeyPrefixMismatch => {
+                write!(
+                    f,
+                    "The output secret key must be a prefix of the input secret key."
+                )
+            }
+            CoreError::Cancelled => {
+                write!(f, "The key generation was cancelled.")
+            }
+            CoreError::ValueOutOfRange => {
+                write!(f, "A value does not fit in the requested integer type.")
+            }
+            CoreError::UnsupportedScalingFactor => {
+                write!(
+                    f,
+                    "The Core Backend only supports scaling factors which are powers of two."
+                )
+            }
+            CoreError::Serialization => {
+                write!(f, "The entity could not be serialized.")
+            }
+            CoreError::Deserialization => {
+                write!(f, "The serialized entity is malformed.")
+            }
+            CoreError::SerializedEntityMismatch => {
+                write!(
+                    f,
+                    "The serialized entity is not of the requested entity type."
+                )
+            }
+            CoreError::UnsupportedSerializationVersion => {
+                write!(
+                    f,
+                    "The serialized entity uses an unsupported version of the format."
+                )
+            }
         }
     }
 }
 
 impl Error for CoreError {}
 
+/// The behavior of the engine when an operation on tagged ciphertexts would overflow their carry
+/// space.
+///
+/// The engines are created with the [`OverflowPolicy::Error`] policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// The checked entry points return a [`CoreError::MessageOverflow`] error, and the operation
+    /// is not performed.
+    Error,
+    /// The operation is performed, and the metadata of its result is recorded by the engine, to
+    /// be retrieved with [`CoreEngine::take_overflow_warnings`].
+    Warn,
+}
+
+/// The strategy used by the engine to select the kernel of the bootstraps.
+///
+/// The engines are created with the [`BootstrapDispatch::Generic`] strategy: the
+/// `bootstrap_dispatch` benchmark of `concrete-benchmark` measures the generic kernel faster than
+/// the small kernel on x86_64, for all the keys supported by the latter. The selection is made at
+/// runtime from the parameters of each bootstrap key, so that a single engine can serve keys of
+/// different polynomial sizes with the same entity types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BootstrapDispatch {
+    /// All the bootstraps use the generic kernel, backed by the fftw plans, which supports all
+    /// the polynomial sizes accepted by the engine.
+    Generic,
+    /// The bootstraps with a key supported by the small kernel use it, and the other ones fall
+    /// back to the generic kernel. The small kernel handles polynomial sizes up to 1024 and GLWE
+    /// sizes up to 3 with the standard Fourier precision, and does not allocate.
+    Specialized,
+}
+
+impl BootstrapDispatch {
+    // Returns whether the small kernel must be used for a key with the given parameters.
+    pub(crate) fn selects_small_kernel(
+        self,
+        poly_size: PolynomialSize,
+        glwe_size: GlweSize,
+        precision: FftPrecision,
+    ) -> bool {
+        self == BootstrapDispatch::Specialized
+            && precision == FftPrecision::Standard
+            && supports_small_bootstrap(poly_size, glwe_size)
+    }
+}
+
 #[derive(Ord, PartialOrd, Eq, PartialEq)]
 pub(crate) struct FourierBufferKey(pub PolynomialSize, pub GlweSize);
 
@@ -63,9 +194,388 @@ pub struct CoreEngine {
     encryption_generator: ImplEncryptionRandomGenerator,
     fourier_buffers_u32: BTreeMap<FourierBufferKey, FourierBuffers<u32>>,
     fourier_buffers_u64: BTreeMap<FourierBufferKey, FourierBuffers<u64>>,
+    key_generation_monitor: KeyGenerationMonitor,
+    #[cfg(feature = "audit")]
+    audit_log: AuditLog,
+    overflow_policy: OverflowPolicy,
+    overflow_warnings: Vec<EncodingMetadata>,
+    fft_precision: FftPrecision,
+    bootstrap_dispatch: BootstrapDispatch,
 }
 
 impl CoreEngine {
+    /// Creates a new engine, whose random generators are all seeded from the given value.
+    ///
+    /// Contrarily to [`AbstractEngine::new`], no entropy is drawn from the platform, which allows
+    /// to use the engine where neither the `rdseed` instruction nor the os source of randomness
+    /// are available, such as in some containers, CI sandboxes or WASM environments.
+    ///
+    /// # Warning:
+    ///
+    /// Every key and ciphertext generated by the engine is determined by the seed. This
+    /// constructor is only meant for testing purpose, and must not be used to protect actual data.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine_1 = CoreEngine::new_seeded(42);
+    /// let mut engine_2 = CoreEngine::new_seeded(42);
+    /// let key_1: LweSecretKey64 = engine_1.create_lwe_secret_key(LweDimension(4))?;
+    /// let key_2: LweSecretKey64 = engine_2.create_lwe_secret_key(LweDimension(4))?;
+    /// assert_eq!(key_1, key_2);
+    ///
+    /// engine_1.destroy(key_1)?;
+    /// engine_2.destroy(key_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_seeded(seed: u128) -> CoreEngine {
+        CoreEngine::new_seeded_with_fft_precision(seed, FftPrecision::Standard)
+    }
+
+    /// Creates an engine converting the 64 bits bootstrap keys to the Fourier domain with the
+    /// given arithmetic strategy.
+    ///
+    /// The engines created by [`AbstractEngine::new`] use the [`FftPrecision::Standard`]
+    /// precision. With 64 bits ciphertexts and large decomposition bases, the rounding errors of
+    /// the `f64` arithmetic can exceed the noise of the ciphertexts: the [`FftPrecision::Split`]
+    /// precision reduces them, at the cost of twice the memory and computations during the
+    /// bootstraps, and the [`FftPrecision::DoubleDouble`] precision removes them, at the cost of
+    /// much slower bootstraps. The precision is recorded in the converted keys, and the 32 bits
+    /// keys, which fit in the mantissa of a `f64`, always use the standard precision.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(1024));
+    /// // A single level with a large base, which stresses the precision of the products.
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(1), DecompositionBaseLog(23));
+    /// let noise = Variance(2_f64.powf(-100.));
+    ///
+    /// let mut engine = CoreEngine::new_with_fft_precision(FftPrecision::Split)?;
+    /// assert_eq!(engine.fft_precision(), FftPrecision::Split);
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let fourier_bsk: FourierLweBootstrapKey64 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// assert_eq!(fourier_bsk.fft_precision(), FftPrecision::Split);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_fft_precision(fft_precision: FftPrecision) -> Result<CoreEngine, CoreError> {
+        Ok(CoreEngine::from_generators(
+            ImplSecretRandomGenerator::new(None),
+            ImplEncryptionRandomGenerator::new(None),
+            fft_precision,
+        ))
+    }
+
+    /// Creates an engine whose random generators are deterministically derived from `seed`, and
+    /// which converts the 64 bits bootstrap keys to the Fourier domain with the given arithmetic
+    /// strategy.
+    ///
+    /// See [`CoreEngine::new_seeded`] and [`CoreEngine::new_with_fft_precision`].
+    ///
+    /// # Warning:
+    ///
+    /// Every key and ciphertext generated by the engine is determined by the seed. This
+    /// constructor is only meant for testing purpose, and must not be used to protect actual data.
+    pub fn new_seeded_with_fft_precision(seed: u128, fft_precision: FftPrecision) -> CoreEngine {
+        CoreEngine::from_generators(
+            ImplSecretRandomGenerator::new_derived(seed, 0, 0),
+            ImplEncryptionRandomGenerator::new_seeded(
+                derive_seed(seed, 1, 0),
+                derive_seed(seed, 2, 0),
+            ),
+            fft_precision,
+        )
+    }
+
+    /// Attaches a monitor to the engine, following the subsequent key generations.
+    ///
+    /// See the [`progress`](crate::backends::core::progress) module for more details.
+    pub fn set_key_generation_monitor(&mut self, monitor: KeyGenerationMonitor) {
+        self.key_generation_monitor = monitor;
+    }
+
+    /// Detaches the key generation monitor of the engine, if any.
+    pub fn clear_key_generation_monitor(&mut self) {
+        self.key_generation_monitor = KeyGenerationMonitor::new();
+    }
+
+    /// Attaches a sink to the engine, recording the subsequent creations and destructions of
+    /// entities.
+    ///
+    /// See the [`audit`](crate::backends::core::audit) module for more details.
+    #[cfg(feature = "audit")]
+    pub fn set_audit_sink<Sink>(&mut self, sink: Sink)
+    where
+        Sink: AuditSink + 'static,
+    {
+        self.audit_log.set_sink(Box::new(sink));
+    }
+
+    /// Detaches the audit sink of the engine, if any.
+    #[cfg(feature = "audit")]
+    pub fn clear_audit_sink(&mut self) {
+        self.audit_log.clear_sink();
+    }
+
+    /// Sets the distribution the noise of the subsequent encryptions is sampled from.
+    ///
+    /// The engines take the noise as a [`Variance`](concrete_commons::dispersion::Variance).
+    /// By default, it is the variance of a gaussian distribution. Once another distribution is
+    /// set, the noise is instead sampled from the distribution of this family with the same
+    /// variance on the torus. The secret keys are not affected.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::{DispersionParameter, NoiseDistribution, TUniform};
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let mut engine = CoreEngine::new()?;
+    /// engine.set_noise_distribution(NoiseDistribution::TUniform);
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(4))?;
+    /// let plaintext = engine.create_plaintext(&(3_u64 << 50))?;
+    /// // The noise is sampled from the TUniform distribution bounded by 2^-40 on the torus.
+    /// let noise = Variance(TUniform::from_log_bound(-40.).get_variance());
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_noise_distribution(&mut self, noise_distribution: NoiseDistribution) {
+        self.encryption_generator
+            .set_noise_distribution(noise_distribution);
+    }
+
+    /// Returns the distribution the noise of the encryptions is sampled from.
+    pub fn noise_distribution(&self) -> NoiseDistribution {
+        self.encryption_generator.noise_distribution()
+    }
+
+    /// Sets the behavior of the engine when an operation on tagged ciphertexts would overflow
+    /// their carry space.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// // Here a hard-set encoding is applied (2 bits of message, 2 bits of carry, shift by 59 bits)
+    /// let delta = 1_u64 << 59;
+    /// let metadata = EncodingMetadata {
+    ///     delta: ScalingFactor(delta),
+    ///     message_modulus: 4,
+    ///     carry_modulus: 4,
+    ///     degree: 3,
+    /// };
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// engine.set_overflow_policy(OverflowPolicy::Warn);
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(2))?;
+    /// let plaintext = engine.create_plaintext(&(3 * delta))?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let input = TaggedCiphertext::new(ciphertext.clone(), metadata);
+    /// let mut accumulator = TaggedCiphertext::new(ciphertext, metadata);
+    ///
+    /// // The degrees sum up to 15, which fills the carry space.
+    /// for _ in 0..4 {
+    ///     engine.fuse_add_lwe_ciphertext(&mut accumulator, &input)?;
+    /// }
+    /// assert!(engine.take_overflow_warnings().is_empty());
+    /// // The next addition overflows the carry space, and is reported as a warning.
+    /// engine.fuse_add_lwe_ciphertext(&mut accumulator, &input)?;
+    /// assert_eq!(engine.take_overflow_warnings().len(), 1);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(accumulator)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_overflow_policy(&mut self, overflow_policy: OverflowPolicy) {
+        self.overflow_policy = overflow_policy;
+    }
+
+    /// Returns the behavior of the engine when an operation would overflow the carry space.
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        self.overflow_policy
+    }
+
+    /// Returns the metadata of the results which overflowed their carry space since the last
+    /// call, under the [`OverflowPolicy::Warn`] policy.
+    pub fn take_overflow_warnings(&mut self) -> Vec<EncodingMetadata> {
+        std::mem::take(&mut self.overflow_warnings)
+    }
+
+    /// Returns the arithmetic strategy of the conversions of 64 bits bootstrap keys to the Fourier
+    /// domain.
+    pub fn fft_precision(&self) -> FftPrecision {
+        self.fft_precision
+    }
+
+    /// Sets the strategy used to select the kernel of the subsequent bootstraps.
+    ///
+    /// The kernels compute the same function, and the rounding errors of their transforms are of
+    /// the same magnitude. The generic kernel is selected by default, and
+    /// [`BootstrapDispatch::Specialized`] selects the small kernel for the keys it supports, which
+    /// does not allocate, and can be faster on the targets where fftw has no simd codelets.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dim = LweDimension(4);
+    /// let glwe_dim = GlweDimension(1);
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// assert_eq!(engine.bootstrap_dispatch(), BootstrapDispatch::Generic);
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let plaintext = engine.create_plaintext(&(3_u32 << 20))?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    ///
+    /// // The same engine bootstraps with keys of different polynomial sizes: the first one uses the
+    /// // small kernel, and the second one the generic kernel.
+    /// engine.set_bootstrap_dispatch(BootstrapDispatch::Specialized);
+    /// for poly_size in [PolynomialSize(512), PolynomialSize(2048)] {
+    ///     let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    ///     let bsk: FourierLweBootstrapKey32 =
+    ///         engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///     let lut = engine.create_plaintext_vector(&vec![8_u32 << 20; poly_size.0])?;
+    ///     let acc = engine.trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &lut)?;
+    ///     let output_sk: LweSecretKey32 =
+    ///         engine.create_lwe_secret_key(LweDimension(glwe_dim.0 * poly_size.0))?;
+    ///     let mut output = engine.zero_encrypt_lwe_ciphertext(&output_sk, noise)?;
+    ///
+    ///     engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    ///
+    ///     engine.destroy(glwe_sk)?;
+    ///     engine.destroy(bsk)?;
+    ///     engine.destroy(lut)?;
+    ///     engine.destroy(acc)?;
+    ///     engine.destroy(output_sk)?;
+    ///     engine.destroy(output)?;
+    /// }
+    ///
+    /// // All the subsequent bootstraps use the generic kernel.
+    /// engine.set_bootstrap_dispatch(BootstrapDispatch::Generic);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(input)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_bootstrap_dispatch(&mut self, bootstrap_dispatch: BootstrapDispatch) {
+        self.bootstrap_dispatch = bootstrap_dispatch;
+    }
+
+    /// Returns the strategy used to select the kernel of the bootstraps.
+    pub fn bootstrap_dispatch(&self) -> BootstrapDispatch {
+        self.bootstrap_dispatch
+    }
+
+    // Applies the overflow policy to the metadata of the result of an operation on tagged
+    // ciphertexts, computed with (`checked`) and without (`saturated`) overflow checks.
+    pub(crate) fn check_carry_overflow(
+        &mut self,
+        checked: Result<EncodingMetadata, CoreError>,
+        saturated: EncodingMetadata,
+    ) -> Result<(), CoreError> {
+        match (checked, self.overflow_policy) {
+            (Err(CoreError::MessageOverflow), OverflowPolicy::Warn) => {
+                self.overflow_warnings.push(saturated);
+                Ok(())
+            }
+            (checked, _) => checked.map(|_| ()),
+        }
+    }
+
+    // Returns an error if the key generations were cancelled.
+    pub(crate) fn check_key_generation_cancellation(&self) -> Result<(), CoreError> {
+        if self.key_generation_monitor.is_cancelled() {
+            Err(CoreError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+
+    // Records the creation of an entity in the audit log, and returns it.
+    #[cfg(feature = "audit")]
+    pub(crate) fn audit_creation<Entity: AuditedEntity>(&mut self, entity: Entity) -> Entity {
+        self.audit_log
+            .record::<Self, _>(AuditAction::Creation, &entity);
+        entity
+    }
+
+    #[cfg(not(feature = "audit"))]
+    #[inline(always)]
+    pub(crate) fn audit_creation<Entity>(&mut self, entity: Entity) -> Entity {
+        entity
+    }
+
+    // Records the destruction of an entity in the audit log.
+    #[cfg(feature = "audit")]
+    pub(crate) fn audit_destruction<Entity: AuditedEntity>(&mut self, entity: &Entity) {
+        self.audit_log
+            .record::<Self, _>(AuditAction::Destruction, entity);
+    }
+
+    #[cfg(not(feature = "audit"))]
+    #[inline(always)]
+    pub(crate) fn audit_destruction<Entity>(&mut self, _entity: &Entity) {}
+
     pub(crate) fn get_fourier_u32_buffer(
         &mut self,
         poly_size: PolynomialSize,
@@ -87,6 +597,27 @@ impl CoreEngine {
             .entry(buffer_key)
             .or_insert_with(|| FourierBuffers::for_params(poly_size, glwe_size))
     }
+
+    // Creates an engine with the default settings around the given generators.
+    fn from_generators(
+        secret_generator: ImplSecretRandomGenerator,
+        encryption_generator: ImplEncryptionRandomGenerator,
+        fft_precision: FftPrecision,
+    ) -> CoreEngine {
+        CoreEngine {
+            secret_generator,
+            encryption_generator,
+            fourier_buffers_u32: Default::default(),
+            fourier_buffers_u64: Default::default(),
+            key_generation_monitor: Default::default(),
+            #[cfg(feature = "audit")]
+            audit_log: Default::default(),
+            overflow_policy: OverflowPolicy::Error,
+            overflow_warnings: Vec::new(),
+            fft_precision,
+            bootstrap_dispatch: BootstrapDispatch::Generic,
+        }
+    }
 }
 
 impl AbstractEngineSeal for CoreEngine {}
@@ -95,12 +626,7 @@ impl AbstractEngine for CoreEngine {
     type EngineError = CoreError;
 
     fn new() -> Result<Self, Self::EngineError> {
-        Ok(CoreEngine {
-            secret_generator: ImplSecretRandomGenerator::new(None),
-            encryption_generator: ImplEncryptionRandomGenerator::new(None),
-            fourier_buffers_u32: Default::default(),
-            fourier_buffers_u64: Default::default(),
-        })
+        CoreEngine::new_with_fft_precision(FftPrecision::Standard)
     }
 }
 
@@ -109,71 +635,148 @@ mod cleartext_discarding_retrieval;
 mod cleartext_retrieval;
 mod cleartext_vector_creation;
 mod cleartext_vector_discarding_retrieval;
+mod cleartext_vector_negacyclic_convolution;
 mod cleartext_vector_retrieval;
 mod destruction;
+mod entity_constant_time_equality;
+#[cfg(feature = "serde_serialize")]
+mod entity_deserialization;
+#[cfg(feature = "serde_serialize")]
+mod entity_serialization;
+mod ggsw_ciphertext_cleartext_trivial_encryption;
+mod ggsw_ciphertext_consuming_retrieval;
 mod ggsw_ciphertext_conversion;
 mod ggsw_ciphertext_discarding_conversion;
 mod ggsw_ciphertext_scalar_discarding_encryption;
 mod ggsw_ciphertext_scalar_encryption;
 mod ggsw_ciphertext_scalar_trivial_encryption;
+mod ggsw_seeded_ciphertext_expansion;
+mod ggsw_seeded_ciphertext_scalar_encryption;
+mod glwe_automorphism_key_creation;
+mod glwe_ciphertext_blind_rotation;
+mod glwe_ciphertext_cleartext_vector_discarding_multiplication;
 mod glwe_ciphertext_conversion;
 mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_discarding_addition;
 mod glwe_ciphertext_discarding_decryption;
 mod glwe_ciphertext_discarding_encryption;
+mod glwe_ciphertext_discarding_offset_addition;
+mod glwe_ciphertext_discarding_relinearization;
+mod glwe_ciphertext_discarding_tensor_product;
+mod glwe_ciphertext_discarding_trivial_encryption;
 mod glwe_ciphertext_encryption;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
+mod glwe_ciphertext_layout_consuming_retrieval;
+mod glwe_ciphertext_layout_creation;
+mod glwe_ciphertext_trace;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
 mod glwe_ciphertext_vector_decryption;
 mod glwe_ciphertext_vector_discarding_decryption;
 mod glwe_ciphertext_vector_discarding_encryption;
 mod glwe_ciphertext_vector_encryption;
+mod glwe_ciphertext_vector_layout_conversion;
 mod glwe_ciphertext_vector_trivial_decryption;
 mod glwe_ciphertext_vector_trivial_encryption;
 mod glwe_ciphertext_vector_zero_encryption;
 mod glwe_ciphertext_zero_encryption;
+mod glwe_relinearization_key_creation;
 mod glwe_secret_key_creation;
 mod glwe_secret_key_to_lwe_secret_key_transmutation;
+mod implementations;
+mod lwe_bootstrap_key_construction;
+mod lwe_bootstrap_key_consuming_retrieval;
 mod lwe_bootstrap_key_conversion;
 mod lwe_bootstrap_key_creation;
+mod lwe_ciphertext_accumulation;
 mod lwe_ciphertext_cleartext_discarding_multiplication;
+mod lwe_ciphertext_cleartext_discarding_rational_multiplication;
 mod lwe_ciphertext_cleartext_fusing_multiplication;
+mod lwe_ciphertext_cleartext_fusing_multiply_add;
+mod lwe_ciphertext_conversion;
 mod lwe_ciphertext_decryption;
 mod lwe_ciphertext_discarding_addition;
+mod lwe_ciphertext_discarding_batched_keyswitch;
 mod lwe_ciphertext_discarding_bootstrap;
 mod lwe_ciphertext_discarding_decryption;
 mod lwe_ciphertext_discarding_encryption;
 mod lwe_ciphertext_discarding_extraction;
 mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_discarding_large_table_lookup;
+mod lwe_ciphertext_discarding_modulus_switch;
+mod lwe_ciphertext_discarding_multiplication;
 mod lwe_ciphertext_discarding_opposite;
+mod lwe_ciphertext_discarding_shrinking_keyswitch;
 mod lwe_ciphertext_discarding_subtraction;
+mod lwe_ciphertext_discarding_trivial_encryption;
 mod lwe_ciphertext_encryption;
 mod lwe_ciphertext_fusing_addition;
+mod lwe_ciphertext_fusing_noise_injection;
 mod lwe_ciphertext_fusing_opposite;
 mod lwe_ciphertext_fusing_subtraction;
+mod lwe_ciphertext_gadget_product;
+mod lwe_ciphertext_jagged_vector_creation;
+mod lwe_ciphertext_jagged_vector_discarding_row_summation;
+mod lwe_ciphertext_jagged_vector_fusing_addition;
 mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_discarding_subtraction;
 mod lwe_ciphertext_plaintext_fusing_addition;
 mod lwe_ciphertext_plaintext_fusing_subtraction;
+mod lwe_ciphertext_public_key_encryption;
 mod lwe_ciphertext_trivial_decryption;
 mod lwe_ciphertext_trivial_encryption;
+mod lwe_ciphertext_truncated_view_creation;
+mod lwe_ciphertext_vector_consuming_retrieval;
+mod lwe_ciphertext_vector_conversion;
+mod lwe_ciphertext_vector_creation;
 mod lwe_ciphertext_vector_decryption;
 mod lwe_ciphertext_vector_discarding_addition;
 mod lwe_ciphertext_vector_discarding_affine_transformation;
+mod lwe_ciphertext_vector_discarding_cmux;
 mod lwe_ciphertext_vector_discarding_decryption;
 mod lwe_ciphertext_vector_discarding_encryption;
+mod lwe_ciphertext_vector_discarding_extremum;
+mod lwe_ciphertext_vector_discarding_gather;
+mod lwe_ciphertext_vector_discarding_keyswitch;
+mod lwe_ciphertext_vector_discarding_parity;
+mod lwe_ciphertext_vector_discarding_permutation;
+mod lwe_ciphertext_vector_discarding_popcount;
+mod lwe_ciphertext_vector_discarding_scatter;
 mod lwe_ciphertext_vector_discarding_subtraction;
 mod lwe_ciphertext_vector_encryption;
 mod lwe_ciphertext_vector_fusing_addition;
 mod lwe_ciphertext_vector_fusing_subtraction;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
+mod lwe_ciphertext_vector_layout_consuming_retrieval;
+mod lwe_ciphertext_vector_layout_conversion;
+mod lwe_ciphertext_vector_layout_creation;
+mod lwe_ciphertext_vector_noise_statistics;
+mod lwe_ciphertext_vector_phase_exporting_decryption;
+mod lwe_ciphertext_vector_plaintext_vector_discarding_subtraction;
+mod lwe_ciphertext_vector_plaintext_vector_fusing_subtraction;
 mod lwe_ciphertext_vector_trivial_decryption;
 mod lwe_ciphertext_vector_trivial_encryption;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_ciphertext_zero_encryption;
+mod lwe_keyswitch_key_batch_appending;
+mod lwe_keyswitch_key_batch_creation;
+mod lwe_keyswitch_key_batch_removal;
+mod lwe_keyswitch_key_conversion;
 mod lwe_keyswitch_key_creation;
+mod lwe_keyswitch_key_delta_creation;
+mod lwe_keyswitch_key_fusing_update;
+mod lwe_phase_vector_retrieval;
+mod lwe_public_key_creation;
 mod lwe_secret_key_creation;
+mod lwe_secret_key_derivation;
+mod lwe_secret_key_shrinking;
+mod lwe_secret_key_to_glwe_secret_key_transmutation;
+mod lwe_seeded_bootstrap_key_creation;
+mod lwe_seeded_bootstrap_key_expansion;
+mod lwe_seeded_ciphertext_encryption;
+mod lwe_seeded_ciphertext_expansion;
+mod lwe_shrinking_keyswitch_key_creation;
 mod packing_keyswitch_key_creation;
 mod plaintext_creation;
 mod plaintext_discarding_retrieval;
@@ -181,3 +784,5 @@ mod plaintext_retrieval;
 mod plaintext_vector_creation;
 mod plaintext_vector_discarding_retrieval;
 mod plaintext_vector_retrieval;
+
+pub use implementations::IMPLEMENTATIONS;
@@ -110,7 +110,7 @@ impl PackingKeyswitchKeyCreationEngine<LweSecretKey32, GlweSecretKey32, PackingK
             noise,
             &mut self.encryption_generator,
         );
-        PackingKeyswitchKey32(ksk)
+        self.audit_creation(PackingKeyswitchKey32(ksk))
     }
 }
 
@@ -213,6 +213,6 @@ impl PackingKeyswitchKeyCreationEngine<LweSecretKey64, GlweSecretKey64, PackingK
             noise,
             &mut self.encryption_generator,
         );
-        PackingKeyswitchKey64(ksk)
+        self.audit_creation(PackingKeyswitchKey64(ksk))
     }
 }
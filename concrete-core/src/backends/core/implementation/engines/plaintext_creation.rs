@@ -1,8 +1,42 @@
 use crate::backends::core::implementation::engines::CoreEngine;
-use crate::backends::core::implementation::entities::{Plaintext32, Plaintext64};
+use crate::backends::core::implementation::entities::{
+    Plaintext128, Plaintext16, Plaintext32, Plaintext64,
+};
 use crate::backends::core::private::crypto::encoding::Plaintext as ImplPlaintext;
 use crate::specification::engines::{PlaintextCreationEngine, PlaintextCreationError};
 
+/// # Description:
+/// Implementation of [`PlaintextCreationEngine`] for [`CoreEngine`] that operates on
+/// 16 bits integers.
+impl PlaintextCreationEngine<u16, Plaintext16> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 10 bits)
+    /// let input = 3_u16 << 10;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext: Plaintext16 = engine.create_plaintext(&input)?;
+    /// engine.destroy(plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_plaintext(
+        &mut self,
+        input: &u16,
+    ) -> Result<Plaintext16, PlaintextCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_plaintext_unchecked(input) })
+    }
+
+    unsafe fn create_plaintext_unchecked(&mut self, input: &u16) -> Plaintext16 {
+        Plaintext16(ImplPlaintext(*input))
+    }
+}
+
 /// # Description:
 /// Implementation of [`PlaintextCreationEngine`] for [`CoreEngine`] that operates on
 /// 32 bits integers.
@@ -66,3 +100,35 @@ impl PlaintextCreationEngine<u64, Plaintext64> for CoreEngine {
         Plaintext64(ImplPlaintext(*input))
     }
 }
+
+/// # Description:
+/// Implementation of [`PlaintextCreationEngine`] for [`CoreEngine`] that operates on
+/// 128 bits integers.
+impl PlaintextCreationEngine<u128, Plaintext128> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 114 bits)
+    /// let input = 3_u128 << 114;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext: Plaintext128 = engine.create_plaintext(&input)?;
+    /// engine.destroy(plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_plaintext(
+        &mut self,
+        input: &u128,
+    ) -> Result<Plaintext128, PlaintextCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_plaintext_unchecked(input) })
+    }
+
+    unsafe fn create_plaintext_unchecked(&mut self, input: &u128) -> Plaintext128 {
+        Plaintext128(ImplPlaintext(*input))
+    }
+}
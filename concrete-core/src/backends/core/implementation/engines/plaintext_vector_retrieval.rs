@@ -1,10 +1,51 @@
 use crate::backends::core::implementation::engines::CoreEngine;
-use crate::backends::core::implementation::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::implementation::entities::{
+    PlaintextVector128, PlaintextVector16, PlaintextVector32, PlaintextVector64,
+};
 use crate::backends::core::private::math::tensor::AsRefTensor;
 use crate::specification::engines::{
     PlaintextVectorRetrievalEngine, PlaintextVectorRetrievalError,
 };
 
+/// # Description:
+/// Implementation of [`PlaintextVectorRetrievalEngine`] for [`CoreEngine`] that operates on 16 bits
+/// integers.
+impl PlaintextVectorRetrievalEngine<PlaintextVector16, u16> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 10 bits)
+    /// let input = vec![3_u16 << 10; 3];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext_vector: PlaintextVector16 = engine.create_plaintext_vector(&input)?;
+    /// let output: Vec<u16> = engine.retrieve_plaintext_vector(&plaintext_vector)?;
+    /// #
+    /// assert_eq!(output[0], 3_u16 << 10);
+    /// engine.destroy(plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn retrieve_plaintext_vector(
+        &mut self,
+        plaintext: &PlaintextVector16,
+    ) -> Result<Vec<u16>, PlaintextVectorRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_plaintext_vector_unchecked(plaintext) })
+    }
+
+    unsafe fn retrieve_plaintext_vector_unchecked(
+        &mut self,
+        plaintext: &PlaintextVector16,
+    ) -> Vec<u16> {
+        plaintext.0.as_tensor().as_container().to_vec()
+    }
+}
+
 /// # Description:
 /// Implementation of [`PlaintextVectorRetrievalEngine`] for [`CoreEngine`] that operates on 32 bits
 /// integers.
@@ -82,3 +123,42 @@ impl PlaintextVectorRetrievalEngine<PlaintextVector64, u64> for CoreEngine {
         plaintext.0.as_tensor().as_container().to_vec()
     }
 }
+
+/// # Description:
+/// Implementation of [`PlaintextVectorRetrievalEngine`] for [`CoreEngine`] that operates on 128 bits
+/// integers.
+impl PlaintextVectorRetrievalEngine<PlaintextVector128, u128> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u128 << 20; 3];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext_vector: PlaintextVector128 = engine.create_plaintext_vector(&input)?;
+    /// let output: Vec<u128> = engine.retrieve_plaintext_vector(&plaintext_vector)?;
+    /// #
+    /// assert_eq!(output[0], 3_u128 << 20);
+    /// engine.destroy(plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn retrieve_plaintext_vector(
+        &mut self,
+        plaintext: &PlaintextVector128,
+    ) -> Result<Vec<u128>, PlaintextVectorRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_plaintext_vector_unchecked(plaintext) })
+    }
+
+    unsafe fn retrieve_plaintext_vector_unchecked(
+        &mut self,
+        plaintext: &PlaintextVector128,
+    ) -> Vec<u128> {
+        plaintext.0.as_tensor().as_container().to_vec()
+    }
+}
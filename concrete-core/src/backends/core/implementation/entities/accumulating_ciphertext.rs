@@ -0,0 +1,145 @@
+use crate::backends::core::implementation::engines::CoreError;
+use crate::backends::core::implementation::entities::{EncodingMetadata, TaggedCiphertext};
+use crate::specification::entities::markers::LweCiphertextKind;
+use crate::specification::entities::{AbstractEntity, LweCiphertextEntity};
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::parameters::LweDimension;
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A structure containing the parameters driving the resets of an [`AccumulatingCiphertext`].
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AccumulationParameters {
+    /// The modulus of the accumulated sum. A reset reduces the message modulo this value, which
+    /// can not exceed the total modulus of the encoding, carries included.
+    pub counter_modulus: usize,
+    /// The variance of the noise of the ciphertexts added to the accumulator.
+    pub input_variance: Variance,
+    /// The variance of the noise of the accumulator after a reset, that is, after a keyswitch
+    /// followed by a bootstrap.
+    pub reset_variance: Variance,
+    /// The largest variance the noise of the accumulator is allowed to reach.
+    pub max_variance: Variance,
+}
+
+/// A structure wrapping an LWE ciphertext accumulating a stream of ciphertexts.
+///
+/// Along with the metadata of its encoding, the accumulator keeps an estimation of its noise, so
+/// that the engines can reset it with a bootstrap whenever the next addition would overflow its
+/// message space or its noise budget.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccumulatingCiphertext<Ciphertext> {
+    pub(crate) ciphertext: Ciphertext,
+    pub(crate) metadata: EncodingMetadata,
+    pub(crate) parameters: AccumulationParameters,
+    pub(crate) variance: Variance,
+    pub(crate) reset_count: usize,
+}
+
+impl<Ciphertext: LweCiphertextEntity> AccumulatingCiphertext<Ciphertext> {
+    /// Starts an accumulation from a ciphertext whose noise has the given variance.
+    pub fn new(
+        ciphertext: Ciphertext,
+        metadata: EncodingMetadata,
+        parameters: AccumulationParameters,
+        variance: Variance,
+    ) -> Self {
+        AccumulatingCiphertext {
+            ciphertext,
+            metadata,
+            parameters,
+            variance,
+            reset_count: 0,
+        }
+    }
+
+    /// Returns the metadata of the encoding.
+    pub fn metadata(&self) -> &EncodingMetadata {
+        &self.metadata
+    }
+
+    /// Returns the parameters of the accumulation.
+    pub fn parameters(&self) -> &AccumulationParameters {
+        &self.parameters
+    }
+
+    /// Returns the current estimation of the variance of the noise.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+
+    /// Returns the number of resets undergone by the accumulator.
+    pub fn reset_count(&self) -> usize {
+        self.reset_count
+    }
+
+    /// Returns a reference to the wrapped ciphertext.
+    pub fn as_ciphertext(&self) -> &Ciphertext {
+        &self.ciphertext
+    }
+
+    /// Consumes the accumulator, and returns the wrapped ciphertext along with its metadata.
+    pub fn into_tagged_ciphertext(self) -> TaggedCiphertext<Ciphertext> {
+        TaggedCiphertext::new(self.ciphertext, self.metadata)
+    }
+
+    /// Returns whether the accumulator must be reset before `input` is added to it.
+    pub(crate) fn needs_reset(&self, input: &EncodingMetadata) -> bool {
+        self.metadata.checked_add(input).is_err()
+            || self.variance.get_variance() + self.parameters.input_variance.get_variance()
+                > self.parameters.max_variance.get_variance()
+    }
+
+    /// Checks that `input` can be added to the accumulator, after a reset if needed.
+    pub(crate) fn check_accumulation(&self, input: &EncodingMetadata) -> Result<(), CoreError> {
+        if !self.needs_reset(input) {
+            return Ok(());
+        }
+        self.metadata_after_reset().checked_add(input)?;
+        if self.parameters.reset_variance.get_variance()
+            + self.parameters.input_variance.get_variance()
+            > self.parameters.max_variance.get_variance()
+        {
+            return Err(CoreError::NoiseOverflow);
+        }
+        Ok(())
+    }
+
+    /// Returns the metadata of the accumulator after a reset.
+    pub(crate) fn metadata_after_reset(&self) -> EncodingMetadata {
+        EncodingMetadata {
+            degree: self
+                .metadata
+                .degree
+                .min(self.parameters.counter_modulus.saturating_sub(1)),
+            ..self.metadata
+        }
+    }
+
+    /// Updates the state of the accumulator after a reset of its ciphertext.
+    pub(crate) fn record_reset(&mut self) {
+        self.metadata = self.metadata_after_reset();
+        self.variance = self.parameters.reset_variance;
+        self.reset_count += 1;
+    }
+
+    /// Updates the state of the accumulator after the addition of `input` to its ciphertext.
+    pub(crate) fn record_addition(&mut self, input: &EncodingMetadata) {
+        self.metadata = self.metadata.saturating_add(input);
+        self.variance =
+            Variance(self.variance.get_variance() + self.parameters.input_variance.get_variance());
+    }
+}
+
+impl<Ciphertext: LweCiphertextEntity> AbstractEntity for AccumulatingCiphertext<Ciphertext> {
+    type Kind = LweCiphertextKind;
+}
+impl<Ciphertext: LweCiphertextEntity> LweCiphertextEntity for AccumulatingCiphertext<Ciphertext> {
+    type KeyDistribution = Ciphertext::KeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.ciphertext.lwe_dimension()
+    }
+}
@@ -0,0 +1,83 @@
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+use crate::backends::core::memory::{AlignedBuffer, Alignment, MemoryAlignment};
+use crate::backends::core::private::crypto::bootstrap::StandardBootstrapKey as ImplStandardBootstrapKey;
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweBootstrapKeyKind};
+use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
+
+/// A structure representing an LWE bootstrap key with 32 bits of precision, stored in an
+/// [`AlignedBuffer`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlignedLweBootstrapKey32(pub(crate) ImplStandardBootstrapKey<AlignedBuffer<u32>>);
+impl AbstractEntity for AlignedLweBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for AlignedLweBootstrapKey32 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+impl MemoryAlignment for AlignedLweBootstrapKey32 {
+    fn alignment(&self) -> Alignment {
+        self.0.as_tensor().as_container().alignment()
+    }
+}
+
+/// A structure representing an LWE bootstrap key with 64 bits of precision, stored in an
+/// [`AlignedBuffer`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlignedLweBootstrapKey64(pub(crate) ImplStandardBootstrapKey<AlignedBuffer<u64>>);
+impl AbstractEntity for AlignedLweBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for AlignedLweBootstrapKey64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+impl MemoryAlignment for AlignedLweBootstrapKey64 {
+    fn alignment(&self) -> Alignment {
+        self.0.as_tensor().as_container().alignment()
+    }
+}
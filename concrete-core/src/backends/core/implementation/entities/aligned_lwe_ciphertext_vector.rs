@@ -0,0 +1,62 @@
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+
+use crate::backends::core::memory::{AlignedBuffer, Alignment, MemoryAlignment};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweCiphertextVectorKind};
+use crate::specification::entities::{AbstractEntity, LweCiphertextVectorEntity};
+
+use super::super::super::private::crypto::lwe::LweList as ImplLweList;
+
+/// A structure representing a vector of LWE ciphertexts with 32 bits of precision, stored in an
+/// [`AlignedBuffer`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlignedLweCiphertextVector32(pub(crate) ImplLweList<AlignedBuffer<u32>>);
+
+impl AbstractEntity for AlignedLweCiphertextVector32 {
+    type Kind = LweCiphertextVectorKind;
+}
+
+impl LweCiphertextVectorEntity for AlignedLweCiphertextVector32 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
+
+impl MemoryAlignment for AlignedLweCiphertextVector32 {
+    fn alignment(&self) -> Alignment {
+        self.0.as_tensor().as_container().alignment()
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts with 64 bits of precision, stored in an
+/// [`AlignedBuffer`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlignedLweCiphertextVector64(pub(crate) ImplLweList<AlignedBuffer<u64>>);
+
+impl AbstractEntity for AlignedLweCiphertextVector64 {
+    type Kind = LweCiphertextVectorKind;
+}
+
+impl LweCiphertextVectorEntity for AlignedLweCiphertextVector64 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
+
+impl MemoryAlignment for AlignedLweCiphertextVector64 {
+    fn alignment(&self) -> Alignment {
+        self.0.as_tensor().as_container().alignment()
+    }
+}
@@ -1,8 +1,11 @@
 use crate::backends::core::private::crypto::encoding::Cleartext as ImplCleartext;
 use crate::specification::entities::markers::CleartextKind;
 use crate::specification::entities::{AbstractEntity, CleartextEntity};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
 
 /// A structure representing a cleartext with 32 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct Cleartext32(pub(crate) ImplCleartext<u32>);
 impl AbstractEntity for Cleartext32 {
@@ -11,6 +14,7 @@ impl AbstractEntity for Cleartext32 {
 impl CleartextEntity for Cleartext32 {}
 
 /// A structure representing a cleartext with 64 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct Cleartext64(pub(crate) ImplCleartext<u64>);
 impl AbstractEntity for Cleartext64 {
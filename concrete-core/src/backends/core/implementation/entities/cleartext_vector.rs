@@ -2,8 +2,11 @@ use crate::backends::core::private::crypto::encoding::CleartextList as ImplClear
 use crate::specification::entities::markers::CleartextVectorKind;
 use crate::specification::entities::{AbstractEntity, CleartextVectorEntity};
 use concrete_commons::parameters::CleartextCount;
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
 
 /// A structure representing a vector of cleartexts with 32 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct CleartextVector32(pub(crate) ImplCleartextList<Vec<u32>>);
 impl AbstractEntity for CleartextVector32 {
@@ -16,6 +19,7 @@ impl CleartextVectorEntity for CleartextVector32 {
 }
 
 /// A structure representing a vector of cleartexts with 64 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct CleartextVector64(pub(crate) ImplCleartextList<Vec<u64>>);
 impl AbstractEntity for CleartextVector64 {
@@ -0,0 +1,68 @@
+use crate::backends::core::private::crypto::lwe::ColumnMajorLweKeyswitchKey as ImplColumnMajorLweKeyswitchKey;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweKeyswitchKeyKind};
+use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyEntity};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+/// A structure representing an LWE keyswitch key with 32 bits of precision, stored in
+/// column-major order.
+///
+/// This key switches the same ciphertexts as an [`LweKeyswitchKey32`](super::LweKeyswitchKey32),
+/// but its coefficients are laid out so that its application streams over the key contiguously,
+/// which pays off for large dimensions and level counts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnMajorLweKeyswitchKey32(pub(crate) ImplColumnMajorLweKeyswitchKey<Vec<u32>>);
+impl AbstractEntity for ColumnMajorLweKeyswitchKey32 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for ColumnMajorLweKeyswitchKey32 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+/// A structure representing an LWE keyswitch key with 64 bits of precision, stored in
+/// column-major order.
+///
+/// This key switches the same ciphertexts as an [`LweKeyswitchKey64`](super::LweKeyswitchKey64),
+/// but its coefficients are laid out so that its application streams over the key contiguously,
+/// which pays off for large dimensions and level counts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnMajorLweKeyswitchKey64(pub(crate) ImplColumnMajorLweKeyswitchKey<Vec<u64>>);
+impl AbstractEntity for ColumnMajorLweKeyswitchKey64 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for ColumnMajorLweKeyswitchKey64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
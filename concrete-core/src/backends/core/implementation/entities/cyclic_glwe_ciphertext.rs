@@ -0,0 +1,57 @@
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+use crate::specification::entities::markers::{
+    BinaryKeyDistribution, CyclicRing, GlweCiphertextKind,
+};
+use crate::specification::entities::{AbstractEntity, GlweCiphertextEntity};
+
+use super::super::super::private::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+
+/// A structure representing a GLWE ciphertext with 32 bits of precision, whose polynomials are
+/// reduced modulo $X^N - 1$.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CyclicGlweCiphertext32(pub(crate) ImplGlweCiphertext<Vec<u32>>);
+
+impl AbstractEntity for CyclicGlweCiphertext32 {
+    type Kind = GlweCiphertextKind;
+}
+
+impl GlweCiphertextEntity for CyclicGlweCiphertext32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type RingStructure = CyclicRing;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+/// A structure representing a GLWE ciphertext with 64 bits of precision, whose polynomials are
+/// reduced modulo $X^N - 1$.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CyclicGlweCiphertext64(pub(crate) ImplGlweCiphertext<Vec<u64>>);
+
+impl AbstractEntity for CyclicGlweCiphertext64 {
+    type Kind = GlweCiphertextKind;
+}
+
+impl GlweCiphertextEntity for CyclicGlweCiphertext64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type RingStructure = CyclicRing;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
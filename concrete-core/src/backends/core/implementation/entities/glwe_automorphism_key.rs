@@ -0,0 +1,62 @@
+use crate::backends::core::private::crypto::glwe::GlweAutomorphismKey as ImplGlweAutomorphismKey;
+use crate::specification::entities::markers::{BinaryKeyDistribution, GlweAutomorphismKeyKind};
+use crate::specification::entities::{AbstractEntity, GlweAutomorphismKeyEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a GLWE automorphism key with 32 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweAutomorphismKey32(pub(crate) ImplGlweAutomorphismKey<Vec<u32>>);
+impl AbstractEntity for GlweAutomorphismKey32 {
+    type Kind = GlweAutomorphismKeyKind;
+}
+impl GlweAutomorphismKeyEntity for GlweAutomorphismKey32 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+/// A structure representing a GLWE automorphism key with 64 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweAutomorphismKey64(pub(crate) ImplGlweAutomorphismKey<Vec<u64>>);
+impl AbstractEntity for GlweAutomorphismKey64 {
+    type Kind = GlweAutomorphismKeyKind;
+}
+impl GlweAutomorphismKeyEntity for GlweAutomorphismKey64 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
@@ -5,13 +5,37 @@ use serde::{Deserialize, Serialize};
 use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 
 use crate::backends::core::private::math::fft::Complex64;
-use crate::specification::entities::markers::{BinaryKeyDistribution, GlweCiphertextKind};
+use crate::specification::entities::markers::{
+    BinaryKeyDistribution, GlweCiphertextKind, NegacyclicRing,
+};
 use crate::specification::entities::{AbstractEntity, GlweCiphertextEntity};
 
 use super::super::super::private::crypto::glwe::{
     FourierGlweCiphertext as ImplFourierGlweCiphertext, GlweCiphertext as ImplGlweCiphertext,
 };
 
+/// A structure representing a GLWE ciphertext with 16 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweCiphertext16(pub(crate) ImplGlweCiphertext<Vec<u16>>);
+
+impl AbstractEntity for GlweCiphertext16 {
+    type Kind = GlweCiphertextKind;
+}
+
+impl GlweCiphertextEntity for GlweCiphertext16 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type RingStructure = NegacyclicRing;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
 /// A structure representing a GLWE ciphertext with 32 bits of precision.
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
@@ -23,6 +47,7 @@ impl AbstractEntity for GlweCiphertext32 {
 
 impl GlweCiphertextEntity for GlweCiphertext32 {
     type KeyDistribution = BinaryKeyDistribution;
+    type RingStructure = NegacyclicRing;
 
     fn glwe_dimension(&self) -> GlweDimension {
         self.0.size().to_glwe_dimension()
@@ -44,6 +69,29 @@ impl AbstractEntity for GlweCiphertext64 {
 
 impl GlweCiphertextEntity for GlweCiphertext64 {
     type KeyDistribution = BinaryKeyDistribution;
+    type RingStructure = NegacyclicRing;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+/// A structure representing a GLWE ciphertext with 128 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweCiphertext128(pub(crate) ImplGlweCiphertext<Vec<u128>>);
+
+impl AbstractEntity for GlweCiphertext128 {
+    type Kind = GlweCiphertextKind;
+}
+
+impl GlweCiphertextEntity for GlweCiphertext128 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type RingStructure = NegacyclicRing;
 
     fn glwe_dimension(&self) -> GlweDimension {
         self.0.size().to_glwe_dimension()
@@ -66,6 +114,7 @@ impl AbstractEntity for FourierGlweCiphertext32 {
 }
 impl GlweCiphertextEntity for FourierGlweCiphertext32 {
     type KeyDistribution = BinaryKeyDistribution;
+    type RingStructure = NegacyclicRing;
 
     fn glwe_dimension(&self) -> GlweDimension {
         self.0.glwe_size().to_glwe_dimension()
@@ -87,6 +136,7 @@ impl AbstractEntity for FourierGlweCiphertext64 {
 }
 impl GlweCiphertextEntity for FourierGlweCiphertext64 {
     type KeyDistribution = BinaryKeyDistribution;
+    type RingStructure = NegacyclicRing;
 
     fn glwe_dimension(&self) -> GlweDimension {
         self.0.glwe_size().to_glwe_dimension()
@@ -6,6 +6,25 @@ use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 #[cfg(feature = "serde_serialize")]
 use serde::{Deserialize, Serialize};
 
+/// A structure representing a GLWE secret key with 16 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweSecretKey16(pub(crate) ImpGlweSecretKey<BinaryKeyKind, Vec<u16>>);
+impl AbstractEntity for GlweSecretKey16 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for GlweSecretKey16 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
 /// A structure representing a GLWE secret key with 32 bits of precision.
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
@@ -43,3 +62,22 @@ impl GlweSecretKeyEntity for GlweSecretKey64 {
         self.0.polynomial_size()
     }
 }
+
+/// A structure representing a GLWE secret key with 128 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweSecretKey128(pub(crate) ImpGlweSecretKey<BinaryKeyKind, Vec<u128>>);
+impl AbstractEntity for GlweSecretKey128 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for GlweSecretKey128 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
@@ -1,8 +1,9 @@
+use crate::backends::core::memory::{Alignment, MemoryAlignment};
 use crate::backends::core::private::crypto::bootstrap::{
     FourierBootstrapKey as ImplFourierBootstrapKey,
     StandardBootstrapKey as ImplStandardBootstrapKey,
 };
-use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::fft::{Complex64, FftPrecision};
 use crate::specification::entities::markers::{BinaryKeyDistribution, LweBootstrapKeyKind};
 use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
 use concrete_commons::parameters::{
@@ -13,6 +14,7 @@ use concrete_fftw::array::AlignedVec;
 use serde::{Deserialize, Serialize};
 
 /// A structure representing an LWE bootstrap key with 32 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct LweBootstrapKey32(pub(crate) ImplStandardBootstrapKey<Vec<u32>>);
 impl AbstractEntity for LweBootstrapKey32 {
@@ -42,8 +44,14 @@ impl LweBootstrapKeyEntity for LweBootstrapKey32 {
         self.0.level_count()
     }
 }
+impl MemoryAlignment for LweBootstrapKey32 {
+    fn alignment(&self) -> Alignment {
+        Alignment::of::<u32>()
+    }
+}
 
 /// A structure representing an LWE bootstrap key with 64 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct LweBootstrapKey64(pub(crate) ImplStandardBootstrapKey<Vec<u64>>);
 impl AbstractEntity for LweBootstrapKey64 {
@@ -73,6 +81,11 @@ impl LweBootstrapKeyEntity for LweBootstrapKey64 {
         self.0.level_count()
     }
 }
+impl MemoryAlignment for LweBootstrapKey64 {
+    fn alignment(&self) -> Alignment {
+        Alignment::of::<u64>()
+    }
+}
 
 /// A structure representing an LWE bootstrap key with 32 bits of precision, in the fourier domain.
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
@@ -81,6 +94,12 @@ pub struct FourierLweBootstrapKey32(pub(crate) ImplFourierBootstrapKey<AlignedVe
 impl AbstractEntity for FourierLweBootstrapKey32 {
     type Kind = LweBootstrapKeyKind;
 }
+impl FourierLweBootstrapKey32 {
+    /// Returns the arithmetic strategy of the products computed with the key.
+    pub fn fft_precision(&self) -> FftPrecision {
+        self.0.precision()
+    }
+}
 impl LweBootstrapKeyEntity for FourierLweBootstrapKey32 {
     type InputKeyDistribution = BinaryKeyDistribution;
     type OutputKeyDistribution = BinaryKeyDistribution;
@@ -113,6 +132,12 @@ pub struct FourierLweBootstrapKey64(pub(crate) ImplFourierBootstrapKey<AlignedVe
 impl AbstractEntity for FourierLweBootstrapKey64 {
     type Kind = LweBootstrapKeyKind;
 }
+impl FourierLweBootstrapKey64 {
+    /// Returns the arithmetic strategy of the products computed with the key.
+    pub fn fft_precision(&self) -> FftPrecision {
+        self.0.precision()
+    }
+}
 impl LweBootstrapKeyEntity for FourierLweBootstrapKey64 {
     type InputKeyDistribution = BinaryKeyDistribution;
     type OutputKeyDistribution = BinaryKeyDistribution;
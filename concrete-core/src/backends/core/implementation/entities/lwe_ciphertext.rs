@@ -5,6 +5,21 @@ use concrete_commons::parameters::LweDimension;
 #[cfg(feature = "serde_serialize")]
 use serde::{Deserialize, Serialize};
 
+/// A structure representing an LWE ciphertext with 16 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCiphertext16(pub(crate) ImplLweCiphertext<Vec<u16>>);
+impl AbstractEntity for LweCiphertext16 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for LweCiphertext16 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+}
+
 /// A structure representing an LWE ciphertext with 32 bits of precision.
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -34,3 +49,18 @@ impl LweCiphertextEntity for LweCiphertext64 {
         self.0.lwe_size().to_lwe_dimension()
     }
 }
+
+/// A structure representing an LWE ciphertext with 128 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCiphertext128(pub(crate) ImplLweCiphertext<Vec<u128>>);
+impl AbstractEntity for LweCiphertext128 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for LweCiphertext128 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+}
@@ -0,0 +1,80 @@
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+
+use crate::backends::core::private::crypto::lwe::LweList as ImplLweList;
+use crate::specification::entities::markers::{
+    BinaryKeyDistribution, LweCiphertextJaggedVectorKind,
+};
+use crate::specification::entities::{AbstractEntity, LweCiphertextJaggedVectorEntity};
+
+/// A structure representing a jagged vector of LWE ciphertexts with 32 bits of precision.
+///
+/// The ciphertexts of all the rows are stored contiguously, and the `row_offsets` hold the index
+/// of the first ciphertext of each row, followed by the total number of ciphertexts.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCiphertextJaggedVector32 {
+    pub(crate) list: ImplLweList<Vec<u32>>,
+    pub(crate) row_offsets: Vec<usize>,
+}
+
+impl AbstractEntity for LweCiphertextJaggedVector32 {
+    type Kind = LweCiphertextJaggedVectorKind;
+}
+
+impl LweCiphertextJaggedVectorEntity for LweCiphertextJaggedVector32 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.list.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.list.count().0)
+    }
+
+    fn row_count(&self) -> usize {
+        self.row_offsets.len() - 1
+    }
+
+    fn row_lwe_ciphertext_count(&self, row: usize) -> LweCiphertextCount {
+        LweCiphertextCount(self.row_offsets[row + 1] - self.row_offsets[row])
+    }
+}
+
+/// A structure representing a jagged vector of LWE ciphertexts with 64 bits of precision.
+///
+/// The ciphertexts of all the rows are stored contiguously, and the `row_offsets` hold the index
+/// of the first ciphertext of each row, followed by the total number of ciphertexts.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCiphertextJaggedVector64 {
+    pub(crate) list: ImplLweList<Vec<u64>>,
+    pub(crate) row_offsets: Vec<usize>,
+}
+
+impl AbstractEntity for LweCiphertextJaggedVector64 {
+    type Kind = LweCiphertextJaggedVectorKind;
+}
+
+impl LweCiphertextJaggedVectorEntity for LweCiphertextJaggedVector64 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.list.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.list.count().0)
+    }
+
+    fn row_count(&self) -> usize {
+        self.row_offsets.len() - 1
+    }
+
+    fn row_lwe_ciphertext_count(&self, row: usize) -> LweCiphertextCount {
+        LweCiphertextCount(self.row_offsets[row + 1] - self.row_offsets[row])
+    }
+}
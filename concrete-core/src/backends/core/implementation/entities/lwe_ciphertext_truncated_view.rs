@@ -0,0 +1,37 @@
+use super::super::super::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweCiphertextKind};
+use crate::specification::entities::{AbstractEntity, LweCiphertextEntity};
+use concrete_commons::parameters::LweDimension;
+
+/// A structure representing a read-only view of an LWE ciphertext with 64 bits of precision, as
+/// an LWE ciphertext with 32 bits of precision.
+///
+/// The coefficients of the view are the 32 most significant bits of the coefficients of the
+/// viewed ciphertext, rounded to the nearest value. They are computed when read, and the viewed
+/// ciphertext is not copied.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweCiphertextTruncatedView32<'a>(pub(crate) ImplLweCiphertext<&'a [u64]>);
+
+impl<'a> LweCiphertextTruncatedView32<'a> {
+    /// Returns an iterator over the truncated coefficients of the view.
+    pub(crate) fn truncated_coefficients(&self) -> impl Iterator<Item = u32> + '_ {
+        self.0
+            .as_tensor()
+            .as_slice()
+            .iter()
+            .map(|coefficient| (coefficient.wrapping_add(1 << 31) >> 32) as u32)
+    }
+}
+
+impl<'a> AbstractEntity for LweCiphertextTruncatedView32<'a> {
+    type Kind = LweCiphertextKind;
+}
+
+impl<'a> LweCiphertextEntity for LweCiphertextTruncatedView32<'a> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
 
+use crate::backends::core::memory::{Alignment, MemoryAlignment};
 use crate::specification::entities::markers::{BinaryKeyDistribution, LweCiphertextVectorKind};
 use crate::specification::entities::{AbstractEntity, LweCiphertextVectorEntity};
 
@@ -49,3 +50,15 @@ impl LweCiphertextVectorEntity for LweCiphertextVector64 {
         LweCiphertextCount(self.0.count().0)
     }
 }
+
+impl MemoryAlignment for LweCiphertextVector32 {
+    fn alignment(&self) -> Alignment {
+        Alignment::of::<u32>()
+    }
+}
+
+impl MemoryAlignment for LweCiphertextVector64 {
+    fn alignment(&self) -> Alignment {
+        Alignment::of::<u64>()
+    }
+}
@@ -0,0 +1,70 @@
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+
+use crate::backends::core::memory::{Alignment, MemoryAlignment, SharedSlice};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweCiphertextVectorKind};
+use crate::specification::entities::{AbstractEntity, LweCiphertextVectorEntity};
+
+use super::super::super::private::crypto::lwe::LweList as ImplLweList;
+
+/// A structure representing a read-only view over a vector of LWE ciphertexts with 32 bits of
+/// precision, stored in a [`SharedSlice`].
+///
+/// When the ciphertexts are written by another process, the slice and the LWE size of the view are
+/// read from the header of a
+/// [`SharedSegment`](crate::backends::core::memory::SharedSegment).
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweCiphertextVectorView32<'a>(pub(crate) ImplLweList<SharedSlice<'a, u32>>);
+
+impl<'a> AbstractEntity for LweCiphertextVectorView32<'a> {
+    type Kind = LweCiphertextVectorKind;
+}
+
+impl<'a> LweCiphertextVectorEntity for LweCiphertextVectorView32<'a> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
+
+impl<'a> MemoryAlignment for LweCiphertextVectorView32<'a> {
+    fn alignment(&self) -> Alignment {
+        self.0.as_tensor().as_container().alignment()
+    }
+}
+
+/// A structure representing a read-only view over a vector of LWE ciphertexts with 64 bits of
+/// precision, stored in a [`SharedSlice`].
+///
+/// When the ciphertexts are written by another process, the slice and the LWE size of the view are
+/// read from the header of a
+/// [`SharedSegment`](crate::backends::core::memory::SharedSegment).
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweCiphertextVectorView64<'a>(pub(crate) ImplLweList<SharedSlice<'a, u64>>);
+
+impl<'a> AbstractEntity for LweCiphertextVectorView64<'a> {
+    type Kind = LweCiphertextVectorKind;
+}
+
+impl<'a> LweCiphertextVectorEntity for LweCiphertextVectorView64<'a> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
+
+impl<'a> MemoryAlignment for LweCiphertextVectorView64<'a> {
+    fn alignment(&self) -> Alignment {
+        self.0.as_tensor().as_container().alignment()
+    }
+}
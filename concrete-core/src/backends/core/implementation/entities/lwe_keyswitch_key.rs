@@ -5,6 +5,34 @@ use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount
 #[cfg(feature = "serde_serialize")]
 use serde::{Deserialize, Serialize};
 
+/// A structure representing an LWE keyswitch key with 16 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweKeyswitchKey16(pub(crate) ImplLweKeyswitchKey<Vec<u16>>);
+impl AbstractEntity for LweKeyswitchKey16 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for LweKeyswitchKey16 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
 /// A structure representing an LWE keyswitch key with 32 bits of precision.
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
@@ -60,3 +88,31 @@ impl LweKeyswitchKeyEntity for LweKeyswitchKey64 {
         self.0.decomposition_base_log()
     }
 }
+
+/// A structure representing an LWE keyswitch key with 128 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweKeyswitchKey128(pub(crate) ImplLweKeyswitchKey<Vec<u128>>);
+impl AbstractEntity for LweKeyswitchKey128 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for LweKeyswitchKey128 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
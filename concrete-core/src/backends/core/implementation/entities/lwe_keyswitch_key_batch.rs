@@ -0,0 +1,72 @@
+use crate::backends::core::private::crypto::lwe::LweKeyswitchKeyBatch as ImplLweKeyswitchKeyBatch;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweKeyswitchKeyBatchKind};
+use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyBatchEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweKeyswitchKeyCount,
+};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a batch of LWE keyswitch keys with 32 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweKeyswitchKeyBatch32(pub(crate) ImplLweKeyswitchKeyBatch<Vec<u32>>);
+impl AbstractEntity for LweKeyswitchKeyBatch32 {
+    type Kind = LweKeyswitchKeyBatchKind;
+}
+impl LweKeyswitchKeyBatchEntity for LweKeyswitchKeyBatch32 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn key_count(&self) -> LweKeyswitchKeyCount {
+        self.0.key_count()
+    }
+}
+
+/// A structure representing a batch of LWE keyswitch keys with 64 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweKeyswitchKeyBatch64(pub(crate) ImplLweKeyswitchKeyBatch<Vec<u64>>);
+impl AbstractEntity for LweKeyswitchKeyBatch64 {
+    type Kind = LweKeyswitchKeyBatchKind;
+}
+impl LweKeyswitchKeyBatchEntity for LweKeyswitchKeyBatch64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn key_count(&self) -> LweKeyswitchKeyCount {
+        self.0.key_count()
+    }
+}
@@ -0,0 +1,79 @@
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweKeyswitchKeyDeltaKind};
+use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyDeltaEntity};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing the delta of an LWE keyswitch key with 32 bits of precision.
+///
+/// The body corrections are stored in the order of the ciphertexts of the keyswitch key, that is,
+/// by input key coefficient, then by decomposition level.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweKeyswitchKeyDelta32 {
+    pub(crate) body_corrections: Vec<u32>,
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) output_lwe_dimension: LweDimension,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+}
+impl AbstractEntity for LweKeyswitchKeyDelta32 {
+    type Kind = LweKeyswitchKeyDeltaKind;
+}
+impl LweKeyswitchKeyDeltaEntity for LweKeyswitchKeyDelta32 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.output_lwe_dimension
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
+
+/// A structure representing the delta of an LWE keyswitch key with 64 bits of precision.
+///
+/// The body corrections are stored in the order of the ciphertexts of the keyswitch key, that is,
+/// by input key coefficient, then by decomposition level.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweKeyswitchKeyDelta64 {
+    pub(crate) body_corrections: Vec<u64>,
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) output_lwe_dimension: LweDimension,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+}
+impl AbstractEntity for LweKeyswitchKeyDelta64 {
+    type Kind = LweKeyswitchKeyDeltaKind;
+}
+impl LweKeyswitchKeyDeltaEntity for LweKeyswitchKeyDelta64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.output_lwe_dimension
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
@@ -0,0 +1,32 @@
+use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::specification::entities::markers::LwePhaseVectorKind;
+use crate::specification::entities::{AbstractEntity, LwePhaseVectorEntity};
+use concrete_commons::parameters::LweCiphertextCount;
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a vector of LWE phases with 32 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LwePhaseVector32(pub(crate) ImplPlaintextList<Vec<u32>>);
+impl AbstractEntity for LwePhaseVector32 {
+    type Kind = LwePhaseVectorKind;
+}
+impl LwePhaseVectorEntity for LwePhaseVector32 {
+    fn phase_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
+
+/// A structure representing a vector of LWE phases with 64 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LwePhaseVector64(pub(crate) ImplPlaintextList<Vec<u64>>);
+impl AbstractEntity for LwePhaseVector64 {
+    type Kind = LwePhaseVectorKind;
+}
+impl LwePhaseVectorEntity for LwePhaseVector64 {
+    fn phase_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
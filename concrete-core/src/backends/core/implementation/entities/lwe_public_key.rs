@@ -0,0 +1,44 @@
+use crate::backends::core::private::crypto::lwe::LweList as ImplLweList;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LwePublicKeyKind};
+use crate::specification::entities::{AbstractEntity, LwePublicKeyEntity};
+use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing an LWE public key with 32 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LwePublicKey32(pub(crate) ImplLweList<Vec<u32>>);
+impl AbstractEntity for LwePublicKey32 {
+    type Kind = LwePublicKeyKind;
+}
+impl LwePublicKeyEntity for LwePublicKey32 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_zero_encryption_count(&self) -> LwePublicKeyZeroEncryptionCount {
+        LwePublicKeyZeroEncryptionCount(self.0.count().0)
+    }
+}
+
+/// A structure representing an LWE public key with 64 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LwePublicKey64(pub(crate) ImplLweList<Vec<u64>>);
+impl AbstractEntity for LwePublicKey64 {
+    type Kind = LwePublicKeyKind;
+}
+impl LwePublicKeyEntity for LwePublicKey64 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_zero_encryption_count(&self) -> LwePublicKeyZeroEncryptionCount {
+        LwePublicKeyZeroEncryptionCount(self.0.count().0)
+    }
+}
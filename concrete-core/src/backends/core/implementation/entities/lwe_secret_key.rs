@@ -6,6 +6,21 @@ use concrete_commons::parameters::LweDimension;
 #[cfg(feature = "serde_serialize")]
 use serde::{Deserialize, Serialize};
 
+/// A structure representing an LWE secret key with 16 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweSecretKey16(pub(crate) ImpLweSecretKey<BinaryKeyKind, Vec<u16>>);
+impl AbstractEntity for LweSecretKey16 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweSecretKey16 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
+
 /// A structure representing an LWE secret key with 32 bits of precision.
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
@@ -35,3 +50,18 @@ impl LweSecretKeyEntity for LweSecretKey64 {
         self.0.key_size()
     }
 }
+
+/// A structure representing an LWE secret key with 128 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweSecretKey128(pub(crate) ImpLweSecretKey<BinaryKeyKind, Vec<u128>>);
+impl AbstractEntity for LweSecretKey128 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweSecretKey128 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
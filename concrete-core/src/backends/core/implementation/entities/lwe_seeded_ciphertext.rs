@@ -0,0 +1,44 @@
+use crate::backends::core::private::crypto::lwe::LweSeededCiphertext as ImplLweSeededCiphertext;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweSeededCiphertextKind};
+use crate::specification::entities::{AbstractEntity, LweSeededCiphertextEntity};
+use concrete_commons::parameters::LweDimension;
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a seeded LWE ciphertext with 32 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweSeededCiphertext32(pub(crate) ImplLweSeededCiphertext<u32>);
+impl AbstractEntity for LweSeededCiphertext32 {
+    type Kind = LweSeededCiphertextKind;
+}
+impl LweSeededCiphertextEntity for LweSeededCiphertext32 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn compression_ratio(&self) -> f64 {
+        self.0.compression_ratio()
+    }
+}
+
+/// A structure representing a seeded LWE ciphertext with 64 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweSeededCiphertext64(pub(crate) ImplLweSeededCiphertext<u64>);
+impl AbstractEntity for LweSeededCiphertext64 {
+    type Kind = LweSeededCiphertextKind;
+}
+impl LweSeededCiphertextEntity for LweSeededCiphertext64 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn compression_ratio(&self) -> f64 {
+        self.0.compression_ratio()
+    }
+}
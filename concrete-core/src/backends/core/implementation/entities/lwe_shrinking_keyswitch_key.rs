@@ -0,0 +1,68 @@
+use crate::backends::core::private::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
+use crate::specification::entities::markers::{
+    BinaryKeyDistribution, LweShrinkingKeyswitchKeyKind,
+};
+use crate::specification::entities::{AbstractEntity, LweShrinkingKeyswitchKeyEntity};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing an LWE shrinking keyswitch key with 32 bits of precision.
+///
+/// The underlying keyswitch key switches from the coefficients of the input key which are not
+/// shared with the output key, to the output key.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweShrinkingKeyswitchKey32(pub(crate) ImplLweKeyswitchKey<Vec<u32>>);
+impl AbstractEntity for LweShrinkingKeyswitchKey32 {
+    type Kind = LweShrinkingKeyswitchKeyKind;
+}
+impl LweShrinkingKeyswitchKeyEntity for LweShrinkingKeyswitchKey32 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.before_key_size().0 + self.0.after_key_size().0)
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+/// A structure representing an LWE shrinking keyswitch key with 64 bits of precision.
+///
+/// The underlying keyswitch key switches from the coefficients of the input key which are not
+/// shared with the output key, to the output key.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweShrinkingKeyswitchKey64(pub(crate) ImplLweKeyswitchKey<Vec<u64>>);
+impl AbstractEntity for LweShrinkingKeyswitchKey64 {
+    type Kind = LweShrinkingKeyswitchKeyKind;
+}
+impl LweShrinkingKeyswitchKeyEntity for LweShrinkingKeyswitchKey64 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.before_key_size().0 + self.0.after_key_size().0)
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
@@ -1,34 +1,72 @@
 //! A module containing all the [entities](crate::specification::entities) exposed by the core
 //! backend.
 
+mod accumulating_ciphertext;
+mod aligned_lwe_bootstrap_key;
+mod aligned_lwe_ciphertext_vector;
 mod cleartext;
 mod cleartext_vector;
+mod column_major_lwe_keyswitch_key;
+mod cyclic_glwe_ciphertext;
 mod ggsw_ciphertext;
+mod ggsw_seeded_ciphertext;
+mod glwe_automorphism_key;
 mod glwe_ciphertext;
 mod glwe_ciphertext_vector;
+mod glwe_relinearization_key;
 mod glwe_secret_key;
 mod gsw_ciphertext;
 mod lwe_bootstrap_key;
 mod lwe_ciphertext;
+mod lwe_ciphertext_jagged_vector;
+mod lwe_ciphertext_truncated_view;
 mod lwe_ciphertext_vector;
+mod lwe_ciphertext_vector_view;
 mod lwe_keyswitch_key;
+mod lwe_keyswitch_key_batch;
+mod lwe_keyswitch_key_delta;
+mod lwe_phase_vector;
+mod lwe_public_key;
 mod lwe_secret_key;
+mod lwe_seeded_bootstrap_key;
+mod lwe_seeded_ciphertext;
+mod lwe_shrinking_keyswitch_key;
 mod packing_keyswitch_key;
 mod plaintext;
 mod plaintext_vector;
+mod tagged_ciphertext;
 
+pub use accumulating_ciphertext::*;
+pub use aligned_lwe_bootstrap_key::*;
+pub use aligned_lwe_ciphertext_vector::*;
 pub use cleartext::*;
 pub use cleartext_vector::*;
+pub use column_major_lwe_keyswitch_key::*;
+pub use cyclic_glwe_ciphertext::*;
 pub use ggsw_ciphertext::*;
+pub use ggsw_seeded_ciphertext::*;
+pub use glwe_automorphism_key::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_vector::*;
+pub use glwe_relinearization_key::*;
 pub use glwe_secret_key::*;
 pub use gsw_ciphertext::*;
 pub use lwe_bootstrap_key::*;
 pub use lwe_ciphertext::*;
+pub use lwe_ciphertext_jagged_vector::*;
+pub use lwe_ciphertext_truncated_view::*;
 pub use lwe_ciphertext_vector::*;
+pub use lwe_ciphertext_vector_view::*;
 pub use lwe_keyswitch_key::*;
+pub use lwe_keyswitch_key_batch::*;
+pub use lwe_keyswitch_key_delta::*;
+pub use lwe_phase_vector::*;
+pub use lwe_public_key::*;
 pub use lwe_secret_key::*;
+pub use lwe_seeded_bootstrap_key::*;
+pub use lwe_seeded_ciphertext::*;
+pub use lwe_shrinking_keyswitch_key::*;
 pub use packing_keyswitch_key::*;
 pub use plaintext::*;
 pub use plaintext_vector::*;
+pub use tagged_ciphertext::*;
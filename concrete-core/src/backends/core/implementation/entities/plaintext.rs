@@ -1,8 +1,20 @@
 use super::super::super::private::crypto::encoding::Plaintext as CorePlaintext;
 use crate::specification::entities::markers::PlaintextKind;
 use crate::specification::entities::{AbstractEntity, PlaintextEntity};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a plaintext with 16 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plaintext16(pub(crate) CorePlaintext<u16>);
+impl AbstractEntity for Plaintext16 {
+    type Kind = PlaintextKind;
+}
+impl PlaintextEntity for Plaintext16 {}
 
 /// A structure representing a plaintext with 32 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct Plaintext32(pub(crate) CorePlaintext<u32>);
 impl AbstractEntity for Plaintext32 {
@@ -11,9 +23,19 @@ impl AbstractEntity for Plaintext32 {
 impl PlaintextEntity for Plaintext32 {}
 
 /// A structure representing a plaintext with 64 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct Plaintext64(pub(crate) CorePlaintext<u64>);
 impl AbstractEntity for Plaintext64 {
     type Kind = PlaintextKind;
 }
 impl PlaintextEntity for Plaintext64 {}
+
+/// A structure representing a plaintext with 128 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plaintext128(pub(crate) CorePlaintext<u128>);
+impl AbstractEntity for Plaintext128 {
+    type Kind = PlaintextKind;
+}
+impl PlaintextEntity for Plaintext128 {}
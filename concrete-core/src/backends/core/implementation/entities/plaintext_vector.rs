@@ -2,8 +2,24 @@ use super::super::super::private::crypto::encoding::PlaintextList as CorePlainte
 use crate::specification::entities::markers::PlaintextVectorKind;
 use crate::specification::entities::{AbstractEntity, PlaintextVectorEntity};
 use concrete_commons::parameters::PlaintextCount;
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a vector of plaintexts with 16 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaintextVector16(pub(crate) CorePlaintextList<Vec<u16>>);
+impl AbstractEntity for PlaintextVector16 {
+    type Kind = PlaintextVectorKind;
+}
+impl PlaintextVectorEntity for PlaintextVector16 {
+    fn plaintext_count(&self) -> PlaintextCount {
+        self.0.count()
+    }
+}
 
 /// A structure representing a vector of plaintexts with 32 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct PlaintextVector32(pub(crate) CorePlaintextList<Vec<u32>>);
 impl AbstractEntity for PlaintextVector32 {
@@ -16,6 +32,7 @@ impl PlaintextVectorEntity for PlaintextVector32 {
 }
 
 /// A structure representing a vector of plaintexts with 64 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct PlaintextVector64(pub(crate) CorePlaintextList<Vec<u64>>);
 impl AbstractEntity for PlaintextVector64 {
@@ -26,3 +43,16 @@ impl PlaintextVectorEntity for PlaintextVector64 {
         self.0.count()
     }
 }
+
+/// A structure representing a vector of plaintexts with 128 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaintextVector128(pub(crate) CorePlaintextList<Vec<u128>>);
+impl AbstractEntity for PlaintextVector128 {
+    type Kind = PlaintextVectorKind;
+}
+impl PlaintextVectorEntity for PlaintextVector128 {
+    fn plaintext_count(&self) -> PlaintextCount {
+        self.0.count()
+    }
+}
@@ -0,0 +1,165 @@
+use crate::backends::core::implementation::engines::CoreError;
+use crate::specification::entities::markers::LweCiphertextKind;
+use crate::specification::entities::{AbstractEntity, LweCiphertextEntity};
+use concrete_commons::parameters::{LweDimension, ScalingFactor};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A structure containing the metadata of the encoding used in a [`TaggedCiphertext`].
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodingMetadata {
+    /// The scaling factor applied to the messages before their encryption.
+    pub delta: ScalingFactor,
+    /// The number of distinct messages which can be represented by the encoding.
+    pub message_modulus: usize,
+    /// The number of distinct carries which can be stored above the message, `1` when the
+    /// encoding leaves no room for carries.
+    pub carry_modulus: usize,
+    /// An upper bound on the message currently encrypted, carries included.
+    pub degree: usize,
+}
+
+impl EncodingMetadata {
+    /// Returns the number of distinct values which can be represented by the encoding, carries
+    /// included.
+    pub fn total_modulus(&self) -> usize {
+        self.message_modulus.saturating_mul(self.carry_modulus)
+    }
+
+    /// Returns the largest degree a ciphertext can reach without overflowing the carry space.
+    pub fn max_degree(&self) -> usize {
+        self.total_modulus().saturating_sub(1)
+    }
+
+    /// Returns whether the message currently encrypted may hold carries.
+    pub fn has_carries(&self) -> bool {
+        self.degree >= self.message_modulus
+    }
+
+    /// Returns the metadata of the sum of two ciphertexts, if it does not overflow.
+    pub(crate) fn checked_add(&self, other: &EncodingMetadata) -> Result<Self, CoreError> {
+        if self.delta != other.delta
+            || self.message_modulus != other.message_modulus
+            || self.carry_modulus != other.carry_modulus
+        {
+            return Err(CoreError::EncodingMismatch);
+        }
+        match self.degree.checked_add(other.degree) {
+            Some(degree) if degree <= self.max_degree() => Ok(EncodingMetadata { degree, ..*self }),
+            _ => Err(CoreError::MessageOverflow),
+        }
+    }
+
+    /// Returns the metadata of the product of a ciphertext by a scalar, if it does not overflow.
+    pub(crate) fn checked_mul(&self, scalar: u64) -> Result<Self, CoreError> {
+        match (self.degree as u64).checked_mul(scalar) {
+            Some(degree) if degree <= self.max_degree() as u64 => Ok(EncodingMetadata {
+                degree: degree as usize,
+                ..*self
+            }),
+            _ => Err(CoreError::MessageOverflow),
+        }
+    }
+
+    /// Returns the metadata of the sum of two ciphertexts, without checking for overflows.
+    pub(crate) fn saturating_add(&self, other: &EncodingMetadata) -> Self {
+        EncodingMetadata {
+            degree: self.degree.saturating_add(other.degree),
+            ..*self
+        }
+    }
+
+    /// Returns the metadata of the product of a ciphertext by a scalar, without checking for
+    /// overflows.
+    pub(crate) fn saturating_mul(&self, scalar: u64) -> Self {
+        EncodingMetadata {
+            degree: (self.degree as u64).saturating_mul(scalar) as usize,
+            ..*self
+        }
+    }
+}
+
+/// A structure wrapping an LWE ciphertext along with the metadata of its encoding.
+///
+/// The engines operating on tagged ciphertexts keep the metadata up to date, and their checked
+/// entry points catch the operations which would overflow the carry space, as configured by the
+/// [`OverflowPolicy`](crate::backends::core::engines::OverflowPolicy) of the engine.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaggedCiphertext<Ciphertext> {
+    pub(crate) ciphertext: Ciphertext,
+    pub(crate) metadata: EncodingMetadata,
+}
+
+impl<Ciphertext: LweCiphertextEntity> TaggedCiphertext<Ciphertext> {
+    /// Attaches encoding metadata to a ciphertext.
+    pub fn new(ciphertext: Ciphertext, metadata: EncodingMetadata) -> Self {
+        TaggedCiphertext {
+            ciphertext,
+            metadata,
+        }
+    }
+
+    /// Returns the metadata of the encoding.
+    pub fn metadata(&self) -> &EncodingMetadata {
+        &self.metadata
+    }
+
+    /// Returns a reference to the wrapped ciphertext.
+    pub fn as_ciphertext(&self) -> &Ciphertext {
+        &self.ciphertext
+    }
+
+    /// Consumes the tagged ciphertext, and returns the wrapped ciphertext.
+    pub fn into_ciphertext(self) -> Ciphertext {
+        self.ciphertext
+    }
+}
+
+impl<Ciphertext: LweCiphertextEntity> AbstractEntity for TaggedCiphertext<Ciphertext> {
+    type Kind = LweCiphertextKind;
+}
+impl<Ciphertext: LweCiphertextEntity> LweCiphertextEntity for TaggedCiphertext<Ciphertext> {
+    type KeyDistribution = Ciphertext::KeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.ciphertext.lwe_dimension()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EncodingMetadata;
+    use crate::backends::core::implementation::engines::CoreError;
+    use concrete_commons::parameters::ScalingFactor;
+
+    fn metadata(carry_modulus: usize, degree: usize) -> EncodingMetadata {
+        EncodingMetadata {
+            delta: ScalingFactor::from_log2(59),
+            message_modulus: 4,
+            carry_modulus,
+            degree,
+        }
+    }
+
+    #[test]
+    fn test_carries_extend_the_degree_up_to_the_carry_space() {
+        let sum = metadata(4, 3).checked_add(&metadata(4, 3)).unwrap();
+        assert_eq!(sum.degree, 6);
+        assert!(sum.has_carries());
+        assert!(metadata(4, 7).checked_mul(2).is_ok());
+        assert!(matches!(
+            metadata(4, 8).checked_add(&metadata(4, 8)),
+            Err(CoreError::MessageOverflow)
+        ));
+        assert!(matches!(
+            metadata(1, 3).checked_add(&metadata(1, 3)),
+            Err(CoreError::MessageOverflow)
+        ));
+        assert!(matches!(
+            metadata(1, 1).checked_add(&metadata(4, 1)),
+            Err(CoreError::EncodingMismatch)
+        ));
+    }
+}
@@ -0,0 +1,250 @@
+//! A module containing an authenticated envelope for serialized entities.
+//!
+//! The serialized entities do not carry any integrity check: a blob corrupted or truncated in
+//! transit may still deserialize into an entity, which then silently produces wrong decryptions.
+//! The [`seal`] function wraps a serialized value in an envelope ending with a keyed BLAKE3 tag,
+//! computed with a [`TransportKey`] shared by both ends of the channel. On the receiving side,
+//! [`open`] verifies the tag before releasing the serialized value, and returns a typed
+//! [`EnvelopeError`] otherwise.
+//!
+//! The envelope only provides integrity and authenticity: the wrapped value is not encrypted.
+//!
+//! # Example:
+//!
+//! ```
+//! use concrete_commons::parameters::LweDimension;
+//! use concrete_core::backends::core::envelope::{open, seal, EnvelopeError, TransportKey};
+//! use concrete_core::prelude::*;
+//! # use std::error::Error;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+//! let mut engine = CoreEngine::new()?;
+//! let key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(4))?;
+//! let plaintext = engine.create_plaintext(&(3_u64 << 50))?;
+//! let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, Variance(2_f64.powf(-25.)))?;
+//!
+//! // Both ends of the channel share the transport key.
+//! let transport_key = TransportKey::new([7; 32]);
+//! let serialized = engine.serialize(&ciphertext)?;
+//! let mut envelope = seal(&transport_key, serialized.as_slice());
+//!
+//! // The receiver verifies the envelope before deserializing the ciphertext.
+//! let received: LweCiphertext64 = engine.deserialize(open(&transport_key, &envelope)?)?;
+//! assert_eq!(received, ciphertext);
+//!
+//! // A corrupted envelope is rejected.
+//! envelope[20] ^= 1;
+//! assert_eq!(
+//!     open(&transport_key, &envelope),
+//!     Err(EnvelopeError::AuthenticationFailure)
+//! );
+//!
+//! engine.destroy(key)?;
+//! engine.destroy(plaintext)?;
+//! engine.destroy(ciphertext)?;
+//! engine.destroy(received)?;
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use crate::backends::core::private::math::tensor::Tensor;
+use std::convert::TryInto;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+/// The version of the layout of the envelope.
+pub const ENVELOPE_FORMAT_VERSION: u32 = 1;
+
+/// The bytes every envelope starts with.
+const ENVELOPE_MAGIC: [u8; 4] = *b"CCEV";
+
+/// The size of the header of an envelope: the magic bytes, the version and the payload length.
+const HEADER_SIZE: usize = 16;
+
+/// The size of the tag ending an envelope.
+const TAG_SIZE: usize = 32;
+
+/// A secret key shared by the two ends of a channel, used to authenticate envelopes.
+///
+/// The key must be kept secret, since it allows to forge envelopes. It is distinct from the
+/// secret keys of the scheme, and is typically derived from the key material of the secure
+/// channel between the client and the server.
+///
+/// Two keys are compared in constant time, so that the comparison does not leak the position of
+/// their first difference.
+#[derive(Clone)]
+pub struct TransportKey([u8; 32]);
+
+impl TransportKey {
+    /// Creates a transport key from 32 uniformly random bytes.
+    pub fn new(bytes: [u8; 32]) -> Self {
+        TransportKey(bytes)
+    }
+
+    /// Derives a transport key from some key material, for a given application `context`.
+    ///
+    /// The context should be a hardcoded string, unique to the application and the purpose of the
+    /// key, such as `"my-service 2022-06-01 ciphertext transport"`.
+    pub fn derive(context: &str, key_material: &[u8]) -> Self {
+        TransportKey(blake3::derive_key(context, key_material))
+    }
+}
+
+impl PartialEq for TransportKey {
+    fn eq(&self, other: &Self) -> bool {
+        Tensor::from_container(&self.0[..]).constant_time_eq(&Tensor::from_container(&other.0[..]))
+    }
+}
+
+impl Eq for TransportKey {}
+
+impl Debug for TransportKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TransportKey(..)")
+    }
+}
+
+/// The error which can occur when opening an envelope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvelopeError {
+    /// The value is not an envelope.
+    NotAnEnvelope,
+    /// The layout of the envelope is not supported by this version of the library.
+    UnsupportedVersion { expected: u32, found: u32 },
+    /// The envelope is shorter than announced by its header.
+    Truncated,
+    /// The envelope is longer than announced by its header.
+    TrailingBytes,
+    /// The tag of the envelope does not match its content, or was computed with another key.
+    AuthenticationFailure,
+}
+
+impl Display for EnvelopeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EnvelopeError::NotAnEnvelope => write!(f, "The value is not an envelope."),
+            EnvelopeError::UnsupportedVersion { expected, found } => write!(
+                f,
+                "The envelope format versions differ (expected: {}, found: {}).",
+                expected, found
+            ),
+            EnvelopeError::Truncated => write!(f, "The envelope is truncated."),
+            EnvelopeError::TrailingBytes => {
+                write!(f, "The envelope is followed by unexpected bytes.")
+            }
+            EnvelopeError::AuthenticationFailure => write!(
+                f,
+                "The envelope was corrupted, or sealed with another transport key."
+            ),
+        }
+    }
+}
+
+impl Error for EnvelopeError {}
+
+/// Wraps a serialized value in an envelope authenticated with the transport `key`.
+pub fn seal(key: &TransportKey, payload: &[u8]) -> Vec<u8> {
+    let mut envelope = Vec::with_capacity(HEADER_SIZE + payload.len() + TAG_SIZE);
+    envelope.extend_from_slice(&ENVELOPE_MAGIC);
+    envelope.extend_from_slice(&ENVELOPE_FORMAT_VERSION.to_le_bytes());
+    envelope.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+    envelope.extend_from_slice(payload);
+    let tag = blake3::keyed_hash(&key.0, envelope.as_slice());
+    envelope.extend_from_slice(tag.as_bytes());
+    envelope
+}
+
+/// Verifies an envelope with the transport `key`, and returns the serialized value it contains.
+pub fn open<'a>(key: &TransportKey, envelope: &'a [u8]) -> Result<&'a [u8], EnvelopeError> {
+    if !envelope.starts_with(&ENVELOPE_MAGIC) {
+        return Err(EnvelopeError::NotAnEnvelope);
+    }
+    if envelope.len() < HEADER_SIZE + TAG_SIZE {
+        return Err(EnvelopeError::Truncated);
+    }
+    let version = u32::from_le_bytes(envelope[4..8].try_into().unwrap());
+    if version != ENVELOPE_FORMAT_VERSION {
+        return Err(EnvelopeError::UnsupportedVersion {
+            expected: ENVELOPE_FORMAT_VERSION,
+            found: version,
+        });
+    }
+    let payload_len = u64::from_le_bytes(envelope[8..HEADER_SIZE].try_into().unwrap());
+    let available = (envelope.len() - HEADER_SIZE - TAG_SIZE) as u64;
+    if payload_len > available {
+        return Err(EnvelopeError::Truncated);
+    }
+    if payload_len < available {
+        return Err(EnvelopeError::TrailingBytes);
+    }
+    let (content, tag) = envelope.split_at(envelope.len() - TAG_SIZE);
+    let tag: [u8; TAG_SIZE] = tag.try_into().unwrap();
+    // The comparison of `blake3::Hash` values runs in constant time.
+    if blake3::keyed_hash(&key.0, content) != blake3::Hash::from(tag) {
+        return Err(EnvelopeError::AuthenticationFailure);
+    }
+    Ok(&content[HEADER_SIZE..])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn key() -> TransportKey {
+        TransportKey::derive("concrete-core envelope tests", b"key material")
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let payload = (0..=255).collect::<Vec<u8>>();
+        let envelope = seal(&key(), &payload);
+        assert_eq!(open(&key(), &envelope), Ok(payload.as_slice()));
+        let empty = seal(&key(), &[]);
+        assert_eq!(open(&key(), &empty), Ok(&[][..]));
+    }
+
+    #[test]
+    fn test_every_corrupted_byte_is_detected() {
+        let envelope = seal(&key(), b"some serialized entity");
+        for index in 0..envelope.len() {
+            let mut corrupted = envelope.clone();
+            corrupted[index] ^= 0x10;
+            assert!(open(&key(), &corrupted).is_err());
+        }
+    }
+
+    #[test]
+    fn test_truncation_is_detected() {
+        let envelope = seal(&key(), b"some serialized entity");
+        for len in 0..envelope.len() {
+            assert!(open(&key(), &envelope[..len]).is_err());
+        }
+        let mut extended = envelope;
+        extended.push(0);
+        assert_eq!(open(&key(), &extended), Err(EnvelopeError::TrailingBytes));
+    }
+
+    #[test]
+    fn test_key_equality() {
+        assert_eq!(key(), key());
+        let mut bytes = [7; 32];
+        let first = TransportKey::new(bytes);
+        for index in 0..bytes.len() {
+            bytes[index] ^= 1;
+            assert_ne!(first, TransportKey::new(bytes));
+            bytes[index] ^= 1;
+        }
+        assert_eq!(first, TransportKey::new(bytes));
+    }
+
+    #[test]
+    fn test_other_key_is_rejected() {
+        let envelope = seal(&key(), b"some serialized entity");
+        let other = TransportKey::new([0; 32]);
+        assert_eq!(
+            open(&other, &envelope),
+            Err(EnvelopeError::AuthenticationFailure)
+        );
+    }
+}
@@ -0,0 +1,469 @@
+//! A module containing helpers to negotiate the on-wire format of a client/server deployment.
+//!
+//! In a typical deployment, a client generates the keys and uploads the evaluation keys to a
+//! server, which then operates on the ciphertexts sent by the client. The evaluation keys
+//! are large, and a mismatch between the parameters or the entity formats expected by both sides
+//! is only noticed once they have been transferred and deserialized.
+//!
+//! The [`DeploymentManifest`] structure is a compact description of a deployment: the parameter
+//! set, the version of the serialized format of every entity, and the evaluation keys the server
+//! needs. Both sides can build their manifest, exchange it (it is serializable when the
+//! `serde_serialize` feature is enabled), and check the compatibility of the manifest of the other
+//! side with [`DeploymentManifest::check_compatibility`], before exchanging any key.
+//!
+//! # Example:
+//!
+//! ```
+//! use concrete_commons::parameters::{
+//!     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+//! };
+//! use concrete_core::backends::core::handshake::{
+//!     DeploymentManifest, DeploymentParameters, RequiredKey, TorusPrecision,
+//! };
+//! use concrete_core::prelude::*;
+//! # use std::error::Error;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+//! let parameters = DeploymentParameters {
+//!     precision: TorusPrecision::Bits64,
+//!     lwe_dimension: LweDimension(4),
+//!     glwe_dimension: GlweDimension(1),
+//!     polynomial_size: PolynomialSize(256),
+//! };
+//! let required_key = RequiredKey::LweKeyswitchKey {
+//!     input_lwe_dimension: LweDimension(256),
+//!     output_lwe_dimension: LweDimension(4),
+//!     decomposition_base_log: DecompositionBaseLog(3),
+//!     decomposition_level_count: DecompositionLevelCount(5),
+//! };
+//! let server_manifest = DeploymentManifest::new(parameters).with_required_key(required_key);
+//!
+//! // The client builds its own manifest, and checks it against the one of the server.
+//! let client_manifest = DeploymentManifest::new(parameters).with_required_key(required_key);
+//! client_manifest.check_compatibility(&server_manifest)?;
+//!
+//! // Before uploading a key, the client checks that the server expects it.
+//! let mut engine = CoreEngine::new()?;
+//! let input_key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(256))?;
+//! let output_key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(4))?;
+//! let ksk: LweKeyswitchKey64 = engine.create_lwe_keyswitch_key(
+//!     &input_key,
+//!     &output_key,
+//!     DecompositionLevelCount(5),
+//!     DecompositionBaseLog(3),
+//!     Variance(2_f64.powf(-50.)),
+//! )?;
+//! server_manifest.check_required_key(&RequiredKey::from_lwe_keyswitch_key(&ksk))?;
+//!
+//! // A server running with different parameters is detected.
+//! let drifted_manifest = DeploymentManifest::new(DeploymentParameters {
+//!     polynomial_size: PolynomialSize(512),
+//!     ..parameters
+//! })
+//! .with_required_key(required_key);
+//! assert!(client_manifest.check_compatibility(&drifted_manifest).is_err());
+//!
+//! engine.destroy(ksk)?;
+//! engine.destroy(input_key)?;
+//! engine.destroy(output_key)?;
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use crate::specification::entities::{LweBootstrapKeyEntity, LweKeyswitchKeyEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The version of the layout of the [`DeploymentManifest`] structure itself.
+pub const MANIFEST_FORMAT_VERSION: u32 = 1;
+
+/// An enumeration of the entities whose serialized format is described in a manifest.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ManifestEntity {
+    LweCiphertext,
+    LweCiphertextVector,
+    GlweCiphertext,
+    GlweCiphertextVector,
+    GgswCiphertext,
+    GgswSeededCiphertext,
+    LweKeyswitchKey,
+    PackingKeyswitchKey,
+    LweBootstrapKey,
+    FourierLweBootstrapKey,
+    TaggedCiphertext,
+}
+
+impl ManifestEntity {
+    /// All the entities described in a manifest.
+    pub const ALL: [ManifestEntity; 11] = [
+        ManifestEntity::LweCiphertext,
+        ManifestEntity::LweCiphertextVector,
+        ManifestEntity::GlweCiphertext,
+        ManifestEntity::GlweCiphertextVector,
+        ManifestEntity::GgswCiphertext,
+        ManifestEntity::GgswSeededCiphertext,
+        ManifestEntity::LweKeyswitchKey,
+        ManifestEntity::PackingKeyswitchKey,
+        ManifestEntity::LweBootstrapKey,
+        ManifestEntity::FourierLweBootstrapKey,
+        ManifestEntity::TaggedCiphertext,
+    ];
+
+    /// Returns the version of the serialized format of the entity, in this version of the library.
+    ///
+    /// This version must be bumped whenever the serialized layout of the entity changes.
+    pub fn current_version(&self) -> u32 {
+        match self {
+            ManifestEntity::LweCiphertext
+            | ManifestEntity::LweCiphertextVector
+            | ManifestEntity::GlweCiphertext
+            | ManifestEntity::GlweCiphertextVector
+            | ManifestEntity::GgswCiphertext
+            | ManifestEntity::GgswSeededCiphertext
+            | ManifestEntity::LweKeyswitchKey
+            | ManifestEntity::PackingKeyswitchKey
+            | ManifestEntity::LweBootstrapKey
+            | ManifestEntity::FourierLweBootstrapKey
+            | ManifestEntity::TaggedCiphertext => 1,
+        }
+    }
+}
+
+/// The version of the serialized format of an entity.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntityVersion {
+    pub entity: ManifestEntity,
+    pub version: u32,
+}
+
+/// The width of the integers used to represent the torus elements of a deployment.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TorusPrecision {
+    Bits32,
+    Bits64,
+}
+
+/// The parameter set of a deployment.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeploymentParameters {
+    pub precision: TorusPrecision,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+}
+
+/// A description of an evaluation key needed by the server of a deployment.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequiredKey {
+    LweKeyswitchKey {
+        input_lwe_dimension: LweDimension,
+        output_lwe_dimension: LweDimension,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    },
+    LweBootstrapKey {
+        input_lwe_dimension: LweDimension,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    },
+}
+
+impl RequiredKey {
+    /// Returns the description of an existing LWE keyswitch key.
+    pub fn from_lwe_keyswitch_key<Key: LweKeyswitchKeyEntity>(key: &Key) -> Self {
+        RequiredKey::LweKeyswitchKey {
+            input_lwe_dimension: key.input_lwe_dimension(),
+            output_lwe_dimension: key.output_lwe_dimension(),
+            decomposition_base_log: key.decomposition_base_log(),
+            decomposition_level_count: key.decomposition_level_count(),
+        }
+    }
+
+    /// Returns the description of an existing LWE bootstrap key.
+    pub fn from_lwe_bootstrap_key<Key: LweBootstrapKeyEntity>(key: &Key) -> Self {
+        RequiredKey::LweBootstrapKey {
+            input_lwe_dimension: key.input_lwe_dimension(),
+            glwe_dimension: key.glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+            decomposition_base_log: key.decomposition_base_log(),
+            decomposition_level_count: key.decomposition_level_count(),
+        }
+    }
+}
+
+/// A compact description of a deployment, exchanged by the client and the server before any key.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeploymentManifest {
+    format_version: u32,
+    parameters: DeploymentParameters,
+    entity_versions: Vec<EntityVersion>,
+    required_keys: Vec<RequiredKey>,
+}
+
+impl DeploymentManifest {
+    /// Creates a manifest for the given parameters, with the entity versions of this version of
+    /// the library, and no required key.
+    pub fn new(parameters: DeploymentParameters) -> Self {
+        DeploymentManifest {
+            format_version: MANIFEST_FORMAT_VERSION,
+            parameters,
+            entity_versions: ManifestEntity::ALL
+                .iter()
+                .map(|entity| EntityVersion {
+                    entity: *entity,
+                    version: entity.current_version(),
+                })
+                .collect(),
+            required_keys: Vec::new(),
+        }
+    }
+
+    /// Adds a key to the keys required by the deployment.
+    pub fn with_required_key(mut self, key: RequiredKey) -> Self {
+        if !self.required_keys.contains(&key) {
+            self.required_keys.push(key);
+        }
+        self
+    }
+
+    /// Returns the version of the layout of the manifest.
+    pub fn format_version(&self) -> u32 {
+        self.format_version
+    }
+
+    /// Returns the parameter set of the deployment.
+    pub fn parameters(&self) -> &DeploymentParameters {
+        &self.parameters
+    }
+
+    /// Returns the versions of the serialized format of the entities.
+    pub fn entity_versions(&self) -> &[EntityVersion] {
+        self.entity_versions.as_slice()
+    }
+
+    /// Returns the version of the serialized format of an entity, if it is described.
+    pub fn entity_version(&self, entity: ManifestEntity) -> Option<u32> {
+        self.entity_versions
+            .iter()
+            .find(|version| version.entity == entity)
+            .map(|version| version.version)
+    }
+
+    /// Returns the keys required by the deployment.
+    pub fn required_keys(&self) -> &[RequiredKey] {
+        self.required_keys.as_slice()
+    }
+
+    /// Checks that the `remote` manifest, received from the other side of the deployment, is
+    /// compatible with the local manifest.
+    ///
+    /// The two manifests are compatible when they have the same layout version, the same
+    /// parameters, the same version for every entity they both describe, and the same required
+    /// keys. The check is symmetric, so that both sides reach the same conclusion.
+    pub fn check_compatibility(&self, remote: &DeploymentManifest) -> Result<(), HandshakeError> {
+        if self.format_version != remote.format_version {
+            return Err(HandshakeError::FormatVersionMismatch {
+                local: self.format_version,
+                remote: remote.format_version,
+            });
+        }
+        if self.parameters != remote.parameters {
+            return Err(HandshakeError::ParametersMismatch);
+        }
+        for local in self.entity_versions.iter() {
+            if let Some(remote_version) = remote.entity_version(local.entity) {
+                if local.version != remote_version {
+                    return Err(HandshakeError::EntityVersionMismatch {
+                        entity: local.entity,
+                        local: local.version,
+                        remote: remote_version,
+                    });
+                }
+            }
+        }
+        if self.required_keys.len() != remote.required_keys.len()
+            || self
+                .required_keys
+                .iter()
+                .any(|key| !remote.required_keys.contains(key))
+        {
+            return Err(HandshakeError::RequiredKeysMismatch);
+        }
+        Ok(())
+    }
+
+    /// Checks that a key is required by the deployment, typically before uploading it.
+    pub fn check_required_key(&self, key: &RequiredKey) -> Result<(), HandshakeError> {
+        if self.required_keys.contains(key) {
+            Ok(())
+        } else {
+            Err(HandshakeError::UnexpectedKey)
+        }
+    }
+}
+
+/// The error which can occur when checking the compatibility of deployment manifests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HandshakeError {
+    /// The layouts of the manifests differ.
+    FormatVersionMismatch { local: u32, remote: u32 },
+    /// The parameter sets differ.
+    ParametersMismatch,
+    /// The serialized formats of an entity differ.
+    EntityVersionMismatch {
+        entity: ManifestEntity,
+        local: u32,
+        remote: u32,
+    },
+    /// The required keys differ.
+    RequiredKeysMismatch,
+    /// The key is not required by the deployment.
+    UnexpectedKey,
+}
+
+impl Display for HandshakeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HandshakeError::FormatVersionMismatch { local, remote } => write!(
+                f,
+                "The manifest format versions differ (local: {}, remote: {}).",
+                local, remote
+            ),
+            HandshakeError::ParametersMismatch => {
+                write!(f, "The deployment parameters differ.")
+            }
+            HandshakeError::EntityVersionMismatch {
+                entity,
+                local,
+                remote,
+            } => write!(
+                f,
+                "The {:?} format versions differ (local: {}, remote: {}).",
+                entity, local, remote
+            ),
+            HandshakeError::RequiredKeysMismatch => write!(f, "The required keys differ."),
+            HandshakeError::UnexpectedKey => {
+                write!(f, "The key is not required by the deployment.")
+            }
+        }
+    }
+}
+
+impl Error for HandshakeError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parameters() -> DeploymentParameters {
+        DeploymentParameters {
+            precision: TorusPrecision::Bits64,
+            lwe_dimension: LweDimension(630),
+            glwe_dimension: GlweDimension(1),
+            polynomial_size: PolynomialSize(1024),
+        }
+    }
+
+    fn bootstrap_key() -> RequiredKey {
+        RequiredKey::LweBootstrapKey {
+            input_lwe_dimension: LweDimension(630),
+            glwe_dimension: GlweDimension(1),
+            polynomial_size: PolynomialSize(1024),
+            decomposition_base_log: DecompositionBaseLog(7),
+            decomposition_level_count: DecompositionLevelCount(3),
+        }
+    }
+
+    fn keyswitch_key() -> RequiredKey {
+        RequiredKey::LweKeyswitchKey {
+            input_lwe_dimension: LweDimension(1024),
+            output_lwe_dimension: LweDimension(630),
+            decomposition_base_log: DecompositionBaseLog(4),
+            decomposition_level_count: DecompositionLevelCount(3),
+        }
+    }
+
+    #[test]
+    fn test_compatible_manifests() {
+        let local = DeploymentManifest::new(parameters())
+            .with_required_key(bootstrap_key())
+            .with_required_key(keyswitch_key());
+        let remote = DeploymentManifest::new(parameters())
+            .with_required_key(keyswitch_key())
+            .with_required_key(bootstrap_key());
+        assert_eq!(local.check_compatibility(&remote), Ok(()));
+        assert_eq!(remote.check_compatibility(&local), Ok(()));
+    }
+
+    #[test]
+    fn test_drift_is_detected() {
+        let local = DeploymentManifest::new(parameters()).with_required_key(bootstrap_key());
+
+        let mut remote = local.clone();
+        remote.parameters.precision = TorusPrecision::Bits32;
+        assert_eq!(
+            local.check_compatibility(&remote),
+            Err(HandshakeError::ParametersMismatch)
+        );
+
+        let mut remote = local.clone();
+        remote.entity_versions[0].version += 1;
+        assert_eq!(
+            remote.check_compatibility(&local),
+            Err(HandshakeError::EntityVersionMismatch {
+                entity: ManifestEntity::LweCiphertext,
+                local: 2,
+                remote: 1
+            })
+        );
+
+        let remote = local.clone().with_required_key(keyswitch_key());
+        assert_eq!(
+            local.check_compatibility(&remote),
+            Err(HandshakeError::RequiredKeysMismatch)
+        );
+        assert_eq!(
+            remote.check_compatibility(&local),
+            Err(HandshakeError::RequiredKeysMismatch)
+        );
+
+        let mut remote = local.clone();
+        remote.format_version += 1;
+        assert!(matches!(
+            local.check_compatibility(&remote),
+            Err(HandshakeError::FormatVersionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_unknown_entities_are_ignored() {
+        let local = DeploymentManifest::new(parameters());
+        let mut remote = local.clone();
+        remote.entity_versions.pop();
+        assert_eq!(local.check_compatibility(&remote), Ok(()));
+    }
+
+    #[test]
+    fn test_check_required_key() {
+        let manifest = DeploymentManifest::new(parameters()).with_required_key(bootstrap_key());
+        assert_eq!(manifest.check_required_key(&bootstrap_key()), Ok(()));
+        assert_eq!(
+            manifest.check_required_key(&keyswitch_key()),
+            Err(HandshakeError::UnexpectedKey)
+        );
+    }
+}
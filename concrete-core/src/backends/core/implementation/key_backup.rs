@@ -0,0 +1,484 @@
+//! A module containing a threshold backup of serialized secret keys.
+//!
+//! Backing up a secret key usually means handing its serialized form to external tooling, which
+//! then has to deal with the raw coefficients of the key. The [`split_secret`] function instead
+//! splits a serialized secret key into `share_count` shares, any `threshold` of which are enough
+//! to [`reconstruct_secret`] the key. The splitting uses Shamir's secret sharing over GF(256),
+//! applied to every byte of the serialized key.
+//!
+//! Every share ends with an integrity tag, such that a corrupted share is detected and reported
+//! before the reconstruction. The shares are also authenticated with a MAC, whose random key is
+//! split along with the secret: the key is reconstructed with the secret, and the MAC of every
+//! given share is checked to reject shares which were modified consistently with their integrity
+//! tag.
+//!
+//! # Security
+//!
+//! Neither the share values nor the MAC depend on the secret alone, such that fewer than
+//! `threshold` shares reveal nothing about the key.
+//!
+//! # Example:
+//!
+//! ```
+//! use concrete_commons::parameters::LweDimension;
+//! use concrete_core::backends::core::key_backup::{
+//!     reconstruct_secret, split_secret, KeyBackupError,
+//! };
+//! use concrete_core::prelude::*;
+//! # use std::error::Error;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+//! let mut engine = CoreEngine::new()?;
+//! let key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(4))?;
+//!
+//! // The key is split into 5 shares, any 3 of which allow to recover it.
+//! let serialized = engine.serialize(&key)?;
+//! let shares = split_secret(serialized.as_slice(), 3, 5)?;
+//!
+//! let recovered = reconstruct_secret(&[&shares[4], &shares[0], &shares[2]])?;
+//! let recovered_key: LweSecretKey64 = engine.deserialize(recovered.as_slice())?;
+//! assert_eq!(recovered_key, key);
+//!
+//! // Two shares are not enough.
+//! assert_eq!(
+//!     reconstruct_secret(&[&shares[0], &shares[1]]),
+//!     Err(KeyBackupError::NotEnoughShares {
+//!         threshold: 3,
+//!         found: 2
+//!     })
+//! );
+//!
+//! engine.destroy(key)?;
+//! engine.destroy(recovered_key)?;
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use crate::backends::core::private::math::random::RandomGenerator;
+use std::convert::TryInto;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The version of the layout of the shares.
+pub const SHARE_FORMAT_VERSION: u32 = 2;
+
+/// The bytes every share starts with.
+const SHARE_MAGIC: [u8; 4] = *b"CCKS";
+
+/// The size of the header of a share: the magic bytes, the version, the threshold, the share
+/// count, the index of the share, a reserved byte, the split identifier and the secret length.
+const HEADER_SIZE: usize = 36;
+
+/// The size of the split identifier, shared by all the shares of a split.
+const SPLIT_ID_SIZE: usize = 16;
+
+/// The size of the key of the MAC, split along with the secret.
+const MAC_KEY_SIZE: usize = 32;
+
+/// The size of the tags ending a share.
+const TAG_SIZE: usize = 32;
+
+/// The error which can occur when splitting a secret, or reconstructing it from shares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyBackupError {
+    /// The threshold is zero, or larger than the number of shares, or more than 255 shares were
+    /// requested.
+    InvalidThreshold {
+        threshold: usize,
+        share_count: usize,
+    },
+    /// The value at the given position is not a share.
+    NotAShare { position: usize },
+    /// The layout of the share at the given position is not supported by this version of the
+    /// library.
+    UnsupportedVersion {
+        position: usize,
+        expected: u32,
+        found: u32,
+    },
+    /// The share at the given position is shorter or longer than announced by its header.
+    InvalidLength { position: usize },
+    /// The integrity tag of the share at the given position does not match its content.
+    CorruptedShare { position: usize },
+    /// The share at the given position does not come from the same split as the first share.
+    MismatchedShare { position: usize },
+    /// The share at the given position has the same index as a previous share.
+    DuplicateShare { position: usize },
+    /// Less shares than the threshold of the split were given.
+    NotEnoughShares { threshold: usize, found: usize },
+    /// The MAC of a share does not match the reconstructed key, meaning that the shares were
+    /// tampered with.
+    ReconstructionFailure,
+}
+
+impl Display for KeyBackupError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeyBackupError::InvalidThreshold {
+                threshold,
+                share_count,
+            } => write!(
+                f,
+                "Invalid threshold {} for {} shares. The threshold must be non zero, and not \
+                larger than the number of shares, which can not exceed 255.",
+                threshold, share_count
+            ),
+            KeyBackupError::NotAShare { position } => {
+                write!(f, "The value at position {} is not a share.", position)
+            }
+            KeyBackupError::UnsupportedVersion {
+                position,
+                expected,
+                found,
+            } => write!(
+                f,
+                "The share format versions differ at position {} (expected: {}, found: {}).",
+                position, expected, found
+            ),
+            KeyBackupError::InvalidLength { position } => write!(
+                f,
+                "The share at position {} is truncated or extended.",
+                position
+            ),
+            KeyBackupError::CorruptedShare { position } => {
+                write!(f, "The share at position {} is corrupted.", position)
+            }
+            KeyBackupError::MismatchedShare { position } => write!(
+                f,
+                "The share at position {} comes from another split than the first share.",
+                position
+            ),
+            KeyBackupError::DuplicateShare { position } => {
+                write!(f, "The share at position {} was already given.", position)
+            }
+            KeyBackupError::NotEnoughShares { threshold, found } => write!(
+                f,
+                "{} shares are needed to reconstruct the secret, but only {} were given.",
+                threshold, found
+            ),
+            KeyBackupError::ReconstructionFailure => write!(
+                f,
+                "The reconstructed secret does not match the shares, which were tampered with."
+            ),
+        }
+    }
+}
+
+impl Error for KeyBackupError {}
+
+/// Splits a serialized secret into `share_count` shares, any `threshold` of which allow to
+/// reconstruct it.
+pub fn split_secret(
+    secret: &[u8],
+    threshold: usize,
+    share_count: usize,
+) -> Result<Vec<Vec<u8>>, KeyBackupError> {
+    if threshold == 0 || threshold > share_count || share_count > 255 {
+        return Err(KeyBackupError::InvalidThreshold {
+            threshold,
+            share_count,
+        });
+    }
+    let mut generator = RandomGenerator::new(None);
+    let split_id: Vec<u8> = (0..SPLIT_ID_SIZE)
+        .map(|_| generator.random_uniform())
+        .collect();
+    let mut mac_key = [0u8; MAC_KEY_SIZE];
+    mac_key
+        .iter_mut()
+        .for_each(|byte| *byte = generator.random_uniform());
+
+    // Every byte of the secret and of the MAC key is the constant coefficient of a random
+    // polynomial of degree `threshold - 1`, and share `x` contains the evaluations of these
+    // polynomials at `x`.
+    let mut shares: Vec<Vec<u8>> = (1..=share_count as u8)
+        .map(|index| {
+            let mut share =
+                Vec::with_capacity(HEADER_SIZE + secret.len() + MAC_KEY_SIZE + 2 * TAG_SIZE);
+            share.extend_from_slice(&SHARE_MAGIC);
+            share.extend_from_slice(&SHARE_FORMAT_VERSION.to_le_bytes());
+            share.extend_from_slice(&[threshold as u8, share_count as u8, index, 0]);
+            share.extend_from_slice(&split_id);
+            share.extend_from_slice(&(secret.len() as u64).to_le_bytes());
+            share
+        })
+        .collect();
+    let mut coefficients = vec![0u8; threshold];
+    for byte in secret.iter().chain(mac_key.iter()) {
+        coefficients[0] = *byte;
+        for coefficient in coefficients[1..].iter_mut() {
+            *coefficient = generator.random_uniform();
+        }
+        for (share, index) in shares.iter_mut().zip(1..=share_count as u8) {
+            share.push(evaluate(&coefficients, index));
+        }
+    }
+    coefficients.iter_mut().for_each(|c| *c = 0);
+
+    for share in shares.iter_mut() {
+        let mac = blake3::keyed_hash(&mac_key, share.as_slice());
+        share.extend_from_slice(mac.as_bytes());
+        let integrity_tag = blake3::hash(share.as_slice());
+        share.extend_from_slice(integrity_tag.as_bytes());
+    }
+    mac_key.iter_mut().for_each(|byte| *byte = 0);
+    Ok(shares)
+}
+
+/// Reconstructs a serialized secret from at least `threshold` shares of the same split.
+///
+/// Every share is verified before the reconstruction, and the first `threshold` shares are used
+/// to reconstruct the secret. The MAC of every given share is then checked with the reconstructed
+/// key.
+pub fn reconstruct_secret(shares: &[&[u8]]) -> Result<Vec<u8>, KeyBackupError> {
+    let parsed = shares
+        .iter()
+        .enumerate()
+        .map(|(position, share)| parse_share(position, share))
+        .collect::<Result<Vec<_>, _>>()?;
+    let first = match parsed.first() {
+        Some(first) => first,
+        None => {
+            return Err(KeyBackupError::NotEnoughShares {
+                threshold: 1,
+                found: 0,
+            })
+        }
+    };
+    for (position, share) in parsed.iter().enumerate().skip(1) {
+        if share.threshold != first.threshold
+            || share.share_count != first.share_count
+            || share.split_id != first.split_id
+            || share.values.len() != first.values.len()
+        {
+            return Err(KeyBackupError::MismatchedShare { position });
+        }
+        if parsed[..position].iter().any(|s| s.index == share.index) {
+            return Err(KeyBackupError::DuplicateShare { position });
+        }
+    }
+    let threshold = first.threshold as usize;
+    if parsed.len() < threshold {
+        return Err(KeyBackupError::NotEnoughShares {
+            threshold,
+            found: parsed.len(),
+        });
+    }
+
+    // The secret and the MAC key are the values at zero of the interpolation polynomials of the
+    // shares.
+    let used = &parsed[..threshold];
+    let lagrange_coefficients: Vec<u8> = used
+        .iter()
+        .map(|share| {
+            used.iter()
+                .filter(|other| other.index != share.index)
+                .fold(1, |acc, other| {
+                    gf_mul(acc, gf_div(other.index, other.index ^ share.index))
+                })
+        })
+        .collect();
+    let mut secret: Vec<u8> = (0..first.values.len())
+        .map(|byte_index| {
+            used.iter()
+                .zip(lagrange_coefficients.iter())
+                .fold(0, |acc, (share, coefficient)| {
+                    acc ^ gf_mul(share.values[byte_index], *coefficient)
+                })
+        })
+        .collect();
+    let mac_key: [u8; MAC_KEY_SIZE] = secret[secret.len() - MAC_KEY_SIZE..].try_into().unwrap();
+    secret.truncate(secret.len() - MAC_KEY_SIZE);
+    // The comparison of `blake3::Hash` values runs in constant time.
+    let authentic = parsed
+        .iter()
+        .all(|share| blake3::keyed_hash(&mac_key, share.authenticated) == share.mac);
+    if !authentic {
+        secret.iter_mut().for_each(|byte| *byte = 0);
+        return Err(KeyBackupError::ReconstructionFailure);
+    }
+    Ok(secret)
+}
+
+/// A share whose integrity was verified.
+struct ParsedShare<'a> {
+    threshold: u8,
+    share_count: u8,
+    index: u8,
+    split_id: &'a [u8],
+    values: &'a [u8],
+    authenticated: &'a [u8],
+    mac: blake3::Hash,
+}
+
+fn parse_share(position: usize, share: &[u8]) -> Result<ParsedShare<'_>, KeyBackupError> {
+    if !share.starts_with(&SHARE_MAGIC) {
+        return Err(KeyBackupError::NotAShare { position });
+    }
+    if share.len() < HEADER_SIZE + MAC_KEY_SIZE + 2 * TAG_SIZE {
+        return Err(KeyBackupError::InvalidLength { position });
+    }
+    let version = u32::from_le_bytes(share[4..8].try_into().unwrap());
+    if version != SHARE_FORMAT_VERSION {
+        return Err(KeyBackupError::UnsupportedVersion {
+            position,
+            expected: SHARE_FORMAT_VERSION,
+            found: version,
+        });
+    }
+    let secret_len = u64::from_le_bytes(share[28..HEADER_SIZE].try_into().unwrap());
+    if secret_len != (share.len() - HEADER_SIZE - MAC_KEY_SIZE - 2 * TAG_SIZE) as u64 {
+        return Err(KeyBackupError::InvalidLength { position });
+    }
+    let (content, integrity_tag) = share.split_at(share.len() - TAG_SIZE);
+    let integrity_tag: [u8; TAG_SIZE] = integrity_tag.try_into().unwrap();
+    if blake3::hash(content) != blake3::Hash::from(integrity_tag) {
+        return Err(KeyBackupError::CorruptedShare { position });
+    }
+    let (threshold, share_count, index) = (share[8], share[9], share[10]);
+    if threshold == 0 || threshold > share_count || index == 0 || index > share_count {
+        return Err(KeyBackupError::NotAShare { position });
+    }
+    let (authenticated, mac) = content.split_at(content.len() - TAG_SIZE);
+    let mac: [u8; TAG_SIZE] = mac.try_into().unwrap();
+    Ok(ParsedShare {
+        threshold,
+        share_count,
+        index,
+        split_id: &share[12..12 + SPLIT_ID_SIZE],
+        values: &authenticated[HEADER_SIZE..],
+        authenticated,
+        mac: blake3::Hash::from(mac),
+    })
+}
+
+// Evaluates a polynomial with coefficients in GF(256) at `x`, with the Horner method.
+fn evaluate(coefficients: &[u8], x: u8) -> u8 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0, |acc, coefficient| gf_mul(acc, x) ^ coefficient)
+}
+
+// Multiplies two elements of GF(256), represented modulo the polynomial of the AES. The loop does
+// not branch on the values, which may be secret.
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    for _ in 0..8 {
+        product ^= a & 0u8.wrapping_sub(b & 1);
+        let carry = 0u8.wrapping_sub(a >> 7);
+        a = (a << 1) ^ (0x1b & carry);
+        b >>= 1;
+    }
+    product
+}
+
+// Divides two elements of GF(256), the divisor being non zero. The inverse is computed as
+// `b^254`.
+fn gf_div(a: u8, b: u8) -> u8 {
+    debug_assert_ne!(b, 0);
+    let mut inverse = 1;
+    let mut power = b;
+    let mut exponent = 254u8;
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            inverse = gf_mul(inverse, power);
+        }
+        power = gf_mul(power, power);
+        exponent >>= 1;
+    }
+    gf_mul(a, inverse)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gf_arithmetic() {
+        assert_eq!(gf_mul(0x53, 0xca), 0x01);
+        for a in 1..=255u8 {
+            assert_eq!(gf_mul(gf_div(1, a), a), 1);
+        }
+    }
+
+    #[test]
+    fn test_every_subset_reconstructs() {
+        let secret = (0..=255).collect::<Vec<u8>>();
+        let shares = split_secret(&secret, 3, 5).unwrap();
+        for (i, first) in shares.iter().enumerate() {
+            for (j, second) in shares.iter().enumerate().filter(|(j, _)| *j != i) {
+                for (_, third) in shares
+                    .iter()
+                    .enumerate()
+                    .filter(|(k, _)| *k != i && *k != j)
+                {
+                    let subset = [&first[..], &second[..], &third[..]];
+                    assert_eq!(reconstruct_secret(&subset), Ok(secret.clone()));
+                }
+            }
+        }
+        let single = split_secret(&secret, 1, 1).unwrap();
+        assert_eq!(reconstruct_secret(&[&single[0]]), Ok(secret));
+    }
+
+    #[test]
+    fn test_invalid_thresholds_are_rejected() {
+        assert!(split_secret(b"secret", 0, 3).is_err());
+        assert!(split_secret(b"secret", 4, 3).is_err());
+        assert!(split_secret(b"secret", 2, 256).is_err());
+    }
+
+    #[test]
+    fn test_every_corrupted_byte_is_detected() {
+        let shares = split_secret(b"some serialized key", 2, 2).unwrap();
+        for index in 0..shares[0].len() {
+            let mut corrupted = shares[0].clone();
+            corrupted[index] ^= 0x10;
+            assert!(reconstruct_secret(&[&corrupted, &shares[1]]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_shares_of_different_splits_are_rejected() {
+        let first = split_secret(b"some serialized key", 2, 3).unwrap();
+        let second = split_secret(b"some serialized key", 2, 3).unwrap();
+        assert_eq!(
+            reconstruct_secret(&[&first[0], &second[1]]),
+            Err(KeyBackupError::MismatchedShare { position: 1 })
+        );
+        assert_eq!(
+            reconstruct_secret(&[&first[0], &first[0]]),
+            Err(KeyBackupError::DuplicateShare { position: 1 })
+        );
+    }
+
+    #[test]
+    fn test_retagged_share_is_rejected() {
+        let shares = split_secret(b"some serialized key", 2, 3).unwrap();
+        let mut forged = shares[0].clone();
+        forged[HEADER_SIZE] ^= 1;
+        let content_len = forged.len() - TAG_SIZE;
+        let tag = blake3::hash(&forged[..content_len]);
+        forged[content_len..].copy_from_slice(tag.as_bytes());
+        assert_eq!(
+            reconstruct_secret(&[&forged, &shares[1]]),
+            Err(KeyBackupError::ReconstructionFailure)
+        );
+    }
+
+    #[test]
+    fn test_retagged_unused_share_is_rejected() {
+        let shares = split_secret(b"some serialized key", 2, 3).unwrap();
+        let mut forged = shares[2].clone();
+        forged[HEADER_SIZE] ^= 1;
+        let content_len = forged.len() - TAG_SIZE;
+        let tag = blake3::hash(&forged[..content_len]);
+        forged[content_len..].copy_from_slice(tag.as_bytes());
+        assert_eq!(
+            reconstruct_secret(&[&shares[0], &shares[1], &forged]),
+            Err(KeyBackupError::ReconstructionFailure)
+        );
+    }
+}
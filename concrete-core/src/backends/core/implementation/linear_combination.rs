@@ -0,0 +1,199 @@
+//! A module containing a builder for linear combinations of LWE ciphertexts.
+//!
+//! The [`LinearCombinationBuilder`] structure accumulates `(cleartext, ciphertext)` terms and a
+//! plaintext bias, while tracking the 2-norm of the cleartext weights. Before anything is
+//! computed, the variance of the noise of the result is estimated with the noise parameter
+//! estimator, and checked against the bound given at the creation of the builder. The whole
+//! combination is then computed in a single pass over the ciphertexts, without materializing the
+//! intermediate products.
+//!
+//! # Example:
+//!
+//! ```
+//! use concrete_commons::dispersion::Variance;
+//! use concrete_commons::parameters::LweDimension;
+//! use concrete_core::backends::core::linear_combination::{
+//!     LinearCombinationBuilder, LinearCombinationError,
+//! };
+//! use concrete_core::prelude::*;
+//! # use std::error::Error;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+//! let noise = Variance(2_f64.powf(-50.));
+//!
+//! let mut engine = CoreEngine::new()?;
+//! let key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(4))?;
+//! let plaintext = engine.create_plaintext(&(1_u64 << 50))?;
+//! let x = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+//! let y = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+//!
+//! // We compute 3 * x - 2 * y + 1, whose noise must stay below 2^-40.
+//! let mut builder = LinearCombinationBuilder::new(Variance(2_f64.powf(-40.)));
+//! builder
+//!     .add_term(3, &x, noise)
+//!     .add_term(2_u64.wrapping_neg(), &y, noise)
+//!     .add_plaintext(1 << 50);
+//! assert_eq!(builder.squared_norm(), 13.);
+//! let output = builder.execute()?;
+//! #
+//! assert_eq!(output.lwe_dimension(), LweDimension(4));
+//!
+//! // A combination with too large weights is rejected before being computed.
+//! let mut builder = LinearCombinationBuilder::new(Variance(2_f64.powf(-40.)));
+//! builder.add_term(1 << 10, &x, noise);
+//! assert_eq!(
+//!     builder.execute().unwrap_err(),
+//!     LinearCombinationError::NoiseBoundExceeded
+//! );
+//!
+//! engine.destroy(key)?;
+//! engine.destroy(plaintext)?;
+//! engine.destroy(x)?;
+//! engine.destroy(y)?;
+//! engine.destroy(output)?;
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use crate::backends::core::implementation::pipeline::{fill_with_multisum, PipelineCiphertext};
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::numeric::{CastInto, Numeric, UnsignedInteger};
+use concrete_npe::estimate_weighted_sum_noise;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The error which can occur when executing a [`LinearCombinationBuilder`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinearCombinationError {
+    /// The combination does not contain any ciphertext.
+    EmptyCombination,
+    /// The LWE dimensions of the ciphertexts of the combination do not match.
+    LweDimensionMismatch,
+    /// The estimated variance of the noise of the result exceeds the bound of the builder.
+    NoiseBoundExceeded,
+}
+
+impl Display for LinearCombinationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LinearCombinationError::EmptyCombination => write!(
+                f,
+                "The linear combination must contain at least one ciphertext."
+            ),
+            LinearCombinationError::LweDimensionMismatch => write!(
+                f,
+                "The LWE dimensions of the ciphertexts of the combination must match."
+            ),
+            LinearCombinationError::NoiseBoundExceeded => write!(
+                f,
+                "The estimated noise of the combination exceeds the maximum variance."
+            ),
+        }
+    }
+}
+
+impl Error for LinearCombinationError {}
+
+/// A builder accumulating the terms of a linear combination of LWE ciphertexts.
+///
+/// The cleartext weights are integers modulo $q$, interpreted as signed integers for the noise
+/// estimation and the norm tracking: the weight `2_u64.wrapping_neg()` stands for $-2$.
+pub struct LinearCombinationBuilder<'c, Ciphertext>
+where
+    Ciphertext: PipelineCiphertext,
+{
+    terms: Vec<(&'c Ciphertext, Ciphertext::Raw)>,
+    variances: Vec<Variance>,
+    bias: Ciphertext::Raw,
+    squared_norm: f64,
+    max_variance: Variance,
+}
+
+impl<'c, Ciphertext> LinearCombinationBuilder<'c, Ciphertext>
+where
+    Ciphertext: PipelineCiphertext,
+{
+    /// Creates an empty combination, whose result must have a noise variance of at most
+    /// `max_variance`.
+    pub fn new(max_variance: Variance) -> Self {
+        LinearCombinationBuilder {
+            terms: Vec::new(),
+            variances: Vec::new(),
+            bias: Ciphertext::Raw::ZERO,
+            squared_norm: 0.,
+            max_variance,
+        }
+    }
+
+    /// Adds the product of a ciphertext, whose noise has the given `variance`, by a cleartext to
+    /// the combination.
+    pub fn add_term(
+        &mut self,
+        cleartext: Ciphertext::Raw,
+        ciphertext: &'c Ciphertext,
+        variance: Variance,
+    ) -> &mut Self {
+        let weight: f64 = cleartext.into_signed().cast_into();
+        self.squared_norm += weight * weight;
+        self.terms.push((ciphertext, cleartext));
+        self.variances.push(variance);
+        self
+    }
+
+    /// Adds a plaintext to the combination.
+    pub fn add_plaintext(&mut self, plaintext: Ciphertext::Raw) -> &mut Self {
+        self.bias = self.bias.wrapping_add(plaintext);
+        self
+    }
+
+    /// Returns the number of ciphertexts in the combination.
+    pub fn term_count(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns the squared 2-norm of the cleartext weights of the combination.
+    pub fn squared_norm(&self) -> f64 {
+        self.squared_norm
+    }
+
+    /// Returns the 2-norm of the cleartext weights of the combination.
+    pub fn norm(&self) -> f64 {
+        self.squared_norm.sqrt()
+    }
+
+    /// Returns the estimated variance of the noise of the result of the combination.
+    pub fn estimated_variance(&self) -> Variance {
+        let weights: Vec<Ciphertext::Raw> = self.terms.iter().map(|(_, weight)| *weight).collect();
+        estimate_weighted_sum_noise(self.variances.as_slice(), weights.as_slice())
+    }
+
+    /// Checks the combination, and computes it in a single pass.
+    ///
+    /// Nothing is computed if the estimated variance of the noise of the result exceeds the bound
+    /// of the builder.
+    pub fn execute(&self) -> Result<Ciphertext, LinearCombinationError> {
+        let lwe_dimension = match self.terms.first() {
+            Some((ciphertext, _)) => ciphertext.lwe_dimension(),
+            None => return Err(LinearCombinationError::EmptyCombination),
+        };
+        if self
+            .terms
+            .iter()
+            .any(|(ciphertext, _)| ciphertext.lwe_dimension() != lwe_dimension)
+        {
+            return Err(LinearCombinationError::LweDimensionMismatch);
+        }
+        if self.estimated_variance().get_variance() > self.max_variance.get_variance() {
+            return Err(LinearCombinationError::NoiseBoundExceeded);
+        }
+        let terms: Vec<(&[Ciphertext::Raw], Ciphertext::Raw)> = self
+            .terms
+            .iter()
+            .map(|(ciphertext, weight)| (ciphertext.as_raw(), *weight))
+            .collect();
+        let mut output = Ciphertext::allocate(lwe_dimension);
+        fill_with_multisum(output.as_mut_raw(), terms.as_slice(), self.bias);
+        Ok(output)
+    }
+}
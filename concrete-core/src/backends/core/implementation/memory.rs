@@ -0,0 +1,840 @@
+//! A module containing aligned containers, to exchange entities with foreign memory.
+//!
+//! Accelerators and SIMD kernels usually require their inputs to be stored in contiguous buffers
+//! aligned on 32 or 64 bytes. The entities of the core backend are backed by `Vec`s, which only
+//! guarantee the natural alignment of their elements, so handing them to a DMA engine may require
+//! a bounce copy.
+//!
+//! The [`AlignedBuffer`] type is a contiguous, fixed-size buffer whose alignment is chosen at
+//! allocation. The aligned entities of the core backend (for instance
+//! [`AlignedLweCiphertextVector64`](super::entities::AlignedLweCiphertextVector64)) are backed by
+//! such buffers: they can be created from a buffer filled by the caller, and consumed to get the
+//! buffer back, without any copy. The alignment of any core entity can be queried with the
+//! [`MemoryAlignment`] trait.
+//!
+//! The [`SharedSlice`] type is a `#[repr(C)]`, drop-glue-free view over elements owned by someone
+//! else, typically a shared memory segment mapped by several processes. The view entities of the
+//! core backend (for instance
+//! [`LweCiphertextVectorView64`](super::entities::LweCiphertextVectorView64)) are backed by such
+//! slices, so that a process can read the ciphertexts written by another one in place, without
+//! serializing them across the process boundary. Since the processes do not share anything but the
+//! segment, the segment starts with a [`SharedSegmentHeader`] of fixed layout, which records the
+//! dimension, the count and the alignment of the ciphertexts. A [`SharedSegment`] reads and checks
+//! this header, and gives the slice and the parameters needed to create the view.
+//!
+//! # Example:
+//!
+//! ```
+//! use concrete_commons::parameters::LweSize;
+//! use concrete_core::backends::core::memory::{AlignedBuffer, Alignment, MemoryAlignment};
+//! use concrete_core::prelude::*;
+//! # use std::error::Error;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! let lwe_size = LweSize(5);
+//! // The buffer would typically be filled by a DMA transfer through `as_mut_ptr`.
+//! let mut buffer = AlignedBuffer::<u64>::new(3 * lwe_size.0, Alignment::CACHE_LINE);
+//! buffer.as_mut_slice()[lwe_size.0 - 1] = 1 << 60;
+//! assert_eq!(buffer.as_ptr() as usize % 64, 0);
+//!
+//! let mut engine = CoreEngine::new()?;
+//! let ciphertext_vector: AlignedLweCiphertextVector64 =
+//!     engine.create_lwe_ciphertext_vector(buffer, lwe_size)?;
+//! assert_eq!(ciphertext_vector.alignment(), Alignment::CACHE_LINE);
+//! assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(3));
+//!
+//! let buffer = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+//! assert_eq!(buffer.as_slice()[lwe_size.0 - 1], 1 << 60);
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use std::alloc::{alloc_zeroed, dealloc, handle_alloc_error, Layout};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+use std::mem::{align_of, size_of};
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+
+use concrete_commons::numeric::Numeric;
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension, LweSize};
+
+/// The alignment of a memory buffer, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Alignment(pub usize);
+
+impl Alignment {
+    /// The alignment required by 256 bits SIMD instructions.
+    pub const SIMD_256: Alignment = Alignment(32);
+
+    /// The alignment of a cache line, which is the one expected by most DMA engines.
+    pub const CACHE_LINE: Alignment = Alignment(64);
+
+    /// Returns the natural alignment of the `T` type.
+    pub fn of<T>() -> Alignment {
+        Alignment(align_of::<T>())
+    }
+
+    /// Returns whether buffers of `T` elements can be allocated with this alignment.
+    ///
+    /// The alignment must be a power of two, at least as large as the natural alignment of `T`.
+    pub fn is_valid_for<T>(&self) -> bool {
+        self.0.is_power_of_two() && self.0 >= align_of::<T>()
+    }
+
+    /// Returns whether the `ptr` pointer is aligned on this alignment.
+    pub fn is_satisfied_by<T>(&self, ptr: *const T) -> bool {
+        (ptr as usize).is_multiple_of(self.0)
+    }
+}
+
+/// A trait for the entities whose memory alignment can be queried.
+pub trait MemoryAlignment {
+    /// Returns the alignment guaranteed for the data of the entity.
+    fn alignment(&self) -> Alignment;
+}
+
+/// A contiguous buffer of numeric elements, allocated with a chosen alignment.
+///
+/// The buffer is zero-initialized at allocation, and its length can not change afterwards.
+pub struct AlignedBuffer<T: Numeric> {
+    ptr: NonNull<T>,
+    len: usize,
+    alignment: Alignment,
+}
+
+// The buffer uniquely owns its allocation, like a `Vec<T>` does.
+unsafe impl<T: Numeric + Send> Send for AlignedBuffer<T> {}
+unsafe impl<T: Numeric + Sync> Sync for AlignedBuffer<T> {}
+
+impl<T: Numeric> AlignedBuffer<T> {
+    /// Allocates a zeroed buffer of `len` elements, aligned on `alignment`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alignment is not valid for `T`, as checked by [`Alignment::is_valid_for`].
+    pub fn new(len: usize, alignment: Alignment) -> Self {
+        assert!(
+            alignment.is_valid_for::<T>(),
+            "Invalid alignment {} for elements aligned on {} bytes.",
+            alignment.0,
+            align_of::<T>()
+        );
+        if len == 0 {
+            // No allocation is made for empty buffers, but the pointer must still be aligned.
+            return AlignedBuffer {
+                ptr: NonNull::new(alignment.0 as *mut T).unwrap(),
+                len,
+                alignment,
+            };
+        }
+        let layout = Self::layout(len, alignment);
+        // Safety: the layout has a non-zero size, and all the numeric types are valid when zeroed.
+        let ptr = unsafe { alloc_zeroed(layout) } as *mut T;
+        let ptr = NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout));
+        AlignedBuffer {
+            ptr,
+            len,
+            alignment,
+        }
+    }
+
+    /// Allocates a buffer aligned on `alignment`, holding a copy of `values`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alignment is not valid for `T`, as checked by [`Alignment::is_valid_for`].
+    pub fn from_slice(values: &[T], alignment: Alignment) -> Self {
+        let mut buffer = Self::new(values.len(), alignment);
+        buffer.as_mut_slice().copy_from_slice(values);
+        buffer
+    }
+
+    /// Returns the alignment of the buffer.
+    pub fn alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    /// Returns the number of elements of the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a slice over the elements of the buffer.
+    pub fn as_slice(&self) -> &[T] {
+        // Safety: the pointer is valid for `len` initialized elements.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Returns a mutable slice over the elements of the buffer.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safety: the pointer is valid for `len` initialized elements, uniquely owned.
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Returns a pointer to the first element of the buffer.
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr.as_ptr()
+    }
+
+    /// Returns a mutable pointer to the first element of the buffer.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    fn layout(len: usize, alignment: Alignment) -> Layout {
+        let size = len
+            .checked_mul(size_of::<T>())
+            .expect("The size of the buffer overflows.");
+        Layout::from_size_align(size, alignment.0).expect("The size of the buffer overflows.")
+    }
+}
+
+impl<T: Numeric> Drop for AlignedBuffer<T> {
+    fn drop(&mut self) {
+        if self.len != 0 {
+            // Safety: the pointer was allocated with this very layout.
+            unsafe {
+                dealloc(
+                    self.ptr.as_ptr() as *mut u8,
+                    Self::layout(self.len, self.alignment),
+                )
+            };
+        }
+    }
+}
+
+impl<T: Numeric> Clone for AlignedBuffer<T> {
+    fn clone(&self) -> Self {
+        Self::from_slice(self.as_slice(), self.alignment)
+    }
+}
+
+impl<T: Numeric + Debug> Debug for AlignedBuffer<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AlignedBuffer")
+            .field("alignment", &self.alignment)
+            .field("data", &self.as_slice())
+            .finish()
+    }
+}
+
+impl<T: Numeric> PartialEq for AlignedBuffer<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Numeric> Deref for AlignedBuffer<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: Numeric> DerefMut for AlignedBuffer<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Numeric> MemoryAlignment for AlignedBuffer<T> {
+    fn alignment(&self) -> Alignment {
+        self.alignment
+    }
+}
+
+/// An error occurring when creating a [`SharedSlice`] from raw parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SharedSliceError {
+    /// The pointer is null.
+    NullPointer,
+    /// The pointer is not aligned on the natural alignment of the elements.
+    MisalignedPointer,
+    /// The size of the slice, in bytes, overflows an `isize`. For a shared segment, the size
+    /// computed from its header overflows a `usize`.
+    SizeOverflow,
+    /// The header of a shared segment does not start with the expected magic bytes, or was
+    /// written with another version of its layout.
+    InvalidHeader,
+    /// The header of a shared segment describes elements of another size.
+    ElementSizeMismatch,
+    /// The header of a shared segment records an alignment which is not a power of two at least
+    /// as large as the one of the elements, or which is not satisfied by the segment.
+    InvalidAlignment,
+    /// The shared segment is shorter than its header, or than the size recorded in its header.
+    SegmentTooShort,
+}
+
+impl Display for SharedSliceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SharedSliceError::NullPointer => write!(f, "The pointer is null."),
+            SharedSliceError::MisalignedPointer => write!(
+                f,
+                "The pointer is not aligned on the natural alignment of the elements."
+            ),
+            SharedSliceError::SizeOverflow => {
+                write!(f, "The size of the slice overflows an isize.")
+            }
+            SharedSliceError::InvalidHeader => {
+                write!(f, "The header of the shared segment is not valid.")
+            }
+            SharedSliceError::ElementSizeMismatch => write!(
+                f,
+                "The header of the shared segment describes elements of another size."
+            ),
+            SharedSliceError::InvalidAlignment => write!(
+                f,
+                "The alignment recorded in the header of the shared segment is not valid."
+            ),
+            SharedSliceError::SegmentTooShort => write!(
+                f,
+                "The shared segment is shorter than the size recorded in its header."
+            ),
+        }
+    }
+}
+
+impl Error for SharedSliceError {}
+
+/// A read-only view over a contiguous buffer of numeric elements owned by someone else.
+///
+/// The view is `#[repr(C)]`, `Copy`, and has no drop glue: it only holds a pointer and a length,
+/// and never frees the memory it points to. It is meant to wrap memory which is not managed by
+/// the Rust allocator, such as a shared memory segment mapped by several processes.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct SharedSlice<'a, T: Numeric> {
+    ptr: NonNull<T>,
+    len: usize,
+    _lifetime: PhantomData<&'a [T]>,
+}
+
+// The view behaves like a `&[T]`.
+unsafe impl<'a, T: Numeric + Sync> Send for SharedSlice<'a, T> {}
+unsafe impl<'a, T: Numeric + Sync> Sync for SharedSlice<'a, T> {}
+
+impl<'a, T: Numeric> SharedSlice<'a, T> {
+    /// Creates a view over `len` elements starting at `ptr`.
+    ///
+    /// The pointer must be non-null and aligned on the natural alignment of `T`, and the size of
+    /// the slice must fit in an `isize`. Otherwise, an error is returned. An empty slice only
+    /// requires a non-null aligned pointer.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be valid for reads of `len` initialized elements, for the whole `'a`
+    /// lifetime, and the memory must not be mutated during this lifetime, including by another
+    /// process sharing it. These properties can not be checked.
+    pub unsafe fn from_raw_parts(ptr: *const T, len: usize) -> Result<Self, SharedSliceError> {
+        let ptr = NonNull::new(ptr as *mut T).ok_or(SharedSliceError::NullPointer)?;
+        if !Alignment::of::<T>().is_satisfied_by(ptr.as_ptr()) {
+            return Err(SharedSliceError::MisalignedPointer);
+        }
+        match len.checked_mul(size_of::<T>()) {
+            Some(size) if size <= isize::MAX as usize => {}
+            _ => return Err(SharedSliceError::SizeOverflow),
+        }
+        Ok(SharedSlice {
+            ptr,
+            len,
+            _lifetime: PhantomData,
+        })
+    }
+
+    /// Creates a view over the elements of an existing slice.
+    pub fn from_slice(values: &'a [T]) -> Self {
+        SharedSlice {
+            ptr: NonNull::from(values).cast(),
+            len: values.len(),
+            _lifetime: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements of the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the view is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a slice over the elements of the view.
+    pub fn as_slice(&self) -> &'a [T] {
+        // Safety: the pointer is valid for reads of `len` elements for the `'a` lifetime.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Returns a pointer to the first element of the view.
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr.as_ptr()
+    }
+}
+
+impl<'a, T: Numeric + Debug> Debug for SharedSlice<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedSlice")
+            .field("data", &self.as_slice())
+            .finish()
+    }
+}
+
+impl<'a, T: Numeric> PartialEq for SharedSlice<'a, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<'a, T: Numeric> Deref for SharedSlice<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<'a, T: Numeric> MemoryAlignment for SharedSlice<'a, T> {
+    /// Returns the largest alignment satisfied by the view, up to [`Alignment::CACHE_LINE`].
+    fn alignment(&self) -> Alignment {
+        [Alignment::CACHE_LINE, Alignment::SIMD_256]
+            .iter()
+            .copied()
+            .find(|alignment| alignment.is_satisfied_by(self.as_ptr()))
+            .unwrap_or_else(Alignment::of::<T>)
+    }
+}
+
+/// The header starting a shared memory segment which holds a vector of LWE ciphertexts.
+///
+/// The header is `#[repr(C)]`, and only contains fixed-width fields stored in the native byte
+/// order, so that it can be written by any process of the machine, whatever its language:
+///
+/// | Offset | Size | Field                                                        |
+/// |--------|------|--------------------------------------------------------------|
+/// | 0      | 4    | The magic bytes `CCSS`.                                      |
+/// | 4      | 4    | The version of the layout, [`SHARED_SEGMENT_FORMAT_VERSION`]. |
+/// | 8      | 4    | The size of the elements, in bytes.                          |
+/// | 12     | 4    | The alignment of the elements, in bytes.                     |
+/// | 16     | 8    | The LWE dimension of the ciphertexts.                        |
+/// | 24     | 8    | The number of ciphertexts.                                   |
+///
+/// The elements start at the first multiple of the alignment following the header, and the
+/// segment itself must be aligned on this alignment.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SharedSegmentHeader {
+    magic: [u8; 4],
+    version: u32,
+    element_size: u32,
+    alignment: u32,
+    lwe_dimension: u64,
+    lwe_ciphertext_count: u64,
+}
+
+/// The version of the layout of the [`SharedSegmentHeader`].
+pub const SHARED_SEGMENT_FORMAT_VERSION: u32 = 1;
+
+/// The bytes every shared segment starts with.
+const SHARED_SEGMENT_MAGIC: [u8; 4] = *b"CCSS";
+
+impl SharedSegmentHeader {
+    /// Creates the header of a segment holding `lwe_ciphertext_count` ciphertexts of dimension
+    /// `lwe_dimension`, with `T` elements aligned on `alignment`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alignment is not valid for `T`, as checked by [`Alignment::is_valid_for`].
+    pub fn new<T: Numeric>(
+        lwe_dimension: LweDimension,
+        lwe_ciphertext_count: LweCiphertextCount,
+        alignment: Alignment,
+    ) -> Self {
+        assert!(
+            alignment.is_valid_for::<T>(),
+            "Invalid alignment {} for elements aligned on {} bytes.",
+            alignment.0,
+            align_of::<T>()
+        );
+        SharedSegmentHeader {
+            magic: SHARED_SEGMENT_MAGIC,
+            version: SHARED_SEGMENT_FORMAT_VERSION,
+            element_size: size_of::<T>() as u32,
+            alignment: alignment.0 as u32,
+            lwe_dimension: lwe_dimension.0 as u64,
+            lwe_ciphertext_count: lwe_ciphertext_count.0 as u64,
+        }
+    }
+
+    /// Returns the LWE dimension of the ciphertexts of the segment.
+    pub fn lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.lwe_dimension as usize)
+    }
+
+    /// Returns the number of ciphertexts of the segment.
+    pub fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.lwe_ciphertext_count as usize)
+    }
+
+    /// Returns the alignment of the elements of the segment.
+    pub fn alignment(&self) -> Alignment {
+        Alignment(self.alignment as usize)
+    }
+
+    /// Returns the offset of the first element, in bytes from the start of the segment.
+    ///
+    /// An error is returned if the alignment is zero, or if the offset overflows a `usize`.
+    pub fn data_offset(&self) -> Result<usize, SharedSliceError> {
+        if self.alignment == 0 {
+            return Err(SharedSliceError::InvalidAlignment);
+        }
+        let alignment = self.alignment as usize;
+        size_of::<SharedSegmentHeader>()
+            .div_ceil(alignment)
+            .checked_mul(alignment)
+            .ok_or(SharedSliceError::SizeOverflow)
+    }
+
+    /// Returns the number of elements of the segment.
+    ///
+    /// An error is returned if the number of elements overflows a `usize`.
+    pub fn element_count(&self) -> Result<usize, SharedSliceError> {
+        let lwe_size = usize::try_from(self.lwe_dimension)
+            .ok()
+            .and_then(|lwe_dimension| lwe_dimension.checked_add(1));
+        let count = usize::try_from(self.lwe_ciphertext_count).ok();
+        lwe_size
+            .zip(count)
+            .and_then(|(lwe_size, count)| lwe_size.checked_mul(count))
+            .ok_or(SharedSliceError::SizeOverflow)
+    }
+
+    /// Returns the total size of the segment, in bytes.
+    ///
+    /// An error is returned if the alignment is zero, or if the size overflows a `usize`.
+    pub fn segment_size(&self) -> Result<usize, SharedSliceError> {
+        let data_offset = self.data_offset()?;
+        self.element_count()?
+            .checked_mul(self.element_size as usize)
+            .and_then(|data_size| data_size.checked_add(data_offset))
+            .ok_or(SharedSliceError::SizeOverflow)
+    }
+
+    // Checks the header against the elements expected by the reader.
+    fn check<T: Numeric>(&self) -> Result<(), SharedSliceError> {
+        if self.magic != SHARED_SEGMENT_MAGIC || self.version != SHARED_SEGMENT_FORMAT_VERSION {
+            return Err(SharedSliceError::InvalidHeader);
+        }
+        if self.element_size as usize != size_of::<T>() {
+            return Err(SharedSliceError::ElementSizeMismatch);
+        }
+        if !self.alignment().is_valid_for::<T>() {
+            return Err(SharedSliceError::InvalidAlignment);
+        }
+        Ok(())
+    }
+}
+
+/// A shared memory segment holding a vector of LWE ciphertexts, read from its header.
+///
+/// # Example:
+///
+/// ```
+/// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+/// use concrete_core::backends::core::memory::{
+///     AlignedBuffer, Alignment, SharedSegment, SharedSegmentHeader,
+/// };
+/// use concrete_core::prelude::*;
+/// # use std::error::Error;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// // The segment would typically be mapped by two processes, the writer filling it in place.
+/// let header = SharedSegmentHeader::new::<u64>(
+///     LweDimension(15),
+///     LweCiphertextCount(4),
+///     Alignment::CACHE_LINE,
+/// );
+/// let mut segment = AlignedBuffer::<u8>::new(header.segment_size()?, Alignment::CACHE_LINE);
+/// unsafe { std::ptr::write(segment.as_mut_ptr() as *mut SharedSegmentHeader, header) };
+///
+/// // The reader only gets the pointer to the segment, and its length.
+/// let segment = unsafe { SharedSegment::<u64>::from_ptr(segment.as_ptr(), segment.len())? };
+/// let mut engine = CoreEngine::new()?;
+/// let ciphertext_vector: LweCiphertextVectorView64 =
+///     engine.create_lwe_ciphertext_vector(segment.slice(), segment.lwe_size())?;
+/// assert_eq!(ciphertext_vector.lwe_dimension(), LweDimension(15));
+/// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(4));
+///
+/// engine.destroy(ciphertext_vector)?;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SharedSegment<'a, T: Numeric> {
+    header: SharedSegmentHeader,
+    slice: SharedSlice<'a, T>,
+}
+
+impl<'a, T: Numeric> SharedSegment<'a, T> {
+    /// Reads the segment of `len` bytes starting at `ptr`.
+    ///
+    /// The header is checked against the `T` elements, the pointer must satisfy the alignment
+    /// it records, and the size it records must fit in the `len` bytes of the segment.
+    /// Otherwise, an error is returned.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be valid for reads of `len` bytes for the `'a` lifetime. The segment
+    /// must not be mutated during this lifetime, including by another process sharing it. These
+    /// properties can not be checked.
+    pub unsafe fn from_ptr(ptr: *const u8, len: usize) -> Result<Self, SharedSliceError> {
+        if ptr.is_null() {
+            return Err(SharedSliceError::NullPointer);
+        }
+        if !Alignment::of::<SharedSegmentHeader>().is_satisfied_by(ptr) {
+            return Err(SharedSliceError::MisalignedPointer);
+        }
+        if len < size_of::<SharedSegmentHeader>() {
+            return Err(SharedSliceError::SegmentTooShort);
+        }
+        let header = std::ptr::read(ptr as *const SharedSegmentHeader);
+        header.check::<T>()?;
+        if !header.alignment().is_satisfied_by(ptr) {
+            return Err(SharedSliceError::InvalidAlignment);
+        }
+        if header.segment_size()? > len {
+            return Err(SharedSliceError::SegmentTooShort);
+        }
+        let slice = SharedSlice::from_raw_parts(
+            ptr.add(header.data_offset()?) as *const T,
+            header.element_count()?,
+        )?;
+        Ok(SharedSegment { header, slice })
+    }
+
+    /// Returns the header of the segment.
+    pub fn header(&self) -> &SharedSegmentHeader {
+        &self.header
+    }
+
+    /// Returns the LWE size of the ciphertexts of the segment.
+    pub fn lwe_size(&self) -> LweSize {
+        self.header.lwe_dimension().to_lwe_size()
+    }
+
+    /// Returns a view over the elements of the segment.
+    pub fn slice(&self) -> SharedSlice<'a, T> {
+        self.slice
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_aligned_buffer_is_aligned() {
+        for alignment in [
+            Alignment::of::<u64>(),
+            Alignment::SIMD_256,
+            Alignment::CACHE_LINE,
+        ] {
+            for len in [0, 1, 7, 1024] {
+                let buffer = AlignedBuffer::<u64>::new(len, alignment);
+                assert!(alignment.is_satisfied_by(buffer.as_ptr()));
+                assert_eq!(buffer.len(), len);
+                assert!(buffer.iter().all(|v| *v == 0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_aligned_buffer_clone_keeps_alignment() {
+        let values: Vec<u32> = (0..100).collect();
+        let buffer = AlignedBuffer::from_slice(&values, Alignment::CACHE_LINE);
+        let clone = buffer.clone();
+        assert_eq!(clone, buffer);
+        assert_eq!(clone.alignment(), Alignment::CACHE_LINE);
+        assert!(Alignment::CACHE_LINE.is_satisfied_by(clone.as_ptr()));
+        assert_eq!(clone.as_slice(), values.as_slice());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_aligned_buffer_rejects_invalid_alignment() {
+        AlignedBuffer::<u64>::new(8, Alignment(24));
+    }
+
+    #[test]
+    fn test_shared_slice_views_foreign_memory() {
+        let buffer = AlignedBuffer::from_slice(&[1u64, 2, 3, 4], Alignment::CACHE_LINE);
+        let view = unsafe { SharedSlice::from_raw_parts(buffer.as_ptr(), buffer.len()) }.unwrap();
+        assert_eq!(view.as_slice(), buffer.as_slice());
+        assert_eq!(view.alignment(), Alignment::CACHE_LINE);
+        assert_eq!(view, SharedSlice::from_slice(buffer.as_slice()));
+    }
+
+    #[test]
+    fn test_shared_slice_rejects_invalid_raw_parts() {
+        let values = [0u32; 4];
+        assert_eq!(
+            unsafe { SharedSlice::<u32>::from_raw_parts(std::ptr::null(), 4) },
+            Err(SharedSliceError::NullPointer)
+        );
+        let misaligned = (values.as_ptr() as *const u8).wrapping_add(1) as *const u32;
+        assert_eq!(
+            unsafe { SharedSlice::from_raw_parts(misaligned, 2) },
+            Err(SharedSliceError::MisalignedPointer)
+        );
+        assert_eq!(
+            unsafe { SharedSlice::from_raw_parts(values.as_ptr(), usize::MAX / 2) },
+            Err(SharedSliceError::SizeOverflow)
+        );
+    }
+
+    #[test]
+    fn test_shared_segment_header_layout() {
+        assert_eq!(size_of::<SharedSegmentHeader>(), 32);
+        let header =
+            SharedSegmentHeader::new::<u32>(LweDimension(3), LweCiphertextCount(2), Alignment(4));
+        assert_eq!(header.data_offset(), Ok(32));
+        assert_eq!(header.segment_size(), Ok(32 + 8 * 4));
+        let bytes: [u8; 32] = unsafe { std::mem::transmute(header) };
+        assert_eq!(&bytes[0..4], b"CCSS");
+        assert_eq!(bytes[4..8], SHARED_SEGMENT_FORMAT_VERSION.to_ne_bytes());
+        assert_eq!(bytes[8..12], 4u32.to_ne_bytes());
+        assert_eq!(bytes[12..16], 4u32.to_ne_bytes());
+        assert_eq!(bytes[16..24], 3u64.to_ne_bytes());
+        assert_eq!(bytes[24..32], 2u64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_shared_segment_reads_its_header() {
+        let header = SharedSegmentHeader::new::<u64>(
+            LweDimension(7),
+            LweCiphertextCount(3),
+            Alignment::CACHE_LINE,
+        );
+        let mut segment =
+            AlignedBuffer::<u8>::new(header.segment_size().unwrap(), Alignment::CACHE_LINE);
+        unsafe {
+            std::ptr::write(segment.as_mut_ptr() as *mut SharedSegmentHeader, header);
+            let data = segment.as_mut_ptr().add(header.data_offset().unwrap()) as *mut u64;
+            for i in 0..header.element_count().unwrap() {
+                *data.add(i) = i as u64;
+            }
+        }
+        let read =
+            unsafe { SharedSegment::<u64>::from_ptr(segment.as_ptr(), segment.len()) }.unwrap();
+        assert_eq!(*read.header(), header);
+        assert_eq!(read.lwe_size(), LweSize(8));
+        assert_eq!(read.slice().alignment(), Alignment::CACHE_LINE);
+        assert!(read.slice().iter().copied().eq(0..24));
+
+        // The elements of another size are rejected.
+        assert_eq!(
+            unsafe { SharedSegment::<u32>::from_ptr(segment.as_ptr(), segment.len()) }.err(),
+            Some(SharedSliceError::ElementSizeMismatch)
+        );
+        // A corrupted header is rejected.
+        unsafe { *segment.as_mut_ptr() = b'X' };
+        assert_eq!(
+            unsafe { SharedSegment::<u64>::from_ptr(segment.as_ptr(), segment.len()) }.err(),
+            Some(SharedSliceError::InvalidHeader)
+        );
+    }
+
+    // Writes `header` at the start of a cache line aligned segment of `len` bytes.
+    fn write_segment(header: SharedSegmentHeader, len: usize) -> AlignedBuffer<u8> {
+        let mut segment = AlignedBuffer::<u8>::new(len, Alignment::CACHE_LINE);
+        unsafe { std::ptr::write(segment.as_mut_ptr() as *mut SharedSegmentHeader, header) };
+        segment
+    }
+
+    #[test]
+    fn test_shared_segment_rejects_overflowing_sizes() {
+        let valid =
+            SharedSegmentHeader::new::<u64>(LweDimension(7), LweCiphertextCount(3), Alignment(8));
+        for header in [
+            SharedSegmentHeader {
+                lwe_dimension: u64::MAX,
+                ..valid
+            },
+            SharedSegmentHeader {
+                lwe_dimension: u64::MAX / 2,
+                lwe_ciphertext_count: 4,
+                ..valid
+            },
+            SharedSegmentHeader {
+                lwe_ciphertext_count: u64::MAX,
+                ..valid
+            },
+            SharedSegmentHeader {
+                lwe_dimension: (usize::MAX / 8) as u64,
+                lwe_ciphertext_count: 1,
+                ..valid
+            },
+        ] {
+            assert_eq!(header.segment_size(), Err(SharedSliceError::SizeOverflow));
+            let segment = write_segment(header, 1024);
+            assert_eq!(
+                unsafe { SharedSegment::<u64>::from_ptr(segment.as_ptr(), segment.len()) }.err(),
+                Some(SharedSliceError::SizeOverflow)
+            );
+        }
+    }
+
+    #[test]
+    fn test_shared_segment_rejects_zero_alignment() {
+        let header = SharedSegmentHeader {
+            alignment: 0,
+            ..SharedSegmentHeader::new::<u64>(LweDimension(7), LweCiphertextCount(3), Alignment(8))
+        };
+        assert_eq!(
+            header.data_offset(),
+            Err(SharedSliceError::InvalidAlignment)
+        );
+        assert_eq!(
+            header.segment_size(),
+            Err(SharedSliceError::InvalidAlignment)
+        );
+        let segment = write_segment(header, 1024);
+        assert_eq!(
+            unsafe { SharedSegment::<u64>::from_ptr(segment.as_ptr(), segment.len()) }.err(),
+            Some(SharedSliceError::InvalidAlignment)
+        );
+    }
+
+    #[test]
+    fn test_shared_segment_rejects_short_segments() {
+        let header = SharedSegmentHeader::new::<u64>(
+            LweDimension(7),
+            LweCiphertextCount(3),
+            Alignment::CACHE_LINE,
+        );
+        let size = header.segment_size().unwrap();
+        let segment = write_segment(header, size);
+        // The header claims more bytes than the segment holds.
+        assert_eq!(
+            unsafe { SharedSegment::<u64>::from_ptr(segment.as_ptr(), size - 1) }.err(),
+            Some(SharedSliceError::SegmentTooShort)
+        );
+        // The segment can not even hold the header.
+        assert_eq!(
+            unsafe { SharedSegment::<u64>::from_ptr(segment.as_ptr(), 16) }.err(),
+            Some(SharedSliceError::SegmentTooShort)
+        );
+        assert!(unsafe { SharedSegment::<u64>::from_ptr(segment.as_ptr(), size) }.is_ok());
+    }
+}
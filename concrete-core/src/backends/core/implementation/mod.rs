@@ -1,2 +1,17 @@
+#[cfg(feature = "async")]
+pub mod asynchronous;
+#[cfg(feature = "audit")]
+pub mod audit;
 pub mod engines;
 pub mod entities;
+#[cfg(feature = "serde_serialize")]
+pub mod envelope;
+pub mod handshake;
+#[cfg(feature = "key_backup")]
+pub mod key_backup;
+#[cfg(feature = "noise_estimation")]
+pub mod linear_combination;
+pub mod memory;
+pub mod pipeline;
+pub mod progress;
+pub mod serialization;
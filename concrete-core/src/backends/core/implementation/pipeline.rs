@@ -0,0 +1,760 @@
+//! A module containing an executor for small graphs of operations on LWE ciphertexts.
+//!
+//! The engines exposed by the core backend execute a single operation per call, which forces the
+//! caller to allocate and traverse an intermediate ciphertext for every operation. The
+//! [`LwePipeline`] structure instead records a small directed acyclic graph of operations, and
+//! executes it as a whole:
+//!
+//! + Chains of linear operations (additions, subtractions, multiplications by cleartexts and
+//!   additions of plaintexts) are fused into a single multi-sum, computed in one pass over the
+//!   ciphertexts, without materializing the intermediate results.
+//! + The buffers holding intermediate results are recycled as soon as they are no longer needed.
+//! + The keyswitches and bootstraps fall back to the per-operation engine calls. The bootstraps
+//!   of a pipeline all share the Fourier buffers cached in the [`CoreEngine`].
+//!
+//! # Example:
+//!
+//! ```
+//! use concrete_commons::dispersion::Variance;
+//! use concrete_commons::parameters::{
+//!     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+//! };
+//! use concrete_core::backends::core::pipeline::LwePipeline;
+//! use concrete_core::prelude::*;
+//! # use std::error::Error;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+//! let (lwe_dimension, glwe_dimension, polynomial_size) =
+//!     (LweDimension(4), GlweDimension(1), PolynomialSize(512));
+//! let (base_log, level) = (DecompositionBaseLog(3), DecompositionLevelCount(5));
+//! let noise = Variance(2_f64.powf(-50.));
+//!
+//! let mut engine = CoreEngine::new()?;
+//! let lwe_key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+//! let glwe_key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+//! let big_lwe_key = engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_key.clone())?;
+//! let ksk: LweKeyswitchKey64 =
+//!     engine.create_lwe_keyswitch_key(&big_lwe_key, &lwe_key, level, base_log, noise)?;
+//! let bsk: LweBootstrapKey64 =
+//!     engine.create_lwe_bootstrap_key(&lwe_key, &glwe_key, base_log, level, noise)?;
+//! let bsk: FourierLweBootstrapKey64 = engine.convert_lwe_bootstrap_key(&bsk)?;
+//! let accumulator_plaintexts = engine.create_plaintext_vector(&vec![1_u64 << 60; 512])?;
+//! let accumulator = engine
+//!     .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &accumulator_plaintexts)?;
+//!
+//! // We compute a bootstrap of `2 * (x + y) + 1`, after a keyswitch.
+//! let mut pipeline = LwePipeline::<LweCiphertext64>::new();
+//! let x = pipeline.input();
+//! let y = pipeline.input();
+//! let sum = pipeline.add(x, y);
+//! let doubled = pipeline.mul_cleartext(sum, 2);
+//! let shifted = pipeline.add_plaintext(doubled, 1 << 59);
+//! let switched = pipeline.keyswitch(shifted, &ksk);
+//! let bootstrapped = pipeline.bootstrap(switched, &bsk, &accumulator);
+//! pipeline.output(bootstrapped);
+//! // The three linear operations are fused in a single step.
+//! assert_eq!(pipeline.step_count(), 3);
+//!
+//! let plaintext = engine.create_plaintext(&(1_u64 << 58))?;
+//! let x = engine.encrypt_lwe_ciphertext(&big_lwe_key, &plaintext, noise)?;
+//! let y = engine.encrypt_lwe_ciphertext(&big_lwe_key, &plaintext, noise)?;
+//! let outputs = pipeline.execute(&mut engine, &[&x, &y])?;
+//! #
+//! assert_eq!(outputs.len(), 1);
+//! assert_eq!(outputs[0].lwe_dimension(), big_lwe_key.lwe_dimension());
+//!
+//! engine.destroy(x)?;
+//! engine.destroy(y)?;
+//! for output in outputs {
+//!     engine.destroy(output)?;
+//! }
+//! engine.destroy(plaintext)?;
+//! engine.destroy(accumulator)?;
+//! engine.destroy(accumulator_plaintexts)?;
+//! engine.destroy(bsk)?;
+//! engine.destroy(ksk)?;
+//! engine.destroy(big_lwe_key)?;
+//! engine.destroy(glwe_key)?;
+//! engine.destroy(lwe_key)?;
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FourierLweBootstrapKey32, FourierLweBootstrapKey64, GlweCiphertext32, GlweCiphertext64,
+    LweCiphertext32, LweCiphertext64, LweKeyswitchKey32, LweKeyswitchKey64,
+};
+use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::specification::engines::{
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingKeyswitchEngine,
+};
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity, LweKeyswitchKeyEntity,
+};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::LweDimension;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+pub(crate) mod sealed {
+    use super::*;
+
+    pub trait PipelineCiphertextSeal: Sized {
+        fn allocate(lwe_dimension: LweDimension) -> Self;
+        fn as_raw(&self) -> &[<Self as PipelineCiphertext>::Raw]
+        where
+            Self: PipelineCiphertext;
+        fn as_mut_raw(&mut self) -> &mut [<Self as PipelineCiphertext>::Raw]
+        where
+            Self: PipelineCiphertext;
+        unsafe fn keyswitch(
+            engine: &mut CoreEngine,
+            output: &mut Self,
+            input: &Self,
+            key: &<Self as PipelineCiphertext>::KeyswitchKey,
+        ) where
+            Self: PipelineCiphertext;
+        unsafe fn bootstrap(
+            engine: &mut CoreEngine,
+            output: &mut Self,
+            input: &Self,
+            key: &<Self as PipelineCiphertext>::BootstrapKey,
+            accumulator: &<Self as PipelineCiphertext>::Accumulator,
+        ) where
+            Self: PipelineCiphertext;
+    }
+}
+
+/// A trait implemented by the LWE ciphertexts which can be processed by an [`LwePipeline`].
+pub trait PipelineCiphertext: sealed::PipelineCiphertextSeal + LweCiphertextEntity + Clone {
+    /// The type of the integers the ciphertexts are made of.
+    type Raw: UnsignedTorus;
+    /// The type of the keyswitch keys used in the pipeline.
+    type KeyswitchKey: LweKeyswitchKeyEntity<
+        InputKeyDistribution = Self::KeyDistribution,
+        OutputKeyDistribution = Self::KeyDistribution,
+    >;
+    /// The type of the bootstrap keys used in the pipeline.
+    type BootstrapKey: LweBootstrapKeyEntity<
+        InputKeyDistribution = Self::KeyDistribution,
+        OutputKeyDistribution = Self::KeyDistribution,
+    >;
+    /// The type of the accumulators used in the bootstraps of the pipeline.
+    type Accumulator: GlweCiphertextEntity<KeyDistribution = Self::KeyDistribution>;
+}
+
+/// An identifier of a node of an [`LwePipeline`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PipelineNode(usize);
+
+/// The error which can occur when executing an [`LwePipeline`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PipelineError {
+    /// The number of input ciphertexts does not match the number of inputs of the pipeline.
+    InputCountMismatch,
+    /// The LWE dimensions of the operands of an operation do not match.
+    LweDimensionMismatch,
+}
+
+impl Display for PipelineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PipelineError::InputCountMismatch => write!(
+                f,
+                "The number of input ciphertexts must match the number of pipeline inputs."
+            ),
+            PipelineError::LweDimensionMismatch => write!(
+                f,
+                "The LWE dimensions of the operands of an operation must match."
+            ),
+        }
+    }
+}
+
+impl Error for PipelineError {}
+
+enum Operation<'k, Ciphertext>
+where
+    Ciphertext: PipelineCiphertext,
+{
+    Input(usize),
+    Linear {
+        terms: Vec<(PipelineNode, Ciphertext::Raw)>,
+        bias: Ciphertext::Raw,
+    },
+    Keyswitch {
+        input: PipelineNode,
+        key: &'k Ciphertext::KeyswitchKey,
+    },
+    Bootstrap {
+        input: PipelineNode,
+        key: &'k Ciphertext::BootstrapKey,
+        accumulator: &'k Ciphertext::Accumulator,
+    },
+}
+
+// A step of the execution plan, producing the value of a node.
+struct Step<Raw> {
+    node: usize,
+    // The terms of a fused multi-sum, expressed over materialized nodes only.
+    terms: Vec<(usize, Raw)>,
+    bias: Raw,
+}
+
+/// A graph of operations on LWE ciphertexts, executed with operation fusion.
+///
+/// Nodes are created by calling the operation methods on previously created nodes, which makes
+/// the graph acyclic by construction. See the [module documentation](self) for an example.
+///
+/// # Panics
+///
+/// The operation methods panic if they are given a node created by another pipeline.
+pub struct LwePipeline<'k, Ciphertext>
+where
+    Ciphertext: PipelineCiphertext,
+{
+    operations: Vec<Operation<'k, Ciphertext>>,
+    input_count: usize,
+    outputs: Vec<PipelineNode>,
+}
+
+impl<'k, Ciphertext> Default for LwePipeline<'k, Ciphertext>
+where
+    Ciphertext: PipelineCiphertext,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'k, Ciphertext> LwePipeline<'k, Ciphertext>
+where
+    Ciphertext: PipelineCiphertext,
+{
+    /// Creates an empty pipeline.
+    pub fn new() -> Self {
+        LwePipeline {
+            operations: Vec::new(),
+            input_count: 0,
+            outputs: Vec::new(),
+        }
+    }
+
+    /// Adds an input to the pipeline. Inputs are given to [`execute`](Self::execute) in the order
+    /// in which they were created.
+    pub fn input(&mut self) -> PipelineNode {
+        self.input_count += 1;
+        self.push(Operation::Input(self.input_count - 1))
+    }
+
+    /// Adds the sum of two nodes to the pipeline.
+    pub fn add(&mut self, lhs: PipelineNode, rhs: PipelineNode) -> PipelineNode {
+        self.linear(vec![
+            (lhs, Ciphertext::Raw::ONE),
+            (rhs, Ciphertext::Raw::ONE),
+        ])
+    }
+
+    /// Adds the difference of two nodes to the pipeline.
+    pub fn sub(&mut self, lhs: PipelineNode, rhs: PipelineNode) -> PipelineNode {
+        self.linear(vec![
+            (lhs, Ciphertext::Raw::ONE),
+            (rhs, Ciphertext::Raw::ONE.wrapping_neg()),
+        ])
+    }
+
+    /// Adds the opposite of a node to the pipeline.
+    pub fn opposite(&mut self, input: PipelineNode) -> PipelineNode {
+        self.linear(vec![(input, Ciphertext::Raw::ONE.wrapping_neg())])
+    }
+
+    /// Adds the product of a node by a cleartext to the pipeline.
+    pub fn mul_cleartext(
+        &mut self,
+        input: PipelineNode,
+        cleartext: Ciphertext::Raw,
+    ) -> PipelineNode {
+        self.linear(vec![(input, cleartext)])
+    }
+
+    /// Adds the sum of a node and a plaintext to the pipeline.
+    pub fn add_plaintext(
+        &mut self,
+        input: PipelineNode,
+        plaintext: Ciphertext::Raw,
+    ) -> PipelineNode {
+        self.check_node(input);
+        self.push(Operation::Linear {
+            terms: vec![(input, Ciphertext::Raw::ONE)],
+            bias: plaintext,
+        })
+    }
+
+    /// Adds the keyswitch of a node to the pipeline.
+    pub fn keyswitch(
+        &mut self,
+        input: PipelineNode,
+        key: &'k Ciphertext::KeyswitchKey,
+    ) -> PipelineNode {
+        self.check_node(input);
+        self.push(Operation::Keyswitch { input, key })
+    }
+
+    /// Adds the bootstrap of a node to the pipeline.
+    pub fn bootstrap(
+        &mut self,
+        input: PipelineNode,
+        key: &'k Ciphertext::BootstrapKey,
+        accumulator: &'k Ciphertext::Accumulator,
+    ) -> PipelineNode {
+        self.check_node(input);
+        self.push(Operation::Bootstrap {
+            input,
+            key,
+            accumulator,
+        })
+    }
+
+    /// Marks a node as an output of the pipeline. Outputs are returned by
+    /// [`execute`](Self::execute) in the order in which they were marked.
+    pub fn output(&mut self, node: PipelineNode) {
+        self.check_node(node);
+        self.outputs.push(node);
+    }
+
+    /// Returns the number of operations of the pipeline, inputs excluded.
+    pub fn operation_count(&self) -> usize {
+        self.operations.len() - self.input_count
+    }
+
+    /// Returns the number of steps executed by the pipeline, once the linear operations are fused.
+    pub fn step_count(&self) -> usize {
+        self.plan().len()
+    }
+
+    /// Executes the pipeline on the `inputs` ciphertexts, and returns the output ciphertexts.
+    pub fn execute(
+        &self,
+        engine: &mut CoreEngine,
+        inputs: &[&Ciphertext],
+    ) -> Result<Vec<Ciphertext>, PipelineError> {
+        if inputs.len() != self.input_count {
+            return Err(PipelineError::InputCountMismatch);
+        }
+        let dimensions = self.dimensions(inputs)?;
+        let plan = self.plan();
+
+        // We compute the last step using each node, to recycle the buffers as soon as possible.
+        let mut last_use = vec![None; self.operations.len()];
+        for (index, step) in plan.iter().enumerate() {
+            for (node, _) in step.terms.iter() {
+                last_use[*node] = Some(index);
+            }
+        }
+        for output in self.outputs.iter() {
+            last_use[output.0] = None;
+        }
+
+        let mut values: Vec<Option<Ciphertext>> =
+            (0..self.operations.len()).map(|_| None).collect();
+        let mut pool: Vec<Ciphertext> = Vec::new();
+        for (index, step) in plan.iter().enumerate() {
+            let dimension = dimensions[step.node];
+            let mut output = match pool.iter().position(|c| c.lwe_dimension() == dimension) {
+                Some(position) => pool.swap_remove(position),
+                None => Ciphertext::allocate(dimension),
+            };
+            let value = |node: usize| -> &Ciphertext {
+                match &self.operations[node] {
+                    Operation::Input(input) => inputs[*input],
+                    _ => values[node].as_ref().unwrap(),
+                }
+            };
+            match &self.operations[step.node] {
+                Operation::Linear { .. } => {
+                    let terms: Vec<(&[Ciphertext::Raw], Ciphertext::Raw)> = step
+                        .terms
+                        .iter()
+                        .map(|(node, weight)| (value(*node).as_raw(), *weight))
+                        .collect();
+                    fill_with_multisum(output.as_mut_raw(), terms.as_slice(), step.bias);
+                }
+                Operation::Keyswitch { input, key } => unsafe {
+                    Ciphertext::keyswitch(engine, &mut output, value(input.0), key);
+                },
+                Operation::Bootstrap {
+                    input,
+                    key,
+                    accumulator,
+                } => unsafe {
+                    Ciphertext::bootstrap(engine, &mut output, value(input.0), key, accumulator);
+                },
+                Operation::Input(_) => unreachable!(),
+            }
+            values[step.node] = Some(output);
+            for (node, _) in step.terms.iter() {
+                if last_use[*node] == Some(index) {
+                    if let Some(buffer) = values[*node].take() {
+                        pool.push(buffer);
+                    }
+                }
+            }
+        }
+
+        Ok(self
+            .outputs
+            .iter()
+            .map(|output| match &self.operations[output.0] {
+                Operation::Input(input) => inputs[*input].clone(),
+                _ => values[output.0].clone().unwrap(),
+            })
+            .collect())
+    }
+
+    fn push(&mut self, operation: Operation<'k, Ciphertext>) -> PipelineNode {
+        self.operations.push(operation);
+        PipelineNode(self.operations.len() - 1)
+    }
+
+    fn linear(&mut self, terms: Vec<(PipelineNode, Ciphertext::Raw)>) -> PipelineNode {
+        for (node, _) in terms.iter() {
+            self.check_node(*node);
+        }
+        self.push(Operation::Linear {
+            terms,
+            bias: Ciphertext::Raw::ZERO,
+        })
+    }
+
+    fn check_node(&self, node: PipelineNode) {
+        assert!(
+            node.0 < self.operations.len(),
+            "The node does not belong to this pipeline."
+        );
+    }
+
+    // Computes the LWE dimension of every node, and checks the consistency of the operands.
+    fn dimensions(&self, inputs: &[&Ciphertext]) -> Result<Vec<LweDimension>, PipelineError> {
+        let mut dimensions: Vec<LweDimension> = Vec::with_capacity(self.operations.len());
+        for operation in self.operations.iter() {
+            let dimension = match operation {
+                Operation::Input(input) => inputs[*input].lwe_dimension(),
+                Operation::Linear { terms, .. } => {
+                    let dimension = dimensions[terms[0].0 .0];
+                    if terms
+                        .iter()
+                        .any(|(node, _)| dimensions[node.0] != dimension)
+                    {
+                        return Err(PipelineError::LweDimensionMismatch);
+                    }
+                    dimension
+                }
+                Operation::Keyswitch { input, key } => {
+                    if dimensions[input.0] != key.input_lwe_dimension() {
+                        return Err(PipelineError::LweDimensionMismatch);
+                    }
+                    key.output_lwe_dimension()
+                }
+                Operation::Bootstrap {
+                    input,
+                    key,
+                    accumulator,
+                } => {
+                    if dimensions[input.0] != key.input_lwe_dimension()
+                        || accumulator.glwe_dimension() != key.glwe_dimension()
+                        || accumulator.polynomial_size() != key.polynomial_size()
+                    {
+                        return Err(PipelineError::LweDimensionMismatch);
+                    }
+                    key.output_lwe_dimension()
+                }
+            };
+            dimensions.push(dimension);
+        }
+        Ok(dimensions)
+    }
+
+    // Computes the execution plan. A linear node is materialized when it is an output, when it is
+    // the input of a keyswitch or a bootstrap, or when it is used more than once. The other
+    // linear nodes are fused into the node using them.
+    fn plan(&self) -> Vec<Step<Ciphertext::Raw>> {
+        let mut uses = vec![0_usize; self.operations.len()];
+        let mut materialized: Vec<bool> = self
+            .operations
+            .iter()
+            .map(|operation| !matches!(operation, Operation::Linear { .. }))
+            .collect();
+        for operation in self.operations.iter() {
+            match operation {
+                Operation::Input(_) => {}
+                Operation::Linear { terms, .. } => {
+                    for (node, _) in terms.iter() {
+                        uses[node.0] += 1;
+                    }
+                }
+                Operation::Keyswitch { input, .. } | Operation::Bootstrap { input, .. } => {
+                    uses[input.0] += 1;
+                    materialized[input.0] = true;
+                }
+            }
+        }
+        for output in self.outputs.iter() {
+            materialized[output.0] = true;
+        }
+        for (node, count) in uses.iter().enumerate() {
+            if *count > 1 {
+                materialized[node] = true;
+            }
+        }
+
+        let needed = self.needed_nodes();
+        let mut plan = Vec::new();
+        for (node, operation) in self.operations.iter().enumerate() {
+            if !materialized[node] || !needed[node] {
+                continue;
+            }
+            match operation {
+                Operation::Input(_) => {}
+                Operation::Linear { .. } => {
+                    let mut terms = Vec::new();
+                    let mut bias = Ciphertext::Raw::ZERO;
+                    self.expand(
+                        node,
+                        Ciphertext::Raw::ONE,
+                        &materialized,
+                        &mut terms,
+                        &mut bias,
+                    );
+                    plan.push(Step { node, terms, bias });
+                }
+                Operation::Keyswitch { input, .. } | Operation::Bootstrap { input, .. } => {
+                    plan.push(Step {
+                        node,
+                        terms: vec![(input.0, Ciphertext::Raw::ONE)],
+                        bias: Ciphertext::Raw::ZERO,
+                    });
+                }
+            }
+        }
+        plan
+    }
+
+    // Expands the linear node `node`, multiplied by `weight`, in terms of materialized nodes.
+    fn expand(
+        &self,
+        node: usize,
+        weight: Ciphertext::Raw,
+        materialized: &[bool],
+        terms: &mut Vec<(usize, Ciphertext::Raw)>,
+        bias: &mut Ciphertext::Raw,
+    ) {
+        if let Operation::Linear {
+            terms: node_terms,
+            bias: node_bias,
+        } = &self.operations[node]
+        {
+            *bias = bias.wrapping_add(weight.wrapping_mul(*node_bias));
+            for (term, term_weight) in node_terms.iter() {
+                let term_weight = weight.wrapping_mul(*term_weight);
+                if materialized[term.0] {
+                    match terms.iter_mut().find(|(existing, _)| *existing == term.0) {
+                        Some((_, existing_weight)) => {
+                            *existing_weight = existing_weight.wrapping_add(term_weight)
+                        }
+                        None => terms.push((term.0, term_weight)),
+                    }
+                } else {
+                    self.expand(term.0, term_weight, materialized, terms, bias);
+                }
+            }
+        }
+    }
+
+    // Returns the nodes whose value is needed to compute the outputs.
+    fn needed_nodes(&self) -> Vec<bool> {
+        let mut needed = vec![false; self.operations.len()];
+        for output in self.outputs.iter() {
+            needed[output.0] = true;
+        }
+        for node in (0..self.operations.len()).rev() {
+            if !needed[node] {
+                continue;
+            }
+            match &self.operations[node] {
+                Operation::Input(_) => {}
+                Operation::Linear { terms, .. } => {
+                    for (term, _) in terms.iter() {
+                        needed[term.0] = true;
+                    }
+                }
+                Operation::Keyswitch { input, .. } | Operation::Bootstrap { input, .. } => {
+                    needed[input.0] = true;
+                }
+            }
+        }
+        needed
+    }
+}
+
+// Fills `output` with the weighted sum of the `terms`, and adds `bias` to its body. Each term is
+// accumulated with the vectorized kernels.
+pub(crate) fn fill_with_multisum<Raw: UnsignedTorus>(
+    output: &mut [Raw],
+    terms: &[(&[Raw], Raw)],
+    bias: Raw,
+) {
+    output.fill(Raw::ZERO);
+    for (term, weight) in terms.iter() {
+        Raw::wrapping_add_scaled_assign(output, term, *weight);
+    }
+    if let Some(body) = output.last_mut() {
+        *body = body.wrapping_add(bias);
+    }
+}
+
+macro_rules! implement_pipeline_ciphertext {
+    ($Ciphertext: ident, $Raw: ty, $KeyswitchKey: ident, $BootstrapKey: ident, $Accumulator: ident) => {
+        impl sealed::PipelineCiphertextSeal for $Ciphertext {
+            fn allocate(lwe_dimension: LweDimension) -> Self {
+                $Ciphertext(ImplLweCiphertext::allocate(
+                    0 as $Raw,
+                    lwe_dimension.to_lwe_size(),
+                ))
+            }
+
+            fn as_raw(&self) -> &[$Raw] {
+                self.0.as_tensor().as_container().as_slice()
+            }
+
+            fn as_mut_raw(&mut self) -> &mut [$Raw] {
+                self.0.as_mut_tensor().as_mut_container().as_mut_slice()
+            }
+
+            unsafe fn keyswitch(
+                engine: &mut CoreEngine,
+                output: &mut Self,
+                input: &Self,
+                key: &$KeyswitchKey,
+            ) {
+                engine.discard_keyswitch_lwe_ciphertext_unchecked(output, input, key);
+            }
+
+            unsafe fn bootstrap(
+                engine: &mut CoreEngine,
+                output: &mut Self,
+                input: &Self,
+                key: &$BootstrapKey,
+                accumulator: &$Accumulator,
+            ) {
+                engine.discard_bootstrap_lwe_ciphertext_unchecked(output, input, accumulator, key);
+            }
+        }
+
+        impl PipelineCiphertext for $Ciphertext {
+            type Raw = $Raw;
+            type KeyswitchKey = $KeyswitchKey;
+            type BootstrapKey = $BootstrapKey;
+            type Accumulator = $Accumulator;
+        }
+    };
+}
+
+implement_pipeline_ciphertext!(
+    LweCiphertext32,
+    u32,
+    LweKeyswitchKey32,
+    FourierLweBootstrapKey32,
+    GlweCiphertext32
+);
+implement_pipeline_ciphertext!(
+    LweCiphertext64,
+    u64,
+    LweKeyswitchKey64,
+    FourierLweBootstrapKey64,
+    GlweCiphertext64
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::specification::engines::AbstractEngine;
+
+    fn ciphertext(coefficients: Vec<u64>) -> LweCiphertext64 {
+        LweCiphertext64(ImplLweCiphertext::from_container(coefficients))
+    }
+
+    #[test]
+    fn test_linear_chain_is_fused() {
+        let mut pipeline = LwePipeline::<LweCiphertext64>::new();
+        let x = pipeline.input();
+        let y = pipeline.input();
+        let sum = pipeline.add(x, y);
+        let difference = pipeline.sub(sum, y);
+        let scaled = pipeline.mul_cleartext(difference, 3);
+        let shifted = pipeline.add_plaintext(scaled, 10);
+        pipeline.output(shifted);
+        assert_eq!(pipeline.operation_count(), 4);
+        assert_eq!(pipeline.step_count(), 1);
+
+        let mut engine = CoreEngine::new().unwrap();
+        let x = ciphertext(vec![1, 2, 3]);
+        let y = ciphertext(vec![4, 5, 6]);
+        let outputs = pipeline.execute(&mut engine, &[&x, &y]).unwrap();
+        assert_eq!(outputs[0], ciphertext(vec![3, 6, 19]));
+    }
+
+    #[test]
+    fn test_shared_nodes_are_materialized() {
+        let mut pipeline = LwePipeline::<LweCiphertext64>::new();
+        let x = pipeline.input();
+        let doubled = pipeline.add(x, x);
+        let opposite = pipeline.opposite(doubled);
+        let shared = pipeline.add_plaintext(doubled, 1);
+        let first = pipeline.add(shared, opposite);
+        let second = pipeline.mul_cleartext(shared, 2);
+        pipeline.output(first);
+        pipeline.output(second);
+        // The `shared` node is used twice, and is computed once.
+        assert_eq!(pipeline.step_count(), 4);
+
+        let mut engine = CoreEngine::new().unwrap();
+        let x = ciphertext(vec![1, 2]);
+        let outputs = pipeline.execute(&mut engine, &[&x]).unwrap();
+        assert_eq!(outputs[0], ciphertext(vec![0, 1]));
+        assert_eq!(outputs[1], ciphertext(vec![4, 10]));
+    }
+
+    #[test]
+    fn test_unused_nodes_are_skipped() {
+        let mut pipeline = LwePipeline::<LweCiphertext64>::new();
+        let x = pipeline.input();
+        let _ = pipeline.mul_cleartext(x, 2);
+        pipeline.output(x);
+        assert_eq!(pipeline.step_count(), 0);
+    }
+
+    #[test]
+    fn test_execute_checks_inputs() {
+        let mut pipeline = LwePipeline::<LweCiphertext64>::new();
+        let x = pipeline.input();
+        let y = pipeline.input();
+        let sum = pipeline.add(x, y);
+        pipeline.output(sum);
+
+        let mut engine = CoreEngine::new().unwrap();
+        let x = ciphertext(vec![1, 2, 3]);
+        let y = ciphertext(vec![4, 5]);
+        assert_eq!(
+            pipeline.execute(&mut engine, &[&x]).unwrap_err(),
+            PipelineError::InputCountMismatch
+        );
+        assert_eq!(
+            pipeline.execute(&mut engine, &[&x, &y]).unwrap_err(),
+            PipelineError::LweDimensionMismatch
+        );
+    }
+}
@@ -0,0 +1,198 @@
+//! A module containing the monitoring of the key generation of the core backend.
+//!
+//! Generating bootstrap keys and keyswitch keys for large parameters can take minutes. A
+//! [`KeyGenerationMonitor`] can be attached to a [`CoreEngine`](super::engines::CoreEngine) to
+//! receive a [`KeyGenerationProgress`] report every time a GGSW ciphertext of a bootstrap key, the
+//! encryptions of a coefficient of the input key of a keyswitch key, or the keyswitching key of an
+//! automorphism of an automorphism key, are generated.
+//!
+//! The monitor may also hold a [`CancellationToken`], which can be shared with an other thread.
+//! When the token gets cancelled, the generation stops after the current GGSW ciphertext (or key
+//! coefficient, or automorphism), and the key creation engines return a
+//! [`CoreError::Cancelled`](super::engines::CoreError::Cancelled) error. The `unchecked`
+//! variants of the key creation engines stop as well, but return the partially generated key: it
+//! is the responsibility of the caller to check the token before using it.
+//!
+//! # Example:
+//!
+//! ```
+//! use concrete_commons::dispersion::Variance;
+//! use concrete_commons::parameters::{
+//!     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+//! };
+//! use concrete_core::backends::core::progress::{CancellationToken, KeyGenerationMonitor};
+//! use concrete_core::prelude::*;
+//! use std::sync::{Arc, Mutex};
+//! # use std::error::Error;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+//! let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+//! let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+//! let noise = Variance(2_f64.powf(-25.));
+//!
+//! let mut engine = CoreEngine::new()?;
+//! let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+//! let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+//!
+//! let reports = Arc::new(Mutex::new(Vec::new()));
+//! let reports_handle = reports.clone();
+//! let token = CancellationToken::new();
+//! engine.set_key_generation_monitor(
+//!     KeyGenerationMonitor::new()
+//!         .with_progress(move |progress| reports_handle.lock().unwrap().push(progress.generated))
+//!         .with_cancellation(token.clone()),
+//! );
+//!
+//! let bsk: LweBootstrapKey64 =
+//!     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+//! assert_eq!(*reports.lock().unwrap(), vec![1, 2, 3, 4]);
+//!
+//! // Once the token is cancelled, the key generation is aborted.
+//! token.cancel();
+//! let result: Result<LweBootstrapKey64, _> =
+//!     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise);
+//! assert_eq!(
+//!     result.unwrap_err().to_string(),
+//!     "Error occurred in the engine: The key generation was cancelled."
+//! );
+//!
+//! engine.clear_key_generation_monitor();
+//! engine.destroy(lwe_sk)?;
+//! engine.destroy(glwe_sk)?;
+//! engine.destroy(bsk)?;
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::{Debug, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// The progress of a key generation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyGenerationProgress {
+    /// The number of units (GGSW ciphertexts, or input key coefficients) generated so far.
+    pub generated: usize,
+    /// The total number of units to generate.
+    pub total: usize,
+}
+
+/// A token allowing to cooperatively cancel a key generation.
+///
+/// Clones of a token share the same state, so that a token can be cancelled from an other thread
+/// than the one generating the key.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Creates a new token, which is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the token, and all its clones.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns whether the token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// A monitor attached to an engine, to follow and cancel its key generations.
+#[derive(Default)]
+pub struct KeyGenerationMonitor {
+    progress: Option<Box<dyn FnMut(KeyGenerationProgress) + Send>>,
+    cancellation: Option<CancellationToken>,
+}
+
+impl KeyGenerationMonitor {
+    /// Creates a new monitor, which neither reports progress nor cancels generations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the same monitor, calling `callback` every time a unit of a key is generated.
+    pub fn with_progress<F>(self, callback: F) -> Self
+    where
+        F: FnMut(KeyGenerationProgress) + Send + 'static,
+    {
+        KeyGenerationMonitor {
+            progress: Some(Box::new(callback)),
+            ..self
+        }
+    }
+
+    /// Returns the same monitor, aborting the generations once `token` is cancelled.
+    pub fn with_cancellation(self, token: CancellationToken) -> Self {
+        KeyGenerationMonitor {
+            cancellation: Some(token),
+            ..self
+        }
+    }
+
+    // Returns a closure reporting the progress of a generation of `total` units, and returning
+    // whether the generation should go on.
+    pub(crate) fn reporter(&mut self, total: usize) -> impl FnMut(usize) -> bool + '_ {
+        move |generated| {
+            if let Some(callback) = self.progress.as_mut() {
+                callback(KeyGenerationProgress { generated, total });
+            }
+            !self.is_cancelled()
+        }
+    }
+
+    // Returns whether the generations should be aborted.
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .map(CancellationToken::is_cancelled)
+            .unwrap_or(false)
+    }
+}
+
+impl Debug for KeyGenerationMonitor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyGenerationMonitor")
+            .field("progress", &self.progress.is_some())
+            .field("cancellation", &self.cancellation)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_reporter_stops_once_cancelled() {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let reports_handle = reports.clone();
+        let token = CancellationToken::new();
+        let mut monitor = KeyGenerationMonitor::new()
+            .with_progress(move |progress| reports_handle.lock().unwrap().push(progress))
+            .with_cancellation(token.clone());
+        let mut reporter = monitor.reporter(3);
+        assert!(reporter(1));
+        token.cancel();
+        assert!(!reporter(2));
+        assert_eq!(
+            *reports.lock().unwrap(),
+            vec![
+                KeyGenerationProgress {
+                    generated: 1,
+                    total: 3
+                },
+                KeyGenerationProgress {
+                    generated: 2,
+                    total: 3
+                },
+            ]
+        );
+        assert!(token.is_cancelled());
+    }
+}
@@ -0,0 +1,504 @@
+//! A module containing helpers to serialize entities along with their structural parameters.
+//!
+//! The serialized form of an entity only contains the raw data of the entity, and deserializing a
+//! blob produced with a different parameter set succeeds as long as the layouts are compatible.
+//! The resulting entity then silently produces garbage outputs when used with the other entities
+//! of the deployment.
+//!
+//! The [`SerializedEntity`] structure wraps an entity with an [`EntityHeader`], which contains the
+//! precision of the entity and its structural parameters (dimensions, polynomial size,
+//! decomposition parameters, ...). It is serializable when the `serde_serialize` feature is
+//! enabled, with any format supported by `serde`. On the receiving side, the entity is only
+//! released by [`SerializedEntity::into_entity`], which checks the header against the parameters
+//! expected by the caller, and against the entity itself, and returns a typed
+//! [`ParameterMismatchError`] otherwise.
+//!
+//! # Example:
+//!
+//! ```
+//! use concrete_commons::parameters::LweDimension;
+//! use concrete_core::backends::core::serialization::{
+//!     EntityParameters, ParameterMismatchError, SerializedEntity,
+//! };
+//! use concrete_core::prelude::*;
+//! # use std::error::Error;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+//! let mut engine = CoreEngine::new()?;
+//! let key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(4))?;
+//! let plaintext = engine.create_plaintext(&(3_u64 << 50))?;
+//! let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, Variance(2_f64.powf(-25.)))?;
+//!
+//! // The wrapped ciphertext is what gets serialized and sent over the wire.
+//! let serialized = SerializedEntity::new(ciphertext);
+//!
+//! // The receiver checks the parameters before using the ciphertext.
+//! let expected = EntityParameters::LweCiphertext {
+//!     lwe_dimension: LweDimension(4),
+//! };
+//! let ciphertext: LweCiphertext64 = serialized.clone().into_entity(&expected)?;
+//!
+//! // A receiver expecting another parameter set gets an error instead of a garbage ciphertext.
+//! let drifted = EntityParameters::LweCiphertext {
+//!     lwe_dimension: LweDimension(630),
+//! };
+//! assert!(matches!(
+//!     serialized.into_entity(&drifted),
+//!     Err(ParameterMismatchError::ParametersMismatch { .. })
+//! ));
+//!
+//! engine.destroy(ciphertext)?;
+//! engine.destroy(plaintext)?;
+//! engine.destroy(key)?;
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use crate::backends::core::entities::{
+    FourierGgswCiphertext32, FourierGgswCiphertext64, FourierLweBootstrapKey32,
+    FourierLweBootstrapKey64, GgswCiphertext32, GgswCiphertext64, GlweCiphertext32,
+    GlweCiphertext64, GlweCiphertextVector32, GlweCiphertextVector64, GlweSecretKey32,
+    GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64, LweCiphertext32, LweCiphertext64,
+    LweCiphertextVector32, LweCiphertextVector64, LweKeyswitchKey32, LweKeyswitchKey64,
+    LweSecretKey32, LweSecretKey64,
+};
+use crate::backends::core::handshake::{RequiredKey, TorusPrecision};
+use crate::specification::entities::{
+    GgswCiphertextEntity, GlweCiphertextEntity, GlweCiphertextVectorEntity, GlweSecretKeyEntity,
+    LweCiphertextEntity, LweCiphertextVectorEntity, LweSecretKeyEntity,
+};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweCiphertextCount, GlweDimension,
+    LweCiphertextCount, LweDimension, PolynomialSize,
+};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The version of the layout of the [`EntityHeader`] structure.
+pub const HEADER_FORMAT_VERSION: u32 = 1;
+
+/// The structural parameters of a serialized entity.
+///
+/// The variant identifies the kind of the entity, and its fields contain every parameter needed
+/// to interpret the raw data of the entity.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityParameters {
+    LweSecretKey {
+        lwe_dimension: LweDimension,
+    },
+    GlweSecretKey {
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    },
+    LweCiphertext {
+        lwe_dimension: LweDimension,
+    },
+    LweCiphertextVector {
+        lwe_dimension: LweDimension,
+        lwe_ciphertext_count: LweCiphertextCount,
+    },
+    GlweCiphertext {
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    },
+    GlweCiphertextVector {
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        glwe_ciphertext_count: GlweCiphertextCount,
+    },
+    GgswCiphertext {
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    },
+    LweKeyswitchKey {
+        input_lwe_dimension: LweDimension,
+        output_lwe_dimension: LweDimension,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    },
+    LweBootstrapKey {
+        input_lwe_dimension: LweDimension,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    },
+}
+
+impl From<RequiredKey> for EntityParameters {
+    fn from(key: RequiredKey) -> Self {
+        match key {
+            RequiredKey::LweKeyswitchKey {
+                input_lwe_dimension,
+                output_lwe_dimension,
+                decomposition_base_log,
+                decomposition_level_count,
+            } => EntityParameters::LweKeyswitchKey {
+                input_lwe_dimension,
+                output_lwe_dimension,
+                decomposition_base_log,
+                decomposition_level_count,
+            },
+            RequiredKey::LweBootstrapKey {
+                input_lwe_dimension,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_base_log,
+                decomposition_level_count,
+            } => EntityParameters::LweBootstrapKey {
+                input_lwe_dimension,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_base_log,
+                decomposition_level_count,
+            },
+        }
+    }
+}
+
+/// A trait implemented by the entities which can be described by an [`EntityHeader`].
+pub trait DescribedEntity {
+    /// The precision of the torus elements of the entity.
+    const PRECISION: TorusPrecision;
+
+    /// Returns the structural parameters of the entity.
+    fn entity_parameters(&self) -> EntityParameters;
+
+    /// Returns the header describing the entity.
+    fn entity_header(&self) -> EntityHeader {
+        EntityHeader {
+            format_version: HEADER_FORMAT_VERSION,
+            precision: Self::PRECISION,
+            parameters: self.entity_parameters(),
+        }
+    }
+}
+
+/// The header serialized along with an entity.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntityHeader {
+    pub format_version: u32,
+    pub precision: TorusPrecision,
+    pub parameters: EntityParameters,
+}
+
+/// An entity wrapped with the header describing it, ready to be serialized.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SerializedEntity<Entity> {
+    header: EntityHeader,
+    entity: Entity,
+}
+
+impl<Entity: DescribedEntity> SerializedEntity<Entity> {
+    /// Wraps an entity with the header describing it.
+    pub fn new(entity: Entity) -> Self {
+        SerializedEntity {
+            header: entity.entity_header(),
+            entity,
+        }
+    }
+
+    /// Returns the header of the wrapped entity.
+    pub fn header(&self) -> &EntityHeader {
+        &self.header
+    }
+
+    /// Checks the header against the `expected` parameters and returns the wrapped entity.
+    ///
+    /// The header is also checked against the precision of the entity type and the parameters
+    /// of the entity itself, so that a header edited independently of the data is detected.
+    pub fn into_entity(
+        self,
+        expected: &EntityParameters,
+    ) -> Result<Entity, ParameterMismatchError> {
+        if self.header.format_version != HEADER_FORMAT_VERSION {
+            return Err(ParameterMismatchError::FormatVersionMismatch {
+                expected: HEADER_FORMAT_VERSION,
+                found: self.header.format_version,
+            });
+        }
+        if self.header.precision != Entity::PRECISION {
+            return Err(ParameterMismatchError::PrecisionMismatch {
+                expected: Entity::PRECISION,
+                found: self.header.precision,
+            });
+        }
+        if self.header.parameters != *expected {
+            return Err(ParameterMismatchError::ParametersMismatch {
+                expected: *expected,
+                found: self.header.parameters,
+            });
+        }
+        let actual = self.entity.entity_parameters();
+        if self.header.parameters != actual {
+            return Err(ParameterMismatchError::InconsistentHeader {
+                header: self.header.parameters,
+                entity: actual,
+            });
+        }
+        Ok(self.entity)
+    }
+}
+
+/// The error which can occur when checking the header of a serialized entity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParameterMismatchError {
+    /// The layout of the header is not supported by this version of the library.
+    FormatVersionMismatch { expected: u32, found: u32 },
+    /// The entity was serialized with another precision.
+    PrecisionMismatch {
+        expected: TorusPrecision,
+        found: TorusPrecision,
+    },
+    /// The entity was serialized with other parameters than the expected ones.
+    ParametersMismatch {
+        expected: EntityParameters,
+        found: EntityParameters,
+    },
+    /// The parameters of the header do not match the ones of the entity.
+    InconsistentHeader {
+        header: EntityParameters,
+        entity: EntityParameters,
+    },
+}
+
+impl Display for ParameterMismatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParameterMismatchError::FormatVersionMismatch { expected, found } => write!(
+                f,
+                "The header format versions differ (expected: {}, found: {}).",
+                expected, found
+            ),
+            ParameterMismatchError::PrecisionMismatch { expected, found } => write!(
+                f,
+                "The precisions differ (expected: {:?}, found: {:?}).",
+                expected, found
+            ),
+            ParameterMismatchError::ParametersMismatch { expected, found } => write!(
+                f,
+                "The parameters differ (expected: {:?}, found: {:?}).",
+                expected, found
+            ),
+            ParameterMismatchError::InconsistentHeader { header, entity } => write!(
+                f,
+                "The header does not describe the entity (header: {:?}, entity: {:?}).",
+                header, entity
+            ),
+        }
+    }
+}
+
+impl Error for ParameterMismatchError {}
+
+macro_rules! implement_described_entity {
+    ($precision: ident => $($entity: ident),+ => |$e: ident| $parameters: expr) => {
+        $(
+            impl DescribedEntity for $entity {
+                const PRECISION: TorusPrecision = TorusPrecision::$precision;
+
+                fn entity_parameters(&self) -> EntityParameters {
+                    let $e = self;
+                    $parameters
+                }
+            }
+        )+
+    };
+}
+
+implement_described_entity!(Bits32 => LweSecretKey32 => |e| EntityParameters::LweSecretKey {
+    lwe_dimension: e.lwe_dimension(),
+});
+implement_described_entity!(Bits64 => LweSecretKey64 => |e| EntityParameters::LweSecretKey {
+    lwe_dimension: e.lwe_dimension(),
+});
+implement_described_entity!(Bits32 => GlweSecretKey32 => |e| EntityParameters::GlweSecretKey {
+    glwe_dimension: e.glwe_dimension(),
+    polynomial_size: e.polynomial_size(),
+});
+implement_described_entity!(Bits64 => GlweSecretKey64 => |e| EntityParameters::GlweSecretKey {
+    glwe_dimension: e.glwe_dimension(),
+    polynomial_size: e.polynomial_size(),
+});
+implement_described_entity!(Bits32 => LweCiphertext32 => |e| EntityParameters::LweCiphertext {
+    lwe_dimension: e.lwe_dimension(),
+});
+implement_described_entity!(Bits64 => LweCiphertext64 => |e| EntityParameters::LweCiphertext {
+    lwe_dimension: e.lwe_dimension(),
+});
+implement_described_entity!(Bits32 => LweCiphertextVector32 => |e| {
+    EntityParameters::LweCiphertextVector {
+        lwe_dimension: e.lwe_dimension(),
+        lwe_ciphertext_count: e.lwe_ciphertext_count(),
+    }
+});
+implement_described_entity!(Bits64 => LweCiphertextVector64 => |e| {
+    EntityParameters::LweCiphertextVector {
+        lwe_dimension: e.lwe_dimension(),
+        lwe_ciphertext_count: e.lwe_ciphertext_count(),
+    }
+});
+implement_described_entity!(Bits32 => GlweCiphertext32 => |e| EntityParameters::GlweCiphertext {
+    glwe_dimension: e.glwe_dimension(),
+    polynomial_size: e.polynomial_size(),
+});
+implement_described_entity!(Bits64 => GlweCiphertext64 => |e| EntityParameters::GlweCiphertext {
+    glwe_dimension: e.glwe_dimension(),
+    polynomial_size: e.polynomial_size(),
+});
+implement_described_entity!(Bits32 => GlweCiphertextVector32 => |e| {
+    EntityParameters::GlweCiphertextVector {
+        glwe_dimension: e.glwe_dimension(),
+        polynomial_size: e.polynomial_size(),
+        glwe_ciphertext_count: e.glwe_ciphertext_count(),
+    }
+});
+implement_described_entity!(Bits64 => GlweCiphertextVector64 => |e| {
+    EntityParameters::GlweCiphertextVector {
+        glwe_dimension: e.glwe_dimension(),
+        polynomial_size: e.polynomial_size(),
+        glwe_ciphertext_count: e.glwe_ciphertext_count(),
+    }
+});
+implement_described_entity!(Bits32 => GgswCiphertext32, FourierGgswCiphertext32 => |e| {
+    EntityParameters::GgswCiphertext {
+        glwe_dimension: e.glwe_dimension(),
+        polynomial_size: e.polynomial_size(),
+        decomposition_base_log: e.decomposition_base_log(),
+        decomposition_level_count: e.decomposition_level_count(),
+    }
+});
+implement_described_entity!(Bits64 => GgswCiphertext64, FourierGgswCiphertext64 => |e| {
+    EntityParameters::GgswCiphertext {
+        glwe_dimension: e.glwe_dimension(),
+        polynomial_size: e.polynomial_size(),
+        decomposition_base_log: e.decomposition_base_log(),
+        decomposition_level_count: e.decomposition_level_count(),
+    }
+});
+implement_described_entity!(Bits32 => LweKeyswitchKey32 => |e| {
+    RequiredKey::from_lwe_keyswitch_key(e).into()
+});
+implement_described_entity!(Bits64 => LweKeyswitchKey64 => |e| {
+    RequiredKey::from_lwe_keyswitch_key(e).into()
+});
+implement_described_entity!(Bits32 => LweBootstrapKey32, FourierLweBootstrapKey32 => |e| {
+    RequiredKey::from_lwe_bootstrap_key(e).into()
+});
+implement_described_entity!(Bits64 => LweBootstrapKey64, FourierLweBootstrapKey64 => |e| {
+    RequiredKey::from_lwe_bootstrap_key(e).into()
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn ggsw_parameters() -> EntityParameters {
+        EntityParameters::GgswCiphertext {
+            glwe_dimension: GlweDimension(1),
+            polynomial_size: PolynomialSize(256),
+            decomposition_base_log: DecompositionBaseLog(4),
+            decomposition_level_count: DecompositionLevelCount(3),
+        }
+    }
+
+    fn ggsw_ciphertext(engine: &mut CoreEngine) -> GgswCiphertext64 {
+        let plaintext = engine.create_plaintext(&1_u64).unwrap();
+        let ciphertext = engine
+            .trivially_encrypt_scalar_ggsw_ciphertext(
+                PolynomialSize(256),
+                GlweDimension(1).to_glwe_size(),
+                DecompositionLevelCount(3),
+                DecompositionBaseLog(4),
+                &plaintext,
+            )
+            .unwrap();
+        engine.destroy(plaintext).unwrap();
+        ciphertext
+    }
+
+    #[test]
+    fn test_header_describes_entity() {
+        let mut engine = CoreEngine::new().unwrap();
+        let serialized = SerializedEntity::new(ggsw_ciphertext(&mut engine));
+        assert_eq!(serialized.header().format_version, HEADER_FORMAT_VERSION);
+        assert_eq!(serialized.header().precision, TorusPrecision::Bits64);
+        assert_eq!(serialized.header().parameters, ggsw_parameters());
+        assert!(serialized.into_entity(&ggsw_parameters()).is_ok());
+    }
+
+    #[test]
+    fn test_parameters_mismatch_is_detected() {
+        let mut engine = CoreEngine::new().unwrap();
+        let serialized = SerializedEntity::new(ggsw_ciphertext(&mut engine));
+        let expected = EntityParameters::GgswCiphertext {
+            glwe_dimension: GlweDimension(1),
+            polynomial_size: PolynomialSize(256),
+            decomposition_base_log: DecompositionBaseLog(5),
+            decomposition_level_count: DecompositionLevelCount(3),
+        };
+        assert_eq!(
+            serialized.into_entity(&expected),
+            Err(ParameterMismatchError::ParametersMismatch {
+                expected,
+                found: ggsw_parameters(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_inconsistent_header_is_detected() {
+        let mut engine = CoreEngine::new().unwrap();
+        let mut serialized = SerializedEntity::new(ggsw_ciphertext(&mut engine));
+        let forged = EntityParameters::GgswCiphertext {
+            glwe_dimension: GlweDimension(1),
+            polynomial_size: PolynomialSize(256),
+            decomposition_base_log: DecompositionBaseLog(6),
+            decomposition_level_count: DecompositionLevelCount(2),
+        };
+        serialized.header.parameters = forged;
+        assert!(matches!(
+            serialized.into_entity(&forged),
+            Err(ParameterMismatchError::InconsistentHeader { .. })
+        ));
+    }
+
+    #[test]
+    fn test_precision_mismatch_is_detected() {
+        let mut engine = CoreEngine::new().unwrap();
+        let mut serialized = SerializedEntity::new(ggsw_ciphertext(&mut engine));
+        serialized.header.precision = TorusPrecision::Bits32;
+        assert!(matches!(
+            serialized.into_entity(&ggsw_parameters()),
+            Err(ParameterMismatchError::PrecisionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_required_key_conversion() {
+        let key = RequiredKey::LweKeyswitchKey {
+            input_lwe_dimension: LweDimension(1024),
+            output_lwe_dimension: LweDimension(630),
+            decomposition_base_log: DecompositionBaseLog(4),
+            decomposition_level_count: DecompositionLevelCount(3),
+        };
+        assert_eq!(
+            EntityParameters::from(key),
+            EntityParameters::LweKeyswitchKey {
+                input_lwe_dimension: LweDimension(1024),
+                output_lwe_dimension: LweDimension(630),
+                decomposition_base_log: DecompositionBaseLog(4),
+                decomposition_level_count: DecompositionLevelCount(3),
+            }
+        );
+    }
+}
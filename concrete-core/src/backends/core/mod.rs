@@ -10,4 +10,14 @@ pub mod private;
 
 mod implementation;
 
-pub use implementation::{engines, entities};
+#[cfg(feature = "async")]
+pub use implementation::asynchronous;
+#[cfg(feature = "audit")]
+pub use implementation::audit;
+#[cfg(feature = "serde_serialize")]
+pub use implementation::envelope;
+#[cfg(feature = "key_backup")]
+pub use implementation::key_backup;
+#[cfg(feature = "noise_estimation")]
+pub use implementation::linear_combination;
+pub use implementation::{engines, entities, handshake, memory, pipeline, progress, serialization};
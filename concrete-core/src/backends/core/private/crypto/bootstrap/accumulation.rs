@@ -0,0 +1,94 @@
+//! Resets of accumulating LWE ciphertexts.
+//!
+//! An accumulator encrypts a sum of messages encoded with one bit of padding. Its reset is a
+//! keyswitch followed by a programmable bootstrap evaluating $m \mapsto m \bmod c$, where $c$ is
+//! the modulus of the accumulated sum, which clears the carries of the sum along with its noise.
+use concrete_commons::numeric::CastFrom;
+use concrete_commons::parameters::{PolynomialSize, ScalingFactor};
+use concrete_fftw::array::AlignedVec;
+
+use crate::backends::core::private::crypto::bootstrap::{
+    large_table_accumulator, FourierBootstrapKey, FourierBuffers,
+};
+use crate::backends::core::private::crypto::lwe::{LweCiphertext, LweKeyswitchKey};
+use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+/// Returns whether an accumulator of `Scalar` integers can be reset with a bootstrap key of
+/// polynomial size `poly_size`.
+///
+/// The message modulus must be a power of two which fits in the accumulator, the counter modulus
+/// must not exceed it, and the scaling factor must keep exactly one bit of padding.
+pub fn accumulation_encoding_is_supported<Scalar>(
+    delta: ScalingFactor,
+    message_modulus: usize,
+    counter_modulus: usize,
+    poly_size: PolynomialSize,
+) -> bool
+where
+    Scalar: UnsignedTorus,
+{
+    message_modulus >= 2
+        && message_modulus.is_power_of_two()
+        && message_modulus <= poly_size.0
+        && counter_modulus >= 1
+        && counter_modulus <= message_modulus
+        && delta.0 as u128 * 2 * message_modulus as u128 == 1 << Scalar::BITS
+}
+
+/// Resets `ciphertext` in place, reducing its message modulo `counter_modulus`.
+///
+/// The ciphertext is encrypted under the output key of `bsk`, and encrypts a message of
+/// `message_modulus` values scaled by `delta`. It is keyswitched with `ksk`, then bootstrapped
+/// back with a trivial accumulator holding the reduced messages.
+pub fn reset_accumulating_lwe<Scalar>(
+    ciphertext: &mut LweCiphertext<Vec<Scalar>>,
+    ksk: &LweKeyswitchKey<Vec<Scalar>>,
+    bsk: &FourierBootstrapKey<AlignedVec<Complex64>, Scalar>,
+    delta: Scalar,
+    message_modulus: usize,
+    counter_modulus: usize,
+    buffers: &mut FourierBuffers<Scalar>,
+) where
+    Scalar: UnsignedTorus + CastFrom<usize>,
+{
+    let table: Vec<Scalar> = (0..message_modulus)
+        .map(|message| Scalar::cast_from(message % counter_modulus).wrapping_mul(delta))
+        .collect();
+    let accumulator = large_table_accumulator(bsk.polynomial_size(), bsk.glwe_size(), &table);
+    let mut switched = LweCiphertext::allocate(Scalar::ZERO, ksk.after_key_size().to_lwe_size());
+    ksk.keyswitch_ciphertext::<Vec<Scalar>, Vec<Scalar>, Scalar>(&mut switched, ciphertext);
+    bsk.bootstrap(ciphertext, &switched, &accumulator, buffers);
+}
+
+#[cfg(test)]
+mod test {
+    use super::accumulation_encoding_is_supported;
+    use concrete_commons::parameters::{PolynomialSize, ScalingFactor};
+
+    #[test]
+    fn test_encoding_must_keep_one_bit_of_padding() {
+        let poly_size = PolynomialSize(1024);
+        let supported_32 = |delta, message_modulus, counter_modulus| {
+            accumulation_encoding_is_supported::<u32>(
+                delta,
+                message_modulus,
+                counter_modulus,
+                poly_size,
+            )
+        };
+        let supported_64 = |delta, message_modulus, counter_modulus| {
+            accumulation_encoding_is_supported::<u64>(
+                delta,
+                message_modulus,
+                counter_modulus,
+                poly_size,
+            )
+        };
+        assert!(supported_64(ScalingFactor::from_log2(59), 16, 4));
+        assert!(!supported_64(ScalingFactor::from_log2(58), 16, 4));
+        assert!(supported_32(ScalingFactor::from_log2(27), 16, 16));
+        assert!(!supported_32(ScalingFactor::from_log2(27), 16, 17));
+        assert!(!supported_32(ScalingFactor::from_log2(20), 2048, 4));
+    }
+}
@@ -0,0 +1,125 @@
+//! Multithreaded bootstrap of LWE ciphertext lists.
+use rayon::prelude::*;
+
+use concrete_fftw::array::AlignedVec;
+
+use crate::backends::core::private::crypto::bootstrap::{FourierBootstrapKey, FourierBuffers};
+use crate::backends::core::private::crypto::glwe::{GlweCiphertext, GlweList};
+use crate::backends::core::private::crypto::lwe::{LweCiphertext, LweList};
+use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::tensor::{
+    ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+/// Fills `output` with the bootstrap of every ciphertext of `input`, each one using the
+/// accumulator with the same index in `accumulators`.
+///
+/// The ciphertexts are split into `chunk_count` chunks of consecutive elements, which are
+/// bootstrapped in parallel on the current rayon pool. Each chunk allocates its own fourier
+/// buffers, which are then reused for all the ciphertexts of the chunk.
+pub fn par_bootstrap_lwe_list<Scalar, C1, C2, C3>(
+    output: &mut LweList<C1>,
+    input: &LweList<C2>,
+    accumulators: &GlweList<C3>,
+    bsk: &FourierBootstrapKey<AlignedVec<Complex64>, Scalar>,
+    chunk_count: usize,
+) where
+    Scalar: UnsignedTorus + Send + Sync,
+    LweList<C1>: AsMutTensor<Element = Scalar>,
+    LweList<C2>: AsRefTensor<Element = Scalar>,
+    GlweList<C3>: AsRefTensor<Element = Scalar>,
+{
+    ck_dim_eq!(output.count().0 => input.count().0, accumulators.ciphertext_count().0);
+    let count = output.count().0;
+    if count == 0 {
+        return;
+    }
+    let (poly_size, glwe_size) = (bsk.polynomial_size(), bsk.glwe_size());
+    let output_size = output.lwe_size().0;
+    let input_size = input.lwe_size().0;
+    let accumulator_size = glwe_size.0 * poly_size.0;
+    let per_chunk = count.div_ceil(chunk_count.max(1));
+    output
+        .as_mut_tensor()
+        .as_mut_slice()
+        .par_chunks_mut(per_chunk * output_size)
+        .zip(
+            input
+                .as_tensor()
+                .as_slice()
+                .par_chunks(per_chunk * input_size),
+        )
+        .zip(
+            accumulators
+                .as_tensor()
+                .as_slice()
+                .par_chunks(per_chunk * accumulator_size),
+        )
+        .for_each(|((output, input), accumulators)| {
+            let mut buffers = FourierBuffers::new(poly_size, glwe_size);
+            for ((output, input), accumulator) in output
+                .chunks_mut(output_size)
+                .zip(input.chunks(input_size))
+                .zip(accumulators.chunks(accumulator_size))
+            {
+                bsk.bootstrap(
+                    &mut LweCiphertext::from_container(output),
+                    &LweCiphertext::from_container(input),
+                    &GlweCiphertext::from_container(accumulator, poly_size),
+                    &mut buffers,
+                );
+            }
+        });
+}
+
+/// Fills `output` with the bootstrap of every ciphertext of `input`, as
+/// [`par_bootstrap_lwe_list`] does, processing the ciphertexts in successive chunks of
+/// `chunk_size` elements.
+///
+/// Every chunk is bootstrapped in parallel with at most `thread_count` fourier buffers, which
+/// bounds the memory allocated at the same time by `chunk_size` of them.
+pub fn par_bootstrap_lwe_list_in_chunks<Scalar, C1, C2, C3>(
+    output: &mut LweList<C1>,
+    input: &LweList<C2>,
+    accumulators: &GlweList<C3>,
+    bsk: &FourierBootstrapKey<AlignedVec<Complex64>, Scalar>,
+    thread_count: usize,
+    chunk_size: usize,
+) where
+    Scalar: UnsignedTorus + Send + Sync,
+    LweList<C1>: AsMutTensor<Element = Scalar>,
+    LweList<C2>: AsRefTensor<Element = Scalar>,
+    GlweList<C3>: AsRefTensor<Element = Scalar>,
+{
+    ck_dim_eq!(output.count().0 => input.count().0, accumulators.ciphertext_count().0);
+    let (output_size, input_size) = (output.lwe_size(), input.lwe_size());
+    let (glwe_dimension, poly_size) = (accumulators.glwe_dimension(), bsk.polynomial_size());
+    let accumulator_size = accumulators.glwe_size().0 * poly_size.0;
+    let chunk_size = chunk_size.max(1);
+    for ((output, input), accumulators) in output
+        .as_mut_tensor()
+        .as_mut_slice()
+        .chunks_mut(chunk_size * output_size.0)
+        .zip(
+            input
+                .as_tensor()
+                .as_slice()
+                .chunks(chunk_size * input_size.0),
+        )
+        .zip(
+            accumulators
+                .as_tensor()
+                .as_slice()
+                .chunks(chunk_size * accumulator_size),
+        )
+    {
+        par_bootstrap_lwe_list::<Scalar, &mut [Scalar], &[Scalar], &[Scalar]>(
+            &mut LweList::from_container(output, output_size),
+            &LweList::from_container(input, input_size),
+            &GlweList::from_container(accumulators, glwe_dimension, poly_size),
+            bsk,
+            thread_count.min(chunk_size),
+        );
+    }
+}
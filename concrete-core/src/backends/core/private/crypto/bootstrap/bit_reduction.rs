@@ -0,0 +1,139 @@
+//! Reduction of LWE bit ciphertext lists.
+//!
+//! The bits encrypted by a list of ciphertexts are reduced with a balanced binary tree: every node
+//! adds the ciphertexts of its two children, and refreshes the sum with a programmable bootstrap
+//! evaluating the reduction on it:
+//!
+//! * the parity of the bits is obtained by evaluating $x \bmod 2$ at every node;
+//! * the population count of the bits is obtained by evaluating the identity at every node.
+use concrete_commons::numeric::CastFrom;
+use concrete_commons::parameters::{GlweSize, MessageBitCount, PolynomialSize};
+use concrete_fftw::array::AlignedVec;
+
+use crate::backends::core::private::crypto::bootstrap::{FourierBootstrapKey, FourierBuffers};
+use crate::backends::core::private::crypto::glwe::GlweCiphertext;
+use crate::backends::core::private::crypto::lwe::{LweCiphertext, LweKeyswitchKey, LweList};
+use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::tensor::{ck_dim_eq, AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+/// The reduction computed by [`discard_reduce_bits_lwe_list`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitReduction {
+    Parity,
+    Popcount,
+}
+
+impl BitReduction {
+    fn evaluate(&self, value: usize) -> usize {
+        match self {
+            BitReduction::Parity => value % 2,
+            BitReduction::Popcount => value,
+        }
+    }
+}
+
+/// Returns the accumulator evaluating the `reduction` on the messages of `message_bits` bits.
+///
+/// Every value is given $N / 2^{p+1}$ coefficients, centered on the value. The last half box would
+/// hold the value $2^{p+1}$, which can not be reached: it is filled with zeros, so that the
+/// negacyclic wrapping of the value $0$ stays correct.
+pub fn bit_reduction_accumulator<Scalar>(
+    poly_size: PolynomialSize,
+    glwe_size: GlweSize,
+    message_bits: MessageBitCount,
+    reduction: BitReduction,
+) -> GlweCiphertext<Vec<Scalar>>
+where
+    Scalar: UnsignedTorus + CastFrom<usize>,
+{
+    let delta_log = Scalar::BITS - message_bits.0 - 2;
+    let value_count = 1 << (message_bits.0 + 1);
+    let box_size = poly_size.0 >> (message_bits.0 + 1);
+    let mut accumulator = GlweCiphertext::allocate(Scalar::ZERO, poly_size, glwe_size);
+    for (index, coefficient) in accumulator
+        .get_mut_body()
+        .as_mut_tensor()
+        .iter_mut()
+        .enumerate()
+    {
+        let value = (index + box_size / 2) / box_size;
+        if value < value_count {
+            *coefficient = Scalar::cast_from(reduction.evaluate(value)) << delta_log;
+        }
+    }
+    accumulator
+}
+
+/// Fills `output` with the reduction of the bits encrypted by `input`.
+///
+/// The inputs encrypt bits, encoded as messages of `message_bits` bits with two bits of padding.
+/// They are reduced with a balanced binary tree: at every level, the ciphertexts are added two by
+/// two, and every sum is keyswitched with `ksk` and bootstrapped with `bsk`. When a level holds an
+/// odd number of ciphertexts, the last one is carried to the next level as is. The noise of every
+/// sum is hence the noise of two inputs or two bootstrapped ciphertexts, whatever the length of
+/// `input`, and the output is a bootstrapped ciphertext as soon as `input` holds more than one
+/// ciphertext. A single ciphertext is copied to the output.
+///
+/// For the parity, the sums are at most $2$, and `message_bits` must be at least one. For the
+/// population count, the sums are at most the input count, which must be smaller than
+/// $2^p$, with $p$ the `message_bits` value.
+pub fn discard_reduce_bits_lwe_list<Scalar, C1, C2>(
+    output: &mut LweCiphertext<C1>,
+    input: &LweList<C2>,
+    ksk: &LweKeyswitchKey<Vec<Scalar>>,
+    bsk: &FourierBootstrapKey<AlignedVec<Complex64>, Scalar>,
+    message_bits: MessageBitCount,
+    reduction: BitReduction,
+    buffers: &mut FourierBuffers<Scalar>,
+) where
+    Scalar: UnsignedTorus + CastFrom<usize>,
+    LweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+    LweList<C2>: AsRefTensor<Element = Scalar>,
+{
+    ck_dim_eq!(output.lwe_size().0 => input.lwe_size().0);
+    let lwe_size = input.lwe_size();
+    let accumulator = bit_reduction_accumulator::<Scalar>(
+        bsk.polynomial_size(),
+        bsk.glwe_size(),
+        message_bits,
+        reduction,
+    );
+    let mut sum = LweCiphertext::allocate(Scalar::ZERO, lwe_size);
+    let mut switched = LweCiphertext::allocate(Scalar::ZERO, ksk.after_key_size().to_lwe_size());
+
+    let mut level: Vec<LweCiphertext<Vec<Scalar>>> = input
+        .ciphertext_iter()
+        .map(|ciphertext| {
+            LweCiphertext::from_container(ciphertext.as_tensor().as_container().to_vec())
+        })
+        .collect();
+    while level.len() > 1 {
+        let mut next_level = Vec::with_capacity(level.len().div_ceil(2));
+        let mut nodes = level.into_iter();
+        while let Some(left) = nodes.next() {
+            let right = match nodes.next() {
+                Some(right) => right,
+                None => {
+                    next_level.push(left);
+                    break;
+                }
+            };
+            sum.as_mut_tensor().fill_with_copy(left.as_tensor());
+            sum.update_with_add::<Vec<Scalar>, Scalar>(&right);
+            ksk.keyswitch_ciphertext::<Vec<Scalar>, Vec<Scalar>, Scalar>(&mut switched, &sum);
+            let mut refreshed = left;
+            bsk.bootstrap::<Vec<Scalar>, Vec<Scalar>, Vec<Scalar>>(
+                &mut refreshed,
+                &switched,
+                &accumulator,
+                buffers,
+            );
+            next_level.push(refreshed);
+        }
+        level = next_level;
+    }
+    if let Some(root) = level.first() {
+        output.as_mut_tensor().fill_with_copy(root.as_tensor());
+    }
+}
@@ -0,0 +1,189 @@
+//! Element-wise extremum of LWE ciphertext lists.
+//!
+//! The extremum of two messages is computed with a programmable bootstrap evaluating the
+//! $\mathrm{relu}$ function on their difference:
+//!
+//! * $\max(a, b) = b + \mathrm{relu}(a - b)$
+//! * $\min(a, b) = a - \mathrm{relu}(a - b)$
+#[cfg(feature = "multithread")]
+use rayon::prelude::*;
+
+use concrete_commons::numeric::CastFrom;
+use concrete_commons::parameters::{Extremum, GlweSize, MessageBitCount, PolynomialSize};
+use concrete_fftw::array::AlignedVec;
+
+use crate::backends::core::private::crypto::bootstrap::{FourierBootstrapKey, FourierBuffers};
+use crate::backends::core::private::crypto::glwe::GlweCiphertext;
+use crate::backends::core::private::crypto::lwe::{LweCiphertext, LweKeyswitchKey, LweList};
+use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::tensor::{ck_dim_eq, AsMutTensor, AsRefTensor};
+#[cfg(feature = "multithread")]
+use crate::backends::core::private::math::tensor::{AsMutSlice, AsRefSlice};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+/// Returns the accumulator evaluating $\mathrm{relu}(x - 2^p)$ on the messages of `message_bits`
+/// bits shifted by $2^p$.
+///
+/// Every value of the shifted difference is given $N / 2^{p+1}$ coefficients, centered on the
+/// value. The last half box would hold the value $2^{p+1}$, which can not be reached: it is filled
+/// with zeros, so that the negacyclic wrapping of the value $0$ stays correct.
+pub fn relu_accumulator<Scalar>(
+    poly_size: PolynomialSize,
+    glwe_size: GlweSize,
+    message_bits: MessageBitCount,
+) -> GlweCiphertext<Vec<Scalar>>
+where
+    Scalar: UnsignedTorus + CastFrom<usize>,
+{
+    let delta_log = Scalar::BITS - message_bits.0 - 2;
+    let shift = 1 << message_bits.0;
+    let box_size = poly_size.0 >> (message_bits.0 + 1);
+    let mut accumulator = GlweCiphertext::allocate(Scalar::ZERO, poly_size, glwe_size);
+    for (index, coefficient) in accumulator
+        .get_mut_body()
+        .as_mut_tensor()
+        .iter_mut()
+        .enumerate()
+    {
+        let value = (index + box_size / 2) / box_size;
+        if value > shift && value < 2 * shift {
+            *coefficient = Scalar::cast_from(value - shift) << delta_log;
+        }
+    }
+    accumulator
+}
+
+// Computes the extremum of two ciphertexts, fusing the linear part with the bootstrap.
+#[allow(clippy::too_many_arguments)]
+fn discard_extremum_lwe<Scalar, C1, C2, C3>(
+    output: &mut LweCiphertext<C1>,
+    input_1: &LweCiphertext<C2>,
+    input_2: &LweCiphertext<C2>,
+    ksk: &LweKeyswitchKey<Vec<Scalar>>,
+    bsk: &FourierBootstrapKey<AlignedVec<Complex64>, Scalar>,
+    accumulator: &GlweCiphertext<C3>,
+    message_bits: MessageBitCount,
+    extremum: Extremum,
+    difference: &mut LweCiphertext<Vec<Scalar>>,
+    switched: &mut LweCiphertext<Vec<Scalar>>,
+    buffers: &mut FourierBuffers<Scalar>,
+) where
+    Scalar: UnsignedTorus,
+    LweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+    LweCiphertext<C2>: AsRefTensor<Element = Scalar>,
+    GlweCiphertext<C3>: AsRefTensor<Element = Scalar>,
+{
+    let delta_log = Scalar::BITS - message_bits.0 - 2;
+    difference
+        .as_mut_tensor()
+        .fill_with_copy(input_1.as_tensor());
+    difference.update_with_sub(input_2);
+    let body = difference.get_mut_body();
+    body.0 = body
+        .0
+        .wrapping_add(Scalar::ONE << (delta_log + message_bits.0));
+    ksk.keyswitch_ciphertext::<Vec<Scalar>, Vec<Scalar>, Scalar>(switched, difference);
+    bsk.bootstrap(output, switched, accumulator, buffers);
+    match extremum {
+        Extremum::Maximum => output.update_with_add(input_2),
+        Extremum::Minimum => {
+            output.update_with_neg();
+            output.update_with_add(input_1);
+        }
+    }
+}
+
+/// Fills `output` with the element-wise extremum of `input_1` and `input_2`.
+///
+/// The inputs encrypt messages of `message_bits` bits, with two bits of padding. For every element,
+/// the difference of the inputs is shifted to be positive, keyswitched with `ksk`, and bootstrapped
+/// with `bsk` to evaluate its $\mathrm{relu}$, which is then combined with one of the inputs.
+///
+/// When the `multithread` feature is enabled, the elements are split into one chunk per thread of
+/// the rayon pool, each chunk using its own fourier buffers. Otherwise, `buffers` is used.
+#[allow(clippy::too_many_arguments)]
+pub fn discard_extremum_lwe_list<Scalar, C1, C2>(
+    output: &mut LweList<C1>,
+    input_1: &LweList<C2>,
+    input_2: &LweList<C2>,
+    ksk: &LweKeyswitchKey<Vec<Scalar>>,
+    bsk: &FourierBootstrapKey<AlignedVec<Complex64>, Scalar>,
+    message_bits: MessageBitCount,
+    extremum: Extremum,
+    #[allow(unused_variables)] buffers: &mut FourierBuffers<Scalar>,
+) where
+    Scalar: UnsignedTorus + CastFrom<usize> + Send + Sync,
+    LweList<C1>: AsMutTensor<Element = Scalar>,
+    LweList<C2>: AsRefTensor<Element = Scalar>,
+{
+    ck_dim_eq!(output.count().0 => input_1.count().0, input_2.count().0);
+    let lwe_size = output.lwe_size();
+    let switched_size = ksk.after_key_size().to_lwe_size();
+    let accumulator =
+        relu_accumulator::<Scalar>(bsk.polynomial_size(), bsk.glwe_size(), message_bits);
+
+    #[cfg(not(feature = "multithread"))]
+    {
+        let mut difference = LweCiphertext::allocate(Scalar::ZERO, lwe_size);
+        let mut switched = LweCiphertext::allocate(Scalar::ZERO, switched_size);
+        for ((mut output, input_1), input_2) in output
+            .ciphertext_iter_mut()
+            .zip(input_1.ciphertext_iter())
+            .zip(input_2.ciphertext_iter())
+        {
+            discard_extremum_lwe(
+                &mut output,
+                &input_1,
+                &input_2,
+                ksk,
+                bsk,
+                &accumulator,
+                message_bits,
+                extremum,
+                &mut difference,
+                &mut switched,
+                buffers,
+            );
+        }
+    }
+
+    #[cfg(feature = "multithread")]
+    {
+        let count = output.count().0;
+        if count == 0 {
+            return;
+        }
+        let chunk_size = count.div_ceil(rayon::current_num_threads()) * lwe_size.0;
+        let (poly_size, glwe_size) = (bsk.polynomial_size(), bsk.glwe_size());
+        output
+            .as_mut_tensor()
+            .as_mut_slice()
+            .par_chunks_mut(chunk_size)
+            .zip(input_1.as_tensor().as_slice().par_chunks(chunk_size))
+            .zip(input_2.as_tensor().as_slice().par_chunks(chunk_size))
+            .for_each(|((output, input_1), input_2)| {
+                let mut buffers = FourierBuffers::new(poly_size, glwe_size);
+                let mut difference = LweCiphertext::allocate(Scalar::ZERO, lwe_size);
+                let mut switched = LweCiphertext::allocate(Scalar::ZERO, switched_size);
+                for ((output, input_1), input_2) in output
+                    .chunks_mut(lwe_size.0)
+                    .zip(input_1.chunks(lwe_size.0))
+                    .zip(input_2.chunks(lwe_size.0))
+                {
+                    discard_extremum_lwe(
+                        &mut LweCiphertext::from_container(output),
+                        &LweCiphertext::from_container(input_1),
+                        &LweCiphertext::from_container(input_2),
+                        ksk,
+                        bsk,
+                        &accumulator,
+                        message_bits,
+                        extremum,
+                        &mut difference,
+                        &mut switched,
+                        &mut buffers,
+                    );
+                }
+            });
+    }
+}
@@ -1,5 +1,7 @@
 use crate::backends::core::private::crypto::glwe::GlweCiphertext;
-use crate::backends::core::private::math::fft::{Complex64, Fft, FourierPolynomial};
+use crate::backends::core::private::math::fft::{
+    Complex64, DoubleDoubleComplex, DoubleDoubleFft, Fft, FourierPolynomial,
+};
 use crate::backends::core::private::math::tensor::Tensor;
 use crate::backends::core::private::math::torus::UnsignedTorus;
 use concrete_commons::parameters::{GlweSize, PolynomialSize};
@@ -15,6 +17,29 @@ pub struct FftBuffers {
     pub first_buffer: FourierPolynomial<AlignedVec<Complex64>>,
     pub second_buffer: FourierPolynomial<AlignedVec<Complex64>>,
     pub output_buffer: Tensor<AlignedVec<Complex64>>,
+    // The products with the high parts of the keys using the split precision are accumulated
+    // separately.
+    pub high_output_buffer: Tensor<AlignedVec<Complex64>>,
+    // The buffers of the double-double precision are only allocated once a key using it is
+    // encountered.
+    pub double_double_buffers: Option<DoubleDoubleBuffers>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DoubleDoubleBuffers {
+    pub fft: DoubleDoubleFft,
+    pub input_buffer: Vec<DoubleDoubleComplex>,
+    pub output_buffer: Vec<DoubleDoubleComplex>,
+}
+
+impl DoubleDoubleBuffers {
+    pub fn new(poly_size: PolynomialSize, glwe_size: GlweSize) -> Self {
+        DoubleDoubleBuffers {
+            fft: DoubleDoubleFft::new(poly_size),
+            input_buffer: vec![DoubleDoubleComplex::ZERO; poly_size.0],
+            output_buffer: vec![DoubleDoubleComplex::ZERO; poly_size.0 * glwe_size.0],
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -33,11 +58,24 @@ where
         Self::new(poly_size, glwe_size)
     }
 
+    /// Returns the number of bytes allocated by the buffers for the given parameters, not
+    /// accounting for the plans of the transforms.
+    pub fn memory_footprint(poly_size: PolynomialSize, glwe_size: GlweSize) -> usize {
+        let complex_bytes = std::mem::size_of::<Complex64>();
+        let scalar_bytes = std::mem::size_of::<Scalar>();
+        // The two fourier polynomials, the two fourier GLWE ciphertexts, and the lut and rounded
+        // GLWE ciphertexts.
+        2 * poly_size.0 * complex_bytes
+            + 2 * glwe_size.0 * poly_size.0 * complex_bytes
+            + 2 * glwe_size.0 * poly_size.0 * scalar_bytes
+    }
+
     pub fn new(poly_size: PolynomialSize, glwe_size: GlweSize) -> Self {
         let fft = Fft::new(poly_size);
         let first_buffer = FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size);
         let second_buffer = FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size);
         let output_buffer = Tensor::from_container(AlignedVec::new(poly_size.0 * glwe_size.0));
+        let high_output_buffer = Tensor::from_container(AlignedVec::new(poly_size.0 * glwe_size.0));
         let lut_buffer = GlweCiphertext::allocate(Scalar::ZERO, poly_size, glwe_size);
         let rounded_buffer = GlweCiphertext::allocate(Scalar::ZERO, poly_size, glwe_size);
 
@@ -49,6 +87,8 @@ where
                 first_buffer,
                 second_buffer,
                 output_buffer,
+                high_output_buffer,
+                double_double_buffers: None,
             },
         }
     }
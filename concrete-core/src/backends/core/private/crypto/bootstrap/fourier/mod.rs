@@ -5,27 +5,35 @@ use concrete_fftw::array::AlignedVec;
 use serde::{Deserialize, Serialize};
 
 use crate::backends::core::private::crypto::bootstrap::standard::StandardBootstrapKey;
-use crate::backends::core::private::crypto::ggsw::FourierGgswCiphertext;
+use crate::backends::core::private::crypto::ggsw::{FourierGgswCiphertext, GgswLevelMatrix};
 use crate::backends::core::private::crypto::glwe::GlweCiphertext;
 use crate::backends::core::private::crypto::lwe::LweCiphertext;
-use crate::backends::core::private::math::decomposition::SignedDecomposer;
-use crate::backends::core::private::math::fft::{Complex64, FourierPolynomial};
+use crate::backends::core::private::math::decomposition::{
+    DecompositionTermTensor, SignedDecomposer,
+};
+use crate::backends::core::private::math::fft::{
+    store_double_double_polynomial, update_with_double_double_multiply_accumulate, Complex64,
+    DoubleDoubleComplex, Fft, FftPrecision, FourierPolynomial,
+};
 use crate::backends::core::private::math::polynomial::{Polynomial, PolynomialList};
 use crate::backends::core::private::math::tensor::{
     ck_dim_div, ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
 };
 use crate::backends::core::private::math::torus::UnsignedTorus;
 use crate::backends::core::private::utils::{zip, zip_args};
+use concrete_commons::numeric::SignedInteger;
 use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LutCountLog, LweDimension,
     ModulusSwitchOffset, MonomialDegree, PolynomialSize,
 };
 
 mod buffers;
+mod small;
 #[cfg(test)]
 mod tests;
 
-pub use buffers::{FftBuffers, FourierBuffers};
+pub use buffers::{DoubleDoubleBuffers, FftBuffers, FourierBuffers};
+pub use small::{supports_small_bootstrap, SMALL_BOOTSTRAP_MAX_GLWE_SIZE};
 
 /// A bootstrapping key in the fourier domain.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -43,6 +51,9 @@ where
     // The decomposition parameters
     decomp_level: DecompositionLevelCount,
     decomp_base_log: DecompositionBaseLog,
+    // The arithmetic strategy of the products with the key
+    #[cfg_attr(feature = "serde", serde(default))]
+    precision: FftPrecision,
     _scalar: std::marker::PhantomData<Scalar>,
 }
 
@@ -81,9 +92,63 @@ where
         decomp_level: DecompositionLevelCount,
         decomp_base_log: DecompositionBaseLog,
         key_size: LweDimension,
+    ) -> Self {
+        Self::allocate_with_precision(
+            value,
+            glwe_size,
+            poly_size,
+            decomp_level,
+            decomp_base_log,
+            key_size,
+            FftPrecision::Standard,
+        )
+    }
+
+    /// Allocates a new complex bootstrapping key whose polynomials coefficients are all `value`,
+    /// and whose products are computed with the given `precision`.
+    ///
+    /// With the [`FftPrecision::Split`] precision, every level matrix of the key is stored twice,
+    /// once for the high part and once for the low part of the coefficients.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::bootstrap::FourierBootstrapKey;
+    /// use concrete_core::backends::core::private::math::fft::{Complex64, FftPrecision};
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    /// let bsk: FourierBootstrapKey<_, u64> = FourierBootstrapKey::allocate_with_precision(
+    ///     Complex64::new(9., 8.),
+    ///     GlweSize(7),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(4),
+    ///     FftPrecision::Split,
+    /// );
+    /// assert_eq!(bsk.precision(), FftPrecision::Split);
+    /// assert_eq!(bsk.level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(bsk.key_size(), LweDimension(4));
+    /// assert_eq!(bsk.as_tensor().len(), 2 * 4 * 3 * 7 * 7 * 256);
+    /// ```
+    pub fn allocate_with_precision(
+        value: Complex64,
+        glwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        key_size: LweDimension,
+        precision: FftPrecision,
     ) -> Self {
         let mut tensor = Tensor::from_container(AlignedVec::new(
-            key_size.0 * decomp_level.0 * glwe_size.0 * glwe_size.0 * poly_size.0,
+            precision.part_count()
+                * key_size.0
+                * decomp_level.0
+                * glwe_size.0
+                * glwe_size.0
+                * poly_size.0,
         ));
         tensor.as_mut_tensor().fill_with_element(value);
         FourierBootstrapKey {
@@ -92,6 +157,7 @@ where
             glwe_size,
             decomp_level,
             decomp_base_log,
+            precision,
             _scalar: Default::default(),
         }
     }
@@ -147,6 +213,7 @@ where
             glwe_size,
             decomp_level,
             decomp_base_log,
+            precision: FftPrecision::Standard,
             _scalar: Default::default(),
         }
     }
@@ -192,10 +259,20 @@ where
         StandardBootstrapKey<InputCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
+        if self.precision == FftPrecision::DoubleDouble {
+            self.fill_with_double_double_forward_fourier(coef_bsk, &mut buffers.fft_buffers);
+            return;
+        }
+
         // We retrieve a buffer for the fft.
         let fft_buffer = &mut buffers.fft_buffers.first_buffer;
         let fft = &mut buffers.fft_buffers.fft;
 
+        if self.precision == FftPrecision::Split {
+            self.fill_with_split_forward_fourier(coef_bsk, fft, fft_buffer);
+            return;
+        }
+
         // We move every polynomials to the fourier domain.
         let iterator = self
             .tensor
@@ -210,6 +287,81 @@ where
         }
     }
 
+    // Fills the key with the double-double fourier transforms of the polynomials of `coef_bsk`.
+    // Every transformed polynomial is stored as its high parts, followed by its low parts.
+    fn fill_with_double_double_forward_fourier<InputCont>(
+        &mut self,
+        coef_bsk: &StandardBootstrapKey<InputCont>,
+        fft_buffers: &mut FftBuffers,
+    ) where
+        Cont: AsMutSlice<Element = Complex64>,
+        StandardBootstrapKey<InputCont>: AsRefTensor<Element = Scalar>,
+    {
+        let poly_size = self.poly_size;
+        let glwe_size = self.glwe_size;
+        let buffers = fft_buffers
+            .double_double_buffers
+            .get_or_insert_with(|| DoubleDoubleBuffers::new(poly_size, glwe_size));
+        let iterator = self
+            .tensor
+            .subtensor_iter_mut(2 * poly_size.0)
+            .zip(coef_bsk.poly_iter());
+        for (mut fourier_poly, coef_poly) in iterator {
+            buffers.fft.forward(&mut buffers.input_buffer, &coef_poly);
+            store_double_double_polynomial(fourier_poly.as_mut_slice(), &buffers.input_buffer);
+        }
+    }
+
+    // Fills the key with the fourier transforms of the high and low parts of the polynomials of
+    // `coef_bsk`. The high part of every level matrix is stored right before its low part.
+    fn fill_with_split_forward_fourier<InputCont>(
+        &mut self,
+        coef_bsk: &StandardBootstrapKey<InputCont>,
+        fft: &Fft,
+        fft_buffer: &mut FourierPolynomial<AlignedVec<Complex64>>,
+    ) where
+        Cont: AsMutSlice<Element = Complex64>,
+        StandardBootstrapKey<InputCont>: AsRefTensor<Element = Scalar>,
+    {
+        let poly_size = self.poly_size;
+        let matrix_size = self.glwe_size.0 * self.glwe_size.0 * poly_size.0;
+        let low_bits = FftPrecision::split_low_bits::<Scalar>();
+        let mut high_poly = Polynomial::allocate(Scalar::ZERO, poly_size);
+        let mut low_poly = Polynomial::allocate(Scalar::ZERO, poly_size);
+
+        let iterator = self.tensor.subtensor_iter_mut(2 * matrix_size).zip(
+            coef_bsk
+                .as_tensor()
+                .subtensor_iter(matrix_size)
+                .map(|t| PolynomialList::from_container(t.into_container(), poly_size)),
+        );
+        for (mut fourier_matrix, coef_matrix) in iterator {
+            let (high_matrix, low_matrix) = fourier_matrix.as_mut_slice().split_at_mut(matrix_size);
+            let iterator = zip!(
+                high_matrix.chunks_exact_mut(poly_size.0),
+                low_matrix.chunks_exact_mut(poly_size.0),
+                coef_matrix.polynomial_iter()
+            );
+            for zip_args!(high_fourier, low_fourier, coef_poly) in iterator {
+                // The high part is the rounding of the coefficient to its most significant bits,
+                // seen as a signed integer, and the low part is the (small) remainder.
+                for zip_args!(high, low, coef) in zip!(
+                    high_poly.as_mut_tensor().iter_mut(),
+                    low_poly.as_mut_tensor().iter_mut(),
+                    coef_poly.as_tensor().iter()
+                ) {
+                    let rounded = (*coef).wrapping_add(Scalar::ONE << (low_bits - 1));
+                    *high = (rounded.into_signed() >> low_bits).into_unsigned();
+                    *low = (*coef).wrapping_sub(*high << low_bits);
+                }
+                fft.forward_as_integer(fft_buffer, &high_poly);
+                high_fourier.copy_from_slice(fft_buffer.as_tensor().as_slice());
+                fft.forward_as_torus(fft_buffer, &low_poly);
+                low_fourier.copy_from_slice(fft_buffer.as_tensor().as_slice());
+            }
+        }
+    }
+
     /// Returns the size of the polynomials used in the bootstrapping key.
     ///
     /// # Example
@@ -361,12 +513,44 @@ where
         );
         LweDimension(
             self.as_tensor().len()
-                / (self.glwe_size.0 * self.glwe_size.0 * self.poly_size.0 * self.decomp_level.0),
+                / (self.precision.part_count()
+                    * self.glwe_size.0
+                    * self.glwe_size.0
+                    * self.poly_size.0
+                    * self.decomp_level.0),
         )
     }
 
+    /// Returns the arithmetic strategy of the products computed with the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::bootstrap::FourierBootstrapKey;
+    /// use concrete_core::backends::core::private::math::fft::{Complex64, FftPrecision};
+    /// let bsk: FourierBootstrapKey<_, u32> = FourierBootstrapKey::allocate(
+    ///     Complex64::new(9., 8.),
+    ///     GlweSize(7),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(4),
+    /// );
+    /// assert_eq!(bsk.precision(), FftPrecision::Standard);
+    /// ```
+    pub fn precision(&self) -> FftPrecision {
+        self.precision
+    }
+
     /// Returns an iterator over the borrowed GGSW ciphertext composing the key.
     ///
+    /// With the [`FftPrecision::Split`] precision, the GGSW ciphertexts hold twice as many level
+    /// matrices as the decomposition levels: the high part of every level comes right before its
+    /// low part.
+    ///
     /// # Example
     ///
     /// ```
@@ -394,8 +578,11 @@ where
     where
         Self: AsRefTensor<Element = Complex64>,
     {
-        let chunks_size =
-            self.glwe_size.0 * self.glwe_size.0 * self.poly_size.0 * self.decomp_level.0;
+        let chunks_size = self.precision.part_count()
+            * self.glwe_size.0
+            * self.glwe_size.0
+            * self.poly_size.0
+            * self.decomp_level.0;
         let rlwe_size = self.glwe_size;
         let poly_size = self.poly_size;
         let base_log = self.decomp_base_log;
@@ -413,6 +600,10 @@ where
 
     /// Returns an iterator over the mutably borrowed GGSW ciphertext composing the key.
     ///
+    /// With the [`FftPrecision::Split`] precision, the GGSW ciphertexts hold twice as many level
+    /// matrices as the decomposition levels: the high part of every level comes right before its
+    /// low part.
+    ///
     /// # Example
     ///
     /// ```
@@ -443,8 +634,11 @@ where
     where
         Self: AsMutTensor<Element = Complex64>,
     {
-        let chunks_size =
-            self.glwe_size.0 * self.glwe_size.0 * self.poly_size.0 * self.decomp_level.0;
+        let chunks_size = self.precision.part_count()
+            * self.glwe_size.0
+            * self.glwe_size.0
+            * self.poly_size.0
+            * self.decomp_level.0;
         let rlwe_size = self.glwe_size;
         let poly_size = self.poly_size;
         let base_log = self.decomp_base_log;
@@ -488,11 +682,17 @@ where
             output.size()
         );
 
+        if self.precision == FftPrecision::DoubleDouble {
+            self.double_double_external_product(output, ggsw, glwe, fft_buffers, rounded_buffer);
+            return;
+        }
+
         // "alias" buffers to save some typing
         let fft = &mut fft_buffers.fft;
         let first_fft_buffer = &mut fft_buffers.first_buffer;
         let second_fft_buffer = &mut fft_buffers.second_buffer;
         let output_fft_buffer = &mut fft_buffers.output_buffer;
+        let high_output_fft_buffer = &mut fft_buffers.high_output_buffer;
         output_fft_buffer.fill_with_element(Complex64::new(0., 0.));
 
         let rounded_input_glwe = rounded_buffer;
@@ -505,105 +705,103 @@ where
         // In this section, we perform the external product in the fourier domain, and accumulate
         // the result in the output_fft_buffer variable.
         let mut decomposition = decomposer.decompose_tensor(rounded_input_glwe);
-        // We loop through the levels (we reverse to match the order of the decomposition iterator.)
-        for ggsw_decomp_matrix in ggsw.level_matrix_iter().rev() {
-            // We retrieve the decomposition of this level.
-            let glwe_decomp_term = decomposition.next_term().unwrap();
-            debug_assert_eq!(
-                ggsw_decomp_matrix.decomposition_level(),
-                glwe_decomp_term.level()
-            );
-            // For each levels we have to add the result of the vector-matrix product between the
-            // decomposition of the glwe, and the ggsw level matrix to the output. To do so, we
-            // iteratively add to the output, the product between every lines of the matrix, and
-            // the corresponding (scalar) polynomial in the glwe decomposition:
-            //
-            //                ggsw_mat                        ggsw_mat
-            //   glwe_dec   | - - - - | <        glwe_dec   | - - - - |
-            //  | - - - | x | - - - - |         | - - - | x | - - - - | <
-            //    ^         | - - - - |             ^       | - - - - |
-            //
-            //        t = 1                           t = 2                     ...
-            // When possible we iterate two times in a row, to benefit from the fact that fft can
-            // transform two polynomials at once.
-            let mut iterator = zip!(
-                ggsw_decomp_matrix.row_iter(),
-                glwe_decomp_term
-                    .as_tensor()
-                    .subtensor_iter(self.poly_size.0)
-                    .map(Polynomial::from_tensor)
-            );
+        match self.precision {
+            FftPrecision::Standard => {
+                // We loop through the levels (we reverse to match the order of the decomposition
+                // iterator.)
+                for ggsw_decomp_matrix in ggsw.level_matrix_iter().rev() {
+                    // We retrieve the decomposition of this level.
+                    let glwe_decomp_term = decomposition.next_term().unwrap();
+                    debug_assert_eq!(
+                        ggsw_decomp_matrix.decomposition_level(),
+                        glwe_decomp_term.level()
+                    );
+                    self.update_with_level_product(
+                        fft,
+                        first_fft_buffer,
+                        second_fft_buffer,
+                        output_fft_buffer,
+                        &ggsw_decomp_matrix,
+                        &glwe_decomp_term,
+                    );
+                }
+            }
+            FftPrecision::Split => {
+                // The products with the high parts are accumulated separately, in the
+                // high_output_fft_buffer variable. Since the high part of every level comes right
+                // before its low part, the reversed iterator yields the low part first.
+                high_output_fft_buffer.fill_with_element(Complex64::new(0., 0.));
+                let mut level_matrices = ggsw.level_matrix_iter().rev();
+                while let (Some(low_decomp_matrix), Some(high_decomp_matrix)) =
+                    (level_matrices.next(), level_matrices.next())
+                {
+                    let glwe_decomp_term = decomposition.next_term().unwrap();
+                    debug_assert_eq!(
+                        low_decomp_matrix.decomposition_level().0,
+                        2 * glwe_decomp_term.level().0
+                    );
+                    self.update_with_level_product(
+                        fft,
+                        first_fft_buffer,
+                        second_fft_buffer,
+                        high_output_fft_buffer,
+                        &high_decomp_matrix,
+                        &glwe_decomp_term,
+                    );
+                    self.update_with_level_product(
+                        fft,
+                        first_fft_buffer,
+                        second_fft_buffer,
+                        output_fft_buffer,
+                        &low_decomp_matrix,
+                        &glwe_decomp_term,
+                    );
+                }
+            }
+            FftPrecision::DoubleDouble => unreachable!(),
+        }
 
-            //---------------------------------------------------------------- VECTOR-MATRIX PRODUCT
-            loop {
-                match (iterator.next(), iterator.next()) {
-                    // Two iterates are available, we use the fast fft.
-                    (Some(first), Some(second)) => {
-                        // We unpack the iterator values
-                        let zip_args!(first_ggsw_row, first_glwe_poly) = first;
-                        let zip_args!(second_ggsw_row, second_glwe_poly) = second;
-                        // We perform the forward fft transform for the glwe polynomials
-                        fft.forward_two_as_integer(
-                            first_fft_buffer,
-                            second_fft_buffer,
-                            &first_glwe_poly,
-                            &second_glwe_poly,
-                        );
-                        // Now we loop through the polynomials of the output, and add the
-                        // corresponding product of polynomials.
-                        let iterator = zip!(
-                            first_ggsw_row
-                                .as_tensor()
-                                .subtensor_iter(self.poly_size.0)
-                                .map(FourierPolynomial::from_tensor),
-                            second_ggsw_row
-                                .as_tensor()
-                                .subtensor_iter(self.poly_size.0)
-                                .map(FourierPolynomial::from_tensor),
-                            output_fft_buffer
-                                .as_mut_tensor()
-                                .subtensor_iter_mut(self.poly_size.0)
-                                .map(FourierPolynomial::from_tensor)
-                        );
-                        for zip_args!(first_ggsw_poly, second_ggsw_poly, mut output_poly) in
-                            iterator
-                        {
-                            output_poly.update_with_two_multiply_accumulate(
-                                &first_ggsw_poly,
-                                first_fft_buffer,
-                                &second_ggsw_poly,
-                                second_fft_buffer,
+        // With the split precision, the products with the high parts are integers, which are
+        // brought back to the standard domain in the (now unused) rounded buffer, and added to the
+        // output once shifted to the most significant bits. This is done before the backward
+        // transform of the low parts, which keeps the output borrowed until the end of the function.
+        if self.precision == FftPrecision::Split {
+            let low_bits = FftPrecision::split_low_bits::<Scalar>();
+            let high_output = rounded_input_glwe;
+            high_output.as_mut_tensor().fill_with_element(Scalar::ZERO);
+            {
+                let mut high_output_list = high_output.as_mut_polynomial_list();
+                let mut iterator = zip!(
+                    high_output_list.polynomial_iter_mut(),
+                    high_output_fft_buffer
+                        .subtensor_iter_mut(self.poly_size.0)
+                        .map(FourierPolynomial::from_tensor)
+                );
+                loop {
+                    match (iterator.next(), iterator.next()) {
+                        (Some(first), Some(second)) => {
+                            let zip_args!(mut first_output, mut first_fourier) = first;
+                            let zip_args!(mut second_output, mut second_fourier) = second;
+                            fft.add_backward_two_as_integer(
+                                &mut first_output,
+                                &mut second_output,
+                                &mut first_fourier,
+                                &mut second_fourier,
                             );
                         }
-                    }
-                    // We reach the  end of the loop and one element remains.
-                    (Some(first), None) => {
-                        // We unpack the iterator values
-                        let (first_ggsw_row, first_glwe_poly) = first;
-                        // We perform the forward fft transform for the glwe polynomial
-                        fft.forward_as_integer(first_fft_buffer, &first_glwe_poly);
-                        // Now we loop through the polynomials of the output, and add the
-                        // corresponding product of polynomials.
-                        let iterator = zip!(
-                            first_ggsw_row
-                                .as_tensor()
-                                .subtensor_iter(self.poly_size.0)
-                                .map(FourierPolynomial::from_tensor),
-                            output_fft_buffer
-                                .subtensor_iter_mut(self.poly_size.0)
-                                .map(FourierPolynomial::from_tensor)
-                        );
-                        for zip_args!(first_ggsw_poly, mut output_poly) in iterator {
-                            output_poly.update_with_multiply_accumulate(
-                                &first_ggsw_poly,
-                                first_fft_buffer,
-                            );
+                        (Some(first), None) => {
+                            let (mut first_output, mut first_fourier) = first;
+                            fft.add_backward_as_integer(&mut first_output, &mut first_fourier);
                         }
+                        _ => break,
                     }
-                    // The loop is over, we can exit.
-                    _ => break,
                 }
             }
+            output
+                .as_mut_tensor()
+                .update_with_one(high_output.as_tensor(), |out, high| {
+                    *out = out.wrapping_add(*high << low_bits)
+                });
         }
 
         // --------------------------------------------  TRANSFORMATION OF RESULT TO STANDARD DOMAIN
@@ -644,6 +842,174 @@ where
         }
     }
 
+    // Computes the external product with the double-double precision. The level matrices of the
+    // key hold the transformed polynomials as their high parts followed by their low parts, which
+    // prevents the use of the level matrix iterators of the GGSW ciphertext.
+    fn double_double_external_product<C1, C2, C3>(
+        &self,
+        output: &mut GlweCiphertext<C1>,
+        ggsw: &FourierGgswCiphertext<C2, Scalar>,
+        glwe: &GlweCiphertext<C3>,
+        fft_buffers: &mut FftBuffers,
+        rounded_buffer: &mut GlweCiphertext<Vec<Scalar>>,
+    ) where
+        GlweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+        FourierGgswCiphertext<C2, Scalar>: AsRefTensor<Element = Complex64>,
+        GlweCiphertext<C3>: AsRefTensor<Element = Scalar>,
+    {
+        let poly_size = self.poly_size;
+        let glwe_size = self.glwe_size;
+        let buffers = fft_buffers
+            .double_double_buffers
+            .get_or_insert_with(|| DoubleDoubleBuffers::new(poly_size, glwe_size));
+        buffers
+            .output_buffer
+            .iter_mut()
+            .for_each(|value| *value = DoubleDoubleComplex::ZERO);
+
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level);
+        decomposer.fill_tensor_with_closest_representable(rounded_buffer, glwe);
+        let mut decomposition = decomposer.decompose_tensor(rounded_buffer);
+
+        // Every row of a level matrix corresponds to a polynomial of the decomposition term, and
+        // every polynomial of a row to a polynomial of the output.
+        let ggsw_polys = ggsw.as_tensor().as_slice();
+        let stored_poly_size = 2 * poly_size.0;
+        while let Some(glwe_decomp_term) = decomposition.next_term() {
+            let matrix_index = glwe_decomp_term.level().0 - 1;
+            let iterator = glwe_decomp_term
+                .as_tensor()
+                .subtensor_iter(poly_size.0)
+                .map(Polynomial::from_tensor)
+                .enumerate();
+            for (row, glwe_poly) in iterator {
+                buffers.fft.forward(&mut buffers.input_buffer, &glwe_poly);
+                let row_offset = (matrix_index * glwe_size.0 + row) * glwe_size.0;
+                let iterator = buffers
+                    .output_buffer
+                    .chunks_exact_mut(poly_size.0)
+                    .enumerate();
+                for (column, output_poly) in iterator {
+                    let offset = (row_offset + column) * stored_poly_size;
+                    update_with_double_double_multiply_accumulate(
+                        output_poly,
+                        &buffers.input_buffer,
+                        &ggsw_polys[offset..offset + stored_poly_size],
+                    );
+                }
+            }
+        }
+
+        let mut output_list = output.as_mut_polynomial_list();
+        for (mut output_poly, fourier_poly) in output_list
+            .polynomial_iter_mut()
+            .zip(buffers.output_buffer.chunks_exact_mut(poly_size.0))
+        {
+            buffers.fft.add_backward(&mut output_poly, fourier_poly);
+        }
+    }
+
+    // Adds the product between the decomposition term `glwe_decomp_term` of the input GLWE
+    // ciphertext, and the level matrix `ggsw_decomp_matrix`, to the fourier polynomials of
+    // `output_fft_buffer`.
+    fn update_with_level_product(
+        &self,
+        fft: &Fft,
+        first_fft_buffer: &mut FourierPolynomial<AlignedVec<Complex64>>,
+        second_fft_buffer: &mut FourierPolynomial<AlignedVec<Complex64>>,
+        output_fft_buffer: &mut Tensor<AlignedVec<Complex64>>,
+        ggsw_decomp_matrix: &GgswLevelMatrix<&[Complex64]>,
+        glwe_decomp_term: &DecompositionTermTensor<Scalar>,
+    ) {
+        // For each levels we have to add the result of the vector-matrix product between the
+        // decomposition of the glwe, and the ggsw level matrix to the output. To do so, we
+        // iteratively add to the output, the product between every lines of the matrix, and
+        // the corresponding (scalar) polynomial in the glwe decomposition:
+        //
+        //                ggsw_mat                        ggsw_mat
+        //   glwe_dec   | - - - - | <        glwe_dec   | - - - - |
+        //  | - - - | x | - - - - |         | - - - | x | - - - - | <
+        //    ^         | - - - - |             ^       | - - - - |
+        //
+        //        t = 1                           t = 2                     ...
+        // When possible we iterate two times in a row, to benefit from the fact that fft can
+        // transform two polynomials at once.
+        let mut iterator = zip!(
+            ggsw_decomp_matrix.row_iter(),
+            glwe_decomp_term
+                .as_tensor()
+                .subtensor_iter(self.poly_size.0)
+                .map(Polynomial::from_tensor)
+        );
+
+        //---------------------------------------------------------------- VECTOR-MATRIX PRODUCT
+        loop {
+            match (iterator.next(), iterator.next()) {
+                // Two iterates are available, we use the fast fft.
+                (Some(first), Some(second)) => {
+                    // We unpack the iterator values
+                    let zip_args!(first_ggsw_row, first_glwe_poly) = first;
+                    let zip_args!(second_ggsw_row, second_glwe_poly) = second;
+                    // We perform the forward fft transform for the glwe polynomials
+                    fft.forward_two_as_integer(
+                        first_fft_buffer,
+                        second_fft_buffer,
+                        &first_glwe_poly,
+                        &second_glwe_poly,
+                    );
+                    // Now we loop through the polynomials of the output, and add the
+                    // corresponding product of polynomials.
+                    let iterator = zip!(
+                        first_ggsw_row
+                            .as_tensor()
+                            .subtensor_iter(self.poly_size.0)
+                            .map(FourierPolynomial::from_tensor),
+                        second_ggsw_row
+                            .as_tensor()
+                            .subtensor_iter(self.poly_size.0)
+                            .map(FourierPolynomial::from_tensor),
+                        output_fft_buffer
+                            .as_mut_tensor()
+                            .subtensor_iter_mut(self.poly_size.0)
+                            .map(FourierPolynomial::from_tensor)
+                    );
+                    for zip_args!(first_ggsw_poly, second_ggsw_poly, mut output_poly) in iterator {
+                        output_poly.update_with_two_multiply_accumulate(
+                            &first_ggsw_poly,
+                            first_fft_buffer,
+                            &second_ggsw_poly,
+                            second_fft_buffer,
+                        );
+                    }
+                }
+                // We reach the  end of the loop and one element remains.
+                (Some(first), None) => {
+                    // We unpack the iterator values
+                    let (first_ggsw_row, first_glwe_poly) = first;
+                    // We perform the forward fft transform for the glwe polynomial
+                    fft.forward_as_integer(first_fft_buffer, &first_glwe_poly);
+                    // Now we loop through the polynomials of the output, and add the
+                    // corresponding product of polynomials.
+                    let iterator = zip!(
+                        first_ggsw_row
+                            .as_tensor()
+                            .subtensor_iter(self.poly_size.0)
+                            .map(FourierPolynomial::from_tensor),
+                        output_fft_buffer
+                            .subtensor_iter_mut(self.poly_size.0)
+                            .map(FourierPolynomial::from_tensor)
+                    );
+                    for zip_args!(first_ggsw_poly, mut output_poly) in iterator {
+                        output_poly
+                            .update_with_multiply_accumulate(&first_ggsw_poly, first_fft_buffer);
+                    }
+                }
+                // The loop is over, we can exit.
+                _ => break,
+            }
+        }
+    }
+
     // This cmux mutates both ct1 and ct0. The result is in ct0 after the method was called.
     fn cmux<C0, C1, C2>(
         &self,
@@ -902,6 +1268,33 @@ where
         let local_accumulator = &mut buffers.lut_buffer;
         constant_sample_extract(lwe_out, &*local_accumulator);
     }
+
+    /// Performs the blind rotation of an accumulator by an input LWE ciphertext, in place.
+    ///
+    /// This is the bootstrap of [`FourierBootstrapKey::bootstrap`] without the final sample
+    /// extraction: the `accumulator` is multiplied by $X^{-\tilde{\varphi}}$, where
+    /// $\tilde{\varphi}$ is the phase of `lwe_in` switched to the modulus $2N$. All the
+    /// coefficients of the rotated accumulator can then be used, and not only the constant one.
+    pub fn blind_rotate_accumulator<C1, C2>(
+        &self,
+        accumulator: &mut GlweCiphertext<C1>,
+        lwe_in: &LweCiphertext<C2>,
+        buffers: &mut FourierBuffers<Scalar>,
+    ) where
+        GlweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<C2>: AsRefTensor<Element = Scalar>,
+    {
+        buffers
+            .lut_buffer
+            .as_mut_tensor()
+            .as_mut_slice()
+            .copy_from_slice(accumulator.as_tensor().as_slice());
+        self.blind_rotate(buffers, lwe_in);
+        accumulator
+            .as_mut_tensor()
+            .as_mut_slice()
+            .copy_from_slice(buffers.lut_buffer.as_tensor().as_slice());
+    }
 }
 
 impl<Element, Cont, Scalar> AsRefTensor for FourierBootstrapKey<Cont, Scalar>
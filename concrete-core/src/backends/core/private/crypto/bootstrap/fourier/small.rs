@@ -0,0 +1,283 @@
+use concrete_commons::parameters::{GlweSize, LutCountLog, ModulusSwitchOffset, PolynomialSize};
+
+use crate::backends::core::private::crypto::ggsw::FourierGgswCiphertext;
+use crate::backends::core::private::crypto::glwe::GlweCiphertext;
+use crate::backends::core::private::crypto::lwe::LweCiphertext;
+use crate::backends::core::private::math::decomposition::{decompose_one_level, SignedDecomposer};
+use crate::backends::core::private::math::fft::{
+    Complex64, ComplexStackBuffer, FftPrecision, FourierPolynomial, SmallFft,
+    SMALL_FFT_MAX_POLY_SIZE,
+};
+use crate::backends::core::private::math::polynomial::{Polynomial, PolynomialList};
+use crate::backends::core::private::math::tensor::{
+    AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::{constant_sample_extract, pbs_modulus_switch, FourierBootstrapKey, FourierBuffers};
+
+/// The largest GLWE size supported by the small bootstrap path.
+pub const SMALL_BOOTSTRAP_MAX_GLWE_SIZE: usize = 3;
+
+// The length of the stack buffers used to store a GLWE ciphertext in the small bootstrap path.
+const SMALL_BOOTSTRAP_BUFFER_LEN: usize = SMALL_FFT_MAX_POLY_SIZE * SMALL_BOOTSTRAP_MAX_GLWE_SIZE;
+
+/// Returns whether the small bootstrap path supports the given parameters.
+///
+/// The small path supports polynomials of size at most 1024, and GLWE size at most
+/// [`SMALL_BOOTSTRAP_MAX_GLWE_SIZE`], which covers the usual parameters of boolean gates. It uses
+/// the [`SmallFft`] transformer instead of the fftw plans, and keeps all its temporary buffers on
+/// the stack, which makes it allocation-free and independent of the fft buffers.
+pub fn supports_small_bootstrap(poly_size: PolynomialSize, glwe_size: GlweSize) -> bool {
+    SmallFft::supports(poly_size) && glwe_size.0 <= SMALL_BOOTSTRAP_MAX_GLWE_SIZE
+}
+
+impl<Cont, Scalar> FourierBootstrapKey<Cont, Scalar>
+where
+    Self: AsRefTensor<Element = Complex64>,
+    Scalar: UnsignedTorus,
+{
+    // Adds the external product between `ggsw` and `glwe` to `output`, using stack buffers only.
+    fn small_external_product<C>(
+        &self,
+        fft: &SmallFft,
+        output: &mut [Scalar],
+        ggsw: &FourierGgswCiphertext<C, Scalar>,
+        glwe: &[Scalar],
+    ) where
+        FourierGgswCiphertext<C, Scalar>: AsRefTensor<Element = Complex64>,
+    {
+        let poly_size = self.poly_size.0;
+        let glwe_len = self.glwe_size.0 * poly_size;
+        let base_log = self.decomp_base_log.0;
+        let level_count = self.decomp_level.0;
+
+        // We round the input mask and body, and initialize the states of the decomposition, as in
+        // the `TensorSignedDecompositionIter` structure.
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level);
+        let mut states = [Scalar::ZERO; SMALL_BOOTSTRAP_BUFFER_LEN];
+        let states = &mut states[..glwe_len];
+        for (state, value) in states.iter_mut().zip(glwe.iter()) {
+            *state =
+                decomposer.closest_representable(*value) >> (Scalar::BITS - base_log * level_count);
+        }
+        let mod_b_mask = (Scalar::ONE << base_log) - Scalar::ONE;
+        let mut terms = [Scalar::ZERO; SMALL_BOOTSTRAP_BUFFER_LEN];
+        let terms = &mut terms[..glwe_len];
+
+        let mut output_fourier = ComplexStackBuffer::<SMALL_BOOTSTRAP_BUFFER_LEN>::new();
+        let output_fourier = &mut output_fourier.0[..glwe_len];
+        let mut first_fourier = ComplexStackBuffer::<SMALL_FFT_MAX_POLY_SIZE>::new();
+        let mut first_fourier =
+            FourierPolynomial::from_container(&mut first_fourier.0[..poly_size]);
+        let mut second_fourier = ComplexStackBuffer::<SMALL_FFT_MAX_POLY_SIZE>::new();
+        let mut second_fourier =
+            FourierPolynomial::from_container(&mut second_fourier.0[..poly_size]);
+
+        // We loop through the levels, in the order of the decomposition.
+        for ggsw_decomp_matrix in ggsw.level_matrix_iter().rev() {
+            for (term, state) in terms.iter_mut().zip(states.iter_mut()) {
+                *term = decompose_one_level(base_log, state, mod_b_mask);
+            }
+            // As in the generic path, we process the rows of the matrix two by two.
+            let mut iterator = ggsw_decomp_matrix
+                .row_iter()
+                .zip(terms.chunks(poly_size).map(Polynomial::from_container));
+            loop {
+                match (iterator.next(), iterator.next()) {
+                    (Some((first_row, first_term)), Some((second_row, second_term))) => {
+                        fft.forward_two_as_integer(
+                            &mut first_fourier,
+                            &mut second_fourier,
+                            &first_term,
+                            &second_term,
+                        );
+                        for ((first_ggsw_poly, second_ggsw_poly), mut output_poly) in first_row
+                            .as_tensor()
+                            .as_slice()
+                            .chunks(poly_size)
+                            .map(FourierPolynomial::from_container)
+                            .zip(
+                                second_row
+                                    .as_tensor()
+                                    .as_slice()
+                                    .chunks(poly_size)
+                                    .map(FourierPolynomial::from_container),
+                            )
+                            .zip(
+                                output_fourier
+                                    .chunks_mut(poly_size)
+                                    .map(FourierPolynomial::from_container),
+                            )
+                        {
+                            output_poly.update_with_two_multiply_accumulate(
+                                &first_ggsw_poly,
+                                &first_fourier,
+                                &second_ggsw_poly,
+                                &second_fourier,
+                            );
+                        }
+                    }
+                    (Some((first_row, first_term)), None) => {
+                        fft.forward_as_integer(&mut first_fourier, &first_term);
+                        for (first_ggsw_poly, mut output_poly) in first_row
+                            .as_tensor()
+                            .as_slice()
+                            .chunks(poly_size)
+                            .map(FourierPolynomial::from_container)
+                            .zip(
+                                output_fourier
+                                    .chunks_mut(poly_size)
+                                    .map(FourierPolynomial::from_container),
+                            )
+                        {
+                            output_poly
+                                .update_with_multiply_accumulate(&first_ggsw_poly, &first_fourier);
+                        }
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        // We bring the result back to the standard domain, and add it to the output.
+        let mut iterator = output
+            .chunks_mut(poly_size)
+            .map(Polynomial::from_container)
+            .zip(
+                output_fourier
+                    .chunks_mut(poly_size)
+                    .map(FourierPolynomial::from_container),
+            );
+        loop {
+            match (iterator.next(), iterator.next()) {
+                (Some((mut first_output, mut first_fourier)), Some(second)) => {
+                    let (mut second_output, mut second_fourier) = second;
+                    fft.add_backward_two_as_torus(
+                        &mut first_output,
+                        &mut second_output,
+                        &mut first_fourier,
+                        &mut second_fourier,
+                    );
+                }
+                (Some((mut first_output, mut first_fourier)), None) => {
+                    fft.add_backward_as_torus(&mut first_output, &mut first_fourier);
+                }
+                _ => break,
+            }
+        }
+    }
+
+    // Performs the blind rotation of the accumulator stored in `lut`.
+    fn small_blind_rotate<C>(&self, lut: &mut GlweCiphertext<Vec<Scalar>>, lwe: &LweCiphertext<C>)
+    where
+        LweCiphertext<C>: AsRefTensor<Element = Scalar>,
+    {
+        let fft = SmallFft::new(self.poly_size);
+        let (lwe_body, lwe_mask) = lwe.get_body_and_mask();
+
+        // We perform the initial clear rotation by performing lut <- lut * X^{-body_hat}
+        lut.as_mut_polynomial_list()
+            .update_with_wrapping_monic_monomial_div(pbs_modulus_switch(
+                lwe_body.0,
+                self.poly_size,
+                ModulusSwitchOffset(0),
+                LutCountLog(0),
+            ));
+
+        let mut rotated = [Scalar::ZERO; SMALL_BOOTSTRAP_BUFFER_LEN];
+        let rotated = &mut rotated[..self.glwe_size.0 * self.poly_size.0];
+        for (lwe_mask_element, bootstrap_key_ggsw) in
+            lwe_mask.mask_element_iter().zip(self.ggsw_iter())
+        {
+            if *lwe_mask_element != Scalar::ZERO {
+                // We compute lut * X^{a_hat} - lut, which is the input of the external product of
+                // the cmux.
+                rotated.copy_from_slice(lut.as_tensor().as_slice());
+                PolynomialList::from_container(&mut *rotated, self.poly_size)
+                    .update_with_wrapping_monic_monomial_mul(pbs_modulus_switch(
+                        *lwe_mask_element,
+                        self.poly_size,
+                        ModulusSwitchOffset(0),
+                        LutCountLog(0),
+                    ));
+                for (rotated_coef, lut_coef) in rotated.iter_mut().zip(lut.as_tensor().iter()) {
+                    *rotated_coef = rotated_coef.wrapping_sub(*lut_coef);
+                }
+                self.small_external_product(
+                    &fft,
+                    lut.as_mut_tensor().as_mut_slice(),
+                    &bootstrap_key_ggsw,
+                    rotated,
+                );
+            }
+        }
+    }
+
+    /// Performs a bootstrap of the input LWE ciphertext, with the small bootstrap path.
+    ///
+    /// The result is the same as the one of [`FourierBootstrapKey::bootstrap`], up to the noise.
+    /// Only the lut buffer of `buffers` is used.
+    ///
+    /// # Note
+    ///
+    /// The path is not selected by [`FourierBootstrapKey::bootstrap`] itself: the engines select
+    /// it with [`supports_small_bootstrap`], according to their bootstrap dispatch strategy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters of the key are not supported by the small path, or if the key uses
+    /// the [`FftPrecision::Split`] precision.
+    pub fn small_bootstrap<C1, C2, C3>(
+        &self,
+        lwe_out: &mut LweCiphertext<C1>,
+        lwe_in: &LweCiphertext<C2>,
+        accumulator: &GlweCiphertext<C3>,
+        buffers: &mut FourierBuffers<Scalar>,
+    ) where
+        LweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<C2>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<C3>: AsRefTensor<Element = Scalar>,
+    {
+        assert!(supports_small_bootstrap(self.poly_size, self.glwe_size));
+        assert_eq!(self.precision, FftPrecision::Standard);
+        let lut = &mut buffers.lut_buffer;
+        lut.as_mut_tensor()
+            .as_mut_slice()
+            .copy_from_slice(accumulator.as_tensor().as_slice());
+        self.small_blind_rotate(lut, lwe_in);
+        constant_sample_extract::<_, Vec<Scalar>, _>(lwe_out, &*lut);
+    }
+
+    /// Performs the blind rotation of an accumulator by an input LWE ciphertext, in place, with
+    /// the small bootstrap path.
+    ///
+    /// The result is the same as the one of [`FourierBootstrapKey::blind_rotate_accumulator`], up
+    /// to the noise. Only the lut buffer of `buffers` is used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters of the key are not supported by the small path, or if the key uses
+    /// the [`FftPrecision::Split`] precision.
+    pub fn small_blind_rotate_accumulator<C1, C2>(
+        &self,
+        accumulator: &mut GlweCiphertext<C1>,
+        lwe_in: &LweCiphertext<C2>,
+        buffers: &mut FourierBuffers<Scalar>,
+    ) where
+        GlweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<C2>: AsRefTensor<Element = Scalar>,
+    {
+        assert!(supports_small_bootstrap(self.poly_size, self.glwe_size));
+        assert_eq!(self.precision, FftPrecision::Standard);
+        let lut = &mut buffers.lut_buffer;
+        lut.as_mut_tensor()
+            .as_mut_slice()
+            .copy_from_slice(accumulator.as_tensor().as_slice());
+        self.small_blind_rotate(lut, lwe_in);
+        accumulator
+            .as_mut_tensor()
+            .as_mut_slice()
+            .copy_from_slice(lut.as_tensor().as_slice());
+    }
+}
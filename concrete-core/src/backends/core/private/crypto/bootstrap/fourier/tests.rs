@@ -8,7 +8,7 @@ use concrete_npe as npe;
 
 use crate::backends::core::private::crypto::bootstrap::fourier::constant_sample_extract;
 use crate::backends::core::private::crypto::bootstrap::{
-    FourierBootstrapKey, FourierBuffers, StandardBootstrapKey,
+    supports_small_bootstrap, FourierBootstrapKey, FourierBuffers, StandardBootstrapKey,
 };
 use crate::backends::core::private::crypto::encoding::{Plaintext, PlaintextList};
 use crate::backends::core::private::crypto::glwe::GlweCiphertext;
@@ -17,7 +17,7 @@ use crate::backends::core::private::crypto::secret::generators::{
     EncryptionRandomGenerator, SecretRandomGenerator,
 };
 use crate::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
-use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::fft::{Complex64, FftPrecision};
 use crate::backends::core::private::math::random::RandomGenerator;
 use crate::backends::core::private::math::tensor::{AsRefTensor, IntoTensor, Tensor};
 use crate::backends::core::private::math::torus::UnsignedTorus;
@@ -308,6 +308,159 @@ fn test_sample_extract<T: UnsignedTorus>() {
     }
 }
 
+fn test_small_bootstrap<T: UnsignedTorus>() {
+    // fix different polynomial degrees and glwe dimensions handled by the small path
+    let parameters = vec![(256, 2), (512, 1), (1024, 1)];
+    let lwe_dimension = LweDimension(20);
+    let level = DecompositionLevelCount(3);
+    let base_log = DecompositionBaseLog(7);
+    let std_dev = LogStandardDev(-25.);
+
+    let mut random_generator = RandomGenerator::new(None);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+
+    for (polynomial_size, glwe_dimension) in parameters {
+        let polynomial_size = PolynomialSize(polynomial_size);
+        let glwe_dimension = GlweDimension(glwe_dimension);
+        assert!(supports_small_bootstrap(
+            polynomial_size,
+            glwe_dimension.to_glwe_size()
+        ));
+
+        // generate the keys
+        let lwe_sk = LweSecretKey::generate_binary(lwe_dimension, &mut secret_generator);
+        let glwe_sk =
+            GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+        let mut coef_bsk = StandardBootstrapKey::allocate(
+            T::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            level,
+            base_log,
+            lwe_dimension,
+        );
+        coef_bsk.fill_with_new_key(&lwe_sk, &glwe_sk, std_dev, &mut encryption_generator);
+        let mut fourier_bsk = FourierBootstrapKey::allocate(
+            Complex64::new(0., 0.),
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            level,
+            base_log,
+            lwe_dimension,
+        );
+        let mut buffers = FourierBuffers::new(fourier_bsk.poly_size, fourier_bsk.glwe_size);
+        fourier_bsk.fill_with_forward_fourier(&coef_bsk, &mut buffers);
+
+        // encrypt a random message, and draw a random accumulator
+        let message = Plaintext(random_generator.random_uniform());
+        let mut lwe_in = LweCiphertext::allocate(T::ZERO, lwe_dimension.to_lwe_size());
+        lwe_sk.encrypt_lwe(&mut lwe_in, &message, std_dev, &mut encryption_generator);
+        let mut accumulator =
+            GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_dimension.to_glwe_size());
+        random_generator.fill_tensor_with_random_uniform(&mut accumulator);
+
+        // bootstrap with both paths
+        let output_size = LweSize(glwe_dimension.0 * polynomial_size.0 + 1);
+        let mut small_output = LweCiphertext::allocate(T::ZERO, output_size);
+        let mut generic_output = LweCiphertext::allocate(T::ZERO, output_size);
+        fourier_bsk.small_bootstrap(&mut small_output, &lwe_in, &accumulator, &mut buffers);
+        fourier_bsk.bootstrap(&mut generic_output, &lwe_in, &accumulator, &mut buffers);
+
+        // the outputs only differ by floating point errors
+        for (small, generic) in small_output
+            .as_tensor()
+            .iter()
+            .zip(generic_output.as_tensor().iter())
+        {
+            let distance = small
+                .wrapping_sub(*generic)
+                .min(generic.wrapping_sub(*small));
+            assert!(distance < T::ONE << (T::BITS - 20));
+        }
+    }
+}
+
+fn external_product_max_error(precision: FftPrecision) -> u64 {
+    let polynomial_size = PolynomialSize(1024);
+    let rlwe_dimension = GlweDimension(1);
+    let level = DecompositionLevelCount(4);
+    let base_log = DecompositionBaseLog(15);
+    let mut random_generator = RandomGenerator::new(None);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+
+    // generate the secret keys
+    let rlwe_sk =
+        GlweSecretKey::generate_binary(rlwe_dimension, polynomial_size, &mut secret_generator);
+    let lwe_sk = LweSecretKey::binary_from_container(vec![1u64]);
+
+    // generate a noiseless key, so that only the errors of the fft remain
+    let mut coef_bsk = StandardBootstrapKey::allocate(
+        0u64,
+        rlwe_dimension.to_glwe_size(),
+        polynomial_size,
+        level,
+        base_log,
+        LweDimension(1),
+    );
+    coef_bsk.fill_with_new_key(&lwe_sk, &rlwe_sk, Variance(0.), &mut encryption_generator);
+    let mut fourier_bsk = FourierBootstrapKey::allocate_with_precision(
+        Complex64::new(0., 0.),
+        rlwe_dimension.to_glwe_size(),
+        polynomial_size,
+        level,
+        base_log,
+        LweDimension(1),
+        precision,
+    );
+    let mut buffers = FourierBuffers::new(fourier_bsk.poly_size, fourier_bsk.glwe_size);
+    fourier_bsk.fill_with_forward_fourier(&coef_bsk, &mut buffers);
+
+    // the external product with an encryption of one must preserve the decrypted input
+    let mut input = GlweCiphertext::allocate(0u64, polynomial_size, rlwe_dimension.to_glwe_size());
+    random_generator.fill_tensor_with_random_uniform(&mut input);
+    let mut output = GlweCiphertext::allocate(0u64, polynomial_size, rlwe_dimension.to_glwe_size());
+    let ggsw = fourier_bsk.ggsw_iter().next().unwrap();
+    fourier_bsk.external_product(
+        &mut output,
+        &ggsw,
+        &input,
+        &mut buffers.fft_buffers,
+        &mut buffers.rounded_buffer,
+    );
+
+    let mut expected = PlaintextList::allocate(0u64, PlaintextCount(polynomial_size.0));
+    rlwe_sk.decrypt_glwe(&mut expected, &input);
+    let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(polynomial_size.0));
+    rlwe_sk.decrypt_glwe(&mut decrypted, &output);
+    decrypted
+        .as_tensor()
+        .iter()
+        .zip(expected.as_tensor().iter())
+        .map(|(d, e)| (d.wrapping_sub(*e) as i64).wrapping_abs() as u64)
+        .max()
+        .unwrap()
+}
+
+#[test]
+pub fn test_split_external_product_u64() {
+    let standard_error = external_product_max_error(FftPrecision::Standard);
+    let split_error = external_product_max_error(FftPrecision::Split);
+    assert!(split_error < 1 << 24);
+    assert!(split_error < standard_error);
+}
+
+#[test]
+pub fn test_double_double_external_product_u64() {
+    // Only the rounding of the input to the decomposition precision remains, which is bounded by
+    // 2^3 for each of the 1 + N coefficients involved in the decryption.
+    let double_double_error = external_product_max_error(FftPrecision::DoubleDouble);
+    let split_error = external_product_max_error(FftPrecision::Split);
+    assert!(double_double_error < 1 << 14);
+    assert!(double_double_error <= split_error);
+}
+
 #[test]
 pub fn test_cmux0_u32() {
     test_cmux_0::<u32>();
@@ -337,3 +490,13 @@ pub fn test_sample_extract_u32() {
 pub fn test_sample_extract_u64() {
     test_sample_extract::<u64>();
 }
+
+#[test]
+pub fn test_small_bootstrap_u32() {
+    test_small_bootstrap::<u32>();
+}
+
+#[test]
+pub fn test_small_bootstrap_u64() {
+    test_small_bootstrap::<u64>();
+}
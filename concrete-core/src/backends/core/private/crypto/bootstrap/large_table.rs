@@ -0,0 +1,112 @@
+//! Lookup of tables larger than an accumulator.
+//!
+//! A table of $2^{h+l}$ entries is cut in $2^h$ chunks, each of which fits in an accumulator. A
+//! tree of CMUXes controlled by $h$ GGSW ciphertexts selects the accumulator of the indexed chunk,
+//! which is then used to bootstrap the LWE ciphertext encrypting the $l$ remaining bits of the
+//! index.
+use concrete_commons::parameters::{GlweSize, PolynomialSize};
+use concrete_fftw::array::AlignedVec;
+
+use crate::backends::core::private::crypto::bootstrap::{FourierBootstrapKey, FourierBuffers};
+use crate::backends::core::private::crypto::ggsw::FourierGgswCiphertext;
+use crate::backends::core::private::crypto::glwe::GlweCiphertext;
+use crate::backends::core::private::crypto::lwe::LweCiphertext;
+use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+/// Returns the trivial accumulator holding the entries of `chunk`.
+///
+/// Every entry is given $N / 2^l$ coefficients, centered on the phase encoding its index with one
+/// bit of padding. The last half box would hold the index $2^l$, which can not be reached: it is
+/// filled with the opposite of the first entry, so that the negacyclic wrapping of the index $0$
+/// stays correct.
+pub fn large_table_accumulator<Scalar>(
+    poly_size: PolynomialSize,
+    glwe_size: GlweSize,
+    chunk: &[Scalar],
+) -> GlweCiphertext<Vec<Scalar>>
+where
+    Scalar: UnsignedTorus,
+{
+    debug_assert!(chunk.len().is_power_of_two() && chunk.len() <= poly_size.0);
+    let box_size = poly_size.0 / chunk.len();
+    let mut accumulator = GlweCiphertext::allocate(Scalar::ZERO, poly_size, glwe_size);
+    for (index, coefficient) in accumulator
+        .get_mut_body()
+        .as_mut_tensor()
+        .iter_mut()
+        .enumerate()
+    {
+        let value = (index + box_size / 2) / box_size;
+        *coefficient = match chunk.get(value) {
+            Some(entry) => *entry,
+            None => chunk[0].wrapping_neg(),
+        };
+    }
+    accumulator
+}
+
+/// Fills `output` with the entry of `table` indexed by `input` and `selectors`.
+///
+/// The `selectors` encrypt the high bits of the index, starting from the least significant one,
+/// and `input` encrypts its low bits with one bit of padding. The table is cut in
+/// `2^selectors.len()` chunks, whose accumulators are reduced pairwise by a CMUX tree, one level
+/// per selector. Since the accumulators are trivial, the noise of the selected accumulator is the
+/// one of a chain of `selectors.len()` CMUXes.
+pub fn large_table_lookup_lwe<Scalar, C1, C2, C3>(
+    output: &mut LweCiphertext<C1>,
+    input: &LweCiphertext<C2>,
+    selectors: &[&FourierGgswCiphertext<C3, Scalar>],
+    table: &[Scalar],
+    bsk: &FourierBootstrapKey<AlignedVec<Complex64>, Scalar>,
+    buffers: &mut FourierBuffers<Scalar>,
+) where
+    Scalar: UnsignedTorus,
+    LweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+    LweCiphertext<C2>: AsRefTensor<Element = Scalar>,
+    FourierGgswCiphertext<C3, Scalar>: AsRefTensor<Element = Complex64>,
+{
+    let (poly_size, glwe_size) = (bsk.polynomial_size(), bsk.glwe_size());
+    let chunk_size = table.len() >> selectors.len();
+    let mut accumulators: Vec<_> = table
+        .chunks(chunk_size)
+        .map(|chunk| large_table_accumulator(poly_size, glwe_size, chunk))
+        .collect();
+    for selector in selectors.iter() {
+        // Each CMUX computes a_0 + s * (a_1 - a_0), the external product adding to its output.
+        accumulators = accumulators
+            .chunks(2)
+            .map(|pair| {
+                let mut selected = pair[0].clone();
+                let mut difference = pair[1].clone();
+                difference
+                    .as_mut_tensor()
+                    .update_with_wrapping_sub(pair[0].as_tensor());
+                selector.external_product(&mut selected, &difference, buffers);
+                selected
+            })
+            .collect();
+    }
+    bsk.bootstrap(output, input, &accumulators[0], buffers);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::backends::core::private::math::tensor::AsRefSlice;
+    use concrete_commons::parameters::GlweDimension;
+
+    #[test]
+    fn test_accumulator_wraps_first_entry() {
+        let chunk = vec![1_u32 << 28, 2 << 28, 3 << 28, 4 << 28];
+        let accumulator =
+            large_table_accumulator(PolynomialSize(32), GlweDimension(1).to_glwe_size(), &chunk);
+        let body = accumulator.get_body();
+        let body = body.as_tensor().as_slice();
+        assert_eq!(&body[..4], &[chunk[0]; 4]);
+        assert_eq!(&body[4..12], &[chunk[1]; 8]);
+        assert_eq!(&body[20..28], &[chunk[3]; 8]);
+        assert_eq!(&body[28..], &[chunk[0].wrapping_neg(); 4]);
+    }
+}
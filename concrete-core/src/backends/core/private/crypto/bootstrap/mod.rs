@@ -3,10 +3,26 @@
 //! The bootstrapping operation allows to reduce the level of noise in an LWE ciphertext, while
 //! evaluating an univariate function.
 
-pub use fourier::{FourierBootstrapKey, FourierBuffers};
+pub use accumulation::{accumulation_encoding_is_supported, reset_accumulating_lwe};
+#[cfg(feature = "multithread")]
+pub use batch::{par_bootstrap_lwe_list, par_bootstrap_lwe_list_in_chunks};
+pub use bit_reduction::{bit_reduction_accumulator, discard_reduce_bits_lwe_list, BitReduction};
+pub use extremum::{discard_extremum_lwe_list, relu_accumulator};
+pub use fourier::{
+    supports_small_bootstrap, FourierBootstrapKey, FourierBuffers, SMALL_BOOTSTRAP_MAX_GLWE_SIZE,
+};
+pub use large_table::{large_table_accumulator, large_table_lookup_lwe};
+pub use seeded::StandardSeededBootstrapKey;
 pub use standard::StandardBootstrapKey;
 
+mod accumulation;
+#[cfg(feature = "multithread")]
+mod batch;
+mod bit_reduction;
+mod extremum;
 pub(crate) mod fourier;
+mod large_table;
+mod seeded;
 mod standard;
 
 #[cfg(all(test, feature = "multithread"))]
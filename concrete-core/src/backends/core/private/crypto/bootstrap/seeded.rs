@@ -0,0 +1,322 @@
+use crate::backends::core::private::crypto::bootstrap::StandardBootstrapKey;
+use crate::backends::core::private::crypto::encoding::Plaintext;
+use crate::backends::core::private::crypto::ggsw::StandardGgswSeededCiphertext;
+use crate::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::backends::core::private::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::backends::core::private::utils::{zip, zip_args};
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
+};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A seeded bootstrapping key represented in the standard domain.
+///
+/// A seeded bootstrapping key is made of seeded GGSW ciphertexts: it only stores the bodies of
+/// the GLWE ciphertexts composing the key, along with the seed of the generator producing their
+/// masks, which divides its size by the GLWE size. The generator is forked into one generator per
+/// GGSW ciphertext, exactly as when filling a [`StandardBootstrapKey`].
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct StandardSeededBootstrapKey<Cont> {
+    tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+    rlwe_size: GlweSize,
+    decomp_level: DecompositionLevelCount,
+    decomp_base_log: DecompositionBaseLog,
+    seed: u128,
+}
+
+tensor_traits!(StandardSeededBootstrapKey);
+
+impl<Scalar> StandardSeededBootstrapKey<Vec<Scalar>> {
+    /// Allocates a new seeded bootstrapping key whose body coefficients are all `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::bootstrap::StandardSeededBootstrapKey;
+    /// let bsk = StandardSeededBootstrapKey::allocate(
+    ///     9u32,
+    ///     GlweSize(7),
+    ///     PolynomialSize(9),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(4),
+    ///     42,
+    /// );
+    /// assert_eq!(bsk.polynomial_size(), PolynomialSize(9));
+    /// assert_eq!(bsk.glwe_size(), GlweSize(7));
+    /// assert_eq!(bsk.level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(bsk.base_log(), DecompositionBaseLog(5));
+    /// assert_eq!(bsk.key_size(), LweDimension(4));
+    /// assert_eq!(bsk.seed(), 42);
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        rlwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        key_size: LweDimension,
+        seed: u128,
+    ) -> StandardSeededBootstrapKey<Vec<Scalar>>
+    where
+        Scalar: UnsignedTorus,
+    {
+        StandardSeededBootstrapKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                key_size.0
+                    * decomp_level.0
+                    * rlwe_size.0
+                    * poly_size.0
+            ]),
+            poly_size,
+            rlwe_size,
+            decomp_level,
+            decomp_base_log,
+            seed,
+        }
+    }
+}
+
+impl<Cont> StandardSeededBootstrapKey<Cont> {
+    /// Returns the size of the polynomials used in the bootstrapping key.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the size of the GLWE ciphertexts used in the bootstrapping key.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.rlwe_size
+    }
+
+    /// Returns the number of levels used to decompose the key bits.
+    pub fn level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level
+    }
+
+    /// Returns the logarithm of the base used to decompose the key bits.
+    pub fn base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the size of the LWE encrypted key.
+    pub fn key_size(&self) -> LweDimension
+    where
+        Self: AsRefTensor,
+    {
+        ck_dim_div!(self.as_tensor().len() =>
+            self.rlwe_size.0,
+            self.poly_size.0,
+            self.decomp_level.0
+        );
+        LweDimension(
+            self.as_tensor().len() / (self.rlwe_size.0 * self.poly_size.0 * self.decomp_level.0),
+        )
+    }
+
+    /// Returns the seed of the generator producing the masks of the bootstrapping key.
+    pub fn seed(&self) -> u128 {
+        self.seed
+    }
+
+    /// Returns the ratio between the size of the expanded bootstrapping key, and the size of the
+    /// seeded bootstrapping key, seed included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::bootstrap::StandardSeededBootstrapKey;
+    /// let bsk = StandardSeededBootstrapKey::allocate(
+    ///     0u64,
+    ///     GlweSize(2),
+    ///     PolynomialSize(1024),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    ///     LweDimension(630),
+    ///     0,
+    /// );
+    /// assert!(bsk.compression_ratio() > 1.99 && bsk.compression_ratio() < 2.);
+    /// ```
+    pub fn compression_ratio(&self) -> f64
+    where
+        Self: AsRefTensor,
+    {
+        let element_bytes = std::mem::size_of::<<Self as AsRefTensor>::Element>();
+        let seeded_bytes = self.as_tensor().len() * element_bytes + std::mem::size_of::<u128>();
+        let expanded_bytes = self.as_tensor().len() * self.rlwe_size.0 * element_bytes;
+        expanded_bytes as f64 / seeded_bytes as f64
+    }
+
+    // Returns an iterator over the seeded GGSW ciphertexts composing the key. Their seed is not
+    // used, since their masks are generated from a generator forked from the seed of the key.
+    fn ggsw_iter(
+        &self,
+    ) -> impl Iterator<Item = StandardGgswSeededCiphertext<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        let chunks_size = self.rlwe_size.0 * self.poly_size.0 * self.decomp_level.0;
+        let (rlwe_size, poly_size, base_log) =
+            (self.rlwe_size, self.poly_size, self.decomp_base_log);
+        self.as_tensor()
+            .subtensor_iter(chunks_size)
+            .map(move |tensor| {
+                StandardGgswSeededCiphertext::from_container(
+                    tensor.into_container(),
+                    rlwe_size,
+                    poly_size,
+                    base_log,
+                    0,
+                )
+            })
+    }
+
+    // Returns an iterator over the mutable seeded GGSW ciphertexts composing the key.
+    fn ggsw_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = StandardGgswSeededCiphertext<&mut [<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+    {
+        let chunks_size = self.rlwe_size.0 * self.poly_size.0 * self.decomp_level.0;
+        let (rlwe_size, poly_size, base_log) =
+            (self.rlwe_size, self.poly_size, self.decomp_base_log);
+        self.as_mut_tensor()
+            .subtensor_iter_mut(chunks_size)
+            .map(move |tensor| {
+                StandardGgswSeededCiphertext::from_container(
+                    tensor.into_container(),
+                    rlwe_size,
+                    poly_size,
+                    base_log,
+                    0,
+                )
+            })
+    }
+
+    /// Generates a new seeded bootstrap key from the input parameters, and fills the current
+    /// container with it.
+    ///
+    /// The masks are generated from the seed of the key, so that they can be regenerated at
+    /// expansion time, while the noise is sampled from a fresh generator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::bootstrap::{
+    ///     StandardBootstrapKey, StandardSeededBootstrapKey,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    ///
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(9));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let mut seeded = StandardSeededBootstrapKey::allocate(
+    ///     0u32,
+    ///     glwe_dim.to_glwe_size(),
+    ///     poly_size,
+    ///     dec_lc,
+    ///     dec_bl,
+    ///     lwe_dim,
+    ///     42,
+    /// );
+    /// let lwe_sk = LweSecretKey::generate_binary(lwe_dim, &mut secret_generator);
+    /// let glwe_sk = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    /// seeded.fill_with_new_key(
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     LogStandardDev::from_log_standard_dev(-15.),
+    /// );
+    /// let mut bsk = StandardBootstrapKey::allocate(
+    ///     0u32,
+    ///     glwe_dim.to_glwe_size(),
+    ///     poly_size,
+    ///     dec_lc,
+    ///     dec_bl,
+    ///     lwe_dim,
+    /// );
+    /// seeded.expand_into(&mut bsk);
+    /// ```
+    pub fn fill_with_new_key<LweCont, RlweCont, Scalar>(
+        &mut self,
+        lwe_secret_key: &LweSecretKey<BinaryKeyKind, LweCont>,
+        glwe_secret_key: &GlweSecretKey<BinaryKeyKind, RlweCont>,
+        noise_parameters: impl DispersionParameter,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, LweCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, RlweCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.key_size().0 => lwe_secret_key.key_size().0);
+        let mut generator = EncryptionRandomGenerator::new(Some(self.seed));
+        let gen_iter = generator
+            .fork_bsk_to_ggsw::<Scalar>(
+                lwe_secret_key.key_size(),
+                self.decomp_level,
+                glwe_secret_key.key_size().to_glwe_size(),
+                self.poly_size,
+            )
+            .expect("Failed to fork generator");
+        for zip_args!(mut ggsw, sk_scalar, mut generator) in zip!(
+            self.ggsw_iter_mut(),
+            lwe_secret_key.as_tensor().iter(),
+            gen_iter
+        ) {
+            glwe_secret_key.encrypt_constant_seeded_ggsw_with_generator(
+                &mut ggsw,
+                &Plaintext(*sk_scalar),
+                noise_parameters,
+                &mut generator,
+            );
+        }
+    }
+
+    /// Expands the seeded bootstrap key into a standard bootstrap key, by regenerating the masks
+    /// from the seed.
+    pub fn expand_into<OutCont, Scalar>(&self, output: &mut StandardBootstrapKey<OutCont>)
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        StandardBootstrapKey<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.glwe_size() => output.glwe_size());
+        ck_dim_eq!(self.polynomial_size() => output.polynomial_size());
+        ck_dim_eq!(self.level_count() => output.level_count());
+        ck_dim_eq!(self.key_size() => output.key_size());
+        let mut generator = EncryptionRandomGenerator::new(Some(self.seed));
+        let gen_iter = generator
+            .fork_bsk_to_ggsw::<Scalar>(
+                self.key_size(),
+                self.decomp_level,
+                self.rlwe_size,
+                self.poly_size,
+            )
+            .expect("Failed to fork generator");
+        for zip_args!(seeded, mut ggsw, mut generator) in
+            zip!(self.ggsw_iter(), output.ggsw_iter_mut(), gen_iter)
+        {
+            seeded.expand_into_with_generator(&mut ggsw, &mut generator);
+        }
+    }
+}
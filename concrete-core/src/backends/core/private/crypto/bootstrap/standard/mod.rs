@@ -16,8 +16,11 @@ use concrete_commons::parameters::{
 };
 #[cfg(feature = "multithread")]
 use rayon::{iter::IndexedParallelIterator, prelude::*};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
 
 /// A bootstrapping key represented in the standard domain.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct StandardBootstrapKey<Cont> {
     tensor: Tensor<Cont>,
@@ -180,6 +183,36 @@ impl<Cont> StandardBootstrapKey<Cont> {
         LweSecretKey<BinaryKeyKind, LweCont>: AsRefTensor<Element = Scalar>,
         GlweSecretKey<BinaryKeyKind, RlweCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
+    {
+        self.fill_with_new_key_monitored(
+            lwe_secret_key,
+            glwe_secret_key,
+            noise_parameters,
+            generator,
+            |_| true,
+        );
+    }
+
+    /// Fills the current bootstrap key container with an actual bootstrapping key, reporting the
+    /// progress of the generation to a monitor.
+    ///
+    /// The `monitor` closure is called with the number of GGSW ciphertexts generated so far, after
+    /// each one of them. The generation stops as soon as it returns `false`, in which case the key
+    /// is left partially filled, and `false` is returned.
+    pub fn fill_with_new_key_monitored<LweCont, RlweCont, Scalar, Monitor>(
+        &mut self,
+        lwe_secret_key: &LweSecretKey<BinaryKeyKind, LweCont>,
+        glwe_secret_key: &GlweSecretKey<BinaryKeyKind, RlweCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+        mut monitor: Monitor,
+    ) -> bool
+    where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, LweCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, RlweCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Monitor: FnMut(usize) -> bool,
     {
         ck_dim_eq!(self.key_size().0 => lwe_secret_key.key_size().0);
         self.as_mut_tensor()
@@ -192,11 +225,13 @@ impl<Cont> StandardBootstrapKey<Cont> {
                 self.poly_size,
             )
             .expect("Failed to fork generator");
-        for zip_args!(mut rgsw, sk_scalar, mut generator) in zip!(
+        for (index, zip_args!(mut rgsw, sk_scalar, mut generator)) in zip!(
             self.ggsw_iter_mut(),
             lwe_secret_key.as_tensor().iter(),
             gen_iter
-        ) {
+        )
+        .enumerate()
+        {
             let encoded = Plaintext(*sk_scalar);
             glwe_secret_key.encrypt_constant_ggsw(
                 &mut rgsw,
@@ -204,7 +239,11 @@ impl<Cont> StandardBootstrapKey<Cont> {
                 noise_parameters,
                 &mut generator,
             );
+            if !monitor(index + 1) {
+                return false;
+            }
         }
+        true
     }
 
     /// Generate a new bootstrap key from the input parameters, and fills the current container
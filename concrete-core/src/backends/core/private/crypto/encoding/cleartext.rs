@@ -3,12 +3,16 @@ use crate::backends::core::private::math::tensor::{
 };
 use concrete_commons::numeric::Numeric;
 use concrete_commons::parameters::CleartextCount;
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
 
 /// A clear, non-encoded, value.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Cleartext<T: Numeric>(pub T);
 
 /// A list of clear, non-encoded, values.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct CleartextList<Cont> {
     tensor: Tensor<Cont>,
@@ -4,13 +4,17 @@ use crate::backends::core::private::math::tensor::{
 };
 use concrete_commons::numeric::Numeric;
 use concrete_commons::parameters::PlaintextCount;
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
 
 /// An plaintext (encoded) value.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct Plaintext<T: Numeric>(pub T);
 
 /// A list of plaintexts
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct PlaintextList<Cont> {
     pub(crate) tensor: Tensor<Cont>,
@@ -509,6 +509,73 @@ impl<Cont, Scalar> FourierGgswCiphertext<Cont, Scalar> {
         }
     }
 
+    /// Fills a GGSW ciphertext in coefficient domain with the inverse fourier transform of a GGSW
+    /// ciphertext in the fourier domain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::bootstrap::FourierBuffers;
+    /// use concrete_core::backends::core::private::crypto::ggsw::{
+    ///     FourierGgswCiphertext, StandardGgswCiphertext,
+    /// };
+    /// use concrete_core::backends::core::private::math::fft::Complex64;
+    /// use concrete_core::backends::core::private::math::random::RandomGenerator;
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    ///
+    /// let (poly_size, glwe_size) = (PolynomialSize(256), GlweSize(3));
+    /// let (level_count, base_log) = (DecompositionLevelCount(2), DecompositionBaseLog(7));
+    /// let mut generator = RandomGenerator::new(None);
+    /// let mut ggsw =
+    ///     StandardGgswCiphertext::allocate(0u32, poly_size, glwe_size, level_count, base_log);
+    /// generator.fill_tensor_with_random_uniform(&mut ggsw);
+    /// let mut fourier_ggsw = FourierGgswCiphertext::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     poly_size,
+    ///     glwe_size,
+    ///     level_count,
+    ///     base_log,
+    /// );
+    /// let mut buffers = FourierBuffers::new(poly_size, glwe_size);
+    /// fourier_ggsw.fill_with_forward_fourier(&ggsw, &mut buffers);
+    ///
+    /// let mut output =
+    ///     StandardGgswCiphertext::allocate(0u32, poly_size, glwe_size, level_count, base_log);
+    /// fourier_ggsw.fill_with_backward_fourier(&mut output, &mut buffers);
+    /// assert_eq!(output.as_tensor(), ggsw.as_tensor());
+    /// ```
+    pub fn fill_with_backward_fourier<OutputCont>(
+        &self,
+        coef_ggsw: &mut StandardGgswCiphertext<OutputCont>,
+        buffers: &mut FourierBuffers<Scalar>,
+    ) where
+        Cont: AsRefSlice<Element = Complex64>,
+        StandardGgswCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.poly_size.0 => coef_ggsw.polynomial_size().0);
+        ck_dim_eq!(self.tensor.len() => coef_ggsw.as_tensor().len());
+
+        // We retrieve a buffer for the fft.
+        let fft_buffer = &mut buffers.fft_buffers.first_buffer;
+        let fft = &mut buffers.fft_buffers.fft;
+
+        // The backward transform overwrites its input, so every polynomial is copied to the
+        // buffer before being moved back to the coefficient domain.
+        let iterator = coef_ggsw
+            .as_mut_tensor()
+            .subtensor_iter_mut(self.poly_size.0)
+            .map(|t| Polynomial::from_container(t.into_container()))
+            .zip(self.tensor.subtensor_iter(self.poly_size.0));
+        for (mut coef_poly, fourier_poly) in iterator {
+            fft_buffer.as_mut_tensor().fill_with_copy(&fourier_poly);
+            fft.backward_as_torus(&mut coef_poly, fft_buffer);
+        }
+    }
+
     pub fn external_product<C1, C2>(
         &self,
         output: &mut GlweCiphertext<C1>,
@@ -8,3 +8,9 @@ pub use fourier::*;
 
 mod levels;
 pub use levels::*;
+
+mod seeded;
+pub use seeded::*;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,235 @@
+use crate::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::backends::core::private::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::StandardGgswCiphertext;
+
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A seeded GGSW ciphertext.
+///
+/// A seeded GGSW ciphertext only stores the bodies of its GLWE ciphertexts, along with the seed of
+/// the generator producing their masks, which divides its size by the GLWE size. The masks being
+/// uniformly random, the message is entirely carried by the bodies (see
+/// [`GlweSecretKey::encrypt_constant_seeded_ggsw`](`crate::backends::core::private::crypto::secret::GlweSecretKey::encrypt_constant_seeded_ggsw`)).
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct StandardGgswSeededCiphertext<Cont> {
+    tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+    rlwe_size: GlweSize,
+    decomp_base_log: DecompositionBaseLog,
+    seed: u128,
+}
+
+tensor_traits!(StandardGgswSeededCiphertext);
+
+impl<Scalar> StandardGgswSeededCiphertext<Vec<Scalar>> {
+    /// Allocates a new seeded GGSW ciphertext whose body coefficients are all `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::ggsw::StandardGgswSeededCiphertext;
+    ///
+    /// let ggsw = StandardGgswSeededCiphertext::allocate(
+    ///     9 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    ///     42,
+    /// );
+    /// assert_eq!(ggsw.glwe_size(), GlweSize(7));
+    /// assert_eq!(ggsw.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(ggsw.decomposition_base_log(), DecompositionBaseLog(4));
+    /// assert_eq!(ggsw.seed(), 42);
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        poly_size: PolynomialSize,
+        rlwe_size: GlweSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        seed: u128,
+    ) -> Self
+    where
+        Scalar: Copy,
+    {
+        StandardGgswSeededCiphertext {
+            tensor: Tensor::from_container(vec![value; decomp_level.0 * rlwe_size.0 * poly_size.0]),
+            poly_size,
+            rlwe_size,
+            decomp_base_log,
+            seed,
+        }
+    }
+}
+
+impl<Cont> StandardGgswSeededCiphertext<Cont> {
+    /// Creates a seeded GGSW ciphertext from an existing container of bodies.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::ggsw::StandardGgswSeededCiphertext;
+    ///
+    /// let ggsw = StandardGgswSeededCiphertext::from_container(
+    ///     vec![9 as u8; 7 * 10 * 3],
+    ///     GlweSize(7),
+    ///     PolynomialSize(10),
+    ///     DecompositionBaseLog(4),
+    ///     42,
+    /// );
+    /// assert_eq!(ggsw.glwe_size(), GlweSize(7));
+    /// assert_eq!(ggsw.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(ggsw.decomposition_base_log(), DecompositionBaseLog(4));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        rlwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        seed: u128,
+    ) -> Self
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() => rlwe_size.0, poly_size.0, rlwe_size.0 * poly_size.0);
+        StandardGgswSeededCiphertext {
+            tensor,
+            poly_size,
+            rlwe_size,
+            decomp_base_log,
+            seed,
+        }
+    }
+
+    /// Returns the size of the glwe ciphertexts composing the ggsw ciphertext.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.rlwe_size
+    }
+
+    /// Returns the number of decomposition levels used in the ciphertext.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount
+    where
+        Self: AsRefTensor,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.rlwe_size.0, self.poly_size.0);
+        DecompositionLevelCount(self.as_tensor().len() / (self.rlwe_size.0 * self.poly_size.0))
+    }
+
+    /// Returns the size of the polynomials used in the ciphertext.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the logarithm of the base used for the gadget decomposition.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the seed of the generator producing the masks of the ciphertext.
+    pub fn seed(&self) -> u128 {
+        self.seed
+    }
+
+    /// Returns the ratio between the size of the expanded ciphertext, and the size of the seeded
+    /// ciphertext, seed included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::ggsw::StandardGgswSeededCiphertext;
+    ///
+    /// let ggsw = StandardGgswSeededCiphertext::allocate(
+    ///     0 as u64,
+    ///     PolynomialSize(1024),
+    ///     GlweSize(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    ///     0,
+    /// );
+    /// assert!(ggsw.compression_ratio() > 1.99 && ggsw.compression_ratio() < 2.);
+    /// ```
+    pub fn compression_ratio(&self) -> f64
+    where
+        Self: AsRefTensor,
+    {
+        let element_bytes = std::mem::size_of::<<Self as AsRefTensor>::Element>();
+        let seeded_bytes = self.as_tensor().len() * element_bytes + std::mem::size_of::<u128>();
+        let expanded_bytes = self.as_tensor().len() * self.rlwe_size.0 * element_bytes;
+        expanded_bytes as f64 / seeded_bytes as f64
+    }
+
+    /// Expands the seeded ciphertext into a standard GGSW ciphertext, by regenerating the masks
+    /// from the seed.
+    ///
+    /// The masks are regenerated by forking the generator exactly as in
+    /// [`GlweSecretKey::encrypt_constant_seeded_ggsw`](`crate::backends::core::private::crypto::secret::GlweSecretKey::encrypt_constant_seeded_ggsw`).
+    pub fn expand_into<OutCont, Scalar>(&self, output: &mut StandardGgswCiphertext<OutCont>)
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        StandardGgswCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        OutCont: AsMutSlice<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let mut generator = EncryptionRandomGenerator::new(Some(self.seed));
+        self.expand_into_with_generator(output, &mut generator);
+    }
+
+    // Expands the seeded ciphertext, regenerating the masks with `generator` instead of a
+    // generator seeded with the seed of the ciphertext. This is the counterpart of
+    // `GlweSecretKey::encrypt_constant_seeded_ggsw_with_generator`.
+    pub(crate) fn expand_into_with_generator<OutCont, Scalar>(
+        &self,
+        output: &mut StandardGgswCiphertext<OutCont>,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        StandardGgswCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        OutCont: AsMutSlice<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.glwe_size() => output.glwe_size());
+        ck_dim_eq!(self.polynomial_size() => output.polynomial_size());
+        ck_dim_eq!(self.decomposition_level_count() => output.decomposition_level_count());
+        let gen_iter = generator
+            .fork_ggsw_to_ggsw_levels::<Scalar>(
+                self.decomposition_level_count(),
+                self.rlwe_size,
+                self.poly_size,
+            )
+            .expect("Failed to split generator into ggsw levels");
+        let mut bodies = self.as_tensor().subtensor_iter(self.poly_size.0);
+        for (mut matrix, mut generator) in output.level_matrix_iter_mut().zip(gen_iter) {
+            let gen_iter = generator
+                .fork_ggsw_level_to_glwe::<Scalar>(self.rlwe_size, self.poly_size)
+                .expect("Failed to split generator into rlwe");
+            for (row, mut generator) in matrix.row_iter_mut().zip(gen_iter) {
+                let mut rlwe_ct = row.into_glwe();
+                let (mut body, mut masks) = rlwe_ct.get_mut_body_and_mask();
+                generator.fill_tensor_with_random_mask(&mut masks);
+                body.as_mut_tensor()
+                    .fill_with_copy(&bodies.next().expect("Missing body"));
+            }
+        }
+    }
+}
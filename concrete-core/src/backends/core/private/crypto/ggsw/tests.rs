@@ -0,0 +1,101 @@
+use crate::backends::core::private::crypto::encoding::{Plaintext, PlaintextList};
+use crate::backends::core::private::crypto::ggsw::{
+    StandardGgswCiphertext, StandardGgswSeededCiphertext,
+};
+use crate::backends::core::private::crypto::secret::generators::{
+    EncryptionRandomGenerator, SecretRandomGenerator,
+};
+use crate::backends::core::private::crypto::secret::GlweSecretKey;
+use crate::backends::core::private::math::random::RandomGenerator;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use concrete_commons::dispersion::LogStandardDev;
+use concrete_commons::numeric::Numeric;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PlaintextCount, PolynomialSize,
+};
+
+fn test_seeded_ggsw<T: UnsignedTorus>() {
+    let glwe_dimension = GlweDimension(2);
+    let polynomial_size = PolynomialSize(256);
+    let level = DecompositionLevelCount(3);
+    let base_log = DecompositionBaseLog(7);
+    let std_dev = LogStandardDev(-25.);
+
+    let mut random_generator = RandomGenerator::new(None);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let key =
+        GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+    let seed: u64 = random_generator.random_uniform();
+    let message = Plaintext(T::ONE);
+
+    // A seeded ciphertext, and its expansion.
+    let mut seeded = StandardGgswSeededCiphertext::allocate(
+        T::ZERO,
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        level,
+        base_log,
+        seed as u128,
+    );
+    key.encrypt_constant_seeded_ggsw(&mut seeded, &message, std_dev);
+    let mut expanded = StandardGgswCiphertext::allocate(
+        T::ZERO,
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        level,
+        base_log,
+    );
+    seeded.expand_into(&mut expanded);
+
+    // A standard ciphertext encrypted with a mask generator seeded with the same seed.
+    let mut reference = StandardGgswCiphertext::allocate(
+        T::ZERO,
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        level,
+        base_log,
+    );
+    let mut encryption_generator = EncryptionRandomGenerator::new(Some(seed as u128));
+    key.encrypt_constant_ggsw(&mut reference, &message, std_dev, &mut encryption_generator);
+
+    let mut expanded_phase = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    let mut reference_phase = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    for (index, (expanded_ct, reference_ct)) in expanded
+        .as_glwe_list()
+        .ciphertext_iter()
+        .zip(reference.as_glwe_list().ciphertext_iter())
+        .enumerate()
+    {
+        // The masks of the last rows do not contain the message, and are regenerated identically.
+        if index % glwe_dimension.to_glwe_size().0 == glwe_dimension.0 {
+            assert_eq!(
+                expanded_ct.get_mask().as_tensor().as_slice(),
+                reference_ct.get_mask().as_tensor().as_slice()
+            );
+        }
+        // The phases only differ by the noise.
+        key.decrypt_glwe(&mut expanded_phase, &expanded_ct);
+        key.decrypt_glwe(&mut reference_phase, &reference_ct);
+        for (a, b) in expanded_phase
+            .as_tensor()
+            .iter()
+            .zip(reference_phase.as_tensor().iter())
+        {
+            let distance = a.wrapping_sub(*b).min(b.wrapping_sub(*a));
+            assert!(distance < T::ONE << (<T as Numeric>::BITS - 16));
+        }
+    }
+
+    assert_eq!(seeded.as_tensor().len() * 3, expanded.as_tensor().len());
+}
+
+#[test]
+fn test_seeded_ggsw_u32() {
+    test_seeded_ggsw::<u32>()
+}
+
+#[test]
+fn test_seeded_ggsw_u64() {
+    test_seeded_ggsw::<u64>()
+}
@@ -0,0 +1,466 @@
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialCount,
+    PolynomialSize,
+};
+
+use crate::backends::core::private::crypto::encoding::PlaintextList;
+use crate::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::backends::core::private::crypto::secret::GlweSecretKey;
+use crate::backends::core::private::math::decomposition::{
+    torus_small_sign_decompose, DecompositionLevel, DecompositionTerm, SignedDecomposer,
+};
+use crate::backends::core::private::math::polynomial::{Polynomial, PolynomialList};
+use crate::backends::core::private::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::{GlweCiphertext, GlweList};
+
+/// A set of automorphism keys.
+///
+/// For a polynomial size $N$, the automorphisms of $\mathbb{Z}\[X\] / (X^N + 1)$ are the maps
+/// $\tau\_g: P(X) \mapsto P(X^g)$, for an odd exponent $g$. Applying $\tau\_g$ to every polynomial
+/// of a GLWE ciphertext encrypted under $S$ gives a ciphertext of $\tau\_g(M)$ under $\tau\_g(S)$.
+/// An automorphism key is a GLWE keyswitching key from $\tau\_g(S)$ back to $S$.
+///
+/// This structure contains the keys of the $\log\_2(N)$ automorphisms $\tau\_{N/2^a + 1}$, for
+/// $a \in \[0, \log\_2(N))$, which are the ones needed to evaluate the trace of a GLWE ciphertext
+/// (see [`GlweAutomorphismKey::trace`]). For every automorphism, and every polynomial $S\_i$ of the
+/// key, the $l$ levels of the decomposition of $\tau\_g(S\_i)$ are encrypted under $S$.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweAutomorphismKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+}
+
+tensor_traits!(GlweAutomorphismKey);
+
+impl<Scalar> GlweAutomorphismKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a set of automorphism keys whose masks and bodies are all `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate automorphism keys, but merely allocates a container of
+    /// the right size. See [`GlweAutomorphismKey::fill_with_automorphism_key`] to fill the
+    /// container with proper keys.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweAutomorphismKey;
+    /// let key = GlweAutomorphismKey::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(key.automorphism_count(), 8);
+    /// assert_eq!(key.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(key.decomposition_base_log(), DecompositionBaseLog(7));
+    /// assert_eq!(key.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(key.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        decomp_level_count: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Self {
+        debug_assert!(polynomial_size.0.is_power_of_two() && polynomial_size.0 > 1);
+        let automorphism_count = polynomial_size.0.trailing_zeros() as usize;
+        GlweAutomorphismKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                automorphism_count
+                    * glwe_dimension.0
+                    * decomp_level_count.0
+                    * glwe_dimension.to_glwe_size().0
+                    * polynomial_size.0
+            ]),
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size: glwe_dimension.to_glwe_size(),
+            polynomial_size,
+        }
+    }
+}
+
+impl<Cont> GlweAutomorphismKey<Cont> {
+    /// Returns the dimension of the GLWE key.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials of the GLWE key.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    /// Returns the number of levels used for the decomposition of the automorphed key.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the automorphed key.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the number of automorphisms whose keys are contained, that is $\log\_2(N)$.
+    pub fn automorphism_count(&self) -> usize {
+        self.polynomial_size.0.trailing_zeros() as usize
+    }
+
+    /// Fills the current container with the automorphism keys of a GLWE secret key.
+    ///
+    /// This is equivalent to [`GlweAutomorphismKey::fill_with_automorphism_key_monitored`] with a
+    /// monitor which never stops the generation.
+    pub fn fill_with_automorphism_key<KeyCont, Scalar>(
+        &mut self,
+        glwe_key: &GlweSecretKey<BinaryKeyKind, KeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        self.fill_with_automorphism_key_monitored(glwe_key, noise_parameters, generator, |_| true);
+    }
+
+    /// Fills the current container with the automorphism keys of a GLWE secret key, reporting the
+    /// progress of the generation to a monitor.
+    ///
+    /// The `monitor` closure is called with the number of automorphism keys generated so far,
+    /// after each one of them. The generation stops as soon as it returns `false`, in which case
+    /// the keys are left partially filled, and `false` is returned.
+    pub fn fill_with_automorphism_key_monitored<KeyCont, Scalar, Monitor>(
+        &mut self,
+        glwe_key: &GlweSecretKey<BinaryKeyKind, KeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+        mut monitor: Monitor,
+    ) -> bool
+    where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Monitor: FnMut(usize) -> bool,
+    {
+        ck_dim_eq!(self.glwe_dimension().0 => glwe_key.key_size().0);
+        ck_dim_eq!(self.polynomial_size.0 => glwe_key.polynomial_size().0);
+
+        let polynomial_size = self.polynomial_size;
+        let decomp_level_count = self.decomp_level_count;
+        let decomp_base_log = self.decomp_base_log;
+        let glwe_dimension = self.glwe_dimension();
+
+        // The buffers holding the automorphed key, and the messages of a single automorphism key.
+        let mut automorphed_key = Polynomial::allocate(Scalar::ZERO, polynomial_size);
+        let mut messages = PlaintextList::from_container(vec![
+            Scalar::ZERO;
+            glwe_dimension.0
+                * decomp_level_count.0
+                * polynomial_size.0
+        ]);
+
+        for (index, mut keys) in self.automorphism_key_iter_mut().enumerate() {
+            let exponent = (polynomial_size.0 >> index) + 1;
+            for (key_polynomial, mut key_messages) in
+                glwe_key.as_polynomial_list().polynomial_iter().zip(
+                    messages
+                        .as_mut_tensor()
+                        .subtensor_iter_mut(decomp_level_count.0 * polynomial_size.0),
+                )
+            {
+                apply_automorphism(
+                    automorphed_key.as_mut_tensor().as_mut_slice(),
+                    key_polynomial.as_tensor().as_slice(),
+                    exponent,
+                );
+                // We fill the messages with the levels of the decomposition of the automorphed
+                // key polynomial. Its coefficients are either 0, 1 or -1.
+                for (level, mut message) in (1..=decomp_level_count.0)
+                    .map(DecompositionLevel)
+                    .zip(key_messages.subtensor_iter_mut(polynomial_size.0))
+                {
+                    let summand = DecompositionTerm::new(level, decomp_base_log, Scalar::ONE)
+                        .to_recomposition_summand();
+                    message.fill_with_one(automorphed_key.as_tensor(), |coefficient| {
+                        coefficient.wrapping_mul(summand)
+                    });
+                }
+            }
+
+            glwe_key.encrypt_glwe_list(&mut keys, &messages, noise_parameters, generator);
+
+            if !monitor(index + 1) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Computes the trace of a GLWE ciphertext.
+    ///
+    /// The trace of a polynomial $M$ is the sum of its images by all the automorphisms
+    /// $\tau\_g$, for an odd $g$ in $\[1, 2N)$. Since this sum is $N m\_0$ for the monomial of degree
+    /// zero, and zero for every other monomial, the `output` ciphertext encrypts the constant
+    /// polynomial $N m\_0$, where $m\_0$ is the constant coefficient of the plaintext of `input`.
+    ///
+    /// The trace is evaluated with $\log\_2(N)$ automorphisms: for $a$ from $0$ to $\log\_2(N) - 1$,
+    /// the ciphertext $C$ is replaced by $C + \tau\_{N/2^a + 1}(C)$, whose automorphed part is
+    /// keyswitched back to the original key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PlaintextCount,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::encoding::PlaintextList;
+    /// use concrete_core::backends::core::private::crypto::glwe::{
+    ///     GlweAutomorphismKey, GlweCiphertext,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::GlweSecretKey;
+    /// use concrete_core::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+    ///
+    /// let polynomial_size = PolynomialSize(256);
+    /// let glwe_dimension = GlweDimension(2);
+    /// let noise = LogStandardDev::from_log_standard_dev(-50.);
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// let glwe_key =
+    ///     GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+    /// let mut key = GlweAutomorphismKey::allocate(
+    ///     0_u64,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(10),
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    /// );
+    /// key.fill_with_automorphism_key(&glwe_key, noise, &mut encryption_generator);
+    ///
+    /// let mut messages = PlaintextList::allocate(0_u64, PlaintextCount(polynomial_size.0));
+    /// messages.as_mut_polynomial().as_mut_tensor().fill_with_element(3 << 50);
+    /// let mut input = GlweCiphertext::allocate(0, polynomial_size, glwe_dimension.to_glwe_size());
+    /// glwe_key.encrypt_glwe(&mut input, &messages, noise, &mut encryption_generator);
+    ///
+    /// let mut output = GlweCiphertext::allocate(0, polynomial_size, glwe_dimension.to_glwe_size());
+    /// key.trace(&mut output, &input);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0_u64, PlaintextCount(polynomial_size.0));
+    /// glwe_key.decrypt_glwe(&mut decrypted, &output);
+    /// // The constant coefficient is 256 * 3 * 2^50 = 3 * 2^58, the others are zero.
+    /// let decoded: Vec<u64> = decrypted
+    ///     .as_tensor()
+    ///     .iter()
+    ///     .map(|d| (d.wrapping_add(1 << 57) >> 58) % 64)
+    ///     .collect();
+    /// assert_eq!(decoded[0], 3);
+    /// assert!(decoded[1..].iter().all(|d| *d == 0));
+    /// ```
+    pub fn trace<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &GlweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.glwe_size.0 => input.size().0, output.size().0);
+        ck_dim_eq!(self.polynomial_size.0 => input.polynomial_size().0, output.polynomial_size().0);
+
+        let polynomial_size = self.polynomial_size;
+        let decomp_level_count = self.decomp_level_count;
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, decomp_level_count);
+
+        output.as_mut_tensor().fill_with_copy(input.as_tensor());
+
+        // The buffers holding the automorphed ciphertext, its keyswitched version, the
+        // decomposition of a mask polynomial, and a product of polynomials.
+        let mut automorphed =
+            GlweCiphertext::allocate(Scalar::ZERO, polynomial_size, self.glwe_size);
+        let mut switched = GlweCiphertext::allocate(Scalar::ZERO, polynomial_size, self.glwe_size);
+        let mut decomposition = PolynomialList::allocate(
+            Scalar::ZERO,
+            PolynomialCount(decomp_level_count.0),
+            polynomial_size,
+        );
+        let mut digits = vec![Scalar::ZERO; decomp_level_count.0];
+        let mut product = Polynomial::allocate(Scalar::ZERO, polynomial_size);
+
+        for (index, keys) in self.automorphism_key_iter().enumerate() {
+            let exponent = (polynomial_size.0 >> index) + 1;
+            for (mut automorphed_polynomial, polynomial) in automorphed
+                .as_mut_polynomial_list()
+                .polynomial_iter_mut()
+                .zip(output.as_polynomial_list().polynomial_iter())
+            {
+                apply_automorphism(
+                    automorphed_polynomial.as_mut_tensor().as_mut_slice(),
+                    polynomial.as_tensor().as_slice(),
+                    exponent,
+                );
+            }
+
+            // We keyswitch the automorphed ciphertext from the automorphed key to the original key.
+            switched.as_mut_tensor().fill_with_element(Scalar::ZERO);
+            switched
+                .get_mut_body()
+                .as_mut_tensor()
+                .fill_with_copy(automorphed.get_body().as_tensor());
+            let mut key_ciphertexts = keys.ciphertext_iter();
+            for mask_polynomial in automorphed
+                .get_mask()
+                .as_polynomial_list()
+                .polynomial_iter()
+            {
+                for (coefficient_index, coefficient) in
+                    mask_polynomial.as_tensor().iter().enumerate()
+                {
+                    let rounded = decomposer.closest_representable(*coefficient);
+                    torus_small_sign_decompose(
+                        digits.as_mut_slice(),
+                        rounded,
+                        self.decomp_base_log.0,
+                    );
+                    for (mut level_polynomial, digit) in
+                        decomposition.polynomial_iter_mut().zip(digits.iter())
+                    {
+                        *level_polynomial
+                            .as_mut_tensor()
+                            .get_element_mut(coefficient_index) = *digit;
+                    }
+                }
+                for level_polynomial in decomposition.polynomial_iter() {
+                    let key_ciphertext = key_ciphertexts.next().unwrap();
+                    for (mut switched_polynomial, key_polynomial) in switched
+                        .as_mut_polynomial_list()
+                        .polynomial_iter_mut()
+                        .zip(key_ciphertext.as_polynomial_list().polynomial_iter())
+                    {
+                        product.fill_with_wrapping_mul(&key_polynomial, &level_polynomial);
+                        switched_polynomial.update_with_wrapping_sub(&product);
+                    }
+                }
+            }
+
+            output
+                .as_mut_tensor()
+                .update_with_wrapping_add(switched.as_tensor());
+        }
+    }
+
+    fn automorphism_key_iter(
+        &self,
+    ) -> impl Iterator<Item = GlweList<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        let ciphertext_count = self.glwe_dimension().0 * self.decomp_level_count.0;
+        let chunk_size = ciphertext_count * self.glwe_size.0 * self.polynomial_size.0;
+        ck_dim_div!(self.as_tensor().len() => chunk_size);
+        let glwe_dimension = self.glwe_dimension();
+        let polynomial_size = self.polynomial_size;
+        self.as_tensor().subtensor_iter(chunk_size).map(move |sub| {
+            GlweList::from_container(sub.into_container(), glwe_dimension, polynomial_size)
+        })
+    }
+
+    fn automorphism_key_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = GlweList<&mut [<Self as AsMutTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+    {
+        let ciphertext_count = self.glwe_dimension().0 * self.decomp_level_count.0;
+        let chunk_size = ciphertext_count * self.glwe_size.0 * self.polynomial_size.0;
+        ck_dim_div!(self.as_tensor().len() => chunk_size);
+        let glwe_dimension = self.glwe_dimension();
+        let polynomial_size = self.polynomial_size;
+        self.as_mut_tensor()
+            .subtensor_iter_mut(chunk_size)
+            .map(move |sub| {
+                GlweList::from_container(sub.into_container(), glwe_dimension, polynomial_size)
+            })
+    }
+}
+
+// Fills `output` with the image of the `input` polynomial by the automorphism X -> X^exponent,
+// for an odd exponent.
+fn apply_automorphism<Scalar: UnsignedTorus>(
+    output: &mut [Scalar],
+    input: &[Scalar],
+    exponent: usize,
+) {
+    let polynomial_size = input.len();
+    for (degree, coefficient) in input.iter().enumerate() {
+        // Since X^N = -1, the monomial X^d is equal to -X^{d-N} when N <= d < 2N.
+        let target = (degree * exponent) % (2 * polynomial_size);
+        if target < polynomial_size {
+            output[target] = *coefficient;
+        } else {
+            output[target - polynomial_size] = coefficient.wrapping_neg();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::apply_automorphism;
+
+    #[test]
+    fn test_apply_automorphism() {
+        // With N = 4 and X -> X^3: X -> X^3, X^2 -> X^6 = -X^2, X^3 -> X^9 = X.
+        let mut output = vec![0_u32; 4];
+        apply_automorphism(&mut output, &[1, 2, 3, 4], 3);
+        assert_eq!(output, vec![1, 4, 3_u32.wrapping_neg(), 2]);
+    }
+
+    #[test]
+    fn test_automorphisms_sum_to_trace() {
+        // Summing the images by the trace automorphisms, as done by the trace, gives N times the
+        // constant coefficient.
+        let polynomial_size = 16;
+        let mut polynomial: Vec<u64> = (1..=polynomial_size as u64).collect();
+        let mut automorphed = vec![0; polynomial_size];
+        for index in 0..polynomial_size.trailing_zeros() as usize {
+            apply_automorphism(
+                &mut automorphed,
+                &polynomial,
+                (polynomial_size >> index) + 1,
+            );
+            for (coefficient, other) in polynomial.iter_mut().zip(automorphed.iter()) {
+                *coefficient = coefficient.wrapping_add(*other);
+            }
+        }
+        assert_eq!(polynomial[0], 16);
+        assert!(polynomial[1..].iter().all(|c| *c == 0));
+    }
+}
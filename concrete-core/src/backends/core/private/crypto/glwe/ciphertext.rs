@@ -3,11 +3,13 @@ use crate::backends::core::private::crypto::encoding::{Plaintext, PlaintextList}
 use crate::backends::core::private::crypto::lwe::LweCiphertext;
 use crate::backends::core::private::math::polynomial::PolynomialList;
 use crate::backends::core::private::math::tensor::{
-    tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
 use crate::backends::core::private::math::torus::UnsignedTorus;
-use concrete_commons::numeric::Numeric;
-use concrete_commons::parameters::{GlweDimension, GlweSize, MonomialDegree, PolynomialSize};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    CiphertextLayout, GlweDimension, GlweSize, MonomialDegree, PolynomialSize,
+};
 #[cfg(feature = "serde_serialize")]
 use serde::{Deserialize, Serialize};
 
@@ -330,6 +332,41 @@ impl<Cont> GlweCiphertext<Cont> {
         }
     }
 
+    /// Reorders the polynomials of the ciphertext in place, from the `from` layout to the `to`
+    /// layout.
+    ///
+    /// The body of a GLWE ciphertext being a polynomial, the whole body polynomial is moved before
+    /// or after the mask polynomials.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{CiphertextLayout, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+    /// let mut glwe = GlweCiphertext::from_container(vec![1_u8, 2, 3, 4, 5, 6], PolynomialSize(2));
+    /// glwe.reorder_layout(CiphertextLayout::MaskFirst, CiphertextLayout::BodyFirst);
+    /// assert_eq!(glwe.as_tensor().as_slice(), &[5, 6, 1, 2, 3, 4]);
+    /// glwe.reorder_layout(CiphertextLayout::BodyFirst, CiphertextLayout::MaskFirst);
+    /// assert_eq!(glwe.as_tensor().as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn reorder_layout(&mut self, from: CiphertextLayout, to: CiphertextLayout)
+    where
+        Self: AsMutTensor,
+    {
+        let poly_size = self.poly_size.0;
+        let coefficients = self.as_mut_tensor().as_mut_slice();
+        match (from, to) {
+            (CiphertextLayout::MaskFirst, CiphertextLayout::BodyFirst) => {
+                coefficients.rotate_right(poly_size)
+            }
+            (CiphertextLayout::BodyFirst, CiphertextLayout::MaskFirst) => {
+                coefficients.rotate_left(poly_size)
+            }
+            _ => {}
+        }
+    }
+
     /// Returns a borrowed polynomial list from the current ciphertext.
     ///
     /// # Example
@@ -504,4 +541,93 @@ impl<Cont> GlweCiphertext<Cont> {
                 },
             );
     }
+
+    /// Adds the product of another ciphertext by the monomial $X^d$, reduced modulo $(X^N+1)$, to
+    /// the current ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweSize, MonomialDegree, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+    /// let mut glwe = GlweCiphertext::allocate(1 as u8, PolynomialSize(4), GlweSize(2));
+    /// let other = GlweCiphertext::from_container(vec![1_u8, 2, 3, 4, 5, 6, 7, 8], PolynomialSize(4));
+    /// glwe.update_with_wrapping_add_negacyclic_monomial_mul(&other, MonomialDegree(1));
+    /// // X(1 + 2X + 3X^2 + 4X^3) = -4 + X + 2X^2 + 3X^3
+    /// assert_eq!(glwe.as_tensor().as_slice(), &[253, 2, 3, 4, 249, 6, 7, 8]);
+    /// ```
+    pub fn update_with_wrapping_add_negacyclic_monomial_mul<OtherCont, Scalar>(
+        &mut self,
+        other: &GlweCiphertext<OtherCont>,
+        degree: MonomialDegree,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<OtherCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedInteger,
+    {
+        // Since X^N = -1, the coefficients wrapping around the polynomial are subtracted.
+        self.update_with_wrapping_add_monomial_mul(other, degree, Scalar::wrapping_sub);
+    }
+
+    /// Adds the product of another ciphertext by the monomial $X^d$, reduced modulo $(X^N-1)$, to
+    /// the current ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweSize, MonomialDegree, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+    /// let mut glwe = GlweCiphertext::allocate(1 as u8, PolynomialSize(4), GlweSize(2));
+    /// let other = GlweCiphertext::from_container(vec![1_u8, 2, 3, 4, 5, 6, 7, 8], PolynomialSize(4));
+    /// glwe.update_with_wrapping_add_cyclic_monomial_mul(&other, MonomialDegree(1));
+    /// // X(1 + 2X + 3X^2 + 4X^3) = 4 + X + 2X^2 + 3X^3
+    /// assert_eq!(glwe.as_tensor().as_slice(), &[5, 2, 3, 4, 9, 6, 7, 8]);
+    /// ```
+    pub fn update_with_wrapping_add_cyclic_monomial_mul<OtherCont, Scalar>(
+        &mut self,
+        other: &GlweCiphertext<OtherCont>,
+        degree: MonomialDegree,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<OtherCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedInteger,
+    {
+        // Since X^N = 1, the coefficients wrapping around the polynomial are added.
+        self.update_with_wrapping_add_monomial_mul(other, degree, Scalar::wrapping_add);
+    }
+
+    // Adds the coefficients of `other` shifted by `degree` to the ones of the current ciphertext.
+    // The coefficients shifted past the degree N are combined with `wrap` at the beginning of the
+    // polynomials.
+    fn update_with_wrapping_add_monomial_mul<OtherCont, Scalar>(
+        &mut self,
+        other: &GlweCiphertext<OtherCont>,
+        degree: MonomialDegree,
+        wrap: impl Fn(Scalar, Scalar) -> Scalar,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<OtherCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedInteger,
+    {
+        ck_dim_eq!(self.poly_size.0 => other.poly_size.0);
+        ck_dim_eq!(self.as_tensor().len() => other.as_tensor().len());
+        let size = self.poly_size.0;
+        debug_assert!(degree.0 < size);
+        for (output, input) in self
+            .as_mut_tensor()
+            .as_mut_slice()
+            .chunks_mut(size)
+            .zip(other.as_tensor().as_slice().chunks(size))
+        {
+            let (kept, wrapped) = input.split_at(size - degree.0);
+            for (output, input) in output[degree.0..].iter_mut().zip(kept.iter()) {
+                *output = output.wrapping_add(*input);
+            }
+            for (output, input) in output[..degree.0].iter_mut().zip(wrapped.iter()) {
+                *output = wrap(*output, *input);
+            }
+        }
+    }
 }
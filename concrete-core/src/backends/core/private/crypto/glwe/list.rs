@@ -9,7 +9,7 @@ use crate::backends::core::private::math::tensor::{
 use super::GlweCiphertext;
 use crate::backends::core::private::crypto::encoding::PlaintextList;
 use concrete_commons::parameters::{
-    CiphertextCount, GlweDimension, GlweSize, PlaintextCount, PolynomialSize,
+    CiphertextCount, CiphertextLayout, GlweDimension, GlweSize, PlaintextCount, PolynomialSize,
 };
 
 /// A list of ciphertexts encoded with the GLWE scheme.
@@ -255,6 +255,33 @@ impl<Cont> GlweList<Cont> {
             .map(move |sub| GlweCiphertext::from_container(sub.into_container(), poly_size))
     }
 
+    /// Reorders the polynomials of every ciphertext of the list in place, from the `from` layout
+    /// to the `to` layout.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{CiphertextLayout, GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweList;
+    /// use concrete_core::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+    /// let mut list = GlweList::from_container(
+    ///     vec![1_u8, 2, 3, 4, 5, 6, 7, 8],
+    ///     GlweDimension(1),
+    ///     PolynomialSize(2),
+    /// );
+    /// list.reorder_layout(CiphertextLayout::MaskFirst, CiphertextLayout::BodyFirst);
+    /// assert_eq!(list.as_tensor().as_slice(), &[3, 4, 1, 2, 7, 8, 5, 6]);
+    /// list.reorder_layout(CiphertextLayout::BodyFirst, CiphertextLayout::MaskFirst);
+    /// assert_eq!(list.as_tensor().as_slice(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    pub fn reorder_layout(&mut self, from: CiphertextLayout, to: CiphertextLayout)
+    where
+        Self: AsMutTensor,
+    {
+        self.ciphertext_iter_mut()
+            .for_each(|mut ciphertext| ciphertext.reorder_layout(from, to));
+    }
+
     pub fn fill_with_trivial_encryption<PlaintextContainer, Scalar>(
         &mut self,
         plaintexts: &PlaintextList<PlaintextContainer>,
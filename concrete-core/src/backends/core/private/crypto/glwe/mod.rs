@@ -1,15 +1,20 @@
 //! GLWE encryption scheme
 
+pub use automorphism::*;
 pub use body::*;
 pub use ciphertext::*;
 pub use fourier::*;
 pub use keyswitch::*;
 pub use list::*;
 pub use mask::*;
+pub use relinearization::*;
 
+mod automorphism;
 mod body;
 mod ciphertext;
 mod fourier;
 mod keyswitch;
 mod list;
 mod mask;
+mod relinearization;
+mod tensor_product;
@@ -0,0 +1,449 @@
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialCount,
+    PolynomialSize,
+};
+
+use crate::backends::core::private::crypto::encoding::PlaintextList;
+use crate::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::backends::core::private::crypto::secret::GlweSecretKey;
+use crate::backends::core::private::math::decomposition::{
+    torus_small_sign_decompose, DecompositionLevel, DecompositionTerm, SignedDecomposer,
+};
+use crate::backends::core::private::math::polynomial::{Polynomial, PolynomialList};
+use crate::backends::core::private::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::{GlweCiphertext, GlweList};
+
+/// A relinearization key.
+///
+/// The tensor product of two GLWE ciphertexts encrypted under $S = (S\_1, \ldots, S\_k)$ is a GLWE
+/// ciphertext of dimension $k(k+3)/2$, encrypted under the extended key made of the $k$ squares
+/// $S\_i^2$, the $k(k-1)/2$ products $S\_i S\_j$ for $i < j$, and the $k$ polynomials $S\_i$.
+///
+/// A relinearization key contains, for each of the $k(k+1)/2$ quadratic polynomials $K\_p$ of the
+/// extended key (in the order above), the $l$ levels of the decomposition of $K\_p$ encrypted under
+/// $S$. It allows to turn such an extended ciphertext back into a GLWE ciphertext of dimension $k$
+/// under $S$ (see [`GlweRelinearizationKey::relinearize`]).
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweRelinearizationKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+}
+
+tensor_traits!(GlweRelinearizationKey);
+
+impl<Scalar> GlweRelinearizationKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a relinearization key whose masks and bodies are all `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a relinearization key, but merely allocates a container
+    /// of the right size. See [`GlweRelinearizationKey::fill_with_relinearization_key`] to fill
+    /// the container with a proper key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweRelinearizationKey;
+    /// let key = GlweRelinearizationKey::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(key.quadratic_polynomial_count(), 3);
+    /// assert_eq!(key.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(key.decomposition_base_log(), DecompositionBaseLog(7));
+    /// assert_eq!(key.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(key.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        decomp_level_count: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Self {
+        let quadratic_polynomial_count = glwe_dimension.0 * (glwe_dimension.0 + 1) / 2;
+        GlweRelinearizationKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                quadratic_polynomial_count
+                    * decomp_level_count.0
+                    * glwe_dimension.to_glwe_size().0
+                    * polynomial_size.0
+            ]),
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size: glwe_dimension.to_glwe_size(),
+            polynomial_size,
+        }
+    }
+}
+
+impl<Cont> GlweRelinearizationKey<Cont> {
+    /// Returns the dimension of the GLWE key.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials of the GLWE key.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    /// Returns the number of levels used for the decomposition of the quadratic key polynomials.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the quadratic key
+    /// polynomials.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the number of quadratic key polynomials whose decompositions are encrypted, that is
+    /// $k(k+1)/2$.
+    pub fn quadratic_polynomial_count(&self) -> usize {
+        let glwe_dimension = self.glwe_dimension().0;
+        glwe_dimension * (glwe_dimension + 1) / 2
+    }
+
+    /// Fills the current container with the relinearization key of a GLWE secret key.
+    ///
+    /// This is equivalent to
+    /// [`GlweRelinearizationKey::fill_with_relinearization_key_monitored`] with a monitor which
+    /// never stops the generation.
+    pub fn fill_with_relinearization_key<KeyCont, Scalar>(
+        &mut self,
+        glwe_key: &GlweSecretKey<BinaryKeyKind, KeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        self.fill_with_relinearization_key_monitored(glwe_key, noise_parameters, generator, |_| {
+            true
+        });
+    }
+
+    /// Fills the current container with the relinearization key of a GLWE secret key, reporting
+    /// the progress of the generation to a monitor.
+    ///
+    /// The `monitor` closure is called with the number of quadratic key polynomials encrypted so
+    /// far, after each one of them. The generation stops as soon as it returns `false`, in which
+    /// case the key is left partially filled, and `false` is returned.
+    pub fn fill_with_relinearization_key_monitored<KeyCont, Scalar, Monitor>(
+        &mut self,
+        glwe_key: &GlweSecretKey<BinaryKeyKind, KeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+        mut monitor: Monitor,
+    ) -> bool
+    where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Monitor: FnMut(usize) -> bool,
+    {
+        ck_dim_eq!(self.glwe_dimension().0 => glwe_key.key_size().0);
+        ck_dim_eq!(self.polynomial_size.0 => glwe_key.polynomial_size().0);
+
+        let polynomial_size = self.polynomial_size;
+        let decomp_level_count = self.decomp_level_count;
+        let decomp_base_log = self.decomp_base_log;
+        let glwe_dimension = self.glwe_dimension();
+        let key_polynomials = glwe_key.as_polynomial_list();
+
+        // The buffers holding the quadratic key polynomial, and the messages of its levels.
+        let mut quadratic = Polynomial::allocate(Scalar::ZERO, polynomial_size);
+        let mut messages = PlaintextList::from_container(vec![
+            Scalar::ZERO;
+            decomp_level_count.0
+                * polynomial_size.0
+        ]);
+
+        for (index, (mut keys, (i, j))) in self
+            .quadratic_key_iter_mut()
+            .zip(quadratic_indices(glwe_dimension))
+            .enumerate()
+        {
+            quadratic.fill_with_wrapping_mul(
+                &key_polynomials.get_polynomial(i),
+                &key_polynomials.get_polynomial(j),
+            );
+            for (level, mut message) in (1..=decomp_level_count.0).map(DecompositionLevel).zip(
+                messages
+                    .as_mut_tensor()
+                    .subtensor_iter_mut(polynomial_size.0),
+            ) {
+                let summand = DecompositionTerm::new(level, decomp_base_log, Scalar::ONE)
+                    .to_recomposition_summand();
+                message.fill_with_one(quadratic.as_tensor(), |coefficient| {
+                    coefficient.wrapping_mul(summand)
+                });
+            }
+
+            glwe_key.encrypt_glwe_list(&mut keys, &messages, noise_parameters, generator);
+
+            if !monitor(index + 1) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Relinearizes a GLWE ciphertext encrypted under an extended key.
+    ///
+    /// The `input` ciphertext has $k(k+3)/2$ mask polynomials, the first $k(k+1)/2$ of which are
+    /// associated with the quadratic polynomials of the extended key (see
+    /// [`GlweRelinearizationKey`]), and the last $k$ with the polynomials of $S$. The `output`
+    /// ciphertext encrypts the same message under $S$: every quadratic mask polynomial is
+    /// decomposed, and the levels of its decomposition are multiplied with the encryptions of the
+    /// corresponding quadratic key polynomial, and subtracted from the linear part of `input`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::{
+    ///     GlweCiphertext, GlweRelinearizationKey,
+    /// };
+    ///
+    /// let key = GlweRelinearizationKey::allocate(
+    ///     0_u64,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// // The extended ciphertext has 2 * (2 + 3) / 2 = 5 mask polynomials.
+    /// let input = GlweCiphertext::allocate(0_u64, PolynomialSize(256), GlweSize(6));
+    /// let mut output = GlweCiphertext::allocate(0_u64, PolynomialSize(256), GlweSize(3));
+    /// key.relinearize(&mut output, &input);
+    /// ```
+    pub fn relinearize<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &GlweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let quadratic_polynomial_count = self.quadratic_polynomial_count();
+        ck_dim_eq!(self.glwe_size.0 => output.size().0);
+        ck_dim_eq!(
+            quadratic_polynomial_count + self.glwe_size.0 => input.size().0
+        );
+        ck_dim_eq!(self.polynomial_size.0 => input.polynomial_size().0, output.polynomial_size().0);
+
+        let polynomial_size = self.polynomial_size;
+        let decomp_level_count = self.decomp_level_count;
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, decomp_level_count);
+
+        // The linear part of the input is copied as is to the output.
+        let input_polynomials = input.as_polynomial_list();
+        for (mut output_polynomial, input_polynomial) in
+            output.as_mut_polynomial_list().polynomial_iter_mut().zip(
+                input_polynomials
+                    .polynomial_iter()
+                    .skip(quadratic_polynomial_count),
+            )
+        {
+            output_polynomial
+                .as_mut_tensor()
+                .fill_with_copy(input_polynomial.as_tensor());
+        }
+
+        // The buffers holding the decomposition of a mask polynomial, and a product of polynomials.
+        let mut decomposition = PolynomialList::allocate(
+            Scalar::ZERO,
+            PolynomialCount(decomp_level_count.0),
+            polynomial_size,
+        );
+        let mut digits = vec![Scalar::ZERO; decomp_level_count.0];
+        let mut product = Polynomial::allocate(Scalar::ZERO, polynomial_size);
+
+        for (keys, mask_polynomial) in self
+            .quadratic_key_iter()
+            .zip(input_polynomials.polynomial_iter())
+        {
+            for (coefficient_index, coefficient) in mask_polynomial.as_tensor().iter().enumerate() {
+                let rounded = decomposer.closest_representable(*coefficient);
+                torus_small_sign_decompose(digits.as_mut_slice(), rounded, self.decomp_base_log.0);
+                for (mut level_polynomial, digit) in
+                    decomposition.polynomial_iter_mut().zip(digits.iter())
+                {
+                    *level_polynomial
+                        .as_mut_tensor()
+                        .get_element_mut(coefficient_index) = *digit;
+                }
+            }
+            for (level_polynomial, key_ciphertext) in
+                decomposition.polynomial_iter().zip(keys.ciphertext_iter())
+            {
+                for (mut output_polynomial, key_polynomial) in output
+                    .as_mut_polynomial_list()
+                    .polynomial_iter_mut()
+                    .zip(key_ciphertext.as_polynomial_list().polynomial_iter())
+                {
+                    product.fill_with_wrapping_mul(&key_polynomial, &level_polynomial);
+                    output_polynomial.update_with_wrapping_sub(&product);
+                }
+            }
+        }
+    }
+
+    fn quadratic_key_iter(
+        &self,
+    ) -> impl Iterator<Item = GlweList<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        let chunk_size = self.decomp_level_count.0 * self.glwe_size.0 * self.polynomial_size.0;
+        ck_dim_div!(self.as_tensor().len() => chunk_size);
+        let glwe_dimension = self.glwe_dimension();
+        let polynomial_size = self.polynomial_size;
+        self.as_tensor().subtensor_iter(chunk_size).map(move |sub| {
+            GlweList::from_container(sub.into_container(), glwe_dimension, polynomial_size)
+        })
+    }
+
+    fn quadratic_key_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = GlweList<&mut [<Self as AsMutTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+    {
+        let chunk_size = self.decomp_level_count.0 * self.glwe_size.0 * self.polynomial_size.0;
+        ck_dim_div!(self.as_tensor().len() => chunk_size);
+        let glwe_dimension = self.glwe_dimension();
+        let polynomial_size = self.polynomial_size;
+        self.as_mut_tensor()
+            .subtensor_iter_mut(chunk_size)
+            .map(move |sub| {
+                GlweList::from_container(sub.into_container(), glwe_dimension, polynomial_size)
+            })
+    }
+}
+
+// Returns the indices (i, j) of the key polynomials whose product S_i S_j is the quadratic
+// polynomial at each position of the extended key: first the squares, then the products with
+// i < j.
+pub(super) fn quadratic_indices(
+    glwe_dimension: GlweDimension,
+) -> impl Iterator<Item = (usize, usize)> {
+    let k = glwe_dimension.0;
+    (0..k)
+        .map(|i| (i, i))
+        .chain((0..k).flat_map(move |i| (i + 1..k).map(move |j| (i, j))))
+}
+
+#[cfg(test)]
+mod test {
+    use concrete_commons::dispersion::LogStandardDev;
+    use concrete_commons::parameters::{
+        DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PlaintextCount,
+        PolynomialSize,
+    };
+
+    use crate::backends::core::private::crypto::encoding::PlaintextList;
+    use crate::backends::core::private::crypto::glwe::GlweCiphertext;
+    use crate::backends::core::private::crypto::secret::generators::{
+        EncryptionRandomGenerator, SecretRandomGenerator,
+    };
+    use crate::backends::core::private::crypto::secret::GlweSecretKey;
+    use crate::backends::core::private::math::polynomial::Polynomial;
+    use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor};
+
+    use super::{quadratic_indices, GlweRelinearizationKey};
+
+    #[test]
+    fn test_quadratic_indices() {
+        let indices: Vec<_> = quadratic_indices(GlweDimension(3)).collect();
+        assert_eq!(
+            indices,
+            vec![(0, 0), (1, 1), (2, 2), (0, 1), (0, 2), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn test_relinearization_preserves_message() {
+        let polynomial_size = PolynomialSize(256);
+        let glwe_dimension = GlweDimension(2);
+        let noise = LogStandardDev::from_log_standard_dev(-50.);
+        let mut secret_generator = SecretRandomGenerator::new(None);
+        let mut encryption_generator = EncryptionRandomGenerator::new(None);
+        let glwe_key =
+            GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+        let mut key = GlweRelinearizationKey::allocate(
+            0_u64,
+            DecompositionLevelCount(4),
+            DecompositionBaseLog(12),
+            glwe_dimension,
+            polynomial_size,
+        );
+        key.fill_with_relinearization_key(&glwe_key, noise, &mut encryption_generator);
+
+        // We build the extended key by hand, and encrypt a message under it.
+        let key_polynomials = glwe_key.as_polynomial_list();
+        let mut extended = Vec::new();
+        let mut product = Polynomial::allocate(0_u64, polynomial_size);
+        for (i, j) in quadratic_indices(glwe_dimension) {
+            product.fill_with_wrapping_mul(
+                &key_polynomials.get_polynomial(i),
+                &key_polynomials.get_polynomial(j),
+            );
+            extended.extend_from_slice(product.as_tensor().as_slice());
+        }
+        extended.extend_from_slice(glwe_key.as_tensor().as_slice());
+        let extended_key = GlweSecretKey::binary_from_container(extended, polynomial_size);
+
+        let mut messages = PlaintextList::allocate(0_u64, PlaintextCount(polynomial_size.0));
+        messages.as_mut_tensor().fill_with_element(5 << 58);
+        let mut input = GlweCiphertext::allocate(
+            0_u64,
+            polynomial_size,
+            extended_key.key_size().to_glwe_size(),
+        );
+        extended_key.encrypt_glwe(&mut input, &messages, noise, &mut encryption_generator);
+
+        let mut output =
+            GlweCiphertext::allocate(0_u64, polynomial_size, glwe_dimension.to_glwe_size());
+        key.relinearize(&mut output, &input);
+
+        let mut decrypted = PlaintextList::allocate(0_u64, PlaintextCount(polynomial_size.0));
+        glwe_key.decrypt_glwe(&mut decrypted, &output);
+        assert!(decrypted
+            .as_tensor()
+            .iter()
+            .all(|d| (d.wrapping_add(1 << 57) >> 58) % 64 == 5));
+    }
+}
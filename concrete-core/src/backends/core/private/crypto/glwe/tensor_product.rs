@@ -0,0 +1,223 @@
+use concrete_commons::numeric::{CastFrom, CastInto, UnsignedInteger};
+use concrete_commons::parameters::ScalingFactor;
+
+use crate::backends::core::private::math::tensor::{
+    ck_dim_eq, AsMutTensor, AsRefSlice, AsRefTensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::relinearization::quadratic_indices;
+use super::GlweCiphertext;
+
+impl<Cont> GlweCiphertext<Cont> {
+    /// Fills the current ciphertext with the tensor product of two GLWE ciphertexts, divided by
+    /// `scale`.
+    ///
+    /// The two input ciphertexts must share the same GLWE dimension $k$ and polynomial size, and
+    /// the current ciphertext must have $k(k+3)/2$ mask polynomials. Its polynomials are ordered
+    /// like the extended key expected by
+    /// [`GlweRelinearizationKey::relinearize`](super::GlweRelinearizationKey::relinearize): first
+    /// the $k$ squares $S\_i^2$, then the products $S\_i S\_j$ for $i < j$, then the $k$ key
+    /// polynomials $S\_i$, and finally the body.
+    ///
+    /// The products of polynomials are computed exactly on 128 bits integers, before being
+    /// divided by `scale` and rounded. For 64 bits ciphertexts, the sums of products may wrap
+    /// around $2^{128}$, which does not affect the result as long as `scale` is a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize, ScalingFactor};
+    /// use concrete_core::backends::core::private::crypto::encoding::PlaintextList;
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    ///
+    /// let polynomial_size = PolynomialSize(4);
+    /// let lhs = GlweCiphertext::new_trivial_encryption(
+    ///     GlweSize(2),
+    ///     &PlaintextList::from_container(vec![3_u64 << 40, 0, 0, 0]),
+    /// );
+    /// let rhs = GlweCiphertext::new_trivial_encryption(
+    ///     GlweSize(2),
+    ///     &PlaintextList::from_container(vec![2_u64 << 40, 0, 0, 0]),
+    /// );
+    /// let mut output = GlweCiphertext::allocate(0_u64, polynomial_size, GlweSize(3));
+    /// output.fill_with_tensor_product(&lhs, &rhs, ScalingFactor::from_log2(40));
+    /// assert_eq!(*output.get_body().as_tensor().first(), 6 << 40);
+    /// ```
+    pub fn fill_with_tensor_product<LhsCont, RhsCont, Scalar>(
+        &mut self,
+        lhs: &GlweCiphertext<LhsCont>,
+        rhs: &GlweCiphertext<RhsCont>,
+        scale: ScalingFactor,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<LhsCont>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<RhsCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + CastFrom<i128>,
+        <Scalar as UnsignedInteger>::Signed: CastInto<i128>,
+    {
+        let glwe_dimension = lhs.mask_size();
+        let k = glwe_dimension.0;
+        ck_dim_eq!(k => rhs.mask_size().0);
+        ck_dim_eq!(k * (k + 3) / 2 => self.mask_size().0);
+        ck_dim_eq!(
+            lhs.polynomial_size().0 => rhs.polynomial_size().0, self.polynomial_size().0
+        );
+
+        let widen = |polynomials: &[Scalar]| -> Vec<i128> {
+            polynomials
+                .iter()
+                .map(|coefficient| -> i128 { coefficient.into_signed().cast_into() })
+                .collect()
+        };
+        let lhs_polynomials = lhs.as_polynomial_list();
+        let rhs_polynomials = rhs.as_polynomial_list();
+        let lhs_polynomials: Vec<Vec<i128>> = lhs_polynomials
+            .polynomial_iter()
+            .map(|polynomial| widen(polynomial.as_tensor().as_slice()))
+            .collect();
+        let rhs_polynomials: Vec<Vec<i128>> = rhs_polynomials
+            .polynomial_iter()
+            .map(|polynomial| widen(polynomial.as_tensor().as_slice()))
+            .collect();
+        let (lhs_masks, lhs_body) = lhs_polynomials.split_at(k);
+        let (rhs_masks, rhs_body) = rhs_polynomials.split_at(k);
+        let (lhs_body, rhs_body) = (&lhs_body[0], &rhs_body[0]);
+
+        // The phase B - <A, S> of the product expands into quadratic terms added to the phase,
+        // which we store as negated masks, and linear terms subtracted from it.
+        let mut products = Vec::with_capacity(k * (k + 3) / 2 + 1);
+        for (i, j) in quadratic_indices(glwe_dimension) {
+            let mut product = vec![0i128; lhs_body.len()];
+            wrapping_sub_negacyclic_mul(&mut product, &lhs_masks[i], &rhs_masks[j]);
+            if i != j {
+                wrapping_sub_negacyclic_mul(&mut product, &lhs_masks[j], &rhs_masks[i]);
+            }
+            products.push(product);
+        }
+        for i in 0..k {
+            let mut product = vec![0i128; lhs_body.len()];
+            wrapping_add_negacyclic_mul(&mut product, &lhs_masks[i], rhs_body);
+            wrapping_add_negacyclic_mul(&mut product, lhs_body, &rhs_masks[i]);
+            products.push(product);
+        }
+        let mut product = vec![0i128; lhs_body.len()];
+        wrapping_add_negacyclic_mul(&mut product, lhs_body, rhs_body);
+        products.push(product);
+
+        let scale = scale.0 as i128;
+        let half_scale = scale / 2;
+        for (coefficient, product) in self
+            .as_mut_tensor()
+            .iter_mut()
+            .zip(products.iter().flatten())
+        {
+            *coefficient = Scalar::cast_from(product.wrapping_add(half_scale).div_euclid(scale));
+        }
+    }
+}
+
+// Adds the negacyclic product of `lhs` and `rhs` to `output`, wrapping around 2^128.
+fn wrapping_add_negacyclic_mul(output: &mut [i128], lhs: &[i128], rhs: &[i128]) {
+    let n = output.len();
+    for (i, lhs_coefficient) in lhs.iter().enumerate() {
+        for (j, rhs_coefficient) in rhs.iter().enumerate() {
+            let product = lhs_coefficient.wrapping_mul(*rhs_coefficient);
+            if i + j < n {
+                output[i + j] = output[i + j].wrapping_add(product);
+            } else {
+                output[i + j - n] = output[i + j - n].wrapping_sub(product);
+            }
+        }
+    }
+}
+
+// Subtracts the negacyclic product of `lhs` and `rhs` from `output`, wrapping around 2^128.
+fn wrapping_sub_negacyclic_mul(output: &mut [i128], lhs: &[i128], rhs: &[i128]) {
+    let n = output.len();
+    for (i, lhs_coefficient) in lhs.iter().enumerate() {
+        for (j, rhs_coefficient) in rhs.iter().enumerate() {
+            let product = lhs_coefficient.wrapping_mul(*rhs_coefficient);
+            if i + j < n {
+                output[i + j] = output[i + j].wrapping_sub(product);
+            } else {
+                output[i + j - n] = output[i + j - n].wrapping_add(product);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use concrete_commons::dispersion::LogStandardDev;
+    use concrete_commons::parameters::{
+        DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PlaintextCount,
+        PolynomialSize, ScalingFactor,
+    };
+
+    use crate::backends::core::private::crypto::encoding::PlaintextList;
+    use crate::backends::core::private::crypto::glwe::{GlweCiphertext, GlweRelinearizationKey};
+    use crate::backends::core::private::crypto::secret::generators::{
+        EncryptionRandomGenerator, SecretRandomGenerator,
+    };
+    use crate::backends::core::private::crypto::secret::GlweSecretKey;
+    use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+
+    #[test]
+    fn test_tensor_product_then_relinearization_multiplies_messages() {
+        let polynomial_size = PolynomialSize(256);
+        let glwe_dimension = GlweDimension(2);
+        let noise = LogStandardDev::from_log_standard_dev(-50.);
+        let scale = ScalingFactor::from_log2(58);
+        let mut secret_generator = SecretRandomGenerator::new(None);
+        let mut encryption_generator = EncryptionRandomGenerator::new(None);
+        let glwe_key =
+            GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+        let mut relinearization_key = GlweRelinearizationKey::allocate(
+            0_u64,
+            DecompositionLevelCount(4),
+            DecompositionBaseLog(12),
+            glwe_dimension,
+            polynomial_size,
+        );
+        relinearization_key.fill_with_relinearization_key(
+            &glwe_key,
+            noise,
+            &mut encryption_generator,
+        );
+
+        // Both messages only have a constant coefficient, so the product is constant too.
+        let mut encrypt = |message: u64| {
+            let mut plaintexts = PlaintextList::allocate(0_u64, PlaintextCount(polynomial_size.0));
+            *plaintexts.as_mut_tensor().first_mut() = message * scale.0;
+            let mut ciphertext =
+                GlweCiphertext::allocate(0_u64, polynomial_size, glwe_dimension.to_glwe_size());
+            glwe_key.encrypt_glwe(
+                &mut ciphertext,
+                &plaintexts,
+                noise,
+                &mut encryption_generator,
+            );
+            ciphertext
+        };
+        let lhs = encrypt(3);
+        let rhs = encrypt(5);
+
+        let mut tensor_product = GlweCiphertext::allocate(0_u64, polynomial_size, GlweSize(6));
+        tensor_product.fill_with_tensor_product(&lhs, &rhs, scale);
+        let mut output =
+            GlweCiphertext::allocate(0_u64, polynomial_size, glwe_dimension.to_glwe_size());
+        relinearization_key.relinearize(&mut output, &tensor_product);
+
+        let mut decrypted = PlaintextList::allocate(0_u64, PlaintextCount(polynomial_size.0));
+        glwe_key.decrypt_glwe(&mut decrypted, &output);
+        let decoded: Vec<u64> = decrypted
+            .as_tensor()
+            .iter()
+            .map(|d| (d.wrapping_add(1 << 57) >> 58) % 64)
+            .collect();
+        assert_eq!(decoded[0], 15);
+        assert!(decoded[1..].iter().all(|d| *d == 0));
+    }
+}
@@ -11,8 +11,10 @@ use super::LweList;
 use crate::backends::core::private::crypto::glwe::GlweCiphertext;
 use crate::backends::core::private::math::torus::UnsignedTorus;
 use concrete_commons::key_kinds::KeyKind;
-use concrete_commons::numeric::{Numeric, UnsignedInteger};
-use concrete_commons::parameters::{LweDimension, LweSize, MonomialDegree};
+use concrete_commons::numeric::{CastFrom, CastInto, Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    CiphertextModulusLog, DenominatorLog, LweDimension, LweSize, MonomialDegree,
+};
 
 /// A ciphertext encrypted using the LWE scheme.
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
@@ -310,6 +312,122 @@ impl<Cont> LweCiphertext<Cont> {
             .fill_with_one(input.as_tensor(), |o| o.wrapping_mul(scalar.0));
     }
 
+    /// Fills the ciphertext with the result of the multiplication of the `input` ciphertext by
+    /// the rational $\frac{numerator}{2^{denominator\\_log}}$.
+    ///
+    /// Every coefficient of the input is multiplied by the numerator without overflow, then
+    /// divided by the denominator with a rounding to the nearest integer. The
+    /// `denominator_log` most significant bits of the decrypted plaintext are undefined.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{DenominatorLog, LweDimension};
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::LweSecretKey;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    ///
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    ///
+    /// // The message 12 is encoded with a shift by 20 bits.
+    /// let plaintext = Plaintext(12_u32 << 20);
+    /// let mut ciphertext = LweCiphertext::from_container(vec![0 as u32; 257]);
+    /// secret_key.encrypt_lwe(
+    ///     &mut ciphertext,
+    ///     &plaintext,
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// // Multiplies by 3/4, which leaves the two most significant bits undefined.
+    /// let mut processed = LweCiphertext::from_container(vec![0 as u32; 257]);
+    /// processed.fill_with_rational_mul(&ciphertext, &Cleartext(3), DenominatorLog(2));
+    ///
+    /// let mut decrypted = Plaintext(0 as u32);
+    /// secret_key.decrypt_lwe(&mut decrypted, &processed);
+    /// let decoded = ((decrypted.0 << 2) >> 21).wrapping_add(1) >> 1;
+    /// assert_eq!(decoded, 9);
+    /// ```
+    pub fn fill_with_rational_mul<Scalar, InputCont>(
+        &mut self,
+        input: &LweCiphertext<InputCont>,
+        numerator: &Cleartext<Scalar>,
+        denominator_log: DenominatorLog,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InputCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedInteger + CastInto<u128> + CastFrom<u128>,
+    {
+        let numerator: u128 = numerator.0.cast_into();
+        let shift = denominator_log.0;
+        self.as_mut_tensor().fill_with_one(input.as_tensor(), |o| {
+            let coefficient: u128 = (*o).cast_into();
+            let product = coefficient * numerator;
+            let rounded = if shift == 0 {
+                product
+            } else {
+                ((product >> (shift - 1)) + 1) >> 1
+            };
+            Scalar::cast_from(rounded)
+        });
+    }
+
+    /// Fills the ciphertext with the `input` ciphertext switched to the modulus $2^k$, where $k$
+    /// is `modulus_log`.
+    ///
+    /// Every coefficient of the input is rescaled from the native modulus of its integers to
+    /// $2^k$, rounded to the closest integer, and stored as an integer of $[0, 2^k)$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::CiphertextModulusLog;
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// use concrete_core::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+    ///
+    /// let input = LweCiphertext::from_container(vec![3_u64 << 60, (1 << 63) + (1 << 31), 1 << 30]);
+    ///
+    /// let mut output = LweCiphertext::from_container(vec![0_u32; 3]);
+    /// output.fill_with_modulus_switch(&input, CiphertextModulusLog(32));
+    /// assert_eq!(output.as_tensor().as_slice(), &[3 << 28, (1 << 31) + 1, 0]);
+    ///
+    /// let mut output = LweCiphertext::from_container(vec![0_u32; 3]);
+    /// output.fill_with_modulus_switch(&input, CiphertextModulusLog(4));
+    /// assert_eq!(output.as_tensor().as_slice(), &[3, 8, 0]);
+    /// ```
+    pub fn fill_with_modulus_switch<InputScalar, OutputScalar, InputCont>(
+        &mut self,
+        input: &LweCiphertext<InputCont>,
+        modulus_log: CiphertextModulusLog,
+    ) where
+        Self: AsMutTensor<Element = OutputScalar>,
+        LweCiphertext<InputCont>: AsRefTensor<Element = InputScalar>,
+        InputScalar: UnsignedInteger + CastInto<u128>,
+        OutputScalar: UnsignedInteger + CastFrom<u128>,
+    {
+        debug_assert!(modulus_log.0 >= 1 && modulus_log.0 <= InputScalar::BITS);
+        debug_assert!(modulus_log.0 <= OutputScalar::BITS);
+        let shift = InputScalar::BITS - modulus_log.0;
+        let mask = (1_u128 << modulus_log.0) - 1;
+        self.as_mut_tensor().fill_with_one(input.as_tensor(), |i| {
+            let coefficient: u128 = (*i).cast_into();
+            let rounded = if shift == 0 {
+                coefficient
+            } else {
+                ((coefficient >> (shift - 1)) + 1) >> 1
+            };
+            OutputScalar::cast_from(rounded & mask)
+        });
+    }
+
     /// Fills the ciphertext with the result of the multisum of the `input_list` with the
     /// `weights` values, and adds a bias.
     ///
@@ -372,14 +490,12 @@ impl<Cont> LweCiphertext<Cont> {
         Self: AsMutTensor<Element = Scalar>,
         LweList<InputCont>: AsRefTensor<Element = Scalar>,
         CleartextList<WeightCont>: AsRefTensor<Element = Scalar>,
-        Scalar: UnsignedInteger,
+        Scalar: UnsignedTorus,
     {
         // loop over the ciphertexts and the weights
         for (input_cipher, weight) in input_list.ciphertext_iter().zip(weights.cleartext_iter()) {
-            let cipher_tens = input_cipher.as_tensor();
-            self.as_mut_tensor().update_with_one(cipher_tens, |o, c| {
-                *o = o.wrapping_add(c.wrapping_mul(weight.0))
-            });
+            self.as_mut_tensor()
+                .update_with_wrapping_add_element_mul(input_cipher.as_tensor(), weight.0);
         }
 
         // add the bias
@@ -586,6 +702,60 @@ impl<Cont> LweCiphertext<Cont> {
             .update_with_wrapping_scalar_mul(&scalar.0)
     }
 
+    /// Adds the multiplication of another ciphertext with a scalar value to the current
+    /// ciphertext, in a single pass.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::LweSecretKey;
+    /// use concrete_core::backends::core::private::crypto::*;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    ///
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    /// let encoder = RealEncoder {
+    ///     offset: 0. as f32,
+    ///     delta: 20.,
+    /// };
+    ///
+    /// let plain_1: Plaintext<u32> = encoder.encode(Cleartext(2. as f32));
+    /// let plain_2: Plaintext<u32> = encoder.encode(Cleartext(1. as f32));
+    /// let mut cipher_1 = LweCiphertext::from_container(vec![0. as u32; 257]);
+    /// let mut cipher_2 = LweCiphertext::from_container(vec![0. as u32; 257]);
+    /// secret_key.encrypt_lwe(&mut cipher_1, &plain_1, noise, &mut encryption_generator);
+    /// secret_key.encrypt_lwe(&mut cipher_2, &plain_2, noise, &mut encryption_generator);
+    ///
+    /// cipher_1.update_with_add_scalar_mul(&cipher_2, &Cleartext(3));
+    ///
+    /// let mut decrypted = Plaintext(0 as u32);
+    /// secret_key.decrypt_lwe(&mut decrypted, &cipher_1);
+    /// let decoded = encoder.decode(decrypted);
+    ///
+    /// assert!((decoded.0 - 5.).abs() < 0.2);
+    /// ```
+    pub fn update_with_add_scalar_mul<Scalar, InputCont>(
+        &mut self,
+        input: &LweCiphertext<InputCont>,
+        scalar: &Cleartext<Scalar>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InputCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        self.as_mut_tensor()
+            .update_with_wrapping_add_element_mul(input.as_tensor(), scalar.0)
+    }
+
     /// Fills an LWE ciphertext with the sample extraction of one of the coefficients of a GLWE
     /// ciphertext.
     ///
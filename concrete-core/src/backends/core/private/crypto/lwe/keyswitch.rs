@@ -339,6 +339,36 @@ impl<Cont> LweKeyswitchKey<Cont> {
         LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
         LweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
+    {
+        self.fill_with_keyswitch_key_monitored(
+            before_key,
+            after_key,
+            noise_parameters,
+            generator,
+            |_| true,
+        );
+    }
+
+    /// Fills the current keyswitch key container with an actual keyswitching key, reporting the
+    /// progress of the generation to a monitor.
+    ///
+    /// The `monitor` closure is called with the number of input key coefficients switched so far,
+    /// after each one of them. The generation stops as soon as it returns `false`, in which case
+    /// the key is left partially filled, and `false` is returned.
+    pub fn fill_with_keyswitch_key_monitored<InKeyCont, OutKeyCont, Scalar, Monitor>(
+        &mut self,
+        before_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        after_key: &LweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+        mut monitor: Monitor,
+    ) -> bool
+    where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Monitor: FnMut(usize) -> bool,
     {
         // We instantiate a buffer
         let mut messages = PlaintextList::from_container(vec![
@@ -351,10 +381,11 @@ impl<Cont> LweKeyswitchKey<Cont> {
         let decomp_base_log = self.decomp_base_log;
 
         // loop over the before key blocks
-        for (input_key_bit, keyswitch_key_block) in before_key
+        for (index, (input_key_bit, keyswitch_key_block)) in before_key
             .as_tensor()
             .iter()
             .zip(self.bit_decomp_iter_mut())
+            .enumerate()
         {
             // We reset the buffer
             messages
@@ -379,7 +410,12 @@ impl<Cont> LweKeyswitchKey<Cont> {
                 noise_parameters,
                 generator,
             );
+
+            if !monitor(index + 1) {
+                return false;
+            }
         }
+        true
     }
 
     /// Iterates over borrowed `LweKeyBitDecomposition` elements.
@@ -557,6 +593,114 @@ impl<Cont> LweKeyswitchKey<Cont> {
         }
     }
 
+    /// Computes the gadget product of the mask of a ciphertext with the keyswitch key, using only
+    /// the `level_count` most significant levels of the decomposition.
+    ///
+    /// The mask elements are rounded to the precision of the kept levels, decomposed, and
+    /// multiplied with the matching encryptions of the keyswitch key. The output encrypts the
+    /// product of the rounded mask with the input key, under the output key. The keyswitch of a
+    /// ciphertext is the difference between its trivially encrypted body and this product,
+    /// computed with all the levels.
+    pub fn gadget_product_ciphertext<InCont, OutCont, Scalar>(
+        &self,
+        after: &mut LweCiphertext<OutCont>,
+        before: &LweCiphertext<InCont>,
+        level_count: DecompositionLevelCount,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.before_key_size().0 => before.get_mask().mask_size().0);
+        ck_dim_eq!(self.after_key_size().0 => after.get_mask().mask_size().0);
+        debug_assert!(level_count.0 >= 1 && level_count.0 <= self.decomp_level_count.0);
+
+        after.as_mut_tensor().fill_with(|| Scalar::ZERO);
+        let mut decomp = Tensor::allocate(Scalar::ZERO, level_count.0);
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, level_count);
+
+        for (block, before_mask) in self
+            .bit_decomp_iter()
+            .zip(before.get_mask().mask_element_iter())
+        {
+            let mask_rounded = decomposer.closest_representable(*before_mask);
+
+            torus_small_sign_decompose(decomp.as_mut_slice(), mask_rounded, self.decomp_base_log.0);
+
+            // The levels of a block are stored from the most significant one, hence the dropped
+            // levels are the last ones.
+            for (level_key_cipher, decomposed) in block
+                .as_tensor()
+                .subtensor_iter(self.after_key_size().0 + 1)
+                .zip(decomp.iter())
+            {
+                after
+                    .as_mut_tensor()
+                    .update_with_wrapping_add_element_mul(&level_key_cipher, *decomposed);
+            }
+        }
+    }
+
+    /// Keyswitches a ciphertext from a key to a prefix of this key.
+    ///
+    /// The keyswitch key must have been filled with the encryptions of the coefficients of the
+    /// input key which are not shared with the output key, that is, the last
+    /// `before_key_size()` coefficients of the input key. The first coefficients of the input
+    /// mask are multiplied by the shared coefficients: they are copied to the output along with
+    /// the body, and only the remaining ones are keyswitched.
+    pub fn shrinking_keyswitch_ciphertext<InCont, OutCont, Scalar>(
+        &self,
+        after: &mut LweCiphertext<OutCont>,
+        before: &LweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let shared_size = self.after_key_size().0;
+        ck_dim_eq!(self.before_key_size().0 + shared_size => before.get_mask().mask_size().0);
+        ck_dim_eq!(shared_size => after.get_mask().mask_size().0);
+
+        // We copy the shared part of the mask, and the body
+        after.as_mut_tensor().as_mut_slice()[..shared_size]
+            .copy_from_slice(&before.as_tensor().as_slice()[..shared_size]);
+        *after.get_mut_body() = *before.get_body();
+
+        // We allocate a buffer to hold the decomposition.
+        let mut decomp = Tensor::allocate(Scalar::ZERO, self.decomp_level_count.0);
+
+        // We instantiate a decomposer
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+
+        for (block, before_mask) in self
+            .bit_decomp_iter()
+            .zip(before.get_mask().mask_element_iter().skip(shared_size))
+        {
+            let mask_rounded = decomposer.closest_representable(*before_mask);
+
+            torus_small_sign_decompose(decomp.as_mut_slice(), mask_rounded, self.decomp_base_log.0);
+
+            // loop over the number of levels
+            for (level_key_cipher, decomposed) in block
+                .as_tensor()
+                .subtensor_iter(shared_size + 1)
+                .zip(decomp.iter())
+            {
+                after
+                    .as_mut_tensor()
+                    .update_with_wrapping_sub_element_mul(&level_key_cipher, *decomposed);
+            }
+        }
+    }
+
+    /// Keyswitches a list of ciphertexts.
+    ///
+    /// The result is the same as the one of [`LweKeyswitchKey::keyswitch_ciphertext`] applied to
+    /// every ciphertext of the list, but the key is traversed only once: the encryptions of each
+    /// input key coefficient are applied to all the ciphertexts while they are hot in the cache,
+    /// and the decomposition buffer is allocated once for the whole list.
     pub fn keyswitch_list<InCont, OutCont, Scalar>(
         &self,
         output: &mut LweList<OutCont>,
@@ -568,11 +712,55 @@ impl<Cont> LweKeyswitchKey<Cont> {
         Scalar: UnsignedTorus,
     {
         ck_dim_eq!(input.count().0 => output.count().0);
-        // for each ciphertext, call mono_key_switch
-        for (input_cipher, mut output_cipher) in
-            input.ciphertext_iter().zip(output.ciphertext_iter_mut())
+        ck_dim_eq!(self.before_key_size().0 => input.lwe_size().to_lwe_dimension().0);
+        ck_dim_eq!(self.after_key_size().0 => output.lwe_size().to_lwe_dimension().0);
+        let level_count = self.decomp_level_count.0;
+        let after_size = output.lwe_size().0;
+
+        // We reset the outputs, and copy the bodies
+        for (mut output_cipher, input_cipher) in
+            output.ciphertext_iter_mut().zip(input.ciphertext_iter())
         {
-            self.keyswitch_ciphertext(&mut output_cipher, &input_cipher);
+            output_cipher.as_mut_tensor().fill_with(|| Scalar::ZERO);
+            *output_cipher.get_mut_body() = *input_cipher.get_body();
+        }
+
+        // We allocate a buffer to hold the decompositions of a mask element of every ciphertext.
+        let mut decomps = Tensor::allocate(Scalar::ZERO, input.count().0 * level_count);
+
+        // We instantiate a decomposer
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+
+        for (mask_index, block) in self.bit_decomp_iter().enumerate() {
+            for (mut decomp, input_cipher) in decomps
+                .subtensor_iter_mut(level_count)
+                .zip(input.ciphertext_iter())
+            {
+                let mask_rounded = decomposer
+                    .closest_representable(input_cipher.as_tensor().as_slice()[mask_index]);
+                torus_small_sign_decompose(
+                    decomp.as_mut_slice(),
+                    mask_rounded,
+                    self.decomp_base_log.0,
+                );
+            }
+
+            // loop over the number of levels
+            for (level, level_key_cipher) in
+                block.as_tensor().subtensor_iter(after_size).enumerate()
+            {
+                for (mut output_cipher, decomp) in output
+                    .ciphertext_iter_mut()
+                    .zip(decomps.subtensor_iter(level_count))
+                {
+                    output_cipher
+                        .as_mut_tensor()
+                        .update_with_wrapping_sub_element_mul(
+                            &level_key_cipher,
+                            *decomp.get_element(level),
+                        );
+                }
+            }
         }
     }
 }
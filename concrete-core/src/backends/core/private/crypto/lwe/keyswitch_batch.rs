@@ -0,0 +1,233 @@
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweKeyswitchKeyCount,
+    LweKeyswitchKeyIndex, LweSize,
+};
+
+use crate::backends::core::private::math::tensor::{
+    ck_dim_eq, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::{LweCiphertext, LweKeyswitchKey};
+
+/// A batch of LWE keyswitching keys.
+///
+/// The keys of a batch share their input key and their parameters, but switch to different output
+/// keys, for instance the keys of the different clients of a server. They are stored contiguously,
+/// one after the other, and are selected by their index when keyswitching a ciphertext.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweKeyswitchKeyBatch<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    input_size: LweDimension,
+    lwe_size: LweSize,
+}
+
+tensor_traits!(LweKeyswitchKeyBatch);
+
+impl<Scalar> LweKeyswitchKeyBatch<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates an empty batch of keyswitching keys.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweKeyswitchKeyCount,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::lwe::LweKeyswitchKeyBatch;
+    /// let batch = LweKeyswitchKeyBatch::<Vec<u64>>::allocate(
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     LweDimension(10),
+    ///     LweDimension(20),
+    /// );
+    /// assert_eq!(batch.key_count(), LweKeyswitchKeyCount(0));
+    /// assert_eq!(batch.before_key_size(), LweDimension(10));
+    /// assert_eq!(batch.after_key_size(), LweDimension(20));
+    /// ```
+    pub fn allocate(
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_size: LweDimension,
+        output_size: LweDimension,
+    ) -> Self {
+        LweKeyswitchKeyBatch {
+            tensor: Tensor::from_container(Vec::new()),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            input_size,
+            lwe_size: output_size.to_lwe_size(),
+        }
+    }
+
+    /// Appends a keyswitching key at the end of the batch.
+    ///
+    /// The key is copied after the last key of the batch, and its index is the number of keys in
+    /// the batch before the call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweKeyswitchKeyCount,
+    ///     LweKeyswitchKeyIndex,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::lwe::{
+    ///     LweKeyswitchKey, LweKeyswitchKeyBatch,
+    /// };
+    /// let mut batch = LweKeyswitchKeyBatch::<Vec<u64>>::allocate(
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    ///     LweDimension(10),
+    ///     LweDimension(20),
+    /// );
+    /// let ksk = LweKeyswitchKey::allocate(
+    ///     1 as u64,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    ///     LweDimension(10),
+    ///     LweDimension(20),
+    /// );
+    /// let index = batch.append_key(&ksk);
+    /// assert_eq!(index, LweKeyswitchKeyIndex(0));
+    /// assert_eq!(batch.key_count(), LweKeyswitchKeyCount(1));
+    /// ```
+    pub fn append_key<KeyCont>(&mut self, key: &LweKeyswitchKey<KeyCont>) -> LweKeyswitchKeyIndex
+    where
+        LweKeyswitchKey<KeyCont>: AsRefTensor<Element = Scalar>,
+    {
+        ck_dim_eq!(self.before_key_size().0 => key.before_key_size().0);
+        ck_dim_eq!(self.after_key_size().0 => key.after_key_size().0);
+        ck_dim_eq!(self.decomp_level_count.0 => key.decomposition_levels_count().0);
+        let index = LweKeyswitchKeyIndex(self.key_count().0);
+        self.tensor
+            .as_mut_container()
+            .extend_from_slice(key.as_tensor().as_slice());
+        index
+    }
+
+    /// Removes the keyswitching key at a given index from the batch.
+    ///
+    /// The keys stored after the removed one are moved one position down, hence their indices are
+    /// decremented.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweKeyswitchKeyCount,
+    ///     LweKeyswitchKeyIndex,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::lwe::{
+    ///     LweKeyswitchKey, LweKeyswitchKeyBatch,
+    /// };
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    /// let mut batch = LweKeyswitchKeyBatch::<Vec<u64>>::allocate(
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    ///     LweDimension(10),
+    ///     LweDimension(20),
+    /// );
+    /// for value in 0..3 {
+    ///     let ksk = LweKeyswitchKey::allocate(
+    ///         value as u64,
+    ///         DecompositionLevelCount(3),
+    ///         DecompositionBaseLog(4),
+    ///         LweDimension(10),
+    ///         LweDimension(20),
+    ///     );
+    ///     batch.append_key(&ksk);
+    /// }
+    /// batch.remove_key(LweKeyswitchKeyIndex(1));
+    /// assert_eq!(batch.key_count(), LweKeyswitchKeyCount(2));
+    /// let moved = batch.get_key(LweKeyswitchKeyIndex(1));
+    /// assert!(moved.as_tensor().iter().all(|value| *value == 2));
+    /// ```
+    pub fn remove_key(&mut self, index: LweKeyswitchKeyIndex) {
+        let key_len = self.key_len();
+        let container = self.tensor.as_mut_container();
+        let len = container.len();
+        container.copy_within((index.0 + 1) * key_len.., index.0 * key_len);
+        container.truncate(len - key_len);
+    }
+}
+
+impl<Cont> LweKeyswitchKeyBatch<Cont> {
+    /// Returns the size of the input key of the keys of the batch.
+    pub fn before_key_size(&self) -> LweDimension {
+        self.input_size
+    }
+
+    /// Returns the size of the output keys of the keys of the batch.
+    pub fn after_key_size(&self) -> LweDimension {
+        LweDimension(self.lwe_size.0 - 1)
+    }
+
+    /// Returns the number of levels used for the decomposition of the keys.
+    pub fn decomposition_levels_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the keys.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the number of keys in the batch.
+    pub fn key_count(&self) -> LweKeyswitchKeyCount
+    where
+        Self: AsRefTensor,
+    {
+        let key_len = self.key_len();
+        if key_len == 0 {
+            return LweKeyswitchKeyCount(0);
+        }
+        LweKeyswitchKeyCount(self.as_tensor().len() / key_len)
+    }
+
+    /// Returns a view of the keyswitching key at a given index.
+    pub fn get_key<Scalar>(&self, index: LweKeyswitchKeyIndex) -> LweKeyswitchKey<&[Scalar]>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+    {
+        let key_len = self.key_len();
+        let tensor = self
+            .as_tensor()
+            .get_sub(index.0 * key_len..(index.0 + 1) * key_len);
+        LweKeyswitchKey::from_container(
+            tensor.into_container(),
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.after_key_size(),
+        )
+    }
+
+    /// Keyswitches a ciphertext with the keyswitching key at a given index.
+    ///
+    /// See [`LweKeyswitchKey::keyswitch_ciphertext`] for the details of the keyswitch.
+    pub fn keyswitch_ciphertext<InCont, OutCont, Scalar>(
+        &self,
+        index: LweKeyswitchKeyIndex,
+        after: &mut LweCiphertext<OutCont>,
+        before: &LweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        self.get_key(index).keyswitch_ciphertext(after, before);
+    }
+
+    fn key_len(&self) -> usize {
+        self.input_size.0 * self.lwe_size.0 * self.decomp_level_count.0
+    }
+}
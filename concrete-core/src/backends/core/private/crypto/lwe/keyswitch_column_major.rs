@@ -0,0 +1,268 @@
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
+};
+
+use crate::backends::core::private::math::decomposition::{
+    torus_small_sign_decompose, SignedDecomposer,
+};
+use crate::backends::core::private::math::tensor::{
+    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::{LweCiphertext, LweKeyswitchKey};
+
+/// An LWE keyswitching key stored in column-major order.
+///
+/// This key holds the same values as an [`LweKeyswitchKey`], but transposed: instead of storing
+/// the ciphertexts encrypting the levels of the decomposition of each input key bit one after the
+/// other, it stores, for each coefficient of the output ciphertext, the row of the matching
+/// coefficients of all these ciphertexts. Applying the key then amounts to decomposing the whole
+/// input mask once, and computing one dot product per output coefficient, which streams over the
+/// key contiguously.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnMajorLweKeyswitchKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    lwe_size: LweSize,
+}
+
+tensor_traits!(ColumnMajorLweKeyswitchKey);
+
+impl<Scalar> ColumnMajorLweKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a column-major keyswitching key whose coefficients are all `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::lwe::ColumnMajorLweKeyswitchKey;
+    /// let ksk = ColumnMajorLweKeyswitchKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     LweDimension(10),
+    ///     LweDimension(20),
+    /// );
+    /// assert_eq!(ksk.lwe_size(), LweSize(21));
+    /// assert_eq!(ksk.before_key_size(), LweDimension(10));
+    /// assert_eq!(ksk.after_key_size(), LweDimension(20));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_size: LweDimension,
+        output_size: LweDimension,
+    ) -> Self {
+        ColumnMajorLweKeyswitchKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                decomp_size.0 * (output_size.0 + 1) * input_size.0
+            ]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            lwe_size: output_size.to_lwe_size(),
+        }
+    }
+
+    /// Creates a column-major keyswitching key holding the transposition of a keyswitching key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::lwe::{
+    ///     ColumnMajorLweKeyswitchKey, LweKeyswitchKey,
+    /// };
+    /// let ksk = LweKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    ///     LweDimension(10),
+    ///     LweDimension(20),
+    /// );
+    /// let transposed = ColumnMajorLweKeyswitchKey::from_keyswitch_key(&ksk);
+    /// assert_eq!(transposed.before_key_size(), LweDimension(10));
+    /// assert_eq!(transposed.after_key_size(), LweDimension(20));
+    /// assert_eq!(
+    ///     transposed.decomposition_levels_count(),
+    ///     DecompositionLevelCount(3)
+    /// );
+    /// ```
+    pub fn from_keyswitch_key<Cont>(ksk: &LweKeyswitchKey<Cont>) -> Self
+    where
+        LweKeyswitchKey<Cont>: AsRefTensor<Element = Scalar>,
+    {
+        let lwe_size = ksk.lwe_size().0;
+        let row_len = ksk.before_key_size().0 * ksk.decomposition_levels_count().0;
+        let source = ksk.as_tensor().as_slice();
+        let mut tensor = Vec::with_capacity(source.len());
+        for coefficient in 0..lwe_size {
+            tensor.extend((0..row_len).map(|index| source[index * lwe_size + coefficient]));
+        }
+        ColumnMajorLweKeyswitchKey {
+            tensor: Tensor::from_container(tensor),
+            decomp_base_log: ksk.decomposition_base_log(),
+            decomp_level_count: ksk.decomposition_levels_count(),
+            lwe_size: ksk.lwe_size(),
+        }
+    }
+}
+
+impl<Cont> ColumnMajorLweKeyswitchKey<Cont> {
+    /// Returns the size of the output key.
+    pub fn after_key_size(&self) -> LweDimension
+    where
+        Self: AsRefTensor,
+    {
+        self.lwe_size.to_lwe_dimension()
+    }
+
+    /// Returns the size of the output ciphertexts.
+    pub fn lwe_size(&self) -> LweSize
+    where
+        Self: AsRefTensor,
+    {
+        self.lwe_size
+    }
+
+    /// Returns the size of the input key.
+    pub fn before_key_size(&self) -> LweDimension
+    where
+        Self: AsRefTensor,
+    {
+        LweDimension(self.as_tensor().len() / (self.lwe_size.0 * self.decomp_level_count.0))
+    }
+
+    /// Returns the number of levels used for the decomposition of the input key bits.
+    pub fn decomposition_levels_count(&self) -> DecompositionLevelCount
+    where
+        Self: AsRefTensor,
+    {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the input key bits.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog
+    where
+        Self: AsRefTensor,
+    {
+        self.decomp_base_log
+    }
+
+    /// Switches the key of a single LWE ciphertext.
+    ///
+    /// The output is the same as the one of [`LweKeyswitchKey::keyswitch_ciphertext`] with the
+    /// row-major version of the key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::LweSecretKey;
+    ///
+    /// let input_size = LweDimension(256);
+    /// let output_size = LweDimension(128);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let output_key = LweSecretKey::generate_binary(output_size, &mut secret_generator);
+    ///
+    /// let mut ksk = LweKeyswitchKey::allocate(
+    ///     0 as u64,
+    ///     DecompositionLevelCount(8),
+    ///     DecompositionBaseLog(3),
+    ///     input_size,
+    ///     output_size,
+    /// );
+    /// ksk.fill_with_keyswitch_key(&input_key, &output_key, noise, &mut encryption_generator);
+    /// let transposed = ColumnMajorLweKeyswitchKey::from_keyswitch_key(&ksk);
+    ///
+    /// let mut ciphertext = LweCiphertext::allocate(0 as u64, LweSize(257));
+    /// input_key.encrypt_lwe(
+    ///     &mut ciphertext,
+    ///     &Plaintext(1432154329994324),
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let mut expected = LweCiphertext::allocate(0 as u64, LweSize(129));
+    /// let mut switched = LweCiphertext::allocate(0 as u64, LweSize(129));
+    /// ksk.keyswitch_ciphertext(&mut expected, &ciphertext);
+    /// transposed.keyswitch_ciphertext(&mut switched, &ciphertext);
+    /// assert_eq!(switched, expected);
+    /// ```
+    pub fn keyswitch_ciphertext<InCont, OutCont, Scalar>(
+        &self,
+        after: &mut LweCiphertext<OutCont>,
+        before: &LweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.before_key_size().0 => before.get_mask().mask_size().0);
+        ck_dim_eq!(self.after_key_size().0 => after.get_mask().mask_size().0);
+        let level_count = self.decomp_level_count.0;
+        let row_len = self.before_key_size().0 * level_count;
+
+        // We decompose the whole input mask at once, in the order of the rows of the key.
+        let mut decomp = vec![Scalar::ZERO; row_len];
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+        for (digits, before_mask) in decomp
+            .chunks_mut(level_count)
+            .zip(before.get_mask().mask_element_iter())
+        {
+            let mask_rounded = decomposer.closest_representable(*before_mask);
+            torus_small_sign_decompose(digits, mask_rounded, self.decomp_base_log.0);
+        }
+
+        // Each output coefficient is minus the dot product of the digits with its row, to which
+        // the input body is added for the output body.
+        let body = *before.get_body();
+        let output_size = self.lwe_size.0;
+        for (index, (output, row)) in after
+            .as_mut_tensor()
+            .as_mut_slice()
+            .iter_mut()
+            .zip(self.as_tensor().as_slice().chunks(row_len))
+            .enumerate()
+        {
+            let product = row
+                .iter()
+                .zip(decomp.iter())
+                .fold(Scalar::ZERO, |acc, (key, digit)| {
+                    acc.wrapping_add(key.wrapping_mul(*digit))
+                });
+            let start = if index == output_size - 1 {
+                body.0
+            } else {
+                Scalar::ZERO
+            };
+            *output = start.wrapping_sub(product);
+        }
+    }
+}
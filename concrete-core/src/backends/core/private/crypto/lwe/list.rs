@@ -3,13 +3,15 @@ use serde::{Deserialize, Serialize};
 
 use crate::backends::core::private::crypto::encoding::{CleartextList, PlaintextList};
 use crate::backends::core::private::math::tensor::{
-    ck_dim_div, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+    ck_dim_div, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
 use crate::backends::core::private::math::torus::UnsignedTorus;
 use crate::backends::core::private::utils::{zip, zip_args};
 
 use super::LweCiphertext;
-use concrete_commons::parameters::{CiphertextCount, CleartextCount, LweDimension, LweSize};
+use concrete_commons::parameters::{
+    CiphertextCount, CiphertextLayout, CleartextCount, LweDimension, LweSize,
+};
 
 /// A list of ciphertext encoded with the LWE scheme.
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
@@ -421,6 +423,38 @@ impl<Cont> LweList<Cont> {
         }
     }
 
+    /// Reorders the coefficients of every ciphertext of the list in place, from the `from`
+    /// layout to the `to` layout.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{CiphertextLayout, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweList;
+    /// use concrete_core::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+    /// let mut list = LweList::from_container(vec![1_u8, 2, 3, 4, 5, 6], LweSize(3));
+    /// list.reorder_layout(CiphertextLayout::MaskFirst, CiphertextLayout::BodyFirst);
+    /// assert_eq!(list.as_tensor().as_slice(), &[3, 1, 2, 6, 4, 5]);
+    /// list.reorder_layout(CiphertextLayout::BodyFirst, CiphertextLayout::MaskFirst);
+    /// assert_eq!(list.as_tensor().as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn reorder_layout(&mut self, from: CiphertextLayout, to: CiphertextLayout)
+    where
+        Self: AsMutTensor,
+    {
+        let lwe_size = self.lwe_size.0;
+        let ciphertexts = self.as_mut_tensor().subtensor_iter_mut(lwe_size);
+        match (from, to) {
+            (CiphertextLayout::MaskFirst, CiphertextLayout::BodyFirst) => {
+                ciphertexts.for_each(|mut ciphertext| ciphertext.as_mut_slice().rotate_right(1))
+            }
+            (CiphertextLayout::BodyFirst, CiphertextLayout::MaskFirst) => {
+                ciphertexts.for_each(|mut ciphertext| ciphertext.as_mut_slice().rotate_left(1))
+            }
+            _ => {}
+        }
+    }
+
     pub fn fill_with_trivial_encryption<InputCont, Scalar>(
         &mut self,
         encoded: &PlaintextList<InputCont>,
@@ -1,8 +1,14 @@
 //! LWE encryption scheme.
 pub use ciphertext::*;
 pub use keyswitch::*;
+pub use keyswitch_batch::*;
+pub use keyswitch_column_major::*;
 pub use list::*;
+pub use seeded::*;
 
 mod ciphertext;
 mod keyswitch;
+mod keyswitch_batch;
+mod keyswitch_column_major;
 mod list;
+mod seeded;
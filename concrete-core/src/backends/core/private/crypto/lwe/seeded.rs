@@ -0,0 +1,122 @@
+use crate::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::backends::core::private::math::tensor::{ck_dim_eq, AsMutTensor};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::{LweBody, LweCiphertext};
+
+use concrete_commons::parameters::{LweDimension, LweSize};
+
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A seeded LWE ciphertext.
+///
+/// A seeded LWE ciphertext only stores the body of the ciphertext, along with the seed of the
+/// generator producing its mask. The mask being uniformly random, the message is entirely carried
+/// by the body (see
+/// [`LweSecretKey::encrypt_seeded_lwe`](`crate::backends::core::private::crypto::secret::LweSecretKey::encrypt_seeded_lwe`)).
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededCiphertext<Scalar> {
+    body: Scalar,
+    lwe_dimension: LweDimension,
+    seed: u128,
+}
+
+impl<Scalar: Copy> LweSeededCiphertext<Scalar> {
+    /// Allocates a new seeded ciphertext whose body is `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::backends::core::private::crypto::lwe::LweSeededCiphertext;
+    ///
+    /// let ciphertext = LweSeededCiphertext::allocate(0 as u8, LweSize(10), 42);
+    /// assert_eq!(ciphertext.lwe_size(), LweSize(10));
+    /// assert_eq!(ciphertext.seed(), 42);
+    /// ```
+    pub fn allocate(value: Scalar, lwe_size: LweSize, seed: u128) -> Self {
+        LweSeededCiphertext {
+            body: value,
+            lwe_dimension: lwe_size.to_lwe_dimension(),
+            seed,
+        }
+    }
+
+    /// Returns the size of the expanded ciphertext, e.g. the size of the mask + 1 for the body.
+    pub fn lwe_size(&self) -> LweSize {
+        self.lwe_dimension.to_lwe_size()
+    }
+
+    /// Returns the seed of the generator producing the mask of the ciphertext.
+    pub fn seed(&self) -> u128 {
+        self.seed
+    }
+
+    /// Returns the body of the ciphertext.
+    pub fn get_body(&self) -> LweBody<Scalar> {
+        LweBody(self.body)
+    }
+
+    /// Sets the body of the ciphertext.
+    pub fn set_body(&mut self, body: LweBody<Scalar>) {
+        self.body = body.0;
+    }
+
+    /// Returns the ratio between the size of the expanded ciphertext, and the size of the seeded
+    /// ciphertext, seed included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::backends::core::private::crypto::lwe::LweSeededCiphertext;
+    ///
+    /// let ciphertext = LweSeededCiphertext::allocate(0 as u64, LweSize(631), 0);
+    /// assert!(ciphertext.compression_ratio() > 210. && ciphertext.compression_ratio() < 211.);
+    /// ```
+    pub fn compression_ratio(&self) -> f64 {
+        let element_bytes = std::mem::size_of::<Scalar>();
+        let seeded_bytes = element_bytes + std::mem::size_of::<u128>();
+        let expanded_bytes = self.lwe_size().0 * element_bytes;
+        expanded_bytes as f64 / seeded_bytes as f64
+    }
+
+    /// Expands the seeded ciphertext into a standard LWE ciphertext, by regenerating the mask from
+    /// the seed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{LweDimension, LweSize};
+    /// use concrete_core::backends::core::private::crypto::encoding::Plaintext;
+    /// use concrete_core::backends::core::private::crypto::lwe::{LweCiphertext, LweSeededCiphertext};
+    /// use concrete_core::backends::core::private::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::backends::core::private::crypto::secret::LweSecretKey;
+    ///
+    /// let mut generator = SecretRandomGenerator::new(None);
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut generator);
+    /// let mut seeded = LweSeededCiphertext::allocate(0 as u32, LweSize(257), 42);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    /// secret_key.encrypt_seeded_lwe(&mut seeded, &Plaintext(1 << 28), noise);
+    ///
+    /// let mut ciphertext = LweCiphertext::allocate(0 as u32, LweSize(257));
+    /// seeded.expand_into(&mut ciphertext);
+    /// let mut decrypted = Plaintext(0 as u32);
+    /// secret_key.decrypt_lwe(&mut decrypted, &ciphertext);
+    /// assert_eq!(decrypted.0.wrapping_add(1 << 27) >> 28, 1);
+    /// ```
+    pub fn expand_into<OutCont>(&self, output: &mut LweCiphertext<OutCont>)
+    where
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.lwe_size().0 => output.lwe_size().0);
+        let mut generator = EncryptionRandomGenerator::new(Some(self.seed));
+        let (body, mut mask) = output.get_mut_body_and_mask();
+        generator.fill_tensor_with_random_mask(&mut mask);
+        body.0 = self.body;
+    }
+}
@@ -1,9 +1,13 @@
 use crate::backends::core::private::math::random::{
-    Gaussian, RandomGenerable, RandomGenerator, Uniform,
+    Gaussian, RandomGenerable, RandomGenerator, TUniform, Uniform, UniformBounded,
 };
 use crate::backends::core::private::math::tensor::AsMutTensor;
+use crate::backends::core::private::math::torus::UnsignedTorus;
 
-use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::dispersion::{
+    BoundedUniform, DispersionParameter, ModularDistribution, NoiseDistribution,
+    TUniform as TUniformDispersion,
+};
 use concrete_commons::numeric::UnsignedInteger;
 use concrete_commons::parameters::{
     DecompositionLevelCount, GlweDimension, GlweSize, LweDimension, LweSize, PolynomialSize,
@@ -17,6 +21,8 @@ pub struct EncryptionRandomGenerator {
     mask: RandomGenerator,
     // A separate noise generator, only used to generate the noise elements.
     noise: RandomGenerator,
+    // The distribution the noise is sampled from, when given as a gaussian dispersion.
+    noise_distribution: NoiseDistribution,
 }
 
 impl EncryptionRandomGenerator {
@@ -25,9 +31,37 @@ impl EncryptionRandomGenerator {
         EncryptionRandomGenerator {
             mask: RandomGenerator::new(seed),
             noise: RandomGenerator::new(None),
+            noise_distribution: NoiseDistribution::Gaussian,
+        }
+    }
+
+    /// Creates a new encryption generator, seeding both the mask and the noise generators.
+    ///
+    /// No entropy is drawn from the platform, and every sample is determined by the seeds. This
+    /// is only meant for testing purpose.
+    pub fn new_seeded(mask_seed: u128, noise_seed: u128) -> EncryptionRandomGenerator {
+        EncryptionRandomGenerator {
+            mask: RandomGenerator::new(Some(mask_seed)),
+            noise: RandomGenerator::new(Some(noise_seed)),
+            noise_distribution: NoiseDistribution::Gaussian,
         }
     }
 
+    /// Returns the distribution the noise is sampled from, when given as a gaussian dispersion.
+    pub fn noise_distribution(&self) -> NoiseDistribution {
+        self.noise_distribution
+    }
+
+    /// Sets the distribution the noise is sampled from, when given as a gaussian dispersion.
+    ///
+    /// The dispersion parameters describing a gaussian distribution, such as
+    /// [`Variance`](concrete_commons::dispersion::Variance), are then replaced by the
+    /// distribution of this family with the same variance on the torus. The other dispersion
+    /// parameters, such as [`BoundedUniform`], are sampled as described.
+    pub fn set_noise_distribution(&mut self, noise_distribution: NoiseDistribution) {
+        self.noise_distribution = noise_distribution;
+    }
+
     // Allows to seed the noise generator. For testing purpose only.
     #[allow(dead_code)]
     pub(crate) fn seed_noise_generator(&mut self, seed: u128) {
@@ -177,10 +211,15 @@ impl EncryptionRandomGenerator {
         let noise_iter = self.noise.try_fork(n_child, noise_bytes)?;
 
         // We return a proper iterator.
+        let noise_distribution = self.noise_distribution;
         Some(
             mask_iter
                 .zip(noise_iter)
-                .map(|(mask, noise)| EncryptionRandomGenerator { mask, noise }),
+                .map(move |(mask, noise)| EncryptionRandomGenerator {
+                    mask,
+                    noise,
+                    noise_distribution,
+                }),
         )
     }
 
@@ -197,13 +236,23 @@ impl EncryptionRandomGenerator {
         let noise_iter = self.noise.par_try_fork(n_child, noise_bytes)?;
 
         // We return a proper iterator.
+        let noise_distribution = self.noise_distribution;
         Some(
             mask_iter
                 .zip(noise_iter)
-                .map(|(mask, noise)| EncryptionRandomGenerator { mask, noise }),
+                .map(move |(mask, noise)| EncryptionRandomGenerator {
+                    mask,
+                    noise,
+                    noise_distribution,
+                }),
         )
     }
 
+    // Draws a fresh seed from the mask generator, used to seed the masks of a seeded ciphertext.
+    pub(crate) fn random_seed(&mut self) -> u128 {
+        self.mask.random_uniform()
+    }
+
     // Fills the tensor with random uniform values, using the mask generator.
     pub(crate) fn fill_tensor_with_random_mask<Scalar, Tensorable>(
         &mut self,
@@ -215,18 +264,53 @@ impl EncryptionRandomGenerator {
         self.mask.fill_tensor_with_random_uniform(output)
     }
 
+    // Returns the distribution of the noise described by a dispersion, in the precision of the
+    // scalars.
+    fn modular_noise_distribution<Scalar>(
+        &self,
+        dispersion: impl DispersionParameter,
+    ) -> ModularDistribution
+    where
+        Scalar: UnsignedTorus,
+    {
+        match (
+            dispersion.get_modular_distribution::<Scalar>(),
+            self.noise_distribution,
+        ) {
+            (ModularDistribution::Gaussian { .. }, NoiseDistribution::BoundedUniform) => {
+                BoundedUniform::from_dispersion(dispersion).get_modular_distribution::<Scalar>()
+            }
+            (ModularDistribution::Gaussian { .. }, NoiseDistribution::TUniform) => {
+                TUniformDispersion::from_dispersion(dispersion).get_modular_distribution::<Scalar>()
+            }
+            (distribution, _) => distribution,
+        }
+    }
+
     // Sample a noise value, using the noise generator.
     pub(crate) fn random_noise<Scalar>(&mut self, std: impl DispersionParameter) -> Scalar
     where
-        Scalar: RandomGenerable<Gaussian<f64>>,
+        Scalar: UnsignedTorus,
     {
-        <Scalar>::generate_one(
-            &mut self.noise,
-            Gaussian {
-                std: std.get_standard_dev(),
-                mean: 0.,
-            },
-        )
+        // A zero dispersion describes a noiseless encryption, whatever the noise distribution.
+        if std.get_variance() == 0. {
+            return Scalar::ZERO;
+        }
+        match self.modular_noise_distribution::<Scalar>(std) {
+            ModularDistribution::Gaussian { .. } => <Scalar>::generate_one(
+                &mut self.noise,
+                Gaussian {
+                    std: std.get_standard_dev(),
+                    mean: 0.,
+                },
+            ),
+            ModularDistribution::BoundedUniform { bound } => {
+                <Scalar>::generate_one(&mut self.noise, UniformBounded { bound })
+            }
+            ModularDistribution::TUniform { log2_bound } => {
+                <Scalar>::generate_one(&mut self.noise, TUniform { log2_bound })
+            }
+        }
     }
 
     // Fills the input tensor with random noise, using the noise generator.
@@ -236,10 +320,25 @@ impl EncryptionRandomGenerator {
         std: impl DispersionParameter,
     ) where
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>>,
+        Scalar: UnsignedTorus,
         Tensorable: AsMutTensor<Element = Scalar>,
     {
-        self.noise
-            .fill_tensor_with_random_gaussian(output, 0., std.get_standard_dev());
+        if std.get_variance() == 0. {
+            output.as_mut_tensor().fill_with_element(Scalar::ZERO);
+            return;
+        }
+        match self.modular_noise_distribution::<Scalar>(std) {
+            ModularDistribution::Gaussian { .. } => {
+                self.noise
+                    .fill_tensor_with_random_gaussian(output, 0., std.get_standard_dev())
+            }
+            ModularDistribution::BoundedUniform { bound } => self
+                .noise
+                .fill_tensor_with_random_uniform_bounded(output, bound),
+            ModularDistribution::TUniform { log2_bound } => self
+                .noise
+                .fill_tensor_with_random_t_uniform(output, log2_bound),
+        }
     }
 }
 
@@ -283,7 +382,8 @@ fn mask_bytes_per_ggsw<T: UnsignedInteger>(
 
 fn noise_bytes_per_coef() -> usize {
     // We use f64 to sample the noise for every precision, and we need 4/pi inputs to generate
-    // such an output (here we take 32 to keep a safety margin).
+    // such an output (here we take 32 to keep a safety margin). The bounded uniform and TUniform
+    // distributions are sampled without rejection, from at most 16 bytes per coefficient.
     8 * 32
 }
 fn noise_bytes_per_polynomial(poly_size: PolynomialSize) -> usize {
@@ -2,4 +2,5 @@ mod encryption;
 pub use encryption::EncryptionRandomGenerator;
 
 mod secret;
+pub(crate) use secret::derive_seed;
 pub use secret::SecretRandomGenerator;
@@ -12,6 +12,15 @@ impl SecretRandomGenerator {
         SecretRandomGenerator(RandomGenerator::new(seed))
     }
 
+    /// Creates a new generator, seeding it with a value derived from a master seed.
+    ///
+    /// The derived seed is the encryption of the `domain` and `index` values under the
+    /// `master_seed` key, such that the generators created for different domains or indices are
+    /// independent, while the same inputs always yield the same generator.
+    pub fn new_derived(master_seed: u128, domain: u64, index: u64) -> SecretRandomGenerator {
+        SecretRandomGenerator::new(Some(derive_seed(master_seed, domain, index)))
+    }
+
     /// Returns the number of remaining bytes, if the generator is bounded.
     pub fn remaining_bytes(&self) -> Option<usize> {
         self.0.remaining_bytes()
@@ -56,3 +65,14 @@ impl SecretRandomGenerator {
             .random_gaussian_tensor(length, 0.0, Scalar::GAUSSIAN_KEY_LOG_STD.get_standard_dev())
     }
 }
+
+// Derives a seed from a master seed, as the encryption of the `domain` and `index` values under
+// the `master_seed` key.
+pub(crate) fn derive_seed(master_seed: u128, domain: u64, index: u64) -> u128 {
+    let counter = ((domain as u128) << 64) | index as u128;
+    let mut derivation = RandomGenerator::new_with_counter(master_seed, counter);
+    let mut seed = [0u8; 16];
+    seed.iter_mut()
+        .for_each(|byte| *byte = derivation.generate_next());
+    u128::from_le_bytes(seed)
+}
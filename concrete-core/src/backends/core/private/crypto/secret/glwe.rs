@@ -1,5 +1,7 @@
 use crate::backends::core::private::crypto::encoding::{Plaintext, PlaintextList};
-use crate::backends::core::private::crypto::ggsw::StandardGgswCiphertext;
+use crate::backends::core::private::crypto::ggsw::{
+    StandardGgswCiphertext, StandardGgswSeededCiphertext,
+};
 use crate::backends::core::private::crypto::glwe::{GlweCiphertext, GlweList};
 use crate::backends::core::private::crypto::secret::LweSecretKey;
 use crate::backends::core::private::math::tensor::{
@@ -335,6 +337,34 @@ where
             kind: PhantomData,
         }
     }
+
+    /// Consumes an LWE secret key and turns it into a GLWE secret key, whose polynomials of size
+    /// `poly_size` are made of the consecutive coefficients of the LWE secret key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let lwe_secret_key: LweSecretKey<_, Vec<u32>> =
+    ///     LweSecretKey::generate_binary(LweDimension(20), &mut secret_generator);
+    /// let glwe_secret_key = GlweSecretKey::from_lwe_secret_key(lwe_secret_key, PolynomialSize(1));
+    /// assert_eq!(glwe_secret_key.key_size(), GlweDimension(20));
+    /// assert_eq!(glwe_secret_key.polynomial_size(), PolynomialSize(1));
+    /// ```
+    pub fn from_lwe_secret_key(
+        lwe_secret_key: LweSecretKey<Kind, Vec<Scalar>>,
+        poly_size: PolynomialSize,
+    ) -> Self {
+        ck_dim_div!(lwe_secret_key.tensor.len() => poly_size.0);
+        GlweSecretKey {
+            tensor: lwe_secret_key.tensor,
+            poly_size,
+            kind: PhantomData,
+        }
+    }
 }
 
 impl<Kind, Cont> GlweSecretKey<Kind, Cont>
@@ -714,6 +744,104 @@ where
             );
     }
 
+    /// Encrypts a single GLWE ciphertext, whose polynomials are reduced modulo $X^N - 1$ instead of
+    /// $X^N + 1$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{GlweDimension, GlweSize, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::encoding::PlaintextList;
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::*;
+    /// use concrete_core::backends::core::private::crypto::*;
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let secret_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(256),
+    ///     PolynomialSize(5),
+    ///     &mut secret_generator,
+    /// );
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    /// let plaintexts =
+    ///     PlaintextList::from_container(vec![100000 as u32, 200000, 300000, 400000, 500000]);
+    /// let mut ciphertext = GlweCiphertext::allocate(0 as u32, PolynomialSize(5), GlweSize(257));
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// secret_key.encrypt_cyclic_glwe(
+    ///     &mut ciphertext,
+    ///     &plaintexts,
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    /// let mut decrypted = PlaintextList::from_container(vec![0 as u32, 0, 0, 0, 0]);
+    /// secret_key.decrypt_cyclic_glwe(&mut decrypted, &ciphertext);
+    /// for (dec, plain) in decrypted.plaintext_iter().zip(plaintexts.plaintext_iter()) {
+    ///     let d0 = dec.0.wrapping_sub(plain.0);
+    ///     let d1 = plain.0.wrapping_sub(dec.0);
+    ///     let dist = std::cmp::min(d0, d1);
+    ///     assert!(dist < 400, "dist: {:?}", dist);
+    /// }
+    /// ```
+    pub fn encrypt_cyclic_glwe<Cont1, Cont2, Scalar>(
+        &self,
+        encrypted: &mut GlweCiphertext<Cont1>,
+        encoded: &PlaintextList<Cont2>,
+        noise_parameter: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<Cont1>: AsMutTensor<Element = Scalar>,
+        PlaintextList<Cont2>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(encoded.count().0 => encrypted.polynomial_size().0);
+        ck_dim_eq!(encrypted.mask_size().0 => self.key_size().0);
+        let (mut body, mut masks) = encrypted.get_mut_body_and_mask();
+        generator.fill_tensor_with_random_noise(&mut body, noise_parameter);
+        generator.fill_tensor_with_random_mask(&mut masks);
+        let mut body = body.as_mut_polynomial();
+        for (mask, key) in masks
+            .as_polynomial_list()
+            .polynomial_iter()
+            .zip(self.as_polynomial_list().polynomial_iter())
+        {
+            body.update_with_wrapping_add_cyclic_mul(&mask, &key);
+        }
+        body.update_with_wrapping_add(&encoded.as_polynomial());
+    }
+
+    /// Decrypts a single GLWE ciphertext, whose polynomials are reduced modulo $X^N - 1$ instead of
+    /// $X^N + 1$.
+    ///
+    /// See ['GlweSecretKey::encrypt_cyclic_glwe`] for an example.
+    pub fn decrypt_cyclic_glwe<CiphCont, EncCont, Scalar>(
+        &self,
+        encoded: &mut PlaintextList<EncCont>,
+        encrypted: &GlweCiphertext<CiphCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        PlaintextList<EncCont>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<CiphCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(encoded.count().0 => encrypted.polynomial_size().0);
+        let (body, masks) = encrypted.get_body_and_mask();
+        encoded
+            .as_mut_tensor()
+            .fill_with_one(body.as_tensor(), |a| *a);
+        let mut encoded = encoded.as_mut_polynomial();
+        for (mask, key) in masks
+            .as_polynomial_list()
+            .polynomial_iter()
+            .zip(self.as_polynomial_list().polynomial_iter())
+        {
+            encoded.update_with_wrapping_sub_cyclic_mul(&mask, &key);
+        }
+    }
+
     /// Decrypts a list of GLWE ciphertexts.
     ///
     /// See ['GlweSecretKey::encrypt_glwe_list`] for an example.
@@ -820,6 +948,135 @@ where
         }
     }
 
+    /// This function encrypts a message as a seeded GGSW ciphertext.
+    ///
+    /// The masks are generated from the seed of the `encrypted` ciphertext, so that they can be
+    /// regenerated at expansion time, while the noise is sampled from a fresh generator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::encoding::Plaintext;
+    /// use concrete_core::backends::core::private::crypto::ggsw::{
+    ///     StandardGgswCiphertext, StandardGgswSeededCiphertext,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::backends::core::private::crypto::secret::GlweSecretKey;
+    /// let mut generator = SecretRandomGenerator::new(None);
+    /// let secret_key =
+    ///     GlweSecretKey::generate_binary(GlweDimension(2), PolynomialSize(10), &mut generator);
+    /// let mut seeded = StandardGgswSeededCiphertext::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(10),
+    ///     GlweSize(3),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    ///     42,
+    /// );
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    /// secret_key.encrypt_constant_seeded_ggsw(&mut seeded, &Plaintext(10), noise);
+    /// let mut ciphertext = StandardGgswCiphertext::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(10),
+    ///     GlweSize(3),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// seeded.expand_into(&mut ciphertext);
+    /// ```
+    pub fn encrypt_constant_seeded_ggsw<OutputCont, Scalar>(
+        &self,
+        encrypted: &mut StandardGgswSeededCiphertext<OutputCont>,
+        encoded: &Plaintext<Scalar>,
+        noise_parameters: impl DispersionParameter,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        StandardGgswSeededCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let mut generator = EncryptionRandomGenerator::new(Some(encrypted.seed()));
+        self.encrypt_constant_seeded_ggsw_with_generator(
+            encrypted,
+            encoded,
+            noise_parameters,
+            &mut generator,
+        );
+    }
+
+    // Encrypts a message as a seeded GGSW ciphertext, generating the masks with `generator`
+    // instead of a generator seeded with the seed of the ciphertext. This allows to encrypt the
+    // GGSW ciphertexts of a seeded bootstrap key with a generator forked from its seed.
+    pub(crate) fn encrypt_constant_seeded_ggsw_with_generator<OutputCont, Scalar>(
+        &self,
+        encrypted: &mut StandardGgswSeededCiphertext<OutputCont>,
+        encoded: &Plaintext<Scalar>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        StandardGgswSeededCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.polynomial_size() => encrypted.polynomial_size());
+        ck_dim_eq!(self.key_size() => encrypted.glwe_size().to_glwe_dimension());
+        // The masks of a standard GGSW ciphertext are not uniformly random: the gadget
+        // decomposition of the message is added to their diagonal polynomials. We use the
+        // equivalent form where the product of this decomposition by the matching key polynomial
+        // is subtracted from the body instead, which leaves the phases unchanged, and keeps the
+        // masks reproducible from the seed.
+        let mut expanded = StandardGgswCiphertext::allocate(
+            Scalar::ZERO,
+            encrypted.polynomial_size(),
+            encrypted.glwe_size(),
+            encrypted.decomposition_level_count(),
+            encrypted.decomposition_base_log(),
+        );
+        let gen_iter = generator
+            .fork_ggsw_to_ggsw_levels::<Scalar>(
+                expanded.decomposition_level_count(),
+                self.key_size().to_glwe_size(),
+                self.poly_size,
+            )
+            .expect("Failed to split generator into ggsw levels");
+        let base_log = expanded.decomposition_base_log();
+        for (mut matrix, mut generator) in expanded.level_matrix_iter_mut().zip(gen_iter) {
+            let decomposition = encoded.0.wrapping_mul(
+                Scalar::ONE
+                    << (<Scalar as Numeric>::BITS
+                        - (base_log.0 * (matrix.decomposition_level().0))),
+            );
+            let gen_iter = generator
+                .fork_ggsw_level_to_glwe::<Scalar>(self.key_size().to_glwe_size(), self.poly_size)
+                .expect("Failed to split generator into rlwe");
+            for ((index, row), mut generator) in matrix.row_iter_mut().enumerate().zip(gen_iter) {
+                let mut rlwe_ct = row.into_glwe();
+                self.encrypt_zero_glwe(&mut rlwe_ct, noise_parameters, &mut generator);
+                let mut body = rlwe_ct.get_mut_body();
+                if index < self.key_size().0 {
+                    body.as_mut_tensor().update_with_wrapping_sub_element_mul(
+                        self.as_polynomial_list().get_polynomial(index).as_tensor(),
+                        decomposition,
+                    );
+                } else {
+                    let first_coef = body.as_mut_tensor().first_mut();
+                    *first_coef = first_coef.wrapping_add(decomposition);
+                }
+            }
+        }
+        let poly_size = encrypted.polynomial_size().0;
+        for (mut body, rlwe_ct) in encrypted
+            .as_mut_tensor()
+            .subtensor_iter_mut(poly_size)
+            .zip(expanded.as_glwe_list().ciphertext_iter())
+        {
+            body.fill_with_copy(rlwe_ct.get_body().as_tensor());
+        }
+    }
+
     /// This function encrypts a message as a GGSW ciphertext, using as many threads as possible.
     ///
     /// # Notes
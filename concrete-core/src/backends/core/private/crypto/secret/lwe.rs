@@ -14,7 +14,7 @@ use concrete_commons::parameters::LweDimension;
 
 use crate::backends::core::private::crypto::encoding::{Plaintext, PlaintextList};
 use crate::backends::core::private::crypto::gsw::GswCiphertext;
-use crate::backends::core::private::crypto::lwe::{LweCiphertext, LweList};
+use crate::backends::core::private::crypto::lwe::{LweCiphertext, LweList, LweSeededCiphertext};
 use crate::backends::core::private::crypto::secret::generators::{
     EncryptionRandomGenerator, SecretRandomGenerator,
 };
@@ -345,6 +345,58 @@ where
         output_body.0 = output_body.0.wrapping_add(encoded.0);
     }
 
+    /// Encrypts a single seeded ciphertext.
+    ///
+    /// The mask is generated from the seed of the `output` ciphertext, so that it can be
+    /// regenerated at expansion time, while the noise is sampled from a fresh generator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{LweDimension, LweSize};
+    /// use concrete_core::backends::core::private::crypto::encoding::Plaintext;
+    /// use concrete_core::backends::core::private::crypto::lwe::{LweCiphertext, LweSeededCiphertext};
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::LweSecretKey;
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let mut seeded = LweSeededCiphertext::allocate(0u32, LweSize(257), 42);
+    /// secret_key.encrypt_seeded_lwe(&mut seeded, &Plaintext(1 << 28), noise);
+    /// let mut expanded = LweCiphertext::allocate(0u32, LweSize(257));
+    /// seeded.expand_into(&mut expanded);
+    ///
+    /// // The mask is the one a generator seeded with the same seed would produce.
+    /// let mut reference = LweCiphertext::allocate(0u32, LweSize(257));
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(Some(42));
+    /// secret_key.encrypt_lwe(&mut reference, &Plaintext(1 << 28), noise, &mut encryption_generator);
+    /// assert_eq!(
+    ///     expanded.get_mask().as_tensor(),
+    ///     reference.get_mask().as_tensor()
+    /// );
+    /// ```
+    pub fn encrypt_seeded_lwe<Scalar>(
+        &self,
+        output: &mut LweSeededCiphertext<Scalar>,
+        encoded: &Plaintext<Scalar>,
+        noise_parameters: impl DispersionParameter,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.key_size().0 => output.lwe_size().to_lwe_dimension().0);
+        let mut generator = EncryptionRandomGenerator::new(Some(output.seed()));
+        let mut expanded = LweCiphertext::allocate(Scalar::ZERO, output.lwe_size());
+        self.encrypt_lwe(&mut expanded, encoded, noise_parameters, &mut generator);
+        output.set_body(*expanded.get_body());
+    }
+
     /// Encrypts a list of ciphertexts.
     ///
     /// # Example
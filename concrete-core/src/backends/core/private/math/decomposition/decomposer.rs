@@ -1,6 +1,7 @@
 use crate::backends::core::private::math::decomposition::{
     SignedDecompositionIter, TensorSignedDecompositionIter,
 };
+use crate::backends::core::private::math::kernels::VectorKernels;
 use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor, Tensor};
 use concrete_commons::numeric::{Numeric, UnsignedInteger};
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
@@ -282,6 +283,7 @@ where
     ) -> Option<()>
     where
         TLike: AsMutTensor<Element = Scalar>,
+        Scalar: VectorKernels,
     {
         let mut decomp = decomp;
         if decomp.is_fresh() {
@@ -1,8 +1,8 @@
 use crate::backends::core::private::math::decomposition::{
     DecompositionLevel, DecompositionTerm, DecompositionTermTensor,
 };
+use crate::backends::core::private::math::kernels::VectorKernels;
 use crate::backends::core::private::math::tensor::Tensor;
-use crate::backends::core::private::utils::{zip, zip_args};
 use concrete_commons::numeric::UnsignedInteger;
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
 
@@ -139,17 +139,23 @@ where
     /// assert_eq!(term.level(), DecompositionLevel(3));
     /// assert_eq!(*term.as_tensor().get_element(0), 4294967295);
     /// ```
-    pub fn next_term(&mut self) -> Option<DecompositionTermTensor<'_, Scalar>> {
+    pub fn next_term(&mut self) -> Option<DecompositionTermTensor<'_, Scalar>>
+    where
+        Scalar: VectorKernels,
+    {
         // The iterator is not fresh anymore.
         self.fresh = false;
         // We check if the decomposition is over
         if self.current_level == 0 {
             return None;
         }
-        // We iterate over the elements of the outputs and decompose
-        for zip_args!(output_i, state_i) in zip!(self.outputs.iter_mut(), self.states.iter_mut()) {
-            *output_i = decompose_one_level(self.base_log, state_i, self.mod_b_mask);
-        }
+        // We decompose all the states at once
+        Scalar::decompose_one_level(
+            self.outputs.as_mut_slice(),
+            self.states.as_mut_slice(),
+            self.base_log,
+            self.mod_b_mask,
+        );
         self.current_level -= 1;
         // We return the term tensor.
         Some(DecompositionTermTensor::new(
@@ -275,7 +281,11 @@ where
     }
 }
 
-fn decompose_one_level<S: UnsignedInteger>(base_log: usize, state: &mut S, mod_b_mask: S) -> S {
+pub(crate) fn decompose_one_level<S: UnsignedInteger>(
+    base_log: usize,
+    state: &mut S,
+    mod_b_mask: S,
+) -> S {
     let res = *state & mod_b_mask;
     *state >>= base_log;
     let mut carry = (res.wrapping_sub(S::ONE) | *state) & res;
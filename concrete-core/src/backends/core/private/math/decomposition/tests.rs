@@ -1,4 +1,5 @@
 use crate::backends::core::private::math::decomposition::SignedDecomposer;
+use crate::backends::core::private::math::kernels::VectorKernels;
 use crate::backends::core::private::math::random::{RandomGenerable, Uniform};
 use crate::backends::core::private::math::tensor::Tensor;
 use crate::backends::core::private::math::torus::UnsignedTorus;
@@ -58,7 +59,9 @@ fn test_decompose_recompose_u64() {
     test_decompose_recompose::<u64>()
 }
 
-fn test_decompose_recompose_tensor<T: UnsignedInteger + Debug + RandomGenerable<Uniform>>()
+fn test_decompose_recompose_tensor<
+    T: UnsignedInteger + VectorKernels + Debug + RandomGenerable<Uniform>,
+>()
 where
     <T as UnsignedInteger>::Signed: Debug + SignedInteger,
 {
@@ -0,0 +1,445 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use concrete_commons::numeric::{CastInto, UnsignedInteger};
+use concrete_commons::parameters::PolynomialSize;
+
+use crate::backends::core::private::math::polynomial::Polynomial;
+use crate::backends::core::private::math::tensor::{ck_dim_eq, AsMutTensor, AsRefTensor};
+
+use super::Complex64;
+
+/// A real number represented as the unevaluated sum of two `f64`, `hi + lo`, with `lo` smaller
+/// than half a unit in the last place of `hi`.
+///
+/// The representation holds 106 bits of mantissa, which is enough to represent the products of the
+/// 64 bits torus polynomials by the decomposed polynomials without rounding errors.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DoubleDouble {
+    pub hi: f64,
+    pub lo: f64,
+}
+
+// Returns the rounded sum of `a` and `b`, along with its rounding error.
+#[inline]
+fn two_sum(a: f64, b: f64) -> DoubleDouble {
+    let hi = a + b;
+    let b_virtual = hi - a;
+    let lo = (a - (hi - b_virtual)) + (b - b_virtual);
+    DoubleDouble { hi, lo }
+}
+
+// Same as `two_sum`, assuming that `|a| >= |b|`.
+#[inline]
+fn quick_two_sum(a: f64, b: f64) -> DoubleDouble {
+    let hi = a + b;
+    let lo = b - (hi - a);
+    DoubleDouble { hi, lo }
+}
+
+// Returns the rounded product of `a` and `b`, along with its rounding error.
+#[inline]
+fn two_prod(a: f64, b: f64) -> DoubleDouble {
+    let hi = a * b;
+    let lo = a.mul_add(b, -hi);
+    DoubleDouble { hi, lo }
+}
+
+// Converts an integral `f64` whose absolute value is smaller than `2^Scalar::BITS` to an integer,
+// modulo `2^Scalar::BITS`.
+#[inline]
+fn wrapping_from_signed_f64<Scalar: UnsignedInteger>(value: f64) -> Scalar {
+    let absolute = Scalar::cast_from(value.abs());
+    if value < 0. {
+        absolute.wrapping_neg()
+    } else {
+        absolute
+    }
+}
+
+impl DoubleDouble {
+    /// The zero value.
+    pub const ZERO: DoubleDouble = DoubleDouble { hi: 0., lo: 0. };
+
+    /// Creates a value from a single `f64`.
+    pub fn from_f64(value: f64) -> DoubleDouble {
+        DoubleDouble { hi: value, lo: 0. }
+    }
+
+    /// Creates a value from an unsigned integer, viewed as a signed integer.
+    ///
+    /// The conversion is exact for integers of up to 106 bits.
+    pub fn from_signed<Scalar: UnsignedInteger>(value: Scalar) -> DoubleDouble {
+        let half = Scalar::BITS / 2;
+        let high: f64 = (value.into_signed() >> half).cast_into();
+        let low: f64 = (value & ((Scalar::ONE << half) - Scalar::ONE)).cast_into();
+        two_sum(high * 2_f64.powi(half as i32), low)
+    }
+
+    /// Rounds the value to the closest integer, and returns it modulo `2^Scalar::BITS`.
+    pub fn round_to_unsigned<Scalar: UnsignedInteger>(self) -> Scalar {
+        let modulus = 2_f64.powi(Scalar::BITS as i32);
+        let hi = self.hi.round();
+        let lo = ((self.hi - hi) + self.lo).round();
+        // The reduction to the signed range is exact, as the result is a multiple of the unit in
+        // the last place of `hi`, and is not larger than it.
+        let hi = hi - modulus * (hi / modulus).round();
+        wrapping_from_signed_f64::<Scalar>(hi).wrapping_add(wrapping_from_signed_f64(lo))
+    }
+
+    /// Multiplies the value by a power of two, which is exact.
+    pub fn scale(self, power_of_two: f64) -> DoubleDouble {
+        DoubleDouble {
+            hi: self.hi * power_of_two,
+            lo: self.lo * power_of_two,
+        }
+    }
+
+    fn sqrt(self) -> DoubleDouble {
+        if self.hi == 0. {
+            return DoubleDouble::ZERO;
+        }
+        // A newton iteration from the `f64` approximation doubles the number of correct bits.
+        let root = self.hi.sqrt();
+        let square = two_prod(root, root);
+        let remainder = ((self.hi - square.hi) - square.lo) + self.lo;
+        quick_two_sum(root, remainder / (2. * root))
+    }
+
+    fn div(self, other: DoubleDouble) -> DoubleDouble {
+        let first = self.hi / other.hi;
+        let remainder = self - other * DoubleDouble::from_f64(first);
+        let second = remainder.hi / other.hi;
+        let remainder = remainder - other * DoubleDouble::from_f64(second);
+        let third = remainder.hi / other.hi;
+        quick_two_sum(first, second) + DoubleDouble::from_f64(third)
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = DoubleDouble;
+
+    #[inline]
+    fn add(self, other: DoubleDouble) -> DoubleDouble {
+        let high = two_sum(self.hi, other.hi);
+        let low = two_sum(self.lo, other.lo);
+        let result = quick_two_sum(high.hi, high.lo + low.hi);
+        quick_two_sum(result.hi, result.lo + low.lo)
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = DoubleDouble;
+
+    #[inline]
+    fn neg(self) -> DoubleDouble {
+        DoubleDouble {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = DoubleDouble;
+
+    #[inline]
+    fn sub(self, other: DoubleDouble) -> DoubleDouble {
+        self + (-other)
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = DoubleDouble;
+
+    #[inline]
+    fn mul(self, other: DoubleDouble) -> DoubleDouble {
+        let product = two_prod(self.hi, other.hi);
+        quick_two_sum(
+            product.hi,
+            product.lo + (self.hi * other.lo + self.lo * other.hi),
+        )
+    }
+}
+
+/// A complex number whose parts are [`DoubleDouble`] values.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DoubleDoubleComplex {
+    pub re: DoubleDouble,
+    pub im: DoubleDouble,
+}
+
+impl DoubleDoubleComplex {
+    /// The zero value.
+    pub const ZERO: DoubleDoubleComplex = DoubleDoubleComplex {
+        re: DoubleDouble::ZERO,
+        im: DoubleDouble::ZERO,
+    };
+
+    /// Returns the conjugate of the value.
+    pub fn conj(self) -> DoubleDoubleComplex {
+        DoubleDoubleComplex {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+
+    // Returns the square root of the value, for values of the upper half of the unit circle.
+    fn half_angle(self) -> DoubleDoubleComplex {
+        let re = ((DoubleDouble::from_f64(1.) + self.re).scale(0.5)).sqrt();
+        let im = self.im.div(re.scale(2.));
+        DoubleDoubleComplex { re, im }
+    }
+}
+
+impl Add for DoubleDoubleComplex {
+    type Output = DoubleDoubleComplex;
+
+    #[inline]
+    fn add(self, other: DoubleDoubleComplex) -> DoubleDoubleComplex {
+        DoubleDoubleComplex {
+            re: self.re + other.re,
+            im: self.im + other.im,
+        }
+    }
+}
+
+impl Sub for DoubleDoubleComplex {
+    type Output = DoubleDoubleComplex;
+
+    #[inline]
+    fn sub(self, other: DoubleDoubleComplex) -> DoubleDoubleComplex {
+        DoubleDoubleComplex {
+            re: self.re - other.re,
+            im: self.im - other.im,
+        }
+    }
+}
+
+impl Mul for DoubleDoubleComplex {
+    type Output = DoubleDoubleComplex;
+
+    #[inline]
+    fn mul(self, other: DoubleDoubleComplex) -> DoubleDoubleComplex {
+        DoubleDoubleComplex {
+            re: self.re * other.re - self.im * other.im,
+            im: self.re * other.im + self.im * other.re,
+        }
+    }
+}
+
+/// A fourier transformer computing the products of polynomials modulo $X^N+1$ with
+/// [`DoubleDouble`] arithmetic.
+///
+/// Contrary to [`Fft`](`super::Fft`), whose `f64` arithmetic rounds the products of 64 bits
+/// polynomials, the 106 bits of mantissa of the double-double arithmetic are enough to compute
+/// the products of the torus polynomials by the decomposed polynomials exactly, for the usual
+/// decomposition parameters. The transforms are computed by a textbook radix-2 algorithm, and are
+/// an order of magnitude slower than the ones of fftw.
+///
+/// The transforms have the size of the polynomials: the coefficients are multiplied by the powers
+/// of a $2N$-th root of unity before a cyclic transform, which turns the cyclic products into
+/// negacyclic ones.
+#[derive(Debug, Clone)]
+pub struct DoubleDoubleFft {
+    size: PolynomialSize,
+    // The powers of the 2N-th root of unity, for exponents from 0 to N - 1.
+    twists: Vec<DoubleDoubleComplex>,
+}
+
+impl DoubleDoubleFft {
+    /// Generates a new transformer for polynomials a given size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::PolynomialSize;
+    /// use concrete_core::backends::core::private::math::fft::DoubleDoubleFft;
+    /// let fft = DoubleDoubleFft::new(PolynomialSize(256));
+    /// assert_eq!(fft.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn new(size: PolynomialSize) -> DoubleDoubleFft {
+        assert!(
+            size.0 >= 2 && size.0.is_power_of_two(),
+            "The size chosen is not valid ({}). Should be a power of two.",
+            size.0
+        );
+        let log_size = size.log2().0;
+        // The roots of unity of order 2^t, for t from 1 to log_size + 1, are computed from -1 by
+        // successive half angles.
+        let mut roots = vec![DoubleDoubleComplex {
+            re: DoubleDouble::from_f64(-1.),
+            im: DoubleDouble::ZERO,
+        }];
+        roots.push(DoubleDoubleComplex {
+            re: DoubleDouble::ZERO,
+            im: DoubleDouble::from_f64(1.),
+        });
+        for t in 1..log_size {
+            let root = roots[t].half_angle();
+            roots.push(root);
+        }
+        // The powers of the 2N-th root of unity are computed from its powers of two, to avoid
+        // accumulating the rounding errors of a long chain of products.
+        let twists = (0..size.0)
+            .map(|exponent| {
+                let mut twist = DoubleDoubleComplex {
+                    re: DoubleDouble::from_f64(1.),
+                    im: DoubleDouble::ZERO,
+                };
+                for bit in 0..log_size {
+                    if exponent >> bit & 1 == 1 {
+                        twist = twist * roots[log_size - bit];
+                    }
+                }
+                twist
+            })
+            .collect();
+        DoubleDoubleFft { size, twists }
+    }
+
+    /// Returns the polynomial size accepted by this transformer.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.size
+    }
+
+    /// Performs the forward fourier transform of the `poly` polynomial, whose coefficients are
+    /// viewed as signed integers, and stores the result in `fourier_poly`.
+    pub fn forward<InCont, Coef>(
+        &self,
+        fourier_poly: &mut [DoubleDoubleComplex],
+        poly: &Polynomial<InCont>,
+    ) where
+        Polynomial<InCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(self.size.0 => fourier_poly.len(), poly.polynomial_size().0);
+        for ((output, coef), twist) in fourier_poly
+            .iter_mut()
+            .zip(poly.as_tensor().iter())
+            .zip(self.twists.iter())
+        {
+            let coef = DoubleDouble::from_signed(*coef);
+            *output = DoubleDoubleComplex {
+                re: twist.re * coef,
+                im: twist.im * coef,
+            };
+        }
+        self.transform(fourier_poly);
+    }
+
+    /// Performs the backward fourier transform of `fourier_poly`, and adds the result, rounded
+    /// modulo `2^Coef::BITS`, to the coefficients of `poly`.
+    ///
+    /// The content of `fourier_poly` is used as a buffer, and is lost.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::PolynomialSize;
+    /// use concrete_core::backends::core::private::math::fft::{
+    ///     DoubleDoubleComplex, DoubleDoubleFft,
+    /// };
+    /// use concrete_core::backends::core::private::math::polynomial::Polynomial;
+    /// use concrete_core::backends::core::private::math::random::RandomGenerator;
+    ///
+    /// let mut generator = RandomGenerator::new(None);
+    /// let fft = DoubleDoubleFft::new(PolynomialSize(256));
+    /// let mut poly = Polynomial::allocate(0u64, PolynomialSize(256));
+    /// generator.fill_tensor_with_random_uniform(&mut poly);
+    /// let mut fourier_poly = vec![DoubleDoubleComplex::ZERO; 256];
+    /// fft.forward(&mut fourier_poly, &poly);
+    /// let mut output = Polynomial::allocate(0u64, PolynomialSize(256));
+    /// fft.add_backward(&mut output, &mut fourier_poly);
+    /// assert_eq!(output, poly);
+    /// ```
+    pub fn add_backward<OutCont, Coef>(
+        &self,
+        poly: &mut Polynomial<OutCont>,
+        fourier_poly: &mut [DoubleDoubleComplex],
+    ) where
+        Polynomial<OutCont>: AsMutTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(self.size.0 => fourier_poly.len(), poly.polynomial_size().0);
+        // The backward transform is the conjugate of the forward transform of the conjugates.
+        fourier_poly
+            .iter_mut()
+            .for_each(|value| *value = value.conj());
+        self.transform(fourier_poly);
+        let scale = 1. / self.size.0 as f64;
+        for ((output, value), twist) in poly
+            .as_mut_tensor()
+            .iter_mut()
+            .zip(fourier_poly.iter())
+            .zip(self.twists.iter())
+        {
+            // The real part of the product of the conjugated value by the conjugated twist.
+            let coef = (value.re * twist.re - value.im * twist.im).scale(scale);
+            *output = output.wrapping_add(coef.round_to_unsigned());
+        }
+    }
+
+    // Computes the cyclic transform of `values` in place, with the N-th root of unity given by the
+    // square of the twists.
+    fn transform(&self, values: &mut [DoubleDoubleComplex]) {
+        let size = values.len();
+        let log_size = self.size.log2().0;
+        for index in 0..size {
+            let reversed = index.reverse_bits() >> (usize::BITS as usize - log_size);
+            if index < reversed {
+                values.swap(index, reversed);
+            }
+        }
+        let mut length = 2;
+        while length <= size {
+            let stride = 2 * size / length;
+            for chunk in values.chunks_exact_mut(length) {
+                let (first, second) = chunk.split_at_mut(length / 2);
+                for (k, (first, second)) in first.iter_mut().zip(second.iter_mut()).enumerate() {
+                    let product = self.twists[k * stride] * *second;
+                    *second = *first - product;
+                    *first = *first + product;
+                }
+            }
+            length *= 2;
+        }
+    }
+}
+
+/// Stores a fourier polynomial computed by a [`DoubleDoubleFft`] in two halves of a slice of
+/// [`Complex64`]: the high parts first, followed by the low parts.
+pub fn store_double_double_polynomial(output: &mut [Complex64], input: &[DoubleDoubleComplex]) {
+    let (high, low) = output.split_at_mut(input.len());
+    for ((high, low), value) in high.iter_mut().zip(low.iter_mut()).zip(input.iter()) {
+        *high = Complex64::new(value.re.hi, value.im.hi);
+        *low = Complex64::new(value.re.lo, value.im.lo);
+    }
+}
+
+/// Adds to `output` the element-wise product of `input` by a fourier polynomial stored by
+/// [`store_double_double_polynomial`].
+pub fn update_with_double_double_multiply_accumulate(
+    output: &mut [DoubleDoubleComplex],
+    input: &[DoubleDoubleComplex],
+    stored: &[Complex64],
+) {
+    let (high, low) = stored.split_at(input.len());
+    for (((output, input), high), low) in output
+        .iter_mut()
+        .zip(input.iter())
+        .zip(high.iter())
+        .zip(low.iter())
+    {
+        let stored = DoubleDoubleComplex {
+            re: DoubleDouble {
+                hi: high.re,
+                lo: low.re,
+            },
+            im: DoubleDouble {
+                hi: high.im,
+                lo: low.im,
+            },
+        };
+        *output = *output + *input * stored;
+    }
+}
@@ -10,12 +10,24 @@ mod twiddles;
 
 use twiddles::*;
 
+mod double_double;
+
+pub use double_double::*;
+
 mod plan;
 
 mod polynomial;
 
 pub use polynomial::*;
 
+mod precision;
+
+pub use precision::*;
+
+mod small;
+
+pub use small::*;
+
 mod transform;
 
 pub use transform::*;
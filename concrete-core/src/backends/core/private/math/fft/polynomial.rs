@@ -343,6 +343,7 @@ fn regular_uhwap<C1, C2, C3>(
     }
 }
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[allow(unused)]
 fn avx2_uhwap<C1, C2, C3>(
     res: &mut FourierPolynomial<C1>,
@@ -417,6 +418,7 @@ fn regular_uhwatp<C1, C2, C3, C4, C5>(
     }
 }
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[allow(unused)]
 fn avx2_uhwatp<C1, C2, C3, C4, C5>(
     res: &mut FourierPolynomial<C1>,
@@ -514,6 +516,7 @@ fn regular_uthwatp<C1, C2, C3, C4, C5, C6, C7, C8>(
     }
 }
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[allow(unused, clippy::too_many_arguments)]
 fn avx2_uthwatp<C1, C2, C3, C4, C5, C6, C7, C8>(
     result_1: &mut FourierPolynomial<C1>,
@@ -0,0 +1,51 @@
+use concrete_commons::numeric::UnsignedInteger;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The arithmetic strategy used for the polynomial products computed in the fourier domain.
+///
+/// The products are computed with `f64` arithmetic, whose 53 bits of mantissa bound the precision
+/// of the result. With 64 bits ciphertexts and large decomposition bases, the rounding errors of
+/// the transforms can exceed the noise of the ciphertexts.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FftPrecision {
+    /// The torus polynomials are transformed at once, with a single `f64` transform.
+    #[default]
+    Standard,
+    /// The torus polynomials are split in a high and a low part, transformed separately.
+    ///
+    /// The high part holds the most significant bits of the coefficients, and its products are
+    /// computed exactly, as integers. Only the products of the low part suffer from rounding
+    /// errors, which are hence scaled down by the size of the high part. This doubles the memory
+    /// footprint of the transformed polynomials, as well as the cost of the products.
+    Split,
+    /// The torus polynomials are transformed with double-double arithmetic.
+    ///
+    /// Each coefficient is represented as the unevaluated sum of two `f64`, which holds 106 bits
+    /// of mantissa. The products of the 64 bits torus polynomials by the decomposed polynomials
+    /// are computed without rounding errors, at the price of transforms an order of magnitude
+    /// slower than the ones of [`FftPrecision::Standard`]. The transformed polynomials are stored
+    /// as their high and low parts, which doubles their memory footprint.
+    DoubleDouble,
+}
+
+impl FftPrecision {
+    /// Returns the number of transformed polynomials stored for each torus polynomial.
+    pub fn part_count(&self) -> usize {
+        match self {
+            FftPrecision::Standard => 1,
+            FftPrecision::Split | FftPrecision::DoubleDouble => 2,
+        }
+    }
+
+    /// Returns the number of bits held by the low part of the coefficients, with the
+    /// [`FftPrecision::Split`] strategy.
+    ///
+    /// The high part holds a quarter of the bits of the coefficients, which keeps its integer
+    /// products far below the precision of `f64` for the usual decomposition bases.
+    pub fn split_low_bits<Scalar: UnsignedInteger>() -> usize {
+        Scalar::BITS - Scalar::BITS / 4
+    }
+}
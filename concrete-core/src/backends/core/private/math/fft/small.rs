@@ -0,0 +1,495 @@
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::PolynomialSize;
+use lazy_static::lazy_static;
+
+use crate::backends::core::private::math::polynomial::Polynomial;
+use crate::backends::core::private::math::tensor::{
+    ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::transform::{
+    merge_two_fourier_polynomials, propagate_conjugates, regular_convert_add_backward_single_torus,
+    regular_convert_add_backward_two_torus, regular_convert_forward_single_integer,
+    regular_convert_forward_two_integer, replicate_coefficients,
+};
+use super::{Complex64, Correctors, FourierPolynomial};
+
+/// The largest polynomial size handled by the [`SmallFft`] transformer.
+pub const SMALL_FFT_MAX_POLY_SIZE: usize = 1024;
+
+/// The polynomial sizes handled by the [`SmallFft`] transformer.
+pub(crate) const SMALL_FFT_ALLOWED_POLY_SIZE: [usize; 4] = [128, 256, 512, 1024];
+
+// The twiddle factors of the radix-4 stages of a transform. For the stage processing
+// sub-sequences of length `n`, the triplets `(w^p, w^2p, w^3p)` are stored contiguously for
+// `p < n / 4`, where `w` is the `n`-th root of unity used by the transform.
+#[derive(Debug)]
+struct StageTwiddles {
+    forward: Vec<[Complex64; 3]>,
+    backward: Vec<[Complex64; 3]>,
+}
+
+impl StageTwiddles {
+    fn new(big_n: usize) -> StageTwiddles {
+        let mut forward = Vec::with_capacity(big_n);
+        let mut n = big_n;
+        while n >= 4 {
+            for p in 0..n / 4 {
+                let root = |k: usize| {
+                    let angle = -2. * std::f64::consts::PI * (k * p) as f64 / n as f64;
+                    Complex64::new(angle.cos(), angle.sin())
+                };
+                forward.push([root(1), root(2), root(3)]);
+            }
+            n /= 4;
+        }
+        let backward = forward
+            .iter()
+            .map(|[w1, w2, w3]| [w1.conj(), w2.conj(), w3.conj()])
+            .collect();
+        StageTwiddles { forward, backward }
+    }
+}
+
+lazy_static! {
+    static ref SMALL_FFT_TWIDDLES: [StageTwiddles; 4] = [
+        StageTwiddles::new(128),
+        StageTwiddles::new(256),
+        StageTwiddles::new(512),
+        StageTwiddles::new(1024),
+    ];
+}
+
+/// A buffer of complex numbers living on the stack.
+///
+/// The buffer is aligned such that the fourier polynomials it contains can be used by the avx2
+/// operations of [`FourierPolynomial`].
+#[repr(C, align(32))]
+pub struct ComplexStackBuffer<const N: usize>(pub [Complex64; N]);
+
+impl<const N: usize> ComplexStackBuffer<N> {
+    /// Creates a new buffer filled with zeros.
+    pub fn new() -> Self {
+        ComplexStackBuffer([Complex64::new(0., 0.); N])
+    }
+}
+
+impl<const N: usize> Default for ComplexStackBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A fast fourier transformer for small polynomials.
+///
+/// This transformer computes the same transforms as [`Fft`](`super::Fft`), for polynomials of
+/// size at most [`SMALL_FFT_MAX_POLY_SIZE`], with a radix-4 Stockham algorithm. Contrary to
+/// [`Fft`](`super::Fft`), it does not rely on fftw and on heap-allocated buffers: its temporary
+/// buffers live on the stack, which removes most of the fixed cost of a transform for the small
+/// sizes. The fourier polynomials it produces are interchangeable with the ones of
+/// [`Fft`](`super::Fft`), up to floating point errors.
+#[derive(Debug, Clone)]
+pub struct SmallFft {
+    size: PolynomialSize,
+    correctors: Correctors,
+    twiddles: &'static StageTwiddles,
+}
+
+impl SmallFft {
+    /// Generates a new transformer for polynomials a given size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::PolynomialSize;
+    /// use concrete_core::backends::core::private::math::fft::SmallFft;
+    /// let fft = SmallFft::new(PolynomialSize(256));
+    /// assert_eq!(fft.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn new(size: PolynomialSize) -> SmallFft {
+        assert!(
+            SMALL_FFT_ALLOWED_POLY_SIZE.contains(&size.0),
+            "The size chosen is not valid ({}). Should be 128, 256, 512 or 1024",
+            size.0
+        );
+        SmallFft {
+            size,
+            correctors: Correctors::new(size.0),
+            twiddles: &SMALL_FFT_TWIDDLES[size.log2().0 - 7],
+        }
+    }
+
+    /// Returns whether polynomials of a given size can be processed by this transformer.
+    pub fn supports(size: PolynomialSize) -> bool {
+        SMALL_FFT_ALLOWED_POLY_SIZE.contains(&size.0)
+    }
+
+    /// Returns the polynomial size accepted by this transformer.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.size
+    }
+
+    /// Performs the forward fourier transform of the `poly` polynomial, viewed as a polynomial of
+    /// integer coefficients, and stores the result in `fourier_poly`.
+    pub fn forward_as_integer<OutCont, InCont, Coef>(
+        &self,
+        fourier_poly: &mut FourierPolynomial<OutCont>,
+        poly: &Polynomial<InCont>,
+    ) where
+        FourierPolynomial<OutCont>: AsMutTensor<Element = Complex64>,
+        Polynomial<InCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(self.size.0 => fourier_poly.polynomial_size().0, poly.polynomial_size().0);
+        let mut buffer = ComplexStackBuffer::<SMALL_FFT_MAX_POLY_SIZE>::new();
+        let mut buffer = FourierPolynomial::from_container(&mut buffer.0[..self.size.0]);
+        regular_convert_forward_single_integer::<&mut [Complex64], _, _>(
+            &mut buffer,
+            poly,
+            &self.correctors.forward,
+        );
+        self.forward_transform(
+            buffer.as_mut_tensor().as_mut_slice(),
+            fourier_poly.as_mut_tensor().as_mut_slice(),
+        );
+    }
+
+    /// Performs the forward fourier transform of the `poly_1` and `poly_2` polynomials, viewed
+    /// as polynomials of integer coefficients, and stores the result in `fourier_poly_1` and
+    /// `fourier_poly_2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::PolynomialSize;
+    /// use concrete_core::backends::core::private::math::fft::{
+    ///     Complex64, Fft, FourierPolynomial, SmallFft,
+    /// };
+    /// use concrete_core::backends::core::private::math::polynomial::Polynomial;
+    /// use concrete_core::backends::core::private::math::random::RandomGenerator;
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    ///
+    /// let mut generator = RandomGenerator::new(None);
+    /// let small_fft = SmallFft::new(PolynomialSize(256));
+    /// let mut fourier_poly_1 =
+    ///     FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(256));
+    /// let mut fourier_poly_2 =
+    ///     FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(256));
+    /// let mut poly_1 = Polynomial::allocate(0u32, PolynomialSize(256));
+    /// let mut poly_2 = Polynomial::allocate(0u32, PolynomialSize(256));
+    /// generator.fill_tensor_with_random_uniform(&mut poly_1);
+    /// generator.fill_tensor_with_random_uniform(&mut poly_2);
+    /// small_fft.forward_two_as_integer(&mut fourier_poly_1, &mut fourier_poly_2, &poly_1, &poly_2);
+    ///
+    /// // The result is interchangeable with the one of the generic transformer.
+    /// let mut out_1 = Polynomial::allocate(0u32, PolynomialSize(256));
+    /// let mut out_2 = Polynomial::allocate(0u32, PolynomialSize(256));
+    /// let fft = Fft::new(PolynomialSize(256));
+    /// fft.add_backward_two_as_integer(
+    ///     &mut out_1,
+    ///     &mut out_2,
+    ///     &mut fourier_poly_1,
+    ///     &mut fourier_poly_2,
+    /// );
+    /// assert_eq!(out_1, poly_1);
+    /// assert_eq!(out_2, poly_2);
+    /// ```
+    pub fn forward_two_as_integer<InCont1, InCont2, OutCont1, OutCont2, Coef>(
+        &self,
+        fourier_poly_1: &mut FourierPolynomial<OutCont1>,
+        fourier_poly_2: &mut FourierPolynomial<OutCont2>,
+        poly_1: &Polynomial<InCont1>,
+        poly_2: &Polynomial<InCont2>,
+    ) where
+        Polynomial<InCont1>: AsRefTensor<Element = Coef>,
+        Polynomial<InCont2>: AsRefTensor<Element = Coef>,
+        FourierPolynomial<OutCont1>: AsMutTensor<Element = Complex64>,
+        FourierPolynomial<OutCont2>: AsMutTensor<Element = Complex64>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(self.size.0 =>
+            fourier_poly_1.polynomial_size().0,
+            poly_1.polynomial_size().0,
+            fourier_poly_2.polynomial_size().0,
+            poly_2.polynomial_size().0
+        );
+        // The second fourier polynomial is only written at the end, and is used as a buffer.
+        regular_convert_forward_two_integer(
+            fourier_poly_2,
+            poly_1,
+            poly_2,
+            &self.correctors.forward,
+        );
+        self.forward_transform(
+            fourier_poly_2.as_mut_tensor().as_mut_slice(),
+            fourier_poly_1.as_mut_tensor().as_mut_slice(),
+        );
+        replicate_coefficients(
+            fourier_poly_1.as_mut_tensor().as_mut_slice(),
+            fourier_poly_2.as_mut_tensor().as_mut_slice(),
+            self.size.0,
+        );
+    }
+
+    /// Performs the backward fourier transform of the `fourier_poly` polynomial, viewed as a
+    /// polynomial of torus coefficients, and adds the result to `poly`.
+    ///
+    /// # Note
+    ///
+    /// The content of `fourier_poly` is used as a temporary buffer, and is lost after the call.
+    pub fn add_backward_as_torus<OutCont, InCont, Coef>(
+        &self,
+        poly: &mut Polynomial<OutCont>,
+        fourier_poly: &mut FourierPolynomial<InCont>,
+    ) where
+        Polynomial<OutCont>: AsMutTensor<Element = Coef>,
+        FourierPolynomial<InCont>: AsMutTensor<Element = Complex64>,
+        Coef: UnsignedTorus,
+    {
+        ck_dim_eq!(self.size.0 => fourier_poly.polynomial_size().0, poly.polynomial_size().0);
+        propagate_conjugates(fourier_poly.as_mut_tensor().as_mut_slice(), self.size.0);
+        let mut buffer = ComplexStackBuffer::<SMALL_FFT_MAX_POLY_SIZE>::new();
+        let buffer = &mut buffer.0[..self.size.0];
+        if self.transform::<true>(fourier_poly.as_mut_tensor().as_mut_slice(), buffer) {
+            regular_convert_add_backward_single_torus::<_, &[Complex64], _>(
+                poly,
+                &FourierPolynomial::from_container(&*buffer),
+                &self.correctors.backward,
+            );
+        } else {
+            regular_convert_add_backward_single_torus(
+                poly,
+                fourier_poly,
+                &self.correctors.backward,
+            );
+        }
+    }
+
+    /// Performs the backward fourier transform of the `fourier_poly_1` and `fourier_poly_2`
+    /// polynomials, viewed as polynomials of torus coefficients, and adds the result to `poly_1`
+    /// and `poly_2`.
+    ///
+    /// # Note
+    ///
+    /// The content of `fourier_poly_1` and `fourier_poly_2` is used as a temporary buffer, and is
+    /// lost after the call.
+    pub fn add_backward_two_as_torus<OutCont1, OutCont2, InCont1, InCont2, Coef>(
+        &self,
+        poly_1: &mut Polynomial<OutCont1>,
+        poly_2: &mut Polynomial<OutCont2>,
+        fourier_poly_1: &mut FourierPolynomial<InCont1>,
+        fourier_poly_2: &mut FourierPolynomial<InCont2>,
+    ) where
+        Polynomial<OutCont1>: AsMutTensor<Element = Coef>,
+        Polynomial<OutCont2>: AsMutTensor<Element = Coef>,
+        FourierPolynomial<InCont1>: AsMutTensor<Element = Complex64>,
+        FourierPolynomial<InCont2>: AsMutTensor<Element = Complex64>,
+        Coef: UnsignedTorus,
+    {
+        ck_dim_eq!(self.size.0 =>
+            fourier_poly_1.polynomial_size().0,
+            poly_1.polynomial_size().0,
+            fourier_poly_2.polynomial_size().0,
+            poly_2.polynomial_size().0
+        );
+        merge_two_fourier_polynomials(
+            fourier_poly_1.as_mut_tensor().as_mut_slice(),
+            fourier_poly_2.as_tensor().as_slice(),
+            self.size.0,
+        );
+        // Once merged, the second fourier polynomial is used as a buffer.
+        if self.transform::<true>(
+            fourier_poly_1.as_mut_tensor().as_mut_slice(),
+            fourier_poly_2.as_mut_tensor().as_mut_slice(),
+        ) {
+            regular_convert_add_backward_two_torus(
+                poly_1,
+                poly_2,
+                fourier_poly_2,
+                &self.correctors.backward,
+            );
+        } else {
+            regular_convert_add_backward_two_torus(
+                poly_1,
+                poly_2,
+                fourier_poly_1,
+                &self.correctors.backward,
+            );
+        }
+    }
+
+    // Performs the forward transform of `input` and stores the result in `output`. The content of
+    // `input` is lost.
+    fn forward_transform(&self, input: &mut [Complex64], output: &mut [Complex64]) {
+        if !self.transform::<false>(input, output) {
+            output.copy_from_slice(input);
+        }
+    }
+
+    // Performs an unnormalized transform of `x`, using `y` as a second buffer. The transform goes
+    // back and forth between the two buffers, and the returned boolean tells whether the result
+    // ended up in `y`. The forward transform uses the same sign convention as fftw.
+    fn transform<const BACKWARD: bool>(&self, x: &mut [Complex64], y: &mut [Complex64]) -> bool {
+        debug_assert_eq!(x.len(), self.size.0);
+        debug_assert_eq!(y.len(), self.size.0);
+        let mut twiddles: &[[Complex64; 3]] = if BACKWARD {
+            &self.twiddles.backward
+        } else {
+            &self.twiddles.forward
+        };
+        let mut n = self.size.0;
+        let mut s = 1;
+        let mut in_y = false;
+        while n >= 4 {
+            let (stage_twiddles, next_twiddles) = twiddles.split_at(n / 4);
+            match (in_y, n == 4) {
+                (false, false) => radix_4_stage::<BACKWARD>(x, y, s, stage_twiddles),
+                (true, false) => radix_4_stage::<BACKWARD>(y, x, s, stage_twiddles),
+                (false, true) => last_radix_4_stage::<BACKWARD>(x, y),
+                (true, true) => last_radix_4_stage::<BACKWARD>(y, x),
+            }
+            twiddles = next_twiddles;
+            n /= 4;
+            s *= 4;
+            in_y = !in_y;
+        }
+        if n == 2 {
+            if in_y {
+                radix_2_stage(y, x);
+            } else {
+                radix_2_stage(x, y);
+            }
+            in_y = !in_y;
+        }
+        in_y
+    }
+}
+
+// Computes a radix-4 butterfly, without twiddle factors.
+#[inline(always)]
+fn plain_butterfly<const BACKWARD: bool>(
+    a: Complex64,
+    b: Complex64,
+    c: Complex64,
+    d: Complex64,
+) -> [Complex64; 4] {
+    let apc = a + c;
+    let amc = a - c;
+    let bpd = b + d;
+    let bmd = b - d;
+    // For the forward transform, the fourth root of unity is -i, and i for the backward one.
+    let jbmd = if BACKWARD {
+        Complex64::new(bmd.im, -bmd.re)
+    } else {
+        Complex64::new(-bmd.im, bmd.re)
+    };
+    [apc + bpd, amc - jbmd, apc - bpd, amc + jbmd]
+}
+
+// Computes a radix-4 butterfly, and multiplies the outputs by the twiddle factors.
+#[inline(always)]
+fn butterfly<const BACKWARD: bool>(
+    a: Complex64,
+    b: Complex64,
+    c: Complex64,
+    d: Complex64,
+    w: &[Complex64; 3],
+) -> [Complex64; 4] {
+    let [r0, r1, r2, r3] = plain_butterfly::<BACKWARD>(a, b, c, d);
+    [r0, w[0] * r1, w[1] * r2, w[2] * r3]
+}
+
+// Performs a decimation in frequency radix-4 stage of the Stockham algorithm, for sub-sequences
+// interleaved with a stride `s`.
+fn radix_4_stage<const BACKWARD: bool>(
+    x: &[Complex64],
+    y: &mut [Complex64],
+    s: usize,
+    twiddles: &[[Complex64; 3]],
+) {
+    let quarter = x.len() / 4;
+    let (x0, rest) = x.split_at(quarter);
+    let (x1, rest) = rest.split_at(quarter);
+    let (x2, x3) = rest.split_at(quarter);
+    if s == 1 {
+        // In the first stage, the sub-sequences are contiguous.
+        for (((((a, b), c), d), w), out) in x0
+            .iter()
+            .zip(x1.iter())
+            .zip(x2.iter())
+            .zip(x3.iter())
+            .zip(twiddles.iter())
+            .zip(y.chunks_exact_mut(4))
+        {
+            out.copy_from_slice(&butterfly::<BACKWARD>(*a, *b, *c, *d, w));
+        }
+        return;
+    }
+    for (((((x0, x1), x2), x3), w), out) in x0
+        .chunks_exact(s)
+        .zip(x1.chunks_exact(s))
+        .zip(x2.chunks_exact(s))
+        .zip(x3.chunks_exact(s))
+        .zip(twiddles.iter())
+        .zip(y.chunks_exact_mut(4 * s))
+    {
+        let (y0, rest) = out.split_at_mut(s);
+        let (y1, rest) = rest.split_at_mut(s);
+        let (y2, y3) = rest.split_at_mut(s);
+        for (((((a, b), c), d), (o0, o1)), (o2, o3)) in x0
+            .iter()
+            .zip(x1.iter())
+            .zip(x2.iter())
+            .zip(x3.iter())
+            .zip(y0.iter_mut().zip(y1.iter_mut()))
+            .zip(y2.iter_mut().zip(y3.iter_mut()))
+        {
+            let [r0, r1, r2, r3] = butterfly::<BACKWARD>(*a, *b, *c, *d, w);
+            *o0 = r0;
+            *o1 = r1;
+            *o2 = r2;
+            *o3 = r3;
+        }
+    }
+}
+
+// Performs the last radix-4 stage of the Stockham algorithm, whose twiddle factors are all one.
+fn last_radix_4_stage<const BACKWARD: bool>(x: &[Complex64], y: &mut [Complex64]) {
+    let quarter = x.len() / 4;
+    let (x0, rest) = x.split_at(quarter);
+    let (x1, rest) = rest.split_at(quarter);
+    let (x2, x3) = rest.split_at(quarter);
+    let (y0, rest) = y.split_at_mut(quarter);
+    let (y1, rest) = rest.split_at_mut(quarter);
+    let (y2, y3) = rest.split_at_mut(quarter);
+    for (((((a, b), c), d), (o0, o1)), (o2, o3)) in x0
+        .iter()
+        .zip(x1.iter())
+        .zip(x2.iter())
+        .zip(x3.iter())
+        .zip(y0.iter_mut().zip(y1.iter_mut()))
+        .zip(y2.iter_mut().zip(y3.iter_mut()))
+    {
+        let [r0, r1, r2, r3] = plain_butterfly::<BACKWARD>(*a, *b, *c, *d);
+        *o0 = r0;
+        *o1 = r1;
+        *o2 = r2;
+        *o3 = r3;
+    }
+}
+
+// Performs the last radix-2 stage of the Stockham algorithm, when the size is not a power of 4.
+fn radix_2_stage(x: &[Complex64], y: &mut [Complex64]) {
+    let half = x.len() / 2;
+    let (x0, x1) = x.split_at(half);
+    let (y0, y1) = y.split_at_mut(half);
+    for ((a, b), (o0, o1)) in x0
+        .iter()
+        .zip(x1.iter())
+        .zip(y0.iter_mut().zip(y1.iter_mut()))
+    {
+        *o0 = a + b;
+        *o1 = a - b;
+    }
+}
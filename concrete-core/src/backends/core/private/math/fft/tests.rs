@@ -1,6 +1,7 @@
 use crate::backends::core::private::math::fft::twiddles::{BackwardCorrector, ForwardCorrector};
 use crate::backends::core::private::math::fft::{
-    Complex64, Fft, FourierPolynomial, ALLOWED_POLY_SIZE,
+    Complex64, DoubleDoubleComplex, DoubleDoubleFft, Fft, FourierPolynomial, SmallFft,
+    ALLOWED_POLY_SIZE, SMALL_FFT_ALLOWED_POLY_SIZE,
 };
 use crate::backends::core::private::math::polynomial::Polynomial;
 use crate::backends::core::private::math::random::RandomGenerator;
@@ -131,3 +132,168 @@ fn test_two_forward_backward() {
         }
     }
 }
+
+#[test]
+fn test_small_fft_matches_fft() {
+    fn assert_close<C1, C2>(expected: &FourierPolynomial<C1>, output: &FourierPolynomial<C2>)
+    where
+        FourierPolynomial<C1>: AsRefTensor<Element = Complex64>,
+        FourierPolynomial<C2>: AsRefTensor<Element = Complex64>,
+    {
+        // Only the first half of the coefficients are meaningful, the others being conjugates.
+        let half = expected.polynomial_size().0 / 2;
+        for (exp, out) in expected
+            .coefficient_iter()
+            .zip(output.coefficient_iter())
+            .take(half)
+        {
+            assert!((exp - out).norm() < 1e-8f64);
+        }
+    }
+    fn assert_torus_close(expected: &Polynomial<Vec<u64>>, output: &Polynomial<Vec<u64>>) {
+        for (exp, out) in expected.as_tensor().iter().zip(output.as_tensor().iter()) {
+            let distance = exp.wrapping_sub(*out).min(out.wrapping_sub(*exp));
+            assert!(distance < 1 << 20);
+        }
+    }
+    let mut generator = RandomGenerator::new(None);
+    for _ in 0..100 {
+        for size in &SMALL_FFT_ALLOWED_POLY_SIZE {
+            let size = PolynomialSize(*size);
+            let fft = Fft::new(size);
+            let small_fft = SmallFft::new(size);
+
+            // The forward transforms of integer polynomials match.
+            let mut poly_1 = Polynomial::allocate(0u64, size);
+            let mut poly_2 = Polynomial::allocate(0u64, size);
+            generator.fill_tensor_with_random_uniform_n_lsb(&mut poly_1, 8);
+            generator.fill_tensor_with_random_uniform_n_lsb(&mut poly_2, 8);
+            let mut expected_1 = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+            let mut expected_2 = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+            let mut fourier_poly_1 = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+            let mut fourier_poly_2 = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+            fft.forward_as_integer(&mut expected_1, &poly_1);
+            small_fft.forward_as_integer(&mut fourier_poly_1, &poly_1);
+            assert_close(&expected_1, &fourier_poly_1);
+            fft.forward_two_as_integer(&mut expected_1, &mut expected_2, &poly_1, &poly_2);
+            small_fft.forward_two_as_integer(
+                &mut fourier_poly_1,
+                &mut fourier_poly_2,
+                &poly_1,
+                &poly_2,
+            );
+            assert_close(&expected_1, &fourier_poly_1);
+            assert_close(&expected_2, &fourier_poly_2);
+
+            // The backward transforms give back torus polynomials transformed with fftw.
+            generator.fill_tensor_with_random_uniform(&mut poly_1);
+            generator.fill_tensor_with_random_uniform(&mut poly_2);
+            fft.forward_as_torus(&mut fourier_poly_1, &poly_1);
+            let mut out_1 = Polynomial::allocate(0u64, size);
+            let mut out_2 = Polynomial::allocate(0u64, size);
+            small_fft.add_backward_as_torus(&mut out_1, &mut fourier_poly_1);
+            assert_torus_close(&poly_1, &out_1);
+            fft.forward_two_as_torus(&mut fourier_poly_1, &mut fourier_poly_2, &poly_1, &poly_2);
+            let mut out_1 = Polynomial::allocate(0u64, size);
+            small_fft.add_backward_two_as_torus(
+                &mut out_1,
+                &mut out_2,
+                &mut fourier_poly_1,
+                &mut fourier_poly_2,
+            );
+            assert_torus_close(&poly_1, &out_1);
+            assert_torus_close(&poly_2, &out_2);
+        }
+    }
+}
+
+#[test]
+fn test_small_fft_product_error_matches_fft() {
+    // Returns the largest torus distance between the exact product and the computed one.
+    fn max_error(expected: &Polynomial<Vec<u64>>, output: &Polynomial<Vec<u64>>) -> u64 {
+        expected
+            .as_tensor()
+            .iter()
+            .zip(output.as_tensor().iter())
+            .map(|(exp, out)| exp.wrapping_sub(*out).min(out.wrapping_sub(*exp)))
+            .max()
+            .unwrap()
+    }
+    let mut generator = RandomGenerator::new(None);
+    for size in &SMALL_FFT_ALLOWED_POLY_SIZE {
+        let size = PolynomialSize(*size);
+        let fft = Fft::new(size);
+        let small_fft = SmallFft::new(size);
+        let (mut fft_error, mut small_fft_error) = (0, 0);
+        for _ in 0..100 {
+            // The products of the bootstrap: a torus polynomial of the key, transformed with
+            // fftw, by a polynomial of small decomposed digits.
+            let mut key = Polynomial::allocate(0u64, size);
+            let mut digits = Polynomial::allocate(0u64, size);
+            generator.fill_tensor_with_random_uniform(&mut key);
+            generator.fill_tensor_with_random_uniform_n_lsb(&mut digits, 8);
+            let mut expected = Polynomial::allocate(0u64, size);
+            expected.fill_with_wrapping_mul(&key, &digits);
+            let mut fourier_key = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+            fft.forward_as_torus(&mut fourier_key, &key);
+
+            let mut fourier_digits = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+            let mut fourier_product = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+            let mut output = Polynomial::allocate(0u64, size);
+            fft.forward_as_integer(&mut fourier_digits, &digits);
+            fourier_product.update_with_multiply_accumulate(&fourier_key, &fourier_digits);
+            fft.add_backward_as_torus(&mut output, &mut fourier_product);
+            fft_error = fft_error.max(max_error(&expected, &output));
+
+            let mut fourier_product = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+            let mut output = Polynomial::allocate(0u64, size);
+            small_fft.forward_as_integer(&mut fourier_digits, &digits);
+            fourier_product.update_with_multiply_accumulate(&fourier_key, &fourier_digits);
+            small_fft.add_backward_as_torus(&mut output, &mut fourier_product);
+            small_fft_error = small_fft_error.max(max_error(&expected, &output));
+        }
+        // The rounding errors of the two transforms are of the same magnitude.
+        assert!(
+            small_fft_error <= 2 * fft_error,
+            "size {}: small fft error {} against fftw error {}",
+            size.0,
+            small_fft_error,
+            fft_error
+        );
+    }
+}
+
+#[test]
+fn test_double_double_fft_product_is_exact() {
+    let mut generator = RandomGenerator::new(None);
+    for size in ALLOWED_POLY_SIZE {
+        let size = PolynomialSize(size);
+        let fft = DoubleDoubleFft::new(size);
+        for _ in 0..10 {
+            // A torus polynomial by a polynomial of signed decomposed digits, whose product
+            // requires more than the 53 bits of mantissa of `f64`.
+            let mut key = Polynomial::allocate(0u64, size);
+            let mut digits = Polynomial::allocate(0u64, size);
+            generator.fill_tensor_with_random_uniform(&mut key);
+            generator.fill_tensor_with_random_uniform_n_lsb(&mut digits, 16);
+            digits
+                .as_mut_tensor()
+                .update_with_wrapping_scalar_sub(&(1 << 15));
+            let mut expected = Polynomial::allocate(0u64, size);
+            expected.fill_with_wrapping_mul(&key, &digits);
+
+            let mut fourier_key = vec![DoubleDoubleComplex::ZERO; size.0];
+            let mut fourier_digits = vec![DoubleDoubleComplex::ZERO; size.0];
+            fft.forward(&mut fourier_key, &key);
+            fft.forward(&mut fourier_digits, &digits);
+            let mut fourier_product: Vec<_> = fourier_key
+                .iter()
+                .zip(fourier_digits.iter())
+                .map(|(key, digit)| *key * *digit)
+                .collect();
+            let mut output = Polynomial::allocate(0u64, size);
+            fft.add_backward(&mut output, &mut fourier_product);
+            assert_eq!(output, expected, "size {}", size.0);
+        }
+    }
+}
@@ -230,7 +230,11 @@ impl Fft {
         Polynomial<InCont>: AsRefTensor<Element = Coef>,
         Coef: UnsignedInteger,
     {
-        self.forward(fourier_poly, poly, regular_convert_forward_single_integer);
+        self.forward(
+            fourier_poly,
+            poly,
+            regular_convert_forward_single_integer::<AlignedVec<Complex64>, _, _>,
+        );
     }
 
     /// Performs the forward fourier transform of the `poly_1` and `poly_2` polynomials, viewed
@@ -327,7 +331,7 @@ impl Fft {
         self.backward(
             poly,
             fourier_poly,
-            regular_convert_add_backward_single_torus,
+            regular_convert_add_backward_single_torus::<_, AlignedVec<Complex64>, _>,
         );
     }
 
@@ -568,11 +572,10 @@ impl Fft {
         FourierPolynomial<InCont>: AsMutTensor<Element = Complex64>,
     {
         // We propagate the values to their conjugates that were not computed.
-        let first_view = fourier_poly.as_mut_tensor().as_mut_slice();
-        let (b_first, b_second) = split_in_imut_mut(first_view, self.polynomial_size().0);
-        for (fft_bj, rot_fft_bj) in zip!(b_first.iter(), b_second.iter_mut().rev()) {
-            *rot_fft_bj = fft_bj.conj();
-        }
+        propagate_conjugates(
+            fourier_poly.as_mut_tensor().as_mut_slice(),
+            self.polynomial_size().0,
+        );
 
         // We perform the backward fft
         self.plans.backward(
@@ -609,25 +612,12 @@ impl Fft {
             poly_2.polynomial_size().0
         );
 
-        // first we deal with the first root of unity
-        let fp1 = fourier_poly_1.as_mut_tensor().as_mut_slice();
-        let fp2 = fourier_poly_2.as_mut_tensor().as_mut_slice();
-        fp1[0] = Complex64::new(fp1[0].re - fp2[0].im, fp1[0].im + fp2[0].re);
-        fp1[1] = Complex64::new(fp1[1].re - fp2[1].im, fp1[1].im + fp2[1].re);
-
-        let first_view = fourier_poly_1.as_mut_tensor().as_mut_slice();
-        let (a_first, a_second) = split_in_mut_mut(first_view, self.polynomial_size().0);
-
-        for (fft_aj, (rot_fft_aj, fft_bj)) in zip!(
-            a_first.iter_mut(),
-            a_second.iter_mut().rev(),
-            fourier_poly_2.as_mut_tensor().as_mut_slice()[2..].iter()
-        ) {
-            let re = fft_aj.re;
-            let im = fft_aj.im;
-            *fft_aj = Complex64::new(fft_aj.re - fft_bj.im, fft_aj.im + fft_bj.re);
-            *rot_fft_aj = Complex64::new(re + fft_bj.im, -im + fft_bj.re);
-        }
+        // We merge the two fourier polynomials in the first one.
+        merge_two_fourier_polynomials(
+            fourier_poly_1.as_mut_tensor().as_mut_slice(),
+            fourier_poly_2.as_tensor().as_slice(),
+            self.polynomial_size().0,
+        );
 
         // We perform the backward fft
         self.plans.backward(
@@ -685,7 +675,44 @@ fn split_in_mut_mut(s: &mut [Complex64], big_n: usize) -> (&mut [Complex64], &mu
     }
 }
 
-fn replicate_coefficients(fft_a: &mut [Complex64], fft_b: &mut [Complex64], big_n: usize) {
+pub(super) fn propagate_conjugates(fft_b: &mut [Complex64], big_n: usize) {
+    // Only the first half of the coefficients of the fourier transform of a real polynomial are
+    // computed, the other half being their conjugates.
+    let (b_first, b_second) = split_in_imut_mut(fft_b, big_n);
+    for (fft_bj, rot_fft_bj) in zip!(b_first.iter(), b_second.iter_mut().rev()) {
+        *rot_fft_bj = fft_bj.conj();
+    }
+}
+
+pub(super) fn merge_two_fourier_polynomials(
+    fft_a: &mut [Complex64],
+    fft_b: &[Complex64],
+    big_n: usize,
+) {
+    // We fill fft_a with FFT(coeff_a + i coeff_b), such that a single backward transform gives
+    // both polynomials. First we deal with the first root of unity.
+    fft_a[0] = Complex64::new(fft_a[0].re - fft_b[0].im, fft_a[0].im + fft_b[0].re);
+    fft_a[1] = Complex64::new(fft_a[1].re - fft_b[1].im, fft_a[1].im + fft_b[1].re);
+
+    let (a_first, a_second) = split_in_mut_mut(fft_a, big_n);
+
+    for (fft_aj, (rot_fft_aj, fft_bj)) in zip!(
+        a_first.iter_mut(),
+        a_second.iter_mut().rev(),
+        fft_b[2..].iter()
+    ) {
+        let re = fft_aj.re;
+        let im = fft_aj.im;
+        *fft_aj = Complex64::new(fft_aj.re - fft_bj.im, fft_aj.im + fft_bj.re);
+        *rot_fft_aj = Complex64::new(re + fft_bj.im, -im + fft_bj.re);
+    }
+}
+
+pub(super) fn replicate_coefficients(
+    fft_a: &mut [Complex64],
+    fft_b: &mut [Complex64],
+    big_n: usize,
+) {
     // in fft_a there is FFT(coeff_a + i coeff_b) we now extract
     // the fourier transfform of coeff_a and of coeff_b using the fact
     // that halves of the roots of -1 are conjugate to the other half
@@ -765,11 +792,12 @@ fn regular_convert_forward_two_torus<InCont1, InCont2, Coef>(
     }
 }
 
-fn regular_convert_forward_single_integer<InCont, Coef>(
-    out: &mut FourierPolynomial<AlignedVec<Complex64>>,
+pub(super) fn regular_convert_forward_single_integer<BufCont, InCont, Coef>(
+    out: &mut FourierPolynomial<BufCont>,
     inp: &Polynomial<InCont>,
     corr: &ForwardCorrector<&'static [Complex64]>,
 ) where
+    FourierPolynomial<BufCont>: AsMutTensor<Element = Complex64>,
     Polynomial<InCont>: AsRefTensor<Element = Coef>,
     Coef: UnsignedInteger,
 {
@@ -784,12 +812,13 @@ fn regular_convert_forward_single_integer<InCont, Coef>(
     }
 }
 
-fn regular_convert_forward_two_integer<InCont1, InCont2, Coef>(
-    out: &mut FourierPolynomial<AlignedVec<Complex64>>,
+pub(super) fn regular_convert_forward_two_integer<BufCont, InCont1, InCont2, Coef>(
+    out: &mut FourierPolynomial<BufCont>,
     inp1: &Polynomial<InCont1>,
     inp2: &Polynomial<InCont2>,
     corr: &ForwardCorrector<&'static [Complex64]>,
 ) where
+    FourierPolynomial<BufCont>: AsMutTensor<Element = Complex64>,
     Polynomial<InCont1>: AsRefTensor<Element = Coef>,
     Polynomial<InCont2>: AsRefTensor<Element = Coef>,
     Coef: UnsignedInteger,
@@ -811,11 +840,12 @@ fn regular_convert_forward_two_integer<InCont1, InCont2, Coef>(
     }
 }
 
-fn regular_convert_add_backward_single_torus<OutCont, Coef>(
+pub(super) fn regular_convert_add_backward_single_torus<OutCont, BufCont, Coef>(
     out: &mut Polynomial<OutCont>,
-    inp: &FourierPolynomial<AlignedVec<Complex64>>,
+    inp: &FourierPolynomial<BufCont>,
     corr: &BackwardCorrector<&'static [Complex64]>,
 ) where
+    FourierPolynomial<BufCont>: AsRefTensor<Element = Complex64>,
     Polynomial<OutCont>: AsMutTensor<Element = Coef>,
     Coef: UnsignedTorus,
 {
@@ -869,12 +899,13 @@ fn regular_convert_add_backward_single_integer<OutCont, Coef>(
     }
 }
 
-fn regular_convert_add_backward_two_torus<OutCont1, OutCont2, Coef>(
+pub(super) fn regular_convert_add_backward_two_torus<OutCont1, OutCont2, BufCont, Coef>(
     out1: &mut Polynomial<OutCont1>,
     out2: &mut Polynomial<OutCont2>,
-    inp: &FourierPolynomial<AlignedVec<Complex64>>,
+    inp: &FourierPolynomial<BufCont>,
     corr: &BackwardCorrector<&'static [Complex64]>,
 ) where
+    FourierPolynomial<BufCont>: AsRefTensor<Element = Complex64>,
     Polynomial<OutCont1>: AsMutTensor<Element = Coef>,
     Polynomial<OutCont2>: AsMutTensor<Element = Coef>,
     Coef: UnsignedTorus,
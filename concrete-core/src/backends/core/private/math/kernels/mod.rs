@@ -0,0 +1,260 @@
+//! Vectorized kernels for the arithmetic on slices of unsigned integers.
+//!
+//! The linear operations on LWE ciphertexts, the schoolbook polynomial multiplication and the
+//! signed decomposition of tensors all boil down to a handful of element-wise loops over slices
+//! of unsigned integers. This module gathers these loops in the [`VectorKernels`] trait, whose
+//! default methods are plain scalar loops.
+//!
+//! On `aarch64`, the implementations for `u32` and `u64` use NEON instructions whenever the cpu
+//! supports them. The support is detected at runtime, which means that the same binary can be
+//! cross-compiled once and executed on any `aarch64` machine, the scalar loops being used as a
+//! fallback. On the other architectures, the scalar loops are always used, and the compiler is
+//! left in charge of the auto-vectorization.
+
+use concrete_commons::numeric::UnsignedInteger;
+
+#[cfg(target_arch = "aarch64")]
+mod neon;
+
+#[cfg(test)]
+mod tests;
+
+/// Returns whether the NEON kernels are used on the current machine.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::backends::core::private::math::kernels::neon_available;
+/// if !cfg!(target_arch = "aarch64") {
+///     assert!(!neon_available());
+/// }
+/// ```
+pub fn neon_available() -> bool {
+    #[cfg(target_arch = "aarch64")]
+    {
+        neon::is_available()
+    }
+    #[cfg(not(target_arch = "aarch64"))]
+    {
+        false
+    }
+}
+
+/// A trait for unsigned integer types that provide kernels operating on slices.
+///
+/// # Note
+///
+/// For every kernel, the slices are expected to have the same length.
+pub trait VectorKernels: UnsignedInteger {
+    /// Adds `rhs` to `lhs`, element-wise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::backends::core::private::math::kernels::VectorKernels;
+    /// let mut lhs = vec![1u32, 2, 3, u32::MAX];
+    /// u32::wrapping_add_assign(&mut lhs, &[1, 1, 1, 1]);
+    /// assert_eq!(lhs, vec![2, 3, 4, 0]);
+    /// ```
+    fn wrapping_add_assign(lhs: &mut [Self], rhs: &[Self]) {
+        scalar::wrapping_add_assign(lhs, rhs)
+    }
+
+    /// Subtracts `rhs` to `lhs`, element-wise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::backends::core::private::math::kernels::VectorKernels;
+    /// let mut lhs = vec![1u32, 2, 3, 0];
+    /// u32::wrapping_sub_assign(&mut lhs, &[1, 1, 1, 1]);
+    /// assert_eq!(lhs, vec![0, 1, 2, u32::MAX]);
+    /// ```
+    fn wrapping_sub_assign(lhs: &mut [Self], rhs: &[Self]) {
+        scalar::wrapping_sub_assign(lhs, rhs)
+    }
+
+    /// Adds `rhs` multiplied by `factor` to `lhs`, element-wise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::backends::core::private::math::kernels::VectorKernels;
+    /// let mut lhs = vec![1u32, 2, 3, 4];
+    /// u32::wrapping_add_scaled_assign(&mut lhs, &[1, 2, 3, 4], 2);
+    /// assert_eq!(lhs, vec![3, 6, 9, 12]);
+    /// ```
+    fn wrapping_add_scaled_assign(lhs: &mut [Self], rhs: &[Self], factor: Self) {
+        scalar::wrapping_add_scaled_assign(lhs, rhs, factor)
+    }
+
+    /// Subtracts `rhs` multiplied by `factor` to `lhs`, element-wise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::backends::core::private::math::kernels::VectorKernels;
+    /// let mut lhs = vec![3u32, 6, 9, 12];
+    /// u32::wrapping_sub_scaled_assign(&mut lhs, &[1, 2, 3, 4], 2);
+    /// assert_eq!(lhs, vec![1, 2, 3, 4]);
+    /// ```
+    fn wrapping_sub_scaled_assign(lhs: &mut [Self], rhs: &[Self], factor: Self) {
+        scalar::wrapping_sub_scaled_assign(lhs, rhs, factor)
+    }
+
+    /// Computes one level of the signed decomposition of the `states`, and writes the decomposed
+    /// values in `outputs`.
+    ///
+    /// The states are updated for the next level, as in the
+    /// [`TensorSignedDecompositionIter`](`super::decomposition::TensorSignedDecompositionIter`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::backends::core::private::math::kernels::VectorKernels;
+    /// let mut states = vec![0b0111u32, 0b1001];
+    /// let mut outputs = vec![0u32; 2];
+    /// u32::decompose_one_level(&mut outputs, &mut states, 4, 0b1111);
+    /// assert_eq!(outputs, vec![7, 0u32.wrapping_sub(7)]);
+    /// assert_eq!(states, vec![0, 1]);
+    /// ```
+    fn decompose_one_level(
+        outputs: &mut [Self],
+        states: &mut [Self],
+        base_log: usize,
+        mod_b_mask: Self,
+    ) {
+        scalar::decompose_one_level(outputs, states, base_log, mod_b_mask)
+    }
+}
+
+impl VectorKernels for u8 {}
+impl VectorKernels for u16 {}
+impl VectorKernels for u128 {}
+
+#[cfg(not(target_arch = "aarch64"))]
+impl VectorKernels for u32 {}
+#[cfg(not(target_arch = "aarch64"))]
+impl VectorKernels for u64 {}
+
+#[cfg(target_arch = "aarch64")]
+macro_rules! implement_neon {
+    ($Type: ty, $module: ident) => {
+        impl VectorKernels for $Type {
+            fn wrapping_add_assign(lhs: &mut [Self], rhs: &[Self]) {
+                debug_assert_eq!(lhs.len(), rhs.len());
+                if neon::is_available() {
+                    unsafe { neon::$module::wrapping_add_assign(lhs, rhs) }
+                } else {
+                    scalar::wrapping_add_assign(lhs, rhs)
+                }
+            }
+
+            fn wrapping_sub_assign(lhs: &mut [Self], rhs: &[Self]) {
+                debug_assert_eq!(lhs.len(), rhs.len());
+                if neon::is_available() {
+                    unsafe { neon::$module::wrapping_sub_assign(lhs, rhs) }
+                } else {
+                    scalar::wrapping_sub_assign(lhs, rhs)
+                }
+            }
+
+            fn wrapping_add_scaled_assign(lhs: &mut [Self], rhs: &[Self], factor: Self) {
+                debug_assert_eq!(lhs.len(), rhs.len());
+                if neon::is_available() {
+                    unsafe { neon::$module::wrapping_add_scaled_assign(lhs, rhs, factor) }
+                } else {
+                    scalar::wrapping_add_scaled_assign(lhs, rhs, factor)
+                }
+            }
+
+            fn wrapping_sub_scaled_assign(lhs: &mut [Self], rhs: &[Self], factor: Self) {
+                debug_assert_eq!(lhs.len(), rhs.len());
+                if neon::is_available() {
+                    unsafe { neon::$module::wrapping_sub_scaled_assign(lhs, rhs, factor) }
+                } else {
+                    scalar::wrapping_sub_scaled_assign(lhs, rhs, factor)
+                }
+            }
+
+            fn decompose_one_level(
+                outputs: &mut [Self],
+                states: &mut [Self],
+                base_log: usize,
+                mod_b_mask: Self,
+            ) {
+                debug_assert_eq!(outputs.len(), states.len());
+                if neon::is_available() {
+                    unsafe {
+                        neon::$module::decompose_one_level(outputs, states, base_log, mod_b_mask)
+                    }
+                } else {
+                    scalar::decompose_one_level(outputs, states, base_log, mod_b_mask)
+                }
+            }
+        }
+    };
+}
+
+#[cfg(target_arch = "aarch64")]
+implement_neon!(u32, u32x4);
+#[cfg(target_arch = "aarch64")]
+implement_neon!(u64, u64x2);
+
+/// The scalar implementations of the kernels.
+///
+/// Those are used as a fallback when no vectorized implementation is available, and as a
+/// reference for the vectorized ones.
+pub mod scalar {
+    use concrete_commons::numeric::UnsignedInteger;
+
+    /// Adds `rhs` to `lhs`, element-wise.
+    pub fn wrapping_add_assign<T: UnsignedInteger>(lhs: &mut [T], rhs: &[T]) {
+        debug_assert_eq!(lhs.len(), rhs.len());
+        for (l, r) in lhs.iter_mut().zip(rhs.iter()) {
+            *l = l.wrapping_add(*r);
+        }
+    }
+
+    /// Subtracts `rhs` to `lhs`, element-wise.
+    pub fn wrapping_sub_assign<T: UnsignedInteger>(lhs: &mut [T], rhs: &[T]) {
+        debug_assert_eq!(lhs.len(), rhs.len());
+        for (l, r) in lhs.iter_mut().zip(rhs.iter()) {
+            *l = l.wrapping_sub(*r);
+        }
+    }
+
+    /// Adds `rhs` multiplied by `factor` to `lhs`, element-wise.
+    pub fn wrapping_add_scaled_assign<T: UnsignedInteger>(lhs: &mut [T], rhs: &[T], factor: T) {
+        debug_assert_eq!(lhs.len(), rhs.len());
+        for (l, r) in lhs.iter_mut().zip(rhs.iter()) {
+            *l = l.wrapping_add(r.wrapping_mul(factor));
+        }
+    }
+
+    /// Subtracts `rhs` multiplied by `factor` to `lhs`, element-wise.
+    pub fn wrapping_sub_scaled_assign<T: UnsignedInteger>(lhs: &mut [T], rhs: &[T], factor: T) {
+        debug_assert_eq!(lhs.len(), rhs.len());
+        for (l, r) in lhs.iter_mut().zip(rhs.iter()) {
+            *l = l.wrapping_sub(r.wrapping_mul(factor));
+        }
+    }
+
+    /// Computes one level of the signed decomposition of the `states`.
+    pub fn decompose_one_level<T: UnsignedInteger>(
+        outputs: &mut [T],
+        states: &mut [T],
+        base_log: usize,
+        mod_b_mask: T,
+    ) {
+        debug_assert_eq!(outputs.len(), states.len());
+        for (output, state) in outputs.iter_mut().zip(states.iter_mut()) {
+            let res = *state & mod_b_mask;
+            *state >>= base_log;
+            let mut carry = (res.wrapping_sub(T::ONE) | *state) & res;
+            carry >>= base_log - 1;
+            *state += carry;
+            *output = res.wrapping_sub(carry << base_log);
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! The NEON implementations of the kernels.
+//!
+//! Every kernel processes the slices by chunks of one vector register, and handles the remaining
+//! elements with the scalar implementation.
+
+/// Returns whether the cpu supports the NEON instructions.
+pub(crate) fn is_available() -> bool {
+    std::arch::is_aarch64_feature_detected!("neon")
+}
+
+// Generates the kernels from the lane-wise operations defined in the enclosing module.
+macro_rules! implement_kernels {
+    ($Scalar: ty) => {
+        #[target_feature(enable = "neon")]
+        pub(crate) unsafe fn wrapping_add_assign(lhs: &mut [$Scalar], rhs: &[$Scalar]) {
+            let mut lhs_chunks = lhs.chunks_exact_mut(LANES);
+            let mut rhs_chunks = rhs.chunks_exact(LANES);
+            for (l, r) in (&mut lhs_chunks).zip(&mut rhs_chunks) {
+                store(l.as_mut_ptr(), add(load(l.as_ptr()), load(r.as_ptr())));
+            }
+            super::super::scalar::wrapping_add_assign(
+                lhs_chunks.into_remainder(),
+                rhs_chunks.remainder(),
+            );
+        }
+
+        #[target_feature(enable = "neon")]
+        pub(crate) unsafe fn wrapping_sub_assign(lhs: &mut [$Scalar], rhs: &[$Scalar]) {
+            let mut lhs_chunks = lhs.chunks_exact_mut(LANES);
+            let mut rhs_chunks = rhs.chunks_exact(LANES);
+            for (l, r) in (&mut lhs_chunks).zip(&mut rhs_chunks) {
+                store(l.as_mut_ptr(), sub(load(l.as_ptr()), load(r.as_ptr())));
+            }
+            super::super::scalar::wrapping_sub_assign(
+                lhs_chunks.into_remainder(),
+                rhs_chunks.remainder(),
+            );
+        }
+
+        #[target_feature(enable = "neon")]
+        pub(crate) unsafe fn wrapping_add_scaled_assign(
+            lhs: &mut [$Scalar],
+            rhs: &[$Scalar],
+            factor: $Scalar,
+        ) {
+            let factors = splat(factor);
+            let mut lhs_chunks = lhs.chunks_exact_mut(LANES);
+            let mut rhs_chunks = rhs.chunks_exact(LANES);
+            for (l, r) in (&mut lhs_chunks).zip(&mut rhs_chunks) {
+                let product = mul(load(r.as_ptr()), factors);
+                store(l.as_mut_ptr(), add(load(l.as_ptr()), product));
+            }
+            super::super::scalar::wrapping_add_scaled_assign(
+                lhs_chunks.into_remainder(),
+                rhs_chunks.remainder(),
+                factor,
+            );
+        }
+
+        #[target_feature(enable = "neon")]
+        pub(crate) unsafe fn wrapping_sub_scaled_assign(
+            lhs: &mut [$Scalar],
+            rhs: &[$Scalar],
+            factor: $Scalar,
+        ) {
+            let factors = splat(factor);
+            let mut lhs_chunks = lhs.chunks_exact_mut(LANES);
+            let mut rhs_chunks = rhs.chunks_exact(LANES);
+            for (l, r) in (&mut lhs_chunks).zip(&mut rhs_chunks) {
+                let product = mul(load(r.as_ptr()), factors);
+                store(l.as_mut_ptr(), sub(load(l.as_ptr()), product));
+            }
+            super::super::scalar::wrapping_sub_scaled_assign(
+                lhs_chunks.into_remainder(),
+                rhs_chunks.remainder(),
+                factor,
+            );
+        }
+
+        #[target_feature(enable = "neon")]
+        pub(crate) unsafe fn decompose_one_level(
+            outputs: &mut [$Scalar],
+            states: &mut [$Scalar],
+            base_log: usize,
+            mod_b_mask: $Scalar,
+        ) {
+            let masks = splat(mod_b_mask);
+            let ones = splat(1);
+            let mut output_chunks = outputs.chunks_exact_mut(LANES);
+            let mut state_chunks = states.chunks_exact_mut(LANES);
+            for (o, s) in (&mut output_chunks).zip(&mut state_chunks) {
+                let mut state = load(s.as_ptr());
+                let res = and(state, masks);
+                state = shr(state, base_log);
+                let mut carry = and(or(sub(res, ones), state), res);
+                carry = shr(carry, base_log - 1);
+                state = add(state, carry);
+                store(s.as_mut_ptr(), state);
+                store(o.as_mut_ptr(), sub(res, shl(carry, base_log)));
+            }
+            super::super::scalar::decompose_one_level(
+                output_chunks.into_remainder(),
+                state_chunks.into_remainder(),
+                base_log,
+                mod_b_mask,
+            );
+        }
+    };
+}
+
+pub(crate) mod u32x4 {
+    use std::arch::aarch64::*;
+
+    const LANES: usize = 4;
+
+    #[inline]
+    unsafe fn load(ptr: *const u32) -> uint32x4_t {
+        vld1q_u32(ptr)
+    }
+
+    #[inline]
+    unsafe fn store(ptr: *mut u32, a: uint32x4_t) {
+        vst1q_u32(ptr, a)
+    }
+
+    #[inline]
+    unsafe fn splat(value: u32) -> uint32x4_t {
+        vdupq_n_u32(value)
+    }
+
+    #[inline]
+    unsafe fn add(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+        vaddq_u32(a, b)
+    }
+
+    #[inline]
+    unsafe fn sub(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+        vsubq_u32(a, b)
+    }
+
+    #[inline]
+    unsafe fn mul(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+        vmulq_u32(a, b)
+    }
+
+    #[inline]
+    unsafe fn and(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+        vandq_u32(a, b)
+    }
+
+    #[inline]
+    unsafe fn or(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+        vorrq_u32(a, b)
+    }
+
+    // A negative shift count shifts to the right.
+    #[inline]
+    unsafe fn shr(a: uint32x4_t, count: usize) -> uint32x4_t {
+        vshlq_u32(a, vdupq_n_s32(-(count as i32)))
+    }
+
+    #[inline]
+    unsafe fn shl(a: uint32x4_t, count: usize) -> uint32x4_t {
+        vshlq_u32(a, vdupq_n_s32(count as i32))
+    }
+
+    implement_kernels!(u32);
+}
+
+pub(crate) mod u64x2 {
+    use std::arch::aarch64::*;
+
+    const LANES: usize = 2;
+
+    #[inline]
+    unsafe fn load(ptr: *const u64) -> uint64x2_t {
+        vld1q_u64(ptr)
+    }
+
+    #[inline]
+    unsafe fn store(ptr: *mut u64, a: uint64x2_t) {
+        vst1q_u64(ptr, a)
+    }
+
+    #[inline]
+    unsafe fn splat(value: u64) -> uint64x2_t {
+        vdupq_n_u64(value)
+    }
+
+    #[inline]
+    unsafe fn add(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+        vaddq_u64(a, b)
+    }
+
+    #[inline]
+    unsafe fn sub(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+        vsubq_u64(a, b)
+    }
+
+    // NEON has no 64 bits multiplication. Writing a = a_hi * 2^32 + a_lo (and the same for b),
+    // we have a * b = a_lo * b_lo + (a_hi * b_lo + a_lo * b_hi) * 2^32 mod 2^64, where the first
+    // product is a widening one, and the cross products only need their 32 lowest bits.
+    #[inline]
+    unsafe fn mul(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+        let a_lo = vmovn_u64(a);
+        let a_hi = vmovn_u64(vshrq_n_u64(a, 32));
+        let b_lo = vmovn_u64(b);
+        let b_hi = vmovn_u64(vshrq_n_u64(b, 32));
+        let cross = vadd_u32(vmul_u32(a_hi, b_lo), vmul_u32(a_lo, b_hi));
+        vaddq_u64(vmull_u32(a_lo, b_lo), vshlq_n_u64(vmovl_u32(cross), 32))
+    }
+
+    #[inline]
+    unsafe fn and(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+        vandq_u64(a, b)
+    }
+
+    #[inline]
+    unsafe fn or(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+        vorrq_u64(a, b)
+    }
+
+    // A negative shift count shifts to the right.
+    #[inline]
+    unsafe fn shr(a: uint64x2_t, count: usize) -> uint64x2_t {
+        vshlq_u64(a, vdupq_n_s64(-(count as i64)))
+    }
+
+    #[inline]
+    unsafe fn shl(a: uint64x2_t, count: usize) -> uint64x2_t {
+        vshlq_u64(a, vdupq_n_s64(count as i64))
+    }
+
+    implement_kernels!(u64);
+}
@@ -0,0 +1,90 @@
+use crate::backends::core::private::math::kernels::{scalar, VectorKernels};
+use crate::backends::core::private::math::random::{RandomGenerable, Uniform};
+use crate::backends::core::private::test_tools::{any_uint, random_usize_between};
+use std::fmt::Debug;
+
+// Returns a random vec, whose length is not necessarily a multiple of the vector sizes.
+fn any_vec<T: VectorKernels + RandomGenerable<Uniform>>(len: usize) -> Vec<T> {
+    (0..len).map(|_| any_uint::<T>()).collect()
+}
+
+fn test_binary_kernels<T: VectorKernels + RandomGenerable<Uniform> + Debug>() {
+    for _ in 0..1000 {
+        let len = random_usize_between(0..100);
+        let lhs = any_vec::<T>(len);
+        let rhs = any_vec::<T>(len);
+        let factor = any_uint::<T>();
+
+        let mut kernel = lhs.clone();
+        let mut reference = lhs.clone();
+        T::wrapping_add_assign(&mut kernel, &rhs);
+        scalar::wrapping_add_assign(&mut reference, &rhs);
+        assert_eq!(kernel, reference);
+
+        let mut kernel = lhs.clone();
+        let mut reference = lhs.clone();
+        T::wrapping_sub_assign(&mut kernel, &rhs);
+        scalar::wrapping_sub_assign(&mut reference, &rhs);
+        assert_eq!(kernel, reference);
+
+        let mut kernel = lhs.clone();
+        let mut reference = lhs.clone();
+        T::wrapping_add_scaled_assign(&mut kernel, &rhs, factor);
+        scalar::wrapping_add_scaled_assign(&mut reference, &rhs, factor);
+        assert_eq!(kernel, reference);
+
+        let mut kernel = lhs.clone();
+        let mut reference = lhs;
+        T::wrapping_sub_scaled_assign(&mut kernel, &rhs, factor);
+        scalar::wrapping_sub_scaled_assign(&mut reference, &rhs, factor);
+        assert_eq!(kernel, reference);
+    }
+}
+
+fn test_decomposition_kernel<T: VectorKernels + RandomGenerable<Uniform> + Debug>() {
+    for _ in 0..1000 {
+        let len = random_usize_between(0..100);
+        let base_log = random_usize_between(1..T::BITS / 2);
+        let mod_b_mask = (T::ONE << base_log) - T::ONE;
+        let mut kernel_states = any_vec::<T>(len);
+        let mut reference_states = kernel_states.clone();
+        let mut kernel_outputs = vec![T::ZERO; len];
+        let mut reference_outputs = vec![T::ZERO; len];
+        for _ in 0..2 {
+            T::decompose_one_level(
+                &mut kernel_outputs,
+                &mut kernel_states,
+                base_log,
+                mod_b_mask,
+            );
+            scalar::decompose_one_level(
+                &mut reference_outputs,
+                &mut reference_states,
+                base_log,
+                mod_b_mask,
+            );
+            assert_eq!(kernel_outputs, reference_outputs);
+            assert_eq!(kernel_states, reference_states);
+        }
+    }
+}
+
+#[test]
+fn test_binary_kernels_u32() {
+    test_binary_kernels::<u32>()
+}
+
+#[test]
+fn test_binary_kernels_u64() {
+    test_binary_kernels::<u64>()
+}
+
+#[test]
+fn test_decomposition_kernel_u32() {
+    test_decomposition_kernel::<u32>()
+}
+
+#[test]
+fn test_decomposition_kernel_u64() {
+    test_decomposition_kernel::<u64>()
+}
@@ -2,6 +2,7 @@
 
 pub mod decomposition;
 pub mod fft;
+pub mod kernels;
 pub mod polynomial;
 pub mod random;
 pub mod tensor;
@@ -1,8 +1,9 @@
 use std::fmt::Debug;
 use std::iter::Iterator;
 
+use crate::backends::core::private::math::kernels::VectorKernels;
 use crate::backends::core::private::math::tensor::{
-    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefTensor, Tensor,
+    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
 
 use super::*;
@@ -276,27 +277,11 @@ impl<Cont> Polynomial<Cont> {
         Self: AsMutTensor<Element = Coef>,
         Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
         Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
-        Coef: UnsignedInteger,
+        Coef: VectorKernels,
     {
         ck_dim_eq!(self.polynomial_size() => lhs.polynomial_size(), rhs.polynomial_size());
         self.coefficient_iter_mut().for_each(|a| *a = Coef::ZERO);
-        let degree = lhs.polynomial_size().0 - 1;
-        for lhsi in lhs.monomial_iter() {
-            for rhsi in rhs.monomial_iter() {
-                let target_degree = lhsi.degree().0 + rhsi.degree().0;
-                if target_degree <= degree {
-                    let element = self.as_mut_tensor().get_element_mut(target_degree);
-                    let new = lhsi.get_coefficient().wrapping_mul(*rhsi.get_coefficient());
-                    *element = element.wrapping_add(new);
-                } else {
-                    let element = self
-                        .as_mut_tensor()
-                        .get_element_mut(target_degree % (degree + 1));
-                    let new = lhsi.get_coefficient().wrapping_mul(*rhsi.get_coefficient());
-                    *element = element.wrapping_sub(new);
-                }
-            }
-        }
+        self.update_with_wrapping_add_mul(lhs, rhs);
     }
 
     /// Fills the current polynomial, with the result of the product of two
@@ -324,7 +309,7 @@ impl<Cont> Polynomial<Cont> {
         Self: AsMutTensor<Element = Coef>,
         Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
         Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
-        Coef: UnsignedInteger,
+        Coef: VectorKernels,
     {
         // check same dimensions
         ck_dim_eq!(self.polynomial_size() => p.polynomial_size(), q.polynomial_size());
@@ -440,7 +425,7 @@ impl<Cont> Polynomial<Cont> {
         PolynomialList<Cont2>: AsRefTensor<Element = Coef>,
         for<'a> Polynomial<&'a [Coef]>: AsRefTensor<Element = Coef>,
         for<'a> Polynomial<&'a [Coef]>: AsRefTensor<Element = Coef>,
-        Coef: UnsignedInteger,
+        Coef: VectorKernels,
     {
         for (poly, bin_poly) in coef_list.polynomial_iter().zip(bin_list.polynomial_iter()) {
             self.update_with_wrapping_add_mul(&poly, &bin_poly);
@@ -491,7 +476,7 @@ impl<Cont> Polynomial<Cont> {
         PolynomialList<BinCont>: AsRefTensor<Element = Coef>,
         for<'a> Polynomial<&'a [Coef]>: AsRefTensor<Element = Coef>,
         for<'a> Polynomial<&'a [Coef]>: AsRefTensor<Element = Coef>,
-        Coef: UnsignedInteger,
+        Coef: VectorKernels,
     {
         for (poly, bin_poly) in coef_list.polynomial_iter().zip(bin_list.polynomial_iter()) {
             self.update_with_wrapping_sub_mul(&poly, &bin_poly);
@@ -521,33 +506,30 @@ impl<Cont> Polynomial<Cont> {
         Self: AsMutTensor<Element = Coef>,
         Polynomial<Cont1>: AsRefTensor<Element = Coef>,
         Polynomial<Cont2>: AsRefTensor<Element = Coef>,
-        Coef: UnsignedInteger,
+        Coef: VectorKernels,
     {
         ck_dim_eq!(
             self.polynomial_size() =>
             polynomial.polynomial_size(),
             bin_polynomial.polynomial_size()
         );
-        let degree = polynomial.polynomial_size().0 - 1;
-        for lhsi in polynomial.monomial_iter() {
-            for rhsi in bin_polynomial.monomial_iter() {
-                let target_degree = lhsi.degree().0 + rhsi.degree().0;
-                if target_degree <= degree {
-                    let update = self
-                        .as_tensor()
-                        .get_element(target_degree)
-                        .wrapping_add(*lhsi.get_coefficient() * *rhsi.get_coefficient());
-                    *self.as_mut_tensor().get_element_mut(target_degree) = update;
-                } else {
-                    let update = self
-                        .as_tensor()
-                        .get_element(target_degree % (degree + 1))
-                        .wrapping_sub(*lhsi.get_coefficient() * *rhsi.get_coefficient());
-                    *self
-                        .as_mut_tensor()
-                        .get_element_mut(target_degree % (degree + 1)) = update;
-                }
-            }
+        let size = polynomial.polynomial_size().0;
+        let rhs = bin_polynomial.as_tensor().as_slice();
+        let output = self.as_mut_tensor().as_mut_slice();
+        // The product of the monomial of degree i with the other polynomial is added to the
+        // coefficients of degree i and above, and its part of degree N and above is subtracted
+        // to the coefficients of degree below i, because X^N = -1.
+        for (degree, coefficient) in polynomial.as_tensor().iter().enumerate() {
+            Coef::wrapping_add_scaled_assign(
+                &mut output[degree..],
+                &rhs[..size - degree],
+                *coefficient,
+            );
+            Coef::wrapping_sub_scaled_assign(
+                &mut output[..degree],
+                &rhs[size - degree..],
+                *coefficient,
+            );
         }
     }
 
@@ -574,35 +556,125 @@ impl<Cont> Polynomial<Cont> {
         Self: AsMutTensor<Element = Coef>,
         Polynomial<PolyCont>: AsRefTensor<Element = Coef>,
         Polynomial<BinCont>: AsRefTensor<Element = Coef>,
-        Coef: UnsignedInteger,
+        Coef: VectorKernels,
     {
         ck_dim_eq!(
             self.polynomial_size() =>
             polynomial.polynomial_size(),
             bin_polynomial.polynomial_size()
         );
-        let degree = polynomial.polynomial_size().0 - 1;
-        for lhsi in polynomial.monomial_iter() {
-            for rhsi in bin_polynomial.monomial_iter() {
-                let target_degree = lhsi.degree().0 + rhsi.degree().0;
-                if target_degree <= degree {
-                    let update = self
-                        .as_tensor()
-                        .get_element(target_degree)
-                        .wrapping_sub(*lhsi.get_coefficient() * *rhsi.get_coefficient());
-                    *self.as_mut_tensor().get_element_mut(target_degree) = update;
-                } else {
-                    let update = self
-                        .as_tensor()
-                        .get_element(target_degree % (degree + 1))
-                        .wrapping_add(*lhsi.get_coefficient() * *rhsi.get_coefficient());
-                    *self
-                        .as_mut_tensor()
-                        .as_mut_slice()
-                        .get_mut(target_degree % (degree + 1))
-                        .unwrap() = update;
-                }
-            }
+        let size = polynomial.polynomial_size().0;
+        let rhs = bin_polynomial.as_tensor().as_slice();
+        let output = self.as_mut_tensor().as_mut_slice();
+        // Same as `update_with_wrapping_add_mul`, with the opposite signs.
+        for (degree, coefficient) in polynomial.as_tensor().iter().enumerate() {
+            Coef::wrapping_sub_scaled_assign(
+                &mut output[degree..],
+                &rhs[..size - degree],
+                *coefficient,
+            );
+            Coef::wrapping_add_scaled_assign(
+                &mut output[..degree],
+                &rhs[size - degree..],
+                *coefficient,
+            );
+        }
+    }
+
+    /// Adds the result of the product between two integer polynomials, reduced modulo $(X^N-1)$,
+    /// to the current polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::backends::core::private::math::polynomial::{MonomialDegree, Polynomial};
+    /// let poly_1 = Polynomial::from_container(vec![1_u8, 2, 3]);
+    /// let poly_2 = Polynomial::from_container(vec![0, 1, 1]);
+    /// let mut res = Polynomial::from_container(vec![1, 0, 253]);
+    /// res.update_with_wrapping_add_cyclic_mul(&poly_1, &poly_2);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 6);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 4);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 0);
+    /// ```
+    pub fn update_with_wrapping_add_cyclic_mul<Coef, Cont1, Cont2>(
+        &mut self,
+        polynomial: &Polynomial<Cont1>,
+        other_polynomial: &Polynomial<Cont2>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<Cont1>: AsRefTensor<Element = Coef>,
+        Polynomial<Cont2>: AsRefTensor<Element = Coef>,
+        Coef: VectorKernels,
+    {
+        ck_dim_eq!(
+            self.polynomial_size() =>
+            polynomial.polynomial_size(),
+            other_polynomial.polynomial_size()
+        );
+        let size = polynomial.polynomial_size().0;
+        let rhs = other_polynomial.as_tensor().as_slice();
+        let output = self.as_mut_tensor().as_mut_slice();
+        // Same as `update_with_wrapping_add_mul`, except that the part of degree N and above is
+        // added, because X^N = 1.
+        for (degree, coefficient) in polynomial.as_tensor().iter().enumerate() {
+            Coef::wrapping_add_scaled_assign(
+                &mut output[degree..],
+                &rhs[..size - degree],
+                *coefficient,
+            );
+            Coef::wrapping_add_scaled_assign(
+                &mut output[..degree],
+                &rhs[size - degree..],
+                *coefficient,
+            );
+        }
+    }
+
+    /// Subtracts the result of the product between two integer polynomials, reduced
+    /// modulo $(X^N-1)$, to the current polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::backends::core::private::math::polynomial::{MonomialDegree, Polynomial};
+    /// let poly_1 = Polynomial::from_container(vec![1_u8, 2, 3]);
+    /// let poly_2 = Polynomial::from_container(vec![0, 1, 1]);
+    /// let mut res = Polynomial::from_container(vec![255, 255, 1]);
+    /// res.update_with_wrapping_sub_cyclic_mul(&poly_1, &poly_2);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 250);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 251);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 254);
+    /// ```
+    pub fn update_with_wrapping_sub_cyclic_mul<Coef, Cont1, Cont2>(
+        &mut self,
+        polynomial: &Polynomial<Cont1>,
+        other_polynomial: &Polynomial<Cont2>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<Cont1>: AsRefTensor<Element = Coef>,
+        Polynomial<Cont2>: AsRefTensor<Element = Coef>,
+        Coef: VectorKernels,
+    {
+        ck_dim_eq!(
+            self.polynomial_size() =>
+            polynomial.polynomial_size(),
+            other_polynomial.polynomial_size()
+        );
+        let size = polynomial.polynomial_size().0;
+        let rhs = other_polynomial.as_tensor().as_slice();
+        let output = self.as_mut_tensor().as_mut_slice();
+        // Same as `update_with_wrapping_add_cyclic_mul`, with the opposite signs.
+        for (degree, coefficient) in polynomial.as_tensor().iter().enumerate() {
+            Coef::wrapping_sub_scaled_assign(
+                &mut output[degree..],
+                &rhs[..size - degree],
+                *coefficient,
+            );
+            Coef::wrapping_sub_scaled_assign(
+                &mut output[..degree],
+                &rhs[size - degree..],
+                *coefficient,
+            );
         }
     }
 
@@ -623,7 +695,7 @@ impl<Cont> Polynomial<Cont> {
     where
         Self: AsMutTensor<Element = Coef>,
         Polynomial<OtherCont>: AsRefTensor<Element = Coef>,
-        Coef: UnsignedInteger,
+        Coef: VectorKernels,
     {
         ck_dim_eq!(
             self.polynomial_size() =>
@@ -659,7 +731,7 @@ impl<Cont> Polynomial<Cont> {
     where
         Self: AsMutTensor<Element = Coef>,
         Polynomial<OtherCont>: AsRefTensor<Element = Coef>,
-        Coef: UnsignedInteger,
+        Coef: VectorKernels,
     {
         ck_dim_eq!(
             self.polynomial_size() =>
@@ -765,7 +837,7 @@ impl<Cont> Polynomial<Cont> {
         Self: AsMutTensor<Element = Coef>,
         PolynomialList<InCont>: AsRefTensor<Element = Coef>,
         for<'a> Polynomial<&'a [Coef]>: AsRefTensor<Element = Coef>,
-        Coef: UnsignedInteger,
+        Coef: VectorKernels,
     {
         for poly in coef_list.polynomial_iter() {
             self.update_with_wrapping_add(&poly);
@@ -804,7 +876,7 @@ impl<Cont> Polynomial<Cont> {
         Self: AsMutTensor<Element = Coef>,
         PolynomialList<InCont>: AsRefTensor<Element = Coef>,
         for<'a> Polynomial<&'a [Coef]>: AsRefTensor<Element = Coef>,
-        Coef: UnsignedInteger,
+        Coef: VectorKernels,
     {
         for poly in coef_list.polynomial_iter() {
             self.update_with_wrapping_sub(&poly);
@@ -818,16 +890,16 @@ fn induction_karatsuba<Coef>(
     p: &Tensor<&[Coef]>,
     q: &Tensor<&[Coef]>,
 ) where
-    Coef: UnsignedInteger,
+    Coef: VectorKernels,
 {
     if p.len() == KARATUSBA_STOP {
         // schoolbook algorithm
-        for i in 0..p.len() {
-            for j in 0..q.len() {
-                *res.get_element_mut(i + j) = res
-                    .get_element(i + j)
-                    .wrapping_add(p.get_element(i).wrapping_mul(*q.get_element(j)))
-            }
+        for (i, p_i) in p.iter().enumerate() {
+            Coef::wrapping_add_scaled_assign(
+                &mut res.as_mut_slice()[i..i + q.len()],
+                q.as_slice(),
+                *p_i,
+            );
         }
     } else {
         let poly_size = res.len();
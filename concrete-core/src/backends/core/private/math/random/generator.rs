@@ -1,6 +1,6 @@
 use crate::backends::core::private::math::random::{
-    Gaussian, RandomGenerable, Uniform, UniformBinary, UniformLsb, UniformMsb, UniformTernary,
-    UniformWithZeros,
+    Gaussian, RandomGenerable, TUniform, Uniform, UniformBinary, UniformBounded, UniformLsb,
+    UniformMsb, UniformTernary, UniformWithZeros,
 };
 use crate::backends::core::private::math::tensor::{AsMutSlice, AsMutTensor, Tensor};
 use concrete_commons::numeric::{FloatingPoint, Numeric};
@@ -69,6 +69,23 @@ impl RandomGenerator {
         RandomGenerator(RandomGeneratorImpl::new(seed))
     }
 
+    /// Generates a new generator seeded with the given value, whose state starts at the given AES
+    /// counter.
+    ///
+    /// Generators built from the same seed with different counters yield independent bytes, as
+    /// long as their counters are more than the number of generated bytes divided by 16 apart.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::backends::core::private::math::random::RandomGenerator;
+    /// let mut generator = RandomGenerator::new_with_counter(0, 1 << 64);
+    /// assert!(!generator.is_bounded());
+    /// ```
+    pub fn new_with_counter(seed: u128, counter: u128) -> RandomGenerator {
+        RandomGenerator(RandomGeneratorImpl::new_with_counter(seed, counter))
+    }
+
     /// Returns the number of bytes that can still be generated, if the generator is bounded.
     ///
     /// # Example
@@ -317,6 +334,86 @@ impl RandomGenerator {
         Scalar::generate_tensor(self, UniformTernary, size)
     }
 
+    /// Generates an unsigned integer sampled uniformly in `[-bound, bound]`, in two's complement.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::backends::core::private::math::random::RandomGenerator;
+    /// let mut generator = RandomGenerator::new(None);
+    /// let random: u32 = generator.random_uniform_bounded(3);
+    /// assert!(random <= 3 || random >= 0u32.wrapping_sub(3));
+    /// ```
+    pub fn random_uniform_bounded<Scalar: RandomGenerable<UniformBounded>>(
+        &mut self,
+        bound: u128,
+    ) -> Scalar {
+        Scalar::generate_one(self, UniformBounded { bound })
+    }
+
+    /// Fills an `AsMutTensor` value with random values sampled uniformly in `[-bound, bound]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::backends::core::private::math::random::RandomGenerator;
+    /// use concrete_core::backends::core::private::math::tensor::Tensor;
+    /// let mut generator = RandomGenerator::new(None);
+    /// let mut tensor = Tensor::allocate(0u64, 100);
+    /// generator.fill_tensor_with_random_uniform_bounded(&mut tensor, 1 << 20);
+    /// ```
+    pub fn fill_tensor_with_random_uniform_bounded<Scalar, Tensorable>(
+        &mut self,
+        output: &mut Tensorable,
+        bound: u128,
+    ) where
+        Scalar: RandomGenerable<UniformBounded>,
+        Tensorable: AsMutTensor<Element = Scalar>,
+    {
+        Scalar::fill_tensor(self, UniformBounded { bound }, output);
+    }
+
+    /// Generates an unsigned integer following a TUniform distribution over
+    /// `[-2^log2_bound, 2^log2_bound]`, in two's complement.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::backends::core::private::math::random::RandomGenerator;
+    /// let mut generator = RandomGenerator::new(None);
+    /// let random: u32 = generator.random_t_uniform(2);
+    /// assert!(random <= 4 || random >= 0u32.wrapping_sub(4));
+    /// ```
+    pub fn random_t_uniform<Scalar: RandomGenerable<TUniform>>(
+        &mut self,
+        log2_bound: u32,
+    ) -> Scalar {
+        Scalar::generate_one(self, TUniform { log2_bound })
+    }
+
+    /// Fills an `AsMutTensor` value with random values following a TUniform distribution over
+    /// `[-2^log2_bound, 2^log2_bound]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::backends::core::private::math::random::RandomGenerator;
+    /// use concrete_core::backends::core::private::math::tensor::Tensor;
+    /// let mut generator = RandomGenerator::new(None);
+    /// let mut tensor = Tensor::allocate(0u64, 100);
+    /// generator.fill_tensor_with_random_t_uniform(&mut tensor, 20);
+    /// ```
+    pub fn fill_tensor_with_random_t_uniform<Scalar, Tensorable>(
+        &mut self,
+        output: &mut Tensorable,
+        log2_bound: u32,
+    ) where
+        Scalar: RandomGenerable<TUniform>,
+        Tensorable: AsMutTensor<Element = Scalar>,
+    {
+        Scalar::fill_tensor(self, TUniform { log2_bound }, output);
+    }
+
     /// Generates an unsigned integer whose n least significant bits are uniformly random, and the
     /// other bits are zero.
     ///
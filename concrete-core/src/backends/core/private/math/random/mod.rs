@@ -17,8 +17,10 @@ use crate::backends::core::private::math::tensor::{AsMutTensor, Tensor};
 use concrete_commons::numeric::FloatingPoint;
 pub use gaussian::*;
 pub use generator::*;
+pub use t_uniform::*;
 pub use uniform::*;
 pub use uniform_binary::*;
+pub use uniform_bounded::*;
 pub use uniform_lsb::*;
 pub use uniform_msb::*;
 pub use uniform_ternary::*;
@@ -29,8 +31,10 @@ mod tests;
 
 mod gaussian;
 mod generator;
+mod t_uniform;
 mod uniform;
 mod uniform_binary;
+mod uniform_bounded;
 mod uniform_lsb;
 mod uniform_msb;
 mod uniform_ternary;
@@ -70,6 +74,8 @@ mod seal {
     impl Sealed for super::UniformWithZeros {}
     impl Sealed for super::UniformBinary {}
     impl Sealed for super::UniformTernary {}
+    impl Sealed for super::UniformBounded {}
+    impl Sealed for super::TUniform {}
     impl<T: super::FloatingPoint> Sealed for super::Gaussian<T> {}
 }
 impl Distribution for Uniform {}
@@ -78,4 +84,6 @@ impl Distribution for UniformLsb {}
 impl Distribution for UniformWithZeros {}
 impl Distribution for UniformBinary {}
 impl Distribution for UniformTernary {}
+impl Distribution for UniformBounded {}
+impl Distribution for TUniform {}
 impl<T: FloatingPoint> Distribution for Gaussian<T> {}
@@ -0,0 +1,41 @@
+use super::*;
+
+/// A distribution type representing TUniform sampling for unsigned integer types.
+///
+/// The values are the integers of `[-2^log2_bound, 2^log2_bound]`, in two's complement. The two
+/// bounds are sampled with probability `2^-(log2_bound + 2)`, and the other values with
+/// probability `2^-(log2_bound + 1)`.
+#[derive(Copy, Clone)]
+pub struct TUniform {
+    /// The base 2 logarithm of the bound.
+    pub log2_bound: u32,
+}
+
+macro_rules! implement_t_uniform {
+    ($T:ty) => {
+        impl RandomGenerable<TUniform> for $T {
+            fn generate_one(
+                generator: &mut RandomGenerator,
+                TUniform { log2_bound }: TUniform,
+            ) -> Self {
+                // The sum of the upper bits and of the last bit of a uniform value of
+                // `log2_bound + 2` bits takes the values of `[0, 2^(log2_bound + 1)]`, the two
+                // ends being reached in a single way, and the other values in two ways.
+                let value = u128::generate_one(
+                    generator,
+                    UniformLsb {
+                        n: log2_bound as usize + 2,
+                    },
+                );
+                let shifted = (value >> 1) + (value & 1);
+                (shifted as $T).wrapping_sub((1_u128 << log2_bound) as $T)
+            }
+        }
+    };
+}
+
+implement_t_uniform!(u8);
+implement_t_uniform!(u16);
+implement_t_uniform!(u32);
+implement_t_uniform!(u64);
+implement_t_uniform!(u128);
@@ -1,4 +1,5 @@
 use concrete_commons::dispersion::LogStandardDev;
+use concrete_commons::numeric::CastInto;
 
 use crate::backends::core::private::math::random::RandomGenerator;
 use crate::backends::core::private::math::tensor::Tensor;
@@ -58,6 +59,24 @@ fn test_normal_random_u64() {
     test_normal_random::<u64>();
 }
 
+#[test]
+fn test_normal_random_u128() {
+    test_normal_random::<u128>();
+}
+
+#[test]
+fn test_small_normal_random_u128() {
+    // The lower bits of the samples must be kept for both signs, far below the precision of the
+    // float they are sampled in.
+    let std_dev: f64 = f64::powi(2., -100);
+    let k = 100_000;
+    let mut generator = RandomGenerator::new(None);
+    let mut samples = Tensor::allocate(0u128, k);
+    generator.fill_tensor_with_random_gaussian(&mut samples, 0., std_dev);
+    let expected = f64::powi(2., 56);
+    assert!((signed_variance(&samples) - expected).abs() < 0.05 * expected);
+}
+
 fn test_distribution<T: UnsignedTorus>() {
     //! tests gaussianity against the rand crate generation
     // settings
@@ -74,6 +93,11 @@ fn test_distribution<T: UnsignedTorus>() {
     assert_noise_distribution(&first, &second, LogStandardDev(-5.));
 }
 
+#[test]
+fn test_distribution_u16() {
+    test_distribution::<u16>();
+}
+
 #[test]
 fn test_distribution_u32() {
     test_distribution::<u32>();
@@ -83,3 +107,89 @@ fn test_distribution_u32() {
 fn test_distribution_u64() {
     test_distribution::<u64>();
 }
+
+// Returns the empirical variance of the samples, interpreted as signed integers.
+fn signed_variance<T: UnsignedTorus>(samples: &Tensor<Vec<T>>) -> f64 {
+    let values: Vec<f64> = samples
+        .iter()
+        .map(|s| {
+            let signed: f64 = s.into_signed().cast_into();
+            signed
+        })
+        .collect();
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64
+}
+
+fn test_uniform_bounded_random<T: UnsignedTorus>() {
+    let bound: u128 = 1000;
+    let k = 1_000_000;
+    let mut generator = RandomGenerator::new(None);
+    let mut samples = Tensor::allocate(T::ZERO, k);
+    generator.fill_tensor_with_random_uniform_bounded(&mut samples, bound);
+    let bound_value: T = T::cast_from(bound as f64);
+    assert!(samples
+        .iter()
+        .all(|s| *s <= bound_value || *s >= T::ZERO.wrapping_sub(bound_value)));
+    let expected = (bound * (bound + 1)) as f64 / 3.;
+    assert!((signed_variance(&samples) - expected).abs() < 0.01 * expected);
+}
+
+#[test]
+fn test_uniform_bounded_random_u16() {
+    test_uniform_bounded_random::<u16>();
+}
+
+#[test]
+fn test_uniform_bounded_random_u32() {
+    test_uniform_bounded_random::<u32>();
+}
+
+#[test]
+fn test_uniform_bounded_random_u64() {
+    test_uniform_bounded_random::<u64>();
+}
+
+#[test]
+fn test_uniform_bounded_random_u128() {
+    test_uniform_bounded_random::<u128>();
+}
+
+fn test_t_uniform_random<T: UnsignedTorus>() {
+    let log2_bound = 10;
+    let k = 1_000_000;
+    let mut generator = RandomGenerator::new(None);
+    let mut samples = Tensor::allocate(T::ZERO, k);
+    generator.fill_tensor_with_random_t_uniform(&mut samples, log2_bound);
+    let bound_value: T = T::ONE << log2_bound as usize;
+    assert!(samples
+        .iter()
+        .all(|s| *s <= bound_value || *s >= T::ZERO.wrapping_sub(bound_value)));
+    // The bounds are reached, with half the probability of the other values.
+    let upper = samples.iter().filter(|s| **s == bound_value).count();
+    let zero = samples.iter().filter(|s| **s == T::ZERO).count();
+    assert!(upper > 0);
+    assert!(((2 * upper) as f64 - zero as f64).abs() < 0.2 * zero as f64);
+    let expected = (f64::powi(2., 2 * log2_bound as i32 + 1) + 1.) / 6.;
+    assert!((signed_variance(&samples) - expected).abs() < 0.01 * expected);
+}
+
+#[test]
+fn test_t_uniform_random_u16() {
+    test_t_uniform_random::<u16>();
+}
+
+#[test]
+fn test_t_uniform_random_u32() {
+    test_t_uniform_random::<u32>();
+}
+
+#[test]
+fn test_t_uniform_random_u64() {
+    test_t_uniform_random::<u64>();
+}
+
+#[test]
+fn test_t_uniform_random_u128() {
+    test_t_uniform_random::<u128>();
+}
@@ -0,0 +1,74 @@
+use super::*;
+use concrete_commons::numeric::Numeric;
+
+/// A distribution type representing uniform sampling for unsigned integer types, over the
+/// integers of `[-bound, bound]`, in two's complement.
+#[derive(Copy, Clone)]
+pub struct UniformBounded {
+    /// The bound of the interval.
+    pub bound: u128,
+}
+
+macro_rules! implement_uniform_bounded {
+    ($T:ty) => {
+        impl RandomGenerable<UniformBounded> for $T {
+            fn generate_one(
+                generator: &mut RandomGenerator,
+                UniformBounded { bound }: UniformBounded,
+            ) -> Self {
+                if bound == 0 {
+                    return 0;
+                }
+                let range = bound.saturating_mul(2).saturating_add(1);
+                // The range, a 128 bits integer, can not exceed the values of a 128 bits type.
+                if 1_u128
+                    .checked_shl(<$T as Numeric>::BITS as u32)
+                    .is_some_and(|limit| range >= limit)
+                {
+                    return <$T>::generate_one(generator, Uniform);
+                }
+                // The value is the integer part of `r * range / 2^128`, for a uniform 128 bits
+                // integer `r`. Contrarily to a rejection sampling, every value consumes the same
+                // number of bytes of the generator, which allows forked generators to be given a
+                // fixed byte budget. The statistical distance to the uniform distribution over the
+                // range is at most `range / 2^128`, which is below `2^-64` for every range
+                // representable in 64 bits.
+                let value = mul_high(u128::generate_one(generator, Uniform), range);
+                (value as $T).wrapping_sub(bound as $T)
+            }
+        }
+    };
+}
+
+implement_uniform_bounded!(u8);
+implement_uniform_bounded!(u16);
+implement_uniform_bounded!(u32);
+implement_uniform_bounded!(u64);
+implement_uniform_bounded!(u128);
+
+// Returns the 128 most significant bits of the 256 bits product of `a` and `b`.
+fn mul_high(a: u128, b: u128) -> u128 {
+    const LOW: u128 = u64::MAX as u128;
+    let (a_high, a_low) = (a >> 64, a & LOW);
+    let (b_high, b_low) = (b >> 64, b & LOW);
+    let low_low = a_low * b_low;
+    let high_low = a_high * b_low;
+    let low_high = a_low * b_high;
+    let high_high = a_high * b_high;
+    // The carry of the middle terms fits in 66 bits, hence no overflow can occur.
+    let middle = (low_low >> 64) + (high_low & LOW) + (low_high & LOW);
+    high_high + (high_low >> 64) + (low_high >> 64) + (middle >> 64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::mul_high;
+
+    #[test]
+    fn test_mul_high() {
+        assert_eq!(mul_high(u128::MAX, u128::MAX), u128::MAX - 1);
+        assert_eq!(mul_high(1 << 127, 2), 1);
+        assert_eq!(mul_high(u128::MAX, 1000), 999);
+        assert_eq!(mul_high(0, u128::MAX), 0);
+    }
+}
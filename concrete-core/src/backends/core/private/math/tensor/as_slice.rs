@@ -1,5 +1,8 @@
+use concrete_commons::numeric::Numeric;
 use concrete_fftw::array::AlignedVec;
 
+use crate::backends::core::memory::{AlignedBuffer, SharedSlice};
+
 /// A trait allowing to extract a slice from a tensor.
 ///
 /// This trait is one of the two traits which allows to use [`Tensor`](super::Tensor) whith any data
@@ -76,6 +79,20 @@ impl<Element> AsRefSlice for AlignedVec<Element> {
     }
 }
 
+impl<Element: Numeric> AsRefSlice for AlignedBuffer<Element> {
+    type Element = Element;
+    fn as_slice(&self) -> &[Element] {
+        self.as_slice()
+    }
+}
+
+impl<'a, Element: Numeric> AsRefSlice for SharedSlice<'a, Element> {
+    type Element = Element;
+    fn as_slice(&self) -> &[Element] {
+        SharedSlice::as_slice(self)
+    }
+}
+
 /// A trait allowing to extract a mutable slice from a tensor.
 ///
 /// The logic is the same as for the `AsRefTensor`, but here, it allows to access mutable slices
@@ -115,3 +132,10 @@ impl<Element> AsMutSlice for AlignedVec<Element> {
         self.as_slice_mut()
     }
 }
+
+impl<Element: Numeric> AsMutSlice for AlignedBuffer<Element> {
+    type Element = Element;
+    fn as_mut_slice(&mut self) -> &mut [Element] {
+        self.as_mut_slice()
+    }
+}
@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 
 use concrete_commons::numeric::{CastFrom, UnsignedInteger};
 
+use crate::backends::core::private::math::kernels::VectorKernels;
 use crate::backends::core::private::utils::zip;
 
 use super::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor};
@@ -125,6 +126,22 @@ macro_rules! update_with_wrapping {
     };
 }
 
+macro_rules! update_with_wrapping_kernel {
+    ($name: ident, $kernel: ident) => {
+            pub fn $name<Other, Element>(
+                &mut self,
+                other: &Tensor<Other>,
+            ) where
+                Self: AsMutSlice<Element=Element>,
+                Tensor<Other>: AsRefSlice<Element=Element>,
+                Element: VectorKernels
+            {
+                ck_dim_eq!(self.len() => other.len());
+                Element::$kernel(self.as_mut_slice(), other.as_slice());
+            }
+    };
+}
+
 macro_rules! update_with_scalar {
     ($Trait:ident, $name: ident, $($func:tt)*) => {
             pub fn $name<Element>(
@@ -1120,8 +1137,8 @@ impl<Container> Tensor<Container> {
     update_with!(ShlAssign, update_with_shl, |s, a| *s <<= *a);
     update_with!(ShrAssign, update_with_shr, |s, a| *s >>= *a);
 
-    update_with_wrapping!(update_with_wrapping_add, |s, a| *s = s.wrapping_add(*a));
-    update_with_wrapping!(update_with_wrapping_sub, |s, a| *s = s.wrapping_sub(*a));
+    update_with_wrapping_kernel!(update_with_wrapping_add, wrapping_add_assign);
+    update_with_wrapping_kernel!(update_with_wrapping_sub, wrapping_sub_assign);
     update_with_wrapping!(update_with_wrapping_mul, |s, a| *s = s.wrapping_mul(*a));
     update_with_wrapping!(update_with_wrapping_div, |s, a| *s = s.wrapping_div(*a));
 
@@ -1283,10 +1300,10 @@ impl<Container> Tensor<Container> {
     ) where
         Self: AsMutSlice<Element = Element>,
         Tensor<Cont>: AsRefSlice<Element = Element>,
-        Element: UnsignedInteger,
+        Element: VectorKernels,
     {
         ck_dim_eq!(self.len() => tensor.len());
-        self.update_with_one(tensor, |s, t| *s = s.wrapping_sub(t.wrapping_mul(scalar)));
+        Element::wrapping_sub_scaled_assign(self.as_mut_slice(), tensor.as_slice(), scalar);
     }
 
     /// Updates the values of a mutable tensor by adding the product of the element of another
@@ -1338,10 +1355,10 @@ impl<Container> Tensor<Container> {
     ) where
         Self: AsMutSlice<Element = Element>,
         Tensor<Cont>: AsRefSlice<Element = Element>,
-        Element: UnsignedInteger,
+        Element: VectorKernels,
     {
         ck_dim_eq!(self.len() => tensor.len());
-        self.update_with_one(tensor, |s, t| *s = s.wrapping_add(t.wrapping_mul(element)));
+        Element::wrapping_add_scaled_assign(self.as_mut_slice(), tensor.as_slice(), element);
     }
 
     /// Computes a value by folding a tensor with another.
@@ -1377,6 +1394,50 @@ impl<Container> Tensor<Container> {
             .fold(acc, |acc, (s_i, o_i)| ope(acc, s_i, o_i))
     }
 
+    /// Returns whether two tensors contain the same elements, in a time which only depends on
+    /// their lengths.
+    ///
+    /// Tensors of different lengths are unequal. Otherwise, the two tensors are compared byte by
+    /// byte, and every byte is compared even after a difference was found. This prevents the time
+    /// of the comparison from leaking the position of the first difference.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::backends::core::private::math::tensor::Tensor;
+    /// let t1 = Tensor::from_container(vec![1u64, 2, 3, 4]);
+    /// let t2 = Tensor::from_container(vec![1u64, 2, 3, 5]);
+    /// let t3 = Tensor::from_container(vec![1u64, 2, 3]);
+    /// assert!(t1.constant_time_eq(&t1));
+    /// assert!(!t1.constant_time_eq(&t2));
+    /// assert!(!t1.constant_time_eq(&t3));
+    /// ```
+    pub fn constant_time_eq<Cont, Element>(&self, other: &Tensor<Cont>) -> bool
+    where
+        Self: AsRefSlice<Element = Element>,
+        Tensor<Cont>: AsRefSlice<Element = Element>,
+        Element: UnsignedInteger,
+    {
+        if self.len() != other.len() {
+            return false;
+        }
+        let byte_len = std::mem::size_of_val(self.as_slice());
+        // Safety: unsigned integers have no padding bytes, and any byte is a valid `u8`.
+        let (lhs, rhs) = unsafe {
+            (
+                std::slice::from_raw_parts(self.as_slice().as_ptr() as *const u8, byte_len),
+                std::slice::from_raw_parts(other.as_slice().as_ptr() as *const u8, byte_len),
+            )
+        };
+        // The accumulator is read back through a volatile load at every step, which prevents the
+        // compiler from turning the loop into an early exit.
+        let difference = lhs.iter().zip(rhs.iter()).fold(0u8, |acc, (l, r)| {
+            let acc = acc | (l ^ r);
+            unsafe { std::ptr::read_volatile(&acc) }
+        });
+        difference == 0
+    }
+
     /// Reverses the elements of the tensor inplace.
     ///
     /// # Example
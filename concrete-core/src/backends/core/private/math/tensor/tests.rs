@@ -1255,3 +1255,18 @@ fn test_add_u64() {
 
     assert_eq!(t_3, ground_truth_t_3, "we are testing u64 add");
 }
+
+#[test]
+fn test_constant_time_eq() {
+    let t_1 = Tensor::from_container(vec![0xFF00_u64; 1 << 20]);
+    let mut t_2 = t_1.clone();
+    assert!(t_1.constant_time_eq(&t_2));
+    // A difference in a single byte, at any position, is detected.
+    for position in [0, 1 << 19, (1 << 20) - 1] {
+        *t_2.get_element_mut(position) ^= 1 << 56;
+        assert!(!t_1.constant_time_eq(&t_2));
+        *t_2.get_element_mut(position) ^= 1 << 56;
+    }
+    let t_3 = Tensor::from_container(vec![0xFF00_u64; (1 << 20) - 1]);
+    assert!(!t_1.constant_time_eq(&t_3));
+}
@@ -12,8 +12,9 @@
 //! traits which allow to go back and forth between an unsigned integer representation and a
 //! floating point representation.
 
+use crate::backends::core::private::math::kernels::VectorKernels;
 use crate::backends::core::private::math::random::{
-    Gaussian, RandomGenerable, Uniform, UniformBinary, UniformTernary,
+    Gaussian, RandomGenerable, TUniform, Uniform, UniformBinary, UniformBounded, UniformTernary,
 };
 use concrete_commons::dispersion::LogStandardDev;
 use concrete_commons::numeric::{CastInto, FloatingPoint, Numeric, UnsignedInteger};
@@ -66,6 +67,11 @@ macro_rules! implement {
                 if carry >= zero_point_five {
                     fract += F::ONE;
                 };
+                // A negative input smaller than half the resolution rounds to the modulus, which
+                // must wrap to zero instead of saturating to the largest integer.
+                if fract >= F::TWO.powi(<Self as Numeric>::BITS as i32) {
+                    fract -= F::TWO.powi(<Self as Numeric>::BITS as i32);
+                };
                 return fract.cast_into();
             }
         }
@@ -76,17 +82,56 @@ implement!(u8);
 implement!(u16);
 implement!(u32);
 implement!(u64);
-implement!(u128);
+
+impl<F> IntoTorus<F> for u128
+where
+    F: FloatingPoint + CastInto<Self>,
+    Self: CastInto<F>,
+{
+    fn into_torus(self) -> F {
+        let self_f: F = self.cast_into();
+        self_f * (F::TWO.powi(-(<Self as Numeric>::BITS as i32)))
+    }
+}
+
+impl<F> FromTorus<F> for u128
+where
+    F: FloatingPoint + CastInto<Self>,
+    Self: CastInto<F>,
+{
+    fn from_torus(input: F) -> Self {
+        // The mantissa of a float is much shorter than 128 bits, so that the fractional part of a
+        // small negative input would round to one. The magnitude of the input is converted
+        // instead, and negated afterwards.
+        let magnitude = input.abs();
+        let mut fract = magnitude - F::floor(magnitude);
+        fract *= F::TWO.powi(<Self as Numeric>::BITS as i32);
+        let carry = fract - F::floor(fract);
+        let zero_point_five = F::ONE / F::TWO;
+        if carry >= zero_point_five {
+            fract += F::ONE;
+        };
+        let value: Self = fract.cast_into();
+        if input < F::ZERO {
+            value.wrapping_neg()
+        } else {
+            value
+        }
+    }
+}
 
 /// A marker trait for unsigned integer types that can be used in ciphertexts, keys etc.
 pub trait UnsignedTorus:
     UnsignedInteger
+    + VectorKernels
     + FromTorus<f64>
     + IntoTorus<f64>
     + RandomGenerable<Gaussian<f64>>
     + RandomGenerable<UniformBinary>
     + RandomGenerable<UniformTernary>
     + RandomGenerable<Uniform>
+    + RandomGenerable<UniformBounded>
+    + RandomGenerable<TUniform>
     + Display
     + Debug
 {
@@ -94,6 +139,10 @@ pub trait UnsignedTorus:
     const GAUSSIAN_KEY_LOG_STD: LogStandardDev;
 }
 
+impl UnsignedTorus for u16 {
+    const GAUSSIAN_KEY_LOG_STD: LogStandardDev = LogStandardDev(-14.32192809488736);
+}
+
 impl UnsignedTorus for u32 {
     const GAUSSIAN_KEY_LOG_STD: LogStandardDev = LogStandardDev(-30.32192809488736);
 }
@@ -101,3 +150,7 @@ impl UnsignedTorus for u32 {
 impl UnsignedTorus for u64 {
     const GAUSSIAN_KEY_LOG_STD: LogStandardDev = LogStandardDev(-62.32192809488736);
 }
+
+impl UnsignedTorus for u128 {
+    const GAUSSIAN_KEY_LOG_STD: LogStandardDev = LogStandardDev(-126.32192809488736);
+}
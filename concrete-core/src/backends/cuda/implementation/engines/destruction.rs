@@ -0,0 +1,31 @@
+use crate::backends::cuda::implementation::engines::CudaEngine;
+use crate::backends::cuda::implementation::entities::{
+    CudaGlweCiphertext64, CudaLweBootstrapKey64, CudaLweCiphertext64, CudaLweKeyswitchKey64,
+};
+use crate::specification::engines::{DestructionEngine, DestructionError};
+
+// The gpu memory of the entities is released when they are dropped.
+macro_rules! implement_destruction {
+    ($($entity: ident),*) => {
+        $(
+            impl DestructionEngine<$entity> for CudaEngine {
+                fn destroy(
+                    &mut self,
+                    entity: $entity,
+                ) -> Result<(), DestructionError<Self::EngineError>> {
+                    unsafe { self.destroy_unchecked(entity) };
+                    Ok(())
+                }
+
+                unsafe fn destroy_unchecked(&mut self, _entity: $entity) {}
+            }
+        )*
+    };
+}
+
+implement_destruction!(
+    CudaGlweCiphertext64,
+    CudaLweBootstrapKey64,
+    CudaLweCiphertext64,
+    CudaLweKeyswitchKey64
+);
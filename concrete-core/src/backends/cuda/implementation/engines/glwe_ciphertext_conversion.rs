@@ -0,0 +1,61 @@
+use crate::backends::core::entities::GlweCiphertext64;
+use crate::backends::core::private::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::cuda::implementation::engines::CudaEngine;
+use crate::backends::cuda::implementation::entities::CudaGlweCiphertext64;
+use crate::backends::cuda::private::CudaVec;
+use crate::specification::engines::{
+    GlweCiphertextConversionEngine, GlweCiphertextConversionError,
+};
+use crate::specification::entities::GlweCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextConversionEngine`] for [`CudaEngine`] that copies a core GLWE
+/// ciphertext into the memory of the gpu.
+impl GlweCiphertextConversionEngine<GlweCiphertext64, CudaGlweCiphertext64> for CudaEngine {
+    fn convert_glwe_ciphertext(
+        &mut self,
+        input: &GlweCiphertext64,
+    ) -> Result<CudaGlweCiphertext64, GlweCiphertextConversionError<Self::EngineError>> {
+        let data = CudaVec::from_cpu(input.0.as_tensor().as_slice(), self.gpu_index)
+            .map_err(GlweCiphertextConversionError::Engine)?;
+        Ok(CudaGlweCiphertext64 {
+            data,
+            glwe_dimension: input.glwe_dimension(),
+            polynomial_size: input.polynomial_size(),
+        })
+    }
+
+    unsafe fn convert_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &GlweCiphertext64,
+    ) -> CudaGlweCiphertext64 {
+        self.convert_glwe_ciphertext(input).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextConversionEngine`] for [`CudaEngine`] that copies a cuda GLWE
+/// ciphertext back into the memory of the cpu.
+impl GlweCiphertextConversionEngine<CudaGlweCiphertext64, GlweCiphertext64> for CudaEngine {
+    fn convert_glwe_ciphertext(
+        &mut self,
+        input: &CudaGlweCiphertext64,
+    ) -> Result<GlweCiphertext64, GlweCiphertextConversionError<Self::EngineError>> {
+        let data = input
+            .data
+            .to_cpu()
+            .map_err(GlweCiphertextConversionError::Engine)?;
+        Ok(GlweCiphertext64(ImplGlweCiphertext::from_container(
+            data,
+            input.polynomial_size,
+        )))
+    }
+
+    unsafe fn convert_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &CudaGlweCiphertext64,
+    ) -> GlweCiphertext64 {
+        self.convert_glwe_ciphertext(input).unwrap()
+    }
+}
@@ -0,0 +1,42 @@
+//! The table of the operations implemented by the [`CudaEngine`].
+
+use crate::backends::core::entities::*;
+use crate::backends::cuda::implementation::engines::CudaEngine;
+use crate::backends::cuda::implementation::entities::*;
+use crate::specification::engines::*;
+
+engine_implementations! {
+    CudaEngine {
+        DestructionEngine(CudaGlweCiphertext64),
+        DestructionEngine(CudaLweBootstrapKey64),
+        DestructionEngine(CudaLweCiphertext64),
+        DestructionEngine(CudaLweKeyswitchKey64),
+        GlweCiphertextConversionEngine(CudaGlweCiphertext64, GlweCiphertext64),
+        GlweCiphertextConversionEngine(GlweCiphertext64, CudaGlweCiphertext64),
+        LweBootstrapKeyConversionEngine(CudaLweBootstrapKey64, LweBootstrapKey64),
+        LweBootstrapKeyConversionEngine(LweBootstrapKey64, CudaLweBootstrapKey64),
+        LweBootstrapKeyUploadEngine(LweBootstrapKey64, CudaLweBootstrapKey64),
+        LweCiphertextConversionEngine(CudaLweCiphertext64, LweCiphertext64),
+        LweCiphertextConversionEngine(LweCiphertext64, CudaLweCiphertext64),
+        LweCiphertextDiscardingBootstrapEngine(
+            CudaLweBootstrapKey64,
+            CudaGlweCiphertext64,
+            CudaLweCiphertext64,
+            CudaLweCiphertext64,
+        ),
+        LweCiphertextDiscardingKeyswitchEngine(
+            CudaLweKeyswitchKey64,
+            CudaLweCiphertext64,
+            CudaLweCiphertext64,
+        ),
+        LweCiphertextVectorDiscardingBudgetedBootstrapEngine(
+            CudaLweBootstrapKey64,
+            GlweCiphertextVector64,
+            LweCiphertextVector64,
+            LweCiphertextVector64,
+        ),
+        LweKeyswitchKeyConversionEngine(LweKeyswitchKey64, CudaLweKeyswitchKey64),
+        LweKeyswitchKeyConversionEngine(CudaLweKeyswitchKey64, LweKeyswitchKey64),
+        LweKeyswitchKeyUploadEngine(LweKeyswitchKey64, CudaLweKeyswitchKey64),
+    }
+}
@@ -0,0 +1,76 @@
+use crate::backends::core::entities::LweBootstrapKey64;
+use crate::backends::core::private::crypto::bootstrap::StandardBootstrapKey as ImplStandardBootstrapKey;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::cuda::implementation::engines::{CudaEngine, CudaError};
+use crate::backends::cuda::implementation::entities::CudaLweBootstrapKey64;
+use crate::backends::cuda::private::{bootstrap_key_from_ntt, bootstrap_key_to_ntt};
+use crate::specification::engines::{
+    LweBootstrapKeyConversionEngine, LweBootstrapKeyConversionError,
+};
+use crate::specification::entities::LweBootstrapKeyEntity;
+use std::rc::Rc;
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CudaEngine`] that copies a core LWE
+/// bootstrap key into the memory of the gpu, where it is transformed to the domain of the bootstrap
+/// kernels.
+impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, CudaLweBootstrapKey64> for CudaEngine {
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweBootstrapKey64,
+    ) -> Result<CudaLweBootstrapKey64, LweBootstrapKeyConversionError<Self::EngineError>> {
+        if !input.polynomial_size().0.is_power_of_two() {
+            return Err(LweBootstrapKeyConversionError::Engine(
+                CudaError::UnsupportedPolynomialSize,
+            ));
+        }
+        let data = bootstrap_key_to_ntt(
+            input.0.as_tensor().as_slice(),
+            input.polynomial_size(),
+            self.gpu_index,
+        )
+        .map_err(LweBootstrapKeyConversionError::Engine)?;
+        Ok(CudaLweBootstrapKey64 {
+            data: Rc::new(data),
+            input_lwe_dimension: input.input_lwe_dimension(),
+            glwe_dimension: input.glwe_dimension(),
+            polynomial_size: input.polynomial_size(),
+            decomposition_level_count: input.decomposition_level_count(),
+            decomposition_base_log: input.decomposition_base_log(),
+        })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweBootstrapKey64,
+    ) -> CudaLweBootstrapKey64 {
+        self.convert_lwe_bootstrap_key(input).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CudaEngine`] that transforms a cuda
+/// LWE bootstrap key back to the standard domain, and copies it into the memory of the cpu.
+impl LweBootstrapKeyConversionEngine<CudaLweBootstrapKey64, LweBootstrapKey64> for CudaEngine {
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &CudaLweBootstrapKey64,
+    ) -> Result<LweBootstrapKey64, LweBootstrapKeyConversionError<Self::EngineError>> {
+        let data = bootstrap_key_from_ntt(&input.data, input.polynomial_size, self.gpu_index)
+            .map_err(LweBootstrapKeyConversionError::Engine)?;
+        Ok(LweBootstrapKey64(ImplStandardBootstrapKey::from_container(
+            data,
+            input.glwe_dimension.to_glwe_size(),
+            input.polynomial_size,
+            input.decomposition_level_count,
+            input.decomposition_base_log,
+        )))
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &CudaLweBootstrapKey64,
+    ) -> LweBootstrapKey64 {
+        self.convert_lwe_bootstrap_key(input).unwrap()
+    }
+}
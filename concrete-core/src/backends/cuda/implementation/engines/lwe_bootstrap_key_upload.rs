@@ -0,0 +1,96 @@
+use crate::backends::core::entities::LweBootstrapKey64;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::cuda::implementation::engines::{CudaEngine, CudaError};
+use crate::backends::cuda::implementation::entities::CudaLweBootstrapKey64;
+use crate::backends::cuda::private::{bootstrap_key_to_ntt, key_hash};
+use crate::specification::engines::{LweBootstrapKeyUploadEngine, LweBootstrapKeyUploadError};
+use crate::specification::entities::LweBootstrapKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyUploadEngine`] for [`CudaEngine`] that copies a core LWE
+/// bootstrap key into the memory of the gpu, where it is transformed to the domain of the bootstrap
+/// kernels, unless the same key is already in the key cache of the engine.
+impl LweBootstrapKeyUploadEngine<LweBootstrapKey64, CudaLweBootstrapKey64> for CudaEngine {
+    /// # Example:
+    /// ```no_run
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(512));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let noise = Variance(2_f64.powf(-60.));
+    ///
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let mut cuda_engine = CudaEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = core_engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     core_engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let d_bsk_1: CudaLweBootstrapKey64 = cuda_engine.upload_lwe_bootstrap_key(&bsk)?;
+    /// let allocated = cuda_engine.allocated_device_memory();
+    /// // The second upload reuses the copy of the key held by the cache.
+    /// let d_bsk_2: CudaLweBootstrapKey64 = cuda_engine.upload_lwe_bootstrap_key(&bsk)?;
+    /// assert_eq!(cuda_engine.allocated_device_memory(), allocated);
+    /// #
+    /// assert_eq!(d_bsk_2.polynomial_size(), poly_size);
+    ///
+    /// core_engine.destroy(lwe_sk)?;
+    /// core_engine.destroy(glwe_sk)?;
+    /// core_engine.destroy(bsk)?;
+    /// cuda_engine.destroy(d_bsk_1)?;
+    /// cuda_engine.destroy(d_bsk_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn upload_lwe_bootstrap_key(
+        &mut self,
+        input: &LweBootstrapKey64,
+    ) -> Result<CudaLweBootstrapKey64, LweBootstrapKeyUploadError<Self::EngineError>> {
+        let polynomial_size = input.polynomial_size();
+        if !polynomial_size.0.is_power_of_two() {
+            return Err(LweBootstrapKeyUploadError::Engine(
+                CudaError::UnsupportedPolynomialSize,
+            ));
+        }
+        let data = input.0.as_tensor().as_slice();
+        let hash = key_hash(
+            "LweBootstrapKey64",
+            &[
+                input.input_lwe_dimension().0,
+                input.glwe_dimension().0,
+                input.polynomial_size().0,
+                input.decomposition_level_count().0,
+                input.decomposition_base_log().0,
+            ],
+            data,
+        );
+        let data = self
+            .upload_key(hash, data, |data, gpu_index| {
+                bootstrap_key_to_ntt(data, polynomial_size, gpu_index)
+            })
+            .map_err(LweBootstrapKeyUploadError::Engine)?;
+        Ok(CudaLweBootstrapKey64 {
+            data,
+            input_lwe_dimension: input.input_lwe_dimension(),
+            glwe_dimension: input.glwe_dimension(),
+            polynomial_size: input.polynomial_size(),
+            decomposition_level_count: input.decomposition_level_count(),
+            decomposition_base_log: input.decomposition_base_log(),
+        })
+    }
+
+    unsafe fn upload_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweBootstrapKey64,
+    ) -> CudaLweBootstrapKey64 {
+        self.upload_lwe_bootstrap_key(input).unwrap()
+    }
+}
@@ -0,0 +1,55 @@
+use crate::backends::core::entities::LweCiphertext64;
+use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::cuda::implementation::engines::CudaEngine;
+use crate::backends::cuda::implementation::entities::CudaLweCiphertext64;
+use crate::backends::cuda::private::CudaVec;
+use crate::specification::engines::{LweCiphertextConversionEngine, LweCiphertextConversionError};
+use crate::specification::entities::LweCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextConversionEngine`] for [`CudaEngine`] that copies a core LWE
+/// ciphertext into the memory of the gpu.
+impl LweCiphertextConversionEngine<LweCiphertext64, CudaLweCiphertext64> for CudaEngine {
+    fn convert_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext64,
+    ) -> Result<CudaLweCiphertext64, LweCiphertextConversionError<Self::EngineError>> {
+        let data = CudaVec::from_cpu(input.0.as_tensor().as_slice(), self.gpu_index)
+            .map_err(LweCiphertextConversionError::Engine)?;
+        Ok(CudaLweCiphertext64 {
+            data,
+            lwe_dimension: input.lwe_dimension(),
+        })
+    }
+
+    unsafe fn convert_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext64,
+    ) -> CudaLweCiphertext64 {
+        self.convert_lwe_ciphertext(input).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextConversionEngine`] for [`CudaEngine`] that copies a cuda LWE
+/// ciphertext back into the memory of the cpu.
+impl LweCiphertextConversionEngine<CudaLweCiphertext64, LweCiphertext64> for CudaEngine {
+    fn convert_lwe_ciphertext(
+        &mut self,
+        input: &CudaLweCiphertext64,
+    ) -> Result<LweCiphertext64, LweCiphertextConversionError<Self::EngineError>> {
+        let data = input
+            .data
+            .to_cpu()
+            .map_err(LweCiphertextConversionError::Engine)?;
+        Ok(LweCiphertext64(ImplLweCiphertext::from_container(data)))
+    }
+
+    unsafe fn convert_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &CudaLweCiphertext64,
+    ) -> LweCiphertext64 {
+        self.convert_lwe_ciphertext(input).unwrap()
+    }
+}
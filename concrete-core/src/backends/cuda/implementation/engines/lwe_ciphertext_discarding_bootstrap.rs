@@ -0,0 +1,141 @@
+use crate::backends::cuda::implementation::engines::{CudaEngine, CudaError};
+use crate::backends::cuda::implementation::entities::{
+    CudaGlweCiphertext64, CudaLweBootstrapKey64, CudaLweCiphertext64,
+};
+use crate::backends::cuda::private::{check, cuda_bootstrap_lwe_ciphertext_vector_64};
+use crate::specification::engines::{
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
+};
+use std::os::raw::c_int;
+
+// Launches the bootstrap kernels on a vector made of a single ciphertext, and returns the code of
+// the cuda runtime.
+unsafe fn bootstrap(
+    gpu_index: u32,
+    output: &mut CudaLweCiphertext64,
+    input: &CudaLweCiphertext64,
+    acc: &CudaGlweCiphertext64,
+    bsk: &CudaLweBootstrapKey64,
+) -> c_int {
+    cuda_bootstrap_lwe_ciphertext_vector_64(
+        gpu_index,
+        output.data.as_mut_ptr(),
+        input.data.as_ptr(),
+        acc.data.as_ptr(),
+        bsk.data.as_ptr(),
+        1,
+        bsk.input_lwe_dimension.0 as u32,
+        bsk.glwe_dimension.0 as u32,
+        bsk.polynomial_size.0 as u32,
+        bsk.decomposition_base_log.0 as u32,
+        bsk.decomposition_level_count.0 as u32,
+    )
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CudaEngine`] that operates
+/// on 64 bits integers. The textbook programmable bootstrap of the reference backend is performed,
+/// with a gpu thread per coefficient of the accumulator: the products of polynomials of the
+/// external products are computed exactly, with a number theoretic transform.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        CudaLweBootstrapKey64,
+        CudaGlweCiphertext64,
+        CudaLweCiphertext64,
+        CudaLweCiphertext64,
+    > for CudaEngine
+{
+    /// # Example:
+    /// ```no_run
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(512));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let noise = Variance(2_f64.powf(-60.));
+    ///
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let mut cuda_engine = CudaEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = core_engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     core_engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// // The accumulator encodes the constant function 1 << 61.
+    /// let lut = core_engine.create_plaintext_vector(&vec![1_u64 << 61; poly_size.0])?;
+    /// let acc = core_engine.trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &lut)?;
+    /// let plaintext = core_engine.create_plaintext(&(1_u64 << 60))?;
+    /// let input = core_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let zero = core_engine.create_plaintext(&0_u64)?;
+    /// let output = core_engine
+    ///     .trivially_encrypt_lwe_ciphertext(LweDimension(poly_size.0).to_lwe_size(), &zero)?;
+    ///
+    /// let d_bsk: CudaLweBootstrapKey64 = cuda_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let d_acc: CudaGlweCiphertext64 = cuda_engine.convert_glwe_ciphertext(&acc)?;
+    /// let d_input: CudaLweCiphertext64 = cuda_engine.convert_lwe_ciphertext(&input)?;
+    /// let mut d_output: CudaLweCiphertext64 = cuda_engine.convert_lwe_ciphertext(&output)?;
+    ///
+    /// cuda_engine.discard_bootstrap_lwe_ciphertext(&mut d_output, &d_input, &d_acc, &d_bsk)?;
+    /// #
+    /// let result: LweCiphertext64 = cuda_engine.convert_lwe_ciphertext(&d_output)?;
+    /// let output_key = core_engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let decrypted_plaintext = core_engine.decrypt_lwe_ciphertext(&output_key, &result)?;
+    /// let decrypted = core_engine.retrieve_plaintext(&decrypted_plaintext)?;
+    /// assert_eq!((decrypted.wrapping_add(1 << 58)) >> 59, 4);
+    ///
+    /// core_engine.destroy(lwe_sk)?;
+    /// core_engine.destroy(output_key)?;
+    /// core_engine.destroy(bsk)?;
+    /// core_engine.destroy(lut)?;
+    /// core_engine.destroy(acc)?;
+    /// core_engine.destroy(plaintext)?;
+    /// core_engine.destroy(input)?;
+    /// core_engine.destroy(zero)?;
+    /// core_engine.destroy(output)?;
+    /// core_engine.destroy(decrypted_plaintext)?;
+    /// core_engine.destroy(result)?;
+    /// cuda_engine.destroy(d_bsk)?;
+    /// cuda_engine.destroy(d_acc)?;
+    /// cuda_engine.destroy(d_input)?;
+    /// cuda_engine.destroy(d_output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CudaLweCiphertext64,
+        input: &CudaLweCiphertext64,
+        acc: &CudaGlweCiphertext64,
+        bsk: &CudaLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        LweCiphertextDiscardingBootstrapError::perform_generic_checks(output, input, acc, bsk)?;
+        if !bsk.polynomial_size.0.is_power_of_two() {
+            return Err(LweCiphertextDiscardingBootstrapError::Engine(
+                CudaError::UnsupportedPolynomialSize,
+            ));
+        }
+        if !bsk.has_exact_products() {
+            return Err(LweCiphertextDiscardingBootstrapError::Engine(
+                CudaError::UnsupportedDecomposition,
+            ));
+        }
+        check(unsafe { bootstrap(self.gpu_index, output, input, acc, bsk) })
+            .map_err(LweCiphertextDiscardingBootstrapError::Engine)
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CudaLweCiphertext64,
+        input: &CudaLweCiphertext64,
+        acc: &CudaGlweCiphertext64,
+        bsk: &CudaLweBootstrapKey64,
+    ) {
+        check(bootstrap(self.gpu_index, output, input, acc, bsk)).unwrap();
+    }
+}
@@ -0,0 +1,106 @@
+use crate::backends::cuda::implementation::engines::CudaEngine;
+use crate::backends::cuda::implementation::entities::{CudaLweCiphertext64, CudaLweKeyswitchKey64};
+use crate::backends::cuda::private::{check, cuda_keyswitch_lwe_ciphertext_64};
+use crate::specification::engines::{
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
+};
+use std::os::raw::c_int;
+
+// Launches the keyswitch kernel, and returns the code of the cuda runtime.
+unsafe fn keyswitch(
+    gpu_index: u32,
+    output: &mut CudaLweCiphertext64,
+    input: &CudaLweCiphertext64,
+    ksk: &CudaLweKeyswitchKey64,
+) -> c_int {
+    cuda_keyswitch_lwe_ciphertext_64(
+        gpu_index,
+        output.data.as_mut_ptr(),
+        input.data.as_ptr(),
+        ksk.data.as_ptr(),
+        ksk.input_lwe_dimension.0 as u32,
+        ksk.output_lwe_dimension.0 as u32,
+        ksk.decomposition_base_log.0 as u32,
+        ksk.decomposition_level_count.0 as u32,
+    )
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`CudaEngine`] that operates
+/// on 64 bits integers. Every coefficient of the output is computed by a gpu thread.
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        CudaLweKeyswitchKey64,
+        CudaLweCiphertext64,
+        CudaLweCiphertext64,
+    > for CudaEngine
+{
+    /// # Example:
+    /// ```no_run
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(2), DecompositionBaseLog(8));
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let mut cuda_engine = CudaEngine::new()?;
+    /// let input_key: LweSecretKey64 = core_engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = core_engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let ksk: LweKeyswitchKey64 =
+    ///     core_engine.create_lwe_keyswitch_key(&input_key, &output_key, dec_lc, dec_bl, noise)?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    /// let ciphertext_1 = core_engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let ciphertext_2 = core_engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// let d_ksk: CudaLweKeyswitchKey64 = cuda_engine.convert_lwe_keyswitch_key(&ksk)?;
+    /// let d_ciphertext_1: CudaLweCiphertext64 = cuda_engine.convert_lwe_ciphertext(&ciphertext_1)?;
+    /// let mut d_ciphertext_2: CudaLweCiphertext64 =
+    ///     cuda_engine.convert_lwe_ciphertext(&ciphertext_2)?;
+    ///
+    /// cuda_engine.discard_keyswitch_lwe_ciphertext(&mut d_ciphertext_2, &d_ciphertext_1, &d_ksk)?;
+    /// #
+    /// let ciphertext_3: LweCiphertext64 = cuda_engine.convert_lwe_ciphertext(&d_ciphertext_2)?;
+    /// assert_eq!(ciphertext_3.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// core_engine.destroy(input_key)?;
+    /// core_engine.destroy(output_key)?;
+    /// core_engine.destroy(ksk)?;
+    /// core_engine.destroy(plaintext)?;
+    /// core_engine.destroy(ciphertext_1)?;
+    /// core_engine.destroy(ciphertext_2)?;
+    /// core_engine.destroy(ciphertext_3)?;
+    /// cuda_engine.destroy(d_ksk)?;
+    /// cuda_engine.destroy(d_ciphertext_1)?;
+    /// cuda_engine.destroy(d_ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut CudaLweCiphertext64,
+        input: &CudaLweCiphertext64,
+        ksk: &CudaLweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        check(unsafe { keyswitch(self.gpu_index, output, input, ksk) })
+            .map_err(LweCiphertextDiscardingKeyswitchError::Engine)
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CudaLweCiphertext64,
+        input: &CudaLweCiphertext64,
+        ksk: &CudaLweKeyswitchKey64,
+    ) {
+        check(keyswitch(self.gpu_index, output, input, ksk)).unwrap();
+    }
+}
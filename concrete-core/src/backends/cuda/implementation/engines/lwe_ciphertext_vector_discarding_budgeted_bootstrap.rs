@@ -0,0 +1,198 @@
+use concrete_commons::parameters::{LweCiphertextCount, MemoryBudget};
+
+use crate::backends::core::entities::{GlweCiphertextVector64, LweCiphertextVector64};
+use crate::backends::core::private::math::tensor::{
+    AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor,
+};
+use crate::backends::cuda::implementation::engines::{CudaEngine, CudaError};
+use crate::backends::cuda::implementation::entities::CudaLweBootstrapKey64;
+use crate::backends::cuda::private::{check, cuda_bootstrap_lwe_ciphertext_vector_64, CudaVec};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingBudgetedBootstrapEngine,
+    LweCiphertextVectorDiscardingBudgetedBootstrapError, VectorChunking,
+};
+use crate::specification::entities::LweCiphertextVectorEntity;
+
+// The number of limbs of 16 bits in which the polynomials of the key are split.
+const LIMB_COUNT: usize = 4;
+
+// Every ciphertext of a chunk holds its input, output and accumulator in the gpu memory, along
+// with the buffers of its blind rotation: the rotated accumulator, its decomposition, and the
+// products of the limbs of the key. The twiddle factors of the transforms are shared by the chunk.
+fn chunking(
+    count: LweCiphertextCount,
+    bsk: &CudaLweBootstrapKey64,
+    budget: MemoryBudget,
+) -> Option<VectorChunking> {
+    let glwe_length = (bsk.glwe_dimension.0 + 1) * bsk.polynomial_size.0;
+    let input_length = bsk.input_lwe_dimension.0 + 1;
+    let output_length = bsk.glwe_dimension.0 * bsk.polynomial_size.0 + 1;
+    let rotation_length = (1 + bsk.decomposition_level_count.0 + LIMB_COUNT) * glwe_length;
+    let word = std::mem::size_of::<u64>();
+    VectorChunking::within_budget(
+        count,
+        word * (input_length + output_length + glwe_length + rotation_length),
+        word * 2 * bsk.polynomial_size.0,
+        budget,
+    )
+}
+
+// Copies the chunks of ciphertexts and accumulators to the gpu, bootstraps every chunk with a
+// single launch of the kernels, and copies the results back, one chunk after the other.
+fn bootstrap_in_chunks(
+    gpu_index: u32,
+    output: &mut LweCiphertextVector64,
+    input: &LweCiphertextVector64,
+    acc: &GlweCiphertextVector64,
+    bsk: &CudaLweBootstrapKey64,
+    chunking: VectorChunking,
+) -> Result<(), CudaError> {
+    let input_length = input.0.lwe_size().0;
+    let output_length = output.0.lwe_size().0;
+    let acc_length = acc.0.glwe_size().0 * acc.0.polynomial_size().0;
+    let chunk_size = chunking.chunk_size.0;
+    let chunks = output
+        .0
+        .as_mut_tensor()
+        .as_mut_slice()
+        .chunks_mut(chunk_size * output_length)
+        .zip(
+            input
+                .0
+                .as_tensor()
+                .as_slice()
+                .chunks(chunk_size * input_length),
+        )
+        .zip(acc.0.as_tensor().as_slice().chunks(chunk_size * acc_length));
+    for ((output_chunk, input_chunk), acc_chunk) in chunks {
+        let d_input = CudaVec::from_cpu(input_chunk, gpu_index)?;
+        let d_acc = CudaVec::from_cpu(acc_chunk, gpu_index)?;
+        let mut d_output = CudaVec::<u64>::new(output_chunk.len(), gpu_index)?;
+        check(unsafe {
+            cuda_bootstrap_lwe_ciphertext_vector_64(
+                gpu_index,
+                d_output.as_mut_ptr(),
+                d_input.as_ptr(),
+                d_acc.as_ptr(),
+                bsk.data.as_ptr(),
+                (output_chunk.len() / output_length) as u32,
+                bsk.input_lwe_dimension.0 as u32,
+                bsk.glwe_dimension.0 as u32,
+                bsk.polynomial_size.0 as u32,
+                bsk.decomposition_base_log.0 as u32,
+                bsk.decomposition_level_count.0 as u32,
+            )
+        })?;
+        output_chunk.copy_from_slice(&d_output.to_cpu()?);
+    }
+    Ok(())
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingBudgetedBootstrapEngine`] for [`CudaEngine`]
+/// that operates on 64 bits integers. The ciphertext vectors are stored in the cpu memory, and
+/// only the chunks being bootstrapped are copied to the gpu, where every chunk is bootstrapped with a
+/// single launch of the kernels: the budget bounds the gpu memory used on top of the bootstrap key.
+impl
+    LweCiphertextVectorDiscardingBudgetedBootstrapEngine<
+        CudaLweBootstrapKey64,
+        GlweCiphertextVector64,
+        LweCiphertextVector64,
+        LweCiphertextVector64,
+    > for CudaEngine
+{
+    /// # Example:
+    /// ```no_run
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweCiphertextCount, GlweDimension,
+    ///     LweCiphertextCount, LweDimension, MemoryBudget, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(512));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let noise = Variance(2_f64.powf(-60.));
+    ///
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let mut cuda_engine = CudaEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = core_engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let output_sk: LweSecretKey64 = core_engine.create_lwe_secret_key(LweDimension(poly_size.0))?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     core_engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// // The accumulators encode the constant function 1 << 61.
+    /// let lut = core_engine.create_plaintext_vector(&vec![1_u64 << 61; 100 * poly_size.0])?;
+    /// let acc = core_engine.trivially_encrypt_glwe_ciphertext_vector(
+    ///     glwe_dim.to_glwe_size(),
+    ///     GlweCiphertextCount(100),
+    ///     &lut,
+    /// )?;
+    /// let plaintexts = core_engine.create_plaintext_vector(&vec![1_u64 << 60; 100])?;
+    /// let input = core_engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintexts, noise)?;
+    /// let mut output =
+    ///     core_engine.zero_encrypt_lwe_ciphertext_vector(&output_sk, noise, LweCiphertextCount(100))?;
+    ///
+    /// // The vectors stay in the cpu memory, and are copied to the gpu in chunks fitting the
+    /// // budget.
+    /// let d_bsk: CudaLweBootstrapKey64 = cuda_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let chunking = cuda_engine.discard_bootstrap_lwe_ciphertext_vector_within_budget(
+    ///     &mut output,
+    ///     &input,
+    ///     &acc,
+    ///     &d_bsk,
+    ///     MemoryBudget(256 * 1024),
+    /// )?;
+    /// assert!(chunking.chunk_count > 1);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(100));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector_within_budget(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        acc: &GlweCiphertextVector64,
+        bsk: &CudaLweBootstrapKey64,
+        budget: MemoryBudget,
+    ) -> Result<
+        VectorChunking,
+        LweCiphertextVectorDiscardingBudgetedBootstrapError<Self::EngineError>,
+    > {
+        LweCiphertextVectorDiscardingBudgetedBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        if !bsk.polynomial_size.0.is_power_of_two() {
+            return Err(LweCiphertextVectorDiscardingBudgetedBootstrapError::Engine(
+                CudaError::UnsupportedPolynomialSize,
+            ));
+        }
+        if !bsk.has_exact_products() {
+            return Err(LweCiphertextVectorDiscardingBudgetedBootstrapError::Engine(
+                CudaError::UnsupportedDecomposition,
+            ));
+        }
+        let chunking = chunking(input.lwe_ciphertext_count(), bsk, budget)
+            .ok_or(LweCiphertextVectorDiscardingBudgetedBootstrapError::MemoryBudgetTooSmall)?;
+        bootstrap_in_chunks(self.gpu_index, output, input, acc, bsk, chunking)
+            .map_err(LweCiphertextVectorDiscardingBudgetedBootstrapError::Engine)?;
+        Ok(chunking)
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_within_budget_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        acc: &GlweCiphertextVector64,
+        bsk: &CudaLweBootstrapKey64,
+        budget: MemoryBudget,
+    ) -> VectorChunking {
+        let chunking = chunking(input.lwe_ciphertext_count(), bsk, budget).unwrap();
+        bootstrap_in_chunks(self.gpu_index, output, input, acc, bsk, chunking).unwrap();
+        chunking
+    }
+}
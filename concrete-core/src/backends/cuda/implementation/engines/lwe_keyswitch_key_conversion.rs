@@ -0,0 +1,66 @@
+use crate::backends::core::entities::LweKeyswitchKey64;
+use crate::backends::core::private::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::cuda::implementation::engines::CudaEngine;
+use crate::backends::cuda::implementation::entities::CudaLweKeyswitchKey64;
+use crate::backends::cuda::private::CudaVec;
+use crate::specification::engines::{
+    LweKeyswitchKeyConversionEngine, LweKeyswitchKeyConversionError,
+};
+use crate::specification::entities::LweKeyswitchKeyEntity;
+use std::rc::Rc;
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConversionEngine`] for [`CudaEngine`] that copies a core LWE
+/// keyswitch key into the memory of the gpu.
+impl LweKeyswitchKeyConversionEngine<LweKeyswitchKey64, CudaLweKeyswitchKey64> for CudaEngine {
+    fn convert_lwe_keyswitch_key(
+        &mut self,
+        input: &LweKeyswitchKey64,
+    ) -> Result<CudaLweKeyswitchKey64, LweKeyswitchKeyConversionError<Self::EngineError>> {
+        let data = CudaVec::from_cpu(input.0.as_tensor().as_slice(), self.gpu_index)
+            .map_err(LweKeyswitchKeyConversionError::Engine)?;
+        Ok(CudaLweKeyswitchKey64 {
+            data: Rc::new(data),
+            input_lwe_dimension: input.input_lwe_dimension(),
+            output_lwe_dimension: input.output_lwe_dimension(),
+            decomposition_level_count: input.decomposition_level_count(),
+            decomposition_base_log: input.decomposition_base_log(),
+        })
+    }
+
+    unsafe fn convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &LweKeyswitchKey64,
+    ) -> CudaLweKeyswitchKey64 {
+        self.convert_lwe_keyswitch_key(input).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConversionEngine`] for [`CudaEngine`] that copies a cuda LWE
+/// keyswitch key back into the memory of the cpu.
+impl LweKeyswitchKeyConversionEngine<CudaLweKeyswitchKey64, LweKeyswitchKey64> for CudaEngine {
+    fn convert_lwe_keyswitch_key(
+        &mut self,
+        input: &CudaLweKeyswitchKey64,
+    ) -> Result<LweKeyswitchKey64, LweKeyswitchKeyConversionError<Self::EngineError>> {
+        let data = input
+            .data
+            .to_cpu()
+            .map_err(LweKeyswitchKeyConversionError::Engine)?;
+        Ok(LweKeyswitchKey64(ImplLweKeyswitchKey::from_container(
+            data,
+            input.decomposition_base_log,
+            input.decomposition_level_count,
+            input.output_lwe_dimension,
+        )))
+    }
+
+    unsafe fn convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &CudaLweKeyswitchKey64,
+    ) -> LweKeyswitchKey64 {
+        self.convert_lwe_keyswitch_key(input).unwrap()
+    }
+}
@@ -0,0 +1,87 @@
+use crate::backends::core::entities::LweKeyswitchKey64;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::cuda::implementation::engines::CudaEngine;
+use crate::backends::cuda::implementation::entities::CudaLweKeyswitchKey64;
+use crate::backends::cuda::private::{key_hash, CudaVec};
+use crate::specification::engines::{LweKeyswitchKeyUploadEngine, LweKeyswitchKeyUploadError};
+use crate::specification::entities::LweKeyswitchKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyUploadEngine`] for [`CudaEngine`] that copies a core LWE
+/// keyswitch key into the memory of the gpu, unless the same key is already in the key cache of
+/// the engine.
+impl LweKeyswitchKeyUploadEngine<LweKeyswitchKey64, CudaLweKeyswitchKey64> for CudaEngine {
+    /// # Example:
+    /// ```no_run
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(2), DecompositionBaseLog(8));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let mut cuda_engine = CudaEngine::new()?;
+    /// let input_key: LweSecretKey64 = core_engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = core_engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let ksk: LweKeyswitchKey64 =
+    ///     core_engine.create_lwe_keyswitch_key(&input_key, &output_key, dec_lc, dec_bl, noise)?;
+    ///
+    /// let d_ksk_1: CudaLweKeyswitchKey64 = cuda_engine.upload_lwe_keyswitch_key(&ksk)?;
+    /// // The second upload reuses the copy of the key held by the cache.
+    /// let d_ksk_2: CudaLweKeyswitchKey64 = cuda_engine.upload_lwe_keyswitch_key(&ksk)?;
+    /// assert_eq!(d_ksk_1, d_ksk_2);
+    /// #
+    /// // Evicting the key does not invalidate the uploaded entities.
+    /// cuda_engine.clear_key_cache();
+    /// assert_eq!(cuda_engine.key_cache_size(), 0);
+    /// assert_eq!(d_ksk_2.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// core_engine.destroy(input_key)?;
+    /// core_engine.destroy(output_key)?;
+    /// core_engine.destroy(ksk)?;
+    /// cuda_engine.destroy(d_ksk_1)?;
+    /// cuda_engine.destroy(d_ksk_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn upload_lwe_keyswitch_key(
+        &mut self,
+        input: &LweKeyswitchKey64,
+    ) -> Result<CudaLweKeyswitchKey64, LweKeyswitchKeyUploadError<Self::EngineError>> {
+        let data = input.0.as_tensor().as_slice();
+        let hash = key_hash(
+            "LweKeyswitchKey64",
+            &[
+                input.input_lwe_dimension().0,
+                input.output_lwe_dimension().0,
+                input.decomposition_level_count().0,
+                input.decomposition_base_log().0,
+            ],
+            data,
+        );
+        let data = self
+            .upload_key(hash, data, CudaVec::from_cpu)
+            .map_err(LweKeyswitchKeyUploadError::Engine)?;
+        Ok(CudaLweKeyswitchKey64 {
+            data,
+            input_lwe_dimension: input.input_lwe_dimension(),
+            output_lwe_dimension: input.output_lwe_dimension(),
+            decomposition_level_count: input.decomposition_level_count(),
+            decomposition_base_log: input.decomposition_base_log(),
+        })
+    }
+
+    unsafe fn upload_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &LweKeyswitchKey64,
+    ) -> CudaLweKeyswitchKey64 {
+        self.upload_lwe_keyswitch_key(input).unwrap()
+    }
+}
@@ -0,0 +1,161 @@
+//! A module containing the [engines](crate::specification::engines) exposed by the cuda backend.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::os::raw::c_int;
+use std::rc::Rc;
+
+use crate::backends::cuda::private::{
+    allocated_bytes, cuda_get_device_count, CudaVec, KeyCache, KeyHash,
+};
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+
+/// The error which can occur in the execution of FHE operations, due to the cuda implementation.
+#[derive(Debug)]
+pub enum CudaError {
+    DeviceNotFound,
+    UnsupportedPolynomialSize,
+    UnsupportedDecomposition,
+    Runtime(i32),
+}
+
+impl Display for CudaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CudaError::DeviceNotFound => {
+                write!(f, "No cuda compatible gpu was found.")
+            }
+            CudaError::UnsupportedPolynomialSize => {
+                write!(
+                    f,
+                    "The Cuda Backend only supports polynomial sizes which are powers of two."
+                )
+            }
+            CudaError::UnsupportedDecomposition => {
+                write!(
+                    f,
+                    "The Cuda Backend only supports bootstrap keys whose decomposition keeps the \
+                    external products exact."
+                )
+            }
+            CudaError::Runtime(code) => {
+                write!(f, "The cuda runtime failed with the error code {}.", code)
+            }
+        }
+    }
+}
+
+impl Error for CudaError {}
+
+/// The main engine exposed by the cuda backend.
+///
+/// The engine executes its operations on the first gpu of the machine, where its entities are
+/// stored. Every operation waits for the kernels it launched to complete before returning.
+///
+/// The keys uploaded with the `*UploadEngine` traits are kept in a cache of the engine, keyed by
+/// the hash of their content, so that uploading the same key again does not copy it to the gpu.
+/// The cache is unbounded by default: its capacity can be limited with
+/// [`CudaEngine::set_key_cache_capacity`], in which case the least recently used keys are evicted
+/// first. The cache is also emptied when the gpu runs out of memory during an upload.
+pub struct CudaEngine {
+    pub(crate) gpu_index: u32,
+    pub(crate) key_cache: KeyCache<Rc<CudaVec<u64>>>,
+}
+
+impl CudaEngine {
+    /// Returns the number of bytes of gpu memory currently allocated by the cuda backend, for all
+    /// the entities and cached keys of the process.
+    pub fn allocated_device_memory(&self) -> usize {
+        allocated_bytes()
+    }
+
+    /// Returns the number of bytes of gpu memory held by the keys of the cache.
+    ///
+    /// The memory of a cached key is shared with the entities returned by the upload, and is only
+    /// released once the key is evicted and these entities are dropped.
+    pub fn key_cache_size(&self) -> usize {
+        self.key_cache.size()
+    }
+
+    /// Returns the maximal number of bytes of gpu memory held by the keys of the cache.
+    pub fn key_cache_capacity(&self) -> usize {
+        self.key_cache.capacity()
+    }
+
+    /// Limits the number of bytes of gpu memory held by the keys of the cache, evicting the least
+    /// recently used keys if needed.
+    ///
+    /// A capacity of zero disables the cache.
+    pub fn set_key_cache_capacity(&mut self, capacity: usize) {
+        self.key_cache.set_capacity(capacity);
+    }
+
+    /// Evicts all the keys of the cache.
+    pub fn clear_key_cache(&mut self) {
+        self.key_cache.clear();
+    }
+
+    // Returns the gpu copy of the key identified by `hash`, made by `copy` from its content `data`.
+    // The key is only copied to the gpu if it is not already in the cache.
+    pub(crate) fn upload_key<Copy>(
+        &mut self,
+        hash: KeyHash,
+        data: &[u64],
+        copy: Copy,
+    ) -> Result<Rc<CudaVec<u64>>, CudaError>
+    where
+        Copy: Fn(&[u64], u32) -> Result<CudaVec<u64>, CudaError>,
+    {
+        if let Some(vec) = self.key_cache.get(&hash) {
+            return Ok(vec);
+        }
+        let vec = match copy(data, self.gpu_index) {
+            // The cached keys which are not used anymore are released before trying again.
+            Err(CudaError::Runtime(MEMORY_ALLOCATION)) => {
+                self.key_cache.clear();
+                copy(data, self.gpu_index)?
+            }
+            result => result?,
+        };
+        let vec = Rc::new(vec);
+        self.key_cache
+            .insert(hash, vec.clone(), vec.size_in_bytes());
+        Ok(vec)
+    }
+}
+
+// The `cudaErrorMemoryAllocation` error code of the cuda runtime.
+const MEMORY_ALLOCATION: i32 = 2;
+
+impl AbstractEngineSeal for CudaEngine {}
+
+impl AbstractEngine for CudaEngine {
+    type EngineError = CudaError;
+
+    fn new() -> Result<Self, Self::EngineError> {
+        let mut count: c_int = 0;
+        let code = unsafe { cuda_get_device_count(&mut count) };
+        if code != 0 || count == 0 {
+            return Err(CudaError::DeviceNotFound);
+        }
+        Ok(CudaEngine {
+            gpu_index: 0,
+            key_cache: KeyCache::new(usize::MAX),
+        })
+    }
+}
+
+mod destruction;
+mod glwe_ciphertext_conversion;
+mod implementations;
+mod lwe_bootstrap_key_conversion;
+mod lwe_bootstrap_key_upload;
+mod lwe_ciphertext_conversion;
+mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_vector_discarding_budgeted_bootstrap;
+mod lwe_keyswitch_key_conversion;
+mod lwe_keyswitch_key_upload;
+
+pub use implementations::IMPLEMENTATIONS;
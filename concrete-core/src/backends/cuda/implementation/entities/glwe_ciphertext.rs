@@ -0,0 +1,34 @@
+use crate::backends::cuda::private::CudaVec;
+use crate::specification::entities::markers::{
+    BinaryKeyDistribution, GlweCiphertextKind, NegacyclicRing,
+};
+use crate::specification::entities::{AbstractEntity, GlweCiphertextEntity};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A structure representing a GLWE ciphertext with 64 bits of precision, stored in the memory of a
+/// gpu.
+///
+/// The mask polynomials are followed by the body polynomial, as in the
+/// [`GlweCiphertext64`](crate::prelude::GlweCiphertext64) of the core backend. It is used as the
+/// accumulator of the bootstrap.
+#[derive(Debug, PartialEq)]
+pub struct CudaGlweCiphertext64 {
+    pub(crate) data: CudaVec<u64>,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+}
+impl AbstractEntity for CudaGlweCiphertext64 {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for CudaGlweCiphertext64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type RingStructure = NegacyclicRing;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+}
@@ -0,0 +1,70 @@
+use crate::backends::cuda::private::CudaVec;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweBootstrapKeyKind};
+use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use std::rc::Rc;
+
+/// A structure representing an LWE bootstrap key with 64 bits of precision, stored in the memory
+/// of a gpu.
+///
+/// The key holds the polynomials of the
+/// [`LweBootstrapKey64`](crate::prelude::LweBootstrapKey64) of the core backend, in the same order:
+/// a GGSW ciphertext per input key coefficient, made of a matrix per level from 1 to l, whose k + 1
+/// rows are GLWE ciphertexts. Every polynomial is split in four limbs of 16 bits, which are stored
+/// in the domain of the number theoretic transform used by the bootstrap kernels.
+///
+/// The memory of a key obtained with the
+/// [`LweBootstrapKeyUploadEngine`](crate::prelude::LweBootstrapKeyUploadEngine) is shared with the
+/// key cache of the engine, and is only released once both are dropped.
+#[derive(Debug, PartialEq)]
+pub struct CudaLweBootstrapKey64 {
+    pub(crate) data: Rc<CudaVec<u64>>,
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+}
+impl CudaLweBootstrapKey64 {
+    // Returns whether the products of the external products of a bootstrap with this key are
+    // computed exactly: their coefficients, which sum over the levels, the rows and the
+    // coefficients of a polynomial the products of a 16 bits limb and a digit of magnitude at most
+    // 2^(base_log - 1), must stay below half the 64 bits prime of the transform.
+    pub(crate) fn has_exact_products(&self) -> bool {
+        let terms = self.decomposition_level_count.0
+            * self.glwe_dimension.to_glwe_size().0
+            * self.polynomial_size.0;
+        let terms_bits = usize::BITS - (terms - 1).leading_zeros();
+        self.decomposition_base_log.0 + 15 + terms_bits as usize <= 62
+    }
+}
+
+impl AbstractEntity for CudaLweBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for CudaLweBootstrapKey64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+}
@@ -0,0 +1,25 @@
+use crate::backends::cuda::private::CudaVec;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweCiphertextKind};
+use crate::specification::entities::{AbstractEntity, LweCiphertextEntity};
+use concrete_commons::parameters::LweDimension;
+
+/// A structure representing an LWE ciphertext with 64 bits of precision, stored in the memory of a
+/// gpu.
+///
+/// The mask is followed by the body, as in the [`LweCiphertext64`](crate::prelude::LweCiphertext64)
+/// of the core backend.
+#[derive(Debug, PartialEq)]
+pub struct CudaLweCiphertext64 {
+    pub(crate) data: CudaVec<u64>,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CudaLweCiphertext64 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for CudaLweCiphertext64 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
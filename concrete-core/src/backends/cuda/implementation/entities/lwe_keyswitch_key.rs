@@ -0,0 +1,46 @@
+use crate::backends::cuda::private::CudaVec;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweKeyswitchKeyKind};
+use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyEntity};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+use std::rc::Rc;
+
+/// A structure representing an LWE keyswitch key with 64 bits of precision, stored in the memory
+/// of a gpu.
+///
+/// The key stores a block per input key coefficient, containing the LWE ciphertexts of the levels
+/// 1 to l, as in the [`LweKeyswitchKey64`](crate::prelude::LweKeyswitchKey64) of the core backend.
+///
+/// The memory of a key obtained with the
+/// [`LweKeyswitchKeyUploadEngine`](crate::prelude::LweKeyswitchKeyUploadEngine) is shared with the
+/// key cache of the engine, and is only released once both are dropped.
+#[derive(Debug, PartialEq)]
+pub struct CudaLweKeyswitchKey64 {
+    pub(crate) data: Rc<CudaVec<u64>>,
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) output_lwe_dimension: LweDimension,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+}
+impl AbstractEntity for CudaLweKeyswitchKey64 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for CudaLweKeyswitchKey64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.output_lwe_dimension
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
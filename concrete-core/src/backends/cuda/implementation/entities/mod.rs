@@ -0,0 +1,15 @@
+//! A module containing all the [entities](crate::specification::entities) exposed by the cuda
+//! backend.
+//!
+//! The cuda entities are stored in the memory of a gpu, with the same layout as the one of the
+//! `core` entities they are converted from. Their memory is released when they are destroyed.
+
+mod glwe_ciphertext;
+mod lwe_bootstrap_key;
+mod lwe_ciphertext;
+mod lwe_keyswitch_key;
+
+pub use glwe_ciphertext::*;
+pub use lwe_bootstrap_key::*;
+pub use lwe_ciphertext::*;
+pub use lwe_keyswitch_key::*;
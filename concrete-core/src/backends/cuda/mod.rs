@@ -0,0 +1,24 @@
+//! A module containing the cuda backend implementation.
+//!
+//! This module contains an implementation of the LWE keyswitch and bootstrap on an nvidia gpu. The
+//! entities of this backend are stored in the memory of the gpu, and are created by converting the
+//! entities of the `core` backend with the `*ConversionEngine` traits. Once the keys have been
+//! transferred, the ciphertexts can be keyswitched and bootstrapped without going back to the cpu.
+//!
+//! The keys can also be transferred with the `*UploadEngine` traits, which keep them in a cache of
+//! the [`CudaEngine`](engines::CudaEngine), keyed by the hash of their content: a service
+//! receiving the same keys with every request only copies them to the gpu once. The gpu memory
+//! used by the backend and by the cache can be queried on the engine, and the cache evicts its
+//! least recently used keys when its capacity is reached.
+//!
+//! The kernels, located in the `cuda` directory of the crate, are compiled with `nvcc` when the
+//! `backend_cuda` feature is activated. They follow the textbook algorithms of the `reference`
+//! backend, except for the polynomial products of the bootstrap: those are computed exactly with a
+//! number theoretic transform modulo the prime 2^64 - 2^32 + 1, the polynomials of the bootstrap
+//! keys being split in limbs of 16 bits, and the ciphertexts of a vector are bootstrapped together
+//! with batched kernel launches. Only the 64 bits precision is supported.
+
+mod implementation;
+mod private;
+
+pub use implementation::{engines, entities};
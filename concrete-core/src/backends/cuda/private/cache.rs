@@ -0,0 +1,195 @@
+//! The cache of the keys uploaded to the gpu.
+//!
+//! Keys are identified by a hash of their parameters and content, so that uploading a key which is
+//! already resident on the gpu does not copy it again. The cache holds the keys up to a capacity
+//! expressed in bytes of gpu memory: when an insertion would exceed it, the least recently used
+//! keys are evicted first.
+
+use std::collections::HashMap;
+
+/// The hash identifying a key of the cache.
+pub(crate) type KeyHash = [u8; 32];
+
+/// Computes the hash of a key, from a tag identifying the kind of key, its parameters and its
+/// content.
+pub(crate) fn key_hash(kind: &str, parameters: &[usize], data: &[u64]) -> KeyHash {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(kind.as_bytes());
+    for parameter in parameters {
+        hasher.update(&(*parameter as u64).to_le_bytes());
+    }
+    // The integers have no padding, so their memory can be read as bytes.
+    let bytes = unsafe {
+        std::slice::from_raw_parts(data.as_ptr() as *const u8, std::mem::size_of_val(data))
+    };
+    hasher.update(bytes);
+    *hasher.finalize().as_bytes()
+}
+
+struct CacheEntry<V> {
+    value: V,
+    size: usize,
+    last_use: u64,
+}
+
+/// A least recently used cache of values of type `V`, whose sizes are given at insertion.
+///
+/// The values are expected to be cheap to clone handles on the gpu memory: evicting a value only
+/// drops the handle of the cache, and the memory is released once the entities sharing it are
+/// dropped as well.
+pub(crate) struct KeyCache<V> {
+    entries: HashMap<KeyHash, CacheEntry<V>>,
+    capacity: usize,
+    size: usize,
+    clock: u64,
+}
+
+impl<V: Clone> KeyCache<V> {
+    /// Creates an empty cache holding up to `capacity` bytes.
+    pub(crate) fn new(capacity: usize) -> Self {
+        KeyCache {
+            entries: HashMap::new(),
+            capacity,
+            size: 0,
+            clock: 0,
+        }
+    }
+
+    /// Returns the maximal number of bytes held by the cache.
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of bytes currently held by the cache.
+    pub(crate) fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Changes the capacity of the cache, evicting the least recently used values if needed.
+    pub(crate) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.evict_until(capacity);
+    }
+
+    /// Returns the value stored for `hash`, if any, and marks it as the most recently used.
+    pub(crate) fn get(&mut self, hash: &KeyHash) -> Option<V> {
+        self.clock += 1;
+        let clock = self.clock;
+        self.entries.get_mut(hash).map(|entry| {
+            entry.last_use = clock;
+            entry.value.clone()
+        })
+    }
+
+    /// Stores a value of `size` bytes for `hash`, evicting the least recently used values to make
+    /// room for it.
+    ///
+    /// A value larger than the capacity of the cache is not stored.
+    pub(crate) fn insert(&mut self, hash: KeyHash, value: V, size: usize) {
+        self.remove(&hash);
+        if size > self.capacity {
+            return;
+        }
+        self.evict_until(self.capacity - size);
+        self.clock += 1;
+        self.size += size;
+        self.entries.insert(
+            hash,
+            CacheEntry {
+                value,
+                size,
+                last_use: self.clock,
+            },
+        );
+    }
+
+    /// Removes the value stored for `hash`, if any.
+    pub(crate) fn remove(&mut self, hash: &KeyHash) {
+        if let Some(entry) = self.entries.remove(hash) {
+            self.size -= entry.size;
+        }
+    }
+
+    /// Removes all the values of the cache.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+        self.size = 0;
+    }
+
+    // Evicts the least recently used values until the cache holds at most `size` bytes.
+    fn evict_until(&mut self, size: usize) {
+        while self.size > size {
+            let oldest = *self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_use)
+                .map(|(hash, _)| hash)
+                .unwrap();
+            self.remove(&oldest);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn hash(index: u8) -> KeyHash {
+        [index; 32]
+    }
+
+    #[test]
+    fn test_key_cache_returns_stored_values() {
+        let mut cache = KeyCache::new(100);
+        cache.insert(hash(1), 'a', 10);
+        cache.insert(hash(2), 'b', 20);
+        assert_eq!(cache.get(&hash(1)), Some('a'));
+        assert_eq!(cache.get(&hash(2)), Some('b'));
+        assert_eq!(cache.get(&hash(3)), None);
+        assert_eq!(cache.size(), 30);
+        cache.insert(hash(1), 'c', 5);
+        assert_eq!(cache.get(&hash(1)), Some('c'));
+        assert_eq!(cache.size(), 25);
+    }
+
+    #[test]
+    fn test_key_cache_evicts_least_recently_used() {
+        let mut cache = KeyCache::new(30);
+        cache.insert(hash(1), 'a', 10);
+        cache.insert(hash(2), 'b', 10);
+        cache.insert(hash(3), 'c', 10);
+        cache.get(&hash(1));
+        cache.insert(hash(4), 'd', 15);
+        assert_eq!(cache.get(&hash(1)), Some('a'));
+        assert_eq!(cache.get(&hash(2)), None);
+        assert_eq!(cache.get(&hash(3)), None);
+        assert_eq!(cache.get(&hash(4)), Some('d'));
+        assert_eq!(cache.size(), 25);
+    }
+
+    #[test]
+    fn test_key_cache_respects_capacity() {
+        let mut cache = KeyCache::new(30);
+        cache.insert(hash(1), 'a', 40);
+        assert_eq!(cache.get(&hash(1)), None);
+        assert_eq!(cache.size(), 0);
+        cache.insert(hash(2), 'b', 10);
+        cache.insert(hash(3), 'c', 20);
+        cache.set_capacity(25);
+        assert_eq!(cache.get(&hash(2)), None);
+        assert_eq!(cache.get(&hash(3)), Some('c'));
+        assert_eq!(cache.size(), 20);
+        cache.clear();
+        assert_eq!(cache.size(), 0);
+        assert_eq!(cache.get(&hash(3)), None);
+    }
+
+    #[test]
+    fn test_key_hash_depends_on_kind_parameters_and_content() {
+        let reference = key_hash("bsk", &[1, 2], &[3, 4]);
+        assert_eq!(reference, key_hash("bsk", &[1, 2], &[3, 4]));
+        assert_ne!(reference, key_hash("ksk", &[1, 2], &[3, 4]));
+        assert_ne!(reference, key_hash("bsk", &[2, 1], &[3, 4]));
+        assert_ne!(reference, key_hash("bsk", &[1, 2], &[3, 5]));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::backends::cuda::implementation::engines::CudaError;
+use crate::backends::cuda::private::ffi::{
+    cuda_free, cuda_malloc, cuda_memcpy_to_cpu, cuda_memcpy_to_gpu,
+};
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+use std::mem::{size_of, size_of_val};
+use std::os::raw::{c_int, c_void};
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// The number of bytes currently allocated by the buffers of the backend, on all the gpus.
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns the number of bytes of gpu memory currently allocated by the backend.
+pub(crate) fn allocated_bytes() -> usize {
+    ALLOCATED_BYTES.load(Ordering::Relaxed)
+}
+
+// Turns the code returned by a cuda function into a result.
+pub(crate) fn check(code: c_int) -> Result<(), CudaError> {
+    if code == 0 {
+        Ok(())
+    } else {
+        Err(CudaError::Runtime(code))
+    }
+}
+
+/// A buffer of `len` elements of type `T`, allocated in the memory of the gpu `gpu_index`.
+///
+/// The memory is released when the buffer is dropped.
+pub(crate) struct CudaVec<T: Copy> {
+    pointer: *mut c_void,
+    len: usize,
+    gpu_index: u32,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Copy> CudaVec<T> {
+    /// Allocates a buffer of `len` elements, whose content is left uninitialized.
+    pub(crate) fn new(len: usize, gpu_index: u32) -> Result<Self, CudaError> {
+        let mut pointer = ptr::null_mut();
+        check(unsafe { cuda_malloc(&mut pointer, (len * size_of::<T>()) as u64, gpu_index) })?;
+        ALLOCATED_BYTES.fetch_add(len * size_of::<T>(), Ordering::Relaxed);
+        Ok(CudaVec {
+            pointer,
+            len,
+            gpu_index,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Allocates a buffer, and copies the `data` of the cpu memory into it.
+    pub(crate) fn from_cpu(data: &[T], gpu_index: u32) -> Result<Self, CudaError> {
+        let vec = Self::new(data.len(), gpu_index)?;
+        check(unsafe {
+            cuda_memcpy_to_gpu(
+                vec.pointer,
+                data.as_ptr() as *const c_void,
+                size_of_val(data) as u64,
+                gpu_index,
+            )
+        })?;
+        Ok(vec)
+    }
+
+    /// Copies the content of the buffer back into the cpu memory.
+    pub(crate) fn to_cpu(&self) -> Result<Vec<T>, CudaError> {
+        let mut data = Vec::with_capacity(self.len);
+        check(unsafe {
+            cuda_memcpy_to_cpu(
+                data.as_mut_ptr() as *mut c_void,
+                self.pointer,
+                self.size_in_bytes() as u64,
+                self.gpu_index,
+            )
+        })?;
+        // The copy succeeded, so the `len` elements are initialized.
+        unsafe { data.set_len(self.len) };
+        Ok(data)
+    }
+
+    /// Returns the number of bytes of gpu memory used by the buffer.
+    pub(crate) fn size_in_bytes(&self) -> usize {
+        self.len * size_of::<T>()
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const T {
+        self.pointer as *const T
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut T {
+        self.pointer as *mut T
+    }
+}
+
+impl<T: Copy> Drop for CudaVec<T> {
+    fn drop(&mut self) {
+        // Errors can not be reported from here, and the memory is lost anyway.
+        unsafe { cuda_free(self.pointer, self.gpu_index) };
+        ALLOCATED_BYTES.fetch_sub(self.size_in_bytes(), Ordering::Relaxed);
+    }
+}
+
+// Two buffers are equal when they are the same allocation: comparing their contents would require
+// to copy them back to the cpu.
+impl<T: Copy> PartialEq for CudaVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.pointer == other.pointer && self.gpu_index == other.gpu_index
+    }
+}
+
+impl<T: Copy> Debug for CudaVec<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CudaVec")
+            .field("len", &self.len)
+            .field("gpu_index", &self.gpu_index)
+            .finish()
+    }
+}
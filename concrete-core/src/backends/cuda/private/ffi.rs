@@ -0,0 +1,167 @@
+//! The bindings to the functions defined in the `cuda` directory.
+//!
+//! All the functions return the cuda error code of the first failing call to the cuda runtime, or
+//! zero on success. The pointers passed to the keyswitch and bootstrap functions must point to the
+//! memory of the gpu `gpu_index`.
+//!
+//! When the kernels could not be compiled (see `build.rs`), the bindings are replaced by stubs
+//! which report that no gpu is available.
+
+#[cfg(cuda_kernels)]
+use std::os::raw::{c_int, c_void};
+
+#[cfg(not(cuda_kernels))]
+pub use stubs::*;
+
+#[cfg(cuda_kernels)]
+extern "C" {
+    pub fn cuda_get_device_count(count: *mut c_int) -> c_int;
+
+    pub fn cuda_malloc(pointer: *mut *mut c_void, size: u64, gpu_index: u32) -> c_int;
+
+    pub fn cuda_free(pointer: *mut c_void, gpu_index: u32) -> c_int;
+
+    pub fn cuda_memcpy_to_gpu(
+        destination: *mut c_void,
+        source: *const c_void,
+        size: u64,
+        gpu_index: u32,
+    ) -> c_int;
+
+    pub fn cuda_memcpy_to_cpu(
+        destination: *mut c_void,
+        source: *const c_void,
+        size: u64,
+        gpu_index: u32,
+    ) -> c_int;
+
+    pub fn cuda_keyswitch_lwe_ciphertext_64(
+        gpu_index: u32,
+        output: *mut u64,
+        input: *const u64,
+        ksk: *const u64,
+        input_lwe_dimension: u32,
+        output_lwe_dimension: u32,
+        base_log: u32,
+        level_count: u32,
+    ) -> c_int;
+
+    pub fn cuda_convert_lwe_bootstrap_key_to_ntt_64(
+        gpu_index: u32,
+        ntt_bsk: *mut u64,
+        bsk: *const u64,
+        polynomial_count: u32,
+        polynomial_size: u32,
+    ) -> c_int;
+
+    pub fn cuda_convert_lwe_bootstrap_key_from_ntt_64(
+        gpu_index: u32,
+        bsk: *mut u64,
+        ntt_bsk: *const u64,
+        polynomial_count: u32,
+        polynomial_size: u32,
+    ) -> c_int;
+
+    pub fn cuda_bootstrap_lwe_ciphertext_vector_64(
+        gpu_index: u32,
+        output: *mut u64,
+        input: *const u64,
+        lut: *const u64,
+        ntt_bsk: *const u64,
+        count: u32,
+        input_lwe_dimension: u32,
+        glwe_dimension: u32,
+        polynomial_size: u32,
+        base_log: u32,
+        level_count: u32,
+    ) -> c_int;
+}
+
+#[cfg(not(cuda_kernels))]
+#[allow(clippy::missing_safety_doc, clippy::too_many_arguments)]
+mod stubs {
+    use std::os::raw::{c_int, c_void};
+
+    // The `cudaErrorNoDevice` error code of the cuda runtime.
+    const NO_DEVICE: c_int = 100;
+
+    pub unsafe fn cuda_get_device_count(count: *mut c_int) -> c_int {
+        *count = 0;
+        0
+    }
+
+    pub unsafe fn cuda_malloc(_pointer: *mut *mut c_void, _size: u64, _gpu_index: u32) -> c_int {
+        NO_DEVICE
+    }
+
+    pub unsafe fn cuda_free(_pointer: *mut c_void, _gpu_index: u32) -> c_int {
+        NO_DEVICE
+    }
+
+    pub unsafe fn cuda_memcpy_to_gpu(
+        _destination: *mut c_void,
+        _source: *const c_void,
+        _size: u64,
+        _gpu_index: u32,
+    ) -> c_int {
+        NO_DEVICE
+    }
+
+    pub unsafe fn cuda_memcpy_to_cpu(
+        _destination: *mut c_void,
+        _source: *const c_void,
+        _size: u64,
+        _gpu_index: u32,
+    ) -> c_int {
+        NO_DEVICE
+    }
+
+    pub unsafe fn cuda_keyswitch_lwe_ciphertext_64(
+        _gpu_index: u32,
+        _output: *mut u64,
+        _input: *const u64,
+        _ksk: *const u64,
+        _input_lwe_dimension: u32,
+        _output_lwe_dimension: u32,
+        _base_log: u32,
+        _level_count: u32,
+    ) -> c_int {
+        NO_DEVICE
+    }
+
+    pub unsafe fn cuda_convert_lwe_bootstrap_key_to_ntt_64(
+        _gpu_index: u32,
+        _ntt_bsk: *mut u64,
+        _bsk: *const u64,
+        _polynomial_count: u32,
+        _polynomial_size: u32,
+    ) -> c_int {
+        NO_DEVICE
+    }
+
+    pub unsafe fn cuda_convert_lwe_bootstrap_key_from_ntt_64(
+        _gpu_index: u32,
+        _bsk: *mut u64,
+        _ntt_bsk: *const u64,
+        _polynomial_count: u32,
+        _polynomial_size: u32,
+    ) -> c_int {
+        NO_DEVICE
+    }
+
+    pub unsafe fn cuda_bootstrap_lwe_ciphertext_vector_64(
+        _gpu_index: u32,
+        _output: *mut u64,
+        _input: *const u64,
+        _lut: *const u64,
+        _ntt_bsk: *const u64,
+        _count: u32,
+        _input_lwe_dimension: u32,
+        _glwe_dimension: u32,
+        _polynomial_size: u32,
+        _base_log: u32,
+        _level_count: u32,
+    ) -> c_int {
+        NO_DEVICE
+    }
+}
@@ -0,0 +1,11 @@
+//! A module containing the bindings to the cuda kernels, and the management of the gpu memory.
+
+mod cache;
+mod device;
+mod ffi;
+mod ntt;
+
+pub(crate) use cache::{key_hash, KeyCache, KeyHash};
+pub(crate) use device::{allocated_bytes, check, CudaVec};
+pub(crate) use ffi::*;
+pub(crate) use ntt::{bootstrap_key_from_ntt, bootstrap_key_to_ntt};
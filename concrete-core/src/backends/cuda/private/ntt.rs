@@ -0,0 +1,51 @@
+use crate::backends::cuda::implementation::engines::CudaError;
+use crate::backends::cuda::private::ffi::{
+    cuda_convert_lwe_bootstrap_key_from_ntt_64, cuda_convert_lwe_bootstrap_key_to_ntt_64,
+};
+use crate::backends::cuda::private::{check, CudaVec};
+use concrete_commons::parameters::PolynomialSize;
+
+// The number of limbs of 16 bits in which the polynomials of the keys are split.
+const LIMB_COUNT: usize = 4;
+
+/// Copies the polynomials of a bootstrap key to the gpu, and returns the transforms of their limbs
+/// used by the bootstrap kernels.
+pub(crate) fn bootstrap_key_to_ntt(
+    data: &[u64],
+    polynomial_size: PolynomialSize,
+    gpu_index: u32,
+) -> Result<CudaVec<u64>, CudaError> {
+    let standard = CudaVec::from_cpu(data, gpu_index)?;
+    let mut ntt = CudaVec::new(data.len() * LIMB_COUNT, gpu_index)?;
+    check(unsafe {
+        cuda_convert_lwe_bootstrap_key_to_ntt_64(
+            gpu_index,
+            ntt.as_mut_ptr(),
+            standard.as_ptr(),
+            (data.len() / polynomial_size.0) as u32,
+            polynomial_size.0 as u32,
+        )
+    })?;
+    Ok(ntt)
+}
+
+/// Recovers the polynomials of a bootstrap key from the transforms of their limbs, and copies them
+/// back to the cpu.
+pub(crate) fn bootstrap_key_from_ntt(
+    ntt: &CudaVec<u64>,
+    polynomial_size: PolynomialSize,
+    gpu_index: u32,
+) -> Result<Vec<u64>, CudaError> {
+    let coefficient_count = ntt.size_in_bytes() / std::mem::size_of::<u64>() / LIMB_COUNT;
+    let mut standard = CudaVec::new(coefficient_count, gpu_index)?;
+    check(unsafe {
+        cuda_convert_lwe_bootstrap_key_from_ntt_64(
+            gpu_index,
+            standard.as_mut_ptr(),
+            ntt.as_ptr(),
+            (coefficient_count / polynomial_size.0) as u32,
+            polynomial_size.0 as u32,
+        )
+    })?;
+    standard.to_cpu()
+}
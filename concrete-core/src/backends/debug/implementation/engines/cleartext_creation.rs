@@ -0,0 +1,18 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{ReferenceCleartext, ReferenceScalar};
+use crate::specification::engines::{CleartextCreationEngine, CleartextCreationError};
+
+/// # Description:
+/// Implementation of [`CleartextCreationEngine`] for [`DebugEngine`].
+impl<T: ReferenceScalar> CleartextCreationEngine<T, ReferenceCleartext<T>> for DebugEngine {
+    fn create_cleartext(
+        &mut self,
+        input: &T,
+    ) -> Result<ReferenceCleartext<T>, CleartextCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_cleartext_unchecked(input) })
+    }
+
+    unsafe fn create_cleartext_unchecked(&mut self, input: &T) -> ReferenceCleartext<T> {
+        self.reference.create_cleartext_unchecked(input)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{ReferenceCleartext, ReferenceScalar};
+use crate::specification::engines::{CleartextRetrievalEngine, CleartextRetrievalError};
+
+/// # Description:
+/// Implementation of [`CleartextRetrievalEngine`] for [`DebugEngine`].
+impl<T: ReferenceScalar> CleartextRetrievalEngine<ReferenceCleartext<T>, T> for DebugEngine {
+    fn retrieve_cleartext(
+        &mut self,
+        cleartext: &ReferenceCleartext<T>,
+    ) -> Result<T, CleartextRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_cleartext_unchecked(cleartext) })
+    }
+
+    unsafe fn retrieve_cleartext_unchecked(&mut self, cleartext: &ReferenceCleartext<T>) -> T {
+        self.reference.retrieve_cleartext_unchecked(cleartext)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{
+    ReferenceCleartext, ReferenceGlweCiphertext, ReferenceGlweSecretKey, ReferenceLweBootstrapKey,
+    ReferenceLweCiphertext, ReferenceLweKeyswitchKey, ReferenceLweSecretKey, ReferencePlaintext,
+    ReferencePlaintextVector, ReferenceScalar,
+};
+use crate::specification::engines::{DestructionEngine, DestructionError};
+
+macro_rules! implement_destruction {
+    ($($entity: ident),*) => {
+        $(
+            impl<T: ReferenceScalar> DestructionEngine<$entity<T>> for DebugEngine {
+                fn destroy(
+                    &mut self,
+                    entity: $entity<T>,
+                ) -> Result<(), DestructionError<Self::EngineError>> {
+                    unsafe { self.destroy_unchecked(entity) };
+                    Ok(())
+                }
+
+                unsafe fn destroy_unchecked(&mut self, _entity: $entity<T>) {}
+            }
+        )*
+    };
+}
+
+implement_destruction!(
+    ReferenceCleartext,
+    ReferenceGlweCiphertext,
+    ReferenceGlweSecretKey,
+    ReferenceLweBootstrapKey,
+    ReferenceLweCiphertext,
+    ReferenceLweKeyswitchKey,
+    ReferenceLweSecretKey,
+    ReferencePlaintext,
+    ReferencePlaintextVector
+);
@@ -0,0 +1,35 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{
+    ReferenceGlweCiphertext, ReferenceGlweSecretKey, ReferencePlaintextVector, ReferenceScalar,
+};
+use crate::specification::engines::{
+    GlweCiphertextDecryptionEngine, GlweCiphertextDecryptionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDecryptionEngine`] for [`DebugEngine`]. Since the debug
+/// ciphertexts hold no noise, the output plaintexts are the exact encrypted ones.
+impl<T: ReferenceScalar>
+    GlweCiphertextDecryptionEngine<
+        ReferenceGlweSecretKey<T>,
+        ReferenceGlweCiphertext<T>,
+        ReferencePlaintextVector<T>,
+    > for DebugEngine
+{
+    fn decrypt_glwe_ciphertext(
+        &mut self,
+        key: &ReferenceGlweSecretKey<T>,
+        input: &ReferenceGlweCiphertext<T>,
+    ) -> Result<ReferencePlaintextVector<T>, GlweCiphertextDecryptionError<Self::EngineError>> {
+        GlweCiphertextDecryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_glwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &ReferenceGlweSecretKey<T>,
+        input: &ReferenceGlweCiphertext<T>,
+    ) -> ReferencePlaintextVector<T> {
+        self.reference.decrypt_glwe_ciphertext_unchecked(key, input)
+    }
+}
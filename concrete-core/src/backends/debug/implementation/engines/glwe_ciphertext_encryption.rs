@@ -0,0 +1,39 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{
+    ReferenceGlweCiphertext, ReferenceGlweSecretKey, ReferencePlaintextVector, ReferenceScalar,
+};
+use crate::specification::engines::{
+    GlweCiphertextEncryptionEngine, GlweCiphertextEncryptionError,
+};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`DebugEngine`]. The `noise`
+/// parameter is ignored: the body is computed as $B = \sum\_i A\_i S\_i + M$.
+impl<T: ReferenceScalar>
+    GlweCiphertextEncryptionEngine<
+        ReferenceGlweSecretKey<T>,
+        ReferencePlaintextVector<T>,
+        ReferenceGlweCiphertext<T>,
+    > for DebugEngine
+{
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &ReferenceGlweSecretKey<T>,
+        input: &ReferencePlaintextVector<T>,
+        noise: Variance,
+    ) -> Result<ReferenceGlweCiphertext<T>, GlweCiphertextEncryptionError<Self::EngineError>> {
+        GlweCiphertextEncryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &ReferenceGlweSecretKey<T>,
+        input: &ReferencePlaintextVector<T>,
+        _noise: Variance,
+    ) -> ReferenceGlweCiphertext<T> {
+        self.reference
+            .encrypt_glwe_ciphertext_unchecked(key, input, Variance(0.))
+    }
+}
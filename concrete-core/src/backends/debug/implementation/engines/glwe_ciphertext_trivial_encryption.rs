@@ -0,0 +1,33 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{
+    ReferenceGlweCiphertext, ReferencePlaintextVector, ReferenceScalar,
+};
+use crate::specification::engines::{
+    GlweCiphertextTrivialEncryptionEngine, GlweCiphertextTrivialEncryptionError,
+};
+use concrete_commons::parameters::GlweSize;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextTrivialEncryptionEngine`] for [`DebugEngine`].
+impl<T: ReferenceScalar>
+    GlweCiphertextTrivialEncryptionEngine<ReferencePlaintextVector<T>, ReferenceGlweCiphertext<T>>
+    for DebugEngine
+{
+    fn trivially_encrypt_glwe_ciphertext(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &ReferencePlaintextVector<T>,
+    ) -> Result<ReferenceGlweCiphertext<T>, GlweCiphertextTrivialEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.trivially_encrypt_glwe_ciphertext_unchecked(glwe_size, input) })
+    }
+
+    unsafe fn trivially_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &ReferencePlaintextVector<T>,
+    ) -> ReferenceGlweCiphertext<T> {
+        self.reference
+            .trivially_encrypt_glwe_ciphertext_unchecked(glwe_size, input)
+    }
+}
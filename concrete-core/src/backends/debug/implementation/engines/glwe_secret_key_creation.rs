@@ -0,0 +1,27 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{ReferenceGlweSecretKey, ReferenceScalar};
+use crate::specification::engines::{GlweSecretKeyCreationEngine, GlweSecretKeyCreationError};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyCreationEngine`] for [`DebugEngine`]. The keys are sampled
+/// from the seeded generator of the engine.
+impl<T: ReferenceScalar> GlweSecretKeyCreationEngine<ReferenceGlweSecretKey<T>> for DebugEngine {
+    fn create_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<ReferenceGlweSecretKey<T>, GlweSecretKeyCreationError<Self::EngineError>> {
+        GlweSecretKeyCreationError::perform_generic_checks(glwe_dimension, polynomial_size)?;
+        Ok(unsafe { self.create_glwe_secret_key_unchecked(glwe_dimension, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> ReferenceGlweSecretKey<T> {
+        self.reference
+            .create_glwe_secret_key_unchecked(glwe_dimension, polynomial_size)
+    }
+}
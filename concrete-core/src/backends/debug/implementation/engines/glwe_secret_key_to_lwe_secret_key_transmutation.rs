@@ -0,0 +1,32 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{
+    ReferenceGlweSecretKey, ReferenceLweSecretKey, ReferenceScalar,
+};
+use crate::specification::engines::{
+    GlweToLweSecretKeyTransmutationEngine, GlweToLweSecretKeyTransmutationEngineError,
+};
+
+/// # Description:
+/// Implementation of [`GlweToLweSecretKeyTransmutationEngine`] for [`DebugEngine`].
+impl<T: ReferenceScalar>
+    GlweToLweSecretKeyTransmutationEngine<ReferenceGlweSecretKey<T>, ReferenceLweSecretKey<T>>
+    for DebugEngine
+{
+    fn transmute_glwe_secret_key_to_lwe_secret_key(
+        &mut self,
+        glwe_secret_key: ReferenceGlweSecretKey<T>,
+    ) -> Result<
+        ReferenceLweSecretKey<T>,
+        GlweToLweSecretKeyTransmutationEngineError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.transmute_glwe_secret_key_to_lwe_secret_key_unchecked(glwe_secret_key) })
+    }
+
+    unsafe fn transmute_glwe_secret_key_to_lwe_secret_key_unchecked(
+        &mut self,
+        glwe_secret_key: ReferenceGlweSecretKey<T>,
+    ) -> ReferenceLweSecretKey<T> {
+        self.reference
+            .transmute_glwe_secret_key_to_lwe_secret_key_unchecked(glwe_secret_key)
+    }
+}
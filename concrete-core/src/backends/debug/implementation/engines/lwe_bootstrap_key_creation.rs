@@ -0,0 +1,69 @@
+use crate::backends::debug::implementation::engines::{DebugEngine, DebugError};
+use crate::backends::reference::entities::{
+    ReferenceGlweSecretKey, ReferenceLweBootstrapKey, ReferenceLweSecretKey, ReferenceScalar,
+};
+use crate::specification::engines::{LweBootstrapKeyCreationEngine, LweBootstrapKeyCreationError};
+use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+impl From<DebugError> for LweBootstrapKeyCreationError<DebugError> {
+    fn from(err: DebugError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationEngine`] for [`DebugEngine`]. The `noise` parameter
+/// is ignored: the GGSW ciphertexts of the key hold no noise.
+impl<T: ReferenceScalar>
+    LweBootstrapKeyCreationEngine<
+        ReferenceLweSecretKey<T>,
+        ReferenceGlweSecretKey<T>,
+        ReferenceLweBootstrapKey<T>,
+    > for DebugEngine
+{
+    fn create_lwe_bootstrap_key(
+        &mut self,
+        input_key: &ReferenceLweSecretKey<T>,
+        output_key: &ReferenceGlweSecretKey<T>,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<ReferenceLweBootstrapKey<T>, LweBootstrapKeyCreationError<Self::EngineError>> {
+        LweBootstrapKeyCreationError::perform_generic_checks(
+            decomposition_base_log,
+            decomposition_level_count,
+            T::BITS,
+        )?;
+        if !output_key.polynomial_size().0.is_power_of_two() {
+            return Err(DebugError::UnsupportedPolynomialSize.into());
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &ReferenceLweSecretKey<T>,
+        output_key: &ReferenceGlweSecretKey<T>,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        _noise: Variance,
+    ) -> ReferenceLweBootstrapKey<T> {
+        self.reference.create_lwe_bootstrap_key_unchecked(
+            input_key,
+            output_key,
+            decomposition_base_log,
+            decomposition_level_count,
+            Variance(0.),
+        )
+    }
+}
@@ -0,0 +1,35 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{
+    ReferenceCleartext, ReferenceLweCiphertext, ReferenceScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextCleartextFusingMultiplicationEngine,
+    LweCiphertextCleartextFusingMultiplicationError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for [`DebugEngine`].
+impl<T: ReferenceScalar>
+    LweCiphertextCleartextFusingMultiplicationEngine<
+        ReferenceLweCiphertext<T>,
+        ReferenceCleartext<T>,
+    > for DebugEngine
+{
+    fn fuse_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input: &ReferenceCleartext<T>,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        unsafe { self.fuse_mul_lwe_ciphertext_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input: &ReferenceCleartext<T>,
+    ) {
+        self.reference
+            .fuse_mul_lwe_ciphertext_cleartext_unchecked(output, input);
+    }
+}
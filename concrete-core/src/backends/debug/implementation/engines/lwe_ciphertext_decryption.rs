@@ -0,0 +1,32 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{
+    ReferenceLweCiphertext, ReferenceLweSecretKey, ReferencePlaintext, ReferenceScalar,
+};
+use crate::specification::engines::{LweCiphertextDecryptionEngine, LweCiphertextDecryptionError};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`DebugEngine`]. Since the debug
+/// ciphertexts hold no noise, the output plaintext is the exact encrypted one.
+impl<T: ReferenceScalar>
+    LweCiphertextDecryptionEngine<
+        ReferenceLweSecretKey<T>,
+        ReferenceLweCiphertext<T>,
+        ReferencePlaintext<T>,
+    > for DebugEngine
+{
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &ReferenceLweSecretKey<T>,
+        input: &ReferenceLweCiphertext<T>,
+    ) -> Result<ReferencePlaintext<T>, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &ReferenceLweSecretKey<T>,
+        input: &ReferenceLweCiphertext<T>,
+    ) -> ReferencePlaintext<T> {
+        self.reference.decrypt_lwe_ciphertext_unchecked(key, input)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{ReferenceLweCiphertext, ReferenceScalar};
+use crate::specification::engines::{
+    LweCiphertextDiscardingAdditionEngine, LweCiphertextDiscardingAdditionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingAdditionEngine`] for [`DebugEngine`].
+impl<T: ReferenceScalar>
+    LweCiphertextDiscardingAdditionEngine<ReferenceLweCiphertext<T>, ReferenceLweCiphertext<T>>
+    for DebugEngine
+{
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input_1: &ReferenceLweCiphertext<T>,
+        input_2: &ReferenceLweCiphertext<T>,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        LweCiphertextDiscardingAdditionError::perform_generic_checks(output, input_1, input_2)?;
+        unsafe { self.discard_add_lwe_ciphertext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input_1: &ReferenceLweCiphertext<T>,
+        input_2: &ReferenceLweCiphertext<T>,
+    ) {
+        self.reference
+            .discard_add_lwe_ciphertext_unchecked(output, input_1, input_2);
+    }
+}
@@ -0,0 +1,44 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{
+    ReferenceGlweCiphertext, ReferenceLweBootstrapKey, ReferenceLweCiphertext, ReferenceScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`DebugEngine`]. The
+/// textbook bootstrap of the reference backend is performed. Even though the bootstrap key holds
+/// no noise, the modulus switching of the input rounds its phase to a multiple of $1 / 2N$, and
+/// the gadget decomposition rounds the accumulator to the precision of the key.
+impl<T: ReferenceScalar>
+    LweCiphertextDiscardingBootstrapEngine<
+        ReferenceLweBootstrapKey<T>,
+        ReferenceGlweCiphertext<T>,
+        ReferenceLweCiphertext<T>,
+        ReferenceLweCiphertext<T>,
+    > for DebugEngine
+{
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input: &ReferenceLweCiphertext<T>,
+        acc: &ReferenceGlweCiphertext<T>,
+        bsk: &ReferenceLweBootstrapKey<T>,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        LweCiphertextDiscardingBootstrapError::perform_generic_checks(output, input, acc, bsk)?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input: &ReferenceLweCiphertext<T>,
+        acc: &ReferenceGlweCiphertext<T>,
+        bsk: &ReferenceLweBootstrapKey<T>,
+    ) {
+        self.reference
+            .discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk);
+    }
+}
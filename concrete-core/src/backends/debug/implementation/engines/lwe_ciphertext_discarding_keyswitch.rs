@@ -0,0 +1,40 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{
+    ReferenceLweCiphertext, ReferenceLweKeyswitchKey, ReferenceScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`DebugEngine`]. Even though
+/// the keyswitch key holds no noise, the gadget decomposition rounds the mask of the input to the
+/// precision of the key.
+impl<T: ReferenceScalar>
+    LweCiphertextDiscardingKeyswitchEngine<
+        ReferenceLweKeyswitchKey<T>,
+        ReferenceLweCiphertext<T>,
+        ReferenceLweCiphertext<T>,
+    > for DebugEngine
+{
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input: &ReferenceLweCiphertext<T>,
+        ksk: &ReferenceLweKeyswitchKey<T>,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input: &ReferenceLweCiphertext<T>,
+        ksk: &ReferenceLweKeyswitchKey<T>,
+    ) {
+        self.reference
+            .discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk);
+    }
+}
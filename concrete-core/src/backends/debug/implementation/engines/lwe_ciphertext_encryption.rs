@@ -0,0 +1,37 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{
+    ReferenceLweCiphertext, ReferenceLweSecretKey, ReferencePlaintext, ReferenceScalar,
+};
+use crate::specification::engines::{LweCiphertextEncryptionEngine, LweCiphertextEncryptionError};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`DebugEngine`]. The mask is sampled
+/// from the seeded generator of the engine, and the `noise` parameter is ignored: the body is
+/// computed as $b = \sum\_i a\_i s\_i + m$.
+impl<T: ReferenceScalar>
+    LweCiphertextEncryptionEngine<
+        ReferenceLweSecretKey<T>,
+        ReferencePlaintext<T>,
+        ReferenceLweCiphertext<T>,
+    > for DebugEngine
+{
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &ReferenceLweSecretKey<T>,
+        input: &ReferencePlaintext<T>,
+        noise: Variance,
+    ) -> Result<ReferenceLweCiphertext<T>, LweCiphertextEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &ReferenceLweSecretKey<T>,
+        input: &ReferencePlaintext<T>,
+        _noise: Variance,
+    ) -> ReferenceLweCiphertext<T> {
+        self.reference
+            .encrypt_lwe_ciphertext_unchecked(key, input, Variance(0.))
+    }
+}
@@ -0,0 +1,31 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{ReferenceLweCiphertext, ReferenceScalar};
+use crate::specification::engines::{
+    LweCiphertextFusingAdditionEngine, LweCiphertextFusingAdditionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`DebugEngine`].
+impl<T: ReferenceScalar>
+    LweCiphertextFusingAdditionEngine<ReferenceLweCiphertext<T>, ReferenceLweCiphertext<T>>
+    for DebugEngine
+{
+    fn fuse_add_lwe_ciphertext(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input: &ReferenceLweCiphertext<T>,
+    ) -> Result<(), LweCiphertextFusingAdditionError<Self::EngineError>> {
+        LweCiphertextFusingAdditionError::perform_generic_checks(output, input)?;
+        unsafe { self.fuse_add_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input: &ReferenceLweCiphertext<T>,
+    ) {
+        self.reference
+            .fuse_add_lwe_ciphertext_unchecked(output, input);
+    }
+}
@@ -0,0 +1,33 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{
+    ReferenceLweCiphertext, ReferencePlaintext, ReferenceScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextTrivialEncryptionEngine, LweCiphertextTrivialEncryptionError,
+};
+use concrete_commons::parameters::LweSize;
+
+/// # Description:
+/// Implementation of [`LweCiphertextTrivialEncryptionEngine`] for [`DebugEngine`].
+impl<T: ReferenceScalar>
+    LweCiphertextTrivialEncryptionEngine<ReferencePlaintext<T>, ReferenceLweCiphertext<T>>
+    for DebugEngine
+{
+    fn trivially_encrypt_lwe_ciphertext(
+        &mut self,
+        lwe_size: LweSize,
+        input: &ReferencePlaintext<T>,
+    ) -> Result<ReferenceLweCiphertext<T>, LweCiphertextTrivialEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.trivially_encrypt_lwe_ciphertext_unchecked(lwe_size, input) })
+    }
+
+    unsafe fn trivially_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        lwe_size: LweSize,
+        input: &ReferencePlaintext<T>,
+    ) -> ReferenceLweCiphertext<T> {
+        self.reference
+            .trivially_encrypt_lwe_ciphertext_unchecked(lwe_size, input)
+    }
+}
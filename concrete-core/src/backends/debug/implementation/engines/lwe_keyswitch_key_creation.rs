@@ -0,0 +1,59 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{
+    ReferenceLweKeyswitchKey, ReferenceLweSecretKey, ReferenceScalar,
+};
+use crate::specification::engines::{LweKeyswitchKeyCreationEngine, LweKeyswitchKeyCreationError};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`DebugEngine`]. The `noise`
+/// parameter is ignored: the LWE ciphertexts of the key hold no noise.
+impl<T: ReferenceScalar>
+    LweKeyswitchKeyCreationEngine<
+        ReferenceLweSecretKey<T>,
+        ReferenceLweSecretKey<T>,
+        ReferenceLweKeyswitchKey<T>,
+    > for DebugEngine
+{
+    fn create_lwe_keyswitch_key(
+        &mut self,
+        input_key: &ReferenceLweSecretKey<T>,
+        output_key: &ReferenceLweSecretKey<T>,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<ReferenceLweKeyswitchKey<T>, LweKeyswitchKeyCreationError<Self::EngineError>> {
+        LweKeyswitchKeyCreationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            T::BITS,
+        )?;
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &ReferenceLweSecretKey<T>,
+        output_key: &ReferenceLweSecretKey<T>,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        _noise: Variance,
+    ) -> ReferenceLweKeyswitchKey<T> {
+        self.reference.create_lwe_keyswitch_key_unchecked(
+            input_key,
+            output_key,
+            decomposition_level_count,
+            decomposition_base_log,
+            Variance(0.),
+        )
+    }
+}
@@ -0,0 +1,25 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{ReferenceLweSecretKey, ReferenceScalar};
+use crate::specification::engines::{LweSecretKeyCreationEngine, LweSecretKeyCreationError};
+use concrete_commons::parameters::LweDimension;
+
+/// # Description:
+/// Implementation of [`LweSecretKeyCreationEngine`] for [`DebugEngine`]. The keys are sampled
+/// from the seeded generator of the engine.
+impl<T: ReferenceScalar> LweSecretKeyCreationEngine<ReferenceLweSecretKey<T>> for DebugEngine {
+    fn create_lwe_secret_key(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<ReferenceLweSecretKey<T>, LweSecretKeyCreationError<Self::EngineError>> {
+        LweSecretKeyCreationError::perform_generic_checks(lwe_dimension)?;
+        Ok(unsafe { self.create_lwe_secret_key_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn create_lwe_secret_key_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> ReferenceLweSecretKey<T> {
+        self.reference
+            .create_lwe_secret_key_unchecked(lwe_dimension)
+    }
+}
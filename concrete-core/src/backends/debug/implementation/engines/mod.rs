@@ -0,0 +1,119 @@
+//! A module containing the [engines](crate::specification::engines) exposed by the debug backend.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::backends::core::private::math::random::RandomGenerator;
+use crate::backends::debug::{DEBUG_SEED, INSECURE_DEBUG_VARIABLE};
+use crate::backends::reference::engines::ReferenceEngine;
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+
+/// The error which can occur in the execution of FHE operations, due to the debug implementation.
+#[derive(Debug)]
+pub enum DebugError {
+    InsecureDebugDisabled,
+    UnsupportedPolynomialSize,
+}
+
+impl Display for DebugError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DebugError::InsecureDebugDisabled => {
+                write!(
+                    f,
+                    "The Debug Backend is insecure, and can only be used when the {} environment \
+                    variable is set to 1.",
+                    INSECURE_DEBUG_VARIABLE
+                )
+            }
+            DebugError::UnsupportedPolynomialSize => {
+                write!(
+                    f,
+                    "The Debug Backend only supports polynomial sizes which are powers of two."
+                )
+            }
+        }
+    }
+}
+
+impl Error for DebugError {}
+
+/// The main engine exposed by the debug backend.
+///
+/// The engine wraps a [`ReferenceEngine`] whose random generator is seeded with [`DEBUG_SEED`],
+/// and executes its operators with a null noise. Two debug engines executing the same sequence of
+/// operations produce the exact same entities.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::backends::debug::{DEBUG_LWE_DIMENSION, INSECURE_DEBUG_VARIABLE};
+/// use concrete_core::prelude::*;
+/// # use std::error::Error;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// if std::env::var(INSECURE_DEBUG_VARIABLE).map_or(true, |value| value != "1") {
+///     assert!(DebugEngine::new().is_err());
+///     return Ok(());
+/// }
+///
+/// let mut engine_1 = DebugEngine::new()?;
+/// let mut engine_2 = DebugEngine::new()?;
+/// let key_1: ReferenceLweSecretKey32 = engine_1.create_lwe_secret_key(DEBUG_LWE_DIMENSION)?;
+/// let key_2: ReferenceLweSecretKey32 = engine_2.create_lwe_secret_key(DEBUG_LWE_DIMENSION)?;
+/// assert_eq!(key_1, key_2);
+///
+/// engine_1.destroy(key_1)?;
+/// engine_2.destroy(key_2)?;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub struct DebugEngine {
+    pub(crate) reference: ReferenceEngine,
+}
+
+impl AbstractEngineSeal for DebugEngine {}
+
+impl AbstractEngine for DebugEngine {
+    type EngineError = DebugError;
+
+    fn new() -> Result<Self, Self::EngineError> {
+        match std::env::var(INSECURE_DEBUG_VARIABLE) {
+            Ok(value) if value == "1" => Ok(DebugEngine {
+                reference: ReferenceEngine {
+                    generator: RandomGenerator::new(Some(DEBUG_SEED)),
+                },
+            }),
+            _ => Err(DebugError::InsecureDebugDisabled),
+        }
+    }
+}
+
+mod cleartext_creation;
+mod cleartext_retrieval;
+mod destruction;
+mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_trivial_encryption;
+mod glwe_secret_key_creation;
+mod glwe_secret_key_to_lwe_secret_key_transmutation;
+mod implementations;
+mod lwe_bootstrap_key_creation;
+mod lwe_ciphertext_cleartext_fusing_multiplication;
+mod lwe_ciphertext_decryption;
+mod lwe_ciphertext_discarding_addition;
+mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_encryption;
+mod lwe_ciphertext_fusing_addition;
+mod lwe_ciphertext_trivial_encryption;
+mod lwe_keyswitch_key_creation;
+mod lwe_secret_key_creation;
+mod plaintext_creation;
+mod plaintext_retrieval;
+mod plaintext_vector_creation;
+mod plaintext_vector_retrieval;
+
+pub use implementations::IMPLEMENTATIONS;
@@ -0,0 +1,18 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{ReferencePlaintext, ReferenceScalar};
+use crate::specification::engines::{PlaintextCreationEngine, PlaintextCreationError};
+
+/// # Description:
+/// Implementation of [`PlaintextCreationEngine`] for [`DebugEngine`].
+impl<T: ReferenceScalar> PlaintextCreationEngine<T, ReferencePlaintext<T>> for DebugEngine {
+    fn create_plaintext(
+        &mut self,
+        input: &T,
+    ) -> Result<ReferencePlaintext<T>, PlaintextCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_plaintext_unchecked(input) })
+    }
+
+    unsafe fn create_plaintext_unchecked(&mut self, input: &T) -> ReferencePlaintext<T> {
+        self.reference.create_plaintext_unchecked(input)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{ReferencePlaintext, ReferenceScalar};
+use crate::specification::engines::{PlaintextRetrievalEngine, PlaintextRetrievalError};
+
+/// # Description:
+/// Implementation of [`PlaintextRetrievalEngine`] for [`DebugEngine`].
+impl<T: ReferenceScalar> PlaintextRetrievalEngine<ReferencePlaintext<T>, T> for DebugEngine {
+    fn retrieve_plaintext(
+        &mut self,
+        plaintext: &ReferencePlaintext<T>,
+    ) -> Result<T, PlaintextRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_plaintext_unchecked(plaintext) })
+    }
+
+    unsafe fn retrieve_plaintext_unchecked(&mut self, plaintext: &ReferencePlaintext<T>) -> T {
+        self.reference.retrieve_plaintext_unchecked(plaintext)
+    }
+}
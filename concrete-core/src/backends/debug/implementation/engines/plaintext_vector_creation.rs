@@ -0,0 +1,24 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{ReferencePlaintextVector, ReferenceScalar};
+use crate::specification::engines::{PlaintextVectorCreationEngine, PlaintextVectorCreationError};
+
+/// # Description:
+/// Implementation of [`PlaintextVectorCreationEngine`] for [`DebugEngine`].
+impl<T: ReferenceScalar> PlaintextVectorCreationEngine<T, ReferencePlaintextVector<T>>
+    for DebugEngine
+{
+    fn create_plaintext_vector(
+        &mut self,
+        input: &[T],
+    ) -> Result<ReferencePlaintextVector<T>, PlaintextVectorCreationError<Self::EngineError>> {
+        PlaintextVectorCreationError::perform_generic_checks(input)?;
+        Ok(unsafe { self.create_plaintext_vector_unchecked(input) })
+    }
+
+    unsafe fn create_plaintext_vector_unchecked(
+        &mut self,
+        input: &[T],
+    ) -> ReferencePlaintextVector<T> {
+        self.reference.create_plaintext_vector_unchecked(input)
+    }
+}
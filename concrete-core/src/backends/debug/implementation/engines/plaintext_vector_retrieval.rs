@@ -0,0 +1,26 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::reference::entities::{ReferencePlaintextVector, ReferenceScalar};
+use crate::specification::engines::{
+    PlaintextVectorRetrievalEngine, PlaintextVectorRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`PlaintextVectorRetrievalEngine`] for [`DebugEngine`].
+impl<T: ReferenceScalar> PlaintextVectorRetrievalEngine<ReferencePlaintextVector<T>, T>
+    for DebugEngine
+{
+    fn retrieve_plaintext_vector(
+        &mut self,
+        plaintext: &ReferencePlaintextVector<T>,
+    ) -> Result<Vec<T>, PlaintextVectorRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_plaintext_vector_unchecked(plaintext) })
+    }
+
+    unsafe fn retrieve_plaintext_vector_unchecked(
+        &mut self,
+        plaintext: &ReferencePlaintextVector<T>,
+    ) -> Vec<T> {
+        self.reference
+            .retrieve_plaintext_vector_unchecked(plaintext)
+    }
+}
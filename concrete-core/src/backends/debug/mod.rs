@@ -0,0 +1,98 @@
+//! A module containing the insecure debug backend implementation.
+//!
+//! This module contains an implementation of the concrete specification meant to run full
+//! pipelines in the unit tests of applications. It operates on the entities of the `reference`
+//! backend, and executes the same textbook algorithms, with two differences:
+//!
+//! + The random generator of the engine is seeded with a constant, so that the keys and the
+//! masks of the ciphertexts are the same from one execution to the other.
+//! + The noise parameters are ignored, and no noise is ever added to the ciphertexts, so that the
+//! arithmetic on the encrypted messages is exact, up to the roundings of the keyswitch and of the
+//! bootstrap.
+//!
+//! With the tiny parameters below, every operator executes in microseconds. The ciphertexts
+//! produced by this backend offer no security at all. To make sure it is never enabled by
+//! mistake, the backend is only compiled with the `insecure_debug` feature, and the
+//! [`DebugEngine`](engines::DebugEngine) can only be created when the `CONCRETE_INSECURE_DEBUG`
+//! environment variable is set to `1`.
+//!
+//! # Example
+//!
+//! ```
+//! use concrete_commons::dispersion::Variance;
+//! use concrete_core::backends::debug::{
+//!     DEBUG_DECOMPOSITION_BASE_LOG, DEBUG_DECOMPOSITION_LEVEL_COUNT, DEBUG_LWE_DIMENSION,
+//!     INSECURE_DEBUG_VARIABLE,
+//! };
+//! use concrete_core::prelude::*;
+//! # use std::error::Error;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! std::env::set_var(INSECURE_DEBUG_VARIABLE, "1");
+//! // The noise is ignored by the debug engine.
+//! let noise = Variance(0.);
+//!
+//! let mut engine = DebugEngine::new()?;
+//! let key_1: ReferenceLweSecretKey64 = engine.create_lwe_secret_key(DEBUG_LWE_DIMENSION)?;
+//! let key_2: ReferenceLweSecretKey64 = engine.create_lwe_secret_key(DEBUG_LWE_DIMENSION)?;
+//! let ksk = engine.create_lwe_keyswitch_key(
+//!     &key_1,
+//!     &key_2,
+//!     DEBUG_DECOMPOSITION_LEVEL_COUNT,
+//!     DEBUG_DECOMPOSITION_BASE_LOG,
+//!     noise,
+//! )?;
+//! let plaintext = engine.create_plaintext(&(3_u64 << 60))?;
+//! let mut ciphertext = engine.encrypt_lwe_ciphertext(&key_1, &plaintext, noise)?;
+//! let cleartext = engine.create_cleartext(&2_u64)?;
+//! engine.fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &cleartext)?;
+//! let mut switched = engine.encrypt_lwe_ciphertext(&key_2, &plaintext, noise)?;
+//! engine.discard_keyswitch_lwe_ciphertext(&mut switched, &ciphertext, &ksk)?;
+//!
+//! // The multiplication is exact, and the keyswitch only rounds the mask.
+//! let decrypted = engine.decrypt_lwe_ciphertext(&key_1, &ciphertext)?;
+//! assert_eq!(engine.retrieve_plaintext(&decrypted)?, 6_u64 << 60);
+//! let decrypted = engine.decrypt_lwe_ciphertext(&key_2, &switched)?;
+//! let decrypted = engine.retrieve_plaintext(&decrypted)?;
+//! assert_eq!(decrypted.wrapping_add(1 << 59) >> 60, 6);
+//!
+//! engine.destroy(key_1)?;
+//! engine.destroy(key_2)?;
+//! engine.destroy(ksk)?;
+//! engine.destroy(plaintext)?;
+//! engine.destroy(ciphertext)?;
+//! engine.destroy(cleartext)?;
+//! engine.destroy(switched)?;
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+mod implementation;
+
+pub use implementation::engines;
+
+/// The environment variable which must be set to `1` for a [`DebugEngine`](engines::DebugEngine)
+/// to be created.
+pub const INSECURE_DEBUG_VARIABLE: &str = "CONCRETE_INSECURE_DEBUG";
+
+/// The seed of the random generator of the debug engines.
+pub const DEBUG_SEED: u128 = 0;
+
+/// The LWE dimension of the debug parameters.
+pub const DEBUG_LWE_DIMENSION: LweDimension = LweDimension(10);
+
+/// The GLWE dimension of the debug parameters.
+pub const DEBUG_GLWE_DIMENSION: GlweDimension = GlweDimension(1);
+
+/// The polynomial size of the debug parameters.
+pub const DEBUG_POLYNOMIAL_SIZE: PolynomialSize = PolynomialSize(32);
+
+/// The logarithm of the decomposition base of the debug parameters.
+pub const DEBUG_DECOMPOSITION_BASE_LOG: DecompositionBaseLog = DecompositionBaseLog(8);
+
+/// The number of decomposition levels of the debug parameters.
+pub const DEBUG_DECOMPOSITION_LEVEL_COUNT: DecompositionLevelCount = DecompositionLevelCount(3);
@@ -1,9 +1,43 @@
 //! A module containing various backends implementing the `concrete` FHE scheme.
 //!
 //! This module contains all the backends implementing the concrete specification. As of now we
-//! support the following backend:
+//! support the following backends:
 //!
 //! + `core` : A single threaded CPU backend geared towards x86_64 architectures.
+//! + `simulation` : A backend simulating the operations on plaintexts, while tracking the noise
+//! variances predicted by `concrete-npe`. It offers no security, and is meant for testing.
+//! + `reference` : A backend implementing the operations with textbook algorithms, favoring
+//! readability over performance. It is meant for audits, and as a ground truth for testing.
+//! + `cuda` : A backend executing the keyswitch and the bootstrap on an nvidia gpu, with entities
+//! stored in the gpu memory.
+//! + `multithread` : A backend executing the batched operations on the entities of the `core`
+//! backend, splitting the ciphertexts across the threads of a rayon pool.
+//! + `debug` : A deterministic and noiseless variant of the `reference` backend, with tiny insecure
+//! parameters, meant to run full pipelines in unit tests. It is only compiled with the
+//! `insecure_debug` feature.
+#![cfg_attr(
+    not(feature = "insecure_debug"),
+    doc = "\n\
+Without the `insecure_debug` feature, the debug engine can not be used:\n\
+```compile_fail\n\
+use concrete_core::backends::debug::engines::DebugEngine;\n\
+```"
+)]
 
 #[cfg(feature = "backend_core")]
 pub mod core;
+
+#[cfg(feature = "backend_simulation")]
+pub mod simulation;
+
+#[cfg(feature = "backend_reference")]
+pub mod reference;
+
+#[cfg(feature = "backend_cuda")]
+pub mod cuda;
+
+#[cfg(feature = "backend_multithread")]
+pub mod multithread;
+
+#[cfg(feature = "insecure_debug")]
+pub mod debug;
@@ -0,0 +1,34 @@
+//! The table of the operations implemented by the [`MultithreadEngine`].
+
+use crate::backends::core::entities::*;
+use crate::backends::multithread::implementation::engines::MultithreadEngine;
+use crate::specification::engines::*;
+
+engine_implementations! {
+    MultithreadEngine {
+        LweCiphertextVectorDiscardingBootstrapEngine(
+            FourierLweBootstrapKey32,
+            GlweCiphertextVector32,
+            LweCiphertextVector32,
+            LweCiphertextVector32,
+        ),
+        LweCiphertextVectorDiscardingBootstrapEngine(
+            FourierLweBootstrapKey64,
+            GlweCiphertextVector64,
+            LweCiphertextVector64,
+            LweCiphertextVector64,
+        ),
+        LweCiphertextVectorDiscardingBudgetedBootstrapEngine(
+            FourierLweBootstrapKey32,
+            GlweCiphertextVector32,
+            LweCiphertextVector32,
+            LweCiphertextVector32,
+        ),
+        LweCiphertextVectorDiscardingBudgetedBootstrapEngine(
+            FourierLweBootstrapKey64,
+            GlweCiphertextVector64,
+            LweCiphertextVector64,
+            LweCiphertextVector64,
+        ),
+    }
+}
@@ -0,0 +1,317 @@
+use concrete_commons::parameters::{LweCiphertextCount, MemoryBudget};
+
+use crate::backends::core::entities::{
+    FourierLweBootstrapKey32, FourierLweBootstrapKey64, GlweCiphertextVector32,
+    GlweCiphertextVector64, LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::backends::core::private::crypto::bootstrap::{
+    par_bootstrap_lwe_list_in_chunks, FourierBuffers,
+};
+use crate::backends::core::private::math::fft::ALLOWED_POLY_SIZE;
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::backends::multithread::implementation::engines::{MultithreadEngine, MultithreadError};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingBudgetedBootstrapEngine,
+    LweCiphertextVectorDiscardingBudgetedBootstrapError, VectorChunking,
+};
+use crate::specification::entities::{GlweCiphertextVectorEntity, LweCiphertextVectorEntity};
+
+// Every ciphertext bootstrapped at the same time holds its own fourier buffers, whose size bounds
+// the number of ciphertexts of a chunk.
+fn chunking<Scalar: UnsignedTorus>(
+    count: LweCiphertextCount,
+    acc: &impl GlweCiphertextVectorEntity,
+    budget: MemoryBudget,
+) -> Option<VectorChunking> {
+    let buffers_bytes = FourierBuffers::<Scalar>::memory_footprint(
+        acc.polynomial_size(),
+        acc.glwe_dimension().to_glwe_size(),
+    );
+    VectorChunking::within_budget(count, buffers_bytes, 0, budget)
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingBudgetedBootstrapEngine`] for
+/// [`MultithreadEngine`] that operates on 32 bits integers.
+impl
+    LweCiphertextVectorDiscardingBudgetedBootstrapEngine<
+        FourierLweBootstrapKey32,
+        GlweCiphertextVector32,
+        LweCiphertextVector32,
+        LweCiphertextVector32,
+    > for MultithreadEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweCiphertextCount, GlweDimension,
+    ///     LweCiphertextCount, LweDimension, MemoryBudget, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap of every ciphertext
+    /// let lut = vec![8_u32 << 20; poly_size.0 * 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // The keys and the ciphertexts are handled by the core engine.
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = core_engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = core_engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey32 =
+    ///     core_engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: LweSecretKey32 = core_engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&input)?;
+    /// let lut_vector = core_engine.create_plaintext_vector(&lut)?;
+    /// let acc = core_engine.trivially_encrypt_glwe_ciphertext_vector(
+    ///     glwe_dim.to_glwe_size(),
+    ///     GlweCiphertextCount(3),
+    ///     &lut_vector,
+    /// )?;
+    /// let input = core_engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector, noise)?;
+    /// let mut output = core_engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_sk_output,
+    ///     noise,
+    ///     LweCiphertextCount(3),
+    /// )?;
+    ///
+    /// // The budget only fits the buffers of a single bootstrap at a time.
+    /// let mut engine = MultithreadEngine::new()?;
+    /// let budget = MemoryBudget(128 * 1024);
+    /// let chunking = engine.discard_bootstrap_lwe_ciphertext_vector_within_budget(
+    ///     &mut output,
+    ///     &input,
+    ///     &acc,
+    ///     &bsk,
+    ///     budget,
+    /// )?;
+    /// #
+    /// assert_eq!(chunking.chunk_size, LweCiphertextCount(1));
+    /// assert_eq!(chunking.chunk_count, 3);
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// core_engine.destroy(lwe_sk)?;
+    /// core_engine.destroy(glwe_sk)?;
+    /// core_engine.destroy(bsk)?;
+    /// core_engine.destroy(lwe_sk_output)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// core_engine.destroy(lut_vector)?;
+    /// core_engine.destroy(acc)?;
+    /// core_engine.destroy(input)?;
+    /// core_engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector_within_budget(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        acc: &GlweCiphertextVector32,
+        bsk: &FourierLweBootstrapKey32,
+        budget: MemoryBudget,
+    ) -> Result<
+        VectorChunking,
+        LweCiphertextVectorDiscardingBudgetedBootstrapError<Self::EngineError>,
+    > {
+        if !ALLOWED_POLY_SIZE.contains(&acc.polynomial_size().0) {
+            return Err(LweCiphertextVectorDiscardingBudgetedBootstrapError::Engine(
+                MultithreadError::UnsupportedPolynomialSize,
+            ));
+        }
+        LweCiphertextVectorDiscardingBudgetedBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        let chunking = chunking::<u32>(input.lwe_ciphertext_count(), acc, budget)
+            .ok_or(LweCiphertextVectorDiscardingBudgetedBootstrapError::MemoryBudgetTooSmall)?;
+        self.pool.install(|| {
+            par_bootstrap_lwe_list_in_chunks(
+                &mut output.0,
+                &input.0,
+                &acc.0,
+                &bsk.0,
+                self.pool.current_num_threads(),
+                chunking.chunk_size.0,
+            )
+        });
+        Ok(chunking)
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_within_budget_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        acc: &GlweCiphertextVector32,
+        bsk: &FourierLweBootstrapKey32,
+        budget: MemoryBudget,
+    ) -> VectorChunking {
+        let chunking = chunking::<u32>(input.lwe_ciphertext_count(), acc, budget).unwrap();
+        self.pool.install(|| {
+            par_bootstrap_lwe_list_in_chunks(
+                &mut output.0,
+                &input.0,
+                &acc.0,
+                &bsk.0,
+                self.pool.current_num_threads(),
+                chunking.chunk_size.0,
+            )
+        });
+        chunking
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingBudgetedBootstrapEngine`] for
+/// [`MultithreadEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextVectorDiscardingBudgetedBootstrapEngine<
+        FourierLweBootstrapKey64,
+        GlweCiphertextVector64,
+        LweCiphertextVector64,
+        LweCiphertextVector64,
+    > for MultithreadEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweCiphertextCount, GlweDimension,
+    ///     LweCiphertextCount, LweDimension, MemoryBudget, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap of every ciphertext
+    /// let lut = vec![8_u64 << 50; poly_size.0 * 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // The keys and the ciphertexts are handled by the core engine.
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = core_engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey64 =
+    ///     core_engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&input)?;
+    /// let lut_vector = core_engine.create_plaintext_vector(&lut)?;
+    /// let acc = core_engine.trivially_encrypt_glwe_ciphertext_vector(
+    ///     glwe_dim.to_glwe_size(),
+    ///     GlweCiphertextCount(3),
+    ///     &lut_vector,
+    /// )?;
+    /// let input = core_engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector, noise)?;
+    /// let mut output = core_engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_sk_output,
+    ///     noise,
+    ///     LweCiphertextCount(3),
+    /// )?;
+    ///
+    /// // The budget only fits the buffers of a single bootstrap at a time.
+    /// let mut engine = MultithreadEngine::new()?;
+    /// let budget = MemoryBudget(128 * 1024);
+    /// let chunking = engine.discard_bootstrap_lwe_ciphertext_vector_within_budget(
+    ///     &mut output,
+    ///     &input,
+    ///     &acc,
+    ///     &bsk,
+    ///     budget,
+    /// )?;
+    /// #
+    /// assert_eq!(chunking.chunk_size, LweCiphertextCount(1));
+    /// assert_eq!(chunking.chunk_count, 3);
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// core_engine.destroy(lwe_sk)?;
+    /// core_engine.destroy(glwe_sk)?;
+    /// core_engine.destroy(bsk)?;
+    /// core_engine.destroy(lwe_sk_output)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// core_engine.destroy(lut_vector)?;
+    /// core_engine.destroy(acc)?;
+    /// core_engine.destroy(input)?;
+    /// core_engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector_within_budget(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        acc: &GlweCiphertextVector64,
+        bsk: &FourierLweBootstrapKey64,
+        budget: MemoryBudget,
+    ) -> Result<
+        VectorChunking,
+        LweCiphertextVectorDiscardingBudgetedBootstrapError<Self::EngineError>,
+    > {
+        if !ALLOWED_POLY_SIZE.contains(&acc.polynomial_size().0) {
+            return Err(LweCiphertextVectorDiscardingBudgetedBootstrapError::Engine(
+                MultithreadError::UnsupportedPolynomialSize,
+            ));
+        }
+        LweCiphertextVectorDiscardingBudgetedBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        let chunking = chunking::<u64>(input.lwe_ciphertext_count(), acc, budget)
+            .ok_or(LweCiphertextVectorDiscardingBudgetedBootstrapError::MemoryBudgetTooSmall)?;
+        self.pool.install(|| {
+            par_bootstrap_lwe_list_in_chunks(
+                &mut output.0,
+                &input.0,
+                &acc.0,
+                &bsk.0,
+                self.pool.current_num_threads(),
+                chunking.chunk_size.0,
+            )
+        });
+        Ok(chunking)
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_within_budget_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        acc: &GlweCiphertextVector64,
+        bsk: &FourierLweBootstrapKey64,
+        budget: MemoryBudget,
+    ) -> VectorChunking {
+        let chunking = chunking::<u64>(input.lwe_ciphertext_count(), acc, budget).unwrap();
+        self.pool.install(|| {
+            par_bootstrap_lwe_list_in_chunks(
+                &mut output.0,
+                &input.0,
+                &acc.0,
+                &bsk.0,
+                self.pool.current_num_threads(),
+                chunking.chunk_size.0,
+            )
+        });
+        chunking
+    }
+}
@@ -0,0 +1,92 @@
+//! A module containing the [engines](crate::specification::engines) exposed by the multithread
+//! backend.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+
+/// The error which can occur in the execution of FHE operations, due to the multithread
+/// implementation.
+#[derive(Debug)]
+pub enum MultithreadError {
+    ThreadPoolCreation,
+    UnsupportedPolynomialSize,
+}
+
+impl Display for MultithreadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MultithreadError::ThreadPoolCreation => {
+                write!(f, "The thread pool of the engine could not be created.")
+            }
+            MultithreadError::UnsupportedPolynomialSize => {
+                write!(
+                    f,
+                    "The Multithread Backend only supports polynomial sizes which are powers of \
+                    two."
+                )
+            }
+        }
+    }
+}
+
+impl Error for MultithreadError {}
+
+/// The main engine exposed by the multithread backend.
+///
+/// The engine owns a rayon thread pool, on which its operations are executed. By default, the
+/// pool has one thread per logical core of the machine.
+pub struct MultithreadEngine {
+    pool: ThreadPool,
+}
+
+impl MultithreadEngine {
+    /// Creates a new engine, whose pool has `thread_count` threads.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let engine = MultithreadEngine::with_thread_count(2)?;
+    /// assert_eq!(engine.thread_count(), 2);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_thread_count(thread_count: usize) -> Result<Self, MultithreadError> {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(thread_count)
+            .build()
+            .map_err(|_| MultithreadError::ThreadPoolCreation)?;
+        Ok(MultithreadEngine { pool })
+    }
+
+    /// Returns the number of threads of the pool of the engine.
+    pub fn thread_count(&self) -> usize {
+        self.pool.current_num_threads()
+    }
+}
+
+impl AbstractEngineSeal for MultithreadEngine {}
+
+impl AbstractEngine for MultithreadEngine {
+    type EngineError = MultithreadError;
+
+    fn new() -> Result<Self, Self::EngineError> {
+        // A thread count of zero lets rayon pick one thread per logical core.
+        MultithreadEngine::with_thread_count(0)
+    }
+}
+
+mod implementations;
+mod lwe_ciphertext_vector_discarding_bootstrap;
+mod lwe_ciphertext_vector_discarding_budgeted_bootstrap;
+
+pub use implementations::IMPLEMENTATIONS;
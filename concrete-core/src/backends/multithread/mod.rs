@@ -0,0 +1,14 @@
+//! A module containing the multithreaded backend implementation.
+//!
+//! This module contains an implementation of the batched operations of the concrete specification
+//! which splits the ciphertexts of a vector across the threads of a rayon pool. It operates on the
+//! entities of the `core` backend, which are created, converted and destroyed with the
+//! [`CoreEngine`](crate::backends::core::engines::CoreEngine); only the batched operations are
+//! executed with the [`MultithreadEngine`](engines::MultithreadEngine).
+//!
+//! Each thread allocates its own fourier buffers, so that the bootstraps of a vector run without
+//! any synchronization. The backend is compiled with the `backend_multithread` feature.
+
+mod implementation;
+
+pub use implementation::engines;
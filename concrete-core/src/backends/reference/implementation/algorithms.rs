@@ -0,0 +1,247 @@
+//! A module containing the textbook algorithms used by the reference engine.
+//!
+//! Torus elements are represented by unsigned integers, and all the arithmetic is wrapping, that
+//! is, computed modulo $q = 2^{BITS}$. Polynomials are vectors of coefficients stored by increasing
+//! degree, and live in $\mathbb{Z}\_q\[X\] / (X^N + 1)$.
+use crate::backends::core::private::math::random::RandomGenerator;
+use crate::backends::reference::implementation::entities::{
+    ReferenceGlweCiphertext, ReferenceGlweSecretKey, ReferenceLweCiphertext, ReferenceLweSecretKey,
+    ReferenceScalar,
+};
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+
+/// Samples a gaussian noise with the given variance.
+pub(crate) fn sample_noise<T: ReferenceScalar>(
+    generator: &mut RandomGenerator,
+    noise: Variance,
+) -> T {
+    let (sample, _): (T, T) = generator.random_gaussian(0., noise.get_standard_dev());
+    sample
+}
+
+/// Computes $\sum\_i a\_i \cdot s\_i$.
+pub(crate) fn dot_product<T: ReferenceScalar>(a: &[T], s: &[T]) -> T {
+    a.iter().zip(s.iter()).fold(T::ZERO, |sum, (a_i, s_i)| {
+        sum.wrapping_add(a_i.wrapping_mul(*s_i))
+    })
+}
+
+/// Computes the coefficient-wise sum of two vectors, or of two polynomials.
+pub(crate) fn vector_add<T: ReferenceScalar>(a: &[T], b: &[T]) -> Vec<T> {
+    a.iter()
+        .zip(b.iter())
+        .map(|(a_i, b_i)| a_i.wrapping_add(*b_i))
+        .collect()
+}
+
+/// Computes the coefficient-wise difference of two vectors, or of two polynomials.
+pub(crate) fn vector_sub<T: ReferenceScalar>(a: &[T], b: &[T]) -> Vec<T> {
+    a.iter()
+        .zip(b.iter())
+        .map(|(a_i, b_i)| a_i.wrapping_sub(*b_i))
+        .collect()
+}
+
+/// Computes $A \cdot B$ modulo $X^N + 1$, with the schoolbook algorithm.
+pub(crate) fn polynomial_mul<T: ReferenceScalar>(a: &[T], b: &[T]) -> Vec<T> {
+    let n = a.len();
+    let mut output = vec![T::ZERO; n];
+    for (i, a_i) in a.iter().enumerate() {
+        for (j, b_j) in b.iter().enumerate() {
+            let product = a_i.wrapping_mul(*b_j);
+            // Since X^N = -1, the monomial X^{i+j} is equal to -X^{i+j-N} when i + j >= N.
+            if i + j < n {
+                output[i + j] = output[i + j].wrapping_add(product);
+            } else {
+                output[i + j - n] = output[i + j - n].wrapping_sub(product);
+            }
+        }
+    }
+    output
+}
+
+/// Computes $A \cdot X^d$ modulo $X^N + 1$, for a degree $d$ in $\[0, 2N)$.
+pub(crate) fn polynomial_monomial_mul<T: ReferenceScalar>(a: &[T], degree: usize) -> Vec<T> {
+    let n = a.len();
+    let mut output = vec![T::ZERO; n];
+    for (i, a_i) in a.iter().enumerate() {
+        // Since X^N = -1, the monomial X^{i+d} is equal to -X^{i+d-N} when N <= i + d < 2N.
+        let shifted = (i + degree) % (2 * n);
+        if shifted < n {
+            output[shifted] = *a_i;
+        } else {
+            output[shifted - n] = a_i.wrapping_neg();
+        }
+    }
+    output
+}
+
+/// Decomposes a torus element in base $B = 2^{\beta}$ over $l$ levels.
+///
+/// The element is first rounded to the closest multiple of $q / B^l$, which is then written as
+/// $\sum\_{j=1}^{l} d\_j \cdot q / B^j$, with digits $d\_j$ in $\[-B/2, B/2)$. The digits are
+/// returned by increasing level, and are represented modulo $q$.
+pub(crate) fn decompose<T: ReferenceScalar>(
+    value: T,
+    base_log: usize,
+    level_count: usize,
+) -> Vec<T> {
+    let dropped_bits = T::BITS - base_log * level_count;
+    // The integer whose digits we compute is the rounding of value / 2^dropped_bits.
+    let mut remainder = if dropped_bits == 0 {
+        value
+    } else {
+        value.wrapping_add(T::ONE << (dropped_bits - 1)) >> dropped_bits
+    };
+    let base = T::ONE << base_log;
+    let mut digits = vec![T::ZERO; level_count];
+    // We extract the digits from the least significant one, which is the one of level l.
+    for level in (0..level_count).rev() {
+        let digit = remainder & (base - T::ONE);
+        remainder >>= base_log;
+        if digit >= base >> 1 {
+            // The digit is too large: we use digit - B instead, and carry one to the next level.
+            digits[level] = digit.wrapping_sub(base);
+            remainder = remainder.wrapping_add(T::ONE);
+        } else {
+            digits[level] = digit;
+        }
+    }
+    digits
+}
+
+/// Switches a torus element to the modulus $2N$, that is, rounds $x \cdot 2N / q$ to the closest
+/// integer modulo $2N$.
+///
+/// The polynomial size $N$ must be a power of two.
+pub(crate) fn modulus_switch<T: ReferenceScalar>(value: T, polynomial_size: usize) -> usize {
+    let log_2n = (2 * polynomial_size).trailing_zeros() as usize;
+    let dropped_bits = T::BITS - log_2n;
+    let rounded: usize =
+        (value.wrapping_add(T::ONE << (dropped_bits - 1)) >> dropped_bits).cast_into();
+    rounded % (2 * polynomial_size)
+}
+
+/// Encrypts a plaintext under an LWE key: the mask is uniformly random, and the body is
+/// $b = \sum\_i a\_i s\_i + m + e$.
+pub(crate) fn lwe_encrypt<T: ReferenceScalar>(
+    generator: &mut RandomGenerator,
+    key: &ReferenceLweSecretKey<T>,
+    plaintext: T,
+    noise: Variance,
+) -> ReferenceLweCiphertext<T> {
+    let mask: Vec<T> = key.0.iter().map(|_| generator.random_uniform()).collect();
+    let body = dot_product(&mask, &key.0)
+        .wrapping_add(plaintext)
+        .wrapping_add(sample_noise(generator, noise));
+    ReferenceLweCiphertext { mask, body }
+}
+
+/// Computes the phase $b - \sum\_i a\_i s\_i$ of an LWE ciphertext.
+pub(crate) fn lwe_phase<T: ReferenceScalar>(
+    key: &ReferenceLweSecretKey<T>,
+    ciphertext: &ReferenceLweCiphertext<T>,
+) -> T {
+    ciphertext
+        .body
+        .wrapping_sub(dot_product(&ciphertext.mask, &key.0))
+}
+
+/// Encrypts a plaintext polynomial under a GLWE key: the mask polynomials are uniformly random,
+/// and the body is $B = \sum\_i A\_i S\_i + M + E$.
+pub(crate) fn glwe_encrypt<T: ReferenceScalar>(
+    generator: &mut RandomGenerator,
+    key: &ReferenceGlweSecretKey<T>,
+    plaintexts: &[T],
+    noise: Variance,
+) -> ReferenceGlweCiphertext<T> {
+    let mask: Vec<Vec<T>> = key
+        .polynomials
+        .iter()
+        .map(|_| {
+            plaintexts
+                .iter()
+                .map(|_| generator.random_uniform())
+                .collect()
+        })
+        .collect();
+    let mut body: Vec<T> = plaintexts
+        .iter()
+        .map(|m| m.wrapping_add(sample_noise(generator, noise)))
+        .collect();
+    for (mask_polynomial, key_polynomial) in mask.iter().zip(key.polynomials.iter()) {
+        body = vector_add(&body, &polynomial_mul(mask_polynomial, key_polynomial));
+    }
+    ReferenceGlweCiphertext { mask, body }
+}
+
+/// Computes the phase $B - \sum\_i A\_i S\_i$ of a GLWE ciphertext.
+pub(crate) fn glwe_phase<T: ReferenceScalar>(
+    key: &ReferenceGlweSecretKey<T>,
+    ciphertext: &ReferenceGlweCiphertext<T>,
+) -> Vec<T> {
+    let mut phase = ciphertext.body.clone();
+    for (mask_polynomial, key_polynomial) in ciphertext.mask.iter().zip(key.polynomials.iter()) {
+        phase = vector_sub(&phase, &polynomial_mul(mask_polynomial, key_polynomial));
+    }
+    phase
+}
+
+/// Computes the external product between a GGSW ciphertext, given by its rows as described in
+/// [`ReferenceLweBootstrapKey`](super::entities::ReferenceLweBootstrapKey), and a GLWE ciphertext.
+///
+/// Every polynomial $C\_r$ of the GLWE ciphertext is decomposed as $\sum\_j D\_{r,j} q / B^j$, and
+/// the output is $\sum\_j \sum\_r D\_{r,j} \cdot \mathsf{row}\_{j,r}$. Since the phase of
+/// $\mathsf{row}\_{j,r}$ is $\mu q / B^j$ times $-S\_r$ for a mask polynomial, and times $1$ for
+/// the body, the output encrypts $\mu$ times the phase of the GLWE ciphertext.
+pub(crate) fn external_product<T: ReferenceScalar>(
+    ggsw: &[Vec<ReferenceGlweCiphertext<T>>],
+    glwe: &ReferenceGlweCiphertext<T>,
+    base_log: usize,
+    level_count: usize,
+) -> ReferenceGlweCiphertext<T> {
+    let polynomial_size = glwe.body.len();
+    let mut output = ReferenceGlweCiphertext {
+        mask: vec![vec![T::ZERO; polynomial_size]; glwe.mask.len()],
+        body: vec![T::ZERO; polynomial_size],
+    };
+    for (r, polynomial) in glwe.polynomials().enumerate() {
+        // decompositions[i] contains the digits of the i-th coefficient of the polynomial.
+        let decompositions: Vec<Vec<T>> = polynomial
+            .iter()
+            .map(|coefficient| decompose(*coefficient, base_log, level_count))
+            .collect();
+        for (level_index, level_rows) in ggsw.iter().enumerate() {
+            let digits: Vec<T> = decompositions.iter().map(|d| d[level_index]).collect();
+            let row = &level_rows[r];
+            for (output_polynomial, row_polynomial) in output.mask.iter_mut().zip(row.mask.iter()) {
+                *output_polynomial =
+                    vector_add(output_polynomial, &polynomial_mul(&digits, row_polynomial));
+            }
+            output.body = vector_add(&output.body, &polynomial_mul(&digits, &row.body));
+        }
+    }
+    output
+}
+
+/// Extracts the constant coefficient of the plaintext of a GLWE ciphertext, as an LWE ciphertext
+/// under the flattened GLWE key.
+///
+/// The constant coefficient of $A\_r S\_r$ is $a\_{r,0} s\_{r,0} - \sum\_{i=1}^{N-1} a\_{r,N-i}
+/// s\_{r,i}$, hence the coefficients of the output mask.
+pub(crate) fn sample_extract<T: ReferenceScalar>(
+    glwe: &ReferenceGlweCiphertext<T>,
+) -> ReferenceLweCiphertext<T> {
+    let polynomial_size = glwe.body.len();
+    let mut mask = Vec::with_capacity(glwe.mask.len() * polynomial_size);
+    for polynomial in glwe.mask.iter() {
+        mask.push(polynomial[0]);
+        for i in 1..polynomial_size {
+            mask.push(polynomial[polynomial_size - i].wrapping_neg());
+        }
+    }
+    ReferenceLweCiphertext {
+        mask,
+        body: glwe.body[0],
+    }
+}
@@ -0,0 +1,18 @@
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{ReferenceCleartext, ReferenceScalar};
+use crate::specification::engines::{CleartextCreationEngine, CleartextCreationError};
+
+/// # Description:
+/// Implementation of [`CleartextCreationEngine`] for [`ReferenceEngine`].
+impl<T: ReferenceScalar> CleartextCreationEngine<T, ReferenceCleartext<T>> for ReferenceEngine {
+    fn create_cleartext(
+        &mut self,
+        input: &T,
+    ) -> Result<ReferenceCleartext<T>, CleartextCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_cleartext_unchecked(input) })
+    }
+
+    unsafe fn create_cleartext_unchecked(&mut self, input: &T) -> ReferenceCleartext<T> {
+        ReferenceCleartext(*input)
+    }
+}
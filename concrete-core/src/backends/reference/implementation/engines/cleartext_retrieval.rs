@@ -0,0 +1,18 @@
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{ReferenceCleartext, ReferenceScalar};
+use crate::specification::engines::{CleartextRetrievalEngine, CleartextRetrievalError};
+
+/// # Description:
+/// Implementation of [`CleartextRetrievalEngine`] for [`ReferenceEngine`].
+impl<T: ReferenceScalar> CleartextRetrievalEngine<ReferenceCleartext<T>, T> for ReferenceEngine {
+    fn retrieve_cleartext(
+        &mut self,
+        cleartext: &ReferenceCleartext<T>,
+    ) -> Result<T, CleartextRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_cleartext_unchecked(cleartext) })
+    }
+
+    unsafe fn retrieve_cleartext_unchecked(&mut self, cleartext: &ReferenceCleartext<T>) -> T {
+        cleartext.0
+    }
+}
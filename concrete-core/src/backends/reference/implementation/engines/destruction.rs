@@ -0,0 +1,38 @@
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceCleartext, ReferenceGgswCiphertext, ReferenceGlweCiphertext, ReferenceGlweSecretKey,
+    ReferenceLweBootstrapKey, ReferenceLweCiphertext, ReferenceLweKeyswitchKey,
+    ReferenceLweSecretKey, ReferencePlaintext, ReferencePlaintextVector, ReferenceScalar,
+};
+use crate::specification::engines::{DestructionEngine, DestructionError};
+
+macro_rules! implement_destruction {
+    ($($entity: ident),*) => {
+        $(
+            impl<T: ReferenceScalar> DestructionEngine<$entity<T>> for ReferenceEngine {
+                fn destroy(
+                    &mut self,
+                    entity: $entity<T>,
+                ) -> Result<(), DestructionError<Self::EngineError>> {
+                    unsafe { self.destroy_unchecked(entity) };
+                    Ok(())
+                }
+
+                unsafe fn destroy_unchecked(&mut self, _entity: $entity<T>) {}
+            }
+        )*
+    };
+}
+
+implement_destruction!(
+    ReferenceCleartext,
+    ReferenceGgswCiphertext,
+    ReferenceGlweCiphertext,
+    ReferenceGlweSecretKey,
+    ReferenceLweBootstrapKey,
+    ReferenceLweCiphertext,
+    ReferenceLweKeyswitchKey,
+    ReferenceLweSecretKey,
+    ReferencePlaintext,
+    ReferencePlaintextVector
+);
@@ -0,0 +1,86 @@
+use crate::backends::core::entities::{GgswCiphertext32, GgswCiphertext64};
+use crate::backends::core::private::crypto::ggsw::StandardGgswCiphertext as ImplStandardGgswCiphertext;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::reference::implementation::engines::glwe_ciphertext_conversion::{
+    glwe_ciphertext_from_core_layout, glwe_ciphertext_to_core_layout,
+};
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceGgswCiphertext, ReferenceGgswCiphertext32, ReferenceGgswCiphertext64,
+};
+use crate::specification::engines::{
+    GgswCiphertextConversionEngine, GgswCiphertextConversionError,
+};
+use crate::specification::entities::GgswCiphertextEntity;
+
+macro_rules! implement_conversions {
+    ($core: ident, $reference: ident) => {
+        /// # Description:
+        /// Implementation of [`GgswCiphertextConversionEngine`] for [`ReferenceEngine`] that
+        /// converts a core GGSW ciphertext, in the standard domain, into a reference one.
+        impl GgswCiphertextConversionEngine<$core, $reference> for ReferenceEngine {
+            fn convert_ggsw_ciphertext(
+                &mut self,
+                input: &$core,
+            ) -> Result<$reference, GgswCiphertextConversionError<Self::EngineError>> {
+                Ok(unsafe { self.convert_ggsw_ciphertext_unchecked(input) })
+            }
+
+            unsafe fn convert_ggsw_ciphertext_unchecked(&mut self, input: &$core) -> $reference {
+                // The core ciphertext stores a matrix per level from 1 to l, whose k + 1 rows are
+                // GLWE ciphertexts.
+                let polynomial_size = input.polynomial_size();
+                let glwe_size = input.glwe_dimension().to_glwe_size().0;
+                let row_size = glwe_size * polynomial_size.0;
+                let rows = input
+                    .0
+                    .as_tensor()
+                    .as_slice()
+                    .chunks(row_size * glwe_size)
+                    .map(|matrix| {
+                        matrix
+                            .chunks(row_size)
+                            .map(|row| glwe_ciphertext_from_core_layout(row, polynomial_size))
+                            .collect()
+                    })
+                    .collect();
+                ReferenceGgswCiphertext {
+                    rows,
+                    glwe_dimension: input.glwe_dimension(),
+                    polynomial_size,
+                    decomposition_base_log: input.decomposition_base_log(),
+                }
+            }
+        }
+
+        /// # Description:
+        /// Implementation of [`GgswCiphertextConversionEngine`] for [`ReferenceEngine`] that
+        /// converts a reference GGSW ciphertext into a core one, in the standard domain.
+        impl GgswCiphertextConversionEngine<$reference, $core> for ReferenceEngine {
+            fn convert_ggsw_ciphertext(
+                &mut self,
+                input: &$reference,
+            ) -> Result<$core, GgswCiphertextConversionError<Self::EngineError>> {
+                Ok(unsafe { self.convert_ggsw_ciphertext_unchecked(input) })
+            }
+
+            unsafe fn convert_ggsw_ciphertext_unchecked(&mut self, input: &$reference) -> $core {
+                let container = input
+                    .rows
+                    .iter()
+                    .flatten()
+                    .flat_map(glwe_ciphertext_to_core_layout)
+                    .collect::<Vec<_>>();
+                $core(ImplStandardGgswCiphertext::from_container(
+                    container,
+                    input.glwe_dimension.to_glwe_size(),
+                    input.polynomial_size,
+                    input.decomposition_base_log,
+                ))
+            }
+        }
+    };
+}
+
+implement_conversions!(GgswCiphertext32, ReferenceGgswCiphertext32);
+implement_conversions!(GgswCiphertext64, ReferenceGgswCiphertext64);
@@ -0,0 +1,79 @@
+use crate::backends::core::entities::{GlweCiphertext32, GlweCiphertext64};
+use crate::backends::core::private::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceGlweCiphertext, ReferenceGlweCiphertext32, ReferenceGlweCiphertext64, ReferenceScalar,
+};
+use crate::specification::engines::{
+    GlweCiphertextConversionEngine, GlweCiphertextConversionError,
+};
+use crate::specification::entities::GlweCiphertextEntity;
+use concrete_commons::parameters::PolynomialSize;
+
+// Reads a GLWE ciphertext stored as in the core backend: the mask polynomials, followed by the
+// body polynomial.
+pub(super) fn glwe_ciphertext_from_core_layout<T: ReferenceScalar>(
+    data: &[T],
+    polynomial_size: PolynomialSize,
+) -> ReferenceGlweCiphertext<T> {
+    let mut polynomials: Vec<Vec<T>> = data.chunks(polynomial_size.0).map(<[T]>::to_vec).collect();
+    let body = polynomials.pop().unwrap();
+    ReferenceGlweCiphertext {
+        mask: polynomials,
+        body,
+    }
+}
+
+// Writes a GLWE ciphertext as in the core backend: the mask polynomials, followed by the body
+// polynomial.
+pub(super) fn glwe_ciphertext_to_core_layout<T: ReferenceScalar>(
+    ciphertext: &ReferenceGlweCiphertext<T>,
+) -> Vec<T> {
+    ciphertext.polynomials().flatten().copied().collect()
+}
+
+macro_rules! implement_conversions {
+    ($core: ident, $reference: ident) => {
+        /// # Description:
+        /// Implementation of [`GlweCiphertextConversionEngine`] for [`ReferenceEngine`] that
+        /// converts a core GLWE ciphertext into a reference one.
+        impl GlweCiphertextConversionEngine<$core, $reference> for ReferenceEngine {
+            fn convert_glwe_ciphertext(
+                &mut self,
+                input: &$core,
+            ) -> Result<$reference, GlweCiphertextConversionError<Self::EngineError>> {
+                Ok(unsafe { self.convert_glwe_ciphertext_unchecked(input) })
+            }
+
+            unsafe fn convert_glwe_ciphertext_unchecked(&mut self, input: &$core) -> $reference {
+                glwe_ciphertext_from_core_layout(
+                    input.0.as_tensor().as_slice(),
+                    input.polynomial_size(),
+                )
+            }
+        }
+
+        /// # Description:
+        /// Implementation of [`GlweCiphertextConversionEngine`] for [`ReferenceEngine`] that
+        /// converts a reference GLWE ciphertext into a core one.
+        impl GlweCiphertextConversionEngine<$reference, $core> for ReferenceEngine {
+            fn convert_glwe_ciphertext(
+                &mut self,
+                input: &$reference,
+            ) -> Result<$core, GlweCiphertextConversionError<Self::EngineError>> {
+                Ok(unsafe { self.convert_glwe_ciphertext_unchecked(input) })
+            }
+
+            unsafe fn convert_glwe_ciphertext_unchecked(&mut self, input: &$reference) -> $core {
+                $core(ImplGlweCiphertext::from_container(
+                    glwe_ciphertext_to_core_layout(input),
+                    input.polynomial_size(),
+                ))
+            }
+        }
+    };
+}
+
+implement_conversions!(GlweCiphertext32, ReferenceGlweCiphertext32);
+implement_conversions!(GlweCiphertext64, ReferenceGlweCiphertext64);
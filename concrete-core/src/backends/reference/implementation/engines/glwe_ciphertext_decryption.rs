@@ -0,0 +1,37 @@
+use crate::backends::reference::implementation::algorithms::glwe_phase;
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceGlweCiphertext, ReferenceGlweSecretKey, ReferencePlaintextVector, ReferenceScalar,
+};
+use crate::specification::engines::{
+    GlweCiphertextDecryptionEngine, GlweCiphertextDecryptionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDecryptionEngine`] for [`ReferenceEngine`]. The output
+/// plaintexts are the coefficients of the phase $B - \sum\_i A\_i S\_i$ of the ciphertext, which
+/// still contain the noise.
+impl<T: ReferenceScalar>
+    GlweCiphertextDecryptionEngine<
+        ReferenceGlweSecretKey<T>,
+        ReferenceGlweCiphertext<T>,
+        ReferencePlaintextVector<T>,
+    > for ReferenceEngine
+{
+    fn decrypt_glwe_ciphertext(
+        &mut self,
+        key: &ReferenceGlweSecretKey<T>,
+        input: &ReferenceGlweCiphertext<T>,
+    ) -> Result<ReferencePlaintextVector<T>, GlweCiphertextDecryptionError<Self::EngineError>> {
+        GlweCiphertextDecryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_glwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &ReferenceGlweSecretKey<T>,
+        input: &ReferenceGlweCiphertext<T>,
+    ) -> ReferencePlaintextVector<T> {
+        ReferencePlaintextVector(glwe_phase(key, input))
+    }
+}
@@ -0,0 +1,40 @@
+use crate::backends::reference::implementation::algorithms::glwe_encrypt;
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceGlweCiphertext, ReferenceGlweSecretKey, ReferencePlaintextVector, ReferenceScalar,
+};
+use crate::specification::engines::{
+    GlweCiphertextEncryptionEngine, GlweCiphertextEncryptionError,
+};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`ReferenceEngine`]. The mask
+/// polynomials $(A\_0, \dots, A\_{k-1})$ are sampled uniformly, and the body is computed as
+/// $B = \sum\_i A\_i S\_i + M + E$, with a gaussian noise polynomial $E$.
+impl<T: ReferenceScalar>
+    GlweCiphertextEncryptionEngine<
+        ReferenceGlweSecretKey<T>,
+        ReferencePlaintextVector<T>,
+        ReferenceGlweCiphertext<T>,
+    > for ReferenceEngine
+{
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &ReferenceGlweSecretKey<T>,
+        input: &ReferencePlaintextVector<T>,
+        noise: Variance,
+    ) -> Result<ReferenceGlweCiphertext<T>, GlweCiphertextEncryptionError<Self::EngineError>> {
+        GlweCiphertextEncryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &ReferenceGlweSecretKey<T>,
+        input: &ReferencePlaintextVector<T>,
+        noise: Variance,
+    ) -> ReferenceGlweCiphertext<T> {
+        glwe_encrypt(&mut self.generator, key, &input.0, noise)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceGlweCiphertext, ReferencePlaintextVector, ReferenceScalar,
+};
+use crate::specification::engines::{
+    GlweCiphertextTrivialEncryptionEngine, GlweCiphertextTrivialEncryptionError,
+};
+use concrete_commons::parameters::GlweSize;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextTrivialEncryptionEngine`] for [`ReferenceEngine`]. The mask
+/// polynomials are zero, and the body is the plaintext polynomial.
+impl<T: ReferenceScalar>
+    GlweCiphertextTrivialEncryptionEngine<ReferencePlaintextVector<T>, ReferenceGlweCiphertext<T>>
+    for ReferenceEngine
+{
+    fn trivially_encrypt_glwe_ciphertext(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &ReferencePlaintextVector<T>,
+    ) -> Result<ReferenceGlweCiphertext<T>, GlweCiphertextTrivialEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.trivially_encrypt_glwe_ciphertext_unchecked(glwe_size, input) })
+    }
+
+    unsafe fn trivially_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &ReferencePlaintextVector<T>,
+    ) -> ReferenceGlweCiphertext<T> {
+        ReferenceGlweCiphertext {
+            mask: vec![vec![T::ZERO; input.0.len()]; glwe_size.to_glwe_dimension().0],
+            body: input.0.clone(),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::backends::core::entities::{GlweSecretKey32, GlweSecretKey64};
+use crate::backends::core::private::crypto::secret::GlweSecretKey as ImplGlweSecretKey;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceGlweSecretKey, ReferenceGlweSecretKey32, ReferenceGlweSecretKey64,
+};
+use crate::specification::engines::{GlweSecretKeyConversionEngine, GlweSecretKeyConversionError};
+use crate::specification::entities::GlweSecretKeyEntity;
+
+macro_rules! implement_conversions {
+    ($core: ident, $reference: ident) => {
+        /// # Description:
+        /// Implementation of [`GlweSecretKeyConversionEngine`] for [`ReferenceEngine`] that
+        /// converts a core GLWE secret key into a reference one.
+        impl GlweSecretKeyConversionEngine<$core, $reference> for ReferenceEngine {
+            fn convert_glwe_secret_key(
+                &mut self,
+                input: &$core,
+            ) -> Result<$reference, GlweSecretKeyConversionError<Self::EngineError>> {
+                Ok(unsafe { self.convert_glwe_secret_key_unchecked(input) })
+            }
+
+            unsafe fn convert_glwe_secret_key_unchecked(&mut self, input: &$core) -> $reference {
+                let polynomial_size = input.polynomial_size();
+                ReferenceGlweSecretKey {
+                    polynomials: input
+                        .0
+                        .as_tensor()
+                        .as_slice()
+                        .chunks(polynomial_size.0)
+                        .map(<[_]>::to_vec)
+                        .collect(),
+                    polynomial_size,
+                }
+            }
+        }
+
+        /// # Description:
+        /// Implementation of [`GlweSecretKeyConversionEngine`] for [`ReferenceEngine`] that
+        /// converts a reference GLWE secret key into a core one.
+        impl GlweSecretKeyConversionEngine<$reference, $core> for ReferenceEngine {
+            fn convert_glwe_secret_key(
+                &mut self,
+                input: &$reference,
+            ) -> Result<$core, GlweSecretKeyConversionError<Self::EngineError>> {
+                Ok(unsafe { self.convert_glwe_secret_key_unchecked(input) })
+            }
+
+            unsafe fn convert_glwe_secret_key_unchecked(&mut self, input: &$reference) -> $core {
+                $core(ImplGlweSecretKey::binary_from_container(
+                    input.polynomials.concat(),
+                    input.polynomial_size,
+                ))
+            }
+        }
+    };
+}
+
+implement_conversions!(GlweSecretKey32, ReferenceGlweSecretKey32);
+implement_conversions!(GlweSecretKey64, ReferenceGlweSecretKey64);
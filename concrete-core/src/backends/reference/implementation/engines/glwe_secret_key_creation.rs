@@ -0,0 +1,40 @@
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceGlweSecretKey, ReferenceScalar,
+};
+use crate::specification::engines::{GlweSecretKeyCreationEngine, GlweSecretKeyCreationError};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyCreationEngine`] for [`ReferenceEngine`]. Every coefficient
+/// of every polynomial of the key is a uniformly random bit.
+impl<T: ReferenceScalar> GlweSecretKeyCreationEngine<ReferenceGlweSecretKey<T>>
+    for ReferenceEngine
+{
+    fn create_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<ReferenceGlweSecretKey<T>, GlweSecretKeyCreationError<Self::EngineError>> {
+        GlweSecretKeyCreationError::perform_generic_checks(glwe_dimension, polynomial_size)?;
+        Ok(unsafe { self.create_glwe_secret_key_unchecked(glwe_dimension, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> ReferenceGlweSecretKey<T> {
+        let polynomials = (0..glwe_dimension.0)
+            .map(|_| {
+                (0..polynomial_size.0)
+                    .map(|_| self.generator.random_uniform_binary())
+                    .collect()
+            })
+            .collect();
+        ReferenceGlweSecretKey {
+            polynomials,
+            polynomial_size,
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! The table of the operations implemented by the [`ReferenceEngine`].
+
+use crate::backends::core::entities::*;
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::*;
+use crate::specification::engines::*;
+
+engine_implementations! {
+    ReferenceEngine {
+        CleartextCreationEngine(u32, ReferenceCleartext32),
+        CleartextCreationEngine(u64, ReferenceCleartext64),
+        CleartextRetrievalEngine(ReferenceCleartext32, u32),
+        CleartextRetrievalEngine(ReferenceCleartext64, u64),
+        DestructionEngine(ReferenceCleartext32),
+        DestructionEngine(ReferenceCleartext64),
+        DestructionEngine(ReferenceGgswCiphertext32),
+        DestructionEngine(ReferenceGgswCiphertext64),
+        DestructionEngine(ReferenceGlweCiphertext32),
+        DestructionEngine(ReferenceGlweCiphertext64),
+        DestructionEngine(ReferenceGlweSecretKey32),
+        DestructionEngine(ReferenceGlweSecretKey64),
+        DestructionEngine(ReferenceLweBootstrapKey32),
+        DestructionEngine(ReferenceLweBootstrapKey64),
+        DestructionEngine(ReferenceLweCiphertext32),
+        DestructionEngine(ReferenceLweCiphertext64),
+        DestructionEngine(ReferenceLweKeyswitchKey32),
+        DestructionEngine(ReferenceLweKeyswitchKey64),
+        DestructionEngine(ReferenceLweSecretKey32),
+        DestructionEngine(ReferenceLweSecretKey64),
+        DestructionEngine(ReferencePlaintext32),
+        DestructionEngine(ReferencePlaintext64),
+        DestructionEngine(ReferencePlaintextVector32),
+        DestructionEngine(ReferencePlaintextVector64),
+        GgswCiphertextConversionEngine(GgswCiphertext32, ReferenceGgswCiphertext32),
+        GgswCiphertextConversionEngine(GgswCiphertext64, ReferenceGgswCiphertext64),
+        GgswCiphertextConversionEngine(ReferenceGgswCiphertext32, GgswCiphertext32),
+        GgswCiphertextConversionEngine(ReferenceGgswCiphertext64, GgswCiphertext64),
+        GlweCiphertextConversionEngine(GlweCiphertext32, ReferenceGlweCiphertext32),
+        GlweCiphertextConversionEngine(GlweCiphertext64, ReferenceGlweCiphertext64),
+        GlweCiphertextConversionEngine(ReferenceGlweCiphertext32, GlweCiphertext32),
+        GlweCiphertextConversionEngine(ReferenceGlweCiphertext64, GlweCiphertext64),
+        GlweCiphertextDecryptionEngine(
+            ReferenceGlweSecretKey32,
+            ReferenceGlweCiphertext32,
+            ReferencePlaintextVector32,
+        ),
+        GlweCiphertextDecryptionEngine(
+            ReferenceGlweSecretKey64,
+            ReferenceGlweCiphertext64,
+            ReferencePlaintextVector64,
+        ),
+        GlweCiphertextEncryptionEngine(
+            ReferenceGlweSecretKey32,
+            ReferencePlaintextVector32,
+            ReferenceGlweCiphertext32,
+        ),
+        GlweCiphertextEncryptionEngine(
+            ReferenceGlweSecretKey64,
+            ReferencePlaintextVector64,
+            ReferenceGlweCiphertext64,
+        ),
+        GlweCiphertextTrivialEncryptionEngine(
+            ReferencePlaintextVector32,
+            ReferenceGlweCiphertext32,
+        ),
+        GlweCiphertextTrivialEncryptionEngine(
+            ReferencePlaintextVector64,
+            ReferenceGlweCiphertext64,
+        ),
+        GlweSecretKeyConversionEngine(GlweSecretKey32, ReferenceGlweSecretKey32),
+        GlweSecretKeyConversionEngine(GlweSecretKey64, ReferenceGlweSecretKey64),
+        GlweSecretKeyConversionEngine(ReferenceGlweSecretKey32, GlweSecretKey32),
+        GlweSecretKeyConversionEngine(ReferenceGlweSecretKey64, GlweSecretKey64),
+        GlweSecretKeyCreationEngine(ReferenceGlweSecretKey32),
+        GlweSecretKeyCreationEngine(ReferenceGlweSecretKey64),
+        GlweToLweSecretKeyTransmutationEngine(ReferenceGlweSecretKey32, ReferenceLweSecretKey32),
+        GlweToLweSecretKeyTransmutationEngine(ReferenceGlweSecretKey64, ReferenceLweSecretKey64),
+        LweBootstrapKeyConversionEngine(LweBootstrapKey32, ReferenceLweBootstrapKey32),
+        LweBootstrapKeyConversionEngine(LweBootstrapKey64, ReferenceLweBootstrapKey64),
+        LweBootstrapKeyConversionEngine(ReferenceLweBootstrapKey32, LweBootstrapKey32),
+        LweBootstrapKeyConversionEngine(ReferenceLweBootstrapKey64, LweBootstrapKey64),
+        LweBootstrapKeyCreationEngine(
+            ReferenceLweSecretKey32,
+            ReferenceGlweSecretKey32,
+            ReferenceLweBootstrapKey32,
+        ),
+        LweBootstrapKeyCreationEngine(
+            ReferenceLweSecretKey64,
+            ReferenceGlweSecretKey64,
+            ReferenceLweBootstrapKey64,
+        ),
+        LweCiphertextCleartextFusingMultiplicationEngine(
+            ReferenceLweCiphertext32,
+            ReferenceCleartext32,
+        ),
+        LweCiphertextCleartextFusingMultiplicationEngine(
+            ReferenceLweCiphertext64,
+            ReferenceCleartext64,
+        ),
+        LweCiphertextConversionEngine(LweCiphertext32, ReferenceLweCiphertext32),
+        LweCiphertextConversionEngine(LweCiphertext64, ReferenceLweCiphertext64),
+        LweCiphertextConversionEngine(ReferenceLweCiphertext32, LweCiphertext32),
+        LweCiphertextConversionEngine(ReferenceLweCiphertext64, LweCiphertext64),
+        LweCiphertextDecryptionEngine(
+            ReferenceLweSecretKey32,
+            ReferenceLweCiphertext32,
+            ReferencePlaintext32,
+        ),
+        LweCiphertextDecryptionEngine(
+            ReferenceLweSecretKey64,
+            ReferenceLweCiphertext64,
+            ReferencePlaintext64,
+        ),
+        LweCiphertextDiscardingAdditionEngine(ReferenceLweCiphertext32, ReferenceLweCiphertext32),
+        LweCiphertextDiscardingAdditionEngine(ReferenceLweCiphertext64, ReferenceLweCiphertext64),
+        LweCiphertextDiscardingBootstrapEngine(
+            ReferenceLweBootstrapKey32,
+            ReferenceGlweCiphertext32,
+            ReferenceLweCiphertext32,
+            ReferenceLweCiphertext32,
+        ),
+        LweCiphertextDiscardingBootstrapEngine(
+            ReferenceLweBootstrapKey64,
+            ReferenceGlweCiphertext64,
+            ReferenceLweCiphertext64,
+            ReferenceLweCiphertext64,
+        ),
+        LweCiphertextDiscardingKeyswitchEngine(
+            ReferenceLweKeyswitchKey32,
+            ReferenceLweCiphertext32,
+            ReferenceLweCiphertext32,
+        ),
+        LweCiphertextDiscardingKeyswitchEngine(
+            ReferenceLweKeyswitchKey64,
+            ReferenceLweCiphertext64,
+            ReferenceLweCiphertext64,
+        ),
+        LweCiphertextEncryptionEngine(
+            ReferenceLweSecretKey32,
+            ReferencePlaintext32,
+            ReferenceLweCiphertext32,
+        ),
+        LweCiphertextEncryptionEngine(
+            ReferenceLweSecretKey64,
+            ReferencePlaintext64,
+            ReferenceLweCiphertext64,
+        ),
+        LweCiphertextFusingAdditionEngine(ReferenceLweCiphertext32, ReferenceLweCiphertext32),
+        LweCiphertextFusingAdditionEngine(ReferenceLweCiphertext64, ReferenceLweCiphertext64),
+        LweCiphertextTrivialEncryptionEngine(ReferencePlaintext32, ReferenceLweCiphertext32),
+        LweCiphertextTrivialEncryptionEngine(ReferencePlaintext64, ReferenceLweCiphertext64),
+        LweKeyswitchKeyConversionEngine(LweKeyswitchKey32, ReferenceLweKeyswitchKey32),
+        LweKeyswitchKeyConversionEngine(LweKeyswitchKey64, ReferenceLweKeyswitchKey64),
+        LweKeyswitchKeyConversionEngine(ReferenceLweKeyswitchKey32, LweKeyswitchKey32),
+        LweKeyswitchKeyConversionEngine(ReferenceLweKeyswitchKey64, LweKeyswitchKey64),
+        LweKeyswitchKeyCreationEngine(
+            ReferenceLweSecretKey32,
+            ReferenceLweSecretKey32,
+            ReferenceLweKeyswitchKey32,
+        ),
+        LweKeyswitchKeyCreationEngine(
+            ReferenceLweSecretKey64,
+            ReferenceLweSecretKey64,
+            ReferenceLweKeyswitchKey64,
+        ),
+        LweSecretKeyConversionEngine(LweSecretKey32, ReferenceLweSecretKey32),
+        LweSecretKeyConversionEngine(LweSecretKey64, ReferenceLweSecretKey64),
+        LweSecretKeyConversionEngine(ReferenceLweSecretKey32, LweSecretKey32),
+        LweSecretKeyConversionEngine(ReferenceLweSecretKey64, LweSecretKey64),
+        LweSecretKeyCreationEngine(ReferenceLweSecretKey32),
+        LweSecretKeyCreationEngine(ReferenceLweSecretKey64),
+        PlaintextCreationEngine(u32, ReferencePlaintext32),
+        PlaintextCreationEngine(u64, ReferencePlaintext64),
+        PlaintextRetrievalEngine(ReferencePlaintext32, u32),
+        PlaintextRetrievalEngine(ReferencePlaintext64, u64),
+        PlaintextVectorCreationEngine(u32, ReferencePlaintextVector32),
+        PlaintextVectorCreationEngine(u64, ReferencePlaintextVector64),
+        PlaintextVectorRetrievalEngine(ReferencePlaintextVector32, u32),
+        PlaintextVectorRetrievalEngine(ReferencePlaintextVector64, u64),
+    }
+}
@@ -0,0 +1,96 @@
+use crate::backends::core::entities::{LweBootstrapKey32, LweBootstrapKey64};
+use crate::backends::core::private::crypto::bootstrap::StandardBootstrapKey as ImplStandardBootstrapKey;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::reference::implementation::engines::glwe_ciphertext_conversion::{
+    glwe_ciphertext_from_core_layout, glwe_ciphertext_to_core_layout,
+};
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceLweBootstrapKey, ReferenceLweBootstrapKey32, ReferenceLweBootstrapKey64,
+};
+use crate::specification::engines::{
+    LweBootstrapKeyConversionEngine, LweBootstrapKeyConversionError,
+};
+use crate::specification::entities::LweBootstrapKeyEntity;
+
+macro_rules! implement_conversions {
+    ($core: ident, $reference: ident) => {
+        /// # Description:
+        /// Implementation of [`LweBootstrapKeyConversionEngine`] for [`ReferenceEngine`] that
+        /// converts a core LWE bootstrap key, in the standard domain, into a reference one.
+        impl LweBootstrapKeyConversionEngine<$core, $reference> for ReferenceEngine {
+            fn convert_lwe_bootstrap_key(
+                &mut self,
+                input: &$core,
+            ) -> Result<$reference, LweBootstrapKeyConversionError<Self::EngineError>> {
+                Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+            }
+
+            unsafe fn convert_lwe_bootstrap_key_unchecked(&mut self, input: &$core) -> $reference {
+                // The core key stores a GGSW ciphertext per input key coefficient, made of a
+                // matrix per level from 1 to l, whose k + 1 rows are GLWE ciphertexts.
+                let polynomial_size = input.polynomial_size();
+                let glwe_size = input.glwe_dimension().to_glwe_size().0;
+                let row_size = glwe_size * polynomial_size.0;
+                let level_count = input.decomposition_level_count();
+                let ggsw_ciphertexts = input
+                    .0
+                    .as_tensor()
+                    .as_slice()
+                    .chunks(row_size * glwe_size * level_count.0)
+                    .map(|ggsw| {
+                        ggsw.chunks(row_size * glwe_size)
+                            .map(|matrix| {
+                                matrix
+                                    .chunks(row_size)
+                                    .map(|row| {
+                                        glwe_ciphertext_from_core_layout(row, polynomial_size)
+                                    })
+                                    .collect()
+                            })
+                            .collect()
+                    })
+                    .collect();
+                ReferenceLweBootstrapKey {
+                    ggsw_ciphertexts,
+                    glwe_dimension: input.glwe_dimension(),
+                    polynomial_size,
+                    decomposition_level_count: level_count,
+                    decomposition_base_log: input.decomposition_base_log(),
+                }
+            }
+        }
+
+        /// # Description:
+        /// Implementation of [`LweBootstrapKeyConversionEngine`] for [`ReferenceEngine`] that
+        /// converts a reference LWE bootstrap key into a core one, in the standard domain.
+        impl LweBootstrapKeyConversionEngine<$reference, $core> for ReferenceEngine {
+            fn convert_lwe_bootstrap_key(
+                &mut self,
+                input: &$reference,
+            ) -> Result<$core, LweBootstrapKeyConversionError<Self::EngineError>> {
+                Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+            }
+
+            unsafe fn convert_lwe_bootstrap_key_unchecked(&mut self, input: &$reference) -> $core {
+                let container = input
+                    .ggsw_ciphertexts
+                    .iter()
+                    .flatten()
+                    .flatten()
+                    .flat_map(glwe_ciphertext_to_core_layout)
+                    .collect::<Vec<_>>();
+                $core(ImplStandardBootstrapKey::from_container(
+                    container,
+                    input.glwe_dimension.to_glwe_size(),
+                    input.polynomial_size,
+                    input.decomposition_level_count,
+                    input.decomposition_base_log,
+                ))
+            }
+        }
+    };
+}
+
+implement_conversions!(LweBootstrapKey32, ReferenceLweBootstrapKey32);
+implement_conversions!(LweBootstrapKey64, ReferenceLweBootstrapKey64);
@@ -0,0 +1,100 @@
+use crate::backends::reference::implementation::algorithms::glwe_encrypt;
+use crate::backends::reference::implementation::engines::{ReferenceEngine, ReferenceError};
+use crate::backends::reference::implementation::entities::{
+    ReferenceGlweSecretKey, ReferenceLweBootstrapKey, ReferenceLweSecretKey, ReferenceScalar,
+};
+use crate::specification::engines::{LweBootstrapKeyCreationEngine, LweBootstrapKeyCreationError};
+use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+impl From<ReferenceError> for LweBootstrapKeyCreationError<ReferenceError> {
+    fn from(err: ReferenceError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationEngine`] for [`ReferenceEngine`]. Every coefficient
+/// $s\_i$ of the input key is encrypted as a GGSW ciphertext: for every level $j$ and every index
+/// $r$, a fresh GLWE encryption of zero is computed, and $s\_i \cdot q / B^j$ is added to the
+/// constant coefficient of its $r$-th polynomial.
+impl<T: ReferenceScalar>
+    LweBootstrapKeyCreationEngine<
+        ReferenceLweSecretKey<T>,
+        ReferenceGlweSecretKey<T>,
+        ReferenceLweBootstrapKey<T>,
+    > for ReferenceEngine
+{
+    fn create_lwe_bootstrap_key(
+        &mut self,
+        input_key: &ReferenceLweSecretKey<T>,
+        output_key: &ReferenceGlweSecretKey<T>,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<ReferenceLweBootstrapKey<T>, LweBootstrapKeyCreationError<Self::EngineError>> {
+        LweBootstrapKeyCreationError::perform_generic_checks(
+            decomposition_base_log,
+            decomposition_level_count,
+            T::BITS,
+        )?;
+        if !output_key.polynomial_size().0.is_power_of_two() {
+            return Err(ReferenceError::UnsupportedPolynomialSize.into());
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &ReferenceLweSecretKey<T>,
+        output_key: &ReferenceGlweSecretKey<T>,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> ReferenceLweBootstrapKey<T> {
+        let glwe_dimension = output_key.glwe_dimension();
+        let polynomial_size = output_key.polynomial_size();
+        let zeros = vec![T::ZERO; polynomial_size.0];
+        let ggsw_ciphertexts = input_key
+            .0
+            .iter()
+            .map(|s_i| {
+                (1..=decomposition_level_count.0)
+                    .map(|level| {
+                        let scaling = T::ONE << (T::BITS - decomposition_base_log.0 * level);
+                        let encoded = s_i.wrapping_mul(scaling);
+                        (0..=glwe_dimension.0)
+                            .map(|r| {
+                                let mut row =
+                                    glwe_encrypt(&mut self.generator, output_key, &zeros, noise);
+                                let polynomial = if r < glwe_dimension.0 {
+                                    &mut row.mask[r]
+                                } else {
+                                    &mut row.body
+                                };
+                                polynomial[0] = polynomial[0].wrapping_add(encoded);
+                                row
+                            })
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect();
+        ReferenceLweBootstrapKey {
+            ggsw_ciphertexts,
+            glwe_dimension,
+            polynomial_size,
+            decomposition_level_count,
+            decomposition_base_log,
+        }
+    }
+}
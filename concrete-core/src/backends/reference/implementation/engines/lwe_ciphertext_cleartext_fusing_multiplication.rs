@@ -0,0 +1,38 @@
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceCleartext, ReferenceLweCiphertext, ReferenceScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextCleartextFusingMultiplicationEngine,
+    LweCiphertextCleartextFusingMultiplicationError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for [`ReferenceEngine`].
+/// Every coefficient of the mask, and the body, are multiplied by the cleartext.
+impl<T: ReferenceScalar>
+    LweCiphertextCleartextFusingMultiplicationEngine<
+        ReferenceLweCiphertext<T>,
+        ReferenceCleartext<T>,
+    > for ReferenceEngine
+{
+    fn fuse_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input: &ReferenceCleartext<T>,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        unsafe { self.fuse_mul_lwe_ciphertext_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input: &ReferenceCleartext<T>,
+    ) {
+        for a_i in output.mask.iter_mut() {
+            *a_i = a_i.wrapping_mul(input.0);
+        }
+        output.body = output.body.wrapping_mul(input.0);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::backends::core::entities::{LweCiphertext32, LweCiphertext64};
+use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceLweCiphertext, ReferenceLweCiphertext32, ReferenceLweCiphertext64, ReferenceScalar,
+};
+use crate::specification::engines::{LweCiphertextConversionEngine, LweCiphertextConversionError};
+
+// Reads an LWE ciphertext stored as in the core backend: the mask, followed by the body.
+pub(super) fn lwe_ciphertext_from_core_layout<T: ReferenceScalar>(
+    data: &[T],
+) -> ReferenceLweCiphertext<T> {
+    let (body, mask) = data.split_last().unwrap();
+    ReferenceLweCiphertext {
+        mask: mask.to_vec(),
+        body: *body,
+    }
+}
+
+// Writes an LWE ciphertext as in the core backend: the mask, followed by the body.
+pub(super) fn lwe_ciphertext_to_core_layout<T: ReferenceScalar>(
+    ciphertext: &ReferenceLweCiphertext<T>,
+) -> Vec<T> {
+    let mut data = ciphertext.mask.clone();
+    data.push(ciphertext.body);
+    data
+}
+
+macro_rules! implement_conversions {
+    ($core: ident, $reference: ident) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextConversionEngine`] for [`ReferenceEngine`] that
+        /// converts a core LWE ciphertext into a reference one.
+        impl LweCiphertextConversionEngine<$core, $reference> for ReferenceEngine {
+            fn convert_lwe_ciphertext(
+                &mut self,
+                input: &$core,
+            ) -> Result<$reference, LweCiphertextConversionError<Self::EngineError>> {
+                Ok(unsafe { self.convert_lwe_ciphertext_unchecked(input) })
+            }
+
+            unsafe fn convert_lwe_ciphertext_unchecked(&mut self, input: &$core) -> $reference {
+                lwe_ciphertext_from_core_layout(input.0.as_tensor().as_slice())
+            }
+        }
+
+        /// # Description:
+        /// Implementation of [`LweCiphertextConversionEngine`] for [`ReferenceEngine`] that
+        /// converts a reference LWE ciphertext into a core one.
+        impl LweCiphertextConversionEngine<$reference, $core> for ReferenceEngine {
+            fn convert_lwe_ciphertext(
+                &mut self,
+                input: &$reference,
+            ) -> Result<$core, LweCiphertextConversionError<Self::EngineError>> {
+                Ok(unsafe { self.convert_lwe_ciphertext_unchecked(input) })
+            }
+
+            unsafe fn convert_lwe_ciphertext_unchecked(&mut self, input: &$reference) -> $core {
+                $core(ImplLweCiphertext::from_container(
+                    lwe_ciphertext_to_core_layout(input),
+                ))
+            }
+        }
+    };
+}
+
+implement_conversions!(LweCiphertext32, ReferenceLweCiphertext32);
+implement_conversions!(LweCiphertext64, ReferenceLweCiphertext64);
@@ -0,0 +1,33 @@
+use crate::backends::reference::implementation::algorithms::lwe_phase;
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceLweCiphertext, ReferenceLweSecretKey, ReferencePlaintext, ReferenceScalar,
+};
+use crate::specification::engines::{LweCiphertextDecryptionEngine, LweCiphertextDecryptionError};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`ReferenceEngine`]. The output
+/// plaintext is the phase $b - \sum\_i a\_i s\_i$ of the ciphertext, which still contains the noise.
+impl<T: ReferenceScalar>
+    LweCiphertextDecryptionEngine<
+        ReferenceLweSecretKey<T>,
+        ReferenceLweCiphertext<T>,
+        ReferencePlaintext<T>,
+    > for ReferenceEngine
+{
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &ReferenceLweSecretKey<T>,
+        input: &ReferenceLweCiphertext<T>,
+    ) -> Result<ReferencePlaintext<T>, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &ReferenceLweSecretKey<T>,
+        input: &ReferenceLweCiphertext<T>,
+    ) -> ReferencePlaintext<T> {
+        ReferencePlaintext(lwe_phase(key, input))
+    }
+}
@@ -0,0 +1,37 @@
+use crate::backends::reference::implementation::algorithms::vector_add;
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceLweCiphertext, ReferenceScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingAdditionEngine, LweCiphertextDiscardingAdditionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingAdditionEngine`] for [`ReferenceEngine`]. The
+/// masks and bodies are added coefficient-wise.
+impl<T: ReferenceScalar>
+    LweCiphertextDiscardingAdditionEngine<ReferenceLweCiphertext<T>, ReferenceLweCiphertext<T>>
+    for ReferenceEngine
+{
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input_1: &ReferenceLweCiphertext<T>,
+        input_2: &ReferenceLweCiphertext<T>,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        LweCiphertextDiscardingAdditionError::perform_generic_checks(output, input_1, input_2)?;
+        unsafe { self.discard_add_lwe_ciphertext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input_1: &ReferenceLweCiphertext<T>,
+        input_2: &ReferenceLweCiphertext<T>,
+    ) {
+        output.mask = vector_add(&input_1.mask, &input_2.mask);
+        output.body = input_1.body.wrapping_add(input_2.body);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::backends::reference::implementation::algorithms::{
+    external_product, modulus_switch, polynomial_monomial_mul, sample_extract, vector_add,
+    vector_sub,
+};
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceGlweCiphertext, ReferenceLweBootstrapKey, ReferenceLweCiphertext, ReferenceScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`ReferenceEngine`]. The
+/// textbook programmable bootstrap is performed:
+///
+/// + The body and mask of the input are switched to the modulus $2N$, giving $\tilde{b}$ and
+///   $\tilde{a}\_i$.
+/// + The accumulator is rotated by $X^{-\tilde{b}}$.
+/// + For every $i$, the accumulator $\mathsf{ACC}$ is replaced by the CMux
+///   $\mathsf{ACC} + \mathsf{bsk}\_i \boxdot (X^{\tilde{a}\_i} \mathsf{ACC} - \mathsf{ACC})$, where
+///   $\boxdot$ is the external product, computed with schoolbook polynomial multiplications.
+/// + The constant coefficient of the accumulator is extracted as an LWE ciphertext.
+impl<T: ReferenceScalar>
+    LweCiphertextDiscardingBootstrapEngine<
+        ReferenceLweBootstrapKey<T>,
+        ReferenceGlweCiphertext<T>,
+        ReferenceLweCiphertext<T>,
+        ReferenceLweCiphertext<T>,
+    > for ReferenceEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(64));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let noise = Variance(2_f64.powf(-60.));
+    ///
+    /// let mut engine = ReferenceEngine::new()?;
+    /// let lwe_sk: ReferenceLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: ReferenceGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk = engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// // The accumulator encodes the constant function 1 << 61.
+    /// let lut = engine.create_plaintext_vector(&vec![1_u64 << 61; poly_size.0])?;
+    /// let acc = engine.trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &lut)?;
+    /// let plaintext = engine.create_plaintext(&(1_u64 << 60))?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let zero = engine.create_plaintext(&0_u64)?;
+    /// let mut output =
+    ///     engine.trivially_encrypt_lwe_ciphertext(LweDimension(64).to_lwe_size(), &zero)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// let output_key = engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&output_key, &output)?;
+    /// let decrypted = engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!((decrypted.wrapping_add(1 << 58)) >> 59, 4);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lut)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input: &ReferenceLweCiphertext<T>,
+        acc: &ReferenceGlweCiphertext<T>,
+        bsk: &ReferenceLweBootstrapKey<T>,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        LweCiphertextDiscardingBootstrapError::perform_generic_checks(output, input, acc, bsk)?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input: &ReferenceLweCiphertext<T>,
+        acc: &ReferenceGlweCiphertext<T>,
+        bsk: &ReferenceLweBootstrapKey<T>,
+    ) {
+        let polynomial_size = bsk.polynomial_size.0;
+        let rotate = |glwe: &ReferenceGlweCiphertext<T>, degree: usize| ReferenceGlweCiphertext {
+            mask: glwe
+                .mask
+                .iter()
+                .map(|polynomial| polynomial_monomial_mul(polynomial, degree))
+                .collect(),
+            body: polynomial_monomial_mul(&glwe.body, degree),
+        };
+
+        // Multiplying by X^{-b} is the same as multiplying by X^{2N - b}, since X^{2N} = 1.
+        let body_degree = modulus_switch(input.body, polynomial_size);
+        let mut accumulator = rotate(
+            acc,
+            (2 * polynomial_size - body_degree) % (2 * polynomial_size),
+        );
+
+        for (a_i, ggsw_i) in input.mask.iter().zip(bsk.ggsw_ciphertexts.iter()) {
+            let rotated = rotate(&accumulator, modulus_switch(*a_i, polynomial_size));
+            let difference = ReferenceGlweCiphertext {
+                mask: rotated
+                    .mask
+                    .iter()
+                    .zip(accumulator.mask.iter())
+                    .map(|(r, a)| vector_sub(r, a))
+                    .collect(),
+                body: vector_sub(&rotated.body, &accumulator.body),
+            };
+            let product = external_product(
+                ggsw_i,
+                &difference,
+                bsk.decomposition_base_log.0,
+                bsk.decomposition_level_count.0,
+            );
+            accumulator = ReferenceGlweCiphertext {
+                mask: accumulator
+                    .mask
+                    .iter()
+                    .zip(product.mask.iter())
+                    .map(|(a, p)| vector_add(a, p))
+                    .collect(),
+                body: vector_add(&accumulator.body, &product.body),
+            };
+        }
+
+        *output = sample_extract(&accumulator);
+    }
+}
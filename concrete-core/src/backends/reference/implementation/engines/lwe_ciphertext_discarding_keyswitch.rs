@@ -0,0 +1,97 @@
+use crate::backends::reference::implementation::algorithms::{decompose, vector_sub};
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceLweCiphertext, ReferenceLweKeyswitchKey, ReferenceScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`ReferenceEngine`]. Every
+/// coefficient $a\_i$ of the input mask is decomposed as $\sum\_j d\_{i,j} q / B^j$, and the
+/// output is the trivial encryption of the input body, minus $\sum\_i \sum\_j d\_{i,j} \cdot
+/// \mathsf{ksk}\_{i,j}$, whose phase is close to $\sum\_i a\_i s\_i$.
+impl<T: ReferenceScalar>
+    LweCiphertextDiscardingKeyswitchEngine<
+        ReferenceLweKeyswitchKey<T>,
+        ReferenceLweCiphertext<T>,
+        ReferenceLweCiphertext<T>,
+    > for ReferenceEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(20);
+    /// let output_lwe_dimension = LweDimension(10);
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(8), DecompositionBaseLog(5));
+    /// let input = 3_u64 << 60;
+    /// let noise = Variance(2_f64.powf(-80.));
+    ///
+    /// let mut engine = ReferenceEngine::new()?;
+    /// let input_key: ReferenceLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: ReferenceLweSecretKey64 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let ksk = engine.create_lwe_keyswitch_key(&input_key, &output_key, dec_lc, dec_bl, noise)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 =
+    ///     engine.trivially_encrypt_lwe_ciphertext(output_lwe_dimension.to_lwe_size(), &plaintext)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &ksk)?;
+    /// #
+    /// let output = engine.decrypt_lwe_ciphertext(&output_key, &ciphertext_2)?;
+    /// let output = engine.retrieve_plaintext(&output)?;
+    /// assert!(((output.wrapping_sub(input) as i64) >> 40).abs() <= 1);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(ksk)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input: &ReferenceLweCiphertext<T>,
+        ksk: &ReferenceLweKeyswitchKey<T>,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input: &ReferenceLweCiphertext<T>,
+        ksk: &ReferenceLweKeyswitchKey<T>,
+    ) {
+        let mut mask = vec![T::ZERO; ksk.output_lwe_dimension.0];
+        let mut body = input.body;
+        for (a_i, ksk_i) in input.mask.iter().zip(ksk.ciphertexts.iter()) {
+            let digits = decompose(
+                *a_i,
+                ksk.decomposition_base_log.0,
+                ksk.decomposition_level_count.0,
+            );
+            for (d_ij, ksk_ij) in digits.iter().zip(ksk_i.iter()) {
+                let scaled_mask: Vec<T> =
+                    ksk_ij.mask.iter().map(|c| c.wrapping_mul(*d_ij)).collect();
+                mask = vector_sub(&mask, &scaled_mask);
+                body = body.wrapping_sub(ksk_ij.body.wrapping_mul(*d_ij));
+            }
+        }
+        output.mask = mask;
+        output.body = body;
+    }
+}
@@ -0,0 +1,64 @@
+use crate::backends::reference::implementation::algorithms::lwe_encrypt;
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceLweCiphertext, ReferenceLweSecretKey, ReferencePlaintext, ReferenceScalar,
+};
+use crate::specification::engines::{LweCiphertextEncryptionEngine, LweCiphertextEncryptionError};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`ReferenceEngine`]. The mask
+/// $(a\_0, \dots, a\_{n-1})$ is sampled uniformly, and the body is computed as
+/// $b = \sum\_i a\_i s\_i + m + e$, with a gaussian noise $e$.
+impl<T: ReferenceScalar>
+    LweCiphertextEncryptionEngine<
+        ReferenceLweSecretKey<T>,
+        ReferencePlaintext<T>,
+        ReferenceLweCiphertext<T>,
+    > for ReferenceEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = ReferenceEngine::new()?;
+    /// let key: ReferenceLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext.mask().len(), lwe_dimension.0);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &ReferenceLweSecretKey<T>,
+        input: &ReferencePlaintext<T>,
+        noise: Variance,
+    ) -> Result<ReferenceLweCiphertext<T>, LweCiphertextEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &ReferenceLweSecretKey<T>,
+        input: &ReferencePlaintext<T>,
+        noise: Variance,
+    ) -> ReferenceLweCiphertext<T> {
+        lwe_encrypt(&mut self.generator, key, input.0, noise)
+    }
+}
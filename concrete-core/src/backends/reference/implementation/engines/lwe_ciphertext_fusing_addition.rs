@@ -0,0 +1,35 @@
+use crate::backends::reference::implementation::algorithms::vector_add;
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceLweCiphertext, ReferenceScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextFusingAdditionEngine, LweCiphertextFusingAdditionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`ReferenceEngine`]. The mask and
+/// body of the input are added coefficient-wise to the ones of the output.
+impl<T: ReferenceScalar>
+    LweCiphertextFusingAdditionEngine<ReferenceLweCiphertext<T>, ReferenceLweCiphertext<T>>
+    for ReferenceEngine
+{
+    fn fuse_add_lwe_ciphertext(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input: &ReferenceLweCiphertext<T>,
+    ) -> Result<(), LweCiphertextFusingAdditionError<Self::EngineError>> {
+        LweCiphertextFusingAdditionError::perform_generic_checks(output, input)?;
+        unsafe { self.fuse_add_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut ReferenceLweCiphertext<T>,
+        input: &ReferenceLweCiphertext<T>,
+    ) {
+        output.mask = vector_add(&output.mask, &input.mask);
+        output.body = output.body.wrapping_add(input.body);
+    }
+}
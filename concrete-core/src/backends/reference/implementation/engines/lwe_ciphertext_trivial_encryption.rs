@@ -0,0 +1,36 @@
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceLweCiphertext, ReferencePlaintext, ReferenceScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextTrivialEncryptionEngine, LweCiphertextTrivialEncryptionError,
+};
+use concrete_commons::parameters::LweSize;
+
+/// # Description:
+/// Implementation of [`LweCiphertextTrivialEncryptionEngine`] for [`ReferenceEngine`]. The mask
+/// is zero, and the body is the plaintext.
+impl<T: ReferenceScalar>
+    LweCiphertextTrivialEncryptionEngine<ReferencePlaintext<T>, ReferenceLweCiphertext<T>>
+    for ReferenceEngine
+{
+    fn trivially_encrypt_lwe_ciphertext(
+        &mut self,
+        lwe_size: LweSize,
+        input: &ReferencePlaintext<T>,
+    ) -> Result<ReferenceLweCiphertext<T>, LweCiphertextTrivialEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.trivially_encrypt_lwe_ciphertext_unchecked(lwe_size, input) })
+    }
+
+    unsafe fn trivially_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        lwe_size: LweSize,
+        input: &ReferencePlaintext<T>,
+    ) -> ReferenceLweCiphertext<T> {
+        ReferenceLweCiphertext {
+            mask: vec![T::ZERO; lwe_size.to_lwe_dimension().0],
+            body: input.0,
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::backends::core::entities::{LweKeyswitchKey32, LweKeyswitchKey64};
+use crate::backends::core::private::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::reference::implementation::engines::lwe_ciphertext_conversion::{
+    lwe_ciphertext_from_core_layout, lwe_ciphertext_to_core_layout,
+};
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceLweKeyswitchKey, ReferenceLweKeyswitchKey32, ReferenceLweKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweKeyswitchKeyConversionEngine, LweKeyswitchKeyConversionError,
+};
+use crate::specification::entities::LweKeyswitchKeyEntity;
+
+macro_rules! implement_conversions {
+    ($core: ident, $reference: ident) => {
+        /// # Description:
+        /// Implementation of [`LweKeyswitchKeyConversionEngine`] for [`ReferenceEngine`] that
+        /// converts a core LWE keyswitch key into a reference one.
+        impl LweKeyswitchKeyConversionEngine<$core, $reference> for ReferenceEngine {
+            fn convert_lwe_keyswitch_key(
+                &mut self,
+                input: &$core,
+            ) -> Result<$reference, LweKeyswitchKeyConversionError<Self::EngineError>> {
+                Ok(unsafe { self.convert_lwe_keyswitch_key_unchecked(input) })
+            }
+
+            unsafe fn convert_lwe_keyswitch_key_unchecked(&mut self, input: &$core) -> $reference {
+                // The core key stores a block per input key coefficient, containing the
+                // ciphertexts of the levels 1 to l.
+                let lwe_size = input.output_lwe_dimension().0 + 1;
+                let level_count = input.decomposition_level_count();
+                let ciphertexts = input
+                    .0
+                    .as_tensor()
+                    .as_slice()
+                    .chunks(lwe_size * level_count.0)
+                    .map(|block| {
+                        block
+                            .chunks(lwe_size)
+                            .map(lwe_ciphertext_from_core_layout)
+                            .collect()
+                    })
+                    .collect();
+                ReferenceLweKeyswitchKey {
+                    ciphertexts,
+                    output_lwe_dimension: input.output_lwe_dimension(),
+                    decomposition_level_count: level_count,
+                    decomposition_base_log: input.decomposition_base_log(),
+                }
+            }
+        }
+
+        /// # Description:
+        /// Implementation of [`LweKeyswitchKeyConversionEngine`] for [`ReferenceEngine`] that
+        /// converts a reference LWE keyswitch key into a core one.
+        impl LweKeyswitchKeyConversionEngine<$reference, $core> for ReferenceEngine {
+            fn convert_lwe_keyswitch_key(
+                &mut self,
+                input: &$reference,
+            ) -> Result<$core, LweKeyswitchKeyConversionError<Self::EngineError>> {
+                Ok(unsafe { self.convert_lwe_keyswitch_key_unchecked(input) })
+            }
+
+            unsafe fn convert_lwe_keyswitch_key_unchecked(&mut self, input: &$reference) -> $core {
+                let container = input
+                    .ciphertexts
+                    .iter()
+                    .flatten()
+                    .flat_map(lwe_ciphertext_to_core_layout)
+                    .collect::<Vec<_>>();
+                $core(ImplLweKeyswitchKey::from_container(
+                    container,
+                    input.decomposition_base_log,
+                    input.decomposition_level_count,
+                    input.output_lwe_dimension,
+                ))
+            }
+        }
+    };
+}
+
+implement_conversions!(LweKeyswitchKey32, ReferenceLweKeyswitchKey32);
+implement_conversions!(LweKeyswitchKey64, ReferenceLweKeyswitchKey64);
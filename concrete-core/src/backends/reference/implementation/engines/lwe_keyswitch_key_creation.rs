@@ -0,0 +1,78 @@
+use crate::backends::reference::implementation::algorithms::lwe_encrypt;
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceLweKeyswitchKey, ReferenceLweSecretKey, ReferenceScalar,
+};
+use crate::specification::engines::{LweKeyswitchKeyCreationEngine, LweKeyswitchKeyCreationError};
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`ReferenceEngine`]. For every
+/// coefficient $s\_i$ of the input key and every level $j$, the plaintext $s\_i \cdot q / B^j$ is
+/// encrypted under the output key.
+impl<T: ReferenceScalar>
+    LweKeyswitchKeyCreationEngine<
+        ReferenceLweSecretKey<T>,
+        ReferenceLweSecretKey<T>,
+        ReferenceLweKeyswitchKey<T>,
+    > for ReferenceEngine
+{
+    fn create_lwe_keyswitch_key(
+        &mut self,
+        input_key: &ReferenceLweSecretKey<T>,
+        output_key: &ReferenceLweSecretKey<T>,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<ReferenceLweKeyswitchKey<T>, LweKeyswitchKeyCreationError<Self::EngineError>> {
+        LweKeyswitchKeyCreationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            T::BITS,
+        )?;
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &ReferenceLweSecretKey<T>,
+        output_key: &ReferenceLweSecretKey<T>,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> ReferenceLweKeyswitchKey<T> {
+        let ciphertexts = input_key
+            .0
+            .iter()
+            .map(|s_i| {
+                (1..=decomposition_level_count.0)
+                    .map(|level| {
+                        let scaling = T::ONE << (T::BITS - decomposition_base_log.0 * level);
+                        lwe_encrypt(
+                            &mut self.generator,
+                            output_key,
+                            s_i.wrapping_mul(scaling),
+                            noise,
+                        )
+                    })
+                    .collect()
+            })
+            .collect();
+        ReferenceLweKeyswitchKey {
+            ciphertexts,
+            output_lwe_dimension: output_key.lwe_dimension(),
+            decomposition_level_count,
+            decomposition_base_log,
+        }
+    }
+}
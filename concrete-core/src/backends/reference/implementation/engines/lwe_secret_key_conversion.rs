@@ -0,0 +1,47 @@
+use crate::backends::core::entities::{LweSecretKey32, LweSecretKey64};
+use crate::backends::core::private::crypto::secret::LweSecretKey as ImplLweSecretKey;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceLweSecretKey, ReferenceLweSecretKey32, ReferenceLweSecretKey64,
+};
+use crate::specification::engines::{LweSecretKeyConversionEngine, LweSecretKeyConversionError};
+
+macro_rules! implement_conversions {
+    ($core: ident, $reference: ident) => {
+        /// # Description:
+        /// Implementation of [`LweSecretKeyConversionEngine`] for [`ReferenceEngine`] that converts
+        /// a core LWE secret key into a reference one.
+        impl LweSecretKeyConversionEngine<$core, $reference> for ReferenceEngine {
+            fn convert_lwe_secret_key(
+                &mut self,
+                input: &$core,
+            ) -> Result<$reference, LweSecretKeyConversionError<Self::EngineError>> {
+                Ok(unsafe { self.convert_lwe_secret_key_unchecked(input) })
+            }
+
+            unsafe fn convert_lwe_secret_key_unchecked(&mut self, input: &$core) -> $reference {
+                ReferenceLweSecretKey(input.0.as_tensor().as_slice().to_vec())
+            }
+        }
+
+        /// # Description:
+        /// Implementation of [`LweSecretKeyConversionEngine`] for [`ReferenceEngine`] that converts
+        /// a reference LWE secret key into a core one.
+        impl LweSecretKeyConversionEngine<$reference, $core> for ReferenceEngine {
+            fn convert_lwe_secret_key(
+                &mut self,
+                input: &$reference,
+            ) -> Result<$core, LweSecretKeyConversionError<Self::EngineError>> {
+                Ok(unsafe { self.convert_lwe_secret_key_unchecked(input) })
+            }
+
+            unsafe fn convert_lwe_secret_key_unchecked(&mut self, input: &$reference) -> $core {
+                $core(ImplLweSecretKey::binary_from_container(input.0.clone()))
+            }
+        }
+    };
+}
+
+implement_conversions!(LweSecretKey32, ReferenceLweSecretKey32);
+implement_conversions!(LweSecretKey64, ReferenceLweSecretKey64);
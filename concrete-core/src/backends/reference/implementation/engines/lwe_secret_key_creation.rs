@@ -0,0 +1,30 @@
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferenceLweSecretKey, ReferenceScalar,
+};
+use crate::specification::engines::{LweSecretKeyCreationEngine, LweSecretKeyCreationError};
+use concrete_commons::parameters::LweDimension;
+
+/// # Description:
+/// Implementation of [`LweSecretKeyCreationEngine`] for [`ReferenceEngine`]. Every coefficient
+/// of the key is a uniformly random bit.
+impl<T: ReferenceScalar> LweSecretKeyCreationEngine<ReferenceLweSecretKey<T>> for ReferenceEngine {
+    fn create_lwe_secret_key(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<ReferenceLweSecretKey<T>, LweSecretKeyCreationError<Self::EngineError>> {
+        LweSecretKeyCreationError::perform_generic_checks(lwe_dimension)?;
+        Ok(unsafe { self.create_lwe_secret_key_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn create_lwe_secret_key_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> ReferenceLweSecretKey<T> {
+        ReferenceLweSecretKey(
+            (0..lwe_dimension.0)
+                .map(|_| self.generator.random_uniform_binary())
+                .collect(),
+        )
+    }
+}
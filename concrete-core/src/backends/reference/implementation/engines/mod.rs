@@ -0,0 +1,97 @@
+//! A module containing the [engines](crate::specification::engines) exposed by the reference
+//! backend.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::backends::core::private::math::random::RandomGenerator;
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+
+/// The error which can occur in the execution of FHE operations, due to the reference
+/// implementation.
+#[derive(Debug)]
+pub enum ReferenceError {
+    UnsupportedPolynomialSize,
+}
+
+impl Display for ReferenceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReferenceError::UnsupportedPolynomialSize => {
+                write!(
+                    f,
+                    "The Reference Backend only supports polynomial sizes which are powers of two."
+                )
+            }
+        }
+    }
+}
+
+impl Error for ReferenceError {}
+
+/// The main engine exposed by the reference backend.
+///
+/// The engine only holds the random generator used to sample the secret keys, the masks and the
+/// noises.
+pub struct ReferenceEngine {
+    pub(crate) generator: RandomGenerator,
+}
+
+impl ReferenceEngine {
+    /// Creates a new engine, whose random generator is seeded with the given value.
+    ///
+    /// No entropy is drawn from the platform, and every sample of the engine is determined by the
+    /// seed. This constructor is only meant for testing purpose.
+    pub fn new_seeded(seed: u128) -> ReferenceEngine {
+        ReferenceEngine {
+            generator: RandomGenerator::new(Some(seed)),
+        }
+    }
+}
+
+impl AbstractEngineSeal for ReferenceEngine {}
+
+impl AbstractEngine for ReferenceEngine {
+    type EngineError = ReferenceError;
+
+    fn new() -> Result<Self, Self::EngineError> {
+        Ok(ReferenceEngine {
+            generator: RandomGenerator::new(None),
+        })
+    }
+}
+
+mod cleartext_creation;
+mod cleartext_retrieval;
+mod destruction;
+mod ggsw_ciphertext_conversion;
+mod glwe_ciphertext_conversion;
+mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_trivial_encryption;
+mod glwe_secret_key_conversion;
+mod glwe_secret_key_creation;
+mod glwe_secret_key_to_lwe_secret_key_transmutation;
+mod implementations;
+mod lwe_bootstrap_key_conversion;
+mod lwe_bootstrap_key_creation;
+mod lwe_ciphertext_cleartext_fusing_multiplication;
+mod lwe_ciphertext_conversion;
+mod lwe_ciphertext_decryption;
+mod lwe_ciphertext_discarding_addition;
+mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_encryption;
+mod lwe_ciphertext_fusing_addition;
+mod lwe_ciphertext_trivial_encryption;
+mod lwe_keyswitch_key_conversion;
+mod lwe_keyswitch_key_creation;
+mod lwe_secret_key_conversion;
+mod lwe_secret_key_creation;
+mod plaintext_creation;
+mod plaintext_retrieval;
+mod plaintext_vector_creation;
+mod plaintext_vector_retrieval;
+
+pub use implementations::IMPLEMENTATIONS;
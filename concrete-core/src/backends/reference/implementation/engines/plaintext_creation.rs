@@ -0,0 +1,18 @@
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{ReferencePlaintext, ReferenceScalar};
+use crate::specification::engines::{PlaintextCreationEngine, PlaintextCreationError};
+
+/// # Description:
+/// Implementation of [`PlaintextCreationEngine`] for [`ReferenceEngine`].
+impl<T: ReferenceScalar> PlaintextCreationEngine<T, ReferencePlaintext<T>> for ReferenceEngine {
+    fn create_plaintext(
+        &mut self,
+        input: &T,
+    ) -> Result<ReferencePlaintext<T>, PlaintextCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_plaintext_unchecked(input) })
+    }
+
+    unsafe fn create_plaintext_unchecked(&mut self, input: &T) -> ReferencePlaintext<T> {
+        ReferencePlaintext(*input)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{ReferencePlaintext, ReferenceScalar};
+use crate::specification::engines::{PlaintextRetrievalEngine, PlaintextRetrievalError};
+
+/// # Description:
+/// Implementation of [`PlaintextRetrievalEngine`] for [`ReferenceEngine`].
+impl<T: ReferenceScalar> PlaintextRetrievalEngine<ReferencePlaintext<T>, T> for ReferenceEngine {
+    fn retrieve_plaintext(
+        &mut self,
+        plaintext: &ReferencePlaintext<T>,
+    ) -> Result<T, PlaintextRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_plaintext_unchecked(plaintext) })
+    }
+
+    unsafe fn retrieve_plaintext_unchecked(&mut self, plaintext: &ReferencePlaintext<T>) -> T {
+        plaintext.0
+    }
+}
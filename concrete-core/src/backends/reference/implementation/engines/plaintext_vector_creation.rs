@@ -0,0 +1,26 @@
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferencePlaintextVector, ReferenceScalar,
+};
+use crate::specification::engines::{PlaintextVectorCreationEngine, PlaintextVectorCreationError};
+
+/// # Description:
+/// Implementation of [`PlaintextVectorCreationEngine`] for [`ReferenceEngine`].
+impl<T: ReferenceScalar> PlaintextVectorCreationEngine<T, ReferencePlaintextVector<T>>
+    for ReferenceEngine
+{
+    fn create_plaintext_vector(
+        &mut self,
+        input: &[T],
+    ) -> Result<ReferencePlaintextVector<T>, PlaintextVectorCreationError<Self::EngineError>> {
+        PlaintextVectorCreationError::perform_generic_checks(input)?;
+        Ok(unsafe { self.create_plaintext_vector_unchecked(input) })
+    }
+
+    unsafe fn create_plaintext_vector_unchecked(
+        &mut self,
+        input: &[T],
+    ) -> ReferencePlaintextVector<T> {
+        ReferencePlaintextVector(input.to_vec())
+    }
+}
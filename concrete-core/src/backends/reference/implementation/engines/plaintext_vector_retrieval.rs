@@ -0,0 +1,27 @@
+use crate::backends::reference::implementation::engines::ReferenceEngine;
+use crate::backends::reference::implementation::entities::{
+    ReferencePlaintextVector, ReferenceScalar,
+};
+use crate::specification::engines::{
+    PlaintextVectorRetrievalEngine, PlaintextVectorRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`PlaintextVectorRetrievalEngine`] for [`ReferenceEngine`].
+impl<T: ReferenceScalar> PlaintextVectorRetrievalEngine<ReferencePlaintextVector<T>, T>
+    for ReferenceEngine
+{
+    fn retrieve_plaintext_vector(
+        &mut self,
+        plaintext: &ReferencePlaintextVector<T>,
+    ) -> Result<Vec<T>, PlaintextVectorRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_plaintext_vector_unchecked(plaintext) })
+    }
+
+    unsafe fn retrieve_plaintext_vector_unchecked(
+        &mut self,
+        plaintext: &ReferencePlaintextVector<T>,
+    ) -> Vec<T> {
+        plaintext.0.clone()
+    }
+}
@@ -0,0 +1,17 @@
+use crate::backends::reference::implementation::entities::ReferenceScalar;
+use crate::specification::entities::markers::CleartextKind;
+use crate::specification::entities::{AbstractEntity, CleartextEntity};
+
+/// A structure representing a reference cleartext.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceCleartext<T: ReferenceScalar>(pub(crate) T);
+impl<T: ReferenceScalar> AbstractEntity for ReferenceCleartext<T> {
+    type Kind = CleartextKind;
+}
+impl<T: ReferenceScalar> CleartextEntity for ReferenceCleartext<T> {}
+
+/// A reference cleartext with 32 bits of precision.
+pub type ReferenceCleartext32 = ReferenceCleartext<u32>;
+
+/// A reference cleartext with 64 bits of precision.
+pub type ReferenceCleartext64 = ReferenceCleartext<u64>;
@@ -0,0 +1,58 @@
+use crate::backends::reference::implementation::entities::{
+    ReferenceGlweCiphertext, ReferenceScalar,
+};
+use crate::specification::entities::markers::{BinaryKeyDistribution, GgswCiphertextKind};
+use crate::specification::entities::{AbstractEntity, GgswCiphertextEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A structure representing a reference GGSW ciphertext.
+///
+/// The ciphertext is stored as its rows, laid out as the GGSW ciphertexts of a
+/// [`ReferenceLweBootstrapKey`](super::ReferenceLweBootstrapKey): for every decomposition level
+/// $j$ from $1$ to $l$, and every index $r$ from $0$ to $k$, the GLWE ciphertext `rows[j - 1][r]`
+/// encrypts zero, with $m \cdot q / B^j$ added to the constant coefficient of its $r$-th
+/// polynomial.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceGgswCiphertext<T: ReferenceScalar> {
+    pub(crate) rows: Vec<Vec<ReferenceGlweCiphertext<T>>>,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+}
+impl<T: ReferenceScalar> AbstractEntity for ReferenceGgswCiphertext<T> {
+    type Kind = GgswCiphertextKind;
+}
+impl<T: ReferenceScalar> GgswCiphertextEntity for ReferenceGgswCiphertext<T> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        DecompositionLevelCount(self.rows.len())
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
+
+impl<T: ReferenceScalar> ReferenceGgswCiphertext<T> {
+    /// Returns the rows of the ciphertext, grouped by decomposition level.
+    pub fn rows(&self) -> &[Vec<ReferenceGlweCiphertext<T>>] {
+        &self.rows
+    }
+}
+
+/// A reference GGSW ciphertext with 32 bits of precision.
+pub type ReferenceGgswCiphertext32 = ReferenceGgswCiphertext<u32>;
+
+/// A reference GGSW ciphertext with 64 bits of precision.
+pub type ReferenceGgswCiphertext64 = ReferenceGgswCiphertext<u64>;
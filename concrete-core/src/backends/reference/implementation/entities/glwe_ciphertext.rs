@@ -0,0 +1,59 @@
+use crate::backends::reference::implementation::entities::ReferenceScalar;
+use crate::specification::entities::markers::{
+    BinaryKeyDistribution, GlweCiphertextKind, NegacyclicRing,
+};
+use crate::specification::entities::{AbstractEntity, GlweCiphertextEntity};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A structure representing a reference GLWE ciphertext.
+///
+/// The ciphertext holds its mask polynomials $(A\_0, \dots, A\_{k-1})$ and its body polynomial
+/// $B$, with coefficients stored by increasing degree, such that its phase is
+/// $B - \sum\_i A\_i S\_i$.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceGlweCiphertext<T: ReferenceScalar> {
+    pub(crate) mask: Vec<Vec<T>>,
+    pub(crate) body: Vec<T>,
+}
+impl<T: ReferenceScalar> AbstractEntity for ReferenceGlweCiphertext<T> {
+    type Kind = GlweCiphertextKind;
+}
+impl<T: ReferenceScalar> GlweCiphertextEntity for ReferenceGlweCiphertext<T> {
+    type KeyDistribution = BinaryKeyDistribution;
+    type RingStructure = NegacyclicRing;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        GlweDimension(self.mask.len())
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.body.len())
+    }
+}
+
+impl<T: ReferenceScalar> ReferenceGlweCiphertext<T> {
+    /// Returns the mask polynomials of the ciphertext.
+    pub fn mask(&self) -> &[Vec<T>] {
+        &self.mask
+    }
+
+    /// Returns the body polynomial of the ciphertext.
+    pub fn body(&self) -> &[T] {
+        &self.body
+    }
+
+    /// Returns an iterator over the $k+1$ polynomials of the ciphertext: the mask polynomials,
+    /// followed by the body.
+    pub(crate) fn polynomials(&self) -> impl Iterator<Item = &[T]> {
+        self.mask
+            .iter()
+            .map(Vec::as_slice)
+            .chain(std::iter::once(self.body.as_slice()))
+    }
+}
+
+/// A reference GLWE ciphertext with 32 bits of precision.
+pub type ReferenceGlweCiphertext32 = ReferenceGlweCiphertext<u32>;
+
+/// A reference GLWE ciphertext with 64 bits of precision.
+pub type ReferenceGlweCiphertext64 = ReferenceGlweCiphertext<u64>;
@@ -0,0 +1,34 @@
+use crate::backends::reference::implementation::entities::ReferenceScalar;
+use crate::specification::entities::markers::{BinaryKeyDistribution, GlweSecretKeyKind};
+use crate::specification::entities::{AbstractEntity, GlweSecretKeyEntity};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A structure representing a reference GLWE secret key.
+///
+/// The key holds its $k$ polynomials $S\_0, \dots, S\_{k-1}$, with binary coefficients stored by
+/// increasing degree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceGlweSecretKey<T: ReferenceScalar> {
+    pub(crate) polynomials: Vec<Vec<T>>,
+    pub(crate) polynomial_size: PolynomialSize,
+}
+impl<T: ReferenceScalar> AbstractEntity for ReferenceGlweSecretKey<T> {
+    type Kind = GlweSecretKeyKind;
+}
+impl<T: ReferenceScalar> GlweSecretKeyEntity for ReferenceGlweSecretKey<T> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        GlweDimension(self.polynomials.len())
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+}
+
+/// A reference GLWE secret key with 32 bits of precision.
+pub type ReferenceGlweSecretKey32 = ReferenceGlweSecretKey<u32>;
+
+/// A reference GLWE secret key with 64 bits of precision.
+pub type ReferenceGlweSecretKey64 = ReferenceGlweSecretKey<u64>;
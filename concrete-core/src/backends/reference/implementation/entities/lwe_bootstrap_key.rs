@@ -0,0 +1,58 @@
+use crate::backends::reference::implementation::entities::{
+    ReferenceGlweCiphertext, ReferenceScalar,
+};
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweBootstrapKeyKind};
+use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// A structure representing a reference LWE bootstrap key.
+///
+/// The key holds a GGSW encryption of every coefficient $s\_i$ of the input LWE key, under the
+/// output GLWE key, in the coefficient domain. A GGSW ciphertext is stored as its rows: for every
+/// decomposition level $j$ from $1$ to $l$, and every index $r$ from $0$ to $k$, the GLWE
+/// ciphertext `ggsw_ciphertexts[i][j - 1][r]` encrypts zero, with $s\_i \cdot q / B^j$ added to
+/// the constant coefficient of its $r$-th polynomial (the mask polynomials come first, and the
+/// body last).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceLweBootstrapKey<T: ReferenceScalar> {
+    pub(crate) ggsw_ciphertexts: Vec<Vec<Vec<ReferenceGlweCiphertext<T>>>>,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+}
+impl<T: ReferenceScalar> AbstractEntity for ReferenceLweBootstrapKey<T> {
+    type Kind = LweBootstrapKeyKind;
+}
+impl<T: ReferenceScalar> LweBootstrapKeyEntity for ReferenceLweBootstrapKey<T> {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.ggsw_ciphertexts.len())
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+}
+
+/// A reference LWE bootstrap key with 32 bits of precision.
+pub type ReferenceLweBootstrapKey32 = ReferenceLweBootstrapKey<u32>;
+
+/// A reference LWE bootstrap key with 64 bits of precision.
+pub type ReferenceLweBootstrapKey64 = ReferenceLweBootstrapKey<u64>;
@@ -0,0 +1,42 @@
+use crate::backends::reference::implementation::entities::ReferenceScalar;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweCiphertextKind};
+use crate::specification::entities::{AbstractEntity, LweCiphertextEntity};
+use concrete_commons::parameters::LweDimension;
+
+/// A structure representing a reference LWE ciphertext.
+///
+/// The ciphertext holds its mask $(a\_0, \dots, a\_{n-1})$ and its body $b$, such that its phase
+/// is $b - \sum\_i a\_i s\_i$.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceLweCiphertext<T: ReferenceScalar> {
+    pub(crate) mask: Vec<T>,
+    pub(crate) body: T,
+}
+impl<T: ReferenceScalar> AbstractEntity for ReferenceLweCiphertext<T> {
+    type Kind = LweCiphertextKind;
+}
+impl<T: ReferenceScalar> LweCiphertextEntity for ReferenceLweCiphertext<T> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.mask.len())
+    }
+}
+
+impl<T: ReferenceScalar> ReferenceLweCiphertext<T> {
+    /// Returns the mask of the ciphertext.
+    pub fn mask(&self) -> &[T] {
+        &self.mask
+    }
+
+    /// Returns the body of the ciphertext.
+    pub fn body(&self) -> T {
+        self.body
+    }
+}
+
+/// A reference LWE ciphertext with 32 bits of precision.
+pub type ReferenceLweCiphertext32 = ReferenceLweCiphertext<u32>;
+
+/// A reference LWE ciphertext with 64 bits of precision.
+pub type ReferenceLweCiphertext64 = ReferenceLweCiphertext<u64>;
@@ -0,0 +1,48 @@
+use crate::backends::reference::implementation::entities::{
+    ReferenceLweCiphertext, ReferenceScalar,
+};
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweKeyswitchKeyKind};
+use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyEntity};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+/// A structure representing a reference LWE keyswitch key.
+///
+/// For every coefficient $s\_i$ of the input key, and every decomposition level $j$ from $1$ to
+/// $l$, the key holds an LWE encryption of $s\_i \cdot q / B^j$ under the output key, stored in
+/// `ciphertexts[i][j - 1]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceLweKeyswitchKey<T: ReferenceScalar> {
+    pub(crate) ciphertexts: Vec<Vec<ReferenceLweCiphertext<T>>>,
+    pub(crate) output_lwe_dimension: LweDimension,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+}
+impl<T: ReferenceScalar> AbstractEntity for ReferenceLweKeyswitchKey<T> {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl<T: ReferenceScalar> LweKeyswitchKeyEntity for ReferenceLweKeyswitchKey<T> {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.ciphertexts.len())
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.output_lwe_dimension
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
+
+/// A reference LWE keyswitch key with 32 bits of precision.
+pub type ReferenceLweKeyswitchKey32 = ReferenceLweKeyswitchKey<u32>;
+
+/// A reference LWE keyswitch key with 64 bits of precision.
+pub type ReferenceLweKeyswitchKey64 = ReferenceLweKeyswitchKey<u64>;
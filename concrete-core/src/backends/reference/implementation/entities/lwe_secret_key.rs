@@ -0,0 +1,26 @@
+use crate::backends::reference::implementation::entities::ReferenceScalar;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweSecretKeyKind};
+use crate::specification::entities::{AbstractEntity, LweSecretKeyEntity};
+use concrete_commons::parameters::LweDimension;
+
+/// A structure representing a reference LWE secret key.
+///
+/// The key holds its binary coefficients $s\_0, \dots, s\_{n-1}$.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceLweSecretKey<T: ReferenceScalar>(pub(crate) Vec<T>);
+impl<T: ReferenceScalar> AbstractEntity for ReferenceLweSecretKey<T> {
+    type Kind = LweSecretKeyKind;
+}
+impl<T: ReferenceScalar> LweSecretKeyEntity for ReferenceLweSecretKey<T> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.len())
+    }
+}
+
+/// A reference LWE secret key with 32 bits of precision.
+pub type ReferenceLweSecretKey32 = ReferenceLweSecretKey<u32>;
+
+/// A reference LWE secret key with 64 bits of precision.
+pub type ReferenceLweSecretKey64 = ReferenceLweSecretKey<u64>;
@@ -0,0 +1,37 @@
+//! A module containing all the [entities](crate::specification::entities) exposed by the
+//! reference backend.
+//!
+//! The reference entities are generic over the integer type used to represent the torus, and
+//! aliases are provided for 32 and 64 bits of precision. Their content is stored in plain vectors,
+//! and all the arithmetic on the torus is performed with wrapping operations.
+
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use concrete_commons::numeric::CastInto;
+
+mod cleartext;
+mod ggsw_ciphertext;
+mod glwe_ciphertext;
+mod glwe_secret_key;
+mod lwe_bootstrap_key;
+mod lwe_ciphertext;
+mod lwe_keyswitch_key;
+mod lwe_secret_key;
+mod plaintext;
+mod plaintext_vector;
+
+pub use cleartext::*;
+pub use ggsw_ciphertext::*;
+pub use glwe_ciphertext::*;
+pub use glwe_secret_key::*;
+pub use lwe_bootstrap_key::*;
+pub use lwe_ciphertext::*;
+pub use lwe_keyswitch_key::*;
+pub use lwe_secret_key::*;
+pub use plaintext::*;
+pub use plaintext_vector::*;
+
+/// A trait for the integer types which can be used to represent the torus in the reference
+/// entities.
+pub trait ReferenceScalar: UnsignedTorus + CastInto<usize> {}
+impl ReferenceScalar for u32 {}
+impl ReferenceScalar for u64 {}
@@ -0,0 +1,17 @@
+use crate::backends::reference::implementation::entities::ReferenceScalar;
+use crate::specification::entities::markers::PlaintextKind;
+use crate::specification::entities::{AbstractEntity, PlaintextEntity};
+
+/// A structure representing a reference plaintext.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferencePlaintext<T: ReferenceScalar>(pub(crate) T);
+impl<T: ReferenceScalar> AbstractEntity for ReferencePlaintext<T> {
+    type Kind = PlaintextKind;
+}
+impl<T: ReferenceScalar> PlaintextEntity for ReferencePlaintext<T> {}
+
+/// A reference plaintext with 32 bits of precision.
+pub type ReferencePlaintext32 = ReferencePlaintext<u32>;
+
+/// A reference plaintext with 64 bits of precision.
+pub type ReferencePlaintext64 = ReferencePlaintext<u64>;
@@ -0,0 +1,22 @@
+use crate::backends::reference::implementation::entities::ReferenceScalar;
+use crate::specification::entities::markers::PlaintextVectorKind;
+use crate::specification::entities::{AbstractEntity, PlaintextVectorEntity};
+use concrete_commons::parameters::PlaintextCount;
+
+/// A structure representing a vector of reference plaintexts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferencePlaintextVector<T: ReferenceScalar>(pub(crate) Vec<T>);
+impl<T: ReferenceScalar> AbstractEntity for ReferencePlaintextVector<T> {
+    type Kind = PlaintextVectorKind;
+}
+impl<T: ReferenceScalar> PlaintextVectorEntity for ReferencePlaintextVector<T> {
+    fn plaintext_count(&self) -> PlaintextCount {
+        PlaintextCount(self.0.len())
+    }
+}
+
+/// A vector of reference plaintexts with 32 bits of precision.
+pub type ReferencePlaintextVector32 = ReferencePlaintextVector<u32>;
+
+/// A vector of reference plaintexts with 64 bits of precision.
+pub type ReferencePlaintextVector64 = ReferencePlaintextVector<u64>;
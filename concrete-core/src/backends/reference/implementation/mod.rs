@@ -0,0 +1,3 @@
+mod algorithms;
+pub mod engines;
+pub mod entities;
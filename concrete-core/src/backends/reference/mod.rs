@@ -0,0 +1,18 @@
+//! A module containing the reference backend implementation.
+//!
+//! This module contains an implementation of the main operators of the concrete specification,
+//! written with the most straightforward algorithms: polynomials are multiplied with the schoolbook
+//! algorithm instead of an FFT, the blind rotation performs the textbook CMux and external
+//! product, and the gadget decomposition is computed digit by digit. The entities are plain
+//! vectors of torus elements, which can be inspected directly.
+//!
+//! The aim of this backend is to be readable and obviously correct, so that it can be audited, and
+//! used as a ground truth by the fixtures of the other backends. Performance is explicitly not a
+//! goal: a bootstrap takes orders of magnitude longer than with the `core` backend.
+//!
+//! The reference entities can be converted back and forth with the entities of the `core` backend,
+//! with the `*ConversionEngine` traits.
+
+mod implementation;
+
+pub use implementation::{engines, entities};
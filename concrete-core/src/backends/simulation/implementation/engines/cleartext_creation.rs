@@ -0,0 +1,18 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{SimulatedCleartext, SimulationScalar};
+use crate::specification::engines::{CleartextCreationEngine, CleartextCreationError};
+
+/// # Description:
+/// Implementation of [`CleartextCreationEngine`] for [`SimulationEngine`].
+impl<T: SimulationScalar> CleartextCreationEngine<T, SimulatedCleartext<T>> for SimulationEngine {
+    fn create_cleartext(
+        &mut self,
+        input: &T,
+    ) -> Result<SimulatedCleartext<T>, CleartextCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_cleartext_unchecked(input) })
+    }
+
+    unsafe fn create_cleartext_unchecked(&mut self, input: &T) -> SimulatedCleartext<T> {
+        SimulatedCleartext(*input)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{SimulatedCleartext, SimulationScalar};
+use crate::specification::engines::{CleartextRetrievalEngine, CleartextRetrievalError};
+
+/// # Description:
+/// Implementation of [`CleartextRetrievalEngine`] for [`SimulationEngine`].
+impl<T: SimulationScalar> CleartextRetrievalEngine<SimulatedCleartext<T>, T> for SimulationEngine {
+    fn retrieve_cleartext(
+        &mut self,
+        cleartext: &SimulatedCleartext<T>,
+    ) -> Result<T, CleartextRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_cleartext_unchecked(cleartext) })
+    }
+
+    unsafe fn retrieve_cleartext_unchecked(&mut self, cleartext: &SimulatedCleartext<T>) -> T {
+        cleartext.0
+    }
+}
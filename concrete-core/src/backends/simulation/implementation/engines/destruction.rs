@@ -0,0 +1,38 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedCleartext, SimulatedGlweCiphertext, SimulatedGlweSecretKey, SimulatedLweBootstrapKey,
+    SimulatedLweCiphertext, SimulatedLweCiphertextVector, SimulatedLweKeyswitchKey,
+    SimulatedLweSecretKey, SimulatedPlaintext, SimulatedPlaintextVector, SimulationScalar,
+};
+use crate::specification::engines::{DestructionEngine, DestructionError};
+
+macro_rules! implement_destruction {
+    ($($entity: ident),*) => {
+        $(
+            impl<T: SimulationScalar> DestructionEngine<$entity<T>> for SimulationEngine {
+                fn destroy(
+                    &mut self,
+                    entity: $entity<T>,
+                ) -> Result<(), DestructionError<Self::EngineError>> {
+                    unsafe { self.destroy_unchecked(entity) };
+                    Ok(())
+                }
+
+                unsafe fn destroy_unchecked(&mut self, _entity: $entity<T>) {}
+            }
+        )*
+    };
+}
+
+implement_destruction!(
+    SimulatedCleartext,
+    SimulatedGlweCiphertext,
+    SimulatedGlweSecretKey,
+    SimulatedLweBootstrapKey,
+    SimulatedLweCiphertext,
+    SimulatedLweCiphertextVector,
+    SimulatedLweKeyswitchKey,
+    SimulatedLweSecretKey,
+    SimulatedPlaintext,
+    SimulatedPlaintextVector
+);
@@ -0,0 +1,35 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedGlweCiphertext, SimulatedGlweSecretKey, SimulatedPlaintextVector, SimulationScalar,
+};
+use crate::specification::engines::{
+    GlweCiphertextDecryptionEngine, GlweCiphertextDecryptionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDecryptionEngine`] for [`SimulationEngine`]. The output
+/// plaintexts are the exact plaintexts held by the ciphertext, without noise.
+impl<T: SimulationScalar>
+    GlweCiphertextDecryptionEngine<
+        SimulatedGlweSecretKey<T>,
+        SimulatedGlweCiphertext<T>,
+        SimulatedPlaintextVector<T>,
+    > for SimulationEngine
+{
+    fn decrypt_glwe_ciphertext(
+        &mut self,
+        key: &SimulatedGlweSecretKey<T>,
+        input: &SimulatedGlweCiphertext<T>,
+    ) -> Result<SimulatedPlaintextVector<T>, GlweCiphertextDecryptionError<Self::EngineError>> {
+        GlweCiphertextDecryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_glwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        _key: &SimulatedGlweSecretKey<T>,
+        input: &SimulatedGlweCiphertext<T>,
+    ) -> SimulatedPlaintextVector<T> {
+        SimulatedPlaintextVector(input.plaintexts.clone())
+    }
+}
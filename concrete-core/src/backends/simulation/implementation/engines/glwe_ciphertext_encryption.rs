@@ -0,0 +1,42 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedGlweCiphertext, SimulatedGlweSecretKey, SimulatedPlaintextVector, SimulationScalar,
+};
+use crate::specification::engines::{
+    GlweCiphertextEncryptionEngine, GlweCiphertextEncryptionError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`SimulationEngine`].
+impl<T: SimulationScalar>
+    GlweCiphertextEncryptionEngine<
+        SimulatedGlweSecretKey<T>,
+        SimulatedPlaintextVector<T>,
+        SimulatedGlweCiphertext<T>,
+    > for SimulationEngine
+{
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &SimulatedGlweSecretKey<T>,
+        input: &SimulatedPlaintextVector<T>,
+        noise: Variance,
+    ) -> Result<SimulatedGlweCiphertext<T>, GlweCiphertextEncryptionError<Self::EngineError>> {
+        GlweCiphertextEncryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &SimulatedGlweSecretKey<T>,
+        input: &SimulatedPlaintextVector<T>,
+        noise: Variance,
+    ) -> SimulatedGlweCiphertext<T> {
+        SimulatedGlweCiphertext {
+            glwe_dimension: key.glwe_dimension(),
+            plaintexts: input.0.clone(),
+            variance: noise,
+        }
+    }
+}
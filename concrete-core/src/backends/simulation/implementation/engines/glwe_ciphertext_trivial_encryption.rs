@@ -0,0 +1,38 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedGlweCiphertext, SimulatedPlaintextVector, SimulationScalar,
+};
+use crate::specification::engines::{
+    GlweCiphertextTrivialEncryptionEngine, GlweCiphertextTrivialEncryptionError,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::GlweSize;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextTrivialEncryptionEngine`] for [`SimulationEngine`]. The
+/// output ciphertext holds the input plaintexts, and a null variance.
+impl<T: SimulationScalar>
+    GlweCiphertextTrivialEncryptionEngine<SimulatedPlaintextVector<T>, SimulatedGlweCiphertext<T>>
+    for SimulationEngine
+{
+    fn trivially_encrypt_glwe_ciphertext(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &SimulatedPlaintextVector<T>,
+    ) -> Result<SimulatedGlweCiphertext<T>, GlweCiphertextTrivialEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.trivially_encrypt_glwe_ciphertext_unchecked(glwe_size, input) })
+    }
+
+    unsafe fn trivially_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &SimulatedPlaintextVector<T>,
+    ) -> SimulatedGlweCiphertext<T> {
+        SimulatedGlweCiphertext {
+            glwe_dimension: glwe_size.to_glwe_dimension(),
+            plaintexts: input.0.clone(),
+            variance: Variance(0.),
+        }
+    }
+}
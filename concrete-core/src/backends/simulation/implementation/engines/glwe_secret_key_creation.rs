@@ -0,0 +1,34 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedGlweSecretKey, SimulationScalar,
+};
+use crate::specification::engines::{GlweSecretKeyCreationEngine, GlweSecretKeyCreationError};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+use std::marker::PhantomData;
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyCreationEngine`] for [`SimulationEngine`].
+impl<T: SimulationScalar> GlweSecretKeyCreationEngine<SimulatedGlweSecretKey<T>>
+    for SimulationEngine
+{
+    fn create_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<SimulatedGlweSecretKey<T>, GlweSecretKeyCreationError<Self::EngineError>> {
+        GlweSecretKeyCreationError::perform_generic_checks(glwe_dimension, polynomial_size)?;
+        Ok(unsafe { self.create_glwe_secret_key_unchecked(glwe_dimension, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> SimulatedGlweSecretKey<T> {
+        SimulatedGlweSecretKey {
+            glwe_dimension,
+            polynomial_size,
+            _precision: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedGlweSecretKey, SimulatedLweSecretKey, SimulationScalar,
+};
+use crate::specification::engines::{
+    GlweToLweSecretKeyTransmutationEngine, GlweToLweSecretKeyTransmutationEngineError,
+};
+use concrete_commons::parameters::LweDimension;
+use std::marker::PhantomData;
+
+/// # Description:
+/// Implementation of [`GlweToLweSecretKeyTransmutationEngine`] for [`SimulationEngine`].
+impl<T: SimulationScalar>
+    GlweToLweSecretKeyTransmutationEngine<SimulatedGlweSecretKey<T>, SimulatedLweSecretKey<T>>
+    for SimulationEngine
+{
+    fn transmute_glwe_secret_key_to_lwe_secret_key(
+        &mut self,
+        glwe_secret_key: SimulatedGlweSecretKey<T>,
+    ) -> Result<
+        SimulatedLweSecretKey<T>,
+        GlweToLweSecretKeyTransmutationEngineError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.transmute_glwe_secret_key_to_lwe_secret_key_unchecked(glwe_secret_key) })
+    }
+
+    unsafe fn transmute_glwe_secret_key_to_lwe_secret_key_unchecked(
+        &mut self,
+        glwe_secret_key: SimulatedGlweSecretKey<T>,
+    ) -> SimulatedLweSecretKey<T> {
+        SimulatedLweSecretKey {
+            lwe_dimension: LweDimension(
+                glwe_secret_key.glwe_dimension.0 * glwe_secret_key.polynomial_size.0,
+            ),
+            _precision: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,198 @@
+//! The table of the operations implemented by the [`SimulationEngine`].
+
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::*;
+use crate::specification::engines::*;
+
+engine_implementations! {
+    SimulationEngine {
+        CleartextCreationEngine(u32, SimulatedCleartext32),
+        CleartextCreationEngine(u64, SimulatedCleartext64),
+        CleartextRetrievalEngine(SimulatedCleartext32, u32),
+        CleartextRetrievalEngine(SimulatedCleartext64, u64),
+        DestructionEngine(SimulatedCleartext32),
+        DestructionEngine(SimulatedCleartext64),
+        DestructionEngine(SimulatedGlweCiphertext32),
+        DestructionEngine(SimulatedGlweCiphertext64),
+        DestructionEngine(SimulatedGlweSecretKey32),
+        DestructionEngine(SimulatedGlweSecretKey64),
+        DestructionEngine(SimulatedLweBootstrapKey32),
+        DestructionEngine(SimulatedLweBootstrapKey64),
+        DestructionEngine(SimulatedLweCiphertext32),
+        DestructionEngine(SimulatedLweCiphertext64),
+        DestructionEngine(SimulatedLweCiphertextVector32),
+        DestructionEngine(SimulatedLweCiphertextVector64),
+        DestructionEngine(SimulatedLweKeyswitchKey32),
+        DestructionEngine(SimulatedLweKeyswitchKey64),
+        DestructionEngine(SimulatedLweSecretKey32),
+        DestructionEngine(SimulatedLweSecretKey64),
+        DestructionEngine(SimulatedPlaintext32),
+        DestructionEngine(SimulatedPlaintext64),
+        DestructionEngine(SimulatedPlaintextVector32),
+        DestructionEngine(SimulatedPlaintextVector64),
+        GlweCiphertextDecryptionEngine(
+            SimulatedGlweSecretKey32,
+            SimulatedGlweCiphertext32,
+            SimulatedPlaintextVector32,
+        ),
+        GlweCiphertextDecryptionEngine(
+            SimulatedGlweSecretKey64,
+            SimulatedGlweCiphertext64,
+            SimulatedPlaintextVector64,
+        ),
+        GlweCiphertextEncryptionEngine(
+            SimulatedGlweSecretKey32,
+            SimulatedPlaintextVector32,
+            SimulatedGlweCiphertext32,
+        ),
+        GlweCiphertextEncryptionEngine(
+            SimulatedGlweSecretKey64,
+            SimulatedPlaintextVector64,
+            SimulatedGlweCiphertext64,
+        ),
+        GlweCiphertextTrivialEncryptionEngine(
+            SimulatedPlaintextVector32,
+            SimulatedGlweCiphertext32,
+        ),
+        GlweCiphertextTrivialEncryptionEngine(
+            SimulatedPlaintextVector64,
+            SimulatedGlweCiphertext64,
+        ),
+        GlweSecretKeyCreationEngine(SimulatedGlweSecretKey32),
+        GlweSecretKeyCreationEngine(SimulatedGlweSecretKey64),
+        GlweToLweSecretKeyTransmutationEngine(SimulatedGlweSecretKey32, SimulatedLweSecretKey32),
+        GlweToLweSecretKeyTransmutationEngine(SimulatedGlweSecretKey64, SimulatedLweSecretKey64),
+        LweBootstrapKeyCreationEngine(
+            SimulatedLweSecretKey32,
+            SimulatedGlweSecretKey32,
+            SimulatedLweBootstrapKey32,
+        ),
+        LweBootstrapKeyCreationEngine(
+            SimulatedLweSecretKey64,
+            SimulatedGlweSecretKey64,
+            SimulatedLweBootstrapKey64,
+        ),
+        LweCiphertextCleartextFusingMultiplicationEngine(
+            SimulatedLweCiphertext32,
+            SimulatedCleartext32,
+        ),
+        LweCiphertextCleartextFusingMultiplicationEngine(
+            SimulatedLweCiphertext64,
+            SimulatedCleartext64,
+        ),
+        LweCiphertextDecryptionEngine(
+            SimulatedLweSecretKey32,
+            SimulatedLweCiphertext32,
+            SimulatedPlaintext32,
+        ),
+        LweCiphertextDecryptionEngine(
+            SimulatedLweSecretKey64,
+            SimulatedLweCiphertext64,
+            SimulatedPlaintext64,
+        ),
+        LweCiphertextDiscardingAdditionEngine(SimulatedLweCiphertext32, SimulatedLweCiphertext32),
+        LweCiphertextDiscardingAdditionEngine(SimulatedLweCiphertext64, SimulatedLweCiphertext64),
+        LweCiphertextDiscardingBootstrapEngine(
+            SimulatedLweBootstrapKey32,
+            SimulatedGlweCiphertext32,
+            SimulatedLweCiphertext32,
+            SimulatedLweCiphertext32,
+        ),
+        LweCiphertextDiscardingBootstrapEngine(
+            SimulatedLweBootstrapKey64,
+            SimulatedGlweCiphertext64,
+            SimulatedLweCiphertext64,
+            SimulatedLweCiphertext64,
+        ),
+        LweCiphertextDiscardingKeyswitchEngine(
+            SimulatedLweKeyswitchKey32,
+            SimulatedLweCiphertext32,
+            SimulatedLweCiphertext32,
+        ),
+        LweCiphertextDiscardingKeyswitchEngine(
+            SimulatedLweKeyswitchKey64,
+            SimulatedLweCiphertext64,
+            SimulatedLweCiphertext64,
+        ),
+        LweCiphertextEncryptionEngine(
+            SimulatedLweSecretKey32,
+            SimulatedPlaintext32,
+            SimulatedLweCiphertext32,
+        ),
+        LweCiphertextEncryptionEngine(
+            SimulatedLweSecretKey64,
+            SimulatedPlaintext64,
+            SimulatedLweCiphertext64,
+        ),
+        LweCiphertextFusingAdditionEngine(SimulatedLweCiphertext32, SimulatedLweCiphertext32),
+        LweCiphertextFusingAdditionEngine(SimulatedLweCiphertext64, SimulatedLweCiphertext64),
+        LweCiphertextFusingNoiseInjectionEngine(SimulatedLweCiphertext32),
+        LweCiphertextFusingNoiseInjectionEngine(SimulatedLweCiphertext64),
+        LweCiphertextFusingOppositeEngine(SimulatedLweCiphertext32),
+        LweCiphertextFusingOppositeEngine(SimulatedLweCiphertext64),
+        LweCiphertextFusingSubtractionEngine(SimulatedLweCiphertext32, SimulatedLweCiphertext32),
+        LweCiphertextFusingSubtractionEngine(SimulatedLweCiphertext64, SimulatedLweCiphertext64),
+        LweCiphertextPlaintextFusingAdditionEngine(SimulatedLweCiphertext32, SimulatedPlaintext32),
+        LweCiphertextPlaintextFusingAdditionEngine(SimulatedLweCiphertext64, SimulatedPlaintext64),
+        LweCiphertextPlaintextFusingSubtractionEngine(
+            SimulatedLweCiphertext32,
+            SimulatedPlaintext32,
+        ),
+        LweCiphertextPlaintextFusingSubtractionEngine(
+            SimulatedLweCiphertext64,
+            SimulatedPlaintext64,
+        ),
+        LweCiphertextTrivialEncryptionEngine(SimulatedPlaintext32, SimulatedLweCiphertext32),
+        LweCiphertextTrivialEncryptionEngine(SimulatedPlaintext64, SimulatedLweCiphertext64),
+        LweCiphertextVectorDecryptionEngine(
+            SimulatedLweSecretKey32,
+            SimulatedLweCiphertextVector32,
+            SimulatedPlaintextVector32,
+        ),
+        LweCiphertextVectorDecryptionEngine(
+            SimulatedLweSecretKey64,
+            SimulatedLweCiphertextVector64,
+            SimulatedPlaintextVector64,
+        ),
+        LweCiphertextVectorEncryptionEngine(
+            SimulatedLweSecretKey32,
+            SimulatedPlaintextVector32,
+            SimulatedLweCiphertextVector32,
+        ),
+        LweCiphertextVectorEncryptionEngine(
+            SimulatedLweSecretKey64,
+            SimulatedPlaintextVector64,
+            SimulatedLweCiphertextVector64,
+        ),
+        LweCiphertextVectorFusingAdditionEngine(
+            SimulatedLweCiphertextVector32,
+            SimulatedLweCiphertextVector32,
+        ),
+        LweCiphertextVectorFusingAdditionEngine(
+            SimulatedLweCiphertextVector64,
+            SimulatedLweCiphertextVector64,
+        ),
+        LweCiphertextZeroEncryptionEngine(SimulatedLweSecretKey32, SimulatedLweCiphertext32),
+        LweCiphertextZeroEncryptionEngine(SimulatedLweSecretKey64, SimulatedLweCiphertext64),
+        LweKeyswitchKeyCreationEngine(
+            SimulatedLweSecretKey32,
+            SimulatedLweSecretKey32,
+            SimulatedLweKeyswitchKey32,
+        ),
+        LweKeyswitchKeyCreationEngine(
+            SimulatedLweSecretKey64,
+            SimulatedLweSecretKey64,
+            SimulatedLweKeyswitchKey64,
+        ),
+        LweSecretKeyCreationEngine(SimulatedLweSecretKey32),
+        LweSecretKeyCreationEngine(SimulatedLweSecretKey64),
+        PlaintextCreationEngine(u32, SimulatedPlaintext32),
+        PlaintextCreationEngine(u64, SimulatedPlaintext64),
+        PlaintextRetrievalEngine(SimulatedPlaintext32, u32),
+        PlaintextRetrievalEngine(SimulatedPlaintext64, u64),
+        PlaintextVectorCreationEngine(u32, SimulatedPlaintextVector32),
+        PlaintextVectorCreationEngine(u64, SimulatedPlaintextVector64),
+        PlaintextVectorRetrievalEngine(SimulatedPlaintextVector32, u32),
+        PlaintextVectorRetrievalEngine(SimulatedPlaintextVector64, u64),
+    }
+}
@@ -0,0 +1,77 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::engines::SimulationError;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedGlweSecretKey, SimulatedLweBootstrapKey, SimulatedLweSecretKey, SimulationScalar,
+};
+use crate::specification::engines::{LweBootstrapKeyCreationEngine, LweBootstrapKeyCreationError};
+use crate::specification::entities::{GlweSecretKeyEntity, LweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use std::marker::PhantomData;
+
+impl From<SimulationError> for LweBootstrapKeyCreationError<SimulationError> {
+    fn from(err: SimulationError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationEngine`] for [`SimulationEngine`].
+///
+/// # Note:
+///
+/// The simulated bootstrap locates the lookup table coefficient by a modulus switching to
+/// $2N$, which requires the polynomial size of the output key to be a power of two.
+impl<T: SimulationScalar>
+    LweBootstrapKeyCreationEngine<
+        SimulatedLweSecretKey<T>,
+        SimulatedGlweSecretKey<T>,
+        SimulatedLweBootstrapKey<T>,
+    > for SimulationEngine
+{
+    fn create_lwe_bootstrap_key(
+        &mut self,
+        input_key: &SimulatedLweSecretKey<T>,
+        output_key: &SimulatedGlweSecretKey<T>,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<SimulatedLweBootstrapKey<T>, LweBootstrapKeyCreationError<Self::EngineError>> {
+        LweBootstrapKeyCreationError::perform_generic_checks(
+            decomposition_base_log,
+            decomposition_level_count,
+            T::BITS,
+        )?;
+        if !output_key.polynomial_size().0.is_power_of_two() {
+            return Err(SimulationError::UnsupportedPolynomialSize.into());
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &SimulatedLweSecretKey<T>,
+        output_key: &SimulatedGlweSecretKey<T>,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> SimulatedLweBootstrapKey<T> {
+        SimulatedLweBootstrapKey {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            glwe_dimension: output_key.glwe_dimension(),
+            polynomial_size: output_key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+            _precision: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedCleartext, SimulatedLweCiphertext, SimulationScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextCleartextFusingMultiplicationEngine,
+    LweCiphertextCleartextFusingMultiplicationError,
+};
+use concrete_npe::estimate_integer_plaintext_multiplication_noise;
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for
+/// [`SimulationEngine`]. The plaintext is multiplied by the cleartext, and the variance is
+/// scaled with [`estimate_integer_plaintext_multiplication_noise`].
+impl<T: SimulationScalar>
+    LweCiphertextCleartextFusingMultiplicationEngine<
+        SimulatedLweCiphertext<T>,
+        SimulatedCleartext<T>,
+    > for SimulationEngine
+{
+    fn fuse_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut SimulatedLweCiphertext<T>,
+        input: &SimulatedCleartext<T>,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        unsafe { self.fuse_mul_lwe_ciphertext_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut SimulatedLweCiphertext<T>,
+        input: &SimulatedCleartext<T>,
+    ) {
+        output.plaintext = output.plaintext.wrapping_mul(input.0);
+        output.variance =
+            estimate_integer_plaintext_multiplication_noise::<T, _>(output.variance, input.0);
+    }
+}
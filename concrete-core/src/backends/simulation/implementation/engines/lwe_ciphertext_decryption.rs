@@ -0,0 +1,32 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedLweCiphertext, SimulatedLweSecretKey, SimulatedPlaintext, SimulationScalar,
+};
+use crate::specification::engines::{LweCiphertextDecryptionEngine, LweCiphertextDecryptionError};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`SimulationEngine`]. The output
+/// plaintext is the exact plaintext held by the ciphertext, without noise.
+impl<T: SimulationScalar>
+    LweCiphertextDecryptionEngine<
+        SimulatedLweSecretKey<T>,
+        SimulatedLweCiphertext<T>,
+        SimulatedPlaintext<T>,
+    > for SimulationEngine
+{
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &SimulatedLweSecretKey<T>,
+        input: &SimulatedLweCiphertext<T>,
+    ) -> Result<SimulatedPlaintext<T>, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        _key: &SimulatedLweSecretKey<T>,
+        input: &SimulatedLweCiphertext<T>,
+    ) -> SimulatedPlaintext<T> {
+        SimulatedPlaintext(input.plaintext)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedLweCiphertext, SimulationScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingAdditionEngine, LweCiphertextDiscardingAdditionError,
+};
+use concrete_npe::estimate_addition_noise;
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingAdditionEngine`] for [`SimulationEngine`]. The
+/// plaintexts are added, and the variances are combined with [`estimate_addition_noise`].
+impl<T: SimulationScalar>
+    LweCiphertextDiscardingAdditionEngine<SimulatedLweCiphertext<T>, SimulatedLweCiphertext<T>>
+    for SimulationEngine
+{
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut SimulatedLweCiphertext<T>,
+        input_1: &SimulatedLweCiphertext<T>,
+        input_2: &SimulatedLweCiphertext<T>,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        LweCiphertextDiscardingAdditionError::perform_generic_checks(output, input_1, input_2)?;
+        unsafe { self.discard_add_lwe_ciphertext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut SimulatedLweCiphertext<T>,
+        input_1: &SimulatedLweCiphertext<T>,
+        input_2: &SimulatedLweCiphertext<T>,
+    ) {
+        output.plaintext = input_1.plaintext.wrapping_add(input_2.plaintext);
+        output.variance = estimate_addition_noise::<T, _, _>(input_1.variance, input_2.variance);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::backends::simulation::implementation::engines::{FftNoiseModel, SimulationEngine};
+use crate::backends::simulation::implementation::entities::{
+    SimulatedGlweCiphertext, SimulatedLweBootstrapKey, SimulatedLweCiphertext, SimulationScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
+};
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_npe::{estimate_addition_noise, estimate_pbs_fft_noise, estimate_pbs_noise};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`SimulationEngine`].
+///
+/// The input plaintext is switched to the modulus $2N$ with exact rounding, and the matching
+/// coefficient of the accumulator is extracted, negated when the index wraps around $N$. The
+/// output variance is the accumulator variance, increased by the prediction of
+/// [`estimate_pbs_noise`]. With the [`FftNoiseModel::DoublePrecision`] model, the prediction of
+/// [`estimate_pbs_fft_noise`] is added as well.
+///
+/// # Note:
+///
+/// The modulus switching noise is not simulated: the output plaintext is the one a bootstrap
+/// would produce if the input noise was small enough to land in the right coefficient.
+impl<T: SimulationScalar>
+    LweCiphertextDiscardingBootstrapEngine<
+        SimulatedLweBootstrapKey<T>,
+        SimulatedGlweCiphertext<T>,
+        SimulatedLweCiphertext<T>,
+        SimulatedLweCiphertext<T>,
+    > for SimulationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(630), GlweDimension(1), PolynomialSize(1024));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let lwe_dim_output = LweDimension(glwe_dim.0 * poly_size.0);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // The accumulator maps the message m (with 3 bits of message and 1 bit of padding) to m + 1.
+    /// let delta = 1_u64 << 60;
+    /// let box_size = poly_size.0 / 8;
+    /// let lut = (0..poly_size.0)
+    ///     .map(|i| (((i + box_size / 2) / box_size % 8) as u64 + 1) * delta)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let mut engine = SimulationEngine::new()?;
+    /// let lwe_sk: SimulatedLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: SimulatedGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk = engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: SimulatedLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&(5 * delta))?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.plaintext(), 6 * delta);
+    /// assert!(output.variance().0 > 0.);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut SimulatedLweCiphertext<T>,
+        input: &SimulatedLweCiphertext<T>,
+        acc: &SimulatedGlweCiphertext<T>,
+        bsk: &SimulatedLweBootstrapKey<T>,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        LweCiphertextDiscardingBootstrapError::perform_generic_checks(output, input, acc, bsk)?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut SimulatedLweCiphertext<T>,
+        input: &SimulatedLweCiphertext<T>,
+        acc: &SimulatedGlweCiphertext<T>,
+        bsk: &SimulatedLweBootstrapKey<T>,
+    ) {
+        let poly_size = bsk.polynomial_size.0;
+        let shift = T::BITS - (2 * poly_size).trailing_zeros() as usize;
+        let rounded = input.plaintext.wrapping_add(T::ONE << (shift - 1));
+        let index: usize = (rounded >> shift).cast_into();
+        output.plaintext = if index < poly_size {
+            acc.plaintexts[index]
+        } else {
+            acc.plaintexts[index - poly_size].wrapping_neg()
+        };
+        let pbs_noise = estimate_pbs_noise::<T, _, BinaryKeyKind>(
+            bsk.input_lwe_dimension,
+            bsk.polynomial_size,
+            bsk.glwe_dimension,
+            bsk.decomposition_base_log,
+            bsk.decomposition_level_count,
+            bsk.noise,
+        );
+        let pbs_noise = match self.fft_noise_model {
+            FftNoiseModel::Exact => pbs_noise,
+            FftNoiseModel::DoublePrecision => estimate_addition_noise::<T, _, _>(
+                pbs_noise,
+                estimate_pbs_fft_noise::<T>(
+                    bsk.input_lwe_dimension,
+                    bsk.polynomial_size,
+                    bsk.glwe_dimension,
+                    bsk.decomposition_base_log,
+                    bsk.decomposition_level_count,
+                ),
+            ),
+        };
+        output.variance = estimate_addition_noise::<T, _, _>(acc.variance, pbs_noise);
+    }
+}
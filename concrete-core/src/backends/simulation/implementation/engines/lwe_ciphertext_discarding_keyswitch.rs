@@ -0,0 +1,88 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedLweCiphertext, SimulatedLweKeyswitchKey, SimulationScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
+};
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms;
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`SimulationEngine`]. The
+/// plaintext is copied, and the output variance is predicted with
+/// [`estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms`].
+impl<T: SimulationScalar>
+    LweCiphertextDiscardingKeyswitchEngine<
+        SimulatedLweKeyswitchKey<T>,
+        SimulatedLweCiphertext<T>,
+        SimulatedLweCiphertext<T>,
+    > for SimulationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(1024);
+    /// let output_lwe_dimension = LweDimension(630);
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(5), DecompositionBaseLog(3));
+    /// let input = 3_u64 << 60;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = SimulationEngine::new()?;
+    /// let input_key: SimulatedLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: SimulatedLweSecretKey64 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let ksk = engine.create_lwe_keyswitch_key(&input_key, &output_key, dec_lc, dec_bl, noise)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &ksk)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(ciphertext_2.plaintext(), input);
+    /// assert!(ciphertext_2.variance().0 > ciphertext_1.variance().0);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(ksk)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut SimulatedLweCiphertext<T>,
+        input: &SimulatedLweCiphertext<T>,
+        ksk: &SimulatedLweKeyswitchKey<T>,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut SimulatedLweCiphertext<T>,
+        input: &SimulatedLweCiphertext<T>,
+        ksk: &SimulatedLweKeyswitchKey<T>,
+    ) {
+        output.plaintext = input.plaintext;
+        output.variance =
+            estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<T, _, _, BinaryKeyKind>(
+                ksk.input_lwe_dimension,
+                input.variance,
+                ksk.noise,
+                ksk.decomposition_base_log,
+                ksk.decomposition_level_count,
+            );
+    }
+}
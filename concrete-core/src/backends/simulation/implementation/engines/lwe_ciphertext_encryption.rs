@@ -0,0 +1,68 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedLweCiphertext, SimulatedLweSecretKey, SimulatedPlaintext, SimulationScalar,
+};
+use crate::specification::engines::{LweCiphertextEncryptionEngine, LweCiphertextEncryptionError};
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`SimulationEngine`]. The output
+/// ciphertext holds the input plaintext, and a variance equal to the encryption noise.
+impl<T: SimulationScalar>
+    LweCiphertextEncryptionEngine<
+        SimulatedLweSecretKey<T>,
+        SimulatedPlaintext<T>,
+        SimulatedLweCiphertext<T>,
+    > for SimulationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(630);
+    /// let input = 3_u64 << 60;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = SimulationEngine::new()?;
+    /// let key: SimulatedLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext.plaintext(), input);
+    /// assert_eq!(ciphertext.variance(), noise);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &SimulatedLweSecretKey<T>,
+        input: &SimulatedPlaintext<T>,
+        noise: Variance,
+    ) -> Result<SimulatedLweCiphertext<T>, LweCiphertextEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &SimulatedLweSecretKey<T>,
+        input: &SimulatedPlaintext<T>,
+        noise: Variance,
+    ) -> SimulatedLweCiphertext<T> {
+        SimulatedLweCiphertext {
+            lwe_dimension: key.lwe_dimension(),
+            plaintext: input.0,
+            variance: noise,
+        }
+    }
+}
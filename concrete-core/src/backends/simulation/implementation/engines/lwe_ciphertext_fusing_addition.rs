@@ -0,0 +1,69 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedLweCiphertext, SimulationScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextFusingAdditionEngine, LweCiphertextFusingAdditionError,
+};
+use concrete_npe::estimate_addition_noise;
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`SimulationEngine`]. The
+/// plaintexts are added, and the variances are combined with [`estimate_addition_noise`].
+impl<T: SimulationScalar>
+    LweCiphertextFusingAdditionEngine<SimulatedLweCiphertext<T>, SimulatedLweCiphertext<T>>
+    for SimulationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(630);
+    /// let input_1 = 3_u32 << 20;
+    /// let input_2 = 5_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = SimulationEngine::new()?;
+    /// let key: SimulatedLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = engine.create_plaintext(&input_1)?;
+    /// let plaintext_2 = engine.create_plaintext(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let mut ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1)?;
+    /// #
+    /// assert_eq!(ciphertext_2.plaintext(), 8_u32 << 20);
+    /// assert!((ciphertext_2.variance().0 - 2. * noise.0).abs() < 1e-20);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_1)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(plaintext_2)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext(
+        &mut self,
+        output: &mut SimulatedLweCiphertext<T>,
+        input: &SimulatedLweCiphertext<T>,
+    ) -> Result<(), LweCiphertextFusingAdditionError<Self::EngineError>> {
+        LweCiphertextFusingAdditionError::perform_generic_checks(output, input)?;
+        unsafe { self.fuse_add_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut SimulatedLweCiphertext<T>,
+        input: &SimulatedLweCiphertext<T>,
+    ) {
+        output.plaintext = output.plaintext.wrapping_add(input.plaintext);
+        output.variance = estimate_addition_noise::<T, _, _>(output.variance, input.variance);
+    }
+}
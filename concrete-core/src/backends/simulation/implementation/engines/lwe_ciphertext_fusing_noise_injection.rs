@@ -0,0 +1,48 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedLweCiphertext, SimulationScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextFusingNoiseInjectionEngine, LweCiphertextFusingNoiseInjectionError,
+};
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingNoiseInjectionEngine`] for [`SimulationEngine`]. The
+/// plaintext is left unchanged, and the difference between the target and current variances is
+/// added to the tracked variance.
+impl<T: SimulationScalar> LweCiphertextFusingNoiseInjectionEngine<SimulatedLweCiphertext<T>>
+    for SimulationEngine
+{
+    fn fuse_inject_noise_lwe_ciphertext(
+        &mut self,
+        ciphertext: &mut SimulatedLweCiphertext<T>,
+        current_variance: Variance,
+        target_variance: Variance,
+    ) -> Result<(), LweCiphertextFusingNoiseInjectionError<Self::EngineError>> {
+        LweCiphertextFusingNoiseInjectionError::perform_generic_checks(
+            current_variance,
+            target_variance,
+        )?;
+        unsafe {
+            self.fuse_inject_noise_lwe_ciphertext_unchecked(
+                ciphertext,
+                current_variance,
+                target_variance,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn fuse_inject_noise_lwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: &mut SimulatedLweCiphertext<T>,
+        current_variance: Variance,
+        target_variance: Variance,
+    ) {
+        ciphertext.variance = Variance(
+            ciphertext.variance.get_variance() + target_variance.get_variance()
+                - current_variance.get_variance(),
+        );
+    }
+}
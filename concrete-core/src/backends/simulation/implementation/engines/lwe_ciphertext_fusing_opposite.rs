@@ -0,0 +1,26 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedLweCiphertext, SimulationScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextFusingOppositeEngine, LweCiphertextFusingOppositeError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingOppositeEngine`] for [`SimulationEngine`]. The
+/// variance is left unchanged.
+impl<T: SimulationScalar> LweCiphertextFusingOppositeEngine<SimulatedLweCiphertext<T>>
+    for SimulationEngine
+{
+    fn fuse_opp_lwe_ciphertext(
+        &mut self,
+        input: &mut SimulatedLweCiphertext<T>,
+    ) -> Result<(), LweCiphertextFusingOppositeError<Self::EngineError>> {
+        unsafe { self.fuse_opp_lwe_ciphertext_unchecked(input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_opp_lwe_ciphertext_unchecked(&mut self, input: &mut SimulatedLweCiphertext<T>) {
+        input.plaintext = input.plaintext.wrapping_neg();
+    }
+}
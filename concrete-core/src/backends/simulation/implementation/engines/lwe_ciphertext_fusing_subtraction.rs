@@ -0,0 +1,35 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedLweCiphertext, SimulationScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextFusingSubtractionEngine, LweCiphertextFusingSubtractionError,
+};
+use concrete_npe::estimate_addition_noise;
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingSubtractionEngine`] for [`SimulationEngine`]. The
+/// plaintexts are subtracted, and the variances are combined with [`estimate_addition_noise`].
+impl<T: SimulationScalar>
+    LweCiphertextFusingSubtractionEngine<SimulatedLweCiphertext<T>, SimulatedLweCiphertext<T>>
+    for SimulationEngine
+{
+    fn fuse_sub_lwe_ciphertext(
+        &mut self,
+        output: &mut SimulatedLweCiphertext<T>,
+        input: &SimulatedLweCiphertext<T>,
+    ) -> Result<(), LweCiphertextFusingSubtractionError<Self::EngineError>> {
+        LweCiphertextFusingSubtractionError::perform_generic_checks(output, input)?;
+        unsafe { self.fuse_sub_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_sub_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut SimulatedLweCiphertext<T>,
+        input: &SimulatedLweCiphertext<T>,
+    ) {
+        output.plaintext = output.plaintext.wrapping_sub(input.plaintext);
+        output.variance = estimate_addition_noise::<T, _, _>(output.variance, input.variance);
+    }
+}
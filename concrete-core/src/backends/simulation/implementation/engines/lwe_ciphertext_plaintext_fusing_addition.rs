@@ -0,0 +1,32 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedLweCiphertext, SimulatedPlaintext, SimulationScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextPlaintextFusingAdditionEngine, LweCiphertextPlaintextFusingAdditionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextPlaintextFusingAdditionEngine`] for [`SimulationEngine`]. The
+/// plaintexts are added, and the variance is left unchanged.
+impl<T: SimulationScalar>
+    LweCiphertextPlaintextFusingAdditionEngine<SimulatedLweCiphertext<T>, SimulatedPlaintext<T>>
+    for SimulationEngine
+{
+    fn fuse_add_lwe_ciphertext_plaintext(
+        &mut self,
+        output: &mut SimulatedLweCiphertext<T>,
+        input: &SimulatedPlaintext<T>,
+    ) -> Result<(), LweCiphertextPlaintextFusingAdditionError<Self::EngineError>> {
+        unsafe { self.fuse_add_lwe_ciphertext_plaintext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_plaintext_unchecked(
+        &mut self,
+        output: &mut SimulatedLweCiphertext<T>,
+        input: &SimulatedPlaintext<T>,
+    ) {
+        output.plaintext = output.plaintext.wrapping_add(input.0);
+    }
+}
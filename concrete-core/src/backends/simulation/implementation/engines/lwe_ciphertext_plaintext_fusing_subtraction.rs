@@ -0,0 +1,32 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedLweCiphertext, SimulatedPlaintext, SimulationScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextPlaintextFusingSubtractionEngine, LweCiphertextPlaintextFusingSubtractionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextPlaintextFusingSubtractionEngine`] for [`SimulationEngine`]. The
+/// plaintexts are subtracted, and the variance is left unchanged.
+impl<T: SimulationScalar>
+    LweCiphertextPlaintextFusingSubtractionEngine<SimulatedLweCiphertext<T>, SimulatedPlaintext<T>>
+    for SimulationEngine
+{
+    fn fuse_sub_lwe_ciphertext_plaintext(
+        &mut self,
+        output: &mut SimulatedLweCiphertext<T>,
+        input: &SimulatedPlaintext<T>,
+    ) -> Result<(), LweCiphertextPlaintextFusingSubtractionError<Self::EngineError>> {
+        unsafe { self.fuse_sub_lwe_ciphertext_plaintext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_sub_lwe_ciphertext_plaintext_unchecked(
+        &mut self,
+        output: &mut SimulatedLweCiphertext<T>,
+        input: &SimulatedPlaintext<T>,
+    ) {
+        output.plaintext = output.plaintext.wrapping_sub(input.0);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedLweCiphertext, SimulatedPlaintext, SimulationScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextTrivialEncryptionEngine, LweCiphertextTrivialEncryptionError,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LweSize;
+
+/// # Description:
+/// Implementation of [`LweCiphertextTrivialEncryptionEngine`] for [`SimulationEngine`]. The
+/// output ciphertext holds the input plaintext, and a null variance.
+impl<T: SimulationScalar>
+    LweCiphertextTrivialEncryptionEngine<SimulatedPlaintext<T>, SimulatedLweCiphertext<T>>
+    for SimulationEngine
+{
+    fn trivially_encrypt_lwe_ciphertext(
+        &mut self,
+        lwe_size: LweSize,
+        input: &SimulatedPlaintext<T>,
+    ) -> Result<SimulatedLweCiphertext<T>, LweCiphertextTrivialEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.trivially_encrypt_lwe_ciphertext_unchecked(lwe_size, input) })
+    }
+
+    unsafe fn trivially_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        lwe_size: LweSize,
+        input: &SimulatedPlaintext<T>,
+    ) -> SimulatedLweCiphertext<T> {
+        SimulatedLweCiphertext {
+            lwe_dimension: lwe_size.to_lwe_dimension(),
+            plaintext: input.0,
+            variance: Variance(0.),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedLweCiphertextVector, SimulatedLweSecretKey, SimulatedPlaintextVector, SimulationScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDecryptionEngine, LweCiphertextVectorDecryptionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`SimulationEngine`]. The
+/// output plaintexts are the exact plaintexts held by the ciphertexts, without noise.
+impl<T: SimulationScalar>
+    LweCiphertextVectorDecryptionEngine<
+        SimulatedLweSecretKey<T>,
+        SimulatedLweCiphertextVector<T>,
+        SimulatedPlaintextVector<T>,
+    > for SimulationEngine
+{
+    fn decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &SimulatedLweSecretKey<T>,
+        input: &SimulatedLweCiphertextVector<T>,
+    ) -> Result<SimulatedPlaintextVector<T>, LweCiphertextVectorDecryptionError<Self::EngineError>>
+    {
+        LweCiphertextVectorDecryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &SimulatedLweSecretKey<T>,
+        input: &SimulatedLweCiphertextVector<T>,
+    ) -> SimulatedPlaintextVector<T> {
+        SimulatedPlaintextVector(input.plaintexts.clone())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedLweCiphertextVector, SimulatedLweSecretKey, SimulatedPlaintextVector, SimulationScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorEncryptionEngine, LweCiphertextVectorEncryptionError,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorEncryptionEngine`] for [`SimulationEngine`].
+impl<T: SimulationScalar>
+    LweCiphertextVectorEncryptionEngine<
+        SimulatedLweSecretKey<T>,
+        SimulatedPlaintextVector<T>,
+        SimulatedLweCiphertextVector<T>,
+    > for SimulationEngine
+{
+    fn encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &SimulatedLweSecretKey<T>,
+        input: &SimulatedPlaintextVector<T>,
+        noise: Variance,
+    ) -> Result<
+        SimulatedLweCiphertextVector<T>,
+        LweCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &SimulatedLweSecretKey<T>,
+        input: &SimulatedPlaintextVector<T>,
+        noise: Variance,
+    ) -> SimulatedLweCiphertextVector<T> {
+        SimulatedLweCiphertextVector {
+            lwe_dimension: key.lwe_dimension(),
+            plaintexts: input.0.clone(),
+            variances: vec![noise; input.0.len()],
+        }
+    }
+}
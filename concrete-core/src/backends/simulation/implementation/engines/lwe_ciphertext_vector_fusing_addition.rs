@@ -0,0 +1,42 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedLweCiphertextVector, SimulationScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorFusingAdditionEngine, LweCiphertextVectorFusingAdditionError,
+};
+use concrete_npe::estimate_addition_noise;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorFusingAdditionEngine`] for [`SimulationEngine`]. The
+/// plaintexts are added element-wise, and the variances are combined with
+/// [`estimate_addition_noise`].
+impl<T: SimulationScalar>
+    LweCiphertextVectorFusingAdditionEngine<
+        SimulatedLweCiphertextVector<T>,
+        SimulatedLweCiphertextVector<T>,
+    > for SimulationEngine
+{
+    fn fuse_add_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut SimulatedLweCiphertextVector<T>,
+        input: &SimulatedLweCiphertextVector<T>,
+    ) -> Result<(), LweCiphertextVectorFusingAdditionError<Self::EngineError>> {
+        LweCiphertextVectorFusingAdditionError::perform_generic_checks(output, input)?;
+        unsafe { self.fuse_add_lwe_ciphertext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut SimulatedLweCiphertextVector<T>,
+        input: &SimulatedLweCiphertextVector<T>,
+    ) {
+        for (out, inp) in output.plaintexts.iter_mut().zip(input.plaintexts.iter()) {
+            *out = out.wrapping_add(*inp);
+        }
+        for (out, inp) in output.variances.iter_mut().zip(input.variances.iter()) {
+            *out = estimate_addition_noise::<T, _, _>(*out, *inp);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedLweCiphertext, SimulatedLweSecretKey, SimulationScalar,
+};
+use crate::specification::engines::{
+    LweCiphertextZeroEncryptionEngine, LweCiphertextZeroEncryptionError,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`LweCiphertextZeroEncryptionEngine`] for [`SimulationEngine`].
+impl<T: SimulationScalar>
+    LweCiphertextZeroEncryptionEngine<SimulatedLweSecretKey<T>, SimulatedLweCiphertext<T>>
+    for SimulationEngine
+{
+    fn zero_encrypt_lwe_ciphertext(
+        &mut self,
+        key: &SimulatedLweSecretKey<T>,
+        noise: Variance,
+    ) -> Result<SimulatedLweCiphertext<T>, LweCiphertextZeroEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.zero_encrypt_lwe_ciphertext_unchecked(key, noise) })
+    }
+
+    unsafe fn zero_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &SimulatedLweSecretKey<T>,
+        noise: Variance,
+    ) -> SimulatedLweCiphertext<T> {
+        SimulatedLweCiphertext {
+            lwe_dimension: key.lwe_dimension(),
+            plaintext: T::ZERO,
+            variance: noise,
+        }
+    }
+}
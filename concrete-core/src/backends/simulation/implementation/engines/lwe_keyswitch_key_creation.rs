@@ -0,0 +1,61 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedLweKeyswitchKey, SimulatedLweSecretKey, SimulationScalar,
+};
+use crate::specification::engines::{LweKeyswitchKeyCreationEngine, LweKeyswitchKeyCreationError};
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use std::marker::PhantomData;
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`SimulationEngine`].
+impl<T: SimulationScalar>
+    LweKeyswitchKeyCreationEngine<
+        SimulatedLweSecretKey<T>,
+        SimulatedLweSecretKey<T>,
+        SimulatedLweKeyswitchKey<T>,
+    > for SimulationEngine
+{
+    fn create_lwe_keyswitch_key(
+        &mut self,
+        input_key: &SimulatedLweSecretKey<T>,
+        output_key: &SimulatedLweSecretKey<T>,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<SimulatedLweKeyswitchKey<T>, LweKeyswitchKeyCreationError<Self::EngineError>> {
+        LweKeyswitchKeyCreationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            T::BITS,
+        )?;
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &SimulatedLweSecretKey<T>,
+        output_key: &SimulatedLweSecretKey<T>,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> SimulatedLweKeyswitchKey<T> {
+        SimulatedLweKeyswitchKey {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            output_lwe_dimension: output_key.lwe_dimension(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+            _precision: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedLweSecretKey, SimulationScalar,
+};
+use crate::specification::engines::{LweSecretKeyCreationEngine, LweSecretKeyCreationError};
+use concrete_commons::parameters::LweDimension;
+use std::marker::PhantomData;
+
+/// # Description:
+/// Implementation of [`LweSecretKeyCreationEngine`] for [`SimulationEngine`].
+impl<T: SimulationScalar> LweSecretKeyCreationEngine<SimulatedLweSecretKey<T>>
+    for SimulationEngine
+{
+    fn create_lwe_secret_key(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<SimulatedLweSecretKey<T>, LweSecretKeyCreationError<Self::EngineError>> {
+        LweSecretKeyCreationError::perform_generic_checks(lwe_dimension)?;
+        Ok(unsafe { self.create_lwe_secret_key_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn create_lwe_secret_key_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> SimulatedLweSecretKey<T> {
+        SimulatedLweSecretKey {
+            lwe_dimension,
+            _precision: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! A module containing the [engines](crate::specification::engines) exposed by the simulation
+//! backend.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+
+/// The error which can occur in the execution of FHE operations, due to the simulation
+/// implementation.
+#[derive(Debug)]
+pub enum SimulationError {
+    UnsupportedPolynomialSize,
+}
+
+impl Display for SimulationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SimulationError::UnsupportedPolynomialSize => {
+                write!(
+                    f,
+                    "The Simulation Backend only supports polynomial sizes which are powers of two."
+                )
+            }
+        }
+    }
+}
+
+impl Error for SimulationError {}
+
+/// The model of the error of the fourier transforms used by the simulated bootstraps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FftNoiseModel {
+    /// The external products of the bootstrap are assumed to be exact, as in the noise
+    /// prediction of `concrete_npe::estimate_pbs_noise`.
+    Exact,
+    /// The external products of the bootstrap are assumed to be computed in the fourier domain
+    /// with double precision floating point numbers, as in the core backend. Their error is
+    /// modeled as an additional gaussian noise, whose variance is predicted by
+    /// `concrete_npe::estimate_pbs_fft_noise`.
+    DoublePrecision,
+}
+
+/// The main engine exposed by the simulation backend.
+///
+/// Every operation is computed on the plaintexts directly, and the variance of its output is
+/// estimated from the variance of its inputs. The only state of the engine is the
+/// [`FftNoiseModel`] used by the bootstraps.
+#[derive(Debug)]
+pub struct SimulationEngine {
+    fft_noise_model: FftNoiseModel,
+}
+
+impl SimulationEngine {
+    /// Sets the model of the error of the fourier transforms used by the simulated bootstraps.
+    ///
+    /// The engines are created with the [`FftNoiseModel::Exact`] model. With the
+    /// [`FftNoiseModel::DoublePrecision`] model, the variances of the bootstrapped ciphertexts
+    /// account for the floating point error of the core backend, which dominates the noise for
+    /// large decomposition bases. The failure rates predicted from these variances then match
+    /// the ones of the core backend, without running the actual bootstraps.
+    ///
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(630), GlweDimension(1), PolynomialSize(1024));
+    /// // A single level with a large base, which stresses the precision of the products.
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(1), DecompositionBaseLog(23));
+    /// let noise = Variance(2_f64.powf(-100.));
+    /// let lut = vec![1_u64 << 60; poly_size.0];
+    ///
+    /// let mut engine = SimulationEngine::new()?;
+    /// let lwe_sk: SimulatedLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: SimulatedGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk = engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: SimulatedLweSecretKey64 =
+    ///     engine.create_lwe_secret_key(LweDimension(poly_size.0))?;
+    /// let plaintext = engine.create_plaintext(&0_u64)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut exact = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    /// let mut double_precision = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut exact, &input, &acc, &bsk)?;
+    /// engine.set_fft_noise_model(FftNoiseModel::DoublePrecision);
+    /// assert_eq!(engine.fft_noise_model(), FftNoiseModel::DoublePrecision);
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut double_precision, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(double_precision.plaintext(), exact.plaintext());
+    /// assert!(double_precision.variance().0 > exact.variance().0);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(exact)?;
+    /// engine.destroy(double_precision)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_fft_noise_model(&mut self, fft_noise_model: FftNoiseModel) {
+        self.fft_noise_model = fft_noise_model;
+    }
+
+    /// Returns the model of the error of the fourier transforms used by the simulated bootstraps.
+    pub fn fft_noise_model(&self) -> FftNoiseModel {
+        self.fft_noise_model
+    }
+}
+
+impl AbstractEngineSeal for SimulationEngine {}
+
+impl AbstractEngine for SimulationEngine {
+    type EngineError = SimulationError;
+
+    fn new() -> Result<Self, Self::EngineError> {
+        Ok(SimulationEngine {
+            fft_noise_model: FftNoiseModel::Exact,
+        })
+    }
+}
+
+mod cleartext_creation;
+mod cleartext_retrieval;
+mod destruction;
+mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_trivial_encryption;
+mod glwe_secret_key_creation;
+mod glwe_secret_key_to_lwe_secret_key_transmutation;
+mod implementations;
+mod lwe_bootstrap_key_creation;
+mod lwe_ciphertext_cleartext_fusing_multiplication;
+mod lwe_ciphertext_decryption;
+mod lwe_ciphertext_discarding_addition;
+mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_encryption;
+mod lwe_ciphertext_fusing_addition;
+mod lwe_ciphertext_fusing_noise_injection;
+mod lwe_ciphertext_fusing_opposite;
+mod lwe_ciphertext_fusing_subtraction;
+mod lwe_ciphertext_plaintext_fusing_addition;
+mod lwe_ciphertext_plaintext_fusing_subtraction;
+mod lwe_ciphertext_trivial_encryption;
+mod lwe_ciphertext_vector_decryption;
+mod lwe_ciphertext_vector_encryption;
+mod lwe_ciphertext_vector_fusing_addition;
+mod lwe_ciphertext_zero_encryption;
+mod lwe_keyswitch_key_creation;
+mod lwe_secret_key_creation;
+mod plaintext_creation;
+mod plaintext_retrieval;
+mod plaintext_vector_creation;
+mod plaintext_vector_retrieval;
+
+pub use implementations::IMPLEMENTATIONS;
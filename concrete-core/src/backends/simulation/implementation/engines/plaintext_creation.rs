@@ -0,0 +1,18 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{SimulatedPlaintext, SimulationScalar};
+use crate::specification::engines::{PlaintextCreationEngine, PlaintextCreationError};
+
+/// # Description:
+/// Implementation of [`PlaintextCreationEngine`] for [`SimulationEngine`].
+impl<T: SimulationScalar> PlaintextCreationEngine<T, SimulatedPlaintext<T>> for SimulationEngine {
+    fn create_plaintext(
+        &mut self,
+        input: &T,
+    ) -> Result<SimulatedPlaintext<T>, PlaintextCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_plaintext_unchecked(input) })
+    }
+
+    unsafe fn create_plaintext_unchecked(&mut self, input: &T) -> SimulatedPlaintext<T> {
+        SimulatedPlaintext(*input)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{SimulatedPlaintext, SimulationScalar};
+use crate::specification::engines::{PlaintextRetrievalEngine, PlaintextRetrievalError};
+
+/// # Description:
+/// Implementation of [`PlaintextRetrievalEngine`] for [`SimulationEngine`].
+impl<T: SimulationScalar> PlaintextRetrievalEngine<SimulatedPlaintext<T>, T> for SimulationEngine {
+    fn retrieve_plaintext(
+        &mut self,
+        plaintext: &SimulatedPlaintext<T>,
+    ) -> Result<T, PlaintextRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_plaintext_unchecked(plaintext) })
+    }
+
+    unsafe fn retrieve_plaintext_unchecked(&mut self, plaintext: &SimulatedPlaintext<T>) -> T {
+        plaintext.0
+    }
+}
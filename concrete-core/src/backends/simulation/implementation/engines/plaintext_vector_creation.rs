@@ -0,0 +1,26 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedPlaintextVector, SimulationScalar,
+};
+use crate::specification::engines::{PlaintextVectorCreationEngine, PlaintextVectorCreationError};
+
+/// # Description:
+/// Implementation of [`PlaintextVectorCreationEngine`] for [`SimulationEngine`].
+impl<T: SimulationScalar> PlaintextVectorCreationEngine<T, SimulatedPlaintextVector<T>>
+    for SimulationEngine
+{
+    fn create_plaintext_vector(
+        &mut self,
+        input: &[T],
+    ) -> Result<SimulatedPlaintextVector<T>, PlaintextVectorCreationError<Self::EngineError>> {
+        PlaintextVectorCreationError::perform_generic_checks(input)?;
+        Ok(unsafe { self.create_plaintext_vector_unchecked(input) })
+    }
+
+    unsafe fn create_plaintext_vector_unchecked(
+        &mut self,
+        input: &[T],
+    ) -> SimulatedPlaintextVector<T> {
+        SimulatedPlaintextVector(input.to_vec())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::backends::simulation::implementation::engines::SimulationEngine;
+use crate::backends::simulation::implementation::entities::{
+    SimulatedPlaintextVector, SimulationScalar,
+};
+use crate::specification::engines::{
+    PlaintextVectorRetrievalEngine, PlaintextVectorRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`PlaintextVectorRetrievalEngine`] for [`SimulationEngine`].
+impl<T: SimulationScalar> PlaintextVectorRetrievalEngine<SimulatedPlaintextVector<T>, T>
+    for SimulationEngine
+{
+    fn retrieve_plaintext_vector(
+        &mut self,
+        plaintext: &SimulatedPlaintextVector<T>,
+    ) -> Result<Vec<T>, PlaintextVectorRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_plaintext_vector_unchecked(plaintext) })
+    }
+
+    unsafe fn retrieve_plaintext_vector_unchecked(
+        &mut self,
+        plaintext: &SimulatedPlaintextVector<T>,
+    ) -> Vec<T> {
+        plaintext.0.clone()
+    }
+}
@@ -0,0 +1,17 @@
+use crate::backends::simulation::implementation::entities::SimulationScalar;
+use crate::specification::entities::markers::CleartextKind;
+use crate::specification::entities::{AbstractEntity, CleartextEntity};
+
+/// A structure representing a simulated cleartext.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedCleartext<T: SimulationScalar>(pub(crate) T);
+impl<T: SimulationScalar> AbstractEntity for SimulatedCleartext<T> {
+    type Kind = CleartextKind;
+}
+impl<T: SimulationScalar> CleartextEntity for SimulatedCleartext<T> {}
+
+/// A simulated cleartext with 32 bits of precision.
+pub type SimulatedCleartext32 = SimulatedCleartext<u32>;
+
+/// A simulated cleartext with 64 bits of precision.
+pub type SimulatedCleartext64 = SimulatedCleartext<u64>;
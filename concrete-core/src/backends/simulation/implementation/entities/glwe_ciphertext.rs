@@ -0,0 +1,52 @@
+use crate::backends::simulation::implementation::entities::SimulationScalar;
+use crate::specification::entities::markers::{
+    BinaryKeyDistribution, GlweCiphertextKind, NegacyclicRing,
+};
+use crate::specification::entities::{AbstractEntity, GlweCiphertextEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A structure representing a simulated GLWE ciphertext.
+///
+/// Instead of a mask and a body, a simulated ciphertext holds the coefficients of the plaintext
+/// polynomial it encrypts, along with the variance of the noise a real ciphertext would carry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedGlweCiphertext<T: SimulationScalar> {
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) plaintexts: Vec<T>,
+    pub(crate) variance: Variance,
+}
+impl<T: SimulationScalar> AbstractEntity for SimulatedGlweCiphertext<T> {
+    type Kind = GlweCiphertextKind;
+}
+impl<T: SimulationScalar> GlweCiphertextEntity for SimulatedGlweCiphertext<T> {
+    type KeyDistribution = BinaryKeyDistribution;
+    type RingStructure = NegacyclicRing;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.plaintexts.len())
+    }
+}
+
+impl<T: SimulationScalar> SimulatedGlweCiphertext<T> {
+    /// Returns the coefficients of the plaintext polynomial encrypted by the ciphertext, without
+    /// noise.
+    pub fn plaintexts(&self) -> &[T] {
+        &self.plaintexts
+    }
+
+    /// Returns the predicted variance of the noise of the ciphertext.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+}
+
+/// A simulated GLWE ciphertext with 32 bits of precision.
+pub type SimulatedGlweCiphertext32 = SimulatedGlweCiphertext<u32>;
+
+/// A simulated GLWE ciphertext with 64 bits of precision.
+pub type SimulatedGlweCiphertext64 = SimulatedGlweCiphertext<u64>;
@@ -0,0 +1,35 @@
+use crate::backends::simulation::implementation::entities::SimulationScalar;
+use crate::specification::entities::markers::{BinaryKeyDistribution, GlweSecretKeyKind};
+use crate::specification::entities::{AbstractEntity, GlweSecretKeyEntity};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+use std::marker::PhantomData;
+
+/// A structure representing a simulated GLWE secret key.
+///
+/// A simulated key holds no secret, only the parameters of the key it stands for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedGlweSecretKey<T: SimulationScalar> {
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) _precision: PhantomData<T>,
+}
+impl<T: SimulationScalar> AbstractEntity for SimulatedGlweSecretKey<T> {
+    type Kind = GlweSecretKeyKind;
+}
+impl<T: SimulationScalar> GlweSecretKeyEntity for SimulatedGlweSecretKey<T> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+}
+
+/// A simulated GLWE secret key with 32 bits of precision.
+pub type SimulatedGlweSecretKey32 = SimulatedGlweSecretKey<u32>;
+
+/// A simulated GLWE secret key with 64 bits of precision.
+pub type SimulatedGlweSecretKey64 = SimulatedGlweSecretKey<u64>;
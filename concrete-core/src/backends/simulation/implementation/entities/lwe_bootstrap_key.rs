@@ -0,0 +1,55 @@
+use crate::backends::simulation::implementation::entities::SimulationScalar;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweBootstrapKeyKind};
+use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use std::marker::PhantomData;
+
+/// A structure representing a simulated LWE bootstrap key.
+///
+/// A simulated key holds the parameters needed to predict the noise of a bootstrap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedLweBootstrapKey<T: SimulationScalar> {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+    pub(crate) _precision: PhantomData<T>,
+}
+impl<T: SimulationScalar> AbstractEntity for SimulatedLweBootstrapKey<T> {
+    type Kind = LweBootstrapKeyKind;
+}
+impl<T: SimulationScalar> LweBootstrapKeyEntity for SimulatedLweBootstrapKey<T> {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+}
+
+/// A simulated LWE bootstrap key with 32 bits of precision.
+pub type SimulatedLweBootstrapKey32 = SimulatedLweBootstrapKey<u32>;
+
+/// A simulated LWE bootstrap key with 64 bits of precision.
+pub type SimulatedLweBootstrapKey64 = SimulatedLweBootstrapKey<u64>;
@@ -0,0 +1,44 @@
+use crate::backends::simulation::implementation::entities::SimulationScalar;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweCiphertextKind};
+use crate::specification::entities::{AbstractEntity, LweCiphertextEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LweDimension;
+
+/// A structure representing a simulated LWE ciphertext.
+///
+/// Instead of a mask and a body, a simulated ciphertext holds the exact plaintext it encrypts,
+/// along with the variance of the noise a real ciphertext would carry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedLweCiphertext<T: SimulationScalar> {
+    pub(crate) lwe_dimension: LweDimension,
+    pub(crate) plaintext: T,
+    pub(crate) variance: Variance,
+}
+impl<T: SimulationScalar> AbstractEntity for SimulatedLweCiphertext<T> {
+    type Kind = LweCiphertextKind;
+}
+impl<T: SimulationScalar> LweCiphertextEntity for SimulatedLweCiphertext<T> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
+
+impl<T: SimulationScalar> SimulatedLweCiphertext<T> {
+    /// Returns the plaintext encrypted by the ciphertext, without noise.
+    pub fn plaintext(&self) -> T {
+        self.plaintext
+    }
+
+    /// Returns the predicted variance of the noise of the ciphertext.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+}
+
+/// A simulated LWE ciphertext with 32 bits of precision.
+pub type SimulatedLweCiphertext32 = SimulatedLweCiphertext<u32>;
+
+/// A simulated LWE ciphertext with 64 bits of precision.
+pub type SimulatedLweCiphertext64 = SimulatedLweCiphertext<u64>;
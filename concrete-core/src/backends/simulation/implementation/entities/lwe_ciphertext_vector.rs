@@ -0,0 +1,48 @@
+use crate::backends::simulation::implementation::entities::SimulationScalar;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweCiphertextVectorKind};
+use crate::specification::entities::{AbstractEntity, LweCiphertextVectorEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+
+/// A structure representing a vector of simulated LWE ciphertexts.
+///
+/// Every element holds the exact plaintext it encrypts, along with the predicted variance of its
+/// noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedLweCiphertextVector<T: SimulationScalar> {
+    pub(crate) lwe_dimension: LweDimension,
+    pub(crate) plaintexts: Vec<T>,
+    pub(crate) variances: Vec<Variance>,
+}
+impl<T: SimulationScalar> AbstractEntity for SimulatedLweCiphertextVector<T> {
+    type Kind = LweCiphertextVectorKind;
+}
+impl<T: SimulationScalar> LweCiphertextVectorEntity for SimulatedLweCiphertextVector<T> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.plaintexts.len())
+    }
+}
+
+impl<T: SimulationScalar> SimulatedLweCiphertextVector<T> {
+    /// Returns the plaintexts encrypted by the ciphertexts, without noise.
+    pub fn plaintexts(&self) -> &[T] {
+        &self.plaintexts
+    }
+
+    /// Returns the predicted variances of the noise of the ciphertexts.
+    pub fn variances(&self) -> &[Variance] {
+        &self.variances
+    }
+}
+
+/// A vector of simulated LWE ciphertexts with 32 bits of precision.
+pub type SimulatedLweCiphertextVector32 = SimulatedLweCiphertextVector<u32>;
+
+/// A vector of simulated LWE ciphertexts with 64 bits of precision.
+pub type SimulatedLweCiphertextVector64 = SimulatedLweCiphertextVector<u64>;
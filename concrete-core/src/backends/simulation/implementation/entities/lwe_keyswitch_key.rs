@@ -0,0 +1,48 @@
+use crate::backends::simulation::implementation::entities::SimulationScalar;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweKeyswitchKeyKind};
+use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+use std::marker::PhantomData;
+
+/// A structure representing a simulated LWE keyswitch key.
+///
+/// A simulated key holds the parameters needed to predict the noise of a keyswitch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedLweKeyswitchKey<T: SimulationScalar> {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) output_lwe_dimension: LweDimension,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+    pub(crate) _precision: PhantomData<T>,
+}
+impl<T: SimulationScalar> AbstractEntity for SimulatedLweKeyswitchKey<T> {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl<T: SimulationScalar> LweKeyswitchKeyEntity for SimulatedLweKeyswitchKey<T> {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.output_lwe_dimension
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
+
+/// A simulated LWE keyswitch key with 32 bits of precision.
+pub type SimulatedLweKeyswitchKey32 = SimulatedLweKeyswitchKey<u32>;
+
+/// A simulated LWE keyswitch key with 64 bits of precision.
+pub type SimulatedLweKeyswitchKey64 = SimulatedLweKeyswitchKey<u64>;
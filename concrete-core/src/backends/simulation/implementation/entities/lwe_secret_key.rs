@@ -0,0 +1,30 @@
+use crate::backends::simulation::implementation::entities::SimulationScalar;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweSecretKeyKind};
+use crate::specification::entities::{AbstractEntity, LweSecretKeyEntity};
+use concrete_commons::parameters::LweDimension;
+use std::marker::PhantomData;
+
+/// A structure representing a simulated LWE secret key.
+///
+/// A simulated key holds no secret, only the dimension of the key it stands for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedLweSecretKey<T: SimulationScalar> {
+    pub(crate) lwe_dimension: LweDimension,
+    pub(crate) _precision: PhantomData<T>,
+}
+impl<T: SimulationScalar> AbstractEntity for SimulatedLweSecretKey<T> {
+    type Kind = LweSecretKeyKind;
+}
+impl<T: SimulationScalar> LweSecretKeyEntity for SimulatedLweSecretKey<T> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
+
+/// A simulated LWE secret key with 32 bits of precision.
+pub type SimulatedLweSecretKey32 = SimulatedLweSecretKey<u32>;
+
+/// A simulated LWE secret key with 64 bits of precision.
+pub type SimulatedLweSecretKey64 = SimulatedLweSecretKey<u64>;
@@ -0,0 +1,36 @@
+//! A module containing all the [entities](crate::specification::entities) exposed by the
+//! simulation backend.
+//!
+//! The simulated entities are generic over the integer type used to represent the torus, and
+//! aliases are provided for 32 and 64 bits of precision.
+
+use concrete_commons::numeric::{CastInto, UnsignedInteger};
+use std::fmt::Debug;
+
+mod cleartext;
+mod glwe_ciphertext;
+mod glwe_secret_key;
+mod lwe_bootstrap_key;
+mod lwe_ciphertext;
+mod lwe_ciphertext_vector;
+mod lwe_keyswitch_key;
+mod lwe_secret_key;
+mod plaintext;
+mod plaintext_vector;
+
+pub use cleartext::*;
+pub use glwe_ciphertext::*;
+pub use glwe_secret_key::*;
+pub use lwe_bootstrap_key::*;
+pub use lwe_ciphertext::*;
+pub use lwe_ciphertext_vector::*;
+pub use lwe_keyswitch_key::*;
+pub use lwe_secret_key::*;
+pub use plaintext::*;
+pub use plaintext_vector::*;
+
+/// A trait for the integer types which can be used to represent the torus in the simulated
+/// entities.
+pub trait SimulationScalar: UnsignedInteger + CastInto<usize> + Debug {}
+impl SimulationScalar for u32 {}
+impl SimulationScalar for u64 {}
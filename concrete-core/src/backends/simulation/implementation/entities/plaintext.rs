@@ -0,0 +1,17 @@
+use crate::backends::simulation::implementation::entities::SimulationScalar;
+use crate::specification::entities::markers::PlaintextKind;
+use crate::specification::entities::{AbstractEntity, PlaintextEntity};
+
+/// A structure representing a simulated plaintext.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedPlaintext<T: SimulationScalar>(pub(crate) T);
+impl<T: SimulationScalar> AbstractEntity for SimulatedPlaintext<T> {
+    type Kind = PlaintextKind;
+}
+impl<T: SimulationScalar> PlaintextEntity for SimulatedPlaintext<T> {}
+
+/// A simulated plaintext with 32 bits of precision.
+pub type SimulatedPlaintext32 = SimulatedPlaintext<u32>;
+
+/// A simulated plaintext with 64 bits of precision.
+pub type SimulatedPlaintext64 = SimulatedPlaintext<u64>;
@@ -0,0 +1,22 @@
+use crate::backends::simulation::implementation::entities::SimulationScalar;
+use crate::specification::entities::markers::PlaintextVectorKind;
+use crate::specification::entities::{AbstractEntity, PlaintextVectorEntity};
+use concrete_commons::parameters::PlaintextCount;
+
+/// A structure representing a vector of simulated plaintexts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedPlaintextVector<T: SimulationScalar>(pub(crate) Vec<T>);
+impl<T: SimulationScalar> AbstractEntity for SimulatedPlaintextVector<T> {
+    type Kind = PlaintextVectorKind;
+}
+impl<T: SimulationScalar> PlaintextVectorEntity for SimulatedPlaintextVector<T> {
+    fn plaintext_count(&self) -> PlaintextCount {
+        PlaintextCount(self.0.len())
+    }
+}
+
+/// A vector of simulated plaintexts with 32 bits of precision.
+pub type SimulatedPlaintextVector32 = SimulatedPlaintextVector<u32>;
+
+/// A vector of simulated plaintexts with 64 bits of precision.
+pub type SimulatedPlaintextVector64 = SimulatedPlaintextVector<u64>;
@@ -0,0 +1,2 @@
+pub mod engines;
+pub mod entities;
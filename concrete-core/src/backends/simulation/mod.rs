@@ -0,0 +1,13 @@
+//! A module containing the simulation backend implementation.
+//!
+//! This module contains an implementation of the concrete specification which performs no
+//! cryptography at all. Instead of ciphertexts, it manipulates the exact plaintexts they would
+//! encrypt, along with the variance of their noise, as predicted by `concrete-npe`. This makes it
+//! possible to unit-test the logic of an application quickly, and to cross-check the outcome of
+//! the fixtures of an actual backend against the predicted variances.
+//!
+//! Since the secret keys hold no secret, this backend must never be used to protect data.
+
+mod implementation;
+
+pub use implementation::{engines, entities};
@@ -32,7 +32,19 @@
 //!
 //! The different backends can be activated using the feature flags `backend_*`. The `backend_core`
 //! contains an engine executing operations on a single thread of the cpu. It is activated by
-//! default.
+//! default. The `backend_simulation` contains an engine which performs no cryptography, and only
+//! tracks the plaintexts and the predicted noise variances of the ciphertexts; it is meant for
+//! testing purposes. The `backend_reference` contains an engine implementing the operations with
+//! textbook algorithms; it is slow, but easy to audit, and serves as a ground truth for the other
+//! backends. The `backend_cuda` contains an engine executing the keyswitch and the bootstrap on an
+//! nvidia gpu, on entities stored in the gpu memory; it requires the cuda toolkit to be installed.
+//! The `backend_multithread` contains an engine bootstrapping the ciphertext vectors of the
+//! `backend_core` on a pool of threads.
+//!
+//! The `insecure_debug` feature activates the `debug` backend, whose engine executes the
+//! algorithms of the `reference` backend deterministically and without noise. It offers no
+//! security at all, and its engine can only be created when the `CONCRETE_INSECURE_DEBUG`
+//! environment variable is set to `1`.
 //!
 //! # Navigating the code
 //!
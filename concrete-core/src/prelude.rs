@@ -1,4 +1,7 @@
 #![doc(hidden)]
+// Every backend exposes its own `IMPLEMENTATIONS` table in its `engines` module, which is meant to
+// be accessed through the path of the backend, and not through the prelude.
+#![allow(ambiguous_glob_reexports)]
 
 // Expose concrete_commons types in the prelude
 // This avoids having to add concrete-commons as a dependency
@@ -12,5 +15,21 @@ pub use concrete_commons::*;
 pub use super::backends::core::engines::*;
 #[cfg(feature = "backend_core")]
 pub use super::backends::core::entities::*;
+#[cfg(feature = "backend_cuda")]
+pub use super::backends::cuda::engines::*;
+#[cfg(feature = "backend_cuda")]
+pub use super::backends::cuda::entities::*;
+#[cfg(feature = "insecure_debug")]
+pub use super::backends::debug::engines::*;
+#[cfg(feature = "backend_multithread")]
+pub use super::backends::multithread::engines::*;
+#[cfg(feature = "backend_reference")]
+pub use super::backends::reference::engines::*;
+#[cfg(feature = "backend_reference")]
+pub use super::backends::reference::entities::*;
+#[cfg(feature = "backend_simulation")]
+pub use super::backends::simulation::engines::*;
+#[cfg(feature = "backend_simulation")]
+pub use super::backends::simulation::entities::*;
 pub use super::specification::engines::*;
 pub use super::specification::entities::*;
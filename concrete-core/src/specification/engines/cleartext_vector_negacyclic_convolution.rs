@@ -0,0 +1,85 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::CleartextVectorEntity;
+use concrete_commons::parameters::PolynomialSize;
+
+engine_error! {
+    CleartextVectorNegacyclicConvolutionError for CleartextVectorNegacyclicConvolutionEngine @
+    CleartextCountMismatch => "The two input cleartext vectors must have the same cleartext count.",
+    InvalidPolynomialSize => "The cleartext count must be a non-zero multiple of the polynomial \
+                              size."
+}
+
+impl<EngineError: std::error::Error> CleartextVectorNegacyclicConvolutionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCleartextVector1, InputCleartextVector2>(
+        input1: &InputCleartextVector1,
+        input2: &InputCleartextVector2,
+        polynomial_size: PolynomialSize,
+    ) -> Result<(), Self>
+    where
+        InputCleartextVector1: CleartextVectorEntity,
+        InputCleartextVector2: CleartextVectorEntity,
+    {
+        if input1.cleartext_count() != input2.cleartext_count() {
+            return Err(Self::CleartextCountMismatch);
+        }
+        if polynomial_size.0 == 0 || !input1.cleartext_count().0.is_multiple_of(polynomial_size.0) {
+            return Err(Self::InvalidPolynomialSize);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the negacyclic convolution of two cleartext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a cleartext vector containing the
+/// polynomial-wise product of the `input1` and `input2` cleartext vectors, both interpreted as
+/// lists of polynomials of size `polynomial_size`, reduced modulo $X^N + 1$.
+///
+/// This is the reference computation of the operations acting on GLWE plaintexts, such as the
+/// tensor product of GLWE ciphertexts.
+///
+/// # Formal Definition
+///
+/// The cleartext vectors are split in polynomials $A\_p = \sum\_{i=0}^{N-1} a\_{p,i} X^i$ and
+/// $B\_p = \sum\_{i=0}^{N-1} b\_{p,i} X^i$, whose coefficients are the consecutive cleartexts of
+/// the vectors. The $p$-th polynomial of the output is:
+/// $$
+/// A\_p \cdot B\_p \mod (X^N + 1) = \sum\_{i=0}^{N-1} \left( \sum\_{j=0}^{i} a\_{p,j}
+/// b\_{p,i-j} - \sum\_{j=i+1}^{N-1} a\_{p,j} b\_{p,N+i-j} \right) X^i
+/// $$
+/// where all the arithmetic is wrapping, that is, computed modulo the native modulus of the
+/// cleartexts.
+pub trait CleartextVectorNegacyclicConvolutionEngine<
+    InputCleartextVector1,
+    InputCleartextVector2,
+    OutputCleartextVector,
+>: AbstractEngine where
+    InputCleartextVector1: CleartextVectorEntity,
+    InputCleartextVector2: CleartextVectorEntity,
+    OutputCleartextVector: CleartextVectorEntity,
+{
+    /// Computes the negacyclic convolution of two cleartext vectors.
+    fn convolve_cleartext_vector(
+        &mut self,
+        input1: &InputCleartextVector1,
+        input2: &InputCleartextVector2,
+        polynomial_size: PolynomialSize,
+    ) -> Result<OutputCleartextVector, CleartextVectorNegacyclicConvolutionError<Self::EngineError>>;
+
+    /// Unsafely computes the negacyclic convolution of two cleartext vectors.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`CleartextVectorNegacyclicConvolutionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn convolve_cleartext_vector_unchecked(
+        &mut self,
+        input1: &InputCleartextVector1,
+        input2: &InputCleartextVector2,
+        polynomial_size: PolynomialSize,
+    ) -> OutputCleartextVector;
+}
@@ -0,0 +1,38 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::AbstractEntity;
+
+engine_error! {
+    EntityConstantTimeEqualityError for EntityConstantTimeEqualityEngine @
+}
+
+/// A trait for engines comparing entities in constant time.
+///
+/// # Semantics
+///
+/// This operation returns whether the `lhs` and `rhs` entities are equal, that is, whether they
+/// have the same structural parameters and the same content.
+///
+/// The content of the entities is compared in a time which only depends on its size. This is
+/// useful for integrity checks, where a received ciphertext is compared against a commitment: a
+/// comparison stopping at the first difference would leak its position through timing. The
+/// structural parameters are public, and entities with different parameters are unequal.
+pub trait EntityConstantTimeEqualityEngine<Entity>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Compares two entities in constant time.
+    fn constant_time_eq(
+        &mut self,
+        lhs: &Entity,
+        rhs: &Entity,
+    ) -> Result<bool, EntityConstantTimeEqualityError<Self::EngineError>>;
+
+    /// Unsafely compares two entities in constant time.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityConstantTimeEqualityError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn constant_time_eq_unchecked(&mut self, lhs: &Entity, rhs: &Entity) -> bool;
+}
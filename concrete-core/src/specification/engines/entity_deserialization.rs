@@ -0,0 +1,34 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::AbstractEntity;
+
+engine_error! {
+    EntityDeserializationError for EntityDeserializationEngine @
+}
+
+/// A trait for engines deserializing entities.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an entity from the `serialized`
+/// value, as produced by an [`EntitySerializationEngine`](super::EntitySerializationEngine) of the
+/// same backend.
+pub trait EntityDeserializationEngine<Serialized, Entity>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Deserializes an entity.
+    fn deserialize(
+        &mut self,
+        serialized: Serialized,
+    ) -> Result<Entity, EntityDeserializationError<Self::EngineError>>;
+
+    /// Unsafely deserializes an entity.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityDeserializationError`]. For safety concerns _specific_ to an engine, refer to the
+    /// implementer safety section. In particular, the `serialized` value must be a valid
+    /// serialization of an entity of the right type.
+    unsafe fn deserialize_unchecked(&mut self, serialized: Serialized) -> Entity;
+}
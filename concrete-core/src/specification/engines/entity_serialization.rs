@@ -0,0 +1,38 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::AbstractEntity;
+
+engine_error! {
+    EntitySerializationError for EntitySerializationEngine @
+}
+
+/// A trait for engines serializing entities.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a `Serialized` value containing the
+/// `entity` entity, which can be stored or sent over the network. The entity can be recovered from
+/// this value with an [`EntityDeserializationEngine`](super::EntityDeserializationEngine) of the
+/// same backend.
+///
+/// The serialized format is backend-specific. Backends are expected to embed a version of the
+/// format in the serialized value, so that entities serialized with an older version of the
+/// library can still be deserialized.
+pub trait EntitySerializationEngine<Entity, Serialized>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Serializes an entity.
+    fn serialize(
+        &mut self,
+        entity: &Entity,
+    ) -> Result<Serialized, EntitySerializationError<Self::EngineError>>;
+
+    /// Unsafely serializes an entity.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntitySerializationError`]. For safety concerns _specific_ to an engine, refer to the
+    /// implementer safety section.
+    unsafe fn serialize_unchecked(&mut self, entity: &Entity) -> Serialized;
+}
@@ -0,0 +1,1266 @@
+//! A module containing the stable error codes attached to the engine errors.
+//!
+//! Every variant of every `*Error` type declared in the specification is given a numeric code
+//! which does not change across versions of the library. This allows foreign bindings and logging
+//! systems to map failures consistently, without relying on the (unstable) names of the variants.
+//!
+//! # Code allocation
+//!
+//! Each error type is allocated a block of 100 codes. The first code of the block is attached to
+//! the `Engine` variant, and the generic variants take the following codes, in declaration order.
+//! Codes are never reassigned: when an error type or a variant gets removed, its code is retired,
+//! and new error types take the next free block.
+//!
+//! The `engine_error!` macro checks at compile time that the error it declares is registered in
+//! the table below, so a new error type can not be added without its codes.
+
+use super::*;
+
+/// A stable numeric code identifying an engine error variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ErrorCode(pub u32);
+
+/// An entry of the [`ERROR_CODES`] table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorCodeEntry {
+    /// The code attached to the variant.
+    pub code: ErrorCode,
+    /// The name of the error type.
+    pub error: &'static str,
+    /// The name of the variant.
+    pub variant: &'static str,
+}
+
+/// A trait implemented by the engine errors, giving access to the stable code of a variant.
+pub trait EngineErrorCode {
+    /// Returns the code attached to the error variant.
+    fn error_code(&self) -> ErrorCode;
+}
+
+macro_rules! error_codes {
+    ($($error:ident { $($variant:ident => $code:literal),+ $(,)? }),+ $(,)?) => {
+        $(
+            impl<EngineError: std::error::Error> EngineErrorCode for $error<EngineError> {
+                fn error_code(&self) -> ErrorCode {
+                    match self {
+                        $(Self::$variant { .. } => ErrorCode($code),)+
+                    }
+                }
+            }
+        )+
+
+        /// The table of all the error codes, ordered by code.
+        pub const ERROR_CODES: &[ErrorCodeEntry] = &[
+            $($(
+                ErrorCodeEntry {
+                    code: ErrorCode($code),
+                    error: stringify!($error),
+                    variant: stringify!($variant),
+                },
+            )+)+
+        ];
+    };
+}
+
+error_codes! {
+    CleartextConversionError { Engine => 100 },
+    CleartextCreationError { Engine => 200 },
+    CleartextDiscardingConversionError { Engine => 300 },
+    CleartextDiscardingRetrievalError { Engine => 400 },
+    CleartextEncodingError { Engine => 500 },
+    CleartextRetrievalError { Engine => 600 },
+    CleartextVectorConversionError { Engine => 700 },
+    CleartextVectorCreationError { Engine => 800, EmptyInput => 801 },
+    CleartextVectorDiscardingConversionError { Engine => 900, CleartextCountMismatch => 901 },
+    CleartextVectorDiscardingRetrievalError { Engine => 1000, CleartextCountMismatch => 1001 },
+    CleartextVectorEncodingError { Engine => 1100, EncoderCountMismatch => 1101 },
+    CleartextVectorRetrievalError { Engine => 1200 },
+    DestructionError { Engine => 1300 },
+    GgswCiphertextConversionError { Engine => 1400 },
+    GgswCiphertextDiscardingConversionError {
+        Engine => 1500,
+        GlweDimensionMismatch => 1501,
+        PolynomialSizeMismatch => 1502,
+        DecompositionLevelCountMismatch => 1503,
+        DecompositionBaseLogMismatch => 1504,
+    },
+    GgswCiphertextScalarDiscardingEncryptionError {
+        Engine => 1600,
+        GlweDimensionMismatch => 1601,
+        PolynomialSizeMismatch => 1602,
+    },
+    GgswCiphertextScalarEncryptionError { Engine => 1700 },
+    GgswCiphertextScalarTrivialEncryptionError { Engine => 1800 },
+    GlweCiphertextConversionError { Engine => 1900 },
+    GlweCiphertextDecryptionError {
+        Engine => 2000,
+        GlweDimensionMismatch => 2001,
+        PolynomialSizeMismatch => 2002,
+    },
+    GlweCiphertextDiscardingConversionError {
+        Engine => 2100,
+        GlweDimensionMismatch => 2101,
+        PolynomialSizeMismatch => 2102,
+    },
+    GlweCiphertextDiscardingDecryptionError {
+        Engine => 2200,
+        GlweDimensionMismatch => 2201,
+        PolynomialSizeMismatch => 2202,
+        PlaintextCountMismatch => 2203,
+    },
+    GlweCiphertextDiscardingEncryptionError {
+        Engine => 2300,
+        GlweDimensionMismatch => 2301,
+        PolynomialSizeMismatch => 2302,
+        PlaintextCountMismatch => 2303,
+    },
+    GlweCiphertextEncryptionError { Engine => 2400, PlaintextCountMismatch => 2401 },
+    GlweCiphertextGgswCiphertextDiscardingExternalProductError {
+        Engine => 2500,
+        PolynomialSizeMismatch => 2501,
+        GlweDimensionMismatch => 2502,
+    },
+    GlweCiphertextGgswCiphertextExternalProductError {
+        Engine => 2600,
+        PolynomialSizeMismatch => 2601,
+        GlweDimensionMismatch => 2602,
+    },
+    GlweCiphertextTrivialDecryptionError { Engine => 2700 },
+    GlweCiphertextTrivialEncryptionError { Engine => 2800 },
+    GlweCiphertextVectorConversionError { Engine => 2900 },
+    GlweCiphertextVectorDecryptionError {
+        Engine => 3000,
+        GlweDimensionMismatch => 3001,
+        PolynomialSizeMismatch => 3002,
+    },
+    GlweCiphertextVectorDiscardingConversionError {
+        Engine => 3100,
+        GlweDimensionMismatch => 3101,
+        PolynomialSizeMismatch => 3102,
+        CiphertextCountMismatch => 3103,
+    },
+    GlweCiphertextVectorDiscardingDecryptionError {
+        Engine => 3200,
+        GlweDimensionMismatch => 3201,
+        PolynomialSizeMismatch => 3202,
+        PlaintextCountMismatch => 3203,
+    },
+    GlweCiphertextVectorDiscardingEncryptionError {
+        Engine => 3300,
+        GlweDimensionMismatch => 3301,
+        PolynomialSizeMismatch => 3302,
+        PlaintextCountMismatch => 3303,
+    },
+    GlweCiphertextVectorEncryptionError { Engine => 3400, PlaintextCountMismatch => 3401 },
+    GlweCiphertextVectorTrivialDecryptionError { Engine => 3500 },
+    GlweCiphertextVectorTrivialEncryptionError { Engine => 3600, PlaintextCountMismatch => 3601 },
+    GlweCiphertextVectorZeroEncryptionError { Engine => 3700, NullCiphertextCount => 3701 },
+    GlweCiphertextZeroEncryptionError { Engine => 3800 },
+    GlweSecretKeyConversionError { Engine => 3900 },
+    GlweSecretKeyCreationError {
+        Engine => 4000,
+        NullGlweDimension => 4001,
+        NullPolynomialSize => 4002,
+        SizeOnePolynomial => 4003,
+    },
+    GlweSecretKeyDiscardingConversionError {
+        Engine => 4100,
+        GlweDimensionMismatch => 4101,
+        PolynomialSizeMismatch => 4102,
+    },
+    GlweToLweSecretKeyTransmutationEngineError { Engine => 4200 },
+    LweBootstrapKeyConversionError { Engine => 4300 },
+    LweBootstrapKeyCreationError {
+        Engine => 4400,
+        NullDecompositionBaseLog => 4401,
+        NullDecompositionLevelCount => 4402,
+        DecompositionTooLarge => 4403,
+    },
+    LweBootstrapKeyDiscardingConversionError {
+        Engine => 4500,
+        LweDimensionMismatch => 4501,
+        GlweDimensionMismatch => 4502,
+        PolynomialSizeMismatch => 4503,
+        DecompositionBaseLogMismatch => 4504,
+        DecompositionLevelCountMismatch => 4505,
+    },
+    LweCiphertextCleartextDiscardingMultiplicationError {
+        Engine => 4600,
+        LweDimensionMismatch => 4601,
+    },
+    LweCiphertextCleartextFusingMultiplicationError { Engine => 4700 },
+    LweCiphertextConversionError { Engine => 4800 },
+    LweCiphertextDecryptionError { Engine => 4900 },
+    LweCiphertextDiscardingAdditionError { Engine => 5000, LweDimensionMismatch => 5001 },
+    LweCiphertextDiscardingBootstrapError {
+        Engine => 5100,
+        InputLweDimensionMismatch => 5101,
+        OutputLweDimensionMismatch => 5102,
+        AccumulatorPolynomialSizeMismatch => 5103,
+        AccumulatorGlweDimensionMismatch => 5104,
+    },
+    LweCiphertextDiscardingConversionError { Engine => 5200, LweDimensionMismatch => 5201 },
+    LweCiphertextDiscardingDecryptionError { Engine => 5300, LweDimensionMismatch => 5301 },
+    LweCiphertextDiscardingEncryptionError { Engine => 5400, LweDimensionMismatch => 5401 },
+    LweCiphertextDiscardingExtractionError {
+        Engine => 5500,
+        SizeMismatch => 5501,
+        MonomialIndexTooLarge => 5502,
+    },
+    LweCiphertextDiscardingKeyswitchError {
+        Engine => 5600,
+        InputLweDimensionMismatch => 5601,
+        OutputLweDimensionMismatch => 5602,
+    },
+    LweCiphertextDiscardingLoadingError {
+        Engine => 5700,
+        LweDimensionMismatch => 5701,
+        IndexTooLarge => 5702,
+    },
+    LweCiphertextDiscardingOppositeError { Engine => 5800, LweDimensionMismatch => 5801 },
+    LweCiphertextDiscardingStoringError {
+        Engine => 5900,
+        LweDimensionMismatch => 5901,
+        IndexTooLarge => 5902,
+    },
+    LweCiphertextDiscardingSubtractionError { Engine => 6000, LweDimensionMismatch => 6001 },
+    LweCiphertextEncryptionError { Engine => 6100 },
+    LweCiphertextFusingAdditionError { Engine => 6200, LweDimensionMismatch => 6201 },
+    LweCiphertextFusingOppositeError { Engine => 6300 },
+    LweCiphertextFusingSubtractionError { Engine => 6400, LweDimensionMismatch => 6401 },
+    LweCiphertextLoadingError { Engine => 6500, IndexTooLarge => 6501 },
+    LweCiphertextPlaintextDiscardingAdditionError { Engine => 6600, LweDimensionMismatch => 6601 },
+    LweCiphertextPlaintextDiscardingSubtractionError {
+        Engine => 6700,
+        LweDimensionMismatch => 6701,
+    },
+    LweCiphertextPlaintextFusingAdditionError { Engine => 6800 },
+    LweCiphertextPlaintextFusingSubtractionError { Engine => 6900 },
+    LweCiphertextTrivialDecryptionError { Engine => 7000 },
+    LweCiphertextTrivialEncryptionError { Engine => 7100 },
+    LweCiphertextVectorConversionError { Engine => 7200 },
+    LweCiphertextVectorDecryptionError { Engine => 7300, LweDimensionMismatch => 7301 },
+    LweCiphertextVectorDiscardingAdditionError {
+        Engine => 7400,
+        LweDimensionMismatch => 7401,
+        CiphertextCountMismatch => 7402,
+    },
+    LweCiphertextVectorDiscardingAffineTransformationError {
+        Engine => 7500,
+        LweDimensionMismatch => 7501,
+        CleartextCountMismatch => 7502,
+    },
+    LweCiphertextVectorDiscardingBootstrapError {
+        Engine => 7600,
+        InputLweDimensionMismatch => 7601,
+        OutputLweDimensionMismatch => 7602,
+        AccumulatorGlweDimensionMismatch => 7603,
+        AccumulatorPolynomialSizeMismatch => 7604,
+        AccumulatorCountMismatch => 7605,
+        CiphertextCountMismatch => 7606,
+    },
+    LweCiphertextVectorDiscardingConversionError {
+        Engine => 7700,
+        LweDimensionMismatch => 7701,
+        CiphertextCountMismatch => 7702,
+    },
+    LweCiphertextVectorDiscardingDecryptionError {
+        Engine => 7800,
+        LweDimensionMismatch => 7801,
+        PlaintextCountMismatch => 7802,
+    },
+    LweCiphertextVectorDiscardingEncryptionError {
+        Engine => 7900,
+        LweDimensionMismatch => 7901,
+        PlaintextCountMismatch => 7902,
+    },
+    LweCiphertextVectorDiscardingKeyswitchError {
+        Engine => 8000,
+        InputLweDimensionMismatch => 8001,
+        OutputLweDimensionMismatch => 8002,
+        CiphertextCountMismatch => 8003,
+    },
+    LweCiphertextVectorDiscardingLoadingError {
+        Engine => 8100,
+        LweDimensionMismatch => 8101,
+        UnorderedInputRange => 8102,
+        OutOfVectorInputRange => 8103,
+        UnorderedOutputRange => 8104,
+        OutOfVectorOutputRange => 8105,
+        RangeSizeMismatch => 8106,
+    },
+    LweCiphertextVectorDiscardingOppositeError {
+        Engine => 8200,
+        LweDimensionMismatch => 8201,
+        CiphertextCountMismatch => 8202,
+    },
+    LweCiphertextVectorDiscardingSubtractionError {
+        Engine => 8300,
+        LweDimensionMismatch => 8301,
+        CiphertextCountMismatch => 8302,
+    },
+    LweCiphertextVectorEncryptionError { Engine => 8400 },
+    LweCiphertextVectorFusingAdditionError {
+        Engine => 8500,
+        LweDimensionMismatch => 8501,
+        CiphertextCountMismatch => 8502,
+    },
+    LweCiphertextVectorFusingOppositeError { Engine => 8600 },
+    LweCiphertextVectorFusingSubtractionError {
+        Engine => 8700,
+        LweDimensionMismatch => 8701,
+        CiphertextCountMismatch => 8702,
+    },
+    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError {
+        Engine => 8800,
+        InputLweDimensionMismatch => 8801,
+        OutputGlweDimensionMismatch => 8802,
+        OutputPolynomialSizeMismatch => 8803,
+        CiphertextCountMismatch => 8804,
+    },
+    LweCiphertextVectorLoadingError {
+        Engine => 8900,
+        UnorderedInputRange => 8901,
+        OutOfVectorInputRange => 8902,
+    },
+    LweCiphertextVectorTrivialDecryptionError { Engine => 9000 },
+    LweCiphertextVectorTrivialEncryptionError { Engine => 9100 },
+    LweCiphertextVectorZeroEncryptionError { Engine => 9200, NullCiphertextCount => 9201 },
+    LweCiphertextZeroEncryptionError { Engine => 9300 },
+    LweKeyswitchKeyConversionError { Engine => 9400 },
+    LweKeyswitchKeyCreationError {
+        Engine => 9500,
+        NullDecompositionBaseLog => 9501,
+        NullDecompositionLevelCount => 9502,
+        DecompositionTooLarge => 9503,
+    },
+    LweKeyswitchKeyDiscardingConversionError {
+        Engine => 9600,
+        InputLweDimensionMismatch => 9601,
+        OutputLweDimensionMismatch => 9602,
+        DecompositionBaseLogMismatch => 9603,
+        DecompositionLevelCountMismatch => 9604,
+    },
+    LweSecretKeyConversionError { Engine => 9700 },
+    LweSecretKeyCreationError { Engine => 9800, NullLweDimension => 9801 },
+    LweSecretKeyDiscardingConversionError { Engine => 9900, LweDimensionMismatch => 9901 },
+    PackingKeyswitchKeyCreationError {
+        Engine => 10000,
+        NullDecompositionBaseLog => 10001,
+        NullDecompositionLevelCount => 10002,
+        DecompositionTooLarge => 10003,
+    },
+    PlaintextConversionError { Engine => 10100 },
+    PlaintextCreationError { Engine => 10200 },
+    PlaintextDecodingError { Engine => 10300 },
+    PlaintextDiscardingConversionError { Engine => 10400 },
+    PlaintextDiscardingRetrievalError { Engine => 10500 },
+    PlaintextRetrievalError { Engine => 10600 },
+    PlaintextVectorConversionError { Engine => 10700 },
+    PlaintextVectorCreationError { Engine => 10800, EmptyInput => 10801 },
+    PlaintextVectorDecodingError { Engine => 10900, EncoderCountMismatch => 10901 },
+    PlaintextVectorDiscardingConversionError { Engine => 11000, PlaintextCountMismatch => 11001 },
+    PlaintextVectorDiscardingRetrievalError { Engine => 11100, PlaintextCountMismatch => 11101 },
+    PlaintextVectorRetrievalError { Engine => 11200 },
+    LweCiphertextVectorDiscardingPermutationError {
+        Engine => 11300,
+        LweDimensionMismatch => 11301,
+        CiphertextCountMismatch => 11302,
+        CiphertextCountNotPowerOfTwo => 11303,
+        SwitchCountMismatch => 11304,
+        SwitchPolynomialSizeMismatch => 11305,
+        SwitchGlweDimensionMismatch => 11306,
+    },
+    GgswSeededCiphertextScalarEncryptionError { Engine => 11400 },
+    GgswSeededCiphertextExpansionError { Engine => 11500 },
+    LweCiphertextVectorCreationError {
+        Engine => 11800,
+        EmptyContainer => 11801,
+        NullLweSize => 11802,
+        InvalidContainerSize => 11803,
+    },
+    LweCiphertextVectorConsumingRetrievalError { Engine => 11900 },
+    LweBootstrapKeyConstructionError {
+        Engine => 12000,
+        EmptyContainer => 12001,
+        NullDecompositionBaseLog => 12002,
+        NullDecompositionLevelCount => 12003,
+        DecompositionTooLarge => 12004,
+        InvalidContainerSize => 12005,
+    },
+    LweBootstrapKeyConsumingRetrievalError { Engine => 12100 },
+    LweCiphertextCleartextFusingMultiplyAddError {
+        Engine => 12200,
+        LweDimensionMismatch => 12201,
+    },
+    LweSecretKeyShrinkingError {
+        Engine => 12300,
+        NullLweDimension => 12301,
+        OutputLweDimensionTooLarge => 12302,
+    },
+    LweShrinkingKeyswitchKeyCreationError {
+        Engine => 12400,
+        NullDecompositionBaseLog => 12401,
+        NullDecompositionLevelCount => 12402,
+        DecompositionTooLarge => 12403,
+        OutputLweDimensionTooLarge => 12404,
+    },
+    LweCiphertextDiscardingShrinkingKeyswitchError {
+        Engine => 12500,
+        InputLweDimensionMismatch => 12501,
+        OutputLweDimensionMismatch => 12502,
+    },
+    GlweCiphertextDiscardingTensorProductError {
+        Engine => 12600,
+        PolynomialSizeMismatch => 12601,
+        GlweDimensionMismatch => 12602,
+        OutputGlweDimensionMismatch => 12603,
+    },
+    CleartextVectorNegacyclicConvolutionError {
+        Engine => 12700,
+        CleartextCountMismatch => 12701,
+        InvalidPolynomialSize => 12702,
+    },
+    GlweAutomorphismKeyCreationError {
+        Engine => 12800,
+        NullDecompositionBaseLog => 12801,
+        NullDecompositionLevelCount => 12802,
+        DecompositionTooLarge => 12803,
+        InvalidPolynomialSize => 12804,
+    },
+    GlweCiphertextTraceError {
+        Engine => 12900,
+        GlweDimensionMismatch => 12901,
+        PolynomialSizeMismatch => 12902,
+    },
+    GgswCiphertextCleartextTrivialEncryptionError {
+        Engine => 13000,
+        NullDecompositionBaseLog => 13001,
+        NullDecompositionLevelCount => 13002,
+        DecompositionTooLarge => 13003,
+    },
+    LweCiphertextVectorNoiseStatisticsError {
+        Engine => 13100,
+        LweDimensionMismatch => 13101,
+        NullMessageBitCount => 13102,
+        MessageBitCountTooLarge => 13103,
+    },
+    LweCiphertextDiscardingLargeTableLookupError {
+        Engine => 13200,
+        InputLweDimensionMismatch => 13201,
+        OutputLweDimensionMismatch => 13202,
+        TableSizeNotPowerOfTwo => 13203,
+        SelectorCountMismatch => 13204,
+        SelectorPolynomialSizeMismatch => 13205,
+        SelectorGlweDimensionMismatch => 13206,
+    },
+    LweCiphertextGadgetProductError {
+        Engine => 13300,
+        InputLweDimensionMismatch => 13301,
+        NullDecompositionLevelCount => 13302,
+        DecompositionLevelCountTooLarge => 13303,
+    },
+    GlweCiphertextDiscardingAdditionError {
+        Engine => 13400,
+        GlweDimensionMismatch => 13401,
+        PolynomialSizeMismatch => 13402,
+    },
+    GlweCiphertextCleartextVectorDiscardingMultiplicationError {
+        Engine => 13500,
+        GlweDimensionMismatch => 13501,
+        PolynomialSizeMismatch => 13502,
+        CleartextCountMismatch => 13503,
+    },
+    LweCiphertextVectorDiscardingGatherError {
+        Engine => 13600,
+        LweDimensionMismatch => 13601,
+        IndexCountMismatch => 13602,
+        OutOfVectorInputIndex => 13603,
+    },
+    LweCiphertextVectorDiscardingScatterError {
+        Engine => 13700,
+        LweDimensionMismatch => 13701,
+        IndexCountMismatch => 13702,
+        OutOfVectorOutputIndex => 13703,
+        DuplicateIndex => 13704,
+    },
+    EntityConstantTimeEqualityError {
+        Engine => 13800,
+    },
+    LweCiphertextAccumulationError {
+        Engine => 13900,
+        InputLweDimensionMismatch => 13901,
+        KeyswitchKeyInputLweDimensionMismatch => 13902,
+        KeyswitchKeyOutputLweDimensionMismatch => 13903,
+        BootstrapKeyOutputLweDimensionMismatch => 13904,
+    },
+    GlweCiphertextDiscardingOffsetAdditionError {
+        Engine => 14000,
+        GlweDimensionMismatch => 14001,
+        PolynomialSizeMismatch => 14002,
+        OffsetOutOfBounds => 14003,
+    },
+    GgswCiphertextConsumingRetrievalError { Engine => 14100 },
+    LweKeyswitchKeyBatchCreationError {
+        Engine => 14200,
+        NullDecompositionBaseLog => 14201,
+        NullDecompositionLevelCount => 14202,
+        DecompositionTooLarge => 14203,
+    },
+    LweKeyswitchKeyBatchAppendingError {
+        Engine => 14300,
+        InputLweDimensionMismatch => 14301,
+        OutputLweDimensionMismatch => 14302,
+        DecompositionLevelCountMismatch => 14303,
+        DecompositionBaseLogMismatch => 14304,
+    },
+    LweKeyswitchKeyBatchRemovalError {
+        Engine => 14400,
+        IndexOutOfBounds => 14401,
+    },
+    LweCiphertextDiscardingBatchedKeyswitchError {
+        Engine => 14500,
+        InputLweDimensionMismatch => 14501,
+        OutputLweDimensionMismatch => 14502,
+        IndexOutOfBounds => 14503,
+    },
+    LweCiphertextCleartextDiscardingRationalMultiplicationError {
+        Engine => 14600,
+        LweDimensionMismatch => 14601,
+        DenominatorLogTooLarge => 14602,
+    },
+    LweCiphertextVectorPhaseExportingDecryptionError {
+        Engine => 14700,
+        LweDimensionMismatch => 14701,
+        NullMessageBitCount => 14702,
+        MessageBitCountTooLarge => 14703,
+    },
+    LwePhaseVectorRetrievalError { Engine => 14800 },
+    LweSecretKeyDerivationError { Engine => 14900, NullLweDimension => 14901 },
+    GlweRelinearizationKeyCreationError {
+        Engine => 15000,
+        NullDecompositionBaseLog => 15001,
+        NullDecompositionLevelCount => 15002,
+        DecompositionTooLarge => 15003,
+    },
+    GlweCiphertextDiscardingRelinearizationError {
+        Engine => 15100,
+        PolynomialSizeMismatch => 15101,
+        OutputGlweDimensionMismatch => 15102,
+        InputGlweDimensionMismatch => 15103,
+    },
+    LweCiphertextDiscardingMultiplicationError {
+        Engine => 15200,
+        InputLweDimensionMismatch => 15201,
+        PackingKeyInputLweDimensionMismatch => 15202,
+        GlweDimensionMismatch => 15203,
+        PolynomialSizeMismatch => 15204,
+        OutputLweDimensionMismatch => 15205,
+    },
+    LweCiphertextVectorDiscardingParityError {
+        Engine => 15300,
+        EmptyInput => 15301,
+        OutputLweDimensionMismatch => 15302,
+        KeyswitchKeyInputLweDimensionMismatch => 15303,
+        KeyswitchKeyOutputLweDimensionMismatch => 15304,
+        BootstrapKeyOutputLweDimensionMismatch => 15305,
+        NullMessageBitCount => 15306,
+        MessageBitCountTooLarge => 15307,
+    },
+    LweCiphertextVectorDiscardingPopcountError {
+        Engine => 15400,
+        EmptyInput => 15401,
+        OutputLweDimensionMismatch => 15402,
+        KeyswitchKeyInputLweDimensionMismatch => 15403,
+        KeyswitchKeyOutputLweDimensionMismatch => 15404,
+        BootstrapKeyOutputLweDimensionMismatch => 15405,
+        MessageBitCountTooLarge => 15406,
+        CiphertextCountTooLarge => 15407,
+    },
+    LweSeededCiphertextEncryptionError { Engine => 15500 },
+    LweSeededCiphertextExpansionError { Engine => 15600 },
+    LweSeededBootstrapKeyCreationError {
+        Engine => 15700,
+        NullDecompositionBaseLog => 15701,
+        NullDecompositionLevelCount => 15702,
+        DecompositionTooLarge => 15703,
+    },
+    LweSeededBootstrapKeyExpansionError { Engine => 15800 },
+    EntitySerializationError { Engine => 15900 },
+    EntityDeserializationError { Engine => 16000 },
+    LweCiphertextFusingNoiseInjectionError {
+        Engine => 16100,
+        TargetVarianceTooSmall => 16101,
+    },
+    LwePublicKeyCreationError {
+        Engine => 16200,
+        NullPublicKeyZeroEncryptionCount => 16201,
+    },
+    LweCiphertextPublicKeyEncryptionError { Engine => 16300 },
+    LweKeyswitchKeyDeltaCreationError {
+        Engine => 16400,
+        OldOutputLweDimensionMismatch => 16401,
+        NewOutputLweDimensionMismatch => 16402,
+    },
+    LweKeyswitchKeyFusingUpdateError {
+        Engine => 16500,
+        InputLweDimensionMismatch => 16501,
+        OutputLweDimensionMismatch => 16502,
+        DecompositionBaseLogMismatch => 16503,
+        DecompositionLevelCountMismatch => 16504,
+    },
+    LweCiphertextDiscardingModulusSwitchError {
+        Engine => 16600,
+        LweDimensionMismatch => 16601,
+        NullModulusLog => 16602,
+        ModulusLogTooLarge => 16603,
+        ModulusLogExceedsOutputPrecision => 16604,
+    },
+    LweCiphertextDiscardingTrivialEncryptionError { Engine => 16700 },
+    GlweCiphertextDiscardingTrivialEncryptionError {
+        Engine => 16800,
+        PlaintextCountMismatch => 16801,
+    },
+    LweCiphertextVectorPlaintextVectorDiscardingSubtractionError {
+        Engine => 16900,
+        LweDimensionMismatch => 16901,
+        CiphertextCountMismatch => 16902,
+        PlaintextCountMismatch => 16903,
+    },
+    LweCiphertextVectorPlaintextVectorFusingSubtractionError {
+        Engine => 17000,
+        PlaintextCountMismatch => 17001,
+    },
+    GlweCiphertextBlindRotationError {
+        Engine => 17100,
+        InputLweDimensionMismatch => 17101,
+        AccumulatorPolynomialSizeMismatch => 17102,
+        AccumulatorGlweDimensionMismatch => 17103,
+    },
+    LweCiphertextJaggedVectorCreationError {
+        Engine => 17200,
+        NullRowCount => 17201,
+        CiphertextCountMismatch => 17202,
+    },
+    LweCiphertextJaggedVectorFusingAdditionError {
+        Engine => 17300,
+        LweDimensionMismatch => 17301,
+        RowCountMismatch => 17302,
+        RowLengthMismatch => 17303,
+    },
+    LweCiphertextJaggedVectorDiscardingRowSummationError {
+        Engine => 17400,
+        LweDimensionMismatch => 17401,
+        CiphertextCountMismatch => 17402,
+    },
+    LweCiphertextVectorDiscardingCmuxError {
+        Engine => 17500,
+        LweDimensionMismatch => 17501,
+        CiphertextCountMismatch => 17502,
+        ConditionDimensionMismatch => 17503,
+    },
+    LweCiphertextVectorLayoutCreationError {
+        Engine => 17600,
+        EmptyContainer => 17601,
+        NullLweSize => 17602,
+        InvalidContainerSize => 17603,
+    },
+    LweCiphertextVectorLayoutConsumingRetrievalError { Engine => 17700 },
+    LweCiphertextVectorLayoutConversionError {
+        Engine => 17800,
+        EmptyContainer => 17801,
+        NullLweSize => 17802,
+        InvalidContainerSize => 17803,
+    },
+    LweCiphertextTruncatedViewCreationError { Engine => 17900 },
+    GlweCiphertextLayoutCreationError {
+        Engine => 18000,
+        EmptyContainer => 18001,
+        NullPolynomialSize => 18002,
+        InvalidContainerSize => 18003,
+    },
+    GlweCiphertextLayoutConsumingRetrievalError { Engine => 18100 },
+    GlweCiphertextVectorLayoutConversionError {
+        Engine => 18200,
+        EmptyContainer => 18201,
+        NullGlweSize => 18202,
+        NullPolynomialSize => 18203,
+        InvalidContainerSize => 18204,
+    },
+    LweBootstrapKeyUploadError { Engine => 18300 },
+    LweKeyswitchKeyUploadError { Engine => 18400 },
+    LweCiphertextVectorDiscardingBudgetedBootstrapError {
+        Engine => 18500,
+        InputLweDimensionMismatch => 18501,
+        OutputLweDimensionMismatch => 18502,
+        AccumulatorGlweDimensionMismatch => 18503,
+        AccumulatorPolynomialSizeMismatch => 18504,
+        AccumulatorCountMismatch => 18505,
+        CiphertextCountMismatch => 18506,
+        MemoryBudgetTooSmall => 18507,
+    },
+    LweToGlweSecretKeyTransmutationEngineError {
+        Engine => 18600,
+        NullPolynomialSize => 18601,
+        IncompatiblePolynomialSize => 18602,
+    },
+    LweCiphertextVectorDiscardingExtremumError {
+        Engine => 18700,
+        InputLweDimensionMismatch => 18701,
+        OutputLweDimensionMismatch => 18702,
+        CiphertextCountMismatch => 18703,
+        KeyswitchKeyInputLweDimensionMismatch => 18704,
+        KeyswitchKeyOutputLweDimensionMismatch => 18705,
+        BootstrapKeyOutputLweDimensionMismatch => 18706,
+        MessageBitCountTooLarge => 18707,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_error_codes_are_unique() {
+        let mut codes = HashSet::new();
+        for entry in ERROR_CODES {
+            assert!(
+                codes.insert(entry.code),
+                "The code {} of {}::{} is already in use.",
+                entry.code.0,
+                entry.error,
+                entry.variant
+            );
+        }
+    }
+
+    #[test]
+    fn test_error_codes_are_ordered() {
+        assert!(ERROR_CODES.windows(2).all(|w| w[0].code < w[1].code));
+    }
+
+    #[test]
+    fn test_error_code_matches_table() {
+        let error: LweCiphertextDiscardingKeyswitchError<std::fmt::Error> =
+            LweCiphertextDiscardingKeyswitchError::OutputLweDimensionMismatch;
+        let entry = ERROR_CODES
+            .iter()
+            .find(|entry| entry.code == error.error_code())
+            .unwrap();
+        assert_eq!(entry.error, "LweCiphertextDiscardingKeyswitchError");
+        assert_eq!(entry.variant, "OutputLweDimensionMismatch");
+    }
+
+    // The codes of the existing variants, which must never change. New variants are appended
+    // here along with their codes. The table is left unformatted to keep one code per line.
+    #[rustfmt::skip]
+    const PINNED_ERROR_CODES: &[(u32, &str, &str)] = &[
+        (100, "CleartextConversionError", "Engine"),
+        (200, "CleartextCreationError", "Engine"),
+        (300, "CleartextDiscardingConversionError", "Engine"),
+        (400, "CleartextDiscardingRetrievalError", "Engine"),
+        (500, "CleartextEncodingError", "Engine"),
+        (600, "CleartextRetrievalError", "Engine"),
+        (700, "CleartextVectorConversionError", "Engine"),
+        (800, "CleartextVectorCreationError", "Engine"),
+        (801, "CleartextVectorCreationError", "EmptyInput"),
+        (900, "CleartextVectorDiscardingConversionError", "Engine"),
+        (901, "CleartextVectorDiscardingConversionError", "CleartextCountMismatch"),
+        (1000, "CleartextVectorDiscardingRetrievalError", "Engine"),
+        (1001, "CleartextVectorDiscardingRetrievalError", "CleartextCountMismatch"),
+        (1100, "CleartextVectorEncodingError", "Engine"),
+        (1101, "CleartextVectorEncodingError", "EncoderCountMismatch"),
+        (1200, "CleartextVectorRetrievalError", "Engine"),
+        (1300, "DestructionError", "Engine"),
+        (1400, "GgswCiphertextConversionError", "Engine"),
+        (1500, "GgswCiphertextDiscardingConversionError", "Engine"),
+        (1501, "GgswCiphertextDiscardingConversionError", "GlweDimensionMismatch"),
+        (1502, "GgswCiphertextDiscardingConversionError", "PolynomialSizeMismatch"),
+        (1503, "GgswCiphertextDiscardingConversionError", "DecompositionLevelCountMismatch"),
+        (1504, "GgswCiphertextDiscardingConversionError", "DecompositionBaseLogMismatch"),
+        (1600, "GgswCiphertextScalarDiscardingEncryptionError", "Engine"),
+        (1601, "GgswCiphertextScalarDiscardingEncryptionError", "GlweDimensionMismatch"),
+        (1602, "GgswCiphertextScalarDiscardingEncryptionError", "PolynomialSizeMismatch"),
+        (1700, "GgswCiphertextScalarEncryptionError", "Engine"),
+        (1800, "GgswCiphertextScalarTrivialEncryptionError", "Engine"),
+        (1900, "GlweCiphertextConversionError", "Engine"),
+        (2000, "GlweCiphertextDecryptionError", "Engine"),
+        (2001, "GlweCiphertextDecryptionError", "GlweDimensionMismatch"),
+        (2002, "GlweCiphertextDecryptionError", "PolynomialSizeMismatch"),
+        (2100, "GlweCiphertextDiscardingConversionError", "Engine"),
+        (2101, "GlweCiphertextDiscardingConversionError", "GlweDimensionMismatch"),
+        (2102, "GlweCiphertextDiscardingConversionError", "PolynomialSizeMismatch"),
+        (2200, "GlweCiphertextDiscardingDecryptionError", "Engine"),
+        (2201, "GlweCiphertextDiscardingDecryptionError", "GlweDimensionMismatch"),
+        (2202, "GlweCiphertextDiscardingDecryptionError", "PolynomialSizeMismatch"),
+        (2203, "GlweCiphertextDiscardingDecryptionError", "PlaintextCountMismatch"),
+        (2300, "GlweCiphertextDiscardingEncryptionError", "Engine"),
+        (2301, "GlweCiphertextDiscardingEncryptionError", "GlweDimensionMismatch"),
+        (2302, "GlweCiphertextDiscardingEncryptionError", "PolynomialSizeMismatch"),
+        (2303, "GlweCiphertextDiscardingEncryptionError", "PlaintextCountMismatch"),
+        (2400, "GlweCiphertextEncryptionError", "Engine"),
+        (2401, "GlweCiphertextEncryptionError", "PlaintextCountMismatch"),
+        (2500, "GlweCiphertextGgswCiphertextDiscardingExternalProductError", "Engine"),
+        (2501, "GlweCiphertextGgswCiphertextDiscardingExternalProductError", "PolynomialSizeMismatch"),
+        (2502, "GlweCiphertextGgswCiphertextDiscardingExternalProductError", "GlweDimensionMismatch"),
+        (2600, "GlweCiphertextGgswCiphertextExternalProductError", "Engine"),
+        (2601, "GlweCiphertextGgswCiphertextExternalProductError", "PolynomialSizeMismatch"),
+        (2602, "GlweCiphertextGgswCiphertextExternalProductError", "GlweDimensionMismatch"),
+        (2700, "GlweCiphertextTrivialDecryptionError", "Engine"),
+        (2800, "GlweCiphertextTrivialEncryptionError", "Engine"),
+        (2900, "GlweCiphertextVectorConversionError", "Engine"),
+        (3000, "GlweCiphertextVectorDecryptionError", "Engine"),
+        (3001, "GlweCiphertextVectorDecryptionError", "GlweDimensionMismatch"),
+        (3002, "GlweCiphertextVectorDecryptionError", "PolynomialSizeMismatch"),
+        (3100, "GlweCiphertextVectorDiscardingConversionError", "Engine"),
+        (3101, "GlweCiphertextVectorDiscardingConversionError", "GlweDimensionMismatch"),
+        (3102, "GlweCiphertextVectorDiscardingConversionError", "PolynomialSizeMismatch"),
+        (3103, "GlweCiphertextVectorDiscardingConversionError", "CiphertextCountMismatch"),
+        (3200, "GlweCiphertextVectorDiscardingDecryptionError", "Engine"),
+        (3201, "GlweCiphertextVectorDiscardingDecryptionError", "GlweDimensionMismatch"),
+        (3202, "GlweCiphertextVectorDiscardingDecryptionError", "PolynomialSizeMismatch"),
+        (3203, "GlweCiphertextVectorDiscardingDecryptionError", "PlaintextCountMismatch"),
+        (3300, "GlweCiphertextVectorDiscardingEncryptionError", "Engine"),
+        (3301, "GlweCiphertextVectorDiscardingEncryptionError", "GlweDimensionMismatch"),
+        (3302, "GlweCiphertextVectorDiscardingEncryptionError", "PolynomialSizeMismatch"),
+        (3303, "GlweCiphertextVectorDiscardingEncryptionError", "PlaintextCountMismatch"),
+        (3400, "GlweCiphertextVectorEncryptionError", "Engine"),
+        (3401, "GlweCiphertextVectorEncryptionError", "PlaintextCountMismatch"),
+        (3500, "GlweCiphertextVectorTrivialDecryptionError", "Engine"),
+        (3600, "GlweCiphertextVectorTrivialEncryptionError", "Engine"),
+        (3601, "GlweCiphertextVectorTrivialEncryptionError", "PlaintextCountMismatch"),
+        (3700, "GlweCiphertextVectorZeroEncryptionError", "Engine"),
+        (3701, "GlweCiphertextVectorZeroEncryptionError", "NullCiphertextCount"),
+        (3800, "GlweCiphertextZeroEncryptionError", "Engine"),
+        (3900, "GlweSecretKeyConversionError", "Engine"),
+        (4000, "GlweSecretKeyCreationError", "Engine"),
+        (4001, "GlweSecretKeyCreationError", "NullGlweDimension"),
+        (4002, "GlweSecretKeyCreationError", "NullPolynomialSize"),
+        (4003, "GlweSecretKeyCreationError", "SizeOnePolynomial"),
+        (4100, "GlweSecretKeyDiscardingConversionError", "Engine"),
+        (4101, "GlweSecretKeyDiscardingConversionError", "GlweDimensionMismatch"),
+        (4102, "GlweSecretKeyDiscardingConversionError", "PolynomialSizeMismatch"),
+        (4200, "GlweToLweSecretKeyTransmutationEngineError", "Engine"),
+        (4300, "LweBootstrapKeyConversionError", "Engine"),
+        (4400, "LweBootstrapKeyCreationError", "Engine"),
+        (4401, "LweBootstrapKeyCreationError", "NullDecompositionBaseLog"),
+        (4402, "LweBootstrapKeyCreationError", "NullDecompositionLevelCount"),
+        (4403, "LweBootstrapKeyCreationError", "DecompositionTooLarge"),
+        (4500, "LweBootstrapKeyDiscardingConversionError", "Engine"),
+        (4501, "LweBootstrapKeyDiscardingConversionError", "LweDimensionMismatch"),
+        (4502, "LweBootstrapKeyDiscardingConversionError", "GlweDimensionMismatch"),
+        (4503, "LweBootstrapKeyDiscardingConversionError", "PolynomialSizeMismatch"),
+        (4504, "LweBootstrapKeyDiscardingConversionError", "DecompositionBaseLogMismatch"),
+        (4505, "LweBootstrapKeyDiscardingConversionError", "DecompositionLevelCountMismatch"),
+        (4600, "LweCiphertextCleartextDiscardingMultiplicationError", "Engine"),
+        (4601, "LweCiphertextCleartextDiscardingMultiplicationError", "LweDimensionMismatch"),
+        (4700, "LweCiphertextCleartextFusingMultiplicationError", "Engine"),
+        (4800, "LweCiphertextConversionError", "Engine"),
+        (4900, "LweCiphertextDecryptionError", "Engine"),
+        (5000, "LweCiphertextDiscardingAdditionError", "Engine"),
+        (5001, "LweCiphertextDiscardingAdditionError", "LweDimensionMismatch"),
+        (5100, "LweCiphertextDiscardingBootstrapError", "Engine"),
+        (5101, "LweCiphertextDiscardingBootstrapError", "InputLweDimensionMismatch"),
+        (5102, "LweCiphertextDiscardingBootstrapError", "OutputLweDimensionMismatch"),
+        (5103, "LweCiphertextDiscardingBootstrapError", "AccumulatorPolynomialSizeMismatch"),
+        (5104, "LweCiphertextDiscardingBootstrapError", "AccumulatorGlweDimensionMismatch"),
+        (5200, "LweCiphertextDiscardingConversionError", "Engine"),
+        (5201, "LweCiphertextDiscardingConversionError", "LweDimensionMismatch"),
+        (5300, "LweCiphertextDiscardingDecryptionError", "Engine"),
+        (5301, "LweCiphertextDiscardingDecryptionError", "LweDimensionMismatch"),
+        (5400, "LweCiphertextDiscardingEncryptionError", "Engine"),
+        (5401, "LweCiphertextDiscardingEncryptionError", "LweDimensionMismatch"),
+        (5500, "LweCiphertextDiscardingExtractionError", "Engine"),
+        (5501, "LweCiphertextDiscardingExtractionError", "SizeMismatch"),
+        (5502, "LweCiphertextDiscardingExtractionError", "MonomialIndexTooLarge"),
+        (5600, "LweCiphertextDiscardingKeyswitchError", "Engine"),
+        (5601, "LweCiphertextDiscardingKeyswitchError", "InputLweDimensionMismatch"),
+        (5602, "LweCiphertextDiscardingKeyswitchError", "OutputLweDimensionMismatch"),
+        (5700, "LweCiphertextDiscardingLoadingError", "Engine"),
+        (5701, "LweCiphertextDiscardingLoadingError", "LweDimensionMismatch"),
+        (5702, "LweCiphertextDiscardingLoadingError", "IndexTooLarge"),
+        (5800, "LweCiphertextDiscardingOppositeError", "Engine"),
+        (5801, "LweCiphertextDiscardingOppositeError", "LweDimensionMismatch"),
+        (5900, "LweCiphertextDiscardingStoringError", "Engine"),
+        (5901, "LweCiphertextDiscardingStoringError", "LweDimensionMismatch"),
+        (5902, "LweCiphertextDiscardingStoringError", "IndexTooLarge"),
+        (6000, "LweCiphertextDiscardingSubtractionError", "Engine"),
+        (6001, "LweCiphertextDiscardingSubtractionError", "LweDimensionMismatch"),
+        (6100, "LweCiphertextEncryptionError", "Engine"),
+        (6200, "LweCiphertextFusingAdditionError", "Engine"),
+        (6201, "LweCiphertextFusingAdditionError", "LweDimensionMismatch"),
+        (6300, "LweCiphertextFusingOppositeError", "Engine"),
+        (6400, "LweCiphertextFusingSubtractionError", "Engine"),
+        (6401, "LweCiphertextFusingSubtractionError", "LweDimensionMismatch"),
+        (6500, "LweCiphertextLoadingError", "Engine"),
+        (6501, "LweCiphertextLoadingError", "IndexTooLarge"),
+        (6600, "LweCiphertextPlaintextDiscardingAdditionError", "Engine"),
+        (6601, "LweCiphertextPlaintextDiscardingAdditionError", "LweDimensionMismatch"),
+        (6700, "LweCiphertextPlaintextDiscardingSubtractionError", "Engine"),
+        (6701, "LweCiphertextPlaintextDiscardingSubtractionError", "LweDimensionMismatch"),
+        (6800, "LweCiphertextPlaintextFusingAdditionError", "Engine"),
+        (6900, "LweCiphertextPlaintextFusingSubtractionError", "Engine"),
+        (7000, "LweCiphertextTrivialDecryptionError", "Engine"),
+        (7100, "LweCiphertextTrivialEncryptionError", "Engine"),
+        (7200, "LweCiphertextVectorConversionError", "Engine"),
+        (7300, "LweCiphertextVectorDecryptionError", "Engine"),
+        (7301, "LweCiphertextVectorDecryptionError", "LweDimensionMismatch"),
+        (7400, "LweCiphertextVectorDiscardingAdditionError", "Engine"),
+        (7401, "LweCiphertextVectorDiscardingAdditionError", "LweDimensionMismatch"),
+        (7402, "LweCiphertextVectorDiscardingAdditionError", "CiphertextCountMismatch"),
+        (7500, "LweCiphertextVectorDiscardingAffineTransformationError", "Engine"),
+        (7501, "LweCiphertextVectorDiscardingAffineTransformationError", "LweDimensionMismatch"),
+        (7502, "LweCiphertextVectorDiscardingAffineTransformationError", "CleartextCountMismatch"),
+        (7600, "LweCiphertextVectorDiscardingBootstrapError", "Engine"),
+        (7601, "LweCiphertextVectorDiscardingBootstrapError", "InputLweDimensionMismatch"),
+        (7602, "LweCiphertextVectorDiscardingBootstrapError", "OutputLweDimensionMismatch"),
+        (7603, "LweCiphertextVectorDiscardingBootstrapError", "AccumulatorGlweDimensionMismatch"),
+        (7604, "LweCiphertextVectorDiscardingBootstrapError", "AccumulatorPolynomialSizeMismatch"),
+        (7605, "LweCiphertextVectorDiscardingBootstrapError", "AccumulatorCountMismatch"),
+        (7606, "LweCiphertextVectorDiscardingBootstrapError", "CiphertextCountMismatch"),
+        (7700, "LweCiphertextVectorDiscardingConversionError", "Engine"),
+        (7701, "LweCiphertextVectorDiscardingConversionError", "LweDimensionMismatch"),
+        (7702, "LweCiphertextVectorDiscardingConversionError", "CiphertextCountMismatch"),
+        (7800, "LweCiphertextVectorDiscardingDecryptionError", "Engine"),
+        (7801, "LweCiphertextVectorDiscardingDecryptionError", "LweDimensionMismatch"),
+        (7802, "LweCiphertextVectorDiscardingDecryptionError", "PlaintextCountMismatch"),
+        (7900, "LweCiphertextVectorDiscardingEncryptionError", "Engine"),
+        (7901, "LweCiphertextVectorDiscardingEncryptionError", "LweDimensionMismatch"),
+        (7902, "LweCiphertextVectorDiscardingEncryptionError", "PlaintextCountMismatch"),
+        (8000, "LweCiphertextVectorDiscardingKeyswitchError", "Engine"),
+        (8001, "LweCiphertextVectorDiscardingKeyswitchError", "InputLweDimensionMismatch"),
+        (8002, "LweCiphertextVectorDiscardingKeyswitchError", "OutputLweDimensionMismatch"),
+        (8003, "LweCiphertextVectorDiscardingKeyswitchError", "CiphertextCountMismatch"),
+        (8100, "LweCiphertextVectorDiscardingLoadingError", "Engine"),
+        (8101, "LweCiphertextVectorDiscardingLoadingError", "LweDimensionMismatch"),
+        (8102, "LweCiphertextVectorDiscardingLoadingError", "UnorderedInputRange"),
+        (8103, "LweCiphertextVectorDiscardingLoadingError", "OutOfVectorInputRange"),
+        (8104, "LweCiphertextVectorDiscardingLoadingError", "UnorderedOutputRange"),
+        (8105, "LweCiphertextVectorDiscardingLoadingError", "OutOfVectorOutputRange"),
+        (8106, "LweCiphertextVectorDiscardingLoadingError", "RangeSizeMismatch"),
+        (8200, "LweCiphertextVectorDiscardingOppositeError", "Engine"),
+        (8201, "LweCiphertextVectorDiscardingOppositeError", "LweDimensionMismatch"),
+        (8202, "LweCiphertextVectorDiscardingOppositeError", "CiphertextCountMismatch"),
+        (8300, "LweCiphertextVectorDiscardingSubtractionError", "Engine"),
+        (8301, "LweCiphertextVectorDiscardingSubtractionError", "LweDimensionMismatch"),
+        (8302, "LweCiphertextVectorDiscardingSubtractionError", "CiphertextCountMismatch"),
+        (8400, "LweCiphertextVectorEncryptionError", "Engine"),
+        (8500, "LweCiphertextVectorFusingAdditionError", "Engine"),
+        (8501, "LweCiphertextVectorFusingAdditionError", "LweDimensionMismatch"),
+        (8502, "LweCiphertextVectorFusingAdditionError", "CiphertextCountMismatch"),
+        (8600, "LweCiphertextVectorFusingOppositeError", "Engine"),
+        (8700, "LweCiphertextVectorFusingSubtractionError", "Engine"),
+        (8701, "LweCiphertextVectorFusingSubtractionError", "LweDimensionMismatch"),
+        (8702, "LweCiphertextVectorFusingSubtractionError", "CiphertextCountMismatch"),
+        (8800, "LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError", "Engine"),
+        (8801, "LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError", "InputLweDimensionMismatch"),
+        (8802, "LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError", "OutputGlweDimensionMismatch"),
+        (8803, "LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError", "OutputPolynomialSizeMismatch"),
+        (8804, "LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError", "CiphertextCountMismatch"),
+        (8900, "LweCiphertextVectorLoadingError", "Engine"),
+        (8901, "LweCiphertextVectorLoadingError", "UnorderedInputRange"),
+        (8902, "LweCiphertextVectorLoadingError", "OutOfVectorInputRange"),
+        (9000, "LweCiphertextVectorTrivialDecryptionError", "Engine"),
+        (9100, "LweCiphertextVectorTrivialEncryptionError", "Engine"),
+        (9200, "LweCiphertextVectorZeroEncryptionError", "Engine"),
+        (9201, "LweCiphertextVectorZeroEncryptionError", "NullCiphertextCount"),
+        (9300, "LweCiphertextZeroEncryptionError", "Engine"),
+        (9400, "LweKeyswitchKeyConversionError", "Engine"),
+        (9500, "LweKeyswitchKeyCreationError", "Engine"),
+        (9501, "LweKeyswitchKeyCreationError", "NullDecompositionBaseLog"),
+        (9502, "LweKeyswitchKeyCreationError", "NullDecompositionLevelCount"),
+        (9503, "LweKeyswitchKeyCreationError", "DecompositionTooLarge"),
+        (9600, "LweKeyswitchKeyDiscardingConversionError", "Engine"),
+        (9601, "LweKeyswitchKeyDiscardingConversionError", "InputLweDimensionMismatch"),
+        (9602, "LweKeyswitchKeyDiscardingConversionError", "OutputLweDimensionMismatch"),
+        (9603, "LweKeyswitchKeyDiscardingConversionError", "DecompositionBaseLogMismatch"),
+        (9604, "LweKeyswitchKeyDiscardingConversionError", "DecompositionLevelCountMismatch"),
+        (9700, "LweSecretKeyConversionError", "Engine"),
+        (9800, "LweSecretKeyCreationError", "Engine"),
+        (9801, "LweSecretKeyCreationError", "NullLweDimension"),
+        (9900, "LweSecretKeyDiscardingConversionError", "Engine"),
+        (9901, "LweSecretKeyDiscardingConversionError", "LweDimensionMismatch"),
+        (10000, "PackingKeyswitchKeyCreationError", "Engine"),
+        (10001, "PackingKeyswitchKeyCreationError", "NullDecompositionBaseLog"),
+        (10002, "PackingKeyswitchKeyCreationError", "NullDecompositionLevelCount"),
+        (10003, "PackingKeyswitchKeyCreationError", "DecompositionTooLarge"),
+        (10100, "PlaintextConversionError", "Engine"),
+        (10200, "PlaintextCreationError", "Engine"),
+        (10300, "PlaintextDecodingError", "Engine"),
+        (10400, "PlaintextDiscardingConversionError", "Engine"),
+        (10500, "PlaintextDiscardingRetrievalError", "Engine"),
+        (10600, "PlaintextRetrievalError", "Engine"),
+        (10700, "PlaintextVectorConversionError", "Engine"),
+        (10800, "PlaintextVectorCreationError", "Engine"),
+        (10801, "PlaintextVectorCreationError", "EmptyInput"),
+        (10900, "PlaintextVectorDecodingError", "Engine"),
+        (10901, "PlaintextVectorDecodingError", "EncoderCountMismatch"),
+        (11000, "PlaintextVectorDiscardingConversionError", "Engine"),
+        (11001, "PlaintextVectorDiscardingConversionError", "PlaintextCountMismatch"),
+        (11100, "PlaintextVectorDiscardingRetrievalError", "Engine"),
+        (11101, "PlaintextVectorDiscardingRetrievalError", "PlaintextCountMismatch"),
+        (11200, "PlaintextVectorRetrievalError", "Engine"),
+        (11300, "LweCiphertextVectorDiscardingPermutationError", "Engine"),
+        (11301, "LweCiphertextVectorDiscardingPermutationError", "LweDimensionMismatch"),
+        (11302, "LweCiphertextVectorDiscardingPermutationError", "CiphertextCountMismatch"),
+        (11303, "LweCiphertextVectorDiscardingPermutationError", "CiphertextCountNotPowerOfTwo"),
+        (11304, "LweCiphertextVectorDiscardingPermutationError", "SwitchCountMismatch"),
+        (11305, "LweCiphertextVectorDiscardingPermutationError", "SwitchPolynomialSizeMismatch"),
+        (11306, "LweCiphertextVectorDiscardingPermutationError", "SwitchGlweDimensionMismatch"),
+        (11400, "GgswSeededCiphertextScalarEncryptionError", "Engine"),
+        (11500, "GgswSeededCiphertextExpansionError", "Engine"),
+        // 11600 and 11700 are retired: the maximum and minimum errors were merged into the
+        // extremum error.
+        (11800, "LweCiphertextVectorCreationError", "Engine"),
+        (11801, "LweCiphertextVectorCreationError", "EmptyContainer"),
+        (11802, "LweCiphertextVectorCreationError", "NullLweSize"),
+        (11803, "LweCiphertextVectorCreationError", "InvalidContainerSize"),
+        (11900, "LweCiphertextVectorConsumingRetrievalError", "Engine"),
+        (12000, "LweBootstrapKeyConstructionError", "Engine"),
+        (12001, "LweBootstrapKeyConstructionError", "EmptyContainer"),
+        (12002, "LweBootstrapKeyConstructionError", "NullDecompositionBaseLog"),
+        (12003, "LweBootstrapKeyConstructionError", "NullDecompositionLevelCount"),
+        (12004, "LweBootstrapKeyConstructionError", "DecompositionTooLarge"),
+        (12005, "LweBootstrapKeyConstructionError", "InvalidContainerSize"),
+        (12100, "LweBootstrapKeyConsumingRetrievalError", "Engine"),
+        (12200, "LweCiphertextCleartextFusingMultiplyAddError", "Engine"),
+        (12201, "LweCiphertextCleartextFusingMultiplyAddError", "LweDimensionMismatch"),
+        (12300, "LweSecretKeyShrinkingError", "Engine"),
+        (12301, "LweSecretKeyShrinkingError", "NullLweDimension"),
+        (12302, "LweSecretKeyShrinkingError", "OutputLweDimensionTooLarge"),
+        (12400, "LweShrinkingKeyswitchKeyCreationError", "Engine"),
+        (12401, "LweShrinkingKeyswitchKeyCreationError", "NullDecompositionBaseLog"),
+        (12402, "LweShrinkingKeyswitchKeyCreationError", "NullDecompositionLevelCount"),
+        (12403, "LweShrinkingKeyswitchKeyCreationError", "DecompositionTooLarge"),
+        (12404, "LweShrinkingKeyswitchKeyCreationError", "OutputLweDimensionTooLarge"),
+        (12500, "LweCiphertextDiscardingShrinkingKeyswitchError", "Engine"),
+        (12501, "LweCiphertextDiscardingShrinkingKeyswitchError", "InputLweDimensionMismatch"),
+        (12502, "LweCiphertextDiscardingShrinkingKeyswitchError", "OutputLweDimensionMismatch"),
+        (12600, "GlweCiphertextDiscardingTensorProductError", "Engine"),
+        (12601, "GlweCiphertextDiscardingTensorProductError", "PolynomialSizeMismatch"),
+        (12602, "GlweCiphertextDiscardingTensorProductError", "GlweDimensionMismatch"),
+        (12603, "GlweCiphertextDiscardingTensorProductError", "OutputGlweDimensionMismatch"),
+        (12700, "CleartextVectorNegacyclicConvolutionError", "Engine"),
+        (12701, "CleartextVectorNegacyclicConvolutionError", "CleartextCountMismatch"),
+        (12702, "CleartextVectorNegacyclicConvolutionError", "InvalidPolynomialSize"),
+        (12800, "GlweAutomorphismKeyCreationError", "Engine"),
+        (12801, "GlweAutomorphismKeyCreationError", "NullDecompositionBaseLog"),
+        (12802, "GlweAutomorphismKeyCreationError", "NullDecompositionLevelCount"),
+        (12803, "GlweAutomorphismKeyCreationError", "DecompositionTooLarge"),
+        (12804, "GlweAutomorphismKeyCreationError", "InvalidPolynomialSize"),
+        (12900, "GlweCiphertextTraceError", "Engine"),
+        (12901, "GlweCiphertextTraceError", "GlweDimensionMismatch"),
+        (12902, "GlweCiphertextTraceError", "PolynomialSizeMismatch"),
+        (13000, "GgswCiphertextCleartextTrivialEncryptionError", "Engine"),
+        (13001, "GgswCiphertextCleartextTrivialEncryptionError", "NullDecompositionBaseLog"),
+        (13002, "GgswCiphertextCleartextTrivialEncryptionError", "NullDecompositionLevelCount"),
+        (13003, "GgswCiphertextCleartextTrivialEncryptionError", "DecompositionTooLarge"),
+        (13100, "LweCiphertextVectorNoiseStatisticsError", "Engine"),
+        (13101, "LweCiphertextVectorNoiseStatisticsError", "LweDimensionMismatch"),
+        (13102, "LweCiphertextVectorNoiseStatisticsError", "NullMessageBitCount"),
+        (13103, "LweCiphertextVectorNoiseStatisticsError", "MessageBitCountTooLarge"),
+        (13200, "LweCiphertextDiscardingLargeTableLookupError", "Engine"),
+        (13201, "LweCiphertextDiscardingLargeTableLookupError", "InputLweDimensionMismatch"),
+        (13202, "LweCiphertextDiscardingLargeTableLookupError", "OutputLweDimensionMismatch"),
+        (13203, "LweCiphertextDiscardingLargeTableLookupError", "TableSizeNotPowerOfTwo"),
+        (13204, "LweCiphertextDiscardingLargeTableLookupError", "SelectorCountMismatch"),
+        (13205, "LweCiphertextDiscardingLargeTableLookupError", "SelectorPolynomialSizeMismatch"),
+        (13206, "LweCiphertextDiscardingLargeTableLookupError", "SelectorGlweDimensionMismatch"),
+        (13300, "LweCiphertextGadgetProductError", "Engine"),
+        (13301, "LweCiphertextGadgetProductError", "InputLweDimensionMismatch"),
+        (13302, "LweCiphertextGadgetProductError", "NullDecompositionLevelCount"),
+        (13303, "LweCiphertextGadgetProductError", "DecompositionLevelCountTooLarge"),
+        (13400, "GlweCiphertextDiscardingAdditionError", "Engine"),
+        (13401, "GlweCiphertextDiscardingAdditionError", "GlweDimensionMismatch"),
+        (13402, "GlweCiphertextDiscardingAdditionError", "PolynomialSizeMismatch"),
+        (13500, "GlweCiphertextCleartextVectorDiscardingMultiplicationError", "Engine"),
+        (13501, "GlweCiphertextCleartextVectorDiscardingMultiplicationError", "GlweDimensionMismatch"),
+        (13502, "GlweCiphertextCleartextVectorDiscardingMultiplicationError", "PolynomialSizeMismatch"),
+        (13503, "GlweCiphertextCleartextVectorDiscardingMultiplicationError", "CleartextCountMismatch"),
+        (13600, "LweCiphertextVectorDiscardingGatherError", "Engine"),
+        (13601, "LweCiphertextVectorDiscardingGatherError", "LweDimensionMismatch"),
+        (13602, "LweCiphertextVectorDiscardingGatherError", "IndexCountMismatch"),
+        (13603, "LweCiphertextVectorDiscardingGatherError", "OutOfVectorInputIndex"),
+        (13700, "LweCiphertextVectorDiscardingScatterError", "Engine"),
+        (13701, "LweCiphertextVectorDiscardingScatterError", "LweDimensionMismatch"),
+        (13702, "LweCiphertextVectorDiscardingScatterError", "IndexCountMismatch"),
+        (13703, "LweCiphertextVectorDiscardingScatterError", "OutOfVectorOutputIndex"),
+        (13704, "LweCiphertextVectorDiscardingScatterError", "DuplicateIndex"),
+        (13800, "EntityConstantTimeEqualityError", "Engine"),
+        (13900, "LweCiphertextAccumulationError", "Engine"),
+        (13901, "LweCiphertextAccumulationError", "InputLweDimensionMismatch"),
+        (13902, "LweCiphertextAccumulationError", "KeyswitchKeyInputLweDimensionMismatch"),
+        (13903, "LweCiphertextAccumulationError", "KeyswitchKeyOutputLweDimensionMismatch"),
+        (13904, "LweCiphertextAccumulationError", "BootstrapKeyOutputLweDimensionMismatch"),
+        (14000, "GlweCiphertextDiscardingOffsetAdditionError", "Engine"),
+        (14001, "GlweCiphertextDiscardingOffsetAdditionError", "GlweDimensionMismatch"),
+        (14002, "GlweCiphertextDiscardingOffsetAdditionError", "PolynomialSizeMismatch"),
+        (14003, "GlweCiphertextDiscardingOffsetAdditionError", "OffsetOutOfBounds"),
+        (14100, "GgswCiphertextConsumingRetrievalError", "Engine"),
+        (14200, "LweKeyswitchKeyBatchCreationError", "Engine"),
+        (14201, "LweKeyswitchKeyBatchCreationError", "NullDecompositionBaseLog"),
+        (14202, "LweKeyswitchKeyBatchCreationError", "NullDecompositionLevelCount"),
+        (14203, "LweKeyswitchKeyBatchCreationError", "DecompositionTooLarge"),
+        (14300, "LweKeyswitchKeyBatchAppendingError", "Engine"),
+        (14301, "LweKeyswitchKeyBatchAppendingError", "InputLweDimensionMismatch"),
+        (14302, "LweKeyswitchKeyBatchAppendingError", "OutputLweDimensionMismatch"),
+        (14303, "LweKeyswitchKeyBatchAppendingError", "DecompositionLevelCountMismatch"),
+        (14304, "LweKeyswitchKeyBatchAppendingError", "DecompositionBaseLogMismatch"),
+        (14400, "LweKeyswitchKeyBatchRemovalError", "Engine"),
+        (14401, "LweKeyswitchKeyBatchRemovalError", "IndexOutOfBounds"),
+        (14500, "LweCiphertextDiscardingBatchedKeyswitchError", "Engine"),
+        (14501, "LweCiphertextDiscardingBatchedKeyswitchError", "InputLweDimensionMismatch"),
+        (14502, "LweCiphertextDiscardingBatchedKeyswitchError", "OutputLweDimensionMismatch"),
+        (14503, "LweCiphertextDiscardingBatchedKeyswitchError", "IndexOutOfBounds"),
+        (14600, "LweCiphertextCleartextDiscardingRationalMultiplicationError", "Engine"),
+        (14601, "LweCiphertextCleartextDiscardingRationalMultiplicationError", "LweDimensionMismatch"),
+        (14602, "LweCiphertextCleartextDiscardingRationalMultiplicationError", "DenominatorLogTooLarge"),
+        (14700, "LweCiphertextVectorPhaseExportingDecryptionError", "Engine"),
+        (14701, "LweCiphertextVectorPhaseExportingDecryptionError", "LweDimensionMismatch"),
+        (14702, "LweCiphertextVectorPhaseExportingDecryptionError", "NullMessageBitCount"),
+        (14703, "LweCiphertextVectorPhaseExportingDecryptionError", "MessageBitCountTooLarge"),
+        (14800, "LwePhaseVectorRetrievalError", "Engine"),
+        (14900, "LweSecretKeyDerivationError", "Engine"),
+        (14901, "LweSecretKeyDerivationError", "NullLweDimension"),
+        (15000, "GlweRelinearizationKeyCreationError", "Engine"),
+        (15001, "GlweRelinearizationKeyCreationError", "NullDecompositionBaseLog"),
+        (15002, "GlweRelinearizationKeyCreationError", "NullDecompositionLevelCount"),
+        (15003, "GlweRelinearizationKeyCreationError", "DecompositionTooLarge"),
+        (15100, "GlweCiphertextDiscardingRelinearizationError", "Engine"),
+        (15101, "GlweCiphertextDiscardingRelinearizationError", "PolynomialSizeMismatch"),
+        (15102, "GlweCiphertextDiscardingRelinearizationError", "OutputGlweDimensionMismatch"),
+        (15103, "GlweCiphertextDiscardingRelinearizationError", "InputGlweDimensionMismatch"),
+        (15200, "LweCiphertextDiscardingMultiplicationError", "Engine"),
+        (15201, "LweCiphertextDiscardingMultiplicationError", "InputLweDimensionMismatch"),
+        (15202, "LweCiphertextDiscardingMultiplicationError", "PackingKeyInputLweDimensionMismatch"),
+        (15203, "LweCiphertextDiscardingMultiplicationError", "GlweDimensionMismatch"),
+        (15204, "LweCiphertextDiscardingMultiplicationError", "PolynomialSizeMismatch"),
+        (15205, "LweCiphertextDiscardingMultiplicationError", "OutputLweDimensionMismatch"),
+        (15300, "LweCiphertextVectorDiscardingParityError", "Engine"),
+        (15301, "LweCiphertextVectorDiscardingParityError", "EmptyInput"),
+        (15302, "LweCiphertextVectorDiscardingParityError", "OutputLweDimensionMismatch"),
+        (15303, "LweCiphertextVectorDiscardingParityError", "KeyswitchKeyInputLweDimensionMismatch"),
+        (15304, "LweCiphertextVectorDiscardingParityError", "KeyswitchKeyOutputLweDimensionMismatch"),
+        (15305, "LweCiphertextVectorDiscardingParityError", "BootstrapKeyOutputLweDimensionMismatch"),
+        (15306, "LweCiphertextVectorDiscardingParityError", "NullMessageBitCount"),
+        (15307, "LweCiphertextVectorDiscardingParityError", "MessageBitCountTooLarge"),
+        (15400, "LweCiphertextVectorDiscardingPopcountError", "Engine"),
+        (15401, "LweCiphertextVectorDiscardingPopcountError", "EmptyInput"),
+        (15402, "LweCiphertextVectorDiscardingPopcountError", "OutputLweDimensionMismatch"),
+        (15403, "LweCiphertextVectorDiscardingPopcountError", "KeyswitchKeyInputLweDimensionMismatch"),
+        (15404, "LweCiphertextVectorDiscardingPopcountError", "KeyswitchKeyOutputLweDimensionMismatch"),
+        (15405, "LweCiphertextVectorDiscardingPopcountError", "BootstrapKeyOutputLweDimensionMismatch"),
+        (15406, "LweCiphertextVectorDiscardingPopcountError", "MessageBitCountTooLarge"),
+        (15407, "LweCiphertextVectorDiscardingPopcountError", "CiphertextCountTooLarge"),
+        (15500, "LweSeededCiphertextEncryptionError", "Engine"),
+        (15600, "LweSeededCiphertextExpansionError", "Engine"),
+        (15700, "LweSeededBootstrapKeyCreationError", "Engine"),
+        (15701, "LweSeededBootstrapKeyCreationError", "NullDecompositionBaseLog"),
+        (15702, "LweSeededBootstrapKeyCreationError", "NullDecompositionLevelCount"),
+        (15703, "LweSeededBootstrapKeyCreationError", "DecompositionTooLarge"),
+        (15800, "LweSeededBootstrapKeyExpansionError", "Engine"),
+        (15900, "EntitySerializationError", "Engine"),
+        (16000, "EntityDeserializationError", "Engine"),
+        (16100, "LweCiphertextFusingNoiseInjectionError", "Engine"),
+        (16101, "LweCiphertextFusingNoiseInjectionError", "TargetVarianceTooSmall"),
+        (16200, "LwePublicKeyCreationError", "Engine"),
+        (16201, "LwePublicKeyCreationError", "NullPublicKeyZeroEncryptionCount"),
+        (16300, "LweCiphertextPublicKeyEncryptionError", "Engine"),
+        (16400, "LweKeyswitchKeyDeltaCreationError", "Engine"),
+        (16401, "LweKeyswitchKeyDeltaCreationError", "OldOutputLweDimensionMismatch"),
+        (16402, "LweKeyswitchKeyDeltaCreationError", "NewOutputLweDimensionMismatch"),
+        (16500, "LweKeyswitchKeyFusingUpdateError", "Engine"),
+        (16501, "LweKeyswitchKeyFusingUpdateError", "InputLweDimensionMismatch"),
+        (16502, "LweKeyswitchKeyFusingUpdateError", "OutputLweDimensionMismatch"),
+        (16503, "LweKeyswitchKeyFusingUpdateError", "DecompositionBaseLogMismatch"),
+        (16504, "LweKeyswitchKeyFusingUpdateError", "DecompositionLevelCountMismatch"),
+        (16600, "LweCiphertextDiscardingModulusSwitchError", "Engine"),
+        (16601, "LweCiphertextDiscardingModulusSwitchError", "LweDimensionMismatch"),
+        (16602, "LweCiphertextDiscardingModulusSwitchError", "NullModulusLog"),
+        (16603, "LweCiphertextDiscardingModulusSwitchError", "ModulusLogTooLarge"),
+        (16604, "LweCiphertextDiscardingModulusSwitchError", "ModulusLogExceedsOutputPrecision"),
+        (16700, "LweCiphertextDiscardingTrivialEncryptionError", "Engine"),
+        (16800, "GlweCiphertextDiscardingTrivialEncryptionError", "Engine"),
+        (16801, "GlweCiphertextDiscardingTrivialEncryptionError", "PlaintextCountMismatch"),
+        (16900, "LweCiphertextVectorPlaintextVectorDiscardingSubtractionError", "Engine"),
+        (16901, "LweCiphertextVectorPlaintextVectorDiscardingSubtractionError", "LweDimensionMismatch"),
+        (16902, "LweCiphertextVectorPlaintextVectorDiscardingSubtractionError", "CiphertextCountMismatch"),
+        (16903, "LweCiphertextVectorPlaintextVectorDiscardingSubtractionError", "PlaintextCountMismatch"),
+        (17000, "LweCiphertextVectorPlaintextVectorFusingSubtractionError", "Engine"),
+        (17001, "LweCiphertextVectorPlaintextVectorFusingSubtractionError", "PlaintextCountMismatch"),
+        (17100, "GlweCiphertextBlindRotationError", "Engine"),
+        (17101, "GlweCiphertextBlindRotationError", "InputLweDimensionMismatch"),
+        (17102, "GlweCiphertextBlindRotationError", "AccumulatorPolynomialSizeMismatch"),
+        (17103, "GlweCiphertextBlindRotationError", "AccumulatorGlweDimensionMismatch"),
+        (17200, "LweCiphertextJaggedVectorCreationError", "Engine"),
+        (17201, "LweCiphertextJaggedVectorCreationError", "NullRowCount"),
+        (17202, "LweCiphertextJaggedVectorCreationError", "CiphertextCountMismatch"),
+        (17300, "LweCiphertextJaggedVectorFusingAdditionError", "Engine"),
+        (17301, "LweCiphertextJaggedVectorFusingAdditionError", "LweDimensionMismatch"),
+        (17302, "LweCiphertextJaggedVectorFusingAdditionError", "RowCountMismatch"),
+        (17303, "LweCiphertextJaggedVectorFusingAdditionError", "RowLengthMismatch"),
+        (17400, "LweCiphertextJaggedVectorDiscardingRowSummationError", "Engine"),
+        (17401, "LweCiphertextJaggedVectorDiscardingRowSummationError", "LweDimensionMismatch"),
+        (17402, "LweCiphertextJaggedVectorDiscardingRowSummationError", "CiphertextCountMismatch"),
+        (17500, "LweCiphertextVectorDiscardingCmuxError", "Engine"),
+        (17501, "LweCiphertextVectorDiscardingCmuxError", "LweDimensionMismatch"),
+        (17502, "LweCiphertextVectorDiscardingCmuxError", "CiphertextCountMismatch"),
+        (17503, "LweCiphertextVectorDiscardingCmuxError", "ConditionDimensionMismatch"),
+        (17600, "LweCiphertextVectorLayoutCreationError", "Engine"),
+        (17601, "LweCiphertextVectorLayoutCreationError", "EmptyContainer"),
+        (17602, "LweCiphertextVectorLayoutCreationError", "NullLweSize"),
+        (17603, "LweCiphertextVectorLayoutCreationError", "InvalidContainerSize"),
+        (17700, "LweCiphertextVectorLayoutConsumingRetrievalError", "Engine"),
+        (17800, "LweCiphertextVectorLayoutConversionError", "Engine"),
+        (17801, "LweCiphertextVectorLayoutConversionError", "EmptyContainer"),
+        (17802, "LweCiphertextVectorLayoutConversionError", "NullLweSize"),
+        (17803, "LweCiphertextVectorLayoutConversionError", "InvalidContainerSize"),
+        (17900, "LweCiphertextTruncatedViewCreationError", "Engine"),
+        (18000, "GlweCiphertextLayoutCreationError", "Engine"),
+        (18001, "GlweCiphertextLayoutCreationError", "EmptyContainer"),
+        (18002, "GlweCiphertextLayoutCreationError", "NullPolynomialSize"),
+        (18003, "GlweCiphertextLayoutCreationError", "InvalidContainerSize"),
+        (18100, "GlweCiphertextLayoutConsumingRetrievalError", "Engine"),
+        (18200, "GlweCiphertextVectorLayoutConversionError", "Engine"),
+        (18201, "GlweCiphertextVectorLayoutConversionError", "EmptyContainer"),
+        (18202, "GlweCiphertextVectorLayoutConversionError", "NullGlweSize"),
+        (18203, "GlweCiphertextVectorLayoutConversionError", "NullPolynomialSize"),
+        (18204, "GlweCiphertextVectorLayoutConversionError", "InvalidContainerSize"),
+        (18300, "LweBootstrapKeyUploadError", "Engine"),
+        (18400, "LweKeyswitchKeyUploadError", "Engine"),
+        (18500, "LweCiphertextVectorDiscardingBudgetedBootstrapError", "Engine"),
+        (18501, "LweCiphertextVectorDiscardingBudgetedBootstrapError", "InputLweDimensionMismatch"),
+        (18502, "LweCiphertextVectorDiscardingBudgetedBootstrapError", "OutputLweDimensionMismatch"),
+        (18503, "LweCiphertextVectorDiscardingBudgetedBootstrapError", "AccumulatorGlweDimensionMismatch"),
+        (18504, "LweCiphertextVectorDiscardingBudgetedBootstrapError", "AccumulatorPolynomialSizeMismatch"),
+        (18505, "LweCiphertextVectorDiscardingBudgetedBootstrapError", "AccumulatorCountMismatch"),
+        (18506, "LweCiphertextVectorDiscardingBudgetedBootstrapError", "CiphertextCountMismatch"),
+        (18507, "LweCiphertextVectorDiscardingBudgetedBootstrapError", "MemoryBudgetTooSmall"),
+        (18600, "LweToGlweSecretKeyTransmutationEngineError", "Engine"),
+        (18601, "LweToGlweSecretKeyTransmutationEngineError", "NullPolynomialSize"),
+        (18602, "LweToGlweSecretKeyTransmutationEngineError", "IncompatiblePolynomialSize"),
+        (18700, "LweCiphertextVectorDiscardingExtremumError", "Engine"),
+        (18701, "LweCiphertextVectorDiscardingExtremumError", "InputLweDimensionMismatch"),
+        (18702, "LweCiphertextVectorDiscardingExtremumError", "OutputLweDimensionMismatch"),
+        (18703, "LweCiphertextVectorDiscardingExtremumError", "CiphertextCountMismatch"),
+        (18704, "LweCiphertextVectorDiscardingExtremumError", "KeyswitchKeyInputLweDimensionMismatch"),
+        (18705, "LweCiphertextVectorDiscardingExtremumError", "KeyswitchKeyOutputLweDimensionMismatch"),
+        (18706, "LweCiphertextVectorDiscardingExtremumError", "BootstrapKeyOutputLweDimensionMismatch"),
+        (18707, "LweCiphertextVectorDiscardingExtremumError", "MessageBitCountTooLarge"),
+    ];
+
+    #[test]
+    fn test_error_codes_are_pinned() {
+        assert_eq!(ERROR_CODES.len(), PINNED_ERROR_CODES.len());
+        for (entry, (code, error, variant)) in ERROR_CODES.iter().zip(PINNED_ERROR_CODES.iter()) {
+            assert_eq!(
+                (entry.code.0, entry.error, entry.variant),
+                (*code, *error, *variant),
+                "The code of {}::{} changed.",
+                error,
+                variant
+            );
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use super::engine_error;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{CleartextEntity, GgswCiphertextEntity};
+
+engine_error! {
+    GgswCiphertextCleartextTrivialEncryptionError for GgswCiphertextCleartextTrivialEncryptionEngine @
+    NullDecompositionBaseLog => "The decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+impl<EngineError: std::error::Error> GgswCiphertextCleartextTrivialEncryptionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        integer_precision: usize,
+    ) -> Result<(), Self> {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+        if decomposition_level_count.0 * decomposition_base_log.0 > integer_precision {
+            return Err(Self::DecompositionTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines trivially encrypting a cleartext into a GGSW ciphertext.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GGSW ciphertext containing the
+/// trivial encryption of the `input` cleartext, with the requested `glwe_size`, `polynomial_size`
+/// and decomposition parameters.
+///
+/// # Formal Definition
+///
+/// A GGSW ciphertext of a cleartext $m$ is made of $(k+1) \cdot \ell$ GLWE ciphertexts, whose
+/// phases are the rows of the gadget matrix multiplied by $m$. Each of these GLWE ciphertexts
+/// contains $m \cdot \frac{q}{B^j}$ in the constant coefficient of its $i$-th polynomial, for
+/// every level $1 \leq j \leq \ell$ and every polynomial index $0 \leq i \leq k$, and zero
+/// elsewhere.
+///
+/// A trivial encryption uses zero masks and no noise, which means that the rows of the gadget
+/// matrix times $m$ are copied as is in the ciphertext. It is absolutely not secure, but it
+/// allows to use public constants as the selectors of external products, for instance when
+/// testing CMUX trees or vertical packing without a secret key.
+pub trait GgswCiphertextCleartextTrivialEncryptionEngine<Cleartext, Ciphertext>:
+    AbstractEngine
+where
+    Cleartext: CleartextEntity,
+    Ciphertext: GgswCiphertextEntity,
+{
+    /// Trivially encrypts a cleartext into a GGSW ciphertext.
+    fn trivially_encrypt_cleartext_ggsw_ciphertext(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &Cleartext,
+    ) -> Result<Ciphertext, GgswCiphertextCleartextTrivialEncryptionError<Self::EngineError>>;
+
+    /// Unsafely creates the trivial GGSW encryption of the cleartext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswCiphertextCleartextTrivialEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn trivially_encrypt_cleartext_ggsw_ciphertext_unchecked(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &Cleartext,
+    ) -> Ciphertext;
+}
@@ -0,0 +1,37 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GgswCiphertextEntity;
+
+engine_error! {
+    GgswCiphertextConsumingRetrievalError for GgswCiphertextConsumingRetrievalEngine @
+}
+
+/// A trait for engines retrieving the containers of GGSW ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation consumes the `ciphertext` GGSW ciphertext,
+/// and returns the container holding its data, without any copy.
+///
+/// # Formal Definition
+pub trait GgswCiphertextConsumingRetrievalEngine<Ciphertext, Container>: AbstractEngine
+where
+    Ciphertext: GgswCiphertextEntity,
+{
+    /// Retrieves the container of a GGSW ciphertext.
+    fn consume_retrieve_ggsw_ciphertext(
+        &mut self,
+        ciphertext: Ciphertext,
+    ) -> Result<Container, GgswCiphertextConsumingRetrievalError<Self::EngineError>>;
+
+    /// Unsafely retrieves the container of a GGSW ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswCiphertextConsumingRetrievalError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn consume_retrieve_ggsw_ciphertext_unchecked(
+        &mut self,
+        ciphertext: Ciphertext,
+    ) -> Container;
+}
@@ -0,0 +1,35 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GgswCiphertextEntity, GgswSeededCiphertextEntity};
+
+engine_error! {
+    GgswSeededCiphertextExpansionError for GgswSeededCiphertextExpansionEngine @
+}
+
+/// A trait for engines expanding seeded GGSW ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GGSW ciphertext containing the
+/// expansion of the `input` seeded GGSW ciphertext, whose masks are regenerated from the seed.
+///
+/// # Formal Definition
+pub trait GgswSeededCiphertextExpansionEngine<Input, Output>: AbstractEngine
+where
+    Input: GgswSeededCiphertextEntity,
+    Output: GgswCiphertextEntity<KeyDistribution = Input::KeyDistribution>,
+{
+    /// Expands a seeded GGSW ciphertext.
+    fn expand_ggsw_seeded_ciphertext(
+        &mut self,
+        input: &Input,
+    ) -> Result<Output, GgswSeededCiphertextExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands a seeded GGSW ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswSeededCiphertextExpansionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn expand_ggsw_seeded_ciphertext_unchecked(&mut self, input: &Input) -> Output;
+}
@@ -0,0 +1,90 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweAutomorphismKeyEntity, GlweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+engine_error! {
+    GlweAutomorphismKeyCreationError for GlweAutomorphismKeyCreationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext.",
+    InvalidPolynomialSize => "The polynomial size of the key must be a power of two greater than \
+                              one."
+}
+
+impl<EngineError: std::error::Error> GlweAutomorphismKeyCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey>(
+        secret_key: &SecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        SecretKey: GlweSecretKeyEntity,
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+
+        if decomposition_level_count.0 * decomposition_base_log.0 > integer_precision {
+            return Err(Self::DecompositionTooLarge);
+        }
+
+        let polynomial_size = secret_key.polynomial_size().0;
+        if !polynomial_size.is_power_of_two() || polynomial_size == 1 {
+            return Err(Self::InvalidPolynomialSize);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines creating GLWE automorphism keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates the GLWE automorphism keys of the
+/// `secret_key` GLWE secret key, which allow to evaluate the trace of ciphertexts encrypted under
+/// this key (see [`GlweCiphertextTraceEngine`](super::GlweCiphertextTraceEngine)).
+///
+/// # Formal Definition
+///
+/// Let $S = (S\_0, \ldots, S\_{k-1})$ be the secret key, with polynomials of size $N$. For every
+/// $a \in \[0, \log\_2(N))$, let $g\_a = N/2^a + 1$ and $\tau\_{g\_a}: P(X) \mapsto P(X^{g\_a})$.
+/// For every $a$, every $i \in \[0, k)$ and every level $j \in \[1, l\]$, the key contains a GLWE
+/// encryption of $\tau\_{g\_a}(S\_i) \cdot q / B^j$ under $S$.
+pub trait GlweAutomorphismKeyCreationEngine<SecretKey, AutomorphismKey>: AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    AutomorphismKey: GlweAutomorphismKeyEntity<KeyDistribution = SecretKey::KeyDistribution>,
+{
+    /// Creates a GLWE automorphism key.
+    fn create_glwe_automorphism_key(
+        &mut self,
+        secret_key: &SecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<AutomorphismKey, GlweAutomorphismKeyCreationError<Self::EngineError>>;
+
+    /// Unsafely creates a GLWE automorphism key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweAutomorphismKeyCreationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn create_glwe_automorphism_key_unchecked(
+        &mut self,
+        secret_key: &SecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> AutomorphismKey;
+}
@@ -0,0 +1,85 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity,
+};
+
+engine_error! {
+    GlweCiphertextBlindRotationError for GlweCiphertextBlindRotationEngine @
+    InputLweDimensionMismatch => "The input ciphertext and key LWE dimension must be the same.",
+    AccumulatorPolynomialSizeMismatch => "The accumulator and key polynomial sizes must be the same.",
+    AccumulatorGlweDimensionMismatch => "The accumulator and key GLWE dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextBlindRotationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<BootstrapKey, InputCiphertext, Accumulator>(
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<(), Self>
+    where
+        BootstrapKey: LweBootstrapKeyEntity,
+        InputCiphertext: LweCiphertextEntity<KeyDistribution = BootstrapKey::InputKeyDistribution>,
+        Accumulator: GlweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(Self::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(Self::AccumulatorGlweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines blind rotating GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext containing the
+/// `acc` accumulator, blindly rotated by the phase of the `input` LWE ciphertext using the `bsk`
+/// bootstrap key.
+///
+/// # Formal Definition
+///
+/// This is the first step of the bootstrap performed by the
+/// [`LweCiphertextDiscardingBootstrapEngine`](super::LweCiphertextDiscardingBootstrapEngine):
+/// the phase of the input ciphertext is rescaled modulo $2N$ into $\tilde{\varphi}$, and the
+/// accumulator is multiplied by the monomial $X^{-\tilde{\varphi}}$, using an external product
+/// with each GGSW ciphertext of the bootstrap key. The output GLWE ciphertext is encrypted under
+/// the output key of the bootstrap key, and no sample extraction is performed: the constant
+/// coefficient of the output encrypts the value the bootstrap would return, while the other
+/// coefficients encrypt the rest of the rotated accumulator.
+pub trait GlweCiphertextBlindRotationEngine<BootstrapKey, InputCiphertext, Accumulator>:
+    AbstractEngine
+where
+    BootstrapKey: LweBootstrapKeyEntity,
+    InputCiphertext: LweCiphertextEntity<KeyDistribution = BootstrapKey::InputKeyDistribution>,
+    Accumulator: GlweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+{
+    /// Blind rotates a GLWE ciphertext.
+    fn blind_rotate_glwe_ciphertext(
+        &mut self,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<Accumulator, GlweCiphertextBlindRotationError<Self::EngineError>>;
+
+    /// Unsafely blind rotates a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextBlindRotationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn blind_rotate_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Accumulator;
+}
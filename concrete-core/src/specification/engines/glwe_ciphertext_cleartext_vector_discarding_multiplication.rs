@@ -0,0 +1,96 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{CleartextVectorEntity, GlweCiphertextEntity};
+
+engine_error! {
+    GlweCiphertextCleartextVectorDiscardingMultiplicationError for
+    GlweCiphertextCleartextVectorDiscardingMultiplicationEngine @
+    GlweDimensionMismatch => "The input and output ciphertext GLWE dimensions must be the same.",
+    PolynomialSizeMismatch => "The input and output ciphertext polynomial sizes must be the same.",
+    CleartextCountMismatch => "The cleartext count of the input vector must be equal to the \
+                               polynomial size of the ciphertexts."
+}
+
+impl<EngineError: std::error::Error>
+    GlweCiphertextCleartextVectorDiscardingMultiplicationError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, CleartextVector, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        cleartext_vector: &CleartextVector,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: GlweCiphertextEntity,
+        CleartextVector: CleartextVectorEntity,
+        OutputCiphertext: GlweCiphertextEntity<
+            KeyDistribution = InputCiphertext::KeyDistribution,
+            RingStructure = InputCiphertext::RingStructure,
+        >,
+    {
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if cleartext_vector.cleartext_count().0 != input.polynomial_size().0 {
+            return Err(Self::CleartextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines multiplying (discarding) GLWE ciphertexts by cleartext polynomials.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the product of the `input` GLWE ciphertext with the `cleartext_vector`, interpreted as a
+/// polynomial whose coefficients are the consecutive cleartexts of the vector.
+///
+/// The product is reduced in the ring of the ciphertexts, given by their
+/// [`RingStructure`](`GlweCiphertextEntity::RingStructure`): the coefficients wrapping around the
+/// polynomial size are negated in the negacyclic ring, and kept as is in the cyclic ring.
+///
+/// # Formal Definition
+///
+/// Let $(A\_0, \dots, A\_{k-1}, B)$ be the polynomials of the input ciphertext, and $C$ the
+/// cleartext polynomial. The output is $(C \cdot A\_0, \dots, C \cdot A\_{k-1}, C \cdot B)$, where
+/// the products are reduced modulo $X^N + 1$ in the negacyclic ring, and modulo $X^N - 1$ in the
+/// cyclic ring. It encrypts the product of the input plaintext polynomial with $C$, and its noise
+/// is the product of the input noise with $C$, whose variance is multiplied by
+/// $\sum\_i c\_i^2$.
+pub trait GlweCiphertextCleartextVectorDiscardingMultiplicationEngine<
+    InputCiphertext,
+    CleartextVector,
+    OutputCiphertext,
+>: AbstractEngine where
+    InputCiphertext: GlweCiphertextEntity,
+    CleartextVector: CleartextVectorEntity,
+    OutputCiphertext: GlweCiphertextEntity<
+        KeyDistribution = InputCiphertext::KeyDistribution,
+        RingStructure = InputCiphertext::RingStructure,
+    >,
+{
+    /// Multiplies a GLWE ciphertext by a cleartext polynomial.
+    fn discard_mul_glwe_ciphertext_cleartext_vector(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        cleartext_vector: &CleartextVector,
+    ) -> Result<(), GlweCiphertextCleartextVectorDiscardingMultiplicationError<Self::EngineError>>;
+
+    /// Unsafely multiplies a GLWE ciphertext by a cleartext polynomial.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextCleartextVectorDiscardingMultiplicationError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_mul_glwe_ciphertext_cleartext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        cleartext_vector: &CleartextVector,
+    );
+}
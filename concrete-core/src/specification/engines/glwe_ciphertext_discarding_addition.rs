@@ -0,0 +1,82 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+
+engine_error! {
+    GlweCiphertextDiscardingAdditionError for GlweCiphertextDiscardingAdditionEngine @
+    GlweDimensionMismatch => "All the ciphertext GLWE dimensions must be the same.",
+    PolynomialSizeMismatch => "All the ciphertext polynomial sizes must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingAdditionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: GlweCiphertextEntity,
+        OutputCiphertext: GlweCiphertextEntity<
+            KeyDistribution = InputCiphertext::KeyDistribution,
+            RingStructure = InputCiphertext::RingStructure,
+        >,
+    {
+        if output.glwe_dimension() != input_1.glwe_dimension()
+            || output.glwe_dimension() != input_2.glwe_dimension()
+        {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input_1.polynomial_size()
+            || output.polynomial_size() != input_2.polynomial_size()
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines adding (discarding) GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the addition of the `input_1` GLWE ciphertext and the `input_2` GLWE ciphertext.
+///
+/// The addition does not depend on the structure of the polynomial ring, but the ciphertexts must
+/// all belong to the same ring.
+///
+/// # Formal Definition
+///
+/// The mask and body polynomials of the output are the coefficient-wise sums of the ones of the
+/// inputs. The output hence encrypts the sum of the input plaintext polynomials.
+pub trait GlweCiphertextDiscardingAdditionEngine<InputCiphertext, OutputCiphertext>:
+    AbstractEngine
+where
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity<
+        KeyDistribution = InputCiphertext::KeyDistribution,
+        RingStructure = InputCiphertext::RingStructure,
+    >,
+{
+    /// Adds two GLWE ciphertexts.
+    fn discard_add_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+    ) -> Result<(), GlweCiphertextDiscardingAdditionError<Self::EngineError>>;
+
+    /// Unsafely adds two GLWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingAdditionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn discard_add_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+    );
+}
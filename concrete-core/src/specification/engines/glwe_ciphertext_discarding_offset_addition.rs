@@ -0,0 +1,105 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+use concrete_commons::parameters::{MonomialIndex, PlaintextCount};
+
+engine_error! {
+    GlweCiphertextDiscardingOffsetAdditionError for GlweCiphertextDiscardingOffsetAdditionEngine @
+    GlweDimensionMismatch => "All the ciphertext GLWE dimensions must be the same.",
+    PolynomialSizeMismatch => "All the ciphertext polynomial sizes must be the same.",
+    OffsetOutOfBounds => "The offset must be smaller than the polynomial size, and the offset added \
+                          to the slot count of the second input must not exceed it."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingOffsetAdditionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+        offset: MonomialIndex,
+        slot_count: PlaintextCount,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: GlweCiphertextEntity,
+        OutputCiphertext: GlweCiphertextEntity<
+            KeyDistribution = InputCiphertext::KeyDistribution,
+            RingStructure = InputCiphertext::RingStructure,
+        >,
+    {
+        if output.glwe_dimension() != input_1.glwe_dimension()
+            || output.glwe_dimension() != input_2.glwe_dimension()
+        {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input_1.polynomial_size()
+            || output.polynomial_size() != input_2.polynomial_size()
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        let polynomial_size = output.polynomial_size().0;
+        match offset.0.checked_add(slot_count.0) {
+            Some(end) if offset.0 < polynomial_size && end <= polynomial_size => Ok(()),
+            _ => Err(Self::OffsetOutOfBounds),
+        }
+    }
+}
+
+/// A trait for engines adding (discarding) a GLWE ciphertext to another one, at a slot offset.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the addition of the `input_1` GLWE ciphertext and the `input_2` GLWE ciphertext, whose slots
+/// are moved `offset` positions up.
+///
+/// The `input_2` ciphertext is expected to encrypt a plaintext of `slot_count` slots, padded with
+/// zeros up to the polynomial size, for instance the result of a chunk of a larger computation.
+/// Its slots land in the slots `offset` to `offset + slot_count` of the output, which must fit in
+/// the polynomial, while the other slots of the output are the ones of `input_1`. Repeating the
+/// operation with consecutive offsets assembles the chunks in a single packed ciphertext.
+///
+/// Since the ciphertexts are encrypted under the same key, they share their polynomial size: the
+/// zero padding is a property of the plaintext of `input_2`, not of the ciphertext itself.
+///
+/// # Formal Definition
+///
+/// The mask and body polynomials of `input_2` are multiplied by the monomial $X^d$, with $d$ the
+/// `offset`, in the ring of the ciphertexts, and are added to the ones of `input_1`. In both
+/// rings, the coefficient of degree $i < N - d$ is moved to the degree $i + d$, while the ones
+/// above wrap around the polynomial. The latter are zeros when `offset + slot_count` does not
+/// exceed $N$, hence the zero-padding semantics.
+pub trait GlweCiphertextDiscardingOffsetAdditionEngine<InputCiphertext, OutputCiphertext>:
+    AbstractEngine
+where
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity<
+        KeyDistribution = InputCiphertext::KeyDistribution,
+        RingStructure = InputCiphertext::RingStructure,
+    >,
+{
+    /// Adds a GLWE ciphertext to another one, at a slot offset.
+    fn discard_offset_add_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+        offset: MonomialIndex,
+        slot_count: PlaintextCount,
+    ) -> Result<(), GlweCiphertextDiscardingOffsetAdditionError<Self::EngineError>>;
+
+    /// Unsafely adds a GLWE ciphertext to another one, at a slot offset.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingOffsetAdditionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_offset_add_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+        offset: MonomialIndex,
+        slot_count: PlaintextCount,
+    );
+}
@@ -0,0 +1,93 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, GlweRelinearizationKeyEntity};
+
+engine_error! {
+    GlweCiphertextDiscardingRelinearizationError for GlweCiphertextDiscardingRelinearizationEngine @
+    PolynomialSizeMismatch => "The relinearization key, input and output ciphertexts polynomial \
+                               sizes must be the same.",
+    OutputGlweDimensionMismatch => "The relinearization key and the output ciphertext GLWE \
+                                    dimensions must be the same.",
+    InputGlweDimensionMismatch => "The input ciphertext GLWE dimension must be equal to k(k+3)/2, \
+                                   where k is the relinearization key GLWE dimension."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingRelinearizationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<RelinearizationKey, InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        key: &RelinearizationKey,
+    ) -> Result<(), Self>
+    where
+        RelinearizationKey: GlweRelinearizationKeyEntity,
+        InputCiphertext: GlweCiphertextEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if input.polynomial_size() != key.polynomial_size()
+            || output.polynomial_size() != key.polynomial_size()
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        let k = key.glwe_dimension().0;
+        if output.glwe_dimension().0 != k {
+            return Err(Self::OutputGlweDimensionMismatch);
+        }
+        if input.glwe_dimension().0 != k * (k + 3) / 2 {
+            return Err(Self::InputGlweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines relinearizing (discarding) GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the relinearization of the `input` GLWE ciphertext, that is, an encryption of the same message
+/// under the secret key the `key` GLWE relinearization key was generated from. The `input` is
+/// typically the output of a
+/// [`GlweCiphertextDiscardingTensorProductEngine`](super::GlweCiphertextDiscardingTensorProductEngine).
+///
+/// # Formal Definition
+///
+/// Let $S = (S\_1, \ldots, S\_k)$ be the secret key, and let the input ciphertext be
+/// $(T\_1, \ldots, T\_{k(k+1)/2}, T'\_1, \ldots, T'\_k, B)$, encrypted under the extended key made
+/// of the quadratic key polynomials $K\_p$ (the $k$ squares $S\_i^2$, followed by the products
+/// $S\_i S\_j$ for $i < j$), and of the polynomials $S\_i$. Let $\mathsf{RLK}\_{p,j}$ be the GLWE
+/// encryption of $K\_p \cdot q / B^j$ contained in the key, and $d\_{p,j}$ the $j$-th level of the
+/// signed decomposition of $T\_p$. The output ciphertext is:
+///
+/// $$ (T'\_1, \ldots, T'\_k, B) - \sum\_{p=1}^{k(k+1)/2} \sum\_{j=1}^{l} d\_{p,j} \cdot
+/// \mathsf{RLK}\_{p,j} $$
+pub trait GlweCiphertextDiscardingRelinearizationEngine<
+    RelinearizationKey,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    RelinearizationKey: GlweRelinearizationKeyEntity,
+    InputCiphertext: GlweCiphertextEntity<KeyDistribution = RelinearizationKey::KeyDistribution>,
+    OutputCiphertext: GlweCiphertextEntity<KeyDistribution = RelinearizationKey::KeyDistribution>,
+{
+    /// Relinearizes a GLWE ciphertext.
+    fn discard_relinearize_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        key: &RelinearizationKey,
+    ) -> Result<(), GlweCiphertextDiscardingRelinearizationError<Self::EngineError>>;
+
+    /// Unsafely relinearizes a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingRelinearizationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_relinearize_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        key: &RelinearizationKey,
+    );
+}
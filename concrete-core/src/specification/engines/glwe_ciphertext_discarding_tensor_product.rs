@@ -0,0 +1,98 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+use concrete_commons::parameters::ScalingFactor;
+
+engine_error! {
+    GlweCiphertextDiscardingTensorProductError for GlweCiphertextDiscardingTensorProductEngine @
+    PolynomialSizeMismatch => "The input and output ciphertexts polynomial sizes must be the same.",
+    GlweDimensionMismatch => "The two input ciphertexts GLWE dimensions must be the same.",
+    OutputGlweDimensionMismatch => "The output ciphertext GLWE dimension must be equal to \
+                                    k(k+3)/2, where k is the input GLWE dimension."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingTensorProductError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext1, InputCiphertext2, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input1: &InputCiphertext1,
+        input2: &InputCiphertext2,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext1: GlweCiphertextEntity,
+        InputCiphertext2: GlweCiphertextEntity<KeyDistribution = InputCiphertext1::KeyDistribution>,
+        OutputCiphertext: GlweCiphertextEntity<KeyDistribution = InputCiphertext1::KeyDistribution>,
+    {
+        if input1.polynomial_size() != input2.polynomial_size()
+            || output.polynomial_size() != input1.polynomial_size()
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if input1.glwe_dimension() != input2.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        let k = input1.glwe_dimension().0;
+        if output.glwe_dimension().0 != k * (k + 3) / 2 {
+            return Err(Self::OutputGlweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing (discarding) the tensor product of two GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the tensor product of the `input1` and `input2` GLWE ciphertexts, divided by `scale`. The
+/// output is encrypted under the tensor product of the input key with itself.
+///
+/// # Formal Definition
+///
+/// Let $\mathsf{C}\_1 = (A\_{1,1}, \ldots, A\_{1,k}, B\_1)$ and
+/// $\mathsf{C}\_2 = (A\_{2,1}, \ldots, A\_{2,k}, B\_2)$ be two GLWE ciphertexts encrypted under
+/// the key $(S\_1, \ldots, S\_k)$, with messages scaled by $\Delta$. The output ciphertext is made
+/// of the following polynomials, all divided by $\Delta$ and rounded:
+///
+/// + $-A\_{1,i} A\_{2,i}$ for $i \in \[1, k\]$, associated with the key polynomial $S\_i^2$;
+/// + $-(A\_{1,i} A\_{2,j} + A\_{1,j} A\_{2,i})$ for $1 \leq i < j \leq k$, associated with the
+///   key polynomial $S\_i S\_j$;
+/// + $A\_{1,i} B\_2 + B\_1 A\_{2,i}$ for $i \in \[1, k\]$, associated with the key polynomial
+///   $S\_i$;
+/// + $B\_1 B\_2$, which is the body of the output.
+///
+/// The output thus has $k + k(k-1)/2 + k = k(k+3)/2$ mask polynomials, and encrypts the product
+/// of the input messages, scaled by $\Delta$. The signs follow from the expansion of the product
+/// of the phases $B\_1 - \sum\_i A\_{1,i} S\_i$ and $B\_2 - \sum\_i A\_{2,i} S\_i$.
+pub trait GlweCiphertextDiscardingTensorProductEngine<
+    InputCiphertext1,
+    InputCiphertext2,
+    OutputCiphertext,
+>: AbstractEngine where
+    InputCiphertext1: GlweCiphertextEntity,
+    InputCiphertext2: GlweCiphertextEntity<KeyDistribution = InputCiphertext1::KeyDistribution>,
+    OutputCiphertext: GlweCiphertextEntity<KeyDistribution = InputCiphertext1::KeyDistribution>,
+{
+    /// Computes the tensor product of two GLWE ciphertexts.
+    fn discard_tensor_product_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input1: &InputCiphertext1,
+        input2: &InputCiphertext2,
+        scale: ScalingFactor,
+    ) -> Result<(), GlweCiphertextDiscardingTensorProductError<Self::EngineError>>;
+
+    /// Unsafely computes the tensor product of two GLWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingTensorProductError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_tensor_product_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input1: &InputCiphertext1,
+        input2: &InputCiphertext2,
+        scale: ScalingFactor,
+    );
+}
@@ -0,0 +1,65 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, PlaintextVectorEntity};
+
+engine_error! {
+    GlweCiphertextDiscardingTrivialEncryptionError for
+        GlweCiphertextDiscardingTrivialEncryptionEngine @
+    PlaintextCountMismatch => "The size of the input plaintext vector and the output ciphertext \
+                               polynomial size must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingTrivialEncryptionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<PlaintextVector, Ciphertext>(
+        output: &Ciphertext,
+        input: &PlaintextVector,
+    ) -> Result<(), Self>
+    where
+        PlaintextVector: PlaintextVectorEntity,
+        Ciphertext: GlweCiphertextEntity,
+    {
+        if output.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(Self::PlaintextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines trivially encrypting (discarding) GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the trivial encryption of the `input` plaintext vector.
+///
+/// # Formal Definition
+///
+/// See [`GlweCiphertextTrivialEncryptionEngine`](super::GlweCiphertextTrivialEncryptionEngine).
+/// The mask polynomials of the `output` ciphertext are set to zero, and its body polynomial to the
+/// plaintext vector, which allows to reuse an accumulator across several lookup tables.
+pub trait GlweCiphertextDiscardingTrivialEncryptionEngine<PlaintextVector, Ciphertext>:
+    AbstractEngine
+where
+    PlaintextVector: PlaintextVectorEntity,
+    Ciphertext: GlweCiphertextEntity,
+{
+    /// Trivially encrypts a plaintext vector in a GLWE ciphertext.
+    fn discard_trivially_encrypt_glwe_ciphertext(
+        &mut self,
+        output: &mut Ciphertext,
+        input: &PlaintextVector,
+    ) -> Result<(), GlweCiphertextDiscardingTrivialEncryptionError<Self::EngineError>>;
+
+    /// Unsafely trivially encrypts a plaintext vector in a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingTrivialEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_trivially_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut Ciphertext,
+        input: &PlaintextVector,
+    );
+}
@@ -0,0 +1,44 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+use concrete_commons::parameters::CiphertextLayout;
+
+engine_error! {
+    GlweCiphertextLayoutConsumingRetrievalError for GlweCiphertextLayoutConsumingRetrievalEngine @
+}
+
+/// A trait for engines retrieving the containers of GLWE ciphertexts, laid out with a given
+/// convention.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation consumes the `ciphertext` GLWE ciphertext,
+/// and returns the container holding its data, with the body polynomial placed following
+/// `layout`. The polynomials are reordered in place, such that the container is returned without
+/// being copied.
+///
+/// # Formal Definition
+pub trait GlweCiphertextLayoutConsumingRetrievalEngine<Ciphertext, Container>:
+    AbstractEngine
+where
+    Ciphertext: GlweCiphertextEntity,
+{
+    /// Retrieves the container of a GLWE ciphertext with a given layout.
+    fn consume_retrieve_glwe_ciphertext_with_layout(
+        &mut self,
+        ciphertext: Ciphertext,
+        layout: CiphertextLayout,
+    ) -> Result<Container, GlweCiphertextLayoutConsumingRetrievalError<Self::EngineError>>;
+
+    /// Unsafely retrieves the container of a GLWE ciphertext with a given layout.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextLayoutConsumingRetrievalError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn consume_retrieve_glwe_ciphertext_with_layout_unchecked(
+        &mut self,
+        ciphertext: Ciphertext,
+        layout: CiphertextLayout,
+    ) -> Container;
+}
@@ -0,0 +1,70 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+use concrete_commons::parameters::{CiphertextLayout, PolynomialSize};
+
+engine_error! {
+    GlweCiphertextLayoutCreationError for GlweCiphertextLayoutCreationEngine @
+    EmptyContainer => "The container used to create the GLWE ciphertext must not be empty.",
+    NullPolynomialSize => "The polynomial size must be greater than zero.",
+    InvalidContainerSize => "The length of the container must be a multiple of the polynomial \
+                             size, and hold at least two polynomials."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextLayoutCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        container_length: usize,
+        polynomial_size: PolynomialSize,
+    ) -> Result<(), Self> {
+        if container_length == 0 {
+            return Err(Self::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(Self::NullPolynomialSize);
+        }
+        if !container_length.is_multiple_of(polynomial_size.0)
+            || container_length / polynomial_size.0 < 2
+        {
+            return Err(Self::InvalidContainerSize);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines creating GLWE ciphertexts from existing containers laid out with a given
+/// convention.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates a GLWE ciphertext from the
+/// `container` of the caller, holding polynomials of size `polynomial_size`, with the body
+/// polynomial placed following `layout`. The polynomials are reordered in place to the layout of
+/// the engine, such that the container is moved into the output ciphertext without being copied.
+///
+/// # Formal Definition
+pub trait GlweCiphertextLayoutCreationEngine<Container, Ciphertext>: AbstractEngine
+where
+    Ciphertext: GlweCiphertextEntity,
+{
+    /// Creates a GLWE ciphertext from an existing container with a given layout.
+    fn create_glwe_ciphertext_with_layout(
+        &mut self,
+        container: Container,
+        polynomial_size: PolynomialSize,
+        layout: CiphertextLayout,
+    ) -> Result<Ciphertext, GlweCiphertextLayoutCreationError<Self::EngineError>>;
+
+    /// Unsafely creates a GLWE ciphertext from an existing container with a given layout.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextLayoutCreationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn create_glwe_ciphertext_with_layout_unchecked(
+        &mut self,
+        container: Container,
+        polynomial_size: PolynomialSize,
+        layout: CiphertextLayout,
+    ) -> Ciphertext;
+}
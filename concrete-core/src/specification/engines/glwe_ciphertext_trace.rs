@@ -0,0 +1,80 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweAutomorphismKeyEntity, GlweCiphertextEntity};
+
+engine_error! {
+    GlweCiphertextTraceError for GlweCiphertextTraceEngine @
+    GlweDimensionMismatch => "The automorphism key and the input ciphertext GLWE dimensions must \
+                              be the same.",
+    PolynomialSizeMismatch => "The automorphism key and the input ciphertext polynomial sizes must \
+                               be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextTraceError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<AutomorphismKey, InputCiphertext>(
+        key: &AutomorphismKey,
+        input: &InputCiphertext,
+    ) -> Result<(), Self>
+    where
+        AutomorphismKey: GlweAutomorphismKeyEntity,
+        InputCiphertext: GlweCiphertextEntity,
+    {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the trace of a GLWE ciphertext.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext containing the
+/// field trace of the `input` GLWE ciphertext, that is, the sum of all its automorphisms, each of
+/// them keyswitched back to the original key with the `key` GLWE automorphism key.
+///
+/// The output ciphertext encrypts a constant polynomial, whose coefficient is $N$ times the
+/// constant coefficient of the input plaintext. In particular, when the slots of a message are
+/// first rotated to the constant coefficient, this allows to aggregate packed values.
+///
+/// # Formal Definition
+///
+/// Let $M = \sum\_{i=0}^{N-1} m\_i X^i$ be the plaintext of the input ciphertext. The trace of
+/// $M$ is the sum of the $\tau\_g(M) = M(X^g)$, for all odd $g \in \[1, 2N)$. Since this sum
+/// vanishes for every non-constant monomial, the output ciphertext encrypts:
+/// $$
+/// \mathrm{Tr}(M) = N \cdot m\_0
+/// $$
+/// The trace is evaluated with $\log\_2(N)$ automorphisms: for $a$ from $0$ to $\log\_2(N) - 1$,
+/// the ciphertext $C$ is replaced by $C + \tau\_{N/2^a + 1}(C)$.
+pub trait GlweCiphertextTraceEngine<AutomorphismKey, InputCiphertext, OutputCiphertext>:
+    AbstractEngine
+where
+    AutomorphismKey: GlweAutomorphismKeyEntity,
+    InputCiphertext: GlweCiphertextEntity<KeyDistribution = AutomorphismKey::KeyDistribution>,
+    OutputCiphertext: GlweCiphertextEntity<KeyDistribution = AutomorphismKey::KeyDistribution>,
+{
+    /// Computes the trace of a GLWE ciphertext.
+    fn trace_glwe_ciphertext(
+        &mut self,
+        key: &AutomorphismKey,
+        input: &InputCiphertext,
+    ) -> Result<OutputCiphertext, GlweCiphertextTraceError<Self::EngineError>>;
+
+    /// Unsafely computes the trace of a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextTraceError`]. For safety concerns _specific_ to an engine, refer to the
+    /// implementer safety section.
+    unsafe fn trace_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &AutomorphismKey,
+        input: &InputCiphertext,
+    ) -> OutputCiphertext;
+}
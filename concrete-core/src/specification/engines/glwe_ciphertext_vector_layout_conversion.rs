@@ -0,0 +1,74 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use concrete_commons::parameters::{CiphertextLayout, GlweSize, PolynomialSize};
+
+engine_error! {
+    GlweCiphertextVectorLayoutConversionError for GlweCiphertextVectorLayoutConversionEngine @
+    EmptyContainer => "The container of the GLWE ciphertexts must not be empty.",
+    NullGlweSize => "The GLWE size must be greater than zero.",
+    NullPolynomialSize => "The polynomial size must be greater than zero.",
+    InvalidContainerSize => "The length of the container must be a multiple of the GLWE size \
+                             times the polynomial size."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextVectorLayoutConversionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        container_length: usize,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+    ) -> Result<(), Self> {
+        if container_length == 0 {
+            return Err(Self::EmptyContainer);
+        }
+        if glwe_size.0 == 0 {
+            return Err(Self::NullGlweSize);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(Self::NullPolynomialSize);
+        }
+        if !container_length.is_multiple_of(glwe_size.0 * polynomial_size.0) {
+            return Err(Self::InvalidContainerSize);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines converting raw containers of GLWE ciphertexts between layouts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation consumes the `input` container, holding GLWE
+/// ciphertexts of size `glwe_size` and polynomial size `polynomial_size` laid out contiguously
+/// with the `input_layout` convention, and returns a container holding the same ciphertexts with
+/// the `output_layout` convention.
+///
+/// # Formal Definition
+pub trait GlweCiphertextVectorLayoutConversionEngine<InputContainer, OutputContainer>:
+    AbstractEngine
+{
+    /// Converts a container of GLWE ciphertexts between layouts.
+    fn convert_glwe_ciphertext_vector_layout(
+        &mut self,
+        input: InputContainer,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        input_layout: CiphertextLayout,
+        output_layout: CiphertextLayout,
+    ) -> Result<OutputContainer, GlweCiphertextVectorLayoutConversionError<Self::EngineError>>;
+
+    /// Unsafely converts a container of GLWE ciphertexts between layouts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextVectorLayoutConversionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn convert_glwe_ciphertext_vector_layout_unchecked(
+        &mut self,
+        input: InputContainer,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        input_layout: CiphertextLayout,
+        output_layout: CiphertextLayout,
+    ) -> OutputContainer;
+}
@@ -0,0 +1,81 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweRelinearizationKeyEntity, GlweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+engine_error! {
+    GlweRelinearizationKeyCreationError for GlweRelinearizationKeyCreationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+impl<EngineError: std::error::Error> GlweRelinearizationKeyCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        integer_precision: usize,
+    ) -> Result<(), Self> {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+
+        if decomposition_level_count.0 * decomposition_base_log.0 > integer_precision {
+            return Err(Self::DecompositionTooLarge);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines creating GLWE relinearization keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates the GLWE relinearization key of the
+/// `secret_key` GLWE secret key, which allows to turn the tensor product of two ciphertexts
+/// encrypted under this key back into a ciphertext encrypted under this key (see
+/// [`GlweCiphertextDiscardingRelinearizationEngine`](super::GlweCiphertextDiscardingRelinearizationEngine)).
+///
+/// # Formal Definition
+///
+/// Let $S = (S\_1, \ldots, S\_k)$ be the secret key. The quadratic key polynomials are the $k$
+/// squares $S\_i^2$, followed by the $k(k-1)/2$ products $S\_i S\_j$ for $1 \leq i < j \leq k$. For
+/// every quadratic key polynomial $K\_p$ and every level $j \in \[1, l\]$, the key contains a GLWE
+/// encryption of $K\_p \cdot q / B^j$ under $S$.
+pub trait GlweRelinearizationKeyCreationEngine<SecretKey, RelinearizationKey>:
+    AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    RelinearizationKey: GlweRelinearizationKeyEntity<KeyDistribution = SecretKey::KeyDistribution>,
+{
+    /// Creates a GLWE relinearization key.
+    fn create_glwe_relinearization_key(
+        &mut self,
+        secret_key: &SecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<RelinearizationKey, GlweRelinearizationKeyCreationError<Self::EngineError>>;
+
+    /// Unsafely creates a GLWE relinearization key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweRelinearizationKeyCreationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn create_glwe_relinearization_key_unchecked(
+        &mut self,
+        secret_key: &SecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> RelinearizationKey;
+}
@@ -0,0 +1,125 @@
+//! A module containing the tables of the operations implemented by the engines.
+//!
+//! Every backend exposes an `IMPLEMENTATIONS` table in its `engines` module, listing the
+//! `*Engine` traits its engine implements, along with the entity types they are implemented for.
+//! This allows tools and foreign bindings to discover the capabilities of a backend, without
+//! reading its sources.
+//!
+//! # Registration
+//!
+//! The tables are declared with the `engine_implementations!` macro, which checks at compile time
+//! that every entry matches an actual implementation of the trait: a table can be incomplete, but
+//! can not list an operation the engine does not implement. Blanket implementations, such as the
+//! conversions of an entity to itself, are not listed. The entries of the operations implemented
+//! behind a feature are gated with the same `#[cfg(...)]` attribute as the implementation.
+
+/// An entry of an `IMPLEMENTATIONS` table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EngineImplementation {
+    /// The name of the engine type.
+    pub engine: &'static str,
+    /// The name of the `*Engine` trait.
+    pub operation: &'static str,
+    /// The names of the entity types the trait is implemented for, in the order of the trait
+    /// generic arguments.
+    pub entities: &'static [&'static str],
+}
+
+impl EngineImplementation {
+    /// Returns whether the entry is the implementation of `operation` for `entities`.
+    pub fn matches(&self, operation: &str, entities: &[&str]) -> bool {
+        self.operation == operation && self.entities == entities
+    }
+}
+
+/// Returns whether `implementations` lists the implementation of `operation` for `entities`.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::backends::core::engines::IMPLEMENTATIONS;
+/// use concrete_core::prelude::is_implemented;
+/// assert!(is_implemented(
+///     IMPLEMENTATIONS,
+///     "LweCiphertextDiscardingKeyswitchEngine",
+///     &["LweKeyswitchKey64", "LweCiphertext64", "LweCiphertext64"],
+/// ));
+/// ```
+pub fn is_implemented(
+    implementations: &[EngineImplementation],
+    operation: &str,
+    entities: &[&str],
+) -> bool {
+    implementations
+        .iter()
+        .any(|implementation| implementation.matches(operation, entities))
+}
+
+// Declares the `IMPLEMENTATIONS` table of an engine. The traits and entities must be in scope.
+macro_rules! engine_implementations {
+    ($engine:ident {
+        $($(#[$meta:meta])* $operation:ident ( $($entity:ty),+ $(,)? )),+ $(,)?
+    }) => {
+        $(
+            $(#[$meta])*
+            const _: fn() = || {
+                fn implements<Engine: $operation<$($entity),+>>() {}
+                implements::<$engine>();
+            };
+        )+
+
+        #[doc=concat!(
+            "The table of the operations implemented by the [`",
+            stringify!($engine),
+            "`], ordered by operation."
+        )]
+        pub const IMPLEMENTATIONS: &[$crate::specification::engines::EngineImplementation] = &[
+            $(
+                $(#[$meta])*
+                $crate::specification::engines::EngineImplementation {
+                    engine: stringify!($engine),
+                    operation: stringify!($operation),
+                    entities: &[$(stringify!($entity)),+],
+                },
+            )+
+        ];
+    };
+}
+pub(crate) use engine_implementations;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TABLE: &[EngineImplementation] = &[
+        EngineImplementation {
+            engine: "Engine",
+            operation: "LweCiphertextDiscardingAdditionEngine",
+            entities: &["LweCiphertext32", "LweCiphertext32"],
+        },
+        EngineImplementation {
+            engine: "Engine",
+            operation: "LweCiphertextEncryptionEngine",
+            entities: &["LweSecretKey32", "Plaintext32", "LweCiphertext32"],
+        },
+    ];
+
+    #[test]
+    fn test_is_implemented_matches_operation_and_entities() {
+        assert!(is_implemented(
+            TABLE,
+            "LweCiphertextDiscardingAdditionEngine",
+            &["LweCiphertext32", "LweCiphertext32"]
+        ));
+        assert!(!is_implemented(
+            TABLE,
+            "LweCiphertextDiscardingAdditionEngine",
+            &["LweCiphertext64", "LweCiphertext64"]
+        ));
+        assert!(!is_implemented(
+            TABLE,
+            "LweCiphertextEncryptionEngine",
+            &["LweSecretKey32", "Plaintext32"]
+        ));
+    }
+}
@@ -0,0 +1,90 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweBootstrapKeyEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+engine_error! {
+    LweBootstrapKeyConstructionError for LweBootstrapKeyConstructionEngine @
+    EmptyContainer => "The container used to construct the LWE bootstrap key must not be empty.",
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext.",
+    InvalidContainerSize => "The length of the container must be a multiple of the level count \
+                             times the squared GLWE size times the polynomial size."
+}
+
+impl<EngineError: std::error::Error> LweBootstrapKeyConstructionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        container_length: usize,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        integer_precision: usize,
+    ) -> Result<(), Self> {
+        if container_length == 0 {
+            return Err(Self::EmptyContainer);
+        }
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > integer_precision {
+            return Err(Self::DecompositionTooLarge);
+        }
+        let glwe_size = glwe_dimension.to_glwe_size().0;
+        let ggsw_length = decomposition_level_count.0 * glwe_size * glwe_size * polynomial_size.0;
+        if !container_length.is_multiple_of(ggsw_length) {
+            return Err(Self::InvalidContainerSize);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines constructing LWE bootstrap keys from existing containers.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation constructs an LWE bootstrap key from the
+/// `container` of the caller, holding the GGSW ciphertexts of the key laid out contiguously. The
+/// container is moved into the output key without any copy, which makes it possible to wrap a
+/// buffer filled by a foreign memory transfer. Contrary to the
+/// [`LweBootstrapKeyCreationEngine`](super::LweBootstrapKeyCreationEngine), no key is generated by
+/// this operation.
+///
+/// # Formal Definition
+pub trait LweBootstrapKeyConstructionEngine<Container, BootstrapKey>: AbstractEngine
+where
+    BootstrapKey: LweBootstrapKeyEntity,
+{
+    /// Constructs an LWE bootstrap key from an existing container.
+    fn construct_lwe_bootstrap_key(
+        &mut self,
+        container: Container,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<BootstrapKey, LweBootstrapKeyConstructionError<Self::EngineError>>;
+
+    /// Unsafely constructs an LWE bootstrap key from an existing container.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweBootstrapKeyConstructionError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn construct_lwe_bootstrap_key_unchecked(
+        &mut self,
+        container: Container,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> BootstrapKey;
+}
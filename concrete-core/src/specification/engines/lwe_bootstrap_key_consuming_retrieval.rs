@@ -0,0 +1,37 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweBootstrapKeyEntity;
+
+engine_error! {
+    LweBootstrapKeyConsumingRetrievalError for LweBootstrapKeyConsumingRetrievalEngine @
+}
+
+/// A trait for engines retrieving the containers of LWE bootstrap keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation consumes the `bootstrap_key` LWE bootstrap key,
+/// and returns the container holding its data, without any copy.
+///
+/// # Formal Definition
+pub trait LweBootstrapKeyConsumingRetrievalEngine<BootstrapKey, Container>: AbstractEngine
+where
+    BootstrapKey: LweBootstrapKeyEntity,
+{
+    /// Retrieves the container of an LWE bootstrap key.
+    fn consume_retrieve_lwe_bootstrap_key(
+        &mut self,
+        bootstrap_key: BootstrapKey,
+    ) -> Result<Container, LweBootstrapKeyConsumingRetrievalError<Self::EngineError>>;
+
+    /// Unsafely retrieves the container of an LWE bootstrap key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweBootstrapKeyConsumingRetrievalError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn consume_retrieve_lwe_bootstrap_key_unchecked(
+        &mut self,
+        bootstrap_key: BootstrapKey,
+    ) -> Container;
+}
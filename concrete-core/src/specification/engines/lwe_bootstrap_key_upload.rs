@@ -0,0 +1,43 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweBootstrapKeyEntity;
+
+engine_error! {
+    LweBootstrapKeyUploadError for LweBootstrapKeyUploadEngine @
+}
+
+/// A trait for engines uploading LWE bootstrap keys to the memory of a device.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a LWE bootstrap key stored in the
+/// memory of a device (for instance a gpu), containing the `input` bootstrap key.
+///
+/// Contrarily to the [`LweBootstrapKeyConversionEngine`](super::LweBootstrapKeyConversionEngine),
+/// the engine may keep the uploaded key resident on the device after the returned entity is
+/// dropped, and return it again when the same key is uploaded later on, instead of copying it
+/// anew.
+///
+/// # Formal Definition
+pub trait LweBootstrapKeyUploadEngine<InputKey, OutputKey>: AbstractEngine
+where
+    InputKey: LweBootstrapKeyEntity,
+    OutputKey: LweBootstrapKeyEntity<
+        InputKeyDistribution = InputKey::InputKeyDistribution,
+        OutputKeyDistribution = InputKey::OutputKeyDistribution,
+    >,
+{
+    /// Uploads an LWE bootstrap key.
+    fn upload_lwe_bootstrap_key(
+        &mut self,
+        input: &InputKey,
+    ) -> Result<OutputKey, LweBootstrapKeyUploadError<Self::EngineError>>;
+
+    /// Unsafely uploads an LWE bootstrap key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweBootstrapKeyUploadError`]. For safety concerns _specific_ to an engine, refer to the
+    /// implementer safety section.
+    unsafe fn upload_lwe_bootstrap_key_unchecked(&mut self, input: &InputKey) -> OutputKey;
+}
@@ -0,0 +1,100 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweBootstrapKeyEntity, LweCiphertextEntity, LweKeyswitchKeyEntity,
+};
+
+engine_error! {
+    LweCiphertextAccumulationError for LweCiphertextAccumulationEngine @
+    InputLweDimensionMismatch => "The accumulator and input LWE dimensions must be the same.",
+    KeyswitchKeyInputLweDimensionMismatch => "The accumulator and keyswitch key input LWE \
+                                              dimensions must be the same.",
+    KeyswitchKeyOutputLweDimensionMismatch => "The keyswitch key output and bootstrap key input \
+                                               LWE dimensions must be the same.",
+    BootstrapKeyOutputLweDimensionMismatch => "The accumulator and bootstrap key output LWE \
+                                               dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextAccumulationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<Accumulator, InputCiphertext, KeyswitchKey, BootstrapKey>(
+        accumulator: &Accumulator,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+        bsk: &BootstrapKey,
+    ) -> Result<(), Self>
+    where
+        Accumulator: LweCiphertextEntity,
+        InputCiphertext: LweCiphertextEntity,
+        KeyswitchKey: LweKeyswitchKeyEntity,
+        BootstrapKey: LweBootstrapKeyEntity,
+    {
+        if accumulator.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if ksk.input_lwe_dimension() != accumulator.lwe_dimension() {
+            return Err(Self::KeyswitchKeyInputLweDimensionMismatch);
+        }
+        if ksk.output_lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::KeyswitchKeyOutputLweDimensionMismatch);
+        }
+        if bsk.output_lwe_dimension() != accumulator.lwe_dimension() {
+            return Err(Self::BootstrapKeyOutputLweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines accumulating LWE ciphertexts into a stateful accumulator.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation adds the `input` LWE ciphertext to the
+/// `accumulator` LWE ciphertext, which keeps track of an upper bound on its message (its
+/// _degree_), and of an estimation of its noise.
+///
+/// Whenever the sum would overflow the message space of the accumulator, or exceed its noise
+/// budget, the accumulator is first reset: it is keyswitched with `ksk` and bootstrapped with
+/// `bsk`, using a lookup table which reduces the message modulo the modulus of the accumulated
+/// sum. The reset brings the degree back below this modulus, and the noise back to the output
+/// noise of the bootstrap. The accumulator is encrypted under the output key of `bsk`, which is
+/// the input key of `ksk`.
+///
+/// The sequence of operations is therefore decided by the tracked state alone, and a stream of
+/// ciphertexts can be summed without deciding by hand when to bootstrap.
+///
+/// # Formal Definition
+pub trait LweCiphertextAccumulationEngine<Accumulator, InputCiphertext, KeyswitchKey, BootstrapKey>:
+    AbstractEngine
+where
+    Accumulator: LweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    InputCiphertext: LweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    KeyswitchKey: LweKeyswitchKeyEntity<
+        InputKeyDistribution = BootstrapKey::OutputKeyDistribution,
+        OutputKeyDistribution = BootstrapKey::InputKeyDistribution,
+    >,
+    BootstrapKey: LweBootstrapKeyEntity,
+{
+    /// Adds an LWE ciphertext to an accumulator, resetting it beforehand if needed.
+    fn accumulate_lwe_ciphertext(
+        &mut self,
+        accumulator: &mut Accumulator,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextAccumulationError<Self::EngineError>>;
+
+    /// Unsafely adds an LWE ciphertext to an accumulator, resetting it beforehand if needed.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextAccumulationError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn accumulate_lwe_ciphertext_unchecked(
+        &mut self,
+        accumulator: &mut Accumulator,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+        bsk: &BootstrapKey,
+    );
+}
@@ -0,0 +1,92 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{CleartextEntity, LweCiphertextEntity};
+use concrete_commons::parameters::DenominatorLog;
+
+engine_error! {
+    LweCiphertextCleartextDiscardingRationalMultiplicationError for LweCiphertextCleartextDiscardingRationalMultiplicationEngine @
+    LweDimensionMismatch => "The input and output ciphertext LWE dimension must be the same.",
+    DenominatorLogTooLarge => "The denominator log must be smaller than the integer precision."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextCleartextDiscardingRationalMultiplicationError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input_1: &InputCiphertext,
+        denominator_log: DenominatorLog,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity<KeyDistribution = InputCiphertext::KeyDistribution>,
+    {
+        if output.lwe_dimension() != input_1.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if denominator_log.0 >= integer_precision {
+            return Err(Self::DenominatorLogTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines multiplying (discarding) LWE ciphertexts by rationals with a power-of-two
+/// denominator.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the multiplication of the `input_1` LWE ciphertext with the rational
+/// $\frac{a}{2^d}$, where $a$ is the `numerator` cleartext and $d$ the `denominator_log` value.
+///
+/// This allows to rescale a fixed-point message by a non-integer factor, without shifting its
+/// encoding by the factor $a$. The division by $2^d$ is computed on every coefficient of the
+/// ciphertext, and rounded to the nearest integer.
+///
+/// # Formal Definition
+///
+/// For a ciphertext $(a\_1, \cdots, a\_n, b)$ with integers of $q$ bits, the output ciphertext is
+/// $(\lfloor a \cdot a\_1 / 2^d \rceil, \cdots, \lfloor a \cdot a\_n / 2^d \rceil,
+/// \lfloor a \cdot b / 2^d \rceil)$, where the products are computed without overflow before
+/// being rounded, and reduced modulo $2^q$ afterwards.
+///
+/// The division on the torus is only defined modulo $2^{q-d}$: the $d$ most significant bits of
+/// the output plaintext are undefined, and the message must fit in the remaining bits. The
+/// rounding of the $n + 1$ coefficients adds a noise which variance is
+/// $\frac{1}{12}(1 + n \cdot \mathbb{E}\[s\_i^2\])$, in units of the least significant bit. See
+/// `concrete_npe::estimate_rational_multiplication_noise` for the full estimation.
+pub trait LweCiphertextCleartextDiscardingRationalMultiplicationEngine<
+    InputCiphertext,
+    Cleartext,
+    OutputCiphertext,
+>: AbstractEngine where
+    Cleartext: CleartextEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity<KeyDistribution = InputCiphertext::KeyDistribution>,
+{
+    /// Multiply an LWE ciphertext with a rational.
+    fn discard_rational_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        numerator: &Cleartext,
+        denominator_log: DenominatorLog,
+    ) -> Result<(), LweCiphertextCleartextDiscardingRationalMultiplicationError<Self::EngineError>>;
+
+    /// Unsafely multiply an LWE ciphertext with a rational.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextCleartextDiscardingRationalMultiplicationError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_rational_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        numerator: &Cleartext,
+        denominator_log: DenominatorLog,
+    );
+}
@@ -0,0 +1,74 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{CleartextEntity, LweCiphertextEntity};
+
+engine_error! {
+    LweCiphertextCleartextFusingMultiplyAddError for LweCiphertextCleartextFusingMultiplyAddEngine @
+    LweDimensionMismatch => "The input and output ciphertext LWE dimension must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextCleartextFusingMultiplyAddError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity<KeyDistribution = InputCiphertext::KeyDistribution>,
+    {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines multiplying LWE ciphertexts by cleartexts, and adding (fusing) the result
+/// to other LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation adds the multiplication of the `input` LWE
+/// ciphertext with the `cleartext` cleartext to the `output` LWE ciphertext, without allocating
+/// the intermediate product.
+///
+/// # Formal Definition
+///
+/// The body and every coefficient of the mask of the `output` ciphertext are updated with:
+/// $$
+/// \mathsf{out}\_i \leftarrow \mathsf{out}\_i + c \cdot \mathsf{in}\_i
+/// $$
+/// such that the `output` ciphertext encrypts the sum of its previous plaintext and of $c$ times
+/// the plaintext of `input`. The noise variance of the `input` ciphertext is multiplied by $c^2$
+/// and added to the one of the `output` ciphertext.
+pub trait LweCiphertextCleartextFusingMultiplyAddEngine<
+    InputCiphertext,
+    Cleartext,
+    OutputCiphertext,
+>: AbstractEngine where
+    Cleartext: CleartextEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity<KeyDistribution = InputCiphertext::KeyDistribution>,
+{
+    /// Multiply an LWE ciphertext with a cleartext, and add the result to an other.
+    fn fuse_mul_add_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        cleartext: &Cleartext,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplyAddError<Self::EngineError>>;
+
+    /// Unsafely multiply an LWE ciphertext with a cleartext, and add the result to an other.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextCleartextFusingMultiplyAddError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn fuse_mul_add_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        cleartext: &Cleartext,
+    );
+}
@@ -0,0 +1,86 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{LweCiphertextEntity, LweKeyswitchKeyBatchEntity};
+use concrete_commons::parameters::LweKeyswitchKeyIndex;
+
+engine_error! {
+    LweCiphertextDiscardingBatchedKeyswitchError for LweCiphertextDiscardingBatchedKeyswitchEngine @
+    InputLweDimensionMismatch => "The input ciphertext LWE dimension and keyswitch key batch input \
+                                  LWE dimensions must be the same.",
+    OutputLweDimensionMismatch => "The output ciphertext LWE dimension and keyswitch key batch \
+                                   output LWE dimensions must be the same.",
+    IndexOutOfBounds => "The key index must be smaller than the number of keys in the batch."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingBatchedKeyswitchError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<KeyswitchKeyBatch, InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        ksk_batch: &KeyswitchKeyBatch,
+        index: LweKeyswitchKeyIndex,
+    ) -> Result<(), Self>
+    where
+        KeyswitchKeyBatch: LweKeyswitchKeyBatchEntity,
+        InputCiphertext:
+            LweCiphertextEntity<KeyDistribution = KeyswitchKeyBatch::InputKeyDistribution>,
+        OutputCiphertext:
+            LweCiphertextEntity<KeyDistribution = KeyswitchKeyBatch::OutputKeyDistribution>,
+    {
+        if input.lwe_dimension() != ksk_batch.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk_batch.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        if index.0 >= ksk_batch.key_count().0 {
+            return Err(Self::IndexOutOfBounds);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines keyswitching (discarding) LWE ciphertexts with a key selected in a batch.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the keyswitch of the `input` LWE ciphertext, using the LWE keyswitch key stored at the `index`
+/// position of the `ksk_batch` LWE keyswitch key batch. The output ciphertext is encrypted under
+/// the output key of the selected keyswitch key.
+///
+/// # Formal Definition
+pub trait LweCiphertextDiscardingBatchedKeyswitchEngine<
+    KeyswitchKeyBatch,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    KeyswitchKeyBatch: LweKeyswitchKeyBatchEntity,
+    InputCiphertext: LweCiphertextEntity<KeyDistribution = KeyswitchKeyBatch::InputKeyDistribution>,
+    OutputCiphertext:
+        LweCiphertextEntity<KeyDistribution = KeyswitchKeyBatch::OutputKeyDistribution>,
+{
+    /// Keyswitch an LWE ciphertext with a key of a batch.
+    fn discard_batched_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk_batch: &KeyswitchKeyBatch,
+        index: LweKeyswitchKeyIndex,
+    ) -> Result<(), LweCiphertextDiscardingBatchedKeyswitchError<Self::EngineError>>;
+
+    /// Unsafely keyswitch an LWE ciphertext with a key of a batch.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingBatchedKeyswitchError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_batched_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk_batch: &KeyswitchKeyBatch,
+        index: LweKeyswitchKeyIndex,
+    );
+}
@@ -0,0 +1,223 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GgswCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity, PlaintextVectorEntity,
+};
+use concrete_commons::parameters::{PlaintextCount, PolynomialSize};
+
+engine_error! {
+    LweCiphertextDiscardingLargeTableLookupError for LweCiphertextDiscardingLargeTableLookupEngine @
+    InputLweDimensionMismatch => "The input ciphertext and key LWE dimension must be the same.",
+    OutputLweDimensionMismatch => "The output ciphertext dimension and key size (dimension * \
+                                   polynomial size) must be the same.",
+    TableSizeNotPowerOfTwo => "The table size must be a power of two greater than one.",
+    SelectorCountMismatch => "The selector count must match the structure of the lookup.",
+    SelectorPolynomialSizeMismatch => "The selectors and key polynomial sizes must be the same.",
+    SelectorGlweDimensionMismatch => "The selectors and key GLWE dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingLargeTableLookupError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<
+        BootstrapKey,
+        GgswCiphertext,
+        PlaintextVector,
+        InputCiphertext,
+        OutputCiphertext,
+    >(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        selectors: &[GgswCiphertext],
+        table: &PlaintextVector,
+        bsk: &BootstrapKey,
+    ) -> Result<(), Self>
+    where
+        BootstrapKey: LweBootstrapKeyEntity,
+        GgswCiphertext: GgswCiphertextEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity,
+    {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        let table_size = table.plaintext_count().0;
+        if table_size < 2 || !table_size.is_power_of_two() {
+            return Err(Self::TableSizeNotPowerOfTwo);
+        }
+        let structure =
+            LargeTableLookupStructure::new(table.plaintext_count(), bsk.polynomial_size());
+        if selectors.len() != structure.high_bits {
+            return Err(Self::SelectorCountMismatch);
+        }
+        for selector in selectors.iter() {
+            if selector.polynomial_size() != bsk.polynomial_size() {
+                return Err(Self::SelectorPolynomialSizeMismatch);
+            }
+            if selector.glwe_dimension() != bsk.glwe_dimension() {
+                return Err(Self::SelectorGlweDimensionMismatch);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The logarithm of the smallest number of accumulator coefficients given to every entry of a
+/// table chunk, which leaves room for the noise of the bootstrapped ciphertext, and for the error
+/// of its modulus switching.
+pub const LARGE_TABLE_LOOKUP_MIN_BOX_SIZE_LOG: usize = 6;
+
+/// The decomposition of the index of a large table lookup.
+///
+/// A table of $2^t$ entries is cut in $2^h$ chunks of $2^l$ entries, with $t = h + l$. The $l$
+/// low bits of the index select an entry of a chunk with a programmable bootstrap, while the $h$
+/// high bits select the chunk with a tree of CMUXes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LargeTableLookupStructure {
+    /// The number of index bits evaluated by the bootstrap.
+    pub low_bits: usize,
+    /// The number of index bits evaluated by the CMUX tree, which is also the number of selectors.
+    pub high_bits: usize,
+}
+
+impl LargeTableLookupStructure {
+    /// Returns the structure of the lookup of a table of `table_size` entries with accumulators of
+    /// `polynomial_size` coefficients.
+    ///
+    /// As many index bits as possible are given to the bootstrap, while keeping at least
+    /// `2^LARGE_TABLE_LOOKUP_MIN_BOX_SIZE_LOG` coefficients per entry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `table_size` is not a power of two greater than one, or if `polynomial_size` is
+    /// too small to hold a single entry.
+    pub fn new(table_size: PlaintextCount, polynomial_size: PolynomialSize) -> Self {
+        assert!(
+            table_size.0 >= 2 && table_size.0.is_power_of_two(),
+            "The table size must be a power of two greater than one."
+        );
+        let poly_size_log = polynomial_size.log2().0;
+        assert!(
+            poly_size_log > LARGE_TABLE_LOOKUP_MIN_BOX_SIZE_LOG,
+            "The polynomial size is too small to hold a table chunk."
+        );
+        let table_size_log = table_size.0.trailing_zeros() as usize;
+        let low_bits = table_size_log.min(poly_size_log - LARGE_TABLE_LOOKUP_MIN_BOX_SIZE_LOG);
+        LargeTableLookupStructure {
+            low_bits,
+            high_bits: table_size_log - low_bits,
+        }
+    }
+
+    /// Returns the number of table chunks, each of which is turned into an accumulator.
+    pub fn chunk_count(&self) -> usize {
+        1 << self.high_bits
+    }
+
+    /// Splits `index` into the part encrypted in the bootstrapped LWE ciphertext, and the bits
+    /// encrypted in the selectors, ordered the way the selectors are expected.
+    pub fn split_index(&self, index: usize) -> (usize, Vec<bool>) {
+        let low = index & ((1 << self.low_bits) - 1);
+        let high = (0..self.high_bits)
+            .map(|bit| (index >> (self.low_bits + bit)) & 1 == 1)
+            .collect();
+        (low, high)
+    }
+}
+
+/// A trait for engines looking up cleartext tables larger than a polynomial with an encrypted
+/// index.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// an encryption of the entry of the `table` plaintext vector whose index is encrypted by the
+/// `input` LWE ciphertext and the `selectors` GGSW ciphertexts.
+///
+/// The table is decomposed according to its size and the polynomial size of the `bsk` bootstrap
+/// key, as described by [`LargeTableLookupStructure`]:
+///
+/// * The `input` LWE ciphertext encrypts the $l$ low bits of the index, with one bit of padding:
+/// for integers of $q$ bits, the low part $i_l$ is encoded as $i_l \cdot 2^{q - l - 1}$.
+/// * The `selectors` encrypt the $h$ high bits of the index, one bit per GGSW ciphertext,
+/// starting from the least significant one. They are encrypted under the output GLWE secret key
+/// of `bsk`. The [`LargeTableLookupStructure::split_index`] method computes both parts of an
+/// index.
+///
+/// Every chunk of the table is turned into an accumulator, and the CMUX tree controlled by the
+/// selectors keeps the accumulator of the indexed chunk, which is used to bootstrap the `input`
+/// ciphertext. The table entries are returned as they are stored, which means that they must
+/// already be encoded.
+///
+/// # Formal Definition
+pub trait LweCiphertextDiscardingLargeTableLookupEngine<
+    BootstrapKey,
+    GgswCiphertext,
+    PlaintextVector,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    BootstrapKey: LweBootstrapKeyEntity,
+    GgswCiphertext: GgswCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    PlaintextVector: PlaintextVectorEntity,
+    InputCiphertext: LweCiphertextEntity<KeyDistribution = BootstrapKey::InputKeyDistribution>,
+    OutputCiphertext: LweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+{
+    /// Looks up a large table with an encrypted index.
+    fn discard_lookup_large_table_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        selectors: &[GgswCiphertext],
+        table: &PlaintextVector,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextDiscardingLargeTableLookupError<Self::EngineError>>;
+
+    /// Unsafely looks up a large table with an encrypted index.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingLargeTableLookupError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_lookup_large_table_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        selectors: &[GgswCiphertext],
+        table: &PlaintextVector,
+        bsk: &BootstrapKey,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_structure_fills_the_accumulator() {
+        let structure =
+            LargeTableLookupStructure::new(PlaintextCount(1 << 14), PolynomialSize(1024));
+        assert_eq!(structure.low_bits, 4);
+        assert_eq!(structure.high_bits, 10);
+        assert_eq!(structure.chunk_count(), 1024);
+    }
+
+    #[test]
+    fn test_small_table_needs_no_selector() {
+        let structure = LargeTableLookupStructure::new(PlaintextCount(8), PolynomialSize(1024));
+        assert_eq!(structure.low_bits, 3);
+        assert_eq!(structure.high_bits, 0);
+    }
+
+    #[test]
+    fn test_split_index() {
+        let structure =
+            LargeTableLookupStructure::new(PlaintextCount(1 << 10), PolynomialSize(2048));
+        let (low, high) = structure.split_index(0b10_1101_0110);
+        assert_eq!(low, 0b1_0110);
+        assert_eq!(high, vec![false, true, true, false, true]);
+    }
+}
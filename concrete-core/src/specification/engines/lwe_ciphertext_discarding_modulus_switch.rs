@@ -0,0 +1,96 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextEntity;
+use concrete_commons::parameters::CiphertextModulusLog;
+
+engine_error! {
+    LweCiphertextDiscardingModulusSwitchError for LweCiphertextDiscardingModulusSwitchEngine @
+    LweDimensionMismatch => "The input and output LWE dimensions must be the same.",
+    NullModulusLog => "The logarithm of the target modulus must be greater than zero.",
+    ModulusLogTooLarge => "The target modulus must not be larger than the modulus of the input \
+                           ciphertext.",
+    ModulusLogExceedsOutputPrecision => "The target modulus must not be larger than the modulus \
+                                         of the output ciphertext."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingModulusSwitchError<EngineError> {
+    /// Validates the inputs, `input_precision` and `output_precision` being the number of bits of
+    /// the integers of the input and output ciphertexts.
+    pub fn perform_generic_checks<InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        modulus_log: CiphertextModulusLog,
+        input_precision: usize,
+        output_precision: usize,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity<KeyDistribution = InputCiphertext::KeyDistribution>,
+    {
+        if input.lwe_dimension() != output.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if modulus_log.0 == 0 {
+            return Err(Self::NullModulusLog);
+        }
+        if modulus_log.0 > input_precision {
+            return Err(Self::ModulusLogTooLarge);
+        }
+        if modulus_log.0 > output_precision {
+            return Err(Self::ModulusLogExceedsOutputPrecision);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines switching the modulus of (discarding) LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the `input` LWE ciphertext, switched to the modulus $2^k$, where $k$ is `modulus_log`.
+///
+/// # Formal Definition
+///
+/// Every coefficient $c$ of the mask and body of the `input` ciphertext, an integer modulo the
+/// native modulus $q = 2^w$ of the input, is replaced with:
+///
+/// $$c' = \left\lfloor \frac{2^k \cdot c}{q} \right\rceil \mod 2^k$$
+///
+/// which is stored in the `output` ciphertext as an integer of $[0, 2^k)$. The output encrypts,
+/// under the same secret key and modulo $2^k$, the input message rescaled by $2^k / q$. The
+/// rounding of the $n + 1$ coefficients adds a noise of variance close to
+/// $\frac{n + 2}{24}$ (in units of $1 / 2^k$), which must remain small compared to the encoding
+/// of the messages.
+///
+/// When $k$ is the number of bits of the output integers, the output is a regular ciphertext of
+/// the output precision, for instance when switching a 64 bits ciphertext to 32 bits. Smaller
+/// moduli are used to shrink ciphertexts before their transmission, or as the first step of a
+/// bootstrap, which switches the input to the modulus $2N$.
+pub trait LweCiphertextDiscardingModulusSwitchEngine<InputCiphertext, OutputCiphertext>:
+    AbstractEngine
+where
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity<KeyDistribution = InputCiphertext::KeyDistribution>,
+{
+    /// Switches the modulus of an LWE ciphertext.
+    fn discard_modulus_switch_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        modulus_log: CiphertextModulusLog,
+    ) -> Result<(), LweCiphertextDiscardingModulusSwitchError<Self::EngineError>>;
+
+    /// Unsafely switches the modulus of an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingModulusSwitchError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_modulus_switch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        modulus_log: CiphertextModulusLog,
+    );
+}
@@ -0,0 +1,130 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlweRelinearizationKeyEntity, LweCiphertextEntity, PackingKeyswitchKeyEntity,
+};
+use concrete_commons::parameters::ScalingFactor;
+
+engine_error! {
+    LweCiphertextDiscardingMultiplicationError for LweCiphertextDiscardingMultiplicationEngine @
+    InputLweDimensionMismatch => "The two input ciphertexts LWE dimensions must be the same.",
+    PackingKeyInputLweDimensionMismatch => "The input ciphertexts LWE dimension must be the same \
+                                            as the packing keyswitch key input LWE dimension.",
+    GlweDimensionMismatch => "The packing keyswitch key output GLWE dimension must be the same as \
+                              the relinearization key GLWE dimension.",
+    PolynomialSizeMismatch => "The packing keyswitch key output polynomial size must be the same \
+                               as the relinearization key polynomial size.",
+    OutputLweDimensionMismatch => "The output ciphertext LWE dimension must be equal to the \
+                                   product of the relinearization key GLWE dimension and \
+                                   polynomial size."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingMultiplicationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<
+        PackingKeyswitchKey,
+        RelinearizationKey,
+        InputCiphertext1,
+        InputCiphertext2,
+        OutputCiphertext,
+    >(
+        output: &OutputCiphertext,
+        input1: &InputCiphertext1,
+        input2: &InputCiphertext2,
+        packing_key: &PackingKeyswitchKey,
+        relinearization_key: &RelinearizationKey,
+    ) -> Result<(), Self>
+    where
+        PackingKeyswitchKey: PackingKeyswitchKeyEntity,
+        RelinearizationKey: GlweRelinearizationKeyEntity,
+        InputCiphertext1: LweCiphertextEntity,
+        InputCiphertext2: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity,
+    {
+        if input1.lwe_dimension() != input2.lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if input1.lwe_dimension() != packing_key.input_lwe_dimension() {
+            return Err(Self::PackingKeyInputLweDimensionMismatch);
+        }
+        if packing_key.output_glwe_dimension() != relinearization_key.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if packing_key.output_polynomial_size() != relinearization_key.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if output.lwe_dimension().0
+            != relinearization_key.glwe_dimension().0 * relinearization_key.polynomial_size().0
+        {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines multiplying (discarding) two LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// an encryption of the product of the messages encrypted by `input1` and `input2`, divided by
+/// `scale`. The output is encrypted under the LWE key extracted from the GLWE secret key the
+/// `relinearization_key` was generated from, like the output of a bootstrap. It can be brought
+/// back under the input key with a keyswitch.
+///
+/// # Formal Definition
+///
+/// The operation chains the following steps:
+///
+/// + each input is packed into the constant coefficient of a GLWE ciphertext, with the
+///   `packing_key` (see
+///   [`LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine`](super::LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine));
+/// + the tensor product of the two GLWE ciphertexts is computed, and divided by `scale` (see
+///   [`GlweCiphertextDiscardingTensorProductEngine`](super::GlweCiphertextDiscardingTensorProductEngine));
+/// + the result is relinearized with the `relinearization_key` (see
+///   [`GlweCiphertextDiscardingRelinearizationEngine`](super::GlweCiphertextDiscardingRelinearizationEngine));
+/// + the constant coefficient of the relinearized ciphertext is extracted into the `output` (see
+///   [`LweCiphertextDiscardingExtractionEngine`](super::LweCiphertextDiscardingExtractionEngine)).
+pub trait LweCiphertextDiscardingMultiplicationEngine<
+    PackingKeyswitchKey,
+    RelinearizationKey,
+    InputCiphertext1,
+    InputCiphertext2,
+    OutputCiphertext,
+>: AbstractEngine where
+    PackingKeyswitchKey: PackingKeyswitchKeyEntity,
+    RelinearizationKey:
+        GlweRelinearizationKeyEntity<KeyDistribution = PackingKeyswitchKey::OutputKeyDistribution>,
+    InputCiphertext1:
+        LweCiphertextEntity<KeyDistribution = PackingKeyswitchKey::InputKeyDistribution>,
+    InputCiphertext2:
+        LweCiphertextEntity<KeyDistribution = PackingKeyswitchKey::InputKeyDistribution>,
+    OutputCiphertext: LweCiphertextEntity<KeyDistribution = RelinearizationKey::KeyDistribution>,
+{
+    /// Multiplies two LWE ciphertexts.
+    fn discard_mul_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input1: &InputCiphertext1,
+        input2: &InputCiphertext2,
+        packing_key: &PackingKeyswitchKey,
+        relinearization_key: &RelinearizationKey,
+        scale: ScalingFactor,
+    ) -> Result<(), LweCiphertextDiscardingMultiplicationError<Self::EngineError>>;
+
+    /// Unsafely multiplies two LWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingMultiplicationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_mul_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input1: &InputCiphertext1,
+        input2: &InputCiphertext2,
+        packing_key: &PackingKeyswitchKey,
+        relinearization_key: &RelinearizationKey,
+        scale: ScalingFactor,
+    );
+}
@@ -0,0 +1,79 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, LweShrinkingKeyswitchKeyEntity};
+
+engine_error! {
+    LweCiphertextDiscardingShrinkingKeyswitchError for
+        LweCiphertextDiscardingShrinkingKeyswitchEngine @
+    InputLweDimensionMismatch => "The input ciphertext LWE dimension and keyswitch key input LWE \
+                                  dimensions must be the same.",
+    OutputLweDimensionMismatch => "The output ciphertext LWE dimension and keyswitch output LWE \
+                                   dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingShrinkingKeyswitchError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<KeyswitchKey, InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), Self>
+    where
+        KeyswitchKey: LweShrinkingKeyswitchKeyEntity,
+        InputCiphertext: LweCiphertextEntity<KeyDistribution = KeyswitchKey::KeyDistribution>,
+        OutputCiphertext: LweCiphertextEntity<KeyDistribution = KeyswitchKey::KeyDistribution>,
+    {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines keyswitching (discarding) LWE ciphertexts with shrinking keyswitch keys.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the keyswitch of the `input` LWE ciphertext, using the `ksk` LWE shrinking keyswitch key.
+///
+/// # Formal Definition
+///
+/// The first $n\_{out}$ coefficients of the mask of the `input` ciphertext, which are multiplied
+/// by the coefficients shared by the input and output keys, are copied along with the body. The
+/// remaining coefficients of the mask are keyswitched as with a regular keyswitch key (see
+/// [`LweCiphertextDiscardingKeyswitchEngine`](super::LweCiphertextDiscardingKeyswitchEngine)),
+/// which makes the operation cheaper, and adds less noise, than a regular keyswitch.
+pub trait LweCiphertextDiscardingShrinkingKeyswitchEngine<
+    KeyswitchKey,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    KeyswitchKey: LweShrinkingKeyswitchKeyEntity,
+    InputCiphertext: LweCiphertextEntity<KeyDistribution = KeyswitchKey::KeyDistribution>,
+    OutputCiphertext: LweCiphertextEntity<KeyDistribution = KeyswitchKey::KeyDistribution>,
+{
+    /// Keyswitch an LWE ciphertext with a shrinking keyswitch key.
+    fn discard_shrinking_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), LweCiphertextDiscardingShrinkingKeyswitchError<Self::EngineError>>;
+
+    /// Unsafely keyswitch an LWE ciphertext with a shrinking keyswitch key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingShrinkingKeyswitchError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_shrinking_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+    );
+}
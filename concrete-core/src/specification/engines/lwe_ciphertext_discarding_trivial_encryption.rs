@@ -0,0 +1,46 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, PlaintextEntity};
+
+engine_error! {
+    LweCiphertextDiscardingTrivialEncryptionError for
+        LweCiphertextDiscardingTrivialEncryptionEngine @
+}
+
+/// A trait for engines trivially encrypting (discarding) LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the trivial encryption of the `input` plaintext.
+///
+/// # Formal Definition
+///
+/// See [`LweCiphertextTrivialEncryptionEngine`](super::LweCiphertextTrivialEncryptionEngine). The
+/// mask of the `output` ciphertext is set to zero, and its body to the plaintext, which allows to
+/// inject a public constant in an already allocated ciphertext.
+pub trait LweCiphertextDiscardingTrivialEncryptionEngine<Plaintext, Ciphertext>:
+    AbstractEngine
+where
+    Plaintext: PlaintextEntity,
+    Ciphertext: LweCiphertextEntity,
+{
+    /// Trivially encrypts a plaintext in an LWE ciphertext.
+    fn discard_trivially_encrypt_lwe_ciphertext(
+        &mut self,
+        output: &mut Ciphertext,
+        input: &Plaintext,
+    ) -> Result<(), LweCiphertextDiscardingTrivialEncryptionError<Self::EngineError>>;
+
+    /// Unsafely trivially encrypts a plaintext in an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingTrivialEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_trivially_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut Ciphertext,
+        input: &Plaintext,
+    );
+}
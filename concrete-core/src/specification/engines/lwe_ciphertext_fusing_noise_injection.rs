@@ -0,0 +1,67 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextEntity;
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+
+engine_error! {
+    LweCiphertextFusingNoiseInjectionError for LweCiphertextFusingNoiseInjectionEngine @
+    TargetVarianceTooSmall => "The target variance must be greater than or equal to the current \
+                               variance."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextFusingNoiseInjectionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        current_variance: Variance,
+        target_variance: Variance,
+    ) -> Result<(), Self> {
+        let current = current_variance.get_variance();
+        let target = target_variance.get_variance();
+        if current.is_nan() || target.is_nan() || target < current {
+            return Err(Self::TargetVarianceTooSmall);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines injecting noise into (fusing) LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation adds to the `ciphertext` a fresh noise of
+/// variance `target_variance - current_variance`. Since the variances of independent noises add
+/// up, a ciphertext whose noise had the `current_variance` carries a noise of the
+/// `target_variance` afterwards.
+///
+/// This operation is meant for testing: it allows to check that a pipeline tolerates the
+/// worst-case noise predicted for its ciphertexts, instead of only the noise it happens to carry.
+///
+/// # Formal Definition
+///
+/// The mask is left unchanged, and a noise $e$ sampled with variance $\sigma\_t^2 - \sigma\_c^2$ is
+/// added to the body: $b \leftarrow b + e$.
+pub trait LweCiphertextFusingNoiseInjectionEngine<Ciphertext>: AbstractEngine
+where
+    Ciphertext: LweCiphertextEntity,
+{
+    /// Injects noise into an LWE ciphertext.
+    fn fuse_inject_noise_lwe_ciphertext(
+        &mut self,
+        ciphertext: &mut Ciphertext,
+        current_variance: Variance,
+        target_variance: Variance,
+    ) -> Result<(), LweCiphertextFusingNoiseInjectionError<Self::EngineError>>;
+
+    /// Unsafely injects noise into an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextFusingNoiseInjectionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn fuse_inject_noise_lwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: &mut Ciphertext,
+        current_variance: Variance,
+        target_variance: Variance,
+    );
+}
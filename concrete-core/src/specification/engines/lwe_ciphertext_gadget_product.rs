@@ -0,0 +1,95 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, LweKeyswitchKeyEntity};
+use concrete_commons::parameters::DecompositionLevelCount;
+
+engine_error! {
+    LweCiphertextGadgetProductError for LweCiphertextGadgetProductEngine @
+    InputLweDimensionMismatch => "The input ciphertext LWE dimension and keyswitch key input LWE \
+                                  dimensions must be the same.",
+    NullDecompositionLevelCount => "The number of used decomposition levels must be greater than \
+                                    zero.",
+    DecompositionLevelCountTooLarge => "The number of used decomposition levels must not exceed \
+                                        the decomposition level count of the keyswitch key."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextGadgetProductError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<KeyswitchKey, InputCiphertext>(
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+        level_count: DecompositionLevelCount,
+    ) -> Result<(), Self>
+    where
+        KeyswitchKey: LweKeyswitchKeyEntity,
+        InputCiphertext: LweCiphertextEntity,
+    {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+        if level_count.0 > ksk.decomposition_level_count().0 {
+            return Err(Self::DecompositionLevelCountTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the gadget product of the mask of an LWE ciphertext with a
+/// keyswitch key.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext encrypted under
+/// the output key of `ksk`, which encrypts the product of the mask of the `input` LWE ciphertext
+/// with the input key of `ksk`. Only the `level_count` most significant levels of the
+/// decomposition of `ksk` are used, which makes the operation faster and removes the noise of
+/// the dropped levels, at the price of a coarser rounding of the mask.
+///
+/// This is the building block of the keyswitch, which is recovered by subtracting the output of
+/// this operation, computed with all the levels, from a trivial encryption of the body of the
+/// input ciphertext. The noise of the output can be estimated with
+/// `concrete_npe::estimate_gadget_product_noise`.
+///
+/// # Formal Definition
+///
+/// Let $(a_1, \dots, a_n, b)$ be the input ciphertext, $(s_1, \dots, s_n)$ the input key of the
+/// keyswitch key, and $B = 2^\beta$ the base of its decomposition. Given $l'$ used levels, every
+/// mask element is rounded to its closest multiple $\bar{a}_i$ of $q / B^{l'}$, and decomposed as
+/// $\bar{a}_i = \sum_{j=1}^{l'} d_{i,j} \cdot q / B^j$, with $-B/2 \leq d_{i,j} < B/2$. The output
+/// is:
+///
+/// $$\sum_{i=1}^n \sum_{j=1}^{l'} d_{i,j} \cdot \mathsf{KSK}_{i,j}$$
+///
+/// where $\mathsf{KSK}_{i,j}$ encrypts $s_i \cdot q / B^j$. It hence encrypts
+/// $\sum_i \bar{a}_i \cdot s_i$.
+pub trait LweCiphertextGadgetProductEngine<KeyswitchKey, InputCiphertext, OutputCiphertext>:
+    AbstractEngine
+where
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    InputCiphertext: LweCiphertextEntity<KeyDistribution = KeyswitchKey::InputKeyDistribution>,
+    OutputCiphertext: LweCiphertextEntity<KeyDistribution = KeyswitchKey::OutputKeyDistribution>,
+{
+    /// Computes the gadget product of the mask of an LWE ciphertext with a keyswitch key.
+    fn compute_gadget_product_lwe_ciphertext(
+        &mut self,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+        level_count: DecompositionLevelCount,
+    ) -> Result<OutputCiphertext, LweCiphertextGadgetProductError<Self::EngineError>>;
+
+    /// Unsafely computes the gadget product of the mask of an LWE ciphertext with a keyswitch key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextGadgetProductError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn compute_gadget_product_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+        level_count: DecompositionLevelCount,
+    ) -> OutputCiphertext;
+}
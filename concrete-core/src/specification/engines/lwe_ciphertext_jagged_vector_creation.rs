@@ -0,0 +1,68 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextJaggedVectorEntity, LweCiphertextVectorEntity};
+use concrete_commons::parameters::LweCiphertextCount;
+
+engine_error! {
+    LweCiphertextJaggedVectorCreationError for LweCiphertextJaggedVectorCreationEngine @
+    NullRowCount => "The jagged vector must contain at least one row.",
+    CiphertextCountMismatch => "The sum of the row lengths must be equal to the input ciphertext \
+                                count."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextJaggedVectorCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<CiphertextVector>(
+        input: &CiphertextVector,
+        row_lengths: &[LweCiphertextCount],
+    ) -> Result<(), Self>
+    where
+        CiphertextVector: LweCiphertextVectorEntity,
+    {
+        if row_lengths.is_empty() {
+            return Err(Self::NullRowCount);
+        }
+        let total: usize = row_lengths.iter().map(|length| length.0).sum();
+        if total != input.lwe_ciphertext_count().0 {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines creating LWE ciphertext jagged vectors from LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates an LWE ciphertext jagged vector from
+/// the `input` LWE ciphertext vector, whose ciphertexts are split into consecutive rows of
+/// `row_lengths` ciphertexts. The rows may be empty. The ciphertexts of the input vector are moved
+/// into the output jagged vector without any copy.
+///
+/// # Formal Definition
+pub trait LweCiphertextJaggedVectorCreationEngine<CiphertextVector, JaggedVector>:
+    AbstractEngine
+where
+    CiphertextVector: LweCiphertextVectorEntity,
+    JaggedVector:
+        LweCiphertextJaggedVectorEntity<KeyDistribution = CiphertextVector::KeyDistribution>,
+{
+    /// Creates an LWE ciphertext jagged vector from an LWE ciphertext vector.
+    fn create_lwe_ciphertext_jagged_vector(
+        &mut self,
+        input: CiphertextVector,
+        row_lengths: &[LweCiphertextCount],
+    ) -> Result<JaggedVector, LweCiphertextJaggedVectorCreationError<Self::EngineError>>;
+
+    /// Unsafely creates an LWE ciphertext jagged vector from an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextJaggedVectorCreationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn create_lwe_ciphertext_jagged_vector_unchecked(
+        &mut self,
+        input: CiphertextVector,
+        row_lengths: &[LweCiphertextCount],
+    ) -> JaggedVector;
+}
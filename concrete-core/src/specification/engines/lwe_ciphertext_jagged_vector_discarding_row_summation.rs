@@ -0,0 +1,71 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextJaggedVectorEntity, LweCiphertextVectorEntity};
+
+engine_error! {
+    LweCiphertextJaggedVectorDiscardingRowSummationError
+    for LweCiphertextJaggedVectorDiscardingRowSummationEngine @
+    LweDimensionMismatch => "The input and output LWE dimension must be the same.",
+    CiphertextCountMismatch => "The output ciphertext count must be equal to the input row count."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextJaggedVectorDiscardingRowSummationError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<JaggedVector, OutputCiphertextVector>(
+        output: &OutputCiphertextVector,
+        input: &JaggedVector,
+    ) -> Result<(), Self>
+    where
+        JaggedVector: LweCiphertextJaggedVectorEntity,
+        OutputCiphertextVector:
+            LweCiphertextVectorEntity<KeyDistribution = JaggedVector::KeyDistribution>,
+    {
+        if input.lwe_dimension() != output.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if input.row_count() != output.lwe_ciphertext_count().0 {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines summing the rows of LWE ciphertext jagged vectors.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext
+/// vector with the sums of the rows of the `input` LWE ciphertext jagged vector: the $i$-th output
+/// ciphertext is the sum of the ciphertexts of the $i$-th row. The sum of an empty row is a
+/// trivial encryption of zero.
+///
+/// # Formal Definition
+pub trait LweCiphertextJaggedVectorDiscardingRowSummationEngine<
+    JaggedVector,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    JaggedVector: LweCiphertextJaggedVectorEntity,
+    OutputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = JaggedVector::KeyDistribution>,
+{
+    /// Sums the rows of an LWE ciphertext jagged vector.
+    fn discard_sum_rows_lwe_ciphertext_jagged_vector(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &JaggedVector,
+    ) -> Result<(), LweCiphertextJaggedVectorDiscardingRowSummationError<Self::EngineError>>;
+
+    /// Unsafely sums the rows of an LWE ciphertext jagged vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextJaggedVectorDiscardingRowSummationError`]. For safety concerns _specific_
+    /// to an engine, refer to the implementer safety section.
+    unsafe fn discard_sum_rows_lwe_ciphertext_jagged_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &JaggedVector,
+    );
+}
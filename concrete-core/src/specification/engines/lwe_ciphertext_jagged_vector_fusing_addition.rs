@@ -0,0 +1,72 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextJaggedVectorEntity;
+
+engine_error! {
+    LweCiphertextJaggedVectorFusingAdditionError for LweCiphertextJaggedVectorFusingAdditionEngine @
+    LweDimensionMismatch => "The input and output LWE dimension must be the same.",
+    RowCountMismatch => "The input and output row counts must be the same.",
+    RowLengthMismatch => "The input and output rows lengths must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextJaggedVectorFusingAdditionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputJaggedVector, OutputJaggedVector>(
+        output: &OutputJaggedVector,
+        input: &InputJaggedVector,
+    ) -> Result<(), Self>
+    where
+        InputJaggedVector: LweCiphertextJaggedVectorEntity,
+        OutputJaggedVector:
+            LweCiphertextJaggedVectorEntity<KeyDistribution = InputJaggedVector::KeyDistribution>,
+    {
+        if input.lwe_dimension() != output.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if input.row_count() != output.row_count() {
+            return Err(Self::RowCountMismatch);
+        }
+        if (0..input.row_count())
+            .any(|row| input.row_lwe_ciphertext_count(row) != output.row_lwe_ciphertext_count(row))
+        {
+            return Err(Self::RowLengthMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines adding (fusing) LWE ciphertext jagged vectors.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation adds the `input` LWE ciphertext jagged
+/// vector to the `output` LWE ciphertext jagged vector, row by row. Both jagged vectors must have
+/// the same rows lengths.
+///
+/// # Formal Definition
+pub trait LweCiphertextJaggedVectorFusingAdditionEngine<InputJaggedVector, OutputJaggedVector>:
+    AbstractEngine
+where
+    InputJaggedVector: LweCiphertextJaggedVectorEntity,
+    OutputJaggedVector:
+        LweCiphertextJaggedVectorEntity<KeyDistribution = InputJaggedVector::KeyDistribution>,
+{
+    /// Adds two LWE ciphertext jagged vectors.
+    fn fuse_add_lwe_ciphertext_jagged_vector(
+        &mut self,
+        output: &mut OutputJaggedVector,
+        input: &InputJaggedVector,
+    ) -> Result<(), LweCiphertextJaggedVectorFusingAdditionError<Self::EngineError>>;
+
+    /// Unsafely adds two LWE ciphertext jagged vectors.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextJaggedVectorFusingAdditionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn fuse_add_lwe_ciphertext_jagged_vector_unchecked(
+        &mut self,
+        output: &mut OutputJaggedVector,
+        input: &InputJaggedVector,
+    );
+}
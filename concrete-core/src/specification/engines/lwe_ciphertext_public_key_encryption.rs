@@ -0,0 +1,49 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, LwePublicKeyEntity, PlaintextEntity};
+
+engine_error! {
+    LweCiphertextPublicKeyEncryptionError for LweCiphertextPublicKeyEncryptionEngine @
+}
+
+/// A trait for engines encrypting LWE ciphertexts with a public key.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext containing the
+/// encryption of the `input` plaintext under the secret key the `key` public key was generated
+/// from. The noise of the ciphertext comes from the encryptions of zero of the public key, and can
+/// not be chosen.
+///
+/// # Formal Definition
+///
+/// A binary vector $(r\_0, \dots, r\_{m-1})$ is sampled uniformly, and the output is
+/// $(0, \dots, 0, \mathsf{pt}) + \sum\_i r\_i \mathsf{pk}\_i$, where the $\mathsf{pk}\_i$ are the
+/// $m$ encryptions of zero of the public key. Its noise variance is the one of the encryptions of
+/// zero, times the number of selected encryptions.
+pub trait LweCiphertextPublicKeyEncryptionEngine<PublicKey, Plaintext, Ciphertext>:
+    AbstractEngine
+where
+    PublicKey: LwePublicKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: LweCiphertextEntity<KeyDistribution = PublicKey::KeyDistribution>,
+{
+    /// Encrypts an LWE ciphertext with a public key.
+    fn encrypt_lwe_ciphertext_with_public_key(
+        &mut self,
+        key: &PublicKey,
+        input: &Plaintext,
+    ) -> Result<Ciphertext, LweCiphertextPublicKeyEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts an LWE ciphertext with a public key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextPublicKeyEncryptionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn encrypt_lwe_ciphertext_with_public_key_unchecked(
+        &mut self,
+        key: &PublicKey,
+        input: &Plaintext,
+    ) -> Ciphertext;
+}
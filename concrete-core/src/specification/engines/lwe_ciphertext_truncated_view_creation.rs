@@ -0,0 +1,40 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextEntity;
+
+engine_error! {
+    LweCiphertextTruncatedViewCreationError for LweCiphertextTruncatedViewCreationEngine @
+}
+
+/// A trait for engines creating truncated views of LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates a read-only view of the `input` LWE
+/// ciphertext with a lower precision, whose coefficients are the most significant bits of the
+/// coefficients of the `input` ciphertext, rounded to the nearest value. The coefficients are
+/// truncated when read, without copying the `input` ciphertext.
+///
+/// The view encrypts the same message as the `input` ciphertext under the same key, with an
+/// additional rounding noise, and can be used as the input of the linear operations implemented
+/// for it. The view can be materialized in an owned ciphertext with a conversion engine.
+///
+/// # Formal Definition
+pub trait LweCiphertextTruncatedViewCreationEngine<Input, View>: AbstractEngine
+where
+    View: LweCiphertextEntity,
+{
+    /// Creates a truncated view of an LWE ciphertext.
+    fn create_lwe_ciphertext_truncated_view(
+        &mut self,
+        input: Input,
+    ) -> Result<View, LweCiphertextTruncatedViewCreationError<Self::EngineError>>;
+
+    /// Unsafely creates a truncated view of an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextTruncatedViewCreationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn create_lwe_ciphertext_truncated_view_unchecked(&mut self, input: Input) -> View;
+}
@@ -0,0 +1,38 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextVectorEntity;
+
+engine_error! {
+    LweCiphertextVectorConsumingRetrievalError for LweCiphertextVectorConsumingRetrievalEngine @
+}
+
+/// A trait for engines retrieving the containers of LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation consumes the `ciphertext_vector` LWE
+/// ciphertext vector, and returns the container holding its data, without any copy.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorConsumingRetrievalEngine<CiphertextVector, Container>:
+    AbstractEngine
+where
+    CiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Retrieves the container of an LWE ciphertext vector.
+    fn consume_retrieve_lwe_ciphertext_vector(
+        &mut self,
+        ciphertext_vector: CiphertextVector,
+    ) -> Result<Container, LweCiphertextVectorConsumingRetrievalError<Self::EngineError>>;
+
+    /// Unsafely retrieves the container of an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorConsumingRetrievalError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn consume_retrieve_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        ciphertext_vector: CiphertextVector,
+    ) -> Container;
+}
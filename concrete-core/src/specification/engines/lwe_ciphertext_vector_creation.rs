@@ -0,0 +1,61 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextVectorEntity;
+use concrete_commons::parameters::LweSize;
+
+engine_error! {
+    LweCiphertextVectorCreationError for LweCiphertextVectorCreationEngine @
+    EmptyContainer => "The container used to create the LWE ciphertext vector must not be empty.",
+    NullLweSize => "The LWE size must be greater than zero.",
+    InvalidContainerSize => "The length of the container must be a multiple of the LWE size."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(container_length: usize, lwe_size: LweSize) -> Result<(), Self> {
+        if container_length == 0 {
+            return Err(Self::EmptyContainer);
+        }
+        if lwe_size.0 == 0 {
+            return Err(Self::NullLweSize);
+        }
+        if !container_length.is_multiple_of(lwe_size.0) {
+            return Err(Self::InvalidContainerSize);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines creating LWE ciphertext vectors from existing containers.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates an LWE ciphertext vector from the
+/// `container` of the caller, holding ciphertexts of size `lwe_size` laid out contiguously. The
+/// container is moved into the output vector without any copy, which makes it possible to wrap a
+/// buffer filled by a foreign memory transfer.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorCreationEngine<Container, CiphertextVector>: AbstractEngine
+where
+    CiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Creates an LWE ciphertext vector from an existing container.
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: Container,
+        lwe_size: LweSize,
+    ) -> Result<CiphertextVector, LweCiphertextVectorCreationError<Self::EngineError>>;
+
+    /// Unsafely creates an LWE ciphertext vector from an existing container.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorCreationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: Container,
+        lwe_size: LweSize,
+    ) -> CiphertextVector;
+}
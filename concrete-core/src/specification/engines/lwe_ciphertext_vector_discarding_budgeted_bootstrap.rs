@@ -0,0 +1,177 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GlweCiphertextVectorEntity, LweBootstrapKeyEntity, LweCiphertextVectorEntity,
+};
+use concrete_commons::parameters::{LweCiphertextCount, MemoryBudget};
+
+engine_error! {
+    LweCiphertextVectorDiscardingBudgetedBootstrapError for LweCiphertextVectorDiscardingBudgetedBootstrapEngine @
+    InputLweDimensionMismatch => "The input vector and key input LWE dimension must be the same.",
+    OutputLweDimensionMismatch => "The output vector and key output LWE dimension must be the same.",
+    AccumulatorGlweDimensionMismatch => "The accumulator vector and key GLWE dimension must be the same.",
+    AccumulatorPolynomialSizeMismatch => "The accumulator vector and key polynomial size must be the same.",
+    AccumulatorCountMismatch => "The accumulator count and input ciphertext count must be the same.",
+    CiphertextCountMismatch => "The input and output ciphertext count must be the same.",
+    MemoryBudgetTooSmall => "The memory budget must fit the bootstrap of a single ciphertext."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextVectorDiscardingBudgetedBootstrapError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<
+        BootstrapKey,
+        AccumulatorVector,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    >(
+        output: &OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        acc: &AccumulatorVector,
+        bsk: &BootstrapKey,
+    ) -> Result<(), Self>
+    where
+        BootstrapKey: LweBootstrapKeyEntity,
+        AccumulatorVector:
+            GlweCiphertextVectorEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+        InputCiphertextVector:
+            LweCiphertextVectorEntity<KeyDistribution = BootstrapKey::InputKeyDistribution>,
+        OutputCiphertextVector:
+            LweCiphertextVectorEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    {
+        if bsk.input_lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+
+        if bsk.output_lwe_dimension() != output.lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+
+        if bsk.glwe_dimension() != acc.glwe_dimension() {
+            return Err(Self::AccumulatorGlweDimensionMismatch);
+        }
+
+        if bsk.polynomial_size() != acc.polynomial_size() {
+            return Err(Self::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_ciphertext_count().0 != input.lwe_ciphertext_count().0 {
+            return Err(Self::AccumulatorCountMismatch);
+        }
+
+        if input.lwe_ciphertext_count() != output.lwe_ciphertext_count() {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// The split of an operation on a vector of ciphertexts in chunks processed one after the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VectorChunking {
+    /// The number of ciphertexts of every chunk, but the last one which may hold less.
+    pub chunk_size: LweCiphertextCount,
+    /// The number of chunks.
+    pub chunk_count: usize,
+}
+
+impl VectorChunking {
+    /// Splits `count` ciphertexts in the largest chunks fitting in `budget`, when processing a
+    /// chunk requires `ciphertext_bytes` bytes per ciphertext, on top of `fixed_bytes` bytes.
+    ///
+    /// Returns `None` if a single ciphertext does not fit in the budget.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, MemoryBudget};
+    /// use concrete_core::specification::engines::VectorChunking;
+    /// let chunking =
+    ///     VectorChunking::within_budget(LweCiphertextCount(10), 100, 50, MemoryBudget(450));
+    /// assert_eq!(
+    ///     chunking,
+    ///     Some(VectorChunking {
+    ///         chunk_size: LweCiphertextCount(4),
+    ///         chunk_count: 3,
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     VectorChunking::within_budget(LweCiphertextCount(10), 100, 50, MemoryBudget(149)),
+    ///     None
+    /// );
+    /// ```
+    pub fn within_budget(
+        count: LweCiphertextCount,
+        ciphertext_bytes: usize,
+        fixed_bytes: usize,
+        budget: MemoryBudget,
+    ) -> Option<VectorChunking> {
+        let chunk_size = budget.0.checked_sub(fixed_bytes)? / ciphertext_bytes.max(1);
+        if chunk_size == 0 {
+            return None;
+        }
+        let chunk_size = chunk_size.min(count.0.max(1));
+        Some(VectorChunking {
+            chunk_size: LweCiphertextCount(chunk_size),
+            chunk_count: count.0.div_ceil(chunk_size),
+        })
+    }
+}
+
+/// A trait for engines bootstrapping (discarding) LWE ciphertext vectors within a memory budget.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext vector
+/// with the element-wise bootstrap of the `input` LWE ciphertext vector, using the `acc`
+/// accumulator as lookup-table, and the `bsk` bootstrap key, exactly as
+/// [`LweCiphertextVectorDiscardingBootstrapEngine`](super::LweCiphertextVectorDiscardingBootstrapEngine).
+///
+/// The ciphertexts are processed in chunks, so that the memory used by the engine for its
+/// intermediate values stays below `budget`. The keys are not accounted for in the budget. The
+/// operation returns the [`VectorChunking`] chosen by the engine.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorDiscardingBudgetedBootstrapEngine<
+    BootstrapKey,
+    AccumulatorVector,
+    InputCiphertextVector,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    BootstrapKey: LweBootstrapKeyEntity,
+    AccumulatorVector:
+        GlweCiphertextVectorEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    InputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = BootstrapKey::InputKeyDistribution>,
+    OutputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+{
+    /// Bootstraps an LWE ciphertext vector within a memory budget.
+    fn discard_bootstrap_lwe_ciphertext_vector_within_budget(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        acc: &AccumulatorVector,
+        bsk: &BootstrapKey,
+        budget: MemoryBudget,
+    ) -> Result<
+        VectorChunking,
+        LweCiphertextVectorDiscardingBudgetedBootstrapError<Self::EngineError>,
+    >;
+
+    /// Unsafely bootstraps an LWE ciphertext vector within a memory budget.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingBudgetedBootstrapError`]. For safety concerns _specific_
+    /// to an engine, refer to the implementer safety section.
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_within_budget_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        acc: &AccumulatorVector,
+        bsk: &BootstrapKey,
+        budget: MemoryBudget,
+    ) -> VectorChunking;
+}
@@ -0,0 +1,95 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GgswCiphertextEntity, LweCiphertextVectorEntity};
+
+engine_error! {
+    LweCiphertextVectorDiscardingCmuxError for LweCiphertextVectorDiscardingCmuxEngine @
+    LweDimensionMismatch => "The input and output LWE dimensions must be the same.",
+    CiphertextCountMismatch => "The input and output ciphertext counts must be the same.",
+    ConditionDimensionMismatch => "The LWE dimension of the ciphertexts must be the product of \
+                                   the GLWE dimension and the polynomial size of the condition."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorDiscardingCmuxError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<GgswCiphertext, InputCiphertextVector, OutputCiphertextVector>(
+        output: &OutputCiphertextVector,
+        condition: &GgswCiphertext,
+        if_true: &InputCiphertextVector,
+        if_false: &InputCiphertextVector,
+    ) -> Result<(), Self>
+    where
+        GgswCiphertext: GgswCiphertextEntity,
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertextVector: LweCiphertextVectorEntity,
+    {
+        if if_true.lwe_dimension() != output.lwe_dimension()
+            || if_false.lwe_dimension() != output.lwe_dimension()
+        {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if if_true.lwe_ciphertext_count() != output.lwe_ciphertext_count()
+            || if_false.lwe_ciphertext_count() != output.lwe_ciphertext_count()
+        {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        if output.lwe_dimension().0 != condition.glwe_dimension().0 * condition.polynomial_size().0
+        {
+            return Err(Self::ConditionDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines selecting (discarding) between two LWE ciphertext vectors with an
+/// encrypted condition.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext
+/// vector with the element-wise CMUX of the `if_true` and `if_false` LWE ciphertext vectors,
+/// controlled by the `condition` GGSW ciphertext encrypting a single bit. Every output ciphertext
+/// encrypts the message of the corresponding `if_true` ciphertext if the condition encrypts `1`,
+/// and the one of the corresponding `if_false` ciphertext if it encrypts `0`.
+///
+/// The input ciphertexts are encrypted under the
+/// [transmutation](super::GlweToLweSecretKeyTransmutationEngine) of the GLWE secret key of the
+/// condition, and the output ciphertexts are encrypted under the same key. Every CMUX is computed
+/// with a single external product, meaning that the noise of the output ciphertexts grows by one
+/// external product, whatever the number of ciphertexts.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorDiscardingCmuxEngine<
+    GgswCiphertext,
+    InputCiphertextVector,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    GgswCiphertext: GgswCiphertextEntity,
+    InputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = GgswCiphertext::KeyDistribution>,
+    OutputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = GgswCiphertext::KeyDistribution>,
+{
+    /// Selects between two LWE ciphertext vectors.
+    fn discard_cmux_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        condition: &GgswCiphertext,
+        if_true: &InputCiphertextVector,
+        if_false: &InputCiphertextVector,
+    ) -> Result<(), LweCiphertextVectorDiscardingCmuxError<Self::EngineError>>;
+
+    /// Unsafely selects between two LWE ciphertext vectors.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingCmuxError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn discard_cmux_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        condition: &GgswCiphertext,
+        if_true: &InputCiphertextVector,
+        if_false: &InputCiphertextVector,
+    );
+}
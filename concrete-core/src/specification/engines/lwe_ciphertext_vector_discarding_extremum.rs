@@ -0,0 +1,163 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweBootstrapKeyEntity, LweCiphertextVectorEntity, LweKeyswitchKeyEntity,
+};
+use concrete_commons::parameters::{Extremum, MessageBitCount};
+
+engine_error! {
+    LweCiphertextVectorDiscardingExtremumError for LweCiphertextVectorDiscardingExtremumEngine @
+    InputLweDimensionMismatch => "The two input LWE dimensions must be the same.",
+    OutputLweDimensionMismatch => "The input and output LWE dimensions must be the same.",
+    CiphertextCountMismatch => "The input and output ciphertext counts must be the same.",
+    KeyswitchKeyInputLweDimensionMismatch => "The input and keyswitch key input LWE dimensions \
+                                              must be the same.",
+    KeyswitchKeyOutputLweDimensionMismatch => "The keyswitch key output and bootstrap key input \
+                                               LWE dimensions must be the same.",
+    BootstrapKeyOutputLweDimensionMismatch => "The input and bootstrap key output LWE dimensions \
+                                               must be the same.",
+    MessageBitCountTooLarge => "The polynomial size of the bootstrap key is too small for the \
+                                message bit count."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorDiscardingExtremumError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<
+        KeyswitchKey,
+        BootstrapKey,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    >(
+        output: &OutputCiphertextVector,
+        input_1: &InputCiphertextVector,
+        input_2: &InputCiphertextVector,
+        ksk: &KeyswitchKey,
+        bsk: &BootstrapKey,
+        message_bits: MessageBitCount,
+    ) -> Result<(), Self>
+    where
+        KeyswitchKey: LweKeyswitchKeyEntity,
+        BootstrapKey: LweBootstrapKeyEntity,
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertextVector: LweCiphertextVectorEntity,
+    {
+        if input_1.lwe_dimension() != input_2.lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if input_1.lwe_dimension() != output.lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        if input_1.lwe_ciphertext_count() != input_2.lwe_ciphertext_count()
+            || input_1.lwe_ciphertext_count() != output.lwe_ciphertext_count()
+        {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        if ksk.input_lwe_dimension() != input_1.lwe_dimension() {
+            return Err(Self::KeyswitchKeyInputLweDimensionMismatch);
+        }
+        if ksk.output_lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::KeyswitchKeyOutputLweDimensionMismatch);
+        }
+        if bsk.output_lwe_dimension() != input_1.lwe_dimension() {
+            return Err(Self::BootstrapKeyOutputLweDimensionMismatch);
+        }
+        if !extremum_message_bit_count_is_valid(message_bits, bsk) {
+            return Err(Self::MessageBitCountTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// Returns whether the bootstrap key can evaluate the extremum of messages of `message_bits`
+/// bits.
+///
+/// The difference of two messages, shifted to be positive, spans `2^(p+1)` values. Each of them
+/// must be given at least two coefficients of the lookup table, which means that `2^(p+2)` must
+/// not exceed the polynomial size of the key.
+pub(crate) fn extremum_message_bit_count_is_valid<BootstrapKey>(
+    message_bits: MessageBitCount,
+    bsk: &BootstrapKey,
+) -> bool
+where
+    BootstrapKey: LweBootstrapKeyEntity,
+{
+    message_bits.0 + 2 < usize::BITS as usize
+        && 1 << (message_bits.0 + 2) <= bsk.polynomial_size().0
+}
+
+/// A trait for engines computing the element-wise extremum of two LWE ciphertext vectors
+/// (discarding).
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext
+/// vector with the element-wise minimum or maximum of the `input_1` and `input_2` LWE ciphertext
+/// vectors, as selected by `extremum`.
+///
+/// The ciphertexts encrypt integers smaller than $2^p$, with $p$ the `message_bits` value, encoded
+/// as $m \cdot \Delta$ where $\Delta = 2^{q - p - 2}$ for integers of $q$ bits. In other words, the
+/// encoding keeps two bits of padding.
+///
+/// Every element is computed from the $\mathrm{relu}$ of the difference of the inputs:
+///
+/// * $\max(a, b) = b + \mathrm{relu}(a - b)$
+/// * $\min(a, b) = a - \mathrm{relu}(a - b)$
+///
+/// The difference $a - b$ is computed and shifted by $2^p \cdot \Delta$ to be positive, it is
+/// then keyswitched with `ksk` and bootstrapped with `bsk`, using a lookup table which evaluates
+/// the $\mathrm{relu}$ function. The linear part and the bootstrap are fused for every element, so
+/// that no intermediate vector is allocated. The inputs are encrypted under the output key of
+/// `bsk`, which is the input key of `ksk`.
+///
+/// The lookup table gives $2^{-p-1} N$ coefficients to every value of the difference, where $N$ is
+/// the polynomial size of `bsk`. The keyswitch and modulus switching noise must remain below half
+/// of this width for the result to be correct.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorDiscardingExtremumEngine<
+    KeyswitchKey,
+    BootstrapKey,
+    InputCiphertextVector,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    KeyswitchKey: LweKeyswitchKeyEntity<
+        InputKeyDistribution = BootstrapKey::OutputKeyDistribution,
+        OutputKeyDistribution = BootstrapKey::InputKeyDistribution,
+    >,
+    BootstrapKey: LweBootstrapKeyEntity,
+    InputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    OutputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+{
+    /// Computes the element-wise extremum of two LWE ciphertext vectors.
+    #[allow(clippy::too_many_arguments)]
+    fn discard_extremum_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input_1: &InputCiphertextVector,
+        input_2: &InputCiphertextVector,
+        ksk: &KeyswitchKey,
+        bsk: &BootstrapKey,
+        message_bits: MessageBitCount,
+        extremum: Extremum,
+    ) -> Result<(), LweCiphertextVectorDiscardingExtremumError<Self::EngineError>>;
+
+    /// Unsafely computes the element-wise extremum of two LWE ciphertext vectors.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingExtremumError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn discard_extremum_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input_1: &InputCiphertextVector,
+        input_2: &InputCiphertextVector,
+        ksk: &KeyswitchKey,
+        bsk: &BootstrapKey,
+        message_bits: MessageBitCount,
+        extremum: Extremum,
+    );
+}
@@ -0,0 +1,80 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextVectorEntity;
+use concrete_commons::parameters::LweCiphertextIndex;
+
+engine_error! {
+    LweCiphertextVectorDiscardingGatherError for LweCiphertextVectorDiscardingGatherEngine @
+    LweDimensionMismatch => "The input and output LWE dimensions must be the same.",
+    IndexCountMismatch => "The number of indices must be equal to the output ciphertext count.",
+    OutOfVectorInputIndex => "The input vector must contain all the indices."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorDiscardingGatherError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertextVector, OutputCiphertextVector>(
+        output: &OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        indices: &[LweCiphertextIndex],
+    ) -> Result<(), Self>
+    where
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertextVector:
+            LweCiphertextVectorEntity<KeyDistribution = InputCiphertextVector::KeyDistribution>,
+    {
+        if input.lwe_dimension() != output.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if indices.len() != output.lwe_ciphertext_count().0 {
+            return Err(Self::IndexCountMismatch);
+        }
+        if indices
+            .iter()
+            .any(|index| index.0 >= input.lwe_ciphertext_count().0)
+        {
+            return Err(Self::OutOfVectorInputIndex);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines gathering (discarding) LWE ciphertexts from an LWE ciphertext vector.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext
+/// vector with the ciphertexts of the `input` LWE ciphertext vector found at `indices`: the `i`-th
+/// output ciphertext is a copy of the input ciphertext at index `indices[i]`.
+///
+/// The indices do not need to be ordered, and the same input ciphertext can be gathered multiple
+/// times.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorDiscardingGatherEngine<InputCiphertextVector, OutputCiphertextVector>:
+    AbstractEngine
+where
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = InputCiphertextVector::KeyDistribution>,
+{
+    /// Gathers LWE ciphertexts from an LWE ciphertext vector.
+    fn discard_gather_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        indices: &[LweCiphertextIndex],
+    ) -> Result<(), LweCiphertextVectorDiscardingGatherError<Self::EngineError>>;
+
+    /// Unsafely gathers LWE ciphertexts from an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingGatherError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_gather_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        indices: &[LweCiphertextIndex],
+    );
+}
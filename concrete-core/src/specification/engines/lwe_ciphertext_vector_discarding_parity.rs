@@ -0,0 +1,133 @@
+use super::engine_error;
+use super::extremum_message_bit_count_is_valid;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweBootstrapKeyEntity, LweCiphertextEntity, LweCiphertextVectorEntity, LweKeyswitchKeyEntity,
+};
+use concrete_commons::parameters::MessageBitCount;
+
+engine_error! {
+    LweCiphertextVectorDiscardingParityError for LweCiphertextVectorDiscardingParityEngine @
+    EmptyInput => "The input ciphertext vector must not be empty.",
+    OutputLweDimensionMismatch => "The input and output LWE dimensions must be the same.",
+    KeyswitchKeyInputLweDimensionMismatch => "The input and keyswitch key input LWE dimensions \
+                                              must be the same.",
+    KeyswitchKeyOutputLweDimensionMismatch => "The keyswitch key output and bootstrap key input \
+                                               LWE dimensions must be the same.",
+    BootstrapKeyOutputLweDimensionMismatch => "The input and bootstrap key output LWE dimensions \
+                                               must be the same.",
+    NullMessageBitCount => "The message bit count must be at least one.",
+    MessageBitCountTooLarge => "The polynomial size of the bootstrap key is too small for the \
+                                message bit count."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorDiscardingParityError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<
+        KeyswitchKey,
+        BootstrapKey,
+        InputCiphertextVector,
+        OutputCiphertext,
+    >(
+        output: &OutputCiphertext,
+        input: &InputCiphertextVector,
+        ksk: &KeyswitchKey,
+        bsk: &BootstrapKey,
+        message_bits: MessageBitCount,
+    ) -> Result<(), Self>
+    where
+        KeyswitchKey: LweKeyswitchKeyEntity,
+        BootstrapKey: LweBootstrapKeyEntity,
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertext: LweCiphertextEntity,
+    {
+        if input.lwe_ciphertext_count().0 == 0 {
+            return Err(Self::EmptyInput);
+        }
+        if input.lwe_dimension() != output.lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        if ksk.input_lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::KeyswitchKeyInputLweDimensionMismatch);
+        }
+        if ksk.output_lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::KeyswitchKeyOutputLweDimensionMismatch);
+        }
+        if bsk.output_lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::BootstrapKeyOutputLweDimensionMismatch);
+        }
+        if message_bits.0 == 0 {
+            return Err(Self::NullMessageBitCount);
+        }
+        if !extremum_message_bit_count_is_valid(message_bits, bsk) {
+            return Err(Self::MessageBitCountTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the parity of the bits encrypted by an LWE ciphertext vector
+/// (discarding).
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the parity, that is the exclusive or, of the bits encrypted by the `input` LWE ciphertext
+/// vector.
+///
+/// The ciphertexts encrypt bits, encoded as messages of $p$ bits, with $p$ the `message_bits`
+/// value: a bit $b$ is encoded as $b \cdot \Delta$ where $\Delta = 2^{q - p - 2}$ for integers of
+/// $q$ bits. The output bit is encoded the same way.
+///
+/// The bits are reduced with a balanced binary tree: every node adds the ciphertexts of its two
+/// children, keyswitches the sum with `ksk`, and bootstraps it with `bsk`, using a lookup table
+/// which evaluates the sum modulo two. Every sum hence holds the noise of two inputs or of two
+/// bootstrapped ciphertexts, whatever the length of the input vector, and the output holds the
+/// noise of a bootstrap. When the input vector holds a single ciphertext, it is copied to the
+/// output. The inputs are encrypted under the output key of `bsk`, which is the input key of
+/// `ksk`.
+///
+/// # Formal Definition
+///
+/// The output encrypts $\bigoplus\_{i} b\_i = \left(\sum\_{i} b\_i\right) \bmod 2$, computed
+/// with $n - 1$ bootstraps for an input vector of $n$ ciphertexts.
+pub trait LweCiphertextVectorDiscardingParityEngine<
+    KeyswitchKey,
+    BootstrapKey,
+    InputCiphertextVector,
+    OutputCiphertext,
+>: AbstractEngine where
+    KeyswitchKey: LweKeyswitchKeyEntity<
+        InputKeyDistribution = BootstrapKey::OutputKeyDistribution,
+        OutputKeyDistribution = BootstrapKey::InputKeyDistribution,
+    >,
+    BootstrapKey: LweBootstrapKeyEntity,
+    InputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    OutputCiphertext: LweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+{
+    /// Computes the parity of the bits encrypted by an LWE ciphertext vector.
+    fn discard_parity_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertextVector,
+        ksk: &KeyswitchKey,
+        bsk: &BootstrapKey,
+        message_bits: MessageBitCount,
+    ) -> Result<(), LweCiphertextVectorDiscardingParityError<Self::EngineError>>;
+
+    /// Unsafely computes the parity of the bits encrypted by an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingParityError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_parity_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertextVector,
+        ksk: &KeyswitchKey,
+        bsk: &BootstrapKey,
+        message_bits: MessageBitCount,
+    );
+}
@@ -0,0 +1,280 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GgswCiphertextEntity, LweCiphertextVectorEntity};
+use concrete_commons::parameters::LweCiphertextCount;
+
+engine_error! {
+    LweCiphertextVectorDiscardingPermutationError for LweCiphertextVectorDiscardingPermutationEngine @
+    LweDimensionMismatch => "The input and output LWE dimensions must be the same.",
+    CiphertextCountMismatch => "The input and output ciphertext counts must be the same.",
+    CiphertextCountNotPowerOfTwo => "The ciphertext count must be a power of two greater than one.",
+    SwitchCountMismatch => "The switch count must match the size of the permutation network.",
+    SwitchPolynomialSizeMismatch => "The polynomial size of the switches must be one.",
+    SwitchGlweDimensionMismatch => "The GLWE dimension of the switches must be the same as the \
+                                    LWE dimension of the ciphertexts."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorDiscardingPermutationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<GgswCiphertext, InputCiphertextVector, OutputCiphertextVector>(
+        output: &OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        switches: &[GgswCiphertext],
+    ) -> Result<(), Self>
+    where
+        GgswCiphertext: GgswCiphertextEntity,
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertextVector: LweCiphertextVectorEntity,
+    {
+        if input.lwe_dimension() != output.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if input.lwe_ciphertext_count() != output.lwe_ciphertext_count() {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        let count = input.lwe_ciphertext_count().0;
+        if count < 2 || !count.is_power_of_two() {
+            return Err(Self::CiphertextCountNotPowerOfTwo);
+        }
+        if switches.len() != permutation_network_switch_count(input.lwe_ciphertext_count()) {
+            return Err(Self::SwitchCountMismatch);
+        }
+        for switch in switches.iter() {
+            if switch.polynomial_size().0 != 1 {
+                return Err(Self::SwitchPolynomialSizeMismatch);
+            }
+            if switch.glwe_dimension().0 != input.lwe_dimension().0 {
+                return Err(Self::SwitchGlweDimensionMismatch);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the number of layers of the permutation network over `ciphertext_count` ciphertexts.
+///
+/// The network over `2^m` ciphertexts is made of `2m - 1` layers of `2^(m-1)` switches each.
+pub fn permutation_network_layer_count(ciphertext_count: LweCiphertextCount) -> usize {
+    debug_assert!(ciphertext_count.0 >= 2 && ciphertext_count.0.is_power_of_two());
+    2 * ciphertext_count.0.trailing_zeros() as usize - 1
+}
+
+/// Returns the number of switches of the permutation network over `ciphertext_count` ciphertexts.
+pub fn permutation_network_switch_count(ciphertext_count: LweCiphertextCount) -> usize {
+    permutation_network_layer_count(ciphertext_count) * ciphertext_count.0 / 2
+}
+
+/// Returns the pairs of positions connected by the switches of a given layer of the permutation
+/// network over `ciphertext_count` ciphertexts, in the order in which the switches are expected.
+pub fn permutation_network_layer_pairs(
+    ciphertext_count: LweCiphertextCount,
+    layer: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    let log_count = ciphertext_count.0.trailing_zeros() as usize;
+    let bit = if layer < log_count {
+        log_count - 1 - layer
+    } else {
+        layer + 1 - log_count
+    };
+    let stride = 1 << bit;
+    (0..ciphertext_count.0)
+        .filter(move |position| position & stride == 0)
+        .map(move |position| (position, position + stride))
+}
+
+/// Computes the switch bits configuring the permutation network so that it maps the `i`-th input
+/// ciphertext to the `permutation[i]`-th output ciphertext.
+///
+/// The returned bits are ordered the way the `switches` of
+/// [`LweCiphertextVectorDiscardingPermutationEngine`] are expected.
+///
+/// # Panics
+///
+/// Panics if `permutation` is not a permutation, or if its length is not a power of two greater
+/// than one.
+pub fn permutation_network_switch_bits(permutation: &[usize]) -> Vec<bool> {
+    let count = permutation.len();
+    assert!(
+        count >= 2 && count.is_power_of_two(),
+        "The permutation length must be a power of two greater than one."
+    );
+    let mut inverse = vec![usize::MAX; count];
+    for (input, output) in permutation.iter().enumerate() {
+        assert!(
+            *output < count && inverse[*output] == usize::MAX,
+            "The input is not a permutation."
+        );
+        inverse[*output] = input;
+    }
+    let layer_count = permutation_network_layer_count(LweCiphertextCount(count));
+    let mut layers = vec![vec![false; count]; layer_count];
+    route_subnetwork(&mut layers, &inverse, 0, 0);
+    layers
+        .iter()
+        .enumerate()
+        .flat_map(|(layer, bits)| {
+            permutation_network_layer_pairs(LweCiphertextCount(count), layer)
+                .map(move |(position, _)| bits[position])
+        })
+        .collect()
+}
+
+// Routes the subnetwork located at `offset`, whose first layer is the `depth`-th layer of the
+// network, with the looping algorithm. The `sources` argument maps each output of the subnetwork
+// to its input. The bits are stored in `layers`, at the lowest position of each switch.
+fn route_subnetwork(layers: &mut [Vec<bool>], sources: &[usize], offset: usize, depth: usize) {
+    let count = sources.len();
+    if count == 2 {
+        layers[depth][offset] = sources[0] == 1;
+        return;
+    }
+    let half = count / 2;
+    let last = layers.len() - 1 - depth;
+    let mut destinations = vec![0; count];
+    for (output, input) in sources.iter().enumerate() {
+        destinations[*input] = output;
+    }
+    // For each input, whether it is routed through the lower subnetwork.
+    let mut lower = vec![None; count];
+    for start in 0..half {
+        if lower[sources[start]].is_some() {
+            continue;
+        }
+        // The output `start` is taken from the upper subnetwork, and we follow the constraints
+        // until the loop is closed.
+        let mut output = start;
+        loop {
+            let input = sources[output];
+            lower[input] = Some(false);
+            let sibling = (input + half) % count;
+            lower[sibling] = Some(true);
+            output = (destinations[sibling] + half) % count;
+            if lower[sources[output]].is_some() {
+                break;
+            }
+        }
+    }
+    let mut upper_sources = vec![0; half];
+    let mut lower_sources = vec![0; half];
+    for position in 0..half {
+        layers[depth][offset + position] = lower[position] == Some(true);
+        let upper_output = if lower[sources[position]] == Some(false) {
+            position
+        } else {
+            position + half
+        };
+        layers[last][offset + position] = upper_output != position;
+        upper_sources[position] = sources[upper_output] % half;
+        lower_sources[position] = sources[(upper_output + half) % count] % half;
+    }
+    route_subnetwork(layers, &upper_sources, offset, depth + 1);
+    route_subnetwork(layers, &lower_sources, offset + half, depth + 1);
+}
+
+/// A trait for engines obliviously permuting LWE ciphertext vectors with a permutation network.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext
+/// vector with a permutation of the `input` LWE ciphertext vector. The permutation is evaluated
+/// with a Benes network, whose switches are controlled by the `switches` GGSW ciphertexts, each
+/// encrypting a single bit.
+///
+/// The network over `2^m` ciphertexts is evaluated in `2m - 1` layers (see
+/// [`permutation_network_layer_count`]). The switches of a layer are listed one after the other,
+/// and the positions they connect are given by [`permutation_network_layer_pairs`]. A switch
+/// encrypting `1` exchanges the two ciphertexts it connects, while a switch encrypting `0` leaves
+/// them in place. The [`permutation_network_switch_bits`] function computes the switch bits
+/// which realize a given permutation.
+///
+/// Every switch is evaluated with two CMUXes, meaning that the noise of the output ciphertexts
+/// grows by one CMUX per layer.
+///
+/// The switches are GGSW ciphertexts with a polynomial size of one, encrypted under a GLWE secret
+/// key whose [transmutation](super::GlweToLweSecretKeyTransmutationEngine) is the LWE secret key
+/// of the input ciphertexts.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorDiscardingPermutationEngine<
+    GgswCiphertext,
+    InputCiphertextVector,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    GgswCiphertext: GgswCiphertextEntity,
+    InputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = GgswCiphertext::KeyDistribution>,
+    OutputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = GgswCiphertext::KeyDistribution>,
+{
+    /// Permutes an LWE ciphertext vector.
+    fn discard_permute_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        switches: &[GgswCiphertext],
+    ) -> Result<(), LweCiphertextVectorDiscardingPermutationError<Self::EngineError>>;
+
+    /// Unsafely permutes an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingPermutationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_permute_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        switches: &[GgswCiphertext],
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn evaluate_network<T: Copy>(input: &[T], bits: &[bool]) -> Vec<T> {
+        let count = LweCiphertextCount(input.len());
+        let mut values = input.to_vec();
+        let mut bits = bits.iter();
+        for layer in 0..permutation_network_layer_count(count) {
+            for (first, second) in permutation_network_layer_pairs(count, layer) {
+                if *bits.next().unwrap() {
+                    values.swap(first, second);
+                }
+            }
+        }
+        assert!(bits.next().is_none());
+        values
+    }
+
+    #[test]
+    fn test_switch_bits_realize_permutation() {
+        for log_count in 1..7 {
+            let count = 1 << log_count;
+            for seed in 0..20_usize {
+                // A cheap deterministic shuffle.
+                let mut permutation: Vec<usize> = (0..count).collect();
+                for i in (1..count).rev() {
+                    let j = (seed * 7919 + i * 104_729 + (i * i) * 31) % (i + 1);
+                    permutation.swap(i, j);
+                }
+                let bits = permutation_network_switch_bits(&permutation);
+                assert_eq!(
+                    bits.len(),
+                    permutation_network_switch_count(LweCiphertextCount(count))
+                );
+                let input: Vec<usize> = (0..count).collect();
+                let output = evaluate_network(&input, &bits);
+                for (i, destination) in permutation.iter().enumerate() {
+                    assert_eq!(output[*destination], input[i]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_switch_bits_reject_non_permutation() {
+        permutation_network_switch_bits(&[0, 0, 1, 2]);
+    }
+}
@@ -0,0 +1,86 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextVectorEntity;
+use concrete_commons::parameters::LweCiphertextIndex;
+
+engine_error! {
+    LweCiphertextVectorDiscardingScatterError for LweCiphertextVectorDiscardingScatterEngine @
+    LweDimensionMismatch => "The input and output LWE dimensions must be the same.",
+    IndexCountMismatch => "The number of indices must be equal to the input ciphertext count.",
+    OutOfVectorOutputIndex => "The output vector must contain all the indices.",
+    DuplicateIndex => "The indices must be distinct."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorDiscardingScatterError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertextVector, OutputCiphertextVector>(
+        output: &OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        indices: &[LweCiphertextIndex],
+    ) -> Result<(), Self>
+    where
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertextVector:
+            LweCiphertextVectorEntity<KeyDistribution = InputCiphertextVector::KeyDistribution>,
+    {
+        if input.lwe_dimension() != output.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if indices.len() != input.lwe_ciphertext_count().0 {
+            return Err(Self::IndexCountMismatch);
+        }
+        let mut scattered = vec![false; output.lwe_ciphertext_count().0];
+        for index in indices.iter() {
+            if index.0 >= scattered.len() {
+                return Err(Self::OutOfVectorOutputIndex);
+            }
+            if scattered[index.0] {
+                return Err(Self::DuplicateIndex);
+            }
+            scattered[index.0] = true;
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines scattering (discarding) LWE ciphertexts into an LWE ciphertext vector.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation copies the ciphertexts of the `input`
+/// LWE ciphertext vector into the `output` LWE ciphertext vector at `indices`: the output
+/// ciphertext at index `indices[i]` is replaced by a copy of the `i`-th input ciphertext. The output
+/// ciphertexts whose index is not listed are left untouched.
+///
+/// The indices do not need to be ordered, but must be distinct, so that every output ciphertext
+/// is written at most once.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorDiscardingScatterEngine<InputCiphertextVector, OutputCiphertextVector>:
+    AbstractEngine
+where
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = InputCiphertextVector::KeyDistribution>,
+{
+    /// Scatters LWE ciphertexts into an LWE ciphertext vector.
+    fn discard_scatter_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        indices: &[LweCiphertextIndex],
+    ) -> Result<(), LweCiphertextVectorDiscardingScatterError<Self::EngineError>>;
+
+    /// Unsafely scatters LWE ciphertexts into an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingScatterError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_scatter_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        indices: &[LweCiphertextIndex],
+    );
+}
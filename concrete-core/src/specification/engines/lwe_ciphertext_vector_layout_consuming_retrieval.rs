@@ -0,0 +1,45 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextVectorEntity;
+use concrete_commons::parameters::CiphertextLayout;
+
+engine_error! {
+    LweCiphertextVectorLayoutConsumingRetrievalError for
+    LweCiphertextVectorLayoutConsumingRetrievalEngine @
+}
+
+/// A trait for engines retrieving the containers of LWE ciphertext vectors, laid out with a given
+/// convention.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation consumes the `ciphertext_vector` LWE
+/// ciphertext vector, and returns the container holding its data, with the coefficients of every
+/// ciphertext ordered following `layout`. The coefficients are reordered in place, such that the
+/// container is returned without being copied.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorLayoutConsumingRetrievalEngine<CiphertextVector, Container>:
+    AbstractEngine
+where
+    CiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Retrieves the container of an LWE ciphertext vector with a given layout.
+    fn consume_retrieve_lwe_ciphertext_vector_with_layout(
+        &mut self,
+        ciphertext_vector: CiphertextVector,
+        layout: CiphertextLayout,
+    ) -> Result<Container, LweCiphertextVectorLayoutConsumingRetrievalError<Self::EngineError>>;
+
+    /// Unsafely retrieves the container of an LWE ciphertext vector with a given layout.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorLayoutConsumingRetrievalError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn consume_retrieve_lwe_ciphertext_vector_with_layout_unchecked(
+        &mut self,
+        ciphertext_vector: CiphertextVector,
+        layout: CiphertextLayout,
+    ) -> Container;
+}
@@ -0,0 +1,63 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use concrete_commons::parameters::{CiphertextLayout, LweSize};
+
+engine_error! {
+    LweCiphertextVectorLayoutConversionError for LweCiphertextVectorLayoutConversionEngine @
+    EmptyContainer => "The container of the LWE ciphertexts must not be empty.",
+    NullLweSize => "The LWE size must be greater than zero.",
+    InvalidContainerSize => "The length of the container must be a multiple of the LWE size."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorLayoutConversionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(container_length: usize, lwe_size: LweSize) -> Result<(), Self> {
+        if container_length == 0 {
+            return Err(Self::EmptyContainer);
+        }
+        if lwe_size.0 == 0 {
+            return Err(Self::NullLweSize);
+        }
+        if !container_length.is_multiple_of(lwe_size.0) {
+            return Err(Self::InvalidContainerSize);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines converting raw containers of LWE ciphertexts between layouts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation consumes the `input` container, holding LWE
+/// ciphertexts of size `lwe_size` laid out contiguously with the `input_layout` convention, and
+/// returns a container holding the same ciphertexts with the `output_layout` convention, without
+/// creating any LWE ciphertext vector entity.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorLayoutConversionEngine<InputContainer, OutputContainer>:
+    AbstractEngine
+{
+    /// Converts a container of LWE ciphertexts between layouts.
+    fn convert_lwe_ciphertext_vector_layout(
+        &mut self,
+        input: InputContainer,
+        lwe_size: LweSize,
+        input_layout: CiphertextLayout,
+        output_layout: CiphertextLayout,
+    ) -> Result<OutputContainer, LweCiphertextVectorLayoutConversionError<Self::EngineError>>;
+
+    /// Unsafely converts a container of LWE ciphertexts between layouts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorLayoutConversionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn convert_lwe_ciphertext_vector_layout_unchecked(
+        &mut self,
+        input: InputContainer,
+        lwe_size: LweSize,
+        input_layout: CiphertextLayout,
+        output_layout: CiphertextLayout,
+    ) -> OutputContainer;
+}
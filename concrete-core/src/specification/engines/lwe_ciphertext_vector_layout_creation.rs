@@ -0,0 +1,66 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextVectorEntity;
+use concrete_commons::parameters::{CiphertextLayout, LweSize};
+
+engine_error! {
+    LweCiphertextVectorLayoutCreationError for LweCiphertextVectorLayoutCreationEngine @
+    EmptyContainer => "The container used to create the LWE ciphertext vector must not be empty.",
+    NullLweSize => "The LWE size must be greater than zero.",
+    InvalidContainerSize => "The length of the container must be a multiple of the LWE size."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorLayoutCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(container_length: usize, lwe_size: LweSize) -> Result<(), Self> {
+        if container_length == 0 {
+            return Err(Self::EmptyContainer);
+        }
+        if lwe_size.0 == 0 {
+            return Err(Self::NullLweSize);
+        }
+        if !container_length.is_multiple_of(lwe_size.0) {
+            return Err(Self::InvalidContainerSize);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines creating LWE ciphertext vectors from existing containers laid out with a
+/// given convention.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates an LWE ciphertext vector from the
+/// `container` of the caller, holding ciphertexts of size `lwe_size` laid out contiguously, with
+/// the coefficients of every ciphertext ordered following `layout`. The coefficients are reordered
+/// in place to the layout of the engine, such that the container is moved into the output vector
+/// without being copied.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorLayoutCreationEngine<Container, CiphertextVector>:
+    AbstractEngine
+where
+    CiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Creates an LWE ciphertext vector from an existing container with a given layout.
+    fn create_lwe_ciphertext_vector_with_layout(
+        &mut self,
+        container: Container,
+        lwe_size: LweSize,
+        layout: CiphertextLayout,
+    ) -> Result<CiphertextVector, LweCiphertextVectorLayoutCreationError<Self::EngineError>>;
+
+    /// Unsafely creates an LWE ciphertext vector from an existing container with a given layout.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorLayoutCreationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn create_lwe_ciphertext_vector_with_layout_unchecked(
+        &mut self,
+        container: Container,
+        lwe_size: LweSize,
+        layout: CiphertextLayout,
+    ) -> CiphertextVector;
+}
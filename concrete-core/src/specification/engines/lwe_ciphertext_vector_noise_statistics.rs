@@ -0,0 +1,112 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextVectorEntity, LweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{LweCiphertextCount, MessageBitCount};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+engine_error! {
+    LweCiphertextVectorNoiseStatisticsError for LweCiphertextVectorNoiseStatisticsEngine @
+    LweDimensionMismatch => "The input and secret key LWE dimensions must be the same.",
+    NullMessageBitCount => "The message bit count must be greater than zero.",
+    MessageBitCountTooLarge => "The message bit count must be smaller than the precision of the \
+                                ciphertexts."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorNoiseStatisticsError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, CiphertextVector>(
+        key: &SecretKey,
+        input: &CiphertextVector,
+        message_bits: MessageBitCount,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        SecretKey: LweSecretKeyEntity,
+        CiphertextVector: LweCiphertextVectorEntity,
+    {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if message_bits.0 == 0 {
+            return Err(Self::NullMessageBitCount);
+        }
+        if message_bits.0 >= integer_precision {
+            return Err(Self::MessageBitCountTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A summary of the noise of the ciphertexts of an LWE ciphertext vector.
+///
+/// All the values are expressed on the torus, which means that they are relative to the modulus
+/// of the ciphertexts.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoiseStatistics {
+    /// The number of ciphertexts the statistics were computed on.
+    pub sample_count: LweCiphertextCount,
+    /// The mean of the noise.
+    pub mean: f64,
+    /// The variance of the noise around its mean.
+    pub variance: Variance,
+    /// The largest absolute value of the noise.
+    pub max_deviation: f64,
+    /// The ratio between the largest absolute value of the noise and the largest noise that can
+    /// be decoded correctly. A ratio of one or more means that some messages were lost.
+    pub budget_consumed: f64,
+}
+
+/// A trait for engines computing statistics on the noise of LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation decrypts the `input` LWE ciphertext vector
+/// with the `key` LWE secret key, and returns a [`NoiseStatistics`] summary of the noise of its
+/// ciphertexts. The noise of every element is never exposed, which allows to monitor the noise
+/// of a deployed pipeline on a sample of its traffic.
+///
+/// The messages do not need to be known: the ciphertexts are expected to encrypt multiples of
+/// $\Delta = 2^{q - p}$ for integers of $q$ bits, with $p$ the `message_bits` value, which counts
+/// all the bits above $\Delta$, padding bits included. The noise of a ciphertext is the distance
+/// between its phase and the closest multiple of $\Delta$.
+///
+/// # Formal Definition
+///
+/// Let $\varphi_i$ be the phase of the $i$-th of the $n$ input ciphertexts, and
+/// $e_i = \varphi_i - \Delta \cdot \left\lfloor \varphi_i / \Delta \right\rceil$ its noise,
+/// expressed on the torus. The statistics are:
+///
+/// * the mean $\mu = \frac{1}{n} \sum_i e_i$,
+/// * the variance $\sigma^2 = \frac{1}{n} \sum_i (e_i - \mu)^2$,
+/// * the max deviation $e_{max} = \max_i |e_i|$,
+/// * the budget consumed $e_{max} / (\Delta / 2)$.
+pub trait LweCiphertextVectorNoiseStatisticsEngine<SecretKey, CiphertextVector>:
+    AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    CiphertextVector: LweCiphertextVectorEntity<KeyDistribution = SecretKey::KeyDistribution>,
+{
+    /// Computes statistics on the noise of an LWE ciphertext vector.
+    fn compute_lwe_ciphertext_vector_noise_statistics(
+        &mut self,
+        key: &SecretKey,
+        input: &CiphertextVector,
+        message_bits: MessageBitCount,
+    ) -> Result<NoiseStatistics, LweCiphertextVectorNoiseStatisticsError<Self::EngineError>>;
+
+    /// Unsafely computes statistics on the noise of an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorNoiseStatisticsError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn compute_lwe_ciphertext_vector_noise_statistics_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &CiphertextVector,
+        message_bits: MessageBitCount,
+    ) -> NoiseStatistics;
+}
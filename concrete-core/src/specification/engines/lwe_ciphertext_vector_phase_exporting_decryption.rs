@@ -0,0 +1,97 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweCiphertextVectorEntity, LwePhaseVectorEntity, LweSecretKeyEntity, PlaintextVectorEntity,
+};
+use concrete_commons::parameters::MessageBitCount;
+
+engine_error! {
+    LweCiphertextVectorPhaseExportingDecryptionError
+    for LweCiphertextVectorPhaseExportingDecryptionEngine @
+    LweDimensionMismatch => "The input and secret key LWE dimensions must be the same.",
+    NullMessageBitCount => "The message bit count must be greater than zero.",
+    MessageBitCountTooLarge => "The message bit count must be smaller than the precision of the \
+                                ciphertexts."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorPhaseExportingDecryptionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, CiphertextVector>(
+        key: &SecretKey,
+        input: &CiphertextVector,
+        message_bits: MessageBitCount,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        SecretKey: LweSecretKeyEntity,
+        CiphertextVector: LweCiphertextVectorEntity,
+    {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if message_bits.0 == 0 {
+            return Err(Self::NullMessageBitCount);
+        }
+        if message_bits.0 >= integer_precision {
+            return Err(Self::MessageBitCountTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines decrypting LWE ciphertext vectors, and exporting the phases of their
+/// ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation decrypts the `input` LWE ciphertext vector
+/// under the `key` secret key, and generates both:
+///
+/// + a plaintext vector containing the decoded messages, that is the phases rounded to the closest
+/// multiple of $\Delta = 2^{q - p}$ for integers of $q$ bits, with $p$ the `message_bits` value.
+/// + a phase vector containing the raw phases of the ciphertexts.
+///
+/// The phases are the witness needed by an external proof system to prove that the decryption, or
+/// a share of a threshold decryption, was performed correctly, without revealing the secret key.
+/// They carry the noise of the ciphertexts, and must not be disclosed in clear.
+///
+/// # Formal Definition
+///
+/// Let $(\vec{a}\_i, b\_i)$ be the $i$-th input ciphertext and $\vec{s}$ the secret key. The
+/// $i$-th phase is $\varphi\_i = b\_i - \left\langle \vec{a}\_i, \vec{s} \right\rangle$, and the
+/// $i$-th plaintext is $\Delta \cdot \left\lfloor \varphi\_i / \Delta \right\rceil$.
+pub trait LweCiphertextVectorPhaseExportingDecryptionEngine<
+    SecretKey,
+    CiphertextVector,
+    PlaintextVector,
+    PhaseVector,
+>: AbstractEngine where
+    SecretKey: LweSecretKeyEntity,
+    CiphertextVector: LweCiphertextVectorEntity<KeyDistribution = SecretKey::KeyDistribution>,
+    PlaintextVector: PlaintextVectorEntity,
+    PhaseVector: LwePhaseVectorEntity,
+{
+    /// Decrypts an LWE ciphertext vector and exports its phases.
+    fn decrypt_lwe_ciphertext_vector_exporting_phases(
+        &mut self,
+        key: &SecretKey,
+        input: &CiphertextVector,
+        message_bits: MessageBitCount,
+    ) -> Result<
+        (PlaintextVector, PhaseVector),
+        LweCiphertextVectorPhaseExportingDecryptionError<Self::EngineError>,
+    >;
+
+    /// Unsafely decrypts an LWE ciphertext vector and exports its phases.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorPhaseExportingDecryptionError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn decrypt_lwe_ciphertext_vector_exporting_phases_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &CiphertextVector,
+        message_bits: MessageBitCount,
+    ) -> (PlaintextVector, PhaseVector);
+}
@@ -0,0 +1,81 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextVectorEntity, PlaintextVectorEntity};
+
+engine_error! {
+    LweCiphertextVectorPlaintextVectorDiscardingSubtractionError for
+        LweCiphertextVectorPlaintextVectorDiscardingSubtractionEngine @
+    LweDimensionMismatch => "The input and output LWE dimensions must be the same.",
+    CiphertextCountMismatch => "The input and output ciphertext count must be the same.",
+    PlaintextCountMismatch => "The plaintext count of the input vector and the input ciphertext \
+                               count must be the same."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextVectorPlaintextVectorDiscardingSubtractionError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertextVector, PlaintextVector, OutputCiphertextVector>(
+        output: &OutputCiphertextVector,
+        input_1: &InputCiphertextVector,
+        input_2: &PlaintextVector,
+    ) -> Result<(), Self>
+    where
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        OutputCiphertextVector:
+            LweCiphertextVectorEntity<KeyDistribution = InputCiphertextVector::KeyDistribution>,
+    {
+        if output.lwe_dimension() != input_1.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if output.lwe_ciphertext_count() != input_1.lwe_ciphertext_count() {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        if input_2.plaintext_count().0 != input_1.lwe_ciphertext_count().0 {
+            return Err(Self::PlaintextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines subtracting (discarding) plaintext vectors to LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext vector
+/// with the element-wise subtraction of the `input_2` plaintext vector to the `input_1` LWE
+/// ciphertext vector.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorPlaintextVectorDiscardingSubtractionEngine<
+    InputCiphertextVector,
+    PlaintextVector,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    OutputCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = InputCiphertextVector::KeyDistribution>,
+{
+    /// Subtracts a plaintext vector to an LWE ciphertext vector.
+    fn discard_sub_lwe_ciphertext_vector_plaintext_vector(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input_1: &InputCiphertextVector,
+        input_2: &PlaintextVector,
+    ) -> Result<(), LweCiphertextVectorPlaintextVectorDiscardingSubtractionError<Self::EngineError>>;
+
+    /// Unsafely subtracts a plaintext vector to an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorPlaintextVectorDiscardingSubtractionError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_sub_lwe_ciphertext_vector_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input_1: &InputCiphertextVector,
+        input_2: &PlaintextVector,
+    );
+}
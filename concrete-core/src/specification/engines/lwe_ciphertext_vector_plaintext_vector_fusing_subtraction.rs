@@ -0,0 +1,64 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextVectorEntity, PlaintextVectorEntity};
+
+engine_error! {
+    LweCiphertextVectorPlaintextVectorFusingSubtractionError for
+        LweCiphertextVectorPlaintextVectorFusingSubtractionEngine @
+    PlaintextCountMismatch => "The plaintext count of the input vector and the output ciphertext \
+                               count must be the same."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextVectorPlaintextVectorFusingSubtractionError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<CiphertextVector, PlaintextVector>(
+        output: &CiphertextVector,
+        input: &PlaintextVector,
+    ) -> Result<(), Self>
+    where
+        CiphertextVector: LweCiphertextVectorEntity,
+        PlaintextVector: PlaintextVectorEntity,
+    {
+        if input.plaintext_count().0 != output.lwe_ciphertext_count().0 {
+            return Err(Self::PlaintextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines subtracting (fusing) plaintext vectors to LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation subtracts the `input` plaintext vector
+/// element-wise to the `output` LWE ciphertext vector.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorPlaintextVectorFusingSubtractionEngine<
+    CiphertextVector,
+    PlaintextVector,
+>: AbstractEngine where
+    CiphertextVector: LweCiphertextVectorEntity,
+    PlaintextVector: PlaintextVectorEntity,
+{
+    /// Subtracts a plaintext vector to an LWE ciphertext vector.
+    fn fuse_sub_lwe_ciphertext_vector_plaintext_vector(
+        &mut self,
+        output: &mut CiphertextVector,
+        input: &PlaintextVector,
+    ) -> Result<(), LweCiphertextVectorPlaintextVectorFusingSubtractionError<Self::EngineError>>;
+
+    /// Unsafely subtracts a plaintext vector to an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorPlaintextVectorFusingSubtractionError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn fuse_sub_lwe_ciphertext_vector_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut CiphertextVector,
+        input: &PlaintextVector,
+    );
+}
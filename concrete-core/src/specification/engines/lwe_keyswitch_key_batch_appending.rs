@@ -0,0 +1,84 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{LweKeyswitchKeyBatchEntity, LweKeyswitchKeyEntity};
+use concrete_commons::parameters::LweKeyswitchKeyIndex;
+
+engine_error! {
+    LweKeyswitchKeyBatchAppendingError for LweKeyswitchKeyBatchAppendingEngine @
+    InputLweDimensionMismatch => "The keyswitch key and the batch input LWE dimensions must be the \
+                                  same.",
+    OutputLweDimensionMismatch => "The keyswitch key and the batch output LWE dimensions must be \
+                                   the same.",
+    DecompositionLevelCountMismatch => "The keyswitch key and the batch decomposition level \
+                                        counts must be the same.",
+    DecompositionBaseLogMismatch => "The keyswitch key and the batch decomposition base logs must \
+                                     be the same."
+}
+
+impl<EngineError: std::error::Error> LweKeyswitchKeyBatchAppendingError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<KeyswitchKey, KeyswitchKeyBatch>(
+        batch: &KeyswitchKeyBatch,
+        key: &KeyswitchKey,
+    ) -> Result<(), Self>
+    where
+        KeyswitchKey: LweKeyswitchKeyEntity,
+        KeyswitchKeyBatch: LweKeyswitchKeyBatchEntity<
+            InputKeyDistribution = KeyswitchKey::InputKeyDistribution,
+            OutputKeyDistribution = KeyswitchKey::OutputKeyDistribution,
+        >,
+    {
+        if batch.input_lwe_dimension() != key.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if batch.output_lwe_dimension() != key.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        if batch.decomposition_level_count() != key.decomposition_level_count() {
+            return Err(Self::DecompositionLevelCountMismatch);
+        }
+        if batch.decomposition_base_log() != key.decomposition_base_log() {
+            return Err(Self::DecompositionBaseLogMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines appending LWE keyswitch keys to batches.
+///
+/// # Semantics
+///
+/// This operation copies the `key` LWE keyswitch key at the end of the `batch` LWE keyswitch key
+/// batch, and returns the index at which it can be selected, which is the number of keys of the
+/// batch before the operation.
+///
+/// # Formal Definition
+pub trait LweKeyswitchKeyBatchAppendingEngine<KeyswitchKey, KeyswitchKeyBatch>:
+    AbstractEngine
+where
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    KeyswitchKeyBatch: LweKeyswitchKeyBatchEntity<
+        InputKeyDistribution = KeyswitchKey::InputKeyDistribution,
+        OutputKeyDistribution = KeyswitchKey::OutputKeyDistribution,
+    >,
+{
+    /// Appends an LWE keyswitch key to a batch.
+    fn append_lwe_keyswitch_key(
+        &mut self,
+        batch: &mut KeyswitchKeyBatch,
+        key: &KeyswitchKey,
+    ) -> Result<LweKeyswitchKeyIndex, LweKeyswitchKeyBatchAppendingError<Self::EngineError>>;
+
+    /// Unsafely appends an LWE keyswitch key to a batch.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweKeyswitchKeyBatchAppendingError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn append_lwe_keyswitch_key_unchecked(
+        &mut self,
+        batch: &mut KeyswitchKeyBatch,
+        key: &KeyswitchKey,
+    ) -> LweKeyswitchKeyIndex;
+}
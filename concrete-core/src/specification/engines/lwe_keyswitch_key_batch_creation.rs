@@ -0,0 +1,74 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::LweKeyswitchKeyBatchEntity;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+engine_error! {
+    LweKeyswitchKeyBatchCreationError for LweKeyswitchKeyBatchCreationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+impl<EngineError: std::error::Error> LweKeyswitchKeyBatchCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        integer_precision: usize,
+    ) -> Result<(), Self> {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+
+        if decomposition_level_count.0 * decomposition_base_log.0 > integer_precision {
+            return Err(Self::DecompositionTooLarge);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines creating empty batches of LWE keyswitch keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates a batch of LWE keyswitch keys which
+/// does not contain any key yet. The keys later appended to the batch must switch from input keys
+/// of `input_lwe_dimension` to output keys of `output_lwe_dimension`, with the given decomposition
+/// parameters.
+///
+/// # Formal Definition
+pub trait LweKeyswitchKeyBatchCreationEngine<KeyswitchKeyBatch>: AbstractEngine
+where
+    KeyswitchKeyBatch: LweKeyswitchKeyBatchEntity,
+{
+    /// Creates an empty LWE keyswitch key batch.
+    fn create_lwe_keyswitch_key_batch(
+        &mut self,
+        input_lwe_dimension: LweDimension,
+        output_lwe_dimension: LweDimension,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<KeyswitchKeyBatch, LweKeyswitchKeyBatchCreationError<Self::EngineError>>;
+
+    /// Unsafely creates an empty LWE keyswitch key batch.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweKeyswitchKeyBatchCreationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn create_lwe_keyswitch_key_batch_unchecked(
+        &mut self,
+        input_lwe_dimension: LweDimension,
+        output_lwe_dimension: LweDimension,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> KeyswitchKeyBatch;
+}
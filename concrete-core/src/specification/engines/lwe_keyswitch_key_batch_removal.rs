@@ -0,0 +1,59 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::LweKeyswitchKeyBatchEntity;
+use concrete_commons::parameters::LweKeyswitchKeyIndex;
+
+engine_error! {
+    LweKeyswitchKeyBatchRemovalError for LweKeyswitchKeyBatchRemovalEngine @
+    IndexOutOfBounds => "The index must be smaller than the number of keys in the batch."
+}
+
+impl<EngineError: std::error::Error> LweKeyswitchKeyBatchRemovalError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<KeyswitchKeyBatch>(
+        batch: &KeyswitchKeyBatch,
+        index: LweKeyswitchKeyIndex,
+    ) -> Result<(), Self>
+    where
+        KeyswitchKeyBatch: LweKeyswitchKeyBatchEntity,
+    {
+        if index.0 >= batch.key_count().0 {
+            return Err(Self::IndexOutOfBounds);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines removing LWE keyswitch keys from batches.
+///
+/// # Semantics
+///
+/// This operation removes the key stored at the `index` position of the `batch` LWE keyswitch key
+/// batch. The keys stored after it are moved one position down: their indices are decremented,
+/// while the ones of the keys stored before it are left unchanged.
+///
+/// # Formal Definition
+pub trait LweKeyswitchKeyBatchRemovalEngine<KeyswitchKeyBatch>: AbstractEngine
+where
+    KeyswitchKeyBatch: LweKeyswitchKeyBatchEntity,
+{
+    /// Removes an LWE keyswitch key from a batch.
+    fn remove_lwe_keyswitch_key(
+        &mut self,
+        batch: &mut KeyswitchKeyBatch,
+        index: LweKeyswitchKeyIndex,
+    ) -> Result<(), LweKeyswitchKeyBatchRemovalError<Self::EngineError>>;
+
+    /// Unsafely removes an LWE keyswitch key from a batch.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweKeyswitchKeyBatchRemovalError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn remove_lwe_keyswitch_key_unchecked(
+        &mut self,
+        batch: &mut KeyswitchKeyBatch,
+        index: LweKeyswitchKeyIndex,
+    );
+}
@@ -0,0 +1,89 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweKeyswitchKeyDeltaEntity, LweKeyswitchKeyEntity, LweSecretKeyEntity,
+};
+use concrete_commons::dispersion::Variance;
+
+engine_error! {
+    LweKeyswitchKeyDeltaCreationError for LweKeyswitchKeyDeltaCreationEngine @
+    OldOutputLweDimensionMismatch => "The old output secret key and the keyswitch key output LWE \
+                                      dimensions must be the same.",
+    NewOutputLweDimensionMismatch => "The new output secret key and the keyswitch key output LWE \
+                                      dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error> LweKeyswitchKeyDeltaCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<KeyswitchKey, SecretKey>(
+        keyswitch_key: &KeyswitchKey,
+        old_output_key: &SecretKey,
+        new_output_key: &SecretKey,
+    ) -> Result<(), Self>
+    where
+        KeyswitchKey: LweKeyswitchKeyEntity,
+        SecretKey: LweSecretKeyEntity<KeyDistribution = KeyswitchKey::OutputKeyDistribution>,
+    {
+        if old_output_key.lwe_dimension() != keyswitch_key.output_lwe_dimension() {
+            return Err(Self::OldOutputLweDimensionMismatch);
+        }
+        if new_output_key.lwe_dimension() != keyswitch_key.output_lwe_dimension() {
+            return Err(Self::NewOutputLweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines creating the deltas updating LWE keyswitch keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates the delta which updates the
+/// `keyswitch_key`, whose output secret key is the `old_output_key`, into a keyswitch key whose
+/// output secret key is the `new_output_key`. The delta is applied with an
+/// [`LweKeyswitchKeyFusingUpdateEngine`](`super::LweKeyswitchKeyFusingUpdateEngine`).
+///
+/// The masks of the keyswitch key are kept by the update. To prevent a holder of the old output
+/// key from learning anything on the new one, every body correction carries a fresh noise of
+/// variance `noise`: the variance of the noise of the updated keyswitch key is the sum of its
+/// previous variance and of `noise`.
+///
+/// # Formal Definition
+///
+/// Let $\mathsf{ct} = (a\_0, \dots, a\_{n-1}, b)$ be a ciphertext of the keyswitch key, and
+/// $s$, $s'$ the old and new output keys. The correction of its body is
+/// $\sum\_{c, s'\_c \neq s\_c} a\_c (s'\_c - s\_c) + e$, where $e$ is a fresh noise. Only the
+/// coefficients which differ between the two keys are visited.
+pub trait LweKeyswitchKeyDeltaCreationEngine<KeyswitchKey, SecretKey, Delta>:
+    AbstractEngine
+where
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    SecretKey: LweSecretKeyEntity<KeyDistribution = KeyswitchKey::OutputKeyDistribution>,
+    Delta: LweKeyswitchKeyDeltaEntity<
+        InputKeyDistribution = KeyswitchKey::InputKeyDistribution,
+        OutputKeyDistribution = KeyswitchKey::OutputKeyDistribution,
+    >,
+{
+    /// Creates the delta updating an LWE keyswitch key to a new output key.
+    fn create_lwe_keyswitch_key_delta(
+        &mut self,
+        keyswitch_key: &KeyswitchKey,
+        old_output_key: &SecretKey,
+        new_output_key: &SecretKey,
+        noise: Variance,
+    ) -> Result<Delta, LweKeyswitchKeyDeltaCreationError<Self::EngineError>>;
+
+    /// Unsafely creates the delta updating an LWE keyswitch key to a new output key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweKeyswitchKeyDeltaCreationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn create_lwe_keyswitch_key_delta_unchecked(
+        &mut self,
+        keyswitch_key: &KeyswitchKey,
+        old_output_key: &SecretKey,
+        new_output_key: &SecretKey,
+        noise: Variance,
+    ) -> Delta;
+}
@@ -0,0 +1,84 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweKeyswitchKeyDeltaEntity, LweKeyswitchKeyEntity};
+
+engine_error! {
+    LweKeyswitchKeyFusingUpdateError for LweKeyswitchKeyFusingUpdateEngine @
+    InputLweDimensionMismatch => "The keyswitch key and delta input LWE dimensions must be the \
+                                  same.",
+    OutputLweDimensionMismatch => "The keyswitch key and delta output LWE dimensions must be the \
+                                   same.",
+    DecompositionBaseLogMismatch => "The keyswitch key and delta decomposition base logarithms \
+                                     must be the same.",
+    DecompositionLevelCountMismatch => "The keyswitch key and delta decomposition level counts \
+                                        must be the same."
+}
+
+impl<EngineError: std::error::Error> LweKeyswitchKeyFusingUpdateError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<KeyswitchKey, Delta>(
+        keyswitch_key: &KeyswitchKey,
+        delta: &Delta,
+    ) -> Result<(), Self>
+    where
+        KeyswitchKey: LweKeyswitchKeyEntity,
+        Delta: LweKeyswitchKeyDeltaEntity<
+            InputKeyDistribution = KeyswitchKey::InputKeyDistribution,
+            OutputKeyDistribution = KeyswitchKey::OutputKeyDistribution,
+        >,
+    {
+        if keyswitch_key.input_lwe_dimension() != delta.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if keyswitch_key.output_lwe_dimension() != delta.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        if keyswitch_key.decomposition_base_log() != delta.decomposition_base_log() {
+            return Err(Self::DecompositionBaseLogMismatch);
+        }
+        if keyswitch_key.decomposition_level_count() != delta.decomposition_level_count() {
+            return Err(Self::DecompositionLevelCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines updating (fusing) LWE keyswitch keys.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation applies the `delta` to the `keyswitch_key`,
+/// which then switches to the new output secret key the delta was created for. The delta must
+/// have been created from this very keyswitch key, with an
+/// [`LweKeyswitchKeyDeltaCreationEngine`](`super::LweKeyswitchKeyDeltaCreationEngine`).
+///
+/// # Formal Definition
+///
+/// The correction of the delta is added to the body of every ciphertext of the keyswitch key.
+pub trait LweKeyswitchKeyFusingUpdateEngine<Delta, KeyswitchKey>: AbstractEngine
+where
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    Delta: LweKeyswitchKeyDeltaEntity<
+        InputKeyDistribution = KeyswitchKey::InputKeyDistribution,
+        OutputKeyDistribution = KeyswitchKey::OutputKeyDistribution,
+    >,
+{
+    /// Updates an LWE keyswitch key with a delta.
+    fn fuse_update_lwe_keyswitch_key(
+        &mut self,
+        keyswitch_key: &mut KeyswitchKey,
+        delta: &Delta,
+    ) -> Result<(), LweKeyswitchKeyFusingUpdateError<Self::EngineError>>;
+
+    /// Unsafely updates an LWE keyswitch key with a delta.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweKeyswitchKeyFusingUpdateError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn fuse_update_lwe_keyswitch_key_unchecked(
+        &mut self,
+        keyswitch_key: &mut KeyswitchKey,
+        delta: &Delta,
+    );
+}
@@ -0,0 +1,43 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweKeyswitchKeyEntity;
+
+engine_error! {
+    LweKeyswitchKeyUploadError for LweKeyswitchKeyUploadEngine @
+}
+
+/// A trait for engines uploading LWE keyswitch keys to the memory of a device.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a LWE keyswitch key stored in the
+/// memory of a device (for instance a gpu), containing the `input` LWE keyswitch key.
+///
+/// Contrarily to the [`LweKeyswitchKeyConversionEngine`](super::LweKeyswitchKeyConversionEngine),
+/// the engine may keep the uploaded key resident on the device after the returned entity is
+/// dropped, and return it again when the same key is uploaded later on, instead of copying it
+/// anew.
+///
+/// # Formal Definition
+pub trait LweKeyswitchKeyUploadEngine<Input, Output>: AbstractEngine
+where
+    Input: LweKeyswitchKeyEntity,
+    Output: LweKeyswitchKeyEntity<
+        InputKeyDistribution = Input::InputKeyDistribution,
+        OutputKeyDistribution = Input::OutputKeyDistribution,
+    >,
+{
+    /// Uploads a LWE keyswitch key.
+    fn upload_lwe_keyswitch_key(
+        &mut self,
+        input: &Input,
+    ) -> Result<Output, LweKeyswitchKeyUploadError<Self::EngineError>>;
+
+    /// Unsafely uploads a LWE keyswitch key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweKeyswitchKeyUploadError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn upload_lwe_keyswitch_key_unchecked(&mut self, input: &Input) -> Output;
+}
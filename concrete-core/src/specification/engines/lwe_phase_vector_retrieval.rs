@@ -0,0 +1,36 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LwePhaseVectorEntity;
+
+engine_error! {
+    LwePhaseVectorRetrievalError for LwePhaseVectorRetrievalEngine @
+}
+
+/// A trait for engines retrieving arbitrary values from LWE phase vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a vec of arbitrary values from the
+/// `input` LWE phase vector, for instance to feed them to an external proof system. By arbitrary
+/// here, we mean that `Value` can be any type that suits the backend implementor (an integer, a
+/// struct wrapping integers, a struct wrapping foreign data or any other thing).
+///
+/// # Formal Definition
+pub trait LwePhaseVectorRetrievalEngine<PhaseVector, Value>: AbstractEngine
+where
+    PhaseVector: LwePhaseVectorEntity,
+{
+    /// Retrieves arbitrary values from an LWE phase vector.
+    fn retrieve_lwe_phase_vector(
+        &mut self,
+        input: &PhaseVector,
+    ) -> Result<Vec<Value>, LwePhaseVectorRetrievalError<Self::EngineError>>;
+
+    /// Unsafely retrieves arbitrary values from an LWE phase vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LwePhaseVectorRetrievalError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn retrieve_lwe_phase_vector_unchecked(&mut self, input: &PhaseVector) -> Vec<Value>;
+}
@@ -0,0 +1,59 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LwePublicKeyEntity, LweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LwePublicKeyZeroEncryptionCount;
+
+engine_error! {
+    LwePublicKeyCreationError for LwePublicKeyCreationEngine @
+    NullPublicKeyZeroEncryptionCount => "The number of LWE encryptions of zero in the public key \
+                                         must be greater than zero."
+}
+
+impl<EngineError: std::error::Error> LwePublicKeyCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Result<(), Self> {
+        if lwe_public_key_zero_encryption_count.0 == 0 {
+            return Err(Self::NullPublicKeyZeroEncryptionCount);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines creating LWE public keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE public key made of
+/// `lwe_public_key_zero_encryption_count` encryptions of zero under the `lwe_secret_key`, with
+/// noises of variance `noise`.
+///
+/// # Formal Definition
+pub trait LwePublicKeyCreationEngine<SecretKey, PublicKey>: AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    PublicKey: LwePublicKeyEntity<KeyDistribution = SecretKey::KeyDistribution>,
+{
+    /// Creates an LWE public key.
+    fn create_lwe_public_key(
+        &mut self,
+        lwe_secret_key: &SecretKey,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Result<PublicKey, LwePublicKeyCreationError<Self::EngineError>>;
+
+    /// Unsafely creates an LWE public key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LwePublicKeyCreationError`]. For safety concerns _specific_ to an engine, refer to the
+    /// implementer safety section.
+    unsafe fn create_lwe_public_key_unchecked(
+        &mut self,
+        lwe_secret_key: &SecretKey,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> PublicKey;
+}
@@ -1,3 +1,4 @@
+use super::engine_error;
 use crate::specification::engines::AbstractEngine;
 use crate::specification::entities::LweSecretKeyEntity;
 use concrete_commons::parameters::LweDimension;
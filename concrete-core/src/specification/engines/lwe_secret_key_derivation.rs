@@ -0,0 +1,59 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::parameters::{ClientId, LweDimension, MasterSeed};
+
+engine_error! {
+    LweSecretKeyDerivationError for LweSecretKeyDerivationEngine @
+    NullLweDimension => "The LWE dimension must be greater than zero."
+}
+
+impl<EngineError: std::error::Error> LweSecretKeyDerivationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(lwe_dimension: LweDimension) -> Result<(), Self> {
+        if lwe_dimension.0 == 0 {
+            return Err(Self::NullLweDimension);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines deriving LWE secret keys from a master seed.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation deterministically derives the LWE secret key
+/// of the `client_id` client from the `master_seed` seed. This allows a service to generate the
+/// keys of many clients from a single secret, and to generate them again when needed instead of
+/// storing them.
+///
+/// The derivation is domain-separated: the keys derived for different clients are independent,
+/// and are independent from the keys derived with the same seed for other purposes. Deriving a key
+/// twice with the same inputs yields the same key.
+///
+/// # Formal Definition
+pub trait LweSecretKeyDerivationEngine<SecretKey>: AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+{
+    /// Derives an LWE secret key.
+    fn derive_lwe_secret_key(
+        &mut self,
+        master_seed: MasterSeed,
+        client_id: ClientId,
+        lwe_dimension: LweDimension,
+    ) -> Result<SecretKey, LweSecretKeyDerivationError<Self::EngineError>>;
+
+    /// Unsafely derives an LWE secret key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSecretKeyDerivationError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn derive_lwe_secret_key_unchecked(
+        &mut self,
+        master_seed: MasterSeed,
+        client_id: ClientId,
+        lwe_dimension: LweDimension,
+    ) -> SecretKey;
+}
@@ -0,0 +1,65 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::parameters::LweDimension;
+
+engine_error! {
+    LweSecretKeyShrinkingError for LweSecretKeyShrinkingEngine @
+    NullLweDimension => "The output LWE dimension must be greater than zero.",
+    OutputLweDimensionTooLarge => "The output LWE dimension must be smaller than the input LWE \
+                                   dimension."
+}
+
+impl<EngineError: std::error::Error> LweSecretKeyShrinkingError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputSecretKey>(
+        input: &InputSecretKey,
+        output_lwe_dimension: LweDimension,
+    ) -> Result<(), Self>
+    where
+        InputSecretKey: LweSecretKeyEntity,
+    {
+        if output_lwe_dimension.0 == 0 {
+            return Err(Self::NullLweDimension);
+        }
+        if output_lwe_dimension.0 >= input.lwe_dimension().0 {
+            return Err(Self::OutputLweDimensionTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines shrinking LWE secret keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates an LWE secret key of dimension
+/// `output_lwe_dimension`, made of the first coefficients of the `input` LWE secret key. The two
+/// keys can then be used with an LWE shrinking keyswitch key (see
+/// [`LweShrinkingKeyswitchKeyCreationEngine`](super::LweShrinkingKeyswitchKeyCreationEngine)).
+///
+/// # Formal Definition
+pub trait LweSecretKeyShrinkingEngine<InputSecretKey, OutputSecretKey>: AbstractEngine
+where
+    InputSecretKey: LweSecretKeyEntity,
+    OutputSecretKey: LweSecretKeyEntity<KeyDistribution = InputSecretKey::KeyDistribution>,
+{
+    /// Shrinks an LWE secret key.
+    fn shrink_lwe_secret_key(
+        &mut self,
+        input: &InputSecretKey,
+        output_lwe_dimension: LweDimension,
+    ) -> Result<OutputSecretKey, LweSecretKeyShrinkingError<Self::EngineError>>;
+
+    /// Unsafely shrinks an LWE secret key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSecretKeyShrinkingError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn shrink_lwe_secret_key_unchecked(
+        &mut self,
+        input: &InputSecretKey,
+        output_lwe_dimension: LweDimension,
+    ) -> OutputSecretKey;
+}
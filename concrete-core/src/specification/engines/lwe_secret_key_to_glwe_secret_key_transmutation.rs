@@ -0,0 +1,75 @@
+use super::engine_error;
+use crate::prelude::AbstractEngine;
+
+use crate::specification::entities::{GlweSecretKeyEntity, LweSecretKeyEntity};
+use concrete_commons::parameters::PolynomialSize;
+
+engine_error! {
+    LweToGlweSecretKeyTransmutationEngineError for LweToGlweSecretKeyTransmutationEngine @
+    NullPolynomialSize => "The polynomial size must be greater than zero.",
+    IncompatiblePolynomialSize => "The polynomial size must divide the LWE dimension of the key."
+}
+
+impl<EngineError: std::error::Error> LweToGlweSecretKeyTransmutationEngineError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputKey>(
+        lwe_secret_key: &InputKey,
+        polynomial_size: PolynomialSize,
+    ) -> Result<(), Self>
+    where
+        InputKey: LweSecretKeyEntity,
+    {
+        if polynomial_size.0 == 0 {
+            return Err(Self::NullPolynomialSize);
+        }
+        if !lwe_secret_key
+            .lwe_dimension()
+            .0
+            .is_multiple_of(polynomial_size.0)
+        {
+            return Err(Self::IncompatiblePolynomialSize);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines transmuting LWE secret keys into GLWE secret keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation moves the existing LWE secret key into a fresh
+/// GLWE secret key, whose polynomials of size `polynomial_size` are made of the consecutive
+/// coefficients of the LWE secret key. This is the inverse of the
+/// [`GlweToLweSecretKeyTransmutationEngine`](super::GlweToLweSecretKeyTransmutationEngine)
+/// operation.
+///
+/// Contrarily to the [`GlweSecretKeyCreationEngine`](super::GlweSecretKeyCreationEngine), a
+/// polynomial size of one is accepted: it gives the GLWE secret keys encrypting the GGSW
+/// ciphertexts which act on the LWE ciphertexts encrypted under the input key, as the switches of
+/// the [`LweCiphertextVectorDiscardingPermutationEngine`](super::LweCiphertextVectorDiscardingPermutationEngine).
+///
+/// # Formal Definition
+pub trait LweToGlweSecretKeyTransmutationEngine<InputKey, OutputKey>: AbstractEngine
+where
+    InputKey: LweSecretKeyEntity,
+    OutputKey: GlweSecretKeyEntity<KeyDistribution = InputKey::KeyDistribution>,
+{
+    /// Does the transmutation of the LWE secret key into a GLWE secret key
+    fn transmute_lwe_secret_key_to_glwe_secret_key(
+        &mut self,
+        lwe_secret_key: InputKey,
+        polynomial_size: PolynomialSize,
+    ) -> Result<OutputKey, LweToGlweSecretKeyTransmutationEngineError<Self::EngineError>>;
+
+    /// Unsafely transmutes an LWE secret key into a GLWE secret key
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweToGlweSecretKeyTransmutationEngineError`].
+    /// For safety concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn transmute_lwe_secret_key_to_glwe_secret_key_unchecked(
+        &mut self,
+        lwe_secret_key: InputKey,
+        polynomial_size: PolynomialSize,
+    ) -> OutputKey;
+}
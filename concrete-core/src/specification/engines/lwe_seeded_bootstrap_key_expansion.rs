@@ -0,0 +1,38 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweBootstrapKeyEntity, LweSeededBootstrapKeyEntity};
+
+engine_error! {
+    LweSeededBootstrapKeyExpansionError for LweSeededBootstrapKeyExpansionEngine @
+}
+
+/// A trait for engines expanding seeded LWE bootstrap keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE bootstrap key containing the
+/// expansion of the `input` seeded LWE bootstrap key, whose masks are regenerated from the seed.
+///
+/// # Formal Definition
+pub trait LweSeededBootstrapKeyExpansionEngine<Input, Output>: AbstractEngine
+where
+    Input: LweSeededBootstrapKeyEntity,
+    Output: LweBootstrapKeyEntity<
+        InputKeyDistribution = Input::InputKeyDistribution,
+        OutputKeyDistribution = Input::OutputKeyDistribution,
+    >,
+{
+    /// Expands a seeded LWE bootstrap key.
+    fn expand_lwe_seeded_bootstrap_key(
+        &mut self,
+        input: &Input,
+    ) -> Result<Output, LweSeededBootstrapKeyExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands a seeded LWE bootstrap key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededBootstrapKeyExpansionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn expand_lwe_seeded_bootstrap_key_unchecked(&mut self, input: &Input) -> Output;
+}
@@ -0,0 +1,98 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweSecretKeyEntity, LweShrinkingKeyswitchKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+engine_error! {
+    LweShrinkingKeyswitchKeyCreationError for LweShrinkingKeyswitchKeyCreationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext.",
+    OutputLweDimensionTooLarge => "The output LWE dimension must be smaller than the input LWE \
+                                   dimension."
+}
+
+impl<EngineError: std::error::Error> LweShrinkingKeyswitchKeyCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputSecretKey, OutputSecretKey>(
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        InputSecretKey: LweSecretKeyEntity,
+        OutputSecretKey: LweSecretKeyEntity<KeyDistribution = InputSecretKey::KeyDistribution>,
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+
+        if decomposition_level_count.0 * decomposition_base_log.0 > integer_precision {
+            return Err(Self::DecompositionTooLarge);
+        }
+
+        if output_key.lwe_dimension().0 >= input_key.lwe_dimension().0 {
+            return Err(Self::OutputLweDimensionTooLarge);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines creating LWE shrinking keyswitch keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates an LWE shrinking keyswitch key
+/// allowing to switch from the `input_key` LWE secret key to the `output_key` LWE secret key,
+/// where the `output_key` is expected to be made of the first coefficients of the `input_key`
+/// (for instance, as returned by
+/// [`LweSecretKeyShrinkingEngine`](super::LweSecretKeyShrinkingEngine)). Only the coefficients of
+/// the `input_key` which are not shared with the `output_key` are encrypted in the key.
+///
+/// # Formal Definition
+///
+/// Let $n\_{in}$ and $n\_{out}$ be the dimensions of the input and output keys, such that
+/// $s\_{out,i} = s\_{in,i}$ for $i < n\_{out}$. For every $n\_{out} \leq i < n\_{in}$ and every
+/// level $j \in \[1, l\]$, the key contains an LWE encryption of $s\_{in,i} \cdot q / B^j$ under
+/// the output key.
+pub trait LweShrinkingKeyswitchKeyCreationEngine<InputSecretKey, OutputSecretKey, KeyswitchKey>:
+    AbstractEngine
+where
+    InputSecretKey: LweSecretKeyEntity,
+    OutputSecretKey: LweSecretKeyEntity<KeyDistribution = InputSecretKey::KeyDistribution>,
+    KeyswitchKey: LweShrinkingKeyswitchKeyEntity<KeyDistribution = InputSecretKey::KeyDistribution>,
+{
+    /// Creates an LWE shrinking keyswitch key.
+    fn create_lwe_shrinking_keyswitch_key(
+        &mut self,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<KeyswitchKey, LweShrinkingKeyswitchKeyCreationError<Self::EngineError>>;
+
+    /// Unsafely creates an LWE shrinking keyswitch key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweShrinkingKeyswitchKeyCreationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn create_lwe_shrinking_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> KeyswitchKey;
+}
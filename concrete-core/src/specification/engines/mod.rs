@@ -39,6 +39,9 @@
 //! This design makes it possible for each operation, to match the error exhaustively against both
 //! general error variants, and backend-related error variants.
 //!
+//! Every error variant is also attached a stable numeric code, accessible through the
+//! [`EngineErrorCode`] trait, and listed in the [`ERROR_CODES`] table.
+//!
 //! # Operation semantics
 //!
 //! For each possible operation, we try to support the three following semantics:
@@ -83,58 +86,73 @@ pub trait AbstractEngine: sealed::AbstractEngineSeal {
         Self: Sized;
 }
 
-macro_rules! engine_error {
-    ($name:ident for $trait:ident @) => {
-        #[doc=concat!("An error used with the [`", stringify!($trait), "`] trait.")]
-        #[non_exhaustive]
-        #[derive(Debug, Clone, Eq, PartialEq)]
-        pub enum $name<EngineError: std::error::Error> {
-            #[doc="_Specific_ error to the implementing engine."]
-            Engine(EngineError),
-        }
-        impl<EngineError: std::error::Error> std::fmt::Display for $name<EngineError>{
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                match self {
-                    Self::Engine(error) => write!(f, "Error occurred in the engine: {}", error),
+// The macro is declared in its own module, such that it is only reachable through imports, and
+// not through the textual scope of the modules declared below.
+mod macros {
+    // Every error declared with this macro must be given codes in the `error_codes` module, which
+    // is checked at compile time.
+    macro_rules! engine_error {
+        ($name:ident for $trait:ident @) => {
+            #[doc=concat!("An error used with the [`", stringify!($trait), "`] trait.")]
+            #[non_exhaustive]
+            #[derive(Debug, Clone, Eq, PartialEq)]
+            pub enum $name<EngineError: std::error::Error> {
+                #[doc="_Specific_ error to the implementing engine."]
+                Engine(EngineError),
+            }
+            impl<EngineError: std::error::Error> std::fmt::Display for $name<EngineError>{
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        Self::Engine(error) => write!(f, "Error occurred in the engine: {}", error),
+                    }
                 }
             }
-        }
-        impl<EngineError: std::error::Error> std::error::Error for $name<EngineError>{}
-    };
-    ($name:ident for $trait:ident @ $($variants:ident => $messages:literal),*) => {
-        #[doc=concat!("An error used with the [`", stringify!($trait), "`] trait.")]
-        #[doc=""]
-        #[doc="This type provides a "]
-        #[doc=concat!("[`", stringify!($name), "::perform_generic_checks`] ")]
-        #[doc="function that does error checking for the general cases, returning an `Ok(())` "]
-        #[doc="if the inputs are valid, meaning that engine implementors would then only "]
-        #[doc="need to check for their own specific errors."]
-        #[doc="Otherwise an `Err(..)` with the proper error variant is returned."]
-        #[non_exhaustive]
-        #[derive(Debug, Clone, Eq, PartialEq)]
-        pub enum $name<EngineError: std::error::Error> {
-            $(
-                #[doc="_Generic_ error: "]
-                #[doc=$messages]
-                $variants,
-            )*
-            #[doc="_Specific_ error to the implementing engine."]
-            Engine(EngineError),
-        }
-        impl<EngineError: std::error::Error> std::fmt::Display for $name<EngineError>{
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                match self {
-                    $(
-                        Self::$variants => write!(f, $messages),
-                    )*
-                    Self::Engine(error) => write!(f, "Error occurred in the engine: {}", error),
+            impl<EngineError: std::error::Error> std::error::Error for $name<EngineError>{}
+            const _: fn() = || {
+                fn has_error_code<Error: $crate::specification::engines::EngineErrorCode>() {}
+                has_error_code::<$name<std::convert::Infallible>>();
+            };
+        };
+        ($name:ident for $trait:ident @ $($variants:ident => $messages:literal),*) => {
+            #[doc=concat!("An error used with the [`", stringify!($trait), "`] trait.")]
+            #[doc=""]
+            #[doc="This type provides a "]
+            #[doc=concat!("[`", stringify!($name), "::perform_generic_checks`] ")]
+            #[doc="function that does error checking for the general cases, returning an `Ok(())` "]
+            #[doc="if the inputs are valid, meaning that engine implementors would then only "]
+            #[doc="need to check for their own specific errors."]
+            #[doc="Otherwise an `Err(..)` with the proper error variant is returned."]
+            #[non_exhaustive]
+            #[derive(Debug, Clone, Eq, PartialEq)]
+            pub enum $name<EngineError: std::error::Error> {
+                $(
+                    #[doc="_Generic_ error: "]
+                    #[doc=$messages]
+                    $variants,
+                )*
+                #[doc="_Specific_ error to the implementing engine."]
+                Engine(EngineError),
+            }
+            impl<EngineError: std::error::Error> std::fmt::Display for $name<EngineError>{
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        $(
+                            Self::$variants => write!(f, $messages),
+                        )*
+                        Self::Engine(error) => write!(f, "Error occurred in the engine: {}", error),
+                    }
                 }
             }
-        }
-        impl<EngineError: std::error::Error> std::error::Error for $name<EngineError>{}
-    };
+            impl<EngineError: std::error::Error> std::error::Error for $name<EngineError>{}
+            const _: fn() = || {
+                fn has_error_code<Error: $crate::specification::engines::EngineErrorCode>() {}
+                has_error_code::<$name<std::convert::Infallible>>();
+            };
+        };
+    }
+    pub(crate) use engine_error;
 }
-pub(crate) use engine_error;
+pub(crate) use macros::engine_error;
 
 mod cleartext_conversion;
 mod cleartext_creation;
@@ -147,21 +165,41 @@ mod cleartext_vector_creation;
 mod cleartext_vector_discarding_conversion;
 mod cleartext_vector_discarding_retrieval;
 mod cleartext_vector_encoding;
+mod cleartext_vector_negacyclic_convolution;
 mod cleartext_vector_retrieval;
 mod destruction;
+mod entity_constant_time_equality;
+mod entity_deserialization;
+mod entity_serialization;
+mod error_codes;
+mod ggsw_ciphertext_cleartext_trivial_encryption;
+mod ggsw_ciphertext_consuming_retrieval;
 mod ggsw_ciphertext_conversion;
 mod ggsw_ciphertext_discarding_conversion;
 mod ggsw_ciphertext_scalar_discarding_encryption;
 mod ggsw_ciphertext_scalar_encryption;
 mod ggsw_ciphertext_scalar_trivial_encryption;
+mod ggsw_seeded_ciphertext_expansion;
+mod ggsw_seeded_ciphertext_scalar_encryption;
+mod glwe_automorphism_key_creation;
+mod glwe_ciphertext_blind_rotation;
+mod glwe_ciphertext_cleartext_vector_discarding_multiplication;
 mod glwe_ciphertext_conversion;
 mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_discarding_addition;
 mod glwe_ciphertext_discarding_conversion;
 mod glwe_ciphertext_discarding_decryption;
 mod glwe_ciphertext_discarding_encryption;
+mod glwe_ciphertext_discarding_offset_addition;
+mod glwe_ciphertext_discarding_relinearization;
+mod glwe_ciphertext_discarding_tensor_product;
+mod glwe_ciphertext_discarding_trivial_encryption;
 mod glwe_ciphertext_encryption;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
+mod glwe_ciphertext_layout_consuming_retrieval;
+mod glwe_ciphertext_layout_creation;
+mod glwe_ciphertext_trace;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
 mod glwe_ciphertext_vector_conversion;
@@ -170,71 +208,126 @@ mod glwe_ciphertext_vector_discarding_conversion;
 mod glwe_ciphertext_vector_discarding_decryption;
 mod glwe_ciphertext_vector_discarding_encryption;
 mod glwe_ciphertext_vector_encryption;
+mod glwe_ciphertext_vector_layout_conversion;
 mod glwe_ciphertext_vector_trivial_decryption;
 mod glwe_ciphertext_vector_trivial_encryption;
 mod glwe_ciphertext_vector_zero_encryption;
 mod glwe_ciphertext_zero_encryption;
+mod glwe_relinearization_key_creation;
 mod glwe_secret_key_conversion;
 mod glwe_secret_key_creation;
 mod glwe_secret_key_discarding_conversion;
 mod glwe_secret_key_to_lwe_secret_key_transmutation;
+mod implementations;
+mod lwe_bootstrap_key_construction;
+mod lwe_bootstrap_key_consuming_retrieval;
 mod lwe_bootstrap_key_conversion;
 mod lwe_bootstrap_key_creation;
 mod lwe_bootstrap_key_discarding_conversion;
+mod lwe_bootstrap_key_upload;
+mod lwe_ciphertext_accumulation;
 mod lwe_ciphertext_cleartext_discarding_multiplication;
+mod lwe_ciphertext_cleartext_discarding_rational_multiplication;
 mod lwe_ciphertext_cleartext_fusing_multiplication;
+mod lwe_ciphertext_cleartext_fusing_multiply_add;
 mod lwe_ciphertext_conversion;
 mod lwe_ciphertext_decryption;
 mod lwe_ciphertext_discarding_addition;
+mod lwe_ciphertext_discarding_batched_keyswitch;
 mod lwe_ciphertext_discarding_bootstrap;
 mod lwe_ciphertext_discarding_conversion;
 mod lwe_ciphertext_discarding_decryption;
 mod lwe_ciphertext_discarding_encryption;
 mod lwe_ciphertext_discarding_extraction;
 mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_discarding_large_table_lookup;
 mod lwe_ciphertext_discarding_loading;
+mod lwe_ciphertext_discarding_modulus_switch;
+mod lwe_ciphertext_discarding_multiplication;
 mod lwe_ciphertext_discarding_opposite;
+mod lwe_ciphertext_discarding_shrinking_keyswitch;
 mod lwe_ciphertext_discarding_storing;
 mod lwe_ciphertext_discarding_subtraction;
+mod lwe_ciphertext_discarding_trivial_encryption;
 mod lwe_ciphertext_encryption;
 mod lwe_ciphertext_fusing_addition;
+mod lwe_ciphertext_fusing_noise_injection;
 mod lwe_ciphertext_fusing_opposite;
 mod lwe_ciphertext_fusing_subtraction;
+mod lwe_ciphertext_gadget_product;
+mod lwe_ciphertext_jagged_vector_creation;
+mod lwe_ciphertext_jagged_vector_discarding_row_summation;
+mod lwe_ciphertext_jagged_vector_fusing_addition;
 mod lwe_ciphertext_loading;
 mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_discarding_subtraction;
 mod lwe_ciphertext_plaintext_fusing_addition;
 mod lwe_ciphertext_plaintext_fusing_subtraction;
+mod lwe_ciphertext_public_key_encryption;
 mod lwe_ciphertext_trivial_decryption;
 mod lwe_ciphertext_trivial_encryption;
+mod lwe_ciphertext_truncated_view_creation;
+mod lwe_ciphertext_vector_consuming_retrieval;
 mod lwe_ciphertext_vector_conversion;
+mod lwe_ciphertext_vector_creation;
 mod lwe_ciphertext_vector_decryption;
 mod lwe_ciphertext_vector_discarding_addition;
 mod lwe_ciphertext_vector_discarding_affine_transformation;
 mod lwe_ciphertext_vector_discarding_bootstrap;
+mod lwe_ciphertext_vector_discarding_budgeted_bootstrap;
+mod lwe_ciphertext_vector_discarding_cmux;
 mod lwe_ciphertext_vector_discarding_conversion;
 mod lwe_ciphertext_vector_discarding_decryption;
 mod lwe_ciphertext_vector_discarding_encryption;
+mod lwe_ciphertext_vector_discarding_extremum;
+mod lwe_ciphertext_vector_discarding_gather;
 mod lwe_ciphertext_vector_discarding_keyswitch;
 mod lwe_ciphertext_vector_discarding_loading;
 mod lwe_ciphertext_vector_discarding_opposite;
+mod lwe_ciphertext_vector_discarding_parity;
+mod lwe_ciphertext_vector_discarding_permutation;
+mod lwe_ciphertext_vector_discarding_popcount;
+mod lwe_ciphertext_vector_discarding_scatter;
 mod lwe_ciphertext_vector_discarding_subtraction;
 mod lwe_ciphertext_vector_encryption;
 mod lwe_ciphertext_vector_fusing_addition;
 mod lwe_ciphertext_vector_fusing_opposite;
 mod lwe_ciphertext_vector_fusing_subtraction;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
+mod lwe_ciphertext_vector_layout_consuming_retrieval;
+mod lwe_ciphertext_vector_layout_conversion;
+mod lwe_ciphertext_vector_layout_creation;
 mod lwe_ciphertext_vector_loading;
+mod lwe_ciphertext_vector_noise_statistics;
+mod lwe_ciphertext_vector_phase_exporting_decryption;
+mod lwe_ciphertext_vector_plaintext_vector_discarding_subtraction;
+mod lwe_ciphertext_vector_plaintext_vector_fusing_subtraction;
 mod lwe_ciphertext_vector_trivial_decryption;
 mod lwe_ciphertext_vector_trivial_encryption;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_ciphertext_zero_encryption;
+mod lwe_keyswitch_key_batch_appending;
+mod lwe_keyswitch_key_batch_creation;
+mod lwe_keyswitch_key_batch_removal;
 mod lwe_keyswitch_key_conversion;
 mod lwe_keyswitch_key_creation;
+mod lwe_keyswitch_key_delta_creation;
 mod lwe_keyswitch_key_discarding_conversion;
+mod lwe_keyswitch_key_fusing_update;
+mod lwe_keyswitch_key_upload;
+mod lwe_phase_vector_retrieval;
+mod lwe_public_key_creation;
 mod lwe_secret_key_conversion;
 mod lwe_secret_key_creation;
+mod lwe_secret_key_derivation;
 mod lwe_secret_key_discarding_conversion;
+mod lwe_secret_key_shrinking;
+mod lwe_secret_key_to_glwe_secret_key_transmutation;
+mod lwe_seeded_bootstrap_key_creation;
+mod lwe_seeded_bootstrap_key_expansion;
+mod lwe_seeded_ciphertext_encryption;
+mod lwe_seeded_ciphertext_expansion;
+mod lwe_shrinking_keyswitch_key_creation;
 mod packing_keyswitch_key_creation;
 mod plaintext_conversion;
 mod plaintext_creation;
@@ -260,21 +353,41 @@ pub use cleartext_vector_creation::*;
 pub use cleartext_vector_discarding_conversion::*;
 pub use cleartext_vector_discarding_retrieval::*;
 pub use cleartext_vector_encoding::*;
+pub use cleartext_vector_negacyclic_convolution::*;
 pub use cleartext_vector_retrieval::*;
 pub use destruction::*;
+pub use entity_constant_time_equality::*;
+pub use entity_deserialization::*;
+pub use entity_serialization::*;
+pub use error_codes::*;
+pub use ggsw_ciphertext_cleartext_trivial_encryption::*;
+pub use ggsw_ciphertext_consuming_retrieval::*;
 pub use ggsw_ciphertext_conversion::*;
 pub use ggsw_ciphertext_discarding_conversion::*;
 pub use ggsw_ciphertext_scalar_discarding_encryption::*;
 pub use ggsw_ciphertext_scalar_encryption::*;
 pub use ggsw_ciphertext_scalar_trivial_encryption::*;
+pub use ggsw_seeded_ciphertext_expansion::*;
+pub use ggsw_seeded_ciphertext_scalar_encryption::*;
+pub use glwe_automorphism_key_creation::*;
+pub use glwe_ciphertext_blind_rotation::*;
+pub use glwe_ciphertext_cleartext_vector_discarding_multiplication::*;
 pub use glwe_ciphertext_conversion::*;
 pub use glwe_ciphertext_decryption::*;
+pub use glwe_ciphertext_discarding_addition::*;
 pub use glwe_ciphertext_discarding_conversion::*;
 pub use glwe_ciphertext_discarding_decryption::*;
 pub use glwe_ciphertext_discarding_encryption::*;
+pub use glwe_ciphertext_discarding_offset_addition::*;
+pub use glwe_ciphertext_discarding_relinearization::*;
+pub use glwe_ciphertext_discarding_tensor_product::*;
+pub use glwe_ciphertext_discarding_trivial_encryption::*;
 pub use glwe_ciphertext_encryption::*;
 pub use glwe_ciphertext_ggsw_ciphertext_discarding_external_product::*;
 pub use glwe_ciphertext_ggsw_ciphertext_external_product::*;
+pub use glwe_ciphertext_layout_consuming_retrieval::*;
+pub use glwe_ciphertext_layout_creation::*;
+pub use glwe_ciphertext_trace::*;
 pub use glwe_ciphertext_trivial_decryption::*;
 pub use glwe_ciphertext_trivial_encryption::*;
 pub use glwe_ciphertext_vector_conversion::*;
@@ -283,71 +396,127 @@ pub use glwe_ciphertext_vector_discarding_conversion::*;
 pub use glwe_ciphertext_vector_discarding_decryption::*;
 pub use glwe_ciphertext_vector_discarding_encryption::*;
 pub use glwe_ciphertext_vector_encryption::*;
+pub use glwe_ciphertext_vector_layout_conversion::*;
 pub use glwe_ciphertext_vector_trivial_decryption::*;
 pub use glwe_ciphertext_vector_trivial_encryption::*;
 pub use glwe_ciphertext_vector_zero_encryption::*;
 pub use glwe_ciphertext_zero_encryption::*;
+pub use glwe_relinearization_key_creation::*;
 pub use glwe_secret_key_conversion::*;
 pub use glwe_secret_key_creation::*;
 pub use glwe_secret_key_discarding_conversion::*;
 pub use glwe_secret_key_to_lwe_secret_key_transmutation::*;
+pub(crate) use implementations::engine_implementations;
+pub use implementations::*;
+pub use lwe_bootstrap_key_construction::*;
+pub use lwe_bootstrap_key_consuming_retrieval::*;
 pub use lwe_bootstrap_key_conversion::*;
 pub use lwe_bootstrap_key_creation::*;
 pub use lwe_bootstrap_key_discarding_conversion::*;
+pub use lwe_bootstrap_key_upload::*;
+pub use lwe_ciphertext_accumulation::*;
 pub use lwe_ciphertext_cleartext_discarding_multiplication::*;
+pub use lwe_ciphertext_cleartext_discarding_rational_multiplication::*;
 pub use lwe_ciphertext_cleartext_fusing_multiplication::*;
+pub use lwe_ciphertext_cleartext_fusing_multiply_add::*;
 pub use lwe_ciphertext_conversion::*;
 pub use lwe_ciphertext_decryption::*;
 pub use lwe_ciphertext_discarding_addition::*;
+pub use lwe_ciphertext_discarding_batched_keyswitch::*;
 pub use lwe_ciphertext_discarding_bootstrap::*;
 pub use lwe_ciphertext_discarding_conversion::*;
 pub use lwe_ciphertext_discarding_decryption::*;
 pub use lwe_ciphertext_discarding_encryption::*;
 pub use lwe_ciphertext_discarding_extraction::*;
 pub use lwe_ciphertext_discarding_keyswitch::*;
+pub use lwe_ciphertext_discarding_large_table_lookup::*;
 pub use lwe_ciphertext_discarding_loading::*;
+pub use lwe_ciphertext_discarding_modulus_switch::*;
+pub use lwe_ciphertext_discarding_multiplication::*;
 pub use lwe_ciphertext_discarding_opposite::*;
+pub use lwe_ciphertext_discarding_shrinking_keyswitch::*;
 pub use lwe_ciphertext_discarding_storing::*;
 pub use lwe_ciphertext_discarding_subtraction::*;
+pub use lwe_ciphertext_discarding_trivial_encryption::*;
 pub use lwe_ciphertext_encryption::*;
 pub use lwe_ciphertext_fusing_addition::*;
+pub use lwe_ciphertext_fusing_noise_injection::*;
 pub use lwe_ciphertext_fusing_opposite::*;
 pub use lwe_ciphertext_fusing_subtraction::*;
+pub use lwe_ciphertext_gadget_product::*;
+pub use lwe_ciphertext_jagged_vector_creation::*;
+pub use lwe_ciphertext_jagged_vector_discarding_row_summation::*;
+pub use lwe_ciphertext_jagged_vector_fusing_addition::*;
 pub use lwe_ciphertext_loading::*;
 pub use lwe_ciphertext_plaintext_discarding_addition::*;
 pub use lwe_ciphertext_plaintext_discarding_subtraction::*;
 pub use lwe_ciphertext_plaintext_fusing_addition::*;
 pub use lwe_ciphertext_plaintext_fusing_subtraction::*;
+pub use lwe_ciphertext_public_key_encryption::*;
 pub use lwe_ciphertext_trivial_decryption::*;
 pub use lwe_ciphertext_trivial_encryption::*;
+pub use lwe_ciphertext_truncated_view_creation::*;
+pub use lwe_ciphertext_vector_consuming_retrieval::*;
 pub use lwe_ciphertext_vector_conversion::*;
+pub use lwe_ciphertext_vector_creation::*;
 pub use lwe_ciphertext_vector_decryption::*;
 pub use lwe_ciphertext_vector_discarding_addition::*;
 pub use lwe_ciphertext_vector_discarding_affine_transformation::*;
 pub use lwe_ciphertext_vector_discarding_bootstrap::*;
+pub use lwe_ciphertext_vector_discarding_budgeted_bootstrap::*;
+pub use lwe_ciphertext_vector_discarding_cmux::*;
 pub use lwe_ciphertext_vector_discarding_conversion::*;
 pub use lwe_ciphertext_vector_discarding_decryption::*;
 pub use lwe_ciphertext_vector_discarding_encryption::*;
+pub use lwe_ciphertext_vector_discarding_extremum::*;
+pub use lwe_ciphertext_vector_discarding_gather::*;
 pub use lwe_ciphertext_vector_discarding_keyswitch::*;
 pub use lwe_ciphertext_vector_discarding_loading::*;
 pub use lwe_ciphertext_vector_discarding_opposite::*;
+pub use lwe_ciphertext_vector_discarding_parity::*;
+pub use lwe_ciphertext_vector_discarding_permutation::*;
+pub use lwe_ciphertext_vector_discarding_popcount::*;
+pub use lwe_ciphertext_vector_discarding_scatter::*;
 pub use lwe_ciphertext_vector_discarding_subtraction::*;
 pub use lwe_ciphertext_vector_encryption::*;
 pub use lwe_ciphertext_vector_fusing_addition::*;
 pub use lwe_ciphertext_vector_fusing_opposite::*;
 pub use lwe_ciphertext_vector_fusing_subtraction::*;
 pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch::*;
+pub use lwe_ciphertext_vector_layout_consuming_retrieval::*;
+pub use lwe_ciphertext_vector_layout_conversion::*;
+pub use lwe_ciphertext_vector_layout_creation::*;
 pub use lwe_ciphertext_vector_loading::*;
+pub use lwe_ciphertext_vector_noise_statistics::*;
+pub use lwe_ciphertext_vector_phase_exporting_decryption::*;
+pub use lwe_ciphertext_vector_plaintext_vector_discarding_subtraction::*;
+pub use lwe_ciphertext_vector_plaintext_vector_fusing_subtraction::*;
 pub use lwe_ciphertext_vector_trivial_decryption::*;
 pub use lwe_ciphertext_vector_trivial_encryption::*;
 pub use lwe_ciphertext_vector_zero_encryption::*;
 pub use lwe_ciphertext_zero_encryption::*;
+pub use lwe_keyswitch_key_batch_appending::*;
+pub use lwe_keyswitch_key_batch_creation::*;
+pub use lwe_keyswitch_key_batch_removal::*;
 pub use lwe_keyswitch_key_conversion::*;
 pub use lwe_keyswitch_key_creation::*;
+pub use lwe_keyswitch_key_delta_creation::*;
 pub use lwe_keyswitch_key_discarding_conversion::*;
+pub use lwe_keyswitch_key_fusing_update::*;
+pub use lwe_keyswitch_key_upload::*;
+pub use lwe_phase_vector_retrieval::*;
+pub use lwe_public_key_creation::*;
 pub use lwe_secret_key_conversion::*;
 pub use lwe_secret_key_creation::*;
+pub use lwe_secret_key_derivation::*;
 pub use lwe_secret_key_discarding_conversion::*;
+pub use lwe_secret_key_shrinking::*;
+pub use lwe_secret_key_to_glwe_secret_key_transmutation::*;
+pub use lwe_seeded_bootstrap_key_creation::*;
+pub use lwe_seeded_bootstrap_key_expansion::*;
+pub use lwe_seeded_ciphertext_encryption::*;
+pub use lwe_seeded_ciphertext_expansion::*;
+pub use lwe_shrinking_keyswitch_key_creation::*;
 pub use packing_keyswitch_key_creation::*;
 pub use plaintext_conversion::*;
 pub use plaintext_creation::*;
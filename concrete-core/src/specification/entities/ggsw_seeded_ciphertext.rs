@@ -0,0 +1,37 @@
+use crate::specification::entities::markers::{GgswSeededCiphertextKind, KeyDistributionMarker};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A trait implemented by types embodying a seeded GGSW ciphertext.
+///
+/// A seeded GGSW ciphertext only stores the bodies of the GLWE ciphertexts it is made of, along
+/// with the seed of the generator producing their masks. It must be expanded to a
+/// [`GgswCiphertextEntity`](`super::GgswCiphertextEntity`) before being used in computations.
+///
+/// A seeded GGSW ciphertext is associated with a
+/// [`KeyDistribution`](`GgswSeededCiphertextEntity::KeyDistribution`) type, which conveys the
+/// distribution of the secret key it was encrypted with.
+///
+/// # Formal Definition
+pub trait GgswSeededCiphertextEntity: AbstractEntity<Kind = GgswSeededCiphertextKind> {
+    /// The distribution of the key the ciphertext was encrypted with.
+    type KeyDistribution: KeyDistributionMarker;
+
+    /// Returns the GLWE dimension of the ciphertext.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the ciphertext.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the ciphertext.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the ciphertext.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+
+    /// Returns the ratio between the size of the expanded ciphertext and the size of the seeded
+    /// ciphertext.
+    fn compression_ratio(&self) -> f64;
+}
@@ -0,0 +1,32 @@
+use crate::specification::entities::markers::{GlweAutomorphismKeyKind, KeyDistributionMarker};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A trait implemented by types embodying a GLWE automorphism key.
+///
+/// A GLWE automorphism key contains, for every automorphism $\tau\_g: P(X) \mapsto P(X^g)$ needed
+/// to evaluate the trace of a GLWE ciphertext, a keyswitching key from the automorphed secret key
+/// $\tau\_g(S)$ back to the secret key $S$.
+///
+/// A GLWE automorphism key is associated with a single [`KeyDistributionMarker`] type, which
+/// conveys the distribution of the secret key it was generated from.
+///
+/// # Formal Definition
+pub trait GlweAutomorphismKeyEntity: AbstractEntity<Kind = GlweAutomorphismKeyKind> {
+    /// The distribution of the key the ciphertexts are encrypted with.
+    type KeyDistribution: KeyDistributionMarker;
+
+    /// Returns the GLWE dimension of the key.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the key.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+}
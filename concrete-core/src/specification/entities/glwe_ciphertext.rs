@@ -1,4 +1,6 @@
-use crate::specification::entities::markers::{GlweCiphertextKind, KeyDistributionMarker};
+use crate::specification::entities::markers::{
+    GlweCiphertextKind, KeyDistributionMarker, RingStructureMarker,
+};
 use crate::specification::entities::AbstractEntity;
 use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 
@@ -6,7 +8,9 @@ use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 ///
 /// A GLWE ciphertext is associated with a
 /// [`KeyDistribution`](`GlweCiphertextEntity::KeyDistribution`) type, which conveys the
-/// distribution of the secret key it was encrypted with.
+/// distribution of the secret key it was encrypted with, and with a
+/// [`RingStructure`](`GlweCiphertextEntity::RingStructure`) type, which conveys the polynomial ring
+/// its polynomials belong to.
 ///
 /// # Formal Definition
 ///
@@ -16,6 +20,9 @@ pub trait GlweCiphertextEntity: AbstractEntity<Kind = GlweCiphertextKind> {
     /// The distribution of the key the ciphertext was encrypted with.
     type KeyDistribution: KeyDistributionMarker;
 
+    /// The structure of the ring the polynomials of the ciphertext belong to.
+    type RingStructure: RingStructureMarker;
+
     /// Returns the GLWE dimension of the ciphertext.
     fn glwe_dimension(&self) -> GlweDimension;
 
@@ -0,0 +1,32 @@
+use crate::specification::entities::markers::{GlweRelinearizationKeyKind, KeyDistributionMarker};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A trait implemented by types embodying a GLWE relinearization key.
+///
+/// A GLWE relinearization key contains the encryptions, under the secret key $S$, of the
+/// decompositions of the quadratic polynomials $S\_i S\_j$ of the key. It allows to turn the
+/// tensor product of two GLWE ciphertexts back into a GLWE ciphertext under $S$.
+///
+/// A GLWE relinearization key is associated with a single [`KeyDistributionMarker`] type, which
+/// conveys the distribution of the secret key it was generated from.
+///
+/// # Formal Definition
+pub trait GlweRelinearizationKeyEntity: AbstractEntity<Kind = GlweRelinearizationKeyKind> {
+    /// The distribution of the key the ciphertexts are encrypted with.
+    type KeyDistribution: KeyDistributionMarker;
+
+    /// Returns the GLWE dimension of the key.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the key.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+}
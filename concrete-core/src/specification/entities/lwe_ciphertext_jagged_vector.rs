@@ -0,0 +1,39 @@
+use crate::specification::entities::markers::{
+    KeyDistributionMarker, LweCiphertextJaggedVectorKind,
+};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+
+/// A trait implemented by types embodying a jagged vector of LWE ciphertexts.
+///
+/// A jagged vector is a vector of rows of LWE ciphertexts, where each row may contain a different
+/// number of ciphertexts, possibly zero. It allows to batch records of variable length without
+/// padding them to the length of the longest one.
+///
+/// An LWE ciphertext jagged vector is associated with a
+/// [`KeyDistribution`](`LweCiphertextJaggedVectorEntity::KeyDistribution`) type, which conveys the
+/// distribution of the secret key it was encrypted with.
+///
+/// # Formal Definition
+pub trait LweCiphertextJaggedVectorEntity:
+    AbstractEntity<Kind = LweCiphertextJaggedVectorKind>
+{
+    /// The distribution of key the ciphertexts were encrypted with.
+    type KeyDistribution: KeyDistributionMarker;
+
+    /// Returns the LWE dimension of the ciphertexts.
+    fn lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the number of ciphertexts contained in all the rows.
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount;
+
+    /// Returns the number of rows.
+    fn row_count(&self) -> usize;
+
+    /// Returns the number of ciphertexts contained in the `row`-th row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is not smaller than the number of rows.
+    fn row_lwe_ciphertext_count(&self, row: usize) -> LweCiphertextCount;
+}
@@ -0,0 +1,42 @@
+use crate::specification::entities::markers::{KeyDistributionMarker, LweKeyswitchKeyBatchKind};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweKeyswitchKeyCount,
+};
+
+/// A trait implemented by types embodying a batch of LWE keyswitch keys.
+///
+/// The keys of a batch switch from the same input secret key to different output secret keys, for
+/// instance the keys of the different clients of a server, and share their parameters. They are
+/// stored contiguously and are selected by their index.
+///
+/// An LWE keyswitch key batch is associated with two [`KeyDistributionMarker`] types:
+///
+/// + The [`InputKeyDistribution`](`LweKeyswitchKeyBatchEntity::InputKeyDistribution`) type conveys
+/// the distribution of the input secret key.
+/// + The [`OutputKeyDistribution`](`LweKeyswitchKeyBatchEntity::OutputKeyDistribution`) type
+/// conveys the distribution of the output secret keys.
+///
+/// # Formal Definition
+pub trait LweKeyswitchKeyBatchEntity: AbstractEntity<Kind = LweKeyswitchKeyBatchKind> {
+    /// The distribution of the key the input ciphertexts are encrypted with.
+    type InputKeyDistribution: KeyDistributionMarker;
+
+    /// The distribution of the keys the output ciphertexts are encrypted with.
+    type OutputKeyDistribution: KeyDistributionMarker;
+
+    /// Returns the input LWE dimension of the keys.
+    fn input_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the output LWE dimension of the keys.
+    fn output_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the number of decomposition levels of the keys.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the keys.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+
+    /// Returns the number of keys in the batch.
+    fn key_count(&self) -> LweKeyswitchKeyCount;
+}
@@ -0,0 +1,39 @@
+use crate::specification::entities::markers::{KeyDistributionMarker, LweKeyswitchKeyDeltaKind};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+/// A trait implemented by types embodying the delta updating an LWE keyswitch key to a new output
+/// secret key.
+///
+/// When only a part of the coefficients of the output secret key change, the masks of the
+/// ciphertexts of a keyswitch key can be kept, and only their bodies need to be corrected. The
+/// delta holds these corrections, one per ciphertext of the key, and is much cheaper to generate
+/// and to transfer than a whole new key.
+///
+/// An LWE keyswitch key delta is associated with two [`KeyDistributionMarker`] types:
+///
+/// + The [`InputKeyDistribution`](`LweKeyswitchKeyDeltaEntity::InputKeyDistribution`) type conveys
+/// the distribution of the input secret key of the updated keyswitch key.
+/// + The [`OutputKeyDistribution`](`LweKeyswitchKeyDeltaEntity::OutputKeyDistribution`) type
+/// conveys the distribution of the output secret keys.
+///
+/// # Formal Definition
+pub trait LweKeyswitchKeyDeltaEntity: AbstractEntity<Kind = LweKeyswitchKeyDeltaKind> {
+    /// The distribution of the input key of the updated keyswitch key.
+    type InputKeyDistribution: KeyDistributionMarker;
+
+    /// The distribution of the output keys of the updated keyswitch key.
+    type OutputKeyDistribution: KeyDistributionMarker;
+
+    /// Returns the input LWE dimension of the updated keyswitch key.
+    fn input_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the output LWE dimension of the updated keyswitch key.
+    fn output_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the number of decomposition levels of the updated keyswitch key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the updated keyswitch key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+}
@@ -0,0 +1,16 @@
+use crate::specification::entities::markers::LwePhaseVectorKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::LweCiphertextCount;
+
+/// A trait implemented by types embodying a vector of LWE phases.
+///
+/// The phase of an LWE ciphertext $(\vec{a}, b)$ under the secret key $\vec{s}$ is the value
+/// $b - \left\langle \vec{a}, \vec{s} \right\rangle$, that is the encoded message along with its
+/// noise. Since the noise of a ciphertext gives information on its secret key, a phase vector must
+/// be handled with the same care as a secret key.
+///
+/// # Formal Definition
+pub trait LwePhaseVectorEntity: AbstractEntity<Kind = LwePhaseVectorKind> {
+    /// Returns the number of ciphertexts the phases were computed from.
+    fn phase_count(&self) -> LweCiphertextCount;
+}
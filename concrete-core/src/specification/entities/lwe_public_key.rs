@@ -0,0 +1,25 @@
+use crate::specification::entities::markers::{KeyDistributionMarker, LwePublicKeyKind};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+
+/// A trait implemented by types embodying an LWE public key.
+///
+/// An LWE public key is a list of LWE encryptions of zero under a secret key. Anyone holding it
+/// can encrypt messages under the secret key, by summing a random subset of these encryptions and
+/// adding the message to the result, but the secret key is still needed to decrypt.
+///
+/// An LWE public key is associated with a
+/// [`KeyDistribution`](`LwePublicKeyEntity::KeyDistribution`) type, which conveys the distribution
+/// of the secret key it was generated from.
+///
+/// # Formal Definition
+pub trait LwePublicKeyEntity: AbstractEntity<Kind = LwePublicKeyKind> {
+    /// The distribution of the secret key the public key was generated from.
+    type KeyDistribution: KeyDistributionMarker;
+
+    /// Returns the LWE dimension of the key.
+    fn lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the number of encryptions of zero contained in the key.
+    fn lwe_zero_encryption_count(&self) -> LwePublicKeyZeroEncryptionCount;
+}
@@ -0,0 +1,26 @@
+use crate::specification::entities::markers::{KeyDistributionMarker, LweSeededCiphertextKind};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::LweDimension;
+
+/// A trait implemented by types embodying a seeded LWE ciphertext.
+///
+/// A seeded LWE ciphertext only stores the body of the ciphertext, along with the seed of the
+/// generator producing its mask. It must be expanded to an
+/// [`LweCiphertextEntity`](`super::LweCiphertextEntity`) before being used in computations.
+///
+/// A seeded LWE ciphertext is associated with a
+/// [`KeyDistribution`](`LweSeededCiphertextEntity::KeyDistribution`) type, which conveys the
+/// distribution of the secret key it was encrypted with.
+///
+/// # Formal Definition
+pub trait LweSeededCiphertextEntity: AbstractEntity<Kind = LweSeededCiphertextKind> {
+    /// The distribution of the key the ciphertext was encrypted with.
+    type KeyDistribution: KeyDistributionMarker;
+
+    /// Returns the LWE dimension of the ciphertext.
+    fn lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the ratio between the size of the expanded ciphertext and the size of the seeded
+    /// ciphertext.
+    fn compression_ratio(&self) -> f64;
+}
@@ -0,0 +1,35 @@
+use crate::specification::entities::markers::{
+    KeyDistributionMarker, LweShrinkingKeyswitchKeyKind,
+};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+/// A trait implemented by types embodying an LWE shrinking keyswitch key.
+///
+/// A shrinking keyswitch key allows to switch from an input LWE secret key to an output LWE secret
+/// key which is a prefix of the input one. Since the first coefficients of the two keys are
+/// shared, the key only contains the encryptions of the remaining coefficients of the input key.
+///
+/// An LWE shrinking keyswitch key is associated with a single [`KeyDistributionMarker`] type,
+/// which conveys the distribution of both the input and the output secret keys.
+///
+/// # Formal Definition
+pub trait LweShrinkingKeyswitchKeyEntity:
+    AbstractEntity<Kind = LweShrinkingKeyswitchKeyKind>
+{
+    /// The distribution of the keys the input and output ciphertexts are encrypted with.
+    type KeyDistribution: KeyDistributionMarker;
+
+    /// Returns the input LWE dimension of the key.
+    fn input_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the output LWE dimension of the key, which is also the number of coefficients
+    /// shared by the input and output secret keys.
+    fn output_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+}
@@ -1,4 +1,14 @@
 //! A module containing various marker traits used for entities.
+#[cfg(feature = "noise_estimation")]
+use concrete_commons::dispersion::Variance;
+#[cfg(feature = "noise_estimation")]
+use concrete_commons::key_kinds::{BinaryKeyKind, GaussianKeyKind, TernaryKeyKind};
+#[cfg(feature = "noise_estimation")]
+use concrete_commons::numeric::UnsignedInteger;
+#[cfg(feature = "noise_estimation")]
+use concrete_commons::parameters::PolynomialSize;
+#[cfg(feature = "noise_estimation")]
+use concrete_npe::KeyDispersion;
 use std::fmt::Debug;
 
 /// A trait implemented by marker types encoding the __kind__ of an FHE entity in
@@ -38,6 +48,12 @@ entity_kind_marker! {
             => "An empty type representing the LWE ciphertext kind in the type system.",
         LweCiphertextVectorKind
             => "An empty type representing the LWE ciphertext vector kind in the type system.",
+        LweCiphertextJaggedVectorKind
+            => "An empty type representing the LWE ciphertext jagged vector kind in the type system.",
+        LwePhaseVectorKind
+            => "An empty type representing the LWE phase vector kind in the type system.",
+        LweSeededCiphertextKind
+            => "An empty type representing the seeded LWE ciphertext kind in the type system.",
         GlweCiphertextKind
             => "An empty type representing the GLWE ciphertext kind in the type system.",
         GlweCiphertextVectorKind
@@ -46,20 +62,36 @@ entity_kind_marker! {
             => "An empty type representing the GGSW ciphertext kind in the type system.",
         GgswCiphertextVectorKind
             => "An empty type representing the GGSW ciphertext vector kind in the type system.",
+        GgswSeededCiphertextKind
+            => "An empty type representing the seeded GGSW ciphertext kind in the type system.",
         GswCiphertextKind
             => "An empty type representing the GSW ciphertext kind in the type system.",
         GswCiphertextVectorKind
             => "An empty type representing the GSW ciphertext vector kind in the type system.",
         LweSecretKeyKind
             => "An empty type representing the LWE secret key kind in the type system.",
+        LwePublicKeyKind
+            => "An empty type representing the LWE public key kind in the type system.",
         GlweSecretKeyKind
             => "An empty type representing the GLWE secret key kind in the type system.",
         LweKeyswitchKeyKind
             => "An empty type representing the LWE keyswitch key kind in the type system.",
+        LweKeyswitchKeyBatchKind
+            => "An empty type representing the LWE keyswitch key batch kind in the type system.",
+        LweKeyswitchKeyDeltaKind
+            => "An empty type representing the LWE keyswitch key delta kind in the type system.",
         PackingKeyswitchKeyKind
             => "An empty type representing the packing keyswitch key kind in the type system.",
+        LweShrinkingKeyswitchKeyKind
+            => "An empty type representing the LWE shrinking keyswitch key kind in the type system.",
+        GlweAutomorphismKeyKind
+            => "An empty type representing the GLWE automorphism key kind in the type system.",
+        GlweRelinearizationKeyKind
+            => "An empty type representing the GLWE relinearization key kind in the type system.",
         LweBootstrapKeyKind
             => "An empty type representing the LWE bootstrap key kind in the type system.",
+        LweSeededBootstrapKeyKind
+            => "An empty type representing the seeded LWE bootstrap key kind in the type system.",
         EncoderKind
             => "An empty type representing the encoder kind in the type system.",
         EncoderVectorKind
@@ -75,28 +107,112 @@ entity_kind_marker! {
 ///
 /// [`KeyDistributionMarker`] types are only defined in the specification part of the library, and
 /// can not be defined by a backend.
+///
+/// With the `noise_estimation` feature, every [`KeyDistributionMarker`] type implements the
+/// `KeyDispersion` trait of `concrete-npe`, which allows to estimate the noise of an operation from
+/// the key distribution of its entities.
+#[cfg(feature = "noise_estimation")]
+pub trait KeyDistributionMarker:
+    seal::KeyDistributionMarkerSealed + KeyDispersion + 'static
+{
+}
+#[cfg(not(feature = "noise_estimation"))]
 pub trait KeyDistributionMarker: seal::KeyDistributionMarkerSealed + 'static {}
 macro_rules! key_distribution_marker {
-        (@ $name: ident => $doc: literal)=>{
+        (@ $name: ident ($kind: ident) => $doc: literal)=>{
             #[doc=$doc]
             #[derive(Debug, Clone, Copy)]
             pub struct $name{}
             impl seal::KeyDistributionMarkerSealed for $name{}
             impl KeyDistributionMarker for $name{}
+            #[cfg(feature = "noise_estimation")]
+            impl KeyDispersion for $name {
+                fn variance_key_coefficient<T: UnsignedInteger>() -> Variance {
+                    $kind::variance_key_coefficient::<T>()
+                }
+                fn expectation_key_coefficient() -> f64 {
+                    $kind::expectation_key_coefficient()
+                }
+                fn variance_key_coefficient_squared<T: UnsignedInteger>() -> Variance {
+                    $kind::variance_key_coefficient_squared::<T>()
+                }
+                fn expectation_key_coefficient_squared<T: UnsignedInteger>() -> f64 {
+                    $kind::expectation_key_coefficient_squared::<T>()
+                }
+                fn variance_odd_coefficient_in_polynomial_key_squared<T: UnsignedInteger>(
+                    poly_size: PolynomialSize,
+                ) -> Variance {
+                    $kind::variance_odd_coefficient_in_polynomial_key_squared::<T>(poly_size)
+                }
+                fn variance_even_coefficient_in_polynomial_key_squared<T: UnsignedInteger>(
+                    poly_size: PolynomialSize,
+                ) -> Variance {
+                    $kind::variance_even_coefficient_in_polynomial_key_squared::<T>(poly_size)
+                }
+                fn squared_expectation_mean_in_polynomial_key_squared<T: UnsignedInteger>(
+                    poly_size: PolynomialSize,
+                ) -> f64 {
+                    $kind::squared_expectation_mean_in_polynomial_key_squared::<T>(poly_size)
+                }
+                fn variance_coefficient_in_polynomial_key_times_key<T: UnsignedInteger>(
+                    poly_size: PolynomialSize,
+                ) -> Variance {
+                    $kind::variance_coefficient_in_polynomial_key_times_key::<T>(poly_size)
+                }
+                fn square_expectation_mean_in_polynomial_key_times_key(
+                    poly_size: PolynomialSize,
+                ) -> f64 {
+                    $kind::square_expectation_mean_in_polynomial_key_times_key(poly_size)
+                }
+            }
         };
-        ($($name: ident => $doc: literal),+) =>{
+        ($($name: ident ($kind: ident) => $doc: literal),+) =>{
             $(
-                key_distribution_marker!(@ $name => $doc);
+                key_distribution_marker!(@ $name ($kind) => $doc);
             )+
         }
     }
 key_distribution_marker! {
-    BinaryKeyDistribution => "An empty type encoding the binary key distribution in the type system.",
-    TernaryKeyDistribution => "An empty type encoding the ternary key distribution in the type system.",
-    GaussianKeyDistribution => "An empty type encoding the gaussian key distribution in the type system."
+    BinaryKeyDistribution (BinaryKeyKind)
+        => "An empty type encoding the binary key distribution in the type system.",
+    TernaryKeyDistribution (TernaryKeyKind)
+        => "An empty type encoding the ternary key distribution in the type system.",
+    GaussianKeyDistribution (GaussianKeyKind)
+        => "An empty type encoding the gaussian key distribution in the type system."
+}
+
+/// A trait implemented by marker types encoding the _structure_ of the polynomial ring of an
+/// entity in the type system.
+///
+/// By _structure_ here, we mean the polynomial used to reduce the products of polynomials, which
+/// defines how the coefficients wrap around the polynomial size.
+///
+/// # Note
+///
+/// [`RingStructureMarker`] types are only defined in the specification part of the library, and
+/// can not be defined by a backend.
+pub trait RingStructureMarker: seal::RingStructureMarkerSealed + 'static {}
+macro_rules! ring_structure_marker {
+        (@ $name: ident => $doc: literal)=>{
+            #[doc=$doc]
+            #[derive(Debug, Clone, Copy)]
+            pub struct $name{}
+            impl seal::RingStructureMarkerSealed for $name{}
+            impl RingStructureMarker for $name{}
+        };
+        ($($name: ident => $doc: literal),+) =>{
+            $(
+                ring_structure_marker!(@ $name => $doc);
+            )+
+        }
+    }
+ring_structure_marker! {
+    NegacyclicRing => "An empty type encoding the negacyclic ring, reduced modulo $X^N + 1$, in the type system.",
+    CyclicRing => "An empty type encoding the cyclic ring, reduced modulo $X^N - 1$, in the type system."
 }
 
 pub(crate) mod seal {
     pub trait EntityKindMarkerSealed {}
     pub trait KeyDistributionMarkerSealed {}
+    pub trait RingStructureMarkerSealed {}
 }
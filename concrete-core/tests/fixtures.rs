@@ -0,0 +1,153 @@
+//! An integration test instantiating the fixtures of `concrete-core-fixture` for every backend.
+//!
+//! This target is only built when the `fixtures` feature is enabled, and allows to check the
+//! statistical correctness of the operators locally with:
+//!
+//! ```text
+//! cargo test -p concrete-core --features fixtures
+//! ```
+//!
+//! The lists of the instantiated fixtures are shared with the `concrete-core-test` crate through
+//! the macros of the `concrete_core_fixture::instantiations` module, so that both runners always
+//! test the same operators. The fixtures are run with a reduced number of repetitions and samples;
+//! the `concrete-core-test` crate remains the reference runner used by the continuous integration.
+use concrete_core_fixture::presets::{REDUCED_REPETITIONS, REDUCED_SAMPLE_SIZE};
+use concrete_core_fixture::{Repetitions, SampleSize};
+
+/// The number of time a test is repeated for a single set of parameter.
+const REPETITIONS: Repetitions = REDUCED_REPETITIONS;
+
+/// The size of the sample used to perform statistical tests.
+const SAMPLE_SIZE: SampleSize = REDUCED_SAMPLE_SIZE;
+
+#[cfg(feature = "backend_core")]
+mod core {
+    use crate::{REPETITIONS, SAMPLE_SIZE};
+    use concrete_core::prelude::*;
+    use concrete_core_fixture::fixture::*;
+    use concrete_core_fixture::generation::{
+        engine_seed, seed_from_env, Maker, Precision128, Precision16, Precision32, Precision64,
+        SEED_VARIABLE,
+    };
+    use concrete_core_fixture::timing::TimingBudget;
+    use paste::paste;
+
+    /// Returns the timing budget the tests are checked against, if one is given by the
+    /// environment.
+    fn timing_budget() -> Option<TimingBudget> {
+        TimingBudget::from_env().unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    macro_rules! test {
+        ($fixture: ident, $precision: ident, ($($types:ident),+)) => {
+            paste!{
+                #[test]
+                fn [< test_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
+                    let seed = seed_from_env();
+                    let mut maker = Maker::new_seeded(seed);
+                    let mut engine = CoreEngine::new_seeded(engine_seed(seed));
+                    let test_result = match timing_budget() {
+                        Some(budget) => <$fixture as Fixture<
+                            $precision,
+                            CoreEngine,
+                            ($($types,)+),
+                        >>::stress_all_parameters_with_budget(
+                            &mut maker,
+                            &mut engine,
+                            REPETITIONS,
+                            SAMPLE_SIZE,
+                            &budget,
+                        ),
+                        None => <$fixture as Fixture<
+                            $precision,
+                            CoreEngine,
+                            ($($types,)+),
+                        >>::stress_all_parameters(
+                            &mut maker,
+                            &mut engine,
+                            REPETITIONS,
+                            SAMPLE_SIZE,
+                        ),
+                    };
+                    assert!(
+                        test_result,
+                        "The fixture failed with the seed {}, which can be reused by setting {}.",
+                        seed,
+                        SEED_VARIABLE
+                    );
+                }
+            }
+        };
+        ($(($fixture: ident, $precision: ident, ($($types:ident),+))),+) => {
+            $(
+                test!{$fixture, $precision, ($($types),+)}
+            )+
+        };
+        ($(($fixture: ident, ($($types:ident),+))),+) => {
+            $(
+                paste!{
+                    test!{$fixture, Precision32, ($([< $types 32 >]),+)}
+                    test!{$fixture, Precision64, ($([< $types 64 >]),+)}
+                }
+            )+
+        };
+    }
+
+    concrete_core_fixture::core_fixtures!(test);
+}
+
+#[cfg(feature = "backend_reference")]
+mod reference {
+    use crate::{REPETITIONS, SAMPLE_SIZE};
+    use concrete_core::prelude::*;
+    use concrete_core_fixture::fixture::*;
+    use concrete_core_fixture::generation::{
+        engine_seed, seed_from_env, Maker, Precision32, Precision64, SEED_VARIABLE,
+    };
+    use paste::paste;
+
+    macro_rules! test {
+        ($fixture: ident, $precision: ident, ($($types:ident),+)) => {
+            paste!{
+                #[test]
+                fn [< test_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
+                    let seed = seed_from_env();
+                    let mut maker = Maker::new_seeded(seed);
+                    let mut engine = ReferenceEngine::new_seeded(engine_seed(seed));
+                    let test_result =
+                        <$fixture as Fixture<
+                            $precision,
+                            ReferenceEngine,
+                            ($($types,)+),
+                        >>::stress_all_parameters(
+                            &mut maker,
+                            &mut engine,
+                            REPETITIONS,
+                            SAMPLE_SIZE,
+                        );
+                    assert!(
+                        test_result,
+                        "The fixture failed with the seed {}, which can be reused by setting {}.",
+                        seed,
+                        SEED_VARIABLE
+                    );
+                }
+            }
+        };
+        ($(($fixture: ident, $precision: ident, ($($types:ident),+))),+) => {
+            $(
+                test!{$fixture, $precision, ($($types),+)}
+            )+
+        };
+        ($(($fixture: ident, ($($types:ident),+))),+) => {
+            $(
+                paste!{
+                    test!{$fixture, Precision32, ($([< $types 32 >]),+)}
+                    test!{$fixture, Precision64, ($([< $types 64 >]),+)}
+                }
+            )+
+        };
+    }
+
+    concrete_core_fixture::reference_fixtures!(test);
+}
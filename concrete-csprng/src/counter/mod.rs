@@ -1,4 +1,6 @@
-use crate::{aesni, software};
+#[cfg(target_arch = "x86_64")]
+use crate::aesni;
+use crate::software;
 #[cfg(feature = "multithread")]
 use rayon::{iter::IndexedParallelIterator, prelude::*};
 use std::cmp::Ordering;
@@ -217,8 +219,14 @@ impl Default for State {
 pub type SoftAesCtrGenerator = AesCtrGenerator<software::Generator>;
 
 /// A generator that uses the hardware implementation.
+#[cfg(target_arch = "x86_64")]
 pub type HardAesCtrGenerator = AesCtrGenerator<aesni::Generator>;
 
+/// On the architectures without `aesni` instructions, the hardware generator is never built, and
+/// is an alias of the software one.
+#[cfg(not(target_arch = "x86_64"))]
+pub type HardAesCtrGenerator = AesCtrGenerator<software::Generator>;
+
 /// A csprng which operates in batch mode.
 #[derive(Clone)]
 pub struct AesCtrGenerator<G: AesBatchedGenerator> {
@@ -11,10 +11,13 @@
 use rayon::prelude::*;
 use std::fmt::{Debug, Display, Formatter, Result};
 
+#[cfg(target_arch = "x86_64")]
 mod aesni;
 mod counter;
 mod software;
-use crate::counter::{AesKey, BytesPerChild, ChildCount, HardAesCtrGenerator, SoftAesCtrGenerator};
+use crate::counter::{
+    AesCtr, AesKey, BytesPerChild, ChildCount, HardAesCtrGenerator, SoftAesCtrGenerator, State,
+};
 pub use software::set_soft_rdseed_secret;
 
 /// The pseudorandom number generator.
@@ -45,6 +48,30 @@ impl RandomGenerator {
         RandomGenerator::new_hardware(seed).unwrap_or_else(|| RandomGenerator::new_software(seed))
     }
 
+    /// Builds a new random generator seeded with a given value, whose state starts at a given AES
+    /// counter instead of zero, selecting the hardware implementation if available.
+    ///
+    /// The first 16 bytes yielded by the generator are the encryption of `counter` under the
+    /// `seed` key. Since AES is a pseudorandom permutation, generators built from the same seed with
+    /// distant counters yield independent bytes, which allows to derive many seeds from a single
+    /// one.
+    ///
+    /// # Note
+    ///
+    /// If using the `slow` feature, this function will return the non-accelerated variant, even
+    /// though the right instructions are available.
+    pub fn new_with_counter(seed: u128, counter: u128) -> RandomGenerator {
+        let state = Some(State::from_aes_counter(AesCtr(counter)));
+        if cfg!(feature = "slow") || !hardware_available() {
+            return RandomGenerator::Software(SoftAesCtrGenerator::new(
+                Some(AesKey(seed)),
+                state,
+                None,
+            ));
+        }
+        RandomGenerator::Hardware(HardAesCtrGenerator::new(Some(AesKey(seed)), state, None))
+    }
+
     /// Builds a new software random generator, optionally seeding it with a given value.
     pub fn new_software(seed: Option<u128>) -> RandomGenerator {
         RandomGenerator::Software(SoftAesCtrGenerator::new(seed.map(AesKey), None, None))
@@ -52,10 +79,7 @@ impl RandomGenerator {
 
     /// Tries to build a new hardware random generator, optionally seeding it with a given value.
     pub fn new_hardware(seed: Option<u128>) -> Option<RandomGenerator> {
-        if !is_x86_feature_detected!("aes")
-            || !is_x86_feature_detected!("rdseed")
-            || !is_x86_feature_detected!("sse2")
-        {
+        if !hardware_available() {
             return None;
         }
         Some(RandomGenerator::Hardware(HardAesCtrGenerator::new(
@@ -238,6 +262,21 @@ impl Display for RandomGenerator {
     }
 }
 
+// Returns whether the instructions used by the hardware generator are available. They only exist
+// on `x86_64`, the software generator being used on the other architectures.
+fn hardware_available() -> bool {
+    #[cfg(target_arch = "x86_64")]
+    {
+        is_x86_feature_detected!("aes")
+            && is_x86_feature_detected!("rdseed")
+            && is_x86_feature_detected!("sse2")
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        false
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -275,6 +314,21 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_generator_with_counter() {
+        // Checks that a generator started at a counter yields the bytes of a generator started at
+        // zero, once it reached the same counter. A batch of 128 bytes spans 8 counters.
+        let key = software::dev_random();
+        let mut generator = RandomGenerator::new(Some(key));
+        for _ in 0..128 {
+            generator.generate_next();
+        }
+        let mut shifted_generator = RandomGenerator::new_with_counter(key, 8);
+        for _ in 0..128 {
+            assert_eq!(generator.generate_next(), shifted_generator.generate_next());
+        }
+    }
+
     #[test]
     fn test_fork() {
         // Checks that forks returns a bounded child, and that the proper number of bytes can be
@@ -0,0 +1,305 @@
+//! Contains material needed to estimate the running time of homomorphic operations from their
+//! parameters, without running them.
+use concrete_commons::parameters::{
+    DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use std::time::Duration;
+
+/// The algorithm used by a backend to multiply polynomials.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolynomialMultiplication {
+    /// The polynomials are sent to the fourier domain with fast fourier transforms, and multiplied
+    /// coefficient-wise there.
+    Fourier,
+    /// The polynomials are multiplied with the schoolbook algorithm, in the standard domain.
+    Schoolbook,
+}
+
+/// The running times of the elementary operations of a backend, in nanoseconds.
+///
+/// The cost of a homomorphic operation is estimated by counting the elementary operations it
+/// performs, and weighting them with the times of this model. The estimations are only meant to
+/// compare candidate parameter sets with each other on the same backend.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostModel {
+    /// The algorithm used to multiply polynomials.
+    pub polynomial_multiplication: PolynomialMultiplication,
+    /// The time of a wrapping multiply-add between two integers.
+    pub integer_multiply_add: f64,
+    /// The time to decompose an integer over one level of a gadget decomposition.
+    pub decomposition: f64,
+    /// The time of a butterfly of a fast fourier transform.
+    pub fft_butterfly: f64,
+    /// The time of a multiply-add between two complex numbers.
+    pub fourier_multiply_add: f64,
+}
+
+impl CostModel {
+    /// The cost model of the `core` backend of `concrete-core`.
+    ///
+    /// The times were fitted for the 64 bits precision on a single x86_64 core, with the
+    /// `calibrate` command of `concrete-benchmark`, which must be run again whenever the backend
+    /// changes. The `multithread` backend executes each bootstrap of a vector with the kernels of
+    /// the `core` backend, and shares this model for a single thread.
+    pub const CORE: CostModel = CostModel {
+        polynomial_multiplication: PolynomialMultiplication::Fourier,
+        integer_multiply_add: 0.83,
+        decomposition: 4.3,
+        fft_butterfly: 1.05,
+        fourier_multiply_add: 0.26,
+    };
+
+    /// The cost model of the `reference` backend of `concrete-core`.
+    ///
+    /// The polynomials are multiplied with the schoolbook algorithm, so the times of the fourier
+    /// operations are left to zero. The times were fitted in the same conditions as the ones of
+    /// [`CostModel::CORE`].
+    pub const REFERENCE: CostModel = CostModel {
+        polynomial_multiplication: PolynomialMultiplication::Schoolbook,
+        integer_multiply_add: 1.4,
+        decomposition: 44.,
+        fft_butterfly: 0.,
+        fourier_multiply_add: 0.,
+    };
+}
+
+fn to_duration(nanoseconds: f64) -> Duration {
+    Duration::from_secs_f64(nanoseconds * 1e-9)
+}
+
+fn fft_nanoseconds(model: &CostModel, poly_size: PolynomialSize) -> f64 {
+    let big_n = poly_size.0 as f64;
+    big_n / 2. * big_n.log2() * model.fft_butterfly
+}
+
+fn external_product_nanoseconds(
+    model: &CostModel,
+    glwe_mask_size: GlweDimension,
+    poly_size: PolynomialSize,
+    level: DecompositionLevelCount,
+) -> f64 {
+    let glwe_size = (glwe_mask_size.0 + 1) as f64;
+    let big_n = poly_size.0 as f64;
+    let l = level.0 as f64;
+    // Each of the (k + 1) * l polynomials of the decomposed GLWE ciphertext is decomposed, then
+    // multiplied with a row of the GGSW ciphertext.
+    let decomposition = glwe_size * big_n * l * model.decomposition;
+    match model.polynomial_multiplication {
+        PolynomialMultiplication::Fourier => {
+            // The decomposed polynomials are sent to the fourier domain, and the (k + 1)
+            // accumulated polynomials are finally sent back.
+            let forward = glwe_size * l * fft_nanoseconds(model, poly_size);
+            let products = glwe_size * l * glwe_size * big_n * model.fourier_multiply_add;
+            let backward = glwe_size * fft_nanoseconds(model, poly_size);
+            decomposition + forward + products + backward
+        }
+        PolynomialMultiplication::Schoolbook => {
+            let products = glwe_size * l * glwe_size * big_n * big_n * model.integer_multiply_add;
+            decomposition + products
+        }
+    }
+}
+
+fn cmux_nanoseconds(
+    model: &CostModel,
+    glwe_mask_size: GlweDimension,
+    poly_size: PolynomialSize,
+    level: DecompositionLevelCount,
+) -> f64 {
+    let glwe_size = (glwe_mask_size.0 + 1) as f64;
+    let big_n = poly_size.0 as f64;
+    let additions = 2. * glwe_size * big_n * model.integer_multiply_add;
+    external_product_nanoseconds(model, glwe_mask_size, poly_size, level) + additions
+}
+
+/// Computes the estimated running time of a keyswitch of an LWE ciphertext.
+///
+/// Each of the $n\_{in}$ coefficients of the input mask is decomposed over $\ell$ levels, and
+/// each level is multiplied with an LWE ciphertext of size $n\_{out} + 1$ of the key.
+/// # Example
+/// ```rust
+/// use concrete_commons::parameters::{DecompositionLevelCount, LweDimension};
+/// use concrete_npe::{estimate_keyswitch_cost, CostModel};
+/// let small = estimate_keyswitch_cost(
+///     &CostModel::CORE,
+///     LweDimension(1024),
+///     LweDimension(630),
+///     DecompositionLevelCount(3),
+/// );
+/// let large = estimate_keyswitch_cost(
+///     &CostModel::CORE,
+///     LweDimension(1024),
+///     LweDimension(630),
+///     DecompositionLevelCount(6),
+/// );
+/// assert!(small < large);
+/// ```
+pub fn estimate_keyswitch_cost(
+    model: &CostModel,
+    input_lwe_mask_size: LweDimension,
+    output_lwe_mask_size: LweDimension,
+    level: DecompositionLevelCount,
+) -> Duration {
+    let n_in = input_lwe_mask_size.0 as f64;
+    let n_out = output_lwe_mask_size.0 as f64;
+    let l = level.0 as f64;
+    let decomposition = n_in * l * model.decomposition;
+    let products = n_in * l * (n_out + 1.) * model.integer_multiply_add;
+    to_duration(decomposition + products)
+}
+
+/// Computes the estimated running time of an external product between a GGSW ciphertext and a
+/// GLWE ciphertext, computed in the fourier domain.
+/// # Example
+/// ```rust
+/// use concrete_commons::parameters::{DecompositionLevelCount, GlweDimension, PolynomialSize};
+/// use concrete_npe::{estimate_external_product_cost, CostModel};
+/// let cost = estimate_external_product_cost(
+///     &CostModel::CORE,
+///     GlweDimension(1),
+///     PolynomialSize(1024),
+///     DecompositionLevelCount(3),
+/// );
+/// assert!(cost.as_nanos() > 0);
+/// ```
+pub fn estimate_external_product_cost(
+    model: &CostModel,
+    glwe_mask_size: GlweDimension,
+    poly_size: PolynomialSize,
+    level: DecompositionLevelCount,
+) -> Duration {
+    to_duration(external_product_nanoseconds(
+        model,
+        glwe_mask_size,
+        poly_size,
+        level,
+    ))
+}
+
+/// Computes the estimated running time of a CMux between two GLWE ciphertexts, computed in the
+/// fourier domain.
+///
+/// The CMux is an external product, along with a subtraction and an addition of GLWE
+/// ciphertexts.
+/// # Example
+/// ```rust
+/// use concrete_commons::parameters::{DecompositionLevelCount, GlweDimension, PolynomialSize};
+/// use concrete_npe::{estimate_cmux_cost, estimate_external_product_cost, CostModel};
+/// let cmux = estimate_cmux_cost(
+///     &CostModel::CORE,
+///     GlweDimension(1),
+///     PolynomialSize(1024),
+///     DecompositionLevelCount(3),
+/// );
+/// let external_product = estimate_external_product_cost(
+///     &CostModel::CORE,
+///     GlweDimension(1),
+///     PolynomialSize(1024),
+///     DecompositionLevelCount(3),
+/// );
+/// assert!(external_product < cmux);
+/// ```
+pub fn estimate_cmux_cost(
+    model: &CostModel,
+    glwe_mask_size: GlweDimension,
+    poly_size: PolynomialSize,
+    level: DecompositionLevelCount,
+) -> Duration {
+    to_duration(cmux_nanoseconds(model, glwe_mask_size, poly_size, level))
+}
+
+/// Computes the estimated running time of a programmable bootstrap of an LWE ciphertext.
+///
+/// The blind rotation performs one CMux per coefficient of the input LWE mask, along with the
+/// rotation of the accumulator. The final sample extraction is negligible.
+/// # Example
+/// ```rust
+/// use concrete_commons::parameters::{
+///     DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+/// };
+/// use concrete_npe::{estimate_pbs_cost, CostModel};
+/// let small = estimate_pbs_cost(
+///     &CostModel::CORE,
+///     LweDimension(630),
+///     GlweDimension(1),
+///     PolynomialSize(1024),
+///     DecompositionLevelCount(3),
+/// );
+/// let large = estimate_pbs_cost(
+///     &CostModel::CORE,
+///     LweDimension(630),
+///     GlweDimension(1),
+///     PolynomialSize(2048),
+///     DecompositionLevelCount(3),
+/// );
+/// assert!(small < large);
+/// ```
+pub fn estimate_pbs_cost(
+    model: &CostModel,
+    lwe_mask_size: LweDimension,
+    glwe_mask_size: GlweDimension,
+    poly_size: PolynomialSize,
+    level: DecompositionLevelCount,
+) -> Duration {
+    let n = lwe_mask_size.0 as f64;
+    let glwe_size = (glwe_mask_size.0 + 1) as f64;
+    let big_n = poly_size.0 as f64;
+    let rotation = glwe_size * big_n * model.integer_multiply_add;
+    let cmux = cmux_nanoseconds(model, glwe_mask_size, poly_size, level);
+    to_duration(n * (cmux + rotation))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pbs_cost_is_linear_in_the_lwe_dimension() {
+        let cost = |n| {
+            estimate_pbs_cost(
+                &CostModel::CORE,
+                LweDimension(n),
+                GlweDimension(1),
+                PolynomialSize(1024),
+                DecompositionLevelCount(2),
+            )
+            .as_secs_f64()
+        };
+        let ratio = cost(1000) / cost(500);
+        assert!((ratio - 2.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn pbs_cost_is_dominated_by_the_cmuxes() {
+        let pbs = estimate_pbs_cost(
+            &CostModel::CORE,
+            LweDimension(1),
+            GlweDimension(1),
+            PolynomialSize(1024),
+            DecompositionLevelCount(2),
+        );
+        let cmux = estimate_cmux_cost(
+            &CostModel::CORE,
+            GlweDimension(1),
+            PolynomialSize(1024),
+            DecompositionLevelCount(2),
+        );
+        assert!(cmux < pbs);
+        assert!(pbs.as_secs_f64() < 1.1 * cmux.as_secs_f64());
+    }
+
+    #[test]
+    fn schoolbook_external_product_is_quadratic_in_the_polynomial_size() {
+        let cost = |big_n| {
+            estimate_external_product_cost(
+                &CostModel::REFERENCE,
+                GlweDimension(1),
+                PolynomialSize(big_n),
+                DecompositionLevelCount(2),
+            )
+            .as_secs_f64()
+        };
+        let ratio = cost(2048) / cost(1024);
+        assert!(ratio > 3.9 && ratio < 4.);
+    }
+}
@@ -0,0 +1,418 @@
+//! Contains material needed to map the parameters of a programmable bootstrapping designed for 64
+//! bits ciphertexts to parameters valid for 32 bits ciphertexts, for the backends and targets
+//! which only support the latter.
+use concrete_commons::dispersion::{DispersionParameter, StandardDev, Variance};
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, MessageBitCount,
+    PolynomialSize, ScalingFactor,
+};
+use std::fmt::{Display, Formatter};
+
+use super::*;
+
+// The smallest modular standard deviation of the encryption noise on 32 bits. Below a few units,
+// most of the noise is rounded away, and the security of the parameters is not given by their
+// torus noise anymore. This is the deviation used for the gaussian secret keys.
+const MINIMAL_MODULAR_STD_DEV: f64 = 3.2;
+
+/// The parameters of a programmable bootstrapping pipeline: a keyswitch from the GLWE secret key
+/// to the LWE secret key, followed by a bootstrap back to the GLWE secret key.
+///
+/// The noises are the torus dispersions of the encryptions of the keyswitch key (under the LWE
+/// secret key) and of the bootstrap key (under the GLWE secret key). The messages are encoded with
+/// one bit of padding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PbsParameters {
+    /// The dimension of the LWE secret key.
+    pub lwe_dimension: LweDimension,
+    /// The dimension of the GLWE secret key.
+    pub glwe_dimension: GlweDimension,
+    /// The size of the polynomials of the GLWE secret key.
+    pub polynomial_size: PolynomialSize,
+    /// The noise of the encryptions under the LWE secret key.
+    pub lwe_noise: Variance,
+    /// The noise of the encryptions under the GLWE secret key.
+    pub glwe_noise: Variance,
+    /// The logarithm of the decomposition base of the bootstrap key.
+    pub pbs_base_log: DecompositionBaseLog,
+    /// The number of decomposition levels of the bootstrap key.
+    pub pbs_level: DecompositionLevelCount,
+    /// The logarithm of the decomposition base of the keyswitch key.
+    pub ks_base_log: DecompositionBaseLog,
+    /// The number of decomposition levels of the keyswitch key.
+    pub ks_level: DecompositionLevelCount,
+    /// The number of bits of the messages.
+    pub message_bits: MessageBitCount,
+}
+
+/// A change made to a parameter by [`downgrade_to_32_bits`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParameterAdjustment {
+    /// The noise of the LWE encryptions was raised to stay representable on 32 bits.
+    LweNoiseRaised { from: Variance, to: Variance },
+    /// The noise of the GLWE encryptions was raised to stay representable on 32 bits.
+    GlweNoiseRaised { from: Variance, to: Variance },
+    /// The decomposition of the bootstrap key was changed.
+    PbsDecompositionChanged {
+        from: (DecompositionBaseLog, DecompositionLevelCount),
+        to: (DecompositionBaseLog, DecompositionLevelCount),
+    },
+    /// The decomposition of the keyswitch key was changed.
+    KsDecompositionChanged {
+        from: (DecompositionBaseLog, DecompositionLevelCount),
+        to: (DecompositionBaseLog, DecompositionLevelCount),
+    },
+}
+
+/// An error occurring when downgrading parameters to 32 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DowngradeError {
+    /// The messages, along with their padding bit, do not fit in 32 bits.
+    MessageTooLarge(MessageBitCount),
+}
+
+impl Display for DowngradeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DowngradeError::MessageTooLarge(bits) => write!(
+                f,
+                "Messages of {} bits and their padding bit do not fit in 32 bits.",
+                bits.0
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DowngradeError {}
+
+/// The outcome of [`downgrade_to_32_bits`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DowngradeReport {
+    /// The parameters valid for 32 bits ciphertexts.
+    pub parameters: PbsParameters,
+    /// The scaling factor $\Delta$ of the messages on 32 bits.
+    pub delta: ScalingFactor,
+    /// The changes made to the 64 bits parameters, in the order they were applied.
+    pub adjustments: Vec<ParameterAdjustment>,
+    /// The estimated probability that a bootstrap fails with the 64 bits parameters.
+    pub failure_probability_64: f64,
+    /// The estimated probability that a bootstrap fails with the 32 bits parameters.
+    pub failure_probability_32: f64,
+}
+
+impl DowngradeReport {
+    /// Returns whether the estimated failure probability of the 32 bits parameters does not
+    /// exceed `max_failure_probability`.
+    pub fn is_correct(&self, max_failure_probability: f64) -> bool {
+        self.failure_probability_32 <= max_failure_probability
+    }
+}
+
+/// Computes the dispersion of the noise of a ciphertext output by a bootstrap, after it went
+/// through the keyswitch and the modulus switching of the next bootstrap.
+///
+/// This is the noise the next bootstrap has to tolerate, such that it decides the correctness of
+/// the whole pipeline.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::{DispersionParameter, Variance};
+/// use concrete_commons::parameters::{
+///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+///     MessageBitCount, PolynomialSize,
+/// };
+/// use concrete_npe::{estimate_pbs_pipeline_noise, PbsParameters};
+/// let parameters = PbsParameters {
+///     lwe_dimension: LweDimension(742),
+///     glwe_dimension: GlweDimension(1),
+///     polynomial_size: PolynomialSize(2048),
+///     lwe_noise: Variance(f64::powf(2., -34.2)),
+///     glwe_noise: Variance(f64::powf(2., -103.)),
+///     pbs_base_log: DecompositionBaseLog(23),
+///     pbs_level: DecompositionLevelCount(1),
+///     ks_base_log: DecompositionBaseLog(3),
+///     ks_level: DecompositionLevelCount(5),
+///     message_bits: MessageBitCount(2),
+/// };
+/// let variance = estimate_pbs_pipeline_noise::<u64>(&parameters);
+/// assert!(variance.get_variance() < f64::powi(2., -10));
+/// ```
+pub fn estimate_pbs_pipeline_noise<T>(parameters: &PbsParameters) -> Variance
+where
+    T: UnsignedInteger,
+{
+    let pbs = estimate_pbs_noise::<T, _, BinaryKeyKind>(
+        parameters.lwe_dimension,
+        parameters.polynomial_size,
+        parameters.glwe_dimension,
+        parameters.pbs_base_log,
+        parameters.pbs_level,
+        parameters.glwe_noise,
+    );
+    let fft = estimate_pbs_fft_noise::<T>(
+        parameters.lwe_dimension,
+        parameters.polynomial_size,
+        parameters.glwe_dimension,
+        parameters.pbs_base_log,
+        parameters.pbs_level,
+    );
+    let pbs = estimate_addition_noise::<T, _, _>(pbs, fft);
+    let ks = estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<T, _, _, BinaryKeyKind>(
+        LweDimension(parameters.glwe_dimension.0 * parameters.polynomial_size.0),
+        pbs,
+        parameters.lwe_noise,
+        parameters.ks_base_log,
+        parameters.ks_level,
+    );
+    // The next bootstrap switches the modulus to 2N.
+    let log_2n = (2 * parameters.polynomial_size.0).trailing_zeros() as usize;
+    estimate_modulus_switching_noise_with_binary_key::<T, _>(parameters.lwe_dimension, log_2n, ks)
+}
+
+/// Computes the probability that a bootstrap fails, that is that the noise estimated by
+/// [`estimate_pbs_pipeline_noise`] exceeds half the scaling factor of the messages.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_commons::parameters::{
+///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+///     MessageBitCount, PolynomialSize,
+/// };
+/// use concrete_npe::{estimate_pbs_failure_probability, PbsParameters};
+/// let parameters = PbsParameters {
+///     lwe_dimension: LweDimension(742),
+///     glwe_dimension: GlweDimension(1),
+///     polynomial_size: PolynomialSize(2048),
+///     lwe_noise: Variance(f64::powf(2., -34.2)),
+///     glwe_noise: Variance(f64::powf(2., -103.)),
+///     pbs_base_log: DecompositionBaseLog(23),
+///     pbs_level: DecompositionLevelCount(1),
+///     ks_base_log: DecompositionBaseLog(3),
+///     ks_level: DecompositionLevelCount(5),
+///     message_bits: MessageBitCount(2),
+/// };
+/// let probability = estimate_pbs_failure_probability::<u64>(&parameters);
+/// assert!(probability < f64::powi(2., -20));
+/// ```
+pub fn estimate_pbs_failure_probability<T>(parameters: &PbsParameters) -> f64
+where
+    T: UnsignedInteger,
+{
+    let std_dev = estimate_pbs_pipeline_noise::<T>(parameters).get_standard_dev();
+    // With one bit of padding, the messages are scaled by 2^-(p + 1), and decrypt correctly as
+    // long as the noise stays below half of it.
+    let bound = f64::powi(2., -(parameters.message_bits.0 as i32 + 2));
+    if std_dev == 0. {
+        return 0.;
+    }
+    erfc(bound / (std_dev * std::f64::consts::SQRT_2))
+}
+
+/// Maps parameters designed for 64 bits ciphertexts to the closest parameters valid for 32 bits
+/// ciphertexts, and estimates the failure probability of both.
+///
+/// The dimensions are kept, along with the torus noises, which keeps the security of the
+/// parameters. The noises which would be rounded away on 32 bits are raised to a modular standard
+/// deviation of 3.2, which only makes the parameters more secure. The decompositions are then
+/// picked among the ones fitting in 32 bits, with at most as many levels as the original ones (so
+/// that the keys do not grow), to minimize the noise estimated by
+/// [`estimate_pbs_pipeline_noise`].
+///
+/// The raised noises may make the 32 bits parameters fail much more often than the 64 bits ones:
+/// the report must be checked with [`DowngradeReport::is_correct`] before the parameters are used.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::{DispersionParameter, Variance};
+/// use concrete_commons::parameters::{
+///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+///     MessageBitCount, PolynomialSize, ScalingFactor,
+/// };
+/// use concrete_npe::{downgrade_to_32_bits, PbsParameters};
+/// let parameters = PbsParameters {
+///     lwe_dimension: LweDimension(742),
+///     glwe_dimension: GlweDimension(1),
+///     polynomial_size: PolynomialSize(2048),
+///     lwe_noise: Variance(f64::powf(2., -34.2)),
+///     glwe_noise: Variance(f64::powf(2., -103.)),
+///     pbs_base_log: DecompositionBaseLog(23),
+///     pbs_level: DecompositionLevelCount(1),
+///     ks_base_log: DecompositionBaseLog(3),
+///     ks_level: DecompositionLevelCount(5),
+///     message_bits: MessageBitCount(2),
+/// };
+/// let report = downgrade_to_32_bits(&parameters).unwrap();
+/// let downgraded = report.parameters;
+/// assert_eq!(downgraded.lwe_dimension, parameters.lwe_dimension);
+/// assert!(downgraded.glwe_noise.get_variance() > parameters.glwe_noise.get_variance());
+/// assert!(downgraded.pbs_base_log.0 * downgraded.pbs_level.0 <= 32);
+/// assert_eq!(report.delta, ScalingFactor::from_log2(29));
+/// // The 32 bits parameters fail more often, but still rarely.
+/// assert!(report.failure_probability_32 > report.failure_probability_64);
+/// assert!(report.is_correct(1e-5));
+/// ```
+pub fn downgrade_to_32_bits(parameters: &PbsParameters) -> Result<DowngradeReport, DowngradeError> {
+    if parameters.message_bits.0 + 1 >= 32 {
+        return Err(DowngradeError::MessageTooLarge(parameters.message_bits));
+    }
+    let mut adjustments = Vec::new();
+    let mut downgraded = *parameters;
+
+    let floor = StandardDev::from_modular_standard_dev::<u32>(MINIMAL_MODULAR_STD_DEV);
+    if parameters.lwe_noise.get_variance() < floor.get_variance() {
+        downgraded.lwe_noise = Variance(floor.get_variance());
+        adjustments.push(ParameterAdjustment::LweNoiseRaised {
+            from: parameters.lwe_noise,
+            to: downgraded.lwe_noise,
+        });
+    }
+    if parameters.glwe_noise.get_variance() < floor.get_variance() {
+        downgraded.glwe_noise = Variance(floor.get_variance());
+        adjustments.push(ParameterAdjustment::GlweNoiseRaised {
+            from: parameters.glwe_noise,
+            to: downgraded.glwe_noise,
+        });
+    }
+
+    let mut best: Option<(f64, PbsParameters)> = None;
+    for (pbs_base_log, pbs_level) in decompositions_32(parameters.pbs_level) {
+        for (ks_base_log, ks_level) in decompositions_32(parameters.ks_level) {
+            let candidate = PbsParameters {
+                pbs_base_log,
+                pbs_level,
+                ks_base_log,
+                ks_level,
+                ..downgraded
+            };
+            let variance = estimate_pbs_pipeline_noise::<u32>(&candidate).get_variance();
+            if best.is_none_or(|(best_variance, _)| variance < best_variance) {
+                best = Some((variance, candidate));
+            }
+        }
+    }
+    // The original decompositions are kept when they are among the best ones.
+    let original = PbsParameters {
+        pbs_base_log: parameters.pbs_base_log,
+        pbs_level: parameters.pbs_level,
+        ks_base_log: parameters.ks_base_log,
+        ks_level: parameters.ks_level,
+        ..downgraded
+    };
+    let original_fits = decompositions_32(parameters.pbs_level)
+        .any(|decomposition| decomposition == (parameters.pbs_base_log, parameters.pbs_level))
+        && decompositions_32(parameters.ks_level)
+            .any(|decomposition| decomposition == (parameters.ks_base_log, parameters.ks_level));
+    downgraded = match best {
+        Some((variance, candidate))
+            if !original_fits
+                || variance < estimate_pbs_pipeline_noise::<u32>(&original).get_variance() =>
+        {
+            candidate
+        }
+        _ => original,
+    };
+    if (downgraded.pbs_base_log, downgraded.pbs_level)
+        != (parameters.pbs_base_log, parameters.pbs_level)
+    {
+        adjustments.push(ParameterAdjustment::PbsDecompositionChanged {
+            from: (parameters.pbs_base_log, parameters.pbs_level),
+            to: (downgraded.pbs_base_log, downgraded.pbs_level),
+        });
+    }
+    if (downgraded.ks_base_log, downgraded.ks_level)
+        != (parameters.ks_base_log, parameters.ks_level)
+    {
+        adjustments.push(ParameterAdjustment::KsDecompositionChanged {
+            from: (parameters.ks_base_log, parameters.ks_level),
+            to: (downgraded.ks_base_log, downgraded.ks_level),
+        });
+    }
+
+    Ok(DowngradeReport {
+        parameters: downgraded,
+        delta: ScalingFactor::from_log2(31 - parameters.message_bits.0),
+        adjustments,
+        failure_probability_64: estimate_pbs_failure_probability::<u64>(parameters),
+        failure_probability_32: estimate_pbs_failure_probability::<u32>(&downgraded),
+    })
+}
+
+// Returns the decompositions fitting in 32 bits, with at most `max_level` levels. The bases are
+// capped to 2^30, the largest base handled by the estimators.
+fn decompositions_32(
+    max_level: DecompositionLevelCount,
+) -> impl Iterator<Item = (DecompositionBaseLog, DecompositionLevelCount)> {
+    (1..=max_level.0.max(1)).flat_map(|level| {
+        (1..=(32 / level).min(30)).map(move |base_log| {
+            (
+                DecompositionBaseLog(base_log),
+                DecompositionLevelCount(level),
+            )
+        })
+    })
+}
+
+// The complementary error function, with a relative error below 1.2e-7 (Numerical Recipes,
+// `erfcc`).
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1. / (1. + 0.5 * z);
+    let polynomial = -1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+    let value = t * (-z * z + polynomial).exp();
+    if x >= 0. {
+        value
+    } else {
+        2. - value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::tests::assert_float_eq;
+
+    #[test]
+    fn erfc_matches_known_values() {
+        assert_float_eq!(erfc(0.), 1., eps = 1e-7);
+        assert_float_eq!(erfc(1.) / 0.157_299_207_050_285_1, 1., eps = 1e-6);
+        assert_float_eq!(erfc(5.) / 1.537_459_794_428_034_8e-12, 1., eps = 1e-6);
+        assert_float_eq!(erfc(-1.), 2. - 0.157_299_207_050_285_1, eps = 1e-7);
+    }
+
+    #[test]
+    fn decompositions_fit_in_32_bits() {
+        let decompositions: Vec<_> = decompositions_32(DecompositionLevelCount(3)).collect();
+        assert!(decompositions
+            .iter()
+            .all(|(base_log, level)| base_log.0 * level.0 <= 32 && level.0 <= 3));
+        assert!(decompositions.contains(&(DecompositionBaseLog(30), DecompositionLevelCount(1))));
+        assert!(decompositions.contains(&(DecompositionBaseLog(10), DecompositionLevelCount(3))));
+    }
+
+    #[test]
+    fn too_large_messages_are_rejected() {
+        let parameters = PbsParameters {
+            lwe_dimension: LweDimension(586),
+            glwe_dimension: GlweDimension(2),
+            polynomial_size: PolynomialSize(512),
+            lwe_noise: Variance(f64::powi(2., -27)),
+            glwe_noise: Variance(f64::powi(2., -76)),
+            pbs_base_log: DecompositionBaseLog(8),
+            pbs_level: DecompositionLevelCount(5),
+            ks_base_log: DecompositionBaseLog(2),
+            ks_level: DecompositionLevelCount(3),
+            message_bits: MessageBitCount(31),
+        };
+        assert_eq!(
+            downgrade_to_32_bits(&parameters),
+            Err(DowngradeError::MessageTooLarge(MessageBitCount(31)))
+        );
+    }
+}
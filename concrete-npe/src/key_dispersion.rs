@@ -13,7 +13,11 @@ const GAUSSIAN_MODULAR_STDEV: f64 = 3.2;
 
 /// This trait contains functions related to the dispersion of secret key coefficients, and
 /// operations related to the secret keys (e.g., products of secret keys).
-pub trait KeyDispersion: KeyKind {
+///
+/// It is implemented by the key kinds of `concrete-commons`, and by the key distribution markers
+/// of `concrete-core`, so that the estimators can be called with the key distribution of the
+/// entities directly.
+pub trait KeyDispersion {
     /// Returns the variance of key coefficients.
     /// # Example
     ///```rust
@@ -36,10 +36,14 @@
 
 #![allow(clippy::upper_case_acronyms)]
 
+mod cost;
+mod downgrade;
 mod key_dispersion;
 mod operators;
 mod tools;
 
+pub use cost::*;
+pub use downgrade::*;
 pub use key_dispersion::*;
 pub use operators::*;
 pub use tools::*;
@@ -3,7 +3,8 @@
 use concrete_commons::dispersion::{DispersionParameter, Variance};
 use concrete_commons::numeric::{CastInto, UnsignedInteger};
 use concrete_commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    DecompositionBaseLog, DecompositionLevelCount, DenominatorLog, GlweDimension, LweDimension,
+    MessageBound, PolynomialSize, ScalingFactor,
 };
 
 use super::*;
@@ -82,6 +83,53 @@ where
     Variance::from_variance(variance.get_variance() * product)
 }
 
+/// Computes the dispersion of a multiplication of an LWE ciphertext by a rational
+/// $\frac{a}{2^d}$, where every coefficient of the product is rounded to the nearest integer.
+///
+/// The input noise is scaled by the rational, and the rounding of the $n + 1$ coefficients adds a
+/// noise of variance $\frac{1}{12}(1 - 4^{-d})(1 + n \cdot \mathbb{E}\[s\_i^2\])$, in modular
+/// units. The variance is expressed relative to the full modulus of the ciphertext, while the
+/// output message is only defined modulo $q / 2^d$.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::{DispersionParameter, Variance};
+/// use concrete_commons::key_kinds::BinaryKeyKind;
+/// use concrete_commons::parameters::{DenominatorLog, LweDimension};
+/// use concrete_npe::estimate_rational_multiplication_noise;
+/// let variance = Variance(f64::powi(2., -40));
+/// let var_out = estimate_rational_multiplication_noise::<u64, _, BinaryKeyKind>(
+///     variance,
+///     3,
+///     DenominatorLog(2),
+///     LweDimension(630),
+/// );
+/// assert!(var_out.get_variance() >= variance.get_variance() * 9. / 16.);
+/// ```
+pub fn estimate_rational_multiplication_noise<T, D, K>(
+    dispersion_ct: D,
+    numerator: T,
+    denominator_log: DenominatorLog,
+    lwe_mask_size: LweDimension,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D: DispersionParameter,
+    K: KeyDispersion,
+{
+    let signed_numerator: f64 = numerator.into_signed().cast_into();
+    let factor = signed_numerator / f64::powi(2., denominator_log.0 as i32);
+    let n = lwe_mask_size.0 as f64;
+
+    // scaling of the input noise
+    let res_1 = square(factor) * dispersion_ct.get_modular_variance::<T>();
+
+    // rounding of the n + 1 coefficients, the fractional parts being multiples of 2^{-d}
+    let rounding = (1. - f64::powi(4., -(denominator_log.0 as i32))) / 12.;
+    let res_2 = rounding * (1. + n * K::expectation_key_coefficient_squared::<T>());
+
+    Variance::from_modular_variance::<T>(res_1 + res_2)
+}
+
 /// Computes the dispersion of a multisum between
 /// uncorrelated ciphertexts and scalar weights $w_i$ i.e.,  $\sigma_{out}^2 = \sum_i w_i^2 *
 /// \sigma_i^2$.
@@ -142,17 +190,18 @@ where
 /// use concrete_commons::dispersion::Variance;
 /// use concrete_commons::key_kinds::BinaryKeyKind;
 /// use concrete_commons::parameters::{
-///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, MessageBitCount, MessageBound,
+///     PolynomialSize, ScalingFactor,
 /// };
 /// use concrete_npe::estimate_tensor_product_noise;
 /// let dimension = GlweDimension(3);
 /// let polynomial_size = PolynomialSize(1024);
 /// let dispersion_rlwe_0 = Variance::from_modular_variance::<u64>(f64::powi(2., 24));
 /// let dispersion_rlwe_1 = Variance::from_modular_variance::<u64>(f64::powi(2., 24));
-/// let delta_1 = f64::powi(2., 40);
-/// let delta_2 = f64::powi(2., 42);
-/// let max_msg_1 = 15.;
-/// let max_msg_2 = 7.;
+/// let delta_1 = ScalingFactor::from_log2(40);
+/// let delta_2 = ScalingFactor::from_log2(42);
+/// let max_msg_1 = MessageBound::from_message_bit_count(MessageBitCount(4));
+/// let max_msg_2 = MessageBound::from_message_bit_count(MessageBitCount(3));
 /// let var_out = estimate_tensor_product_noise::<u64, _, _, BinaryKeyKind>(
 ///     polynomial_size,
 ///     dimension,
@@ -170,10 +219,10 @@ pub fn estimate_tensor_product_noise<T, D1, D2, K>(
     rlwe_dimension: GlweDimension,
     dispersion_glwe1: D1,
     dispersion_glwe2: D2,
-    delta_1: f64,
-    delta_2: f64,
-    max_msg_1: f64,
-    max_msg_2: f64,
+    delta_1: ScalingFactor,
+    delta_2: ScalingFactor,
+    max_msg_1: MessageBound,
+    max_msg_2: MessageBound,
 ) -> Variance
 where
     T: UnsignedInteger,
@@ -184,30 +233,20 @@ where
     // constants
     let big_n = poly_size.0 as f64;
     let k = rlwe_dimension.0 as f64;
-    let delta = f64::min(delta_1, delta_2);
+    let delta = f64::min(delta_1.to_f64(), delta_2.to_f64());
     let delta_square = square(delta);
-    let q_square = f64::powi(2., (2 * T::BITS) as i32);
-    // #1
-    let res_1 = big_n / delta_square
-        * (dispersion_glwe1.get_modular_variance::<T>() * square(delta_2) * square(max_msg_2)
-            + dispersion_glwe2.get_modular_variance::<T>() * square(delta_1) * square(max_msg_1)
-            + dispersion_glwe1.get_modular_variance::<T>()
-                * dispersion_glwe2.get_modular_variance::<T>());
 
-    // #2
-    let res_2 = (
-        // 1ere parenthese
-        (q_square - 1.) / 12.
-            * (1.
-                + k * big_n * K::variance_key_coefficient::<T>().get_modular_variance::<T>()
-                + k * big_n * square(K::expectation_key_coefficient()))
-            + k * big_n / 4. * K::variance_key_coefficient::<T>().get_modular_variance::<T>()
-            + 1. / 4. * square(1. + k * big_n * K::expectation_key_coefficient())
-    ) * (
-        // 2e parenthese
-        dispersion_glwe1.get_modular_variance::<T>() + dispersion_glwe2.get_modular_variance::<T>()
-    ) * big_n
-        / delta_square;
+    // #1 and #2
+    let res_12 = tensor_product_input_noise::<T, _, _, K>(
+        poly_size,
+        rlwe_dimension,
+        dispersion_glwe1,
+        dispersion_glwe2,
+        delta_1,
+        delta_2,
+        max_msg_1,
+        max_msg_2,
+    );
 
     // #3
     let res_3 = 1. / 12.
@@ -235,7 +274,60 @@ where
                     + K::variance_even_coefficient_in_polynomial_key_squared::<T>(poly_size)
                         .get_modular_variance::<T>()));
 
-    Variance::from_modular_variance::<T>(res_2 + res_1 + res_3)
+    Variance::from_modular_variance::<T>(res_12 + res_3)
+}
+
+// Computes the modular variance of the part of the noise of a tensor product which comes from the
+// noise of the inputs, and from the multiples of the modulus removed when computing their phases.
+// It does not depend on whether the inputs are encrypted under the same key or not.
+#[allow(clippy::too_many_arguments)]
+fn tensor_product_input_noise<T, D1, D2, K>(
+    poly_size: PolynomialSize,
+    rlwe_dimension: GlweDimension,
+    dispersion_glwe1: D1,
+    dispersion_glwe2: D2,
+    delta_1: ScalingFactor,
+    delta_2: ScalingFactor,
+    max_msg_1: MessageBound,
+    max_msg_2: MessageBound,
+) -> f64
+where
+    T: UnsignedInteger,
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    K: KeyDispersion,
+{
+    // constants
+    let big_n = poly_size.0 as f64;
+    let k = rlwe_dimension.0 as f64;
+    let (delta_1, delta_2) = (delta_1.to_f64(), delta_2.to_f64());
+    let (max_msg_1, max_msg_2) = (max_msg_1.0, max_msg_2.0);
+    let delta = f64::min(delta_1, delta_2);
+    let delta_square = square(delta);
+    let q_square = f64::powi(2., (2 * T::BITS) as i32);
+    // #1
+    let res_1 = big_n / delta_square
+        * (dispersion_glwe1.get_modular_variance::<T>() * square(delta_2) * square(max_msg_2)
+            + dispersion_glwe2.get_modular_variance::<T>() * square(delta_1) * square(max_msg_1)
+            + dispersion_glwe1.get_modular_variance::<T>()
+                * dispersion_glwe2.get_modular_variance::<T>());
+
+    // #2
+    let res_2 = (
+        // 1ere parenthese
+        (q_square - 1.) / 12.
+            * (1.
+                + k * big_n * K::variance_key_coefficient::<T>().get_modular_variance::<T>()
+                + k * big_n * square(K::expectation_key_coefficient()))
+            + k * big_n / 4. * K::variance_key_coefficient::<T>().get_modular_variance::<T>()
+            + 1. / 4. * square(1. + k * big_n * K::expectation_key_coefficient())
+    ) * (
+        // 2e parenthese
+        dispersion_glwe1.get_modular_variance::<T>() + dispersion_glwe2.get_modular_variance::<T>()
+    ) * big_n
+        / delta_square;
+
+    res_1 + res_2
 }
 
 /// Computes the dispersion of a GLWE after relinearization.
@@ -317,17 +409,18 @@ where
 /// use concrete_commons::dispersion::Variance;
 /// use concrete_commons::key_kinds::BinaryKeyKind;
 /// use concrete_commons::parameters::{
-///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, MessageBitCount, MessageBound,
+///     PolynomialSize, ScalingFactor,
 /// };
 /// use concrete_npe::estimate_multiplication_noise;
 /// let dimension = GlweDimension(3);
 /// let polynomial_size = PolynomialSize(1024);
 /// let dispersion_rlwe_0 = Variance::from_modular_variance::<u64>(f64::powi(2., 24));
 /// let dispersion_rlwe_1 = Variance::from_modular_variance::<u64>(f64::powi(2., 24));
-/// let delta_1 = f64::powi(2., 40);
-/// let delta_2 = f64::powi(2., 42);
-/// let max_msg_1 = 15.;
-/// let max_msg_2 = 7.;
+/// let delta_1 = ScalingFactor::from_log2(40);
+/// let delta_2 = ScalingFactor::from_log2(42);
+/// let max_msg_1 = MessageBound::from_message_bit_count(MessageBitCount(4));
+/// let max_msg_2 = MessageBound::from_message_bit_count(MessageBitCount(3));
 /// let l_gadget = DecompositionLevelCount(4);
 /// let base_log = DecompositionBaseLog(7);
 /// let dispersion_rlk = Variance(f64::powi(2., -38));
@@ -351,10 +444,10 @@ pub fn estimate_multiplication_noise<T, D1, D2, D3, K>(
     mask_size: GlweDimension,
     dispersion_glwe1: D1,
     dispersion_glwe2: D2,
-    delta_1: f64,
-    delta_2: f64,
-    max_msg_1: f64,
-    max_msg_2: f64,
+    delta_1: ScalingFactor,
+    delta_2: ScalingFactor,
+    max_msg_1: MessageBound,
+    max_msg_2: MessageBound,
     dispersion_rlk: D3,
     base_log: DecompositionBaseLog,
     level: DecompositionLevelCount,
@@ -392,6 +485,258 @@ where
     )
 }
 
+/// Computes the dispersion of a tensor product between two GLWEs encrypted under independent
+/// secret keys, given a set of parameters.
+///
+/// Both keys have the same dimension and the same distribution. Contrary to
+/// [`estimate_tensor_product_noise`], the output is encrypted under the concatenation of the two
+/// input keys and of all their $k^2$ pairwise products, so its noise does not depend on the
+/// squares of the key polynomials.
+/// # Example:
+/// ```rust
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_commons::key_kinds::BinaryKeyKind;
+/// use concrete_commons::parameters::{
+///     GlweDimension, MessageBitCount, MessageBound, PolynomialSize, ScalingFactor,
+/// };
+/// use concrete_npe::estimate_tensor_product_noise_with_independent_keys;
+/// let dimension = GlweDimension(3);
+/// let polynomial_size = PolynomialSize(1024);
+/// let dispersion_rlwe_0 = Variance::from_modular_variance::<u64>(f64::powi(2., 24));
+/// let dispersion_rlwe_1 = Variance::from_modular_variance::<u64>(f64::powi(2., 24));
+/// let delta_1 = ScalingFactor::from_log2(40);
+/// let delta_2 = ScalingFactor::from_log2(42);
+/// let max_msg_1 = MessageBound::from_message_bit_count(MessageBitCount(4));
+/// let max_msg_2 = MessageBound::from_message_bit_count(MessageBitCount(3));
+/// let var_out = estimate_tensor_product_noise_with_independent_keys::<u64, _, _, BinaryKeyKind>(
+///     polynomial_size,
+///     dimension,
+///     dispersion_rlwe_0,
+///     dispersion_rlwe_1,
+///     delta_1,
+///     delta_2,
+///     max_msg_1,
+///     max_msg_2,
+/// );
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn estimate_tensor_product_noise_with_independent_keys<T, D1, D2, K>(
+    poly_size: PolynomialSize,
+    rlwe_dimension: GlweDimension,
+    dispersion_glwe1: D1,
+    dispersion_glwe2: D2,
+    delta_1: ScalingFactor,
+    delta_2: ScalingFactor,
+    max_msg_1: MessageBound,
+    max_msg_2: MessageBound,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    K: KeyDispersion,
+{
+    // constants
+    let big_n = poly_size.0 as f64;
+    let k = rlwe_dimension.0 as f64;
+    let delta = f64::min(delta_1.to_f64(), delta_2.to_f64());
+    let delta_square = square(delta);
+
+    // #1 and #2
+    let res_12 = tensor_product_input_noise::<T, _, _, K>(
+        poly_size,
+        rlwe_dimension,
+        dispersion_glwe1,
+        dispersion_glwe2,
+        delta_1,
+        delta_2,
+        max_msg_1,
+        max_msg_2,
+    );
+
+    // #3: the rounding errors are multiplied by the 2k key polynomials, and by the k^2 products
+    // of two independent key polynomials
+    let res_3 = 1. / 12.
+        + 2. * k * big_n / (12. * delta_square)
+            * ((delta_square - 1.)
+                * (K::variance_key_coefficient::<T>().get_modular_variance::<T>()
+                    + square(K::expectation_key_coefficient()))
+                + 3. * K::variance_key_coefficient::<T>().get_modular_variance::<T>())
+        + k * k * big_n / (12. * delta_square)
+            * ((delta_square - 1.)
+                * (K::variance_coefficient_in_polynomial_key_times_key::<T>(poly_size)
+                    .get_modular_variance::<T>()
+                    + K::square_expectation_mean_in_polynomial_key_times_key(poly_size))
+                + 3. * K::variance_coefficient_in_polynomial_key_times_key::<T>(poly_size)
+                    .get_modular_variance::<T>());
+
+    Variance::from_modular_variance::<T>(res_12 + res_3)
+}
+
+/// Computes the dispersion of a GLWE after the relinearization of a tensor product between two
+/// GLWEs encrypted under independent secret keys.
+///
+/// The relinearization key encrypts the $k^2$ products of two independent key polynomials under
+/// the concatenation of the two keys.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_commons::key_kinds::BinaryKeyKind;
+/// use concrete_commons::parameters::{
+///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+/// };
+/// use concrete_npe::{
+///     estimate_relinearization_noise, estimate_relinearization_noise_with_independent_keys,
+/// };
+/// let dimension = GlweDimension(3);
+/// let l_gadget = DecompositionLevelCount(4);
+/// let base_log = DecompositionBaseLog(7);
+/// let polynomial_size = PolynomialSize(1024);
+/// let dispersion_rlk = Variance(f64::powi(2., -38));
+/// let var_independent =
+///     estimate_relinearization_noise_with_independent_keys::<u64, _, BinaryKeyKind>(
+///         polynomial_size,
+///         dimension,
+///         dispersion_rlk,
+///         base_log,
+///         l_gadget,
+///     );
+/// let var_same = estimate_relinearization_noise::<u64, _, BinaryKeyKind>(
+///     polynomial_size,
+///     dimension,
+///     dispersion_rlk,
+///     base_log,
+///     l_gadget,
+/// );
+/// assert!(var_same.0 < var_independent.0);
+/// ```
+pub fn estimate_relinearization_noise_with_independent_keys<T, D, K>(
+    poly_size: PolynomialSize,
+    glwe_dimension: GlweDimension,
+    dispersion_rlk: D,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D: DispersionParameter,
+    K: KeyDispersion,
+{
+    // constants
+    let big_n = poly_size.0 as f64;
+    let k = glwe_dimension.0 as f64;
+    let base = f64::powi(2., base_log.0 as i32);
+    let q_square = f64::powi(2., (2 * T::BITS) as i32);
+    let var_key_times_key = K::variance_coefficient_in_polynomial_key_times_key::<T>(poly_size)
+        .get_modular_variance::<T>();
+
+    // first term
+    let res_1 = k
+        * k
+        * (level.0 as f64)
+        * big_n
+        * dispersion_rlk.get_modular_variance::<T>()
+        * (square(base) + 2.)
+        / 12.;
+
+    // second term
+    let res_2 = k
+        * k
+        * big_n
+        * (q_square / (12. * f64::powi(base, (2 * level.0) as i32)) - 1. / 12.)
+        * (var_key_times_key + K::square_expectation_mean_in_polynomial_key_times_key(poly_size));
+
+    // third term
+    let res_3 = k * k * big_n / 4. * var_key_times_key;
+
+    Variance::from_modular_variance::<T>(res_1 + res_2 + res_3)
+}
+
+/// Computes the dispersion of a GLWE multiplication between two GLWEs encrypted under independent
+/// secret keys (i.e., a tensor product followed by a relinearization).
+///
+/// The output is encrypted under the concatenation of the two input keys.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_commons::key_kinds::BinaryKeyKind;
+/// use concrete_commons::parameters::{
+///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, MessageBitCount, MessageBound,
+///     PolynomialSize, ScalingFactor,
+/// };
+/// use concrete_npe::estimate_multiplication_noise_with_independent_keys;
+/// let dimension = GlweDimension(3);
+/// let polynomial_size = PolynomialSize(1024);
+/// let dispersion_rlwe_0 = Variance::from_modular_variance::<u64>(f64::powi(2., 24));
+/// let dispersion_rlwe_1 = Variance::from_modular_variance::<u64>(f64::powi(2., 24));
+/// let delta_1 = ScalingFactor::from_log2(40);
+/// let delta_2 = ScalingFactor::from_log2(42);
+/// let max_msg_1 = MessageBound::from_message_bit_count(MessageBitCount(4));
+/// let max_msg_2 = MessageBound::from_message_bit_count(MessageBitCount(3));
+/// let l_gadget = DecompositionLevelCount(4);
+/// let base_log = DecompositionBaseLog(7);
+/// let dispersion_rlk = Variance(f64::powi(2., -38));
+/// let var_out = estimate_multiplication_noise_with_independent_keys::<u64, _, _, _, BinaryKeyKind>(
+///     polynomial_size,
+///     dimension,
+///     dispersion_rlwe_0,
+///     dispersion_rlwe_1,
+///     delta_1,
+///     delta_2,
+///     max_msg_1,
+///     max_msg_2,
+///     dispersion_rlk,
+///     base_log,
+///     l_gadget,
+/// );
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn estimate_multiplication_noise_with_independent_keys<T, D1, D2, D3, K>(
+    poly_size: PolynomialSize,
+    mask_size: GlweDimension,
+    dispersion_glwe1: D1,
+    dispersion_glwe2: D2,
+    delta_1: ScalingFactor,
+    delta_2: ScalingFactor,
+    max_msg_1: MessageBound,
+    max_msg_2: MessageBound,
+    dispersion_rlk: D3,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    D3: DispersionParameter,
+    K: KeyDispersion,
+{
+    // res 1
+    let res_1: Variance = estimate_tensor_product_noise_with_independent_keys::<T, _, _, K>(
+        poly_size,
+        mask_size,
+        dispersion_glwe1,
+        dispersion_glwe2,
+        delta_1,
+        delta_2,
+        max_msg_1,
+        max_msg_2,
+    );
+
+    // res 2
+    let res_2: Variance = estimate_relinearization_noise_with_independent_keys::<T, _, K>(
+        poly_size,
+        mask_size,
+        dispersion_rlk,
+        base_log,
+        level,
+    );
+
+    Variance::from_modular_variance::<T>(
+        res_1.get_modular_variance::<T>() + res_2.get_modular_variance::<T>(),
+    )
+}
+
 /// Computes the dispersion of a modulus switching of an LWE encrypted with binary keys.
 /// # Example
 /// ```rust
@@ -426,6 +771,71 @@ where
     )
 }
 
+/// Computes the dispersion of the gadget product of the mask of an LWE ciphertext with a
+/// keyswitch key, evaluated with the `used_level` most significant levels of the key.
+///
+/// The dispersion is measured against the exact product of the mask with the input key. Every
+/// dropped level removes the noise of its encryptions from the output, but makes the rounding of
+/// the mask coarser by a factor of $B$.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::{DispersionParameter, Variance};
+/// use concrete_commons::key_kinds::BinaryKeyKind;
+/// use concrete_commons::parameters::{
+///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+/// };
+/// use concrete_npe::estimate_gadget_product_noise;
+/// let lwe_mask_size = LweDimension(630);
+/// let base_log = DecompositionBaseLog(7);
+/// let dispersion_ks = Variance(f64::powi(2., -60));
+/// let var_all_levels = estimate_gadget_product_noise::<u64, _, BinaryKeyKind>(
+///     lwe_mask_size,
+///     dispersion_ks,
+///     base_log,
+///     DecompositionLevelCount(4),
+/// );
+/// let var_two_levels = estimate_gadget_product_noise::<u64, _, BinaryKeyKind>(
+///     lwe_mask_size,
+///     dispersion_ks,
+///     base_log,
+///     DecompositionLevelCount(2),
+/// );
+/// assert!(var_two_levels.get_variance() > var_all_levels.get_variance());
+/// ```
+pub fn estimate_gadget_product_noise<T, D, K>(
+    lwe_mask_size: LweDimension,
+    dispersion_ksk: D,
+    base_log: DecompositionBaseLog,
+    used_level: DecompositionLevelCount,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D: DispersionParameter,
+    K: KeyDispersion,
+{
+    let n = lwe_mask_size.0 as f64;
+    let base = (1 << base_log.0) as f64;
+    let q_square = f64::powi(2., (2 * T::BITS) as i32);
+
+    // rounding of the mask to the precision of the used levels
+    let res_1 = n
+        * (q_square / (12. * f64::powi(base, 2 * used_level.0 as i32)) - 1. / 12.)
+        * (K::variance_key_coefficient::<T>().get_modular_variance::<T>()
+            + square(K::expectation_key_coefficient()));
+
+    // variance of the key coefficients
+    let res_2 = n / 4. * K::variance_key_coefficient::<T>().get_modular_variance::<T>();
+
+    // noise of the encryptions of the used levels
+    let res_3 = n
+        * (used_level.0 as f64)
+        * dispersion_ksk.get_modular_variance::<T>()
+        * (square(base) + 2.)
+        / 12.;
+
+    Variance::from_modular_variance::<T>(res_1 + res_2 + res_3)
+}
+
 /// Computes the dispersion of the constant terms of a GLWE after an LWE
 /// to GLWE keyswitch.
 /// # Example
@@ -463,28 +873,9 @@ where
     D2: DispersionParameter,
     K: KeyDispersion,
 {
-    let n = lwe_mask_size.0 as f64;
-    let base = (1 << base_log.0) as f64;
-    let q_square = f64::powi(2., (2 * T::BITS) as i32);
-
-    // res 1
-    let res_1 = dispersion_lwe.get_modular_variance::<T>();
-
-    // res 2
-    let res_2 = n
-        * (q_square / (12. * f64::powi(base, 2 * level.0 as i32)) - 1. / 12.)
-        * (K::variance_key_coefficient::<T>().get_modular_variance::<T>()
-            + square(K::expectation_key_coefficient()));
-
-    // res 3
-    let res_3 = n / 4. * K::variance_key_coefficient::<T>().get_modular_variance::<T>();
-
-    // res 4
-    let res_4 =
-        n * (level.0 as f64) * dispersion_ksk.get_modular_variance::<T>() * (square(base) + 2.)
-            / 12.;
-
-    Variance::from_modular_variance::<T>(res_1 + res_2 + res_3 + res_4)
+    let var_gadget_product =
+        estimate_gadget_product_noise::<T, _, K>(lwe_mask_size, dispersion_ksk, base_log, level);
+    estimate_addition_noise::<T, _, _>(dispersion_lwe, var_gadget_product)
 }
 
 /// Computes the dispersion of the non-constant GLWE terms after an LWE to GLWE keyswitch.
@@ -616,7 +1007,8 @@ where
     Variance::from_modular_variance::<T>(res_1 + res_2 + res_3 + res_4 + res_5)
 }
 
-/// Computes the dispersion of a CMUX controlled with a GGSW encrypting binary keys.
+/// Computes the dispersion of a CMUX controlled with a GGSW encrypting binary keys, averaged over
+/// the value of the key.
 /// # Example
 /// ```rust
 /// use concrete_commons::dispersion::{DispersionParameter, Variance};
@@ -659,15 +1051,75 @@ where
     D3: DispersionParameter,
     K: KeyDispersion,
 {
-    let var_external_product = estimate_external_product_noise_with_binary_ggsw::<T, _, _, K>(
+    // The output is C0 + b * (C1 - C0), whose input noise (1 - b) * E0 + b * E1 is correlated
+    // with the difference. Averaged over the binary message b, its variance is half the one of
+    // the difference, which is already accounted for by the external product estimate.
+    estimate_external_product_noise_with_binary_ggsw::<T, _, _, K>(
         polynomial_size,
         dimension,
         estimate_addition_noise::<T, _, _>(dispersion_rlwe_0, dispersion_rlwe_1),
         dispersion_rgsw,
         base_log,
         l_gadget,
-    );
-    estimate_addition_noise::<T, _, _>(var_external_product, dispersion_rlwe_0)
+    )
+}
+
+/// Computes the dispersion of the ciphertexts after each layer of a permutation network whose
+/// switches are CMUXes controlled with GGSW ciphertexts encrypting binary values. The GGSW
+/// ciphertexts have a polynomial size of one, and the input ciphertexts are assumed to share the
+/// same dispersion.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::{DispersionParameter, Variance};
+/// use concrete_commons::key_kinds::BinaryKeyKind;
+/// use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+/// use concrete_npe::estimate_permutation_network_noise_with_binary_ggsw;
+/// let lwe_dimension = LweDimension(630);
+/// let l_gadget = DecompositionLevelCount(4);
+/// let base_log = DecompositionBaseLog(7);
+/// let dispersion_ggsw = Variance::from_modular_variance::<u64>(f64::powi(2., 26));
+/// let dispersion_lwe = Variance::from_modular_variance::<u64>(f64::powi(2., 25));
+/// // Compute the noise after each of the 5 layers of a network over 8 ciphertexts
+/// let var_layers = estimate_permutation_network_noise_with_binary_ggsw::<u64, _, _, BinaryKeyKind>(
+///     5,
+///     lwe_dimension,
+///     base_log,
+///     l_gadget,
+///     dispersion_lwe,
+///     dispersion_ggsw,
+/// );
+/// assert_eq!(var_layers.len(), 5);
+/// assert!(var_layers[0].get_variance() < var_layers[4].get_variance());
+/// ```
+pub fn estimate_permutation_network_noise_with_binary_ggsw<T, D1, D2, K>(
+    layer_count: usize,
+    lwe_dimension: LweDimension,
+    base_log: DecompositionBaseLog,
+    l_gadget: DecompositionLevelCount,
+    dispersion_lwe: D1,
+    dispersion_ggsw: D2,
+) -> Vec<Variance>
+where
+    T: UnsignedInteger,
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    K: KeyDispersion,
+{
+    let mut dispersion = Variance(dispersion_lwe.get_variance());
+    (0..layer_count)
+        .map(|_| {
+            dispersion = estimate_cmux_noise_with_binary_ggsw::<T, _, _, _, K>(
+                GlweDimension(lwe_dimension.0),
+                PolynomialSize(1),
+                base_log,
+                l_gadget,
+                dispersion,
+                dispersion,
+                dispersion_ggsw,
+            );
+            dispersion
+        })
+        .collect()
 }
 
 /// Computes the dispersion of a PBS *a la TFHE* (i.e., the GGSW encrypts a
@@ -728,6 +1180,99 @@ where
     Variance::from_modular_variance::<T>(res_1 + res_2)
 }
 
+/// Computes an upper bound on the dispersion of the error introduced in a polynomial of uniform
+/// torus coefficients by a forward fourier transform followed by a backward one, computed with
+/// double precision floating point numbers.
+///
+/// Each of the $\log_2(N) + 1$ stages of the two transforms (the butterflies, along with the
+/// twisting of the coefficients) rounds values whose variance is at most $1/12$. A complex
+/// multiply-add by a rounded twiddle factor accumulates a handful of roundings, hence a relative
+/// error of at most $2^{-50}$ per stage. The result is then rounded to the discretized torus.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::DispersionParameter;
+/// use concrete_commons::parameters::PolynomialSize;
+/// use concrete_npe::estimate_fourier_round_trip_noise;
+/// let var_64 = estimate_fourier_round_trip_noise::<u64>(PolynomialSize(1024));
+/// let var_32 = estimate_fourier_round_trip_noise::<u32>(PolynomialSize(1024));
+/// assert!(var_64.get_variance() < f64::powi(2., -90));
+/// // On 32 bits, the rounding to the discretized torus dominates the error.
+/// assert!(var_32.get_variance() < f64::powi(2., -64));
+/// ```
+pub fn estimate_fourier_round_trip_noise<T>(poly_size: PolynomialSize) -> Variance
+where
+    T: UnsignedInteger,
+{
+    let stages = 2. * ((poly_size.0 as f64).log2() + 1.);
+    let relative_error = f64::powi(2., -50);
+    let rounding = f64::powi(2., -2 * (T::BITS as i32)) / 12.;
+    Variance(stages * square(relative_error) / 12. + rounding)
+}
+
+/// Computes an upper bound on the dispersion of the error introduced in the output of a PBS *a la
+/// TFHE* by the external products being computed in the fourier domain, with double precision
+/// floating point numbers.
+///
+/// Each of the $n$ external products of the blind rotation multiplies $(k+1)\cdot\ell$ polynomials
+/// of decomposition digits, whose variance is $(B^2+2)/12$, with the polynomials of a GGSW
+/// ciphertext, whose coefficients are uniform on the torus. The relative error of the fourier
+/// transforms, as modeled in [`estimate_fourier_round_trip_noise`], is amplified by the
+/// $(k+1)\cdot\ell\cdot N$ terms of each output coefficient, which is then rounded to the
+/// discretized torus. This error adds up to the dispersion given by [`estimate_pbs_noise`], which
+/// assumes the external products to be exact.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::DispersionParameter;
+/// use concrete_commons::parameters::{
+///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+/// };
+/// use concrete_npe::estimate_pbs_fft_noise;
+/// let mask_size = LweDimension(630);
+/// let poly_size = PolynomialSize(1024);
+/// let rlwe_mask_size = GlweDimension(1);
+/// let level = DecompositionLevelCount(1);
+/// let var_small_base = estimate_pbs_fft_noise::<u64>(
+///     mask_size,
+///     poly_size,
+///     rlwe_mask_size,
+///     DecompositionBaseLog(7),
+///     level,
+/// );
+/// let var_large_base = estimate_pbs_fft_noise::<u64>(
+///     mask_size,
+///     poly_size,
+///     rlwe_mask_size,
+///     DecompositionBaseLog(23),
+///     level,
+/// );
+/// // The error grows with the magnitude of the decomposition digits.
+/// assert!(var_small_base.get_variance() < var_large_base.get_variance());
+/// assert!(var_large_base.get_variance() > f64::powi(2., -50));
+/// ```
+pub fn estimate_pbs_fft_noise<T>(
+    lwe_mask_size: LweDimension,
+    poly_size: PolynomialSize,
+    rlwe_mask_size: GlweDimension,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+) -> Variance
+where
+    T: UnsignedInteger,
+{
+    let n = lwe_mask_size.0 as f64;
+    let k = rlwe_mask_size.0 as f64;
+    let b = (1_u64 << base_log.0) as f64;
+    let l = level.0 as f64;
+    let big_n = poly_size.0 as f64;
+
+    let stages = 2. * (big_n.log2() + 1.);
+    let relative_error = f64::powi(2., -50);
+    let rounding = f64::powi(2., -2 * (T::BITS as i32)) / 12.;
+    let external_product =
+        (k + 1.) * l * big_n * (square(b) + 2.) / 12. / 12. * stages * square(relative_error);
+    Variance(n * (external_product + rounding))
+}
+
 #[cfg(test)]
 mod tests_estimate_weighted_sum_noise {
     use super::estimate_weighted_sum_noise;
@@ -762,3 +1307,64 @@ mod tests_estimate_weighted_sum_noise {
         assert_float_eq!(47.0, variance_out.get_variance(), eps = 0.001);
     }
 }
+
+#[cfg(test)]
+mod tests_zero_noise {
+    use super::{
+        estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms,
+        estimate_modulus_switching_noise_with_binary_key, estimate_pbs_noise,
+        estimate_rational_multiplication_noise,
+    };
+    use concrete_commons::dispersion::{DispersionParameter, Variance};
+    use concrete_commons::key_kinds::BinaryKeyKind;
+    use concrete_commons::parameters::{
+        DecompositionBaseLog, DecompositionLevelCount, DenominatorLog, GlweDimension, LweDimension,
+        PolynomialSize,
+    };
+    #[test]
+    fn keyswitch_of_noiseless_inputs() {
+        let variance_out =
+            estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<u64, _, _, BinaryKeyKind>(
+                LweDimension(630),
+                Variance(0.),
+                Variance(0.),
+                DecompositionBaseLog(7),
+                DecompositionLevelCount(3),
+            );
+        // Only the rounding of the decomposition remains.
+        assert!(variance_out.get_variance().is_finite());
+        assert!(variance_out.get_variance() > 0.);
+    }
+    #[test]
+    fn pbs_with_noiseless_key() {
+        let variance_out = estimate_pbs_noise::<u64, _, BinaryKeyKind>(
+            LweDimension(630),
+            PolynomialSize(1024),
+            GlweDimension(1),
+            DecompositionBaseLog(7),
+            DecompositionLevelCount(3),
+            Variance(0.),
+        );
+        assert!(variance_out.get_variance().is_finite());
+    }
+    #[test]
+    fn modulus_switching_of_noiseless_input() {
+        let variance_out = estimate_modulus_switching_noise_with_binary_key::<u64, _>(
+            LweDimension(630),
+            11,
+            Variance(0.),
+        );
+        assert!(variance_out.get_variance().is_finite());
+        assert!(variance_out.get_variance() > 0.);
+    }
+    #[test]
+    fn exact_rational_multiplication_of_noiseless_input() {
+        let variance_out = estimate_rational_multiplication_noise::<u64, _, BinaryKeyKind>(
+            Variance(0.),
+            3,
+            DenominatorLog(0),
+            LweDimension(630),
+        );
+        assert_eq!(variance_out.get_variance(), 0.);
+    }
+}
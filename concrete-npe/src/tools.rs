@@ -1,20 +1,44 @@
-use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::dispersion::{DispersionParameter, ModularDistribution};
 use concrete_commons::numeric::UnsignedInteger;
 use std::ops::Mul;
 
-/// Computes the number of bits affected by the noise with a dispersion
-/// describing a normal distribution.
+/// Computes the number of bits affected by the noise with a given dispersion.
+///
+/// For a gaussian distribution, the noise is bounded by four times its standard deviation, with
+/// overwhelming probability. The bounded distributions have their own exact bounds. A zero
+/// dispersion, as used by the trivial and noiseless encryptions, affects no bits.
+///
+/// # Example:
+///
+/// ```rust
+/// use concrete_commons::dispersion::{TUniform, Variance};
+/// use concrete_npe::estimate_number_of_noise_bits;
+/// let gaussian = Variance(2_f64.powf(-50.));
+/// assert_eq!(estimate_number_of_noise_bits::<u64, _>(gaussian), 41);
+/// let t_uniform = TUniform::from_modular_log_bound::<u64>(40);
+/// assert_eq!(estimate_number_of_noise_bits::<u64, _>(t_uniform), 41);
+/// assert_eq!(estimate_number_of_noise_bits::<u64, _>(Variance(0.)), 0);
+/// ```
 pub fn estimate_number_of_noise_bits<T, D>(dispersion: D) -> usize
 where
     D: DispersionParameter,
     T: UnsignedInteger,
 {
-    // get the standard deviation
-    let std_dev: f64 = dispersion.get_modular_standard_dev::<T>();
-
-    // the constant used for the computation
-    let z: f64 = 4.;
-    let tmp = f64::log2(std_dev * z);
+    if dispersion.get_variance() == 0. {
+        // the logarithm of a null bound is not defined
+        return 0;
+    }
+    let bound: f64 = match dispersion.get_modular_distribution::<T>() {
+        ModularDistribution::Gaussian { std } => {
+            // the constant used for the computation
+            let z: f64 = 4.;
+            std * z
+        }
+        // The values of the bounds themselves are reached.
+        ModularDistribution::BoundedUniform { bound } => bound as f64 + 1.,
+        ModularDistribution::TUniform { log2_bound } => f64::powi(2., log2_bound as i32) + 1.,
+    };
+    let tmp = f64::log2(bound);
     if tmp < 0. {
         // means no bits are affected by the noise in the integer representation
         // (discrete space)
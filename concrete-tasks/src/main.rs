@@ -76,6 +76,10 @@ fn main() -> Result<(), std::io::Error> {
             App::new("test_commons").about("Tests the `concrete-commons` crate in native mode"),
         )
         .subcommand(App::new("test_core").about("Tests the `concrete-core` crate in native mode"))
+        .subcommand(
+            App::new("test_cuda")
+                .about("Tests the cuda backend in native mode, failing if no gpu is available"),
+        )
         .subcommand(
             App::new("test_csprng").about("Tests the `concrete-csprng` crate in native mode"),
         )
@@ -151,6 +155,9 @@ fn main() -> Result<(), std::io::Error> {
     if matches.subcommand_matches("test_core").is_some() {
         test::core()?;
     }
+    if matches.subcommand_matches("test_cuda").is_some() {
+        test::cuda()?;
+    }
     if matches.subcommand_matches("test_csprng").is_some() {
         test::csprng()?;
     }
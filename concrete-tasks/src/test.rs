@@ -9,6 +9,19 @@ lazy_static! {
         env.insert("CARGO_INCREMENTAL", "0");
         env.insert("RUSTFLAGS", "-Zprofile -Ccodegen-units=1 -Cinline-threshold=0 -Clink-dead-code -Coverflow-checks=off -Cpanic=abort -Zpanic_abort_tests");
         env.insert("RUSTDOCFLAGS", "-Zprofile -Ccodegen-units=1 -Cinline-threshold=0 -Clink-dead-code -Coverflow-checks=off -Cpanic=abort -Zpanic_abort_tests");
+        env.insert("CONCRETE_INSECURE_DEBUG", "1");
+        env
+    };
+    static ref ENV_INSECURE_DEBUG: Environment = {
+        let mut env = HashMap::new();
+        env.insert("RUSTFLAGS", "-Ctarget-cpu=native");
+        env.insert("CONCRETE_INSECURE_DEBUG", "1");
+        env
+    };
+    static ref ENV_CUDA_REQUIRED: Environment = {
+        let mut env = HashMap::new();
+        env.insert("RUSTFLAGS", "-Ctarget-cpu=native");
+        env.insert("CONCRETE_CUDA_REQUIRED", "1");
         env
     };
 }
@@ -22,11 +35,15 @@ pub fn commons() -> Result<(), Error> {
 }
 
 pub fn core() -> Result<(), Error> {
-    cmd!(<ENV_TARGET_NATIVE> "cargo test --release --no-fail-fast --all-features -p concrete-core")
+    cmd!(<ENV_INSECURE_DEBUG> "cargo test --release --no-fail-fast --all-features -p concrete-core")
 }
 
 pub fn core_test() -> Result<(), Error> {
-    cmd!(<ENV_TARGET_NATIVE> "cargo test --release --no-fail-fast --all-features -p concrete-core-test")
+    cmd!(<ENV_INSECURE_DEBUG> "cargo test --release --no-fail-fast --all-features -p concrete-core-test")
+}
+
+pub fn cuda() -> Result<(), Error> {
+    cmd!(<ENV_CUDA_REQUIRED> "cargo test --release --no-fail-fast --features backend_cuda -p concrete-core-test cuda::")
 }
 
 pub fn csprng() -> Result<(), Error> {